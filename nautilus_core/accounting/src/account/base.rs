@@ -15,6 +15,7 @@
 
 use std::collections::HashMap;
 
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 use nautilus_model::{
     enums::{AccountType, LiquiditySide, OrderSide},
     events::{account::state::AccountState, order::filled::OrderFilled},
@@ -22,7 +23,12 @@ use nautilus_model::{
     instruments::any::InstrumentAny,
     position::Position,
     types::{
-        balance::AccountBalance, currency::Currency, money::Money, price::Price, quantity::Quantity,
+        balance::{AccountBalance, MarginBalance},
+        currency::Currency,
+        money::Money,
+        price::Price,
+        quantity::Quantity,
+        rounding::get_rounding_policy,
     },
 };
 use rust_decimal::prelude::ToPrimitive;
@@ -138,6 +144,93 @@ impl BaseAccount {
         self.events.push(event);
     }
 
+    /// Adds the given `commission` to the total commissions for its currency.
+    pub fn update_commissions(&mut self, commission: Money) {
+        if commission.is_zero() {
+            return;
+        }
+        *self.commissions.entry(commission.currency).or_insert(0.0) += commission.as_f64();
+    }
+
+    /// Updates the balances with the realized `pnls` and `commission` from a fill.
+    ///
+    /// A multi-currency account opens a balance for any new currency, whereas a
+    /// single-currency account must already hold a balance in each currency.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if there is no balance for a currency on a
+    /// single-currency account, or a balance total would become negative.
+    pub fn update_balances_from_fill(
+        &mut self,
+        pnls: &[Money],
+        commission: Option<Money>,
+    ) -> anyhow::Result<()> {
+        let mut deltas: HashMap<Currency, Money> = HashMap::new();
+        for pnl in pnls {
+            *deltas
+                .entry(pnl.currency)
+                .or_insert_with(|| Money::from_raw(0, pnl.currency)) += *pnl;
+        }
+        if let Some(commission) = commission {
+            *deltas
+                .entry(commission.currency)
+                .or_insert_with(|| Money::from_raw(0, commission.currency)) -= commission;
+        }
+
+        let mut balances = Vec::with_capacity(deltas.len());
+        for (currency, delta) in deltas {
+            if delta.is_zero() {
+                continue;
+            }
+            let balance = match self.balances.get(&currency) {
+                Some(balance) => *balance,
+                None if self.base_currency.is_none() => {
+                    let zero = Money::from_raw(0, currency);
+                    AccountBalance::new(zero, zero, zero)?
+                }
+                None => anyhow::bail!(
+                    "No {currency} balance for single-currency account {}",
+                    self.id
+                ),
+            };
+            let total = balance.total + delta;
+            if total.raw < 0 {
+                anyhow::bail!(
+                    "Cannot update {currency} balance for account {}, total would be {total}",
+                    self.id
+                );
+            }
+            balances.push(AccountBalance::new(
+                total,
+                balance.locked,
+                total - balance.locked,
+            )?);
+        }
+        self.update_balances(balances);
+        Ok(())
+    }
+
+    /// Returns a new calculated [`AccountState`] from the current balances and the given
+    /// `margins`.
+    pub fn base_generate_account_state(
+        &self,
+        margins: Vec<MarginBalance>,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<AccountState> {
+        AccountState::new(
+            self.id,
+            self.account_type,
+            self.balances.values().copied().collect(),
+            margins,
+            false,
+            UUID4::new(),
+            ts_event,
+            ts_event,
+            self.base_currency,
+        )
+    }
+
     pub fn base_calculate_balance_locked(
         &mut self,
         instrument: InstrumentAny,
@@ -237,10 +330,11 @@ impl BaseAccount {
         } else {
             panic!("Invalid `LiquiditySide` {liquidity_side}")
         };
-        if instrument.is_inverse() && !use_quote_for_inverse.unwrap_or(false) {
-            Ok(Money::new(commission, instrument.base_currency().unwrap()).unwrap())
+        let currency = if instrument.is_inverse() && !use_quote_for_inverse.unwrap_or(false) {
+            instrument.base_currency().unwrap()
         } else {
-            Ok(Money::new(commission, instrument.quote_currency()).unwrap())
-        }
+            instrument.quote_currency()
+        };
+        get_rounding_policy(&instrument.id()).round_fee(commission, currency)
     }
 }
@@ -20,11 +20,13 @@ use std::{
 };
 
 use nautilus_common::interface::account::Account;
+use nautilus_core::nanos::UnixNanos;
 use nautilus_model::{
     enums::{AccountType, LiquiditySide, OrderSide},
     events::{account::state::AccountState, order::filled::OrderFilled},
-    identifiers::account_id::AccountId,
+    identifiers::{account_id::AccountId, instrument_id::InstrumentId},
     instruments::any::InstrumentAny,
+    orders::any::OrderAny,
     position::Position,
     types::{
         balance::AccountBalance, currency::Currency, money::Money, price::Price, quantity::Quantity,
@@ -40,6 +42,7 @@ use crate::account::base::BaseAccount;
 )]
 pub struct CashAccount {
     pub base: BaseAccount,
+    pub balances_locked: HashMap<(InstrumentId, Currency), Money>,
 }
 
 impl CashAccount {
@@ -47,6 +50,7 @@ impl CashAccount {
     pub fn new(event: AccountState, calculate_account_state: bool) -> anyhow::Result<Self> {
         Ok(Self {
             base: BaseAccount::new(event, calculate_account_state)?,
+            balances_locked: HashMap::new(),
         })
     }
 
@@ -63,6 +67,127 @@ impl CashAccount {
     pub fn is_unleveraged(&self) -> bool {
         false
     }
+
+    /// Updates the balance locked for the given `instrument_id` in the currency of `locked`,
+    /// then recalculates the balance for the currency.
+    pub fn update_balance_locked(
+        &mut self,
+        instrument_id: InstrumentId,
+        locked: Money,
+    ) -> anyhow::Result<()> {
+        if locked.raw < 0 {
+            anyhow::bail!("Invalid locked balance for {instrument_id}, was {locked}");
+        }
+        self.balances_locked
+            .insert((instrument_id, locked.currency), locked);
+        self.recalculate_balance(locked.currency)
+    }
+
+    /// Clears all balances locked for the given `instrument_id`, then recalculates the
+    /// balances for the affected currencies.
+    pub fn clear_balance_locked(&mut self, instrument_id: InstrumentId) -> anyhow::Result<()> {
+        let keys: Vec<(InstrumentId, Currency)> = self
+            .balances_locked
+            .keys()
+            .filter(|(id, _)| *id == instrument_id)
+            .copied()
+            .collect();
+        for key in keys {
+            self.balances_locked.remove(&key);
+            self.recalculate_balance(key.1)?;
+        }
+        Ok(())
+    }
+
+    /// Recalculates the locked and free balance for the given `currency` from the balances
+    /// locked for all instruments.
+    pub fn recalculate_balance(&mut self, currency: Currency) -> anyhow::Result<()> {
+        let Some(balance) = self.balances.get(&currency).copied() else {
+            anyhow::bail!("Cannot recalculate balance when no current {currency} balance");
+        };
+
+        let total_locked: i64 = self
+            .balances_locked
+            .values()
+            .filter(|locked| locked.currency == currency)
+            .map(|locked| locked.raw)
+            .sum();
+        let locked = Money::from_raw(total_locked, currency);
+        let free = balance.total - locked;
+        if free.raw < 0 {
+            anyhow::bail!(
+                "Cannot lock {locked} when the balance total is {}",
+                balance.total
+            );
+        }
+        self.balances
+            .insert(currency, AccountBalance::new(balance.total, locked, free)?);
+        Ok(())
+    }
+
+    /// Updates the balances locked for the given `instrument` from its open orders, and
+    /// returns the resulting account state.
+    ///
+    /// Orders without a price or trigger price (e.g. market orders) do not lock a balance.
+    pub fn update_orders(
+        &mut self,
+        instrument: &InstrumentAny,
+        orders_open: &[&OrderAny],
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<AccountState> {
+        let instrument_id = instrument.id();
+        let mut total_locked: HashMap<Currency, Money> = HashMap::new();
+        for order in orders_open {
+            if order.instrument_id() != instrument_id {
+                anyhow::bail!(
+                    "Order {} is not for instrument {instrument_id}",
+                    order.client_order_id()
+                );
+            }
+            let Some(price) = order.price().or_else(|| order.trigger_price()) else {
+                continue;
+            };
+            let locked = self.calculate_balance_locked(
+                instrument.clone(),
+                order.order_side(),
+                order.leaves_qty(),
+                price,
+                None,
+            )?;
+            *total_locked
+                .entry(locked.currency)
+                .or_insert_with(|| Money::from_raw(0, locked.currency)) += locked;
+        }
+
+        self.clear_balance_locked(instrument_id)?;
+        for locked in total_locked.into_values() {
+            self.update_balance_locked(instrument_id, locked)?;
+        }
+        self.generate_account_state(ts_event)
+    }
+
+    /// Updates the balances and commissions for the given `fill`, and returns the resulting
+    /// account state.
+    ///
+    /// The `position` is the position for the fill (if any) prior to the fill being applied.
+    pub fn apply_fill(
+        &mut self,
+        instrument: &InstrumentAny,
+        fill: &OrderFilled,
+        position: Option<&Position>,
+    ) -> anyhow::Result<AccountState> {
+        let pnls = self.calculate_pnls(instrument.clone(), *fill, position.cloned())?;
+        self.update_balances_from_fill(&pnls, fill.commission)?;
+        if let Some(commission) = fill.commission {
+            self.update_commissions(commission);
+        }
+        self.generate_account_state(fill.ts_event)
+    }
+
+    /// Returns a new calculated [`AccountState`] from the current balances.
+    pub fn generate_account_state(&self, ts_event: UnixNanos) -> anyhow::Result<AccountState> {
+        self.base_generate_account_state(vec![], ts_event)
+    }
 }
 
 impl Account for CashAccount {
@@ -87,7 +212,7 @@ impl Account for CashAccount {
     }
 
     fn calculated_account_state(&self) -> bool {
-        false // TODO (implement this logic)
+        self.calculate_account_state
     }
 
     fn balance_total(&self, currency: Option<Currency>) -> Option<Money> {
@@ -233,7 +358,7 @@ mod tests {
             any::InstrumentAny, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
             equity::Equity, stubs::*, Instrument,
         },
-        orders::stubs::TestOrderEventStubs,
+        orders::stubs::{TestOrderEventStubs, TestOrderStubs},
         position::Position,
         types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
     };
@@ -621,4 +746,81 @@ mod tests {
             .unwrap();
         assert_eq!(result, Money::from("5294 JPY"));
     }
+
+    #[rstest]
+    fn test_update_orders_locks_balance(
+        mut cash_account_million_usd: CashAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = TestOrderStubs::limit_order(
+            audusd_sim.id(),
+            OrderSide::Buy,
+            Price::from("0.80000"),
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+
+        let state = cash_account_million_usd
+            .update_orders(&audusd_sim, &[&order], 1.into())
+            .unwrap();
+
+        assert!(!state.is_reported);
+        assert_eq!(state.balances.len(), 1);
+        assert_eq!(
+            cash_account_million_usd.balance_locked(None),
+            Some(Money::from("80003.20 USD"))
+        );
+        assert_eq!(
+            cash_account_million_usd.balance_free(None),
+            Some(Money::from("919996.80 USD"))
+        );
+
+        cash_account_million_usd
+            .update_orders(&audusd_sim, &[], 2.into())
+            .unwrap();
+
+        assert_eq!(
+            cash_account_million_usd.balance_locked(None),
+            Some(Money::from("0 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_apply_fill_updates_balance_and_commissions(
+        mut cash_account_million_usd: CashAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = TestOrderStubs::market_order(
+            audusd_sim.id(),
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &audusd_sim,
+            None,
+            None,
+            Some(Price::from("0.80000")),
+            None,
+            Some(Money::from("2 USD")),
+            None,
+            None,
+        );
+
+        let state = cash_account_million_usd
+            .apply_fill(&audusd_sim, &fill.into(), None)
+            .unwrap();
+
+        assert_eq!(state.balances[0].total, Money::from("919998 USD"));
+        assert_eq!(
+            cash_account_million_usd.balance_free(None),
+            Some(Money::from("919998 USD"))
+        );
+        assert_eq!(cash_account_million_usd.commissions[&Currency::USD()], 2.0);
+    }
 }
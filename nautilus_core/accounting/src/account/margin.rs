@@ -23,11 +23,14 @@ use std::{
 };
 
 use nautilus_common::interface::account::Account;
+use nautilus_core::nanos::UnixNanos;
 use nautilus_model::{
+    data::borrow::BorrowRateUpdate,
     enums::{AccountType, LiquiditySide, OrderSide},
     events::{account::state::AccountState, order::filled::OrderFilled},
     identifiers::{account_id::AccountId, instrument_id::InstrumentId},
     instruments::{any::InstrumentAny, Instrument},
+    orders::any::OrderAny,
     position::Position,
     types::{
         balance::{AccountBalance, MarginBalance},
@@ -51,6 +54,11 @@ pub struct MarginAccount {
     pub leverages: HashMap<InstrumentId, f64>,
     pub margins: HashMap<InstrumentId, MarginBalance>,
     pub default_leverage: f64,
+    pub borrowed: HashMap<Currency, Money>,
+    pub borrow_rates: HashMap<Currency, BorrowRateUpdate>,
+    pub interest_accrued: HashMap<Currency, Money>,
+    interest_accrued_to: HashMap<Currency, UnixNanos>,
+    position_borrowed: HashMap<InstrumentId, Money>,
 }
 
 impl MarginAccount {
@@ -61,6 +69,11 @@ impl MarginAccount {
             leverages: HashMap::new(),
             margins: HashMap::new(),
             default_leverage: 1.0,
+            borrowed: HashMap::new(),
+            borrow_rates: HashMap::new(),
+            interest_accrued: HashMap::new(),
+            interest_accrued_to: HashMap::new(),
+            position_borrowed: HashMap::new(),
         })
     }
 
@@ -186,22 +199,10 @@ impl MarginAccount {
         use_quote_for_inverse: Option<bool>,
     ) -> Money {
         let notional = instrument.calculate_notional_value(quantity, price, use_quote_for_inverse);
-        let leverage = self.get_leverage(&instrument.id());
-        if leverage == 0.0 {
-            self.leverages
-                .insert(instrument.id(), self.default_leverage);
-        }
-        let adjusted_notional = notional / leverage;
-        let initial_margin_f64 = instrument.margin_init().to_f64().unwrap();
-        let mut margin = adjusted_notional * initial_margin_f64;
-        // add taker fee
-        margin += adjusted_notional * instrument.taker_fee().to_f64().unwrap() * 2.0;
-        let use_quote_for_inverse = use_quote_for_inverse.unwrap_or(false);
-        if instrument.is_inverse() && !use_quote_for_inverse {
-            Money::new(margin, instrument.base_currency().unwrap()).unwrap()
-        } else {
-            Money::new(margin, instrument.quote_currency()).unwrap()
-        }
+        let margin_rate = instrument.margin_init().to_f64().unwrap();
+        // Add taker fee for opening and closing
+        let fee_rate = instrument.taker_fee().to_f64().unwrap() * 2.0;
+        self.calculate_margin(instrument.id(), notional, margin_rate, fee_rate)
     }
 
     pub fn calculate_maintenance_margin<T: Instrument>(
@@ -212,22 +213,220 @@ impl MarginAccount {
         use_quote_for_inverse: Option<bool>,
     ) -> Money {
         let notional = instrument.calculate_notional_value(quantity, price, use_quote_for_inverse);
-        let leverage = self.get_leverage(&instrument.id());
+        let margin_rate = instrument.margin_maint().to_f64().unwrap();
+        // Add taker fee for closing
+        let fee_rate = instrument.taker_fee().to_f64().unwrap();
+        self.calculate_margin(instrument.id(), notional, margin_rate, fee_rate)
+    }
+
+    fn calculate_margin(
+        &mut self,
+        instrument_id: InstrumentId,
+        notional: Money,
+        margin_rate: f64,
+        fee_rate: f64,
+    ) -> Money {
+        let mut leverage = self.get_leverage(&instrument_id);
         if leverage == 0.0 {
-            self.leverages
-                .insert(instrument.id(), self.default_leverage);
+            self.leverages.insert(instrument_id, self.default_leverage);
+            leverage = self.default_leverage;
         }
         let adjusted_notional = notional / leverage;
-        let margin_maint_f64 = instrument.margin_maint().to_f64().unwrap();
-        let mut margin = adjusted_notional * margin_maint_f64;
-        // Add taker fee
-        margin += adjusted_notional * instrument.taker_fee().to_f64().unwrap();
-        let use_quote_for_inverse = use_quote_for_inverse.unwrap_or(false);
-        if instrument.is_inverse() && !use_quote_for_inverse {
-            Money::new(margin, instrument.base_currency().unwrap()).unwrap()
-        } else {
-            Money::new(margin, instrument.quote_currency()).unwrap()
+        let margin = adjusted_notional * margin_rate + adjusted_notional * fee_rate;
+        Money::new(margin, notional.currency).unwrap()
+    }
+
+    /// Updates the initial margin for the given `instrument` from its open orders, and
+    /// returns the resulting account state.
+    ///
+    /// Orders without a price or trigger price (e.g. market orders) do not require margin.
+    pub fn update_orders(
+        &mut self,
+        instrument: &InstrumentAny,
+        orders_open: &[&OrderAny],
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<AccountState> {
+        let instrument_id = instrument.id();
+        let margin_rate = instrument.margin_init().to_f64().unwrap_or(0.0);
+        let fee_rate = instrument.taker_fee().to_f64().unwrap_or(0.0) * 2.0;
+
+        let mut total_margin = Money::from_raw(0, margin_currency(instrument));
+        for order in orders_open {
+            if order.instrument_id() != instrument_id {
+                anyhow::bail!(
+                    "Order {} is not for instrument {instrument_id}",
+                    order.client_order_id()
+                );
+            }
+            let Some(price) = order.price().or_else(|| order.trigger_price()) else {
+                continue;
+            };
+            let notional = instrument.calculate_notional_value(order.leaves_qty(), price, None);
+            total_margin += self.calculate_margin(instrument_id, notional, margin_rate, fee_rate);
+        }
+
+        self.update_initial_margin(instrument_id, total_margin);
+        self.generate_account_state(ts_event)
+    }
+
+    /// Updates the maintenance margin for the given `instrument` from its open positions,
+    /// and returns the resulting account state.
+    ///
+    /// The notional of the open positions not covered by margin (i.e. after leverage) is
+    /// borrowed in the margin currency, and accrues interest at the borrow rate.
+    pub fn update_positions(
+        &mut self,
+        instrument: &InstrumentAny,
+        positions_open: &[&Position],
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<AccountState> {
+        let instrument_id = instrument.id();
+        let margin_rate = instrument.margin_maint().to_f64().unwrap_or(0.0);
+        let fee_rate = instrument.taker_fee().to_f64().unwrap_or(0.0);
+
+        let currency = margin_currency(instrument);
+        let mut total_margin = Money::from_raw(0, currency);
+        let mut total_borrowed = 0.0;
+        for position in positions_open {
+            if position.instrument_id != instrument_id {
+                anyhow::bail!(
+                    "Position {} is not for instrument {instrument_id}",
+                    position.id
+                );
+            }
+            let price = instrument.make_price(position.avg_px_open)?;
+            let notional = instrument.calculate_notional_value(position.quantity, price, None);
+            total_margin += self.calculate_margin(instrument_id, notional, margin_rate, fee_rate);
+            total_borrowed += notional.as_f64() * (1.0 - 1.0 / self.get_leverage(&instrument_id));
+        }
+
+        self.update_maintenance_margin(instrument_id, total_margin);
+        self.update_position_borrowed(
+            instrument_id,
+            Money::new(total_borrowed, currency)?,
+            ts_event,
+        )?;
+        self.generate_account_state(ts_event)
+    }
+
+    fn update_position_borrowed(
+        &mut self,
+        instrument_id: InstrumentId,
+        borrowed: Money,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        self.position_borrowed.insert(instrument_id, borrowed);
+        let total = self
+            .position_borrowed
+            .values()
+            .filter(|amount| amount.currency == borrowed.currency)
+            .fold(Money::from_raw(0, borrowed.currency), |total, amount| {
+                total + *amount
+            });
+        self.update_borrowed(total, ts_event)?;
+        Ok(())
+    }
+
+    /// Updates the balances and commissions for the given `fill`, and returns the resulting
+    /// account state.
+    ///
+    /// The `position` is the position for the fill (if any) prior to the fill being applied.
+    pub fn apply_fill(
+        &mut self,
+        instrument: &InstrumentAny,
+        fill: &OrderFilled,
+        position: Option<&Position>,
+    ) -> anyhow::Result<AccountState> {
+        let pnls = self.calculate_pnls(instrument.clone(), *fill, position.cloned())?;
+        self.update_balances_from_fill(&pnls, fill.commission)?;
+        if let Some(commission) = fill.commission {
+            self.update_commissions(commission);
+        }
+        self.generate_account_state(fill.ts_event)
+    }
+
+    /// Returns a new calculated [`AccountState`] from the current balances and margins.
+    pub fn generate_account_state(&self, ts_event: UnixNanos) -> anyhow::Result<AccountState> {
+        self.base_generate_account_state(self.margins.values().copied().collect(), ts_event)
+    }
+
+    /// Updates the borrow rate for the rate currency, first accruing interest at the previous
+    /// rate up to the time of the update, and returns the interest accrued (if any).
+    pub fn update_borrow_rate(
+        &mut self,
+        borrow_rate: BorrowRateUpdate,
+    ) -> anyhow::Result<Option<Money>> {
+        let currency = borrow_rate.currency;
+        let ts_event = borrow_rate.ts_event;
+        let accrued = self.accrue_currency_interest(currency, ts_event)?;
+        self.borrow_rates.insert(currency, borrow_rate);
+        self.interest_accrued_to.insert(currency, ts_event);
+        Ok(accrued)
+    }
+
+    /// Updates the `borrowed` amount for its currency, first accruing interest on the previous
+    /// amount up to `ts_event`, and returns the interest accrued (if any).
+    pub fn update_borrowed(
+        &mut self,
+        borrowed: Money,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<Option<Money>> {
+        let currency = borrowed.currency;
+        let accrued = self.accrue_currency_interest(currency, ts_event)?;
+        self.borrowed.insert(currency, borrowed);
+        self.interest_accrued_to.insert(currency, ts_event);
+        Ok(accrued)
+    }
+
+    /// Accrues interest on all borrowed balances up to `ts_now`, deducting it from the account
+    /// balances, and returns the interest accrued for each currency.
+    pub fn accrue_interest(&mut self, ts_now: UnixNanos) -> anyhow::Result<Vec<Money>> {
+        let currencies: Vec<Currency> = self.borrowed.keys().copied().collect();
+        let mut accrued = Vec::new();
+        for currency in currencies {
+            if let Some(interest) = self.accrue_currency_interest(currency, ts_now)? {
+                accrued.push(interest);
+            }
+        }
+        Ok(accrued)
+    }
+
+    fn accrue_currency_interest(
+        &mut self,
+        currency: Currency,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<Option<Money>> {
+        let (Some(borrowed), Some(borrow_rate), Some(accrued_to)) = (
+            self.borrowed.get(&currency),
+            self.borrow_rates.get(&currency),
+            self.interest_accrued_to.get(&currency).copied(),
+        ) else {
+            return Ok(None);
+        };
+        if ts_now <= accrued_to || borrowed.is_zero() {
+            return Ok(None);
+        }
+
+        let interest = borrow_rate.interest(*borrowed, ts_now.as_u64() - accrued_to.as_u64())?;
+        self.interest_accrued_to.insert(currency, ts_now);
+        self.interest_accrued
+            .entry(currency)
+            .and_modify(|total| *total += interest)
+            .or_insert(interest);
+
+        if let Some(balance) = self.balances.get(&currency).copied() {
+            let total = balance.total - interest;
+            if total.raw < 0 {
+                anyhow::bail!(
+                    "Cannot accrue interest {interest} on balance {}",
+                    balance.total
+                );
+            }
+            let new_balance = AccountBalance::new(total, balance.locked, balance.free - interest)?;
+            self.balances.insert(currency, new_balance);
         }
+
+        Ok(Some(interest))
     }
 
     pub fn recalculate_balance(&mut self, currency: Currency) {
@@ -260,6 +459,17 @@ impl MarginAccount {
     }
 }
 
+/// Returns the currency in which margin is held for the given `instrument`.
+fn margin_currency(instrument: &InstrumentAny) -> Currency {
+    if instrument.is_inverse() {
+        instrument
+            .base_currency()
+            .unwrap_or(instrument.quote_currency())
+    } else {
+        instrument.quote_currency()
+    }
+}
+
 impl Deref for MarginAccount {
     type Target = BaseAccount;
 
@@ -296,7 +506,7 @@ impl Account for MarginAccount {
     }
 
     fn calculated_account_state(&self) -> bool {
-        false // TODO (implement this logic)
+        self.calculate_account_state
     }
 
     fn balance_total(&self, currency: Option<Currency>) -> Option<Money> {
@@ -352,11 +562,22 @@ impl Account for MarginAccount {
     }
     fn calculate_pnls(
         &self,
-        instrument: InstrumentAny,
+        _instrument: InstrumentAny,
         fill: OrderFilled,
         position: Option<Position>,
     ) -> anyhow::Result<Vec<Money>> {
-        self.base_calculate_pnls(instrument, fill, position)
+        // Only a fill reducing an open position realizes PnL
+        let pnls = match position {
+            Some(position) if !position.quantity.is_zero() && position.entry != fill.order_side => {
+                vec![position.calculate_pnl(
+                    position.avg_px_open,
+                    fill.last_px.as_f64(),
+                    position.quantity.min(fill.last_qty),
+                )]
+            }
+            _ => vec![],
+        };
+        Ok(pnls)
     }
     fn calculate_commission(
         &self,
@@ -374,6 +595,27 @@ impl Account for MarginAccount {
             use_quote_for_inverse,
         )
     }
+
+    fn apply_borrow_rate(
+        &mut self,
+        borrow_rate: BorrowRateUpdate,
+    ) -> anyhow::Result<Option<AccountState>> {
+        let ts_event = borrow_rate.ts_event;
+        match self.update_borrow_rate(borrow_rate)? {
+            Some(_) => Ok(Some(self.generate_account_state(ts_event)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn accrue_borrow_interest(
+        &mut self,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<Option<AccountState>> {
+        if self.accrue_interest(ts_now)?.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(self.generate_account_state(ts_now)?))
+    }
 }
 
 impl PartialEq for MarginAccount {
@@ -413,10 +655,23 @@ mod tests {
     use std::collections::HashMap;
 
     use nautilus_common::interface::account::Account;
+    use nautilus_core::nanos::UnixNanos;
     use nautilus_model::{
-        events::account::{state::AccountState, stubs::*},
-        identifiers::{instrument_id::InstrumentId, stubs::*},
-        instruments::{crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair, stubs::*},
+        data::borrow::{BorrowRateUpdate, NANOSECONDS_IN_YEAR},
+        enums::OrderSide,
+        events::{
+            account::{state::AccountState, stubs::*},
+            order::filled::OrderFilled,
+        },
+        identifiers::{
+            client_order_id::ClientOrderId, instrument_id::InstrumentId, stubs::*, venue::Venue,
+        },
+        instruments::{
+            any::InstrumentAny, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
+            stubs::*,
+        },
+        orders::stubs::{TestOrderEventStubs, TestOrderStubs},
+        position::Position,
         types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
     };
     use rstest::rstest;
@@ -560,6 +815,147 @@ mod tests {
         assert_eq!(margins, vec![margin]);
     }
 
+    fn usd_borrow_rate(rate: f64, ts_event: u64) -> BorrowRateUpdate {
+        BorrowRateUpdate::new(
+            Venue::from("SIM"),
+            Currency::USD(),
+            rate,
+            UnixNanos::from(ts_event),
+            UnixNanos::from(ts_event),
+        )
+    }
+
+    #[rstest]
+    fn test_accrue_interest(mut margin_account: MarginAccount) {
+        let one_day_ns = NANOSECONDS_IN_YEAR / 365;
+        margin_account
+            .update_borrow_rate(usd_borrow_rate(0.0365, 0))
+            .unwrap();
+        margin_account
+            .update_borrowed(Money::from("100000 USD"), UnixNanos::default())
+            .unwrap();
+
+        let accrued = margin_account
+            .accrue_interest(UnixNanos::from(one_day_ns))
+            .unwrap();
+
+        assert_eq!(accrued, vec![Money::from("10 USD")]);
+        assert_eq!(
+            margin_account.interest_accrued.get(&Currency::USD()),
+            Some(&Money::from("10 USD"))
+        );
+        assert_eq!(
+            margin_account.balance_total(None),
+            Some(Money::from("1524990 USD"))
+        );
+        assert_eq!(
+            margin_account.balance_free(None),
+            Some(Money::from("1499990 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_accrue_interest_across_rate_change(mut margin_account: MarginAccount) {
+        let one_day_ns = NANOSECONDS_IN_YEAR / 365;
+        margin_account
+            .update_borrow_rate(usd_borrow_rate(0.0365, 0))
+            .unwrap();
+        margin_account
+            .update_borrowed(Money::from("100000 USD"), UnixNanos::default())
+            .unwrap();
+        margin_account
+            .update_borrow_rate(usd_borrow_rate(0.073, one_day_ns))
+            .unwrap();
+
+        let accrued = margin_account
+            .accrue_interest(UnixNanos::from(2 * one_day_ns))
+            .unwrap();
+
+        assert_eq!(accrued, vec![Money::from("20 USD")]);
+        assert_eq!(
+            margin_account.interest_accrued.get(&Currency::USD()),
+            Some(&Money::from("30 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_accrue_interest_with_no_borrow_rate(mut margin_account: MarginAccount) {
+        margin_account
+            .update_borrowed(Money::from("100000 USD"), UnixNanos::default())
+            .unwrap();
+
+        let accrued = margin_account
+            .accrue_interest(UnixNanos::from(NANOSECONDS_IN_YEAR))
+            .unwrap();
+
+        assert!(accrued.is_empty());
+        assert_eq!(
+            margin_account.balance_total(None),
+            Some(Money::from("1525000 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_update_positions_borrows_leveraged_notional(
+        mut margin_account: MarginAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let one_day_ns = NANOSECONDS_IN_YEAR / 365;
+        let order = TestOrderStubs::market_order(
+            audusd_sim.id(),
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+        let fill: OrderFilled = TestOrderEventStubs::order_filled(
+            &order,
+            &audusd_sim,
+            None,
+            None,
+            Some(Price::from("0.80000")),
+            None,
+            None,
+            None,
+            None,
+        )
+        .into();
+        let position = Position::new(&audusd_sim, fill).unwrap();
+        margin_account.set_leverage(audusd_sim.id(), 10.0);
+        margin_account
+            .update_borrow_rate(usd_borrow_rate(0.0365, 0))
+            .unwrap();
+
+        margin_account
+            .update_positions(&audusd_sim, &[&position], UnixNanos::default())
+            .unwrap();
+        let state = margin_account
+            .accrue_borrow_interest(UnixNanos::from(one_day_ns))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            margin_account.borrowed.get(&Currency::USD()),
+            Some(&Money::from("72000 USD"))
+        );
+        assert_eq!(state.balances[0].total, Money::from("1524992.80 USD"));
+        assert_eq!(state.ts_event, UnixNanos::from(one_day_ns));
+    }
+
+    #[rstest]
+    fn test_accrue_borrow_interest_when_nothing_borrowed(mut margin_account: MarginAccount) {
+        margin_account
+            .update_borrow_rate(usd_borrow_rate(0.0365, 0))
+            .unwrap();
+
+        let state = margin_account
+            .accrue_borrow_interest(UnixNanos::from(NANOSECONDS_IN_YEAR))
+            .unwrap();
+
+        assert!(state.is_none());
+    }
+
     #[rstest]
     fn test_calculate_margin_init_with_leverage(
         mut margin_account: MarginAccount,
@@ -654,4 +1050,91 @@ mod tests {
         );
         assert_eq!(result, Money::from("0.00042500 BTC"));
     }
+
+    #[rstest]
+    fn test_update_orders_initial_margin(
+        mut margin_account: MarginAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = TestOrderStubs::limit_order(
+            audusd_sim.id(),
+            OrderSide::Buy,
+            Price::from("0.80000"),
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+
+        let state = margin_account
+            .update_orders(&audusd_sim, &[&order], 1.into())
+            .unwrap();
+
+        assert_eq!(state.margins.len(), 1);
+        assert_eq!(
+            margin_account.initial_margin(audusd_sim.id()),
+            Money::from("2403.20 USD")
+        );
+        assert_eq!(
+            margin_account.balance_free(None),
+            Some(Money::from("1522596.80 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_calculate_pnls_for_reducing_fill(
+        margin_account: MarginAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let order1 = TestOrderStubs::market_order(
+            audusd_sim.id(),
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            Some(ClientOrderId::from("O-1")),
+            None,
+        );
+        let order2 = TestOrderStubs::market_order(
+            audusd_sim.id(),
+            OrderSide::Sell,
+            Quantity::from(50_000),
+            Some(ClientOrderId::from("O-2")),
+            None,
+        );
+        let fill1: OrderFilled = TestOrderEventStubs::order_filled(
+            &order1,
+            &audusd_sim,
+            None,
+            None,
+            Some(Price::from("0.80000")),
+            None,
+            None,
+            None,
+            None,
+        )
+        .into();
+        let fill2: OrderFilled = TestOrderEventStubs::order_filled(
+            &order2,
+            &audusd_sim,
+            None,
+            None,
+            Some(Price::from("0.81000")),
+            None,
+            None,
+            None,
+            None,
+        )
+        .into();
+        let position = Position::new(&audusd_sim, fill1).unwrap();
+
+        let opening = margin_account
+            .calculate_pnls(audusd_sim.clone(), fill1, None)
+            .unwrap();
+        let reducing = margin_account
+            .calculate_pnls(audusd_sim, fill2, Some(position))
+            .unwrap();
+
+        assert!(opening.is_empty());
+        assert_eq!(reducing, vec![Money::from("500 USD")]);
+    }
 }
@@ -0,0 +1,306 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Attribution of realized PnL to the order tags (signals) which opened each position.
+
+use std::collections::HashMap;
+
+use nautilus_common::cache::Cache;
+use nautilus_model::{
+    identifiers::{client_order_id::ClientOrderId, strategy_id::StrategyId},
+    position::Position,
+    types::{currency::Currency, money::Money},
+};
+use ustr::Ustr;
+
+/// Represents the realized PnL and position outcomes attributed to a single tag.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TagAttribution {
+    pub realized_pnls: HashMap<Currency, Money>,
+    pub positions: usize,
+    pub winners: usize,
+    pub losers: usize,
+}
+
+impl TagAttribution {
+    /// Returns the realized PnL for the given `currency` (if any).
+    #[must_use]
+    pub fn realized_pnl(&self, currency: &Currency) -> Option<Money> {
+        self.realized_pnls.get(currency).copied()
+    }
+
+    /// Returns the ratio of winning to closed positions (if any positions have closed).
+    #[must_use]
+    pub fn win_rate(&self) -> Option<f64> {
+        let closed = self.winners + self.losers;
+        if closed == 0 {
+            return None;
+        }
+        Some(self.winners as f64 / closed as f64)
+    }
+
+    fn add_position(&mut self, position: &Position) {
+        self.positions += 1;
+
+        let Some(realized_pnl) = position.realized_pnl else {
+            return;
+        };
+        *self
+            .realized_pnls
+            .entry(realized_pnl.currency)
+            .or_insert_with(|| Money::from_raw(0, realized_pnl.currency)) += realized_pnl;
+
+        if position.is_closed() {
+            if realized_pnl.raw > 0 {
+                self.winners += 1;
+            } else {
+                self.losers += 1;
+            }
+        }
+    }
+}
+
+/// Returns the realized PnL of the given `positions` attributed to the tags of each position's
+/// opening order, as returned by `tags_for`.
+///
+/// Positions opened by an untagged order are attributed to the `None` key, and positions opened
+/// by an order with multiple tags are attributed in full to each tag.
+pub fn attribute_realized_pnls<'a, F>(
+    positions: impl IntoIterator<Item = &'a Position>,
+    tags_for: F,
+) -> HashMap<Option<Ustr>, TagAttribution>
+where
+    F: Fn(&ClientOrderId) -> Option<Vec<Ustr>>,
+{
+    let mut attributions: HashMap<Option<Ustr>, TagAttribution> = HashMap::new();
+    for position in positions {
+        match tags_for(&position.opening_order_id) {
+            Some(tags) if !tags.is_empty() => {
+                for tag in tags {
+                    attributions
+                        .entry(Some(tag))
+                        .or_default()
+                        .add_position(position);
+                }
+            }
+            _ => attributions.entry(None).or_default().add_position(position),
+        }
+    }
+    attributions
+}
+
+/// Returns the realized PnL of the cached positions attributed to the tags of each position's
+/// opening order, optionally filtered by `strategy_id`.
+#[must_use]
+pub fn attribute_cached_realized_pnls(
+    cache: &Cache,
+    strategy_id: Option<&StrategyId>,
+) -> HashMap<Option<Ustr>, TagAttribution> {
+    attribute_realized_pnls(
+        cache.positions(None, None, strategy_id, None),
+        |client_order_id| {
+            cache
+                .order(client_order_id)
+                .and_then(|order| order.tags())
+                .map(<[Ustr]>::to_vec)
+        },
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OmsType, OrderSide},
+        identifiers::position_id::PositionId,
+        instruments::{any::InstrumentAny, currency_pair::CurrencyPair, stubs::audusd_sim},
+        orders::{
+            any::OrderAny,
+            stubs::{TestOrderEventStubs, TestOrderStubs},
+        },
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn market_order(
+        instrument: &InstrumentAny,
+        side: OrderSide,
+        client_order_id: &str,
+        tags: &[&str],
+    ) -> OrderAny {
+        let mut order = TestOrderStubs::market_order(
+            instrument.id(),
+            side,
+            Quantity::from(100_000),
+            Some(ClientOrderId::from(client_order_id)),
+            None,
+        );
+        if let OrderAny::Market(ref mut market) = order {
+            if !tags.is_empty() {
+                market.tags = Some(tags.iter().map(|tag| Ustr::from(tag)).collect());
+            }
+        }
+        order
+    }
+
+    fn add_position(
+        cache: &mut Cache,
+        instrument: &InstrumentAny,
+        position_id: &str,
+        tags: &[&str],
+        open_px: &str,
+        close_px: Option<&str>,
+    ) {
+        let position_id = PositionId::from(position_id);
+        let open_order = market_order(
+            instrument,
+            OrderSide::Buy,
+            &format!("O-{position_id}-1"),
+            tags,
+        );
+        let fill = TestOrderEventStubs::order_filled(
+            &open_order,
+            instrument,
+            None,
+            Some(position_id),
+            Some(Price::from(open_px)),
+            None,
+            None,
+            None,
+            None,
+        );
+        let mut position = Position::new(instrument, fill.into()).unwrap();
+        cache.add_order(open_order, None, None, false).unwrap();
+
+        if let Some(close_px) = close_px {
+            let close_order = market_order(
+                instrument,
+                OrderSide::Sell,
+                &format!("O-{position_id}-2"),
+                &[],
+            );
+            let fill = TestOrderEventStubs::order_filled(
+                &close_order,
+                instrument,
+                None,
+                Some(position_id),
+                Some(Price::from(close_px)),
+                None,
+                None,
+                None,
+                None,
+            );
+            position.apply(&fill.into());
+            cache.add_order(close_order, None, None, false).unwrap();
+        }
+
+        cache.add_position(position, OmsType::Netting).unwrap();
+    }
+
+    #[rstest]
+    fn test_attribute_cached_realized_pnls(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut cache = Cache::default();
+        add_position(
+            &mut cache,
+            &instrument,
+            "P-1",
+            &["momentum"],
+            "0.80000",
+            Some("0.81000"),
+        );
+        add_position(
+            &mut cache,
+            &instrument,
+            "P-2",
+            &["momentum"],
+            "0.80000",
+            Some("0.79500"),
+        );
+        add_position(
+            &mut cache,
+            &instrument,
+            "P-3",
+            &["carry"],
+            "0.80000",
+            Some("0.80500"),
+        );
+        add_position(&mut cache, &instrument, "P-4", &[], "0.80000", None);
+
+        let attributions = attribute_cached_realized_pnls(&cache, None);
+
+        let usd = Currency::USD();
+        let momentum = &attributions[&Some(Ustr::from("momentum"))];
+        assert_eq!(momentum.positions, 2);
+        assert_eq!(momentum.winners, 1);
+        assert_eq!(momentum.losers, 1);
+        assert_eq!(momentum.win_rate(), Some(0.5));
+        assert_eq!(momentum.realized_pnl(&usd), Some(Money::from("492 USD")));
+
+        let carry = &attributions[&Some(Ustr::from("carry"))];
+        assert_eq!(carry.positions, 1);
+        assert_eq!(carry.realized_pnl(&usd), Some(Money::from("496 USD")));
+        assert_eq!(carry.win_rate(), Some(1.0));
+
+        let untagged = &attributions[&None];
+        assert_eq!(untagged.positions, 1);
+        assert_eq!(untagged.realized_pnl(&usd), Some(Money::from("-2 USD")));
+        assert_eq!(untagged.win_rate(), None);
+    }
+
+    #[rstest]
+    fn test_attribute_cached_realized_pnls_filters_by_strategy(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut cache = Cache::default();
+        add_position(
+            &mut cache,
+            &instrument,
+            "P-1",
+            &["momentum"],
+            "0.80000",
+            Some("0.81000"),
+        );
+
+        let attributions = attribute_cached_realized_pnls(&cache, Some(&StrategyId::from("S-002")));
+
+        assert!(attributions.is_empty());
+    }
+
+    #[rstest]
+    fn test_multiple_tags_attributed_to_each(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut cache = Cache::default();
+        add_position(
+            &mut cache,
+            &instrument,
+            "P-1",
+            &["momentum", "breakout"],
+            "0.80000",
+            Some("0.81000"),
+        );
+
+        let attributions = attribute_cached_realized_pnls(&cache, None);
+
+        assert_eq!(attributions.len(), 2);
+        assert_eq!(
+            attributions[&Some(Ustr::from("momentum"))],
+            attributions[&Some(Ustr::from("breakout"))]
+        );
+    }
+}
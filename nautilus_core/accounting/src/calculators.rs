@@ -0,0 +1,597 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Margin, PnL and exchange rate calculators for accounts.
+
+use std::collections::{HashMap, VecDeque};
+
+use nautilus_common::cache::Cache;
+use nautilus_model::{
+    enums::{PositionSide, PriceType},
+    identifiers::venue::Venue,
+    instruments::any::InstrumentAny,
+    position::Position,
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+use rust_decimal::prelude::ToPrimitive;
+
+/// Provides initial and maintenance margin calculations by instrument type.
+///
+/// The notional value is adjusted for the given leverage, with margin rates then applied
+/// from the instrument:
+///
+/// - Spot instruments (currency pairs and equities) without an initial margin rate require
+///   the full adjusted notional, i.e. the full notional for an unleveraged cash purchase.
+/// - Derivative instruments (futures, perpetuals, options and spreads) apply the initial
+///   and maintenance margin rates of the instrument.
+///
+/// Margin for inverse instruments is in the base currency, unless `use_quote_for_inverse`.
+/// The initial margin includes the taker fee for opening and closing, and the maintenance
+/// margin the taker fee for closing.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MarginCalculator;
+
+impl MarginCalculator {
+    /// Returns the initial margin for the given `quantity` at `price` with `leverage`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `leverage` is not positive.
+    pub fn initial_margin(
+        &self,
+        instrument: &InstrumentAny,
+        quantity: Quantity,
+        price: Price,
+        leverage: f64,
+        use_quote_for_inverse: bool,
+    ) -> anyhow::Result<Money> {
+        let notional =
+            adjusted_notional(instrument, quantity, price, leverage, use_quote_for_inverse)?;
+        let margin_init = instrument.margin_init().to_f64().unwrap_or(0.0);
+        let rate = match instrument {
+            InstrumentAny::CurrencyPair(_) | InstrumentAny::Equity(_) if margin_init == 0.0 => 1.0,
+            _ => margin_init,
+        };
+        let taker_fee = instrument.taker_fee().to_f64().unwrap_or(0.0);
+        let margin = notional.as_f64() * taker_fee.mul_add(2.0, rate);
+        Money::new(margin, notional.currency)
+    }
+
+    /// Returns the maintenance margin for the given `quantity` at `price` with `leverage`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `leverage` is not positive.
+    pub fn maintenance_margin(
+        &self,
+        instrument: &InstrumentAny,
+        quantity: Quantity,
+        price: Price,
+        leverage: f64,
+        use_quote_for_inverse: bool,
+    ) -> anyhow::Result<Money> {
+        let notional =
+            adjusted_notional(instrument, quantity, price, leverage, use_quote_for_inverse)?;
+        let margin_maint = instrument.margin_maint().to_f64().unwrap_or(0.0);
+        let taker_fee = instrument.taker_fee().to_f64().unwrap_or(0.0);
+        let margin = notional.as_f64() * (margin_maint + taker_fee);
+        Money::new(margin, notional.currency)
+    }
+}
+
+fn adjusted_notional(
+    instrument: &InstrumentAny,
+    quantity: Quantity,
+    price: Price,
+    leverage: f64,
+    use_quote_for_inverse: bool,
+) -> anyhow::Result<Money> {
+    if leverage <= 0.0 || !leverage.is_finite() {
+        anyhow::bail!("Invalid `leverage`, was {leverage}");
+    }
+    let notional =
+        instrument.calculate_notional_value(quantity, price, Some(use_quote_for_inverse));
+    Money::new(notional.as_f64() / leverage, notional.currency)
+}
+
+/// Provides PnL calculations for linear and inverse instruments.
+///
+/// PnL for linear instruments is in the settlement currency. PnL for inverse instruments
+/// is in the base currency, unless `use_quote_for_inverse`, in which case it is converted
+/// to the quote currency at the closing price.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PnLCalculator;
+
+impl PnLCalculator {
+    /// Returns the PnL for closing `quantity` of a position on the given `side`, opened at
+    /// `avg_px_open`, at `avg_px_close`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if an inverse instrument has no base currency, or a
+    /// price is not positive for an inverse instrument.
+    pub fn calculate_pnl(
+        &self,
+        instrument: &InstrumentAny,
+        side: PositionSide,
+        avg_px_open: f64,
+        avg_px_close: f64,
+        quantity: Quantity,
+        use_quote_for_inverse: bool,
+    ) -> anyhow::Result<Money> {
+        let direction = match side {
+            PositionSide::Long => 1.0,
+            PositionSide::Short => -1.0,
+            _ => 0.0,
+        };
+        let contracts = quantity.as_f64() * instrument.multiplier().as_f64();
+
+        if !instrument.is_inverse() {
+            let pnl = contracts * (avg_px_close - avg_px_open) * direction;
+            return Money::new(pnl, instrument.settlement_currency());
+        }
+
+        if avg_px_open <= 0.0 || avg_px_close <= 0.0 {
+            anyhow::bail!(
+                "Invalid prices for inverse PnL, was open={avg_px_open}, close={avg_px_close}"
+            );
+        }
+        let pnl = contracts * (1.0 / avg_px_open - 1.0 / avg_px_close) * direction;
+        if use_quote_for_inverse {
+            Money::new(pnl * avg_px_close, instrument.quote_currency())
+        } else {
+            let base_currency = instrument.base_currency().ok_or_else(|| {
+                anyhow::anyhow!("No base currency for inverse {}", instrument.id())
+            })?;
+            Money::new(pnl, base_currency)
+        }
+    }
+
+    /// Returns the unrealized PnL for the given `position` at the `last` price.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the PnL cannot be calculated for the instrument.
+    pub fn unrealized_pnl(
+        &self,
+        instrument: &InstrumentAny,
+        position: &Position,
+        last: Price,
+        use_quote_for_inverse: bool,
+    ) -> anyhow::Result<Money> {
+        self.calculate_pnl(
+            instrument,
+            position.side,
+            position.avg_px_open,
+            last.as_f64(),
+            position.quantity,
+            use_quote_for_inverse,
+        )
+    }
+}
+
+/// Provides exchange rate calculations between currencies, from tables of currency pair
+/// bid and ask quotes keyed by `(base, quote)` currency.
+///
+/// Where no quote directly relates two currencies, the rate is resolved as a cross rate
+/// through the fewest intermediate currencies (e.g. AUD to JPY via USD), using inverse
+/// rates where required.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExchangeRateCalculator;
+
+impl ExchangeRateCalculator {
+    /// Returns the exchange rate from `from_currency` to `to_currency` for the given
+    /// `price_type`, or `None` if there are insufficient quotes to resolve a rate.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `price_type` is not `Bid`, `Ask` or `Mid`.
+    pub fn get_rate(
+        &self,
+        from_currency: Currency,
+        to_currency: Currency,
+        price_type: PriceType,
+        bid_quotes: &HashMap<(Currency, Currency), f64>,
+        ask_quotes: &HashMap<(Currency, Currency), f64>,
+    ) -> anyhow::Result<Option<f64>> {
+        let quotes: HashMap<(Currency, Currency), f64> = match price_type {
+            PriceType::Bid => bid_quotes.clone(),
+            PriceType::Ask => ask_quotes.clone(),
+            PriceType::Mid => bid_quotes
+                .iter()
+                .filter_map(|(pair, bid)| {
+                    ask_quotes.get(pair).map(|ask| (*pair, (bid + ask) / 2.0))
+                })
+                .collect(),
+            _ => anyhow::bail!("Cannot calculate exchange rate for `PriceType` {price_type}"),
+        };
+
+        if from_currency == to_currency {
+            return Ok(Some(1.0)); // No conversion necessary
+        }
+
+        let mut rates: HashMap<Currency, Vec<(Currency, f64)>> = HashMap::new();
+        for ((base, quote), rate) in quotes {
+            if rate <= 0.0 || !rate.is_finite() {
+                continue;
+            }
+            rates.entry(base).or_default().push((quote, rate));
+            rates.entry(quote).or_default().push((base, 1.0 / rate));
+        }
+
+        // Breadth-first search for the path through the fewest currencies
+        let mut resolved: HashMap<Currency, f64> = HashMap::from([(from_currency, 1.0)]);
+        let mut queue = VecDeque::from([from_currency]);
+        while let Some(currency) = queue.pop_front() {
+            let rate = resolved[&currency];
+            for (next, next_rate) in rates.get(&currency).into_iter().flatten() {
+                if resolved.contains_key(next) {
+                    continue;
+                }
+                let cross_rate = rate * next_rate;
+                if *next == to_currency {
+                    return Ok(Some(cross_rate));
+                }
+                resolved.insert(*next, cross_rate);
+                queue.push_back(*next);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the exchange rate from `from_currency` to `to_currency` for the given
+    /// `price_type`, using the latest cached prices of the currency pairs at `venue`.
+    ///
+    /// The latest quote for each pair is used, or the latest trade if no quotes are cached.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `price_type` is not `Bid`, `Ask` or `Mid`.
+    pub fn get_cached_rate(
+        &self,
+        cache: &Cache,
+        venue: &Venue,
+        from_currency: Currency,
+        to_currency: Currency,
+        price_type: PriceType,
+    ) -> anyhow::Result<Option<f64>> {
+        let (bid_quotes, ask_quotes) = build_quote_tables(cache, venue);
+        self.get_rate(
+            from_currency,
+            to_currency,
+            price_type,
+            &bid_quotes,
+            &ask_quotes,
+        )
+    }
+
+    /// Returns the given `money` converted to `to_currency`, using the latest cached prices
+    /// of the currency pairs at `venue`, or `None` if no rate can be resolved.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `price_type` is not `Bid`, `Ask` or `Mid`.
+    pub fn convert(
+        &self,
+        cache: &Cache,
+        venue: &Venue,
+        money: Money,
+        to_currency: Currency,
+        price_type: PriceType,
+    ) -> anyhow::Result<Option<Money>> {
+        let Some(rate) =
+            self.get_cached_rate(cache, venue, money.currency, to_currency, price_type)?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(Money::new(money.as_f64() * rate, to_currency)?))
+    }
+}
+
+type QuoteTable = HashMap<(Currency, Currency), f64>;
+
+fn build_quote_tables(cache: &Cache, venue: &Venue) -> (QuoteTable, QuoteTable) {
+    let mut bid_quotes = HashMap::new();
+    let mut ask_quotes = HashMap::new();
+
+    for instrument in cache.instruments(venue) {
+        if !matches!(
+            instrument,
+            InstrumentAny::CurrencyPair(_) | InstrumentAny::CryptoPerpetual(_)
+        ) {
+            continue;
+        }
+        let Some(base_currency) = instrument.base_currency() else {
+            continue;
+        };
+        let pair = (base_currency, instrument.quote_currency());
+
+        let instrument_id = instrument.id();
+        if let Some(quote) = cache.quote_tick(&instrument_id) {
+            bid_quotes.insert(pair, quote.bid_price.as_f64());
+            ask_quotes.insert(pair, quote.ask_price.as_f64());
+        } else if let Some(trade) = cache.trade_tick(&instrument_id) {
+            bid_quotes.insert(pair, trade.price.as_f64());
+            ask_quotes.insert(pair, trade.price.as_f64());
+        }
+    }
+
+    (bid_quotes, ask_quotes)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::{
+        data::quote::QuoteTick,
+        identifiers::symbol::Symbol,
+        instruments::{
+            crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair, equity::Equity,
+            stubs::*,
+        },
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_spot_initial_margin_is_full_notional(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let calculator = MarginCalculator;
+
+        let cash = calculator
+            .initial_margin(
+                &instrument,
+                Quantity::from(100),
+                Price::from("150.00"),
+                1.0,
+                false,
+            )
+            .unwrap();
+        let leveraged = calculator
+            .initial_margin(
+                &instrument,
+                Quantity::from(100),
+                Price::from("150.00"),
+                4.0,
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(cash, Money::from("15000 USD"));
+        assert_eq!(leveraged, Money::from("3750 USD"));
+    }
+
+    #[rstest]
+    fn test_inverse_perpetual_margin_in_base_currency(xbtusd_bitmex: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(xbtusd_bitmex);
+        let calculator = MarginCalculator;
+        let quantity = Quantity::from(100_000);
+        let price = Price::from("10000.0");
+
+        let initial = calculator
+            .initial_margin(&instrument, quantity, price, 1.0, false)
+            .unwrap();
+        let maintenance = calculator
+            .maintenance_margin(&instrument, quantity, price, 1.0, false)
+            .unwrap();
+
+        assert_eq!(initial, Money::from("0.115 BTC"));
+        assert_eq!(maintenance, Money::from("0.0425 BTC"));
+    }
+
+    #[rstest]
+    fn test_margin_with_invalid_leverage(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+
+        let result = MarginCalculator.initial_margin(
+            &instrument,
+            Quantity::from(100),
+            Price::from("150.00"),
+            0.0,
+            false,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_linear_pnl(currency_pair_btcusdt: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let calculator = PnLCalculator;
+
+        let long = calculator
+            .calculate_pnl(
+                &instrument,
+                PositionSide::Long,
+                10_000.0,
+                10_500.0,
+                Quantity::from(1),
+                false,
+            )
+            .unwrap();
+        let short = calculator
+            .calculate_pnl(
+                &instrument,
+                PositionSide::Short,
+                10_000.0,
+                10_500.0,
+                Quantity::from(1),
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(long, Money::from("500 USDT"));
+        assert_eq!(short, Money::from("-500 USDT"));
+    }
+
+    #[rstest]
+    fn test_inverse_pnl(xbtusd_bitmex: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(xbtusd_bitmex);
+        let calculator = PnLCalculator;
+        let quantity = Quantity::from(100_000);
+
+        let base = calculator
+            .calculate_pnl(
+                &instrument,
+                PositionSide::Long,
+                10_000.0,
+                11_000.0,
+                quantity,
+                false,
+            )
+            .unwrap();
+        let quote = calculator
+            .calculate_pnl(
+                &instrument,
+                PositionSide::Long,
+                10_000.0,
+                11_000.0,
+                quantity,
+                true,
+            )
+            .unwrap();
+
+        assert_eq!(base, Money::from("0.90909091 BTC"));
+        assert_eq!(quote, Money::from("10000 USD"));
+    }
+
+    fn add_fx_quote(cache: &mut Cache, symbol: &str, bid: &str, ask: &str) {
+        let instrument = default_fx_ccy(Symbol::from(symbol), Some(Venue::from("SIM")));
+        let quote = QuoteTick::new(
+            instrument.id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1_000_000),
+            Quantity::from(1_000_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(instrument))
+            .unwrap();
+        cache.add_quote(quote).unwrap();
+    }
+
+    #[rstest]
+    fn test_exchange_rate_direct_and_inverse() {
+        let bid_quotes = HashMap::from([((Currency::AUD(), Currency::USD()), 0.75)]);
+        let ask_quotes = HashMap::from([((Currency::AUD(), Currency::USD()), 0.76)]);
+        let calculator = ExchangeRateCalculator;
+
+        let direct = calculator
+            .get_rate(
+                Currency::AUD(),
+                Currency::USD(),
+                PriceType::Mid,
+                &bid_quotes,
+                &ask_quotes,
+            )
+            .unwrap();
+        let inverse = calculator
+            .get_rate(
+                Currency::USD(),
+                Currency::AUD(),
+                PriceType::Bid,
+                &bid_quotes,
+                &ask_quotes,
+            )
+            .unwrap();
+        let unknown = calculator
+            .get_rate(
+                Currency::AUD(),
+                Currency::GBP(),
+                PriceType::Mid,
+                &bid_quotes,
+                &ask_quotes,
+            )
+            .unwrap();
+
+        assert!((direct.unwrap() - 0.755).abs() < 1e-9);
+        assert_eq!(inverse, Some(1.0 / 0.75));
+        assert_eq!(unknown, None);
+    }
+
+    #[rstest]
+    fn test_exchange_rate_with_invalid_price_type() {
+        let result = ExchangeRateCalculator.get_rate(
+            Currency::AUD(),
+            Currency::USD(),
+            PriceType::Last,
+            &HashMap::new(),
+            &HashMap::new(),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_cached_cross_rate() {
+        let mut cache = Cache::default();
+        add_fx_quote(&mut cache, "AUD/USD", "0.75000", "0.75000");
+        add_fx_quote(&mut cache, "USD/JPY", "150.000", "150.000");
+        add_fx_quote(&mut cache, "EUR/JPY", "160.000", "160.000");
+        let venue = Venue::from("SIM");
+        let calculator = ExchangeRateCalculator;
+
+        let aud_jpy = calculator
+            .get_cached_rate(
+                &cache,
+                &venue,
+                Currency::AUD(),
+                Currency::JPY(),
+                PriceType::Mid,
+            )
+            .unwrap()
+            .unwrap();
+        let aud_eur = calculator
+            .get_cached_rate(
+                &cache,
+                &venue,
+                Currency::AUD(),
+                Currency::EUR(),
+                PriceType::Mid,
+            )
+            .unwrap()
+            .unwrap();
+
+        assert!((aud_jpy - 112.5).abs() < 1e-9);
+        assert!((aud_eur - 112.5 / 160.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_convert_money() {
+        let mut cache = Cache::default();
+        add_fx_quote(&mut cache, "AUD/USD", "0.75000", "0.75000");
+        add_fx_quote(&mut cache, "USD/JPY", "150.000", "150.000");
+
+        let converted = ExchangeRateCalculator
+            .convert(
+                &cache,
+                &Venue::from("SIM"),
+                Money::from("1000 AUD"),
+                Currency::JPY(),
+                PriceType::Mid,
+            )
+            .unwrap();
+
+        assert_eq!(converted, Some(Money::from("112500 JPY")));
+    }
+}
@@ -0,0 +1,355 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Reconstruction of account balance and margin history from persisted account events.
+
+use std::collections::HashMap;
+
+use nautilus_common::{cache::Cache, interface::account::Account};
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    events::account::state::AccountState,
+    identifiers::{account_id::AccountId, instrument_id::InstrumentId},
+    types::{
+        balance::{AccountBalance, MarginBalance},
+        currency::Currency,
+        money::Money,
+    },
+};
+
+/// Represents the balances and margins of an account as at a point in time.
+#[derive(Clone, Debug)]
+pub struct BalanceSnapshot {
+    pub ts_event: UnixNanos,
+    pub balances: HashMap<Currency, AccountBalance>,
+    pub margins: HashMap<InstrumentId, MarginBalance>,
+}
+
+/// Provides the balance and margin history of an account, reconstructed by replaying its
+/// [`AccountState`] events.
+///
+/// Each event updates the balances and margins it contains, with all others carried forward
+/// from the previous state, in the same way as applying the events to an account.
+#[derive(Clone, Debug)]
+pub struct BalanceHistory {
+    pub account_id: AccountId,
+    snapshots: Vec<BalanceSnapshot>,
+}
+
+impl BalanceHistory {
+    /// Creates a new [`BalanceHistory`] by replaying the given account `events`.
+    ///
+    /// Events are replayed in order of `ts_event`, with events at the same timestamp replayed
+    /// in the order given.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `events` is empty or contains events for more than
+    /// one account.
+    pub fn from_events(events: &[AccountState]) -> anyhow::Result<Self> {
+        let Some(first) = events.first() else {
+            anyhow::bail!("No account events to replay");
+        };
+        let account_id = first.account_id;
+
+        let mut sorted: Vec<&AccountState> = events.iter().collect();
+        sorted.sort_by_key(|event| event.ts_event);
+
+        let mut balances = HashMap::new();
+        let mut margins = HashMap::new();
+        let mut snapshots: Vec<BalanceSnapshot> = Vec::with_capacity(sorted.len());
+        for event in sorted {
+            if event.account_id != account_id {
+                anyhow::bail!(
+                    "Account ID mismatch replaying events, expected {account_id}, was {}",
+                    event.account_id
+                );
+            }
+            for balance in &event.balances {
+                balances.insert(balance.currency, *balance);
+            }
+            for margin in &event.margins {
+                margins.insert(margin.instrument_id, *margin);
+            }
+
+            let snapshot = BalanceSnapshot {
+                ts_event: event.ts_event,
+                balances: balances.clone(),
+                margins: margins.clone(),
+            };
+            // Only the final state at each timestamp is retained
+            match snapshots.last_mut() {
+                Some(last) if last.ts_event == event.ts_event => *last = snapshot,
+                _ => snapshots.push(snapshot),
+            }
+        }
+
+        Ok(Self {
+            account_id,
+            snapshots,
+        })
+    }
+
+    /// Creates a new [`BalanceHistory`] by replaying the events of the given `account`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the account has no events.
+    pub fn from_account(account: &dyn Account) -> anyhow::Result<Self> {
+        Self::from_events(&account.events())
+    }
+
+    /// Creates a new [`BalanceHistory`] by replaying the events of the account for the given
+    /// `account_id` held in the `cache`.
+    ///
+    /// Accounts persisted to the cache database are loaded when the cache is built, so this
+    /// covers both live and previously persisted accounts.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the account is not found in the cache, or has no events.
+    pub fn from_cache(cache: &Cache, account_id: &AccountId) -> anyhow::Result<Self> {
+        let Some(account) = cache.account(account_id) else {
+            anyhow::bail!("No account {account_id} found in the cache");
+        };
+        Self::from_account(account)
+    }
+
+    /// Returns all snapshots in order of `ts_event`.
+    #[must_use]
+    pub fn snapshots(&self) -> &[BalanceSnapshot] {
+        &self.snapshots
+    }
+
+    /// Returns the snapshots with a `ts_event` within the given range (inclusive).
+    #[must_use]
+    pub fn range(&self, start: Option<UnixNanos>, end: Option<UnixNanos>) -> &[BalanceSnapshot] {
+        let from = start.map_or(0, |start| {
+            self.snapshots.partition_point(|s| s.ts_event < start)
+        });
+        let to = end.map_or(self.snapshots.len(), |end| {
+            self.snapshots.partition_point(|s| s.ts_event <= end)
+        });
+        &self.snapshots[from..to.max(from)]
+    }
+
+    /// Returns the snapshot in effect at the given `ts` (if any).
+    #[must_use]
+    pub fn snapshot_at(&self, ts: UnixNanos) -> Option<&BalanceSnapshot> {
+        let index = self.snapshots.partition_point(|s| s.ts_event <= ts);
+        index.checked_sub(1).map(|index| &self.snapshots[index])
+    }
+
+    /// Returns a time series of the total balance for the given `currency`.
+    ///
+    /// When a `start` is given, the series begins with the balance in effect at `start`.
+    #[must_use]
+    pub fn total_series(
+        &self,
+        currency: Currency,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> Vec<(UnixNanos, Money)> {
+        self.series(start, end, |s| s.balances.get(&currency).map(|b| b.total))
+    }
+
+    /// Returns a time series of the free balance for the given `currency`.
+    ///
+    /// When a `start` is given, the series begins with the balance in effect at `start`.
+    #[must_use]
+    pub fn free_series(
+        &self,
+        currency: Currency,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> Vec<(UnixNanos, Money)> {
+        self.series(start, end, |s| s.balances.get(&currency).map(|b| b.free))
+    }
+
+    /// Returns a time series of the locked balance for the given `currency`.
+    ///
+    /// When a `start` is given, the series begins with the balance in effect at `start`.
+    #[must_use]
+    pub fn locked_series(
+        &self,
+        currency: Currency,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> Vec<(UnixNanos, Money)> {
+        self.series(start, end, |s| s.balances.get(&currency).map(|b| b.locked))
+    }
+
+    /// Returns a time series of the maintenance margin for the given `instrument_id`.
+    ///
+    /// When a `start` is given, the series begins with the margin in effect at `start`.
+    #[must_use]
+    pub fn maintenance_margin_series(
+        &self,
+        instrument_id: InstrumentId,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> Vec<(UnixNanos, Money)> {
+        self.series(start, end, |s| {
+            s.margins.get(&instrument_id).map(|m| m.maintenance)
+        })
+    }
+
+    fn series<F>(
+        &self,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        value: F,
+    ) -> Vec<(UnixNanos, Money)>
+    where
+        F: Fn(&BalanceSnapshot) -> Option<Money>,
+    {
+        let mut series = Vec::new();
+        if let Some(start) = start {
+            let opening = self
+                .snapshot_at(start)
+                .filter(|s| s.ts_event < start)
+                .and_then(&value);
+            if let Some(money) = opening {
+                series.push((start, money));
+            }
+        }
+        series.extend(
+            self.range(start, end)
+                .iter()
+                .filter_map(|s| value(s).map(|money| (s.ts_event, money))),
+        );
+        series
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::enums::AccountType;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::account::margin::MarginAccount;
+
+    fn account_state(ts: u64, balances: &[(&str, &str)]) -> AccountState {
+        let balances = balances
+            .iter()
+            .map(|(total, locked)| {
+                let total = Money::from(*total);
+                let locked = Money::from(*locked);
+                AccountBalance::new(total, locked, total - locked).unwrap()
+            })
+            .collect();
+        AccountState::new(
+            AccountId::from("SIM-001"),
+            AccountType::Margin,
+            balances,
+            vec![],
+            true,
+            UUID4::new(),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+            None,
+        )
+        .unwrap()
+    }
+
+    fn history() -> BalanceHistory {
+        let events = vec![
+            account_state(3, &[("1200 USD", "200 USD")]),
+            account_state(1, &[("1000 USD", "0 USD"), ("1 BTC", "0 BTC")]),
+            account_state(2, &[("1100 USD", "100 USD")]),
+        ];
+        BalanceHistory::from_events(&events).unwrap()
+    }
+
+    #[rstest]
+    fn test_replay_carries_forward_balances() {
+        let history = history();
+        let btc = Money::from("1 BTC").currency;
+
+        assert_eq!(history.snapshots().len(), 3);
+        assert_eq!(history.snapshots()[0].ts_event, 1);
+        assert_eq!(
+            history.snapshots()[2].balances[&btc].total,
+            Money::from("1 BTC")
+        );
+    }
+
+    #[rstest]
+    fn test_total_series_with_range() {
+        let history = history();
+        let usd = Money::from("1 USD").currency;
+
+        let series = history.total_series(usd, Some(UnixNanos::from(2)), None);
+
+        assert_eq!(
+            series,
+            vec![
+                (UnixNanos::from(2), Money::from("1100 USD")),
+                (UnixNanos::from(3), Money::from("1200 USD")),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_free_series_begins_with_opening_balance() {
+        let history = history();
+        let usd = Money::from("1 USD").currency;
+
+        let series = history.free_series(
+            usd,
+            Some(UnixNanos::from(1_000)),
+            Some(UnixNanos::from(2_000)),
+        );
+
+        assert_eq!(
+            series,
+            vec![(UnixNanos::from(1_000), Money::from("1000 USD"))]
+        );
+        assert!(history.snapshot_at(UnixNanos::default()).is_none());
+    }
+
+    #[rstest]
+    fn test_from_cache() {
+        let mut account =
+            MarginAccount::new(account_state(1, &[("1000 USD", "0 USD")]), false).unwrap();
+        account.apply(account_state(2, &[("1100 USD", "100 USD")]));
+        let mut cache = Cache::default();
+        cache.add_account(Box::new(account)).unwrap();
+
+        let history = BalanceHistory::from_cache(&cache, &AccountId::from("SIM-001")).unwrap();
+
+        assert_eq!(history.snapshots().len(), 2);
+        assert!(BalanceHistory::from_cache(&cache, &AccountId::from("SIM-002")).is_err());
+    }
+
+    #[rstest]
+    fn test_replay_with_no_events() {
+        assert!(BalanceHistory::from_events(&[]).is_err());
+    }
+
+    #[rstest]
+    fn test_replay_with_mixed_accounts() {
+        let mut other = account_state(4, &[("1 USD", "0 USD")]);
+        other.account_id = AccountId::from("SIM-002");
+        let events = vec![account_state(1, &[("1 USD", "0 USD")]), other];
+
+        assert!(BalanceHistory::from_events(&events).is_err());
+    }
+}
@@ -0,0 +1,294 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Pre-trade what-if checks of the margin and exposure impact of a candidate order.
+
+use nautilus_common::cache::Cache;
+use nautilus_model::{
+    enums::OrderSide,
+    identifiers::instrument_id::InstrumentId,
+    instruments::any::InstrumentAny,
+    orders::any::OrderAny,
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+
+use crate::calculators::MarginCalculator;
+
+/// Represents the hypothetical impact of a candidate order filling fully.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderImpact {
+    pub instrument_id: InstrumentId,
+    /// The price the order is assumed to fill at.
+    pub price: Price,
+    /// The signed net position quantity for the instrument before the order fills.
+    pub net_qty_before: f64,
+    /// The signed net position quantity for the instrument after the order fills.
+    pub net_qty_after: f64,
+    /// The notional value of the net position before the order fills.
+    pub exposure_before: Money,
+    /// The notional value of the net position after the order fills.
+    pub exposure_after: Money,
+    /// The initial margin required for the order.
+    pub margin_init: Money,
+    /// The maintenance margin for the net position before the order fills.
+    pub margin_maint_before: Money,
+    /// The maintenance margin for the net position after the order fills.
+    pub margin_maint_after: Money,
+}
+
+impl OrderImpact {
+    /// Returns the marginal maintenance margin requirement of the order, which is negative
+    /// if the order reduces the net position.
+    #[must_use]
+    pub fn marginal_margin(&self) -> Money {
+        self.margin_maint_after - self.margin_maint_before
+    }
+
+    /// Returns the change in exposure from the order.
+    #[must_use]
+    pub fn exposure_change(&self) -> Money {
+        self.exposure_after - self.exposure_before
+    }
+
+    /// Returns whether the order reduces the absolute net position.
+    #[must_use]
+    pub fn is_reducing(&self) -> bool {
+        self.net_qty_after.abs() < self.net_qty_before.abs()
+    }
+}
+
+/// Returns the hypothetical margin and exposure impact of the candidate `order` filling
+/// fully, against the open positions in the `cache` for the instrument.
+///
+/// The order is assumed to fill at its limit price, or its trigger price for stop orders,
+/// otherwise at the latest cached quote on the opposite side (or the latest cached trade).
+/// Margins are calculated with the given `leverage`.
+///
+/// # Errors
+///
+/// This function returns an error if the instrument is not in the cache, no fill price can
+/// be determined, or a margin cannot be calculated.
+pub fn check_order_impact(
+    cache: &Cache,
+    order: &OrderAny,
+    leverage: f64,
+    use_quote_for_inverse: bool,
+) -> anyhow::Result<OrderImpact> {
+    let instrument_id = order.instrument_id();
+    let instrument = cache
+        .instrument(&instrument_id)
+        .ok_or_else(|| anyhow::anyhow!("No instrument found for {instrument_id}"))?;
+    let price = fill_price(cache, order)?;
+
+    let net_qty_before: f64 = cache
+        .positions_open(None, Some(&instrument_id), None, None)
+        .iter()
+        .map(|position| position.signed_qty)
+        .sum();
+    let order_qty = match order.order_side() {
+        OrderSide::Buy => order.leaves_qty().as_f64(),
+        OrderSide::Sell => -order.leaves_qty().as_f64(),
+        OrderSide::NoOrderSide => anyhow::bail!("Invalid `OrderSide` for order impact"),
+    };
+    let net_qty_after = net_qty_before + order_qty;
+
+    let calculator = MarginCalculator;
+    let margin_init = calculator.initial_margin(
+        instrument,
+        order.leaves_qty(),
+        price,
+        leverage,
+        use_quote_for_inverse,
+    )?;
+    let (exposure_before, margin_maint_before) = net_exposure(
+        instrument,
+        net_qty_before,
+        price,
+        leverage,
+        use_quote_for_inverse,
+    )?;
+    let (exposure_after, margin_maint_after) = net_exposure(
+        instrument,
+        net_qty_after,
+        price,
+        leverage,
+        use_quote_for_inverse,
+    )?;
+
+    Ok(OrderImpact {
+        instrument_id,
+        price,
+        net_qty_before,
+        net_qty_after,
+        exposure_before,
+        exposure_after,
+        margin_init,
+        margin_maint_before,
+        margin_maint_after,
+    })
+}
+
+fn fill_price(cache: &Cache, order: &OrderAny) -> anyhow::Result<Price> {
+    if let Some(price) = order.price().or_else(|| order.trigger_price()) {
+        return Ok(price);
+    }
+
+    let instrument_id = order.instrument_id();
+    if let Some(quote) = cache.quote_tick(&instrument_id) {
+        return Ok(match order.order_side() {
+            OrderSide::Buy => quote.ask_price,
+            _ => quote.bid_price,
+        });
+    }
+    if let Some(trade) = cache.trade_tick(&instrument_id) {
+        return Ok(trade.price);
+    }
+    anyhow::bail!("No market price to estimate fill for {instrument_id}")
+}
+
+fn net_exposure(
+    instrument: &InstrumentAny,
+    net_qty: f64,
+    price: Price,
+    leverage: f64,
+    use_quote_for_inverse: bool,
+) -> anyhow::Result<(Money, Money)> {
+    let quantity = Quantity::new(net_qty.abs(), instrument.size_precision())?;
+    let exposure =
+        instrument.calculate_notional_value(quantity, price, Some(use_quote_for_inverse));
+    let margin_maint = MarginCalculator.maintenance_margin(
+        instrument,
+        quantity,
+        price,
+        leverage,
+        use_quote_for_inverse,
+    )?;
+    Ok((exposure, margin_maint))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::{
+        data::quote::QuoteTick,
+        enums::OmsType,
+        events::order::filled::OrderFilled,
+        identifiers::{client_order_id::ClientOrderId, position_id::PositionId},
+        instruments::{currency_pair::CurrencyPair, stubs::*},
+        orders::stubs::{TestOrderEventStubs, TestOrderStubs},
+        position::Position,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn cache_with_position(instrument: &InstrumentAny) -> Cache {
+        let mut cache = Cache::default();
+        cache.add_instrument(instrument.clone()).unwrap();
+        let order = TestOrderStubs::market_order(
+            instrument.id(),
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            Some(ClientOrderId::from("O-1")),
+            None,
+        );
+        let fill: OrderFilled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            Some(PositionId::from("P-1")),
+            Some(Price::from("0.80000")),
+            None,
+            None,
+            None,
+            None,
+        )
+        .into();
+        let position = Position::new(instrument, fill).unwrap();
+        cache.add_position(position, OmsType::Netting).unwrap();
+        cache
+    }
+
+    fn market_order(instrument: &InstrumentAny, side: OrderSide, quantity: i64) -> OrderAny {
+        TestOrderStubs::market_order(
+            instrument.id(),
+            side,
+            Quantity::from(quantity),
+            Some(ClientOrderId::from("O-2")),
+            None,
+        )
+    }
+
+    #[rstest]
+    fn test_increasing_order_impact(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let cache = cache_with_position(&instrument);
+        let order = TestOrderStubs::limit_order(
+            instrument.id(),
+            OrderSide::Buy,
+            Price::from("0.80000"),
+            Quantity::from(100_000),
+            Some(ClientOrderId::from("O-2")),
+            None,
+        );
+
+        let impact = check_order_impact(&cache, &order, 1.0, false).unwrap();
+
+        assert_eq!(impact.price, Price::from("0.80000"));
+        assert_eq!(impact.net_qty_before, 100_000.0);
+        assert_eq!(impact.net_qty_after, 200_000.0);
+        assert_eq!(impact.exposure_change(), Money::from("80000 USD"));
+        assert!(impact.marginal_margin().as_f64() > 0.0);
+        assert!(impact.margin_init.as_f64() > 0.0);
+        assert!(!impact.is_reducing());
+    }
+
+    #[rstest]
+    fn test_reducing_market_order_impact_uses_quote(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut cache = cache_with_position(&instrument);
+        let quote = QuoteTick::new(
+            instrument.id(),
+            Price::from("0.79990"),
+            Price::from("0.80010"),
+            Quantity::from(1_000_000),
+            Quantity::from(1_000_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        cache.add_quote(quote).unwrap();
+        let order = market_order(&instrument, OrderSide::Sell, 50_000);
+
+        let impact = check_order_impact(&cache, &order, 1.0, false).unwrap();
+
+        assert_eq!(impact.price, Price::from("0.79990"));
+        assert_eq!(impact.net_qty_after, 50_000.0);
+        assert!(impact.marginal_margin().as_f64() < 0.0);
+        assert!(impact.is_reducing());
+    }
+
+    #[rstest]
+    fn test_market_order_without_prices(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let cache = cache_with_position(&instrument);
+        let order = market_order(&instrument, OrderSide::Buy, 50_000);
+
+        assert!(check_order_impact(&cache, &order, 1.0, false).is_err());
+    }
+}
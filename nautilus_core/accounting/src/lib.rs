@@ -27,6 +27,11 @@
 //! - `python`: Enables Python bindings from `pyo3`
 
 pub mod account;
+pub mod attribution;
+pub mod calculators;
+pub mod history;
+pub mod impact;
+pub mod stress;
 #[cfg(test)]
 pub mod stubs;
 
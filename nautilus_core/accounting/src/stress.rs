@@ -0,0 +1,547 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Stress testing of open positions against hypothetical price and volatility shocks.
+//!
+//! A [`StressScenario`] assigns a [`Shock`] by instrument, underlying or asset class, and
+//! a [`StressTester`] applies the scenario to the open positions in the cache, reporting the
+//! hypothetical PnL and change in maintenance margin. As only the cache is read, scenarios
+//! can be run on a live node as a pre-trade what-if check.
+
+use std::collections::HashMap;
+
+use nautilus_common::cache::Cache;
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    analytics::greeks::{instrument_greeks, GreeksParams, PricingModel},
+    enums::{AssetClass, PriceType},
+    identifiers::{instrument_id::InstrumentId, position_id::PositionId, venue::Venue},
+    instruments::any::InstrumentAny,
+    position::Position,
+    types::{currency::Currency, money::Money, price::Price},
+};
+use ustr::Ustr;
+
+use crate::calculators::MarginCalculator;
+
+/// Represents a hypothetical shock to the price and implied volatility of an instrument.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Shock {
+    /// The relative change in price (e.g. -0.1 for a 10% fall).
+    pub price_change: f64,
+    /// The absolute change in implied volatility (e.g. 0.05 for five volatility points),
+    /// only applied to options with pricing inputs.
+    pub volatility_change: f64,
+}
+
+impl Shock {
+    /// Creates a new [`Shock`] instance.
+    #[must_use]
+    pub const fn new(price_change: f64, volatility_change: f64) -> Self {
+        Self {
+            price_change,
+            volatility_change,
+        }
+    }
+}
+
+/// Represents a named set of shocks to apply to positions.
+///
+/// The shock for an instrument is resolved from the most specific match, in the order of
+/// instrument, underlying, asset class, and then the default shock (if any).
+#[derive(Clone, Debug, Default)]
+pub struct StressScenario {
+    pub name: String,
+    pub default_shock: Option<Shock>,
+    pub instrument_shocks: HashMap<InstrumentId, Shock>,
+    pub underlying_shocks: HashMap<Ustr, Shock>,
+    pub asset_class_shocks: HashMap<AssetClass, Shock>,
+}
+
+impl StressScenario {
+    /// Creates a new [`StressScenario`] instance with no shocks.
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_default_shock(mut self, shock: Shock) -> Self {
+        self.default_shock = Some(shock);
+        self
+    }
+
+    #[must_use]
+    pub fn with_instrument_shock(mut self, instrument_id: InstrumentId, shock: Shock) -> Self {
+        self.instrument_shocks.insert(instrument_id, shock);
+        self
+    }
+
+    #[must_use]
+    pub fn with_underlying_shock(mut self, underlying: Ustr, shock: Shock) -> Self {
+        self.underlying_shocks.insert(underlying, shock);
+        self
+    }
+
+    #[must_use]
+    pub fn with_asset_class_shock(mut self, asset_class: AssetClass, shock: Shock) -> Self {
+        self.asset_class_shocks.insert(asset_class, shock);
+        self
+    }
+
+    /// Returns the shock to apply to the given `instrument` (if any).
+    #[must_use]
+    pub fn shock_for(&self, instrument: &InstrumentAny) -> Option<Shock> {
+        self.instrument_shocks
+            .get(&instrument.id())
+            .or_else(|| {
+                instrument
+                    .underlying()
+                    .and_then(|underlying| self.underlying_shocks.get(&underlying))
+            })
+            .or_else(|| self.asset_class_shocks.get(&instrument.asset_class()))
+            .copied()
+            .or(self.default_shock)
+    }
+}
+
+/// Represents the hypothetical impact of a scenario on a single position.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositionStress {
+    pub position_id: PositionId,
+    pub instrument_id: InstrumentId,
+    pub mark_price: Price,
+    pub shocked_price: Price,
+    /// The hypothetical PnL from the mark price to the shocked price.
+    pub pnl: Money,
+    pub margin_maint: Money,
+    pub shocked_margin_maint: Money,
+}
+
+/// Represents the hypothetical impact of a scenario on the open positions.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StressReport {
+    pub scenario: String,
+    pub positions: Vec<PositionStress>,
+    /// The instruments with open positions which could not be marked (no cached prices).
+    pub unpriced: Vec<InstrumentId>,
+}
+
+impl StressReport {
+    /// Returns the total hypothetical PnL per currency.
+    #[must_use]
+    pub fn pnls(&self) -> HashMap<Currency, Money> {
+        sum_by_currency(self.positions.iter().map(|p| p.pnl))
+    }
+
+    /// Returns the total change in maintenance margin per currency.
+    #[must_use]
+    pub fn margin_changes(&self) -> HashMap<Currency, Money> {
+        sum_by_currency(
+            self.positions
+                .iter()
+                .map(|p| p.shocked_margin_maint - p.margin_maint),
+        )
+    }
+}
+
+fn sum_by_currency(amounts: impl Iterator<Item = Money>) -> HashMap<Currency, Money> {
+    let mut totals: HashMap<Currency, Money> = HashMap::new();
+    for amount in amounts {
+        *totals
+            .entry(amount.currency)
+            .or_insert_with(|| Money::from_raw(0, amount.currency)) += amount;
+    }
+    totals
+}
+
+/// Provides stress testing of the open positions in the cache.
+///
+/// Positions are marked at the mid of the latest cached quote, or the latest cached trade.
+/// Options with pricing inputs are repriced with the shocked underlying price and
+/// volatility, with the change in theoretical value applied to the mark price. All other
+/// instruments have the price change applied directly.
+#[derive(Clone, Debug)]
+pub struct StressTester {
+    pub pricing_model: PricingModel,
+    /// The leverage applied to the maintenance margin.
+    pub leverage: f64,
+    /// The market inputs for repricing options (keyed by option instrument ID).
+    pub option_params: HashMap<InstrumentId, GreeksParams>,
+}
+
+impl Default for StressTester {
+    /// Creates a new default [`StressTester`] instance.
+    fn default() -> Self {
+        Self {
+            pricing_model: PricingModel::BlackScholes,
+            leverage: 1.0,
+            option_params: HashMap::new(),
+        }
+    }
+}
+
+impl StressTester {
+    /// Creates a new [`StressTester`] instance.
+    #[must_use]
+    pub fn new(pricing_model: PricingModel, leverage: f64) -> Self {
+        Self {
+            pricing_model,
+            leverage,
+            option_params: HashMap::new(),
+        }
+    }
+
+    /// Sets the market inputs for repricing the option with the given `instrument_id`.
+    pub fn set_option_params(&mut self, instrument_id: InstrumentId, params: GreeksParams) {
+        self.option_params.insert(instrument_id, params);
+    }
+
+    /// Returns the hypothetical impact of the `scenario` on the open positions in the
+    /// `cache`, optionally filtered by `venue`.
+    ///
+    /// Positions in instruments without a shock in the scenario are not included.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a shock is invalid, an option cannot be repriced
+    /// at `ts_now`, or a margin cannot be calculated.
+    pub fn run(
+        &self,
+        cache: &Cache,
+        scenario: &StressScenario,
+        venue: Option<&Venue>,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<StressReport> {
+        let mut report = StressReport {
+            scenario: scenario.name.clone(),
+            ..Default::default()
+        };
+
+        for position in cache.positions_open(venue, None, None, None) {
+            let Some(instrument) = cache.instrument(&position.instrument_id) else {
+                log::warn!("No instrument for {} to stress", position.instrument_id);
+                continue;
+            };
+            let Some(shock) = scenario.shock_for(instrument) else {
+                continue;
+            };
+            let Some(mark_price) = mark_price(cache, instrument)? else {
+                if !report.unpriced.contains(&position.instrument_id) {
+                    report.unpriced.push(position.instrument_id);
+                }
+                continue;
+            };
+            report
+                .positions
+                .push(self.stress_position(position, instrument, mark_price, shock, ts_now)?);
+        }
+
+        Ok(report)
+    }
+
+    /// Returns the hypothetical impact of the `shock` on the given `position` marked at
+    /// `mark_price`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the shock is invalid, an option cannot be repriced
+    /// at `ts_now`, or a margin cannot be calculated.
+    pub fn stress_position(
+        &self,
+        position: &Position,
+        instrument: &InstrumentAny,
+        mark_price: Price,
+        shock: Shock,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<PositionStress> {
+        let shocked_price = self.shocked_price(instrument, mark_price, shock, ts_now)?;
+        let pnl = position.unrealized_pnl(shocked_price) - position.unrealized_pnl(mark_price);
+
+        let calculator = MarginCalculator;
+        let margin_maint = calculator.maintenance_margin(
+            instrument,
+            position.quantity,
+            mark_price,
+            self.leverage,
+            false,
+        )?;
+        let shocked_margin_maint = calculator.maintenance_margin(
+            instrument,
+            position.quantity,
+            shocked_price,
+            self.leverage,
+            false,
+        )?;
+
+        Ok(PositionStress {
+            position_id: position.id,
+            instrument_id: position.instrument_id,
+            mark_price,
+            shocked_price,
+            pnl,
+            margin_maint,
+            shocked_margin_maint,
+        })
+    }
+
+    fn shocked_price(
+        &self,
+        instrument: &InstrumentAny,
+        mark_price: Price,
+        shock: Shock,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<Price> {
+        if shock.price_change <= -1.0 || !shock.price_change.is_finite() {
+            anyhow::bail!(
+                "Invalid `price_change` for shock, was {}",
+                shock.price_change
+            );
+        }
+        if !shock.volatility_change.is_finite() {
+            anyhow::bail!(
+                "Invalid `volatility_change` for shock, was {}",
+                shock.volatility_change
+            );
+        }
+
+        let option_params = match instrument {
+            InstrumentAny::OptionsContract(option) => self
+                .option_params
+                .get(&option.id)
+                .map(|params| (option, params)),
+            _ => None,
+        };
+        let Some((option, params)) = option_params else {
+            return instrument.make_price(mark_price.as_f64() * (1.0 + shock.price_change));
+        };
+
+        let shocked_params = GreeksParams {
+            underlying_price: params.underlying_price * (1.0 + shock.price_change),
+            volatility: params.volatility + shock.volatility_change,
+            ..*params
+        };
+        let value = instrument_greeks(option, self.pricing_model, params, ts_now)?.price;
+        let shocked_value =
+            instrument_greeks(option, self.pricing_model, &shocked_params, ts_now)?.price;
+        let shocked_price = (mark_price.as_f64() + shocked_value - value).max(0.0);
+        instrument.make_price(shocked_price)
+    }
+}
+
+fn mark_price(cache: &Cache, instrument: &InstrumentAny) -> anyhow::Result<Option<Price>> {
+    let instrument_id = instrument.id();
+    let price = if let Some(quote) = cache.quote_tick(&instrument_id) {
+        quote.extract_price(PriceType::Mid)
+    } else if let Some(trade) = cache.trade_tick(&instrument_id) {
+        trade.price
+    } else {
+        return Ok(None);
+    };
+    Ok(Some(instrument.make_price(price.as_f64())?))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::quote::QuoteTick,
+        enums::{OmsType, OrderSide},
+        events::order::filled::OrderFilled,
+        instruments::{
+            currency_pair::CurrencyPair, equity::Equity, options_contract::OptionsContract,
+            stubs::*,
+        },
+        orders::stubs::{TestOrderEventStubs, TestOrderStubs},
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn open_position(
+        instrument: &InstrumentAny,
+        side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+    ) -> Position {
+        let order = TestOrderStubs::market_order(instrument.id(), side, quantity, None, None);
+        let fill: OrderFilled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            Some(PositionId::from(format!("P-{}", instrument.id()).as_str())),
+            Some(price),
+            None,
+            None,
+            None,
+            None,
+        )
+        .into();
+        Position::new(instrument, fill).unwrap()
+    }
+
+    fn add_quote(cache: &mut Cache, instrument: &InstrumentAny, bid: &str, ask: &str) {
+        let quote = QuoteTick::new(
+            instrument.id(),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1),
+            Quantity::from(1),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        cache.add_quote(quote).unwrap();
+    }
+
+    #[rstest]
+    fn test_shock_resolution(equity_aapl: Equity, options_contract_appl: OptionsContract) {
+        let equity = InstrumentAny::Equity(equity_aapl);
+        let option = InstrumentAny::OptionsContract(options_contract_appl);
+        let scenario = StressScenario::new("crash")
+            .with_default_shock(Shock::new(-0.01, 0.0))
+            .with_asset_class_shock(AssetClass::Equity, Shock::new(-0.2, 0.1))
+            .with_underlying_shock(Ustr::from("AAPL"), Shock::new(-0.3, 0.2))
+            .with_instrument_shock(equity.id(), Shock::new(-0.1, 0.0));
+
+        assert_eq!(scenario.shock_for(&equity), Some(Shock::new(-0.1, 0.0)));
+        assert_eq!(scenario.shock_for(&option), Some(Shock::new(-0.3, 0.2)));
+        assert_eq!(StressScenario::new("empty").shock_for(&equity), None,);
+    }
+
+    #[rstest]
+    fn test_run_reports_pnl_and_margin(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut cache = Cache::default();
+        cache.add_instrument(instrument.clone()).unwrap();
+        add_quote(&mut cache, &instrument, "0.79990", "0.80010");
+        let position = open_position(
+            &instrument,
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            Price::from("0.80000"),
+        );
+        cache.add_position(position, OmsType::Netting).unwrap();
+        let scenario = StressScenario::new("aud-down").with_default_shock(Shock::new(-0.05, 0.0));
+
+        let report = StressTester::default()
+            .run(&cache, &scenario, None, UnixNanos::default())
+            .unwrap();
+
+        assert_eq!(report.scenario, "aud-down");
+        assert_eq!(report.positions.len(), 1);
+        let stress = &report.positions[0];
+        assert_eq!(stress.mark_price, Price::from("0.80000"));
+        assert_eq!(stress.shocked_price, Price::from("0.76000"));
+        assert_eq!(stress.pnl, Money::from("-4000 USD"));
+        assert_eq!(report.pnls()[&Currency::USD()], Money::from("-4000 USD"));
+        assert!(report.margin_changes()[&Currency::USD()].as_f64() <= 0.0);
+    }
+
+    #[rstest]
+    fn test_run_reports_unpriced_instruments(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut cache = Cache::default();
+        cache.add_instrument(instrument.clone()).unwrap();
+        let position = open_position(
+            &instrument,
+            OrderSide::Sell,
+            Quantity::from(100_000),
+            Price::from("0.80000"),
+        );
+        cache.add_position(position, OmsType::Netting).unwrap();
+        let scenario = StressScenario::new("aud-up").with_default_shock(Shock::new(0.05, 0.0));
+
+        let report = StressTester::default()
+            .run(&cache, &scenario, None, UnixNanos::default())
+            .unwrap();
+
+        assert!(report.positions.is_empty());
+        assert_eq!(report.unpriced, vec![instrument.id()]);
+    }
+
+    #[rstest]
+    fn test_stress_option_with_volatility_shock(options_contract_appl: OptionsContract) {
+        let expiration_ns = options_contract_appl.expiration_ns;
+        let instrument = InstrumentAny::OptionsContract(options_contract_appl);
+        let position = open_position(
+            &instrument,
+            OrderSide::Buy,
+            Quantity::from(1),
+            Price::from("5.00"),
+        );
+        let mut tester = StressTester::default();
+        tester.set_option_params(
+            instrument.id(),
+            GreeksParams {
+                underlying_price: 150.0,
+                volatility: 0.2,
+                interest_rate: 0.0,
+                dividend_yield: 0.0,
+            },
+        );
+        let ts_now = UnixNanos::from(expiration_ns.as_u64() - 30 * 86_400_000_000_000);
+
+        let vol_up = tester
+            .stress_position(
+                &position,
+                &instrument,
+                Price::from("5.00"),
+                Shock::new(0.0, 0.1),
+                ts_now,
+            )
+            .unwrap();
+        let price_only = StressTester::default()
+            .stress_position(
+                &position,
+                &instrument,
+                Price::from("5.00"),
+                Shock::new(0.0, 0.1),
+                ts_now,
+            )
+            .unwrap();
+
+        assert!(vol_up.shocked_price > Price::from("5.00"));
+        assert!(vol_up.pnl.as_f64() > 0.0);
+        assert_eq!(price_only.shocked_price, Price::from("5.00"));
+    }
+
+    #[rstest]
+    fn test_invalid_shock(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let position = open_position(
+            &instrument,
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            Price::from("0.80000"),
+        );
+
+        let result = StressTester::default().stress_position(
+            &position,
+            &instrument,
+            Price::from("0.80000"),
+            Shock::new(-1.0, 0.0),
+            UnixNanos::default(),
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,290 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A small zero-copy toolkit for decoding binary venue protocols (e.g. SBE-like encodings).
+//!
+//! Provides a bounds-checked cursor over a byte slice for fixed-width fields, bitfield
+//! extraction, conversion of scaled decimals (mantissa and exponent) into `Price` and
+//! `Quantity`, and lookup of wire values in enum maps.
+
+use std::fmt::Display;
+
+use nautilus_model::types::{
+    fixed::{check_fixed_precision, FIXED_PRECISION},
+    price::Price,
+    quantity::Quantity,
+};
+
+macro_rules! impl_read {
+    ($($name:ident => $ty:ty, $from:ident;)*) => {
+        $(
+            #[doc = concat!("Reads a `", stringify!($ty), "` using `", stringify!($from), "`.")]
+            pub fn $name(&mut self) -> anyhow::Result<$ty> {
+                let bytes = self.read_array::<{ std::mem::size_of::<$ty>() }>()?;
+                Ok(<$ty>::$from(bytes))
+            }
+        )*
+    };
+}
+
+/// Provides a bounds-checked, zero-copy cursor for reading fields from a binary message.
+#[derive(Clone, Debug)]
+pub struct BinaryReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BinaryReader<'a> {
+    /// Creates a new [`BinaryReader`] instance positioned at the start of `buf`.
+    #[must_use]
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// Returns the current read position.
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the count of bytes remaining to be read.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// Advances the read position by `len` bytes (e.g. over padding or unused fields).
+    pub fn skip(&mut self, len: usize) -> anyhow::Result<()> {
+        self.read_bytes(len).map(|_| ())
+    }
+
+    /// Reads the next `len` bytes as a slice borrowed from the underlying buffer.
+    pub fn read_bytes(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        if len > self.remaining() {
+            anyhow::bail!(
+                "Buffer underflow reading {len} bytes at position {}, {} remaining",
+                self.pos,
+                self.remaining()
+            );
+        }
+        let bytes = &self.buf[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    /// Reads a fixed-width ASCII field of `len` bytes, trimming trailing NUL and space padding.
+    pub fn read_fixed_str(&mut self, len: usize) -> anyhow::Result<&'a str> {
+        let bytes = self.read_bytes(len)?;
+        let end = bytes
+            .iter()
+            .rposition(|b| *b != 0 && *b != b' ')
+            .map_or(0, |i| i + 1);
+        Ok(std::str::from_utf8(&bytes[..end])?)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> anyhow::Result<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.read_bytes(N)?);
+        Ok(array)
+    }
+
+    impl_read! {
+        read_u8 => u8, from_le_bytes;
+        read_i8 => i8, from_le_bytes;
+        read_u16_le => u16, from_le_bytes;
+        read_u16_be => u16, from_be_bytes;
+        read_i16_le => i16, from_le_bytes;
+        read_i16_be => i16, from_be_bytes;
+        read_u32_le => u32, from_le_bytes;
+        read_u32_be => u32, from_be_bytes;
+        read_i32_le => i32, from_le_bytes;
+        read_i32_be => i32, from_be_bytes;
+        read_u64_le => u64, from_le_bytes;
+        read_u64_be => u64, from_be_bytes;
+        read_i64_le => i64, from_le_bytes;
+        read_i64_be => i64, from_be_bytes;
+    }
+}
+
+/// Returns whether the bit at `index` (from the least significant bit) is set in `value`.
+#[must_use]
+pub fn bit(value: u64, index: u8) -> bool {
+    index < 64 && (value >> index) & 1 == 1
+}
+
+/// Returns the `width` bits of `value` starting at bit `offset` (from the least significant bit).
+#[must_use]
+pub fn bits(value: u64, offset: u8, width: u8) -> u64 {
+    if offset >= 64 || width == 0 {
+        return 0;
+    }
+    let shifted = value >> offset;
+    if width >= 64 {
+        shifted
+    } else {
+        shifted & ((1 << width) - 1)
+    }
+}
+
+/// Returns the raw fixed-point value (at `FIXED_PRECISION`) for the scaled decimal
+/// `mantissa * 10^exponent`, which must be exactly representable.
+fn scaled_to_fixed(mantissa: i128, exponent: i8) -> anyhow::Result<i128> {
+    let shift = i32::from(FIXED_PRECISION) + i32::from(exponent);
+    if shift >= 0 {
+        10_i128
+            .checked_pow(shift as u32)
+            .and_then(|scalar| mantissa.checked_mul(scalar))
+            .ok_or_else(|| anyhow::anyhow!("Overflow scaling {mantissa}e{exponent}"))
+    } else {
+        let divisor = 10_i128
+            .checked_pow(shift.unsigned_abs())
+            .ok_or_else(|| anyhow::anyhow!("Invalid exponent {exponent}"))?;
+        if mantissa % divisor != 0 {
+            anyhow::bail!("Scaled decimal {mantissa}e{exponent} exceeds the maximum precision");
+        }
+        Ok(mantissa / divisor)
+    }
+}
+
+/// Returns a [`Price`] from the scaled decimal `mantissa * 10^exponent`.
+///
+/// # Errors
+///
+/// This function returns an error if the value overflows, is not representable at the
+/// maximum fixed precision, or if `precision` is invalid.
+pub fn scaled_to_price(mantissa: i64, exponent: i8, precision: u8) -> anyhow::Result<Price> {
+    check_fixed_precision(precision)?;
+    let raw = i64::try_from(scaled_to_fixed(i128::from(mantissa), exponent)?)?;
+    Price::from_raw(raw, precision)
+}
+
+/// Returns a [`Quantity`] from the scaled decimal `mantissa * 10^exponent`.
+///
+/// # Errors
+///
+/// This function returns an error if the value overflows, is not representable at the
+/// maximum fixed precision, or if `precision` is invalid.
+pub fn scaled_to_quantity(mantissa: u64, exponent: i8, precision: u8) -> anyhow::Result<Quantity> {
+    check_fixed_precision(precision)?;
+    let raw = u64::try_from(scaled_to_fixed(i128::from(mantissa), exponent)?)?;
+    Quantity::from_raw(raw, precision)
+}
+
+/// Returns the value mapped to the wire value `raw` in the given `table`.
+///
+/// # Errors
+///
+/// This function returns an error if `raw` is not contained in the table.
+pub fn map_enum<R, T>(raw: R, table: &[(R, T)], name: &str) -> anyhow::Result<T>
+where
+    R: PartialEq + Display,
+    T: Copy,
+{
+    table
+        .iter()
+        .find(|(key, _)| *key == raw)
+        .map(|(_, value)| *value)
+        .ok_or_else(|| anyhow::anyhow!("Invalid `{name}` value, was {raw}"))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::enums::OrderSide;
+    use rstest::rstest;
+
+    use super::*;
+
+    const SIDE_MAP: [(u8, OrderSide); 2] = [(b'B', OrderSide::Buy), (b'S', OrderSide::Sell)];
+
+    #[rstest]
+    fn test_read_message_fields() {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&0x0102_u16.to_be_bytes());
+        buf.extend_from_slice(&(-12_345_i64).to_le_bytes());
+        buf.extend_from_slice(b"AAPL\0\0\0\0");
+        buf.push(b'S');
+
+        let mut reader = BinaryReader::new(&buf);
+
+        assert_eq!(reader.read_u16_be().unwrap(), 0x0102);
+        assert_eq!(reader.read_i64_le().unwrap(), -12_345);
+        assert_eq!(reader.read_fixed_str(8).unwrap(), "AAPL");
+        let side = map_enum(reader.read_u8().unwrap(), &SIDE_MAP, "side").unwrap();
+        assert_eq!(side, OrderSide::Sell);
+        assert_eq!(reader.remaining(), 0);
+    }
+
+    #[rstest]
+    fn test_read_past_end() {
+        let buf = [1, 2, 3];
+        let mut reader = BinaryReader::new(&buf);
+        reader.skip(2).unwrap();
+
+        assert!(reader.read_u16_le().is_err());
+        assert_eq!(reader.position(), 2);
+    }
+
+    #[rstest]
+    #[case(0b1011_0000, 4, 3, 0b011)]
+    #[case(0b1011_0000, 7, 1, 1)]
+    #[case(u64::MAX, 0, 64, u64::MAX)]
+    #[case(u64::MAX, 64, 1, 0)]
+    fn test_bits(#[case] value: u64, #[case] offset: u8, #[case] width: u8, #[case] expected: u64) {
+        assert_eq!(bits(value, offset, width), expected);
+    }
+
+    #[rstest]
+    fn test_bit() {
+        assert!(bit(0b100, 2));
+        assert!(!bit(0b100, 1));
+        assert!(!bit(u64::MAX, 64));
+    }
+
+    #[rstest]
+    #[case(1_234_500, -4, 4, "123.4500")]
+    #[case(-25, -1, 1, "-2.5")]
+    #[case(12, 2, 0, "1200")]
+    fn test_scaled_to_price(
+        #[case] mantissa: i64,
+        #[case] exponent: i8,
+        #[case] precision: u8,
+        #[case] expected: &str,
+    ) {
+        let price = scaled_to_price(mantissa, exponent, precision).unwrap();
+        assert_eq!(price, Price::from(expected));
+        assert_eq!(price.to_string(), expected);
+    }
+
+    #[rstest]
+    fn test_scaled_to_price_exceeds_precision() {
+        assert!(scaled_to_price(1, -10, 9).is_err());
+        assert!(scaled_to_price(i64::MAX, 1, 0).is_err());
+    }
+
+    #[rstest]
+    fn test_scaled_to_quantity() {
+        let qty = scaled_to_quantity(150, -2, 2).unwrap();
+        assert_eq!(qty, Quantity::from("1.50"));
+    }
+
+    #[rstest]
+    fn test_map_enum_invalid_value() {
+        let result = map_enum(b'X', &SIDE_MAP, "side");
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,112 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Message types for the Coinbase Advanced Trade WebSocket feed.
+
+use serde::Deserialize;
+use ustr::Ustr;
+
+/// Represents a message received from the Coinbase Advanced Trade WebSocket feed.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "channel", rename_all = "snake_case")]
+pub enum CoinbaseWsMessage {
+    Ticker(CoinbaseWsEnvelope<CoinbaseTickerEvent>),
+    MarketTrades(CoinbaseWsEnvelope<CoinbaseTradesEvent>),
+    L2Data(CoinbaseWsEnvelope<CoinbaseL2Event>),
+    /// Any other channel (e.g. heartbeats or subscriptions).
+    #[serde(other)]
+    Other,
+}
+
+/// Represents the envelope common to all channel messages.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseWsEnvelope<E> {
+    pub timestamp: String,
+    pub sequence_num: u64,
+    pub events: Vec<E>,
+}
+
+/// Represents the type of a channel event.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoinbaseEventType {
+    Snapshot,
+    Update,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseTickerEvent {
+    #[serde(rename = "type")]
+    pub event_type: CoinbaseEventType,
+    pub tickers: Vec<CoinbaseTicker>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseTicker {
+    pub product_id: Ustr,
+    pub price: String,
+    pub best_bid: String,
+    pub best_bid_quantity: String,
+    pub best_ask: String,
+    pub best_ask_quantity: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseTradesEvent {
+    #[serde(rename = "type")]
+    pub event_type: CoinbaseEventType,
+    pub trades: Vec<CoinbaseTrade>,
+}
+
+/// Represents the side of the maker for a Coinbase trade.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CoinbaseTradeSide {
+    Buy,
+    Sell,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseTrade {
+    pub trade_id: String,
+    pub product_id: Ustr,
+    pub price: String,
+    pub size: String,
+    pub side: CoinbaseTradeSide,
+    pub time: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseL2Event {
+    #[serde(rename = "type")]
+    pub event_type: CoinbaseEventType,
+    pub product_id: Ustr,
+    pub updates: Vec<CoinbaseL2Update>,
+}
+
+/// Represents the book side for a Coinbase level 2 update.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoinbaseBookSide {
+    Bid,
+    Offer,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoinbaseL2Update {
+    pub side: CoinbaseBookSide,
+    pub price_level: String,
+    pub new_quantity: String,
+}
@@ -0,0 +1,283 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Parsing of Coinbase Advanced Trade WebSocket messages into Nautilus data types.
+
+use nautilus_core::{datetime::iso8601_to_unix_nanos, nanos::UnixNanos};
+use nautilus_model::{
+    data::{
+        delta::OrderBookDelta, deltas::OrderBookDeltas, order::BookOrder, quote::QuoteTick,
+        trade::TradeTick,
+    },
+    enums::{AggressorSide, BookAction, OrderSide, RecordFlag},
+    identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+    types::{price::Price, quantity::Quantity},
+};
+
+use super::messages::{
+    CoinbaseBookSide, CoinbaseEventType, CoinbaseL2Event, CoinbaseTicker, CoinbaseTrade,
+    CoinbaseTradeSide,
+};
+
+fn parse_price(value: &str, precision: u8) -> anyhow::Result<Price> {
+    Price::new(value.parse::<f64>()?, precision)
+}
+
+fn parse_quantity(value: &str, precision: u8) -> anyhow::Result<Quantity> {
+    Quantity::new(value.parse::<f64>()?, precision)
+}
+
+/// Returns a [`QuoteTick`] parsed from the best bid and ask of the given `ticker`.
+pub fn parse_quote_tick(
+    ticker: &CoinbaseTicker,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> anyhow::Result<QuoteTick> {
+    QuoteTick::new(
+        instrument_id,
+        parse_price(&ticker.best_bid, price_precision)?,
+        parse_price(&ticker.best_ask, price_precision)?,
+        parse_quantity(&ticker.best_bid_quantity, size_precision)?,
+        parse_quantity(&ticker.best_ask_quantity, size_precision)?,
+        ts_event,
+        ts_init,
+    )
+}
+
+/// Returns a [`TradeTick`] parsed from the given `trade`.
+pub fn parse_trade_tick(
+    trade: &CoinbaseTrade,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    ts_init: UnixNanos,
+) -> anyhow::Result<TradeTick> {
+    // The side is that of the maker, so the aggressor is on the opposite side
+    let aggressor_side = match trade.side {
+        CoinbaseTradeSide::Buy => AggressorSide::Seller,
+        CoinbaseTradeSide::Sell => AggressorSide::Buyer,
+    };
+    Ok(TradeTick::new(
+        instrument_id,
+        parse_price(&trade.price, price_precision)?,
+        parse_quantity(&trade.size, size_precision)?,
+        aggressor_side,
+        TradeId::new(&trade.trade_id)?,
+        iso8601_to_unix_nanos(&trade.time)?,
+        ts_init,
+    ))
+}
+
+/// Returns [`OrderBookDeltas`] parsed from the given level 2 `event`.
+///
+/// A snapshot is preceded by a `Clear` delta, and updates with a zero quantity delete the
+/// price level.
+pub fn parse_order_book_deltas(
+    event: &CoinbaseL2Event,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    sequence: u64,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> anyhow::Result<OrderBookDeltas> {
+    let is_snapshot = event.event_type == CoinbaseEventType::Snapshot;
+    let flags = if is_snapshot {
+        RecordFlag::F_SNAPSHOT as u8
+    } else {
+        0
+    };
+
+    let mut deltas = Vec::with_capacity(event.updates.len() + 1);
+    if is_snapshot {
+        deltas.push(OrderBookDelta::clear(
+            instrument_id,
+            sequence,
+            ts_event,
+            ts_init,
+        ));
+    }
+
+    for update in &event.updates {
+        let side = match update.side {
+            CoinbaseBookSide::Bid => OrderSide::Buy,
+            CoinbaseBookSide::Offer => OrderSide::Sell,
+        };
+        let size = parse_quantity(&update.new_quantity, size_precision)?;
+        let action = match (event.event_type, size.is_zero()) {
+            (_, true) => BookAction::Delete,
+            (CoinbaseEventType::Snapshot, false) => BookAction::Add,
+            (CoinbaseEventType::Update, false) => BookAction::Update,
+        };
+        let order = BookOrder::new(
+            side,
+            parse_price(&update.price_level, price_precision)?,
+            size,
+            0,
+        );
+        deltas.push(OrderBookDelta::new(
+            instrument_id,
+            action,
+            order,
+            flags,
+            sequence,
+            ts_event,
+            ts_init,
+        ));
+    }
+
+    if let Some(last) = deltas.last_mut() {
+        last.flags |= RecordFlag::F_LAST as u8;
+    } else {
+        anyhow::bail!("No updates for {instrument_id}");
+    }
+
+    Ok(OrderBookDeltas::new(instrument_id, deltas))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::coinbase::messages::CoinbaseWsMessage;
+
+    const TICKER: &str = r#"{
+        "channel": "ticker",
+        "client_id": "",
+        "timestamp": "2023-02-09T20:30:37.167359596Z",
+        "sequence_num": 0,
+        "events": [{"type": "snapshot", "tickers": [{
+            "type": "ticker",
+            "product_id": "BTC-USD",
+            "price": "21932.98",
+            "volume_24_h": "16038.28770938",
+            "best_bid": "21931.98",
+            "best_bid_quantity": "8000.21",
+            "best_ask": "21933.98",
+            "best_ask_quantity": "8038.07770938"
+        }]}]
+    }"#;
+
+    const TRADES: &str = r#"{
+        "channel": "market_trades",
+        "client_id": "",
+        "timestamp": "2023-02-09T20:19:35.39625135Z",
+        "sequence_num": 0,
+        "events": [{"type": "update", "trades": [{
+            "trade_id": "12345",
+            "product_id": "BTC-USD",
+            "price": "21932.98",
+            "size": "0.3",
+            "side": "BUY",
+            "time": "2019-08-14T20:42:27.265Z"
+        }]}]
+    }"#;
+
+    const L2_SNAPSHOT: &str = r#"{
+        "channel": "l2_data",
+        "client_id": "",
+        "timestamp": "2023-02-09T20:32:50.714964855Z",
+        "sequence_num": 1,
+        "events": [{"type": "snapshot", "product_id": "BTC-USD", "updates": [
+            {"side": "bid", "event_time": "1970-01-01T00:00:00Z", "price_level": "21921.73", "new_quantity": "0.06317902"},
+            {"side": "offer", "event_time": "1970-01-01T00:00:00Z", "price_level": "21921.74", "new_quantity": "0.00000000"}
+        ]}]
+    }"#;
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("BTC-USD.COINBASE")
+    }
+
+    #[rstest]
+    fn test_parse_quote_tick() {
+        let CoinbaseWsMessage::Ticker(msg) = serde_json::from_str(TICKER).unwrap() else {
+            panic!("Expected ticker");
+        };
+        let ticker = &msg.events[0].tickers[0];
+
+        let quote = parse_quote_tick(
+            ticker,
+            instrument_id(),
+            2,
+            8,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(quote.bid_price, Price::from("21931.98"));
+        assert_eq!(quote.ask_size, Quantity::from("8038.07770938"));
+        assert_eq!(quote.bid_size, Quantity::from("8000.21000000"));
+    }
+
+    #[rstest]
+    fn test_parse_trade_tick() {
+        let CoinbaseWsMessage::MarketTrades(msg) = serde_json::from_str(TRADES).unwrap() else {
+            panic!("Expected market trades");
+        };
+        let trade = &msg.events[0].trades[0];
+
+        let tick = parse_trade_tick(trade, instrument_id(), 2, 8, UnixNanos::default()).unwrap();
+
+        assert_eq!(tick.price, Price::from("21932.98"));
+        assert_eq!(tick.size, Quantity::from("0.30000000"));
+        assert_eq!(tick.aggressor_side, AggressorSide::Seller);
+        assert_eq!(tick.trade_id.to_string(), "12345");
+        assert_eq!(
+            tick.ts_event,
+            UnixNanos::from(1_565_815_347_265_000_000_u64)
+        );
+    }
+
+    #[rstest]
+    fn test_parse_l2_snapshot() {
+        let CoinbaseWsMessage::L2Data(msg) = serde_json::from_str(L2_SNAPSHOT).unwrap() else {
+            panic!("Expected l2 data");
+        };
+
+        let deltas = parse_order_book_deltas(
+            &msg.events[0],
+            instrument_id(),
+            2,
+            8,
+            msg.sequence_num,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(deltas.deltas.len(), 3);
+        assert_eq!(deltas.deltas[0].action, BookAction::Clear);
+        assert_eq!(deltas.deltas[1].action, BookAction::Add);
+        assert_eq!(deltas.deltas[1].order.side, OrderSide::Buy);
+        assert_eq!(deltas.deltas[2].action, BookAction::Delete);
+        assert!(RecordFlag::F_LAST.matches(deltas.flags));
+    }
+
+    #[rstest]
+    fn test_other_channel() {
+        let msg: CoinbaseWsMessage =
+            serde_json::from_str(r#"{"channel": "heartbeats", "events": []}"#).unwrap();
+
+        assert!(matches!(msg, CoinbaseWsMessage::Other));
+    }
+}
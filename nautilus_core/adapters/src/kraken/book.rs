@@ -0,0 +1,278 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Maintains a local order book from Kraken `book` messages.
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::{deltas::OrderBookDeltas, order::BookOrder},
+    enums::{BookType, OrderSide},
+    identifiers::instrument_id::InstrumentId,
+    orderbook::book::OrderBook,
+    types::quantity::Quantity,
+};
+
+use super::{
+    messages::{KrakenBook, KrakenEventType},
+    parse::{parse_order_book_deltas, verify_book_checksum},
+};
+
+/// The outcome of applying a Kraken `book` message.
+#[derive(Debug)]
+pub enum KrakenBookUpdate {
+    /// The deltas were applied and the book checksum matched.
+    Applied(OrderBookDeltas),
+    /// The message was dropped while awaiting a fresh snapshot.
+    Skipped,
+    /// The book checksum did not match, the local book was cleared and the channel should be
+    /// resubscribed to receive a new snapshot.
+    Resync,
+}
+
+/// Applies Kraken `book` messages to a local [`OrderBook`], verifying the checksum of each.
+#[derive(Debug)]
+pub struct KrakenBookHandler {
+    book: OrderBook,
+    depth: usize,
+    price_precision: u8,
+    size_precision: u8,
+    sequence: u64,
+    awaiting_snapshot: bool,
+}
+
+impl KrakenBookHandler {
+    /// Creates a new [`KrakenBookHandler`] instance for a book subscribed at the given `depth`.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        depth: usize,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> Self {
+        Self {
+            book: OrderBook::new(BookType::L2_MBP, instrument_id),
+            depth,
+            price_precision,
+            size_precision,
+            sequence: 0,
+            awaiting_snapshot: true,
+        }
+    }
+
+    #[must_use]
+    pub fn book(&self) -> &OrderBook {
+        &self.book
+    }
+
+    /// Applies the given `book` message to the local book and verifies its checksum.
+    ///
+    /// Updates received before the first snapshot, or after a checksum mismatch, are skipped
+    /// until the next snapshot arrives.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the message cannot be parsed.
+    pub fn apply(
+        &mut self,
+        msg: &KrakenBook,
+        event_type: KrakenEventType,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<KrakenBookUpdate> {
+        if event_type == KrakenEventType::Snapshot {
+            self.awaiting_snapshot = false;
+        } else if self.awaiting_snapshot {
+            return Ok(KrakenBookUpdate::Skipped);
+        }
+
+        self.sequence += 1;
+        let deltas = parse_order_book_deltas(
+            msg,
+            event_type,
+            self.book.instrument_id,
+            self.price_precision,
+            self.size_precision,
+            self.sequence,
+            ts_init,
+        )?;
+        self.book.apply_deltas(deltas.clone());
+        self.truncate()?;
+
+        if let Err(e) = verify_book_checksum(&self.book, self.size_precision, msg.checksum) {
+            tracing::warn!("{e}, resubscribing");
+            self.book.reset();
+            self.awaiting_snapshot = true;
+            return Ok(KrakenBookUpdate::Resync);
+        }
+
+        Ok(KrakenBookUpdate::Applied(deltas))
+    }
+
+    // Kraken does not send deletes for levels pushed beyond the subscribed depth, so these
+    // must be dropped locally to keep the checksum in line with the venue book.
+    fn truncate(&mut self) -> anyhow::Result<()> {
+        let mut stale = Vec::new();
+        for (side, levels) in [
+            (
+                OrderSide::Buy,
+                self.book.bids().skip(self.depth).collect::<Vec<_>>(),
+            ),
+            (
+                OrderSide::Sell,
+                self.book.asks().skip(self.depth).collect::<Vec<_>>(),
+            ),
+        ] {
+            for level in levels {
+                let size = Quantity::from_raw(level.size_raw(), self.size_precision)?;
+                stale.push(BookOrder::new(side, level.price.value, size, 0));
+            }
+        }
+
+        let ts_event = self.book.ts_last;
+        for order in stale {
+            self.book.delete(order, 0, self.sequence, ts_event);
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::types::price::Price;
+    use rstest::rstest;
+    use ustr::Ustr;
+
+    use super::*;
+    use crate::kraken::messages::{KrakenBookLevel, KrakenWsMessage};
+
+    fn apply(handler: &mut KrakenBookHandler, json: &str) -> KrakenBookUpdate {
+        let KrakenWsMessage::Book(msg) = serde_json::from_str(json).unwrap() else {
+            panic!("Expected book");
+        };
+        handler
+            .apply(&msg.data[0], msg.event_type, UnixNanos::default())
+            .unwrap()
+    }
+
+    fn handler() -> KrakenBookHandler {
+        KrakenBookHandler::new(InstrumentId::from("MATIC/USD.KRAKEN"), 10, 4, 8)
+    }
+
+    const BOOK_SNAPSHOT: &str = r#"{
+        "channel": "book",
+        "type": "snapshot",
+        "data": [{
+            "symbol": "MATIC/USD",
+            "bids": [{"price": 0.5666, "qty": 4831.75496356}, {"price": 0.5665, "qty": 6658.22734739}],
+            "asks": [{"price": 0.5668, "qty": 4410.79769741}, {"price": 0.5669, "qty": 4655.40412487}],
+            "checksum": 3588693387
+        }]
+    }"#;
+
+    #[rstest]
+    fn test_apply_snapshot_and_update() {
+        let mut handler = handler();
+        let update = r#"{
+            "channel": "book",
+            "type": "update",
+            "data": [{
+                "symbol": "MATIC/USD",
+                "bids": [{"price": 0.5665, "qty": 0.0}],
+                "asks": [{"price": 0.5668, "qty": 4000.5}],
+                "checksum": 890190776,
+                "timestamp": "2023-10-06T17:35:55.440295Z"
+            }]
+        }"#;
+
+        assert!(matches!(
+            apply(&mut handler, BOOK_SNAPSHOT),
+            KrakenBookUpdate::Applied(_)
+        ));
+        assert!(matches!(
+            apply(&mut handler, update),
+            KrakenBookUpdate::Applied(_)
+        ));
+        assert_eq!(
+            handler.book().best_ask_size(),
+            Some(Quantity::from("4000.50000000"))
+        );
+        assert_eq!(handler.book().bids().count(), 1);
+    }
+
+    #[rstest]
+    fn test_apply_checksum_mismatch_triggers_resync() {
+        let mut handler = handler();
+        let update = r#"{
+            "channel": "book",
+            "type": "update",
+            "data": [{
+                "symbol": "MATIC/USD",
+                "bids": [{"price": 0.5665, "qty": 0.0}],
+                "asks": [],
+                "checksum": 1,
+                "timestamp": "2023-10-06T17:35:55.440295Z"
+            }]
+        }"#;
+        apply(&mut handler, BOOK_SNAPSHOT);
+
+        assert!(matches!(
+            apply(&mut handler, update),
+            KrakenBookUpdate::Resync
+        ));
+        assert!(!handler.book().has_bid());
+        assert!(!handler.book().has_ask());
+        assert!(matches!(
+            apply(&mut handler, update),
+            KrakenBookUpdate::Skipped
+        ));
+        assert!(matches!(
+            apply(&mut handler, BOOK_SNAPSHOT),
+            KrakenBookUpdate::Applied(_)
+        ));
+    }
+
+    #[rstest]
+    fn test_apply_truncates_to_depth() {
+        let mut handler = KrakenBookHandler::new(InstrumentId::from("MATIC/USD.KRAKEN"), 1, 4, 8);
+        let msg = KrakenBook {
+            symbol: Ustr::from("MATIC/USD"),
+            bids: vec![
+                KrakenBookLevel {
+                    price: 0.5666,
+                    qty: 4831.75496356,
+                },
+                KrakenBookLevel {
+                    price: 0.5665,
+                    qty: 6658.22734739,
+                },
+            ],
+            asks: vec![KrakenBookLevel {
+                price: 0.5668,
+                qty: 4410.79769741,
+            }],
+            checksum: 2652227746,
+            timestamp: None,
+        };
+
+        handler
+            .apply(&msg, KrakenEventType::Snapshot, UnixNanos::default())
+            .unwrap();
+
+        assert_eq!(handler.book().bids().count(), 1);
+        assert_eq!(handler.book().best_bid_price(), Some(Price::from("0.5666")));
+    }
+}
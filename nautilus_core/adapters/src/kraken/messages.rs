@@ -0,0 +1,96 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Message types for the Kraken WebSocket v2 feed.
+
+use serde::Deserialize;
+use ustr::Ustr;
+
+/// Represents a channel message received from the Kraken WebSocket v2 feed.
+///
+/// Method responses (e.g. for `subscribe`) have no `channel` field and should be handled
+/// before deserializing to this type.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "channel", rename_all = "snake_case")]
+pub enum KrakenWsMessage {
+    Ticker(KrakenWsEnvelope<KrakenTicker>),
+    Trade(KrakenWsEnvelope<KrakenTrade>),
+    Book(KrakenWsEnvelope<KrakenBook>),
+    /// Any other channel (e.g. heartbeat or status).
+    #[serde(other)]
+    Other,
+}
+
+/// Represents the envelope common to all channel messages.
+#[derive(Clone, Debug, Deserialize)]
+pub struct KrakenWsEnvelope<T> {
+    #[serde(rename = "type")]
+    pub event_type: KrakenEventType,
+    pub data: Vec<T>,
+}
+
+/// Represents the type of a channel message.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KrakenEventType {
+    Snapshot,
+    Update,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct KrakenTicker {
+    pub symbol: Ustr,
+    pub bid: f64,
+    pub bid_qty: f64,
+    pub ask: f64,
+    pub ask_qty: f64,
+    pub last: f64,
+}
+
+/// Represents the side of the taker for a Kraken trade.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KrakenTradeSide {
+    Buy,
+    Sell,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct KrakenTrade {
+    pub symbol: Ustr,
+    pub side: KrakenTradeSide,
+    pub price: f64,
+    pub qty: f64,
+    pub trade_id: u64,
+    pub timestamp: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct KrakenBookLevel {
+    pub price: f64,
+    pub qty: f64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct KrakenBook {
+    pub symbol: Ustr,
+    #[serde(default)]
+    pub bids: Vec<KrakenBookLevel>,
+    #[serde(default)]
+    pub asks: Vec<KrakenBookLevel>,
+    pub checksum: u32,
+    /// The time of the update (not present for snapshots).
+    pub timestamp: Option<String>,
+}
@@ -0,0 +1,348 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Parsing of Kraken WebSocket v2 messages into Nautilus data types.
+
+use nautilus_core::{datetime::iso8601_to_unix_nanos, nanos::UnixNanos};
+use nautilus_model::{
+    data::{
+        delta::OrderBookDelta, deltas::OrderBookDeltas, order::BookOrder, quote::QuoteTick,
+        trade::TradeTick,
+    },
+    enums::{AggressorSide, BookAction, OrderSide, RecordFlag},
+    identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+    orderbook::{book::OrderBook, level::Level},
+    types::{price::Price, quantity::Quantity},
+};
+
+use super::messages::{KrakenBook, KrakenEventType, KrakenTicker, KrakenTrade, KrakenTradeSide};
+
+/// The count of levels per side included in the Kraken book checksum.
+pub const KRAKEN_CHECKSUM_DEPTH: usize = 10;
+
+/// Returns a [`QuoteTick`] parsed from the best bid and ask of the given `ticker`.
+pub fn parse_quote_tick(
+    ticker: &KrakenTicker,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> anyhow::Result<QuoteTick> {
+    QuoteTick::new(
+        instrument_id,
+        Price::new(ticker.bid, price_precision)?,
+        Price::new(ticker.ask, price_precision)?,
+        Quantity::new(ticker.bid_qty, size_precision)?,
+        Quantity::new(ticker.ask_qty, size_precision)?,
+        ts_event,
+        ts_init,
+    )
+}
+
+/// Returns a [`TradeTick`] parsed from the given `trade`.
+pub fn parse_trade_tick(
+    trade: &KrakenTrade,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    ts_init: UnixNanos,
+) -> anyhow::Result<TradeTick> {
+    let aggressor_side = match trade.side {
+        KrakenTradeSide::Buy => AggressorSide::Buyer,
+        KrakenTradeSide::Sell => AggressorSide::Seller,
+    };
+    Ok(TradeTick::new(
+        instrument_id,
+        Price::new(trade.price, price_precision)?,
+        Quantity::new(trade.qty, size_precision)?,
+        aggressor_side,
+        TradeId::new(&trade.trade_id.to_string())?,
+        iso8601_to_unix_nanos(&trade.timestamp)?,
+        ts_init,
+    ))
+}
+
+/// Returns [`OrderBookDeltas`] parsed from the given `book` message.
+///
+/// A snapshot is preceded by a `Clear` delta, and levels with a zero quantity are deleted.
+/// Snapshots carry no timestamp, so `ts_init` is used for the event timestamp.
+pub fn parse_order_book_deltas(
+    book: &KrakenBook,
+    event_type: KrakenEventType,
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+    sequence: u64,
+    ts_init: UnixNanos,
+) -> anyhow::Result<OrderBookDeltas> {
+    let ts_event = match &book.timestamp {
+        Some(timestamp) => iso8601_to_unix_nanos(timestamp)?,
+        None => ts_init,
+    };
+    let is_snapshot = event_type == KrakenEventType::Snapshot;
+    let flags = if is_snapshot {
+        RecordFlag::F_SNAPSHOT as u8
+    } else {
+        0
+    };
+
+    let mut deltas = Vec::with_capacity(book.bids.len() + book.asks.len() + 1);
+    if is_snapshot {
+        deltas.push(OrderBookDelta::clear(
+            instrument_id,
+            sequence,
+            ts_event,
+            ts_init,
+        ));
+    }
+
+    let levels = book
+        .bids
+        .iter()
+        .map(|level| (OrderSide::Buy, level))
+        .chain(book.asks.iter().map(|level| (OrderSide::Sell, level)));
+    for (side, level) in levels {
+        let size = Quantity::new(level.qty, size_precision)?;
+        let action = match (is_snapshot, size.is_zero()) {
+            (_, true) => BookAction::Delete,
+            (true, false) => BookAction::Add,
+            (false, false) => BookAction::Update,
+        };
+        let order = BookOrder::new(side, Price::new(level.price, price_precision)?, size, 0);
+        deltas.push(OrderBookDelta::new(
+            instrument_id,
+            action,
+            order,
+            flags,
+            sequence,
+            ts_event,
+            ts_init,
+        ));
+    }
+
+    if let Some(last) = deltas.last_mut() {
+        last.flags |= RecordFlag::F_LAST as u8;
+    } else {
+        anyhow::bail!("No levels for {instrument_id}");
+    }
+
+    Ok(OrderBookDeltas::new(instrument_id, deltas))
+}
+
+fn push_checksum_level(
+    input: &mut String,
+    level: &Level,
+    size_precision: u8,
+) -> anyhow::Result<()> {
+    let size = Quantity::from_raw(level.size_raw(), size_precision)?;
+    for value in [level.price.value.to_string(), size.to_string()] {
+        input.push_str(value.replace('.', "").trim_start_matches('0'));
+    }
+    Ok(())
+}
+
+fn checksum_input(book: &OrderBook, size_precision: u8) -> anyhow::Result<String> {
+    let mut input = String::new();
+    for level in book.asks().take(KRAKEN_CHECKSUM_DEPTH) {
+        push_checksum_level(&mut input, level, size_precision)?;
+    }
+    for level in book.bids().take(KRAKEN_CHECKSUM_DEPTH) {
+        push_checksum_level(&mut input, level, size_precision)?;
+    }
+    Ok(input)
+}
+
+/// Returns the Kraken CRC32 checksum for the top levels of the given `book`.
+///
+/// Prices must be at the instrument price precision, and sizes are formatted with the given
+/// `size_precision`.
+pub fn kraken_book_checksum(book: &OrderBook, size_precision: u8) -> anyhow::Result<u32> {
+    Ok(crc32fast::hash(
+        checksum_input(book, size_precision)?.as_bytes(),
+    ))
+}
+
+/// Verifies the `expected` checksum from a Kraken book message against the given `book`.
+///
+/// # Errors
+///
+/// This function returns an error if the checksum does not match, in which case the book
+/// should be resubscribed.
+pub fn verify_book_checksum(
+    book: &OrderBook,
+    size_precision: u8,
+    expected: u32,
+) -> anyhow::Result<()> {
+    let checksum = kraken_book_checksum(book, size_precision)?;
+    if checksum != expected {
+        anyhow::bail!(
+            "Book checksum mismatch for {}, expected {expected}, was {checksum}",
+            book.instrument_id
+        );
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::enums::BookType;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::kraken::messages::KrakenWsMessage;
+
+    const BOOK_SNAPSHOT: &str = r#"{
+        "channel": "book",
+        "type": "snapshot",
+        "data": [{
+            "symbol": "MATIC/USD",
+            "bids": [{"price": 0.5666, "qty": 4831.75496356}, {"price": 0.5665, "qty": 6658.22734739}],
+            "asks": [{"price": 0.5668, "qty": 4410.79769741}, {"price": 0.5669, "qty": 4655.40412487}],
+            "checksum": 3588693387
+        }]
+    }"#;
+
+    const BOOK_UPDATE: &str = r#"{
+        "channel": "book",
+        "type": "update",
+        "data": [{
+            "symbol": "MATIC/USD",
+            "bids": [{"price": 0.5665, "qty": 0.0}],
+            "asks": [{"price": 0.5668, "qty": 4000.5}],
+            "checksum": 890190776,
+            "timestamp": "2023-10-06T17:35:55.440295Z"
+        }]
+    }"#;
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("MATIC/USD.KRAKEN")
+    }
+
+    fn parse_book(json: &str) -> OrderBookDeltas {
+        let KrakenWsMessage::Book(msg) = serde_json::from_str(json).unwrap() else {
+            panic!("Expected book");
+        };
+        parse_order_book_deltas(
+            &msg.data[0],
+            msg.event_type,
+            instrument_id(),
+            4,
+            8,
+            0,
+            UnixNanos::default(),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_parse_quote_tick() {
+        let json = r#"{
+            "channel": "ticker",
+            "type": "snapshot",
+            "data": [{"symbol": "BTC/USD", "bid": 63471.1, "bid_qty": 0.5, "ask": 63471.2,
+                      "ask_qty": 1.25, "last": 63471.2, "volume": 1000.0}]
+        }"#;
+        let KrakenWsMessage::Ticker(msg) = serde_json::from_str(json).unwrap() else {
+            panic!("Expected ticker");
+        };
+
+        let quote = parse_quote_tick(
+            &msg.data[0],
+            InstrumentId::from("BTC/USD.KRAKEN"),
+            1,
+            8,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(quote.bid_price, Price::from("63471.1"));
+        assert_eq!(quote.ask_size, Quantity::from("1.25000000"));
+    }
+
+    #[rstest]
+    fn test_parse_trade_tick() {
+        let json = r#"{
+            "channel": "trade",
+            "type": "update",
+            "data": [{"symbol": "MATIC/USD", "side": "sell", "price": 0.5117, "qty": 40.0,
+                      "ord_type": "market", "trade_id": 4665906,
+                      "timestamp": "2023-09-25T07:49:37.708706Z"}]
+        }"#;
+        let KrakenWsMessage::Trade(msg) = serde_json::from_str(json).unwrap() else {
+            panic!("Expected trade");
+        };
+
+        let tick =
+            parse_trade_tick(&msg.data[0], instrument_id(), 4, 8, UnixNanos::default()).unwrap();
+
+        assert_eq!(tick.price, Price::from("0.5117"));
+        assert_eq!(tick.aggressor_side, AggressorSide::Seller);
+        assert_eq!(tick.trade_id.to_string(), "4665906");
+        assert_eq!(
+            tick.ts_event,
+            UnixNanos::from(1_695_628_177_708_706_000_u64)
+        );
+    }
+
+    #[rstest]
+    fn test_parse_book_snapshot() {
+        let deltas = parse_book(BOOK_SNAPSHOT);
+
+        assert_eq!(deltas.deltas.len(), 5);
+        assert_eq!(deltas.deltas[0].action, BookAction::Clear);
+        assert_eq!(deltas.deltas[1].action, BookAction::Add);
+        assert_eq!(deltas.deltas[1].order.side, OrderSide::Buy);
+        assert_eq!(deltas.deltas[4].order.side, OrderSide::Sell);
+        assert!(RecordFlag::F_LAST.matches(deltas.flags));
+    }
+
+    #[rstest]
+    fn test_parse_book_update() {
+        let deltas = parse_book(BOOK_UPDATE);
+
+        assert_eq!(deltas.deltas.len(), 2);
+        assert_eq!(deltas.deltas[0].action, BookAction::Delete);
+        assert_eq!(deltas.deltas[1].action, BookAction::Update);
+        assert_eq!(
+            deltas.ts_event,
+            UnixNanos::from(1_696_613_755_440_295_000_u64)
+        );
+    }
+
+    #[rstest]
+    fn test_book_checksum() {
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id());
+        book.apply_deltas(parse_book(BOOK_SNAPSHOT));
+        book.apply_deltas(parse_book(BOOK_UPDATE));
+
+        let input = checksum_input(&book, 8).unwrap();
+        let checksum = kraken_book_checksum(&book, 8).unwrap();
+
+        assert_eq!(
+            input,
+            "5668400050000000\
+             5669465540412487\
+             5666483175496356"
+        );
+        assert_eq!(checksum, 890_190_776);
+        assert!(verify_book_checksum(&book, 8, checksum).is_ok());
+        assert!(verify_book_checksum(&book, 8, checksum.wrapping_add(1)).is_err());
+    }
+}
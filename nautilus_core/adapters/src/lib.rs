@@ -24,9 +24,27 @@
 //! depending on the intended use case, i.e. whether to provide Python bindings
 //! for the main `nautilus_trader` Python package, or as part of a Rust only build.
 //!
+//! - `coinbase`: Includes the Coinbase WebSocket market data parsers
 //! - `databento`: Includes the Databento integration adapter
 //! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`
+//! - `kraken`: Includes the Kraken WebSocket market data parsers
+//! - `nasdaq`: Includes the Nasdaq ITCH/OUCH reference binary adapter
 //! - `python`: Enables Python bindings from `pyo3`
+//! - `sbe`: Includes Simple Binary Encoding (SBE) schema-driven decoding
+
+pub mod binary;
+
+#[cfg(feature = "coinbase")]
+pub mod coinbase;
 
 #[cfg(feature = "databento")]
 pub mod databento;
+
+#[cfg(feature = "kraken")]
+pub mod kraken;
+
+#[cfg(feature = "nasdaq")]
+pub mod nasdaq;
+
+#[cfg(feature = "sbe")]
+pub mod sbe;
@@ -0,0 +1,216 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Session layer framing for Nasdaq protocols.
+//!
+//! ITCH is disseminated over UDP multicast in MoldUDP64 packets, and OUCH runs over TCP
+//! with SoupBinTCP packet framing.
+
+use crate::binary::BinaryReader;
+
+/// The length of the MoldUDP64 packet header in bytes.
+pub const MOLDUDP64_HEADER_LENGTH: usize = 20;
+
+/// The MoldUDP64 message count which signals the end of the session.
+pub const MOLDUDP64_END_OF_SESSION: u16 = 0xFFFF;
+
+/// SoupBinTCP packet type for sequenced data from the server.
+pub const SOUP_SEQUENCED_DATA: u8 = b'S';
+/// SoupBinTCP packet type for unsequenced data from the client.
+pub const SOUP_UNSEQUENCED_DATA: u8 = b'U';
+/// SoupBinTCP packet type for a server heartbeat.
+pub const SOUP_SERVER_HEARTBEAT: u8 = b'H';
+/// SoupBinTCP packet type for a client heartbeat.
+pub const SOUP_CLIENT_HEARTBEAT: u8 = b'R';
+/// SoupBinTCP packet type for a login request from the client.
+pub const SOUP_LOGIN_REQUEST: u8 = b'L';
+/// SoupBinTCP packet type for an accepted login.
+pub const SOUP_LOGIN_ACCEPTED: u8 = b'A';
+/// SoupBinTCP packet type for a rejected login.
+pub const SOUP_LOGIN_REJECTED: u8 = b'J';
+/// SoupBinTCP packet type for the end of the session.
+pub const SOUP_END_OF_SESSION: u8 = b'Z';
+/// SoupBinTCP packet type for a logout request from the client.
+pub const SOUP_LOGOUT_REQUEST: u8 = b'O';
+/// SoupBinTCP packet type for free form debug text, which is ignored.
+pub const SOUP_DEBUG: u8 = b'+';
+
+/// Represents a MoldUDP64 downstream packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoldUdp64Packet<'a> {
+    /// The session the packet belongs to.
+    pub session: &'a str,
+    /// The sequence number of the first message in the packet.
+    pub sequence: u64,
+    /// The count of messages, or `MOLDUDP64_END_OF_SESSION`.
+    pub count: u16,
+    /// The messages contained in the packet.
+    pub messages: Vec<&'a [u8]>,
+}
+
+impl<'a> MoldUdp64Packet<'a> {
+    /// Returns a packet parsed from the given UDP datagram `buf`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the datagram is truncated.
+    pub fn parse(buf: &'a [u8]) -> anyhow::Result<Self> {
+        let mut reader = BinaryReader::new(buf);
+        let session = reader.read_fixed_str(10)?;
+        let sequence = reader.read_u64_be()?;
+        let count = reader.read_u16_be()?;
+
+        let mut messages = Vec::new();
+        if count != MOLDUDP64_END_OF_SESSION {
+            messages.reserve(usize::from(count));
+            for _ in 0..count {
+                let len = reader.read_u16_be()?;
+                messages.push(reader.read_bytes(usize::from(len))?);
+            }
+        }
+
+        Ok(Self {
+            session,
+            sequence,
+            count,
+            messages,
+        })
+    }
+
+    /// Returns whether the packet is a heartbeat (contains no messages).
+    #[must_use]
+    pub fn is_heartbeat(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns whether the packet signals the end of the session.
+    #[must_use]
+    pub fn is_end_of_session(&self) -> bool {
+        self.count == MOLDUDP64_END_OF_SESSION
+    }
+
+    /// Returns the sequence number expected for the first message of the next packet.
+    #[must_use]
+    pub fn next_sequence(&self) -> u64 {
+        self.sequence + self.messages.len() as u64
+    }
+}
+
+/// Returns the SoupBinTCP packet for the given `packet_type` and `payload`.
+///
+/// # Errors
+///
+/// This function returns an error if the payload is too long to frame.
+pub fn encode_soup_packet(packet_type: u8, payload: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let len = u16::try_from(payload.len() + 1)
+        .map_err(|_| anyhow::anyhow!("Payload too long, was {} bytes", payload.len()))?;
+    let mut buf = Vec::with_capacity(payload.len() + 3);
+    buf.extend_from_slice(&len.to_be_bytes());
+    buf.push(packet_type);
+    buf.extend_from_slice(payload);
+    Ok(buf)
+}
+
+/// Provides reassembly of SoupBinTCP packets from a TCP byte stream.
+#[derive(Clone, Debug, Default)]
+pub struct SoupFramer {
+    buf: Vec<u8>,
+}
+
+impl SoupFramer {
+    /// Creates a new empty [`SoupFramer`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the given `bytes` read from the stream.
+    pub fn extend(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Returns the next complete packet as its type and payload, or `None` if more bytes
+    /// are required.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a packet has a zero length (is missing its type).
+    pub fn next_packet(&mut self) -> anyhow::Result<Option<(u8, Vec<u8>)>> {
+        if self.buf.len() < 2 {
+            return Ok(None);
+        }
+        let len = usize::from(u16::from_be_bytes([self.buf[0], self.buf[1]]));
+        if len == 0 {
+            anyhow::bail!("Invalid SoupBinTCP packet length 0");
+        }
+        if self.buf.len() < len + 2 {
+            return Ok(None);
+        }
+
+        let packet: Vec<u8> = self.buf.drain(..len + 2).collect();
+        Ok(Some((packet[2], packet[3..].to_vec())))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_parse_moldudp64_packet() {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(b"000000001A");
+        buf.extend_from_slice(&100_u64.to_be_bytes());
+        buf.extend_from_slice(&2_u16.to_be_bytes());
+        for message in [&b"abc"[..], &b"de"[..]] {
+            buf.extend_from_slice(&(message.len() as u16).to_be_bytes());
+            buf.extend_from_slice(message);
+        }
+
+        let packet = MoldUdp64Packet::parse(&buf).unwrap();
+
+        assert_eq!(packet.session, "000000001A");
+        assert_eq!(packet.messages, vec![&b"abc"[..], &b"de"[..]]);
+        assert_eq!(packet.next_sequence(), 102);
+        assert!(!packet.is_heartbeat());
+        assert!(MoldUdp64Packet::parse(&buf[..buf.len() - 1]).is_err());
+    }
+
+    #[rstest]
+    fn test_soup_framer_reassembles_split_packets() {
+        let mut stream = encode_soup_packet(SOUP_SEQUENCED_DATA, b"hello").unwrap();
+        stream.extend(encode_soup_packet(SOUP_SERVER_HEARTBEAT, &[]).unwrap());
+        let mut framer = SoupFramer::new();
+
+        framer.extend(&stream[..4]);
+        assert_eq!(framer.next_packet().unwrap(), None);
+        framer.extend(&stream[4..]);
+
+        assert_eq!(
+            framer.next_packet().unwrap(),
+            Some((SOUP_SEQUENCED_DATA, b"hello".to_vec()))
+        );
+        assert_eq!(
+            framer.next_packet().unwrap(),
+            Some((SOUP_SERVER_HEARTBEAT, vec![]))
+        );
+        assert_eq!(framer.next_packet().unwrap(), None);
+    }
+}
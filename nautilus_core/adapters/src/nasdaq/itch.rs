@@ -0,0 +1,574 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Decoding of Nasdaq TotalView-ITCH 5.0 messages, and construction of Nautilus order book
+//! deltas and trades from the order-level feed.
+
+use std::collections::HashMap;
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::{delta::OrderBookDelta, order::BookOrder, trade::TradeTick, Data},
+    enums::{AggressorSide, BookAction, OrderSide},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol, trade_id::TradeId, venue::Venue},
+    types::{price::Price, quantity::Quantity},
+};
+
+use super::parse_price;
+use crate::binary::{map_enum, BinaryReader};
+
+const SIDE_MAP: [(u8, OrderSide); 2] = [(b'B', OrderSide::Buy), (b'S', OrderSide::Sell)];
+
+/// Represents the header common to all ITCH messages.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ItchHeader {
+    /// The message type.
+    pub message_type: u8,
+    /// The locate code identifying the security.
+    pub stock_locate: u16,
+    /// The Nasdaq internal tracking number.
+    pub tracking_number: u16,
+    /// The nanoseconds since midnight (US/Eastern) of the trading day.
+    pub timestamp: u64,
+}
+
+/// Represents the body of a decoded ITCH message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ItchBody {
+    SystemEvent {
+        event_code: char,
+    },
+    AddOrder {
+        order_ref: u64,
+        side: OrderSide,
+        shares: u32,
+        stock: String,
+        price: u32,
+    },
+    OrderExecuted {
+        order_ref: u64,
+        executed_shares: u32,
+        match_number: u64,
+    },
+    OrderExecutedWithPrice {
+        order_ref: u64,
+        executed_shares: u32,
+        match_number: u64,
+        printable: bool,
+        execution_price: u32,
+    },
+    OrderCancel {
+        order_ref: u64,
+        cancelled_shares: u32,
+    },
+    OrderDelete {
+        order_ref: u64,
+    },
+    OrderReplace {
+        original_order_ref: u64,
+        new_order_ref: u64,
+        shares: u32,
+        price: u32,
+    },
+    Trade {
+        order_ref: u64,
+        side: OrderSide,
+        shares: u32,
+        stock: String,
+        price: u32,
+        match_number: u64,
+    },
+    /// A message type which is not handled (e.g. administrative or NOII messages).
+    Unhandled,
+}
+
+/// Represents a decoded ITCH message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ItchMessage {
+    pub header: ItchHeader,
+    pub body: ItchBody,
+}
+
+fn read_timestamp(reader: &mut BinaryReader) -> anyhow::Result<u64> {
+    let bytes = reader.read_bytes(6)?;
+    Ok(bytes.iter().fold(0, |acc, b| (acc << 8) | u64::from(*b)))
+}
+
+/// Returns the ITCH message decoded from the given `buf` (a single message, without framing).
+///
+/// # Errors
+///
+/// This function returns an error if the message is truncated or contains an invalid value.
+pub fn decode_itch_message(buf: &[u8]) -> anyhow::Result<ItchMessage> {
+    let mut reader = BinaryReader::new(buf);
+    let header = ItchHeader {
+        message_type: reader.read_u8()?,
+        stock_locate: reader.read_u16_be()?,
+        tracking_number: reader.read_u16_be()?,
+        timestamp: read_timestamp(&mut reader)?,
+    };
+
+    let body = match header.message_type {
+        b'S' => ItchBody::SystemEvent {
+            event_code: char::from(reader.read_u8()?),
+        },
+        b'A' | b'F' => ItchBody::AddOrder {
+            order_ref: reader.read_u64_be()?,
+            side: map_enum(reader.read_u8()?, &SIDE_MAP, "side")?,
+            shares: reader.read_u32_be()?,
+            stock: reader.read_fixed_str(8)?.to_string(),
+            price: reader.read_u32_be()?,
+        },
+        b'E' => ItchBody::OrderExecuted {
+            order_ref: reader.read_u64_be()?,
+            executed_shares: reader.read_u32_be()?,
+            match_number: reader.read_u64_be()?,
+        },
+        b'C' => ItchBody::OrderExecutedWithPrice {
+            order_ref: reader.read_u64_be()?,
+            executed_shares: reader.read_u32_be()?,
+            match_number: reader.read_u64_be()?,
+            printable: reader.read_u8()? == b'Y',
+            execution_price: reader.read_u32_be()?,
+        },
+        b'X' => ItchBody::OrderCancel {
+            order_ref: reader.read_u64_be()?,
+            cancelled_shares: reader.read_u32_be()?,
+        },
+        b'D' => ItchBody::OrderDelete {
+            order_ref: reader.read_u64_be()?,
+        },
+        b'U' => ItchBody::OrderReplace {
+            original_order_ref: reader.read_u64_be()?,
+            new_order_ref: reader.read_u64_be()?,
+            shares: reader.read_u32_be()?,
+            price: reader.read_u32_be()?,
+        },
+        b'P' => ItchBody::Trade {
+            order_ref: reader.read_u64_be()?,
+            side: map_enum(reader.read_u8()?, &SIDE_MAP, "side")?,
+            shares: reader.read_u32_be()?,
+            stock: reader.read_fixed_str(8)?.to_string(),
+            price: reader.read_u32_be()?,
+            match_number: reader.read_u64_be()?,
+        },
+        _ => ItchBody::Unhandled,
+    };
+
+    Ok(ItchMessage { header, body })
+}
+
+#[derive(Copy, Clone, Debug)]
+struct RestingOrder {
+    instrument_id: InstrumentId,
+    side: OrderSide,
+    price: Price,
+    shares: u32,
+}
+
+/// Provides a handler which builds Nautilus data from an ITCH order-level feed.
+///
+/// The handler tracks resting orders by reference number, as executions, cancels and deletes
+/// refer to an order only by its reference number.
+#[derive(Debug)]
+pub struct ItchBookHandler {
+    venue: Venue,
+    midnight_ns: UnixNanos,
+    orders: HashMap<u64, RestingOrder>,
+}
+
+impl ItchBookHandler {
+    /// Creates a new [`ItchBookHandler`] instance.
+    ///
+    /// The `midnight_ns` is the UNIX timestamp of midnight (US/Eastern) for the trading day,
+    /// from which ITCH timestamps are offset.
+    #[must_use]
+    pub fn new(venue: Venue, midnight_ns: UnixNanos) -> Self {
+        Self {
+            venue,
+            midnight_ns,
+            orders: HashMap::new(),
+        }
+    }
+
+    /// Returns the count of resting orders being tracked.
+    #[must_use]
+    pub fn order_count(&self) -> usize {
+        self.orders.len()
+    }
+
+    /// Returns the Nautilus data produced by the given ITCH `message`, where `sequence` is
+    /// the MoldUDP64 sequence number of the message.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the message refers to an unknown order, or contains
+    /// an invalid value.
+    pub fn handle(
+        &mut self,
+        message: &ItchMessage,
+        sequence: u64,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Vec<Data>> {
+        let ts_event = UnixNanos::from(self.midnight_ns.as_u64() + message.header.timestamp);
+
+        let data = match &message.body {
+            ItchBody::AddOrder {
+                order_ref,
+                side,
+                shares,
+                stock,
+                price,
+            } => {
+                let order = RestingOrder {
+                    instrument_id: self.instrument_id(stock)?,
+                    side: *side,
+                    price: parse_price(*price)?,
+                    shares: *shares,
+                };
+                self.orders.insert(*order_ref, order);
+                vec![delta(
+                    BookAction::Add,
+                    *order_ref,
+                    &order,
+                    sequence,
+                    ts_event,
+                    ts_init,
+                )]
+            }
+            ItchBody::OrderExecuted {
+                order_ref,
+                executed_shares,
+                match_number,
+            } => {
+                let order = self.reduce_order(*order_ref, *executed_shares)?;
+                vec![
+                    trade(
+                        &order,
+                        order.price,
+                        *executed_shares,
+                        *match_number,
+                        ts_event,
+                        ts_init,
+                    )?,
+                    reduce_delta(*order_ref, &order, sequence, ts_event, ts_init),
+                ]
+            }
+            ItchBody::OrderExecutedWithPrice {
+                order_ref,
+                executed_shares,
+                match_number,
+                printable,
+                execution_price,
+            } => {
+                let order = self.reduce_order(*order_ref, *executed_shares)?;
+                let mut data = Vec::with_capacity(2);
+                if *printable {
+                    let price = parse_price(*execution_price)?;
+                    data.push(trade(
+                        &order,
+                        price,
+                        *executed_shares,
+                        *match_number,
+                        ts_event,
+                        ts_init,
+                    )?);
+                }
+                data.push(reduce_delta(
+                    *order_ref, &order, sequence, ts_event, ts_init,
+                ));
+                data
+            }
+            ItchBody::OrderCancel {
+                order_ref,
+                cancelled_shares,
+            } => {
+                let order = self.reduce_order(*order_ref, *cancelled_shares)?;
+                vec![reduce_delta(
+                    *order_ref, &order, sequence, ts_event, ts_init,
+                )]
+            }
+            ItchBody::OrderDelete { order_ref } => {
+                let order = self.remove_order(*order_ref)?;
+                vec![delta(
+                    BookAction::Delete,
+                    *order_ref,
+                    &order,
+                    sequence,
+                    ts_event,
+                    ts_init,
+                )]
+            }
+            ItchBody::OrderReplace {
+                original_order_ref,
+                new_order_ref,
+                shares,
+                price,
+            } => {
+                let original = self.remove_order(*original_order_ref)?;
+                let order = RestingOrder {
+                    price: parse_price(*price)?,
+                    shares: *shares,
+                    ..original
+                };
+                self.orders.insert(*new_order_ref, order);
+                vec![
+                    delta(
+                        BookAction::Delete,
+                        *original_order_ref,
+                        &original,
+                        sequence,
+                        ts_event,
+                        ts_init,
+                    ),
+                    delta(
+                        BookAction::Add,
+                        *new_order_ref,
+                        &order,
+                        sequence,
+                        ts_event,
+                        ts_init,
+                    ),
+                ]
+            }
+            ItchBody::Trade {
+                side,
+                shares,
+                stock,
+                price,
+                match_number,
+                ..
+            } => {
+                // Execution against a non-displayed order, so the book is unaffected
+                let order = RestingOrder {
+                    instrument_id: self.instrument_id(stock)?,
+                    side: *side,
+                    price: parse_price(*price)?,
+                    shares: *shares,
+                };
+                vec![trade(
+                    &order,
+                    order.price,
+                    *shares,
+                    *match_number,
+                    ts_event,
+                    ts_init,
+                )?]
+            }
+            ItchBody::SystemEvent { .. } | ItchBody::Unhandled => vec![],
+        };
+
+        Ok(data)
+    }
+
+    fn instrument_id(&self, stock: &str) -> anyhow::Result<InstrumentId> {
+        Ok(InstrumentId::new(Symbol::new(stock)?, self.venue))
+    }
+
+    /// Reduces the shares of the order by `shares`, removing the order when fully reduced,
+    /// and returns the order with its remaining shares.
+    fn reduce_order(&mut self, order_ref: u64, shares: u32) -> anyhow::Result<RestingOrder> {
+        let order = self
+            .orders
+            .get_mut(&order_ref)
+            .ok_or_else(|| anyhow::anyhow!("Unknown order reference {order_ref}"))?;
+        order.shares = order.shares.saturating_sub(shares);
+        let order = *order;
+        if order.shares == 0 {
+            self.orders.remove(&order_ref);
+        }
+        Ok(order)
+    }
+
+    fn remove_order(&mut self, order_ref: u64) -> anyhow::Result<RestingOrder> {
+        self.orders
+            .remove(&order_ref)
+            .ok_or_else(|| anyhow::anyhow!("Unknown order reference {order_ref}"))
+    }
+}
+
+fn delta(
+    action: BookAction,
+    order_ref: u64,
+    order: &RestingOrder,
+    sequence: u64,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> Data {
+    let book_order = BookOrder::new(
+        order.side,
+        order.price,
+        Quantity::from(i64::from(order.shares)),
+        order_ref,
+    );
+    Data::Delta(OrderBookDelta::new(
+        order.instrument_id,
+        action,
+        book_order,
+        0,
+        sequence,
+        ts_event,
+        ts_init,
+    ))
+}
+
+fn reduce_delta(
+    order_ref: u64,
+    order: &RestingOrder,
+    sequence: u64,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> Data {
+    let action = if order.shares == 0 {
+        BookAction::Delete
+    } else {
+        BookAction::Update
+    };
+    delta(action, order_ref, order, sequence, ts_event, ts_init)
+}
+
+fn trade(
+    order: &RestingOrder,
+    price: Price,
+    shares: u32,
+    match_number: u64,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Data> {
+    // The aggressor is on the opposite side of the resting order
+    let aggressor_side = match order.side {
+        OrderSide::Buy => AggressorSide::Seller,
+        OrderSide::Sell => AggressorSide::Buyer,
+        OrderSide::NoOrderSide => AggressorSide::NoAggressor,
+    };
+    Ok(Data::Trade(TradeTick::new(
+        order.instrument_id,
+        price,
+        Quantity::from(i64::from(shares)),
+        aggressor_side,
+        TradeId::new(&match_number.to_string())?,
+        ts_event,
+        ts_init,
+    )))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn header(message_type: u8, tracking_number: u16, timestamp: u64) -> Vec<u8> {
+        let mut buf = vec![message_type];
+        buf.extend_from_slice(&1_u16.to_be_bytes());
+        buf.extend_from_slice(&tracking_number.to_be_bytes());
+        buf.extend_from_slice(&timestamp.to_be_bytes()[2..]);
+        buf
+    }
+
+    fn add_order(order_ref: u64, side: u8, shares: u32, price: u32) -> Vec<u8> {
+        let mut buf = header(b'A', 1, 1_000);
+        buf.extend_from_slice(&order_ref.to_be_bytes());
+        buf.push(side);
+        buf.extend_from_slice(&shares.to_be_bytes());
+        buf.extend_from_slice(b"AAPL    ");
+        buf.extend_from_slice(&price.to_be_bytes());
+        buf
+    }
+
+    fn order_executed(order_ref: u64, shares: u32, match_number: u64) -> Vec<u8> {
+        let mut buf = header(b'E', 2, 2_000);
+        buf.extend_from_slice(&order_ref.to_be_bytes());
+        buf.extend_from_slice(&shares.to_be_bytes());
+        buf.extend_from_slice(&match_number.to_be_bytes());
+        buf
+    }
+
+    fn handler() -> ItchBookHandler {
+        ItchBookHandler::new(Venue::from("XNAS"), UnixNanos::from(1_000_000))
+    }
+
+    #[rstest]
+    fn test_decode_add_order() {
+        let message = decode_itch_message(&add_order(42, b'B', 100, 1_895_000)).unwrap();
+
+        assert_eq!(message.header.timestamp, 1_000);
+        assert_eq!(
+            message.body,
+            ItchBody::AddOrder {
+                order_ref: 42,
+                side: OrderSide::Buy,
+                shares: 100,
+                stock: "AAPL".to_string(),
+                price: 1_895_000,
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_decode_truncated_message() {
+        let buf = add_order(42, b'B', 100, 1_895_000);
+
+        assert!(decode_itch_message(&buf[..buf.len() - 1]).is_err());
+    }
+
+    #[rstest]
+    fn test_handle_add_then_partial_and_full_execution() {
+        let mut handler = handler();
+        let add = decode_itch_message(&add_order(42, b'S', 100, 1_895_000)).unwrap();
+        let partial = decode_itch_message(&order_executed(42, 40, 7)).unwrap();
+        let full = decode_itch_message(&order_executed(42, 60, 8)).unwrap();
+
+        let added = handler.handle(&add, 1, UnixNanos::default()).unwrap();
+        let executed = handler.handle(&partial, 2, UnixNanos::default()).unwrap();
+
+        let Data::Delta(delta) = &added[0] else {
+            panic!("Expected delta");
+        };
+        assert_eq!(delta.instrument_id, InstrumentId::from("AAPL.XNAS"));
+        assert_eq!(delta.action, BookAction::Add);
+        assert_eq!(delta.order.price, Price::from("189.5000"));
+        assert_eq!(delta.ts_event, UnixNanos::from(1_001_000));
+        let Data::Trade(trade) = &executed[0] else {
+            panic!("Expected trade");
+        };
+        assert_eq!(trade.size, Quantity::from(40));
+        assert_eq!(trade.aggressor_side, AggressorSide::Buyer);
+        assert_eq!(trade.trade_id.to_string(), "7");
+        let Data::Delta(delta) = &executed[1] else {
+            panic!("Expected delta");
+        };
+        assert_eq!(delta.action, BookAction::Update);
+        assert_eq!(delta.order.size, Quantity::from(60));
+
+        let executed = handler.handle(&full, 3, UnixNanos::default()).unwrap();
+        let Data::Delta(delta) = &executed[1] else {
+            panic!("Expected delta");
+        };
+        assert_eq!(delta.action, BookAction::Delete);
+        assert_eq!(handler.order_count(), 0);
+    }
+
+    #[rstest]
+    fn test_handle_unknown_order_reference() {
+        let mut handler = handler();
+        let message = decode_itch_message(&order_executed(99, 10, 1)).unwrap();
+
+        assert!(handler.handle(&message, 1, UnixNanos::default()).is_err());
+    }
+}
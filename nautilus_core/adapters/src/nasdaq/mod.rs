@@ -0,0 +1,55 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A reference binary adapter for Nasdaq equities protocols.
+//!
+//! Provides handlers for TotalView-ITCH 5.0 market data and OUCH 4.2 order entry, along with
+//! the MoldUDP64 and SoupBinTCP session framing they are carried over, and a SoupBinTCP session
+//! client for order entry.
+
+pub mod framing;
+pub mod itch;
+pub mod ouch;
+pub mod session;
+
+use nautilus_model::types::{fixed::FIXED_PRECISION, price::Price};
+
+use crate::binary::scaled_to_price;
+
+/// The count of implied decimal places for Nasdaq `Price(4)` fields.
+pub const NASDAQ_PRICE_PRECISION: u8 = 4;
+
+/// Returns a [`Price`] from the given Nasdaq `Price(4)` field value.
+pub fn parse_price(value: u32) -> anyhow::Result<Price> {
+    scaled_to_price(
+        i64::from(value),
+        -(NASDAQ_PRICE_PRECISION as i8),
+        NASDAQ_PRICE_PRECISION,
+    )
+}
+
+/// Returns the Nasdaq `Price(4)` field value for the given `price`.
+///
+/// # Errors
+///
+/// This function returns an error if the price is negative, too large, or has more than
+/// four decimal places.
+pub fn format_price(price: Price) -> anyhow::Result<u32> {
+    let divisor = 10_i64.pow(u32::from(FIXED_PRECISION - NASDAQ_PRICE_PRECISION));
+    if price.raw % divisor != 0 {
+        anyhow::bail!("Price {price} exceeds the maximum precision {NASDAQ_PRICE_PRECISION}");
+    }
+    u32::try_from(price.raw / divisor).map_err(|_| anyhow::anyhow!("Invalid price {price}"))
+}
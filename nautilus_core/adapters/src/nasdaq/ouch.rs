@@ -0,0 +1,336 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Encoding and decoding of Nasdaq OUCH 4.2 order entry messages.
+//!
+//! Outbound messages are built from Nautilus orders and commands, and inbound messages are
+//! decoded with accessors returning Nautilus types.
+
+use nautilus_model::{
+    enums::{LiquiditySide, OrderSide, TimeInForce},
+    identifiers::{client_order_id::ClientOrderId, trade_id::TradeId},
+    orders::limit::LimitOrder,
+    types::{fixed::FIXED_PRECISION, price::Price, quantity::Quantity},
+};
+
+use super::{format_price, parse_price};
+use crate::binary::BinaryReader;
+
+/// The OUCH time in force for an order which is live until the end of the market hours.
+pub const OUCH_TIF_MARKET_HOURS: u32 = 99_998;
+/// The OUCH time in force for an order which is immediately canceled if not executed.
+pub const OUCH_TIF_IOC: u32 = 0;
+
+const TOKEN_LENGTH: usize = 14;
+
+fn put_alpha(buf: &mut Vec<u8>, value: &str, len: usize, field: &str) -> anyhow::Result<()> {
+    if value.len() > len || !value.is_ascii() {
+        anyhow::bail!("Invalid `{field}` for OUCH field of {len} bytes, was '{value}'");
+    }
+    buf.extend_from_slice(value.as_bytes());
+    buf.resize(buf.len() + len - value.len(), b' ');
+    Ok(())
+}
+
+fn format_shares(quantity: Quantity) -> anyhow::Result<u32> {
+    let scalar = 10_u64.pow(u32::from(FIXED_PRECISION));
+    if quantity.raw % scalar != 0 {
+        anyhow::bail!("Quantity {quantity} is not a whole number of shares");
+    }
+    u32::try_from(quantity.raw / scalar).map_err(|_| anyhow::anyhow!("Invalid quantity {quantity}"))
+}
+
+/// Represents an OUCH Enter Order message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OuchEnterOrder {
+    pub token: String,
+    pub side: OrderSide,
+    pub shares: u32,
+    pub stock: String,
+    pub price: u32,
+    pub time_in_force: u32,
+    pub firm: String,
+}
+
+impl OuchEnterOrder {
+    /// Creates a new [`OuchEnterOrder`] from the given limit `order`.
+    ///
+    /// The client order ID is used as the order token.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order cannot be represented in OUCH, e.g. the
+    /// time in force is not supported or the quantity is not a whole number of shares.
+    pub fn from_order(order: &LimitOrder, firm: &str) -> anyhow::Result<Self> {
+        let time_in_force = match order.time_in_force {
+            TimeInForce::Day => OUCH_TIF_MARKET_HOURS,
+            TimeInForce::Ioc => OUCH_TIF_IOC,
+            tif => anyhow::bail!("`TimeInForce` {tif} not supported for OUCH"),
+        };
+        Ok(Self {
+            token: order.client_order_id.to_string(),
+            side: order.side,
+            shares: format_shares(order.quantity)?,
+            stock: order.instrument_id.symbol.to_string(),
+            price: format_price(order.price)?,
+            time_in_force,
+            firm: firm.to_string(),
+        })
+    }
+
+    /// Returns the encoded message.
+    pub fn encode(&self) -> anyhow::Result<Vec<u8>> {
+        let side = match self.side {
+            OrderSide::Buy => b'B',
+            OrderSide::Sell => b'S',
+            OrderSide::NoOrderSide => anyhow::bail!("Invalid `OrderSide`, was {}", self.side),
+        };
+
+        let mut buf = Vec::with_capacity(49);
+        buf.push(b'O');
+        put_alpha(&mut buf, &self.token, TOKEN_LENGTH, "token")?;
+        buf.push(side);
+        buf.extend_from_slice(&self.shares.to_be_bytes());
+        put_alpha(&mut buf, &self.stock, 8, "stock")?;
+        buf.extend_from_slice(&self.price.to_be_bytes());
+        buf.extend_from_slice(&self.time_in_force.to_be_bytes());
+        put_alpha(&mut buf, &self.firm, 4, "firm")?;
+        buf.push(b'Y'); // Display
+        buf.push(b'A'); // Capacity (agency)
+        buf.push(b'N'); // Intermarket sweep eligibility
+        buf.extend_from_slice(&0_u32.to_be_bytes()); // Minimum quantity
+        buf.push(b'N'); // Cross type (continuous market)
+        buf.push(b' '); // Customer type (default)
+        Ok(buf)
+    }
+}
+
+/// Returns an encoded OUCH Cancel Order message, reducing the order to `shares` remaining
+/// (zero cancels the order).
+pub fn encode_cancel_order(
+    client_order_id: &ClientOrderId,
+    shares: u32,
+) -> anyhow::Result<Vec<u8>> {
+    let mut buf = Vec::with_capacity(19);
+    buf.push(b'X');
+    put_alpha(&mut buf, client_order_id.as_str(), TOKEN_LENGTH, "token")?;
+    buf.extend_from_slice(&shares.to_be_bytes());
+    Ok(buf)
+}
+
+/// Represents a decoded inbound OUCH message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OuchMessage {
+    Accepted {
+        timestamp: u64,
+        token: String,
+        shares: u32,
+        price: u32,
+        order_ref: u64,
+    },
+    Canceled {
+        timestamp: u64,
+        token: String,
+        decrement_shares: u32,
+        reason: char,
+    },
+    Executed {
+        timestamp: u64,
+        token: String,
+        executed_shares: u32,
+        execution_price: u32,
+        liquidity_flag: char,
+        match_number: u64,
+    },
+    Rejected {
+        timestamp: u64,
+        token: String,
+        reason: char,
+    },
+    /// A message type which is not handled (e.g. system events or broken trades).
+    Unhandled(u8),
+}
+
+impl OuchMessage {
+    /// Returns the client order ID for the message (if the message relates to an order).
+    pub fn client_order_id(&self) -> anyhow::Result<Option<ClientOrderId>> {
+        match self {
+            Self::Accepted { token, .. }
+            | Self::Canceled { token, .. }
+            | Self::Executed { token, .. }
+            | Self::Rejected { token, .. } => Ok(Some(ClientOrderId::new(token)?)),
+            Self::Unhandled(_) => Ok(None),
+        }
+    }
+
+    /// Returns the last price, quantity, trade ID and liquidity side for an `Executed` message.
+    pub fn fill(&self) -> anyhow::Result<Option<(Price, Quantity, TradeId, LiquiditySide)>> {
+        let Self::Executed {
+            executed_shares,
+            execution_price,
+            liquidity_flag,
+            match_number,
+            ..
+        } = self
+        else {
+            return Ok(None);
+        };
+
+        let liquidity_side = match liquidity_flag {
+            'A' | 'F' | 'k' | 'e' => LiquiditySide::Maker,
+            'R' | 'X' | 'm' | 'f' => LiquiditySide::Taker,
+            _ => LiquiditySide::NoLiquiditySide,
+        };
+        Ok(Some((
+            parse_price(*execution_price)?,
+            Quantity::from(i64::from(*executed_shares)),
+            TradeId::new(&match_number.to_string())?,
+            liquidity_side,
+        )))
+    }
+}
+
+/// Returns the inbound OUCH message decoded from the given `buf`.
+///
+/// # Errors
+///
+/// This function returns an error if the message is truncated.
+pub fn decode_ouch_message(buf: &[u8]) -> anyhow::Result<OuchMessage> {
+    let mut reader = BinaryReader::new(buf);
+    let message_type = reader.read_u8()?;
+    if !matches!(message_type, b'A' | b'C' | b'E' | b'J') {
+        return Ok(OuchMessage::Unhandled(message_type));
+    }
+
+    let timestamp = reader.read_u64_be()?;
+    let token = reader.read_fixed_str(TOKEN_LENGTH)?.to_string();
+
+    let message = match message_type {
+        b'A' => {
+            reader.skip(1)?; // Side
+            let shares = reader.read_u32_be()?;
+            reader.skip(8)?; // Stock
+            let price = reader.read_u32_be()?;
+            reader.skip(9)?; // Time in force, firm and display
+            OuchMessage::Accepted {
+                timestamp,
+                token,
+                shares,
+                price,
+                order_ref: reader.read_u64_be()?,
+            }
+        }
+        b'C' => OuchMessage::Canceled {
+            timestamp,
+            token,
+            decrement_shares: reader.read_u32_be()?,
+            reason: char::from(reader.read_u8()?),
+        },
+        b'E' => OuchMessage::Executed {
+            timestamp,
+            token,
+            executed_shares: reader.read_u32_be()?,
+            execution_price: reader.read_u32_be()?,
+            liquidity_flag: char::from(reader.read_u8()?),
+            match_number: reader.read_u64_be()?,
+        },
+        _ => OuchMessage::Rejected {
+            timestamp,
+            token,
+            reason: char::from(reader.read_u8()?),
+        },
+    };
+
+    Ok(message)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{identifiers::instrument_id::InstrumentId, orders::stubs::TestOrderStubs};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn limit_order(time_in_force: TimeInForce) -> LimitOrder {
+        TestOrderStubs::limit_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Price::from("189.50"),
+            Quantity::from(100),
+            Some(ClientOrderId::from("O-123")),
+            Some(time_in_force),
+        )
+        .into()
+    }
+
+    #[rstest]
+    fn test_encode_enter_order() {
+        let order = limit_order(TimeInForce::Day);
+
+        let buf = OuchEnterOrder::from_order(&order, "NAUT")
+            .unwrap()
+            .encode()
+            .unwrap();
+
+        assert_eq!(buf.len(), 49);
+        assert_eq!(&buf[..16], b"OO-123         B");
+        assert_eq!(&buf[16..20], &100_u32.to_be_bytes());
+        assert_eq!(&buf[20..28], b"AAPL    ");
+        assert_eq!(&buf[28..32], &1_895_000_u32.to_be_bytes());
+        assert_eq!(&buf[32..36], &OUCH_TIF_MARKET_HOURS.to_be_bytes());
+    }
+
+    #[rstest]
+    fn test_enter_order_unsupported_time_in_force() {
+        let order = limit_order(TimeInForce::Gtc);
+
+        assert!(OuchEnterOrder::from_order(&order, "NAUT").is_err());
+    }
+
+    #[rstest]
+    fn test_encode_cancel_order() {
+        let buf = encode_cancel_order(&ClientOrderId::from("O-123"), 0).unwrap();
+
+        assert_eq!(buf.len(), 19);
+        assert_eq!(buf[0], b'X');
+    }
+
+    #[rstest]
+    fn test_decode_executed() {
+        let mut buf = vec![b'E'];
+        buf.extend_from_slice(&1_000_u64.to_be_bytes());
+        buf.extend_from_slice(b"O-123         ");
+        buf.extend_from_slice(&40_u32.to_be_bytes());
+        buf.extend_from_slice(&1_895_000_u32.to_be_bytes());
+        buf.push(b'A');
+        buf.extend_from_slice(&7_u64.to_be_bytes());
+
+        let message = decode_ouch_message(&buf).unwrap();
+        let (last_px, last_qty, trade_id, liquidity_side) = message.fill().unwrap().unwrap();
+
+        assert_eq!(
+            message.client_order_id().unwrap(),
+            Some(ClientOrderId::from("O-123"))
+        );
+        assert_eq!(last_px, Price::from("189.5000"));
+        assert_eq!(last_qty, Quantity::from(40));
+        assert_eq!(trade_id.to_string(), "7");
+        assert_eq!(liquidity_side, LiquiditySide::Maker);
+        assert!(decode_ouch_message(&buf[..buf.len() - 1]).is_err());
+    }
+}
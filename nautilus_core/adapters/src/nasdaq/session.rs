@@ -0,0 +1,562 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! SoupBinTCP 4.0 session handling for OUCH order entry.
+//!
+//! [`SoupSession`] implements the login, sequencing and heartbeat rules independently of any
+//! transport, and [`SoupBinTcpClient`] drives a session over a TCP stream. The network crate's
+//! `SocketClient` splits its stream on a delimiter suffix, which does not apply to the length
+//! prefixed SoupBinTCP packets, so the client reads the stream directly.
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpStream, ToSocketAddrs},
+};
+
+use super::framing::{
+    encode_soup_packet, SoupFramer, SOUP_CLIENT_HEARTBEAT, SOUP_DEBUG, SOUP_END_OF_SESSION,
+    SOUP_LOGIN_ACCEPTED, SOUP_LOGIN_REJECTED, SOUP_LOGIN_REQUEST, SOUP_LOGOUT_REQUEST,
+    SOUP_SEQUENCED_DATA, SOUP_SERVER_HEARTBEAT, SOUP_UNSEQUENCED_DATA,
+};
+use crate::binary::BinaryReader;
+
+/// The interval after which a heartbeat is sent if no other packet has been sent.
+pub const SOUP_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The interval without any packet received after which the connection is considered lost.
+pub const SOUP_SESSION_TIMEOUT: Duration = Duration::from_secs(15);
+
+fn put_alpha(buf: &mut Vec<u8>, value: &str, len: usize, field: &str) -> anyhow::Result<()> {
+    if value.len() > len || !value.is_ascii() {
+        anyhow::bail!("Invalid `{field}` for SoupBinTCP field of {len} bytes, was '{value}'");
+    }
+    buf.extend_from_slice(value.as_bytes());
+    buf.resize(buf.len() + len - value.len(), b' ');
+    Ok(())
+}
+
+/// Represents the credentials and start point for a SoupBinTCP login.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SoupLogin {
+    /// The username (up to 6 characters).
+    pub username: String,
+    /// The password (up to 10 characters).
+    pub password: String,
+    /// The session to log into, or `None` for the currently active session.
+    pub session: Option<String>,
+    /// The sequence number of the next message to receive, or 0 for the most recent message.
+    pub sequence: u64,
+}
+
+impl SoupLogin {
+    /// Returns the encoded Login Request payload.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a field is too long or not ASCII.
+    pub fn encode(&self) -> anyhow::Result<Vec<u8>> {
+        let mut buf = Vec::with_capacity(46);
+        put_alpha(&mut buf, &self.username, 6, "username")?;
+        put_alpha(&mut buf, &self.password, 10, "password")?;
+        put_alpha(
+            &mut buf,
+            self.session.as_deref().unwrap_or_default(),
+            10,
+            "session",
+        )?;
+        buf.extend_from_slice(format!("{:>20}", self.sequence).as_bytes());
+        Ok(buf)
+    }
+}
+
+/// The reason given by the server for rejecting a login.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoupRejectReason {
+    /// The username and password were not authorized.
+    NotAuthorized,
+    /// The requested session was not available.
+    SessionNotAvailable,
+}
+
+/// Represents the state of a [`SoupSession`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoupSessionState {
+    /// The login has not yet been sent.
+    Idle,
+    /// The login has been sent and a response is awaited.
+    LoggingIn,
+    /// The login was accepted and the session is active.
+    Active,
+    /// The session ended, was logged out, or the login was rejected.
+    Closed,
+}
+
+/// Represents an event from the server for a [`SoupSession`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SoupEvent {
+    /// The login was accepted for `session`, starting from the message `sequence`.
+    LoginAccepted { session: String, sequence: u64 },
+    /// The login was rejected.
+    LoginRejected(SoupRejectReason),
+    /// A sequenced message from the server.
+    Sequenced { sequence: u64, payload: Vec<u8> },
+    /// The server ended the session.
+    EndOfSession,
+}
+
+/// Provides the client side of a SoupBinTCP session, independent of the transport.
+///
+/// Bytes read from the stream are passed to [`SoupSession::on_bytes`], and the packets
+/// returned by the session methods are written to the stream.
+#[derive(Debug)]
+pub struct SoupSession {
+    state: SoupSessionState,
+    session: Option<String>,
+    next_sequence: u64,
+    framer: SoupFramer,
+    last_sent: Instant,
+    last_received: Instant,
+}
+
+impl SoupSession {
+    /// Creates a new idle [`SoupSession`] instance.
+    #[must_use]
+    pub fn new(now: Instant) -> Self {
+        Self {
+            state: SoupSessionState::Idle,
+            session: None,
+            next_sequence: 0,
+            framer: SoupFramer::new(),
+            last_sent: now,
+            last_received: now,
+        }
+    }
+
+    #[must_use]
+    pub fn state(&self) -> SoupSessionState {
+        self.state
+    }
+
+    /// Returns the session name assigned by the server, once logged in.
+    #[must_use]
+    pub fn session(&self) -> Option<&str> {
+        self.session.as_deref()
+    }
+
+    /// Returns the sequence number expected for the next sequenced message.
+    #[must_use]
+    pub fn next_sequence(&self) -> u64 {
+        self.next_sequence
+    }
+
+    /// Returns the Login Request packet for the given `login`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the session is not idle, or the login is invalid.
+    pub fn login(&mut self, login: &SoupLogin, now: Instant) -> anyhow::Result<Vec<u8>> {
+        if self.state != SoupSessionState::Idle {
+            anyhow::bail!("Cannot login, session was {:?}", self.state);
+        }
+        let packet = encode_soup_packet(SOUP_LOGIN_REQUEST, &login.encode()?)?;
+        self.state = SoupSessionState::LoggingIn;
+        self.last_received = now;
+        self.last_sent = now;
+        Ok(packet)
+    }
+
+    /// Returns the Unsequenced Data packet carrying the given `payload`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the session is not active.
+    pub fn unsequenced(&mut self, payload: &[u8], now: Instant) -> anyhow::Result<Vec<u8>> {
+        self.check_active()?;
+        self.last_sent = now;
+        encode_soup_packet(SOUP_UNSEQUENCED_DATA, payload)
+    }
+
+    /// Returns the Logout Request packet, closing the session.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the session is not active.
+    pub fn logout(&mut self, now: Instant) -> anyhow::Result<Vec<u8>> {
+        self.check_active()?;
+        self.state = SoupSessionState::Closed;
+        self.last_sent = now;
+        encode_soup_packet(SOUP_LOGOUT_REQUEST, &[])
+    }
+
+    /// Returns a Client Heartbeat packet if no packet has been sent for the heartbeat interval.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no packet has been received from the server within
+    /// the session timeout, in which case the connection should be dropped.
+    pub fn poll_heartbeat(&mut self, now: Instant) -> anyhow::Result<Option<Vec<u8>>> {
+        if !matches!(
+            self.state,
+            SoupSessionState::LoggingIn | SoupSessionState::Active
+        ) {
+            return Ok(None);
+        }
+        if now.saturating_duration_since(self.last_received) >= SOUP_SESSION_TIMEOUT {
+            self.state = SoupSessionState::Closed;
+            anyhow::bail!("SoupBinTCP session timed out awaiting the server");
+        }
+        if self.state == SoupSessionState::Active
+            && now.saturating_duration_since(self.last_sent) >= SOUP_HEARTBEAT_INTERVAL
+        {
+            self.last_sent = now;
+            return Ok(Some(encode_soup_packet(SOUP_CLIENT_HEARTBEAT, &[])?));
+        }
+        Ok(None)
+    }
+
+    /// Returns the events for the packets completed by the given `bytes` read from the stream.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a packet is malformed or unexpected for the session
+    /// state.
+    pub fn on_bytes(&mut self, bytes: &[u8], now: Instant) -> anyhow::Result<Vec<SoupEvent>> {
+        self.framer.extend(bytes);
+        let mut events = Vec::new();
+        while let Some((packet_type, payload)) = self.framer.next_packet()? {
+            self.last_received = now;
+            if let Some(event) = self.on_packet(packet_type, payload)? {
+                events.push(event);
+            }
+        }
+        Ok(events)
+    }
+
+    fn on_packet(
+        &mut self,
+        packet_type: u8,
+        payload: Vec<u8>,
+    ) -> anyhow::Result<Option<SoupEvent>> {
+        match (self.state, packet_type) {
+            (_, SOUP_SERVER_HEARTBEAT | SOUP_DEBUG) => Ok(None),
+            (SoupSessionState::LoggingIn, SOUP_LOGIN_ACCEPTED) => {
+                let mut reader = BinaryReader::new(&payload);
+                let session = reader.read_fixed_str(10)?.to_string();
+                let sequence = reader.read_fixed_str(20)?.trim_start().parse::<u64>()?;
+                self.state = SoupSessionState::Active;
+                self.session = Some(session.clone());
+                self.next_sequence = sequence;
+                Ok(Some(SoupEvent::LoginAccepted { session, sequence }))
+            }
+            (SoupSessionState::LoggingIn, SOUP_LOGIN_REJECTED) => {
+                let reason = match payload.first() {
+                    Some(b'A') => SoupRejectReason::NotAuthorized,
+                    Some(b'S') => SoupRejectReason::SessionNotAvailable,
+                    code => anyhow::bail!("Invalid SoupBinTCP reject reason {code:?}"),
+                };
+                self.state = SoupSessionState::Closed;
+                Ok(Some(SoupEvent::LoginRejected(reason)))
+            }
+            (SoupSessionState::Active, SOUP_SEQUENCED_DATA) => {
+                let sequence = self.next_sequence;
+                self.next_sequence += 1;
+                Ok(Some(SoupEvent::Sequenced { sequence, payload }))
+            }
+            (SoupSessionState::Active, SOUP_END_OF_SESSION) => {
+                self.state = SoupSessionState::Closed;
+                Ok(Some(SoupEvent::EndOfSession))
+            }
+            (state, packet_type) => anyhow::bail!(
+                "Unexpected SoupBinTCP packet type '{}' for session {state:?}",
+                char::from(packet_type)
+            ),
+        }
+    }
+
+    fn check_active(&self) -> anyhow::Result<()> {
+        if self.state != SoupSessionState::Active {
+            anyhow::bail!("SoupBinTCP session not active, was {:?}", self.state);
+        }
+        Ok(())
+    }
+}
+
+/// Provides a SoupBinTCP client over TCP, sending heartbeats while awaiting server packets.
+#[derive(Debug)]
+pub struct SoupBinTcpClient {
+    stream: TcpStream,
+    session: SoupSession,
+    events: VecDeque<SoupEvent>,
+}
+
+impl SoupBinTcpClient {
+    /// Connects to the server at `addr` and logs in with the given `login`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the connection fails, or the login is rejected.
+    pub async fn connect(addr: impl ToSocketAddrs, login: &SoupLogin) -> anyhow::Result<Self> {
+        let stream = TcpStream::connect(addr).await?;
+        stream.set_nodelay(true)?;
+        let mut client = Self {
+            stream,
+            session: SoupSession::new(Instant::now()),
+            events: VecDeque::new(),
+        };
+
+        let packet = client.session.login(login, Instant::now())?;
+        client.stream.write_all(&packet).await?;
+        match client.recv().await? {
+            SoupEvent::LoginAccepted { .. } => Ok(client),
+            SoupEvent::LoginRejected(reason) => anyhow::bail!("Login rejected: {reason:?}"),
+            event => anyhow::bail!("Unexpected {event:?} awaiting login"),
+        }
+    }
+
+    #[must_use]
+    pub fn session(&self) -> &SoupSession {
+        &self.session
+    }
+
+    /// Sends the given `payload` (e.g. an encoded OUCH message) as unsequenced data.
+    pub async fn send(&mut self, payload: &[u8]) -> anyhow::Result<()> {
+        let packet = self.session.unsequenced(payload, Instant::now())?;
+        self.stream.write_all(&packet).await?;
+        Ok(())
+    }
+
+    /// Returns the next event from the server, sending heartbeats while waiting.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the connection is closed or times out.
+    pub async fn recv(&mut self) -> anyhow::Result<SoupEvent> {
+        let mut buf = [0_u8; 4096];
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Ok(event);
+            }
+            if let Some(heartbeat) = self.session.poll_heartbeat(Instant::now())? {
+                self.stream.write_all(&heartbeat).await?;
+            }
+
+            let read =
+                tokio::time::timeout(SOUP_HEARTBEAT_INTERVAL, self.stream.read(&mut buf)).await;
+            match read {
+                Ok(Ok(0)) => anyhow::bail!("SoupBinTCP connection closed by the server"),
+                Ok(Ok(len)) => {
+                    let events = self.session.on_bytes(&buf[..len], Instant::now())?;
+                    self.events.extend(events);
+                }
+                Ok(Err(e)) => return Err(e.into()),
+                Err(_) => {} // Check heartbeats
+            }
+        }
+    }
+
+    /// Logs out of the session and closes the connection.
+    pub async fn logout(mut self) -> anyhow::Result<()> {
+        let packet = self.session.logout(Instant::now())?;
+        self.stream.write_all(&packet).await?;
+        self.stream.shutdown().await?;
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    fn login() -> SoupLogin {
+        SoupLogin {
+            username: "TRADER".to_string(),
+            password: "secret".to_string(),
+            session: None,
+            sequence: 1,
+        }
+    }
+
+    fn login_accepted(session: &str, sequence: u64) -> Vec<u8> {
+        let payload = format!("{session:<10}{sequence:>20}");
+        encode_soup_packet(SOUP_LOGIN_ACCEPTED, payload.as_bytes()).unwrap()
+    }
+
+    #[rstest]
+    fn test_login_encode() {
+        let payload = login().encode().unwrap();
+
+        assert_eq!(payload.len(), 46);
+        assert_eq!(&payload[..16], b"TRADERsecret    ");
+        assert_eq!(&payload[16..26], b"          ");
+        assert_eq!(&payload[26..], format!("{:>20}", 1).as_bytes());
+    }
+
+    #[rstest]
+    fn test_session_login_and_sequenced_data() {
+        let now = Instant::now();
+        let mut session = SoupSession::new(now);
+        let packet = session.login(&login(), now).unwrap();
+        assert_eq!(packet[2], SOUP_LOGIN_REQUEST);
+        assert!(session.unsequenced(b"O", now).is_err());
+
+        let mut stream = login_accepted("000001", 42);
+        stream.extend(encode_soup_packet(SOUP_SEQUENCED_DATA, b"A").unwrap());
+        stream.extend(encode_soup_packet(SOUP_SERVER_HEARTBEAT, &[]).unwrap());
+        stream.extend(encode_soup_packet(SOUP_SEQUENCED_DATA, b"E").unwrap());
+        let events = session.on_bytes(&stream, now).unwrap();
+
+        assert_eq!(
+            events,
+            vec![
+                SoupEvent::LoginAccepted {
+                    session: "000001".to_string(),
+                    sequence: 42
+                },
+                SoupEvent::Sequenced {
+                    sequence: 42,
+                    payload: b"A".to_vec()
+                },
+                SoupEvent::Sequenced {
+                    sequence: 43,
+                    payload: b"E".to_vec()
+                },
+            ]
+        );
+        assert_eq!(session.state(), SoupSessionState::Active);
+        assert_eq!(session.session(), Some("000001"));
+        assert_eq!(session.next_sequence(), 44);
+        assert_eq!(
+            session.unsequenced(b"O", now).unwrap()[2],
+            SOUP_UNSEQUENCED_DATA
+        );
+    }
+
+    #[rstest]
+    fn test_session_login_rejected() {
+        let now = Instant::now();
+        let mut session = SoupSession::new(now);
+        session.login(&login(), now).unwrap();
+
+        let packet = encode_soup_packet(SOUP_LOGIN_REJECTED, b"A").unwrap();
+        let events = session.on_bytes(&packet, now).unwrap();
+
+        assert_eq!(
+            events,
+            vec![SoupEvent::LoginRejected(SoupRejectReason::NotAuthorized)]
+        );
+        assert_eq!(session.state(), SoupSessionState::Closed);
+    }
+
+    #[rstest]
+    fn test_session_sequenced_data_before_login_is_error() {
+        let now = Instant::now();
+        let mut session = SoupSession::new(now);
+        session.login(&login(), now).unwrap();
+
+        let packet = encode_soup_packet(SOUP_SEQUENCED_DATA, b"A").unwrap();
+
+        assert!(session.on_bytes(&packet, now).is_err());
+    }
+
+    #[rstest]
+    fn test_session_heartbeat_and_timeout() {
+        let now = Instant::now();
+        let mut session = SoupSession::new(now);
+        session.login(&login(), now).unwrap();
+        session.on_bytes(&login_accepted("000001", 1), now).unwrap();
+
+        assert_eq!(session.poll_heartbeat(now).unwrap(), None);
+        let heartbeat = session
+            .poll_heartbeat(now + SOUP_HEARTBEAT_INTERVAL)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            heartbeat,
+            encode_soup_packet(SOUP_CLIENT_HEARTBEAT, &[]).unwrap()
+        );
+        assert!(session.poll_heartbeat(now + SOUP_SESSION_TIMEOUT).is_err());
+        assert_eq!(session.state(), SoupSessionState::Closed);
+    }
+
+    #[rstest]
+    fn test_session_logout_and_end_of_session() {
+        let now = Instant::now();
+        let mut session = SoupSession::new(now);
+        session.login(&login(), now).unwrap();
+        session.on_bytes(&login_accepted("000001", 1), now).unwrap();
+
+        let end = encode_soup_packet(SOUP_END_OF_SESSION, &[]).unwrap();
+        assert_eq!(
+            session.on_bytes(&end, now).unwrap(),
+            vec![SoupEvent::EndOfSession]
+        );
+        assert!(session.logout(now).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_client_login_send_and_recv() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut framer = SoupFramer::new();
+            let mut buf = [0_u8; 1024];
+            let mut packets = Vec::new();
+            while packets.len() < 3 {
+                let len = socket.read(&mut buf).await.unwrap();
+                framer.extend(&buf[..len]);
+                while let Some(packet) = framer.next_packet().unwrap() {
+                    if packet.0 == SOUP_LOGIN_REQUEST {
+                        let mut reply = login_accepted("000001", 7);
+                        reply.extend(encode_soup_packet(SOUP_SEQUENCED_DATA, b"A").unwrap());
+                        socket.write_all(&reply).await.unwrap();
+                    }
+                    if packet.0 != SOUP_CLIENT_HEARTBEAT {
+                        packets.push(packet);
+                    }
+                }
+            }
+            packets
+        });
+
+        let mut client = SoupBinTcpClient::connect(addr, &login()).await.unwrap();
+        let event = client.recv().await.unwrap();
+        client.send(b"O").await.unwrap();
+        client.logout().await.unwrap();
+        let packets = server.await.unwrap();
+
+        assert_eq!(
+            event,
+            SoupEvent::Sequenced {
+                sequence: 7,
+                payload: b"A".to_vec()
+            }
+        );
+        assert_eq!(packets[0].0, SOUP_LOGIN_REQUEST);
+        assert_eq!(packets[1], (SOUP_UNSEQUENCED_DATA, b"O".to_vec()));
+        assert_eq!(packets[2], (SOUP_LOGOUT_REQUEST, vec![]));
+    }
+}
@@ -0,0 +1,609 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Schema-driven decoding of SBE messages into field values, and on into Nautilus model types.
+
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+use nautilus_model::types::{price::Price, quantity::Quantity};
+
+use super::schema::{SbeByteOrder, SbeField, SbeGroup, SbeSchema, SbeType, SbeVarData};
+use crate::binary::{scaled_to_price, scaled_to_quantity, BinaryReader};
+
+/// The encoded length of the standard SBE message header in bytes.
+pub const SBE_HEADER_LENGTH: usize = 8;
+
+/// Represents the standard SBE message header.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SbeMessageHeader {
+    pub block_length: u16,
+    pub template_id: u16,
+    pub schema_id: u16,
+    pub version: u16,
+}
+
+/// Represents a decoded field value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SbeValue {
+    Null,
+    Char(char),
+    Int(i64),
+    UInt(u64),
+    String(String),
+    Bytes(Vec<u8>),
+    Decimal { mantissa: i64, exponent: i8 },
+}
+
+/// Represents a decoded SBE message, with field and var data values in schema order.
+///
+/// The entries of repeating groups are decoded as messages named for the group, which share
+/// the header of the message they were decoded from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SbeMessage {
+    pub header: SbeMessageHeader,
+    pub name: String,
+    pub fields: IndexMap<String, SbeValue>,
+    pub groups: IndexMap<String, Vec<SbeMessage>>,
+}
+
+impl SbeMessage {
+    fn field(&self, name: &str) -> anyhow::Result<&SbeValue> {
+        match self.fields.get(name) {
+            Some(SbeValue::Null) => anyhow::bail!("Field '{name}' of '{}' is null", self.name),
+            Some(value) => Ok(value),
+            None => anyhow::bail!("No field '{name}' for '{}'", self.name),
+        }
+    }
+
+    /// Returns whether the field `name` is null (or not present).
+    #[must_use]
+    pub fn is_null(&self, name: &str) -> bool {
+        matches!(self.fields.get(name), None | Some(SbeValue::Null))
+    }
+
+    /// Returns the value of the integer field `name`.
+    pub fn get_i64(&self, name: &str) -> anyhow::Result<i64> {
+        match self.field(name)? {
+            SbeValue::Int(value) => Ok(*value),
+            SbeValue::UInt(value) => Ok(i64::try_from(*value)?),
+            value => anyhow::bail!("Field '{name}' is not an integer, was {value:?}"),
+        }
+    }
+
+    /// Returns the value of the unsigned integer field `name`.
+    pub fn get_u64(&self, name: &str) -> anyhow::Result<u64> {
+        match self.field(name)? {
+            SbeValue::UInt(value) => Ok(*value),
+            SbeValue::Int(value) => Ok(u64::try_from(*value)?),
+            value => anyhow::bail!("Field '{name}' is not an integer, was {value:?}"),
+        }
+    }
+
+    /// Returns the value of the char field `name`.
+    pub fn get_char(&self, name: &str) -> anyhow::Result<char> {
+        match self.field(name)? {
+            SbeValue::Char(value) => Ok(*value),
+            value => anyhow::bail!("Field '{name}' is not a char, was {value:?}"),
+        }
+    }
+
+    /// Returns the value of the string field `name`.
+    pub fn get_str(&self, name: &str) -> anyhow::Result<&str> {
+        match self.field(name)? {
+            SbeValue::String(value) => Ok(value),
+            value => anyhow::bail!("Field '{name}' is not a string, was {value:?}"),
+        }
+    }
+
+    /// Returns the value of the var data field `name` as bytes.
+    pub fn get_bytes(&self, name: &str) -> anyhow::Result<&[u8]> {
+        match self.field(name)? {
+            SbeValue::Bytes(value) => Ok(value),
+            SbeValue::String(value) => Ok(value.as_bytes()),
+            value => anyhow::bail!("Field '{name}' is not var data, was {value:?}"),
+        }
+    }
+
+    /// Returns the entries of the repeating group `name`.
+    pub fn get_group(&self, name: &str) -> anyhow::Result<&[SbeMessage]> {
+        self.groups
+            .get(name)
+            .map(Vec::as_slice)
+            .ok_or_else(|| anyhow::anyhow!("No group '{name}' for '{}'", self.name))
+    }
+
+    /// Returns the value of the decimal field `name` as a [`Price`] with the given `precision`.
+    pub fn get_price(&self, name: &str, precision: u8) -> anyhow::Result<Price> {
+        match self.field(name)? {
+            SbeValue::Decimal { mantissa, exponent } => {
+                scaled_to_price(*mantissa, *exponent, precision)
+            }
+            value => anyhow::bail!("Field '{name}' is not a decimal, was {value:?}"),
+        }
+    }
+
+    /// Returns the value of the decimal field `name` as a [`Quantity`] with the given `precision`.
+    pub fn get_quantity(&self, name: &str, precision: u8) -> anyhow::Result<Quantity> {
+        match self.field(name)? {
+            SbeValue::Decimal { mantissa, exponent } => {
+                scaled_to_quantity(u64::try_from(*mantissa)?, *exponent, precision)
+            }
+            SbeValue::UInt(value) => scaled_to_quantity(*value, 0, precision),
+            value => anyhow::bail!("Field '{name}' is not a decimal, was {value:?}"),
+        }
+    }
+}
+
+/// A function which maps a decoded SBE message to a Nautilus type.
+pub type SbeMapFn<T> = fn(&SbeMessage) -> anyhow::Result<T>;
+
+/// Provides a decoder for the messages of an SBE schema.
+///
+/// Messages are decoded into field values according to the schema, and then mapped to `T`
+/// (e.g. `Data`) by the function registered for the messages template ID.
+#[derive(Clone, Debug)]
+pub struct SbeDecoder<T> {
+    schema: SbeSchema,
+    mappers: HashMap<u16, SbeMapFn<T>>,
+}
+
+impl<T> SbeDecoder<T> {
+    /// Creates a new [`SbeDecoder`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the schema fails validation.
+    pub fn new(schema: SbeSchema) -> anyhow::Result<Self> {
+        schema.validate()?;
+        Ok(Self {
+            schema,
+            mappers: HashMap::new(),
+        })
+    }
+
+    /// Registers the function `map_fn` to map messages with the given `template_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the template ID is not defined in the schema.
+    pub fn register(&mut self, template_id: u16, map_fn: SbeMapFn<T>) -> anyhow::Result<()> {
+        if self.schema.message(template_id).is_none() {
+            anyhow::bail!(
+                "Template ID {template_id} not defined in schema {}",
+                self.schema.id
+            );
+        }
+        self.mappers.insert(template_id, map_fn);
+        Ok(())
+    }
+
+    /// Returns the decoded message header for the given `buf`.
+    pub fn decode_header(&self, buf: &[u8]) -> anyhow::Result<SbeMessageHeader> {
+        let mut reader = BinaryReader::new(buf);
+        Ok(SbeMessageHeader {
+            block_length: self.read_u16(&mut reader)?,
+            template_id: self.read_u16(&mut reader)?,
+            schema_id: self.read_u16(&mut reader)?,
+            version: self.read_u16(&mut reader)?,
+        })
+    }
+
+    /// Returns the decoded message for the given `buf`, which must start with the message header.
+    ///
+    /// The root block is followed by any repeating groups, and then any var data, in schema
+    /// order. Block bytes beyond the fields of the schema (added in a later version) are
+    /// skipped, and fields beyond the encoded block length (added in this version) decode as
+    /// null.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The buffer is too short for the header, blocks, groups or var data.
+    /// - The schema ID of the message does not match the schema.
+    /// - The template ID of the message is not defined in the schema.
+    pub fn decode_message(&self, buf: &[u8]) -> anyhow::Result<SbeMessage> {
+        let header = self.decode_header(buf)?;
+        if header.schema_id != self.schema.id {
+            anyhow::bail!(
+                "Invalid schema ID {}, expected {}",
+                header.schema_id,
+                self.schema.id
+            );
+        }
+        let definition = self.schema.message(header.template_id).ok_or_else(|| {
+            anyhow::anyhow!("Template ID {} not defined in schema", header.template_id)
+        })?;
+
+        let mut reader = BinaryReader::new(buf);
+        reader.skip(SBE_HEADER_LENGTH)?;
+        self.decode_block(
+            &mut reader,
+            header,
+            &definition.name,
+            usize::from(header.block_length),
+            &definition.fields,
+            &definition.groups,
+            &definition.data,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn decode_block(
+        &self,
+        reader: &mut BinaryReader,
+        header: SbeMessageHeader,
+        name: &str,
+        block_length: usize,
+        fields: &[SbeField],
+        groups: &[SbeGroup],
+        data: &[SbeVarData],
+    ) -> anyhow::Result<SbeMessage> {
+        let block = reader.read_bytes(block_length)?;
+
+        let mut values = IndexMap::with_capacity(fields.len() + data.len());
+        for field in fields {
+            let value = if field.offset + field.sbe_type.encoded_length() > block.len() {
+                SbeValue::Null
+            } else {
+                let mut block_reader = BinaryReader::new(block);
+                block_reader.skip(field.offset)?;
+                let value = self.read_value(&mut block_reader, field.sbe_type)?;
+                if field.optional && is_null_value(&value, field) {
+                    SbeValue::Null
+                } else {
+                    value
+                }
+            };
+            values.insert(field.name.clone(), value);
+        }
+
+        let mut entries = IndexMap::with_capacity(groups.len());
+        for group in groups {
+            let entry_length = self.read_length(reader, group.dimension.block_length)?;
+            let num_in_group = self.read_length(reader, group.dimension.num_in_group)?;
+            let mut group_entries = Vec::with_capacity(num_in_group.min(reader.remaining()));
+            for _ in 0..num_in_group {
+                group_entries.push(self.decode_block(
+                    reader,
+                    header,
+                    &group.name,
+                    entry_length,
+                    &group.fields,
+                    &group.groups,
+                    &group.data,
+                )?);
+            }
+            entries.insert(group.name.clone(), group_entries);
+        }
+
+        for var_data in data {
+            let length = self.read_length(reader, var_data.length)?;
+            let bytes = reader.read_bytes(length)?;
+            let value = if var_data.is_string {
+                SbeValue::String(String::from_utf8(bytes.to_vec())?)
+            } else {
+                SbeValue::Bytes(bytes.to_vec())
+            };
+            values.insert(var_data.name.clone(), value);
+        }
+
+        Ok(SbeMessage {
+            header,
+            name: name.to_string(),
+            fields: values,
+            groups: entries,
+        })
+    }
+
+    /// Returns the Nautilus type mapped from the message decoded from the given `buf`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if decoding fails, or if no function is registered for
+    /// the messages template ID.
+    pub fn decode(&self, buf: &[u8]) -> anyhow::Result<T> {
+        let message = self.decode_message(buf)?;
+        let map_fn = self
+            .mappers
+            .get(&message.header.template_id)
+            .ok_or_else(|| anyhow::anyhow!("No mapping registered for '{}'", message.name))?;
+        map_fn(&message)
+    }
+
+    fn read_u16(&self, reader: &mut BinaryReader) -> anyhow::Result<u16> {
+        match self.schema.byte_order {
+            SbeByteOrder::LittleEndian => reader.read_u16_le(),
+            SbeByteOrder::BigEndian => reader.read_u16_be(),
+        }
+    }
+
+    fn read_value(&self, reader: &mut BinaryReader, sbe_type: SbeType) -> anyhow::Result<SbeValue> {
+        let le = self.schema.byte_order == SbeByteOrder::LittleEndian;
+        let value = match sbe_type {
+            SbeType::Char => SbeValue::Char(char::from(reader.read_u8()?)),
+            SbeType::Int8 => SbeValue::Int(i64::from(reader.read_i8()?)),
+            SbeType::Int16 if le => SbeValue::Int(i64::from(reader.read_i16_le()?)),
+            SbeType::Int16 => SbeValue::Int(i64::from(reader.read_i16_be()?)),
+            SbeType::Int32 if le => SbeValue::Int(i64::from(reader.read_i32_le()?)),
+            SbeType::Int32 => SbeValue::Int(i64::from(reader.read_i32_be()?)),
+            SbeType::Int64 if le => SbeValue::Int(reader.read_i64_le()?),
+            SbeType::Int64 => SbeValue::Int(reader.read_i64_be()?),
+            SbeType::Uint8 => SbeValue::UInt(u64::from(reader.read_u8()?)),
+            SbeType::Uint16 => SbeValue::UInt(u64::from(self.read_u16(reader)?)),
+            SbeType::Uint32 if le => SbeValue::UInt(u64::from(reader.read_u32_le()?)),
+            SbeType::Uint32 => SbeValue::UInt(u64::from(reader.read_u32_be()?)),
+            SbeType::Uint64 if le => SbeValue::UInt(reader.read_u64_le()?),
+            SbeType::Uint64 => SbeValue::UInt(reader.read_u64_be()?),
+            SbeType::String { length } => {
+                SbeValue::String(reader.read_fixed_str(length)?.to_string())
+            }
+            SbeType::Decimal { exponent } => SbeValue::Decimal {
+                mantissa: self.read_i64(reader)?,
+                exponent,
+            },
+            SbeType::DecimalComposite => SbeValue::Decimal {
+                mantissa: self.read_i64(reader)?,
+                exponent: reader.read_i8()?,
+            },
+        };
+        Ok(value)
+    }
+
+    fn read_length(&self, reader: &mut BinaryReader, sbe_type: SbeType) -> anyhow::Result<usize> {
+        match self.read_value(reader, sbe_type)? {
+            SbeValue::UInt(value) => Ok(usize::try_from(value)?),
+            value => anyhow::bail!("Invalid length {value:?}"),
+        }
+    }
+
+    fn read_i64(&self, reader: &mut BinaryReader) -> anyhow::Result<i64> {
+        match self.schema.byte_order {
+            SbeByteOrder::LittleEndian => reader.read_i64_le(),
+            SbeByteOrder::BigEndian => reader.read_i64_be(),
+        }
+    }
+}
+
+/// Returns whether the decoded `value` is the null value of the given `field`.
+///
+/// The null value is the one declared for the field by the schema, otherwise the SBE null
+/// value for the encoding type of the field.
+fn is_null_value(value: &SbeValue, field: &SbeField) -> bool {
+    let null_value = field.null_value();
+    match value {
+        SbeValue::Null => true,
+        SbeValue::Char(c) => i128::from(u32::from(*c)) == null_value,
+        SbeValue::Int(v) => i128::from(*v) == null_value,
+        SbeValue::UInt(v) => i128::from(*v) == null_value,
+        SbeValue::String(s) => s.is_empty(),
+        SbeValue::Bytes(b) => b.is_empty(),
+        SbeValue::Decimal { mantissa, .. } => i128::from(*mantissa) == null_value,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::{
+        data::trade::TradeTick,
+        enums::AggressorSide,
+        identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    const TRADE_TEMPLATE_ID: u16 = 1;
+
+    const SCHEMA_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<sbe:messageSchema xmlns:sbe="http://fixprotocol.io/2016/sbe"
+                   package="test" id="42" version="1" byteOrder="littleEndian">
+    <types>
+        <composite name="messageHeader">
+            <type name="blockLength" primitiveType="uint16"/>
+            <type name="templateId" primitiveType="uint16"/>
+            <type name="schemaId" primitiveType="uint16"/>
+            <type name="version" primitiveType="uint16"/>
+        </composite>
+        <composite name="groupSizeEncoding">
+            <type name="blockLength" primitiveType="uint16"/>
+            <type name="numInGroup" primitiveType="uint16"/>
+        </composite>
+        <composite name="varStringEncoding">
+            <type name="length" primitiveType="uint16"/>
+            <type name="varData" primitiveType="uint8" length="0" characterEncoding="UTF-8"/>
+        </composite>
+        <composite name="PRICE2">
+            <type name="mantissa" primitiveType="int64"/>
+            <type name="exponent" primitiveType="int8" presence="constant">-2</type>
+        </composite>
+        <composite name="Decimal">
+            <type name="mantissa" primitiveType="int64"/>
+            <type name="exponent" primitiveType="int8"/>
+        </composite>
+        <type name="Symbol" primitiveType="char" length="8"/>
+        <type name="Int16NULL" primitiveType="int16" presence="optional" nullValue="32767"/>
+        <enum name="Side" encodingType="char">
+            <validValue name="Buy">B</validValue>
+            <validValue name="Sell">S</validValue>
+        </enum>
+    </types>
+    <sbe:message name="Trade" id="1" blockLength="44">
+        <field name="symbol" id="1" type="Symbol" offset="0"/>
+        <field name="price" id="2" type="PRICE2" offset="8"/>
+        <field name="size" id="3" type="Decimal" offset="16"/>
+        <field name="side" id="4" type="Side" offset="25"/>
+        <field name="tradeId" id="5" type="uint64" offset="26"/>
+        <field name="transactTime" id="6" type="uint64" offset="34" presence="optional"/>
+        <field name="priceLevel" id="7" type="Int16NULL" offset="42"/>
+        <group name="fills" id="10" dimensionType="groupSizeEncoding" blockLength="16">
+            <field name="orderId" id="11" type="uint64"/>
+            <field name="fillQty" id="12" type="uint64"/>
+        </group>
+        <data name="text" id="20" type="varStringEncoding"/>
+    </sbe:message>
+</sbe:messageSchema>"#;
+
+    fn trade_message(transact_time: u64, price_level: i16, fills: &[(u64, u64)]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        for value in [44_u16, TRADE_TEMPLATE_ID, 42, 1] {
+            buf.extend_from_slice(&value.to_le_bytes());
+        }
+        buf.extend_from_slice(b"ESZ4\0\0\0\0");
+        buf.extend_from_slice(&598_125_i64.to_le_bytes());
+        buf.extend_from_slice(&15_i64.to_le_bytes());
+        buf.extend_from_slice(&0_i8.to_le_bytes());
+        buf.push(b'B');
+        buf.extend_from_slice(&123_456_u64.to_le_bytes());
+        buf.extend_from_slice(&transact_time.to_le_bytes());
+        buf.extend_from_slice(&price_level.to_le_bytes());
+        buf.extend_from_slice(&16_u16.to_le_bytes());
+        buf.extend_from_slice(&u16::try_from(fills.len()).unwrap().to_le_bytes());
+        for (order_id, fill_qty) in fills {
+            buf.extend_from_slice(&order_id.to_le_bytes());
+            buf.extend_from_slice(&fill_qty.to_le_bytes());
+        }
+        buf.extend_from_slice(&5_u16.to_le_bytes());
+        buf.extend_from_slice(b"hello");
+        buf
+    }
+
+    fn map_trade(message: &SbeMessage) -> anyhow::Result<TradeTick> {
+        let ts_event = UnixNanos::from(message.get_u64("transactTime")?);
+        let aggressor_side = match message.get_char("side")? {
+            'B' => AggressorSide::Buyer,
+            'S' => AggressorSide::Seller,
+            _ => AggressorSide::NoAggressor,
+        };
+        Ok(TradeTick::new(
+            InstrumentId::from(format!("{}.GLBX", message.get_str("symbol")?).as_str()),
+            message.get_price("price", 2)?,
+            message.get_quantity("size", 0)?,
+            aggressor_side,
+            TradeId::new(&message.get_u64("tradeId")?.to_string())?,
+            ts_event,
+            ts_event,
+        ))
+    }
+
+    #[fixture]
+    fn decoder() -> SbeDecoder<TradeTick> {
+        let schema = SbeSchema::from_xml(SCHEMA_XML).unwrap();
+        let mut decoder = SbeDecoder::new(schema).unwrap();
+        decoder.register(TRADE_TEMPLATE_ID, map_trade).unwrap();
+        decoder
+    }
+
+    #[rstest]
+    fn test_decode_to_trade_tick(decoder: SbeDecoder<TradeTick>) {
+        let trade = decoder.decode(&trade_message(1_000, 1, &[])).unwrap();
+
+        assert_eq!(trade.instrument_id, InstrumentId::from("ESZ4.GLBX"));
+        assert_eq!(trade.price, Price::from("5981.25"));
+        assert_eq!(trade.size, Quantity::from(15));
+        assert_eq!(trade.aggressor_side, AggressorSide::Buyer);
+        assert_eq!(trade.trade_id.to_string(), "123456");
+        assert_eq!(trade.ts_event, UnixNanos::from(1_000));
+    }
+
+    #[rstest]
+    fn test_decode_optional_null_field(decoder: SbeDecoder<TradeTick>) {
+        let message = decoder
+            .decode_message(&trade_message(u64::MAX, 1, &[]))
+            .unwrap();
+
+        assert!(message.is_null("transactTime"));
+        assert!(decoder.decode(&trade_message(u64::MAX, 1, &[])).is_err());
+    }
+
+    #[rstest]
+    #[case(u64::from(u8::MAX))]
+    #[case(u64::from(u16::MAX))]
+    #[case(u64::from(u32::MAX))]
+    fn test_decode_optional_field_with_null_value_of_other_width(
+        decoder: SbeDecoder<TradeTick>,
+        #[case] transact_time: u64,
+    ) {
+        let message = decoder
+            .decode_message(&trade_message(transact_time, 1, &[]))
+            .unwrap();
+
+        assert_eq!(message.get_u64("transactTime").unwrap(), transact_time);
+    }
+
+    #[rstest]
+    #[case(i16::from(i8::MIN), Some(-128))]
+    #[case(i16::MIN, Some(i64::from(i16::MIN)))]
+    #[case(i16::MAX, None)]
+    fn test_decode_optional_field_with_schema_null_value(
+        decoder: SbeDecoder<TradeTick>,
+        #[case] price_level: i16,
+        #[case] expected: Option<i64>,
+    ) {
+        let message = decoder
+            .decode_message(&trade_message(1_000, price_level, &[]))
+            .unwrap();
+
+        assert_eq!(message.get_i64("priceLevel").ok(), expected);
+    }
+
+    #[rstest]
+    fn test_decode_repeating_group_and_var_data(decoder: SbeDecoder<TradeTick>) {
+        let message = decoder
+            .decode_message(&trade_message(1_000, 1, &[(1, 10), (2, 5)]))
+            .unwrap();
+
+        let fills = message.get_group("fills").unwrap();
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[0].get_u64("orderId").unwrap(), 1);
+        assert_eq!(fills[0].get_u64("fillQty").unwrap(), 10);
+        assert_eq!(fills[1].get_u64("orderId").unwrap(), 2);
+        assert_eq!(fills[1].get_u64("fillQty").unwrap(), 5);
+        assert_eq!(message.get_str("text").unwrap(), "hello");
+    }
+
+    #[rstest]
+    fn test_decode_truncated_message(decoder: SbeDecoder<TradeTick>) {
+        let buf = trade_message(1_000, 1, &[(1, 10)]);
+
+        assert!(decoder.decode_message(&buf[..buf.len() - 1]).is_err());
+    }
+
+    #[rstest]
+    fn test_decode_unknown_template(decoder: SbeDecoder<TradeTick>) {
+        let mut buf = trade_message(1_000, 1, &[]);
+        buf[2..4].copy_from_slice(&99_u16.to_le_bytes());
+
+        assert!(decoder.decode_message(&buf).is_err());
+    }
+
+    #[rstest]
+    fn test_schema_field_beyond_block_length() {
+        let xml = SCHEMA_XML.replace("blockLength=\"44\"", "blockLength=\"40\"");
+
+        assert!(SbeSchema::from_xml(&xml).is_err());
+    }
+
+    #[rstest]
+    fn test_schema_undefined_type() {
+        let xml = SCHEMA_XML.replace("type=\"Symbol\"", "type=\"Ticker\"");
+
+        assert!(SbeSchema::from_xml(&xml).is_err());
+    }
+}
@@ -0,0 +1,566 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+//! SBE message schema definitions, loadable from the XML message schema published by a venue.
+
+use std::collections::{HashMap, HashSet};
+
+use roxmltree::{Document, Node};
+
+/// The byte order of the encoded fields for a schema.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SbeByteOrder {
+    #[default]
+    LittleEndian,
+    BigEndian,
+}
+
+/// The encoding type of a field.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SbeType {
+    Char,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    Uint8,
+    Uint16,
+    Uint32,
+    Uint64,
+    /// A fixed-length ASCII character array.
+    String {
+        length: usize,
+    },
+    /// An `int64` mantissa with a constant exponent defined by the schema.
+    Decimal {
+        exponent: i8,
+    },
+    /// An `int64` mantissa followed by an `int8` exponent (the standard `decimal` composite).
+    DecimalComposite,
+}
+
+impl SbeType {
+    /// Returns the encoded length of the type in bytes.
+    #[must_use]
+    pub fn encoded_length(&self) -> usize {
+        match self {
+            Self::Char | Self::Int8 | Self::Uint8 => 1,
+            Self::Int16 | Self::Uint16 => 2,
+            Self::Int32 | Self::Uint32 => 4,
+            Self::Int64 | Self::Uint64 | Self::Decimal { .. } => 8,
+            Self::String { length } => *length,
+            Self::DecimalComposite => 9,
+        }
+    }
+
+    /// Returns the default SBE null value for the type (for decimals, of the mantissa).
+    #[must_use]
+    pub fn null_value(&self) -> i128 {
+        match self {
+            Self::Char | Self::String { .. } => 0,
+            Self::Int8 => i128::from(i8::MIN),
+            Self::Int16 => i128::from(i16::MIN),
+            Self::Int32 => i128::from(i32::MIN),
+            Self::Int64 | Self::Decimal { .. } | Self::DecimalComposite => i128::from(i64::MIN),
+            Self::Uint8 => i128::from(u8::MAX),
+            Self::Uint16 => i128::from(u16::MAX),
+            Self::Uint32 => i128::from(u32::MAX),
+            Self::Uint64 => i128::from(u64::MAX),
+        }
+    }
+
+    fn from_primitive(primitive: &str) -> Option<Self> {
+        let sbe_type = match primitive {
+            "char" => Self::Char,
+            "int8" => Self::Int8,
+            "int16" => Self::Int16,
+            "int32" => Self::Int32,
+            "int64" => Self::Int64,
+            "uint8" => Self::Uint8,
+            "uint16" => Self::Uint16,
+            "uint32" => Self::Uint32,
+            "uint64" => Self::Uint64,
+            _ => return None,
+        };
+        Some(sbe_type)
+    }
+
+    fn is_unsigned(&self) -> bool {
+        matches!(
+            self,
+            Self::Uint8 | Self::Uint16 | Self::Uint32 | Self::Uint64
+        )
+    }
+}
+
+/// Represents a field within the block of a message or repeating group.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SbeField {
+    /// The name of the field.
+    pub name: String,
+    /// The byte offset of the field from the start of the block.
+    pub offset: usize,
+    /// The encoding type of the field.
+    pub sbe_type: SbeType,
+    /// If the field is optional, in which case its null value decodes as null.
+    pub optional: bool,
+    /// The null value declared by the schema, overriding the default for the type.
+    pub null_value: Option<i128>,
+}
+
+impl SbeField {
+    /// Returns the null value of the field.
+    #[must_use]
+    pub fn null_value(&self) -> i128 {
+        self.null_value
+            .unwrap_or_else(|| self.sbe_type.null_value())
+    }
+}
+
+/// Represents the dimension header of a repeating group (e.g. `groupSizeEncoding`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SbeGroupDimension {
+    /// The encoding type of the block length of each entry.
+    pub block_length: SbeType,
+    /// The encoding type of the number of entries.
+    pub num_in_group: SbeType,
+}
+
+impl SbeGroupDimension {
+    /// Returns the encoded length of the dimension header in bytes.
+    #[must_use]
+    pub fn encoded_length(&self) -> usize {
+        self.block_length.encoded_length() + self.num_in_group.encoded_length()
+    }
+}
+
+/// Represents a variable length data field (e.g. `varStringEncoding`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SbeVarData {
+    /// The name of the field.
+    pub name: String,
+    /// The encoding type of the length prefix.
+    pub length: SbeType,
+    /// If the data has a character encoding, in which case it decodes as a string.
+    pub is_string: bool,
+}
+
+/// Represents a repeating group within a message (or within another group).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SbeGroup {
+    /// The name of the group.
+    pub name: String,
+    /// The dimension header of the group.
+    pub dimension: SbeGroupDimension,
+    /// The length of the block of each entry in bytes.
+    pub block_length: u16,
+    /// The fields of each entry.
+    pub fields: Vec<SbeField>,
+    /// The nested repeating groups of each entry.
+    pub groups: Vec<SbeGroup>,
+    /// The variable length data of each entry.
+    pub data: Vec<SbeVarData>,
+}
+
+/// Represents the definition of a message within a schema.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SbeMessageSchema {
+    /// The template ID for the message.
+    pub id: u16,
+    /// The name of the message.
+    pub name: String,
+    /// The length of the root block in bytes.
+    pub block_length: u16,
+    /// The fields of the root block.
+    pub fields: Vec<SbeField>,
+    /// The repeating groups, in encoded order.
+    pub groups: Vec<SbeGroup>,
+    /// The variable length data, in encoded order.
+    pub data: Vec<SbeVarData>,
+}
+
+/// Represents an SBE message schema, as published by a venue.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SbeSchema {
+    /// The schema ID.
+    pub id: u16,
+    /// The schema version.
+    pub version: u16,
+    /// The byte order of the encoded messages.
+    pub byte_order: SbeByteOrder,
+    /// The message definitions.
+    pub messages: Vec<SbeMessageSchema>,
+}
+
+impl SbeSchema {
+    /// Returns a schema parsed from the given SBE XML message schema `xml`.
+    ///
+    /// Fields are resolved through the `<types>` of the schema, where primitive types, `char`
+    /// arrays, enums, sets and `mantissa`/`exponent` decimal composites are supported. Constant
+    /// fields are not encoded, so are not included.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the XML is invalid, a type is unsupported, or the
+    /// schema fails validation.
+    pub fn from_xml(xml: &str) -> anyhow::Result<Self> {
+        let doc = Document::parse(xml)?;
+        let root = doc.root_element();
+        if root.tag_name().name() != "messageSchema" {
+            anyhow::bail!(
+                "Invalid root element '{}', expected 'messageSchema'",
+                root.tag_name().name()
+            );
+        }
+
+        let mut types = HashMap::new();
+        for types_node in elements(root).filter(|n| n.tag_name().name() == "types") {
+            for node in elements(types_node) {
+                types.insert(required_attr(node, "name")?, node);
+            }
+        }
+        let parser = SchemaParser { types };
+
+        let byte_order = match root.attribute("byteOrder") {
+            None | Some("littleEndian") => SbeByteOrder::LittleEndian,
+            Some("bigEndian") => SbeByteOrder::BigEndian,
+            Some(other) => anyhow::bail!("Invalid byte order '{other}'"),
+        };
+
+        let mut messages = Vec::new();
+        for node in elements(root).filter(|n| n.tag_name().name() == "message") {
+            let (fields, groups, data, block_length) = parser.parse_members(node)?;
+            messages.push(SbeMessageSchema {
+                id: parse_attr(node, "id")?,
+                name: required_attr(node, "name")?.to_string(),
+                block_length,
+                fields,
+                groups,
+                data,
+            });
+        }
+
+        let schema = Self {
+            id: parse_attr(root, "id")?,
+            version: root.attribute("version").map_or(Ok(0), str::parse)?,
+            byte_order,
+            messages,
+        };
+        schema.validate()?;
+        Ok(schema)
+    }
+
+    /// Validates the schema.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - A template ID is defined more than once.
+    /// - A field extends beyond the block length of its message or group.
+    /// - A group dimension or var data length is not an unsigned integer.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let mut template_ids = HashSet::new();
+        for message in &self.messages {
+            if !template_ids.insert(message.id) {
+                anyhow::bail!(
+                    "Duplicate template ID {} for '{}'",
+                    message.id,
+                    message.name
+                );
+            }
+            validate_block(
+                &message.name,
+                message.block_length,
+                &message.fields,
+                &message.groups,
+                &message.data,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Returns the message definition for the given `template_id` (if found).
+    #[must_use]
+    pub fn message(&self, template_id: u16) -> Option<&SbeMessageSchema> {
+        self.messages.iter().find(|m| m.id == template_id)
+    }
+}
+
+fn validate_block(
+    name: &str,
+    block_length: u16,
+    fields: &[SbeField],
+    groups: &[SbeGroup],
+    data: &[SbeVarData],
+) -> anyhow::Result<()> {
+    for field in fields {
+        let end = field.offset + field.sbe_type.encoded_length();
+        if end > usize::from(block_length) {
+            anyhow::bail!(
+                "Field '{}' of '{name}' ends at {end}, beyond block length {block_length}",
+                field.name,
+            );
+        }
+    }
+    for group in groups {
+        if !group.dimension.block_length.is_unsigned()
+            || !group.dimension.num_in_group.is_unsigned()
+        {
+            anyhow::bail!("Dimension of group '{}' must be unsigned", group.name);
+        }
+        validate_block(
+            &group.name,
+            group.block_length,
+            &group.fields,
+            &group.groups,
+            &group.data,
+        )?;
+    }
+    for var_data in data {
+        if !var_data.length.is_unsigned() {
+            anyhow::bail!("Length of var data '{}' must be unsigned", var_data.name);
+        }
+    }
+    Ok(())
+}
+
+/// The encoding of a type resolved from the schema.
+struct ResolvedType {
+    sbe_type: SbeType,
+    optional: bool,
+    constant: bool,
+    null_value: Option<i128>,
+}
+
+type Members = (Vec<SbeField>, Vec<SbeGroup>, Vec<SbeVarData>, u16);
+
+struct SchemaParser<'a, 'input> {
+    types: HashMap<&'a str, Node<'a, 'input>>,
+}
+
+impl<'a, 'input> SchemaParser<'a, 'input> {
+    /// Returns the fields, groups and var data of a message or group, and its block length.
+    ///
+    /// Fields without an explicit offset follow on from the previous field, and the block
+    /// length defaults to the end of the last field.
+    fn parse_members(&self, node: Node) -> anyhow::Result<Members> {
+        let mut fields = Vec::new();
+        let mut groups = Vec::new();
+        let mut data = Vec::new();
+        let mut offset = 0;
+
+        for child in elements(node) {
+            let name = required_attr(child, "name")?;
+            match child.tag_name().name() {
+                "field" => {
+                    let resolved = self.resolve(required_attr(child, "type")?)?;
+                    let presence = child.attribute("presence");
+                    if resolved.constant || presence == Some("constant") {
+                        continue;
+                    }
+                    if let Some(value) = child.attribute("offset") {
+                        offset = value.parse()?;
+                    }
+                    fields.push(SbeField {
+                        name: name.to_string(),
+                        offset,
+                        sbe_type: resolved.sbe_type,
+                        optional: resolved.optional || presence == Some("optional"),
+                        null_value: resolved.null_value,
+                    });
+                    offset += resolved.sbe_type.encoded_length();
+                }
+                "group" => {
+                    let dimension = self.resolve_dimension(
+                        child
+                            .attribute("dimensionType")
+                            .unwrap_or("groupSizeEncoding"),
+                    )?;
+                    let (fields, nested, var_data, block_length) = self.parse_members(child)?;
+                    groups.push(SbeGroup {
+                        name: name.to_string(),
+                        dimension,
+                        block_length,
+                        fields,
+                        groups: nested,
+                        data: var_data,
+                    });
+                }
+                "data" => data.push(self.resolve_var_data(name, required_attr(child, "type")?)?),
+                _ => {}
+            }
+        }
+
+        let block_length = match node.attribute("blockLength") {
+            Some(value) => value.parse()?,
+            None => u16::try_from(offset)?,
+        };
+        Ok((fields, groups, data, block_length))
+    }
+
+    fn resolve(&self, type_name: &str) -> anyhow::Result<ResolvedType> {
+        if let Some(sbe_type) = SbeType::from_primitive(type_name) {
+            return Ok(ResolvedType {
+                sbe_type,
+                optional: false,
+                constant: false,
+                null_value: None,
+            });
+        }
+
+        let node = self
+            .types
+            .get(type_name)
+            .ok_or_else(|| anyhow::anyhow!("Type '{type_name}' not defined in schema"))?;
+        match node.tag_name().name() {
+            "type" => parse_type(*node),
+            "enum" | "set" => {
+                let encoding_type = required_attr(*node, "encodingType")?;
+                self.resolve(encoding_type)
+            }
+            "composite" => self.resolve_decimal(type_name, *node),
+            other => anyhow::bail!("Unsupported type '{other}' for '{type_name}'"),
+        }
+    }
+
+    fn resolve_decimal(&self, type_name: &str, node: Node) -> anyhow::Result<ResolvedType> {
+        let member = |name: &str| {
+            elements(node)
+                .find(|n| n.attribute("name") == Some(name))
+                .ok_or_else(|| anyhow::anyhow!("Unsupported composite '{type_name}'"))
+        };
+        let mantissa = self.resolve_member(member("mantissa")?)?;
+        let exponent_node = member("exponent")?;
+        let exponent = self.resolve_member(exponent_node)?;
+        if mantissa.sbe_type != SbeType::Int64 || exponent.sbe_type != SbeType::Int8 {
+            anyhow::bail!(
+                "Decimal composite '{type_name}' must be an int64 mantissa and int8 exponent"
+            );
+        }
+
+        let sbe_type = if exponent.constant {
+            let value = exponent_node.text().unwrap_or_default().trim();
+            SbeType::Decimal {
+                exponent: value.parse()?,
+            }
+        } else {
+            SbeType::DecimalComposite
+        };
+        Ok(ResolvedType {
+            sbe_type,
+            optional: mantissa.optional,
+            constant: false,
+            null_value: mantissa.null_value,
+        })
+    }
+
+    fn resolve_member(&self, node: Node) -> anyhow::Result<ResolvedType> {
+        match node.tag_name().name() {
+            "type" => parse_type(node),
+            "ref" => self.resolve(required_attr(node, "type")?),
+            other => anyhow::bail!("Unsupported composite member '{other}'"),
+        }
+    }
+
+    fn resolve_dimension(&self, type_name: &str) -> anyhow::Result<SbeGroupDimension> {
+        let node = self
+            .types
+            .get(type_name)
+            .ok_or_else(|| anyhow::anyhow!("Dimension type '{type_name}' not defined in schema"))?;
+        let member = |name: &str| -> anyhow::Result<SbeType> {
+            let child = elements(*node)
+                .find(|n| n.attribute("name") == Some(name))
+                .ok_or_else(|| anyhow::anyhow!("No '{name}' for dimension type '{type_name}'"))?;
+            Ok(self.resolve_member(child)?.sbe_type)
+        };
+        Ok(SbeGroupDimension {
+            block_length: member("blockLength")?,
+            num_in_group: member("numInGroup")?,
+        })
+    }
+
+    fn resolve_var_data(&self, name: &str, type_name: &str) -> anyhow::Result<SbeVarData> {
+        let node = self
+            .types
+            .get(type_name)
+            .ok_or_else(|| anyhow::anyhow!("Data type '{type_name}' not defined in schema"))?;
+        let member = |member_name: &str| {
+            elements(*node)
+                .find(|n| n.attribute("name") == Some(member_name))
+                .ok_or_else(|| anyhow::anyhow!("No '{member_name}' for data type '{type_name}'"))
+        };
+        Ok(SbeVarData {
+            name: name.to_string(),
+            length: self.resolve_member(member("length")?)?.sbe_type,
+            is_string: member("varData")?.attribute("characterEncoding").is_some(),
+        })
+    }
+}
+
+fn parse_type(node: Node) -> anyhow::Result<ResolvedType> {
+    let primitive = required_attr(node, "primitiveType")?;
+    let sbe_type = SbeType::from_primitive(primitive)
+        .ok_or_else(|| anyhow::anyhow!("Unsupported primitive type '{primitive}'"))?;
+    let length: usize = node.attribute("length").map_or(Ok(1), str::parse)?;
+    let sbe_type = match (sbe_type, length) {
+        (_, 1) => sbe_type,
+        (SbeType::Char, length) => SbeType::String { length },
+        _ => anyhow::bail!(
+            "Unsupported array of '{primitive}' for '{:?}'",
+            node.attribute("name")
+        ),
+    };
+    let null_value = match node.attribute("nullValue") {
+        Some(value) => Some(parse_null_value(value)?),
+        None => None,
+    };
+    Ok(ResolvedType {
+        sbe_type,
+        optional: node.attribute("presence") == Some("optional"),
+        constant: node.attribute("presence") == Some("constant"),
+        null_value,
+    })
+}
+
+fn parse_null_value(value: &str) -> anyhow::Result<i128> {
+    if let Ok(value) = value.parse() {
+        return Ok(value);
+    }
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(i128::from(u32::from(c))),
+        _ => anyhow::bail!("Invalid null value '{value}'"),
+    }
+}
+
+fn elements<'a, 'input>(node: Node<'a, 'input>) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children().filter(Node::is_element)
+}
+
+fn required_attr<'a>(node: Node<'a, '_>, name: &str) -> anyhow::Result<&'a str> {
+    node.attribute(name).ok_or_else(|| {
+        anyhow::anyhow!(
+            "No '{name}' attribute for '{}' element",
+            node.tag_name().name()
+        )
+    })
+}
+
+fn parse_attr<T>(node: Node, name: &str) -> anyhow::Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    Ok(required_attr(node, name)?.parse()?)
+}
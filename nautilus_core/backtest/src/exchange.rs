@@ -0,0 +1,380 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `SimulatedExchange` which routes trading commands to its order matching engines, after
+//! a simulated latency.
+
+use std::{
+    cell::RefCell,
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap},
+    rc::Rc,
+};
+
+use log::{debug, error};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+use nautilus_execution::messages::TradingCommand;
+use nautilus_model::{
+    enums::OrderStatus,
+    events::order::{submitted::OrderSubmitted, OrderEventAny},
+    identifiers::{account_id::AccountId, instrument_id::InstrumentId, venue::Venue},
+    orders::any::OrderAny,
+};
+
+use crate::{
+    matching_engine::OrderMatchingEngine,
+    models::{FeeModel, LatencyModel},
+};
+
+/// A trading command in flight to the exchange, ordered by arrival time (then by the sequence
+/// it was sent in).
+#[derive(Debug)]
+struct InflightCommand {
+    ts_arrival: UnixNanos,
+    sequence: u64,
+    command: TradingCommand,
+}
+
+impl PartialEq for InflightCommand {
+    fn eq(&self, other: &Self) -> bool {
+        self.ts_arrival == other.ts_arrival && self.sequence == other.sequence
+    }
+}
+
+impl Eq for InflightCommand {}
+
+impl PartialOrd for InflightCommand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for InflightCommand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.ts_arrival, self.sequence).cmp(&(other.ts_arrival, other.sequence))
+    }
+}
+
+/// Provides a simulated exchange for a venue, with an order matching engine per instrument.
+///
+/// Commands sent to the exchange are held in an inbox until their arrival time, as given by
+/// the optional [`LatencyModel`], and are then processed in arrival order. Commissions for all
+/// instruments on the venue are calculated by the venues [`FeeModel`].
+pub struct SimulatedExchange {
+    /// The venue for the exchange.
+    pub venue: Venue,
+    /// The account ID for orders processed by the exchange.
+    pub account_id: AccountId,
+    clock: &'static AtomicTime,
+    latency_model: Option<LatencyModel>,
+    fee_model: Rc<RefCell<dyn FeeModel>>,
+    matching_engines: HashMap<InstrumentId, OrderMatchingEngine>,
+    inbox: BinaryHeap<Reverse<InflightCommand>>,
+    sequence: u64,
+}
+
+impl SimulatedExchange {
+    /// Creates a new [`SimulatedExchange`] instance.
+    pub fn new(
+        venue: Venue,
+        clock: &'static AtomicTime,
+        latency_model: Option<LatencyModel>,
+        fee_model: Rc<RefCell<dyn FeeModel>>,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            venue,
+            account_id: AccountId::new(&format!("{venue}-001"))?,
+            clock,
+            latency_model,
+            fee_model,
+            matching_engines: HashMap::new(),
+            inbox: BinaryHeap::new(),
+            sequence: 0,
+        })
+    }
+
+    /// Adds the given matching `engine` for its instrument, using the fee model of the venue.
+    pub fn add_matching_engine(&mut self, mut engine: OrderMatchingEngine) {
+        engine.set_fee_model(Rc::clone(&self.fee_model));
+        self.matching_engines.insert(engine.instrument.id(), engine);
+    }
+
+    #[must_use]
+    pub fn get_matching_engine(
+        &self,
+        instrument_id: &InstrumentId,
+    ) -> Option<&OrderMatchingEngine> {
+        self.matching_engines.get(instrument_id)
+    }
+
+    pub fn get_matching_engine_mut(
+        &mut self,
+        instrument_id: &InstrumentId,
+    ) -> Option<&mut OrderMatchingEngine> {
+        self.matching_engines.get_mut(instrument_id)
+    }
+
+    /// Returns the count of commands in flight to the exchange.
+    #[must_use]
+    pub fn inflight_count(&self) -> usize {
+        self.inbox.len()
+    }
+
+    /// Returns the arrival time of the next command in flight (if any).
+    #[must_use]
+    pub fn next_arrival_ns(&self) -> Option<UnixNanos> {
+        self.inbox
+            .peek()
+            .map(|Reverse(inflight)| inflight.ts_arrival)
+    }
+
+    /// Sends the given `command` to the exchange at `ts_now`, arriving after the latency
+    /// given by the latency model (or immediately if there is no model).
+    pub fn send(&mut self, command: TradingCommand, ts_now: UnixNanos) {
+        let latency_nanos = self
+            .latency_model
+            .as_mut()
+            .map_or(0, |model| model.latency_nanos(&command));
+
+        self.sequence += 1;
+        self.inbox.push(Reverse(InflightCommand {
+            ts_arrival: ts_now + latency_nanos,
+            sequence: self.sequence,
+            command,
+        }));
+    }
+
+    /// Processes the commands which have arrived at the exchange by `ts_now` (in arrival
+    /// order), returning the order events generated by the matching engines.
+    pub fn process(&mut self, ts_now: UnixNanos) -> Vec<OrderEventAny> {
+        while self
+            .inbox
+            .peek()
+            .map_or(false, |Reverse(inflight)| inflight.ts_arrival <= ts_now)
+        {
+            // SAFETY: Inbox was checked to be non-empty
+            let Reverse(inflight) = self.inbox.pop().unwrap();
+            self.clock.set_time(inflight.ts_arrival);
+
+            if let Err(e) = self.process_command(inflight.command) {
+                error!("Error processing command: {e}");
+            }
+        }
+
+        self.matching_engines
+            .values_mut()
+            .flat_map(OrderMatchingEngine::drain_events)
+            .collect()
+    }
+
+    fn process_command(&mut self, command: TradingCommand) -> anyhow::Result<()> {
+        let instrument_id = command.instrument_id();
+        let account_id = self.account_id;
+        let ts_now = self.clock.get_time_ns();
+        let engine = self
+            .matching_engines
+            .get_mut(&instrument_id)
+            .ok_or_else(|| anyhow::anyhow!("No matching engine for {instrument_id}"))?;
+
+        match command {
+            TradingCommand::SubmitOrder(command) => {
+                let order = submitted_order(command.order, account_id, ts_now)?;
+                engine.process_order(&order, account_id)
+            }
+            TradingCommand::SubmitOrderList(command) => {
+                for order in command.order_list.orders {
+                    let order = submitted_order(order, account_id, ts_now)?;
+                    engine.process_order(&order, account_id)?;
+                }
+                Ok(())
+            }
+            TradingCommand::ModifyOrder(command) => engine.process_modify(&command, account_id),
+            TradingCommand::CancelOrder(command) => engine.process_cancel(&command, account_id),
+            TradingCommand::CancelAllOrders(command) => engine.process_cancel_all(&command),
+            TradingCommand::BatchCancelOrders(command) => {
+                for cancel in &command.cancels {
+                    engine.process_cancel(cancel, account_id)?;
+                }
+                Ok(())
+            }
+            TradingCommand::QueryOrder(command) => {
+                debug!("Ignoring query for {}", command.client_order_id);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Returns the given `order` in the submitted state, as the `OrderSubmitted` event is
+/// generated by the execution client rather than the exchange.
+fn submitted_order(
+    mut order: OrderAny,
+    account_id: AccountId,
+    ts_now: UnixNanos,
+) -> anyhow::Result<OrderAny> {
+    if order.status() == OrderStatus::Initialized {
+        let event = OrderSubmitted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            account_id,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        )?;
+        order.apply(OrderEventAny::Submitted(event))?;
+    }
+    Ok(order)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::{cache::Cache, msgbus::MessageBus};
+    use nautilus_execution::messages::{cancel::CancelOrder, submit::SubmitOrder};
+    use nautilus_model::{
+        enums::{AccountType, BookType, OmsType, OrderSide},
+        identifiers::{
+            client_id::ClientId, client_order_id::ClientOrderId, strategy_id::StrategyId,
+            trader_id::TraderId, venue_order_id::VenueOrderId,
+        },
+        instruments::stubs::equity_aapl,
+        orders::stubs::TestOrderStubs,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        matching_engine::OrderMatchingEngineConfig,
+        models::{FillModel, MakerTakerFeeModel},
+    };
+
+    const INSERT_LATENCY_NS: u64 = 10_000_000;
+
+    fn create_exchange() -> SimulatedExchange {
+        let clock = Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default())));
+        let msgbus =
+            MessageBus::new(TraderId::from("TRADER-001"), UUID4::new(), None, None).unwrap();
+        let engine = OrderMatchingEngine::new(
+            Box::new(equity_aapl()),
+            1,
+            FillModel::default(),
+            BookType::L1_MBP,
+            OmsType::Netting,
+            AccountType::Cash,
+            clock,
+            Box::leak(Box::new(msgbus)),
+            Box::leak(Box::new(Cache::default())),
+            OrderMatchingEngineConfig::default(),
+        );
+        let latency_model = LatencyModel::new(0, INSERT_LATENCY_NS, 0, 0, 0, None);
+        let mut exchange = SimulatedExchange::new(
+            Venue::from("XNAS"),
+            clock,
+            Some(latency_model),
+            Rc::new(RefCell::new(MakerTakerFeeModel)),
+        )
+        .unwrap();
+        exchange.add_matching_engine(engine);
+        exchange
+    }
+
+    fn submit_command(client_order_id: ClientOrderId) -> TradingCommand {
+        let order = TestOrderStubs::limit_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from(100),
+            Some(client_order_id),
+            None,
+        );
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::default(),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        TradingCommand::SubmitOrder(command)
+    }
+
+    fn cancel_command(client_order_id: ClientOrderId) -> TradingCommand {
+        let command = CancelOrder::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            StrategyId::default(),
+            InstrumentId::from("AAPL.XNAS"),
+            client_order_id,
+            VenueOrderId::default(),
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        TradingCommand::CancelOrder(command)
+    }
+
+    #[rstest]
+    fn test_commands_processed_in_arrival_order() {
+        let mut exchange = create_exchange();
+        let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+
+        exchange.send(submit_command(client_order_id), UnixNanos::default());
+        exchange.send(cancel_command(client_order_id), UnixNanos::default());
+
+        assert_eq!(exchange.inflight_count(), 2);
+        assert_eq!(exchange.next_arrival_ns(), Some(UnixNanos::default()));
+
+        // Cancel arrives before the order it targets
+        let events = exchange.process(UnixNanos::default());
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEventAny::CancelRejected(_)));
+        assert_eq!(exchange.inflight_count(), 1);
+        assert_eq!(
+            exchange.next_arrival_ns(),
+            Some(UnixNanos::from(INSERT_LATENCY_NS))
+        );
+
+        let events = exchange.process(UnixNanos::from(INSERT_LATENCY_NS));
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEventAny::Accepted(_)));
+        assert_eq!(exchange.inflight_count(), 0);
+        assert_eq!(exchange.next_arrival_ns(), None);
+    }
+
+    #[rstest]
+    fn test_command_not_processed_before_arrival() {
+        let mut exchange = create_exchange();
+        let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-2");
+
+        exchange.send(submit_command(client_order_id), UnixNanos::default());
+        let events = exchange.process(UnixNanos::from(INSERT_LATENCY_NS - 1));
+
+        assert!(events.is_empty());
+        assert_eq!(exchange.inflight_count(), 1);
+    }
+}
@@ -28,4 +28,8 @@
 //! - `python`: Enables Python bindings from `pyo3`
 
 pub mod engine;
+pub mod exchange;
 pub mod matching_engine;
+pub mod models;
+pub mod progress;
+pub mod results;
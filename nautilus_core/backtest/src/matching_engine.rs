@@ -14,36 +14,64 @@
 // -------------------------------------------------------------------------------------------------
 
 //! An `OrderMatchingEngine` for use in research, backtesting and sandbox environments.
+//!
+//! Working stop, if-touched and trailing stop orders are triggered against the BID/ASK/LAST
+//! prices for their trigger type, with trailing stop prices recalculated on each iteration.
 
 // Under development
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use std::collections::HashMap;
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use log::{debug, info};
-use nautilus_common::{cache::Cache, msgbus::MessageBus};
-use nautilus_core::{nanos::UnixNanos, time::AtomicTime};
-use nautilus_execution::matching_core::OrderMatchingCore;
+use log::{debug, error, info};
+use nautilus_common::{cache::Cache, msgbus::MessageBus, timer::TestTimer};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+use nautilus_execution::{
+    matching_core::OrderMatchingCore,
+    messages::{cancel::CancelOrder, cancel_all::CancelAllOrders, modify::ModifyOrder},
+    trailing::trailing_stop_calculate,
+};
 use nautilus_model::{
     data::{
         bar::{Bar, BarType},
         delta::OrderBookDelta,
+        order::BookOrder,
+        quote::QuoteTick,
+        trade::TradeTick,
+    },
+    enums::{
+        AccountType, BookType, LiquiditySide, MarketStatus, OmsType, OrderSide, OrderSideSpecified,
+        OrderType, TimeInForce,
+    },
+    events::order::{
+        accepted::OrderAccepted, cancel_rejected::OrderCancelRejected, canceled::OrderCanceled,
+        expired::OrderExpired, filled::OrderFilled, modify_rejected::OrderModifyRejected,
+        rejected::OrderRejected, triggered::OrderTriggered, updated::OrderUpdated, OrderEventAny,
     },
-    enums::{AccountType, BookType, MarketStatus, OmsType},
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
-        trader_id::TraderId, venue::Venue,
+        trade_id::TradeId, trader_id::TraderId, venue::Venue, venue_order_id::VenueOrderId,
     },
     instruments::Instrument,
     orderbook::book::OrderBook,
-    orders::{
-        any::{PassiveOrderAny, StopOrderAny},
-        trailing_stop_limit::TrailingStopLimitOrder,
-        trailing_stop_market::TrailingStopMarketOrder,
-    },
-    types::price::Price,
+    orders::any::{LimitOrderAny, OrderAny, PassiveOrderAny, StopOrderAny},
+    types::{price::Price, quantity::Quantity},
 };
+use ustr::Ustr;
+
+use crate::models::{FeeModel, FillModel, MakerTakerFeeModel};
+
+/// The mode for computing fills of orders taking liquidity.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FillMode {
+    /// Fills at the top-of-book price, for the top-of-book size.
+    #[default]
+    TopOfBook,
+    /// Fills by walking the levels of the order book, simulating market impact (falls back
+    /// to top-of-book when the book has no liquidity).
+    BookDepth,
+}
 
 pub struct OrderMatchingEngineConfig {
     pub bar_execution: bool,
@@ -53,6 +81,26 @@ pub struct OrderMatchingEngineConfig {
     pub use_position_ids: bool,
     pub use_random_ids: bool,
     pub use_reduce_only: bool,
+    pub fill_mode: FillMode,
+    /// If passive orders joining a book level wait for the size ahead of them in the queue to
+    /// trade before filling at that level.
+    pub queue_position: bool,
+}
+
+impl Default for OrderMatchingEngineConfig {
+    fn default() -> Self {
+        Self {
+            bar_execution: true,
+            reject_stop_orders: true,
+            support_gtd_orders: true,
+            support_contingent_orders: true,
+            use_position_ids: true,
+            use_random_ids: false,
+            use_reduce_only: true,
+            fill_mode: FillMode::TopOfBook,
+            queue_position: false,
+        }
+    }
 }
 
 /// An order matching engine for a single market.
@@ -76,11 +124,15 @@ pub struct OrderMatchingEngine {
     clock: &'static AtomicTime,
     msgbus: &'static MessageBus,
     cache: &'static Cache,
+    fill_model: FillModel,
+    fee_model: Rc<RefCell<dyn FeeModel>>,
     book: OrderBook,
     core: OrderMatchingCore,
     target_bid: Option<Price>,
     target_ask: Option<Price>,
     target_last: Option<Price>,
+    bid_size: Option<Quantity>,
+    ask_size: Option<Quantity>,
     last_bar_bid: Option<Bar>,
     last_bar_ask: Option<Bar>,
     execution_bar_types: HashMap<InstrumentId, BarType>,
@@ -89,15 +141,18 @@ pub struct OrderMatchingEngine {
     position_count: usize,
     order_count: usize,
     execution_count: usize,
+    expire_timers: HashMap<ClientOrderId, TestTimer>,
+    queue_ahead: HashMap<ClientOrderId, Quantity>,
+    events: Vec<OrderEventAny>,
 }
 
-// TODO: we'll probably be changing the `FillModel` (don't add for now)
 impl OrderMatchingEngine {
     /// Creates a new [`OrderMatchingEngine`] instance.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         instrument: Box<dyn Instrument>,
         raw_id: u32,
+        fill_model: FillModel,
         book_type: BookType,
         oms_type: OmsType,
         account_type: AccountType,
@@ -124,6 +179,8 @@ impl OrderMatchingEngine {
             clock,
             msgbus,
             cache,
+            fill_model,
+            fee_model: Rc::new(RefCell::new(MakerTakerFeeModel)),
             book,
             core,
             market_status: MarketStatus::Open,
@@ -131,6 +188,8 @@ impl OrderMatchingEngine {
             target_bid: None,
             target_ask: None,
             target_last: None,
+            bid_size: None,
+            ask_size: None,
             last_bar_bid: None,
             last_bar_ask: None,
             execution_bar_types: HashMap::new(),
@@ -139,6 +198,9 @@ impl OrderMatchingEngine {
             position_count: 0,
             order_count: 0,
             execution_count: 0,
+            expire_timers: HashMap::new(),
+            queue_ahead: HashMap::new(),
+            events: Vec::new(),
         }
     }
 
@@ -151,9 +213,14 @@ impl OrderMatchingEngine {
         self.target_bid = None;
         self.target_ask = None;
         self.target_last = None;
+        self.bid_size = None;
+        self.ask_size = None;
+        self.expire_timers.clear();
+        self.queue_ahead.clear();
         self.position_count = 0;
         self.order_count = 0;
         self.execution_count = 0;
+        self.events.clear();
 
         info!("Reset {}", self.instrument.id());
     }
@@ -188,6 +255,41 @@ impl OrderMatchingEngine {
         self.core.order_exists(client_order_id)
     }
 
+    /// Sets the fee model used to calculate the commission for fills, which defaults to the
+    /// maker and taker fees of the instrument.
+    pub fn set_fee_model(&mut self, fee_model: Rc<RefCell<dyn FeeModel>>) {
+        self.fee_model = fee_model;
+    }
+
+    /// Returns the order events generated since the last call, for publishing by the venue.
+    pub fn drain_events(&mut self) -> Vec<OrderEventAny> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Validates the given order `price` against the price precision of the instrument,
+    /// and its tick scheme (or price increment).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `price` is not a valid price for the instrument.
+    pub fn validate_price(&self, price: Price) -> anyhow::Result<()> {
+        let price_precision = self.instrument.price_precision();
+        if price.precision != price_precision {
+            anyhow::bail!(
+                "Invalid price {price} for {}, precision {} != {price_precision}",
+                self.instrument.id(),
+                price.precision,
+            );
+        }
+        if !self.instrument.is_valid_price(price) {
+            anyhow::bail!(
+                "Invalid price {price} for {}, not a valid tick",
+                self.instrument.id()
+            );
+        }
+        Ok(())
+    }
+
     // -- DATA PROCESSING -----------------------------------------------------
 
     /// Process the venues market for the given order book delta.
@@ -195,17 +297,298 @@ impl OrderMatchingEngine {
         debug!("Processing {delta}");
 
         self.book.apply_delta(delta);
+        self.iterate(delta.ts_init);
+    }
+
+    /// Process the venues market for the given quote tick, matching working orders against
+    /// the updated BID and ASK.
+    pub fn process_quote_tick(&mut self, quote: &QuoteTick) {
+        debug!("Processing {quote}");
+
+        self.core.bid = Some(quote.bid_price);
+        self.core.ask = Some(quote.ask_price);
+        self.bid_size = Some(quote.bid_size);
+        self.ask_size = Some(quote.ask_size);
+        self.iterate_core(quote.ts_init);
+    }
+
+    /// Process the venues market for the given trade tick, matching working orders against
+    /// the updated LAST.
+    pub fn process_trade_tick(&mut self, trade: &TradeTick) {
+        debug!("Processing {trade}");
+
+        self.core.last = Some(trade.price);
+        if self.config.queue_position {
+            self.process_queue_trade(trade);
+        }
+        self.iterate_core(trade.ts_init);
     }
 
     // -- ORDER PROCESSING ----------------------------------------------------
 
+    /// Process the given submitted `order`.
+    ///
+    /// Market orders, and limit orders which are marketable on arrival, are filled immediately
+    /// as takers. All other orders are accepted as working orders on the venue, with `GTD`
+    /// orders registering a timer to expire them. `IOC` and `FOK` orders which are not
+    /// immediately marketable are canceled.
+    ///
+    /// Stop orders with a trigger price already in the market are rejected if the config
+    /// `reject_stop_orders` is set, otherwise they will trigger on the next iteration.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order type is not supported for matching, or an
+    /// order event could not be applied to the order.
+    pub fn process_order(&mut self, order: &OrderAny, account_id: AccountId) -> anyhow::Result<()> {
+        match order.order_type() {
+            OrderType::Market
+            | OrderType::Limit
+            | OrderType::StopMarket
+            | OrderType::StopLimit
+            | OrderType::MarketIfTouched
+            | OrderType::LimitIfTouched
+            | OrderType::TrailingStopMarket
+            | OrderType::TrailingStopLimit => {}
+            order_type => anyhow::bail!("Unsupported order type {order_type} for matching"),
+        }
+
+        self.account_ids.insert(order.trader_id(), account_id);
+        let ts_now = self.clock.get_time_ns();
+        let side = order.order_side_specified();
+
+        for price in [order.price(), order.trigger_price()].into_iter().flatten() {
+            if let Err(e) = self.validate_price(price) {
+                return self.reject_order(order, account_id, &e.to_string(), ts_now);
+            }
+        }
+
+        if order.order_type() == OrderType::Market {
+            let Some(last_px) = self.market_price(side) else {
+                let reason = format!("No market for {}", order.instrument_id());
+                return self.reject_order(order, account_id, &reason, ts_now);
+            };
+            return self.fill_taker_order(order.clone(), last_px, ts_now);
+        }
+
+        if let PassiveOrderAny::Stop(stop) = PassiveOrderAny::from(order.clone()) {
+            let is_touch = matches!(
+                stop,
+                StopOrderAny::LimitIfTouched(_) | StopOrderAny::MarketIfTouched(_)
+            );
+            if self.config.reject_stop_orders && !is_touch && self.core.is_stop_matched(&stop) {
+                let reason = format!(
+                    "{} {} order trigger px of {} was in the market: bid={:?}, ask={:?}",
+                    order.order_side(),
+                    order.order_type(),
+                    stop.stop_px(),
+                    self.core.bid,
+                    self.core.ask,
+                );
+                return self.reject_order(order, account_id, &reason, ts_now);
+            }
+        }
+
+        let event = OrderAccepted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            self.generate_venue_order_id()?,
+            account_id,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+        )?;
+        let mut order = order.clone();
+        self.apply_event(&mut order, OrderEventAny::Accepted(event))?;
+
+        if let Some(price) = order.price() {
+            if order.order_type() == OrderType::Limit
+                && self.core.is_limit_price_matched(side, price)
+            {
+                // Marketable on arrival, so takes liquidity
+                let last_px = self.market_price(side).unwrap_or(price);
+                return self.fill_taker_order(order, last_px, ts_now);
+            }
+        }
+
+        if order.order_type() == OrderType::Limit
+            && matches!(order.time_in_force(), TimeInForce::Ioc | TimeInForce::Fok)
+        {
+            return self.cancel_order(order, ts_now);
+        }
+
+        self.rest_order(order, ts_now)
+    }
+
+    /// Process the given modify order `command`, updating the working orders quantity and
+    /// prices (which take effect from the next iteration).
+    ///
+    /// Modifies for orders which are not working, with invalid prices, or which would reduce
+    /// the quantity below the filled quantity, are rejected.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if an order event could not be applied to the order.
+    pub fn process_modify(
+        &mut self,
+        command: &ModifyOrder,
+        account_id: AccountId,
+    ) -> anyhow::Result<()> {
+        let ts_now = self.clock.get_time_ns();
+        let Some(order) = self.core.get_order(command.client_order_id).cloned() else {
+            let reason = format!("{} not found", command.client_order_id);
+            return self.modify_rejected(command, account_id, &reason, ts_now);
+        };
+
+        for price in [command.price, command.trigger_price].into_iter().flatten() {
+            if let Err(e) = self.validate_price(price) {
+                return self.modify_rejected(command, account_id, &e.to_string(), ts_now);
+            }
+        }
+
+        let mut order = OrderAny::from(order);
+        if let Some(quantity) = command.quantity {
+            if quantity < order.filled_qty() {
+                let reason = format!(
+                    "Modified quantity {quantity} less than filled quantity {}",
+                    order.filled_qty()
+                );
+                return self.modify_rejected(command, account_id, &reason, ts_now);
+            }
+        }
+
+        let event = OrderUpdated::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            command.quantity.unwrap_or(order.quantity()),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            order.venue_order_id(),
+            Some(account_id),
+            command.price,
+            command.trigger_price,
+        )?;
+        self.apply_event(&mut order, OrderEventAny::Updated(event))?;
+
+        let order = PassiveOrderAny::from(order);
+        if self.config.queue_position {
+            // A modified order loses its place in the queue
+            if let PassiveOrderAny::Limit(o) = &order {
+                let size_ahead = self.book_level_size(o.order_side_specified(), o.limit_px());
+                self.queue_ahead.insert(o.client_order_id(), size_ahead);
+            }
+        }
+        self.core.update_order(order)?;
+        Ok(())
+    }
+
+    /// Process the given cancel order `command`, canceling the working order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if an order event could not be applied to the order.
+    pub fn process_cancel(
+        &mut self,
+        command: &CancelOrder,
+        account_id: AccountId,
+    ) -> anyhow::Result<()> {
+        let ts_now = self.clock.get_time_ns();
+        match self.core.get_order(command.client_order_id).cloned() {
+            Some(order) => self.cancel_order(order.into(), ts_now),
+            None => {
+                let reason = format!("{} not found", command.client_order_id);
+                let event = OrderCancelRejected::new(
+                    command.trader_id,
+                    command.strategy_id,
+                    command.instrument_id,
+                    command.client_order_id,
+                    Ustr::from(&reason),
+                    UUID4::new(),
+                    ts_now,
+                    ts_now,
+                    false,
+                    Some(command.venue_order_id),
+                    Some(account_id),
+                )?;
+                self.events.push(OrderEventAny::CancelRejected(event));
+                Ok(())
+            }
+        }
+    }
+
+    /// Process the given cancel all orders `command`, canceling the working orders of the
+    /// strategy (on the commands side, if specified).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if an order event could not be applied to an order.
+    pub fn process_cancel_all(&mut self, command: &CancelAllOrders) -> anyhow::Result<()> {
+        let ts_now = self.clock.get_time_ns();
+        let orders: Vec<OrderAny> = self
+            .core
+            .get_orders_bid()
+            .iter()
+            .chain(self.core.get_orders_ask())
+            .cloned()
+            .map(OrderAny::from)
+            .filter(|order| {
+                order.strategy_id() == command.strategy_id
+                    && (command.order_side == OrderSide::NoOrderSide
+                        || order.order_side() == command.order_side)
+            })
+            .collect();
+
+        for order in orders {
+            self.cancel_order(order, ts_now)?;
+        }
+        Ok(())
+    }
+
+    /// Adds the given `order` to the matching core as a working order, registering its expire
+    /// timer and queue position (when configured).
+    fn rest_order(&mut self, order: OrderAny, ts_now: UnixNanos) -> anyhow::Result<()> {
+        let is_gtd = order.time_in_force() == TimeInForce::Gtd;
+        let order = PassiveOrderAny::from(order);
+        let client_order_id = order.client_order_id();
+
+        if self.config.support_gtd_orders && is_gtd {
+            if let Some(expire_time) = order.expire_time() {
+                self.set_expire_timer(client_order_id, expire_time, ts_now)?;
+            }
+        }
+
+        if self.config.queue_position {
+            if let PassiveOrderAny::Limit(o) = &order {
+                let size_ahead = self.book_level_size(o.order_side_specified(), o.limit_px());
+                self.queue_ahead.insert(client_order_id, size_ahead);
+            }
+        }
+
+        self.core.add_order(order)?;
+        Ok(())
+    }
+
     /// Iterate the matching engine by processing the bid and ask order sides
     /// and advancing time up to the given UNIX `timestamp_ns`.
     pub fn iterate(&mut self, timestamp_ns: UnixNanos) {
-        self.clock.set_time(timestamp_ns);
-
         self.core.bid = self.book.best_bid_price();
         self.core.ask = self.book.best_ask_price();
+        self.bid_size = self.book.best_bid_size();
+        self.ask_size = self.book.best_ask_size();
+
+        self.iterate_core(timestamp_ns);
+    }
+
+    fn iterate_core(&mut self, timestamp_ns: UnixNanos) {
+        self.clock.set_time(timestamp_ns);
+        self.process_expire_timers(timestamp_ns);
 
         let orders_bid = self.core.get_orders_bid().to_vec();
         let orders_ask = self.core.get_orders_ask().to_vec();
@@ -216,34 +599,36 @@ impl OrderMatchingEngine {
 
     fn iterate_orders(&mut self, timestamp_ns: UnixNanos, orders: &[PassiveOrderAny]) {
         for order in orders {
-            if order.is_closed() {
+            // The order may have been closed earlier in this iteration
+            if order.is_closed() || !self.core.order_exists(order.client_order_id()) {
                 continue;
             };
 
-            // Check expiration
-            if self.config.support_gtd_orders {
-                if let Some(expire_time) = order.expire_time() {
-                    if timestamp_ns >= expire_time {
-                        // SAFTEY: We know this order is in the core
-                        self.core.delete_order(order).unwrap();
-                        self.expire_order(order);
-                    }
-                }
+            if let Err(e) = self.match_order(order, timestamp_ns) {
+                error!("Error matching {}: {e}", order.client_order_id());
             }
 
-            // Manage trailing stop
-            if let PassiveOrderAny::Stop(o) = order {
-                match o {
-                    StopOrderAny::TrailingStopMarket(o) => self.update_trailing_stop_market(o),
-                    StopOrderAny::TrailingStopLimit(o) => self.update_trailing_stop_limit(o),
-                    _ => {}
+            // Manage trailing stop (using the latest order state, if still working)
+            if let Some(PassiveOrderAny::Stop(o)) =
+                self.core.get_order(order.client_order_id()).cloned()
+            {
+                if !o.is_triggered() {
+                    if let Err(e) = self.update_trailing_stop(&o, timestamp_ns) {
+                        error!("Error updating trailing stop {}: {e}", o.client_order_id());
+                    }
                 }
             }
 
             // Move market back to targets
-            self.core.bid = self.target_bid;
-            self.core.ask = self.target_ask;
-            self.core.last = self.target_last;
+            if let Some(target_bid) = self.target_bid {
+                self.core.bid = Some(target_bid);
+            }
+            if let Some(target_ask) = self.target_ask {
+                self.core.ask = Some(target_ask);
+            }
+            if let Some(target_last) = self.target_last {
+                self.core.last = Some(target_last);
+            }
         }
 
         // Reset any targets after iteration
@@ -252,15 +637,1223 @@ impl OrderMatchingEngine {
         self.target_last = None;
     }
 
-    fn expire_order(&mut self, order: &PassiveOrderAny) {
-        todo!();
+    fn match_order(&mut self, order: &PassiveOrderAny, ts_now: UnixNanos) -> anyhow::Result<()> {
+        match order {
+            PassiveOrderAny::Limit(o) => {
+                if self.core.is_limit_matched(o)
+                    && !self.is_queued(o)
+                    && self.is_limit_filled(o.order_side_specified(), o.limit_px())
+                {
+                    let mut order_any = OrderAny::from(order.clone());
+                    let last_qty = order_any.leaves_qty();
+                    self.fill_order(
+                        &mut order_any,
+                        last_qty,
+                        o.limit_px(),
+                        LiquiditySide::Maker,
+                        ts_now,
+                    )?;
+                }
+            }
+            PassiveOrderAny::Stop(o) => {
+                if o.is_triggered() {
+                    // Triggered stop-limit orders work as limit orders
+                    if let Some(price) = o.limit_px() {
+                        let side = o.order_side_specified();
+                        if self.core.is_limit_price_matched(side, price)
+                            && self.is_limit_filled(side, price)
+                        {
+                            let mut order_any = OrderAny::from(order.clone());
+                            let last_qty = order_any.leaves_qty();
+                            self.fill_order(
+                                &mut order_any,
+                                last_qty,
+                                price,
+                                LiquiditySide::Maker,
+                                ts_now,
+                            )?;
+                        }
+                    }
+                } else if self.core.is_stop_matched(o) {
+                    self.trigger_stop_order(o, ts_now)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn trigger_stop_order(
+        &mut self,
+        order: &StopOrderAny,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let side = order.order_side_specified();
+        let mut order_any = OrderAny::from(order.clone());
+
+        let Some(price) = order.limit_px() else {
+            // Stop-market, market-if-touched and trailing-stop-market orders fill immediately
+            let last_px = self.market_price(side).unwrap_or(order.stop_px());
+            return self.fill_taker_order(order_any, last_px, ts_now);
+        };
+
+        let event = OrderTriggered::new(
+            order_any.trader_id(),
+            order_any.strategy_id(),
+            order_any.instrument_id(),
+            order_any.client_order_id(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            order_any.venue_order_id(),
+            order_any.account_id(),
+        )?;
+        self.apply_event(&mut order_any, OrderEventAny::Triggered(event))?;
+
+        if self.core.is_limit_price_matched(side, price) {
+            // Marketable on trigger, so takes liquidity
+            let last_px = self.market_price(side).unwrap_or(price);
+            self.fill_taker_order(order_any, last_px, ts_now)
+        } else if matches!(
+            order_any.time_in_force(),
+            TimeInForce::Ioc | TimeInForce::Fok
+        ) {
+            self.cancel_order(order_any, ts_now)
+        } else {
+            self.core.update_order(order_any.into())?;
+            Ok(())
+        }
+    }
+
+    fn update_trailing_stop(
+        &mut self,
+        order: &StopOrderAny,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let price_increment = self.instrument.price_increment();
+        let (bid, ask, last) = (self.core.bid, self.core.ask, self.core.last);
+        let (trigger_price, price) = match order {
+            StopOrderAny::TrailingStopMarket(o) => {
+                trailing_stop_calculate(price_increment, o, bid, ask, last)?
+            }
+            StopOrderAny::TrailingStopLimit(o) => {
+                trailing_stop_calculate(price_increment, o, bid, ask, last)?
+            }
+            _ => return Ok(()),
+        };
+
+        if trigger_price.is_none() && price.is_none() {
+            return Ok(());
+        }
+
+        let mut order_any = OrderAny::from(order.clone());
+        let event = OrderUpdated::new(
+            order_any.trader_id(),
+            order_any.strategy_id(),
+            order_any.instrument_id(),
+            order_any.client_order_id(),
+            order_any.quantity(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            order_any.venue_order_id(),
+            order_any.account_id(),
+            price,
+            trigger_price,
+        )?;
+        self.apply_event(&mut order_any, OrderEventAny::Updated(event))?;
+        self.core.update_order(order_any.into())?;
+        Ok(())
+    }
+
+    /// Fills the given `order` as a taker at `last_px`, applying its time in force.
+    ///
+    /// `IOC` and `FOK` fills are limited to the top-of-book size (when known), with a `FOK`
+    /// order canceled if it cannot be filled in full, and any `IOC` remainder canceled.
+    /// Fills may slip one tick against the order (but not beyond its limit price), as given
+    /// by the fill model.
+    fn fill_taker_order(
+        &mut self,
+        mut order: OrderAny,
+        last_px: Price,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<()> {
+        if self.config.fill_mode == FillMode::BookDepth
+            && self.has_book_liquidity(order.order_side_specified())
+        {
+            return self.fill_book_depth_order(order, ts_now);
+        }
+
+        let time_in_force = order.time_in_force();
+        let leaves_qty = order.leaves_qty();
+        let last_qty = match time_in_force {
+            TimeInForce::Ioc | TimeInForce::Fok => self
+                .available_qty(order.order_side_specified())
+                .map_or(leaves_qty, |available| available.min(leaves_qty)),
+            _ => leaves_qty,
+        };
+
+        if last_qty.raw == 0 || (time_in_force == TimeInForce::Fok && last_qty < leaves_qty) {
+            return self.cancel_order(order, ts_now);
+        }
+
+        let last_px = if self.fill_model.is_slipped() {
+            let slipped_px = self.slipped_price(order.order_side(), last_px)?;
+            match (order.price(), order.order_side()) {
+                (Some(price), OrderSide::Buy) => slipped_px.min(price),
+                (Some(price), _) => slipped_px.max(price),
+                (None, _) => slipped_px,
+            }
+        } else {
+            last_px
+        };
+        self.fill_order(&mut order, last_qty, last_px, LiquiditySide::Taker, ts_now)?;
+
+        if time_in_force == TimeInForce::Ioc && order.is_open() {
+            self.cancel_order(order, ts_now)?;
+        }
+        Ok(())
+    }
+
+    /// Fills the given `order` as a taker by walking the levels of the order book, up to
+    /// its limit price (if any).
+    ///
+    /// Any remainder of a market order is filled one tick beyond the last level filled, and
+    /// any remainder of a limit order rests as a working order (unless `IOC` or `FOK`).
+    fn fill_book_depth_order(
+        &mut self,
+        mut order: OrderAny,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let side = order.order_side();
+        let price = match (order.price(), side) {
+            (Some(price), _) => price,
+            (None, OrderSide::Buy) => Price::max(self.instrument.price_precision()),
+            (None, _) => Price::min(self.instrument.price_precision()),
+        };
+        let book_order = BookOrder::new(side, price, order.leaves_qty(), 0);
+        let fills = self.book.simulate_fills(&book_order);
+
+        let time_in_force = order.time_in_force();
+        let fills_qty_raw: u64 = fills.iter().map(|(_, qty)| qty.raw).sum();
+        if fills.is_empty()
+            || (time_in_force == TimeInForce::Fok && fills_qty_raw < order.leaves_qty().raw)
+        {
+            return match time_in_force {
+                TimeInForce::Ioc | TimeInForce::Fok => self.cancel_order(order, ts_now),
+                _ if self.core.order_exists(order.client_order_id()) => Ok(()),
+                _ => self.rest_order(order, ts_now),
+            };
+        }
+
+        let mut last_px = price;
+        for (fill_px, fill_qty) in fills {
+            last_px = fill_px;
+            self.fill_order(&mut order, fill_qty, fill_px, LiquiditySide::Taker, ts_now)?;
+        }
+
+        if !order.is_open() {
+            return Ok(());
+        }
+
+        match time_in_force {
+            TimeInForce::Ioc | TimeInForce::Fok => self.cancel_order(order, ts_now),
+            _ if order.price().is_none() => {
+                // Book exhausted, so slip the remainder one tick
+                let slipped_px = self.slipped_price(side, last_px)?;
+                let leaves_qty = order.leaves_qty();
+                self.fill_order(
+                    &mut order,
+                    leaves_qty,
+                    slipped_px,
+                    LiquiditySide::Taker,
+                    ts_now,
+                )
+            }
+            _ if self.core.order_exists(order.client_order_id()) => Ok(()),
+            _ => self.rest_order(order, ts_now),
+        }
+    }
+
+    fn fill_order(
+        &mut self,
+        order: &mut OrderAny,
+        last_qty: Quantity,
+        last_px: Price,
+        liquidity_side: LiquiditySide,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let venue_order_id = match order.venue_order_id() {
+            Some(venue_order_id) => venue_order_id,
+            None => self.generate_venue_order_id()?,
+        };
+        let commission = self.fee_model.borrow_mut().get_commission(
+            order,
+            last_qty,
+            last_px,
+            liquidity_side,
+            self.instrument.as_ref(),
+        )?;
+        let event = OrderFilled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            venue_order_id,
+            self.account_id(order)?,
+            self.generate_trade_id()?,
+            order.order_side(),
+            order.order_type(),
+            last_qty,
+            last_px,
+            self.instrument.quote_currency(),
+            liquidity_side,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            None,
+            Some(commission),
+        )?;
+
+        let event = if last_qty < order.leaves_qty() {
+            OrderEventAny::PartiallyFilled(event)
+        } else {
+            OrderEventAny::Filled(event)
+        };
+        self.apply_event(order, event)?;
+
+        if order.is_closed() {
+            self.remove_order(order);
+        } else if self.core.order_exists(order.client_order_id()) {
+            self.core.update_order(order.clone().into())?;
+        }
+        Ok(())
+    }
+
+    fn cancel_order(&mut self, mut order: OrderAny, ts_now: UnixNanos) -> anyhow::Result<()> {
+        let event = OrderCanceled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            order.venue_order_id(),
+            order.account_id(),
+        )?;
+        self.apply_event(&mut order, OrderEventAny::Canceled(event))?;
+        self.remove_order(&order);
+        Ok(())
+    }
+
+    fn expire_order(&mut self, mut order: OrderAny, ts_now: UnixNanos) -> anyhow::Result<()> {
+        let event = OrderExpired::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            order.venue_order_id(),
+            order.account_id(),
+        )?;
+        self.apply_event(&mut order, OrderEventAny::Expired(event))?;
+        self.remove_order(&order);
+        Ok(())
+    }
+
+    fn reject_order(
+        &mut self,
+        order: &OrderAny,
+        account_id: AccountId,
+        reason: &str,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let event = OrderRejected::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            account_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+        )?;
+        self.events.push(OrderEventAny::Rejected(event));
+        Ok(())
+    }
+
+    fn modify_rejected(
+        &mut self,
+        command: &ModifyOrder,
+        account_id: AccountId,
+        reason: &str,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let event = OrderModifyRejected::new(
+            command.trader_id,
+            command.strategy_id,
+            command.instrument_id,
+            command.client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(command.venue_order_id),
+            Some(account_id),
+        )?;
+        self.events.push(OrderEventAny::ModifyRejected(event));
+        Ok(())
+    }
+
+    /// Removes the given closed `order` from the matching core, along with any expire timer.
+    fn remove_order(&mut self, order: &OrderAny) {
+        let client_order_id = order.client_order_id();
+        if self.core.order_exists(client_order_id) {
+            // SAFETY: Order was checked to exist in the core
+            self.core.delete_order(&order.clone().into()).unwrap();
+        }
+        if let Some(mut timer) = self.expire_timers.remove(&client_order_id) {
+            timer.cancel();
+        }
+        self.queue_ahead.remove(&client_order_id);
+    }
+
+    // -- QUEUE POSITION ------------------------------------------------------
+
+    /// Returns whether the given passive `order` is still waiting behind size ahead of it in
+    /// the queue at its price level (orders priced through the market are never queued).
+    fn is_queued(&self, order: &LimitOrderAny) -> bool {
+        let is_at_touch = match order.order_side_specified() {
+            OrderSideSpecified::Buy => self.core.ask == Some(order.limit_px()),
+            OrderSideSpecified::Sell => self.core.bid == Some(order.limit_px()),
+        };
+        is_at_touch
+            && self
+                .queue_ahead
+                .get(&order.client_order_id())
+                .map_or(false, Quantity::is_positive)
+    }
+
+    /// Consumes the queue ahead of the working orders at the `trade` price, filling orders
+    /// (as makers) from any trade volume remaining once their queue is exhausted.
+    fn process_queue_trade(&mut self, trade: &TradeTick) {
+        let orders: Vec<LimitOrderAny> = self
+            .core
+            .get_orders_bid()
+            .iter()
+            .chain(self.core.get_orders_ask())
+            .filter_map(|order| match order {
+                PassiveOrderAny::Limit(o) if o.limit_px() == trade.price => Some(o.clone()),
+                _ => None,
+            })
+            .collect();
+
+        for order in orders {
+            let client_order_id = order.client_order_id();
+            let Some(size_ahead) = self.queue_ahead.get_mut(&client_order_id) else {
+                continue;
+            };
+
+            let leftover = if trade.size.raw > size_ahead.raw {
+                let leftover = trade.size.raw - size_ahead.raw;
+                size_ahead.raw = 0;
+                leftover
+            } else {
+                size_ahead.raw -= trade.size.raw;
+                0
+            };
+            if leftover == 0 {
+                continue;
+            }
+
+            let mut order_any = OrderAny::from(order.clone());
+            let leaves_qty = order_any.leaves_qty();
+            let last_qty = Quantity::from_raw(leftover.min(leaves_qty.raw), leaves_qty.precision)
+                .expect("Invalid size precision");
+            if let Err(e) = self.fill_order(
+                &mut order_any,
+                last_qty,
+                order.limit_px(),
+                LiquiditySide::Maker,
+                trade.ts_init,
+            ) {
+                error!("Error filling {client_order_id}: {e}");
+            }
+        }
+    }
+
+    fn book_level_size(&self, side: OrderSideSpecified, price: Price) -> Quantity {
+        let level = match side {
+            OrderSideSpecified::Buy => self.book.bids().find(|level| level.price.value == price),
+            OrderSideSpecified::Sell => self.book.asks().find(|level| level.price.value == price),
+        };
+        let size_raw = level.map_or(0, |level| level.size_raw());
+        Quantity::from_raw(size_raw, self.instrument.size_precision())
+            .expect("Invalid size precision")
+    }
+
+    fn has_book_liquidity(&self, side: OrderSideSpecified) -> bool {
+        match side {
+            OrderSideSpecified::Buy => self.book.has_ask(),
+            OrderSideSpecified::Sell => self.book.has_bid(),
+        }
+    }
+
+    // -- EXPIRY TIMERS -------------------------------------------------------
+
+    fn set_expire_timer(
+        &mut self,
+        client_order_id: ClientOrderId,
+        expire_time: UnixNanos,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let timer = TestTimer::new(
+            &format!("{client_order_id}-EXPIRE"),
+            expire_time.saturating_sub(ts_now.as_u64()),
+            ts_now,
+            Some(expire_time),
+        )?;
+        self.expire_timers.insert(client_order_id, timer);
+        Ok(())
+    }
+
+    /// Advances the expire timers to `ts_now`, expiring the orders of any which fire (in
+    /// expire time order).
+    fn process_expire_timers(&mut self, ts_now: UnixNanos) {
+        let mut expired: Vec<(UnixNanos, ClientOrderId)> = self
+            .expire_timers
+            .iter_mut()
+            .filter_map(|(client_order_id, timer)| {
+                timer
+                    .advance(ts_now)
+                    .next()
+                    .map(|event| (event.ts_event, *client_order_id))
+            })
+            .collect();
+        expired.sort();
+
+        for (ts_event, client_order_id) in expired {
+            self.expire_timers.remove(&client_order_id);
+            let Some(order) = self.core.get_order(client_order_id).cloned() else {
+                continue;
+            };
+            if let Err(e) = self.expire_order(order.into(), ts_event) {
+                error!("Error expiring {client_order_id}: {e}");
+            }
+        }
+    }
+
+    fn apply_event(&mut self, order: &mut OrderAny, event: OrderEventAny) -> anyhow::Result<()> {
+        order.apply(event.clone())?;
+        self.events.push(event);
+        Ok(())
+    }
+
+    /// Returns whether a limit order on the given `side` at `price` is filled, with an order
+    /// at the touch (rather than through it) filled with the fill model's probability.
+    fn is_limit_filled(&mut self, side: OrderSideSpecified, price: Price) -> bool {
+        if self.core.side_price(side) == Some(price) {
+            self.fill_model.is_limit_filled()
+        } else {
+            true
+        }
+    }
+
+    /// Returns the given `price` slipped one tick against an order on the given `side`.
+    fn slipped_price(&self, side: OrderSide, price: Price) -> anyhow::Result<Price> {
+        let tick = self.instrument.price_increment().raw;
+        match side {
+            OrderSide::Buy => Price::from_raw(price.raw + tick, price.precision),
+            _ => Price::from_raw(price.raw - tick, price.precision),
+        }
+    }
+
+    /// Returns the market price an order on the given `side` would fill at as a taker,
+    /// falling back to the LAST price when there is no BID/ASK.
+    fn market_price(&self, side: OrderSideSpecified) -> Option<Price> {
+        self.core.side_price(side).or(self.core.last)
+    }
+
+    /// Returns the displayed top-of-book size an order on the given `side` would fill
+    /// against, or `None` if unknown.
+    fn available_qty(&self, side: OrderSideSpecified) -> Option<Quantity> {
+        match side {
+            OrderSideSpecified::Buy => self.ask_size,
+            OrderSideSpecified::Sell => self.bid_size,
+        }
+    }
+
+    fn account_id(&self, order: &OrderAny) -> anyhow::Result<AccountId> {
+        order
+            .account_id()
+            .or_else(|| self.account_ids.get(&order.trader_id()).copied())
+            .ok_or_else(|| anyhow::anyhow!("No account ID for {}", order.trader_id()))
+    }
+
+    // -- IDENTIFIER GENERATORS -----------------------------------------------
+
+    fn generate_venue_order_id(&mut self) -> anyhow::Result<VenueOrderId> {
+        self.order_count += 1;
+        if self.config.use_random_ids {
+            VenueOrderId::new(&UUID4::new().to_string())
+        } else {
+            VenueOrderId::new(&format!(
+                "{}-{}-{:03}",
+                self.venue, self.raw_id, self.order_count
+            ))
+        }
+    }
+
+    fn generate_trade_id(&mut self) -> anyhow::Result<TradeId> {
+        self.execution_count += 1;
+        if self.config.use_random_ids {
+            TradeId::new(&UUID4::new().to_string())
+        } else {
+            TradeId::new(&format!(
+                "{}-{}-{:03}",
+                self.venue, self.raw_id, self.execution_count
+            ))
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{AggressorSide, BookAction, TrailingOffsetType, TriggerType},
+        identifiers::{client_id::ClientId, strategy_id::StrategyId},
+        instruments::stubs::equity_aapl,
+        orders::stubs::{TestOrderEventStubs, TestOrderStubs},
+        types::{money::Money, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::models::FixedFeeModel;
+
+    fn create_matching_engine(
+        config: OrderMatchingEngineConfig,
+        book_type: BookType,
+        fill_model: FillModel,
+    ) -> OrderMatchingEngine {
+        let msgbus =
+            MessageBus::new(TraderId::from("TRADER-001"), UUID4::new(), None, None).unwrap();
+        OrderMatchingEngine::new(
+            Box::new(equity_aapl()),
+            1,
+            fill_model,
+            book_type,
+            OmsType::Netting,
+            AccountType::Cash,
+            Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default()))),
+            Box::leak(Box::new(msgbus)),
+            Box::leak(Box::new(Cache::default())),
+            config,
+        )
+    }
+
+    fn submit(engine: &mut OrderMatchingEngine, mut order: OrderAny) -> ClientOrderId {
+        let account_id = AccountId::from("SIM-001");
+        order
+            .apply(TestOrderEventStubs::order_submitted(&order, account_id))
+            .unwrap();
+        engine.process_order(&order, account_id).unwrap();
+        order.client_order_id()
+    }
+
+    fn modify(
+        engine: &mut OrderMatchingEngine,
+        client_order_id: ClientOrderId,
+        quantity: Option<Quantity>,
+        price: Option<Price>,
+    ) {
+        let command = ModifyOrder::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            StrategyId::default(),
+            InstrumentId::from("AAPL.XNAS"),
+            client_order_id,
+            VenueOrderId::default(),
+            quantity,
+            price,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        engine
+            .process_modify(&command, AccountId::from("SIM-001"))
+            .unwrap();
+    }
+
+    fn quote(bid: &str, ask: &str, ts: u64) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from("AAPL.XNAS"),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(100),
+            Quantity::from(100),
+            ts.into(),
+            ts.into(),
+        )
+        .unwrap()
+    }
+
+    fn add_book_level(engine: &mut OrderMatchingEngine, side: OrderSide, price: &str, size: i64) {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+        let delta = OrderBookDelta::new(
+            InstrumentId::from("AAPL.XNAS"),
+            BookAction::Add,
+            order,
+            0,
+            0,
+            1.into(),
+            1.into(),
+        );
+        engine.process_order_book_delta(delta);
+    }
+
+    fn trade(price: &str, size: i64, aggressor_side: AggressorSide, ts: u64) -> TradeTick {
+        TradeTick {
+            instrument_id: InstrumentId::from("AAPL.XNAS"),
+            price: Price::from(price),
+            size: Quantity::from(size),
+            aggressor_side,
+            trade_id: TradeId::from(ts.to_string().as_str()),
+            ts_event: ts.into(),
+            ts_init: ts.into(),
+        }
+    }
+
+    #[rstest]
+    fn test_stop_market_order_triggers_and_fills() {
+        let mut engine = create_matching_engine(
+            OrderMatchingEngineConfig::default(),
+            BookType::L1_MBP,
+            FillModel::default(),
+        );
+        engine.process_quote_tick(&quote("100.00", "100.05", 1));
+        let order = TestOrderStubs::stop_market_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Price::from("100.10"),
+            Quantity::from(100),
+            None,
+            None,
+            None,
+        );
+        let client_order_id = submit(&mut engine, order);
+
+        engine.process_quote_tick(&quote("100.05", "100.08", 2));
+        assert!(engine.order_exists(client_order_id));
+
+        engine.process_quote_tick(&quote("100.10", "100.12", 3));
+        let events = engine.drain_events();
+
+        assert!(!engine.order_exists(client_order_id));
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], OrderEventAny::Accepted(_)));
+        match &events[1] {
+            OrderEventAny::Filled(fill) => {
+                assert_eq!(fill.last_px, Price::from("100.12"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+                assert_eq!(fill.trade_id, TradeId::from("XNAS-1-001"));
+            }
+            event => panic!("Expected fill, was {event:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_stop_order_rejected_when_trigger_in_market() {
+        let mut engine = create_matching_engine(
+            OrderMatchingEngineConfig::default(),
+            BookType::L1_MBP,
+            FillModel::default(),
+        );
+        engine.process_quote_tick(&quote("100.00", "100.05", 1));
+        let order = TestOrderStubs::stop_market_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Sell,
+            Price::from("100.01"),
+            Quantity::from(100),
+            None,
+            None,
+            None,
+        );
+        let client_order_id = submit(&mut engine, order);
+
+        let events = engine.drain_events();
+
+        assert!(!engine.order_exists(client_order_id));
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEventAny::Rejected(_)));
+    }
+
+    #[rstest]
+    fn test_market_if_touched_order_triggers_on_last() {
+        let mut engine = create_matching_engine(
+            OrderMatchingEngineConfig::default(),
+            BookType::L1_MBP,
+            FillModel::default(),
+        );
+        engine.process_quote_tick(&quote("100.00", "100.05", 1));
+        let order = TestOrderStubs::market_if_touched_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Sell,
+            Price::from("100.20"),
+            Quantity::from(100),
+            Some(TriggerType::LastTrade),
+        );
+        let client_order_id = submit(&mut engine, order);
+
+        engine.process_trade_tick(&trade("100.20", 100, AggressorSide::Buyer, 2));
+        let events = engine.drain_events();
+
+        assert!(!engine.order_exists(client_order_id));
+        assert!(matches!(events.last(), Some(OrderEventAny::Filled(_))));
+    }
+
+    #[rstest]
+    fn test_trailing_stop_market_order_trails_then_triggers() {
+        let mut engine = create_matching_engine(
+            OrderMatchingEngineConfig::default(),
+            BookType::L1_MBP,
+            FillModel::default(),
+        );
+        engine.process_quote_tick(&quote("100.00", "100.05", 1));
+        let order = TestOrderStubs::trailing_stop_market_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Sell,
+            Price::from("99.50"),
+            Quantity::from(100),
+            TriggerType::BidAsk,
+            Price::from("0.50"),
+            TrailingOffsetType::Price,
+        );
+        let client_order_id = submit(&mut engine, order);
+
+        // Market rallies, so the trigger trails up behind the bid
+        engine.process_quote_tick(&quote("101.00", "101.05", 2));
+        match engine.core.get_order(client_order_id) {
+            Some(PassiveOrderAny::Stop(o)) => assert_eq!(o.stop_px(), Price::from("100.50")),
+            _ => panic!("Expected working stop order"),
+        }
+
+        engine.process_quote_tick(&quote("100.40", "100.45", 3));
+        let events = engine.drain_events();
+
+        assert!(!engine.order_exists(client_order_id));
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, OrderEventAny::Updated(_))));
+        match events.last() {
+            Some(OrderEventAny::Filled(fill)) => assert_eq!(fill.last_px, Price::from("100.40")),
+            event => panic!("Expected fill, was {event:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_fok_market_order_canceled_when_insufficient_liquidity() {
+        let mut engine = create_matching_engine(
+            OrderMatchingEngineConfig::default(),
+            BookType::L1_MBP,
+            FillModel::default(),
+        );
+        engine.process_quote_tick(&quote("100.00", "100.05", 1));
+        let order = TestOrderStubs::market_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Quantity::from(200),
+            None,
+            Some(TimeInForce::Fok),
+        );
+        submit(&mut engine, order);
+
+        let events = engine.drain_events();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEventAny::Canceled(_)));
+    }
+
+    #[rstest]
+    fn test_ioc_limit_order_partially_filled_then_canceled() {
+        let mut engine = create_matching_engine(
+            OrderMatchingEngineConfig::default(),
+            BookType::L1_MBP,
+            FillModel::default(),
+        );
+        engine.process_quote_tick(&quote("100.00", "100.05", 1));
+        let order = TestOrderStubs::limit_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Price::from("100.10"),
+            Quantity::from(200),
+            None,
+            Some(TimeInForce::Ioc),
+        );
+        let client_order_id = submit(&mut engine, order);
+
+        let events = engine.drain_events();
+
+        assert!(!engine.order_exists(client_order_id));
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], OrderEventAny::Accepted(_)));
+        match &events[1] {
+            OrderEventAny::PartiallyFilled(fill) => {
+                assert_eq!(fill.last_qty, Quantity::from(100));
+                assert_eq!(fill.last_px, Price::from("100.05"));
+            }
+            event => panic!("Expected partial fill, was {event:?}"),
+        }
+        assert!(matches!(events[2], OrderEventAny::Canceled(_)));
+    }
+
+    #[rstest]
+    fn test_ioc_limit_order_canceled_when_not_marketable() {
+        let mut engine = create_matching_engine(
+            OrderMatchingEngineConfig::default(),
+            BookType::L1_MBP,
+            FillModel::default(),
+        );
+        engine.process_quote_tick(&quote("100.00", "100.05", 1));
+        let order = TestOrderStubs::limit_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Price::from("99.00"),
+            Quantity::from(100),
+            None,
+            Some(TimeInForce::Ioc),
+        );
+        let client_order_id = submit(&mut engine, order);
+
+        let events = engine.drain_events();
+
+        assert!(!engine.order_exists(client_order_id));
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[1], OrderEventAny::Canceled(_)));
+    }
+
+    #[rstest]
+    fn test_gtd_order_expires_on_timer() {
+        let mut engine = create_matching_engine(
+            OrderMatchingEngineConfig::default(),
+            BookType::L1_MBP,
+            FillModel::default(),
+        );
+        engine.process_quote_tick(&quote("100.00", "100.05", 1_000));
+        let mut order = TestOrderStubs::limit_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Price::from("99.00"),
+            Quantity::from(100),
+            None,
+            None,
+        );
+        if let OrderAny::Limit(ref mut o) = order {
+            o.time_in_force = TimeInForce::Gtd;
+            o.expire_time = Some(UnixNanos::from(2_000));
+        }
+        let client_order_id = submit(&mut engine, order);
+
+        engine.process_quote_tick(&quote("100.00", "100.05", 1_500));
+        assert!(engine.order_exists(client_order_id));
+
+        engine.process_quote_tick(&quote("100.00", "100.05", 2_500));
+        let events = engine.drain_events();
+
+        assert!(!engine.order_exists(client_order_id));
+        match events.last() {
+            Some(OrderEventAny::Expired(event)) => {
+                assert_eq!(event.ts_event, UnixNanos::from(2_000));
+            }
+            event => panic!("Expected expired, was {event:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case(120, vec![("100.05", 50), ("100.10", 70)])]
+    #[case(200, vec![("100.05", 50), ("100.10", 100), ("100.11", 50)])] // Slips once exhausted
+    fn test_book_depth_market_order_walks_levels(
+        #[case] quantity: i64,
+        #[case] expected: Vec<(&str, i64)>,
+    ) {
+        let config = OrderMatchingEngineConfig {
+            fill_mode: FillMode::BookDepth,
+            ..Default::default()
+        };
+        let mut engine = create_matching_engine(config, BookType::L2_MBP, FillModel::default());
+        add_book_level(&mut engine, OrderSide::Buy, "100.00", 100);
+        add_book_level(&mut engine, OrderSide::Sell, "100.05", 50);
+        add_book_level(&mut engine, OrderSide::Sell, "100.10", 100);
+        let order = TestOrderStubs::market_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Quantity::from(quantity),
+            None,
+            None,
+        );
+        submit(&mut engine, order);
+
+        let fills: Vec<(Price, Quantity)> = engine
+            .drain_events()
+            .iter()
+            .filter_map(|event| match event {
+                OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill) => {
+                    Some((fill.last_px, fill.last_qty))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let expected: Vec<(Price, Quantity)> = expected
+            .into_iter()
+            .map(|(price, size)| (Price::from(price), Quantity::from(size)))
+            .collect();
+        assert_eq!(fills, expected);
+    }
+
+    #[rstest]
+    fn test_queue_position_delays_passive_fill() {
+        let config = OrderMatchingEngineConfig {
+            queue_position: true,
+            ..Default::default()
+        };
+        let mut engine = create_matching_engine(config, BookType::L2_MBP, FillModel::default());
+        add_book_level(&mut engine, OrderSide::Buy, "100.00", 100);
+        add_book_level(&mut engine, OrderSide::Sell, "100.05", 100);
+        let order = TestOrderStubs::limit_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from(50),
+            None,
+            None,
+        );
+        let client_order_id = submit(&mut engine, order);
+
+        engine.process_trade_tick(&trade("100.00", 80, AggressorSide::Seller, 2));
+        let events = engine.drain_events();
+        assert_eq!(events.len(), 1); // Accepted only, 20 still ahead
+
+        engine.process_trade_tick(&trade("100.00", 50, AggressorSide::Seller, 3));
+        let events = engine.drain_events();
+
+        assert!(engine.order_exists(client_order_id));
+        match &events[..] {
+            [OrderEventAny::PartiallyFilled(fill)] => {
+                assert_eq!(fill.last_qty, Quantity::from(30));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+            }
+            events => panic!("Expected partial fill, was {events:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_fill_model_limit_order_not_filled_at_touch() {
+        let fill_model = FillModel::new(0.0, 0.0, None).unwrap();
+        let mut engine = create_matching_engine(
+            OrderMatchingEngineConfig::default(),
+            BookType::L1_MBP,
+            fill_model,
+        );
+        engine.process_quote_tick(&quote("99.95", "100.05", 1));
+        let order = TestOrderStubs::limit_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from(100),
+            None,
+            None,
+        );
+        let client_order_id = submit(&mut engine, order);
+        engine.drain_events();
+
+        engine.process_quote_tick(&quote("99.95", "100.00", 2));
+
+        assert!(engine.order_exists(client_order_id));
+        assert!(engine.drain_events().is_empty());
+
+        engine.process_quote_tick(&quote("99.90", "99.95", 3));
+        let events = engine.drain_events();
+
+        assert!(!engine.order_exists(client_order_id));
+        match &events[..] {
+            [OrderEventAny::Filled(fill)] => assert_eq!(fill.last_px, Price::from("100.00")),
+            events => panic!("Expected fill, was {events:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_fill_model_market_order_slipped() {
+        let fill_model = FillModel::new(1.0, 1.0, None).unwrap();
+        let mut engine = create_matching_engine(
+            OrderMatchingEngineConfig::default(),
+            BookType::L1_MBP,
+            fill_model,
+        );
+        engine.process_quote_tick(&quote("100.00", "100.05", 1));
+        let order = TestOrderStubs::market_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Quantity::from(100),
+            None,
+            None,
+        );
+        submit(&mut engine, order);
+
+        match &engine.drain_events()[..] {
+            [OrderEventAny::Filled(fill)] => assert_eq!(fill.last_px, Price::from("100.06")),
+            events => panic!("Expected fill, was {events:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case("100.10", "100.06")]
+    #[case("100.05", "100.05")] // Not slipped beyond limit price
+    fn test_fill_model_marketable_limit_order_slipped(#[case] price: &str, #[case] expected: &str) {
+        let fill_model = FillModel::new(1.0, 1.0, None).unwrap();
+        let mut engine = create_matching_engine(
+            OrderMatchingEngineConfig::default(),
+            BookType::L1_MBP,
+            fill_model,
+        );
+        engine.process_quote_tick(&quote("100.00", "100.05", 1));
+        let order = TestOrderStubs::limit_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Price::from(price),
+            Quantity::from(100),
+            None,
+            None,
+        );
+        submit(&mut engine, order);
+
+        match engine.drain_events().last() {
+            Some(OrderEventAny::Filled(fill)) => assert_eq!(fill.last_px, Price::from(expected)),
+            event => panic!("Expected fill, was {event:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_fill_commission_from_fee_model() {
+        let mut engine = create_matching_engine(
+            OrderMatchingEngineConfig::default(),
+            BookType::L1_MBP,
+            FillModel::default(),
+        );
+        let fee_model = FixedFeeModel::per_contract(Money::from("0.01 USD"));
+        engine.set_fee_model(Rc::new(RefCell::new(fee_model)));
+        engine.process_quote_tick(&quote("100.00", "100.05", 1));
+        let order = TestOrderStubs::market_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Quantity::from(100),
+            None,
+            None,
+        );
+        submit(&mut engine, order);
+
+        match &engine.drain_events()[..] {
+            [OrderEventAny::Filled(fill)] => {
+                assert_eq!(fill.commission, Some(Money::from("1.00 USD")));
+            }
+            events => panic!("Expected fill, was {events:?}"),
+        }
     }
 
-    fn update_trailing_stop_market(&mut self, order: &TrailingStopMarketOrder) {
-        todo!()
+    #[rstest]
+    fn test_modify_rejected_when_quantity_below_filled() {
+        let config = OrderMatchingEngineConfig {
+            fill_mode: FillMode::BookDepth,
+            ..Default::default()
+        };
+        let mut engine = create_matching_engine(config, BookType::L2_MBP, FillModel::default());
+        add_book_level(&mut engine, OrderSide::Buy, "100.00", 100);
+        add_book_level(&mut engine, OrderSide::Sell, "100.05", 50);
+        let order = TestOrderStubs::limit_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Price::from("100.05"),
+            Quantity::from(100),
+            None,
+            None,
+        );
+        let client_order_id = submit(&mut engine, order);
+        engine.drain_events();
+
+        modify(&mut engine, client_order_id, Some(Quantity::from(40)), None);
+        let events = engine.drain_events();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEventAny::ModifyRejected(_)));
+        let order = OrderAny::from(engine.core.get_order(client_order_id).unwrap().clone());
+        assert_eq!(order.quantity(), Quantity::from(100));
+        assert_eq!(order.filled_qty(), Quantity::from(50));
     }
 
-    fn update_trailing_stop_limit(&mut self, order: &TrailingStopLimitOrder) {
-        todo!()
+    #[rstest]
+    fn test_order_rejected_when_price_invalid() {
+        let mut engine = create_matching_engine(
+            OrderMatchingEngineConfig::default(),
+            BookType::L1_MBP,
+            FillModel::default(),
+        );
+        let order = TestOrderStubs::limit_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Price::from("100.001"),
+            Quantity::from(100),
+            None,
+            None,
+        );
+        let client_order_id = submit(&mut engine, order);
+        let events = engine.drain_events();
+
+        assert!(!engine.order_exists(client_order_id));
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEventAny::Rejected(_)));
+    }
+
+    #[rstest]
+    fn test_modify_rejected_when_price_invalid() {
+        let mut engine = create_matching_engine(
+            OrderMatchingEngineConfig::default(),
+            BookType::L1_MBP,
+            FillModel::default(),
+        );
+        let order = TestOrderStubs::limit_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from(100),
+            None,
+            None,
+        );
+        let client_order_id = submit(&mut engine, order);
+        engine.drain_events();
+
+        modify(
+            &mut engine,
+            client_order_id,
+            None,
+            Some(Price::from("99.991")),
+        );
+        let events = engine.drain_events();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEventAny::ModifyRejected(_)));
     }
 }
@@ -0,0 +1,644 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Models for simulating venue behavior in backtests.
+
+use std::collections::HashMap;
+
+use nautilus_core::correctness::{check_in_range_inclusive_f64, check_slice_not_empty};
+use nautilus_execution::messages::TradingCommand;
+use nautilus_model::{
+    enums::LiquiditySide,
+    instruments::Instrument,
+    orders::any::OrderAny,
+    types::{
+        currency::Currency, money::Money, price::Price, quantity::Quantity,
+        rounding::get_rounding_policy,
+    },
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+const NANOSECONDS_IN_MILLISECOND: u64 = 1_000_000;
+
+/// Provides a latency model for commands sent to a simulated exchange.
+///
+/// The latency of a command is the base latency, plus the latency for its kind of command
+/// (insert, update or cancel), plus an optional uniformly random jitter.
+#[derive(Clone, Debug)]
+pub struct LatencyModel {
+    /// The latency (nanoseconds) applied to all commands.
+    pub base_latency_nanos: u64,
+    /// The additional latency (nanoseconds) for order insert commands.
+    pub insert_latency_nanos: u64,
+    /// The additional latency (nanoseconds) for order update commands.
+    pub update_latency_nanos: u64,
+    /// The additional latency (nanoseconds) for order cancel commands.
+    pub cancel_latency_nanos: u64,
+    /// The maximum random jitter (nanoseconds) added to each latency.
+    pub jitter_nanos: u64,
+    rng: StdRng,
+}
+
+impl LatencyModel {
+    /// Creates a new [`LatencyModel`] instance.
+    ///
+    /// The optional `random_seed` makes the jitter reproducible between backtest runs.
+    #[must_use]
+    pub fn new(
+        base_latency_nanos: u64,
+        insert_latency_nanos: u64,
+        update_latency_nanos: u64,
+        cancel_latency_nanos: u64,
+        jitter_nanos: u64,
+        random_seed: Option<u64>,
+    ) -> Self {
+        let rng = match random_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        Self {
+            base_latency_nanos,
+            insert_latency_nanos,
+            update_latency_nanos,
+            cancel_latency_nanos,
+            jitter_nanos,
+            rng,
+        }
+    }
+
+    /// Returns the latency (nanoseconds) for the given `command` to arrive at the exchange.
+    pub fn latency_nanos(&mut self, command: &TradingCommand) -> u64 {
+        let command_latency_nanos = match command {
+            TradingCommand::SubmitOrder(_) | TradingCommand::SubmitOrderList(_) => {
+                self.insert_latency_nanos
+            }
+            TradingCommand::ModifyOrder(_) => self.update_latency_nanos,
+            TradingCommand::CancelOrder(_)
+            | TradingCommand::CancelAllOrders(_)
+            | TradingCommand::BatchCancelOrders(_) => self.cancel_latency_nanos,
+            TradingCommand::QueryOrder(_) => 0,
+        };
+        let jitter_nanos = if self.jitter_nanos > 0 {
+            self.rng.gen_range(0..=self.jitter_nanos)
+        } else {
+            0
+        };
+
+        self.base_latency_nanos + command_latency_nanos + jitter_nanos
+    }
+}
+
+impl Default for LatencyModel {
+    /// Creates a new default [`LatencyModel`] instance, with a base latency of 1ms.
+    fn default() -> Self {
+        Self::new(NANOSECONDS_IN_MILLISECOND, 0, 0, 0, 0, None)
+    }
+}
+
+/// Provides a probabilistic fill model for orders on a simulated exchange.
+///
+/// Limit orders with a price at the touch (rather than through it) are filled with the
+/// probability `prob_fill_on_limit`, and top-of-book taker fills slip one tick against the
+/// order (but not beyond its limit price) with the probability `prob_slippage`. When filling
+/// with the book depth, slippage comes from walking the levels of the book instead.
+#[derive(Clone, Debug)]
+pub struct FillModel {
+    /// The probability of a limit order filling when its price is at the touch.
+    pub prob_fill_on_limit: f64,
+    /// The probability of a top-of-book taker fill slipping one tick.
+    pub prob_slippage: f64,
+    rng: StdRng,
+}
+
+impl FillModel {
+    /// Creates a new [`FillModel`] instance.
+    ///
+    /// The optional `random_seed` makes the fills reproducible between backtest runs.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if either probability is not in the range [0, 1].
+    pub fn new(
+        prob_fill_on_limit: f64,
+        prob_slippage: f64,
+        random_seed: Option<u64>,
+    ) -> anyhow::Result<Self> {
+        check_in_range_inclusive_f64(prob_fill_on_limit, 0.0, 1.0, "prob_fill_on_limit")?;
+        check_in_range_inclusive_f64(prob_slippage, 0.0, 1.0, "prob_slippage")?;
+
+        let rng = match random_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Ok(Self {
+            prob_fill_on_limit,
+            prob_slippage,
+            rng,
+        })
+    }
+
+    /// Returns whether a limit order at the touch is filled.
+    pub fn is_limit_filled(&mut self) -> bool {
+        self.event_success(self.prob_fill_on_limit)
+    }
+
+    /// Returns whether a top-of-book taker fill slips one tick.
+    pub fn is_slipped(&mut self) -> bool {
+        self.event_success(self.prob_slippage)
+    }
+
+    fn event_success(&mut self, probability: f64) -> bool {
+        match probability {
+            p if p <= 0.0 => false,
+            p if p >= 1.0 => true,
+            p => self.rng.gen_bool(p),
+        }
+    }
+}
+
+impl Default for FillModel {
+    /// Creates a new default [`FillModel`] instance, which always fills limit orders at the
+    /// touch and never slips.
+    fn default() -> Self {
+        Self {
+            prob_fill_on_limit: 1.0,
+            prob_slippage: 0.0,
+            rng: StdRng::from_entropy(),
+        }
+    }
+}
+
+/// Provides commissions for fills generated by a simulated exchange.
+pub trait FeeModel {
+    /// Returns the commission for a fill of `fill_qty` at `fill_px` for the given `order`,
+    /// which has not yet had the fill applied.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the commission cannot be calculated.
+    fn get_commission(
+        &mut self,
+        order: &OrderAny,
+        fill_qty: Quantity,
+        fill_px: Price,
+        liquidity_side: LiquiditySide,
+        instrument: &dyn Instrument,
+    ) -> anyhow::Result<Money>;
+}
+
+/// Provides a fee model which charges the maker or taker fee rate of the instrument on the
+/// notional value of each fill.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MakerTakerFeeModel;
+
+impl FeeModel for MakerTakerFeeModel {
+    fn get_commission(
+        &mut self,
+        _order: &OrderAny,
+        fill_qty: Quantity,
+        fill_px: Price,
+        liquidity_side: LiquiditySide,
+        instrument: &dyn Instrument,
+    ) -> anyhow::Result<Money> {
+        let rate = match liquidity_side {
+            LiquiditySide::Maker => instrument.maker_fee(),
+            LiquiditySide::Taker => instrument.taker_fee(),
+            LiquiditySide::NoLiquiditySide => anyhow::bail!("Invalid `LiquiditySide`"),
+        };
+        calculate_commission(instrument, fill_qty, fill_px, rate)
+    }
+}
+
+/// Provides a fee model which charges a fixed commission, either once per order (on its first
+/// fill) or per contract filled.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedFeeModel {
+    /// The fixed commission charged.
+    pub commission: Money,
+    /// If the commission is charged per contract filled, rather than once per order.
+    pub per_contract: bool,
+}
+
+impl FixedFeeModel {
+    /// Creates a new [`FixedFeeModel`] instance, charging `commission` once per order.
+    #[must_use]
+    pub fn new(commission: Money) -> Self {
+        Self {
+            commission,
+            per_contract: false,
+        }
+    }
+
+    /// Creates a new [`FixedFeeModel`] instance, charging `commission` per contract filled.
+    #[must_use]
+    pub fn per_contract(commission: Money) -> Self {
+        Self {
+            commission,
+            per_contract: true,
+        }
+    }
+}
+
+impl FeeModel for FixedFeeModel {
+    fn get_commission(
+        &mut self,
+        order: &OrderAny,
+        fill_qty: Quantity,
+        _fill_px: Price,
+        _liquidity_side: LiquiditySide,
+        _instrument: &dyn Instrument,
+    ) -> anyhow::Result<Money> {
+        let currency = self.commission.currency;
+        if self.per_contract {
+            Money::new(self.commission.as_f64() * fill_qty.as_f64(), currency)
+        } else if order.filled_qty().raw == 0 {
+            Ok(self.commission)
+        } else {
+            Money::new(0.0, currency)
+        }
+    }
+}
+
+/// Represents a tier of a volume based fee schedule.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FeeTier {
+    /// The minimum cumulative notional volume traded for the tier to apply.
+    pub min_volume: f64,
+    /// The fee rate for fills providing liquidity.
+    pub maker_fee: Decimal,
+    /// The fee rate for fills taking liquidity.
+    pub taker_fee: Decimal,
+}
+
+/// Provides a fee model which charges maker and taker fee rates from the tier reached by the
+/// cumulative notional volume traded (prior to each fill).
+///
+/// Volume is accumulated per notional currency, with the tier for a fill selected by the
+/// volume traded in the currency of its notional value.
+#[derive(Clone, Debug)]
+pub struct TieredVolumeFeeModel {
+    tiers: Vec<FeeTier>,
+    volumes: HashMap<Currency, f64>,
+}
+
+impl TieredVolumeFeeModel {
+    /// Creates a new [`TieredVolumeFeeModel`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `tiers` is empty, or the first tier has a non-zero
+    /// minimum volume.
+    pub fn new(mut tiers: Vec<FeeTier>) -> anyhow::Result<Self> {
+        check_slice_not_empty(&tiers, "tiers")?;
+        tiers.sort_by(|a, b| a.min_volume.total_cmp(&b.min_volume));
+        if tiers[0].min_volume > 0.0 {
+            anyhow::bail!(
+                "Invalid `tiers`, first tier minimum volume was {}",
+                tiers[0].min_volume
+            );
+        }
+        Ok(Self {
+            tiers,
+            volumes: HashMap::new(),
+        })
+    }
+
+    /// Returns the cumulative notional volume traded in the given `currency`.
+    #[must_use]
+    pub fn volume(&self, currency: Currency) -> f64 {
+        self.volumes.get(&currency).copied().unwrap_or(0.0)
+    }
+
+    /// Returns the tier for the current cumulative notional volume traded in the given
+    /// `currency`.
+    #[must_use]
+    pub fn current_tier(&self, currency: Currency) -> &FeeTier {
+        let volume = self.volume(currency);
+        self.tiers
+            .iter()
+            .rev()
+            .find(|tier| volume >= tier.min_volume)
+            .unwrap_or(&self.tiers[0])
+    }
+
+    /// Resets the cumulative notional volume traded in all currencies.
+    pub fn reset(&mut self) {
+        self.volumes.clear();
+    }
+}
+
+impl FeeModel for TieredVolumeFeeModel {
+    fn get_commission(
+        &mut self,
+        _order: &OrderAny,
+        fill_qty: Quantity,
+        fill_px: Price,
+        liquidity_side: LiquiditySide,
+        instrument: &dyn Instrument,
+    ) -> anyhow::Result<Money> {
+        let notional = instrument.calculate_notional_value(fill_qty, fill_px, None);
+        let tier = self.current_tier(notional.currency);
+        let rate = match liquidity_side {
+            LiquiditySide::Maker => tier.maker_fee,
+            LiquiditySide::Taker => tier.taker_fee,
+            LiquiditySide::NoLiquiditySide => anyhow::bail!("Invalid `LiquiditySide`"),
+        };
+        let commission = calculate_commission(instrument, fill_qty, fill_px, rate)?;
+
+        *self.volumes.entry(notional.currency).or_insert(0.0) += notional.as_f64();
+        Ok(commission)
+    }
+}
+
+fn calculate_commission(
+    instrument: &dyn Instrument,
+    fill_qty: Quantity,
+    fill_px: Price,
+    rate: Decimal,
+) -> anyhow::Result<Money> {
+    let notional = instrument.calculate_notional_value(fill_qty, fill_px, None);
+    let rate = rate
+        .to_f64()
+        .ok_or_else(|| anyhow::anyhow!("Invalid fee rate {rate}"))?;
+    get_rounding_policy(&instrument.id()).round_fee(notional.as_f64() * rate, notional.currency)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use nautilus_execution::messages::{cancel::CancelOrder, query::QueryOrder};
+    use nautilus_model::{
+        enums::OrderSide,
+        identifiers::{
+            client_id::ClientId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+            strategy_id::StrategyId, trader_id::TraderId, venue_order_id::VenueOrderId,
+        },
+        instruments::{
+            crypto_perpetual::CryptoPerpetual,
+            stubs::{audusd_sim, crypto_perpetual_ethusdt},
+        },
+        orders::stubs::TestOrderStubs,
+    };
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn cancel_command() -> TradingCommand {
+        let command = CancelOrder::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            StrategyId::default(),
+            InstrumentId::from("AAPL.XNAS"),
+            ClientOrderId::default(),
+            VenueOrderId::default(),
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        TradingCommand::CancelOrder(command)
+    }
+
+    #[rstest]
+    fn test_latency_nanos_for_cancel() {
+        let mut model = LatencyModel::new(1_000, 200, 300, 400, 0, None);
+
+        assert_eq!(model.latency_nanos(&cancel_command()), 1_400);
+    }
+
+    #[rstest]
+    fn test_latency_nanos_for_query_is_base_latency() {
+        let mut model = LatencyModel::new(1_000, 200, 300, 400, 0, None);
+        let command = QueryOrder::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            StrategyId::default(),
+            InstrumentId::from("AAPL.XNAS"),
+            ClientOrderId::default(),
+            VenueOrderId::default(),
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            model.latency_nanos(&TradingCommand::QueryOrder(command)),
+            1_000
+        );
+    }
+
+    #[rstest]
+    #[case(-0.1, 0.0)]
+    #[case(0.0, 1.1)]
+    fn test_fill_model_invalid_probability(
+        #[case] prob_fill_on_limit: f64,
+        #[case] prob_slippage: f64,
+    ) {
+        assert!(FillModel::new(prob_fill_on_limit, prob_slippage, None).is_err());
+    }
+
+    #[rstest]
+    fn test_fill_model_certain_probabilities() {
+        let mut model = FillModel::new(0.0, 1.0, None).unwrap();
+
+        for _ in 0..10 {
+            assert!(!model.is_limit_filled());
+            assert!(model.is_slipped());
+        }
+    }
+
+    #[rstest]
+    fn test_fill_model_seeded_is_reproducible() {
+        let mut model1 = FillModel::new(0.5, 0.5, Some(42)).unwrap();
+        let mut model2 = FillModel::new(0.5, 0.5, Some(42)).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(model1.is_limit_filled(), model2.is_limit_filled());
+            assert_eq!(model1.is_slipped(), model2.is_slipped());
+        }
+    }
+
+    #[rstest]
+    fn test_latency_nanos_with_seeded_jitter() {
+        let mut model1 = LatencyModel::new(1_000, 0, 0, 400, 50, Some(42));
+        let mut model2 = LatencyModel::new(1_000, 0, 0, 400, 50, Some(42));
+
+        for _ in 0..10 {
+            let latency = model1.latency_nanos(&cancel_command());
+            assert!((1_400..=1_450).contains(&latency));
+            assert_eq!(latency, model2.latency_nanos(&cancel_command()));
+        }
+    }
+
+    fn ethusdt_order() -> OrderAny {
+        TestOrderStubs::market_order(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            OrderSide::Buy,
+            Quantity::from("10.000"),
+            None,
+            None,
+        )
+    }
+
+    #[rstest]
+    #[case(LiquiditySide::Maker, "0.40 USDT")]
+    #[case(LiquiditySide::Taker, "0.80 USDT")]
+    fn test_maker_taker_fee_model(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] liquidity_side: LiquiditySide,
+        #[case] expected: &str,
+    ) {
+        let commission = MakerTakerFeeModel
+            .get_commission(
+                &ethusdt_order(),
+                Quantity::from("1.000"),
+                Price::from("2000.00"),
+                liquidity_side,
+                &crypto_perpetual_ethusdt,
+            )
+            .unwrap();
+
+        assert_eq!(commission, Money::from(expected));
+    }
+
+    #[rstest]
+    #[case(FixedFeeModel::new(Money::from("2.00 USD")), "2.00 USD")]
+    #[case(FixedFeeModel::per_contract(Money::from("2.00 USD")), "6.00 USD")]
+    fn test_fixed_fee_model(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] mut fee_model: FixedFeeModel,
+        #[case] expected: &str,
+    ) {
+        let commission = fee_model
+            .get_commission(
+                &ethusdt_order(),
+                Quantity::from("3.000"),
+                Price::from("2000.00"),
+                LiquiditySide::Taker,
+                &crypto_perpetual_ethusdt,
+            )
+            .unwrap();
+
+        assert_eq!(commission, Money::from(expected));
+    }
+
+    #[rstest]
+    fn test_tiered_volume_fee_model_invalid_first_tier() {
+        let tiers = vec![FeeTier {
+            min_volume: 1_000.0,
+            maker_fee: dec!(0.0002),
+            taker_fee: dec!(0.0004),
+        }];
+
+        assert!(TieredVolumeFeeModel::new(tiers).is_err());
+    }
+
+    #[rstest]
+    fn test_tiered_volume_fee_model_moves_tier_with_volume(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let tiers = vec![
+            FeeTier {
+                min_volume: 10_000.0,
+                maker_fee: dec!(0.0001),
+                taker_fee: dec!(0.0002),
+            },
+            FeeTier {
+                min_volume: 0.0,
+                maker_fee: dec!(0.0002),
+                taker_fee: dec!(0.0004),
+            },
+        ];
+        let mut fee_model = TieredVolumeFeeModel::new(tiers).unwrap();
+        let order = ethusdt_order();
+
+        let commission1 = fee_model
+            .get_commission(
+                &order,
+                Quantity::from("5.000"),
+                Price::from("2000.00"),
+                LiquiditySide::Taker,
+                &crypto_perpetual_ethusdt,
+            )
+            .unwrap();
+        let commission2 = fee_model
+            .get_commission(
+                &order,
+                Quantity::from("1.000"),
+                Price::from("2000.00"),
+                LiquiditySide::Taker,
+                &crypto_perpetual_ethusdt,
+            )
+            .unwrap();
+
+        assert_eq!(commission1, Money::from("4.00 USDT"));
+        assert_eq!(commission2, Money::from("0.40 USDT"));
+        assert_eq!(fee_model.volume(Currency::USDT()), 12_000.0);
+        assert_eq!(
+            fee_model.current_tier(Currency::USDT()).min_volume,
+            10_000.0
+        );
+    }
+
+    #[rstest]
+    fn test_tiered_volume_fee_model_tracks_volume_per_currency(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let tiers = vec![
+            FeeTier {
+                min_volume: 0.0,
+                maker_fee: dec!(0.0002),
+                taker_fee: dec!(0.0004),
+            },
+            FeeTier {
+                min_volume: 10_000.0,
+                maker_fee: dec!(0.0001),
+                taker_fee: dec!(0.0002),
+            },
+        ];
+        let mut fee_model = TieredVolumeFeeModel::new(tiers).unwrap();
+        let order = ethusdt_order();
+        let audusd = audusd_sim();
+
+        fee_model
+            .get_commission(
+                &order,
+                Quantity::from(20_000),
+                Price::from("1.00000"),
+                LiquiditySide::Taker,
+                &audusd,
+            )
+            .unwrap();
+        let commission = fee_model
+            .get_commission(
+                &order,
+                Quantity::from("1.000"),
+                Price::from("2000.00"),
+                LiquiditySide::Taker,
+                &crypto_perpetual_ethusdt,
+            )
+            .unwrap();
+
+        assert_eq!(commission, Money::from("0.80 USDT"));
+        assert_eq!(fee_model.volume(Currency::USD()), 20_000.0);
+        assert_eq!(fee_model.current_tier(Currency::USD()).min_volume, 10_000.0);
+        assert_eq!(fee_model.current_tier(Currency::USDT()).min_volume, 0.0);
+    }
+}
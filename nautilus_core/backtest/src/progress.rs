@@ -0,0 +1,270 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Progress reporting for long running backtests.
+//!
+//! Cancellation of a run is requested through the engine, and may be triggered from a progress
+//! callback.
+
+use std::{
+    num::NonZeroU64,
+    ops::{Deref, DerefMut},
+    sync::mpsc::Sender,
+    time::{Duration, Instant},
+};
+
+use nautilus_core::nanos::UnixNanos;
+
+/// Represents a snapshot of backtest progress.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BacktestProgress {
+    /// The count of data events processed.
+    pub processed: u64,
+    /// The total count of data events to process.
+    pub total: u64,
+    /// The percentage of data events processed (0.0 to 100.0).
+    pub percent: f64,
+    /// The current simulated UNIX timestamp (nanoseconds).
+    pub ts_sim: UnixNanos,
+    /// The processing rate in events per wall clock second.
+    pub events_per_sec: f64,
+    /// The estimated wall clock time remaining, if a rate has been established.
+    pub eta: Option<Duration>,
+}
+
+/// A callback receiving progress reports.
+pub type ProgressCallback = Box<dyn FnMut(&BacktestProgress) + Send>;
+
+/// Provides progress reports for a backtest run at a fixed interval of processed events.
+pub struct ProgressReporter {
+    total: u64,
+    report_interval: NonZeroU64,
+    processed: u64,
+    ts_sim: UnixNanos,
+    started: Option<Instant>,
+    callbacks: Vec<ProgressCallback>,
+}
+
+impl ProgressReporter {
+    /// Creates a new [`ProgressReporter`] instance.
+    ///
+    /// A report is delivered every `report_interval` processed events, and on the final event.
+    #[must_use]
+    pub fn new(total: u64, report_interval: NonZeroU64) -> Self {
+        Self {
+            total,
+            report_interval,
+            processed: 0,
+            ts_sim: UnixNanos::default(),
+            started: None,
+            callbacks: Vec::new(),
+        }
+    }
+
+    /// Registers the given `callback` to receive progress reports.
+    pub fn register_callback(&mut self, callback: ProgressCallback) {
+        self.callbacks.push(callback);
+    }
+
+    /// Registers the given channel `sender` to receive progress reports.
+    ///
+    /// Reports are silently dropped once the receiver has disconnected.
+    pub fn register_channel(&mut self, sender: Sender<BacktestProgress>) {
+        self.register_callback(Box::new(move |progress| {
+            let _ = sender.send(progress.clone());
+        }));
+    }
+
+    /// Records a processed data event at the simulated time `ts_sim`, delivering a progress
+    /// report if due.
+    ///
+    /// Returns whether a report was due for this event.
+    pub fn on_event(&mut self, ts_sim: UnixNanos) -> bool {
+        if self.started.is_none() {
+            self.started = Some(Instant::now());
+        }
+        self.processed += 1;
+        self.ts_sim = ts_sim;
+
+        let is_due =
+            self.processed % self.report_interval.get() == 0 || self.processed == self.total;
+        if is_due {
+            let progress = self.progress();
+            for callback in &mut self.callbacks {
+                callback(&progress);
+            }
+        }
+        is_due
+    }
+
+    /// Returns the current progress of the run.
+    #[must_use]
+    pub fn progress(&self) -> BacktestProgress {
+        let elapsed = self.started.map(|s| s.elapsed()).unwrap_or_default();
+        let percent = if self.total == 0 {
+            100.0
+        } else {
+            (self.processed as f64 / self.total as f64 * 100.0).min(100.0)
+        };
+        let events_per_sec = if elapsed.is_zero() {
+            0.0
+        } else {
+            self.processed as f64 / elapsed.as_secs_f64()
+        };
+        let eta = (events_per_sec > 0.0).then(|| {
+            let remaining = self.total.saturating_sub(self.processed);
+            Duration::from_secs_f64(remaining as f64 / events_per_sec)
+        });
+
+        BacktestProgress {
+            processed: self.processed,
+            total: self.total,
+            percent,
+            ts_sim: self.ts_sim,
+            events_per_sec,
+            eta,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+#[repr(C)]
+pub struct ProgressReporterAPI(Box<ProgressReporter>);
+
+impl Deref for ProgressReporterAPI {
+    type Target = ProgressReporter;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ProgressReporterAPI {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// A zero `report_interval` is treated as a report on every event.
+#[no_mangle]
+pub extern "C" fn progress_reporter_new(total: u64, report_interval: u64) -> ProgressReporterAPI {
+    let report_interval = NonZeroU64::new(report_interval).unwrap_or(NonZeroU64::MIN);
+    ProgressReporterAPI(Box::new(ProgressReporter::new(total, report_interval)))
+}
+
+#[no_mangle]
+pub extern "C" fn progress_reporter_drop(reporter: ProgressReporterAPI) {
+    drop(reporter); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn progress_reporter_on_event(
+    reporter: &mut ProgressReporterAPI,
+    ts_sim: UnixNanos,
+) -> u8 {
+    u8::from(reporter.on_event(ts_sim))
+}
+
+#[no_mangle]
+pub extern "C" fn progress_reporter_processed(reporter: &ProgressReporterAPI) -> u64 {
+    reporter.processed
+}
+
+#[no_mangle]
+pub extern "C" fn progress_reporter_percent(reporter: &ProgressReporterAPI) -> f64 {
+    reporter.progress().percent
+}
+
+#[no_mangle]
+pub extern "C" fn progress_reporter_events_per_sec(reporter: &ProgressReporterAPI) -> f64 {
+    reporter.progress().events_per_sec
+}
+
+/// Returns the estimated seconds remaining, or NaN if no rate has been established.
+#[no_mangle]
+pub extern "C" fn progress_reporter_eta_secs(reporter: &ProgressReporterAPI) -> f64 {
+    reporter
+        .progress()
+        .eta
+        .map_or(f64::NAN, |eta| eta.as_secs_f64())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::{mpsc::channel, Arc, Mutex};
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn interval(value: u64) -> NonZeroU64 {
+        NonZeroU64::new(value).unwrap()
+    }
+
+    #[rstest]
+    fn test_ffi_new_with_zero_interval_reports_every_event() {
+        let mut reporter = progress_reporter_new(10, 0);
+
+        assert_eq!(
+            progress_reporter_on_event(&mut reporter, UnixNanos::from(1)),
+            1
+        );
+        assert_eq!(
+            progress_reporter_on_event(&mut reporter, UnixNanos::from(2)),
+            1
+        );
+        assert_eq!(progress_reporter_processed(&reporter), 2);
+        assert_eq!(progress_reporter_percent(&reporter), 20.0);
+    }
+
+    #[rstest]
+    fn test_reports_at_interval_and_final_event() {
+        let mut reporter = ProgressReporter::new(5, interval(2));
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let reports_clone = reports.clone();
+        reporter.register_callback(Box::new(move |progress| {
+            reports_clone.lock().unwrap().push(progress.clone());
+        }));
+
+        let due: Vec<bool> = (1..=5)
+            .map(|i| reporter.on_event(UnixNanos::from(i * 1_000)))
+            .collect();
+
+        let reports = reports.lock().unwrap();
+        let processed: Vec<u64> = reports.iter().map(|p| p.processed).collect();
+        assert_eq!(processed, vec![2, 4, 5]);
+        assert_eq!(due, vec![false, true, false, true, true]);
+        assert_eq!(reports[0].percent, 40.0);
+        assert_eq!(reports[2].percent, 100.0);
+        assert_eq!(reports[2].ts_sim, UnixNanos::from(5_000));
+    }
+
+    #[rstest]
+    fn test_reports_to_channel() {
+        let mut reporter = ProgressReporter::new(2, interval(1));
+        let (sender, receiver) = channel();
+        reporter.register_channel(sender);
+
+        reporter.on_event(UnixNanos::from(1));
+        assert_eq!(receiver.recv().unwrap().processed, 1);
+        drop(receiver);
+        reporter.on_event(UnixNanos::from(2)); // Receiver disconnected
+    }
+}
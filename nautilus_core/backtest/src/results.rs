@@ -0,0 +1,335 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Aggregation of backtest results across distributed workers.
+//!
+//! Each worker summarizes its runs into a [`ResultsAggregate`], which is serialized and sent to
+//! a coordinator. Aggregates merge associatively, so partial results can be combined in any
+//! order as they stream in, without shipping the full run journals.
+
+use std::{cmp::Ordering, collections::BTreeMap};
+
+use serde::{Deserialize, Serialize};
+
+/// Provides mergeable summary statistics for a metric over a stream of values.
+///
+/// The mean and variance are accumulated using Welford's online algorithm, and merged using
+/// Chan's parallel algorithm.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatsAccumulator {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl StatsAccumulator {
+    /// Updates the statistics with the given `value`.
+    pub fn update(&mut self, value: f64) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        }
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// Merges the statistics from `other` into these statistics.
+    pub fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = other.clone();
+            return;
+        }
+
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 += other.m2 + delta * delta * (self.count * other.count) as f64 / count as f64;
+        self.count = count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// Returns the count of values.
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean of the values, or `None` if there are no values.
+    #[must_use]
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Returns the sample standard deviation of the values, or `None` if there are fewer than
+    /// two values.
+    #[must_use]
+    pub fn std_dev(&self) -> Option<f64> {
+        (self.count > 1).then(|| (self.m2 / (self.count - 1) as f64).sqrt())
+    }
+
+    /// Returns the minimum value, or `None` if there are no values.
+    #[must_use]
+    pub fn min(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    /// Returns the maximum value, or `None` if there are no values.
+    #[must_use]
+    pub fn max(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.max)
+    }
+}
+
+/// Represents the summary metrics of a single backtest run.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RunSummary {
+    /// The backtest run ID.
+    pub run_id: String,
+    /// The parameters for the run (e.g. from a parameter sweep).
+    pub params: BTreeMap<String, String>,
+    /// The count of data iterations for the run.
+    pub iterations: u64,
+    /// The total count of orders for the run.
+    pub total_orders: u64,
+    /// The total count of positions for the run.
+    pub total_positions: u64,
+    /// The performance statistics for the run, keyed by name (e.g. `Sharpe Ratio (252 days)`).
+    pub stats: BTreeMap<String, f64>,
+}
+
+impl RunSummary {
+    /// Creates a new [`RunSummary`] instance.
+    #[must_use]
+    pub fn new(
+        run_id: String,
+        params: BTreeMap<String, String>,
+        iterations: u64,
+        total_orders: u64,
+        total_positions: u64,
+        stats: BTreeMap<String, f64>,
+    ) -> Self {
+        Self {
+            run_id,
+            params,
+            iterations,
+            total_orders,
+            total_positions,
+            stats,
+        }
+    }
+}
+
+/// Represents the merged results of many backtest runs, for a comparative report.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResultsAggregate {
+    runs: BTreeMap<String, RunSummary>,
+    stats: BTreeMap<String, StatsAccumulator>,
+}
+
+impl ResultsAggregate {
+    /// Creates a new empty [`ResultsAggregate`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given run `summary` to the aggregate.
+    ///
+    /// Statistics which are not finite (e.g. a ratio undefined for a run with no trades)
+    /// are dropped from the summary.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a run with the same ID has already been added.
+    pub fn add_run(&mut self, mut summary: RunSummary) -> anyhow::Result<()> {
+        if self.runs.contains_key(&summary.run_id) {
+            anyhow::bail!("Run {} already aggregated", summary.run_id);
+        }
+
+        summary.stats.retain(|_, value| value.is_finite());
+        for (name, value) in &summary.stats {
+            self.stats.entry(name.clone()).or_default().update(*value);
+        }
+        self.runs.insert(summary.run_id.clone(), summary);
+        Ok(())
+    }
+
+    /// Merges the partial results from `other` into this aggregate.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any run is contained in both aggregates, in which case
+    /// this aggregate is left unchanged.
+    pub fn merge(&mut self, other: Self) -> anyhow::Result<()> {
+        if let Some(run_id) = other.runs.keys().find(|id| self.runs.contains_key(*id)) {
+            anyhow::bail!("Run {run_id} already aggregated");
+        }
+
+        for (name, stats) in &other.stats {
+            self.stats.entry(name.clone()).or_default().merge(stats);
+        }
+        self.runs.extend(other.runs);
+        Ok(())
+    }
+
+    /// Returns the count of aggregated runs.
+    #[must_use]
+    pub fn run_count(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// Returns the aggregated run summaries, keyed by run ID.
+    #[must_use]
+    pub fn runs(&self) -> &BTreeMap<String, RunSummary> {
+        &self.runs
+    }
+
+    /// Returns the summary statistics across all runs for the statistic `name`.
+    #[must_use]
+    pub fn stats(&self, name: &str) -> Option<&StatsAccumulator> {
+        self.stats.get(name)
+    }
+
+    /// Returns the runs ranked by the statistic `name`, best (highest) first.
+    ///
+    /// Runs without a value for the statistic are ranked last.
+    #[must_use]
+    pub fn ranked_by(&self, name: &str) -> Vec<&RunSummary> {
+        let mut runs: Vec<&RunSummary> = self.runs.values().collect();
+        runs.sort_by(|a, b| match (a.stats.get(name), b.stats.get(name)) {
+            (Some(a), Some(b)) => b.total_cmp(a),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+        runs
+    }
+
+    /// Returns the aggregate encoded as JSON bytes, for transfer between workers.
+    pub fn to_json_bytes(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    /// Returns an aggregate decoded from the given JSON `bytes`.
+    pub fn from_json_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const SHARPE: &str = "Sharpe Ratio (252 days)";
+
+    fn summary(run_id: &str, fast_ema: u32, sharpe: f64) -> RunSummary {
+        RunSummary::new(
+            run_id.to_string(),
+            BTreeMap::from([("fast_ema".to_string(), fast_ema.to_string())]),
+            1_000,
+            10,
+            5,
+            BTreeMap::from([(SHARPE.to_string(), sharpe)]),
+        )
+    }
+
+    #[rstest]
+    fn test_stats_accumulator_merge_matches_sequential() {
+        let values = [1.0, 2.5, -3.0, 4.0, 7.5, 0.5];
+        let mut sequential = StatsAccumulator::default();
+        let mut left = StatsAccumulator::default();
+        let mut right = StatsAccumulator::default();
+        for (i, value) in values.iter().enumerate() {
+            sequential.update(*value);
+            if i < 2 {
+                left.update(*value);
+            } else {
+                right.update(*value);
+            }
+        }
+
+        left.merge(&right);
+
+        assert_eq!(left.count(), 6);
+        assert!((left.mean().unwrap() - sequential.mean().unwrap()).abs() < 1e-12);
+        assert!((left.std_dev().unwrap() - sequential.std_dev().unwrap()).abs() < 1e-12);
+        assert_eq!(left.min(), Some(-3.0));
+        assert_eq!(left.max(), Some(7.5));
+    }
+
+    #[rstest]
+    fn test_stats_accumulator_empty() {
+        let stats = StatsAccumulator::default();
+
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.std_dev(), None);
+        assert_eq!(stats.min(), None);
+    }
+
+    #[rstest]
+    fn test_merge_partial_aggregates_from_workers() {
+        let mut worker1 = ResultsAggregate::new();
+        worker1.add_run(summary("run-1", 10, 1.5)).unwrap();
+        worker1.add_run(summary("run-2", 20, 0.5)).unwrap();
+        let mut worker2 = ResultsAggregate::new();
+        worker2.add_run(summary("run-3", 30, 2.5)).unwrap();
+        worker2.add_run(summary("run-4", 40, f64::NAN)).unwrap();
+
+        let bytes = worker2.to_json_bytes().unwrap();
+        let mut aggregate = worker1;
+        aggregate
+            .merge(ResultsAggregate::from_json_bytes(&bytes).unwrap())
+            .unwrap();
+
+        let ranked: Vec<&str> = aggregate
+            .ranked_by(SHARPE)
+            .iter()
+            .map(|r| r.run_id.as_str())
+            .collect();
+        let stats = aggregate.stats(SHARPE).unwrap();
+        assert_eq!(aggregate.run_count(), 4);
+        assert_eq!(ranked, vec!["run-3", "run-1", "run-2", "run-4"]);
+        assert_eq!(stats.count(), 3);
+        assert_eq!(stats.mean(), Some(1.5));
+    }
+
+    #[rstest]
+    fn test_merge_with_duplicate_run() {
+        let mut aggregate = ResultsAggregate::new();
+        aggregate.add_run(summary("run-1", 10, 1.5)).unwrap();
+        let mut other = ResultsAggregate::new();
+        other.add_run(summary("run-1", 10, 1.5)).unwrap();
+        other.add_run(summary("run-2", 20, 0.5)).unwrap();
+
+        assert!(aggregate.merge(other).is_err());
+        assert_eq!(aggregate.run_count(), 1);
+        assert!(aggregate.add_run(summary("run-1", 10, 1.5)).is_err());
+    }
+}
@@ -0,0 +1,1075 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Bar aggregation machinery for building bars from quote and trade ticks.
+//!
+//! Mirrors the `INTERNAL` aggregation semantics of the Python `BarAggregator` family.
+
+use std::fmt::{Debug, Formatter};
+
+use chrono::{
+    DateTime, Datelike, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeDelta, TimeZone,
+};
+use chrono_tz::Tz;
+use nautilus_core::{
+    correctness::{self, check_predicate_true},
+    datetime::NANOSECONDS_IN_SECOND,
+    nanos::UnixNanos,
+};
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarBuilder, BarSpecification, BarType},
+        quote::QuoteTick,
+        trade::TradeTick,
+    },
+    enums::BarAggregation,
+    instruments::any::InstrumentAny,
+    types::{fixed::FIXED_SCALAR, price::Price, quantity::Quantity},
+};
+
+use crate::{clock::Clock, handlers::EventHandler, timer::TimeEvent};
+
+/// The handler called with each bar built by an aggregator.
+pub type BarHandler = Box<dyn FnMut(Bar)>;
+
+/// Provides the core state and behavior shared by all bar aggregators.
+pub struct BarAggregatorCore {
+    bar_type: BarType,
+    builder: BarBuilder,
+    handler: BarHandler,
+    await_partial: bool,
+}
+
+impl Debug for BarAggregatorCore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(BarAggregatorCore))
+            .field("bar_type", &self.bar_type)
+            .field("builder", &self.builder)
+            .field("await_partial", &self.await_partial)
+            .finish()
+    }
+}
+
+impl BarAggregatorCore {
+    /// Creates a new [`BarAggregatorCore`] instance.
+    pub fn new(
+        instrument: &InstrumentAny,
+        bar_type: BarType,
+        handler: BarHandler,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(
+            instrument.id() == bar_type.instrument_id,
+            "`instrument.id` was not equal to `bar_type.instrument_id`",
+        )?;
+
+        Ok(Self {
+            bar_type,
+            builder: BarBuilder::new(bar_type, instrument.size_precision()),
+            handler,
+            await_partial: false,
+        })
+    }
+
+    /// Returns a reference to the underlying bar builder.
+    #[must_use]
+    pub fn builder(&self) -> &BarBuilder {
+        &self.builder
+    }
+
+    fn apply_update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
+        self.builder.update(price, size, ts_event);
+    }
+
+    fn build_now_and_send(&mut self) {
+        let bar = self.builder.build_now();
+        self.send(bar);
+    }
+
+    fn build_and_send(&mut self, ts_event: UnixNanos, ts_init: UnixNanos) {
+        let bar = self.builder.build(ts_event, ts_init);
+        self.send(bar);
+    }
+
+    fn send(&mut self, bar: Bar) {
+        // Inconsistent bars can only result from an invalid partial bar
+        if !bar.is_valid() {
+            log::error!("Invalid bar not sent: {bar}");
+            return;
+        }
+        (self.handler)(bar);
+    }
+}
+
+/// Provides a means of aggregating specified bars and sending them to a registered handler.
+pub trait BarAggregator {
+    /// The bar type for the aggregator.
+    fn bar_type(&self) -> BarType;
+
+    /// Whether the aggregator is awaiting a partial bar before aggregating.
+    fn await_partial(&self) -> bool;
+
+    /// Set whether the aggregator should await a partial bar before aggregating.
+    fn set_await_partial(&mut self, value: bool);
+
+    /// Update the aggregator with the given `price` and `size`.
+    fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos);
+
+    /// Set the initial values for a partially completed bar.
+    fn set_partial(&mut self, partial_bar: Bar);
+
+    /// Update the aggregator with the given quote.
+    fn handle_quote_tick(&mut self, quote: QuoteTick) {
+        if self.await_partial() {
+            return;
+        }
+
+        let price_type = self.bar_type().spec.price_type;
+        self.update(
+            quote.extract_price(price_type),
+            quote.extract_volume(price_type),
+            quote.ts_event,
+        );
+    }
+
+    /// Update the aggregator with the given trade.
+    fn handle_trade_tick(&mut self, trade: TradeTick) {
+        if self.await_partial() {
+            return;
+        }
+
+        self.update(trade.price, trade.size, trade.ts_event);
+    }
+}
+
+macro_rules! impl_bar_aggregator_core {
+    ($name:ident) => {
+        impl $name {
+            /// Returns a reference to the aggregators core.
+            #[must_use]
+            pub fn core(&self) -> &BarAggregatorCore {
+                &self.core
+            }
+        }
+    };
+}
+
+/// Provides a means of building tick bars from ticks.
+///
+/// When received tick count reaches the step threshold of the bar
+/// specification, then a bar is created and sent to the handler.
+#[derive(Debug)]
+pub struct TickBarAggregator {
+    core: BarAggregatorCore,
+}
+
+impl TickBarAggregator {
+    /// Creates a new [`TickBarAggregator`] instance.
+    pub fn new(
+        instrument: &InstrumentAny,
+        bar_type: BarType,
+        handler: BarHandler,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            core: BarAggregatorCore::new(instrument, bar_type, handler)?,
+        })
+    }
+}
+
+impl_bar_aggregator_core!(TickBarAggregator);
+
+impl BarAggregator for TickBarAggregator {
+    fn bar_type(&self) -> BarType {
+        self.core.bar_type
+    }
+
+    fn await_partial(&self) -> bool {
+        self.core.await_partial
+    }
+
+    fn set_await_partial(&mut self, value: bool) {
+        self.core.await_partial = value;
+    }
+
+    fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
+        self.core.apply_update(price, size, ts_event);
+
+        if self.core.builder.count() >= self.core.bar_type.spec.step {
+            self.core.build_now_and_send();
+        }
+    }
+
+    fn set_partial(&mut self, partial_bar: Bar) {
+        self.core.builder.set_partial(partial_bar);
+    }
+}
+
+/// Provides a means of building volume bars from ticks.
+///
+/// When received volume reaches the step threshold of the bar
+/// specification, then a bar is created and sent to the handler.
+#[derive(Debug)]
+pub struct VolumeBarAggregator {
+    core: BarAggregatorCore,
+}
+
+impl VolumeBarAggregator {
+    /// Creates a new [`VolumeBarAggregator`] instance.
+    pub fn new(
+        instrument: &InstrumentAny,
+        bar_type: BarType,
+        handler: BarHandler,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            core: BarAggregatorCore::new(instrument, bar_type, handler)?,
+        })
+    }
+}
+
+impl_bar_aggregator_core!(VolumeBarAggregator);
+
+impl BarAggregator for VolumeBarAggregator {
+    fn bar_type(&self) -> BarType {
+        self.core.bar_type
+    }
+
+    fn await_partial(&self) -> bool {
+        self.core.await_partial
+    }
+
+    fn set_await_partial(&mut self, value: bool) {
+        self.core.await_partial = value;
+    }
+
+    fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
+        let mut raw_size_update = size.raw;
+        let raw_step = (self.core.bar_type.spec.step as f64 * FIXED_SCALAR) as u64;
+
+        while raw_size_update > 0 {
+            let raw_volume = self.core.builder.volume().raw;
+            if raw_volume + raw_size_update < raw_step {
+                self.core.apply_update(
+                    price,
+                    Quantity::from_raw(raw_size_update, size.precision).unwrap(),
+                    ts_event,
+                );
+                break;
+            }
+
+            let raw_size_diff = raw_step - raw_volume;
+            self.core.apply_update(
+                price,
+                Quantity::from_raw(raw_size_diff, size.precision).unwrap(),
+                ts_event,
+            );
+
+            self.core.build_now_and_send();
+            raw_size_update -= raw_size_diff;
+        }
+    }
+
+    fn set_partial(&mut self, partial_bar: Bar) {
+        self.core.builder.set_partial(partial_bar);
+    }
+}
+
+/// Provides a means of building value bars from ticks.
+///
+/// When received value reaches the step threshold of the bar
+/// specification, then a bar is created and sent to the handler.
+#[derive(Debug)]
+pub struct ValueBarAggregator {
+    core: BarAggregatorCore,
+    cum_value: f64,
+}
+
+impl ValueBarAggregator {
+    /// Creates a new [`ValueBarAggregator`] instance.
+    pub fn new(
+        instrument: &InstrumentAny,
+        bar_type: BarType,
+        handler: BarHandler,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            core: BarAggregatorCore::new(instrument, bar_type, handler)?,
+            cum_value: 0.0,
+        })
+    }
+
+    /// Returns the cumulative value for the aggregator.
+    #[must_use]
+    pub fn get_cumulative_value(&self) -> f64 {
+        self.cum_value
+    }
+}
+
+impl_bar_aggregator_core!(ValueBarAggregator);
+
+impl BarAggregator for ValueBarAggregator {
+    fn bar_type(&self) -> BarType {
+        self.core.bar_type
+    }
+
+    fn await_partial(&self) -> bool {
+        self.core.await_partial
+    }
+
+    fn set_await_partial(&mut self, value: bool) {
+        self.core.await_partial = value;
+    }
+
+    fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
+        let mut size_update = size.as_f64();
+        let step = self.core.bar_type.spec.step as f64;
+
+        while size_update > 0.0 {
+            let value_update = price.as_f64() * size_update;
+            if self.cum_value + value_update < step {
+                self.cum_value += value_update;
+                self.core.apply_update(
+                    price,
+                    Quantity::new(size_update, size.precision).unwrap(),
+                    ts_event,
+                );
+                break;
+            }
+
+            let value_diff = step - self.cum_value;
+            let size_diff = size_update * (value_diff / value_update);
+            self.core.apply_update(
+                price,
+                Quantity::new(size_diff, size.precision).unwrap(),
+                ts_event,
+            );
+
+            self.core.build_now_and_send();
+            self.cum_value = 0.0;
+            size_update -= size_diff;
+        }
+    }
+
+    fn set_partial(&mut self, partial_bar: Bar) {
+        self.core.builder.set_partial(partial_bar);
+    }
+}
+
+/// Provides a means of building time bars from ticks with an internal timer.
+///
+/// When the time reaches the next time interval of the bar specification, then
+/// a bar is created and sent to the handler.
+///
+/// Interval boundaries are calendar aware in the local time of the configured time zone
+/// (see [`get_time_bar_start`]). `DAY`, `WEEK` and `MONTH` intervals have no fixed duration
+/// across daylight saving changes, so rather than a repeating timer a time alert is set for
+/// each bar close.
+#[derive(Debug)]
+pub struct TimeBarAggregator {
+    core: BarAggregatorCore,
+    /// The fixed interval for the aggregator (`None` for calendar intervals).
+    pub interval_ns: Option<u64>,
+    /// The UNIX timestamp (nanoseconds) of the next bar close.
+    pub next_close_ns: UnixNanos,
+    time_zone: Tz,
+    timer_name: String,
+    build_on_next_tick: bool,
+    stored_open_ns: UnixNanos,
+    stored_close_ns: UnixNanos,
+    build_with_no_updates: bool,
+    timestamp_on_close: bool,
+}
+
+impl TimeBarAggregator {
+    /// Creates a new [`TimeBarAggregator`] instance.
+    ///
+    /// The `time_zone` is the local time in which interval boundaries are aligned.
+    pub fn new(
+        instrument: &InstrumentAny,
+        bar_type: BarType,
+        handler: BarHandler,
+        build_with_no_updates: bool,
+        timestamp_on_close: bool,
+        time_zone: Tz,
+    ) -> anyhow::Result<Self> {
+        let interval_ns = if is_calendar_interval(&bar_type.spec) {
+            correctness::check_positive_u64(bar_type.spec.step as u64, "step")?;
+            None
+        } else {
+            Some(get_bar_interval_ns(&bar_type)?)
+        };
+
+        Ok(Self {
+            core: BarAggregatorCore::new(instrument, bar_type, handler)?,
+            interval_ns,
+            next_close_ns: UnixNanos::default(),
+            time_zone,
+            timer_name: bar_type.to_string(),
+            build_on_next_tick: false,
+            stored_open_ns: UnixNanos::default(),
+            stored_close_ns: UnixNanos::default(),
+            build_with_no_updates,
+            timestamp_on_close,
+        })
+    }
+
+    /// Returns the start time for the aggregators first bar, aligned to the interval.
+    pub fn get_start_time(&self, now: UnixNanos) -> anyhow::Result<UnixNanos> {
+        get_time_bar_start(&self.core.bar_type.spec, now, self.time_zone)
+    }
+
+    /// Start the aggregators timer on the given `clock`.
+    pub fn start(
+        &mut self,
+        clock: &mut dyn Clock,
+        now: UnixNanos,
+        callback: Option<EventHandler>,
+    ) -> anyhow::Result<()> {
+        let start_time_ns = self.get_start_time(now)?;
+        self.stored_open_ns = start_time_ns;
+
+        match self.interval_ns {
+            Some(interval_ns) => {
+                clock.set_timer_ns(&self.timer_name, interval_ns, start_time_ns, None, callback)?;
+                self.next_close_ns = clock.next_time_ns(&self.timer_name);
+            }
+            None => {
+                self.next_close_ns =
+                    get_time_bar_close(&self.core.bar_type.spec, start_time_ns, self.time_zone)?;
+                self.schedule_next_close(clock, callback)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets a time alert on the given `clock` for the next bar close, for calendar intervals
+    /// which have no repeating timer.
+    ///
+    /// Should be called after each [`TimeBarAggregator::build_bar`]; this is a no-op for
+    /// fixed intervals.
+    pub fn schedule_next_close(
+        &self,
+        clock: &mut dyn Clock,
+        callback: Option<EventHandler>,
+    ) -> anyhow::Result<()> {
+        if self.interval_ns.is_some() {
+            return Ok(()); // Repeating timer already set
+        }
+
+        clock.set_time_alert_ns(&self.timer_name, self.next_close_ns, callback)
+    }
+
+    /// Stop the aggregators timer on the given `clock`.
+    pub fn stop(&mut self, clock: &mut dyn Clock) {
+        clock.cancel_timer(&self.timer_name);
+    }
+
+    /// Build and send a bar for the interval closed by the given time `event`.
+    pub fn build_bar(&mut self, event: &TimeEvent) {
+        let close_ns = self.next_close_ns;
+
+        // The next close time is one interval ahead of this event
+        self.next_close_ns = match self.interval_ns {
+            Some(interval_ns) => event.ts_event + interval_ns,
+            None => get_time_bar_close(&self.core.bar_type.spec, event.ts_event, self.time_zone)
+                .unwrap_or_else(|e| {
+                    log::error!("Cannot compute next close for {}: {e}", self.core.bar_type);
+                    close_ns
+                }),
+        };
+
+        if !self.core.builder.initialized() {
+            // Set flag to build on next close with the stored close time
+            self.build_on_next_tick = true;
+            self.stored_close_ns = close_ns;
+            return;
+        }
+
+        if !self.build_with_no_updates && self.core.builder.count() == 0 {
+            return; // Do not build and emit bar
+        }
+
+        let ts_init = event.ts_event;
+        let ts_event = if self.timestamp_on_close {
+            ts_init
+        } else {
+            self.stored_open_ns
+        };
+
+        self.core.build_and_send(ts_event, ts_init);
+
+        // Close time becomes the next open time
+        self.stored_open_ns = event.ts_event;
+    }
+}
+
+impl_bar_aggregator_core!(TimeBarAggregator);
+
+impl BarAggregator for TimeBarAggregator {
+    fn bar_type(&self) -> BarType {
+        self.core.bar_type
+    }
+
+    fn await_partial(&self) -> bool {
+        self.core.await_partial
+    }
+
+    fn set_await_partial(&mut self, value: bool) {
+        self.core.await_partial = value;
+    }
+
+    fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
+        self.core.apply_update(price, size, ts_event);
+
+        if self.build_on_next_tick {
+            let ts_init = ts_event;
+            let ts_event = if self.timestamp_on_close {
+                self.stored_close_ns
+            } else {
+                self.stored_open_ns
+            };
+
+            self.core.build_and_send(ts_event, ts_init);
+            self.build_on_next_tick = false;
+            self.stored_close_ns = UnixNanos::default();
+        }
+    }
+
+    fn set_partial(&mut self, partial_bar: Bar) {
+        self.core.builder.set_partial(partial_bar);
+    }
+}
+
+/// Returns the fixed time interval (nanoseconds) for the given time `bar_type`.
+pub fn get_bar_interval_ns(bar_type: &BarType) -> anyhow::Result<u64> {
+    let step = bar_type.spec.step as u64;
+    correctness::check_positive_u64(step, "step")?;
+
+    Ok(fixed_interval_ns(&bar_type.spec)? as u64)
+}
+
+/// Returns the open time of the time bar interval containing `now`, for the given `spec`.
+///
+/// Interval boundaries are aligned in the local time of the given `time_zone`, so that
+/// daily and longer bars follow the venue calendar (including daylight saving changes):
+/// `DAY` bars open at midnight, `WEEK` bars at midnight on Monday, and `MONTH` bars at
+/// midnight on the first day of the month. Steps greater than one are aligned to multiples
+/// of the step since the epoch (for `MONTH` since the year zero, so that e.g. 3-MONTH bars
+/// align to quarters). Shorter intervals are aligned with the UTC offset in effect at `now`.
+pub fn get_time_bar_start(
+    spec: &BarSpecification,
+    now: UnixNanos,
+    time_zone: Tz,
+) -> anyhow::Result<UnixNanos> {
+    let step = spec.step as i64;
+    correctness::check_positive_i64(step, "step")?;
+
+    let local = to_local(now, time_zone);
+    match spec.aggregation {
+        BarAggregation::Month => {
+            let month_index = i64::from(local.year()) * 12 + i64::from(local.month0());
+            let start_index = month_index - month_index.rem_euclid(step);
+            to_utc(month_start(start_index)?, time_zone)
+        }
+        BarAggregation::Day | BarAggregation::Week => {
+            let (interval_days, origin_days) = calendar_interval_days(spec)?;
+            let days = local.date().signed_duration_since(epoch_date()).num_days();
+            let start_days = days - (days - origin_days).rem_euclid(interval_days);
+            to_utc(date_start(start_days)?, time_zone)
+        }
+        _ => {
+            let interval_ns = fixed_interval_ns(spec)?;
+            let offset_ns = utc_offset_ns(now, time_zone);
+            let local_ns = now.as_u64() as i64 + offset_ns;
+            to_unix_nanos(local_ns - local_ns.rem_euclid(interval_ns) - offset_ns)
+        }
+    }
+}
+
+/// Returns the close time of the time bar interval which opened at `open`, for the given `spec`.
+///
+/// See [`get_time_bar_start`] for how interval boundaries are aligned.
+pub fn get_time_bar_close(
+    spec: &BarSpecification,
+    open: UnixNanos,
+    time_zone: Tz,
+) -> anyhow::Result<UnixNanos> {
+    let local = to_local(open, time_zone);
+    match spec.aggregation {
+        BarAggregation::Month => {
+            let month_index = i64::from(local.year()) * 12 + i64::from(local.month0());
+            to_utc(month_start(month_index + spec.step as i64)?, time_zone)
+        }
+        BarAggregation::Day | BarAggregation::Week => {
+            let (interval_days, _) = calendar_interval_days(spec)?;
+            let days = local.date().signed_duration_since(epoch_date()).num_days();
+            to_utc(date_start(days + interval_days)?, time_zone)
+        }
+        _ => Ok(open + fixed_interval_ns(spec)? as u64),
+    }
+}
+
+// The UNIX epoch was a Thursday, so the first Monday was 1970-01-05
+const MONDAY_ORIGIN_DAYS: i64 = 4;
+
+fn is_calendar_interval(spec: &BarSpecification) -> bool {
+    matches!(
+        spec.aggregation,
+        BarAggregation::Day | BarAggregation::Week | BarAggregation::Month
+    )
+}
+
+fn fixed_interval_ns(spec: &BarSpecification) -> anyhow::Result<i64> {
+    spec.timedelta()?
+        .num_nanoseconds()
+        .ok_or_else(|| anyhow::anyhow!("Interval for {spec} overflowed nanoseconds"))
+}
+
+/// Returns the interval and alignment origin (days since the epoch) for `DAY` or `WEEK` bars.
+fn calendar_interval_days(spec: &BarSpecification) -> anyhow::Result<(i64, i64)> {
+    let step = i64::try_from(spec.step)?;
+    match spec.aggregation {
+        BarAggregation::Day => Ok((step, 0)),
+        _ => step
+            .checked_mul(7)
+            .map(|days| (days, MONDAY_ORIGIN_DAYS))
+            .ok_or_else(|| anyhow::anyhow!("Interval for {spec} overflowed days")),
+    }
+}
+
+fn epoch_date() -> NaiveDate {
+    DateTime::UNIX_EPOCH.date_naive()
+}
+
+fn date_start(days: i64) -> anyhow::Result<NaiveDateTime> {
+    TimeDelta::try_days(days)
+        .and_then(|delta| epoch_date().checked_add_signed(delta))
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .ok_or_else(|| anyhow::anyhow!("Date out of range for {days} days since the epoch"))
+}
+
+fn month_start(month_index: i64) -> anyhow::Result<NaiveDateTime> {
+    let year = i32::try_from(month_index.div_euclid(12))?;
+    let month = month_index.rem_euclid(12) as u32 + 1;
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .ok_or_else(|| anyhow::anyhow!("Month start out of range for index {month_index}"))
+}
+
+fn to_local(ts: UnixNanos, time_zone: Tz) -> NaiveDateTime {
+    DateTime::from_timestamp_nanos(ts.as_u64() as i64)
+        .with_timezone(&time_zone)
+        .naive_local()
+}
+
+fn utc_offset_ns(ts: UnixNanos, time_zone: Tz) -> i64 {
+    let utc = DateTime::from_timestamp_nanos(ts.as_u64() as i64).naive_utc();
+    let offset_secs = time_zone
+        .offset_from_utc_datetime(&utc)
+        .fix()
+        .local_minus_utc();
+    i64::from(offset_secs) * NANOSECONDS_IN_SECOND as i64
+}
+
+/// Returns the UNIX timestamp for the `local` time in the given `time_zone`.
+///
+/// An ambiguous local time (clocks turned back) resolves to the earliest instant, and a
+/// local time skipped by a daylight saving change resolves with the offset before the change.
+fn to_utc(local: NaiveDateTime, time_zone: Tz) -> anyhow::Result<UnixNanos> {
+    let utc = match time_zone.from_local_datetime(&local) {
+        LocalResult::Single(datetime) | LocalResult::Ambiguous(datetime, _) => datetime.naive_utc(),
+        LocalResult::None => {
+            let before = local
+                .checked_sub_signed(TimeDelta::days(1))
+                .ok_or_else(|| anyhow::anyhow!("Local time {local} out of range"))?;
+            let offset = time_zone.offset_from_utc_datetime(&before).fix();
+            local - offset
+        }
+    };
+    let value = utc
+        .and_utc()
+        .timestamp_nanos_opt()
+        .ok_or_else(|| anyhow::anyhow!("Time bar boundary {utc} out of range"))?;
+    to_unix_nanos(value)
+}
+
+fn to_unix_nanos(value: i64) -> anyhow::Result<UnixNanos> {
+    let value = u64::try_from(value)
+        .map_err(|_| anyhow::anyhow!("Time bar boundary {value} was before the UNIX epoch"))?;
+    Ok(value.into())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::instruments::{currency_pair::CurrencyPair, stubs::*};
+    use rstest::rstest;
+    use ustr::Ustr;
+
+    use super::*;
+
+    fn bar_handler() -> (BarHandler, Rc<RefCell<Vec<Bar>>>) {
+        let bars = Rc::new(RefCell::new(Vec::new()));
+        let bars_clone = bars.clone();
+        let handler: BarHandler = Box::new(move |bar| bars_clone.borrow_mut().push(bar));
+        (handler, bars)
+    }
+
+    fn utc() -> Tz {
+        Tz::UTC
+    }
+
+    fn unix_nanos(datetime: &str) -> UnixNanos {
+        let datetime = DateTime::parse_from_rfc3339(datetime).unwrap();
+        UnixNanos::from(datetime.timestamp_nanos_opt().unwrap() as u64)
+    }
+
+    fn trade(price: &str, size: i64, ts_event: u64) -> TradeTick {
+        TradeTick {
+            price: Price::from(price),
+            size: Quantity::from(size),
+            ts_event: ts_event.into(),
+            ts_init: ts_event.into(),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn test_aggregator_instrument_mismatch(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let bar_type = BarType::from("ETHUSDT.BINANCE-1-TICK-LAST-INTERNAL");
+        let (handler, _) = bar_handler();
+
+        assert!(TickBarAggregator::new(&instrument, bar_type, handler).is_err());
+    }
+
+    #[rstest]
+    fn test_tick_bar_aggregator(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let bar_type = BarType::from("AUD/USD.SIM-3-TICK-LAST-INTERNAL");
+        let (handler, bars) = bar_handler();
+        let mut aggregator = TickBarAggregator::new(&instrument, bar_type, handler).unwrap();
+
+        aggregator.handle_trade_tick(trade("1.00001", 1, 1));
+        aggregator.handle_trade_tick(trade("1.00002", 1, 2));
+        assert!(bars.borrow().is_empty());
+
+        aggregator.handle_trade_tick(trade("1.00003", 1, 3));
+
+        let bars = bars.borrow();
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].open, Price::from("1.00001"));
+        assert_eq!(bars[0].close, Price::from("1.00003"));
+        assert_eq!(bars[0].volume, Quantity::from(3));
+    }
+
+    #[rstest]
+    fn test_tick_bar_aggregator_with_quotes(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let bar_type = BarType::from("AUD/USD.SIM-2-TICK-MID-INTERNAL");
+        let (handler, bars) = bar_handler();
+        let mut aggregator = TickBarAggregator::new(&instrument, bar_type, handler).unwrap();
+
+        aggregator.handle_quote_tick(QuoteTick {
+            instrument_id: instrument.id(),
+            ..Default::default()
+        });
+        aggregator.handle_quote_tick(QuoteTick {
+            instrument_id: instrument.id(),
+            bid_price: Price::from("1.00002"),
+            ask_price: Price::from("1.00004"),
+            ..Default::default()
+        });
+
+        let bars = bars.borrow();
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].high, Price::from("1.00003"));
+        assert_eq!(bars[0].volume, Quantity::from(200_000));
+    }
+
+    #[rstest]
+    fn test_aggregator_awaiting_partial_ignores_ticks(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let bar_type = BarType::from("AUD/USD.SIM-1-TICK-LAST-INTERNAL");
+        let (handler, bars) = bar_handler();
+        let mut aggregator = TickBarAggregator::new(&instrument, bar_type, handler).unwrap();
+        aggregator.set_await_partial(true);
+
+        aggregator.handle_trade_tick(trade("1.00001", 1, 1));
+
+        assert!(bars.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_aggregator_does_not_send_invalid_bar(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let bar_type = BarType::from("AUD/USD.SIM-1-TICK-LAST-INTERNAL");
+        let (handler, bars) = bar_handler();
+        let mut aggregator = TickBarAggregator::new(&instrument, bar_type, handler).unwrap();
+        let partial_bar = Bar::new(
+            bar_type,
+            Price::from("1.00005"), // Above high
+            Price::from("1.00004"),
+            Price::from("1.00000"),
+            Price::from("1.00003"),
+            Quantity::from(1),
+            1.into(),
+            1.into(),
+        );
+        aggregator.set_partial(partial_bar);
+
+        aggregator.handle_trade_tick(trade("1.00002", 1, 2));
+
+        assert!(bars.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_volume_bar_aggregator_splits_large_update(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let bar_type = BarType::from("AUD/USD.SIM-10-VOLUME-LAST-INTERNAL");
+        let (handler, bars) = bar_handler();
+        let mut aggregator = VolumeBarAggregator::new(&instrument, bar_type, handler).unwrap();
+
+        aggregator.handle_trade_tick(trade("1.00001", 3, 1));
+        aggregator.handle_trade_tick(trade("1.00002", 25, 2));
+
+        let bars = bars.borrow();
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].volume, Quantity::from(10));
+        assert_eq!(bars[1].volume, Quantity::from(10));
+        assert_eq!(aggregator.core().builder().count(), 1);
+    }
+
+    #[rstest]
+    fn test_value_bar_aggregator(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let bar_type = BarType::from("AUD/USD.SIM-100-VALUE-LAST-INTERNAL");
+        let (handler, bars) = bar_handler();
+        let mut aggregator = ValueBarAggregator::new(&instrument, bar_type, handler).unwrap();
+
+        aggregator.handle_trade_tick(trade("2.00000", 20, 1));
+        assert!(bars.borrow().is_empty());
+        assert_eq!(aggregator.get_cumulative_value(), 40.0);
+
+        aggregator.handle_trade_tick(trade("2.00000", 40, 2));
+
+        let bars = bars.borrow();
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].volume, Quantity::from(50));
+        assert_eq!(aggregator.get_cumulative_value(), 20.0);
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_build_bar(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let bar_type = BarType::from("AUD/USD.SIM-1-MINUTE-LAST-INTERNAL");
+        let (handler, bars) = bar_handler();
+        let mut aggregator =
+            TimeBarAggregator::new(&instrument, bar_type, handler, false, true, utc()).unwrap();
+
+        assert_eq!(aggregator.interval_ns, Some(60 * NANOSECONDS_IN_SECOND));
+
+        aggregator.handle_trade_tick(trade("1.00001", 1, 1));
+        aggregator.handle_trade_tick(trade("1.00003", 1, 2));
+
+        let ts = UnixNanos::from(60 * NANOSECONDS_IN_SECOND);
+        let event = TimeEvent::new(Ustr::from(&bar_type.to_string()), UUID4::new(), ts, ts);
+        aggregator.build_bar(&event);
+        // No updates since the last bar, so no bar is built
+        aggregator.build_bar(&event);
+
+        let bars = bars.borrow();
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].ts_event, ts);
+        assert_eq!(bars[0].high, Price::from("1.00003"));
+        assert_eq!(aggregator.next_close_ns, ts + 60 * NANOSECONDS_IN_SECOND);
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_start_time(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let bar_type = BarType::from("AUD/USD.SIM-1-MINUTE-LAST-INTERNAL");
+        let (handler, _) = bar_handler();
+        let aggregator =
+            TimeBarAggregator::new(&instrument, bar_type, handler, false, true, utc()).unwrap();
+
+        let now = UnixNanos::from(90 * NANOSECONDS_IN_SECOND);
+
+        assert_eq!(
+            aggregator.get_start_time(now).unwrap(),
+            UnixNanos::from(60 * NANOSECONDS_IN_SECOND)
+        );
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_month_build_bar(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let bar_type = BarType::from("AUD/USD.SIM-1-MONTH-LAST-INTERNAL");
+        let (handler, bars) = bar_handler();
+        let mut aggregator =
+            TimeBarAggregator::new(&instrument, bar_type, handler, false, true, utc()).unwrap();
+
+        assert_eq!(aggregator.interval_ns, None);
+
+        aggregator.handle_trade_tick(trade("1.00001", 1, 1));
+        let ts = unix_nanos("2024-02-01T00:00:00Z");
+        let event = TimeEvent::new(Ustr::from(&bar_type.to_string()), UUID4::new(), ts, ts);
+        aggregator.build_bar(&event);
+
+        assert_eq!(bars.borrow().len(), 1);
+        assert_eq!(aggregator.next_close_ns, unix_nanos("2024-03-01T00:00:00Z"));
+    }
+
+    #[rstest]
+    #[case(
+        "1-HOUR",
+        "2024-03-14T12:34:56Z",
+        "2024-03-14T12:00:00Z",
+        "2024-03-14T13:00:00Z"
+    )]
+    #[case(
+        "1-DAY",
+        "2024-03-14T12:34:56Z",
+        "2024-03-14T00:00:00Z",
+        "2024-03-15T00:00:00Z"
+    )]
+    #[case(
+        "1-WEEK",
+        "2024-03-14T12:34:56Z",
+        "2024-03-11T00:00:00Z",
+        "2024-03-18T00:00:00Z"
+    )]
+    #[case(
+        "1-MONTH",
+        "2024-03-14T12:34:56Z",
+        "2024-03-01T00:00:00Z",
+        "2024-04-01T00:00:00Z"
+    )]
+    #[case(
+        "1-MONTH",
+        "2024-12-31T23:59:59Z",
+        "2024-12-01T00:00:00Z",
+        "2025-01-01T00:00:00Z"
+    )]
+    #[case(
+        "3-MONTH",
+        "2024-05-20T00:00:00Z",
+        "2024-04-01T00:00:00Z",
+        "2024-07-01T00:00:00Z"
+    )]
+    #[case(
+        "12-MONTH",
+        "2024-05-20T00:00:00Z",
+        "2024-01-01T00:00:00Z",
+        "2025-01-01T00:00:00Z"
+    )]
+    fn test_time_bar_boundaries(
+        #[case] spec: &str,
+        #[case] now: &str,
+        #[case] expected_start: &str,
+        #[case] expected_close: &str,
+    ) {
+        let bar_type = BarType::from(format!("AUD/USD.SIM-{spec}-LAST-INTERNAL").as_str());
+
+        let start = get_time_bar_start(&bar_type.spec, unix_nanos(now), utc()).unwrap();
+        let close = get_time_bar_close(&bar_type.spec, start, utc()).unwrap();
+
+        assert_eq!(start, unix_nanos(expected_start));
+        assert_eq!(close, unix_nanos(expected_close));
+    }
+
+    #[rstest]
+    #[case(
+        "1-DAY",
+        "2024-03-06T03:00:00Z", // 22:00 on the 5th local time
+        "2024-03-05T00:00:00-05:00",
+        "2024-03-06T00:00:00-05:00"
+    )]
+    #[case(
+        "1-DAY",
+        "2024-03-10T12:00:00Z", // Clocks go forward at 02:00 local time
+        "2024-03-10T00:00:00-05:00",
+        "2024-03-11T00:00:00-04:00"
+    )]
+    #[case(
+        "1-WEEK",
+        "2024-10-31T12:00:00Z", // Clocks go back on Sunday the 3rd
+        "2024-10-28T00:00:00-04:00",
+        "2024-11-04T00:00:00-05:00"
+    )]
+    #[case(
+        "1-MONTH",
+        "2024-03-31T12:00:00Z",
+        "2024-03-01T00:00:00-05:00",
+        "2024-04-01T00:00:00-04:00"
+    )]
+    #[case(
+        "1-HOUR",
+        "2024-07-01T12:30:00Z",
+        "2024-07-01T08:00:00-04:00",
+        "2024-07-01T09:00:00-04:00"
+    )]
+    fn test_time_bar_boundaries_with_time_zone(
+        #[case] spec: &str,
+        #[case] now: &str,
+        #[case] expected_start: &str,
+        #[case] expected_close: &str,
+    ) {
+        let bar_type = BarType::from(format!("AUD/USD.SIM-{spec}-LAST-INTERNAL").as_str());
+        let time_zone = chrono_tz::America::New_York;
+
+        let start = get_time_bar_start(&bar_type.spec, unix_nanos(now), time_zone).unwrap();
+        let close = get_time_bar_close(&bar_type.spec, start, time_zone).unwrap();
+
+        assert_eq!(start, unix_nanos(expected_start));
+        assert_eq!(close, unix_nanos(expected_close));
+    }
+
+    #[rstest]
+    fn test_time_bar_boundaries_with_huge_step() {
+        let bar_type =
+            BarType::from(format!("AUD/USD.SIM-{}-WEEK-LAST-INTERNAL", u64::MAX).as_str());
+
+        assert!(get_time_bar_start(&bar_type.spec, UnixNanos::from(1), utc()).is_err());
+        assert!(get_bar_interval_ns(&bar_type).is_err());
+    }
+
+    #[rstest]
+    fn test_get_bar_interval_ns_week() {
+        let bar_type = BarType::from("AUD/USD.SIM-1-WEEK-LAST-INTERNAL");
+
+        assert_eq!(
+            get_bar_interval_ns(&bar_type).unwrap(),
+            7 * 86_400 * NANOSECONDS_IN_SECOND
+        );
+    }
+
+    #[rstest]
+    fn test_get_bar_interval_ns_month_has_no_fixed_interval() {
+        let bar_type = BarType::from("AUD/USD.SIM-1-MONTH-LAST-INTERNAL");
+
+        assert!(get_bar_interval_ns(&bar_type).is_err());
+    }
+
+    #[rstest]
+    fn test_get_bar_interval_ns_not_time_based() {
+        let bar_type = BarType::from("AUD/USD.SIM-1-TICK-LAST-INTERNAL");
+
+        assert!(get_bar_interval_ns(&bar_type).is_err());
+    }
+}
@@ -25,10 +25,12 @@ use nautilus_core::correctness::{check_key_not_in_map, check_slice_not_empty, ch
 use nautilus_model::{
     data::{
         bar::{Bar, BarType},
+        custom::{CustomData, DataType},
         quote::QuoteTick,
         trade::TradeTick,
     },
     enums::{AggregationSource, OmsType, OrderSide, PositionSide, PriceType, TriggerType},
+    events::account::state::AccountState,
     identifiers::{
         account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
         component_id::ComponentId, exec_algorithm_id::ExecAlgorithmId, instrument_id::InstrumentId,
@@ -44,7 +46,11 @@ use nautilus_model::{
 use ustr::Ustr;
 
 use super::database::CacheDatabaseAdapter;
-use crate::{enums::SerializationEncoding, interface::account::Account};
+use crate::{
+    enums::SerializationEncoding,
+    interface::account::Account,
+    memory::{memory_gauge, MemorySubsystem},
+};
 
 /// Configuration for `Cache` instances.
 pub struct CacheConfig {
@@ -177,6 +183,7 @@ pub struct Cache {
     trades: HashMap<InstrumentId, VecDeque<TradeTick>>,
     books: HashMap<InstrumentId, OrderBook>,
     bars: HashMap<BarType, VecDeque<Bar>>,
+    custom_data: HashMap<DataType, VecDeque<CustomData>>,
     currencies: HashMap<Ustr, Currency>,
     instruments: HashMap<InstrumentId, InstrumentAny>,
     synthetics: HashMap<InstrumentId, SyntheticInstrument>,
@@ -238,6 +245,7 @@ impl Cache {
             trades: HashMap::new(),
             books: HashMap::new(),
             bars: HashMap::new(),
+            custom_data: HashMap::new(),
             currencies: HashMap::new(),
             instruments: HashMap::new(),
             synthetics: HashMap::new(),
@@ -918,6 +926,7 @@ impl Cache {
         self.trades.clear();
         self.books.clear();
         self.bars.clear();
+        self.custom_data.clear();
         self.instruments.clear();
         self.synthetics.clear();
         self.accounts.clear();
@@ -927,6 +936,7 @@ impl Cache {
         self.position_snapshots.clear();
 
         self.clear_index();
+        self.record_memory_usage();
 
         info!("Reset cache");
     }
@@ -949,6 +959,33 @@ impl Cache {
         Ok(())
     }
 
+    /// Returns the approximate memory (bytes) held by the cached order books.
+    #[must_use]
+    pub fn order_books_size_bytes(&self) -> usize {
+        self.books.values().map(OrderBook::approx_size_bytes).sum()
+    }
+
+    /// Returns the approximate memory (bytes) held by the cached quote, trade and bar buffers.
+    #[must_use]
+    pub fn data_buffers_size_bytes(&self) -> usize {
+        let quotes: usize = self.quotes.values().map(VecDeque::capacity).sum();
+        let trades: usize = self.trades.values().map(VecDeque::capacity).sum();
+        let bars: usize = self.bars.values().map(VecDeque::capacity).sum();
+
+        quotes * std::mem::size_of::<QuoteTick>()
+            + trades * std::mem::size_of::<TradeTick>()
+            + bars * std::mem::size_of::<Bar>()
+    }
+
+    /// Records the approximate memory held by the cache with the memory accounting gauges.
+    ///
+    /// This is called when order books or batches of data are added, and periodically by the
+    /// live node to capture books mutated in place and single tick updates.
+    pub fn record_memory_usage(&self) {
+        memory_gauge(MemorySubsystem::OrderBooks).set(self.order_books_size_bytes());
+        memory_gauge(MemorySubsystem::DataBuffers).set(self.data_buffers_size_bytes());
+    }
+
     /// Adds a general object `value` (as bytes) to the cache at the given `key`.
     ///
     /// The cache is agnostic to what the bytes actually represent (and how it may be serialized),
@@ -970,6 +1007,7 @@ impl Cache {
     pub fn add_order_book(&mut self, book: OrderBook) -> anyhow::Result<()> {
         debug!("Adding `OrderBook` {}", book.instrument_id);
         self.books.insert(book.instrument_id, book);
+        self.record_memory_usage();
         Ok(())
     }
 
@@ -998,6 +1036,7 @@ impl Cache {
         for quote in quotes {
             quotes_deque.push_front(*quote);
         }
+        self.record_memory_usage();
         Ok(())
     }
 
@@ -1026,6 +1065,7 @@ impl Cache {
         for trade in trades {
             trades_deque.push_front(*trade);
         }
+        self.record_memory_usage();
         Ok(())
     }
 
@@ -1054,6 +1094,18 @@ impl Cache {
         for bar in bars {
             bars_deque.push_front(*bar);
         }
+        self.record_memory_usage();
+        Ok(())
+    }
+
+    /// Adds the given `custom` data to the cache.
+    pub fn add_custom_data(&mut self, custom: CustomData) -> anyhow::Result<()> {
+        debug!("Adding `CustomData` {}", custom.data_type);
+        let custom_deque = self
+            .custom_data
+            .entry(custom.data_type.clone())
+            .or_insert_with(|| VecDeque::with_capacity(self.config.tick_capacity));
+        custom_deque.push_front(custom);
         Ok(())
     }
 
@@ -1363,6 +1415,22 @@ impl Cache {
         Ok(())
     }
 
+    /// Applies the given account `event` to its cached account, then updates the account
+    /// in the database (if configured).
+    pub fn apply_account_state(&mut self, event: AccountState) -> anyhow::Result<()> {
+        let account_id = event.account_id;
+        let account = self
+            .accounts
+            .get_mut(&account_id)
+            .ok_or_else(|| anyhow::anyhow!("Account {account_id} not found in cache"))?;
+        account.apply(event);
+
+        if let Some(database) = &mut self.database {
+            database.update_account(account.as_ref())?;
+        }
+        Ok(())
+    }
+
     /// Updates the given `order` in the cache.
     pub fn update_order(&mut self, order: &OrderAny) -> anyhow::Result<()> {
         let client_order_id = order.client_order_id();
@@ -1410,6 +1478,7 @@ impl Cache {
             // }
         }
 
+        self.orders.insert(client_order_id, order.clone());
         Ok(())
     }
 
@@ -2306,6 +2375,14 @@ impl Cache {
             .map(|trades| trades.iter().copied().collect())
     }
 
+    /// Gets all custom data for the given `data_type`.
+    #[must_use]
+    pub fn custom_data(&self, data_type: &DataType) -> Option<Vec<CustomData>> {
+        self.custom_data
+            .get(data_type)
+            .map(|custom| custom.iter().cloned().collect())
+    }
+
     /// Gets all bars for the given `bar_type`.
     #[must_use]
     pub fn bars(&self, bar_type: &BarType) -> Option<Vec<Bar>> {
@@ -2477,6 +2554,19 @@ impl Cache {
             .map(std::convert::AsRef::as_ref)
     }
 
+    /// Returns a mutable reference to the account for the given `account_id` (if found).
+    pub fn account_mut(&mut self, account_id: &AccountId) -> Option<&mut (dyn Account + 'static)> {
+        self.accounts
+            .get_mut(account_id)
+            .map(std::convert::AsMut::as_mut)
+    }
+
+    /// Returns the IDs of all cached accounts.
+    #[must_use]
+    pub fn account_ids(&self) -> Vec<AccountId> {
+        self.accounts.keys().copied().collect()
+    }
+
     /// Returns a reference to the account for the given `venue` (if found).
     #[must_use]
     pub fn account_for_venue(&self, venue: &Venue) -> Option<&dyn Account> {
@@ -2509,7 +2599,10 @@ impl Cache {
 #[cfg(test)]
 mod tests {
     use nautilus_model::{
-        data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+        data::{
+            bar::Bar, custom::CustomData, quote::QuoteTick, stubs::stub_custom_data,
+            trade::TradeTick,
+        },
         enums::{OrderSide, OrderStatus},
         events::order::{
             accepted::OrderAccepted, rejected::OrderRejected, submitted::OrderSubmitted,
@@ -2526,6 +2619,7 @@ mod tests {
     use rstest::*;
 
     use super::Cache;
+    use crate::memory::{memory_gauge, MemorySubsystem};
 
     #[fixture]
     fn cache() -> Cache {
@@ -2924,6 +3018,24 @@ mod tests {
         assert_eq!(result, Some(&quote));
     }
 
+    #[rstest]
+    fn test_data_buffers_size_bytes(mut cache: Cache) {
+        assert_eq!(cache.data_buffers_size_bytes(), 0);
+
+        cache.add_quote(QuoteTick::default()).unwrap();
+
+        assert!(cache.data_buffers_size_bytes() >= std::mem::size_of::<QuoteTick>());
+    }
+
+    #[rstest]
+    fn test_add_quotes_records_memory_usage(mut cache: Cache) {
+        cache.add_quotes(&[QuoteTick::default()]).unwrap();
+
+        let gauge = memory_gauge(MemorySubsystem::DataBuffers);
+        assert!(gauge.high_water_mark() >= cache.data_buffers_size_bytes());
+        assert!(gauge.high_water_mark() > 0);
+    }
+
     #[rstest]
     fn test_quote_ticks_when_empty(cache: Cache, audusd_sim: CurrencyPair) {
         let result = cache.quote_ticks(&audusd_sim.id);
@@ -2974,6 +3086,19 @@ mod tests {
         assert_eq!(result, Some(trades));
     }
 
+    #[rstest]
+    fn test_custom_data_when_empty(cache: Cache, stub_custom_data: CustomData) {
+        let result = cache.custom_data(&stub_custom_data.data_type);
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    fn test_custom_data_when_some(mut cache: Cache, stub_custom_data: CustomData) {
+        cache.add_custom_data(stub_custom_data.clone()).unwrap();
+        let result = cache.custom_data(&stub_custom_data.data_type);
+        assert_eq!(result, Some(vec![stub_custom_data]));
+    }
+
     #[rstest]
     fn test_bar_when_empty(cache: Cache) {
         let bar = Bar::default();
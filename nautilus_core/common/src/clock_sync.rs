@@ -0,0 +1,295 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Clock synchronization from externally measured clock offsets.
+//!
+//! Offsets measured by a synchronization daemon (e.g. `ptp4l` or `chronyd`) are applied to
+//! the real-time `AtomicTime`, so that `ts_init` timestamps are corrected for clock drift.
+
+use std::fmt::{Display, Formatter};
+
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+/// A source of externally measured clock offsets.
+///
+/// The offset is the correction (nanoseconds) to add to the local system time to obtain the
+/// reference time, i.e. a negative offset when the local clock is fast.
+pub trait ClockSyncSource {
+    /// The name of the source, e.g. `PTP` or `NTP`.
+    fn name(&self) -> Ustr;
+
+    /// Returns the latest measured offset (nanoseconds), if a new measurement is available.
+    fn poll_offset_ns(&mut self) -> anyhow::Result<Option<i64>>;
+}
+
+/// Represents a change in the offset applied to the clock, for auditing.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClockOffsetEvent {
+    /// The name of the source which measured the offset.
+    pub source: Ustr,
+    /// The offset (nanoseconds) applied prior to the change.
+    pub previous_offset_ns: i64,
+    /// The offset (nanoseconds) applied from the change.
+    pub offset_ns: i64,
+    /// The UNIX timestamp (nanoseconds) when the offset was applied.
+    pub ts_event: UnixNanos,
+}
+
+impl Display for ClockOffsetEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(source={}, previous_offset_ns={}, offset_ns={}, ts_event={})",
+            stringify!(ClockOffsetEvent),
+            self.source,
+            self.previous_offset_ns,
+            self.offset_ns,
+            self.ts_event,
+        )
+    }
+}
+
+pub type ClockOffsetHandler = Box<dyn FnMut(ClockOffsetEvent)>;
+
+/// Provides a means of applying offsets from a [`ClockSyncSource`] to an [`AtomicTime`].
+pub struct ClockSynchronizer {
+    clock: &'static AtomicTime,
+    source: Box<dyn ClockSyncSource>,
+    max_abs_offset_ns: u64,
+    min_change_ns: u64,
+    handler: Option<ClockOffsetHandler>,
+}
+
+impl ClockSynchronizer {
+    /// Creates a new [`ClockSynchronizer`] instance.
+    ///
+    /// Offsets with a magnitude greater than `max_abs_offset_ns` are rejected as implausible,
+    /// and changes smaller than `min_change_ns` are ignored to avoid churn from noise.
+    /// The optional `handler` receives a [`ClockOffsetEvent`] for each applied change.
+    pub fn new(
+        clock: &'static AtomicTime,
+        source: Box<dyn ClockSyncSource>,
+        max_abs_offset_ns: u64,
+        min_change_ns: u64,
+        handler: Option<ClockOffsetHandler>,
+    ) -> Self {
+        Self {
+            clock,
+            source,
+            max_abs_offset_ns,
+            min_change_ns,
+            handler,
+        }
+    }
+
+    /// Returns the offset (nanoseconds) currently applied to the clock.
+    #[must_use]
+    pub fn offset_ns(&self) -> i64 {
+        self.clock.offset_ns()
+    }
+
+    /// Polls the source and applies any new offset measurement to the clock.
+    ///
+    /// Returns the event for the applied change, if any.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the source fails, or the measured offset exceeds
+    /// the maximum absolute offset (in which case the applied offset is unchanged).
+    pub fn update(&mut self) -> anyhow::Result<Option<ClockOffsetEvent>> {
+        let Some(offset_ns) = self.source.poll_offset_ns()? else {
+            return Ok(None); // No new measurement
+        };
+
+        if offset_ns.unsigned_abs() > self.max_abs_offset_ns {
+            anyhow::bail!(
+                "Clock offset {offset_ns}ns from {} exceeded maximum {}ns",
+                self.source.name(),
+                self.max_abs_offset_ns,
+            );
+        }
+
+        let previous_offset_ns = self.clock.offset_ns();
+        if offset_ns.abs_diff(previous_offset_ns) < self.min_change_ns {
+            return Ok(None);
+        }
+
+        self.clock.set_offset_ns(offset_ns);
+
+        let event = ClockOffsetEvent {
+            source: self.source.name(),
+            previous_offset_ns,
+            offset_ns,
+            ts_event: self.clock.get_time_ns(),
+        };
+
+        if let Some(handler) = &mut self.handler {
+            handler(event.clone());
+        }
+
+        Ok(Some(event))
+    }
+}
+
+/// Parses the clock offset (nanoseconds) from the output of `chronyc tracking`.
+///
+/// Uses the `System time` line, e.g. `System time : 0.000001234 seconds fast of NTP time`.
+pub fn parse_chrony_tracking(output: &str) -> anyhow::Result<i64> {
+    let line = output
+        .lines()
+        .find(|line| line.trim_start().starts_with("System time"))
+        .ok_or_else(|| anyhow::anyhow!("Missing 'System time' in chrony tracking output"))?;
+
+    let value = line
+        .split(':')
+        .nth(1)
+        .ok_or_else(|| anyhow::anyhow!("Invalid chrony 'System time' line: {line}"))?;
+    let mut parts = value.split_whitespace();
+    let seconds: f64 = parts
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("Invalid chrony 'System time' line: {line}"))?
+        .parse()?;
+    let offset_ns = (seconds * 1_000_000_000.0).round() as i64;
+
+    // The local clock being fast requires a negative correction
+    match parts.nth(1) {
+        Some("fast") => Ok(-offset_ns),
+        Some("slow") => Ok(offset_ns),
+        _ => anyhow::bail!("Invalid chrony 'System time' line: {line}"),
+    }
+}
+
+/// Parses the clock offset (nanoseconds) from the output of
+/// `pmc -u -b 0 'GET TIME_STATUS_NP'` for a `ptp4l` daemon.
+///
+/// The `master_offset` is the local clock minus the grandmaster clock.
+pub fn parse_pmc_time_status(output: &str) -> anyhow::Result<i64> {
+    let master_offset = output
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("master_offset"))
+        .ok_or_else(|| anyhow::anyhow!("Missing 'master_offset' in pmc output"))?;
+
+    let master_offset: i64 = master_offset.trim().parse()?;
+    Ok(-master_offset)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+    use rstest::*;
+
+    use super::*;
+
+    struct StubSource {
+        offsets: VecDeque<Option<i64>>,
+    }
+
+    impl ClockSyncSource for StubSource {
+        fn name(&self) -> Ustr {
+            Ustr::from("PTP")
+        }
+
+        fn poll_offset_ns(&mut self) -> anyhow::Result<Option<i64>> {
+            Ok(self.offsets.pop_front().flatten())
+        }
+    }
+
+    fn synchronizer(
+        offsets: Vec<Option<i64>>,
+    ) -> (ClockSynchronizer, Rc<RefCell<Vec<ClockOffsetEvent>>>) {
+        let clock: &'static AtomicTime =
+            Box::leak(Box::new(AtomicTime::new(true, UnixNanos::default())));
+        let source = Box::new(StubSource {
+            offsets: offsets.into(),
+        });
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let events_clone = events.clone();
+        let handler: ClockOffsetHandler =
+            Box::new(move |event| events_clone.borrow_mut().push(event));
+
+        let synchronizer = ClockSynchronizer::new(clock, source, 1_000_000, 100, Some(handler));
+        (synchronizer, events)
+    }
+
+    #[rstest]
+    fn test_update_applies_offset() {
+        let (mut synchronizer, events) = synchronizer(vec![Some(-5_000)]);
+
+        let event = synchronizer.update().unwrap().unwrap();
+
+        assert_eq!(synchronizer.offset_ns(), -5_000);
+        assert_eq!(event.previous_offset_ns, 0);
+        assert_eq!(event.offset_ns, -5_000);
+        assert_eq!(event.source, Ustr::from("PTP"));
+        assert_eq!(events.borrow().len(), 1);
+    }
+
+    #[rstest]
+    fn test_update_with_no_measurement() {
+        let (mut synchronizer, events) = synchronizer(vec![None]);
+
+        assert!(synchronizer.update().unwrap().is_none());
+        assert_eq!(synchronizer.offset_ns(), 0);
+        assert!(events.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_update_ignores_small_change() {
+        let (mut synchronizer, events) = synchronizer(vec![Some(5_000), Some(5_050)]);
+        synchronizer.update().unwrap();
+
+        assert!(synchronizer.update().unwrap().is_none());
+        assert_eq!(synchronizer.offset_ns(), 5_000);
+        assert_eq!(events.borrow().len(), 1);
+    }
+
+    #[rstest]
+    fn test_update_rejects_implausible_offset() {
+        let (mut synchronizer, events) = synchronizer(vec![Some(2_000_000)]);
+
+        assert!(synchronizer.update().is_err());
+        assert_eq!(synchronizer.offset_ns(), 0);
+        assert!(events.borrow().is_empty());
+    }
+
+    #[rstest]
+    #[case("System time     : 0.000001234 seconds fast of NTP time", -1_234)]
+    #[case("System time     : 0.000000500 seconds slow of NTP time", 500)]
+    fn test_parse_chrony_tracking(#[case] line: &str, #[case] expected: i64) {
+        let output = format!(
+            "Reference ID    : C0A80101 (gateway)\n{line}\nLast offset     : -0.000000123 seconds"
+        );
+
+        assert_eq!(parse_chrony_tracking(&output).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_parse_chrony_tracking_invalid() {
+        assert!(parse_chrony_tracking("Reference ID    : C0A80101").is_err());
+    }
+
+    #[rstest]
+    fn test_parse_pmc_time_status() {
+        let output = "sending: GET TIME_STATUS_NP\n\t\tmaster_offset              -42\n\t\tingress_time               1718000000000000000";
+
+        assert_eq!(parse_pmc_time_status(output).unwrap(), 42);
+    }
+}
@@ -132,6 +132,60 @@ pub enum ComponentTrigger {
     FaultCompleted = 15,
 }
 
+impl ComponentState {
+    /// Returns the state resulting from `trigger`, or `None` if the transition is invalid
+    /// from this state.
+    #[rustfmt::skip]
+    #[must_use]
+    pub fn next_state(self, trigger: ComponentTrigger) -> Option<Self> {
+        let new_state = match (self, trigger) {
+            (Self::PreInitialized, ComponentTrigger::Initialize) => Self::Ready,
+            (Self::Ready, ComponentTrigger::Reset) => Self::Resetting,  // Transitional state
+            (Self::Ready, ComponentTrigger::Start) => Self::Starting,  // Transitional state
+            (Self::Ready, ComponentTrigger::Dispose) => Self::Disposing,  // Transitional state
+            (Self::Resetting, ComponentTrigger::ResetCompleted) => Self::Ready,
+            (Self::Starting, ComponentTrigger::StartCompleted) => Self::Running,
+            (Self::Starting, ComponentTrigger::Stop) => Self::Stopping,  // Transitional state
+            (Self::Starting, ComponentTrigger::Fault) => Self::Faulting,  // Transitional state
+            (Self::Running, ComponentTrigger::Stop) => Self::Stopping,  // Transitional state
+            (Self::Running, ComponentTrigger::Degrade) => Self::Degrading,  // Transitional state
+            (Self::Running, ComponentTrigger::Fault) => Self::Faulting,  // Transitional state
+            (Self::Resuming, ComponentTrigger::Stop) => Self::Stopping,  // Transitional state
+            (Self::Resuming, ComponentTrigger::ResumeCompleted) => Self::Running,
+            (Self::Resuming, ComponentTrigger::Fault) => Self::Faulting,  // Transitional state
+            (Self::Stopping, ComponentTrigger::StopCompleted) => Self::Stopped,
+            (Self::Stopping, ComponentTrigger::Fault) => Self::Faulting,  // Transitional state
+            (Self::Stopped, ComponentTrigger::Reset) => Self::Resetting,  // Transitional state
+            (Self::Stopped, ComponentTrigger::Resume) => Self::Resuming,  // Transitional state
+            (Self::Stopped, ComponentTrigger::Dispose) => Self::Disposing,  // Transitional state
+            (Self::Stopped, ComponentTrigger::Fault) => Self::Faulting,  // Transitional state
+            (Self::Degrading, ComponentTrigger::DegradeCompleted) => Self::Degraded,
+            (Self::Degraded, ComponentTrigger::Resume) => Self::Resuming,  // Transitional state
+            (Self::Degraded, ComponentTrigger::Stop) => Self::Stopping,  // Transitional state
+            (Self::Degraded, ComponentTrigger::Fault) => Self::Faulting,  // Transitional state
+            (Self::Disposing, ComponentTrigger::DisposeCompleted) => Self::Disposed,  // Terminal state
+            (Self::Faulting, ComponentTrigger::FaultCompleted) => Self::Faulted,  // Terminal state
+            _ => return None,
+        };
+        Some(new_state)
+    }
+
+    /// Transitions the state on the given `trigger`, returning the new state.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the transition is invalid from the current state.
+    pub fn transition(&mut self, trigger: ComponentTrigger) -> anyhow::Result<Self> {
+        match self.next_state(trigger) {
+            Some(new_state) => {
+                *self = new_state;
+                Ok(new_state)
+            }
+            None => anyhow::bail!("Invalid state trigger {self} -> {trigger}"),
+        }
+    }
+}
+
 /// The log level for log messages.
 #[repr(C)]
 #[derive(
@@ -318,3 +372,37 @@ pub enum SerializationEncoding {
     #[serde(rename = "json")]
     Json = 1,
 }
+
+/// The severity of an operational error raised by a component.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    FromRepr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.common.enums")
+)]
+pub enum ErrorSeverity {
+    /// The error was recovered from, but may indicate degraded operation.
+    Warning = 1,
+    /// The error caused an operation to fail, the component continues to run.
+    Error = 2,
+    /// The error leaves the component unable to fulfill its specification.
+    Critical = 3,
+}
@@ -0,0 +1,287 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Export of the order and component finite-state machines (FSMs) as DOT or JSON.
+//!
+//! Either the full state transition table can be exported, or the transitions actually taken
+//! by an order as replayed from its cached event history, for post-incident analysis.
+
+use std::fmt::Write;
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    enums::OrderStatus, events::order::OrderEventType, identifiers::client_order_id::ClientOrderId,
+    orders::any::OrderAny,
+};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::{
+    cache::Cache,
+    enums::{ComponentState, ComponentTrigger},
+};
+
+/// Represents a single state transition within a state machine graph.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateTransition {
+    /// The state before the transition.
+    pub from: String,
+    /// The trigger (or event type) causing the transition.
+    pub trigger: String,
+    /// The state after the transition.
+    pub to: String,
+    /// The UNIX timestamp (nanoseconds) when the transition was taken, if from history.
+    pub ts_event: Option<UnixNanos>,
+}
+
+/// Represents a state machine as its states and transitions, for export to DOT or JSON.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateMachineGraph {
+    /// The name of the state machine.
+    pub name: String,
+    /// The states of the state machine.
+    pub states: Vec<String>,
+    /// The transitions of the state machine.
+    pub transitions: Vec<StateTransition>,
+}
+
+impl StateMachineGraph {
+    /// Returns the full order status state transition table.
+    #[must_use]
+    pub fn order_status() -> Self {
+        let transitions = OrderStatus::iter()
+            .flat_map(|from| {
+                OrderEventType::iter().filter_map(move |event_type| {
+                    from.next_status(event_type)
+                        .map(|to| transition(from, event_type, to, None))
+                })
+            })
+            .collect();
+
+        Self {
+            name: stringify!(OrderStatus).to_string(),
+            states: OrderStatus::iter().map(|s| s.to_string()).collect(),
+            transitions,
+        }
+    }
+
+    /// Returns the full component state transition table.
+    #[must_use]
+    pub fn component_state() -> Self {
+        let transitions = ComponentState::iter()
+            .flat_map(|from| {
+                ComponentTrigger::iter().filter_map(move |trigger| {
+                    from.next_state(trigger)
+                        .map(|to| transition(from, trigger, to, None))
+                })
+            })
+            .collect();
+
+        Self {
+            name: stringify!(ComponentState).to_string(),
+            states: ComponentState::iter().map(|s| s.to_string()).collect(),
+            transitions,
+        }
+    }
+
+    /// Returns the transitions taken by the given `order`, replayed from its event history.
+    ///
+    /// Events which do not change the order status (e.g. `ModifyRejected`) are recorded as
+    /// transitions back to the same state.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order has no events.
+    pub fn from_order(order: &OrderAny) -> anyhow::Result<Self> {
+        let events = order.events();
+        let Some(first) = events.first() else {
+            anyhow::bail!("No events for order {}", order.client_order_id());
+        };
+        if first.event_type() != OrderEventType::Initialized {
+            anyhow::bail!("First event must be `OrderInitialized`");
+        }
+
+        let mut status = OrderStatus::Initialized;
+        let mut states = vec![status.to_string()];
+        let mut transitions = Vec::with_capacity(events.len() - 1);
+
+        for event in events.iter().skip(1) {
+            let event_type = event.event_type();
+            let next = status.next_status(event_type).unwrap_or(status);
+            if !states.contains(&next.to_string()) {
+                states.push(next.to_string());
+            }
+            transitions.push(transition(status, event_type, next, Some(event.ts_event())));
+            status = next;
+        }
+
+        Ok(Self {
+            name: order.client_order_id().to_string(),
+            states,
+            transitions,
+        })
+    }
+
+    /// Returns the transitions taken by the order for `client_order_id` in the `cache`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order is not found in the cache.
+    pub fn from_cache(cache: &Cache, client_order_id: &ClientOrderId) -> anyhow::Result<Self> {
+        match cache.order(client_order_id) {
+            Some(order) => Self::from_order(order),
+            None => anyhow::bail!("Order {client_order_id} not found in cache"),
+        }
+    }
+
+    /// Returns the state machine as a JSON string.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if serialization fails.
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Returns the state machine as a Graphviz DOT digraph.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph \"{}\" {{", self.name).unwrap();
+        for state in &self.states {
+            writeln!(dot, "    \"{state}\";").unwrap();
+        }
+        for t in &self.transitions {
+            let label = match t.ts_event {
+                Some(ts_event) => format!("{} @ {ts_event}", t.trigger),
+                None => t.trigger.clone(),
+            };
+            writeln!(
+                dot,
+                "    \"{}\" -> \"{}\" [label=\"{label}\"];",
+                t.from, t.to
+            )
+            .unwrap();
+        }
+        dot.push('}');
+        dot
+    }
+}
+
+fn transition(
+    from: impl ToString,
+    trigger: impl ToString,
+    to: impl ToString,
+    ts_event: Option<UnixNanos>,
+) -> StateTransition {
+    StateTransition {
+        from: from.to_string(),
+        trigger: trigger.to_string(),
+        to: to.to_string(),
+        ts_event,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderSide,
+        events::order::{submitted::OrderSubmitted, OrderEventAny},
+        identifiers::instrument_id::InstrumentId,
+        orders::stubs::TestOrderStubs,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_order_status_table() {
+        let graph = StateMachineGraph::order_status();
+
+        assert_eq!(graph.states.len(), OrderStatus::iter().count());
+        assert!(graph.transitions.contains(&StateTransition {
+            from: "INITIALIZED".to_string(),
+            trigger: "Submitted".to_string(),
+            to: "SUBMITTED".to_string(),
+            ts_event: None,
+        }));
+    }
+
+    #[rstest]
+    fn test_component_state_table() {
+        let graph = StateMachineGraph::component_state();
+
+        assert_eq!(graph.transitions.len(), 26);
+        assert!(graph
+            .transitions
+            .iter()
+            .all(|t| t.from != "DISPOSED" && t.from != "FAULTED"));
+    }
+
+    #[rstest]
+    fn test_component_state_transition() {
+        let mut state = ComponentState::PreInitialized;
+
+        assert_eq!(
+            state.transition(ComponentTrigger::Initialize).unwrap(),
+            ComponentState::Ready
+        );
+        assert!(state.transition(ComponentTrigger::StopCompleted).is_err());
+        assert_eq!(state, ComponentState::Ready);
+    }
+
+    #[rstest]
+    fn test_order_history_from_cache() {
+        let mut cache = Cache::default();
+        let mut order = TestOrderStubs::limit_order(
+            InstrumentId::from("AUD/USD.SIM"),
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+        let submitted = OrderSubmitted {
+            ts_event: 5.into(),
+            ..Default::default()
+        };
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        cache.add_order(order.clone(), None, None, false).unwrap();
+
+        let graph = StateMachineGraph::from_cache(&cache, &order.client_order_id()).unwrap();
+
+        assert_eq!(graph.states, vec!["INITIALIZED", "SUBMITTED"]);
+        assert_eq!(graph.transitions.len(), 1);
+        assert_eq!(graph.transitions[0].ts_event, Some(5.into()));
+        assert!(graph
+            .to_dot()
+            .contains("\"INITIALIZED\" -> \"SUBMITTED\" [label=\"Submitted @ 5\"];"));
+        assert!(graph
+            .to_json()
+            .unwrap()
+            .contains("\"trigger\": \"Submitted\""));
+    }
+
+    #[rstest]
+    fn test_order_history_not_in_cache() {
+        let cache = Cache::default();
+
+        assert!(StateMachineGraph::from_cache(&cache, &ClientOrderId::default()).is_err());
+    }
+}
@@ -0,0 +1,423 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Guards for protecting data ingest from pathological message bursts.
+//!
+//! A burst of messages for a single instrument (e.g. quote stuffing) can otherwise delay
+//! the processing of data for all other instruments.
+
+use std::collections::{HashMap, VecDeque};
+
+use nautilus_core::{
+    correctness::{check_positive_u64, check_predicate_true},
+    nanos::UnixNanos,
+};
+use nautilus_model::{
+    data::{quote::QuoteTick, Data},
+    identifiers::instrument_id::InstrumentId,
+};
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+/// Configuration for a [`BurstGuard`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BurstGuardConfig {
+    /// The sliding window (nanoseconds) over which messages are counted.
+    pub window_ns: u64,
+    /// The maximum messages per instrument within the window before throttling.
+    pub max_messages: usize,
+    /// The duration (nanoseconds) an instrument remains throttled after its last burst.
+    pub cooldown_ns: u64,
+}
+
+impl Default for BurstGuardConfig {
+    fn default() -> Self {
+        Self {
+            window_ns: 1_000_000_000,
+            max_messages: 1_000,
+            cooldown_ns: 1_000_000_000,
+        }
+    }
+}
+
+/// The kind of a [`BurstEvent`].
+#[derive(Copy, Clone, Debug, Display, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum BurstEventKind {
+    /// A burst was detected and the instrument is now throttled.
+    Started,
+    /// The burst subsided and the instrument is no longer throttled.
+    Ended,
+}
+
+/// Represents a change in the throttling state of an instrument's data stream.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BurstEvent {
+    /// The instrument ID for the event.
+    pub instrument_id: InstrumentId,
+    /// The kind of the event.
+    pub kind: BurstEventKind,
+    /// The count of messages within the window when the burst was detected.
+    pub message_count: usize,
+    /// The count of quotes conflated (dropped) while throttled.
+    pub conflated_count: usize,
+    /// The UNIX timestamp (nanoseconds) when the event occurred.
+    pub ts_event: UnixNanos,
+}
+
+pub type BurstEventHandler = Box<dyn FnMut(BurstEvent)>;
+
+#[derive(Debug, Default)]
+struct InstrumentState {
+    arrivals: VecDeque<UnixNanos>,
+    throttled_until: Option<UnixNanos>,
+    pending_quote: Option<QuoteTick>,
+    conflated_count: usize,
+}
+
+/// Provides a per-instrument ingest guard which detects message bursts and temporarily
+/// conflates the affected instrument's stream.
+///
+/// While an instrument is throttled, its quotes are conflated so that only the latest quote
+/// is released once the cooldown expires. Other data types pass through unchanged, as
+/// dropping them would lose information (e.g. order book deltas).
+pub struct BurstGuard {
+    config: BurstGuardConfig,
+    states: HashMap<InstrumentId, InstrumentState>,
+    handler: Option<BurstEventHandler>,
+}
+
+impl BurstGuard {
+    /// Creates a new [`BurstGuard`] instance.
+    ///
+    /// The optional `handler` receives a [`BurstEvent`] whenever throttling starts or ends.
+    pub fn new(
+        config: BurstGuardConfig,
+        handler: Option<BurstEventHandler>,
+    ) -> anyhow::Result<Self> {
+        check_positive_u64(config.window_ns, "config.window_ns")?;
+        check_positive_u64(config.cooldown_ns, "config.cooldown_ns")?;
+        check_predicate_true(config.max_messages > 0, "`config.max_messages` was zero")?;
+
+        Ok(Self {
+            config,
+            states: HashMap::new(),
+            handler,
+        })
+    }
+
+    /// Returns the configuration for the guard.
+    #[must_use]
+    pub fn config(&self) -> &BurstGuardConfig {
+        &self.config
+    }
+
+    /// Returns whether the stream for the given `instrument_id` is currently throttled.
+    #[must_use]
+    pub fn is_throttled(&self, instrument_id: &InstrumentId) -> bool {
+        self.states
+            .get(instrument_id)
+            .map_or(false, |state| state.throttled_until.is_some())
+    }
+
+    /// Returns the instrument IDs which are currently throttled, sorted.
+    #[must_use]
+    pub fn throttled_instruments(&self) -> Vec<InstrumentId> {
+        let mut instrument_ids: Vec<InstrumentId> = self
+            .states
+            .iter()
+            .filter(|(_, state)| state.throttled_until.is_some())
+            .map(|(instrument_id, _)| *instrument_id)
+            .collect();
+        instrument_ids.sort();
+        instrument_ids
+    }
+
+    /// Processes the given `data` received at `now`, returning the data to forward.
+    pub fn process(&mut self, data: Data, now: UnixNanos) -> Vec<Data> {
+        let Some(instrument_id) = data.instrument_id() else {
+            return vec![data]; // Venue level data is never throttled
+        };
+        let window_ns = self.config.window_ns;
+        let max_messages = self.config.max_messages;
+        let cooldown_ns = self.config.cooldown_ns;
+        let state = self.states.entry(instrument_id).or_default();
+        let mut output = Vec::new();
+
+        // Release an expired throttle before handling the new message
+        if state
+            .throttled_until
+            .map_or(false, |throttled_until| now >= throttled_until)
+        {
+            release(state, instrument_id, now, &mut self.handler, &mut output);
+        }
+
+        // Only the count of arrivals up to the limit is needed to detect a burst
+        state.arrivals.push_back(now);
+        while let Some(first) = state.arrivals.front() {
+            let is_expired = first.as_u64() + window_ns <= now.as_u64();
+            if is_expired || state.arrivals.len() > max_messages + 1 {
+                state.arrivals.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        if state.arrivals.len() > max_messages {
+            let throttled_until = Some(UnixNanos::from(now.as_u64() + cooldown_ns));
+            if state.throttled_until.is_none() {
+                if let Some(handler) = &mut self.handler {
+                    handler(BurstEvent {
+                        instrument_id,
+                        kind: BurstEventKind::Started,
+                        message_count: state.arrivals.len(),
+                        conflated_count: 0,
+                        ts_event: now,
+                    });
+                }
+            }
+            state.throttled_until = throttled_until; // Extends while the burst continues
+        }
+
+        match data {
+            Data::Quote(quote) if state.throttled_until.is_some() => {
+                if state.pending_quote.replace(quote).is_some() {
+                    state.conflated_count += 1;
+                }
+            }
+            _ => output.push(data),
+        }
+
+        output
+    }
+
+    /// Handles the current time `now`, releasing any throttles whose cooldown has expired.
+    ///
+    /// Returns the latest conflated quotes for the released instruments.
+    pub fn on_time(&mut self, now: UnixNanos) -> Vec<Data> {
+        let mut expired: Vec<InstrumentId> = self
+            .states
+            .iter()
+            .filter(|(_, state)| {
+                state
+                    .throttled_until
+                    .map_or(false, |throttled_until| now >= throttled_until)
+            })
+            .map(|(instrument_id, _)| *instrument_id)
+            .collect();
+        expired.sort();
+
+        let mut output = Vec::new();
+        for instrument_id in expired {
+            if let Some(state) = self.states.get_mut(&instrument_id) {
+                release(state, instrument_id, now, &mut self.handler, &mut output);
+            }
+        }
+        output
+    }
+
+    /// Resets the guard, discarding all state (including any pending conflated quotes).
+    pub fn reset(&mut self) {
+        self.states.clear();
+    }
+}
+
+fn release(
+    state: &mut InstrumentState,
+    instrument_id: InstrumentId,
+    now: UnixNanos,
+    handler: &mut Option<BurstEventHandler>,
+    output: &mut Vec<Data>,
+) {
+    if let Some(quote) = state.pending_quote.take() {
+        output.push(Data::Quote(quote));
+    }
+
+    if let Some(handler) = handler {
+        handler(BurstEvent {
+            instrument_id,
+            kind: BurstEventKind::Ended,
+            message_count: state.arrivals.len(),
+            conflated_count: state.conflated_count,
+            ts_event: now,
+        });
+    }
+
+    state.throttled_until = None;
+    state.conflated_count = 0;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_model::data::trade::TradeTick;
+    use rstest::*;
+
+    use super::*;
+
+    fn config() -> BurstGuardConfig {
+        BurstGuardConfig {
+            window_ns: 100,
+            max_messages: 3,
+            cooldown_ns: 50,
+        }
+    }
+
+    fn guard() -> (BurstGuard, Rc<RefCell<Vec<BurstEvent>>>) {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let events_clone = events.clone();
+        let handler: BurstEventHandler =
+            Box::new(move |event| events_clone.borrow_mut().push(event));
+        let guard = BurstGuard::new(config(), Some(handler)).unwrap();
+        (guard, events)
+    }
+
+    fn quote(instrument_id: &str, ts_init: u64) -> Data {
+        Data::Quote(QuoteTick {
+            instrument_id: InstrumentId::from(instrument_id),
+            ts_init: ts_init.into(),
+            ..Default::default()
+        })
+    }
+
+    #[rstest]
+    fn test_new_with_invalid_config() {
+        let config = BurstGuardConfig {
+            max_messages: 0,
+            ..Default::default()
+        };
+
+        assert!(BurstGuard::new(config, None).is_err());
+    }
+
+    #[rstest]
+    fn test_passes_through_below_limit() {
+        let (mut guard, events) = guard();
+
+        let mut output = Vec::new();
+        for ts in [10, 20, 30] {
+            output.extend(guard.process(quote("AUD/USD.SIM", ts), ts.into()));
+        }
+
+        assert_eq!(output.len(), 3);
+        assert!(!guard.is_throttled(&InstrumentId::from("AUD/USD.SIM")));
+        assert!(events.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_messages_outside_window_not_counted() {
+        let (mut guard, _) = guard();
+
+        for ts in [0, 50, 100, 150, 200, 250] {
+            assert_eq!(guard.process(quote("AUD/USD.SIM", ts), ts.into()).len(), 1);
+        }
+
+        assert!(guard.throttled_instruments().is_empty());
+    }
+
+    #[rstest]
+    fn test_burst_starts_throttle_and_conflates_quotes() {
+        let (mut guard, events) = guard();
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+
+        let mut output = Vec::new();
+        for ts in [10, 11, 12, 13, 14, 15] {
+            output.extend(guard.process(quote("AUD/USD.SIM", ts), ts.into()));
+        }
+
+        assert_eq!(output.len(), 3);
+        assert!(guard.is_throttled(&instrument_id));
+        assert_eq!(events.borrow().len(), 1);
+        assert_eq!(events.borrow()[0].kind, BurstEventKind::Started);
+        assert_eq!(events.borrow()[0].message_count, 4);
+        assert_eq!(events.borrow()[0].ts_event, 13);
+    }
+
+    #[rstest]
+    fn test_non_quotes_pass_through_while_throttled() {
+        let (mut guard, _) = guard();
+        for ts in [10, 11, 12, 13] {
+            guard.process(quote("AUD/USD.SIM", ts), ts.into());
+        }
+
+        let trade = Data::Trade(TradeTick {
+            instrument_id: InstrumentId::from("AUD/USD.SIM"),
+            ..Default::default()
+        });
+        let output = guard.process(trade, 14.into());
+
+        assert_eq!(output.len(), 1);
+        assert!(matches!(output[0], Data::Trade(_)));
+    }
+
+    #[rstest]
+    fn test_other_instruments_unaffected() {
+        let (mut guard, _) = guard();
+        for ts in [10, 11, 12, 13] {
+            guard.process(quote("AUD/USD.SIM", ts), ts.into());
+        }
+
+        let output = guard.process(quote("EUR/USD.SIM", 14), 14.into());
+
+        assert_eq!(output.len(), 1);
+        assert_eq!(
+            guard.throttled_instruments(),
+            vec![InstrumentId::from("AUD/USD.SIM")]
+        );
+    }
+
+    #[rstest]
+    fn test_on_time_releases_latest_quote() {
+        let (mut guard, events) = guard();
+        for ts in [10, 11, 12, 13, 14, 15] {
+            guard.process(quote("AUD/USD.SIM", ts), ts.into());
+        }
+
+        assert!(guard.on_time(64.into()).is_empty());
+        let output = guard.on_time(65.into());
+
+        assert_eq!(output.len(), 1);
+        assert_eq!(
+            output[0].instrument_id(),
+            Some(InstrumentId::from("AUD/USD.SIM"))
+        );
+        match &output[0] {
+            Data::Quote(quote) => assert_eq!(quote.ts_init, 15),
+            _ => panic!("Expected quote"),
+        }
+        assert!(guard.throttled_instruments().is_empty());
+        assert_eq!(events.borrow().len(), 2);
+        assert_eq!(events.borrow()[1].kind, BurstEventKind::Ended);
+        assert_eq!(events.borrow()[1].conflated_count, 2);
+    }
+
+    #[rstest]
+    fn test_expired_throttle_released_on_next_message() {
+        let (mut guard, events) = guard();
+        for ts in [10, 11, 12, 13] {
+            guard.process(quote("AUD/USD.SIM", ts), ts.into());
+        }
+
+        let output = guard.process(quote("AUD/USD.SIM", 500), 500.into());
+
+        assert_eq!(output.len(), 2);
+        assert!(!guard.is_throttled(&InstrumentId::from("AUD/USD.SIM")));
+        assert_eq!(events.borrow()[1].kind, BurstEventKind::Ended);
+    }
+}
@@ -15,7 +15,9 @@
 
 use std::collections::HashMap;
 
+use nautilus_core::nanos::UnixNanos;
 use nautilus_model::{
+    data::borrow::BorrowRateUpdate,
     enums::{AccountType, LiquiditySide, OrderSide},
     events::{account::state::AccountState, order::filled::OrderFilled},
     identifiers::account_id::AccountId,
@@ -68,4 +70,24 @@ pub trait Account {
         liquidity_side: LiquiditySide,
         use_quote_for_inverse: Option<bool>,
     ) -> anyhow::Result<Money>;
+
+    /// Updates the rate at which interest accrues on borrowed balances, returning the
+    /// resulting account state if interest was accrued at the previous rate.
+    ///
+    /// Accounts which cannot borrow ignore the update.
+    fn apply_borrow_rate(
+        &mut self,
+        _borrow_rate: BorrowRateUpdate,
+    ) -> anyhow::Result<Option<AccountState>> {
+        Ok(None)
+    }
+
+    /// Accrues interest on borrowed balances up to `ts_now`, returning the resulting account
+    /// state if any interest was accrued.
+    fn accrue_borrow_interest(
+        &mut self,
+        _ts_now: UnixNanos,
+    ) -> anyhow::Result<Option<AccountState>> {
+        Ok(None)
+    }
 }
@@ -28,14 +28,21 @@
 //! - `python`: Enables Python bindings from `pyo3`
 //! - `stubs`: Enables type stubs for use in testing scenarios
 
+pub mod aggregation;
 pub mod cache;
 pub mod clock;
+pub mod clock_sync;
 pub mod enums;
 pub mod factories;
+pub mod fsm;
 pub mod generators;
 pub mod handlers;
+pub mod ingest;
 pub mod interface;
+pub mod live;
 pub mod logging;
+pub mod memory;
+pub mod messages;
 pub mod msgbus;
 pub mod runtime;
 pub mod testing;
@@ -0,0 +1,781 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a `LiveDataEngine` which manages data subscriptions and requests across data
+//! clients, and routes the received data onto the message bus.
+//!
+//! Subscriptions are de-duplicated across subscribers (e.g. actors and strategies), so a
+//! client is only subscribed on the first subscriber and unsubscribed after the last.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+use indexmap::{IndexMap, IndexSet};
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    data::{bar::BarType, custom::DataType, Data},
+    identifiers::{client_id::ClientId, instrument_id::InstrumentId, venue::Venue},
+};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tracing::{debug, error, warn};
+use ustr::Ustr;
+
+use crate::msgbus::switchboard::{
+    get_bars_topic, get_custom_data_topic, get_data_topic, get_deltas_topic, get_depth_topic,
+    get_funding_rates_topic, get_imbalances_topic, get_instrument_status_topic, get_quotes_topic,
+    get_trades_topic,
+};
+
+/// Represents a stream of data which can be subscribed to or requested.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DataSubscription {
+    BookDeltas(InstrumentId),
+    BookDepth10(InstrumentId),
+    Quotes(InstrumentId),
+    Trades(InstrumentId),
+    Bars(BarType),
+    FundingRates(InstrumentId),
+    InstrumentStatus(InstrumentId),
+    Imbalances(InstrumentId),
+    Custom(DataType),
+}
+
+impl DataSubscription {
+    /// Returns the message bus topic on which the data is published.
+    #[must_use]
+    pub fn topic(&self) -> Ustr {
+        match self {
+            Self::BookDeltas(instrument_id) => get_deltas_topic(instrument_id),
+            Self::BookDepth10(instrument_id) => get_depth_topic(instrument_id),
+            Self::Quotes(instrument_id) => get_quotes_topic(instrument_id),
+            Self::Trades(instrument_id) => get_trades_topic(instrument_id),
+            Self::Bars(bar_type) => get_bars_topic(bar_type),
+            Self::FundingRates(instrument_id) => get_funding_rates_topic(instrument_id),
+            Self::InstrumentStatus(instrument_id) => get_instrument_status_topic(instrument_id),
+            Self::Imbalances(instrument_id) => get_imbalances_topic(instrument_id),
+            Self::Custom(data_type) => get_custom_data_topic(data_type),
+        }
+    }
+
+    /// Returns the venue for the data, or `None` for custom data.
+    #[must_use]
+    pub fn venue(&self) -> Option<Venue> {
+        match self {
+            Self::BookDeltas(instrument_id)
+            | Self::BookDepth10(instrument_id)
+            | Self::Quotes(instrument_id)
+            | Self::Trades(instrument_id)
+            | Self::FundingRates(instrument_id)
+            | Self::InstrumentStatus(instrument_id)
+            | Self::Imbalances(instrument_id) => Some(instrument_id.venue),
+            Self::Bars(bar_type) => Some(bar_type.instrument_id.venue),
+            Self::Custom(_) => None,
+        }
+    }
+}
+
+impl Display for DataSubscription {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.topic())
+    }
+}
+
+/// Represents a command to subscribe to or unsubscribe from a stream of data.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataCommand {
+    Subscribe {
+        /// The component subscribing, e.g. an actor ID.
+        subscriber: Ustr,
+        subscription: DataSubscription,
+        /// The client to route to, otherwise routed by venue.
+        client_id: Option<ClientId>,
+    },
+    Unsubscribe {
+        /// The component unsubscribing, e.g. an actor ID.
+        subscriber: Ustr,
+        subscription: DataSubscription,
+        /// The client to route to, otherwise routed by venue.
+        client_id: Option<ClientId>,
+    },
+}
+
+/// Represents a request for historical data, answered with a [`DataResponse`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataRequest {
+    /// The unique identifier for the request.
+    pub request_id: UUID4,
+    /// The component requesting the data, which receives the response.
+    pub requester: Ustr,
+    /// The data being requested.
+    pub subscription: DataSubscription,
+    /// The client to route to, otherwise routed by venue.
+    pub client_id: Option<ClientId>,
+    /// The start of the requested time range (inclusive).
+    pub start: Option<UnixNanos>,
+    /// The end of the requested time range (inclusive).
+    pub end: Option<UnixNanos>,
+    /// The maximum number of data points to return.
+    pub limit: Option<usize>,
+    /// The UNIX timestamp (nanoseconds) when the request was initialized.
+    pub ts_init: UnixNanos,
+}
+
+/// Represents the response from a data client to a [`DataRequest`].
+#[derive(Clone, Debug)]
+pub struct DataResponse {
+    /// The identifier of the request being responded to.
+    pub correlation_id: UUID4,
+    /// The client which responded.
+    pub client_id: ClientId,
+    /// The data for the response.
+    pub data: Vec<Data>,
+    /// The UNIX timestamp (nanoseconds) when the response was initialized.
+    pub ts_init: UnixNanos,
+}
+
+/// A client providing data from a venue or data provider to the [`LiveDataEngine`].
+///
+/// Received data and responses are passed back to the engine, e.g. with the
+/// [`DataEngineMessage`] channel.
+pub trait DataClient {
+    /// Returns the identifier for the client.
+    fn client_id(&self) -> ClientId;
+    /// Returns the venue the client provides data for (if a single venue).
+    fn venue(&self) -> Option<Venue>;
+    /// Subscribes the client to the given stream of data.
+    fn subscribe(&mut self, subscription: &DataSubscription) -> anyhow::Result<()>;
+    /// Unsubscribes the client from the given stream of data.
+    fn unsubscribe(&mut self, subscription: &DataSubscription) -> anyhow::Result<()>;
+    /// Requests historical data, to be answered asynchronously with a [`DataResponse`].
+    fn request(&mut self, request: &DataRequest) -> anyhow::Result<()>;
+}
+
+/// Publishes the data and responses routed by the [`LiveDataEngine`], e.g. onto the
+/// message bus.
+pub trait DataPublisher {
+    /// Publishes the `data` on the given `topic`.
+    fn publish_data(&mut self, topic: Ustr, data: Data);
+    /// Sends the `response` to the `requester`.
+    fn send_response(&mut self, requester: Ustr, response: DataResponse);
+}
+
+/// Represents an output of the [`LiveDataEngine`] when publishing through a channel.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum DataEngineOutput {
+    Data {
+        topic: Ustr,
+        data: Data,
+    },
+    Response {
+        requester: Ustr,
+        response: DataResponse,
+    },
+}
+
+impl DataPublisher for UnboundedSender<DataEngineOutput> {
+    fn publish_data(&mut self, topic: Ustr, data: Data) {
+        if let Err(e) = self.send(DataEngineOutput::Data { topic, data }) {
+            error!("Error publishing data: {e}");
+        }
+    }
+
+    fn send_response(&mut self, requester: Ustr, response: DataResponse) {
+        if let Err(e) = self.send(DataEngineOutput::Response {
+            requester,
+            response,
+        }) {
+            error!("Error sending response: {e}");
+        }
+    }
+}
+
+/// Represents a message processed by the [`LiveDataEngine`] run loop.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum DataEngineMessage {
+    Command(DataCommand),
+    Request(DataRequest),
+    Response(DataResponse),
+    Data(Data),
+}
+
+/// Provides a live data engine which manages subscriptions and requests for data across
+/// the registered data clients, and routes the received data onto the message bus.
+pub struct LiveDataEngine<P: DataPublisher> {
+    pub command_count: u64,
+    pub data_count: u64,
+    pub request_count: u64,
+    pub response_count: u64,
+    publisher: P,
+    clients: IndexMap<ClientId, Box<dyn DataClient>>,
+    default_client: Option<ClientId>,
+    routing_map: HashMap<Venue, ClientId>,
+    subscribers: IndexMap<DataSubscription, IndexSet<Ustr>>,
+    subscription_clients: HashMap<DataSubscription, ClientId>,
+    pending_requests: HashMap<UUID4, Ustr>,
+}
+
+impl<P: DataPublisher> LiveDataEngine<P> {
+    /// Creates a new [`LiveDataEngine`] instance.
+    pub fn new(publisher: P) -> Self {
+        Self {
+            command_count: 0,
+            data_count: 0,
+            request_count: 0,
+            response_count: 0,
+            publisher,
+            clients: IndexMap::new(),
+            default_client: None,
+            routing_map: HashMap::new(),
+            subscribers: IndexMap::new(),
+            subscription_clients: HashMap::new(),
+            pending_requests: HashMap::new(),
+        }
+    }
+
+    /// Returns the IDs of the registered clients.
+    #[must_use]
+    pub fn registered_clients(&self) -> Vec<ClientId> {
+        self.clients.keys().copied().collect()
+    }
+
+    /// Returns the active subscriptions (with at least one subscriber).
+    #[must_use]
+    pub fn subscriptions(&self) -> Vec<&DataSubscription> {
+        self.subscribers.keys().collect()
+    }
+
+    /// Returns the subscribers for the given `subscription`.
+    #[must_use]
+    pub fn subscribers(&self, subscription: &DataSubscription) -> Vec<Ustr> {
+        self.subscribers
+            .get(subscription)
+            .map(|subscribers| subscribers.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns whether a response is pending for the given `request_id`.
+    #[must_use]
+    pub fn is_pending_request(&self, request_id: &UUID4) -> bool {
+        self.pending_requests.contains_key(request_id)
+    }
+
+    // -- REGISTRATION --------------------------------------------------------
+
+    /// Registers the given data `client` with the engine.
+    ///
+    /// If the client has a venue then commands for that venue are routed to it.
+    pub fn register_client(&mut self, client: Box<dyn DataClient>) -> anyhow::Result<()> {
+        let client_id = client.client_id();
+        if self.clients.contains_key(&client_id) {
+            anyhow::bail!("Data client {client_id} already registered");
+        }
+
+        if let Some(venue) = client.venue() {
+            self.routing_map.entry(venue).or_insert(client_id);
+        }
+        self.clients.insert(client_id, client);
+        debug!("Registered data client {client_id}");
+        Ok(())
+    }
+
+    /// Registers the given data `client` as the default, for commands with no other route.
+    pub fn register_default_client(&mut self, client: Box<dyn DataClient>) -> anyhow::Result<()> {
+        let client_id = client.client_id();
+        self.register_client(client)?;
+        self.default_client = Some(client_id);
+        Ok(())
+    }
+
+    /// Routes commands for the given `venue` to the registered client.
+    pub fn register_venue_routing(
+        &mut self,
+        client_id: ClientId,
+        venue: Venue,
+    ) -> anyhow::Result<()> {
+        if !self.clients.contains_key(&client_id) {
+            anyhow::bail!("Data client {client_id} not registered");
+        }
+        self.routing_map.insert(venue, client_id);
+        Ok(())
+    }
+
+    /// Deregisters the data client, dropping any subscriptions routed to it.
+    pub fn deregister_client(&mut self, client_id: ClientId) -> anyhow::Result<()> {
+        if self.clients.shift_remove(&client_id).is_none() {
+            anyhow::bail!("Data client {client_id} not registered");
+        }
+
+        self.routing_map.retain(|_, id| *id != client_id);
+        if self.default_client == Some(client_id) {
+            self.default_client = None;
+        }
+
+        let dropped: Vec<DataSubscription> = self
+            .subscription_clients
+            .iter()
+            .filter(|(_, id)| **id == client_id)
+            .map(|(subscription, _)| subscription.clone())
+            .collect();
+        for subscription in dropped {
+            warn!("Dropping subscription {subscription} for deregistered client {client_id}");
+            self.subscription_clients.remove(&subscription);
+            self.subscribers.shift_remove(&subscription);
+        }
+
+        debug!("Deregistered data client {client_id}");
+        Ok(())
+    }
+
+    // -- COMMANDS ------------------------------------------------------------
+
+    /// Executes the subscription `command`.
+    ///
+    /// The client is only subscribed for the first subscriber to a stream, and only
+    /// unsubscribed once the last subscriber has unsubscribed.
+    pub fn execute(&mut self, command: DataCommand) -> anyhow::Result<()> {
+        self.command_count += 1;
+
+        match command {
+            DataCommand::Subscribe {
+                subscriber,
+                subscription,
+                client_id,
+            } => self.subscribe(subscriber, subscription, client_id),
+            DataCommand::Unsubscribe {
+                subscriber,
+                subscription,
+                client_id,
+            } => self.unsubscribe(subscriber, &subscription, client_id),
+        }
+    }
+
+    fn subscribe(
+        &mut self,
+        subscriber: Ustr,
+        subscription: DataSubscription,
+        client_id: Option<ClientId>,
+    ) -> anyhow::Result<()> {
+        if let Some(subscribers) = self.subscribers.get_mut(&subscription) {
+            if !subscribers.insert(subscriber) {
+                warn!("{subscriber} already subscribed to {subscription}");
+            }
+            return Ok(());
+        }
+
+        let client_id = self.route(client_id, subscription.venue())?;
+        self.client_mut(&client_id)?.subscribe(&subscription)?;
+        debug!("Subscribed {client_id} to {subscription}");
+
+        self.subscription_clients
+            .insert(subscription.clone(), client_id);
+        self.subscribers
+            .insert(subscription, IndexSet::from([subscriber]));
+        Ok(())
+    }
+
+    fn unsubscribe(
+        &mut self,
+        subscriber: Ustr,
+        subscription: &DataSubscription,
+        client_id: Option<ClientId>,
+    ) -> anyhow::Result<()> {
+        let Some(subscribers) = self.subscribers.get_mut(subscription) else {
+            warn!("No subscription to {subscription} for {subscriber}");
+            return Ok(());
+        };
+        if !subscribers.shift_remove(&subscriber) {
+            warn!("{subscriber} not subscribed to {subscription}");
+            return Ok(());
+        }
+        if !subscribers.is_empty() {
+            return Ok(());
+        }
+
+        let client_id = match self.subscription_clients.get(subscription) {
+            Some(client_id) => *client_id,
+            None => self.route(client_id, subscription.venue())?,
+        };
+        self.subscribers.shift_remove(subscription);
+        self.subscription_clients.remove(subscription);
+        self.client_mut(&client_id)?.unsubscribe(subscription)?;
+        debug!("Unsubscribed {client_id} from {subscription}");
+        Ok(())
+    }
+
+    // -- REQUESTS ------------------------------------------------------------
+
+    /// Routes the `request` to a data client, recording the requester for the response.
+    pub fn request(&mut self, request: DataRequest) -> anyhow::Result<()> {
+        self.request_count += 1;
+
+        if self.pending_requests.contains_key(&request.request_id) {
+            anyhow::bail!("Duplicate request {}", request.request_id);
+        }
+
+        let client_id = self.route(request.client_id, request.subscription.venue())?;
+        self.client_mut(&client_id)?.request(&request)?;
+        self.pending_requests
+            .insert(request.request_id, request.requester);
+        Ok(())
+    }
+
+    /// Sends the `response` to the requester of the matching request.
+    ///
+    /// Responses with no matching pending request are dropped.
+    pub fn response(&mut self, response: DataResponse) {
+        self.response_count += 1;
+
+        match self.pending_requests.remove(&response.correlation_id) {
+            Some(requester) => self.publisher.send_response(requester, response),
+            None => warn!(
+                "Dropping response with no pending request {}",
+                response.correlation_id
+            ),
+        }
+    }
+
+    // -- DATA ----------------------------------------------------------------
+
+    /// Publishes the `data` on its message bus topic.
+    pub fn process(&mut self, data: Data) {
+        self.data_count += 1;
+        self.publisher.publish_data(get_data_topic(&data), data);
+    }
+
+    /// Handles the given engine `msg`, logging any error.
+    pub fn handle(&mut self, msg: DataEngineMessage) {
+        let result = match msg {
+            DataEngineMessage::Command(command) => self.execute(command),
+            DataEngineMessage::Request(request) => self.request(request),
+            DataEngineMessage::Response(response) => {
+                self.response(response);
+                Ok(())
+            }
+            DataEngineMessage::Data(data) => {
+                self.process(data);
+                Ok(())
+            }
+        };
+
+        if let Err(e) = result {
+            error!("Error handling data engine message: {e}");
+        }
+    }
+
+    /// Handles messages from the `rx` channel until all senders have been dropped.
+    pub async fn run(&mut self, mut rx: UnboundedReceiver<DataEngineMessage>) {
+        debug!("Running data engine");
+        while let Some(msg) = rx.recv().await {
+            self.handle(msg);
+        }
+        debug!("Data engine stopped");
+    }
+
+    fn route(&self, client_id: Option<ClientId>, venue: Option<Venue>) -> anyhow::Result<ClientId> {
+        if let Some(client_id) = client_id {
+            return Ok(client_id);
+        }
+
+        venue
+            .and_then(|venue| self.routing_map.get(&venue).copied())
+            .or(self.default_client)
+            .ok_or_else(|| anyhow::anyhow!("No data client to route to for venue {venue:?}"))
+    }
+
+    fn client_mut(&mut self, client_id: &ClientId) -> anyhow::Result<&mut Box<dyn DataClient>> {
+        self.clients
+            .get_mut(client_id)
+            .ok_or_else(|| anyhow::anyhow!("Data client {client_id} not registered"))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_model::data::stubs::quote_tick_ethusdt_binance;
+    use rstest::*;
+    use tokio::sync::mpsc::unbounded_channel;
+
+    use super::*;
+
+    type CallLog = Rc<RefCell<Vec<String>>>;
+
+    struct StubDataClient {
+        client_id: ClientId,
+        venue: Option<Venue>,
+        calls: CallLog,
+    }
+
+    impl DataClient for StubDataClient {
+        fn client_id(&self) -> ClientId {
+            self.client_id
+        }
+
+        fn venue(&self) -> Option<Venue> {
+            self.venue
+        }
+
+        fn subscribe(&mut self, subscription: &DataSubscription) -> anyhow::Result<()> {
+            self.calls
+                .borrow_mut()
+                .push(format!("{}:subscribe:{subscription}", self.client_id));
+            Ok(())
+        }
+
+        fn unsubscribe(&mut self, subscription: &DataSubscription) -> anyhow::Result<()> {
+            self.calls
+                .borrow_mut()
+                .push(format!("{}:unsubscribe:{subscription}", self.client_id));
+            Ok(())
+        }
+
+        fn request(&mut self, request: &DataRequest) -> anyhow::Result<()> {
+            self.calls.borrow_mut().push(format!(
+                "{}:request:{}",
+                self.client_id, request.subscription
+            ));
+            Ok(())
+        }
+    }
+
+    struct TestEngine {
+        engine: LiveDataEngine<UnboundedSender<DataEngineOutput>>,
+        rx: UnboundedReceiver<DataEngineOutput>,
+        calls: CallLog,
+    }
+
+    #[fixture]
+    fn test_engine() -> TestEngine {
+        let (tx, rx) = unbounded_channel();
+        let calls = CallLog::default();
+        let mut engine = LiveDataEngine::new(tx);
+        engine
+            .register_client(Box::new(StubDataClient {
+                client_id: ClientId::from("BINANCE"),
+                venue: Some(Venue::from("BINANCE")),
+                calls: calls.clone(),
+            }))
+            .unwrap();
+        TestEngine { engine, rx, calls }
+    }
+
+    fn quotes() -> DataSubscription {
+        DataSubscription::Quotes(InstrumentId::from("ETHUSDT-PERP.BINANCE"))
+    }
+
+    fn subscribe(subscriber: &str) -> DataCommand {
+        DataCommand::Subscribe {
+            subscriber: Ustr::from(subscriber),
+            subscription: quotes(),
+            client_id: None,
+        }
+    }
+
+    fn unsubscribe(subscriber: &str) -> DataCommand {
+        DataCommand::Unsubscribe {
+            subscriber: Ustr::from(subscriber),
+            subscription: quotes(),
+            client_id: None,
+        }
+    }
+
+    fn request(requester: &str) -> DataRequest {
+        DataRequest {
+            request_id: UUID4::new(),
+            requester: Ustr::from(requester),
+            subscription: quotes(),
+            client_id: None,
+            start: None,
+            end: None,
+            limit: Some(100),
+            ts_init: UnixNanos::default(),
+        }
+    }
+
+    #[rstest]
+    fn test_subscriptions_deduplicated_across_subscribers(test_engine: TestEngine) {
+        let TestEngine {
+            mut engine, calls, ..
+        } = test_engine;
+
+        engine.execute(subscribe("ACTOR-001")).unwrap();
+        engine.execute(subscribe("ACTOR-002")).unwrap();
+        engine.execute(subscribe("ACTOR-002")).unwrap();
+        engine.execute(unsubscribe("ACTOR-001")).unwrap();
+
+        assert_eq!(engine.subscribers(&quotes()), vec![Ustr::from("ACTOR-002")]);
+        assert_eq!(
+            *calls.borrow(),
+            vec!["BINANCE:subscribe:data.quotes.BINANCE.ETHUSDT-PERP"]
+        );
+
+        engine.execute(unsubscribe("ACTOR-002")).unwrap();
+
+        assert!(engine.subscriptions().is_empty());
+        assert_eq!(
+            calls.borrow().last().unwrap(),
+            "BINANCE:unsubscribe:data.quotes.BINANCE.ETHUSDT-PERP"
+        );
+        assert_eq!(engine.command_count, 5);
+    }
+
+    #[rstest]
+    fn test_unsubscribe_when_not_subscribed(test_engine: TestEngine) {
+        let TestEngine {
+            mut engine, calls, ..
+        } = test_engine;
+
+        engine.execute(unsubscribe("ACTOR-001")).unwrap();
+
+        assert!(calls.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_subscribe_with_no_route(test_engine: TestEngine) {
+        let TestEngine { mut engine, .. } = test_engine;
+        let command = DataCommand::Subscribe {
+            subscriber: Ustr::from("ACTOR-001"),
+            subscription: DataSubscription::Trades(InstrumentId::from("AAPL.XNAS")),
+            client_id: None,
+        };
+
+        assert!(engine.execute(command).is_err());
+        assert!(engine.subscriptions().is_empty());
+    }
+
+    #[rstest]
+    fn test_subscribe_routes_to_default_client(test_engine: TestEngine) {
+        let TestEngine {
+            mut engine, calls, ..
+        } = test_engine;
+        engine
+            .register_default_client(Box::new(StubDataClient {
+                client_id: ClientId::from("DATABENTO"),
+                venue: None,
+                calls: calls.clone(),
+            }))
+            .unwrap();
+        let command = DataCommand::Subscribe {
+            subscriber: Ustr::from("ACTOR-001"),
+            subscription: DataSubscription::Trades(InstrumentId::from("AAPL.XNAS")),
+            client_id: None,
+        };
+
+        engine.execute(command).unwrap();
+
+        assert_eq!(
+            *calls.borrow(),
+            vec!["DATABENTO:subscribe:data.trades.XNAS.AAPL"]
+        );
+    }
+
+    #[rstest]
+    fn test_deregister_client_drops_subscriptions(test_engine: TestEngine) {
+        let TestEngine { mut engine, .. } = test_engine;
+        engine.execute(subscribe("ACTOR-001")).unwrap();
+
+        engine.deregister_client(ClientId::from("BINANCE")).unwrap();
+
+        assert!(engine.registered_clients().is_empty());
+        assert!(engine.subscriptions().is_empty());
+        assert!(engine.execute(subscribe("ACTOR-001")).is_err());
+    }
+
+    #[rstest]
+    fn test_request_and_response(test_engine: TestEngine) {
+        let TestEngine {
+            mut engine,
+            mut rx,
+            calls,
+        } = test_engine;
+        let request = request("ACTOR-001");
+        let request_id = request.request_id;
+
+        engine.request(request).unwrap();
+
+        assert!(engine.is_pending_request(&request_id));
+        assert_eq!(
+            *calls.borrow(),
+            vec!["BINANCE:request:data.quotes.BINANCE.ETHUSDT-PERP"]
+        );
+
+        engine.response(DataResponse {
+            correlation_id: request_id,
+            client_id: ClientId::from("BINANCE"),
+            data: vec![Data::Quote(quote_tick_ethusdt_binance())],
+            ts_init: UnixNanos::default(),
+        });
+
+        assert!(!engine.is_pending_request(&request_id));
+        match rx.try_recv().unwrap() {
+            DataEngineOutput::Response {
+                requester,
+                response,
+            } => {
+                assert_eq!(requester, Ustr::from("ACTOR-001"));
+                assert_eq!(response.data.len(), 1);
+            }
+            output => panic!("Unexpected output {output:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_response_with_no_pending_request_dropped(test_engine: TestEngine) {
+        let TestEngine {
+            mut engine, mut rx, ..
+        } = test_engine;
+
+        engine.response(DataResponse {
+            correlation_id: UUID4::new(),
+            client_id: ClientId::from("BINANCE"),
+            data: vec![],
+            ts_init: UnixNanos::default(),
+        });
+
+        assert!(rx.try_recv().is_err());
+        assert_eq!(engine.response_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_run_publishes_data_on_topic() {
+        let TestEngine {
+            mut engine, mut rx, ..
+        } = test_engine();
+        let (tx, engine_rx) = unbounded_channel();
+        let quote = quote_tick_ethusdt_binance();
+
+        tx.send(DataEngineMessage::Data(Data::Quote(quote)))
+            .unwrap();
+        drop(tx);
+        engine.run(engine_rx).await;
+
+        match rx.try_recv().unwrap() {
+            DataEngineOutput::Data { topic, data } => {
+                assert_eq!(topic, get_quotes_topic(&quote.instrument_id));
+                assert!(matches!(data, Data::Quote(q) if q == quote));
+            }
+            output => panic!("Unexpected output {output:?}"),
+        }
+        assert_eq!(engine.data_count, 1);
+    }
+}
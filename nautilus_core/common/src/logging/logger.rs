@@ -20,6 +20,7 @@ use std::{
     sync::{
         atomic::Ordering,
         mpsc::{channel, Receiver, SendError, Sender},
+        OnceLock,
     },
     thread::{self, JoinHandle},
 };
@@ -39,12 +40,17 @@ use nautilus_model::identifiers::trader_id::TraderId;
 use serde::{Deserialize, Serialize, Serializer};
 use ustr::Ustr;
 
-use super::{LOGGING_BYPASSED, LOGGING_REALTIME};
+use super::{LOGGING_BYPASSED, LOGGING_FILEOUT_LEVEL, LOGGING_REALTIME, LOGGING_STDOUT_LEVEL};
 use crate::{
     enums::{LogColor, LogLevel},
     logging::writer::{FileWriter, FileWriterConfig, LogWriter, StderrWriter, StdoutWriter},
 };
 
+const COMPONENT_LEVEL_DEFAULT: &str = "DEFAULT";
+
+/// The channel to the logging thread, retained for runtime control of the logger.
+static LOGGER_TX: OnceLock<Sender<LogEvent>> = OnceLock::new();
+
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.common")
@@ -164,10 +170,82 @@ pub struct Logger {
 pub enum LogEvent {
     /// A log line event.
     Log(LogLine),
+    /// A command to change the logger configuration at runtime.
+    Control(LogControl),
     /// A command to flush all logger buffers.
     Flush,
 }
 
+/// Represents a command to change the logger configuration of a running system.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LogControl {
+    /// Sets the maximum log level to write to stdout.
+    SetStdoutLevel(LevelFilter),
+    /// Sets the maximum log level to write to file.
+    SetFileoutLevel(LevelFilter),
+    /// Sets the maximum log level to write for the given component.
+    SetComponentLevel(Ustr, LevelFilter),
+    /// Clears the log level for the given component, reverting to the IO levels.
+    ClearComponentLevel(Ustr),
+}
+
+impl LogControl {
+    /// Parses the log control commands from the given `spec`.
+    ///
+    /// The spec uses the same format as [`LoggerConfig::from_spec`], e.g.
+    /// "stdout=Debug;fileout=Info;RiskEngine=Error". A component level of "Default"
+    /// clears any level previously set for the component.
+    pub fn from_spec(spec: &str) -> anyhow::Result<Vec<Self>> {
+        let mut controls = Vec::new();
+        for kv in spec.split(';').filter(|kv| !kv.is_empty()) {
+            let (k, v) = kv
+                .split_once('=')
+                .ok_or_else(|| anyhow::anyhow!("Invalid log control '{kv}', expected key=level"))?;
+
+            if k != "stdout" && k != "fileout" && v.eq_ignore_ascii_case(COMPONENT_LEVEL_DEFAULT) {
+                controls.push(Self::ClearComponentLevel(Ustr::from(k)));
+                continue;
+            }
+
+            let level = LevelFilter::from_str(v)
+                .map_err(|_| anyhow::anyhow!("Invalid log level '{v}' for '{k}'"))?;
+            let control = match k {
+                "stdout" => Self::SetStdoutLevel(level),
+                "fileout" => Self::SetFileoutLevel(level),
+                component => Self::SetComponentLevel(Ustr::from(component), level),
+            };
+            controls.push(control);
+        }
+        Ok(controls)
+    }
+}
+
+/// Applies the given `control` command to the running logger.
+///
+/// # Errors
+///
+/// This function returns an error if the logger has not been initialized,
+/// or the logging thread has shut down.
+pub fn apply_log_control(control: LogControl) -> anyhow::Result<()> {
+    let tx = LOGGER_TX
+        .get()
+        .ok_or_else(|| anyhow::anyhow!("Logger has not been initialized"))?;
+
+    // Update the IO levels checked on the calling side before forwarding to the thread
+    match control {
+        LogControl::SetStdoutLevel(level) => {
+            LOGGING_STDOUT_LEVEL.store(level as usize, Ordering::Relaxed);
+        }
+        LogControl::SetFileoutLevel(level) => {
+            LOGGING_FILEOUT_LEVEL.store(level as usize, Ordering::Relaxed);
+        }
+        _ => {}
+    }
+
+    tx.send(LogEvent::Control(control))
+        .map_err(|e| anyhow::anyhow!("Error sending log control: {e}"))
+}
+
 /// Represents a log event which includes a message.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LogLine {
@@ -262,10 +340,11 @@ impl Serialize for LogLineWrapper {
 
 impl Log for Logger {
     fn enabled(&self, metadata: &log::Metadata) -> bool {
+        let level = metadata.level() as usize;
         !LOGGING_BYPASSED.load(Ordering::Relaxed)
             && (metadata.level() == Level::Error
-                || metadata.level() <= self.config.stdout_level
-                || metadata.level() <= self.config.fileout_level)
+                || level <= LOGGING_STDOUT_LEVEL.load(Ordering::Relaxed)
+                || level <= LOGGING_FILEOUT_LEVEL.load(Ordering::Relaxed))
     }
 
     fn log(&self, record: &log::Record) {
@@ -319,7 +398,7 @@ impl Logger {
         let (tx, rx) = channel::<LogEvent>();
 
         let logger = Self {
-            tx,
+            tx: tx.clone(),
             config: config.clone(),
         };
 
@@ -332,6 +411,10 @@ impl Logger {
         let mut handle: Option<JoinHandle<()>> = None;
         match set_boxed_logger(Box::new(logger)) {
             Ok(()) => {
+                LOGGING_STDOUT_LEVEL.store(config.stdout_level as usize, Ordering::Relaxed);
+                LOGGING_FILEOUT_LEVEL.store(config.fileout_level as usize, Ordering::Relaxed);
+                let _ = LOGGER_TX.set(tx);
+
                 handle = Some(
                     thread::Builder::new()
                         .name("logging".to_string())
@@ -375,7 +458,7 @@ impl Logger {
         let LoggerConfig {
             stdout_level,
             fileout_level,
-            ref component_level,
+            mut component_level,
             is_colored,
             print_config: _,
         } = config;
@@ -388,7 +471,12 @@ impl Logger {
 
         // Conditionally create file writer based on fileout_level
         let mut file_writer_opt = if fileout_level != LevelFilter::Off {
-            FileWriter::new(trader_id, instance_id, file_config, fileout_level)
+            FileWriter::new(
+                trader_id.clone(),
+                instance_id.clone(),
+                file_config.clone(),
+                fileout_level,
+            )
         } else {
             None
         };
@@ -399,6 +487,27 @@ impl Logger {
                 LogEvent::Flush => {
                     break;
                 }
+                LogEvent::Control(control) => match control {
+                    LogControl::SetStdoutLevel(level) => stdout_writer.set_level(level),
+                    LogControl::SetFileoutLevel(level) => match file_writer_opt {
+                        Some(ref mut writer) => writer.set_level(level),
+                        None if level != LevelFilter::Off => {
+                            file_writer_opt = FileWriter::new(
+                                trader_id.clone(),
+                                instance_id.clone(),
+                                file_config.clone(),
+                                level,
+                            );
+                        }
+                        None => {}
+                    },
+                    LogControl::SetComponentLevel(component, level) => {
+                        component_level.insert(component, level);
+                    }
+                    LogControl::ClearComponentLevel(component) => {
+                        component_level.remove(&component);
+                    }
+                },
                 LogEvent::Log(line) => {
                     let timestamp = match LOGGING_REALTIME.load(Ordering::Relaxed) {
                         true => get_atomic_clock_realtime().get_time_ns(),
@@ -578,6 +687,30 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn log_control_parsing() {
+        let controls =
+            LogControl::from_spec("stdout=Debug;fileout=Info;RiskEngine=Error;Portfolio=Default;")
+                .unwrap();
+        assert_eq!(
+            controls,
+            vec![
+                LogControl::SetStdoutLevel(LevelFilter::Debug),
+                LogControl::SetFileoutLevel(LevelFilter::Info),
+                LogControl::SetComponentLevel(Ustr::from("RiskEngine"), LevelFilter::Error),
+                LogControl::ClearComponentLevel(Ustr::from("Portfolio")),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("stdout")]
+    #[case("stdout=Verbose")]
+    #[case("stdout=Default")]
+    fn log_control_parsing_invalid(#[case] spec: &str) {
+        assert!(LogControl::from_spec(spec).is_err());
+    }
+
     #[rstest]
     fn test_logging_to_file() {
         let config = LoggerConfig {
@@ -19,7 +19,7 @@ use std::{
     collections::HashMap,
     env,
     str::FromStr,
-    sync::atomic::{AtomicBool, Ordering},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
 };
 
 use log::LevelFilter;
@@ -43,6 +43,8 @@ static LOGGING_INITIALIZED: AtomicBool = AtomicBool::new(false);
 static LOGGING_BYPASSED: AtomicBool = AtomicBool::new(false);
 static LOGGING_REALTIME: AtomicBool = AtomicBool::new(true);
 static LOGGING_COLORED: AtomicBool = AtomicBool::new(true);
+static LOGGING_STDOUT_LEVEL: AtomicUsize = AtomicUsize::new(LevelFilter::Info as usize);
+static LOGGING_FILEOUT_LEVEL: AtomicUsize = AtomicUsize::new(LevelFilter::Off as usize);
 
 /// Returns whether the core logger is enabled.
 #[no_mangle]
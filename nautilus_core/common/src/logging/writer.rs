@@ -51,6 +51,11 @@ impl StdoutWriter {
             is_colored,
         }
     }
+
+    /// Sets the maximum log level to write.
+    pub fn set_level(&mut self, level: LevelFilter) {
+        self.level = level;
+    }
 }
 
 impl LogWriter for StdoutWriter {
@@ -193,6 +198,11 @@ impl FileWriter {
         }
     }
 
+    /// Sets the maximum log level to write.
+    pub fn set_level(&mut self, level: LevelFilter) {
+        self.level = level;
+    }
+
     fn create_log_file_path(
         file_config: &FileWriterConfig,
         trader_id: &str,
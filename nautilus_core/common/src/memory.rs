@@ -0,0 +1,186 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Lightweight memory accounting for the main data holding subsystems.
+//!
+//! Byte counts are approximations based on the in-memory size of the stored types,
+//! intended to help right-size instances rather than for exact profiling.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+/// A subsystem for which memory usage is accounted.
+#[derive(Copy, Clone, Debug, Display, Hash, PartialEq, Eq, EnumIter, Serialize, Deserialize)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum MemorySubsystem {
+    /// The order books held in the cache.
+    OrderBooks = 0,
+    /// The quote, trade and bar buffers held in the cache.
+    DataBuffers = 1,
+    /// The pending write commands queued for the cache database.
+    CacheWriterQueue = 2,
+}
+
+/// Tracks the current and high-water mark byte counts for a subsystem.
+#[derive(Debug)]
+pub struct MemoryGauge {
+    current: AtomicUsize,
+    high_water_mark: AtomicUsize,
+}
+
+impl MemoryGauge {
+    const fn new() -> Self {
+        Self {
+            current: AtomicUsize::new(0),
+            high_water_mark: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the current byte count.
+    #[must_use]
+    pub fn current(&self) -> usize {
+        self.current.load(Ordering::Relaxed)
+    }
+
+    /// Returns the highest byte count observed since the last reset.
+    #[must_use]
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water_mark.load(Ordering::Relaxed)
+    }
+
+    /// Sets the current byte count.
+    pub fn set(&self, bytes: usize) {
+        self.current.store(bytes, Ordering::Relaxed);
+        self.high_water_mark.fetch_max(bytes, Ordering::Relaxed);
+    }
+
+    /// Increments the current byte count by `bytes`.
+    pub fn add(&self, bytes: usize) {
+        let current = self.current.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        self.high_water_mark.fetch_max(current, Ordering::Relaxed);
+    }
+
+    /// Decrements the current byte count by `bytes` (saturating at zero).
+    pub fn sub(&self, bytes: usize) {
+        let _ = self
+            .current
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                Some(current.saturating_sub(bytes))
+            });
+    }
+
+    /// Resets the high-water mark to the current byte count.
+    pub fn reset(&self) {
+        self.high_water_mark
+            .store(self.current(), Ordering::Relaxed);
+    }
+}
+
+static GAUGES: [MemoryGauge; 3] = [MemoryGauge::new(), MemoryGauge::new(), MemoryGauge::new()];
+
+/// Returns the memory gauge for the given `subsystem`.
+#[must_use]
+pub fn memory_gauge(subsystem: MemorySubsystem) -> &'static MemoryGauge {
+    &GAUGES[subsystem as usize]
+}
+
+/// Represents a snapshot of the memory accounted for a subsystem.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryStats {
+    /// The subsystem for the stats.
+    pub subsystem: MemorySubsystem,
+    /// The approximate bytes currently held.
+    pub current_bytes: usize,
+    /// The highest approximate bytes held since the last reset.
+    pub high_water_mark_bytes: usize,
+}
+
+/// Returns a snapshot of the memory stats for all subsystems.
+#[must_use]
+pub fn memory_stats() -> Vec<MemoryStats> {
+    MemorySubsystem::iter()
+        .map(|subsystem| {
+            let gauge = memory_gauge(subsystem);
+            MemoryStats {
+                subsystem,
+                current_bytes: gauge.current(),
+                high_water_mark_bytes: gauge.high_water_mark(),
+            }
+        })
+        .collect()
+}
+
+/// Resets the high-water marks for all subsystems to their current byte counts.
+pub fn reset_memory_stats() {
+    for subsystem in MemorySubsystem::iter() {
+        memory_gauge(subsystem).reset();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_gauge_tracks_high_water_mark() {
+        let gauge = MemoryGauge::new();
+
+        gauge.add(100);
+        gauge.add(50);
+        gauge.sub(120);
+
+        assert_eq!(gauge.current(), 30);
+        assert_eq!(gauge.high_water_mark(), 150);
+    }
+
+    #[rstest]
+    fn test_gauge_sub_saturates() {
+        let gauge = MemoryGauge::new();
+
+        gauge.add(10);
+        gauge.sub(20);
+
+        assert_eq!(gauge.current(), 0);
+    }
+
+    #[rstest]
+    fn test_gauge_set_and_reset() {
+        let gauge = MemoryGauge::new();
+
+        gauge.set(200);
+        gauge.set(80);
+        assert_eq!(gauge.high_water_mark(), 200);
+
+        gauge.reset();
+
+        assert_eq!(gauge.current(), 80);
+        assert_eq!(gauge.high_water_mark(), 80);
+    }
+
+    #[rstest]
+    fn test_memory_stats_covers_all_subsystems() {
+        let stats = memory_stats();
+
+        assert_eq!(stats.len(), MemorySubsystem::iter().count());
+        assert_eq!(stats[0].subsystem, MemorySubsystem::OrderBooks);
+    }
+}
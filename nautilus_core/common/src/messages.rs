@@ -0,0 +1,169 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! System level messages published on the message bus.
+
+use std::fmt::{Display, Formatter};
+
+use indexmap::IndexMap;
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::identifiers::trader_id::TraderId;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{enums::ErrorSeverity, msgbus::BusMessage};
+
+/// The topic prefix for error events, the component name is appended.
+pub const ERROR_EVENTS_TOPIC: &str = "events.errors";
+
+/// Represents an operational error raised by a system component.
+///
+/// Error events are published on the message bus (and persisted by any backing database)
+/// so that failures such as adapter parse errors or dropped cache writes can be consumed
+/// by monitoring, rather than only being written to the logs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ErrorEvent {
+    /// The trader ID associated with the event.
+    pub trader_id: TraderId,
+    /// The component which raised the error.
+    pub component: Ustr,
+    /// The severity of the error.
+    pub severity: ErrorSeverity,
+    /// The machine readable error code, e.g. `PARSE_FAILURE`.
+    pub code: Ustr,
+    /// The human readable error message.
+    pub message: String,
+    /// The additional context for the error.
+    pub context: IndexMap<String, String>,
+    /// The unique identifier for the event.
+    pub event_id: UUID4,
+    /// The UNIX timestamp (nanoseconds) when the error occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the event was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl ErrorEvent {
+    /// Creates a new [`ErrorEvent`] instance.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        trader_id: TraderId,
+        component: Ustr,
+        severity: ErrorSeverity,
+        code: Ustr,
+        message: String,
+        context: IndexMap<String, String>,
+        event_id: UUID4,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            trader_id,
+            component,
+            severity,
+            code,
+            message,
+            context,
+            event_id,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the message bus topic for the event.
+    #[must_use]
+    pub fn topic(&self) -> String {
+        format!("{ERROR_EVENTS_TOPIC}.{}", self.component)
+    }
+
+    /// Returns the event as a JSON encoded [`BusMessage`] on the events topic.
+    pub fn to_bus_message(&self) -> anyhow::Result<BusMessage> {
+        Ok(BusMessage {
+            topic: self.topic(),
+            payload: serde_json::to_vec(self)?,
+        })
+    }
+
+    /// Returns an event decoded from the JSON `payload` of a bus message.
+    pub fn from_json_bytes(payload: &[u8]) -> anyhow::Result<Self> {
+        Ok(serde_json::from_slice(payload)?)
+    }
+}
+
+impl Display for ErrorEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(component={}, severity={}, code={}, message='{}', event_id={}, ts_event={})",
+            stringify!(ErrorEvent),
+            self.component,
+            self.severity,
+            self.code,
+            self.message,
+            self.event_id,
+            self.ts_event,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+
+    use super::*;
+
+    #[fixture]
+    fn error_event() -> ErrorEvent {
+        let mut context = IndexMap::new();
+        context.insert("instrument_id".to_string(), "ETHUSDT.BINANCE".to_string());
+
+        ErrorEvent::new(
+            TraderId::from("TRADER-001"),
+            Ustr::from("BinanceDataClient"),
+            ErrorSeverity::Error,
+            Ustr::from("PARSE_FAILURE"),
+            "Invalid price field".to_string(),
+            context,
+            UUID4::new(),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        )
+    }
+
+    #[rstest]
+    fn test_topic(error_event: ErrorEvent) {
+        assert_eq!(error_event.topic(), "events.errors.BinanceDataClient");
+    }
+
+    #[rstest]
+    fn test_bus_message_round_trip(error_event: ErrorEvent) {
+        let msg = error_event.to_bus_message().unwrap();
+        let decoded = ErrorEvent::from_json_bytes(&msg.payload).unwrap();
+
+        assert_eq!(msg.topic, error_event.topic());
+        assert_eq!(decoded, error_event);
+    }
+
+    #[rstest]
+    fn test_display(error_event: ErrorEvent) {
+        assert!(error_event.to_string().starts_with(
+            "ErrorEvent(component=BinanceDataClient, severity=ERROR, code=PARSE_FAILURE"
+        ));
+    }
+}
@@ -18,6 +18,8 @@ use std::collections::HashMap;
 use nautilus_core::uuid::UUID4;
 use nautilus_model::identifiers::trader_id::TraderId;
 
+use crate::messages::ErrorEvent;
+
 /// A generic message bus database facade.
 ///
 /// The main operations take a consistent `key` and `payload` which should provide enough
@@ -34,4 +36,11 @@ pub trait MessageBusDatabaseAdapter {
     ) -> anyhow::Result<Self::DatabaseType>;
     fn publish(&self, topic: String, payload: Vec<u8>) -> anyhow::Result<()>;
     fn close(&mut self) -> anyhow::Result<()>;
+
+    /// Publishes the given error `event` on its events topic, so that it is persisted
+    /// alongside other bus messages.
+    fn publish_error(&self, event: &ErrorEvent) -> anyhow::Result<()> {
+        let msg = event.to_bus_message()?;
+        self.publish(msg.topic, msg.payload)
+    }
 }
@@ -17,5 +17,6 @@
 
 pub mod core;
 pub mod database;
+pub mod switchboard;
 
 pub use self::core::{BusMessage, MessageBus};
@@ -0,0 +1,222 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Message bus topic naming for built-in data types and order events.
+//!
+//! Topics follow the same conventions as the Python `DataEngine` and `ExecutionEngine`, so
+//! that subscriptions made from either side match.
+
+use nautilus_model::{
+    data::{bar::BarType, custom::DataType, Data},
+    identifiers::{instrument_id::InstrumentId, strategy_id::StrategyId, venue::Venue},
+    types::currency::Currency,
+};
+use ustr::Ustr;
+
+#[must_use]
+pub fn get_deltas_topic(instrument_id: &InstrumentId) -> Ustr {
+    Ustr::from(&format!(
+        "data.book.deltas.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    ))
+}
+
+#[must_use]
+pub fn get_depth_topic(instrument_id: &InstrumentId) -> Ustr {
+    Ustr::from(&format!(
+        "data.book.depth.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    ))
+}
+
+#[must_use]
+pub fn get_quotes_topic(instrument_id: &InstrumentId) -> Ustr {
+    Ustr::from(&format!(
+        "data.quotes.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    ))
+}
+
+#[must_use]
+pub fn get_trades_topic(instrument_id: &InstrumentId) -> Ustr {
+    Ustr::from(&format!(
+        "data.trades.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    ))
+}
+
+#[must_use]
+pub fn get_bars_topic(bar_type: &BarType) -> Ustr {
+    Ustr::from(&format!("data.bars.{bar_type}"))
+}
+
+#[must_use]
+pub fn get_funding_rates_topic(instrument_id: &InstrumentId) -> Ustr {
+    Ustr::from(&format!(
+        "data.funding_rates.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    ))
+}
+
+#[must_use]
+pub fn get_borrow_rates_topic(venue: &Venue, currency: &Currency) -> Ustr {
+    Ustr::from(&format!("data.borrow_rates.{venue}.{currency}"))
+}
+
+#[must_use]
+pub fn get_instrument_status_topic(instrument_id: &InstrumentId) -> Ustr {
+    Ustr::from(&format!(
+        "data.status.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    ))
+}
+
+#[must_use]
+pub fn get_venue_status_topic(venue: &Venue) -> Ustr {
+    Ustr::from(&format!("data.status.{venue}"))
+}
+
+#[must_use]
+pub fn get_imbalances_topic(instrument_id: &InstrumentId) -> Ustr {
+    Ustr::from(&format!(
+        "data.imbalances.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    ))
+}
+
+#[must_use]
+pub fn get_custom_data_topic(data_type: &DataType) -> Ustr {
+    Ustr::from(&format!("data.{}", data_type.topic))
+}
+
+#[must_use]
+pub fn get_order_events_topic(strategy_id: &StrategyId) -> Ustr {
+    Ustr::from(&format!("events.order.{strategy_id}"))
+}
+
+/// Returns the topic on which the given `data` is published.
+#[must_use]
+pub fn get_data_topic(data: &Data) -> Ustr {
+    match data {
+        Data::Delta(delta) => get_deltas_topic(&delta.instrument_id),
+        Data::Deltas(deltas) => get_deltas_topic(&deltas.instrument_id),
+        Data::Depth10(depth) => get_depth_topic(&depth.instrument_id),
+        Data::Quote(quote) => get_quotes_topic(&quote.instrument_id),
+        Data::Trade(trade) => get_trades_topic(&trade.instrument_id),
+        Data::Bar(bar) => get_bars_topic(&bar.bar_type),
+        Data::FundingRate(funding) => get_funding_rates_topic(&funding.instrument_id),
+        Data::BorrowRate(borrow) => get_borrow_rates_topic(&borrow.venue, &borrow.currency),
+        Data::InstrumentStatus(status) => get_instrument_status_topic(&status.instrument_id),
+        Data::VenueStatus(status) => get_venue_status_topic(&status.venue),
+        Data::AuctionImbalance(imbalance) => get_imbalances_topic(&imbalance.instrument_id),
+        Data::Custom(custom) => get_custom_data_topic(&custom.data_type),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::{
+            borrow::BorrowRateUpdate,
+            funding::FundingRateUpdate,
+            quote::QuoteTick,
+            status::{InstrumentStatus, VenueStatus},
+        },
+        enums::{HaltReason, MarketStatus},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::msgbus::core::is_matching;
+
+    #[rstest]
+    fn test_get_data_topic_for_funding_rate() {
+        let funding = FundingRateUpdate::new(
+            InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+            0.0001,
+            0.into(),
+            1.into(),
+            1.into(),
+        );
+
+        let topic = get_data_topic(&Data::FundingRate(funding));
+
+        assert_eq!(topic.as_str(), "data.funding_rates.BINANCE.BTCUSDT-PERP");
+        assert!(is_matching(&topic, &Ustr::from("data.funding_rates.*")));
+    }
+
+    #[rstest]
+    fn test_get_data_topic_for_borrow_rate() {
+        let borrow = BorrowRateUpdate::new(
+            Venue::from("BINANCE"),
+            Currency::USDT(),
+            0.05,
+            1.into(),
+            1.into(),
+        );
+
+        let topic = get_data_topic(&Data::BorrowRate(borrow));
+
+        assert_eq!(topic.as_str(), "data.borrow_rates.BINANCE.USDT");
+        assert!(is_matching(
+            &topic,
+            &Ustr::from("data.borrow_rates.BINANCE.*")
+        ));
+    }
+
+    #[rstest]
+    fn test_get_data_topic_for_quote() {
+        let quote = QuoteTick::default();
+
+        let topic = get_data_topic(&Data::Quote(quote));
+
+        assert_eq!(topic, get_quotes_topic(&quote.instrument_id));
+        assert!(topic.starts_with("data.quotes."));
+    }
+
+    #[rstest]
+    fn test_get_data_topic_for_status() {
+        let instrument_status = InstrumentStatus::new(
+            InstrumentId::from("MSFT.XNAS"),
+            MarketStatus::Halt,
+            HaltReason::General,
+            1.into(),
+            1.into(),
+        );
+        let venue_status = VenueStatus::new(
+            Venue::from("XNAS"),
+            MarketStatus::Closed,
+            HaltReason::NotHalted,
+            1.into(),
+            1.into(),
+        );
+
+        let instrument_topic = get_data_topic(&Data::InstrumentStatus(instrument_status));
+        let venue_topic = get_data_topic(&Data::VenueStatus(venue_status));
+
+        assert_eq!(instrument_topic.as_str(), "data.status.XNAS.MSFT");
+        assert_eq!(venue_topic.as_str(), "data.status.XNAS");
+    }
+
+    #[rstest]
+    fn test_get_order_events_topic() {
+        let topic = get_order_events_topic(&StrategyId::from("EMACross-001"));
+
+        assert_eq!(topic.as_str(), "events.order.EMACross-001");
+    }
+}
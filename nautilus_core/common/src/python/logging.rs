@@ -16,7 +16,10 @@
 use std::collections::HashMap;
 
 use log::LevelFilter;
-use nautilus_core::uuid::UUID4;
+use nautilus_core::{
+    python::{to_pyruntime_err, to_pyvalue_err},
+    uuid::UUID4,
+};
 use nautilus_model::identifiers::trader_id::TraderId;
 use pyo3::prelude::*;
 use ustr::Ustr;
@@ -25,7 +28,7 @@ use crate::{
     enums::{LogColor, LogLevel},
     logging::{
         self, headers,
-        logger::{self, LogGuard, LoggerConfig},
+        logger::{self, LogControl, LogGuard, LoggerConfig},
         logging_set_bypass, map_log_level_to_filter, parse_level_filter_str,
         writer::FileWriterConfig,
     },
@@ -133,6 +136,20 @@ fn parse_component_levels(
     }
 }
 
+/// Changes the log levels of the running logger from the given `spec`.
+///
+/// The spec has the same format as the `NAUTILUS_LOG` configuration, e.g.
+/// "stdout=Debug;RiskEngine=Error", with a component level of "Default" clearing
+/// any level previously set for the component.
+#[pyfunction]
+#[pyo3(name = "set_log_levels")]
+pub fn py_set_log_levels(spec: &str) -> PyResult<()> {
+    for control in LogControl::from_spec(spec).map_err(to_pyvalue_err)? {
+        logger::apply_log_control(control).map_err(to_pyruntime_err)?;
+    }
+    Ok(())
+}
+
 /// Create a new log event.
 #[pyfunction]
 #[pyo3(name = "logger_log")]
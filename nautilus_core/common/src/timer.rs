@@ -310,6 +310,12 @@ impl LiveTimer {
                 // first then no tick has been consumed (no event was ready).
                 tokio::select! {
                     _ = timer.tick() => {
+                        // Simulate a timer task dying without expiring
+                        #[cfg(feature = "chaos")]
+                        if nautilus_core::chaos::is_timers_killed() {
+                            return Ok(());
+                        }
+
                         let now_ns = clock.get_time_ns();
                         call_python_with_time_event(event_name, next_time_ns, now_ns, &callback);
 
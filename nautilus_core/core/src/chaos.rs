@@ -0,0 +1,212 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Fault injection hooks for chaos testing of infrastructure components.
+//!
+//! Faults are configured process-wide and are only compiled into components with the `chaos`
+//! feature, so production builds are unaffected. Random faults are drawn from a seeded
+//! generator, so a test run is deterministic for a given seed.
+
+use std::{sync::Mutex, time::Duration};
+
+use crate::correctness::check_in_range_inclusive_f64;
+
+/// Configuration for the faults to inject.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChaosConfig {
+    /// The probability in the range [0, 1] that a cache database write is dropped.
+    pub cache_write_drop_prob: f64,
+    /// The delay to apply before each socket read.
+    pub socket_read_delay: Option<Duration>,
+    /// If live timers should silently stop firing (without being marked as expired).
+    pub kill_timers: bool,
+    /// The seed for the random fault generator.
+    pub seed: u64,
+}
+
+impl Default for ChaosConfig {
+    /// Creates a new default [`ChaosConfig`] instance which injects no faults.
+    fn default() -> Self {
+        Self {
+            cache_write_drop_prob: 0.0,
+            socket_read_delay: None,
+            kill_timers: false,
+            seed: 42,
+        }
+    }
+}
+
+/// The state of the fault injector, holding the config and random generator.
+#[derive(Clone, Debug)]
+pub struct ChaosState {
+    pub config: ChaosConfig,
+    rng_state: u64,
+}
+
+impl ChaosState {
+    /// Creates a new [`ChaosState`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `cache_write_drop_prob` is not in the range [0, 1].
+    pub fn new(config: ChaosConfig) -> anyhow::Result<Self> {
+        check_in_range_inclusive_f64(
+            config.cache_write_drop_prob,
+            0.0,
+            1.0,
+            "config.cache_write_drop_prob",
+        )?;
+        let rng_state = config.seed;
+        Ok(Self { config, rng_state })
+    }
+
+    /// Returns whether the next cache database write should be dropped.
+    pub fn should_drop_cache_write(&mut self) -> bool {
+        if self.config.cache_write_drop_prob <= 0.0 {
+            return false;
+        }
+        self.next_f64() < self.config.cache_write_drop_prob
+    }
+
+    // SplitMix64, which is sufficient for fault sampling without an extra dependency
+    fn next_f64(&mut self) -> f64 {
+        self.rng_state = self.rng_state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.rng_state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
+
+static CHAOS: Mutex<Option<ChaosState>> = Mutex::new(None);
+
+/// Sets the process-wide faults to inject, replacing any existing config.
+///
+/// # Errors
+///
+/// This function returns an error if the `config` is invalid.
+pub fn set_chaos_config(config: ChaosConfig) -> anyhow::Result<()> {
+    let state = ChaosState::new(config)?;
+    *CHAOS.lock().expect("Failed to lock chaos state") = Some(state);
+    Ok(())
+}
+
+/// Clears the process-wide faults, so that no further faults are injected.
+pub fn clear_chaos_config() {
+    *CHAOS.lock().expect("Failed to lock chaos state") = None;
+}
+
+/// Returns whether the next cache database write should be dropped.
+#[must_use]
+pub fn should_drop_cache_write() -> bool {
+    CHAOS
+        .lock()
+        .expect("Failed to lock chaos state")
+        .as_mut()
+        .map_or(false, ChaosState::should_drop_cache_write)
+}
+
+/// Returns the delay to apply before the next socket read (if any).
+#[must_use]
+pub fn socket_read_delay() -> Option<Duration> {
+    CHAOS
+        .lock()
+        .expect("Failed to lock chaos state")
+        .as_ref()
+        .and_then(|state| state.config.socket_read_delay)
+}
+
+/// Returns whether live timers should silently stop firing.
+#[must_use]
+pub fn is_timers_killed() -> bool {
+    CHAOS
+        .lock()
+        .expect("Failed to lock chaos state")
+        .as_ref()
+        .map_or(false, |state| state.config.kill_timers)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn drop_count(config: ChaosConfig, writes: usize) -> usize {
+        let mut state = ChaosState::new(config).unwrap();
+        (0..writes)
+            .filter(|_| state.should_drop_cache_write())
+            .count()
+    }
+
+    #[rstest]
+    #[case(0.0, 0)]
+    #[case(1.0, 1_000)]
+    fn test_should_drop_cache_write_bounds(#[case] prob: f64, #[case] expected: usize) {
+        let config = ChaosConfig {
+            cache_write_drop_prob: prob,
+            ..Default::default()
+        };
+
+        assert_eq!(drop_count(config, 1_000), expected);
+    }
+
+    #[rstest]
+    fn test_should_drop_cache_write_is_deterministic_for_seed() {
+        let config = ChaosConfig {
+            cache_write_drop_prob: 0.25,
+            ..Default::default()
+        };
+
+        let dropped = drop_count(config.clone(), 10_000);
+
+        assert_eq!(dropped, drop_count(config, 10_000));
+        assert!((2_000..3_000).contains(&dropped));
+    }
+
+    #[rstest]
+    fn test_new_with_invalid_drop_prob() {
+        let config = ChaosConfig {
+            cache_write_drop_prob: 1.5,
+            ..Default::default()
+        };
+
+        assert!(ChaosState::new(config).is_err());
+    }
+
+    #[rstest]
+    fn test_set_and_clear_chaos_config() {
+        let config = ChaosConfig {
+            socket_read_delay: Some(Duration::from_millis(10)),
+            kill_timers: true,
+            ..Default::default()
+        };
+
+        set_chaos_config(config).unwrap();
+        let delay = socket_read_delay();
+        let killed = is_timers_killed();
+        clear_chaos_config();
+
+        assert_eq!(delay, Some(Duration::from_millis(10)));
+        assert!(killed);
+        assert_eq!(socket_read_delay(), None);
+        assert!(!is_timers_killed());
+        assert!(!should_drop_cache_write());
+    }
+}
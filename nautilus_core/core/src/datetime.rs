@@ -94,6 +94,20 @@ pub fn unix_nanos_to_iso8601(unix_nanos: UnixNanos) -> String {
     dt.to_rfc3339_opts(SecondsFormat::Nanos, true)
 }
 
+/// Converts an ISO 8601 (RFC 3339) formatted string to a UNIX nanoseconds timestamp.
+///
+/// # Errors
+///
+/// This function returns an error if the string is not a valid RFC 3339 datetime, or is
+/// before the UNIX epoch.
+pub fn iso8601_to_unix_nanos(value: &str) -> anyhow::Result<UnixNanos> {
+    let dt = DateTime::parse_from_rfc3339(value)?;
+    let nanos = dt
+        .timestamp_nanos_opt()
+        .ok_or_else(|| anyhow::anyhow!("Datetime out of range, was {value}"))?;
+    Ok(UnixNanos::from(u64::try_from(nanos)?))
+}
+
 /// Floor the given UNIX nanoseconds to the nearest microsecond.
 #[must_use]
 pub fn floor_to_nearest_microsecond(unix_nanos: u64) -> u64 {
@@ -280,4 +294,24 @@ mod tests {
             .unwrap();
         assert!(!is_within_last_24_hours(UnixNanos::from(past_ns as u64)).unwrap());
     }
+
+    #[rstest]
+    #[case("1970-01-01T00:00:00Z", 0)]
+    #[case("2023-02-09T20:19:35.39625135Z", 1_675_973_975_396_251_350)]
+    #[case("2023-02-09T21:19:35.396+01:00", 1_675_973_975_396_000_000)]
+    fn test_iso8601_to_unix_nanos(#[case] value: &str, #[case] expected: u64) {
+        let result = iso8601_to_unix_nanos(value).unwrap();
+        assert_eq!(result, UnixNanos::from(expected));
+        assert_eq!(
+            iso8601_to_unix_nanos(&unix_nanos_to_iso8601(result)).unwrap(),
+            result
+        );
+    }
+
+    #[rstest]
+    #[case("2023-02-09")]
+    #[case("1969-12-31T23:59:59Z")]
+    fn test_iso8601_to_unix_nanos_invalid(#[case] value: &str) {
+        assert!(iso8601_to_unix_nanos(value).is_err());
+    }
 }
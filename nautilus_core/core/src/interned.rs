@@ -0,0 +1,117 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Introspection of the global interned string ([`Ustr`]) cache.
+//!
+//! Interned strings are never freed, so a very large dynamic set of identifiers (e.g. symbols)
+//! will grow the cache for the lifetime of the process. These functions report the cache size
+//! and its largest entries, and allow a known universe of strings to be interned at startup.
+
+use std::fmt::{Display, Formatter};
+
+use ustr::Ustr;
+
+/// Represents a snapshot of the interned string cache metrics.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InternedStringStats {
+    /// The number of interned strings.
+    pub num_entries: usize,
+    /// The total bytes allocated by the cache.
+    pub total_allocated: usize,
+    /// The total bytes of capacity of the cache.
+    pub total_capacity: usize,
+}
+
+impl Display for InternedStringStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(num_entries={}, total_allocated={}, total_capacity={})",
+            stringify!(InternedStringStats),
+            self.num_entries,
+            self.total_allocated,
+            self.total_capacity,
+        )
+    }
+}
+
+/// Returns the current interned string cache metrics.
+#[must_use]
+pub fn interned_string_stats() -> InternedStringStats {
+    InternedStringStats {
+        num_entries: ustr::num_entries(),
+        total_allocated: ustr::total_allocated(),
+        total_capacity: ustr::total_capacity(),
+    }
+}
+
+/// Returns the `n` largest interned strings, in descending order of length.
+#[must_use]
+pub fn largest_interned_strings(n: usize) -> Vec<Ustr> {
+    let mut entries: Vec<Ustr> = ustr::string_cache_iter().map(Ustr::from).collect();
+    entries.sort_unstable_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    entries.truncate(n);
+    entries
+}
+
+/// Interns each of the given `values`, returning the number of newly interned strings.
+///
+/// This can be used to intern a known symbol universe at startup.
+pub fn pre_intern<'a>(values: impl IntoIterator<Item = &'a str>) -> usize {
+    let initial = ustr::num_entries();
+    for value in values {
+        let _ = Ustr::from(value);
+    }
+    ustr::num_entries().saturating_sub(initial)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_pre_intern_and_stats() {
+        let values = [
+            "PRE-INTERN-TEST-1.XNAS",
+            "PRE-INTERN-TEST-2.XNAS",
+            "PRE-INTERN-TEST-1.XNAS",
+        ];
+
+        let count = pre_intern(values);
+        let stats = interned_string_stats();
+
+        // Other tests may intern strings concurrently
+        assert!(count >= 2);
+        assert!(stats.num_entries >= 2);
+        assert!(stats.total_capacity >= stats.total_allocated);
+        assert_eq!(pre_intern(["PRE-INTERN-TEST-1.XNAS"]), 0);
+    }
+
+    #[rstest]
+    fn test_largest_interned_strings() {
+        let value = "LARGEST-INTERNED-STRING-TEST-".repeat(64);
+        pre_intern([value.as_str()]);
+
+        let largest = largest_interned_strings(1);
+
+        assert_eq!(largest.len(), 1);
+        assert!(largest[0].len() >= value.len());
+    }
+}
@@ -24,12 +24,14 @@
 //! depending on the intended use case, i.e. whether to provide Python bindings
 //! for the main `nautilus_trader` Python package, or as part of a Rust only build.
 //!
+//! - `chaos`: Enables fault injection hooks for chaos testing
 //! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`
 //! - `python`: Enables Python bindings from `pyo3`
 
 pub mod correctness;
 pub mod datetime;
 pub mod equality;
+pub mod interned;
 pub mod message;
 pub mod nanos;
 pub mod parsing;
@@ -37,6 +39,9 @@ pub mod serialization;
 pub mod time;
 pub mod uuid;
 
+#[cfg(feature = "chaos")]
+pub mod chaos;
+
 #[cfg(feature = "ffi")]
 pub mod ffi;
 
@@ -0,0 +1,46 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use pyo3::prelude::*;
+
+use crate::interned::{interned_string_stats, largest_interned_strings, pre_intern};
+
+#[must_use]
+#[pyfunction(name = "interned_string_stats")]
+pub fn py_interned_string_stats() -> HashMap<&'static str, usize> {
+    let stats = interned_string_stats();
+    HashMap::from([
+        ("num_entries", stats.num_entries),
+        ("total_allocated", stats.total_allocated),
+        ("total_capacity", stats.total_capacity),
+    ])
+}
+
+#[must_use]
+#[pyfunction(name = "largest_interned_strings")]
+pub fn py_largest_interned_strings(n: usize) -> Vec<String> {
+    largest_interned_strings(n)
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[must_use]
+#[pyfunction(name = "pre_intern")]
+pub fn py_pre_intern(values: Vec<String>) -> usize {
+    pre_intern(values.iter().map(String::as_str))
+}
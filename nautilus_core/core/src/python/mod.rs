@@ -28,6 +28,7 @@ use pyo3::{
 use crate::uuid::UUID4;
 pub mod casing;
 pub mod datetime;
+pub mod interned;
 pub mod serialization;
 pub mod uuid;
 
@@ -66,5 +67,8 @@ pub fn core(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(datetime::py_unix_nanos_to_iso8601, m)?)?;
     m.add_function(wrap_pyfunction!(datetime::py_last_weekday_nanos, m)?)?;
     m.add_function(wrap_pyfunction!(datetime::py_is_within_last_24_hours, m)?)?;
+    m.add_function(wrap_pyfunction!(interned::py_interned_string_stats, m)?)?;
+    m.add_function(wrap_pyfunction!(interned::py_largest_interned_strings, m)?)?;
+    m.add_function(wrap_pyfunction!(interned::py_pre_intern, m)?)?;
     Ok(())
 }
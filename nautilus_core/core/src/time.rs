@@ -18,7 +18,7 @@
 use std::{
     ops::Deref,
     sync::{
-        atomic::{AtomicBool, AtomicU64, Ordering},
+        atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
         OnceLock,
     },
     time::{Duration, SystemTime, UNIX_EPOCH},
@@ -67,6 +67,8 @@ pub fn duration_since_unix_epoch() -> Duration {
 ///    the clock is in a manual or static mode, allowing for controlled time setting.
 /// - `timestamp_ns`: The last recorded time for the clock in Unix nanoseconds.
 ///    This value is atomically updated and represents the precise time measurement.
+/// - `offset_ns`: The correction applied to the system time in real-time mode, as
+///    measured by an external clock synchronization source (e.g. PTP or NTP).
 #[repr(C)]
 #[derive(Debug)]
 pub struct AtomicTime {
@@ -74,6 +76,8 @@ pub struct AtomicTime {
     pub realtime: AtomicBool,
     /// The last recorded time for the clock in UNIX nanoseconds.
     pub timestamp_ns: AtomicU64,
+    /// The correction (nanoseconds) added to the system time in real-time mode.
+    pub offset_ns: AtomicI64,
 }
 
 impl Deref for AtomicTime {
@@ -101,6 +105,7 @@ impl AtomicTime {
         Self {
             realtime: AtomicBool::new(realtime),
             timestamp_ns: AtomicU64::new(time.into()),
+            offset_ns: AtomicI64::new(0),
         }
     }
 
@@ -144,10 +149,24 @@ impl AtomicTime {
         UnixNanos::from(self.fetch_add(delta, Ordering::Relaxed) + delta)
     }
 
+    /// Returns the correction (nanoseconds) added to the system time in real-time mode.
+    #[must_use]
+    pub fn offset_ns(&self) -> i64 {
+        self.offset_ns.load(Ordering::Relaxed)
+    }
+
+    /// Sets the correction (nanoseconds) added to the system time in real-time mode.
+    ///
+    /// A negative offset will not cause the clock to go backwards, as the time remains monotonic.
+    pub fn set_offset_ns(&self, offset_ns: i64) {
+        self.offset_ns.store(offset_ns, Ordering::Relaxed);
+    }
+
     /// Stores and returns current time.
     pub fn time_since_epoch(&self) -> UnixNanos {
         // Increment by 1 nanosecond to keep increasing time
-        let now = duration_since_unix_epoch().as_nanos() as u64 + 1;
+        let system_ns = duration_since_unix_epoch().as_nanos() as u64;
+        let now = system_ns.saturating_add_signed(self.offset_ns()) + 1;
         let last = self.load(Ordering::SeqCst) + 1;
         let time = now.max(last);
         self.store(time, Ordering::SeqCst);
@@ -191,6 +210,30 @@ mod tests {
         assert!(duration > Duration::from_secs(1_650_000_000));
     }
 
+    #[rstest]
+    fn test_offset_applied_to_realtime() {
+        let time = AtomicTime::new(true, UnixNanos::default());
+        let offset_ns = 3_600 * NANOSECONDS_IN_SECOND as i64;
+        time.set_offset_ns(offset_ns);
+
+        let system_ns = duration_since_unix_epoch().as_nanos() as u64;
+        let result = time.get_time_ns();
+
+        assert_eq!(time.offset_ns(), offset_ns);
+        assert!(result.as_u64() >= system_ns + offset_ns as u64);
+    }
+
+    #[rstest]
+    fn test_negative_offset_remains_monotonic() {
+        let time = AtomicTime::new(true, UnixNanos::default());
+        let result1 = time.get_time_ns();
+
+        time.set_offset_ns(-(NANOSECONDS_IN_SECOND as i64));
+        let result2 = time.get_time_ns();
+
+        assert!(result2 > result1);
+    }
+
     #[rstest]
     fn test_unix_timestamp_is_monotonic_increasing() {
         let time = AtomicTime::new(true, UnixNanos::default());
@@ -107,7 +107,7 @@ impl Serialize for UUID4 {
     where
         S: Serializer,
     {
-        self.value.serialize(serializer)
+        self.to_string().serialize(serializer)
     }
 }
 
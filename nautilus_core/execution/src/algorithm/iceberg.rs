@@ -0,0 +1,303 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An iceberg execution algorithm, which shows only part of a limit order at a time.
+
+use std::collections::HashMap;
+
+use log::warn;
+use nautilus_model::{
+    events::order::OrderEventAny,
+    identifiers::{client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId},
+    orders::any::OrderAny,
+    types::quantity::Quantity,
+};
+use ustr::Ustr;
+
+use super::{ExecAlgorithm, ExecAlgorithmContext};
+
+/// Provides an iceberg execution algorithm, which executes a primary limit order as a
+/// sequence of visible slices at the primary price.
+///
+/// The primary order must have the `exec_algorithm_params`:
+/// - `visible_qty`: the quantity of each visible slice.
+///
+/// The next slice is spawned when the previous slice is filled, and the primary order
+/// itself is submitted once its quantity is no greater than the visible quantity.
+pub struct IcebergExecAlgorithm {
+    id: ExecAlgorithmId,
+    visible_qtys: HashMap<ClientOrderId, Quantity>,
+}
+
+impl IcebergExecAlgorithm {
+    /// Creates a new [`IcebergExecAlgorithm`] instance, with the ID "ICEBERG" unless given.
+    #[must_use]
+    pub fn new(id: Option<ExecAlgorithmId>) -> Self {
+        Self {
+            id: id.unwrap_or_else(|| ExecAlgorithmId::from("ICEBERG")),
+            visible_qtys: HashMap::new(),
+        }
+    }
+
+    /// Returns whether the primary order is being executed in slices.
+    #[must_use]
+    pub fn is_executing(&self, primary_id: &ClientOrderId) -> bool {
+        self.visible_qtys.contains_key(primary_id)
+    }
+
+    fn execute_slice(
+        &mut self,
+        ctx: &mut ExecAlgorithmContext,
+        mut primary: OrderAny,
+        visible_qty: Quantity,
+    ) -> anyhow::Result<()> {
+        let primary_id = primary.client_order_id();
+        if primary.quantity() <= visible_qty {
+            self.visible_qtys.remove(&primary_id);
+            return ctx.submit_order(primary, None);
+        }
+
+        let OrderAny::Limit(limit) = &primary else {
+            anyhow::bail!("Cannot execute {primary_id}: iceberg requires a limit order");
+        };
+        let (price, expire_time, post_only) = (limit.price, limit.expire_time, limit.is_post_only);
+        let time_in_force = primary.time_in_force();
+        let reduce_only = primary.is_reduce_only();
+
+        let spawned = ctx.spawn_limit(
+            &mut primary,
+            visible_qty,
+            price,
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+        )?;
+        self.visible_qtys.insert(primary_id, visible_qty);
+        ctx.submit_order(spawned, None)
+    }
+}
+
+impl ExecAlgorithm for IcebergExecAlgorithm {
+    fn id(&self) -> ExecAlgorithmId {
+        self.id
+    }
+
+    fn on_order(&mut self, ctx: &mut ExecAlgorithmContext, order: &OrderAny) -> anyhow::Result<()> {
+        let primary_id = order.client_order_id();
+        if !matches!(order, OrderAny::Limit(_)) {
+            anyhow::bail!(
+                "Cannot execute {primary_id}: iceberg requires a limit order, was {}",
+                order.order_type()
+            );
+        }
+
+        let value = order
+            .exec_algorithm_params()
+            .and_then(|params| params.get(&Ustr::from("visible_qty")))
+            .ok_or_else(|| {
+                anyhow::anyhow!("Cannot execute {primary_id}: no `visible_qty` param")
+            })?;
+        let visible_qty = value
+            .parse::<f64>()
+            .map_err(anyhow::Error::from)
+            .and_then(|value| Quantity::new(value, order.quantity().precision))
+            .map_err(|e| anyhow::anyhow!("Invalid `visible_qty` param '{value}': {e}"))?;
+        if visible_qty.is_zero() {
+            anyhow::bail!("Cannot execute {primary_id}: `visible_qty` was zero");
+        }
+
+        self.execute_slice(ctx, order.clone(), visible_qty)
+    }
+
+    fn on_order_event(
+        &mut self,
+        ctx: &mut ExecAlgorithmContext,
+        event: &OrderEventAny,
+    ) -> anyhow::Result<()> {
+        let Some(primary_id) = ctx
+            .cache()
+            .order(&event.client_order_id())
+            .and_then(OrderAny::exec_spawn_id)
+        else {
+            return Ok(());
+        };
+        let Some(visible_qty) = self.visible_qtys.get(&primary_id).copied() else {
+            return Ok(());
+        };
+
+        match event {
+            OrderEventAny::Filled(_) => {
+                let primary = ctx
+                    .cache()
+                    .order(&primary_id)
+                    .cloned()
+                    .ok_or_else(|| anyhow::anyhow!("Primary order {primary_id} not found"))?;
+                self.execute_slice(ctx, primary, visible_qty)
+            }
+            OrderEventAny::Denied(_)
+            | OrderEventAny::Rejected(_)
+            | OrderEventAny::Canceled(_)
+            | OrderEventAny::Expired(_) => {
+                warn!(
+                    "Stopped executing {primary_id}: slice {} closed unfilled",
+                    event.client_order_id()
+                );
+                self.visible_qtys.remove(&primary_id);
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::cache::Cache;
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use nautilus_model::{
+        enums::{OrderSide, TimeInForce},
+        identifiers::{
+            client_id::ClientId, instrument_id::InstrumentId, strategy_id::StrategyId,
+            trader_id::TraderId, venue_order_id::VenueOrderId,
+        },
+        instruments::{any::InstrumentAny, stubs::audusd_sim},
+        orders::{limit::LimitOrder, stubs::TestOrderEventStubs},
+        types::price::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        algorithm::ExecAlgorithmEngine,
+        messages::{submit::SubmitOrder, TradingCommand},
+    };
+
+    fn primary_order() -> OrderAny {
+        let client_order_id = ClientOrderId::from("O-001");
+        let params = HashMap::from([(Ustr::from("visible_qty"), Ustr::from("100000"))]);
+        let order = LimitOrder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::from("AUD/USD.SIM"),
+            client_order_id,
+            OrderSide::Buy,
+            Quantity::from(250_000),
+            Price::from("1.00000"),
+            TimeInForce::Gtc,
+            None,
+            false,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(ExecAlgorithmId::from("ICEBERG")),
+            Some(params),
+            Some(client_order_id),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        OrderAny::Limit(order)
+    }
+
+    fn submitted_order(commands: Vec<TradingCommand>) -> OrderAny {
+        assert_eq!(commands.len(), 1);
+        match commands.into_iter().next().unwrap() {
+            TradingCommand::SubmitOrder(submit) => submit.order,
+            command => panic!("Expected submit, was {command}"),
+        }
+    }
+
+    #[rstest]
+    fn test_iceberg_spawns_next_slice_on_fill() {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let mut cache = Cache::default();
+        let mut engine = ExecAlgorithmEngine::new(TraderId::default());
+        engine
+            .register(Box::new(IcebergExecAlgorithm::new(None)))
+            .unwrap();
+        let primary = primary_order();
+        let submit = SubmitOrder::new(
+            primary.trader_id(),
+            ClientId::from("SIM"),
+            primary.strategy_id(),
+            primary.instrument_id(),
+            primary.client_order_id(),
+            VenueOrderId::default(),
+            primary,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        let mut slices = Vec::new();
+        let mut commands = engine
+            .execute(&mut cache, submit, UnixNanos::default())
+            .unwrap();
+        for _ in 0..3 {
+            let slice = submitted_order(commands);
+            slices.push((slice.client_order_id(), slice.quantity(), slice.price()));
+            if cache.order(&slice.client_order_id()).is_none() {
+                cache.add_order(slice.clone(), None, None, false).unwrap();
+            }
+            let filled = TestOrderEventStubs::order_filled(
+                &slice,
+                &instrument,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            );
+            commands = engine.process(&mut cache, &filled, UnixNanos::default());
+        }
+
+        assert_eq!(
+            slices,
+            vec![
+                (
+                    ClientOrderId::from("O-001-E1"),
+                    Quantity::from(100_000),
+                    Some(Price::from("1.00000"))
+                ),
+                (
+                    ClientOrderId::from("O-001-E2"),
+                    Quantity::from(100_000),
+                    Some(Price::from("1.00000"))
+                ),
+                (
+                    ClientOrderId::from("O-001"),
+                    Quantity::from(50_000),
+                    Some(Price::from("1.00000"))
+                ),
+            ]
+        );
+        assert!(commands.is_empty());
+    }
+}
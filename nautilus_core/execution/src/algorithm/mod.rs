@@ -0,0 +1,564 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The `ExecAlgorithm` trait for execution algorithms, which slice primary orders into
+//! spawned child orders, and the `ExecAlgorithmEngine` which hosts them.
+//!
+//! A primary order is routed to the algorithm named by its `exec_algorithm_id`. Spawned
+//! orders reduce the quantity of the primary order, which is itself submitted for the
+//! final slice (as with the Python `ExecAlgorithm`).
+
+pub mod iceberg;
+pub mod twap;
+
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+use log::{debug, error, warn};
+use nautilus_common::{cache::Cache, timer::TimeEvent};
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::TimeInForce,
+    events::order::{updated::OrderUpdated, OrderEventAny},
+    identifiers::{
+        client_id::ClientId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
+        trader_id::TraderId,
+    },
+    orders::{any::OrderAny, limit::LimitOrder, market::MarketOrder},
+    types::{price::Price, quantity::Quantity},
+};
+use ustr::Ustr;
+
+use crate::messages::{cancel::CancelOrder, submit::SubmitOrder, TradingCommand};
+
+/// An execution algorithm, which executes primary orders by spawning and submitting
+/// child orders.
+///
+/// Each handler receives an [`ExecAlgorithmContext`] through which it can issue commands
+/// and set timers, which are executed by the host after the handler returns.
+pub trait ExecAlgorithm {
+    /// Returns the identifier for the algorithm.
+    fn id(&self) -> ExecAlgorithmId;
+
+    /// Called when a primary `order` is received for execution.
+    fn on_order(&mut self, ctx: &mut ExecAlgorithmContext, order: &OrderAny) -> anyhow::Result<()>;
+
+    /// Called with each event for the primary and spawned orders of the algorithm.
+    fn on_order_event(
+        &mut self,
+        _ctx: &mut ExecAlgorithmContext,
+        _event: &OrderEventAny,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Called when a timer set by the algorithm fires.
+    fn on_time_event(
+        &mut self,
+        _ctx: &mut ExecAlgorithmContext,
+        _event: &TimeEvent,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+enum TimerRequest {
+    Set {
+        name: Ustr,
+        interval_ns: u64,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+    },
+    Cancel {
+        name: Ustr,
+    },
+}
+
+/// Provides an execution algorithm with access to the cache, and collects the commands and
+/// timer requests it issues while handling a callback.
+pub struct ExecAlgorithmContext<'a> {
+    trader_id: TraderId,
+    exec_algorithm_id: ExecAlgorithmId,
+    ts_now: UnixNanos,
+    cache: &'a mut Cache,
+    spawn_sequences: &'a mut HashMap<ClientOrderId, u32>,
+    commands: Vec<TradingCommand>,
+    timers: Vec<TimerRequest>,
+}
+
+impl<'a> ExecAlgorithmContext<'a> {
+    /// Returns the trader ID.
+    #[must_use]
+    pub fn trader_id(&self) -> TraderId {
+        self.trader_id
+    }
+
+    /// Returns the current UNIX timestamp (nanoseconds).
+    #[must_use]
+    pub fn ts_now(&self) -> UnixNanos {
+        self.ts_now
+    }
+
+    /// Returns a reference to the cache.
+    #[must_use]
+    pub fn cache(&self) -> &Cache {
+        self.cache
+    }
+
+    /// Spawns a market order for `quantity` from the `primary` order, reducing the quantity
+    /// of the primary order (and its cached state) by the same amount.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `quantity` is greater than the primary quantity.
+    pub fn spawn_market(
+        &mut self,
+        primary: &mut OrderAny,
+        quantity: Quantity,
+        time_in_force: TimeInForce,
+        reduce_only: bool,
+    ) -> anyhow::Result<OrderAny> {
+        let client_order_id = self.spawn_client_order_id(primary)?;
+        self.reduce_primary(primary, quantity)?;
+
+        let order = MarketOrder::new(
+            primary.trader_id(),
+            primary.strategy_id(),
+            primary.instrument_id(),
+            client_order_id,
+            primary.order_side(),
+            quantity,
+            time_in_force,
+            UUID4::new(),
+            self.ts_now,
+            reduce_only,
+            false,
+            None,
+            None,
+            None,
+            None,
+            Some(self.exec_algorithm_id),
+            None,
+            Some(primary.client_order_id()),
+            primary.tags().map(<[Ustr]>::to_vec),
+        )?;
+        Ok(OrderAny::Market(order))
+    }
+
+    /// Spawns a limit order for `quantity` at `price` from the `primary` order, reducing the
+    /// quantity of the primary order (and its cached state) by the same amount.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `quantity` is greater than the primary quantity.
+    #[allow(clippy::too_many_arguments)]
+    pub fn spawn_limit(
+        &mut self,
+        primary: &mut OrderAny,
+        quantity: Quantity,
+        price: Price,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        post_only: bool,
+        reduce_only: bool,
+    ) -> anyhow::Result<OrderAny> {
+        let client_order_id = self.spawn_client_order_id(primary)?;
+        self.reduce_primary(primary, quantity)?;
+
+        let order = LimitOrder::new(
+            primary.trader_id(),
+            primary.strategy_id(),
+            primary.instrument_id(),
+            client_order_id,
+            primary.order_side(),
+            quantity,
+            price,
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(self.exec_algorithm_id),
+            None,
+            Some(primary.client_order_id()),
+            primary.tags().map(<[Ustr]>::to_vec),
+            UUID4::new(),
+            self.ts_now,
+        )?;
+        Ok(OrderAny::Limit(order))
+    }
+
+    /// Submits the spawned (or primary) `order`, with the execution client of the primary
+    /// order unless a `client_id` is given.
+    pub fn submit_order(
+        &mut self,
+        order: OrderAny,
+        client_id: Option<ClientId>,
+    ) -> anyhow::Result<()> {
+        let client_id = client_id.unwrap_or_else(|| self.client_id_for(&order));
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            client_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.venue_order_id().unwrap_or_default(),
+            order,
+            Some(self.exec_algorithm_id),
+            None,
+            UUID4::new(),
+            self.ts_now,
+        )?;
+        self.commands.push(TradingCommand::SubmitOrder(command));
+        Ok(())
+    }
+
+    /// Cancels the cached order with the given `client_order_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order is not found in the cache, or has not
+    /// been accepted by the venue.
+    pub fn cancel_order(&mut self, client_order_id: &ClientOrderId) -> anyhow::Result<()> {
+        let order = self
+            .cache
+            .order(client_order_id)
+            .ok_or_else(|| anyhow::anyhow!("Order {client_order_id} not found in cache"))?;
+        let venue_order_id = order
+            .venue_order_id()
+            .ok_or_else(|| anyhow::anyhow!("Order {client_order_id} has no venue order ID"))?;
+
+        let command = CancelOrder::new(
+            order.trader_id(),
+            self.client_id_for(order),
+            order.strategy_id(),
+            order.instrument_id(),
+            *client_order_id,
+            venue_order_id,
+            UUID4::new(),
+            self.ts_now,
+        )?;
+        self.commands.push(TradingCommand::CancelOrder(command));
+        Ok(())
+    }
+
+    /// Sets a timer `name` firing every `interval_ns` from `start_time_ns` (exclusive) until
+    /// the optional `stop_time_ns`, replacing any timer of the same name.
+    pub fn set_timer(
+        &mut self,
+        name: &str,
+        interval_ns: u64,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+    ) -> anyhow::Result<()> {
+        if interval_ns == 0 {
+            anyhow::bail!("Invalid `interval_ns` for timer {name}, was 0");
+        }
+        self.timers.push(TimerRequest::Set {
+            name: Ustr::from(name),
+            interval_ns,
+            start_time_ns,
+            stop_time_ns,
+        });
+        Ok(())
+    }
+
+    /// Cancels the timer `name` (if set).
+    pub fn cancel_timer(&mut self, name: &str) {
+        self.timers.push(TimerRequest::Cancel {
+            name: Ustr::from(name),
+        });
+    }
+
+    fn spawn_client_order_id(&mut self, primary: &OrderAny) -> anyhow::Result<ClientOrderId> {
+        let primary_id = primary.client_order_id();
+        let sequence = self.spawn_sequences.entry(primary_id).or_insert(0);
+        *sequence += 1;
+        ClientOrderId::new(&format!("{primary_id}-E{sequence}"))
+    }
+
+    fn reduce_primary(&mut self, primary: &mut OrderAny, quantity: Quantity) -> anyhow::Result<()> {
+        if quantity > primary.quantity() {
+            anyhow::bail!(
+                "Spawn quantity {quantity} greater than primary {} quantity {}",
+                primary.client_order_id(),
+                primary.quantity()
+            );
+        }
+
+        let updated = OrderUpdated::new(
+            primary.trader_id(),
+            primary.strategy_id(),
+            primary.instrument_id(),
+            primary.client_order_id(),
+            primary.quantity() - quantity,
+            UUID4::new(),
+            self.ts_now,
+            self.ts_now,
+            false,
+            primary.venue_order_id(),
+            primary.account_id(),
+            None,
+            None,
+        )?;
+        primary
+            .apply(OrderEventAny::Updated(updated))
+            .map_err(|e| {
+                anyhow::anyhow!("Error reducing primary {}: {e}", primary.client_order_id())
+            })?;
+        self.cache.update_order(primary)
+    }
+
+    fn client_id_for(&self, order: &OrderAny) -> ClientId {
+        let primary_id = order.exec_spawn_id().unwrap_or(order.client_order_id());
+        self.cache
+            .client_id(&primary_id)
+            .copied()
+            .unwrap_or_else(|| ClientId::from(order.instrument_id().venue.as_str()))
+    }
+}
+
+struct AlgorithmTimer {
+    exec_algorithm_id: ExecAlgorithmId,
+    name: Ustr,
+    interval_ns: u64,
+    next_time_ns: UnixNanos,
+    stop_time_ns: Option<UnixNanos>,
+}
+
+/// Provides a host for execution algorithms, dispatching primary orders, order events and
+/// timer events to them, and returning the commands they issue for execution.
+pub struct ExecAlgorithmEngine {
+    trader_id: TraderId,
+    algorithms: IndexMap<ExecAlgorithmId, Box<dyn ExecAlgorithm>>,
+    timers: Vec<AlgorithmTimer>,
+    spawn_sequences: HashMap<ClientOrderId, u32>,
+}
+
+impl ExecAlgorithmEngine {
+    /// Creates a new [`ExecAlgorithmEngine`] instance.
+    #[must_use]
+    pub fn new(trader_id: TraderId) -> Self {
+        Self {
+            trader_id,
+            algorithms: IndexMap::new(),
+            timers: Vec::new(),
+            spawn_sequences: HashMap::new(),
+        }
+    }
+
+    /// Returns the IDs of the registered algorithms.
+    #[must_use]
+    pub fn algorithm_ids(&self) -> Vec<ExecAlgorithmId> {
+        self.algorithms.keys().copied().collect()
+    }
+
+    /// Returns the count of active timers.
+    #[must_use]
+    pub fn timer_count(&self) -> usize {
+        self.timers.len()
+    }
+
+    /// Registers the given execution `algorithm` with the engine.
+    pub fn register(&mut self, algorithm: Box<dyn ExecAlgorithm>) -> anyhow::Result<()> {
+        let exec_algorithm_id = algorithm.id();
+        if self.algorithms.contains_key(&exec_algorithm_id) {
+            anyhow::bail!("Execution algorithm {exec_algorithm_id} already registered");
+        }
+        self.algorithms.insert(exec_algorithm_id, algorithm);
+        debug!("Registered execution algorithm {exec_algorithm_id}");
+        Ok(())
+    }
+
+    /// Returns the time of the next timer to fire (if any).
+    #[must_use]
+    pub fn next_timer_ns(&self) -> Option<UnixNanos> {
+        self.timers.iter().map(|timer| timer.next_time_ns).min()
+    }
+
+    /// Returns whether the `order` is for a registered algorithm.
+    #[must_use]
+    pub fn handles(&self, order: &OrderAny) -> bool {
+        order
+            .exec_algorithm_id()
+            .map_or(false, |id| self.algorithms.contains_key(&id))
+    }
+
+    /// Executes the primary order of the `submit` command with its algorithm, adding the
+    /// order to the `cache`.
+    ///
+    /// Returns the commands issued by the algorithm.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order has no registered algorithm, or could
+    /// not be cached.
+    pub fn execute(
+        &mut self,
+        cache: &mut Cache,
+        submit: SubmitOrder,
+        ts_now: UnixNanos,
+    ) -> anyhow::Result<Vec<TradingCommand>> {
+        let order = submit.order;
+        let Some(exec_algorithm_id) = order.exec_algorithm_id() else {
+            anyhow::bail!(
+                "Order {} has no execution algorithm",
+                order.client_order_id()
+            );
+        };
+        if !self.algorithms.contains_key(&exec_algorithm_id) {
+            anyhow::bail!("Execution algorithm {exec_algorithm_id} not registered");
+        }
+
+        if cache.order(&order.client_order_id()).is_none() {
+            cache.add_order(
+                order.clone(),
+                submit.position_id,
+                Some(submit.client_id),
+                false,
+            )?;
+        }
+
+        Ok(
+            self.call(exec_algorithm_id, cache, ts_now, |algorithm, ctx| {
+                algorithm.on_order(ctx, &order)
+            }),
+        )
+    }
+
+    /// Processes the order `event` with the algorithm of the order (if any).
+    ///
+    /// Returns the commands issued by the algorithm.
+    pub fn process(
+        &mut self,
+        cache: &mut Cache,
+        event: &OrderEventAny,
+        ts_now: UnixNanos,
+    ) -> Vec<TradingCommand> {
+        let exec_algorithm_id = cache
+            .order(&event.client_order_id())
+            .and_then(OrderAny::exec_algorithm_id);
+        match exec_algorithm_id {
+            Some(id) if self.algorithms.contains_key(&id) => {
+                self.call(id, cache, ts_now, |algorithm, ctx| {
+                    algorithm.on_order_event(ctx, event)
+                })
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Fires the timers due at or before `ts_now` in time order.
+    ///
+    /// Returns the commands issued by the algorithms.
+    pub fn advance_time(&mut self, cache: &mut Cache, ts_now: UnixNanos) -> Vec<TradingCommand> {
+        let mut commands = Vec::new();
+        while let Some(index) = self.next_due_timer(ts_now) {
+            let timer = &mut self.timers[index];
+            let exec_algorithm_id = timer.exec_algorithm_id;
+            let event = TimeEvent::new(timer.name, UUID4::new(), timer.next_time_ns, ts_now);
+
+            timer.next_time_ns += timer.interval_ns;
+            if timer
+                .stop_time_ns
+                .map_or(false, |stop_time_ns| timer.next_time_ns > stop_time_ns)
+            {
+                self.timers.remove(index);
+            }
+
+            commands.extend(
+                self.call(exec_algorithm_id, cache, ts_now, |algorithm, ctx| {
+                    algorithm.on_time_event(ctx, &event)
+                }),
+            );
+        }
+        commands
+    }
+
+    fn next_due_timer(&self, ts_now: UnixNanos) -> Option<usize> {
+        self.timers
+            .iter()
+            .enumerate()
+            .filter(|(_, timer)| timer.next_time_ns <= ts_now)
+            .min_by_key(|(_, timer)| timer.next_time_ns)
+            .map(|(index, _)| index)
+    }
+
+    fn call(
+        &mut self,
+        exec_algorithm_id: ExecAlgorithmId,
+        cache: &mut Cache,
+        ts_now: UnixNanos,
+        handler: impl FnOnce(&mut dyn ExecAlgorithm, &mut ExecAlgorithmContext) -> anyhow::Result<()>,
+    ) -> Vec<TradingCommand> {
+        let Some(algorithm) = self.algorithms.get_mut(&exec_algorithm_id) else {
+            warn!("No execution algorithm {exec_algorithm_id} to dispatch to");
+            return Vec::new();
+        };
+
+        let mut ctx = ExecAlgorithmContext {
+            trader_id: self.trader_id,
+            exec_algorithm_id,
+            ts_now,
+            cache,
+            spawn_sequences: &mut self.spawn_sequences,
+            commands: Vec::new(),
+            timers: Vec::new(),
+        };
+        if let Err(e) = handler(algorithm.as_mut(), &mut ctx) {
+            error!("Error in execution algorithm {exec_algorithm_id}: {e}");
+        }
+
+        let ExecAlgorithmContext {
+            commands, timers, ..
+        } = ctx;
+        for request in timers {
+            self.apply_timer_request(exec_algorithm_id, request);
+        }
+        commands
+    }
+
+    fn apply_timer_request(&mut self, exec_algorithm_id: ExecAlgorithmId, request: TimerRequest) {
+        let name = match &request {
+            TimerRequest::Set { name, .. } | TimerRequest::Cancel { name } => *name,
+        };
+        self.timers
+            .retain(|timer| timer.exec_algorithm_id != exec_algorithm_id || timer.name != name);
+
+        if let TimerRequest::Set {
+            name,
+            interval_ns,
+            start_time_ns,
+            stop_time_ns,
+        } = request
+        {
+            self.timers.push(AlgorithmTimer {
+                exec_algorithm_id,
+                name,
+                interval_ns,
+                next_time_ns: start_time_ns + interval_ns,
+                stop_time_ns,
+            });
+        }
+    }
+}
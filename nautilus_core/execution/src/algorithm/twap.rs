@@ -0,0 +1,356 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A time-weighted average price (TWAP) execution algorithm.
+
+use std::collections::{HashMap, VecDeque};
+
+use log::warn;
+use nautilus_common::timer::TimeEvent;
+use nautilus_model::{
+    enums::OrderType,
+    identifiers::{client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId},
+    orders::any::OrderAny,
+    types::{fixed::FIXED_PRECISION, quantity::Quantity},
+};
+use ustr::Ustr;
+
+use super::{ExecAlgorithm, ExecAlgorithmContext};
+
+/// Provides a TWAP execution algorithm, which executes a primary market order in equal
+/// slices at a regular interval over a horizon.
+///
+/// The primary order must have the `exec_algorithm_params`:
+/// - `horizon_secs`: the execution horizon (seconds).
+/// - `interval_secs`: the interval between slices (seconds).
+///
+/// The first slice is spawned immediately, and the primary order itself is submitted for
+/// the final slice.
+pub struct TwapExecAlgorithm {
+    id: ExecAlgorithmId,
+    scheduled_sizes: HashMap<ClientOrderId, VecDeque<Quantity>>,
+}
+
+impl TwapExecAlgorithm {
+    /// Creates a new [`TwapExecAlgorithm`] instance, with the ID "TWAP" unless given.
+    #[must_use]
+    pub fn new(id: Option<ExecAlgorithmId>) -> Self {
+        Self {
+            id: id.unwrap_or_else(|| ExecAlgorithmId::from("TWAP")),
+            scheduled_sizes: HashMap::new(),
+        }
+    }
+
+    /// Returns the remaining scheduled slice sizes for the primary order (if executing).
+    #[must_use]
+    pub fn scheduled_sizes(&self, primary_id: &ClientOrderId) -> Option<&VecDeque<Quantity>> {
+        self.scheduled_sizes.get(primary_id)
+    }
+
+    fn execute_slice(
+        &mut self,
+        ctx: &mut ExecAlgorithmContext,
+        primary_id: ClientOrderId,
+    ) -> anyhow::Result<()> {
+        let Some(mut primary) = ctx.cache().order(&primary_id).cloned() else {
+            self.complete(ctx, primary_id);
+            anyhow::bail!("Primary order {primary_id} not found in cache");
+        };
+        if primary.is_closed() {
+            self.complete(ctx, primary_id);
+            return Ok(());
+        }
+        let Some(quantity) = self
+            .scheduled_sizes
+            .get_mut(&primary_id)
+            .and_then(VecDeque::pop_front)
+        else {
+            warn!("No scheduled sizes for {primary_id}");
+            return Ok(());
+        };
+
+        if self
+            .scheduled_sizes
+            .get(&primary_id)
+            .map_or(true, VecDeque::is_empty)
+        {
+            self.complete(ctx, primary_id);
+            return ctx.submit_order(primary, None);
+        }
+
+        let time_in_force = primary.time_in_force();
+        let reduce_only = primary.is_reduce_only();
+        let spawned = ctx.spawn_market(&mut primary, quantity, time_in_force, reduce_only)?;
+        ctx.submit_order(spawned, None)
+    }
+
+    fn complete(&mut self, ctx: &mut ExecAlgorithmContext, primary_id: ClientOrderId) {
+        self.scheduled_sizes.remove(&primary_id);
+        ctx.cancel_timer(primary_id.as_str());
+    }
+}
+
+impl ExecAlgorithm for TwapExecAlgorithm {
+    fn id(&self) -> ExecAlgorithmId {
+        self.id
+    }
+
+    fn on_order(&mut self, ctx: &mut ExecAlgorithmContext, order: &OrderAny) -> anyhow::Result<()> {
+        let primary_id = order.client_order_id();
+        if order.order_type() != OrderType::Market {
+            anyhow::bail!(
+                "Cannot execute {primary_id}: TWAP requires a market order, was {}",
+                order.order_type()
+            );
+        }
+
+        let horizon_secs = param(order, "horizon_secs")?;
+        let interval_secs = param(order, "interval_secs")?;
+        if !(interval_secs > 0.0 && horizon_secs >= interval_secs) {
+            anyhow::bail!(
+                "Cannot execute {primary_id}: invalid horizon {horizon_secs}s for interval {interval_secs}s"
+            );
+        }
+
+        // Slice sizes are rounded down to the quantity precision, with any remainder
+        // executed as an additional final slice
+        let quantity = order.quantity();
+        let num_intervals = (horizon_secs / interval_secs).floor() as u64;
+        let unit = 10u64.pow(u32::from(FIXED_PRECISION - quantity.precision));
+        let slice_raw = quantity.raw / num_intervals / unit * unit;
+        if num_intervals == 1 || slice_raw == 0 {
+            return ctx.submit_order(order.clone(), None);
+        }
+
+        let mut sizes: VecDeque<Quantity> = (0..num_intervals)
+            .map(|_| Quantity::from_raw(slice_raw, quantity.precision))
+            .collect::<anyhow::Result<_>>()?;
+        let remainder_raw = quantity.raw - slice_raw * num_intervals;
+        if remainder_raw > 0 {
+            sizes.push_back(Quantity::from_raw(remainder_raw, quantity.precision)?);
+        }
+        self.scheduled_sizes.insert(primary_id, sizes);
+
+        let ts_now = ctx.ts_now();
+        ctx.set_timer(
+            primary_id.as_str(),
+            (interval_secs * 1e9) as u64,
+            ts_now,
+            Some(ts_now + (horizon_secs * 1e9) as u64),
+        )?;
+        self.execute_slice(ctx, primary_id)
+    }
+
+    fn on_time_event(
+        &mut self,
+        ctx: &mut ExecAlgorithmContext,
+        event: &TimeEvent,
+    ) -> anyhow::Result<()> {
+        self.execute_slice(ctx, ClientOrderId::new(event.name.as_str())?)
+    }
+}
+
+fn param(order: &OrderAny, key: &str) -> anyhow::Result<f64> {
+    let value = order
+        .exec_algorithm_params()
+        .and_then(|params| params.get(&Ustr::from(key)))
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Cannot execute {}: no `{key}` param",
+                order.client_order_id()
+            )
+        })?;
+    value
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Invalid `{key}` param '{value}': {e}"))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::cache::Cache;
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use nautilus_model::{
+        enums::{OrderSide, TimeInForce},
+        identifiers::{
+            client_id::ClientId, instrument_id::InstrumentId, strategy_id::StrategyId,
+            trader_id::TraderId, venue_order_id::VenueOrderId,
+        },
+        orders::market::MarketOrder,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        algorithm::ExecAlgorithmEngine,
+        messages::{submit::SubmitOrder, TradingCommand},
+    };
+
+    fn primary_order(quantity: i64, horizon_secs: &str, interval_secs: &str) -> OrderAny {
+        let client_order_id = ClientOrderId::from("O-001");
+        let params = HashMap::from([
+            (Ustr::from("horizon_secs"), Ustr::from(horizon_secs)),
+            (Ustr::from("interval_secs"), Ustr::from(interval_secs)),
+        ]);
+        let order = MarketOrder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::from("AUD/USD.SIM"),
+            client_order_id,
+            OrderSide::Buy,
+            Quantity::from(quantity),
+            TimeInForce::Gtc,
+            UUID4::new(),
+            UnixNanos::default(),
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            Some(ExecAlgorithmId::from("TWAP")),
+            Some(params),
+            Some(client_order_id),
+            None,
+        )
+        .unwrap();
+        OrderAny::Market(order)
+    }
+
+    fn submit(order: OrderAny) -> SubmitOrder {
+        SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::default(),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap()
+    }
+
+    fn submitted(commands: &[TradingCommand]) -> Vec<(ClientOrderId, Quantity)> {
+        commands
+            .iter()
+            .filter_map(|command| match command {
+                TradingCommand::SubmitOrder(submit) => {
+                    Some((submit.client_order_id, submit.order.quantity()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_twap_executes_slices_on_timer() {
+        let mut cache = Cache::default();
+        let mut engine = ExecAlgorithmEngine::new(TraderId::default());
+        engine
+            .register(Box::new(TwapExecAlgorithm::new(None)))
+            .unwrap();
+        let primary_id = ClientOrderId::from("O-001");
+
+        let commands = engine
+            .execute(
+                &mut cache,
+                submit(primary_order(90_000, "3", "1")),
+                UnixNanos::default(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            submitted(&commands),
+            vec![(ClientOrderId::from("O-001-E1"), Quantity::from(30_000))]
+        );
+        assert_eq!(
+            cache.order(&primary_id).unwrap().quantity(),
+            Quantity::from(60_000)
+        );
+        assert_eq!(engine.timer_count(), 1);
+
+        let commands = engine.advance_time(&mut cache, UnixNanos::from(1_000_000_000));
+
+        assert_eq!(
+            submitted(&commands),
+            vec![(ClientOrderId::from("O-001-E2"), Quantity::from(30_000))]
+        );
+
+        let commands = engine.advance_time(&mut cache, UnixNanos::from(2_000_000_000));
+
+        assert_eq!(
+            submitted(&commands),
+            vec![(primary_id, Quantity::from(30_000))]
+        );
+        assert_eq!(engine.timer_count(), 0);
+    }
+
+    #[rstest]
+    fn test_twap_with_remainder_slice() {
+        let mut cache = Cache::default();
+        let mut engine = ExecAlgorithmEngine::new(TraderId::default());
+        engine
+            .register(Box::new(TwapExecAlgorithm::new(None)))
+            .unwrap();
+        engine
+            .execute(
+                &mut cache,
+                submit(primary_order(100_000, "3", "1")),
+                UnixNanos::default(),
+            )
+            .unwrap();
+
+        let commands = engine.advance_time(&mut cache, UnixNanos::from(5_000_000_000));
+
+        assert_eq!(
+            submitted(&commands),
+            vec![
+                (ClientOrderId::from("O-001-E2"), Quantity::from(33_333)),
+                (ClientOrderId::from("O-001-E3"), Quantity::from(33_333)),
+                (ClientOrderId::from("O-001"), Quantity::from(1)),
+            ]
+        );
+        assert_eq!(engine.timer_count(), 0);
+    }
+
+    #[rstest]
+    #[case("1", "2")]
+    #[case("3", "0")]
+    #[case("3", "x")]
+    fn test_twap_with_invalid_params(#[case] horizon_secs: &str, #[case] interval_secs: &str) {
+        let mut cache = Cache::default();
+        let mut engine = ExecAlgorithmEngine::new(TraderId::default());
+        engine
+            .register(Box::new(TwapExecAlgorithm::new(None)))
+            .unwrap();
+
+        let commands = engine
+            .execute(
+                &mut cache,
+                submit(primary_order(90_000, horizon_secs, interval_secs)),
+                UnixNanos::default(),
+            )
+            .unwrap();
+
+        assert!(commands.is_empty());
+        assert_eq!(engine.timer_count(), 0);
+    }
+}
@@ -0,0 +1,260 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Venue capability descriptors and time-in-force (TIF) emulation.
+//!
+//! Strategies may use a uniform set of TIFs regardless of venue. Where a venue lacks a TIF,
+//! the order is submitted with a TIF the venue does support, and the original semantics are
+//! enforced locally (e.g. a FOK order is submitted and then immediately canceled if not
+//! fully filled).
+
+use std::collections::{HashMap, HashSet};
+
+use nautilus_model::{enums::TimeInForce, identifiers::venue::Venue};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+/// The local enforcement applied to an order submitted with an emulated TIF.
+#[derive(Copy, Clone, Debug, Display, Hash, PartialEq, Eq, EnumString, Serialize, Deserialize)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum TifEnforcement {
+    /// Cancel any remaining quantity as soon as the order is accepted by the venue.
+    CancelRemaining,
+    /// Cancel the order as soon as it is accepted by the venue unless fully filled.
+    ///
+    /// Fills received before the cancel is acknowledged are kept, so unlike a native FOK
+    /// this cannot guarantee the order is never partially filled.
+    CancelUnlessFilled,
+    /// Cancel the order locally once its expire time is reached.
+    CancelAtExpireTime,
+}
+
+/// Represents how an order with a requested TIF is to be submitted to a venue.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TifEmulation {
+    /// The TIF to submit to the venue.
+    pub submit_as: TimeInForce,
+    /// The local enforcement to apply, or `None` if the venue supports the TIF natively.
+    pub enforcement: Option<TifEnforcement>,
+}
+
+impl TifEmulation {
+    /// Creates a new [`TifEmulation`] for a TIF the venue supports natively.
+    #[must_use]
+    pub fn native(time_in_force: TimeInForce) -> Self {
+        Self {
+            submit_as: time_in_force,
+            enforcement: None,
+        }
+    }
+
+    /// Creates a new [`TifEmulation`] submitting as `submit_as` with local `enforcement`.
+    #[must_use]
+    pub fn emulated(submit_as: TimeInForce, enforcement: TifEnforcement) -> Self {
+        Self {
+            submit_as,
+            enforcement: Some(enforcement),
+        }
+    }
+
+    /// Returns whether the TIF is emulated locally.
+    #[must_use]
+    pub fn is_emulated(&self) -> bool {
+        self.enforcement.is_some()
+    }
+}
+
+/// Describes the order capabilities of a venue, as declared by its execution client.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VenueCapabilities {
+    /// The venue for the capabilities.
+    pub venue: Venue,
+    /// The TIFs supported natively by the venue.
+    pub supported_tifs: HashSet<TimeInForce>,
+    /// Explicit emulations overriding the defaults, keyed by requested TIF.
+    pub tif_overrides: HashMap<TimeInForce, TifEmulation>,
+}
+
+impl VenueCapabilities {
+    /// Creates a new [`VenueCapabilities`] instance.
+    #[must_use]
+    pub fn new(venue: Venue, supported_tifs: impl IntoIterator<Item = TimeInForce>) -> Self {
+        Self {
+            venue,
+            supported_tifs: supported_tifs.into_iter().collect(),
+            tif_overrides: HashMap::new(),
+        }
+    }
+
+    /// Sets an explicit emulation for the `requested` TIF, overriding the default.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the emulation submits a TIF the venue does not support.
+    pub fn set_tif_emulation(
+        &mut self,
+        requested: TimeInForce,
+        emulation: TifEmulation,
+    ) -> anyhow::Result<()> {
+        if !self.supports(emulation.submit_as) {
+            anyhow::bail!(
+                "Cannot emulate {requested} as {}, not supported by {}",
+                emulation.submit_as,
+                self.venue,
+            );
+        }
+        self.tif_overrides.insert(requested, emulation);
+        Ok(())
+    }
+
+    /// Returns whether the venue supports the given `time_in_force` natively.
+    #[must_use]
+    pub fn supports(&self, time_in_force: TimeInForce) -> bool {
+        self.supported_tifs.contains(&time_in_force)
+    }
+
+    /// Resolves how an order with the `requested` TIF is to be submitted to the venue.
+    ///
+    /// Explicit overrides take precedence, then native support, then the default
+    /// emulation for the TIF:
+    /// - FOK: submitted as IOC, GTC or DAY, canceled on acceptance unless fully filled.
+    /// - IOC: submitted as GTC or DAY, with any remainder canceled on acceptance.
+    /// - GTD: submitted as GTC, canceled locally at the expire time.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the TIF is neither supported nor can be emulated.
+    pub fn resolve_time_in_force(&self, requested: TimeInForce) -> anyhow::Result<TifEmulation> {
+        if let Some(emulation) = self.tif_overrides.get(&requested) {
+            return Ok(*emulation);
+        }
+        if self.supports(requested) {
+            return Ok(TifEmulation::native(requested));
+        }
+
+        let (candidates, enforcement): (&[TimeInForce], TifEnforcement) = match requested {
+            TimeInForce::Fok => (
+                &[TimeInForce::Ioc, TimeInForce::Gtc, TimeInForce::Day],
+                TifEnforcement::CancelUnlessFilled,
+            ),
+            TimeInForce::Ioc => (
+                &[TimeInForce::Gtc, TimeInForce::Day],
+                TifEnforcement::CancelRemaining,
+            ),
+            TimeInForce::Gtd => (&[TimeInForce::Gtc], TifEnforcement::CancelAtExpireTime),
+            _ => (&[], TifEnforcement::CancelRemaining),
+        };
+
+        candidates
+            .iter()
+            .find(|tif| self.supports(**tif))
+            .map(|tif| TifEmulation::emulated(*tif, enforcement))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Time in force {requested} not supported by {} and cannot be emulated",
+                    self.venue,
+                )
+            })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn capabilities() -> VenueCapabilities {
+        VenueCapabilities::new(
+            Venue::new("XNAS").unwrap(),
+            [TimeInForce::Gtc, TimeInForce::Day],
+        )
+    }
+
+    #[rstest]
+    #[case(TimeInForce::Gtc, TifEmulation::native(TimeInForce::Gtc))]
+    #[case(TimeInForce::Day, TifEmulation::native(TimeInForce::Day))]
+    #[case(
+        TimeInForce::Fok,
+        TifEmulation::emulated(TimeInForce::Gtc, TifEnforcement::CancelUnlessFilled)
+    )]
+    #[case(
+        TimeInForce::Ioc,
+        TifEmulation::emulated(TimeInForce::Gtc, TifEnforcement::CancelRemaining)
+    )]
+    #[case(
+        TimeInForce::Gtd,
+        TifEmulation::emulated(TimeInForce::Gtc, TifEnforcement::CancelAtExpireTime)
+    )]
+    fn test_resolve_time_in_force(
+        capabilities: VenueCapabilities,
+        #[case] requested: TimeInForce,
+        #[case] expected: TifEmulation,
+    ) {
+        assert_eq!(
+            capabilities.resolve_time_in_force(requested).unwrap(),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_resolve_fok_prefers_ioc(mut capabilities: VenueCapabilities) {
+        capabilities.supported_tifs.insert(TimeInForce::Ioc);
+
+        let emulation = capabilities
+            .resolve_time_in_force(TimeInForce::Fok)
+            .unwrap();
+
+        assert_eq!(emulation.submit_as, TimeInForce::Ioc);
+        assert!(emulation.is_emulated());
+    }
+
+    #[rstest]
+    fn test_resolve_unsupported_auction_tif(capabilities: VenueCapabilities) {
+        assert!(capabilities
+            .resolve_time_in_force(TimeInForce::AtTheOpen)
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_set_tif_emulation_override(mut capabilities: VenueCapabilities) {
+        let emulation = TifEmulation::emulated(TimeInForce::Day, TifEnforcement::CancelRemaining);
+        capabilities
+            .set_tif_emulation(TimeInForce::Ioc, emulation)
+            .unwrap();
+
+        assert_eq!(
+            capabilities
+                .resolve_time_in_force(TimeInForce::Ioc)
+                .unwrap(),
+            emulation
+        );
+    }
+
+    #[rstest]
+    fn test_set_tif_emulation_unsupported_submit_as(mut capabilities: VenueCapabilities) {
+        let emulation =
+            TifEmulation::emulated(TimeInForce::Ioc, TifEnforcement::CancelUnlessFilled);
+
+        assert!(capabilities
+            .set_tif_emulation(TimeInForce::Fok, emulation)
+            .is_err());
+    }
+}
@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-//! Base execution client functionality.
+//! The `ExecutionClient` trait for venue adapters, and base execution client functionality.
 
 // Under development
 #![allow(dead_code)]
@@ -25,10 +25,11 @@ use nautilus_model::{
     enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType},
     events::{account::state::AccountState, order::OrderEventAny},
     identifiers::{
-        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
-        position_id::PositionId, strategy_id::StrategyId, trade_id::TradeId, venue::Venue,
-        venue_order_id::VenueOrderId,
+        account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
+        instrument_id::InstrumentId, position_id::PositionId, strategy_id::StrategyId,
+        trade_id::TradeId, venue::Venue, venue_order_id::VenueOrderId,
     },
+    reports::{fill::FillReport, order::OrderStatusReport, position::PositionStatusReport},
     types::{
         balance::{AccountBalance, MarginBalance},
         currency::Currency,
@@ -38,22 +39,103 @@ use nautilus_model::{
     },
 };
 
-use crate::messages::{
-    cancel::CancelOrder, cancel_batch::BatchCancelOrders, modify::ModifyOrder, query::QueryOrder,
-    submit::SubmitOrder, submit_list::SubmitOrderList,
+use crate::{
+    capabilities::VenueCapabilities,
+    messages::{
+        cancel::CancelOrder, cancel_all::CancelAllOrders, cancel_batch::BatchCancelOrders,
+        modify::ModifyOrder, query::QueryOrder, submit::SubmitOrder, submit_list::SubmitOrderList,
+        TradingCommand,
+    },
 };
 
-pub struct ExecutionClient {
+/// A client for executing trading commands with a venue, implemented by venue adapters.
+///
+/// The client also reports the state of orders, fills and positions at the venue, which
+/// the execution engine reconciles with the cached state.
+pub trait ExecutionClient {
+    /// Returns the identifier for the client.
+    fn client_id(&self) -> ClientId;
+    /// Returns the venue the client executes on.
+    fn venue(&self) -> Venue;
+    /// Returns the order management system type for the venue.
+    fn oms_type(&self) -> OmsType;
+    /// Returns the account ID for the client.
+    fn account_id(&self) -> AccountId;
+    /// Returns whether the client is connected to the venue.
+    fn is_connected(&self) -> bool;
+    /// Returns the order capabilities of the venue, or `None` if every TIF is supported natively.
+    fn capabilities(&self) -> Option<&VenueCapabilities> {
+        None
+    }
+
+    fn submit_order(&mut self, command: &SubmitOrder) -> anyhow::Result<()>;
+    fn submit_order_list(&mut self, command: &SubmitOrderList) -> anyhow::Result<()>;
+    fn modify_order(&mut self, command: &ModifyOrder) -> anyhow::Result<()>;
+    fn cancel_order(&mut self, command: &CancelOrder) -> anyhow::Result<()>;
+    fn cancel_all_orders(&mut self, command: &CancelAllOrders) -> anyhow::Result<()>;
+    fn query_order(&mut self, command: &QueryOrder) -> anyhow::Result<()>;
+
+    /// Cancels each order in the batch, for venues with no batch cancel support.
+    fn batch_cancel_orders(&mut self, command: &BatchCancelOrders) -> anyhow::Result<()> {
+        command
+            .cancels
+            .iter()
+            .try_for_each(|cancel| self.cancel_order(cancel))
+    }
+
+    /// Executes the trading `command` with its handler.
+    fn execute(&mut self, command: &TradingCommand) -> anyhow::Result<()> {
+        match command {
+            TradingCommand::SubmitOrder(command) => self.submit_order(command),
+            TradingCommand::SubmitOrderList(command) => self.submit_order_list(command),
+            TradingCommand::ModifyOrder(command) => self.modify_order(command),
+            TradingCommand::CancelOrder(command) => self.cancel_order(command),
+            TradingCommand::CancelAllOrders(command) => self.cancel_all_orders(command),
+            TradingCommand::BatchCancelOrders(command) => self.batch_cancel_orders(command),
+            TradingCommand::QueryOrder(command) => self.query_order(command),
+        }
+    }
+
+    /// Returns the status of orders at the venue, optionally filtered.
+    fn generate_order_status_reports(
+        &self,
+        instrument_id: Option<InstrumentId>,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        open_only: bool,
+    ) -> anyhow::Result<Vec<OrderStatusReport>>;
+
+    /// Returns the fills at the venue, optionally filtered.
+    fn generate_fill_reports(
+        &self,
+        instrument_id: Option<InstrumentId>,
+        venue_order_id: Option<VenueOrderId>,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<Vec<FillReport>>;
+
+    /// Returns the status of positions at the venue, optionally filtered.
+    fn generate_position_status_reports(
+        &self,
+        instrument_id: Option<InstrumentId>,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<Vec<PositionStatusReport>>;
+}
+
+/// Provides common functionality for execution clients, such as generating order events.
+pub struct BaseExecutionClient {
     pub venue: Venue,
     pub oms_type: OmsType,
     pub account_id: AccountId,
     pub account_type: AccountType,
     pub base_currency: Option<Currency>,
+    pub capabilities: VenueCapabilities,
     pub is_connected: bool,
     cache: &'static Cache,
 }
 
-impl ExecutionClient {
+impl BaseExecutionClient {
     // TODO: Polymorphism for `Account` TBD?
     // pub fn get_account(&self) -> Box<dyn Account> {
     //     todo!();
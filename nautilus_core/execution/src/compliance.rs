@@ -0,0 +1,424 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Per-venue compliance counters for order-to-trade ratio (OTR) policies.
+//!
+//! Order submissions, modifications and cancels are counted against fills over a rolling
+//! window, with configurable thresholds at which further order messages should be warned
+//! about or blocked, avoiding venue penalties for excessive messaging.
+
+use std::collections::{HashMap, VecDeque};
+
+use nautilus_core::{
+    correctness::{check_positive_u64, check_predicate_true},
+    nanos::UnixNanos,
+};
+use nautilus_model::identifiers::venue::Venue;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, EnumString};
+
+/// The type of order activity counted towards venue compliance.
+#[derive(
+    Copy, Clone, Debug, Display, Hash, PartialEq, Eq, EnumIter, EnumString, Serialize, Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum ComplianceAction {
+    Submit,
+    Modify,
+    Cancel,
+    Fill,
+}
+
+/// The compliance status for a venue.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum ComplianceStatus {
+    /// Activity is within the configured thresholds.
+    Ok,
+    /// Activity has reached the warning threshold.
+    Warning,
+    /// Activity has reached the blocking threshold, further order messages should be denied.
+    Blocked,
+}
+
+/// Configuration for venue compliance thresholds.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ComplianceConfig {
+    /// The rolling window (nanoseconds) over which activity is counted.
+    pub window_ns: u64,
+    /// The order-to-trade ratio at which a warning is raised.
+    pub warn_ratio: f64,
+    /// The order-to-trade ratio at which order messages are blocked.
+    pub block_ratio: f64,
+    /// The minimum order messages in the window before ratios are enforced, so that a
+    /// handful of unfilled orders does not trigger a breach.
+    pub min_order_messages: u64,
+    /// The optional maximum order messages within the window, regardless of fills.
+    pub max_order_messages: Option<u64>,
+}
+
+impl Default for ComplianceConfig {
+    /// Creates a new default [`ComplianceConfig`] instance.
+    fn default() -> Self {
+        Self {
+            window_ns: 86_400_000_000_000, // 1 day
+            warn_ratio: 50.0,
+            block_ratio: 100.0,
+            min_order_messages: 100,
+            max_order_messages: None,
+        }
+    }
+}
+
+impl ComplianceConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        check_positive_u64(self.window_ns, "window_ns")?;
+        check_predicate_true(
+            self.warn_ratio > 0.0 && self.warn_ratio <= self.block_ratio,
+            "invalid ratios: expected 0 < `warn_ratio` <= `block_ratio`",
+        )
+    }
+}
+
+/// The order activity counts for a venue within the rolling window.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplianceCounts {
+    pub submits: u64,
+    pub modifies: u64,
+    pub cancels: u64,
+    pub fills: u64,
+}
+
+impl ComplianceCounts {
+    /// Returns the total count of order messages (submits, modifies and cancels).
+    #[must_use]
+    pub fn order_messages(&self) -> u64 {
+        self.submits + self.modifies + self.cancels
+    }
+
+    /// Returns the order-to-trade ratio, treating no fills as a single fill.
+    #[must_use]
+    pub fn order_to_trade_ratio(&self) -> f64 {
+        self.order_messages() as f64 / self.fills.max(1) as f64
+    }
+
+    fn add(&mut self, action: ComplianceAction) {
+        match action {
+            ComplianceAction::Submit => self.submits += 1,
+            ComplianceAction::Modify => self.modifies += 1,
+            ComplianceAction::Cancel => self.cancels += 1,
+            ComplianceAction::Fill => self.fills += 1,
+        }
+    }
+
+    fn remove(&mut self, action: ComplianceAction) {
+        match action {
+            ComplianceAction::Submit => self.submits -= 1,
+            ComplianceAction::Modify => self.modifies -= 1,
+            ComplianceAction::Cancel => self.cancels -= 1,
+            ComplianceAction::Fill => self.fills -= 1,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct VenueCounter {
+    events: VecDeque<(UnixNanos, ComplianceAction)>,
+    counts: ComplianceCounts,
+    status: Option<ComplianceStatus>,
+}
+
+impl VenueCounter {
+    fn expire(&mut self, now: UnixNanos, window_ns: u64) {
+        let Some(cutoff) = now.as_u64().checked_sub(window_ns) else {
+            return; // Window still open from the epoch, so nothing to expire
+        };
+        while let Some((ts, action)) = self.events.front().copied() {
+            if ts.as_u64() > cutoff {
+                break;
+            }
+            self.events.pop_front();
+            self.counts.remove(action);
+        }
+    }
+}
+
+/// Provides per-venue rolling counters of order activity, evaluated against
+/// order-to-trade ratio thresholds.
+#[derive(Debug)]
+pub struct VenueComplianceTracker {
+    default_config: ComplianceConfig,
+    configs: HashMap<Venue, ComplianceConfig>,
+    counters: HashMap<Venue, VenueCounter>,
+}
+
+impl VenueComplianceTracker {
+    /// Creates a new [`VenueComplianceTracker`] instance.
+    ///
+    /// The `default_config` applies to all venues without a specific configuration.
+    pub fn new(default_config: ComplianceConfig) -> anyhow::Result<Self> {
+        default_config.validate()?;
+        Ok(Self {
+            default_config,
+            configs: HashMap::new(),
+            counters: HashMap::new(),
+        })
+    }
+
+    /// Sets the compliance configuration for the given `venue`.
+    pub fn set_venue_config(
+        &mut self,
+        venue: Venue,
+        config: ComplianceConfig,
+    ) -> anyhow::Result<()> {
+        config.validate()?;
+        self.configs.insert(venue, config);
+        Ok(())
+    }
+
+    /// Returns the compliance configuration for the given `venue`.
+    #[must_use]
+    pub fn config(&self, venue: &Venue) -> &ComplianceConfig {
+        self.configs.get(venue).unwrap_or(&self.default_config)
+    }
+
+    /// Records the order `action` for the given `venue`, returning the resulting status.
+    pub fn record(
+        &mut self,
+        venue: Venue,
+        action: ComplianceAction,
+        ts_event: UnixNanos,
+    ) -> ComplianceStatus {
+        let counter = self.counters.entry(venue).or_default();
+        counter.events.push_back((ts_event, action));
+        counter.counts.add(action);
+        self.evaluate(venue, ts_event)
+    }
+
+    /// Returns the activity counts for the given `venue` within the window ending at `now`.
+    pub fn counts(&mut self, venue: Venue, now: UnixNanos) -> ComplianceCounts {
+        let window_ns = self.config(&venue).window_ns;
+        match self.counters.get_mut(&venue) {
+            Some(counter) => {
+                counter.expire(now, window_ns);
+                counter.counts
+            }
+            None => ComplianceCounts::default(),
+        }
+    }
+
+    /// Returns the compliance status for the given `venue` at `now`.
+    pub fn status(&mut self, venue: Venue, now: UnixNanos) -> ComplianceStatus {
+        self.evaluate(venue, now)
+    }
+
+    /// Returns whether an order message of `action` for the given `venue` would exceed
+    /// the blocking threshold at `now`, in which case it should be denied.
+    ///
+    /// Fills are never blocked.
+    pub fn would_block(&mut self, venue: Venue, action: ComplianceAction, now: UnixNanos) -> bool {
+        if action == ComplianceAction::Fill {
+            return false;
+        }
+        let config = self.config(&venue).clone();
+        let mut counts = self.counts(venue, now);
+        counts.add(action);
+        Self::status_for(&config, &counts) == ComplianceStatus::Blocked
+    }
+
+    /// Resets the counters for all venues.
+    pub fn reset(&mut self) {
+        self.counters.clear();
+    }
+
+    fn evaluate(&mut self, venue: Venue, now: UnixNanos) -> ComplianceStatus {
+        let config = self.config(&venue).clone();
+        let Some(counter) = self.counters.get_mut(&venue) else {
+            return ComplianceStatus::Ok;
+        };
+        counter.expire(now, config.window_ns);
+
+        let status = Self::status_for(&config, &counter.counts);
+        let previous = counter.status.replace(status);
+        if previous != Some(status) && (previous.is_some() || status != ComplianceStatus::Ok) {
+            let counts = counter.counts;
+            match status {
+                ComplianceStatus::Ok => log::info!("{venue} compliance status restored to OK"),
+                ComplianceStatus::Warning => log::warn!(
+                    "{venue} order-to-trade ratio {:.2} reached warning threshold {} ({counts:?})",
+                    counts.order_to_trade_ratio(),
+                    config.warn_ratio,
+                ),
+                ComplianceStatus::Blocked => log::error!(
+                    "{venue} order messages blocked, order-to-trade ratio {:.2} ({counts:?})",
+                    counts.order_to_trade_ratio(),
+                ),
+            }
+        }
+        status
+    }
+
+    fn status_for(config: &ComplianceConfig, counts: &ComplianceCounts) -> ComplianceStatus {
+        if let Some(max) = config.max_order_messages {
+            if counts.order_messages() > max {
+                return ComplianceStatus::Blocked;
+            }
+        }
+
+        if counts.order_messages() < config.min_order_messages {
+            return ComplianceStatus::Ok;
+        }
+
+        let ratio = counts.order_to_trade_ratio();
+        if ratio >= config.block_ratio {
+            ComplianceStatus::Blocked
+        } else if ratio >= config.warn_ratio {
+            ComplianceStatus::Warning
+        } else {
+            ComplianceStatus::Ok
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn tracker() -> VenueComplianceTracker {
+        let config = ComplianceConfig {
+            window_ns: 1_000,
+            warn_ratio: 5.0,
+            block_ratio: 10.0,
+            min_order_messages: 4,
+            max_order_messages: None,
+        };
+        VenueComplianceTracker::new(config).unwrap()
+    }
+
+    #[rstest]
+    fn test_new_with_invalid_ratios() {
+        let config = ComplianceConfig {
+            warn_ratio: 20.0,
+            block_ratio: 10.0,
+            ..Default::default()
+        };
+
+        assert!(VenueComplianceTracker::new(config).is_err());
+    }
+
+    #[rstest]
+    fn test_counts_by_action(mut tracker: VenueComplianceTracker) {
+        let venue = Venue::from("XCME");
+        tracker.record(venue, ComplianceAction::Submit, 1.into());
+        tracker.record(venue, ComplianceAction::Modify, 2.into());
+        tracker.record(venue, ComplianceAction::Cancel, 3.into());
+        tracker.record(venue, ComplianceAction::Fill, 4.into());
+
+        let counts = tracker.counts(venue, 5.into());
+
+        assert_eq!(counts.submits, 1);
+        assert_eq!(counts.modifies, 1);
+        assert_eq!(counts.cancels, 1);
+        assert_eq!(counts.fills, 1);
+        assert_eq!(counts.order_to_trade_ratio(), 3.0);
+    }
+
+    #[rstest]
+    fn test_below_minimum_messages_is_ok(mut tracker: VenueComplianceTracker) {
+        let venue = Venue::from("XCME");
+        for ts in 0..3 {
+            tracker.record(venue, ComplianceAction::Submit, ts.into());
+        }
+
+        assert_eq!(tracker.status(venue, 3.into()), ComplianceStatus::Ok);
+    }
+
+    #[rstest]
+    fn test_warning_then_blocked(mut tracker: VenueComplianceTracker) {
+        let venue = Venue::from("XCME");
+        let mut statuses = Vec::new();
+        for ts in 0..10 {
+            statuses.push(tracker.record(venue, ComplianceAction::Submit, ts.into()));
+        }
+
+        assert_eq!(statuses[3], ComplianceStatus::Ok);
+        assert_eq!(statuses[4], ComplianceStatus::Warning);
+        assert_eq!(statuses[9], ComplianceStatus::Blocked);
+        assert!(tracker.would_block(venue, ComplianceAction::Cancel, 10.into()));
+        assert!(!tracker.would_block(venue, ComplianceAction::Fill, 10.into()));
+    }
+
+    #[rstest]
+    fn test_fills_reduce_ratio(mut tracker: VenueComplianceTracker) {
+        let venue = Venue::from("XCME");
+        for ts in 0..10 {
+            tracker.record(venue, ComplianceAction::Submit, ts.into());
+        }
+
+        tracker.record(venue, ComplianceAction::Fill, 10.into());
+        let status = tracker.record(venue, ComplianceAction::Fill, 11.into());
+
+        assert_eq!(status, ComplianceStatus::Warning);
+    }
+
+    #[rstest]
+    fn test_window_expiry(mut tracker: VenueComplianceTracker) {
+        let venue = Venue::from("XCME");
+        for ts in 0..10 {
+            tracker.record(venue, ComplianceAction::Submit, ts.into());
+        }
+
+        assert_eq!(tracker.status(venue, 1_009.into()), ComplianceStatus::Ok);
+        assert_eq!(tracker.counts(venue, 1_009.into()).submits, 0);
+    }
+
+    #[rstest]
+    fn test_venue_config_and_max_order_messages(mut tracker: VenueComplianceTracker) {
+        let venue = Venue::from("XEUR");
+        let config = ComplianceConfig {
+            window_ns: 1_000,
+            max_order_messages: Some(2),
+            ..Default::default()
+        };
+        tracker.set_venue_config(venue, config).unwrap();
+
+        tracker.record(venue, ComplianceAction::Submit, 0.into());
+        tracker.record(venue, ComplianceAction::Submit, 1.into());
+
+        assert!(tracker.would_block(venue, ComplianceAction::Submit, 2.into()));
+        assert!(!tracker.would_block(Venue::from("XCME"), ComplianceAction::Submit, 2.into()));
+    }
+}
@@ -0,0 +1,187 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Deduplication of redelivered trading commands by their idempotency key (command ID).
+
+use std::collections::{HashSet, VecDeque};
+
+use nautilus_common::cache::Cache;
+use nautilus_core::{correctness::check_predicate_true, nanos::UnixNanos, uuid::UUID4};
+
+/// The general cache key under which the recent command window is persisted.
+pub const RECENT_COMMANDS_CACHE_KEY: &str = "execution.recent_commands";
+
+/// Provides a bounded window of recently executed command IDs, so that commands redelivered
+/// after a reconnect or journal replay are not executed twice.
+///
+/// Commands are retained while within `window_ns` of the latest command, up to `capacity`.
+#[derive(Clone, Debug)]
+pub struct RecentCommands {
+    window_ns: u64,
+    capacity: usize,
+    ids: HashSet<UUID4>,
+    entries: VecDeque<(UUID4, UnixNanos)>,
+}
+
+impl RecentCommands {
+    /// Creates a new [`RecentCommands`] instance.
+    pub fn new(window_ns: u64, capacity: usize) -> anyhow::Result<Self> {
+        check_predicate_true(capacity > 0, "`capacity` must be positive")?;
+        Ok(Self {
+            window_ns,
+            capacity,
+            ids: HashSet::new(),
+            entries: VecDeque::new(),
+        })
+    }
+
+    /// Returns the count of command IDs in the window.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the window is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns whether the given `command_id` is in the window.
+    #[must_use]
+    pub fn contains(&self, command_id: &UUID4) -> bool {
+        self.ids.contains(command_id)
+    }
+
+    /// Records the given `command_id`, returning false if it was already in the window
+    /// (i.e. the command is a duplicate and should not be executed).
+    pub fn insert(&mut self, command_id: UUID4, ts_init: UnixNanos) -> bool {
+        if !self.ids.insert(command_id) {
+            return false;
+        }
+        self.entries.push_back((command_id, ts_init));
+        self.evict(ts_init);
+        true
+    }
+
+    /// Clears all command IDs from the window.
+    pub fn clear(&mut self) {
+        self.ids.clear();
+        self.entries.clear();
+    }
+
+    /// Persists the window to the general `cache` (and its database, if configured).
+    pub fn save(&self, cache: &mut Cache) -> anyhow::Result<()> {
+        let entries: Vec<(UUID4, UnixNanos)> = self.entries.iter().copied().collect();
+        cache.add(RECENT_COMMANDS_CACHE_KEY, serde_json::to_vec(&entries)?)
+    }
+
+    /// Restores the window from the general `cache`, replacing any current entries.
+    ///
+    /// Returns the count of command IDs restored.
+    pub fn load(&mut self, cache: &Cache) -> anyhow::Result<usize> {
+        self.clear();
+
+        let Some(bytes) = cache.get(RECENT_COMMANDS_CACHE_KEY)? else {
+            return Ok(0);
+        };
+
+        let entries: Vec<(UUID4, UnixNanos)> = serde_json::from_slice(bytes)?;
+        for (command_id, ts_init) in entries {
+            self.insert(command_id, ts_init);
+        }
+        Ok(self.len())
+    }
+
+    fn evict(&mut self, now: UnixNanos) {
+        let cutoff = now.as_u64().saturating_sub(self.window_ns);
+        while let Some((command_id, ts_init)) = self.entries.front().copied() {
+            if self.entries.len() <= self.capacity && ts_init.as_u64() >= cutoff {
+                break;
+            }
+            self.entries.pop_front();
+            self.ids.remove(&command_id);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_insert_duplicate() {
+        let mut recent = RecentCommands::new(1_000, 10).unwrap();
+        let command_id = UUID4::new();
+
+        assert!(recent.insert(command_id, 1.into()));
+        assert!(!recent.insert(command_id, 2.into()));
+        assert_eq!(recent.len(), 1);
+    }
+
+    #[rstest]
+    fn test_evicts_outside_window() {
+        let mut recent = RecentCommands::new(1_000, 10).unwrap();
+        let command_id = UUID4::new();
+        recent.insert(command_id, 1.into());
+
+        recent.insert(UUID4::new(), 1_002.into());
+
+        assert!(!recent.contains(&command_id));
+        assert_eq!(recent.len(), 1);
+    }
+
+    #[rstest]
+    fn test_evicts_over_capacity() {
+        let mut recent = RecentCommands::new(1_000, 2).unwrap();
+        let command_id = UUID4::new();
+        recent.insert(command_id, 1.into());
+        recent.insert(UUID4::new(), 2.into());
+
+        recent.insert(UUID4::new(), 3.into());
+
+        assert!(!recent.contains(&command_id));
+        assert_eq!(recent.len(), 2);
+    }
+
+    #[rstest]
+    fn test_save_and_load_from_cache() {
+        let mut cache = Cache::default();
+        let mut recent = RecentCommands::new(1_000, 10).unwrap();
+        let command_id = UUID4::new();
+        recent.insert(command_id, 1.into());
+        recent.save(&mut cache).unwrap();
+
+        let mut restored = RecentCommands::new(1_000, 10).unwrap();
+        let count = restored.load(&cache).unwrap();
+
+        assert_eq!(count, 1);
+        assert!(!restored.insert(command_id, 5.into()));
+    }
+
+    #[rstest]
+    fn test_load_when_nothing_persisted() {
+        let cache = Cache::default();
+        let mut recent = RecentCommands::new(1_000, 10).unwrap();
+
+        assert_eq!(recent.load(&cache).unwrap(), 0);
+        assert!(recent.is_empty());
+    }
+}
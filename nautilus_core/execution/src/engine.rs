@@ -53,9 +53,9 @@ pub struct ExecutionEngine {
     pub event_count: u64,
     pub report_count: u64,
     cache: &'static Cache,
-    default_client: Option<ExecutionClient>,
+    default_client: Option<Box<dyn ExecutionClient>>,
     pos_id_generator: PositionIdGenerator,
-    clients: HashMap<ClientId, ExecutionClient>,
+    clients: HashMap<ClientId, Box<dyn ExecutionClient>>,
     routing_map: HashMap<Venue, ClientId>,
     oms_overrides: HashMap<StrategyId, OmsType>,
     external_order_claims: HashMap<InstrumentId, StrategyId>,
@@ -95,11 +95,14 @@ impl ExecutionEngine {
 
     // -- REGISTRATION --------------------------------------------------------
 
-    pub fn register_client(&mut self, client: ExecutionClient) -> anyhow::Result<()> {
+    pub fn register_client(&mut self, client: Box<dyn ExecutionClient>) -> anyhow::Result<()> {
         todo!();
     }
 
-    pub fn register_default_client(&mut self, client: ExecutionClient) -> anyhow::Result<()> {
+    pub fn register_default_client(
+        &mut self,
+        client: Box<dyn ExecutionClient>,
+    ) -> anyhow::Result<()> {
         todo!();
     }
 
@@ -142,20 +145,22 @@ impl ExecutionEngine {
 
     fn execute_command(&mut self, command: TradingCommand) {
         debug!("<--[CMD] {:?}", command); // TODO: Log constants
+
         self.command_count += 1;
 
         // TODO: Refine getting the client (no need for two expects)
-        let client = if let Some(client) = self.clients.get(&command.client_id()) {
-            client
-        } else if let Some(client_id) = self.routing_map.get(&command.instrument_id().venue) {
-            if let Some(client) = self.clients.get(client_id) {
-                client
+        let client: &dyn ExecutionClient =
+            if let Some(client) = self.clients.get(&command.client_id()) {
+                client.as_ref()
+            } else if let Some(client_id) = self.routing_map.get(&command.instrument_id().venue) {
+                if let Some(client) = self.clients.get(client_id) {
+                    client.as_ref()
+                } else {
+                    self.default_client.as_deref().expect("No client found")
+                }
             } else {
-                self.default_client.as_ref().expect("No client found")
-            }
-        } else {
-            self.default_client.as_ref().expect("No client found")
-        };
+                self.default_client.as_deref().expect("No client found")
+            };
 
         match command {
             TradingCommand::SubmitOrder(cmd) => self.handle_submit_order(client, cmd),
@@ -168,31 +173,31 @@ impl ExecutionEngine {
         }
     }
 
-    fn handle_submit_order(&self, client: &ExecutionClient, command: SubmitOrder) {
+    fn handle_submit_order(&self, client: &dyn ExecutionClient, command: SubmitOrder) {
         todo!();
     }
 
-    fn handle_submit_order_list(&self, client: &ExecutionClient, command: SubmitOrderList) {
+    fn handle_submit_order_list(&self, client: &dyn ExecutionClient, command: SubmitOrderList) {
         todo!();
     }
 
-    fn handle_modify_order(&self, client: &ExecutionClient, command: ModifyOrder) {
+    fn handle_modify_order(&self, client: &dyn ExecutionClient, command: ModifyOrder) {
         todo!();
     }
 
-    fn handle_cancel_order(&self, client: &ExecutionClient, command: CancelOrder) {
+    fn handle_cancel_order(&self, client: &dyn ExecutionClient, command: CancelOrder) {
         todo!();
     }
 
-    fn handle_cancel_all_orders(&self, client: &ExecutionClient, command: CancelAllOrders) {
+    fn handle_cancel_all_orders(&self, client: &dyn ExecutionClient, command: CancelAllOrders) {
         todo!();
     }
 
-    fn handle_batch_cancel_orders(&self, client: &ExecutionClient, command: BatchCancelOrders) {
+    fn handle_batch_cancel_orders(&self, client: &dyn ExecutionClient, command: BatchCancelOrders) {
         todo!();
     }
 
-    fn handle_query_order(&self, client: &ExecutionClient, command: QueryOrder) {
+    fn handle_query_order(&self, client: &dyn ExecutionClient, command: QueryOrder) {
         todo!();
     }
 
@@ -27,7 +27,15 @@
 //! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`
 //! - `python`: Enables Python bindings from `pyo3`
 
+pub mod algorithm;
+pub mod capabilities;
 pub mod client;
+pub mod compliance;
+pub mod dedup;
 pub mod engine;
+pub mod live;
 pub mod matching_core;
 pub mod messages;
+pub mod overrides;
+pub mod positions;
+pub mod trailing;
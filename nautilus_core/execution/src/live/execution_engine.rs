@@ -0,0 +1,1656 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a `LiveExecutionEngine` which routes trading commands to execution clients,
+//! tracks in-flight commands, and processes order events onto the message bus.
+//!
+//! Commands which have not been acknowledged by the venue within a threshold are queried
+//! with the client, and on startup the cached orders and positions are reconciled with the
+//! execution reports from each client.
+//!
+//! Commands redelivered after a reconnect or journal replay are deduplicated by their command
+//! ID, using a window of recent commands which is persisted in the cache across restarts.
+//!
+//! Orders with a TIF the venue does not support are submitted with a supported TIF, and the
+//! requested TIF is then enforced by canceling the order (see [`TifEnforcement`]).
+
+use std::{collections::HashMap, time::Duration};
+
+use indexmap::IndexMap;
+use log::{debug, error, info, warn};
+use nautilus_common::{cache::Cache, msgbus::switchboard::get_order_events_topic};
+use nautilus_core::{nanos::UnixNanos, time::get_atomic_clock_realtime, uuid::UUID4};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderStatus},
+    events::order::{
+        accepted::OrderAccepted, canceled::OrderCanceled, expired::OrderExpired,
+        filled::OrderFilled, rejected::OrderRejected, submitted::OrderSubmitted, OrderEventAny,
+    },
+    identifiers::{
+        client_id::ClientId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId, strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
+        venue::Venue, venue_order_id::VenueOrderId,
+    },
+    orders::any::OrderAny,
+    reports::{fill::FillReport, order::OrderStatusReport, position::PositionStatusReport},
+    types::{price::Price, quantity::Quantity},
+};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use ustr::Ustr;
+
+use crate::{
+    capabilities::TifEnforcement,
+    client::ExecutionClient,
+    dedup::RecentCommands,
+    messages::{cancel::CancelOrder, query::QueryOrder, TradingCommand},
+};
+
+/// Configuration for [`LiveExecutionEngine`] instances.
+#[derive(Clone, Debug)]
+pub struct LiveExecutionEngineConfig {
+    /// The interval (milliseconds) between checks for timed out in-flight commands.
+    pub inflight_check_interval_ms: u64,
+    /// The time (milliseconds) after which an unacknowledged command is queried.
+    pub inflight_check_threshold_ms: u64,
+    /// The number of queries for an unacknowledged command before it is no longer tracked.
+    pub inflight_check_retries: u32,
+    /// The time (milliseconds) for which executed command IDs are retained for deduplication.
+    pub recent_commands_window_ms: u64,
+    /// The maximum number of executed command IDs retained for deduplication.
+    pub recent_commands_capacity: usize,
+}
+
+impl Default for LiveExecutionEngineConfig {
+    fn default() -> Self {
+        Self {
+            inflight_check_interval_ms: 2_000,
+            inflight_check_threshold_ms: 5_000,
+            inflight_check_retries: 5,
+            recent_commands_window_ms: 86_400_000,
+            recent_commands_capacity: 10_000,
+        }
+    }
+}
+
+/// Represents the kind of an in-flight command, which determines the events resolving it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InFlightKind {
+    Submit,
+    Modify,
+    Cancel,
+}
+
+/// Represents a command sent to an execution client which the venue has not yet acknowledged.
+#[derive(Clone, Debug)]
+pub struct InFlightCommand {
+    pub kind: InFlightKind,
+    pub command_id: UUID4,
+    pub trader_id: TraderId,
+    pub client_id: ClientId,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub venue_order_id: VenueOrderId,
+    /// The UNIX timestamp (nanoseconds) when the command was last sent or queried.
+    pub ts_sent: UnixNanos,
+    /// The number of queries made for the command.
+    pub retries: u32,
+}
+
+impl InFlightCommand {
+    /// Returns whether the given order `event` acknowledges the command.
+    #[must_use]
+    pub fn is_resolved_by(&self, event: &OrderEventAny) -> bool {
+        match event {
+            OrderEventAny::Rejected(_)
+            | OrderEventAny::Canceled(_)
+            | OrderEventAny::Expired(_)
+            | OrderEventAny::Filled(_) => true,
+            OrderEventAny::Accepted(_)
+            | OrderEventAny::Triggered(_)
+            | OrderEventAny::PartiallyFilled(_) => self.kind == InFlightKind::Submit,
+            OrderEventAny::Updated(_) | OrderEventAny::ModifyRejected(_) => {
+                self.kind == InFlightKind::Modify
+            }
+            OrderEventAny::CancelRejected(_) => self.kind == InFlightKind::Cancel,
+            _ => false,
+        }
+    }
+
+    fn query(&self, ts_init: UnixNanos) -> anyhow::Result<QueryOrder> {
+        QueryOrder::new(
+            self.trader_id,
+            self.client_id,
+            self.strategy_id,
+            self.instrument_id,
+            self.client_order_id,
+            self.venue_order_id,
+            UUID4::new(),
+            ts_init,
+        )
+    }
+}
+
+/// Represents an order submitted with an emulated TIF, awaiting local enforcement.
+#[derive(Clone, Copy, Debug)]
+pub struct EmulatedTif {
+    pub client_id: ClientId,
+    pub enforcement: TifEnforcement,
+    pub expire_time: Option<UnixNanos>,
+}
+
+/// Publishes the order events processed by the [`LiveExecutionEngine`], e.g. onto the
+/// message bus.
+pub trait ExecutionPublisher {
+    /// Publishes the order `event` on the given `topic`.
+    fn publish_event(&mut self, topic: Ustr, event: OrderEventAny);
+}
+
+impl ExecutionPublisher for UnboundedSender<(Ustr, OrderEventAny)> {
+    fn publish_event(&mut self, topic: Ustr, event: OrderEventAny) {
+        if let Err(e) = self.send((topic, event)) {
+            error!("Error publishing order event: {e}");
+        }
+    }
+}
+
+/// Represents a message processed by the [`LiveExecutionEngine`] run loop.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum ExecutionEngineMessage {
+    Command(TradingCommand),
+    Event(OrderEventAny),
+}
+
+/// Provides a live execution engine which routes trading commands across the registered
+/// execution clients, and applies the resulting order events to the cache before
+/// publishing them onto the message bus.
+pub struct LiveExecutionEngine<P: ExecutionPublisher> {
+    pub command_count: u64,
+    pub event_count: u64,
+    pub report_count: u64,
+    config: LiveExecutionEngineConfig,
+    cache: Cache,
+    publisher: P,
+    clients: IndexMap<ClientId, Box<dyn ExecutionClient>>,
+    default_client: Option<ClientId>,
+    routing_map: HashMap<Venue, ClientId>,
+    in_flight: IndexMap<ClientOrderId, InFlightCommand>,
+    recent_commands: RecentCommands,
+    emulated_tifs: IndexMap<ClientOrderId, EmulatedTif>,
+}
+
+impl<P: ExecutionPublisher> LiveExecutionEngine<P> {
+    /// Creates a new [`LiveExecutionEngine`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `config.recent_commands_capacity` is zero.
+    pub fn new(
+        cache: Cache,
+        publisher: P,
+        config: LiveExecutionEngineConfig,
+    ) -> anyhow::Result<Self> {
+        let recent_commands = RecentCommands::new(
+            config.recent_commands_window_ms * 1_000_000,
+            config.recent_commands_capacity,
+        )?;
+
+        Ok(Self {
+            command_count: 0,
+            event_count: 0,
+            report_count: 0,
+            config,
+            cache,
+            publisher,
+            clients: IndexMap::new(),
+            default_client: None,
+            routing_map: HashMap::new(),
+            in_flight: IndexMap::new(),
+            recent_commands,
+            emulated_tifs: IndexMap::new(),
+        })
+    }
+
+    /// Returns a reference to the cache.
+    #[must_use]
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
+    /// Returns a mutable reference to the cache.
+    pub fn cache_mut(&mut self) -> &mut Cache {
+        &mut self.cache
+    }
+
+    /// Returns the IDs of the registered clients.
+    #[must_use]
+    pub fn registered_clients(&self) -> Vec<ClientId> {
+        self.clients.keys().copied().collect()
+    }
+
+    /// Returns the in-flight command for the given `client_order_id` (if found).
+    #[must_use]
+    pub fn in_flight(&self, client_order_id: &ClientOrderId) -> Option<&InFlightCommand> {
+        self.in_flight.get(client_order_id)
+    }
+
+    /// Returns the count of in-flight commands.
+    #[must_use]
+    pub fn in_flight_count(&self) -> usize {
+        self.in_flight.len()
+    }
+
+    /// Returns the emulated TIF awaiting enforcement for the given `client_order_id` (if found).
+    #[must_use]
+    pub fn emulated_tif(&self, client_order_id: &ClientOrderId) -> Option<&EmulatedTif> {
+        self.emulated_tifs.get(client_order_id)
+    }
+
+    /// Returns the window of recently executed commands.
+    #[must_use]
+    pub fn recent_commands(&self) -> &RecentCommands {
+        &self.recent_commands
+    }
+
+    /// Restores the recent command window from the cache, so that commands redelivered
+    /// after a restart are not executed twice.
+    pub fn load_recent_commands(&mut self) -> anyhow::Result<()> {
+        let count = self.recent_commands.load(&self.cache)?;
+        debug!("Loaded {count} recent command(s) from cache");
+        Ok(())
+    }
+
+    /// Persists the recent command window to the cache (and its database, if configured).
+    pub fn save_recent_commands(&mut self) -> anyhow::Result<()> {
+        self.recent_commands.save(&mut self.cache)?;
+        debug!(
+            "Saved {} recent command(s) to cache",
+            self.recent_commands.len()
+        );
+        Ok(())
+    }
+
+    // -- REGISTRATION --------------------------------------------------------
+
+    /// Registers the given execution `client` with the engine.
+    ///
+    /// Commands for the client's venue are routed to it, unless another client was
+    /// registered for the venue first.
+    pub fn register_client(&mut self, client: Box<dyn ExecutionClient>) -> anyhow::Result<()> {
+        let client_id = client.client_id();
+        if self.clients.contains_key(&client_id) {
+            anyhow::bail!("Execution client {client_id} already registered");
+        }
+
+        self.routing_map.entry(client.venue()).or_insert(client_id);
+        self.clients.insert(client_id, client);
+        debug!("Registered execution client {client_id}");
+        Ok(())
+    }
+
+    /// Registers the given execution `client` as the default, for commands with no other route.
+    pub fn register_default_client(
+        &mut self,
+        client: Box<dyn ExecutionClient>,
+    ) -> anyhow::Result<()> {
+        let client_id = client.client_id();
+        self.register_client(client)?;
+        self.default_client = Some(client_id);
+        Ok(())
+    }
+
+    /// Routes commands for the given `venue` to the registered client.
+    pub fn register_venue_routing(
+        &mut self,
+        client_id: ClientId,
+        venue: Venue,
+    ) -> anyhow::Result<()> {
+        if !self.clients.contains_key(&client_id) {
+            anyhow::bail!("Execution client {client_id} not registered");
+        }
+        self.routing_map.insert(venue, client_id);
+        Ok(())
+    }
+
+    /// Deregisters the execution client, no longer tracking its in-flight commands.
+    pub fn deregister_client(&mut self, client_id: ClientId) -> anyhow::Result<()> {
+        if self.clients.shift_remove(&client_id).is_none() {
+            anyhow::bail!("Execution client {client_id} not registered");
+        }
+
+        self.routing_map.retain(|_, id| *id != client_id);
+        if self.default_client == Some(client_id) {
+            self.default_client = None;
+        }
+        self.in_flight
+            .retain(|_, command| command.client_id != client_id);
+        self.emulated_tifs
+            .retain(|_, emulated| emulated.client_id != client_id);
+
+        debug!("Deregistered execution client {client_id}");
+        Ok(())
+    }
+
+    // -- COMMANDS ------------------------------------------------------------
+
+    /// Routes the trading `command` to an execution client, tracking it as in-flight until
+    /// the venue acknowledges it.
+    ///
+    /// Orders for submit commands are added to the cache if not already cached, and are sent
+    /// to the client with any TIF its venue does not support emulated. Commands already in the
+    /// recent command window are ignored, with a command only recorded in the window once its
+    /// client has accepted it (so a failed command can be redelivered).
+    pub fn execute(&mut self, command: TradingCommand) -> anyhow::Result<()> {
+        if self.recent_commands.contains(&command.command_id()) {
+            warn!("Duplicate command {} ignored", command.command_id());
+            return Ok(());
+        }
+        self.command_count += 1;
+
+        let client_id = self.route(&command)?;
+        let (routed, emulated) = self.emulate_time_in_force(&command, client_id)?;
+
+        match &command {
+            TradingCommand::SubmitOrder(submit) => {
+                self.cache_order(&submit.order, submit.position_id, client_id)?;
+            }
+            TradingCommand::SubmitOrderList(submit) => {
+                for order in &submit.order_list.orders {
+                    self.cache_order(order, submit.position_id, client_id)?;
+                }
+            }
+            _ => {}
+        }
+
+        self.client_mut(&client_id)?.execute(&routed)?;
+        self.recent_commands
+            .insert(command.command_id(), command.ts_init());
+        self.emulated_tifs.extend(emulated);
+        self.track(&command, client_id);
+        Ok(())
+    }
+
+    /// Resolves the TIF of each order submitted by the `command` with the capabilities of the
+    /// client's venue, returning the command to send with any emulated TIFs downgraded.
+    fn emulate_time_in_force(
+        &self,
+        command: &TradingCommand,
+        client_id: ClientId,
+    ) -> anyhow::Result<(TradingCommand, Vec<(ClientOrderId, EmulatedTif)>)> {
+        let mut routed = command.clone();
+        let mut emulated = Vec::new();
+        let Some(capabilities) = self
+            .clients
+            .get(&client_id)
+            .and_then(|client| client.capabilities())
+        else {
+            return Ok((routed, emulated));
+        };
+
+        let orders: Vec<&mut OrderAny> = match &mut routed {
+            TradingCommand::SubmitOrder(submit) => vec![&mut submit.order],
+            TradingCommand::SubmitOrderList(submit) => {
+                submit.order_list.orders.iter_mut().collect()
+            }
+            _ => Vec::new(),
+        };
+
+        for order in orders {
+            let requested = order.time_in_force();
+            let emulation = capabilities.resolve_time_in_force(requested)?;
+            if let Some(enforcement) = emulation.enforcement {
+                debug!(
+                    "Submitting {} {requested} order as {} with {enforcement}",
+                    order.client_order_id(),
+                    emulation.submit_as,
+                );
+                emulated.push((
+                    order.client_order_id(),
+                    EmulatedTif {
+                        client_id,
+                        enforcement,
+                        expire_time: order.expire_time(),
+                    },
+                ));
+            }
+            order.set_time_in_force(emulation.submit_as);
+        }
+
+        Ok((routed, emulated))
+    }
+
+    /// Cancels the order with an emulated TIF, tracking the cancel as in-flight.
+    fn cancel_emulated(
+        &mut self,
+        client_order_id: ClientOrderId,
+        client_id: ClientId,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let order = self
+            .cache
+            .order(&client_order_id)
+            .ok_or_else(|| anyhow::anyhow!("Order {client_order_id} not found in cache"))?;
+        let command = TradingCommand::CancelOrder(CancelOrder::new(
+            order.trader_id(),
+            client_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            order.venue_order_id().unwrap_or_default(),
+            UUID4::new(),
+            ts_init,
+        )?);
+
+        debug!("Canceling {client_order_id} to enforce emulated TIF");
+        self.client_mut(&client_id)?.execute(&command)?;
+        self.track(&command, client_id);
+        Ok(())
+    }
+
+    /// Cancels the orders with an emulated GTD TIF whose expire time is at or before `now`,
+    /// returning their client order IDs.
+    pub fn check_expired_tifs(&mut self, now: UnixNanos) -> Vec<ClientOrderId> {
+        let expired: Vec<(ClientOrderId, ClientId)> = self
+            .emulated_tifs
+            .iter()
+            .filter(|(_, emulated)| {
+                emulated.enforcement == TifEnforcement::CancelAtExpireTime
+                    && emulated
+                        .expire_time
+                        .map_or(false, |expire_time| expire_time <= now)
+            })
+            .map(|(client_order_id, emulated)| (*client_order_id, emulated.client_id))
+            .collect();
+
+        for (client_order_id, client_id) in &expired {
+            self.emulated_tifs.shift_remove(client_order_id);
+            if let Err(e) = self.cancel_emulated(*client_order_id, *client_id, now) {
+                error!("Error canceling expired order {client_order_id}: {e}");
+            }
+        }
+
+        expired
+            .into_iter()
+            .map(|(client_order_id, _)| client_order_id)
+            .collect()
+    }
+
+    fn cache_order(
+        &mut self,
+        order: &OrderAny,
+        position_id: Option<PositionId>,
+        client_id: ClientId,
+    ) -> anyhow::Result<()> {
+        if self.cache.order(&order.client_order_id()).is_none() {
+            self.cache
+                .add_order(order.clone(), position_id, Some(client_id), false)?;
+        }
+        Ok(())
+    }
+
+    fn track(&mut self, command: &TradingCommand, client_id: ClientId) {
+        let ts_sent = command.ts_init();
+        let mut track = |kind, client_order_id, venue_order_id, strategy_id, trader_id| {
+            self.in_flight.insert(
+                client_order_id,
+                InFlightCommand {
+                    kind,
+                    command_id: command.command_id(),
+                    trader_id,
+                    client_id,
+                    strategy_id,
+                    instrument_id: command.instrument_id(),
+                    client_order_id,
+                    venue_order_id,
+                    ts_sent,
+                    retries: 0,
+                },
+            );
+        };
+
+        match command {
+            TradingCommand::SubmitOrder(submit) => track(
+                InFlightKind::Submit,
+                submit.client_order_id,
+                submit.venue_order_id,
+                submit.strategy_id,
+                submit.trader_id,
+            ),
+            TradingCommand::SubmitOrderList(submit) => {
+                for order in &submit.order_list.orders {
+                    track(
+                        InFlightKind::Submit,
+                        order.client_order_id(),
+                        submit.venue_order_id,
+                        submit.strategy_id,
+                        submit.trader_id,
+                    );
+                }
+            }
+            TradingCommand::ModifyOrder(modify) => track(
+                InFlightKind::Modify,
+                modify.client_order_id,
+                modify.venue_order_id,
+                modify.strategy_id,
+                modify.trader_id,
+            ),
+            TradingCommand::CancelOrder(cancel) => track(
+                InFlightKind::Cancel,
+                cancel.client_order_id,
+                cancel.venue_order_id,
+                cancel.strategy_id,
+                cancel.trader_id,
+            ),
+            TradingCommand::BatchCancelOrders(batch) => {
+                for cancel in &batch.cancels {
+                    track(
+                        InFlightKind::Cancel,
+                        cancel.client_order_id,
+                        cancel.venue_order_id,
+                        cancel.strategy_id,
+                        cancel.trader_id,
+                    );
+                }
+            }
+            TradingCommand::CancelAllOrders(_) | TradingCommand::QueryOrder(_) => {}
+        }
+    }
+
+    /// Queries the in-flight commands sent more than the threshold before `now`, returning
+    /// the client order IDs of any commands which are no longer tracked after exhausting
+    /// their retries.
+    pub fn check_in_flight(&mut self, now: UnixNanos) -> Vec<ClientOrderId> {
+        let threshold_ns = self.config.inflight_check_threshold_ms * 1_000_000;
+        let mut queries = Vec::new();
+        let mut abandoned = Vec::new();
+
+        for (client_order_id, command) in &mut self.in_flight {
+            if now.as_u64().saturating_sub(command.ts_sent.as_u64()) < threshold_ns {
+                continue;
+            }
+
+            if command.retries >= self.config.inflight_check_retries {
+                abandoned.push(*client_order_id);
+                continue;
+            }
+
+            command.retries += 1;
+            command.ts_sent = now;
+            queries.push(command.clone());
+        }
+
+        for client_order_id in &abandoned {
+            warn!("No response for in-flight command for {client_order_id}, no longer tracking");
+            self.in_flight.shift_remove(client_order_id);
+        }
+
+        for command in queries {
+            debug!(
+                "Querying in-flight {:?} command for {} (retry {})",
+                command.kind, command.client_order_id, command.retries
+            );
+            let result = command.query(now).and_then(|query| {
+                self.client_mut(&command.client_id)?
+                    .execute(&TradingCommand::QueryOrder(query))
+            });
+            if let Err(e) = result {
+                error!("Error querying order {}: {e}", command.client_order_id);
+            }
+        }
+
+        abandoned
+    }
+
+    // -- EVENTS --------------------------------------------------------------
+
+    /// Applies the order `event` to its cached order, then publishes it on the message bus.
+    ///
+    /// Any in-flight command acknowledged by the event is no longer tracked.
+    pub fn process(&mut self, event: OrderEventAny) -> anyhow::Result<()> {
+        self.event_count += 1;
+
+        let client_order_id = event.client_order_id();
+        if self
+            .in_flight
+            .get(&client_order_id)
+            .map_or(false, |command| command.is_resolved_by(&event))
+        {
+            self.in_flight.shift_remove(&client_order_id);
+        }
+
+        let is_acknowledged = matches!(
+            event,
+            OrderEventAny::Accepted(_) | OrderEventAny::PartiallyFilled(_)
+        );
+        self.apply_event(event)?;
+        self.enforce_time_in_force(client_order_id, is_acknowledged)
+    }
+
+    /// Cancels an order with an emulated IOC or FOK TIF once acknowledged by the venue, and
+    /// no longer tracks the emulated TIF once the order is closed.
+    fn enforce_time_in_force(
+        &mut self,
+        client_order_id: ClientOrderId,
+        is_acknowledged: bool,
+    ) -> anyhow::Result<()> {
+        let Some(emulated) = self.emulated_tifs.get(&client_order_id).copied() else {
+            return Ok(());
+        };
+
+        if self
+            .cache
+            .order(&client_order_id)
+            .map_or(true, OrderAny::is_closed)
+        {
+            self.emulated_tifs.shift_remove(&client_order_id);
+            return Ok(());
+        }
+
+        let cancel_on_ack = matches!(
+            emulated.enforcement,
+            TifEnforcement::CancelRemaining | TifEnforcement::CancelUnlessFilled
+        );
+        if cancel_on_ack && is_acknowledged {
+            self.emulated_tifs.shift_remove(&client_order_id);
+            let ts_init = get_atomic_clock_realtime().get_time_ns();
+            self.cancel_emulated(client_order_id, emulated.client_id, ts_init)?;
+        }
+        Ok(())
+    }
+
+    fn apply_event(&mut self, event: OrderEventAny) -> anyhow::Result<()> {
+        let client_order_id = event.client_order_id();
+        let mut order = self
+            .cache
+            .order(&client_order_id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Order {client_order_id} not found in cache"))?;
+
+        order
+            .apply(event.clone())
+            .map_err(|e| anyhow::anyhow!("Error applying event to order {client_order_id}: {e}"))?;
+        self.cache.update_order(&order)?;
+
+        self.publisher
+            .publish_event(get_order_events_topic(&event.strategy_id()), event);
+        Ok(())
+    }
+
+    // -- RECONCILIATION ------------------------------------------------------
+
+    /// Reconciles the cached orders and positions with the execution reports from each
+    /// client, generating and processing the events for any state missed while disconnected.
+    ///
+    /// Returns the count of events generated.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any report could not be reconciled.
+    pub fn reconcile(&mut self, ts_init: UnixNanos) -> anyhow::Result<usize> {
+        let mut order_reports = Vec::new();
+        let mut fill_reports = Vec::new();
+        let mut position_reports = Vec::new();
+        for client in self.clients.values() {
+            order_reports.extend(client.generate_order_status_reports(None, None, None, false)?);
+            fill_reports.extend(client.generate_fill_reports(None, None, None, None)?);
+            position_reports.extend(client.generate_position_status_reports(None, None, None)?);
+        }
+        self.report_count += fill_reports.len() as u64;
+
+        let mut event_count = 0;
+        let mut failed = 0;
+        for report in order_reports {
+            self.report_count += 1;
+
+            let result = self
+                .reconcile_report(&report, &fill_reports, ts_init)
+                .and_then(|events| {
+                    event_count += events.len();
+                    events
+                        .into_iter()
+                        .try_for_each(|event| self.apply_event(event))
+                });
+            if let Err(e) = result {
+                error!("Error reconciling {}: {e}", report.venue_order_id);
+                failed += 1;
+            }
+        }
+
+        for report in position_reports {
+            self.report_count += 1;
+
+            if !self.is_position_in_sync(&report) {
+                failed += 1;
+            }
+        }
+
+        if failed > 0 {
+            anyhow::bail!("Failed to reconcile {failed} execution report(s)");
+        }
+        info!("Reconciled state with {event_count} generated event(s)");
+        Ok(event_count)
+    }
+
+    /// Returns the events which bring the cached order to the state in the `report`, using
+    /// the `fills` reported for the order (or inferring a fill when none were reported).
+    ///
+    /// An order still initialized (e.g. the node stopped before the submit was recorded) is
+    /// first submitted, then accepted, as the venue has evidently received it.
+    fn reconcile_report(
+        &self,
+        report: &OrderStatusReport,
+        fills: &[FillReport],
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Vec<OrderEventAny>> {
+        let client_order_id = report
+            .client_order_id
+            .or_else(|| self.cache.client_order_id(&report.venue_order_id).copied());
+        let Some(order) = client_order_id.and_then(|id| self.cache.order(&id)) else {
+            warn!(
+                "No cached order for {} (external orders are not reconciled)",
+                report.venue_order_id
+            );
+            return Ok(Vec::new());
+        };
+
+        if order.is_closed() || report.is_in_sync(order) {
+            return Ok(Vec::new());
+        }
+
+        let mut events = Vec::new();
+
+        if report.order_status == OrderStatus::Rejected {
+            let reason = report
+                .cancel_reason
+                .unwrap_or_else(|| Ustr::from("UNKNOWN"));
+            events.push(OrderEventAny::Rejected(OrderRejected::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                report.account_id,
+                reason,
+                UUID4::new(),
+                report.ts_last,
+                ts_init,
+                true,
+            )?));
+            return Ok(events);
+        }
+
+        if order.status() == OrderStatus::Initialized {
+            events.push(OrderEventAny::Submitted(OrderSubmitted::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                report.account_id,
+                UUID4::new(),
+                report.ts_accepted,
+                ts_init,
+            )?));
+        }
+
+        if matches!(
+            order.status(),
+            OrderStatus::Initialized | OrderStatus::Submitted
+        ) {
+            events.push(OrderEventAny::Accepted(OrderAccepted::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                report.venue_order_id,
+                report.account_id,
+                UUID4::new(),
+                report.ts_accepted,
+                ts_init,
+                true,
+            )?));
+        }
+
+        let fills: Vec<&FillReport> = fills
+            .iter()
+            .filter(|fill| fill.venue_order_id == report.venue_order_id)
+            .filter(|fill| !fill.is_applied_to(order))
+            .collect();
+        if !fills.is_empty() {
+            let mut filled_qty = order.filled_qty();
+            for fill in fills {
+                filled_qty = filled_qty + fill.last_qty;
+                events.push(self.reported_fill(order, fill, filled_qty, ts_init)?);
+            }
+        } else if report.filled_qty > order.filled_qty() {
+            events.push(self.inferred_fill(order, report, ts_init)?);
+        }
+
+        match report.order_status {
+            OrderStatus::Canceled => events.push(OrderEventAny::Canceled(OrderCanceled::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                UUID4::new(),
+                report.ts_last,
+                ts_init,
+                true,
+                Some(report.venue_order_id),
+                Some(report.account_id),
+            )?)),
+            OrderStatus::Expired => events.push(OrderEventAny::Expired(OrderExpired::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                UUID4::new(),
+                report.ts_last,
+                ts_init,
+                true,
+                Some(report.venue_order_id),
+                Some(report.account_id),
+            )?)),
+            _ => {}
+        }
+
+        Ok(events)
+    }
+
+    /// Returns the fill event for the `fill` report, which brings the order's filled quantity
+    /// to `filled_qty`.
+    fn reported_fill(
+        &self,
+        order: &OrderAny,
+        fill: &FillReport,
+        filled_qty: Quantity,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<OrderEventAny> {
+        let instrument = self
+            .cache
+            .instrument(&fill.instrument_id)
+            .ok_or_else(|| anyhow::anyhow!("Instrument {} not found", fill.instrument_id))?;
+
+        let event = OrderFilled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            fill.venue_order_id,
+            fill.account_id,
+            fill.trade_id,
+            fill.order_side,
+            order.order_type(),
+            fill.last_qty,
+            fill.last_px,
+            instrument.quote_currency(),
+            fill.liquidity_side,
+            UUID4::new(),
+            fill.ts_event,
+            ts_init,
+            true,
+            fill.venue_position_id,
+            Some(fill.commission),
+        )?;
+
+        if filled_qty < order.quantity() {
+            Ok(OrderEventAny::PartiallyFilled(event))
+        } else {
+            Ok(OrderEventAny::Filled(event))
+        }
+    }
+
+    /// Returns whether the open positions in the cache match the position `report`, logging
+    /// any difference (positions are not adjusted by reconciliation).
+    fn is_position_in_sync(&self, report: &PositionStatusReport) -> bool {
+        let signed_qty: f64 = self
+            .cache
+            .positions_open(None, Some(&report.instrument_id), None, None)
+            .iter()
+            .map(|position| position.signed_qty)
+            .sum();
+
+        if report.is_in_sync(signed_qty) {
+            true
+        } else {
+            error!(
+                "Position for {} not in sync: cached {signed_qty}, reported {}",
+                report.instrument_id,
+                report.signed_qty()
+            );
+            false
+        }
+    }
+
+    /// Returns a fill for the quantity filled at the venue but not yet applied to the
+    /// `order`, priced so the order's average price matches the `report`.
+    fn inferred_fill(
+        &self,
+        order: &OrderAny,
+        report: &OrderStatusReport,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<OrderEventAny> {
+        let Some(avg_px) = report.avg_px else {
+            anyhow::bail!(
+                "Cannot infer fill for {}: no `avg_px`",
+                report.venue_order_id
+            );
+        };
+        let instrument = self
+            .cache
+            .instrument(&report.instrument_id)
+            .ok_or_else(|| anyhow::anyhow!("Instrument {} not found", report.instrument_id))?;
+
+        let last_qty = report.filled_qty - order.filled_qty();
+        let last_px = match order.avg_px() {
+            Some(order_avg_px) if order.filled_qty().is_positive() => {
+                let report_notional = avg_px * report.filled_qty.as_f64();
+                let order_notional = order_avg_px * order.filled_qty().as_f64();
+                (report_notional - order_notional) / last_qty.as_f64()
+            }
+            _ => avg_px,
+        };
+
+        let fill = OrderFilled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.venue_order_id,
+            report.account_id,
+            TradeId::new(&UUID4::new().to_string())?,
+            order.order_side(),
+            order.order_type(),
+            last_qty,
+            Price::new(last_px, instrument.price_precision())?,
+            instrument.quote_currency(),
+            LiquiditySide::NoLiquiditySide,
+            UUID4::new(),
+            report.ts_last,
+            ts_init,
+            true,
+            None,
+            None,
+        )?;
+
+        if report.filled_qty < order.quantity() {
+            Ok(OrderEventAny::PartiallyFilled(fill))
+        } else {
+            Ok(OrderEventAny::Filled(fill))
+        }
+    }
+
+    /// Handles the given engine `msg`, logging any error.
+    pub fn handle(&mut self, msg: ExecutionEngineMessage) {
+        let result = match msg {
+            ExecutionEngineMessage::Command(command) => self.execute(command),
+            ExecutionEngineMessage::Event(event) => self.process(event),
+        };
+
+        if let Err(e) = result {
+            error!("Error handling execution engine message: {e}");
+        }
+    }
+
+    /// Handles messages from the `rx` channel until all senders have been dropped, checking
+    /// the in-flight commands at the configured interval.
+    ///
+    /// The recent command window is loaded from the cache on start, and saved when stopped.
+    pub async fn run(&mut self, mut rx: UnboundedReceiver<ExecutionEngineMessage>) {
+        debug!("Running execution engine");
+        if let Err(e) = self.load_recent_commands() {
+            error!("Error loading recent commands: {e}");
+        }
+        let mut interval = tokio::time::interval(Duration::from_millis(
+            self.config.inflight_check_interval_ms,
+        ));
+
+        loop {
+            tokio::select! {
+                msg = rx.recv() => match msg {
+                    Some(msg) => self.handle(msg),
+                    None => break,
+                },
+                _ = interval.tick() => {
+                    let now = get_atomic_clock_realtime().get_time_ns();
+                    self.check_in_flight(now);
+                    self.check_expired_tifs(now);
+                }
+            }
+        }
+
+        if let Err(e) = self.save_recent_commands() {
+            error!("Error saving recent commands: {e}");
+        }
+        debug!("Execution engine stopped");
+    }
+
+    fn route(&self, command: &TradingCommand) -> anyhow::Result<ClientId> {
+        let client_id = command.client_id();
+        if self.clients.contains_key(&client_id) {
+            return Ok(client_id);
+        }
+
+        let venue = command.instrument_id().venue;
+        self.routing_map
+            .get(&venue)
+            .copied()
+            .or(self.default_client)
+            .ok_or_else(|| anyhow::anyhow!("No execution client to route to for venue {venue}"))
+    }
+
+    fn client_mut(
+        &mut self,
+        client_id: &ClientId,
+    ) -> anyhow::Result<&mut Box<dyn ExecutionClient>> {
+        self.clients
+            .get_mut(client_id)
+            .ok_or_else(|| anyhow::anyhow!("Execution client {client_id} not registered"))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_model::{
+        enums::{OmsType, OrderSide, OrderType, PositionSide, TimeInForce},
+        identifiers::account_id::AccountId,
+        instruments::{any::InstrumentAny, stubs::audusd_sim},
+        orders::stubs::{TestOrderEventStubs, TestOrderStubs},
+        types::money::Money,
+    };
+    use rstest::*;
+    use tokio::sync::mpsc::unbounded_channel;
+
+    use super::*;
+    use crate::{
+        capabilities::VenueCapabilities,
+        dedup::RECENT_COMMANDS_CACHE_KEY,
+        messages::{
+            cancel_all::CancelAllOrders, modify::ModifyOrder, submit::SubmitOrder,
+            submit_list::SubmitOrderList,
+        },
+    };
+
+    type CallLog = Rc<RefCell<Vec<String>>>;
+
+    #[derive(Default)]
+    struct StubReports {
+        orders: Vec<OrderStatusReport>,
+        fills: Vec<FillReport>,
+        positions: Vec<PositionStatusReport>,
+    }
+
+    struct StubExecutionClient {
+        client_id: ClientId,
+        venue: Venue,
+        calls: CallLog,
+        reports: StubReports,
+        capabilities: Option<VenueCapabilities>,
+    }
+
+    impl StubExecutionClient {
+        fn record(&self, command: &str) -> anyhow::Result<()> {
+            self.calls
+                .borrow_mut()
+                .push(format!("{}:{command}", self.client_id));
+            Ok(())
+        }
+    }
+
+    impl ExecutionClient for StubExecutionClient {
+        fn client_id(&self) -> ClientId {
+            self.client_id
+        }
+
+        fn venue(&self) -> Venue {
+            self.venue
+        }
+
+        fn oms_type(&self) -> OmsType {
+            OmsType::Netting
+        }
+
+        fn account_id(&self) -> AccountId {
+            AccountId::default()
+        }
+
+        fn is_connected(&self) -> bool {
+            true
+        }
+
+        fn capabilities(&self) -> Option<&VenueCapabilities> {
+            self.capabilities.as_ref()
+        }
+
+        fn submit_order(&mut self, command: &SubmitOrder) -> anyhow::Result<()> {
+            if self.capabilities.is_some() {
+                return self.record(&format!("SubmitOrder({})", command.order.time_in_force()));
+            }
+            self.record("SubmitOrder")
+        }
+
+        fn submit_order_list(&mut self, _command: &SubmitOrderList) -> anyhow::Result<()> {
+            self.record("SubmitOrderList")
+        }
+
+        fn modify_order(&mut self, _command: &ModifyOrder) -> anyhow::Result<()> {
+            self.record("ModifyOrder")
+        }
+
+        fn cancel_order(&mut self, _command: &CancelOrder) -> anyhow::Result<()> {
+            self.record("CancelOrder")
+        }
+
+        fn cancel_all_orders(&mut self, _command: &CancelAllOrders) -> anyhow::Result<()> {
+            self.record("CancelAllOrders")
+        }
+
+        fn query_order(&mut self, _command: &QueryOrder) -> anyhow::Result<()> {
+            self.record("QueryOrder")
+        }
+
+        fn generate_order_status_reports(
+            &self,
+            _instrument_id: Option<InstrumentId>,
+            _start: Option<UnixNanos>,
+            _end: Option<UnixNanos>,
+            _open_only: bool,
+        ) -> anyhow::Result<Vec<OrderStatusReport>> {
+            Ok(self.reports.orders.clone())
+        }
+
+        fn generate_fill_reports(
+            &self,
+            _instrument_id: Option<InstrumentId>,
+            _venue_order_id: Option<VenueOrderId>,
+            _start: Option<UnixNanos>,
+            _end: Option<UnixNanos>,
+        ) -> anyhow::Result<Vec<FillReport>> {
+            Ok(self.reports.fills.clone())
+        }
+
+        fn generate_position_status_reports(
+            &self,
+            _instrument_id: Option<InstrumentId>,
+            _start: Option<UnixNanos>,
+            _end: Option<UnixNanos>,
+        ) -> anyhow::Result<Vec<PositionStatusReport>> {
+            Ok(self.reports.positions.clone())
+        }
+    }
+
+    struct TestEngine {
+        engine: LiveExecutionEngine<UnboundedSender<(Ustr, OrderEventAny)>>,
+        rx: UnboundedReceiver<(Ustr, OrderEventAny)>,
+        calls: CallLog,
+    }
+
+    fn test_engine_with_reports(reports: StubReports) -> TestEngine {
+        test_engine_with_client(reports, None)
+    }
+
+    fn test_engine_with_client(
+        reports: StubReports,
+        capabilities: Option<VenueCapabilities>,
+    ) -> TestEngine {
+        let (tx, rx) = unbounded_channel();
+        let calls = CallLog::default();
+        let mut cache = Cache::default();
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim()))
+            .unwrap();
+        let config = LiveExecutionEngineConfig {
+            inflight_check_threshold_ms: 1,
+            inflight_check_retries: 1,
+            ..Default::default()
+        };
+        let mut engine = LiveExecutionEngine::new(cache, tx, config).unwrap();
+        engine
+            .register_client(Box::new(StubExecutionClient {
+                client_id: ClientId::from("SIM"),
+                venue: Venue::from("SIM"),
+                calls: calls.clone(),
+                reports,
+                capabilities,
+            }))
+            .unwrap();
+        TestEngine { engine, rx, calls }
+    }
+
+    #[fixture]
+    fn test_engine() -> TestEngine {
+        test_engine_with_reports(StubReports::default())
+    }
+
+    fn limit_order() -> OrderAny {
+        TestOrderStubs::limit_order(
+            InstrumentId::from("AUD/USD.SIM"),
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            Some(ClientOrderId::from("O-001")),
+            None,
+        )
+    }
+
+    fn gtc_only_capabilities() -> VenueCapabilities {
+        VenueCapabilities::new(Venue::from("SIM"), [TimeInForce::Gtc])
+    }
+
+    fn submit(order: &OrderAny) -> TradingCommand {
+        TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                order.trader_id(),
+                ClientId::from("SIM"),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                VenueOrderId::default(),
+                order.clone(),
+                None,
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn report(order_status: OrderStatus, filled_qty: i64) -> OrderStatusReport {
+        OrderStatusReport::new(
+            AccountId::default(),
+            InstrumentId::from("AUD/USD.SIM"),
+            Some(ClientOrderId::from("O-001")),
+            VenueOrderId::from("V-001"),
+            OrderSide::Buy,
+            OrderType::Limit,
+            TimeInForce::Gtc,
+            order_status,
+            Quantity::from(100_000),
+            Quantity::from(filled_qty),
+            UUID4::new(),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+            UnixNanos::from(3),
+        )
+    }
+
+    #[rstest]
+    fn test_execute_routes_and_tracks_in_flight(test_engine: TestEngine) {
+        let TestEngine {
+            mut engine, calls, ..
+        } = test_engine;
+        let order = limit_order();
+
+        engine.execute(submit(&order)).unwrap();
+
+        let in_flight = engine.in_flight(&order.client_order_id()).unwrap();
+        assert_eq!(in_flight.kind, InFlightKind::Submit);
+        assert!(engine.cache().order(&order.client_order_id()).is_some());
+        assert_eq!(*calls.borrow(), vec!["SIM:SubmitOrder"]);
+        assert_eq!(engine.command_count, 1);
+    }
+
+    #[rstest]
+    fn test_execute_with_no_route(test_engine: TestEngine) {
+        let TestEngine { mut engine, .. } = test_engine;
+        engine.deregister_client(ClientId::from("SIM")).unwrap();
+
+        let result = engine.execute(submit(&limit_order()));
+
+        assert!(result.is_err());
+        assert_eq!(engine.in_flight_count(), 0);
+    }
+
+    #[rstest]
+    fn test_execute_duplicate_command_ignored(test_engine: TestEngine) {
+        let TestEngine {
+            mut engine, calls, ..
+        } = test_engine;
+        let command = submit(&limit_order());
+
+        engine.execute(command.clone()).unwrap();
+        engine.execute(command).unwrap();
+
+        assert_eq!(*calls.borrow(), vec!["SIM:SubmitOrder"]);
+        assert_eq!(engine.command_count, 1);
+    }
+
+    #[rstest]
+    fn test_execute_failed_command_redelivered(test_engine: TestEngine) {
+        let TestEngine { mut engine, .. } = test_engine;
+        let command = submit(&limit_order());
+        engine.deregister_client(ClientId::from("SIM")).unwrap();
+
+        assert!(engine.execute(command.clone()).is_err());
+
+        let calls = CallLog::default();
+        engine
+            .register_client(Box::new(StubExecutionClient {
+                client_id: ClientId::from("SIM"),
+                venue: Venue::from("SIM"),
+                calls: calls.clone(),
+                reports: StubReports::default(),
+                capabilities: None,
+            }))
+            .unwrap();
+        engine.execute(command).unwrap();
+
+        assert_eq!(*calls.borrow(), vec!["SIM:SubmitOrder"]);
+        assert_eq!(engine.recent_commands().len(), 1);
+    }
+
+    #[rstest]
+    fn test_recent_commands_restored_after_restart(test_engine: TestEngine) {
+        let TestEngine { mut engine, .. } = test_engine;
+        let command = submit(&limit_order());
+        engine.execute(command.clone()).unwrap();
+        engine.save_recent_commands().unwrap();
+
+        // Restart with the persisted general cache entry
+        let TestEngine {
+            engine: mut restarted,
+            calls,
+            ..
+        } = test_engine_with_reports(StubReports::default());
+        let persisted = engine
+            .cache()
+            .get(RECENT_COMMANDS_CACHE_KEY)
+            .unwrap()
+            .unwrap();
+        restarted
+            .cache_mut()
+            .add(RECENT_COMMANDS_CACHE_KEY, persisted.to_vec())
+            .unwrap();
+        restarted.load_recent_commands().unwrap();
+        restarted.execute(command).unwrap();
+
+        assert_eq!(restarted.recent_commands().len(), 1);
+        assert!(calls.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_execute_emulates_ioc_and_cancels_on_accept() {
+        let TestEngine {
+            mut engine, calls, ..
+        } = test_engine_with_client(StubReports::default(), Some(gtc_only_capabilities()));
+        let order = TestOrderStubs::limit_order(
+            InstrumentId::from("AUD/USD.SIM"),
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            Some(ClientOrderId::from("O-001")),
+            Some(TimeInForce::Ioc),
+        );
+        engine.execute(submit(&order)).unwrap();
+
+        let cached = engine.cache().order(&order.client_order_id()).unwrap();
+        assert_eq!(cached.time_in_force(), TimeInForce::Ioc);
+        assert_eq!(*calls.borrow(), vec!["SIM:SubmitOrder(GTC)"]);
+
+        engine
+            .process(TestOrderEventStubs::order_submitted(
+                &order,
+                AccountId::default(),
+            ))
+            .unwrap();
+        engine
+            .process(TestOrderEventStubs::order_accepted(
+                &order,
+                AccountId::default(),
+                VenueOrderId::from("V-001"),
+            ))
+            .unwrap();
+
+        assert_eq!(calls.borrow().last().unwrap(), "SIM:CancelOrder");
+        let in_flight = engine.in_flight(&order.client_order_id()).unwrap();
+        assert_eq!(in_flight.kind, InFlightKind::Cancel);
+        assert_eq!(in_flight.venue_order_id, VenueOrderId::from("V-001"));
+        assert!(engine.emulated_tif(&order.client_order_id()).is_none());
+    }
+
+    #[rstest]
+    fn test_check_expired_tifs_cancels_emulated_gtd() {
+        let TestEngine {
+            mut engine, calls, ..
+        } = test_engine_with_client(StubReports::default(), Some(gtc_only_capabilities()));
+        let mut order = limit_order();
+        if let OrderAny::Limit(limit) = &mut order {
+            limit.time_in_force = TimeInForce::Gtd;
+            limit.expire_time = Some(UnixNanos::from(1_000));
+        }
+        engine.execute(submit(&order)).unwrap();
+
+        assert!(engine.check_expired_tifs(UnixNanos::from(999)).is_empty());
+
+        let expired = engine.check_expired_tifs(UnixNanos::from(1_000));
+
+        assert_eq!(expired, vec![order.client_order_id()]);
+        assert_eq!(
+            *calls.borrow(),
+            vec!["SIM:SubmitOrder(GTC)", "SIM:CancelOrder"]
+        );
+    }
+
+    #[rstest]
+    fn test_process_resolves_in_flight_and_publishes(test_engine: TestEngine) {
+        let TestEngine {
+            mut engine, mut rx, ..
+        } = test_engine;
+        let order = limit_order();
+        engine.execute(submit(&order)).unwrap();
+
+        engine
+            .process(TestOrderEventStubs::order_submitted(
+                &order,
+                AccountId::default(),
+            ))
+            .unwrap();
+        assert_eq!(engine.in_flight_count(), 1);
+
+        engine
+            .process(TestOrderEventStubs::order_accepted(
+                &order,
+                AccountId::default(),
+                VenueOrderId::from("V-001"),
+            ))
+            .unwrap();
+
+        let (topic, _) = rx.try_recv().unwrap();
+        let (_, event) = rx.try_recv().unwrap();
+        assert_eq!(topic.as_str(), "events.order.S-001");
+        assert!(matches!(event, OrderEventAny::Accepted(_)));
+        assert_eq!(engine.in_flight_count(), 0);
+        assert_eq!(
+            engine
+                .cache()
+                .order(&order.client_order_id())
+                .unwrap()
+                .status(),
+            OrderStatus::Accepted
+        );
+    }
+
+    #[rstest]
+    fn test_check_in_flight_queries_then_abandons(test_engine: TestEngine) {
+        let TestEngine {
+            mut engine, calls, ..
+        } = test_engine;
+        let order = limit_order();
+        engine.execute(submit(&order)).unwrap();
+
+        assert!(engine.check_in_flight(UnixNanos::from(500_000)).is_empty());
+        assert_eq!(calls.borrow().len(), 1);
+
+        assert!(engine
+            .check_in_flight(UnixNanos::from(2_000_000))
+            .is_empty());
+        assert_eq!(calls.borrow().last().unwrap(), "SIM:QueryOrder");
+        assert_eq!(
+            engine.in_flight(&order.client_order_id()).unwrap().retries,
+            1
+        );
+
+        let abandoned = engine.check_in_flight(UnixNanos::from(4_000_000));
+
+        assert_eq!(abandoned, vec![order.client_order_id()]);
+        assert_eq!(engine.in_flight_count(), 0);
+    }
+
+    #[rstest]
+    fn test_reconcile_generates_missed_events() {
+        let report = report(OrderStatus::Canceled, 50_000).with_avg_px(1.25);
+        let TestEngine {
+            mut engine, mut rx, ..
+        } = test_engine_with_reports(StubReports {
+            orders: vec![report],
+            ..Default::default()
+        });
+        let order = limit_order();
+        engine.execute(submit(&order)).unwrap();
+        engine
+            .process(TestOrderEventStubs::order_submitted(
+                &order,
+                AccountId::default(),
+            ))
+            .unwrap();
+        rx.try_recv().unwrap();
+
+        let event_count = engine.reconcile(UnixNanos::from(10)).unwrap();
+
+        let mut events = Vec::new();
+        while let Ok((_, event)) = rx.try_recv() {
+            events.push(event);
+        }
+        let cached = engine.cache().order(&order.client_order_id()).unwrap();
+        assert_eq!(event_count, 3);
+        assert!(matches!(events[0], OrderEventAny::Accepted(_)));
+        assert!(matches!(events[1], OrderEventAny::PartiallyFilled(_)));
+        assert!(matches!(events[2], OrderEventAny::Canceled(_)));
+        assert_eq!(cached.status(), OrderStatus::Canceled);
+        assert_eq!(cached.filled_qty(), Quantity::from(50_000));
+        assert_eq!(cached.avg_px(), Some(1.25));
+        assert_eq!(engine.report_count, 1);
+    }
+
+    #[rstest]
+    fn test_reconcile_submits_and_accepts_initialized_order() {
+        let TestEngine {
+            mut engine, mut rx, ..
+        } = test_engine_with_reports(StubReports {
+            orders: vec![report(OrderStatus::Accepted, 0)],
+            ..Default::default()
+        });
+        let order = limit_order();
+        engine.execute(submit(&order)).unwrap();
+
+        let event_count = engine.reconcile(UnixNanos::from(10)).unwrap();
+
+        let mut events = Vec::new();
+        while let Ok((_, event)) = rx.try_recv() {
+            events.push(event);
+        }
+        let cached = engine.cache().order(&order.client_order_id()).unwrap();
+        assert_eq!(event_count, 2);
+        assert!(matches!(events[0], OrderEventAny::Submitted(_)));
+        assert!(matches!(events[1], OrderEventAny::Accepted(_)));
+        assert_eq!(cached.status(), OrderStatus::Accepted);
+        assert_eq!(cached.venue_order_id(), Some(VenueOrderId::from("V-001")));
+    }
+
+    #[rstest]
+    fn test_reconcile_skips_unknown_orders() {
+        let mut report = report(OrderStatus::Accepted, 0);
+        report.client_order_id = None;
+        let TestEngine { mut engine, .. } = test_engine_with_reports(StubReports {
+            orders: vec![report],
+            ..Default::default()
+        });
+
+        let event_count = engine.reconcile(UnixNanos::from(10)).unwrap();
+
+        assert_eq!(event_count, 0);
+        assert_eq!(engine.report_count, 1);
+    }
+
+    #[rstest]
+    fn test_reconcile_applies_fill_reports() {
+        let fill = FillReport::new(
+            AccountId::default(),
+            InstrumentId::from("AUD/USD.SIM"),
+            Some(ClientOrderId::from("O-001")),
+            VenueOrderId::from("V-001"),
+            None,
+            TradeId::from("T-001"),
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            Price::from("0.99000"),
+            Money::from("2.00 USD"),
+            LiquiditySide::Maker,
+            UUID4::new(),
+            UnixNanos::from(2),
+            UnixNanos::from(3),
+        );
+        let TestEngine { mut engine, .. } = test_engine_with_reports(StubReports {
+            orders: vec![report(OrderStatus::Filled, 100_000)],
+            fills: vec![fill],
+            ..Default::default()
+        });
+        let order = limit_order();
+        engine.execute(submit(&order)).unwrap();
+        engine
+            .process(TestOrderEventStubs::order_submitted(
+                &order,
+                AccountId::default(),
+            ))
+            .unwrap();
+
+        let event_count = engine.reconcile(UnixNanos::from(10)).unwrap();
+
+        let cached = engine.cache().order(&order.client_order_id()).unwrap();
+        assert_eq!(event_count, 2);
+        assert_eq!(cached.status(), OrderStatus::Filled);
+        assert_eq!(cached.trade_ids(), vec![&TradeId::from("T-001")]);
+        assert_eq!(cached.avg_px(), Some(0.99));
+        assert_eq!(engine.report_count, 2);
+    }
+
+    #[rstest]
+    fn test_reconcile_when_position_not_in_sync() {
+        let position = PositionStatusReport::new(
+            AccountId::default(),
+            InstrumentId::from("AUD/USD.SIM"),
+            PositionSide::Long,
+            Quantity::from(100_000),
+            None,
+            UUID4::new(),
+            UnixNanos::from(2),
+            UnixNanos::from(3),
+        );
+        let TestEngine { mut engine, .. } = test_engine_with_reports(StubReports {
+            positions: vec![position],
+            ..Default::default()
+        });
+
+        let result = engine.reconcile(UnixNanos::from(10));
+
+        assert!(result.is_err());
+        assert_eq!(engine.report_count, 1);
+    }
+}
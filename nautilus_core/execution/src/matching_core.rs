@@ -20,7 +20,7 @@
 #![allow(unused_variables)]
 
 use nautilus_model::{
-    enums::OrderSideSpecified,
+    enums::{OrderSideSpecified, TriggerType},
     identifiers::{client_order_id::ClientOrderId, instrument_id::InstrumentId},
     orders::{
         any::{LimitOrderAny, PassiveOrderAny, StopOrderAny},
@@ -101,6 +101,14 @@ impl OrderMatchingCore {
                 .any(|o| o.client_order_id() == client_order_id)
     }
 
+    #[must_use]
+    pub fn get_order(&self, client_order_id: ClientOrderId) -> Option<&PassiveOrderAny> {
+        self.orders_bid
+            .iter()
+            .chain(self.orders_ask.iter())
+            .find(|o| o.client_order_id() == client_order_id)
+    }
+
     // -- COMMANDS --------------------------------------------------------------------------------
 
     pub fn reset(&mut self) {
@@ -124,6 +132,21 @@ impl OrderMatchingCore {
         }
     }
 
+    /// Replaces the matching cores copy of the given `order` (matched by client order ID),
+    /// e.g. after it has been triggered or its prices have been modified.
+    pub fn update_order(&mut self, order: PassiveOrderAny) -> Result<(), OrderError> {
+        let orders = match order.order_side_specified() {
+            OrderSideSpecified::Buy => &mut self.orders_bid,
+            OrderSideSpecified::Sell => &mut self.orders_ask,
+        };
+        let existing = orders
+            .iter_mut()
+            .find(|o| **o == order)
+            .ok_or(OrderError::NotFound(order.client_order_id()))?;
+        *existing = order;
+        Ok(())
+    }
+
     pub fn delete_order(&mut self, order: &PassiveOrderAny) -> Result<(), OrderError> {
         match order.order_side_specified() {
             OrderSideSpecified::Buy => {
@@ -193,21 +216,109 @@ impl OrderMatchingCore {
 
     #[must_use]
     pub fn is_limit_matched(&self, order: &LimitOrderAny) -> bool {
-        match order.order_side_specified() {
-            OrderSideSpecified::Buy => self.ask.map_or(false, |a| a <= order.limit_px()),
-            OrderSideSpecified::Sell => self.bid.map_or(false, |b| b >= order.limit_px()),
+        self.is_limit_price_matched(order.order_side_specified(), order.limit_px())
+    }
+
+    #[must_use]
+    pub fn is_limit_price_matched(&self, side: OrderSideSpecified, price: Price) -> bool {
+        match side {
+            OrderSideSpecified::Buy => self.ask.map_or(false, |a| a <= price),
+            OrderSideSpecified::Sell => self.bid.map_or(false, |b| b >= price),
         }
     }
 
+    /// Returns whether the given stop `order` is triggered by the current market, evaluated
+    /// against the market prices for the orders trigger type.
     #[must_use]
     pub fn is_stop_matched(&self, order: &StopOrderAny) -> bool {
-        match order.order_side_specified() {
-            OrderSideSpecified::Buy => self.ask.map_or(false, |a| a >= order.stop_px()),
-            OrderSideSpecified::Sell => self.bid.map_or(false, |b| b <= order.stop_px()),
+        let side = order.order_side_specified();
+        let trigger_price = order.stop_px();
+        let is_touch = matches!(
+            order,
+            StopOrderAny::LimitIfTouched(_) | StopOrderAny::MarketIfTouched(_)
+        );
+
+        self.trigger_prices(side, order.trigger_type())
+            .into_iter()
+            .flatten()
+            .any(|market| {
+                if is_touch {
+                    is_touch_price_triggered(side, market, trigger_price)
+                } else {
+                    is_stop_price_triggered(side, market, trigger_price)
+                }
+            })
+    }
+
+    #[must_use]
+    pub fn is_stop_triggered(&self, side: OrderSideSpecified, trigger_price: Price) -> bool {
+        self.side_price(side).map_or(false, |market| {
+            is_stop_price_triggered(side, market, trigger_price)
+        })
+    }
+
+    #[must_use]
+    pub fn is_touch_triggered(&self, side: OrderSideSpecified, trigger_price: Price) -> bool {
+        self.side_price(side).map_or(false, |market| {
+            is_touch_price_triggered(side, market, trigger_price)
+        })
+    }
+
+    /// Returns the market price an order on the given `side` would execute against (the ask
+    /// for buy orders and the bid for sell orders).
+    #[must_use]
+    pub fn side_price(&self, side: OrderSideSpecified) -> Option<Price> {
+        match side {
+            OrderSideSpecified::Buy => self.ask,
+            OrderSideSpecified::Sell => self.bid,
+        }
+    }
+
+    #[must_use]
+    pub fn mid_price(&self) -> Option<Price> {
+        match (self.bid, self.ask) {
+            (Some(bid), Some(ask)) => Some(
+                Price::from_raw((bid.raw + ask.raw) / 2, self.price_precision())
+                    .expect("Invalid price precision"),
+            ),
+            _ => None,
+        }
+    }
+
+    fn trigger_prices(
+        &self,
+        side: OrderSideSpecified,
+        trigger_type: TriggerType,
+    ) -> [Option<Price>; 2] {
+        match trigger_type {
+            TriggerType::NoTrigger
+            | TriggerType::Default
+            | TriggerType::BidAsk
+            | TriggerType::DoubleBidAsk => [self.side_price(side), None],
+            TriggerType::LastTrade
+            | TriggerType::DoubleLast
+            | TriggerType::MarkPrice
+            | TriggerType::IndexPrice => [self.last, None],
+            TriggerType::LastOrBidAsk => [self.last, self.side_price(side)],
+            TriggerType::MidPoint => [self.mid_price(), None],
         }
     }
 }
 
+fn is_stop_price_triggered(side: OrderSideSpecified, market: Price, trigger_price: Price) -> bool {
+    match side {
+        OrderSideSpecified::Buy => market >= trigger_price,
+        OrderSideSpecified::Sell => market <= trigger_price,
+    }
+}
+
+fn is_touch_price_triggered(side: OrderSideSpecified, market: Price, trigger_price: Price) -> bool {
+    match side {
+        OrderSideSpecified::Buy => market <= trigger_price,
+        OrderSideSpecified::Sell => market >= trigger_price,
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -216,7 +327,9 @@ mod tests {
     use std::sync::Mutex;
 
     use nautilus_model::{
-        enums::OrderSide, orders::stubs::TestOrderStubs, types::quantity::Quantity,
+        enums::OrderSide,
+        orders::{any::OrderAny, stubs::TestOrderStubs},
+        types::quantity::Quantity,
     };
     use rstest::rstest;
 
@@ -492,6 +605,133 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    #[case(
+        Some(Price::from("100.00")),
+        Some(Price::from("101.00")),
+        Price::from("100.00"),  // Trigger below ask
+        OrderSide::Buy,
+        false
+    )]
+    #[case(
+        Some(Price::from("100.00")),
+        Some(Price::from("101.00")),
+        Price::from("101.00"),  // <-- Trigger at ask
+        OrderSide::Buy,
+        true
+    )]
+    #[case(
+        Some(Price::from("100.00")),
+        Some(Price::from("101.00")),
+        Price::from("101.00"),  // Trigger above bid
+        OrderSide::Sell,
+        false
+    )]
+    #[case(
+        Some(Price::from("100.00")),
+        Some(Price::from("101.00")),
+        Price::from("99.00"),  // <-- Trigger below bid
+        OrderSide::Sell,
+        true
+    )]
+    #[case(
+        None,
+        None,
+        Price::from("100.00"),  // No market
+        OrderSide::Buy,
+        false
+    )]
+    fn test_is_touch_matched(
+        #[case] bid: Option<Price>,
+        #[case] ask: Option<Price>,
+        #[case] trigger_price: Price,
+        #[case] order_side: OrderSide,
+        #[case] expected: bool,
+    ) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut matching_core = create_matching_core(instrument_id, Price::from("0.01"));
+        matching_core.bid = bid;
+        matching_core.ask = ask;
+
+        let order = TestOrderStubs::market_if_touched_order(
+            instrument_id,
+            order_side,
+            trigger_price,
+            Quantity::from("100"),
+            None,
+        );
+
+        let result = matching_core.is_stop_matched(&order.into());
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(TriggerType::BidAsk, OrderSide::Buy, false)]
+    #[case(TriggerType::BidAsk, OrderSide::Sell, true)]
+    #[case(TriggerType::LastTrade, OrderSide::Buy, true)]
+    #[case(TriggerType::LastTrade, OrderSide::Sell, false)]
+    #[case(TriggerType::LastOrBidAsk, OrderSide::Buy, true)]
+    #[case(TriggerType::MidPoint, OrderSide::Buy, false)]
+    #[case(TriggerType::MidPoint, OrderSide::Sell, true)]
+    fn test_is_stop_matched_with_trigger_type(
+        #[case] trigger_type: TriggerType,
+        #[case] order_side: OrderSide,
+        #[case] expected: bool,
+    ) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut matching_core = create_matching_core(instrument_id, Price::from("0.01"));
+        matching_core.bid = Some(Price::from("99.00"));
+        matching_core.ask = Some(Price::from("101.00"));
+        matching_core.last = Some(Price::from("102.00"));
+
+        // Buy stops between the ask and last, sell stops at the mid
+        let trigger_price = match order_side {
+            OrderSide::Buy => Price::from("101.50"),
+            _ => Price::from("100.00"),
+        };
+        let order = TestOrderStubs::stop_market_order(
+            instrument_id,
+            order_side,
+            trigger_price,
+            Quantity::from("100"),
+            Some(trigger_type),
+            None,
+            None,
+        );
+
+        let result = matching_core.is_stop_matched(&order.into());
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    fn test_update_order() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut matching_core = create_matching_core(instrument_id, Price::from("0.01"));
+
+        let mut order = TestOrderStubs::stop_market_order(
+            instrument_id,
+            OrderSide::Sell,
+            Price::from("100.00"),
+            Quantity::from("100"),
+            None,
+            None,
+            None,
+        );
+        matching_core.add_order(order.clone().into()).unwrap();
+
+        if let OrderAny::StopMarket(ref mut o) = order {
+            o.trigger_price = Price::from("100.50");
+        }
+        matching_core.update_order(order.into()).unwrap();
+
+        let orders = matching_core.get_orders_ask();
+        assert_eq!(orders.len(), 1);
+        match &orders[0] {
+            PassiveOrderAny::Stop(o) => assert_eq!(o.stop_px(), Price::from("100.50")),
+            PassiveOrderAny::Limit(_) => panic!("Expected stop order"),
+        }
+    }
+
     #[rstest]
     #[case(OrderSide::Buy)]
     #[case(OrderSide::Sell)]
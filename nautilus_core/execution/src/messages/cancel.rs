@@ -16,7 +16,7 @@
 use std::fmt::Display;
 
 use derive_builder::Builder;
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable, uuid::UUID4};
 use nautilus_model::identifiers::{
     client_id::ClientId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
     strategy_id::StrategyId, trader_id::TraderId, venue_order_id::VenueOrderId,
@@ -73,8 +73,40 @@ impl Display for CancelOrder {
     }
 }
 
+impl Serializable for CancelOrder {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn cancel_order() -> CancelOrder {
+        CancelOrderBuilder::default()
+            .client_order_id(ClientOrderId::from("O-123"))
+            .venue_order_id(VenueOrderId::from("V-123"))
+            .ts_init(UnixNanos::from(1_u64))
+            .build()
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_json_serialization() {
+        let command = cancel_order();
+        let serialized = command.as_json_bytes().unwrap();
+        let deserialized = CancelOrder::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[rstest]
+    fn test_msgpack_serialization() {
+        let command = cancel_order();
+        let serialized = command.as_msgpack_bytes().unwrap();
+        let deserialized = CancelOrder::from_msgpack_bytes(serialized).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
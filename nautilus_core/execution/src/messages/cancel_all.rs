@@ -16,7 +16,7 @@
 use std::fmt::Display;
 
 use derive_builder::Builder;
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable, uuid::UUID4};
 use nautilus_model::{
     enums::OrderSide,
     identifiers::{
@@ -73,8 +73,39 @@ impl Display for CancelAllOrders {
     }
 }
 
+impl Serializable for CancelAllOrders {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn cancel_all_orders() -> CancelAllOrders {
+        CancelAllOrdersBuilder::default()
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .order_side(OrderSide::Sell)
+            .build()
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_json_serialization() {
+        let command = cancel_all_orders();
+        let serialized = command.as_json_bytes().unwrap();
+        let deserialized = CancelAllOrders::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[rstest]
+    fn test_msgpack_serialization() {
+        let command = cancel_all_orders();
+        let serialized = command.as_msgpack_bytes().unwrap();
+        let deserialized = CancelAllOrders::from_msgpack_bytes(serialized).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
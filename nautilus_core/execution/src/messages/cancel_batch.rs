@@ -16,7 +16,7 @@
 use std::fmt::Display;
 
 use derive_builder::Builder;
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable, uuid::UUID4};
 use nautilus_model::identifiers::{
     client_id::ClientId, instrument_id::InstrumentId, strategy_id::StrategyId, trader_id::TraderId,
 };
@@ -71,6 +71,8 @@ impl Display for BatchCancelOrders {
     }
 }
 
+impl Serializable for BatchCancelOrders {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -15,6 +15,7 @@
 
 //! Execution specific messages such as order commands.
 
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 use nautilus_model::identifiers::{client_id::ClientId, instrument_id::InstrumentId};
 use strum::Display;
 
@@ -68,4 +69,33 @@ impl TradingCommand {
             Self::QueryOrder(command) => command.instrument_id,
         }
     }
+
+    /// Returns the command ID, which also serves as the idempotency key for the command.
+    ///
+    /// A redelivered command (e.g. after a reconnect or journal replay) retains its original ID.
+    #[must_use]
+    pub fn command_id(&self) -> UUID4 {
+        match self {
+            Self::SubmitOrder(command) => command.command_id,
+            Self::SubmitOrderList(command) => command.command_id,
+            Self::ModifyOrder(command) => command.command_id,
+            Self::CancelOrder(command) => command.command_id,
+            Self::CancelAllOrders(command) => command.command_id,
+            Self::BatchCancelOrders(command) => command.command_id,
+            Self::QueryOrder(command) => command.command_id,
+        }
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::SubmitOrder(command) => command.ts_init,
+            Self::SubmitOrderList(command) => command.ts_init,
+            Self::ModifyOrder(command) => command.ts_init,
+            Self::CancelOrder(command) => command.ts_init,
+            Self::CancelAllOrders(command) => command.ts_init,
+            Self::BatchCancelOrders(command) => command.ts_init,
+            Self::QueryOrder(command) => command.ts_init,
+        }
+    }
 }
@@ -16,7 +16,7 @@
 use std::fmt::Display;
 
 use derive_builder::Builder;
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable, uuid::UUID4};
 use nautilus_model::{
     identifiers::{
         client_id::ClientId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
@@ -88,8 +88,40 @@ impl Display for ModifyOrder {
     }
 }
 
+impl Serializable for ModifyOrder {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn modify_order() -> ModifyOrder {
+        ModifyOrderBuilder::default()
+            .client_order_id(ClientOrderId::from("O-123"))
+            .quantity(Some(Quantity::from(100_000)))
+            .price(Some(Price::from("1.00010")))
+            .build()
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_json_serialization() {
+        let command = modify_order();
+        let serialized = command.as_json_bytes().unwrap();
+        let deserialized = ModifyOrder::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[rstest]
+    fn test_msgpack_serialization() {
+        let command = modify_order();
+        let serialized = command.as_msgpack_bytes().unwrap();
+        let deserialized = ModifyOrder::from_msgpack_bytes(serialized).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
@@ -16,7 +16,7 @@
 use std::fmt::Display;
 
 use derive_builder::Builder;
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable, uuid::UUID4};
 use nautilus_model::identifiers::{
     client_id::ClientId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
     strategy_id::StrategyId, trader_id::TraderId, venue_order_id::VenueOrderId,
@@ -73,6 +73,8 @@ impl Display for QueryOrder {
     }
 }
 
+impl Serializable for QueryOrder {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -15,17 +15,18 @@
 
 use std::fmt::Display;
 
-use derive_builder::Builder;
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
-use nautilus_model::identifiers::{
-    client_id::ClientId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
-    instrument_id::InstrumentId, position_id::PositionId, strategy_id::StrategyId,
-    trader_id::TraderId, venue_order_id::VenueOrderId,
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable, uuid::UUID4};
+use nautilus_model::{
+    identifiers::{
+        client_id::ClientId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
+        instrument_id::InstrumentId, position_id::PositionId, strategy_id::StrategyId,
+        trader_id::TraderId, venue_order_id::VenueOrderId,
+    },
+    orders::any::OrderAny,
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
-#[builder(default)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub struct SubmitOrder {
     pub trader_id: TraderId,
@@ -34,7 +35,7 @@ pub struct SubmitOrder {
     pub instrument_id: InstrumentId,
     pub client_order_id: ClientOrderId,
     pub venue_order_id: VenueOrderId,
-    // order: OrderAny,  // TODO: Implement Eq
+    pub order: OrderAny,
     pub exec_algorith_id: Option<ExecAlgorithmId>,
     pub position_id: Option<PositionId>,
     pub command_id: UUID4,
@@ -51,6 +52,7 @@ impl SubmitOrder {
         instrument_id: InstrumentId,
         client_order_id: ClientOrderId,
         venue_order_id: VenueOrderId,
+        order: OrderAny,
         exec_algorith_id: Option<ExecAlgorithmId>,
         position_id: Option<PositionId>,
         command_id: UUID4,
@@ -63,6 +65,7 @@ impl SubmitOrder {
             instrument_id,
             client_order_id,
             venue_order_id,
+            order,
             exec_algorith_id,
             position_id,
             command_id,
@@ -75,16 +78,80 @@ impl Display for SubmitOrder {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "SubmitOrder(instrument_id={}, order=TBD, position_id={})",
+            "SubmitOrder(instrument_id={}, order={}, position_id={})",
             self.instrument_id,
+            self.order,
             self.position_id
                 .map_or("None".to_string(), |position_id| format!("{position_id}")),
         )
     }
 }
 
+impl Serializable for SubmitOrder {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use nautilus_model::{
+        enums::OrderSide,
+        orders::stubs::TestOrderStubs,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn submit_order() -> SubmitOrder {
+        let order = TestOrderStubs::limit_order(
+            InstrumentId::from("AUD/USD.SIM"),
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+        SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::default(),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_display() {
+        let command = submit_order();
+        assert!(command
+            .to_string()
+            .starts_with("SubmitOrder(instrument_id=AUD/USD.SIM, order=LimitOrder("));
+    }
+
+    #[rstest]
+    fn test_json_serialization() {
+        let command = submit_order();
+        let serialized = command.as_json_bytes().unwrap();
+        let deserialized = SubmitOrder::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, command);
+        assert_eq!(deserialized.order.quantity(), Quantity::from(100_000));
+    }
+
+    #[rstest]
+    fn test_msgpack_serialization() {
+        let command = submit_order();
+        let serialized = command.as_msgpack_bytes().unwrap();
+        let deserialized = SubmitOrder::from_msgpack_bytes(serialized).unwrap();
+        assert_eq!(deserialized, command);
+        assert_eq!(deserialized.order.quantity(), Quantity::from(100_000));
+    }
+}
@@ -15,7 +15,7 @@
 
 use std::fmt::Display;
 
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable, uuid::UUID4};
 use nautilus_model::{
     identifiers::{
         client_id::ClientId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
@@ -86,6 +86,8 @@ impl Display for SubmitOrderList {
     }
 }
 
+impl Serializable for SubmitOrderList {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,308 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Per-instrument execution configuration overrides for pre-trade risk checks.
+//!
+//! Overrides allow compliance constraints which differ by product, such as a maximum order
+//! size, a minimum resting time before an order may be modified or canceled, and the order
+//! types which are allowed. Commands breaching an override should be denied before they are
+//! sent to the venue.
+
+use std::collections::HashMap;
+
+use nautilus_core::{correctness::check_predicate_true, nanos::UnixNanos};
+use nautilus_model::{
+    enums::OrderType, events::order::any::OrderEventAny, identifiers::instrument_id::InstrumentId,
+    orders::any::OrderAny, types::quantity::Quantity,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::messages::modify::ModifyOrder;
+
+/// Configuration overrides for execution of a specific instrument.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct InstrumentExecConfig {
+    /// The maximum quantity for any order.
+    pub max_order_size: Option<Quantity>,
+    /// The minimum time (nanoseconds) an order must rest after being accepted or updated
+    /// before it may be modified or canceled.
+    pub min_resting_time_ns: Option<u64>,
+    /// The order types which are allowed (all order types when `None`).
+    pub allowed_order_types: Option<Vec<OrderType>>,
+}
+
+impl InstrumentExecConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        if let Some(max_order_size) = self.max_order_size {
+            check_predicate_true(
+                max_order_size.is_positive(),
+                "`max_order_size` must be positive",
+            )?;
+        }
+        if let Some(allowed_order_types) = &self.allowed_order_types {
+            check_predicate_true(
+                !allowed_order_types.is_empty(),
+                "`allowed_order_types` must not be empty",
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Provides per-instrument execution configuration overrides, with checks for trading
+/// commands against them.
+#[derive(Clone, Debug, Default)]
+pub struct InstrumentExecOverrides {
+    configs: HashMap<InstrumentId, InstrumentExecConfig>,
+}
+
+impl InstrumentExecOverrides {
+    /// Creates a new [`InstrumentExecOverrides`] instance with no overrides.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the execution configuration overrides for the given `instrument_id`.
+    pub fn set_config(
+        &mut self,
+        instrument_id: InstrumentId,
+        config: InstrumentExecConfig,
+    ) -> anyhow::Result<()> {
+        config.validate()?;
+        self.configs.insert(instrument_id, config);
+        Ok(())
+    }
+
+    /// Removes any execution configuration overrides for the given `instrument_id`.
+    pub fn remove_config(&mut self, instrument_id: &InstrumentId) -> Option<InstrumentExecConfig> {
+        self.configs.remove(instrument_id)
+    }
+
+    /// Returns the execution configuration overrides for the given `instrument_id` (if any).
+    #[must_use]
+    pub fn config(&self, instrument_id: &InstrumentId) -> Option<&InstrumentExecConfig> {
+        self.configs.get(instrument_id)
+    }
+
+    /// Checks the given `order` may be submitted.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error with the reason to deny the order if the order type
+    /// is not allowed, or the quantity exceeds the maximum order size.
+    pub fn check_submit(&self, order: &OrderAny) -> anyhow::Result<()> {
+        let instrument_id = order.instrument_id();
+        let Some(config) = self.configs.get(&instrument_id) else {
+            return Ok(());
+        };
+
+        if let Some(allowed_order_types) = &config.allowed_order_types {
+            let order_type = order.order_type();
+            if !allowed_order_types.contains(&order_type) {
+                anyhow::bail!("{order_type} orders not allowed for {instrument_id}");
+            }
+        }
+        check_max_order_size(config, order.quantity(), instrument_id)
+    }
+
+    /// Checks the given `order` may be modified by the given `command`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error with the reason to deny the command if the new
+    /// quantity exceeds the maximum order size, or the order has not rested for the minimum
+    /// resting time.
+    pub fn check_modify(&self, order: &OrderAny, command: &ModifyOrder) -> anyhow::Result<()> {
+        let Some(config) = self.configs.get(&command.instrument_id) else {
+            return Ok(());
+        };
+
+        if let Some(quantity) = command.quantity {
+            check_max_order_size(config, quantity, command.instrument_id)?;
+        }
+        check_min_resting_time(config, order, command.ts_init)
+    }
+
+    /// Checks the given `order` may be canceled at `ts_now`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error with the reason to deny the cancel if the order has not
+    /// rested for the minimum resting time.
+    pub fn check_cancel(&self, order: &OrderAny, ts_now: UnixNanos) -> anyhow::Result<()> {
+        match self.configs.get(&order.instrument_id()) {
+            Some(config) => check_min_resting_time(config, order, ts_now),
+            None => Ok(()),
+        }
+    }
+}
+
+fn check_max_order_size(
+    config: &InstrumentExecConfig,
+    quantity: Quantity,
+    instrument_id: InstrumentId,
+) -> anyhow::Result<()> {
+    match config.max_order_size {
+        Some(max_order_size) if quantity > max_order_size => anyhow::bail!(
+            "Quantity {quantity} exceeds maximum order size {max_order_size} for {instrument_id}"
+        ),
+        _ => Ok(()),
+    }
+}
+
+fn check_min_resting_time(
+    config: &InstrumentExecConfig,
+    order: &OrderAny,
+    ts_now: UnixNanos,
+) -> anyhow::Result<()> {
+    let Some(min_resting_time_ns) = config.min_resting_time_ns else {
+        return Ok(());
+    };
+
+    // The order rests from when it was last accepted or updated by the venue
+    let ts_resting = order
+        .events()
+        .into_iter()
+        .rev()
+        .find_map(|event| match event {
+            OrderEventAny::Accepted(_) | OrderEventAny::Updated(_) => Some(event.ts_event()),
+            _ => None,
+        });
+    let Some(ts_resting) = ts_resting else {
+        return Ok(());
+    };
+
+    let rested_ns = ts_now.as_u64().saturating_sub(ts_resting.as_u64());
+    if rested_ns < min_resting_time_ns {
+        anyhow::bail!(
+            "Order {} has rested {rested_ns}ns, less than the minimum resting time {min_resting_time_ns}ns",
+            order.client_order_id()
+        );
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderSide,
+        identifiers::{account_id::AccountId, venue_order_id::VenueOrderId},
+        orders::stubs::{TestOrderEventStubs, TestOrderStubs},
+        types::price::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("ESZ4.XCME")
+    }
+
+    fn overrides() -> InstrumentExecOverrides {
+        let mut overrides = InstrumentExecOverrides::new();
+        overrides
+            .set_config(
+                instrument_id(),
+                InstrumentExecConfig {
+                    max_order_size: Some(Quantity::from(10)),
+                    min_resting_time_ns: Some(1_000),
+                    allowed_order_types: Some(vec![OrderType::Limit]),
+                },
+            )
+            .unwrap();
+        overrides
+    }
+
+    fn accepted_limit_order(quantity: i64) -> OrderAny {
+        let mut order = TestOrderStubs::limit_order(
+            instrument_id(),
+            OrderSide::Buy,
+            Price::from("5000.00"),
+            Quantity::from(quantity),
+            None,
+            None,
+        );
+        let account_id = AccountId::from("SIM-001");
+        order
+            .apply(TestOrderEventStubs::order_submitted(&order, account_id))
+            .unwrap();
+        order
+            .apply(TestOrderEventStubs::order_accepted(
+                &order,
+                account_id,
+                VenueOrderId::from("1"),
+            ))
+            .unwrap();
+        order
+    }
+
+    #[rstest]
+    fn test_check_submit() {
+        let overrides = overrides();
+        let market = TestOrderStubs::market_order(
+            instrument_id(),
+            OrderSide::Buy,
+            Quantity::from(1),
+            None,
+            None,
+        );
+
+        assert!(overrides.check_submit(&accepted_limit_order(10)).is_ok());
+        assert!(overrides.check_submit(&accepted_limit_order(11)).is_err());
+        assert!(overrides.check_submit(&market).is_err());
+    }
+
+    #[rstest]
+    fn test_check_submit_without_overrides() {
+        let order = TestOrderStubs::market_order(
+            InstrumentId::from("AAPL.XNAS"),
+            OrderSide::Buy,
+            Quantity::from(1_000),
+            None,
+            None,
+        );
+
+        assert!(overrides().check_submit(&order).is_ok());
+    }
+
+    #[rstest]
+    fn test_check_cancel_min_resting_time() {
+        let overrides = overrides();
+        let order = accepted_limit_order(1);
+
+        assert!(overrides
+            .check_cancel(&order, UnixNanos::from(999))
+            .is_err());
+        assert!(overrides
+            .check_cancel(&order, UnixNanos::from(1_000))
+            .is_ok());
+    }
+
+    #[rstest]
+    fn test_invalid_config() {
+        let mut overrides = InstrumentExecOverrides::new();
+        let config = InstrumentExecConfig {
+            allowed_order_types: Some(vec![]),
+            ..Default::default()
+        };
+
+        assert!(overrides.set_config(instrument_id(), config).is_err());
+        assert!(overrides.config(&instrument_id()).is_none());
+    }
+}
@@ -0,0 +1,393 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a `PositionManager` which applies fills to positions according to the OMS type.
+
+use std::collections::HashMap;
+
+use nautilus_common::generators::position_id::PositionIdGenerator;
+use nautilus_model::{
+    enums::{OmsType, PositionSide},
+    events::order::filled::OrderFilled,
+    identifiers::{client_order_id::ClientOrderId, position_id::PositionId},
+    instruments::any::InstrumentAny,
+    position::Position,
+    types::money::Money,
+};
+
+/// Represents the effect of a fill on a position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PositionUpdate {
+    /// A position was opened (or reopened).
+    Opened(PositionId),
+    /// An open position was changed.
+    Changed(PositionId),
+    /// A position was closed.
+    Closed(PositionId),
+}
+
+/// Provides management of positions, applying fills with `HEDGING` or `NETTING` OMS
+/// (order management system) handling.
+///
+/// With `NETTING` there is a single position per instrument and strategy, and a closed
+/// position which is reopened is snapshotted first. With `HEDGING` each fill without a
+/// position ID opens a new position, unless the order has already been assigned one.
+///
+/// A fill which would flip a position is split into a closing fill and an opening fill
+/// for the remaining quantity, with the commission apportioned between them.
+pub struct PositionManager {
+    pos_id_generator: PositionIdGenerator,
+    positions: HashMap<PositionId, Position>,
+    snapshots: HashMap<PositionId, Vec<Position>>,
+    order_position_ids: HashMap<ClientOrderId, PositionId>,
+}
+
+impl PositionManager {
+    /// Creates a new [`PositionManager`] instance.
+    #[must_use]
+    pub fn new(pos_id_generator: PositionIdGenerator) -> Self {
+        Self {
+            pos_id_generator,
+            positions: HashMap::new(),
+            snapshots: HashMap::new(),
+            order_position_ids: HashMap::new(),
+        }
+    }
+
+    /// Returns a reference to the position for the given `position_id` (if found).
+    #[must_use]
+    pub fn position(&self, position_id: &PositionId) -> Option<&Position> {
+        self.positions.get(position_id)
+    }
+
+    /// Returns references to all positions.
+    #[must_use]
+    pub fn positions(&self) -> Vec<&Position> {
+        self.positions.values().collect()
+    }
+
+    /// Returns the snapshots of previously closed positions for the given `position_id`,
+    /// in the order they were closed.
+    #[must_use]
+    pub fn snapshots(&self, position_id: &PositionId) -> &[Position] {
+        self.snapshots.get(position_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the position ID for the given `fill` with the given `oms_type`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `oms_type` is `Unspecified`.
+    pub fn determine_position_id(
+        &mut self,
+        fill: &OrderFilled,
+        oms_type: OmsType,
+    ) -> anyhow::Result<PositionId> {
+        match oms_type {
+            OmsType::Hedging => Ok(self.determine_hedging_position_id(fill)),
+            OmsType::Netting => Ok(determine_netting_position_id(fill)),
+            OmsType::Unspecified => {
+                anyhow::bail!("Cannot determine position ID with an unspecified `OmsType`")
+            }
+        }
+    }
+
+    fn determine_hedging_position_id(&mut self, fill: &OrderFilled) -> PositionId {
+        if let Some(position_id) = fill.position_id {
+            return position_id;
+        }
+        if let Some(position_id) = self.order_position_ids.get(&fill.client_order_id) {
+            return *position_id;
+        }
+        self.pos_id_generator.generate(fill.strategy_id, false)
+    }
+
+    /// Applies the given `fill` to the relevant position, opening a new position if
+    /// required, and returns the resulting position updates in order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the position ID cannot be determined, or the
+    /// position cannot be created from the fill.
+    pub fn apply_fill(
+        &mut self,
+        instrument: &InstrumentAny,
+        mut fill: OrderFilled,
+        oms_type: OmsType,
+    ) -> anyhow::Result<Vec<PositionUpdate>> {
+        let position_id = self.determine_position_id(&fill, oms_type)?;
+        fill.position_id = Some(position_id);
+        self.order_position_ids
+            .insert(fill.client_order_id, position_id);
+
+        let (is_open, will_flip) = match self.positions.get(&position_id) {
+            Some(position) if !position.is_closed() => (true, will_flip_position(position, &fill)),
+            _ => (false, false),
+        };
+
+        let mut updates = Vec::new();
+        if !is_open {
+            self.open_position(instrument, fill, &mut updates)?;
+        } else if will_flip {
+            self.flip_position(instrument, fill, oms_type, &mut updates)?;
+        } else {
+            self.update_position(fill, &mut updates);
+        }
+        Ok(updates)
+    }
+
+    fn open_position(
+        &mut self,
+        instrument: &InstrumentAny,
+        fill: OrderFilled,
+        updates: &mut Vec<PositionUpdate>,
+    ) -> anyhow::Result<()> {
+        let position_id = fill.position_id.expect("Position ID not assigned");
+        match self.positions.get_mut(&position_id) {
+            Some(position) => {
+                // Reopening a closed (netting) position
+                self.snapshots
+                    .entry(position_id)
+                    .or_default()
+                    .push(position.clone());
+                position.apply(&fill);
+            }
+            None => {
+                let position = Position::new(instrument, fill)?;
+                self.positions.insert(position_id, position);
+            }
+        }
+        updates.push(PositionUpdate::Opened(position_id));
+        Ok(())
+    }
+
+    fn update_position(&mut self, fill: OrderFilled, updates: &mut Vec<PositionUpdate>) {
+        let position_id = fill.position_id.expect("Position ID not assigned");
+        let position = self
+            .positions
+            .get_mut(&position_id)
+            .expect("Position not found");
+        position.apply(&fill);
+
+        if position.is_closed() {
+            updates.push(PositionUpdate::Closed(position_id));
+        } else {
+            updates.push(PositionUpdate::Changed(position_id));
+        }
+    }
+
+    fn flip_position(
+        &mut self,
+        instrument: &InstrumentAny,
+        fill: OrderFilled,
+        oms_type: OmsType,
+        updates: &mut Vec<PositionUpdate>,
+    ) -> anyhow::Result<()> {
+        let position_id = fill.position_id.expect("Position ID not assigned");
+        let position_qty = self.positions[&position_id].quantity;
+        let difference = fill.last_qty - position_qty;
+
+        // Apportion the commission between the closing and opening fills
+        let (commission1, commission2) = match fill.commission {
+            Some(commission) => {
+                let fill_percent = position_qty.as_f64() / fill.last_qty.as_f64();
+                let commission1 = Money::new(commission * fill_percent, commission.currency)?;
+                (Some(commission1), Some(commission - commission1))
+            }
+            None => (None, None),
+        };
+
+        let mut fill_split1 = fill;
+        fill_split1.last_qty = position_qty;
+        fill_split1.commission = commission1;
+        self.update_position(fill_split1, updates);
+
+        let position_id_flip = match oms_type {
+            OmsType::Hedging => self.pos_id_generator.generate(fill.strategy_id, true),
+            _ => position_id,
+        };
+        self.order_position_ids
+            .insert(fill.client_order_id, position_id_flip);
+
+        let mut fill_split2 = fill;
+        fill_split2.position_id = Some(position_id_flip);
+        fill_split2.last_qty = difference;
+        fill_split2.commission = commission2;
+        self.open_position(instrument, fill_split2, updates)
+    }
+}
+
+fn determine_netting_position_id(fill: &OrderFilled) -> PositionId {
+    PositionId::from(format!("{}-{}", fill.instrument_id, fill.strategy_id).as_str())
+}
+
+fn will_flip_position(position: &Position, fill: &OrderFilled) -> bool {
+    position.side != PositionSide::Flat
+        && position.is_opposite_side(fill.order_side)
+        && fill.last_qty > position.quantity
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::time::get_atomic_clock_static;
+    use nautilus_model::{
+        enums::OrderSide,
+        identifiers::{strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId},
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        orders::stubs::{TestOrderEventStubs, TestOrderStubs},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn manager() -> PositionManager {
+        PositionManager::new(PositionIdGenerator::new(
+            TraderId::from("TRADER-001"),
+            get_atomic_clock_static(),
+        ))
+    }
+
+    fn fill(
+        instrument: &InstrumentAny,
+        side: OrderSide,
+        quantity: i64,
+        trade_id: &str,
+        commission: &str,
+    ) -> OrderFilled {
+        let order = TestOrderStubs::market_order(
+            instrument.id(),
+            side,
+            Quantity::from(quantity),
+            Some(ClientOrderId::from(format!("O-{trade_id}").as_str())),
+            None,
+        );
+        let mut fill: OrderFilled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            Some(TradeId::from(trade_id)),
+            None,
+            Some(Price::from("1.00000")),
+            None,
+            Some(Money::from(commission)),
+            None,
+            None,
+        )
+        .into();
+        fill.position_id = None;
+        fill
+    }
+
+    #[rstest]
+    fn test_netting_position_id(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut manager = manager();
+        let fill = fill(&instrument, OrderSide::Buy, 100_000, "1", "2 USD");
+
+        let position_id = manager
+            .determine_position_id(&fill, OmsType::Netting)
+            .unwrap();
+
+        assert_eq!(position_id, PositionId::from("AUD/USD.SIM-S-001"));
+        assert!(manager
+            .determine_position_id(&fill, OmsType::Unspecified)
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_hedging_opens_separate_positions(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut manager = manager();
+        let fill1 = fill(&instrument, OrderSide::Buy, 100_000, "1", "2 USD");
+        let fill2 = fill(&instrument, OrderSide::Sell, 50_000, "2", "1 USD");
+
+        manager
+            .apply_fill(&instrument, fill1, OmsType::Hedging)
+            .unwrap();
+        manager
+            .apply_fill(&instrument, fill2, OmsType::Hedging)
+            .unwrap();
+
+        assert_eq!(manager.positions().len(), 2);
+    }
+
+    #[rstest]
+    fn test_netting_flip_closes_and_reopens(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut manager = manager();
+        let fill1 = fill(&instrument, OrderSide::Buy, 100_000, "1", "2 USD");
+        let fill2 = fill(&instrument, OrderSide::Sell, 150_000, "2", "3 USD");
+
+        manager
+            .apply_fill(&instrument, fill1, OmsType::Netting)
+            .unwrap();
+        let updates = manager
+            .apply_fill(&instrument, fill2, OmsType::Netting)
+            .unwrap();
+
+        let position_id = PositionId::from("AUD/USD.SIM-S-001");
+        let position = manager.position(&position_id).unwrap();
+        assert_eq!(
+            updates,
+            vec![
+                PositionUpdate::Closed(position_id),
+                PositionUpdate::Opened(position_id),
+            ]
+        );
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(position.quantity, Quantity::from(50_000));
+        assert_eq!(position.commissions(), vec![Money::from("1 USD")]);
+        assert_eq!(manager.snapshots(&position_id).len(), 1);
+        assert!(manager.snapshots(&position_id)[0].is_closed());
+    }
+
+    #[rstest]
+    fn test_hedging_flip_opens_flipped_position(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut manager = manager();
+        let fill1 = fill(&instrument, OrderSide::Buy, 100_000, "1", "2 USD");
+        let mut fill2 = fill(&instrument, OrderSide::Sell, 150_000, "2", "3 USD");
+
+        let updates = manager
+            .apply_fill(&instrument, fill1, OmsType::Hedging)
+            .unwrap();
+        let PositionUpdate::Opened(position_id) = updates[0] else {
+            panic!("Expected position opened");
+        };
+        fill2.position_id = Some(position_id);
+        let updates = manager
+            .apply_fill(&instrument, fill2, OmsType::Hedging)
+            .unwrap();
+
+        let PositionUpdate::Opened(flipped_id) = updates[1] else {
+            panic!("Expected position opened");
+        };
+        assert_eq!(updates[0], PositionUpdate::Closed(position_id));
+        assert!(flipped_id.as_str().ends_with('F'));
+        assert!(manager.position(&position_id).unwrap().is_closed());
+        assert_eq!(
+            manager.position(&flipped_id).unwrap().quantity,
+            Quantity::from(50_000)
+        );
+        assert_eq!(manager.positions().len(), 2);
+        assert_eq!(
+            StrategyId::from("S-001"),
+            manager.position(&flipped_id).unwrap().strategy_id
+        );
+    }
+}
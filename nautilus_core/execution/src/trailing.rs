@@ -0,0 +1,355 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trailing stop calculations for `TrailingStopMarket` and `TrailingStopLimit` orders.
+
+use nautilus_model::{
+    enums::{OrderSide, OrderType, TrailingOffsetType, TriggerType},
+    orders::base::Order,
+    types::price::Price,
+};
+
+/// Calculates the new trigger price, and for `TrailingStopLimit` orders the new limit price,
+/// for the given trailing stop `order` based on the current market.
+///
+/// A price is only returned if it improves on (trails closer to the market than) the current
+/// price of the order, otherwise `None`.
+///
+/// # Errors
+///
+/// This function returns an error if:
+/// - The order is not a trailing stop order.
+/// - A market price required by the orders trigger type is not available.
+/// - The orders trigger type or trailing offset type is not supported.
+pub fn trailing_stop_calculate(
+    price_increment: Price,
+    order: &impl Order,
+    bid: Option<Price>,
+    ask: Option<Price>,
+    last: Option<Price>,
+) -> anyhow::Result<(Option<Price>, Option<Price>)> {
+    let order_type = order.order_type();
+    let is_limit = match order_type {
+        OrderType::TrailingStopMarket => false,
+        OrderType::TrailingStopLimit => true,
+        _ => anyhow::bail!("Invalid `OrderType` for calculation, was {order_type}"),
+    };
+
+    let side = order.side();
+    let offset_type = order
+        .trailing_offset_type()
+        .ok_or_else(|| anyhow::anyhow!("No `trailing_offset_type` for order"))?;
+    let trailing_offset = order
+        .trailing_offset()
+        .ok_or_else(|| anyhow::anyhow!("No `trailing_offset` for order"))?
+        .as_f64();
+    let limit_offset = if is_limit {
+        let limit_offset = order
+            .limit_offset()
+            .ok_or_else(|| anyhow::anyhow!("No `limit_offset` for order"))?;
+        Some(limit_offset.as_f64())
+    } else {
+        None
+    };
+
+    let instrument_id = order.instrument_id();
+    let get_last = || {
+        last.ok_or_else(|| {
+            anyhow::anyhow!("No LAST price for {instrument_id} (add trade ticks or use bars)")
+        })
+    };
+    let get_bid_ask = || match (bid, ask) {
+        (Some(bid), Some(ask)) => Ok((bid, ask)),
+        _ => anyhow::bail!("No BID or ASK price for {instrument_id} (add quote ticks or use bars)"),
+    };
+
+    let with_last = |offset: f64, last: Price| {
+        calculate_with_last(price_increment, offset_type, side, offset, last)
+    };
+    let with_bid_ask = |offset: f64, (bid, ask): (Price, Price)| {
+        calculate_with_bid_ask(price_increment, offset_type, side, offset, bid, ask)
+    };
+
+    // Candidate (trigger price, limit price) pairs, in the order they are applied
+    let mut candidates = Vec::with_capacity(2);
+    let trigger_type = order.trigger_type().unwrap_or_default();
+    match trigger_type {
+        TriggerType::Default | TriggerType::LastTrade | TriggerType::MarkPrice => {
+            let last = get_last()?;
+            candidates.push((
+                with_last(trailing_offset, last)?,
+                limit_offset.map(|o| with_last(o, last)).transpose()?,
+            ));
+        }
+        TriggerType::BidAsk => {
+            let bid_ask = get_bid_ask()?;
+            candidates.push((
+                with_bid_ask(trailing_offset, bid_ask)?,
+                limit_offset.map(|o| with_bid_ask(o, bid_ask)).transpose()?,
+            ));
+        }
+        TriggerType::LastOrBidAsk => {
+            let last = get_last()?;
+            let bid_ask = get_bid_ask()?;
+            candidates.push((
+                with_last(trailing_offset, last)?,
+                limit_offset.map(|o| with_last(o, last)).transpose()?,
+            ));
+            candidates.push((
+                with_bid_ask(trailing_offset, bid_ask)?,
+                limit_offset.map(|o| with_bid_ask(o, bid_ask)).transpose()?,
+            ));
+        }
+        _ => anyhow::bail!("`TriggerType` {trigger_type} not currently supported"),
+    }
+
+    let mut trigger_price = order.trigger_price();
+    let mut price = if is_limit { order.price() } else { None };
+    let mut new_trigger_price = None;
+    let mut new_price = None;
+
+    for (candidate_trigger_price, candidate_price) in candidates {
+        if is_improvement(side, trigger_price, candidate_trigger_price) {
+            trigger_price = Some(candidate_trigger_price);
+            new_trigger_price = trigger_price;
+        }
+        if let Some(candidate_price) = candidate_price {
+            if is_improvement(side, price, candidate_price) {
+                price = Some(candidate_price);
+                new_price = price;
+            }
+        }
+    }
+
+    Ok((new_trigger_price, new_price))
+}
+
+/// Returns whether the `candidate` price trails closer to the market than the `current` price.
+fn is_improvement(side: OrderSide, current: Option<Price>, candidate: Price) -> bool {
+    match (side, current) {
+        (_, None) => true,
+        (OrderSide::Buy, Some(current)) => current > candidate,
+        (_, Some(current)) => current < candidate,
+    }
+}
+
+/// Returns the absolute price offset for the given `offset` of `offset_type` from `reference`.
+fn absolute_offset(
+    price_increment: Price,
+    offset_type: TrailingOffsetType,
+    offset: f64,
+    reference: Price,
+) -> anyhow::Result<f64> {
+    match offset_type {
+        TrailingOffsetType::Price => Ok(offset),
+        TrailingOffsetType::BasisPoints => Ok(reference.as_f64() * (offset / 100.0) / 100.0),
+        TrailingOffsetType::Ticks => Ok(offset * price_increment.as_f64()),
+        _ => anyhow::bail!("`TrailingOffsetType` {offset_type} not currently supported"),
+    }
+}
+
+/// Returns the trailing price at `offset` from the `last` traded price.
+pub fn calculate_with_last(
+    price_increment: Price,
+    offset_type: TrailingOffsetType,
+    side: OrderSide,
+    offset: f64,
+    last: Price,
+) -> anyhow::Result<Price> {
+    let offset = absolute_offset(price_increment, offset_type, offset, last)?;
+    match side {
+        OrderSide::Buy => Price::new(last.as_f64() + offset, price_increment.precision),
+        OrderSide::Sell => Price::new(last.as_f64() - offset, price_increment.precision),
+        OrderSide::NoOrderSide => anyhow::bail!("Invalid `OrderSide`, was {side}"),
+    }
+}
+
+/// Returns the trailing price at `offset` from the `ask` (for buys) or `bid` (for sells).
+pub fn calculate_with_bid_ask(
+    price_increment: Price,
+    offset_type: TrailingOffsetType,
+    side: OrderSide,
+    offset: f64,
+    bid: Price,
+    ask: Price,
+) -> anyhow::Result<Price> {
+    match side {
+        OrderSide::Buy => {
+            let offset = absolute_offset(price_increment, offset_type, offset, ask)?;
+            Price::new(ask.as_f64() + offset, price_increment.precision)
+        }
+        OrderSide::Sell => {
+            let offset = absolute_offset(price_increment, offset_type, offset, bid)?;
+            Price::new(bid.as_f64() - offset, price_increment.precision)
+        }
+        OrderSide::NoOrderSide => anyhow::bail!("Invalid `OrderSide`, was {side}"),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        identifiers::instrument_id::InstrumentId,
+        orders::{
+            limit::LimitOrder, stubs::TestOrderStubs, trailing_stop_limit::TrailingStopLimitOrder,
+            trailing_stop_market::TrailingStopMarketOrder,
+        },
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn trailing_stop_market(
+        side: OrderSide,
+        trigger_price: &str,
+        trigger_type: TriggerType,
+        offset: &str,
+        offset_type: TrailingOffsetType,
+    ) -> TrailingStopMarketOrder {
+        TestOrderStubs::trailing_stop_market_order(
+            InstrumentId::from("AUD/USD.SIM"),
+            side,
+            Price::from(trigger_price),
+            Quantity::from(100_000),
+            trigger_type,
+            Price::from(offset),
+            offset_type,
+        )
+        .into()
+    }
+
+    #[rstest]
+    #[case(TrailingOffsetType::Price, "0.00100", "1.00100")]
+    #[case(TrailingOffsetType::BasisPoints, "50", "1.00500")]
+    #[case(TrailingOffsetType::Ticks, "20", "1.00020")]
+    fn test_buy_with_last_offset_types(
+        #[case] offset_type: TrailingOffsetType,
+        #[case] offset: &str,
+        #[case] expected: &str,
+    ) {
+        let order = trailing_stop_market(
+            OrderSide::Buy,
+            "1.10000",
+            TriggerType::LastTrade,
+            offset,
+            offset_type,
+        );
+
+        let (trigger_price, price) = trailing_stop_calculate(
+            Price::from("0.00001"),
+            &order,
+            None,
+            None,
+            Some(Price::from("1.00000")),
+        )
+        .unwrap();
+
+        assert_eq!(trigger_price, Some(Price::from(expected)));
+        assert_eq!(price, None);
+    }
+
+    #[rstest]
+    fn test_sell_does_not_trail_away_from_market() {
+        let order = trailing_stop_market(
+            OrderSide::Sell,
+            "0.99500",
+            TriggerType::BidAsk,
+            "0.00100",
+            TrailingOffsetType::Price,
+        );
+
+        let (trigger_price, _) = trailing_stop_calculate(
+            Price::from("0.00001"),
+            &order,
+            Some(Price::from("0.99000")),
+            Some(Price::from("0.99010")),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(trigger_price, None);
+    }
+
+    #[rstest]
+    fn test_sell_limit_with_last_or_bid_ask() {
+        let order: TrailingStopLimitOrder = TestOrderStubs::trailing_stop_limit_order(
+            InstrumentId::from("AUD/USD.SIM"),
+            OrderSide::Sell,
+            Price::from("0.90000"),
+            Price::from("0.90500"),
+            Quantity::from(100_000),
+            TriggerType::LastOrBidAsk,
+            Price::from("0.00200"),
+            Price::from("0.00100"),
+            TrailingOffsetType::Price,
+        )
+        .into();
+
+        let (trigger_price, price) = trailing_stop_calculate(
+            Price::from("0.00001"),
+            &order,
+            Some(Price::from("1.00010")),
+            Some(Price::from("1.00020")),
+            Some(Price::from("1.00000")),
+        )
+        .unwrap();
+
+        // The bid is above the last, so trails closest from the bid
+        assert_eq!(trigger_price, Some(Price::from("0.99910")));
+        assert_eq!(price, Some(Price::from("0.99810")));
+    }
+
+    #[rstest]
+    fn test_missing_market_price() {
+        let order = trailing_stop_market(
+            OrderSide::Buy,
+            "1.10000",
+            TriggerType::BidAsk,
+            "0.00100",
+            TrailingOffsetType::Price,
+        );
+
+        let result = trailing_stop_calculate(Price::from("0.00001"), &order, None, None, None);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_invalid_order_type() {
+        let order: LimitOrder = TestOrderStubs::limit_order(
+            InstrumentId::from("AUD/USD.SIM"),
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            None,
+            None,
+        )
+        .into();
+
+        let result = trailing_stop_calculate(
+            Price::from("0.00001"),
+            &order,
+            None,
+            None,
+            Some(Price::from("1.00000")),
+        );
+
+        assert!(result.is_err());
+    }
+}
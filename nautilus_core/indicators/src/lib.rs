@@ -33,6 +33,7 @@ pub mod momentum;
 pub mod ratio;
 pub mod testing;
 pub mod volatility;
+pub mod warmup;
 
 #[cfg(test)]
 mod stubs;
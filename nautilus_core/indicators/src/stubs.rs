@@ -84,11 +84,7 @@ pub fn bar_ethusdt_binance_minute_bid(#[default("1522")] close_price: &str) -> B
         aggregation: BarAggregation::Minute,
         price_type: PriceType::Bid,
     };
-    let bar_type = BarType {
-        instrument_id,
-        spec: bar_spec,
-        aggregation_source: AggregationSource::External,
-    };
+    let bar_type = BarType::new(instrument_id, bar_spec, AggregationSource::External);
     Bar {
         bar_type,
         open: Price::from("1500.0"),
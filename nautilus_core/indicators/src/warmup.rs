@@ -0,0 +1,362 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Indicator warm-up requirements and bootstrapping from historical bars.
+//!
+//! A strategy declares a [`WarmupRequirement`] per bar type (e.g. 200 one-minute bars),
+//! requests the history window through the data engine from the catalog or an adapter, and the
+//! [`IndicatorWarmup`] replays it through the indicators. Live bars received while the
+//! history is outstanding are buffered, then released once the requirement is satisfied,
+//! so indicators see a single contiguous and ordered stream before switching to live data.
+
+use std::collections::{HashMap, HashSet};
+
+use nautilus_core::{
+    correctness::check_predicate_true,
+    datetime::{NANOSECONDS_IN_MILLISECOND, NANOSECONDS_IN_SECOND},
+    nanos::UnixNanos,
+};
+use nautilus_model::{
+    data::bar::{Bar, BarType},
+    enums::BarAggregation,
+};
+
+use crate::indicator::Indicator;
+
+const NANOSECONDS_IN_MINUTE: u64 = 60 * NANOSECONDS_IN_SECOND;
+const NANOSECONDS_IN_HOUR: u64 = 60 * NANOSECONDS_IN_MINUTE;
+const NANOSECONDS_IN_DAY: u64 = 24 * NANOSECONDS_IN_HOUR;
+
+/// Represents the historical data required to warm up indicators for a bar type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WarmupRequirement {
+    /// The bar type for the warm-up data.
+    pub bar_type: BarType,
+    /// The number of bars required to warm up.
+    pub count: usize,
+}
+
+impl WarmupRequirement {
+    /// Creates a new [`WarmupRequirement`] instance.
+    pub fn new(bar_type: BarType, count: usize) -> anyhow::Result<Self> {
+        check_predicate_true(count > 0, "`count` must be positive")?;
+        Ok(Self { bar_type, count })
+    }
+
+    /// Returns the duration (nanoseconds) spanned by the required bars, if the bar type
+    /// is time aggregated and the duration does not overflow.
+    ///
+    /// Month bars use the maximum month length, so the window may be wider than needed.
+    #[must_use]
+    pub fn lookback_ns(&self) -> Option<u64> {
+        let interval_ns = match self.bar_type.spec.aggregation {
+            BarAggregation::Millisecond => NANOSECONDS_IN_MILLISECOND,
+            BarAggregation::Second => NANOSECONDS_IN_SECOND,
+            BarAggregation::Minute => NANOSECONDS_IN_MINUTE,
+            BarAggregation::Hour => NANOSECONDS_IN_HOUR,
+            BarAggregation::Day => NANOSECONDS_IN_DAY,
+            BarAggregation::Week => 7 * NANOSECONDS_IN_DAY,
+            BarAggregation::Month => 31 * NANOSECONDS_IN_DAY,
+            _ => return None,
+        };
+        interval_ns
+            .checked_mul(self.bar_type.spec.step as u64)?
+            .checked_mul(self.count as u64)
+    }
+
+    /// Returns the start of the history window to request, ending at `now`.
+    ///
+    /// Returns `None` if the bar type is not time aggregated (or the window overflows), in
+    /// which case the history should be requested by count.
+    #[must_use]
+    pub fn start(&self, now: UnixNanos) -> Option<UnixNanos> {
+        self.lookback_ns()
+            .map(|lookback| UnixNanos::from(now.as_u64().saturating_sub(lookback)))
+    }
+
+    /// Returns the most recent bars which satisfy the requirement, ordered by `ts_init`.
+    #[must_use]
+    pub fn select<'a>(&self, bars: &'a [Bar]) -> Vec<&'a Bar> {
+        let mut selected: Vec<&Bar> = bars
+            .iter()
+            .filter(|bar| bar.bar_type == self.bar_type)
+            .collect();
+        selected.sort_by_key(|bar| bar.ts_init);
+        let skip = selected.len().saturating_sub(self.count);
+        selected.split_off(skip)
+    }
+}
+
+/// Provides indicator warm-up bootstrapping for a strategy.
+#[derive(Debug, Default)]
+pub struct IndicatorWarmup {
+    requirements: HashMap<BarType, WarmupRequirement>,
+    completed: HashSet<BarType>,
+    last_ts_init: HashMap<BarType, UnixNanos>,
+    buffered: HashMap<BarType, Vec<Bar>>,
+}
+
+impl IndicatorWarmup {
+    /// Creates a new [`IndicatorWarmup`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given warm-up `requirement`.
+    ///
+    /// If a requirement already exists for the bar type then the larger count is kept.
+    pub fn add_requirement(&mut self, requirement: WarmupRequirement) {
+        self.requirements
+            .entry(requirement.bar_type)
+            .and_modify(|existing| existing.count = existing.count.max(requirement.count))
+            .or_insert(requirement);
+    }
+
+    /// Returns the warm-up requirements which are still outstanding.
+    #[must_use]
+    pub fn pending(&self) -> Vec<WarmupRequirement> {
+        self.requirements
+            .values()
+            .filter(|r| !self.completed.contains(&r.bar_type))
+            .copied()
+            .collect()
+    }
+
+    /// Returns whether the warm-up for the given `bar_type` is complete.
+    #[must_use]
+    pub fn is_warm(&self, bar_type: &BarType) -> bool {
+        !self.requirements.contains_key(bar_type) || self.completed.contains(bar_type)
+    }
+
+    /// Returns whether all warm-up requirements are complete, and the strategy can
+    /// switch to live data.
+    #[must_use]
+    pub fn is_live(&self) -> bool {
+        self.completed.len() == self.requirements.len()
+    }
+
+    /// Handles the historical `bars` received for `bar_type`, replaying the required bars
+    /// through the `indicators` followed by any live bars buffered in the meantime.
+    ///
+    /// Returns the number of bars replayed.
+    pub fn handle_history(
+        &mut self,
+        bar_type: &BarType,
+        bars: &[Bar],
+        indicators: &mut [&mut dyn Indicator],
+    ) -> anyhow::Result<usize> {
+        let requirement = self
+            .requirements
+            .get(bar_type)
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("No warm-up requirement for {bar_type}"))?;
+
+        if self.completed.contains(bar_type) {
+            anyhow::bail!("Warm-up for {bar_type} already complete");
+        }
+
+        let selected = requirement.select(bars);
+        if selected.len() < requirement.count {
+            log::warn!(
+                "Insufficient warm-up history for {bar_type}: required {}, received {}",
+                requirement.count,
+                selected.len()
+            );
+        }
+
+        let mut replayed = 0;
+        for bar in selected {
+            Self::update_indicators(bar, indicators);
+            self.last_ts_init.insert(*bar_type, bar.ts_init);
+            replayed += 1;
+        }
+
+        // Release live bars received during the warm-up which follow the history
+        let mut last_ts_init = self.last_ts_init.get(bar_type).copied();
+        for bar in self.buffered.remove(bar_type).unwrap_or_default() {
+            if last_ts_init.map_or(true, |ts| bar.ts_init > ts) {
+                Self::update_indicators(&bar, indicators);
+                last_ts_init = Some(bar.ts_init);
+                self.last_ts_init.insert(*bar_type, bar.ts_init);
+                replayed += 1;
+            }
+        }
+
+        self.completed.insert(*bar_type);
+        log::info!("Warm-up complete for {bar_type} ({replayed} bars)");
+
+        Ok(replayed)
+    }
+
+    /// Handles the live `bar`, updating the `indicators` if the warm-up for its bar type is
+    /// complete, otherwise buffering it until the history has been replayed.
+    ///
+    /// Returns whether the bar was passed through to the indicators.
+    pub fn handle_bar(&mut self, bar: &Bar, indicators: &mut [&mut dyn Indicator]) -> bool {
+        if !self.is_warm(&bar.bar_type) {
+            self.buffered.entry(bar.bar_type).or_default().push(*bar);
+            return false;
+        }
+
+        // Drop any bar already covered by the replayed history
+        if let Some(ts) = self.last_ts_init.get(&bar.bar_type) {
+            if bar.ts_init <= *ts {
+                return false;
+            }
+        }
+
+        Self::update_indicators(bar, indicators);
+        true
+    }
+
+    /// Resets the warm-up so that all requirements are outstanding again.
+    pub fn reset(&mut self) {
+        self.completed.clear();
+        self.last_ts_init.clear();
+        self.buffered.clear();
+    }
+
+    fn update_indicators(bar: &Bar, indicators: &mut [&mut dyn Indicator]) {
+        for indicator in indicators.iter_mut() {
+            indicator.handle_bar(bar);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::bar::Bar,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{average::sma::SimpleMovingAverage, indicator::MovingAverage, stubs::*};
+
+    fn bars(template: &Bar, closes: &[&str], start_ts: u64) -> Vec<Bar> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| Bar {
+                close: Price::from(*close),
+                volume: Quantity::from("1"),
+                ts_event: (start_ts + i as u64).into(),
+                ts_init: (start_ts + i as u64).into(),
+                ..*template
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_requirement_lookback(bar_ethusdt_binance_minute_bid: Bar) {
+        let requirement =
+            WarmupRequirement::new(bar_ethusdt_binance_minute_bid.bar_type, 200).unwrap();
+
+        assert_eq!(requirement.lookback_ns(), Some(200 * NANOSECONDS_IN_MINUTE));
+        assert_eq!(
+            requirement.start(UnixNanos::from(300 * NANOSECONDS_IN_MINUTE)),
+            Some(UnixNanos::from(100 * NANOSECONDS_IN_MINUTE))
+        );
+    }
+
+    #[rstest]
+    fn test_requirement_lookback_overflow(bar_ethusdt_binance_minute_bid: Bar) {
+        let requirement =
+            WarmupRequirement::new(bar_ethusdt_binance_minute_bid.bar_type, usize::MAX).unwrap();
+
+        assert_eq!(requirement.lookback_ns(), None);
+        assert_eq!(requirement.start(UnixNanos::from(1)), None);
+    }
+
+    #[rstest]
+    fn test_requirement_zero_count_errors(bar_ethusdt_binance_minute_bid: Bar) {
+        assert!(WarmupRequirement::new(bar_ethusdt_binance_minute_bid.bar_type, 0).is_err());
+    }
+
+    #[rstest]
+    fn test_requirement_select_most_recent(bar_ethusdt_binance_minute_bid: Bar) {
+        let bar_type = bar_ethusdt_binance_minute_bid.bar_type;
+        let history = bars(&bar_ethusdt_binance_minute_bid, &["1", "2", "3", "4"], 10);
+        let requirement = WarmupRequirement::new(bar_type, 2).unwrap();
+
+        let selected = requirement.select(&history);
+
+        assert_eq!(selected.len(), 2);
+        assert_eq!(selected[0].close, Price::from("3"));
+        assert_eq!(selected[1].close, Price::from("4"));
+    }
+
+    #[rstest]
+    fn test_warmup_replays_history_then_buffered_bars(bar_ethusdt_binance_minute_bid: Bar) {
+        let bar_type = bar_ethusdt_binance_minute_bid.bar_type;
+        let mut sma = SimpleMovingAverage::new(3, None).unwrap();
+        let mut warmup = IndicatorWarmup::new();
+        warmup.add_requirement(WarmupRequirement::new(bar_type, 3).unwrap());
+        assert!(!warmup.is_live());
+
+        // Live bars arriving before the history are buffered
+        let live = bars(&bar_ethusdt_binance_minute_bid, &["3", "4"], 12);
+        for bar in &live {
+            assert!(!warmup.handle_bar(bar, &mut [&mut sma]));
+        }
+        assert_eq!(sma.count(), 0);
+
+        let history = bars(&bar_ethusdt_binance_minute_bid, &["1", "2", "3"], 10);
+        let replayed = warmup
+            .handle_history(&bar_type, &history, &mut [&mut sma])
+            .unwrap();
+
+        // The bar at ts 12 is covered by the history, so only one buffered bar is released
+        assert_eq!(replayed, 4);
+        assert!(warmup.is_live());
+        assert!(sma.initialized());
+        assert_eq!(sma.value(), 3.0);
+    }
+
+    #[rstest]
+    fn test_warmup_passes_through_when_live(bar_ethusdt_binance_minute_bid: Bar) {
+        let mut sma = SimpleMovingAverage::new(3, None).unwrap();
+        let mut warmup = IndicatorWarmup::new();
+
+        assert!(warmup.is_live());
+        assert!(warmup.handle_bar(&bar_ethusdt_binance_minute_bid, &mut [&mut sma]));
+        assert_eq!(sma.count(), 1);
+    }
+
+    #[rstest]
+    fn test_handle_history_without_requirement_errors(bar_ethusdt_binance_minute_bid: Bar) {
+        let mut warmup = IndicatorWarmup::new();
+        let result = warmup.handle_history(&bar_ethusdt_binance_minute_bid.bar_type, &[], &mut []);
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_add_requirement_keeps_larger_count(bar_ethusdt_binance_minute_bid: Bar) {
+        let bar_type = bar_ethusdt_binance_minute_bid.bar_type;
+        let mut warmup = IndicatorWarmup::new();
+        warmup.add_requirement(WarmupRequirement::new(bar_type, 50).unwrap());
+        warmup.add_requirement(WarmupRequirement::new(bar_type, 200).unwrap());
+        warmup.add_requirement(WarmupRequirement::new(bar_type, 20).unwrap());
+
+        let pending = warmup.pending();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].count, 200);
+    }
+}
@@ -26,13 +26,21 @@
 //!
 //! - `python`: Enables Python bindings from `pyo3`
 //! - `redis`: Enables the Redis cache database and message bus backing implementations
+//! - `nats`: Enables the NATS message bus backing implementation
+//! - `zmq`: Enables the ZeroMQ message bus backing implementation
 //! - `sql`: Enables the SQL models and cache database
 
 #[cfg(feature = "python")]
 pub mod python;
 
+#[cfg(feature = "nats")]
+pub mod nats;
+
 #[cfg(feature = "redis")]
 pub mod redis;
 
 #[cfg(feature = "postgres")]
 pub mod sql;
+
+#[cfg(feature = "zmq")]
+pub mod zmq;
@@ -0,0 +1,202 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a NATS backed `MessageBusDatabase` implementation.
+
+pub mod msgbus;
+
+use std::{collections::HashMap, time::Duration};
+
+use async_nats::{Client, ConnectOptions};
+use nautilus_core::uuid::UUID4;
+use nautilus_model::identifiers::trader_id::TraderId;
+use serde_json::{json, Value};
+use tracing::debug;
+
+const NATS_DELIMITER: char = '.';
+const NATS_SINGLE_WILDCARD: &str = "*";
+const NATS_TAIL_WILDCARD: &str = ">";
+
+pub fn get_nats_url(database_config: &serde_json::Value) -> String {
+    let host = database_config
+        .get("host")
+        .and_then(|v| v.as_str())
+        .unwrap_or("127.0.0.1");
+    let port = database_config
+        .get("port")
+        .and_then(|v| {
+            v.as_u64()
+                .or_else(|| v.as_str().and_then(|s| s.parse().ok()))
+        })
+        .unwrap_or(4222);
+    let use_ssl = database_config
+        .get("ssl")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    format!(
+        "{}://{}:{}",
+        if use_ssl { "tls" } else { "nats" },
+        host,
+        port
+    )
+}
+
+pub async fn create_nats_client(database_config: &serde_json::Value) -> anyhow::Result<Client> {
+    let url = get_nats_url(database_config);
+    debug!("Connecting to {url}");
+
+    let mut options =
+        ConnectOptions::new().connection_timeout(get_timeout_duration(database_config, 20));
+
+    let username = database_config.get("username").and_then(|v| v.as_str());
+    let password = database_config.get("password").and_then(|v| v.as_str());
+    if let (Some(username), Some(password)) = (username, password) {
+        options = options.user_and_password(username.to_string(), password.to_string());
+    } else if let Some(token) = database_config.get("token").and_then(|v| v.as_str()) {
+        options = options.token(token.to_string());
+    }
+
+    if database_config
+        .get("ssl")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+    {
+        options = options.require_tls(true);
+    }
+
+    let client = options.connect(url).await?;
+    debug!("Connected to NATS");
+    Ok(client)
+}
+
+pub fn get_timeout_duration(database_config: &serde_json::Value, default: u64) -> Duration {
+    let timeout_seconds = database_config
+        .get("timeout")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(default);
+    Duration::from_secs(timeout_seconds)
+}
+
+/// Returns the subject prefix for the message bus, always terminated by the NATS delimiter.
+fn get_subject_prefix(
+    trader_id: TraderId,
+    instance_id: UUID4,
+    config: &HashMap<String, Value>,
+) -> String {
+    let mut prefix = String::new();
+
+    if let Some(json!(true)) = config.get("use_trader_prefix") {
+        prefix.push_str("trader-");
+    }
+
+    if let Some(json!(true)) = config.get("use_trader_id") {
+        prefix.push_str(trader_id.as_str());
+        prefix.push(NATS_DELIMITER);
+    }
+
+    if let Some(json!(true)) = config.get("use_instance_id") {
+        prefix.push_str(&format!("{instance_id}"));
+        prefix.push(NATS_DELIMITER);
+    }
+
+    let streams_prefix = config
+        .get("streams_prefix")
+        .expect("Invalid configuration: no `streams_prefix` key found")
+        .as_str()
+        .expect("Invalid configuration: `streams_prefix` is not a string");
+    prefix.push_str(streams_prefix);
+    prefix.push(NATS_DELIMITER);
+    prefix
+}
+
+/// Returns the JetStream stream name for the given subject `prefix`.
+///
+/// Stream names cannot contain the NATS delimiter or wildcards, so these are replaced.
+fn get_jetstream_name(prefix: &str) -> String {
+    prefix
+        .trim_end_matches(NATS_DELIMITER)
+        .replace([NATS_DELIMITER, '*', '>', ' '], "-")
+}
+
+/// Converts a message bus topic `pattern` to a NATS subject.
+///
+/// A trailing `*` matches any number of remaining tokens, so maps to the NATS tail
+/// wildcard `>`, whilst a `*` token elsewhere matches a single token as for NATS.
+#[must_use]
+pub fn topic_pattern_to_subject(prefix: &str, pattern: &str) -> String {
+    let mut tokens: Vec<&str> = pattern.split(NATS_DELIMITER).collect();
+    if let Some(last) = tokens.last_mut() {
+        if *last == NATS_SINGLE_WILDCARD {
+            *last = NATS_TAIL_WILDCARD;
+        }
+    }
+    format!("{prefix}{}", tokens.join("."))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_nats_url_default_values() {
+        let config = json!({});
+        assert_eq!(get_nats_url(&config), "nats://127.0.0.1:4222");
+    }
+
+    #[rstest]
+    fn test_get_nats_url_with_ssl() {
+        let config = json!({
+            "host": "example.com",
+            "port": "4223",
+            "ssl": true,
+        });
+        assert_eq!(get_nats_url(&config), "tls://example.com:4223");
+    }
+
+    #[rstest]
+    fn test_get_subject_prefix_with_trader_prefix_and_instance_id() {
+        let trader_id = TraderId::from("tester-123");
+        let instance_id = UUID4::new();
+        let mut config = HashMap::new();
+        config.insert("use_trader_prefix".to_string(), json!(true));
+        config.insert("use_trader_id".to_string(), json!(true));
+        config.insert("use_instance_id".to_string(), json!(true));
+        config.insert("streams_prefix".to_string(), json!("streams"));
+
+        let prefix = get_subject_prefix(trader_id, instance_id, &config);
+        assert_eq!(prefix, format!("trader-tester-123.{instance_id}.streams."));
+    }
+
+    #[rstest]
+    fn test_get_jetstream_name() {
+        assert_eq!(
+            get_jetstream_name("trader-tester-123.streams."),
+            "trader-tester-123-streams"
+        );
+    }
+
+    #[rstest]
+    #[case("data.quotes.BINANCE.ETHUSDT", "streams.data.quotes.BINANCE.ETHUSDT")]
+    #[case("data.quotes.*", "streams.data.quotes.>")]
+    #[case("data.*.BINANCE.*", "streams.data.*.BINANCE.>")]
+    #[case("*", "streams.>")]
+    fn test_topic_pattern_to_subject(#[case] pattern: &str, #[case] expected: &str) {
+        assert_eq!(topic_pattern_to_subject("streams.", pattern), expected);
+    }
+}
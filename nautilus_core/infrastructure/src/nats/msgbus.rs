@@ -0,0 +1,239 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use async_nats::{
+    jetstream::{self, stream},
+    Client, Message, Subscriber,
+};
+use nautilus_common::msgbus::{core::CLOSE_TOPIC, database::MessageBusDatabaseAdapter, BusMessage};
+use nautilus_core::uuid::UUID4;
+use nautilus_model::identifiers::trader_id::TraderId;
+use serde_json::Value;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tracing::{debug, error};
+
+use crate::nats::{
+    create_nats_client, get_jetstream_name, get_subject_prefix, topic_pattern_to_subject,
+};
+
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.infrastructure")
+)]
+pub struct NatsMessageBusDatabase {
+    pub trader_id: TraderId,
+    tx: UnboundedSender<BusMessage>,
+    handle: Option<JoinHandle<anyhow::Result<()>>>,
+}
+
+impl MessageBusDatabaseAdapter for NatsMessageBusDatabase {
+    type DatabaseType = NatsMessageBusDatabase;
+
+    fn new(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: HashMap<String, serde_json::Value>,
+    ) -> anyhow::Result<Self> {
+        let (tx, rx) = unbounded_channel::<BusMessage>();
+        let handle = Some(
+            thread::Builder::new()
+                .name("msgbus-nats".to_string())
+                .spawn(move || {
+                    let runtime = tokio::runtime::Builder::new_current_thread()
+                        .enable_all()
+                        .build()?;
+                    runtime.block_on(handle_messages(rx, trader_id, instance_id, config))
+                })
+                .expect("Error spawning `msgbus-nats` thread"),
+        );
+
+        Ok(Self {
+            trader_id,
+            tx,
+            handle,
+        })
+    }
+
+    fn publish(&self, topic: String, payload: Vec<u8>) -> anyhow::Result<()> {
+        let msg = BusMessage { topic, payload };
+        self.tx.send(msg).map_err(anyhow::Error::new)
+    }
+
+    fn close(&mut self) -> anyhow::Result<()> {
+        debug!("Closing message bus database adapter");
+
+        let msg = BusMessage {
+            topic: CLOSE_TOPIC.to_string(),
+            payload: vec![],
+        };
+        self.tx.send(msg).map_err(anyhow::Error::new)?;
+
+        if let Some(handle) = self.handle.take() {
+            debug!("Joining `msgbus-nats` thread");
+            handle.join().map_err(|e| anyhow::anyhow!("{:?}", e))?
+        } else {
+            Err(anyhow::anyhow!("message bus database already shutdown"))
+        }
+    }
+}
+
+pub async fn handle_messages(
+    mut rx: UnboundedReceiver<BusMessage>,
+    trader_id: TraderId,
+    instance_id: UUID4,
+    config: HashMap<String, Value>,
+) -> anyhow::Result<()> {
+    let database_config = config
+        .get("database")
+        .ok_or(anyhow::anyhow!("No database config"))?;
+    debug!("Creating msgbus NATS client");
+    let client = create_nats_client(database_config).await?;
+
+    let subject_prefix = get_subject_prefix(trader_id, instance_id, &config);
+
+    // JetStream persistence
+    let jetstream = match config.get("use_jetstream") {
+        Some(Value::Bool(true)) => Some(create_jetstream(&client, &subject_prefix, &config).await?),
+        _ => None,
+    };
+
+    // Continue to receive and handle messages until channel is hung up
+    // or the close topic is received.
+    while let Some(msg) = rx.recv().await {
+        if msg.topic == CLOSE_TOPIC {
+            rx.close();
+            break;
+        }
+
+        let subject = format!("{subject_prefix}{}", msg.topic);
+        let result = match jetstream {
+            Some(ref context) => publish_jetstream(context, subject, msg.payload).await,
+            None => client
+                .publish(subject, msg.payload.into())
+                .await
+                .map_err(anyhow::Error::from),
+        };
+
+        if let Err(e) = result {
+            error!("Error publishing to NATS: {e}");
+        }
+    }
+
+    client.flush().await.map_err(anyhow::Error::from)
+}
+
+async fn create_jetstream(
+    client: &Client,
+    subject_prefix: &str,
+    config: &HashMap<String, Value>,
+) -> anyhow::Result<jetstream::Context> {
+    let context = jetstream::new(client.clone());
+
+    let name = get_jetstream_name(subject_prefix);
+    let max_age_mins = config
+        .get("autotrim_mins")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0);
+    let stream_config = stream::Config {
+        name: name.clone(),
+        subjects: vec![topic_pattern_to_subject(subject_prefix, "*")],
+        max_age: Duration::from_secs(max_age_mins * 60), // Zero is unlimited
+        ..Default::default()
+    };
+
+    context
+        .get_or_create_stream(stream_config)
+        .await
+        .map_err(|e| anyhow::anyhow!("Error creating JetStream stream '{name}': {e}"))?;
+    debug!("Using JetStream stream '{name}'");
+
+    Ok(context)
+}
+
+async fn publish_jetstream(
+    context: &jetstream::Context,
+    subject: String,
+    payload: Vec<u8>,
+) -> anyhow::Result<()> {
+    // Wait for the server acknowledgement so that persistence failures are reported
+    context
+        .publish(subject, payload.into())
+        .await
+        .map_err(anyhow::Error::from)?
+        .await
+        .map_err(anyhow::Error::from)?;
+    Ok(())
+}
+
+/// Provides a NATS subscriber for the messages published by a [`NatsMessageBusDatabase`],
+/// using subject-based routing of the message bus topics.
+pub struct NatsMessageBusSubscriber {
+    client: Client,
+    subject_prefix: String,
+}
+
+impl NatsMessageBusSubscriber {
+    /// Creates a new [`NatsMessageBusSubscriber`] instance.
+    ///
+    /// The `config` is the same message bus configuration used for the publishing
+    /// [`NatsMessageBusDatabase`], so that both resolve the same subjects.
+    pub async fn connect(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: &HashMap<String, Value>,
+    ) -> anyhow::Result<Self> {
+        let database_config = config
+            .get("database")
+            .ok_or(anyhow::anyhow!("No database config"))?;
+        let client = create_nats_client(database_config).await?;
+
+        Ok(Self {
+            client,
+            subject_prefix: get_subject_prefix(trader_id, instance_id, config),
+        })
+    }
+
+    /// Subscribes to the messages for the given topic `pattern`.
+    ///
+    /// The pattern supports the NATS single token wildcard `*`, with a trailing `*`
+    /// matching all remaining tokens.
+    pub async fn subscribe(&self, pattern: &str) -> anyhow::Result<Subscriber> {
+        let subject = topic_pattern_to_subject(&self.subject_prefix, pattern);
+        debug!("Subscribing to {subject}");
+        self.client
+            .subscribe(subject)
+            .await
+            .map_err(anyhow::Error::from)
+    }
+
+    /// Converts the received NATS `msg` to a bus message, restoring the original topic.
+    #[must_use]
+    pub fn parse_message(&self, msg: &Message) -> BusMessage {
+        let subject = msg.subject.as_str();
+        BusMessage {
+            topic: subject
+                .strip_prefix(&self.subject_prefix)
+                .unwrap_or(subject)
+                .to_string(),
+            payload: msg.payload.to_vec(),
+        }
+    }
+}
@@ -15,12 +15,18 @@
 
 //! Python bindings from `pyo3`.
 
+#[cfg(feature = "nats")]
+pub mod nats;
+
 #[cfg(feature = "redis")]
 pub mod redis;
 
 #[cfg(feature = "postgres")]
 pub mod sql;
 
+#[cfg(feature = "zmq")]
+pub mod zmq;
+
 use pyo3::{prelude::*, pymodule};
 
 #[pymodule]
@@ -28,8 +34,14 @@ pub fn infrastructure(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     #[cfg(feature = "redis")]
     m.add_class::<crate::redis::cache::RedisCacheDatabase>()?;
     #[cfg(feature = "redis")]
+    m.add_class::<crate::redis::lease::RedisLeaderLease>()?;
+    #[cfg(feature = "redis")]
     m.add_class::<crate::redis::msgbus::RedisMessageBusDatabase>()?;
+    #[cfg(feature = "nats")]
+    m.add_class::<crate::nats::msgbus::NatsMessageBusDatabase>()?;
     #[cfg(feature = "postgres")]
     m.add_class::<crate::sql::cache_database::PostgresCacheDatabase>()?;
+    #[cfg(feature = "zmq")]
+    m.add_class::<crate::zmq::msgbus::ZmqMessageBusDatabase>()?;
     Ok(())
 }
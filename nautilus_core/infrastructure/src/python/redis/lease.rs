@@ -0,0 +1,67 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use nautilus_core::{
+    python::{to_pyruntime_err, to_pyvalue_err},
+    uuid::UUID4,
+};
+use nautilus_model::identifiers::trader_id::TraderId;
+use pyo3::prelude::*;
+
+use crate::redis::lease::{LeaseTransition, RedisLeaderLease};
+
+#[pymethods]
+impl RedisLeaderLease {
+    #[new]
+    fn py_new(trader_id: TraderId, instance_id: UUID4, config_json: Vec<u8>) -> PyResult<Self> {
+        let config: HashMap<String, serde_json::Value> =
+            serde_json::from_slice(&config_json).map_err(to_pyvalue_err)?;
+
+        Self::new(trader_id, instance_id, config).map_err(to_pyruntime_err)
+    }
+
+    #[getter]
+    #[pyo3(name = "is_leader")]
+    fn py_is_leader(&self) -> bool {
+        self.is_leader()
+    }
+
+    #[getter]
+    #[pyo3(name = "term")]
+    fn py_term(&self) -> u64 {
+        self.term()
+    }
+
+    #[pyo3(name = "holder")]
+    fn py_holder(&mut self) -> PyResult<Option<String>> {
+        self.holder().map_err(to_pyruntime_err)
+    }
+
+    /// Returns the new fencing term when promoted, otherwise `None`.
+    #[pyo3(name = "poll")]
+    fn py_poll(&mut self) -> PyResult<Option<u64>> {
+        match self.poll().map_err(to_pyruntime_err)? {
+            LeaseTransition::Promoted(term) => Ok(Some(term)),
+            LeaseTransition::Unchanged | LeaseTransition::Demoted => Ok(None),
+        }
+    }
+
+    #[pyo3(name = "release")]
+    fn py_release(&mut self) -> PyResult<()> {
+        self.release().map_err(to_pyruntime_err)
+    }
+}
@@ -18,4 +18,5 @@
 #![allow(warnings)] // non-local `impl` definition, temporary allow until pyo3 upgrade
 
 pub mod cache;
+pub mod lease;
 pub mod msgbus;
@@ -21,8 +21,10 @@ use std::{
 };
 
 use nautilus_common::{
-    cache::database::CacheDatabaseAdapter, enums::SerializationEncoding,
+    cache::database::CacheDatabaseAdapter,
+    enums::SerializationEncoding,
     interface::account::Account,
+    memory::{memory_gauge, MemorySubsystem},
 };
 use nautilus_core::{correctness::check_slice_not_empty, nanos::UnixNanos, uuid::UUID4};
 use nautilus_model::{
@@ -45,6 +47,7 @@ use crate::redis::{create_redis_connection, get_buffer_interval};
 
 // Error constants
 const FAILED_TX_CHANNEL: &str = "Failed to send to channel";
+const READ_ONLY: &str = "Cache database is read-only";
 
 // Redis constants
 const FLUSHDB: &str = "FLUSHDB";
@@ -117,6 +120,17 @@ impl DatabaseCommand {
             payload: None,
         }
     }
+
+    /// Returns the approximate memory (bytes) held by the command.
+    #[must_use]
+    pub fn approx_size_bytes(&self) -> usize {
+        let key = self.key.as_ref().map_or(0, String::len);
+        let payload = self
+            .payload
+            .as_ref()
+            .map_or(0, |p| p.iter().map(Vec::len).sum());
+        std::mem::size_of::<Self>() + key + payload
+    }
 }
 
 #[cfg_attr(
@@ -126,6 +140,7 @@ impl DatabaseCommand {
 pub struct RedisCacheDatabase {
     pub trader_id: TraderId,
     trader_key: String,
+    read_only: bool,
     conn: Connection,
     tx: Sender<DatabaseCommand>,
     handle: Option<JoinHandle<()>>,
@@ -133,6 +148,10 @@ pub struct RedisCacheDatabase {
 
 impl RedisCacheDatabase {
     /// Creates a new [`RedisCacheDatabase`] instance.
+    ///
+    /// If the config sets `read_only` then no cache-write connection is created, and all
+    /// write operations return an error. This allows a secondary process to attach to a
+    /// live trader's cache (e.g. for analytics) without any risk of issuing writes.
     pub fn new(
         trader_id: TraderId,
         instance_id: UUID4,
@@ -147,24 +166,50 @@ impl RedisCacheDatabase {
         let (tx, rx) = channel::<DatabaseCommand>();
         let trader_key = get_trader_key(trader_id, instance_id, &config);
         let trader_key_clone = trader_key.clone();
+        let read_only = get_read_only(&config);
 
-        let handle = thread::Builder::new()
-            .name("cache".to_string())
-            .spawn(move || {
-                Self::handle_messages(rx, trader_key_clone, config);
-            })
-            .expect("Error spawning `cache` thread");
+        let handle = if read_only {
+            debug!("Read-only, not creating cache-write redis connection");
+            None
+        } else {
+            let handle = thread::Builder::new()
+                .name("cache".to_string())
+                .spawn(move || {
+                    Self::handle_messages(rx, trader_key_clone, config);
+                })
+                .expect("Error spawning `cache` thread");
+            Some(handle)
+        };
 
         Ok(RedisCacheDatabase {
             trader_id,
             trader_key,
+            read_only,
             conn,
             tx,
-            handle: Some(handle),
+            handle,
         })
     }
 
+    /// Returns whether the database is read-only.
+    #[must_use]
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    fn check_writable(&self) -> anyhow::Result<()> {
+        if self.read_only {
+            anyhow::bail!(READ_ONLY);
+        }
+        Ok(())
+    }
+
     pub fn close(&mut self) -> anyhow::Result<()> {
+        if self.read_only {
+            debug!("Closed read-only cache database adapter");
+            return Ok(());
+        }
+
         debug!("Closing cache database adapter");
         self.tx
             .send(DatabaseCommand::close())
@@ -179,6 +224,7 @@ impl RedisCacheDatabase {
     }
 
     pub fn flushdb(&mut self) -> anyhow::Result<()> {
+        self.check_writable()?;
         match redis::cmd(FLUSHDB).query::<()>(&mut self.conn) {
             Ok(_) => Ok(()),
             Err(e) => Err(e.into()),
@@ -214,6 +260,7 @@ impl RedisCacheDatabase {
     }
 
     pub fn insert(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> anyhow::Result<()> {
+        self.check_writable()?;
         let op = DatabaseCommand::new(DatabaseOperation::Insert, key, payload);
         match self.tx.send(op) {
             Ok(_) => Ok(()),
@@ -222,6 +269,7 @@ impl RedisCacheDatabase {
     }
 
     pub fn update(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> anyhow::Result<()> {
+        self.check_writable()?;
         let op = DatabaseCommand::new(DatabaseOperation::Update, key, payload);
         match self.tx.send(op) {
             Ok(_) => Ok(()),
@@ -230,6 +278,7 @@ impl RedisCacheDatabase {
     }
 
     pub fn delete(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> anyhow::Result<()> {
+        self.check_writable()?;
         let op = DatabaseCommand::new(DatabaseOperation::Delete, key, payload);
         match self.tx.send(op) {
             Ok(_) => Ok(()),
@@ -266,6 +315,8 @@ impl RedisCacheDatabase {
                             drop(rx);
                             break;
                         }
+                        memory_gauge(MemorySubsystem::CacheWriterQueue)
+                            .add(msg.approx_size_bytes());
                         buffer.push_back(msg)
                     }
                     Err(TryRecvError::Empty) => thread::sleep(recv_interval),
@@ -282,10 +333,19 @@ impl RedisCacheDatabase {
 }
 
 fn drain_buffer(conn: &mut Connection, trader_key: &str, buffer: &mut VecDeque<DatabaseCommand>) {
+    let buffered_bytes: usize = buffer.iter().map(DatabaseCommand::approx_size_bytes).sum();
+    memory_gauge(MemorySubsystem::CacheWriterQueue).sub(buffered_bytes);
+
     let mut pipe = redis::pipe();
     pipe.atomic();
 
     for msg in buffer.drain(..) {
+        #[cfg(feature = "chaos")]
+        if nautilus_core::chaos::should_drop_cache_write() {
+            debug!("Chaos: dropped cache write");
+            continue;
+        }
+
         let key = msg.key.expect("Null command `key`");
         let collection = match get_collection_key(&key) {
             Ok(collection) => collection,
@@ -634,6 +694,10 @@ fn get_trader_key(
     key
 }
 
+fn get_read_only(config: &HashMap<String, serde_json::Value>) -> bool {
+    matches!(config.get("read_only"), Some(json!(true)))
+}
+
 fn get_collection_key(key: &str) -> anyhow::Result<&str> {
     key.split_once(DELIMITER)
         .map(|(collection, _)| collection)
@@ -681,6 +745,14 @@ pub struct RedisCacheDatabaseAdapter {
     database: RedisCacheDatabase,
 }
 
+impl RedisCacheDatabaseAdapter {
+    /// Creates a new [`RedisCacheDatabaseAdapter`] instance.
+    #[must_use]
+    pub fn new(database: RedisCacheDatabase, encoding: SerializationEncoding) -> Self {
+        Self { encoding, database }
+    }
+}
+
 #[allow(dead_code)] // Under development
 #[allow(unused)] // Under development
 impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
@@ -876,6 +948,20 @@ mod tests {
 
     use super::*;
 
+    #[rstest]
+    fn test_database_command_approx_size_bytes() {
+        let command = DatabaseCommand::new(
+            DatabaseOperation::Insert,
+            "orders:O-123".to_string(),
+            Some(vec![vec![0; 10], vec![0; 5]]),
+        );
+
+        assert_eq!(
+            command.approx_size_bytes(),
+            std::mem::size_of::<DatabaseCommand>() + 12 + 15
+        );
+    }
+
     #[rstest]
     fn test_get_trader_key_with_prefix_and_instance_id() {
         let trader_id = TraderId::from("tester-123");
@@ -889,6 +975,19 @@ mod tests {
         assert!(key.ends_with(&instance_id.to_string()));
     }
 
+    #[rstest]
+    #[case(None, false)]
+    #[case(Some(json!(false)), false)]
+    #[case(Some(json!(true)), true)]
+    fn test_get_read_only(#[case] value: Option<Value>, #[case] expected: bool) {
+        let mut config = HashMap::new();
+        if let Some(value) = value {
+            config.insert("read_only".to_string(), value);
+        }
+
+        assert_eq!(get_read_only(&config), expected);
+    }
+
     #[rstest]
     fn test_get_collection_key_valid() {
         let key = "collection:123";
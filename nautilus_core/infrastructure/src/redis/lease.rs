@@ -0,0 +1,299 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a Redis backed leader lease for high-availability failover between nodes.
+//!
+//! Nodes sharing a trader ID compete for a single lease key. The leader renews the lease on
+//! every poll, and a standby acquires the lease once it expires (i.e. the leader has stopped
+//! heartbeating). Every promotion increments a fencing term, so stale leaders can be detected.
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use nautilus_core::uuid::UUID4;
+use nautilus_model::identifiers::trader_id::TraderId;
+use redis::{Commands, Connection, Script};
+use serde_json::Value;
+use tracing::{info, warn};
+
+use crate::redis::create_redis_connection;
+
+const DELIMITER: char = ':';
+const LEASE_KEY: &str = "leader";
+const TERM_KEY: &str = "leader_term";
+const DEFAULT_LEASE_TTL_MS: u64 = 5_000;
+// The fraction of the TTL held back for clock drift between the node and Redis
+const LEASE_SAFETY_MARGIN_DIVISOR: u32 = 5;
+
+// Only extend or delete the lease when still held by this node
+const RENEW_SCRIPT: &str = r"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('PEXPIRE', KEYS[1], ARGV[2])
+end
+return 0
+";
+const RELEASE_SCRIPT: &str = r"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+";
+
+/// The role of a node participating in leader election.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LeaseRole {
+    /// The node holds the lease and is the active trading node.
+    Leader,
+    /// The node is waiting to acquire the lease.
+    Standby,
+}
+
+/// A change of role resulting from polling the lease.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LeaseTransition {
+    /// The role of the node did not change.
+    Unchanged,
+    /// The node acquired the lease with the given fencing term.
+    Promoted(u64),
+    /// The node lost the lease and must stop trading.
+    Demoted,
+}
+
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.infrastructure")
+)]
+pub struct RedisLeaderLease {
+    pub trader_id: TraderId,
+    pub instance_id: UUID4,
+    conn: Connection,
+    lease_key: String,
+    term_key: String,
+    ttl: Duration,
+    role: LeaseRole,
+    term: u64,
+    last_renewal: Option<Instant>,
+}
+
+impl RedisLeaderLease {
+    /// Creates a new [`RedisLeaderLease`] instance in the standby role.
+    ///
+    /// The lease time-to-live is taken from the `lease_ttl_ms` config key (default 5 seconds).
+    pub fn new(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: HashMap<String, Value>,
+    ) -> anyhow::Result<Self> {
+        let database_config = config
+            .get("database")
+            .ok_or(anyhow::anyhow!("No database config"))?;
+        let ttl_ms = config
+            .get("lease_ttl_ms")
+            .and_then(Value::as_u64)
+            .unwrap_or(DEFAULT_LEASE_TTL_MS);
+        if ttl_ms == 0 {
+            anyhow::bail!("Invalid `lease_ttl_ms`, was {ttl_ms}");
+        }
+
+        let conn = create_redis_connection(database_config)?;
+        let prefix = get_lease_prefix(trader_id, &config);
+
+        Ok(Self {
+            trader_id,
+            instance_id,
+            conn,
+            lease_key: format!("{prefix}{DELIMITER}{LEASE_KEY}"),
+            term_key: format!("{prefix}{DELIMITER}{TERM_KEY}"),
+            ttl: Duration::from_millis(ttl_ms),
+            role: LeaseRole::Standby,
+            term: 0,
+            last_renewal: None,
+        })
+    }
+
+    /// Returns the current role of the node.
+    #[must_use]
+    pub fn role(&self) -> LeaseRole {
+        self.role
+    }
+
+    /// Returns whether the node currently holds the lease.
+    #[must_use]
+    pub fn is_leader(&self) -> bool {
+        self.role == LeaseRole::Leader
+    }
+
+    /// Returns the fencing term of the most recent promotion (zero if never promoted).
+    #[must_use]
+    pub fn term(&self) -> u64 {
+        self.term
+    }
+
+    /// Returns the lease time-to-live.
+    #[must_use]
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the instance ID of the node currently holding the lease (if any).
+    pub fn holder(&mut self) -> anyhow::Result<Option<String>> {
+        Ok(self.conn.get(&self.lease_key)?)
+    }
+
+    /// Polls the lease, renewing it when leader or attempting to acquire it when standby.
+    ///
+    /// The lease is timed from just before the command is sent, as Redis may apply it at any
+    /// point during the round-trip. A leader which cannot reach Redis demotes itself a safety
+    /// margin before the lease would have expired, as a standby may then acquire it.
+    pub fn poll(&mut self) -> anyhow::Result<LeaseTransition> {
+        let sent = Instant::now();
+        match self.role {
+            LeaseRole::Leader => match self.renew() {
+                Ok(true) => {
+                    self.last_renewal = Some(sent);
+                    Ok(LeaseTransition::Unchanged)
+                }
+                Ok(false) => {
+                    self.demote();
+                    Ok(LeaseTransition::Demoted)
+                }
+                Err(e) => {
+                    if is_lease_expired(self.last_renewal, self.ttl, Instant::now()) {
+                        warn!("Failed to renew leader lease: {e}");
+                        self.demote();
+                        Ok(LeaseTransition::Demoted)
+                    } else {
+                        Err(e)
+                    }
+                }
+            },
+            LeaseRole::Standby => {
+                if !self.try_acquire()? {
+                    return Ok(LeaseTransition::Unchanged);
+                }
+                self.term = self.conn.incr(&self.term_key, 1)?;
+                self.role = LeaseRole::Leader;
+                self.last_renewal = Some(sent);
+                info!("Promoted to leader (term {})", self.term);
+                Ok(LeaseTransition::Promoted(self.term))
+            }
+        }
+    }
+
+    /// Releases the lease if held, allowing a standby to take over without waiting for expiry.
+    pub fn release(&mut self) -> anyhow::Result<()> {
+        if self.role == LeaseRole::Leader {
+            let _: i64 = Script::new(RELEASE_SCRIPT)
+                .key(&self.lease_key)
+                .arg(self.instance_id.to_string())
+                .invoke(&mut self.conn)?;
+            self.demote();
+        }
+        Ok(())
+    }
+
+    fn try_acquire(&mut self) -> anyhow::Result<bool> {
+        let result: Option<String> = redis::cmd("SET")
+            .arg(&self.lease_key)
+            .arg(self.instance_id.to_string())
+            .arg("NX")
+            .arg("PX")
+            .arg(self.ttl_ms())
+            .query(&mut self.conn)?;
+        Ok(result.is_some())
+    }
+
+    fn renew(&mut self) -> anyhow::Result<bool> {
+        let renewed: i64 = Script::new(RENEW_SCRIPT)
+            .key(&self.lease_key)
+            .arg(self.instance_id.to_string())
+            .arg(self.ttl_ms())
+            .invoke(&mut self.conn)?;
+        Ok(renewed == 1)
+    }
+
+    fn demote(&mut self) {
+        self.role = LeaseRole::Standby;
+        self.last_renewal = None;
+        warn!("Demoted to standby (term {})", self.term);
+    }
+
+    fn ttl_ms(&self) -> u64 {
+        u64::try_from(self.ttl.as_millis()).unwrap_or(u64::MAX)
+    }
+}
+
+fn get_lease_prefix(trader_id: TraderId, config: &HashMap<String, Value>) -> String {
+    let mut prefix = String::new();
+
+    if let Some(Value::Bool(true)) = config.get("use_trader_prefix") {
+        prefix.push_str("trader-");
+    }
+
+    prefix.push_str(trader_id.as_str());
+    prefix
+}
+
+/// Returns whether the lease renewed at `last_renewal` should be treated as expired at `now`,
+/// allowing a safety margin below the `ttl`.
+fn is_lease_expired(last_renewal: Option<Instant>, ttl: Duration, now: Instant) -> bool {
+    let validity = ttl - ttl / LEASE_SAFETY_MARGIN_DIVISOR;
+    last_renewal.map_or(true, |ts| now.saturating_duration_since(ts) >= validity)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+
+    #[rstest]
+    #[case(true, "trader-tester-123")]
+    #[case(false, "tester-123")]
+    fn test_get_lease_prefix(#[case] use_trader_prefix: bool, #[case] expected: &str) {
+        let mut config = HashMap::new();
+        config.insert("use_trader_prefix".to_string(), json!(use_trader_prefix));
+
+        let prefix = get_lease_prefix(TraderId::from("tester-123"), &config);
+
+        assert_eq!(prefix, expected);
+    }
+
+    #[rstest]
+    fn test_is_lease_expired() {
+        let ttl = Duration::from_millis(100);
+        let renewed = Instant::now();
+
+        assert!(is_lease_expired(None, ttl, renewed));
+        assert!(!is_lease_expired(Some(renewed), ttl, renewed));
+        assert!(!is_lease_expired(
+            Some(renewed),
+            ttl,
+            renewed + Duration::from_millis(79)
+        ));
+        assert!(is_lease_expired(
+            Some(renewed),
+            ttl,
+            renewed + Duration::from_millis(80)
+        ));
+        assert!(is_lease_expired(Some(renewed), ttl, renewed + ttl));
+    }
+}
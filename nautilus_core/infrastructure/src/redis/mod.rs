@@ -16,6 +16,7 @@
 //! Provides a Redis backed `CacheDatabase` and `MessageBusDatabase` implementation.
 
 pub mod cache;
+pub mod lease;
 pub mod msgbus;
 
 use std::{collections::HashMap, time::Duration};
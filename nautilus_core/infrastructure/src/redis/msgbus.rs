@@ -14,7 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     sync::mpsc::{channel, Receiver, Sender, TryRecvError},
     thread::{self, JoinHandle},
     time::{Duration, Instant},
@@ -23,7 +23,10 @@ use std::{
 use nautilus_common::msgbus::{core::CLOSE_TOPIC, database::MessageBusDatabaseAdapter, BusMessage};
 use nautilus_core::{time::duration_since_unix_epoch, uuid::UUID4};
 use nautilus_model::identifiers::trader_id::TraderId;
-use redis::*;
+use redis::{
+    streams::{StreamId, StreamRangeReply, StreamReadOptions, StreamReadReply},
+    *,
+};
 use serde_json::Value;
 use tracing::{debug, error};
 
@@ -32,6 +35,9 @@ use crate::redis::{create_redis_connection, get_buffer_interval, get_stream_name
 const XTRIM: &str = "XTRIM";
 const MINID: &str = "MINID";
 const TRIM_BUFFER_SECONDS: u64 = 60;
+const PAYLOAD_FIELD: &str = "payload";
+const BUSYGROUP: &str = "BUSYGROUP";
+const STREAM_NEW_ENTRIES_ID: &str = ">";
 
 #[cfg_attr(
     feature = "python",
@@ -116,6 +122,13 @@ pub fn handle_messages(
     };
     let mut last_trim_index: HashMap<String, usize> = HashMap::new();
 
+    // Consumer groups
+    let consumer_group = config
+        .get("consumer_group")
+        .and_then(|v| v.as_str())
+        .map(ToString::to_string);
+    let mut grouped_streams: HashSet<String> = HashSet::new();
+
     // Buffering
     let mut buffer: VecDeque<BusMessage> = VecDeque::new();
     let mut last_drain = Instant::now();
@@ -129,6 +142,8 @@ pub fn handle_messages(
                 &stream_name,
                 autotrim_duration,
                 &mut last_trim_index,
+                consumer_group.as_deref(),
+                &mut grouped_streams,
                 &mut buffer,
             )?;
             last_drain = Instant::now();
@@ -156,6 +171,8 @@ pub fn handle_messages(
             &stream_name,
             autotrim_duration,
             &mut last_trim_index,
+            consumer_group.as_deref(),
+            &mut grouped_streams,
             &mut buffer,
         )?;
     }
@@ -168,6 +185,8 @@ fn drain_buffer(
     stream_name: &str,
     autotrim_duration: Option<Duration>,
     last_trim_index: &mut HashMap<String, usize>,
+    consumer_group: Option<&str>,
+    grouped_streams: &mut HashSet<String>,
     buffer: &mut VecDeque<BusMessage>,
 ) -> anyhow::Result<()> {
     let mut pipe = redis::pipe();
@@ -175,7 +194,17 @@ fn drain_buffer(
 
     for msg in buffer.drain(..) {
         let key = format!("{stream_name}{}", &msg.topic);
-        let items: Vec<(&str, &Vec<u8>)> = vec![("payload", &msg.payload)];
+
+        // Ensure the consumer group exists before the first entry is added,
+        // so that readers of the group receive the stream from its beginning.
+        if let Some(group) = consumer_group {
+            if !grouped_streams.contains(&key) {
+                create_consumer_group(conn, &key, group, "0")?;
+                grouped_streams.insert(key.clone());
+            }
+        }
+
+        let items: Vec<(&str, &Vec<u8>)> = vec![(PAYLOAD_FIELD, &msg.payload)];
         pipe.xadd(&key, "*", &items);
 
         if autotrim_duration.is_none() {
@@ -207,3 +236,272 @@ fn drain_buffer(
 
     pipe.query::<()>(conn).map_err(anyhow::Error::from)
 }
+
+/// Creates the consumer `group` for the stream at `key` starting from the entry `start_id`.
+///
+/// The stream is created if it does not yet exist, and an already existing group is not an error.
+pub fn create_consumer_group(
+    conn: &mut Connection,
+    key: &str,
+    group: &str,
+    start_id: &str,
+) -> anyhow::Result<()> {
+    let result: RedisResult<()> = conn.xgroup_create_mkstream(key, group, start_id);
+    match result {
+        Ok(()) => {
+            debug!("Created consumer group '{group}' for stream '{key}'");
+            Ok(())
+        }
+        Err(e) if e.code() == Some(BUSYGROUP) => Ok(()),
+        Err(e) => Err(anyhow::Error::from(e)),
+    }
+}
+
+/// Returns the topic for the given stream `key`, stripping the `stream_name` prefix.
+fn topic_from_stream_key<'a>(stream_name: &str, key: &'a str) -> &'a str {
+    key.strip_prefix(stream_name).unwrap_or(key)
+}
+
+fn parse_stream_entry(stream_name: &str, key: &str, entry: &StreamId) -> Option<StreamMessage> {
+    let payload: Vec<u8> = match entry.get(PAYLOAD_FIELD) {
+        Some(payload) => payload,
+        None => {
+            error!("Stream entry '{}' on '{key}' has no payload", entry.id);
+            return None;
+        }
+    };
+
+    Some(StreamMessage {
+        id: entry.id.clone(),
+        stream: key.to_string(),
+        msg: BusMessage {
+            topic: topic_from_stream_key(stream_name, key).to_string(),
+            payload,
+        },
+    })
+}
+
+/// Represents a bus message read back from a Redis stream.
+#[derive(Clone, Debug)]
+pub struct StreamMessage {
+    /// The stream entry ID assigned by Redis on `XADD`.
+    pub id: String,
+    /// The stream key the entry was read from.
+    pub stream: String,
+    /// The bus message for the entry.
+    pub msg: BusMessage,
+}
+
+/// Provides a Redis Streams consumer for tailing and replaying the messages published
+/// by a [`RedisMessageBusDatabase`].
+///
+/// Consumers within the same group share the message flow (each entry is delivered to one
+/// consumer of the group), whilst separate groups each receive the full flow. Entries read
+/// through a group remain pending until acknowledged, and can be replayed from any entry ID.
+pub struct RedisMessageBusConsumer {
+    /// The consumer group name.
+    pub group: String,
+    /// The consumer name within the group.
+    pub consumer: String,
+    stream_name: String,
+    conn: Connection,
+}
+
+impl RedisMessageBusConsumer {
+    /// Creates a new [`RedisMessageBusConsumer`] instance.
+    ///
+    /// The `config` is the same message bus configuration used for the publishing
+    /// [`RedisMessageBusDatabase`], so that both resolve the same stream names.
+    pub fn new(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: &HashMap<String, Value>,
+        group: &str,
+        consumer: &str,
+    ) -> anyhow::Result<Self> {
+        let database_config = config
+            .get("database")
+            .ok_or(anyhow::anyhow!("No database config"))?;
+        debug!("Creating msgbus consumer redis connection");
+        let conn = create_redis_connection(database_config)?;
+
+        Ok(Self {
+            group: group.to_string(),
+            consumer: consumer.to_string(),
+            stream_name: get_stream_name(trader_id, instance_id, config),
+            conn,
+        })
+    }
+
+    /// Returns the stream key for the given `topic`.
+    #[must_use]
+    pub fn stream_key(&self, topic: &str) -> String {
+        format!("{}{topic}", self.stream_name)
+    }
+
+    /// Returns all stream keys currently existing for the message bus.
+    pub fn stream_keys(&mut self) -> anyhow::Result<Vec<String>> {
+        let pattern = format!("{}*", self.stream_name);
+        let keys: Vec<String> = self.conn.scan_match(pattern)?.collect();
+        Ok(keys)
+    }
+
+    /// Joins the consumer group for the stream of each of the given `topics`.
+    ///
+    /// The `start_id` determines where a newly created group starts reading from,
+    /// use "0" to replay the full stream or "$" to receive only new entries.
+    pub fn join(&mut self, topics: &[&str], start_id: &str) -> anyhow::Result<()> {
+        for topic in topics {
+            let key = self.stream_key(topic);
+            create_consumer_group(&mut self.conn, &key, &self.group, start_id)?;
+        }
+        Ok(())
+    }
+
+    /// Reads up to `count` new entries per topic which have not yet been delivered to any
+    /// consumer of the group, blocking for up to `block_ms` milliseconds if none are available.
+    pub fn read(
+        &mut self,
+        topics: &[&str],
+        count: usize,
+        block_ms: Option<usize>,
+    ) -> anyhow::Result<Vec<StreamMessage>> {
+        self.read_group(topics, STREAM_NEW_ENTRIES_ID, count, block_ms)
+    }
+
+    /// Reads up to `count` entries per topic which were delivered to this consumer
+    /// but not yet acknowledged, for replay after a consumer restart.
+    pub fn read_pending(
+        &mut self,
+        topics: &[&str],
+        count: usize,
+    ) -> anyhow::Result<Vec<StreamMessage>> {
+        self.read_group(topics, "0", count, None)
+    }
+
+    /// Acknowledges the entries with the given `ids` on the stream for `topic`.
+    ///
+    /// Returns the number of entries acknowledged.
+    pub fn ack(&mut self, topic: &str, ids: &[String]) -> anyhow::Result<usize> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        let key = self.stream_key(topic);
+        let count: usize = self.conn.xack(&key, &self.group, ids)?;
+        Ok(count)
+    }
+
+    /// Replays the entries for `topic` between the `start` and `end` entry IDs (inclusive)
+    /// without affecting the state of the consumer group.
+    ///
+    /// Use "-" and "+" for the minimum and maximum possible IDs respectively.
+    pub fn replay(
+        &mut self,
+        topic: &str,
+        start: &str,
+        end: &str,
+        count: Option<usize>,
+    ) -> anyhow::Result<Vec<StreamMessage>> {
+        let key = self.stream_key(topic);
+        let reply: StreamRangeReply = match count {
+            Some(count) => self.conn.xrange_count(&key, start, end, count)?,
+            None => self.conn.xrange(&key, start, end)?,
+        };
+
+        Ok(reply
+            .ids
+            .iter()
+            .filter_map(|entry| parse_stream_entry(&self.stream_name, &key, entry))
+            .collect())
+    }
+
+    fn read_group(
+        &mut self,
+        topics: &[&str],
+        id: &str,
+        count: usize,
+        block_ms: Option<usize>,
+    ) -> anyhow::Result<Vec<StreamMessage>> {
+        if topics.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let keys: Vec<String> = topics.iter().map(|t| self.stream_key(t)).collect();
+        let ids: Vec<&str> = vec![id; keys.len()];
+
+        let mut opts = StreamReadOptions::default()
+            .group(&self.group, &self.consumer)
+            .count(count);
+        if let Some(block_ms) = block_ms {
+            opts = opts.block(block_ms);
+        }
+
+        // A blocking read which times out returns nil
+        let reply: Option<StreamReadReply> = self.conn.xread_options(&keys, &ids, &opts)?;
+
+        let mut messages = Vec::new();
+        for stream in reply.map(|r| r.keys).unwrap_or_default() {
+            for entry in &stream.ids {
+                if let Some(msg) = parse_stream_entry(&self.stream_name, &stream.key, entry) {
+                    messages.push(msg);
+                }
+            }
+        }
+
+        Ok(messages)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_topic_from_stream_key() {
+        let topic = topic_from_stream_key(
+            "trader-tester-123:streams:",
+            "trader-tester-123:streams:data.quotes",
+        );
+        assert_eq!(topic, "data.quotes");
+    }
+
+    #[rstest]
+    fn test_topic_from_stream_key_without_prefix() {
+        let topic = topic_from_stream_key("streams:", "other:data.quotes");
+        assert_eq!(topic, "other:data.quotes");
+    }
+
+    #[rstest]
+    fn test_parse_stream_entry() {
+        let mut map = HashMap::new();
+        map.insert(
+            PAYLOAD_FIELD.to_string(),
+            redis::Value::Data(b"hello".to_vec()),
+        );
+        let entry = StreamId {
+            id: "1-0".to_string(),
+            map,
+        };
+
+        let msg = parse_stream_entry("streams:", "streams:events", &entry).unwrap();
+        assert_eq!(msg.id, "1-0");
+        assert_eq!(msg.stream, "streams:events");
+        assert_eq!(msg.msg.topic, "events");
+        assert_eq!(msg.msg.payload, b"hello".to_vec());
+    }
+
+    #[rstest]
+    fn test_parse_stream_entry_without_payload() {
+        let entry = StreamId {
+            id: "1-0".to_string(),
+            map: HashMap::new(),
+        };
+
+        assert!(parse_stream_entry("streams:", "streams:events", &entry).is_none());
+    }
+}
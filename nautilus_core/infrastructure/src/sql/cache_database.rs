@@ -69,6 +69,18 @@ async fn drain_buffer(pool: &PgPool, buffer: &mut VecDeque<DatabaseQuery>) {
                 DatabaseQueries::add_currency(pool, currency).await.unwrap();
             }
             DatabaseQuery::AddInstrument(instrument_any) => match instrument_any {
+                InstrumentAny::Betting(instrument) => {
+                    log::error!(
+                        "Instrument type not yet supported for PostgreSQL: {}",
+                        instrument.id
+                    );
+                }
+                InstrumentAny::BinaryOption(instrument) => {
+                    log::error!(
+                        "Instrument type not yet supported for PostgreSQL: {}",
+                        instrument.id
+                    );
+                }
                 InstrumentAny::CryptoFuture(instrument) => {
                     DatabaseQueries::add_instrument(pool, "CRYPTO_FUTURE", Box::new(instrument))
                         .await
@@ -0,0 +1,170 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a ZeroMQ backed `MessageBusDatabase` implementation.
+//!
+//! Messages are published on a PUB socket as two frame messages of the prefixed topic
+//! followed by the payload, so that subscribers in any language with a ZeroMQ binding
+//! can filter by topic prefix.
+
+pub mod msgbus;
+
+use std::collections::HashMap;
+
+use nautilus_core::uuid::UUID4;
+use nautilus_model::identifiers::trader_id::TraderId;
+use serde_json::{json, Value};
+
+const ZMQ_DELIMITER: char = '.';
+const ZMQ_WILDCARD: char = '*';
+
+/// Returns the endpoint for the PUB socket from the `database_config`.
+///
+/// An explicit `endpoint` takes precedence (e.g. `ipc:///tmp/nautilus.ipc`), otherwise
+/// a TCP endpoint is built from the `host` and `port`.
+pub fn get_zmq_endpoint(database_config: &Value) -> String {
+    if let Some(endpoint) = database_config.get("endpoint").and_then(|v| v.as_str()) {
+        return endpoint.to_string();
+    }
+
+    let host = database_config
+        .get("host")
+        .and_then(|v| v.as_str())
+        .unwrap_or("127.0.0.1");
+    let port = database_config
+        .get("port")
+        .and_then(|v| {
+            v.as_u64()
+                .or_else(|| v.as_str().and_then(|s| s.parse().ok()))
+        })
+        .unwrap_or(5555);
+
+    format!("tcp://{host}:{port}")
+}
+
+/// Returns the topic prefix for the message bus, always terminated by the delimiter.
+fn get_topic_prefix(
+    trader_id: TraderId,
+    instance_id: UUID4,
+    config: &HashMap<String, Value>,
+) -> String {
+    let mut prefix = String::new();
+
+    if let Some(json!(true)) = config.get("use_trader_prefix") {
+        prefix.push_str("trader-");
+    }
+
+    if let Some(json!(true)) = config.get("use_trader_id") {
+        prefix.push_str(trader_id.as_str());
+        prefix.push(ZMQ_DELIMITER);
+    }
+
+    if let Some(json!(true)) = config.get("use_instance_id") {
+        prefix.push_str(&format!("{instance_id}"));
+        prefix.push(ZMQ_DELIMITER);
+    }
+
+    let streams_prefix = config
+        .get("streams_prefix")
+        .expect("Invalid configuration: no `streams_prefix` key found")
+        .as_str()
+        .expect("Invalid configuration: `streams_prefix` is not a string");
+    prefix.push_str(streams_prefix);
+    prefix.push(ZMQ_DELIMITER);
+    prefix
+}
+
+/// Converts a message bus topic `pattern` to a ZeroMQ subscription.
+///
+/// ZeroMQ only filters on a byte prefix, so the subscription is the pattern up to its
+/// first wildcard. Any remaining wildcards must be matched by the subscriber.
+#[must_use]
+pub fn topic_pattern_to_subscription(prefix: &str, pattern: &str) -> String {
+    let literal = pattern
+        .find(['*', '?'])
+        .map_or(pattern, |index| &pattern[..index]);
+    format!("{prefix}{literal}")
+}
+
+/// Returns whether the `pattern` requires matching beyond the ZeroMQ prefix filter.
+fn requires_filter(pattern: &str) -> bool {
+    match pattern.find(['*', '?']) {
+        Some(index) => !(index == pattern.len() - 1 && pattern.ends_with(ZMQ_WILDCARD)),
+        None => true, // Exact topics must not match longer topics sharing the prefix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_zmq_endpoint_default_values() {
+        let config = json!({});
+        assert_eq!(get_zmq_endpoint(&config), "tcp://127.0.0.1:5555");
+    }
+
+    #[rstest]
+    fn test_get_zmq_endpoint_with_host_and_port() {
+        let config = json!({
+            "host": "0.0.0.0",
+            "port": "6000",
+        });
+        assert_eq!(get_zmq_endpoint(&config), "tcp://0.0.0.0:6000");
+    }
+
+    #[rstest]
+    fn test_get_zmq_endpoint_with_explicit_endpoint() {
+        let config = json!({
+            "endpoint": "ipc:///tmp/nautilus.ipc",
+            "port": 6000,
+        });
+        assert_eq!(get_zmq_endpoint(&config), "ipc:///tmp/nautilus.ipc");
+    }
+
+    #[rstest]
+    fn test_get_topic_prefix_with_trader_id() {
+        let trader_id = TraderId::from("tester-123");
+        let instance_id = UUID4::new();
+        let mut config = HashMap::new();
+        config.insert("use_trader_prefix".to_string(), json!(true));
+        config.insert("use_trader_id".to_string(), json!(true));
+        config.insert("streams_prefix".to_string(), json!("streams"));
+
+        let prefix = get_topic_prefix(trader_id, instance_id, &config);
+        assert_eq!(prefix, "trader-tester-123.streams.");
+    }
+
+    #[rstest]
+    #[case(
+        "data.quotes.BINANCE.ETHUSDT",
+        "streams.data.quotes.BINANCE.ETHUSDT",
+        true
+    )]
+    #[case("data.quotes.*", "streams.data.quotes.", false)]
+    #[case("data.*.BINANCE.*", "streams.data.", true)]
+    #[case("*", "streams.", false)]
+    fn test_topic_pattern_to_subscription(
+        #[case] pattern: &str,
+        #[case] expected: &str,
+        #[case] filtered: bool,
+    ) {
+        assert_eq!(topic_pattern_to_subscription("streams.", pattern), expected);
+        assert_eq!(requires_filter(pattern), filtered);
+    }
+}
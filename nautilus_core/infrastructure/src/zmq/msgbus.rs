@@ -0,0 +1,312 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    sync::mpsc,
+    thread::{self, JoinHandle},
+};
+
+use nautilus_common::msgbus::{
+    core::{is_matching, CLOSE_TOPIC},
+    database::MessageBusDatabaseAdapter,
+    BusMessage,
+};
+use nautilus_core::uuid::UUID4;
+use nautilus_model::identifiers::trader_id::TraderId;
+use serde_json::Value;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tracing::{debug, error, warn};
+use ustr::Ustr;
+use zeromq::{PubSocket, Socket, SocketRecv, SocketSend, SubSocket, ZmqMessage};
+
+use crate::zmq::{
+    get_topic_prefix, get_zmq_endpoint, requires_filter, topic_pattern_to_subscription,
+};
+
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.infrastructure")
+)]
+pub struct ZmqMessageBusDatabase {
+    pub trader_id: TraderId,
+    endpoint: String,
+    tx: UnboundedSender<BusMessage>,
+    handle: Option<JoinHandle<anyhow::Result<()>>>,
+}
+
+impl ZmqMessageBusDatabase {
+    /// Returns the endpoint the PUB socket is bound to.
+    ///
+    /// When binding to port zero this is the resolved endpoint with the assigned port.
+    #[must_use]
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+}
+
+impl MessageBusDatabaseAdapter for ZmqMessageBusDatabase {
+    type DatabaseType = ZmqMessageBusDatabase;
+
+    fn new(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: HashMap<String, serde_json::Value>,
+    ) -> anyhow::Result<Self> {
+        let database_config = config
+            .get("database")
+            .ok_or(anyhow::anyhow!("No database config"))?;
+        let endpoint = get_zmq_endpoint(database_config);
+        let topic_prefix = get_topic_prefix(trader_id, instance_id, &config);
+
+        let (tx, rx) = unbounded_channel::<BusMessage>();
+        let (bind_tx, bind_rx) = mpsc::channel::<String>();
+        let handle = thread::Builder::new()
+            .name("msgbus-zmq".to_string())
+            .spawn(move || {
+                let runtime = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()?;
+                runtime.block_on(handle_messages(rx, endpoint, topic_prefix, bind_tx))
+            })
+            .expect("Error spawning `msgbus-zmq` thread");
+
+        // Wait for the bind so that an unavailable endpoint fails construction
+        let Ok(endpoint) = bind_rx.recv() else {
+            let result = handle.join().map_err(|e| anyhow::anyhow!("{:?}", e))?;
+            return Err(result
+                .err()
+                .unwrap_or_else(|| anyhow::anyhow!("Error binding ZeroMQ PUB socket")));
+        };
+
+        Ok(Self {
+            trader_id,
+            endpoint,
+            tx,
+            handle: Some(handle),
+        })
+    }
+
+    fn publish(&self, topic: String, payload: Vec<u8>) -> anyhow::Result<()> {
+        let msg = BusMessage { topic, payload };
+        self.tx.send(msg).map_err(anyhow::Error::new)
+    }
+
+    fn close(&mut self) -> anyhow::Result<()> {
+        debug!("Closing message bus database adapter");
+
+        let msg = BusMessage {
+            topic: CLOSE_TOPIC.to_string(),
+            payload: vec![],
+        };
+        self.tx.send(msg).map_err(anyhow::Error::new)?;
+
+        if let Some(handle) = self.handle.take() {
+            debug!("Joining `msgbus-zmq` thread");
+            handle.join().map_err(|e| anyhow::anyhow!("{:?}", e))?
+        } else {
+            Err(anyhow::anyhow!("message bus database already shutdown"))
+        }
+    }
+}
+
+pub async fn handle_messages(
+    mut rx: UnboundedReceiver<BusMessage>,
+    endpoint: String,
+    topic_prefix: String,
+    bind_tx: mpsc::Sender<String>,
+) -> anyhow::Result<()> {
+    let mut socket = PubSocket::new();
+    let bound = socket
+        .bind(&endpoint)
+        .await
+        .map_err(|e| anyhow::anyhow!("Error binding ZeroMQ PUB socket to {endpoint}: {e}"))?;
+    debug!("Bound ZeroMQ PUB socket to {bound}");
+    bind_tx.send(bound.to_string())?;
+
+    // Continue to receive and handle messages until channel is hung up
+    // or the close topic is received.
+    while let Some(msg) = rx.recv().await {
+        if msg.topic == CLOSE_TOPIC {
+            rx.close();
+            break;
+        }
+
+        let mut zmq_msg = ZmqMessage::from(format!("{topic_prefix}{}", msg.topic));
+        zmq_msg.push_back(msg.payload.into());
+
+        if let Err(e) = socket.send(zmq_msg).await {
+            error!("Error publishing to ZeroMQ: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Provides a ZeroMQ subscriber for the messages published by a [`ZmqMessageBusDatabase`].
+pub struct ZmqMessageBusSubscriber {
+    socket: SubSocket,
+    topic_prefix: String,
+    patterns: Vec<String>,
+}
+
+impl ZmqMessageBusSubscriber {
+    /// Creates a new [`ZmqMessageBusSubscriber`] instance connected to the publisher.
+    ///
+    /// The `config` is the same message bus configuration used for the publishing
+    /// [`ZmqMessageBusDatabase`], so that both resolve the same endpoint and topics.
+    pub async fn connect(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config: &HashMap<String, Value>,
+    ) -> anyhow::Result<Self> {
+        let database_config = config
+            .get("database")
+            .ok_or(anyhow::anyhow!("No database config"))?;
+        let endpoint = get_zmq_endpoint(database_config);
+
+        let mut socket = SubSocket::new();
+        debug!("Connecting to {endpoint}");
+        socket.connect(&endpoint).await?;
+
+        Ok(Self {
+            socket,
+            topic_prefix: get_topic_prefix(trader_id, instance_id, config),
+            patterns: Vec::new(),
+        })
+    }
+
+    /// Subscribes to the messages for the given topic `pattern`.
+    ///
+    /// The pattern supports the message bus wildcards `*` and `?`.
+    pub async fn subscribe(&mut self, pattern: &str) -> anyhow::Result<()> {
+        let subscription = topic_pattern_to_subscription(&self.topic_prefix, pattern);
+        debug!("Subscribing to {subscription}");
+        self.socket.subscribe(&subscription).await?;
+        self.patterns.push(pattern.to_string());
+        Ok(())
+    }
+
+    /// Receives the next message matching a subscribed pattern, restoring the original topic.
+    pub async fn recv(&mut self) -> anyhow::Result<BusMessage> {
+        loop {
+            let mut frames = self.socket.recv().await?.into_vec();
+            if frames.len() != 2 {
+                warn!("Discarding ZeroMQ message with {} frames", frames.len());
+                continue;
+            }
+
+            let payload = frames.pop().expect("Two frames").to_vec();
+            let subject = String::from_utf8(frames.pop().expect("Two frames").to_vec())?;
+            let topic = subject.strip_prefix(&self.topic_prefix).unwrap_or(&subject);
+
+            if self.patterns.iter().any(|p| is_matching_pattern(topic, p)) {
+                return Ok(BusMessage {
+                    topic: topic.to_string(),
+                    payload,
+                });
+            }
+        }
+    }
+}
+
+fn is_matching_pattern(topic: &str, pattern: &str) -> bool {
+    if requires_filter(pattern) {
+        is_matching(&Ustr::from(topic), &Ustr::from(pattern))
+    } else {
+        topic.starts_with(pattern.trim_end_matches('*'))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+
+    fn test_config(endpoint: &str) -> HashMap<String, Value> {
+        let mut config = HashMap::new();
+        config.insert("database".to_string(), json!({ "endpoint": endpoint }));
+        config.insert("use_trader_id".to_string(), json!(true));
+        config.insert("streams_prefix".to_string(), json!("stream"));
+        config
+    }
+
+    #[rstest]
+    #[case("data.quotes.BINANCE.ETHUSDT", "data.quotes.*", true)]
+    #[case("data.trades.BINANCE.ETHUSDT", "data.quotes.*", false)]
+    #[case("data.quotes.BINANCE.ETHUSDT", "data.*.BINANCE.*", true)]
+    #[case("data.quotes.BINANCE.ETHUSDT.X", "data.quotes.BINANCE.ETHUSDT", false)]
+    fn test_is_matching_pattern(
+        #[case] topic: &str,
+        #[case] pattern: &str,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(is_matching_pattern(topic, pattern), expected);
+    }
+
+    #[rstest]
+    fn test_new_when_endpoint_invalid() {
+        let result = ZmqMessageBusDatabase::new(
+            TraderId::from("tester-001"),
+            UUID4::new(),
+            test_config("invalid://endpoint"),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_publish_and_subscribe() {
+        let trader_id = TraderId::from("tester-001");
+        let instance_id = UUID4::new();
+        let mut database =
+            ZmqMessageBusDatabase::new(trader_id, instance_id, test_config("tcp://127.0.0.1:0"))
+                .unwrap();
+        let config = test_config(database.endpoint());
+        let mut subscriber = ZmqMessageBusSubscriber::connect(trader_id, instance_id, &config)
+            .await
+            .unwrap();
+        subscriber.subscribe("data.quotes.*").await.unwrap();
+
+        // Keep publishing until the subscription has propagated to the publisher
+        let msg = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                database
+                    .publish("data.trades.ETHUSDT".to_string(), b"trade".to_vec())
+                    .unwrap();
+                database
+                    .publish("data.quotes.ETHUSDT".to_string(), b"quote".to_vec())
+                    .unwrap();
+                let recv = tokio::time::timeout(Duration::from_millis(100), subscriber.recv());
+                if let Ok(Ok(msg)) = recv.await {
+                    break msg;
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(msg.topic, "data.quotes.ETHUSDT");
+        assert_eq!(msg.payload, b"quote".to_vec());
+        database.close().unwrap();
+    }
+}
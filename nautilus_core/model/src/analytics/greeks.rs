@@ -0,0 +1,347 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Option greeks using the Black-Scholes (spot) and Black-76 (forward) pricing models.
+//!
+//! Greeks are per unit of the underlying, with vega per unit change in volatility
+//! (i.e. per 100 volatility points) and theta per year.
+
+use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, nanos::UnixNanos};
+
+use crate::{
+    enums::OptionKind, identifiers::instrument_id::InstrumentId,
+    instruments::options_contract::OptionsContract,
+};
+
+const SECONDS_IN_YEAR: f64 = 365.0 * 24.0 * 60.0 * 60.0;
+
+/// The model for pricing an option.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PricingModel {
+    /// The Black-Scholes model for options on a spot underlying (with a continuous dividend yield).
+    BlackScholes,
+    /// The Black-76 model for options on a futures or forward underlying.
+    Black76,
+}
+
+/// Represents the market inputs for pricing an option.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GreeksParams {
+    /// The price of the underlying (the spot price for Black-Scholes, or the forward price for Black-76).
+    pub underlying_price: f64,
+    /// The annualized volatility of the underlying.
+    pub volatility: f64,
+    /// The continuously compounded annual risk-free interest rate.
+    pub interest_rate: f64,
+    /// The continuously compounded annual dividend yield (Black-Scholes only).
+    pub dividend_yield: f64,
+}
+
+/// Represents the theoretical price and greeks of an option.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OptionGreeks {
+    pub price: f64,
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+}
+
+/// Returns the theoretical price and greeks of a European option with the given `strike`
+/// and `time_to_expiry` (years).
+///
+/// # Errors
+///
+/// This function returns an error if any of the prices, the volatility or the time to expiry
+/// is not positive.
+pub fn calculate_greeks(
+    model: PricingModel,
+    option_kind: OptionKind,
+    strike: f64,
+    time_to_expiry: f64,
+    params: &GreeksParams,
+) -> anyhow::Result<OptionGreeks> {
+    let GreeksParams {
+        underlying_price: s,
+        volatility: sigma,
+        interest_rate: r,
+        dividend_yield: q,
+    } = *params;
+    let t = time_to_expiry;
+    for (value, param) in [
+        (s, "underlying_price"),
+        (strike, "strike"),
+        (sigma, "volatility"),
+        (t, "time_to_expiry"),
+    ] {
+        if value <= 0.0 || !value.is_finite() {
+            anyhow::bail!("Invalid `{param}` for greeks, was {value}");
+        }
+    }
+
+    // Generalized Black-Scholes with cost of carry `b`
+    let b = match model {
+        PricingModel::BlackScholes => r - q,
+        PricingModel::Black76 => 0.0,
+    };
+    let sqrt_t = t.sqrt();
+    let d1 = ((s / strike).ln() + (b + 0.5 * sigma * sigma) * t) / (sigma * sqrt_t);
+    let d2 = d1 - sigma * sqrt_t;
+    let carry_discount = ((b - r) * t).exp();
+    let discount = (-r * t).exp();
+    let pdf_d1 = norm_pdf(d1);
+
+    let gamma = carry_discount * pdf_d1 / (s * sigma * sqrt_t);
+    let vega = s * carry_discount * pdf_d1 * sqrt_t;
+    let time_decay = -s * carry_discount * pdf_d1 * sigma / (2.0 * sqrt_t);
+
+    let greeks = match option_kind {
+        OptionKind::Call => OptionGreeks {
+            price: s * carry_discount * norm_cdf(d1) - strike * discount * norm_cdf(d2),
+            delta: carry_discount * norm_cdf(d1),
+            gamma,
+            vega,
+            theta: time_decay
+                - (b - r) * s * carry_discount * norm_cdf(d1)
+                - r * strike * discount * norm_cdf(d2),
+        },
+        OptionKind::Put => OptionGreeks {
+            price: strike * discount * norm_cdf(-d2) - s * carry_discount * norm_cdf(-d1),
+            delta: carry_discount * (norm_cdf(d1) - 1.0),
+            gamma,
+            vega,
+            theta: time_decay
+                + (b - r) * s * carry_discount * norm_cdf(-d1)
+                + r * strike * discount * norm_cdf(-d2),
+        },
+    };
+    Ok(greeks)
+}
+
+/// Returns the theoretical price and greeks of the given options `instrument` at `ts_now`.
+///
+/// # Errors
+///
+/// This function returns an error if the option has expired at `ts_now`, or the greeks
+/// cannot be calculated for the `params`.
+pub fn instrument_greeks(
+    instrument: &OptionsContract,
+    model: PricingModel,
+    params: &GreeksParams,
+    ts_now: UnixNanos,
+) -> anyhow::Result<OptionGreeks> {
+    if instrument.expiration_ns <= ts_now {
+        anyhow::bail!(
+            "Option {} expired at {}",
+            instrument.id,
+            instrument.expiration_ns
+        );
+    }
+    let time_to_expiry = (instrument.expiration_ns.as_u64() - ts_now.as_u64()) as f64
+        / NANOSECONDS_IN_SECOND as f64
+        / SECONDS_IN_YEAR;
+
+    calculate_greeks(
+        model,
+        instrument.option_kind,
+        instrument.strike_price.as_f64(),
+        time_to_expiry,
+        params,
+    )
+}
+
+/// Returns the theoretical prices and greeks of the given options `instruments` at `ts_now`,
+/// in the order of the instruments.
+///
+/// The market inputs for each instrument are returned by `params_for`, and instruments
+/// without inputs result in an error.
+pub fn batch_instrument_greeks<'a, F>(
+    instruments: impl IntoIterator<Item = &'a OptionsContract>,
+    model: PricingModel,
+    ts_now: UnixNanos,
+    params_for: F,
+) -> Vec<(InstrumentId, anyhow::Result<OptionGreeks>)>
+where
+    F: Fn(&OptionsContract) -> Option<GreeksParams>,
+{
+    instruments
+        .into_iter()
+        .map(|instrument| {
+            let greeks = match params_for(instrument) {
+                Some(params) => instrument_greeks(instrument, model, &params, ts_now),
+                None => Err(anyhow::anyhow!("No greeks params for {}", instrument.id)),
+            };
+            (instrument.id, greeks)
+        })
+        .collect()
+}
+
+/// Returns the standard normal probability density at `x`.
+fn norm_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+/// Returns the standard normal cumulative distribution at `x`.
+fn norm_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / std::f64::consts::SQRT_2)
+}
+
+/// Returns the complementary error function at `x`, with a fractional error below 1.2e-7.
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / 0.5f64.mul_add(z, 1.0);
+    let poly = t.mul_add(0.170_872_77, -0.822_152_23);
+    let poly = t.mul_add(poly, 1.488_515_87);
+    let poly = t.mul_add(poly, -1.135_203_98);
+    let poly = t.mul_add(poly, 0.278_868_07);
+    let poly = t.mul_add(poly, -0.186_288_06);
+    let poly = t.mul_add(poly, 0.096_784_18);
+    let poly = t.mul_add(poly, 0.374_091_96);
+    let poly = t.mul_add(poly, 1.000_023_68);
+    let poly = t.mul_add(poly, -1.265_512_23);
+    let result = t * (-z * z + poly).exp();
+    if x >= 0.0 {
+        result
+    } else {
+        2.0 - result
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::instruments::stubs::options_contract_appl;
+
+    const PARAMS: GreeksParams = GreeksParams {
+        underlying_price: 100.0,
+        volatility: 0.2,
+        interest_rate: 0.05,
+        dividend_yield: 0.0,
+    };
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-3,
+            "expected {expected}, was {actual}"
+        );
+    }
+
+    #[rstest]
+    fn test_black_scholes_call() {
+        let greeks = calculate_greeks(
+            PricingModel::BlackScholes,
+            OptionKind::Call,
+            100.0,
+            1.0,
+            &PARAMS,
+        )
+        .unwrap();
+
+        assert_close(greeks.price, 10.4506);
+        assert_close(greeks.delta, 0.6368);
+        assert_close(greeks.gamma, 0.0188);
+        assert_close(greeks.vega, 37.5240);
+        assert_close(greeks.theta, -6.4140);
+    }
+
+    #[rstest]
+    fn test_black_scholes_put() {
+        let greeks = calculate_greeks(
+            PricingModel::BlackScholes,
+            OptionKind::Put,
+            100.0,
+            1.0,
+            &PARAMS,
+        )
+        .unwrap();
+
+        assert_close(greeks.price, 5.5735);
+        assert_close(greeks.delta, -0.3632);
+        assert_close(greeks.theta, -1.6579);
+    }
+
+    #[rstest]
+    fn test_black76_put_call_parity() {
+        let strike = 95.0;
+        let call = calculate_greeks(
+            PricingModel::Black76,
+            OptionKind::Call,
+            strike,
+            0.5,
+            &PARAMS,
+        )
+        .unwrap();
+        let put =
+            calculate_greeks(PricingModel::Black76, OptionKind::Put, strike, 0.5, &PARAMS).unwrap();
+
+        let discount = (-PARAMS.interest_rate * 0.5).exp();
+        assert_close(
+            call.price - put.price,
+            discount * (PARAMS.underlying_price - strike),
+        );
+        assert_close(call.delta - put.delta, discount);
+        assert_close(call.gamma, put.gamma);
+    }
+
+    #[rstest]
+    fn test_invalid_volatility() {
+        let params = GreeksParams {
+            volatility: 0.0,
+            ..PARAMS
+        };
+
+        let result = calculate_greeks(
+            PricingModel::BlackScholes,
+            OptionKind::Call,
+            100.0,
+            1.0,
+            &params,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_batch_instrument_greeks(options_contract_appl: OptionsContract) {
+        let expired = OptionsContract {
+            id: InstrumentId::from("AAPL211217C00100000.OPRA"),
+            expiration_ns: UnixNanos::default(),
+            ..options_contract_appl
+        };
+        let ts_now = options_contract_appl.activation_ns;
+        let params = GreeksParams {
+            underlying_price: 150.0,
+            ..PARAMS
+        };
+
+        let results = batch_instrument_greeks(
+            [&options_contract_appl, &expired],
+            PricingModel::BlackScholes,
+            ts_now,
+            |_| Some(params),
+        );
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, options_contract_appl.id);
+        let greeks = results[0].1.as_ref().unwrap();
+        assert!(greeks.delta > 0.5 && greeks.delta < 1.0);
+        assert!(results[1].1.is_err());
+    }
+}
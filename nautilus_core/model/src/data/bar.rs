@@ -22,12 +22,15 @@ use std::{
     str::FromStr,
 };
 
+use chrono::TimeDelta;
 use derive_builder::Builder;
 use indexmap::IndexMap;
-use nautilus_core::{nanos::UnixNanos, serialization::Serializable};
+use nautilus_core::{
+    correctness::check_predicate_true, nanos::UnixNanos, serialization::Serializable,
+};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use super::GetTsInit;
+use super::{trade::TradeTick, GetTsInit};
 use crate::{
     enums::{AggregationSource, BarAggregation, PriceType},
     identifiers::instrument_id::InstrumentId,
@@ -62,6 +65,30 @@ impl BarSpecification {
             price_type,
         }
     }
+
+    /// Returns the fixed time duration of the bar specification.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the aggregation is not time based, or is `MONTH`
+    /// which is calendar based and so has no fixed duration, or if the duration is out of range.
+    pub fn timedelta(&self) -> anyhow::Result<TimeDelta> {
+        let step = i64::try_from(self.step)?;
+        let timedelta = match self.aggregation {
+            BarAggregation::Millisecond => TimeDelta::try_milliseconds(step),
+            BarAggregation::Second => TimeDelta::try_seconds(step),
+            BarAggregation::Minute => TimeDelta::try_minutes(step),
+            BarAggregation::Hour => TimeDelta::try_hours(step),
+            BarAggregation::Day => TimeDelta::try_days(step),
+            BarAggregation::Week => TimeDelta::try_weeks(step),
+            BarAggregation::Month => {
+                anyhow::bail!("Aggregation MONTH is calendar based and has no fixed duration")
+            }
+            aggregation => anyhow::bail!("Aggregation not time based, was {aggregation}"),
+        };
+
+        timedelta.ok_or_else(|| anyhow::anyhow!("Duration for {self} out of range"))
+    }
 }
 
 impl Display for BarSpecification {
@@ -85,10 +112,16 @@ pub struct BarType {
     pub spec: BarSpecification,
     /// The bar types aggregation source.
     pub aggregation_source: AggregationSource,
+    /// The step of the source bars for a composite bar type (zero for a standard bar type).
+    pub composite_step: usize,
+    /// The aggregation of the source bars for a composite bar type.
+    pub composite_aggregation: BarAggregation,
+    /// The aggregation source of the source bars for a composite bar type.
+    pub composite_aggregation_source: AggregationSource,
 }
 
 impl BarType {
-    /// Creates a new [`BarType`] instance.
+    /// Creates a new standard [`BarType`] instance.
     #[must_use]
     pub fn new(
         instrument_id: InstrumentId,
@@ -99,8 +132,73 @@ impl BarType {
             instrument_id,
             spec,
             aggregation_source,
+            composite_step: 0,
+            composite_aggregation: spec.aggregation,
+            composite_aggregation_source: aggregation_source,
         }
     }
+
+    /// Creates a new composite [`BarType`] instance, for bars which are internally aggregated
+    /// from source bars with the given `composite_step`, `composite_aggregation` and
+    /// `composite_aggregation_source` (the source bars share the price type).
+    ///
+    /// For example `AUDUSD.SIM-5-MINUTE-LAST-INTERNAL@1-MINUTE-EXTERNAL` describes 5-minute bars
+    /// aggregated from external 1-minute bars.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `composite_step` is zero.
+    #[must_use]
+    pub fn composite(
+        instrument_id: InstrumentId,
+        spec: BarSpecification,
+        aggregation_source: AggregationSource,
+        composite_step: usize,
+        composite_aggregation: BarAggregation,
+        composite_aggregation_source: AggregationSource,
+    ) -> Self {
+        assert!(composite_step > 0, "`composite_step` was zero");
+
+        Self {
+            instrument_id,
+            spec,
+            aggregation_source,
+            composite_step,
+            composite_aggregation,
+            composite_aggregation_source,
+        }
+    }
+
+    /// Returns whether the bar type is aggregated from other (source) bars.
+    #[must_use]
+    pub fn is_composite(&self) -> bool {
+        self.composite_step > 0
+    }
+
+    /// Returns the standard bar type, without any composite source.
+    #[must_use]
+    pub fn standard(&self) -> Self {
+        Self::new(self.instrument_id, self.spec, self.aggregation_source)
+    }
+
+    /// Returns the bar type of the source bars (if composite).
+    #[must_use]
+    pub fn composite_source(&self) -> Option<Self> {
+        if !self.is_composite() {
+            return None;
+        }
+
+        let spec = BarSpecification::new(
+            self.composite_step,
+            self.composite_aggregation,
+            self.spec.price_type,
+        );
+        Some(Self::new(
+            self.instrument_id,
+            spec,
+            self.composite_aggregation_source,
+        ))
+    }
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -114,14 +212,19 @@ pub struct BarTypeParseError {
 impl FromStr for BarType {
     type Err = BarTypeParseError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (s, composite) = match input.split_once('@') {
+            Some((standard, composite)) => (standard, Some(composite)),
+            None => (input, None),
+        };
+
         // TODO: Requires handling some trait related thing
         #[allow(clippy::needless_collect)]
         let pieces: Vec<&str> = s.rsplitn(5, '-').collect();
         let rev_pieces: Vec<&str> = pieces.into_iter().rev().collect();
         if rev_pieces.len() != 5 {
             return Err(BarTypeParseError {
-                input: s.to_string(),
+                input: input.to_string(),
                 token: String::new(),
                 position: 0,
             });
@@ -129,43 +232,79 @@ impl FromStr for BarType {
 
         let instrument_id =
             InstrumentId::from_str(rev_pieces[0]).map_err(|_| BarTypeParseError {
-                input: s.to_string(),
+                input: input.to_string(),
                 token: rev_pieces[0].to_string(),
                 position: 0,
             })?;
 
         let step = rev_pieces[1].parse().map_err(|_| BarTypeParseError {
-            input: s.to_string(),
+            input: input.to_string(),
             token: rev_pieces[1].to_string(),
             position: 1,
         })?;
         let aggregation =
             BarAggregation::from_str(rev_pieces[2]).map_err(|_| BarTypeParseError {
-                input: s.to_string(),
+                input: input.to_string(),
                 token: rev_pieces[2].to_string(),
                 position: 2,
             })?;
         let price_type = PriceType::from_str(rev_pieces[3]).map_err(|_| BarTypeParseError {
-            input: s.to_string(),
+            input: input.to_string(),
             token: rev_pieces[3].to_string(),
             position: 3,
         })?;
         let aggregation_source =
             AggregationSource::from_str(rev_pieces[4]).map_err(|_| BarTypeParseError {
-                input: s.to_string(),
+                input: input.to_string(),
                 token: rev_pieces[4].to_string(),
                 position: 4,
             })?;
 
-        Ok(Self {
+        let spec = BarSpecification::new(step, aggregation, price_type);
+
+        let Some(composite) = composite else {
+            return Ok(Self::new(instrument_id, spec, aggregation_source));
+        };
+
+        let composite_pieces: Vec<&str> = composite.splitn(3, '-').collect();
+        if composite_pieces.len() != 3 {
+            return Err(BarTypeParseError {
+                input: input.to_string(),
+                token: composite.to_string(),
+                position: 5,
+            });
+        }
+
+        let composite_step = composite_pieces[0]
+            .parse()
+            .ok()
+            .filter(|step: &usize| *step > 0)
+            .ok_or_else(|| BarTypeParseError {
+                input: input.to_string(),
+                token: composite_pieces[0].to_string(),
+                position: 5,
+            })?;
+        let composite_aggregation =
+            BarAggregation::from_str(composite_pieces[1]).map_err(|_| BarTypeParseError {
+                input: input.to_string(),
+                token: composite_pieces[1].to_string(),
+                position: 6,
+            })?;
+        let composite_aggregation_source = AggregationSource::from_str(composite_pieces[2])
+            .map_err(|_| BarTypeParseError {
+                input: input.to_string(),
+                token: composite_pieces[2].to_string(),
+                position: 7,
+            })?;
+
+        Ok(Self::composite(
             instrument_id,
-            spec: BarSpecification {
-                step,
-                aggregation,
-                price_type,
-            },
+            spec,
             aggregation_source,
-        })
+            composite_step,
+            composite_aggregation,
+            composite_aggregation_source,
+        ))
     }
 }
 
@@ -181,7 +320,16 @@ impl Display for BarType {
             f,
             "{}-{}-{}",
             self.instrument_id, self.spec, self.aggregation_source
-        )
+        )?;
+
+        if self.is_composite() {
+            write!(
+                f,
+                "@{}-{}-{}",
+                self.composite_step, self.composite_aggregation, self.composite_aggregation_source
+            )?;
+        }
+        Ok(())
     }
 }
 
@@ -257,6 +405,52 @@ impl Bar {
         }
     }
 
+    /// Creates a new [`Bar`] instance with correctness checking.
+    ///
+    /// The `high` must be the highest and the `low` the lowest of the prices. The `volume`
+    /// is unsigned, and so is always non-negative.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_checked(
+        bar_type: BarType,
+        open: Price,
+        high: Price,
+        low: Price,
+        close: Price,
+        volume: Quantity,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_ohlc(open, high, low, close)?;
+
+        Ok(Self::new(
+            bar_type, open, high, low, close, volume, ts_event, ts_init,
+        ))
+    }
+
+    /// Returns whether the bar prices are consistent, i.e. the `high` is the highest
+    /// and the `low` is the lowest of the prices.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        check_ohlc(self.open, self.high, self.low, self.close).is_ok()
+    }
+
+    /// Creates a new [`Bar`] instance aggregated from the given `trades`.
+    ///
+    /// The bar timestamps are taken from the last trade.
+    pub fn from_ticks(bar_type: BarType, trades: &[TradeTick]) -> anyhow::Result<Self> {
+        let first = trades
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("Cannot create `Bar` from empty `trades`"))?;
+
+        let mut builder = BarBuilder::new(bar_type, first.size.precision);
+        for trade in trades {
+            builder.update(trade.price, trade.size, trade.ts_event);
+        }
+
+        let last = trades.last().unwrap(); // Non-empty checked above
+        Ok(builder.build(last.ts_event, last.ts_init))
+    }
+
     /// Returns the metadata for the type, for use with serialization formats.
     #[must_use]
     pub fn get_metadata(
@@ -288,6 +482,21 @@ impl Bar {
     }
 }
 
+fn check_ohlc(open: Price, high: Price, low: Price, close: Price) -> anyhow::Result<()> {
+    check_predicate_true(
+        high >= low,
+        &format!("`high` {high} was less than `low` {low}"),
+    )?;
+    check_predicate_true(
+        open >= low && open <= high,
+        &format!("`open` {open} was not in range [{low}, {high}]"),
+    )?;
+    check_predicate_true(
+        close >= low && close <= high,
+        &format!("`close` {close} was not in range [{low}, {high}]"),
+    )
+}
+
 impl Display for Bar {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -306,6 +515,216 @@ impl GetTsInit for Bar {
     }
 }
 
+/// Provides a builder for incrementally aggregating a [`Bar`] from price and size updates.
+#[derive(Clone, Debug)]
+pub struct BarBuilder {
+    bar_type: BarType,
+    size_precision: u8,
+    initialized: bool,
+    ts_last: UnixNanos,
+    count: usize,
+    partial_set: bool,
+    is_partial: bool,
+    last_close: Option<Price>,
+    open: Option<Price>,
+    high: Option<Price>,
+    low: Option<Price>,
+    close: Option<Price>,
+    volume: Quantity,
+}
+
+impl BarBuilder {
+    /// Creates a new [`BarBuilder`] instance.
+    #[must_use]
+    pub fn new(bar_type: BarType, size_precision: u8) -> Self {
+        Self {
+            bar_type,
+            size_precision,
+            initialized: false,
+            ts_last: UnixNanos::default(),
+            count: 0,
+            partial_set: false,
+            is_partial: false,
+            last_close: None,
+            open: None,
+            high: None,
+            low: None,
+            close: None,
+            volume: Quantity::zero(size_precision),
+        }
+    }
+
+    /// Returns the bar type for the builder.
+    #[must_use]
+    pub fn bar_type(&self) -> BarType {
+        self.bar_type
+    }
+
+    /// Returns whether the builder has received at least one update.
+    #[must_use]
+    pub fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// Returns whether the bar being built was seeded from a partially completed bar.
+    #[must_use]
+    pub fn is_partial(&self) -> bool {
+        self.is_partial
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) of the last update.
+    #[must_use]
+    pub fn ts_last(&self) -> UnixNanos {
+        self.ts_last
+    }
+
+    /// Returns the count of updates since the last reset.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the accumulated volume since the last reset.
+    #[must_use]
+    pub fn volume(&self) -> Quantity {
+        self.volume
+    }
+
+    /// Set the initial values for a partially completed bar.
+    ///
+    /// Only the first call has any effect.
+    pub fn set_partial(&mut self, partial_bar: Bar) {
+        if self.partial_set {
+            return; // Already updated
+        }
+
+        self.open = Some(partial_bar.open);
+
+        if self.high.is_none() || partial_bar.high > self.high.unwrap() {
+            self.high = Some(partial_bar.high);
+        }
+
+        if self.low.is_none() || partial_bar.low < self.low.unwrap() {
+            self.low = Some(partial_bar.low);
+        }
+
+        if self.close.is_none() {
+            self.close = Some(partial_bar.close);
+        }
+
+        self.volume = partial_bar.volume;
+
+        if self.ts_last == 0 {
+            self.ts_last = partial_bar.ts_init;
+        }
+
+        self.partial_set = true;
+        self.is_partial = true;
+        self.initialized = true;
+    }
+
+    /// Update the builder with the given `price` and `size`.
+    ///
+    /// Updates older than the last update are ignored.
+    pub fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
+        if ts_event < self.ts_last {
+            return; // Not applicable
+        }
+
+        if self.open.is_none() {
+            self.open = Some(price);
+            self.high = Some(price);
+            self.low = Some(price);
+            self.initialized = true;
+        } else {
+            if price > self.high.unwrap() {
+                self.high = Some(price);
+            }
+            if price < self.low.unwrap() {
+                self.low = Some(price);
+            }
+        }
+
+        self.close = Some(price);
+        self.volume.raw += size.raw;
+        self.count += 1;
+        self.ts_last = ts_event;
+    }
+
+    /// Reset the builder.
+    ///
+    /// All stateful fields are reset to their initial value, except the last close.
+    pub fn reset(&mut self) {
+        self.open = None;
+        self.high = None;
+        self.low = None;
+        self.volume = Quantity::zero(self.size_precision);
+        self.count = 0;
+        self.is_partial = false;
+    }
+
+    /// Returns the bar aggregated so far (if initialized), without resetting the builder.
+    #[must_use]
+    pub fn partial(&self, ts_init: UnixNanos) -> Option<Bar> {
+        let open = self.open.or(self.last_close)?;
+
+        Some(Bar::new(
+            self.bar_type,
+            open,
+            self.high.unwrap_or(open),
+            self.low.unwrap_or(open),
+            self.close.unwrap_or(open),
+            self.volume,
+            self.ts_last,
+            ts_init,
+        ))
+    }
+
+    /// Return the aggregated bar and reset, using the last update as the timestamps.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the builder has never been initialized.
+    pub fn build_now(&mut self) -> Bar {
+        self.build(self.ts_last, self.ts_last)
+    }
+
+    /// Return the aggregated bar with the given timestamps, and reset.
+    ///
+    /// If no updates were received since the last reset then the bar is built
+    /// flat from the last close.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the builder has never been initialized.
+    pub fn build(&mut self, ts_event: UnixNanos, ts_init: UnixNanos) -> Bar {
+        if self.open.is_none() {
+            let last_close = self
+                .last_close
+                .expect("`BarBuilder` not initialized: no updates or last close");
+            self.open = Some(last_close);
+            self.high = Some(last_close);
+            self.low = Some(last_close);
+            self.close = Some(last_close);
+        }
+
+        let bar = Bar::new(
+            self.bar_type,
+            self.open.unwrap(),
+            self.high.unwrap(),
+            self.low.unwrap(),
+            self.close.unwrap(),
+            self.volume,
+            ts_event,
+            ts_init,
+        );
+
+        self.last_close = self.close;
+        self.reset();
+        bar
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -330,6 +749,32 @@ mod tests {
         assert_eq!(format!("{bar_spec}"), "1-MINUTE-BID");
     }
 
+    #[rstest]
+    #[case(1, BarAggregation::Millisecond, TimeDelta::milliseconds(1))]
+    #[case(15, BarAggregation::Minute, TimeDelta::minutes(15))]
+    #[case(4, BarAggregation::Hour, TimeDelta::hours(4))]
+    #[case(1, BarAggregation::Day, TimeDelta::days(1))]
+    #[case(2, BarAggregation::Week, TimeDelta::weeks(2))]
+    fn test_bar_spec_timedelta(
+        #[case] step: usize,
+        #[case] aggregation: BarAggregation,
+        #[case] expected: TimeDelta,
+    ) {
+        let bar_spec = BarSpecification::new(step, aggregation, PriceType::Last);
+
+        assert_eq!(bar_spec.timedelta().unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(BarAggregation::Month)]
+    #[case(BarAggregation::Tick)]
+    #[case(BarAggregation::Volume)]
+    fn test_bar_spec_timedelta_with_no_fixed_duration(#[case] aggregation: BarAggregation) {
+        let bar_spec = BarSpecification::new(1, aggregation, PriceType::Last);
+
+        assert!(bar_spec.timedelta().is_err());
+    }
+
     #[rstest]
     fn test_bar_type_parse_valid() {
         let input = "BTCUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL";
@@ -351,6 +796,80 @@ mod tests {
         assert_eq!(bar_type, BarType::from(input));
     }
 
+    #[rstest]
+    fn test_bar_type_parse_composite() {
+        let input = "BTCUSDT-PERP.BINANCE-5-MINUTE-LAST-INTERNAL@1-MINUTE-EXTERNAL";
+        let bar_type = BarType::from_str(input).unwrap();
+
+        assert!(bar_type.is_composite());
+        assert_eq!(bar_type.spec.step, 5);
+        assert_eq!(bar_type.aggregation_source, AggregationSource::Internal);
+        assert_eq!(bar_type.composite_step, 1);
+        assert_eq!(bar_type.composite_aggregation, BarAggregation::Minute);
+        assert_eq!(
+            bar_type.composite_aggregation_source,
+            AggregationSource::External
+        );
+        assert_eq!(bar_type.to_string(), input);
+        assert_eq!(
+            bar_type.standard(),
+            BarType::from("BTCUSDT-PERP.BINANCE-5-MINUTE-LAST-INTERNAL")
+        );
+        assert_eq!(
+            bar_type.composite_source(),
+            Some(BarType::from("BTCUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL"))
+        );
+    }
+
+    #[rstest]
+    fn test_bar_type_standard_has_no_composite_source() {
+        let bar_type = BarType::from("BTCUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL");
+
+        assert!(!bar_type.is_composite());
+        assert_eq!(bar_type.composite_source(), None);
+        assert_eq!(bar_type.standard(), bar_type);
+    }
+
+    #[rstest]
+    #[case("BTCUSDT-PERP.BINANCE-5-MINUTE-LAST-INTERNAL@1-MINUTE", "1-MINUTE", 5)]
+    #[case(
+        "BTCUSDT-PERP.BINANCE-5-MINUTE-LAST-INTERNAL@0-MINUTE-EXTERNAL",
+        "0",
+        5
+    )]
+    #[case(
+        "BTCUSDT-PERP.BINANCE-5-MINUTE-LAST-INTERNAL@1-INVALID-EXTERNAL",
+        "INVALID",
+        6
+    )]
+    #[case(
+        "BTCUSDT-PERP.BINANCE-5-MINUTE-LAST-INTERNAL@1-MINUTE-INVALID",
+        "INVALID",
+        7
+    )]
+    fn test_bar_type_parse_composite_invalid(
+        #[case] input: &str,
+        #[case] token: &str,
+        #[case] position: usize,
+    ) {
+        let result = BarType::from_str(input);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!(
+                "Error parsing `BarType` from '{input}', invalid token: '{token}' at position {position}"
+            )
+        );
+    }
+
+    #[rstest]
+    fn test_bar_type_composite_json_round_trip() {
+        let bar_type = BarType::from("AUDUSD.SIM-5-MINUTE-LAST-INTERNAL@1-MINUTE-EXTERNAL");
+        let json = serde_json::to_string(&bar_type).unwrap();
+
+        assert_eq!(serde_json::from_str::<BarType>(&json).unwrap(), bar_type);
+    }
+
     #[rstest]
     fn test_bar_type_parse_invalid_token_pos_0() {
         let input = "BTCUSDT-PERP-1-MINUTE-LAST-INTERNAL";
@@ -430,21 +949,9 @@ mod tests {
             aggregation: BarAggregation::Minute,
             price_type: PriceType::Bid,
         };
-        let bar_type1 = BarType {
-            instrument_id: instrument_id1,
-            spec: bar_spec,
-            aggregation_source: AggregationSource::External,
-        };
-        let bar_type2 = BarType {
-            instrument_id: instrument_id1,
-            spec: bar_spec,
-            aggregation_source: AggregationSource::External,
-        };
-        let bar_type3 = BarType {
-            instrument_id: instrument_id2,
-            spec: bar_spec,
-            aggregation_source: AggregationSource::External,
-        };
+        let bar_type1 = BarType::new(instrument_id1, bar_spec, AggregationSource::External);
+        let bar_type2 = BarType::new(instrument_id1, bar_spec, AggregationSource::External);
+        let bar_type3 = BarType::new(instrument_id2, bar_spec, AggregationSource::External);
         assert_eq!(bar_type1, bar_type1);
         assert_eq!(bar_type1, bar_type2);
         assert_ne!(bar_type1, bar_type3);
@@ -466,21 +973,9 @@ mod tests {
             aggregation: BarAggregation::Minute,
             price_type: PriceType::Bid,
         };
-        let bar_type1 = BarType {
-            instrument_id: instrument_id1,
-            spec: bar_spec,
-            aggregation_source: AggregationSource::External,
-        };
-        let bar_type2 = BarType {
-            instrument_id: instrument_id1,
-            spec: bar_spec,
-            aggregation_source: AggregationSource::External,
-        };
-        let bar_type3 = BarType {
-            instrument_id: instrument_id2,
-            spec: bar_spec,
-            aggregation_source: AggregationSource::External,
-        };
+        let bar_type1 = BarType::new(instrument_id1, bar_spec, AggregationSource::External);
+        let bar_type2 = BarType::new(instrument_id1, bar_spec, AggregationSource::External);
+        let bar_type3 = BarType::new(instrument_id2, bar_spec, AggregationSource::External);
 
         assert!(bar_type1 <= bar_type2);
         assert!(bar_type1 < bar_type3);
@@ -499,11 +994,7 @@ mod tests {
             aggregation: BarAggregation::Minute,
             price_type: PriceType::Bid,
         };
-        let bar_type = BarType {
-            instrument_id,
-            spec: bar_spec,
-            aggregation_source: AggregationSource::External,
-        };
+        let bar_type = BarType::new(instrument_id, bar_spec, AggregationSource::External);
         let bar1 = Bar {
             bar_type,
             open: Price::from("1.00001"),
@@ -529,6 +1020,49 @@ mod tests {
         assert_ne!(bar1, bar2);
     }
 
+    #[rstest]
+    fn test_bar_new_checked() {
+        let bar = Bar::new_checked(
+            BarType::from("AUDUSD.SIM-1-MINUTE-BID-EXTERNAL"),
+            Price::from("1.00001"),
+            Price::from("1.00004"),
+            Price::from("1.00000"),
+            Price::from("1.00003"),
+            Quantity::from("100000"),
+            UnixNanos::default(),
+            UnixNanos::from(1),
+        )
+        .unwrap();
+
+        assert!(bar.is_valid());
+    }
+
+    #[rstest]
+    #[case("1.00001", "1.00000", "1.00002", "1.00001")] // High below low
+    #[case("1.00005", "1.00004", "1.00000", "1.00003")] // Open above high
+    #[case("1.00001", "1.00004", "1.00002", "1.00003")] // Open below low
+    #[case("1.00001", "1.00004", "1.00000", "1.00005")] // Close above high
+    #[case("1.00001", "1.00004", "1.00000", "0.99999")] // Close below low
+    fn test_bar_new_checked_invalid_ohlc(
+        #[case] open: &str,
+        #[case] high: &str,
+        #[case] low: &str,
+        #[case] close: &str,
+    ) {
+        let bar_type = BarType::from("AUDUSD.SIM-1-MINUTE-BID-EXTERNAL");
+        let open = Price::from(open);
+        let high = Price::from(high);
+        let low = Price::from(low);
+        let close = Price::from(close);
+        let volume = Quantity::from("100000");
+
+        let result = Bar::new_checked(bar_type, open, high, low, close, volume, 0.into(), 1.into());
+        let bar = Bar::new(bar_type, open, high, low, close, volume, 0.into(), 1.into());
+
+        assert!(result.is_err());
+        assert!(!bar.is_valid());
+    }
+
     #[rstest]
     fn test_json_serialization() {
         let bar = Bar::default();
@@ -544,4 +1078,92 @@ mod tests {
         let deserialized = Bar::from_msgpack_bytes(serialized).unwrap();
         assert_eq!(deserialized, bar);
     }
+
+    #[rstest]
+    fn test_bar_from_ticks() {
+        let bar_type = BarType::from("AUDUSD.SIM-3-TICK-LAST-INTERNAL");
+        let trades: Vec<TradeTick> = [("1.00001", 1_i64), ("1.00003", 2), ("1.00000", 3)]
+            .iter()
+            .enumerate()
+            .map(|(i, (price, size))| TradeTick {
+                price: Price::from(*price),
+                size: Quantity::from(*size),
+                ts_event: UnixNanos::from(i as u64),
+                ts_init: UnixNanos::from(i as u64 + 1),
+                ..Default::default()
+            })
+            .collect();
+
+        let bar = Bar::from_ticks(bar_type, &trades).unwrap();
+
+        assert_eq!(bar.open, Price::from("1.00001"));
+        assert_eq!(bar.high, Price::from("1.00003"));
+        assert_eq!(bar.low, Price::from("1.00000"));
+        assert_eq!(bar.close, Price::from("1.00000"));
+        assert_eq!(bar.volume, Quantity::from(6));
+        assert_eq!(bar.ts_event, 2);
+        assert_eq!(bar.ts_init, 3);
+    }
+
+    #[rstest]
+    fn test_bar_from_ticks_when_empty() {
+        let bar_type = BarType::from("AUDUSD.SIM-3-TICK-LAST-INTERNAL");
+
+        assert!(Bar::from_ticks(bar_type, &[]).is_err());
+    }
+
+    #[rstest]
+    fn test_bar_builder_ignores_stale_update() {
+        let mut builder = BarBuilder::new(BarType::from("AUDUSD.SIM-3-TICK-LAST-INTERNAL"), 0);
+
+        builder.update(Price::from("1.00001"), Quantity::from(1), 2.into());
+        builder.update(Price::from("1.00005"), Quantity::from(1), 1.into());
+
+        assert_eq!(builder.count(), 1);
+        assert_eq!(builder.ts_last(), 2);
+    }
+
+    #[rstest]
+    fn test_bar_builder_partial_does_not_reset() {
+        let mut builder = BarBuilder::new(BarType::from("AUDUSD.SIM-3-TICK-LAST-INTERNAL"), 0);
+        assert!(builder.partial(0.into()).is_none());
+
+        builder.update(Price::from("1.00001"), Quantity::from(1), 1.into());
+        builder.update(Price::from("1.00002"), Quantity::from(2), 2.into());
+        let partial = builder.partial(3.into()).unwrap();
+
+        assert_eq!(partial.high, Price::from("1.00002"));
+        assert_eq!(partial.volume, Quantity::from(3));
+        assert_eq!(builder.count(), 2);
+    }
+
+    #[rstest]
+    fn test_bar_builder_set_partial() {
+        let mut builder = BarBuilder::new(BarType::from("AUDUSD.SIM-1-MINUTE-LAST-INTERNAL"), 0);
+        let partial_bar = Bar::default();
+
+        builder.set_partial(partial_bar);
+        builder.update(Price::from("1.00030"), Quantity::from(1), 2.into());
+
+        assert!(builder.is_partial());
+
+        let bar = builder.build_now();
+
+        assert_eq!(bar.open, partial_bar.open);
+        assert_eq!(bar.high, Price::from("1.00030"));
+        assert!(!builder.is_partial());
+    }
+
+    #[rstest]
+    fn test_bar_builder_build_with_no_updates_uses_last_close() {
+        let mut builder = BarBuilder::new(BarType::from("AUDUSD.SIM-1-MINUTE-LAST-INTERNAL"), 0);
+
+        builder.update(Price::from("1.00001"), Quantity::from(1), 1.into());
+        builder.build_now();
+        let bar = builder.build(10.into(), 10.into());
+
+        assert_eq!(bar.open, Price::from("1.00001"));
+        assert_eq!(bar.close, Price::from("1.00001"));
+        assert_eq!(bar.volume, Quantity::from(0));
+    }
 }
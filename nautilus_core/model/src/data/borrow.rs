@@ -0,0 +1,145 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `BorrowRateUpdate` data type representing the interest rate for borrowing a currency.
+
+use std::fmt::{Display, Formatter};
+
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable};
+use serde::{Deserialize, Serialize};
+
+use super::GetTsInit;
+use crate::{
+    identifiers::venue::Venue,
+    types::{currency::Currency, money::Money},
+};
+
+/// The number of nanoseconds in a (365 day) year, used to accrue annualized rates.
+pub const NANOSECONDS_IN_YEAR: u64 = 365 * 24 * 60 * 60 * 1_000_000_000;
+
+/// Represents a borrow (margin lending) rate update for a currency at a venue.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct BorrowRateUpdate {
+    /// The venue for the borrow rate.
+    pub venue: Venue,
+    /// The borrowed currency.
+    pub currency: Currency,
+    /// The annualized borrow rate (e.g. 0.05 for 5% per year).
+    pub rate: f64,
+    /// The UNIX timestamp (nanoseconds) when the borrow rate event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl BorrowRateUpdate {
+    /// Creates a new [`BorrowRateUpdate`] instance.
+    #[must_use]
+    pub fn new(
+        venue: Venue,
+        currency: Currency,
+        rate: f64,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            venue,
+            currency,
+            rate,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the simple interest accrued on the `borrowed` amount over `duration_ns`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `borrowed` is not in the borrow rate currency.
+    pub fn interest(&self, borrowed: Money, duration_ns: u64) -> anyhow::Result<Money> {
+        if borrowed.currency != self.currency {
+            anyhow::bail!(
+                "Borrowed currency {} did not match borrow rate currency {}",
+                borrowed.currency,
+                self.currency
+            );
+        }
+        let years = duration_ns as f64 / NANOSECONDS_IN_YEAR as f64;
+        Money::new(borrowed.as_f64() * self.rate * years, self.currency)
+    }
+}
+
+impl Display for BorrowRateUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.venue, self.currency, self.rate, self.ts_event,
+        )
+    }
+}
+
+impl Serializable for BorrowRateUpdate {}
+
+impl GetTsInit for BorrowRateUpdate {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::stubs::stub_borrow_rate;
+
+    #[rstest]
+    fn test_to_string(stub_borrow_rate: BorrowRateUpdate) {
+        assert_eq!(stub_borrow_rate.to_string(), "BINANCE,USDT,0.0365,1");
+    }
+
+    #[rstest]
+    fn test_interest(stub_borrow_rate: BorrowRateUpdate) {
+        let one_day_ns = NANOSECONDS_IN_YEAR / 365;
+
+        let interest = stub_borrow_rate
+            .interest(Money::from("10000 USDT"), one_day_ns)
+            .unwrap();
+
+        assert_eq!(interest, Money::from("1 USDT"));
+    }
+
+    #[rstest]
+    fn test_interest_with_mismatched_currency(stub_borrow_rate: BorrowRateUpdate) {
+        assert!(stub_borrow_rate
+            .interest(Money::from("1 BTC"), NANOSECONDS_IN_YEAR)
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_json_serialization(stub_borrow_rate: BorrowRateUpdate) {
+        let serialized = stub_borrow_rate.as_json_bytes().unwrap();
+        let deserialized = BorrowRateUpdate::from_json_bytes(serialized).unwrap();
+
+        assert_eq!(deserialized, stub_borrow_rate);
+    }
+}
@@ -0,0 +1,240 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `CustomData` type carrying user-defined data (e.g. news, sentiment or alternative data)
+//! as raw bytes, identified by a registered `DataType`.
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::{Display, Formatter},
+    hash::Hash,
+    ops::{Deref, DerefMut},
+    sync::Mutex,
+};
+
+use nautilus_core::{correctness::check_valid_string, nanos::UnixNanos};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::GetTsInit;
+
+/// The registered custom data type names.
+static CUSTOM_DATA_TYPES: Lazy<Mutex<HashSet<Ustr>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Registers the given custom data `type_name`, so that data of the type can be constructed,
+/// published, cached and persisted.
+pub fn register_custom_data_type(type_name: &str) -> anyhow::Result<()> {
+    check_valid_string(type_name, stringify!(type_name))?;
+    CUSTOM_DATA_TYPES
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire lock on `CUSTOM_DATA_TYPES`: {e}"))?
+        .insert(Ustr::from(type_name));
+    Ok(())
+}
+
+/// Returns whether the given custom data `type_name` has been registered.
+#[must_use]
+pub fn is_custom_data_type_registered(type_name: &str) -> bool {
+    CUSTOM_DATA_TYPES
+        .lock()
+        .map(|types| types.contains(&Ustr::from(type_name)))
+        .unwrap_or(false)
+}
+
+/// Represents a data type including its metadata, which together identify a stream of data.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct DataType {
+    /// The name of the data type.
+    pub type_name: Ustr,
+    /// The metadata for the data type.
+    pub metadata: BTreeMap<String, String>,
+    /// The message bus topic for the data type.
+    pub topic: Ustr,
+}
+
+impl DataType {
+    /// Creates a new [`DataType`] instance.
+    ///
+    /// The topic is formed from the type name followed by each metadata `key=value` pair
+    /// (in key order), separated by `.`.
+    #[must_use]
+    pub fn new(type_name: &str, metadata: BTreeMap<String, String>) -> Self {
+        let mut topic = type_name.to_string();
+        for (key, value) in &metadata {
+            topic.push_str(&format!(".{key}={value}"));
+        }
+
+        Self {
+            type_name: Ustr::from(type_name),
+            metadata,
+            topic: Ustr::from(&topic),
+        }
+    }
+}
+
+impl Display for DataType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.topic)
+    }
+}
+
+/// Represents a user-defined data payload of a registered [`DataType`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CustomData {
+    /// The data type for the payload.
+    pub data_type: DataType,
+    /// The raw payload bytes, in a format defined by the data type.
+    pub value: Vec<u8>,
+    /// The UNIX timestamp (nanoseconds) when the data event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl CustomData {
+    /// Creates a new [`CustomData`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the data type has not been registered.
+    pub fn new(
+        data_type: DataType,
+        value: Vec<u8>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        if !is_custom_data_type_registered(&data_type.type_name) {
+            anyhow::bail!("Custom data type `{}` not registered", data_type.type_name);
+        }
+
+        Ok(Self {
+            data_type,
+            value,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the data type metadata cannot be serialized to JSON.
+    #[must_use]
+    pub fn get_metadata(data_type: &DataType) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("type_name".to_string(), data_type.type_name.to_string());
+        metadata.insert(
+            "metadata".to_string(),
+            serde_json::to_string(&data_type.metadata).unwrap(),
+        );
+        metadata
+    }
+}
+
+impl Display for CustomData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{} bytes,{}",
+            self.data_type,
+            self.value.len(),
+            self.ts_event,
+        )
+    }
+}
+
+impl GetTsInit for CustomData {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`CustomData`].
+///
+/// This struct wraps `CustomData` in a way that makes it compatible with C function
+/// calls, enabling interaction with `CustomData` in a C environment.
+#[repr(C)]
+#[derive(Debug, Clone)]
+#[allow(non_camel_case_types)]
+pub struct CustomData_API(Box<CustomData>);
+
+impl CustomData_API {
+    #[must_use]
+    pub fn new(data: CustomData) -> Self {
+        Self(Box::new(data))
+    }
+}
+
+impl Deref for CustomData_API {
+    type Target = CustomData;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for CustomData_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::stubs::stub_custom_data;
+
+    #[rstest]
+    fn test_data_type_topic() {
+        let metadata = BTreeMap::from([
+            ("source".to_string(), "RSS".to_string()),
+            ("lang".to_string(), "en".to_string()),
+        ]);
+        let data_type = DataType::new("NewsEvent", metadata);
+
+        assert_eq!(data_type.topic.as_str(), "NewsEvent.lang=en.source=RSS");
+        assert_eq!(
+            DataType::new("NewsEvent", BTreeMap::new()).topic.as_str(),
+            "NewsEvent"
+        );
+    }
+
+    #[rstest]
+    fn test_new_when_not_registered() {
+        let data_type = DataType::new("UnregisteredType", BTreeMap::new());
+
+        assert!(CustomData::new(data_type, vec![1], 1.into(), 1.into()).is_err());
+    }
+
+    #[rstest]
+    fn test_register_invalid_type_name() {
+        assert!(register_custom_data_type("").is_err());
+    }
+
+    #[rstest]
+    fn test_to_string(stub_custom_data: CustomData) {
+        assert_eq!(
+            stub_custom_data.to_string(),
+            "NewsEvent.source=RSS,5 bytes,1"
+        );
+    }
+}
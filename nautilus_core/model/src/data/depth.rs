@@ -13,7 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-//! An `OrderBookDepth10` aggregated top-of-book data type with a fixed depth of 10 levels per side.
+//! Aggregated order book depth data types with a fixed depth of levels per side.
+//!
+//! - `OrderBookDepth10`: The top 10 levels per side.
+//! - `OrderBookDepth50`: The top 50 levels per side, for venues providing deep snapshots.
 
 use std::{
     collections::HashMap,
@@ -24,10 +27,19 @@ use indexmap::IndexMap;
 use nautilus_core::{nanos::UnixNanos, serialization::Serializable};
 use serde::{Deserialize, Serialize};
 
-use super::{order::BookOrder, GetTsInit};
-use crate::identifiers::instrument_id::InstrumentId;
+use super::{
+    delta::OrderBookDelta,
+    deltas::{OrderBookDeltas, OrderBookDeltas_API},
+    order::BookOrder,
+    Data, GetTsInit,
+};
+use crate::{
+    enums::{BookAction, RecordFlag},
+    identifiers::instrument_id::InstrumentId,
+};
 
 pub const DEPTH10_LEN: usize = 10;
+pub const DEPTH50_LEN: usize = 50;
 
 /// Represents a aggregated order book update with a fixed depth of 10 levels per side.
 ///
@@ -199,6 +211,185 @@ impl GetTsInit for OrderBookDepth10 {
     }
 }
 
+/// Represents an aggregated order book update with a fixed depth of 50 levels per side.
+///
+/// This structure is intended for recording and replaying deep book snapshots from venues
+/// which provide them. Unused levels are null orders with a zero count.
+///
+/// Unlike `OrderBookDepth10` this type is not a `Data` variant; instead it converts to an
+/// `OrderBookDeltas` snapshot (see [`OrderBookDepth50::to_deltas`]) for processing.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OrderBookDepth50 {
+    /// The instrument ID for the book.
+    pub instrument_id: InstrumentId,
+    /// The bid orders for the depth update.
+    #[serde(with = "depth_array")]
+    pub bids: [BookOrder; DEPTH50_LEN],
+    /// The ask orders for the depth update.
+    #[serde(with = "depth_array")]
+    pub asks: [BookOrder; DEPTH50_LEN],
+    /// The count of bid orders per level for the depth update.
+    #[serde(with = "depth_array")]
+    pub bid_counts: [u32; DEPTH50_LEN],
+    /// The count of ask orders per level for the depth update.
+    #[serde(with = "depth_array")]
+    pub ask_counts: [u32; DEPTH50_LEN],
+    /// The record flags bit field, indicating event end and data information.
+    pub flags: u8,
+    /// The message sequence number assigned at the venue.
+    pub sequence: u64,
+    /// The UNIX timestamp (nanoseconds) when the book event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl OrderBookDepth50 {
+    /// Creates a new [`OrderBookDepth50`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        bids: [BookOrder; DEPTH50_LEN],
+        asks: [BookOrder; DEPTH50_LEN],
+        bid_counts: [u32; DEPTH50_LEN],
+        ask_counts: [u32; DEPTH50_LEN],
+        flags: u8,
+        sequence: u64,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            bids,
+            asks,
+            bid_counts,
+            ask_counts,
+            flags,
+            sequence,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> HashMap<String, String> {
+        OrderBookDepth10::get_metadata(instrument_id, price_precision, size_precision)
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        for (prefix, data_type) in [
+            ("bid_price", "Int64"),
+            ("ask_price", "Int64"),
+            ("bid_size", "UInt64"),
+            ("ask_size", "UInt64"),
+            ("bid_count", "UInt32"),
+            ("ask_count", "UInt32"),
+        ] {
+            for i in 0..DEPTH50_LEN {
+                metadata.insert(format!("{prefix}_{i}"), data_type.to_string());
+            }
+        }
+        metadata.insert("flags".to_string(), "UInt8".to_string());
+        metadata.insert("sequence".to_string(), "UInt64".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+
+    /// Returns the depth as a snapshot of order book deltas, which clears the book and then
+    /// adds each populated level (null order levels are skipped).
+    #[must_use]
+    pub fn to_deltas(&self) -> OrderBookDeltas {
+        let flags = self.flags | RecordFlag::F_SNAPSHOT as u8;
+        let mut deltas = vec![OrderBookDelta::clear(
+            self.instrument_id,
+            self.sequence,
+            self.ts_event,
+            self.ts_init,
+        )];
+
+        for order in self.bids.iter().chain(self.asks.iter()) {
+            if order.size.raw == 0 {
+                continue; // Unused level
+            }
+            deltas.push(OrderBookDelta::new(
+                self.instrument_id,
+                BookAction::Add,
+                *order,
+                flags,
+                self.sequence,
+                self.ts_event,
+                self.ts_init,
+            ));
+        }
+
+        if let Some(last) = deltas.last_mut() {
+            last.flags |= RecordFlag::F_LAST as u8;
+        }
+
+        OrderBookDeltas::new(self.instrument_id, deltas)
+    }
+}
+
+impl Display for OrderBookDepth50 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},flags={},sequence={},ts_event={},ts_init={}",
+            self.instrument_id, self.flags, self.sequence, self.ts_event, self.ts_init
+        )
+    }
+}
+
+impl Serializable for OrderBookDepth50 {}
+
+impl GetTsInit for OrderBookDepth50 {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl From<OrderBookDepth50> for Data {
+    fn from(value: OrderBookDepth50) -> Self {
+        Self::Deltas(OrderBookDeltas_API::new(value.to_deltas()))
+    }
+}
+
+/// Serializes fixed length depth arrays as sequences, as `serde` only derives arrays
+/// up to 32 elements.
+mod depth_array {
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S, T, const N: usize>(array: &[T; N], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        serializer.collect_seq(array)
+    }
+
+    pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        let values = Vec::<T>::deserialize(deserializer)?;
+        let len = values.len();
+        values
+            .try_into()
+            .map_err(|_| D::Error::invalid_length(len, &format!("{N} elements").as_str()))
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -235,6 +426,37 @@ mod tests {
         assert_eq!(depth.ts_init, ts_init);
     }
 
+    #[rstest]
+    fn test_depth50_to_deltas(stub_depth50: OrderBookDepth50) {
+        let deltas = stub_depth50.to_deltas();
+
+        // Clear plus 3 bids and 2 asks (remaining levels are unused)
+        assert_eq!(deltas.deltas.len(), 6);
+        assert_eq!(deltas.deltas[0].action, BookAction::Clear);
+        assert_eq!(deltas.deltas[1].order, stub_depth50.bids[0]);
+        assert_eq!(deltas.deltas[5].order, stub_depth50.asks[1]);
+        assert!(RecordFlag::F_LAST.matches(deltas.flags));
+        assert!(RecordFlag::F_SNAPSHOT.matches(deltas.deltas[1].flags));
+        assert_eq!(deltas.ts_init, stub_depth50.ts_init);
+    }
+
+    #[rstest]
+    fn test_depth50_json_round_trip(stub_depth50: OrderBookDepth50) {
+        let serialized = stub_depth50.as_json_bytes().unwrap();
+        let deserialized = OrderBookDepth50::from_json_bytes(serialized).unwrap();
+
+        assert_eq!(deserialized, stub_depth50);
+    }
+
+    #[rstest]
+    fn test_depth50_get_fields() {
+        let fields = OrderBookDepth50::get_fields();
+
+        assert_eq!(fields.len(), 6 * DEPTH50_LEN + 4);
+        assert_eq!(fields.get_index(0).unwrap().0, "bid_price_0");
+        assert_eq!(fields.get_index(DEPTH50_LEN).unwrap().0, "ask_price_0");
+    }
+
     // TODO: Exact format for Debug and Display TBD
     #[rstest]
     fn test_display(stub_depth10: OrderBookDepth10) {
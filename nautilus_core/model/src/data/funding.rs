@@ -0,0 +1,145 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `FundingRateUpdate` data type representing a funding rate for a perpetual contract.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable};
+use serde::{Deserialize, Serialize};
+
+use super::GetTsInit;
+use crate::identifiers::instrument_id::InstrumentId;
+
+/// Represents a funding rate update for a perpetual contract instrument.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct FundingRateUpdate {
+    /// The instrument ID for the funding rate.
+    pub instrument_id: InstrumentId,
+    /// The funding rate for the interval (e.g. 0.0001 for 0.01%).
+    pub rate: f64,
+    /// The UNIX timestamp (nanoseconds) of the next funding, zero if unknown.
+    pub next_funding_ns: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the funding rate event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl FundingRateUpdate {
+    /// Creates a new [`FundingRateUpdate`] instance.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        rate: f64,
+        next_funding_ns: UnixNanos,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            rate,
+            next_funding_ns,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) of the next funding, if known.
+    #[must_use]
+    pub fn next_funding(&self) -> Option<UnixNanos> {
+        (self.next_funding_ns.as_u64() > 0).then_some(self.next_funding_ns)
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(instrument_id: &InstrumentId) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        metadata.insert("rate".to_string(), "Float64".to_string());
+        metadata.insert("next_funding_ns".to_string(), "UInt64".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl Display for FundingRateUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.instrument_id, self.rate, self.next_funding_ns, self.ts_event,
+        )
+    }
+}
+
+impl Serializable for FundingRateUpdate {}
+
+impl GetTsInit for FundingRateUpdate {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::stubs::stub_funding_rate;
+
+    #[rstest]
+    fn test_next_funding(stub_funding_rate: FundingRateUpdate) {
+        let mut funding = stub_funding_rate;
+        assert_eq!(funding.next_funding(), Some(UnixNanos::from(3)));
+
+        funding.next_funding_ns = UnixNanos::default();
+        assert_eq!(funding.next_funding(), None);
+    }
+
+    #[rstest]
+    fn test_to_string(stub_funding_rate: FundingRateUpdate) {
+        assert_eq!(
+            stub_funding_rate.to_string(),
+            "BTCUSDT-PERP.BINANCE,0.0001,3,1"
+        );
+    }
+
+    #[rstest]
+    fn test_json_serialization(stub_funding_rate: FundingRateUpdate) {
+        let serialized = stub_funding_rate.as_json_bytes().unwrap();
+        let deserialized = FundingRateUpdate::from_json_bytes(serialized).unwrap();
+
+        assert_eq!(deserialized, stub_funding_rate);
+    }
+}
@@ -0,0 +1,173 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An `AuctionImbalance` data type representing an auction imbalance publication, such as
+//! the closing auction imbalance for an equity.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable};
+use serde::{Deserialize, Serialize};
+
+use super::GetTsInit;
+use crate::{
+    enums::OrderSide,
+    identifiers::instrument_id::InstrumentId,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents an auction imbalance for an instrument.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct AuctionImbalance {
+    /// The instrument ID for the auction.
+    pub instrument_id: InstrumentId,
+    /// The indicative price at which the auction would currently match.
+    pub indicative_price: Price,
+    /// The quantity matched at the indicative price.
+    pub paired_qty: Quantity,
+    /// The unmatched quantity at the indicative price.
+    pub imbalance_qty: Quantity,
+    /// The side of the unmatched quantity, or `NoOrderSide` if balanced.
+    pub imbalance_side: OrderSide,
+    /// The UNIX timestamp (nanoseconds) when the imbalance event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl AuctionImbalance {
+    /// Creates a new [`AuctionImbalance`] instance.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        indicative_price: Price,
+        paired_qty: Quantity,
+        imbalance_qty: Quantity,
+        imbalance_side: OrderSide,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            indicative_price,
+            paired_qty,
+            imbalance_qty,
+            imbalance_side,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns whether the auction is currently balanced (no unmatched quantity).
+    #[must_use]
+    pub fn is_balanced(&self) -> bool {
+        self.imbalance_qty.is_zero() || self.imbalance_side == OrderSide::NoOrderSide
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata.insert("price_precision".to_string(), price_precision.to_string());
+        metadata.insert("size_precision".to_string(), size_precision.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        metadata.insert("indicative_price".to_string(), "Int64".to_string());
+        metadata.insert("paired_qty".to_string(), "UInt64".to_string());
+        metadata.insert("imbalance_qty".to_string(), "UInt64".to_string());
+        metadata.insert("imbalance_side".to_string(), "UInt8".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl Display for AuctionImbalance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{}",
+            self.instrument_id,
+            self.indicative_price,
+            self.paired_qty,
+            self.imbalance_qty,
+            self.imbalance_side,
+            self.ts_event,
+        )
+    }
+}
+
+impl Serializable for AuctionImbalance {}
+
+impl GetTsInit for AuctionImbalance {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::stubs::stub_auction_imbalance;
+
+    #[rstest]
+    fn test_to_string(stub_auction_imbalance: AuctionImbalance) {
+        assert_eq!(
+            stub_auction_imbalance.to_string(),
+            "MSFT.XNAS,420.50,150000,25000,BUY,1"
+        );
+    }
+
+    #[rstest]
+    fn test_is_balanced(stub_auction_imbalance: AuctionImbalance) {
+        let mut imbalance = stub_auction_imbalance;
+        assert!(!imbalance.is_balanced());
+
+        imbalance.imbalance_qty = Quantity::from("0");
+        imbalance.imbalance_side = OrderSide::NoOrderSide;
+        assert!(imbalance.is_balanced());
+    }
+
+    #[rstest]
+    fn test_json_serialization(stub_auction_imbalance: AuctionImbalance) {
+        let serialized = stub_auction_imbalance.as_json_bytes().unwrap();
+        let deserialized = AuctionImbalance::from_json_bytes(serialized).unwrap();
+
+        assert_eq!(deserialized, stub_auction_imbalance);
+    }
+}
@@ -16,11 +16,16 @@
 //! Data types for the trading domain model.
 
 pub mod bar;
+pub mod borrow;
+pub mod custom;
 pub mod delta;
 pub mod deltas;
 pub mod depth;
+pub mod funding;
+pub mod imbalance;
 pub mod order;
 pub mod quote;
+pub mod status;
 #[cfg(feature = "stubs")]
 pub mod stubs;
 pub mod trade;
@@ -28,9 +33,19 @@ pub mod trade;
 use nautilus_core::nanos::UnixNanos;
 
 use self::{
-    bar::Bar, delta::OrderBookDelta, deltas::OrderBookDeltas_API, depth::OrderBookDepth10,
-    quote::QuoteTick, trade::TradeTick,
+    bar::Bar,
+    borrow::BorrowRateUpdate,
+    custom::{CustomData, CustomData_API},
+    delta::OrderBookDelta,
+    deltas::OrderBookDeltas_API,
+    depth::OrderBookDepth10,
+    funding::FundingRateUpdate,
+    imbalance::AuctionImbalance,
+    quote::QuoteTick,
+    status::{InstrumentStatus, VenueStatus},
+    trade::TradeTick,
 };
+use crate::identifiers::instrument_id::InstrumentId;
 
 /// A built-in Nautilus data type.
 ///
@@ -46,6 +61,34 @@ pub enum Data {
     Quote(QuoteTick),
     Trade(TradeTick),
     Bar(Bar),
+    FundingRate(FundingRateUpdate),
+    BorrowRate(BorrowRateUpdate),
+    InstrumentStatus(InstrumentStatus),
+    VenueStatus(VenueStatus),
+    AuctionImbalance(AuctionImbalance),
+    Custom(CustomData_API),
+}
+
+impl Data {
+    /// Returns the instrument ID for the data, or `None` for venue level, currency level and
+    /// custom data.
+    #[must_use]
+    pub fn instrument_id(&self) -> Option<InstrumentId> {
+        match self {
+            Self::Delta(d) => Some(d.instrument_id),
+            Self::Deltas(d) => Some(d.instrument_id),
+            Self::Depth10(d) => Some(d.instrument_id),
+            Self::Quote(q) => Some(q.instrument_id),
+            Self::Trade(t) => Some(t.instrument_id),
+            Self::Bar(b) => Some(b.bar_type.instrument_id),
+            Self::FundingRate(f) => Some(f.instrument_id),
+            Self::BorrowRate(_) => None,
+            Self::InstrumentStatus(s) => Some(s.instrument_id),
+            Self::VenueStatus(_) => None,
+            Self::AuctionImbalance(i) => Some(i.instrument_id),
+            Self::Custom(_) => None,
+        }
+    }
 }
 
 pub trait GetTsInit {
@@ -61,6 +104,12 @@ impl GetTsInit for Data {
             Self::Quote(q) => q.ts_init,
             Self::Trade(t) => t.ts_init,
             Self::Bar(b) => b.ts_init,
+            Self::FundingRate(f) => f.ts_init,
+            Self::BorrowRate(b) => b.ts_init,
+            Self::InstrumentStatus(s) => s.ts_init,
+            Self::VenueStatus(s) => s.ts_init,
+            Self::AuctionImbalance(i) => i.ts_init,
+            Self::Custom(c) => c.ts_init,
         }
     }
 }
@@ -106,6 +155,42 @@ impl From<Bar> for Data {
     }
 }
 
+impl From<FundingRateUpdate> for Data {
+    fn from(value: FundingRateUpdate) -> Self {
+        Self::FundingRate(value)
+    }
+}
+
+impl From<BorrowRateUpdate> for Data {
+    fn from(value: BorrowRateUpdate) -> Self {
+        Self::BorrowRate(value)
+    }
+}
+
+impl From<InstrumentStatus> for Data {
+    fn from(value: InstrumentStatus) -> Self {
+        Self::InstrumentStatus(value)
+    }
+}
+
+impl From<VenueStatus> for Data {
+    fn from(value: VenueStatus) -> Self {
+        Self::VenueStatus(value)
+    }
+}
+
+impl From<AuctionImbalance> for Data {
+    fn from(value: AuctionImbalance) -> Self {
+        Self::AuctionImbalance(value)
+    }
+}
+
+impl From<CustomData> for Data {
+    fn from(value: CustomData) -> Self {
+        Self::Custom(CustomData_API::new(value))
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn data_clone(data: &Data) -> Data {
     data.clone()
@@ -0,0 +1,250 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! `InstrumentStatus` and `VenueStatus` data types representing market status changes,
+//! such as trading halts, auctions and trading session transitions.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{nanos::UnixNanos, serialization::Serializable};
+use serde::{Deserialize, Serialize};
+
+use super::GetTsInit;
+use crate::{
+    enums::{HaltReason, MarketStatus},
+    identifiers::{instrument_id::InstrumentId, venue::Venue},
+};
+
+/// Represents a market status change for an instrument.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct InstrumentStatus {
+    /// The instrument ID for the status change.
+    pub instrument_id: InstrumentId,
+    /// The market status for the instrument.
+    pub status: MarketStatus,
+    /// The reason for a halt, or `NotHalted`.
+    pub halt_reason: HaltReason,
+    /// The UNIX timestamp (nanoseconds) when the status event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl InstrumentStatus {
+    /// Creates a new [`InstrumentStatus`] instance.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        status: MarketStatus,
+        halt_reason: HaltReason,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            status,
+            halt_reason,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns whether trading is currently possible for the instrument.
+    #[must_use]
+    pub fn is_trading(&self) -> bool {
+        is_trading_status(self.status)
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(instrument_id: &InstrumentId) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        status_fields()
+    }
+}
+
+impl Display for InstrumentStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.instrument_id, self.status, self.halt_reason, self.ts_event,
+        )
+    }
+}
+
+impl Serializable for InstrumentStatus {}
+
+impl GetTsInit for InstrumentStatus {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+/// Represents a market status change for an entire venue.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct VenueStatus {
+    /// The venue for the status change.
+    pub venue: Venue,
+    /// The market status for the venue.
+    pub status: MarketStatus,
+    /// The reason for a halt, or `NotHalted`.
+    pub halt_reason: HaltReason,
+    /// The UNIX timestamp (nanoseconds) when the status event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl VenueStatus {
+    /// Creates a new [`VenueStatus`] instance.
+    #[must_use]
+    pub fn new(
+        venue: Venue,
+        status: MarketStatus,
+        halt_reason: HaltReason,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            venue,
+            status,
+            halt_reason,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns whether trading is currently possible on the venue.
+    #[must_use]
+    pub fn is_trading(&self) -> bool {
+        is_trading_status(self.status)
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(venue: &Venue) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("venue".to_string(), venue.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        status_fields()
+    }
+}
+
+impl Display for VenueStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.venue, self.status, self.halt_reason, self.ts_event,
+        )
+    }
+}
+
+impl Serializable for VenueStatus {}
+
+impl GetTsInit for VenueStatus {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+fn is_trading_status(status: MarketStatus) -> bool {
+    matches!(status, MarketStatus::Open | MarketStatus::Reopen)
+}
+
+fn status_fields() -> IndexMap<String, String> {
+    let mut metadata = IndexMap::new();
+    metadata.insert("status".to_string(), "UInt8".to_string());
+    metadata.insert("halt_reason".to_string(), "UInt8".to_string());
+    metadata.insert("ts_event".to_string(), "UInt64".to_string());
+    metadata.insert("ts_init".to_string(), "UInt64".to_string());
+    metadata
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::stubs::{stub_instrument_status, stub_venue_status};
+
+    #[rstest]
+    fn test_instrument_status_to_string(stub_instrument_status: InstrumentStatus) {
+        assert_eq!(
+            stub_instrument_status.to_string(),
+            "MSFT.XNAS,HALT,VOLATILITY,1"
+        );
+    }
+
+    #[rstest]
+    #[case(MarketStatus::PreOpen, false)]
+    #[case(MarketStatus::Open, true)]
+    #[case(MarketStatus::Halt, false)]
+    #[case(MarketStatus::Reopen, true)]
+    #[case(MarketStatus::Closed, false)]
+    fn test_is_trading(
+        stub_venue_status: VenueStatus,
+        #[case] status: MarketStatus,
+        #[case] expected: bool,
+    ) {
+        let venue_status = VenueStatus {
+            status,
+            ..stub_venue_status
+        };
+
+        assert_eq!(venue_status.is_trading(), expected);
+    }
+
+    #[rstest]
+    fn test_json_serialization(
+        stub_instrument_status: InstrumentStatus,
+        stub_venue_status: VenueStatus,
+    ) {
+        let serialized = stub_instrument_status.as_json_bytes().unwrap();
+        let deserialized = InstrumentStatus::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, stub_instrument_status);
+
+        let serialized = stub_venue_status.as_json_bytes().unwrap();
+        let deserialized = VenueStatus::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized, stub_venue_status);
+    }
+}
@@ -15,22 +15,32 @@
 
 //! Type stubs to facilitate testing.
 
+use std::collections::BTreeMap;
+
 use nautilus_core::nanos::UnixNanos;
 use rstest::fixture;
 
 use super::{
     bar::{Bar, BarSpecification, BarType},
+    borrow::BorrowRateUpdate,
+    custom::{register_custom_data_type, CustomData, DataType},
     deltas::OrderBookDeltas,
-    depth::DEPTH10_LEN,
+    depth::{OrderBookDepth50, DEPTH10_LEN, DEPTH50_LEN},
+    funding::FundingRateUpdate,
+    imbalance::AuctionImbalance,
     quote::QuoteTick,
+    status::{InstrumentStatus, VenueStatus},
     trade::TradeTick,
     OrderBookDelta, OrderBookDepth10,
 };
 use crate::{
     data::order::BookOrder,
-    enums::{AggregationSource, AggressorSide, BarAggregation, BookAction, OrderSide, PriceType},
+    enums::{
+        AggregationSource, AggressorSide, BarAggregation, BookAction, HaltReason, MarketStatus,
+        OrderSide, PriceType,
+    },
     identifiers::{instrument_id::InstrumentId, symbol::Symbol, trade_id::TradeId, venue::Venue},
-    types::{price::Price, quantity::Quantity},
+    types::{currency::Currency, price::Price, quantity::Quantity},
 };
 
 impl Default for QuoteTick {
@@ -272,6 +282,49 @@ pub fn stub_depth10() -> OrderBookDepth10 {
     )
 }
 
+#[fixture]
+pub fn stub_depth50() -> OrderBookDepth50 {
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    let mut bids: [BookOrder; DEPTH50_LEN] = [BookOrder::default(); DEPTH50_LEN];
+    let mut asks: [BookOrder; DEPTH50_LEN] = [BookOrder::default(); DEPTH50_LEN];
+    let mut bid_counts: [u32; DEPTH50_LEN] = [0; DEPTH50_LEN];
+    let mut ask_counts: [u32; DEPTH50_LEN] = [0; DEPTH50_LEN];
+
+    // Populate the top 3 bid and 2 ask levels only
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..3 {
+        bids[i] = BookOrder::new(
+            OrderSide::Buy,
+            Price::new(99.0 - i as f64, 2).unwrap(),
+            Quantity::new(100.0 * (i + 1) as f64, 0).unwrap(),
+            i as u64 + 1,
+        );
+        bid_counts[i] = 1;
+    }
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..2 {
+        asks[i] = BookOrder::new(
+            OrderSide::Sell,
+            Price::new(100.0 + i as f64, 2).unwrap(),
+            Quantity::new(100.0 * (i + 1) as f64, 0).unwrap(),
+            i as u64 + 51,
+        );
+        ask_counts[i] = 1;
+    }
+
+    OrderBookDepth50::new(
+        instrument_id,
+        bids,
+        asks,
+        bid_counts,
+        ask_counts,
+        0,
+        0,
+        1.into(),
+        2.into(),
+    )
+}
+
 #[fixture]
 pub fn stub_book_order() -> BookOrder {
     let price = Price::from("100.00");
@@ -329,11 +382,7 @@ pub fn stub_bar() -> Bar {
         aggregation: BarAggregation::Minute,
         price_type: PriceType::Bid,
     };
-    let bar_type = BarType {
-        instrument_id,
-        spec: bar_spec,
-        aggregation_source: AggregationSource::External,
-    };
+    let bar_type = BarType::new(instrument_id, bar_spec, AggregationSource::External);
     Bar {
         bar_type,
         open: Price::from("1.00001"),
@@ -345,3 +394,73 @@ pub fn stub_bar() -> Bar {
         ts_init: UnixNanos::from(1),
     }
 }
+
+#[fixture]
+pub fn stub_funding_rate() -> FundingRateUpdate {
+    FundingRateUpdate::new(
+        InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+        0.0001,
+        3.into(),
+        1.into(),
+        2.into(),
+    )
+}
+
+#[fixture]
+pub fn stub_borrow_rate() -> BorrowRateUpdate {
+    BorrowRateUpdate::new(
+        Venue::from("BINANCE"),
+        Currency::USDT(),
+        0.0365,
+        1.into(),
+        2.into(),
+    )
+}
+
+#[fixture]
+pub fn stub_instrument_status() -> InstrumentStatus {
+    InstrumentStatus::new(
+        InstrumentId::from("MSFT.XNAS"),
+        MarketStatus::Halt,
+        HaltReason::Volatility,
+        1.into(),
+        2.into(),
+    )
+}
+
+#[fixture]
+pub fn stub_venue_status() -> VenueStatus {
+    VenueStatus::new(
+        Venue::from("XNAS"),
+        MarketStatus::Open,
+        HaltReason::NotHalted,
+        1.into(),
+        2.into(),
+    )
+}
+
+#[fixture]
+pub fn stub_auction_imbalance() -> AuctionImbalance {
+    AuctionImbalance::new(
+        InstrumentId::from("MSFT.XNAS"),
+        Price::from("420.50"),
+        Quantity::from("150000"),
+        Quantity::from("25000"),
+        OrderSide::Buy,
+        1.into(),
+        2.into(),
+    )
+}
+
+#[fixture]
+pub fn stub_custom_data() -> CustomData {
+    register_custom_data_type("NewsEvent").unwrap();
+    let metadata = BTreeMap::from([("source".to_string(), "RSS".to_string())]);
+    CustomData::new(
+        DataType::new("NewsEvent", metadata),
+        b"hello".to_vec(),
+        1.into(),
+        2.into(),
+    )
+    .unwrap()
+}
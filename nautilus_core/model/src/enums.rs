@@ -702,6 +702,7 @@ impl FromU8 for OrderSide {
 }
 
 /// The specified order side (BUY or SELL).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum OrderSideSpecified {
     /// The order is a BUY.
     Buy = 1,
@@ -1120,6 +1121,7 @@ enum_strum_serde!(BookAction);
 enum_strum_serde!(BookType);
 enum_strum_serde!(ContingencyType);
 enum_strum_serde!(CurrencyType);
+enum_strum_serde!(HaltReason);
 enum_strum_serde!(InstrumentCloseType);
 enum_strum_serde!(LiquiditySide);
 enum_strum_serde!(MarketStatus);
@@ -0,0 +1,133 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A compact snapshot of the order book context at the time of an order decision.
+
+use std::fmt::{Display, Formatter};
+
+use nautilus_core::nanos::UnixNanos;
+use serde::{Deserialize, Serialize};
+
+use crate::{data::quote::QuoteTick, orderbook::book::OrderBook, types::price::Price};
+
+/// Represents the top of book context at the time an order was generated.
+///
+/// The context is attached to the [`OrderInitialized`](super::initialized::OrderInitialized)
+/// event and persisted with it, enabling execution-quality analysis (e.g. slippage against the
+/// decision mid) without joining market data streams by timestamp.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookContext {
+    /// The best bid price (if any).
+    pub best_bid: Option<Price>,
+    /// The best ask price (if any).
+    pub best_ask: Option<Price>,
+    /// The UNIX timestamp (nanoseconds) of the book state.
+    pub ts_book: UnixNanos,
+}
+
+impl BookContext {
+    /// Creates a new [`BookContext`] instance.
+    #[must_use]
+    pub fn new(best_bid: Option<Price>, best_ask: Option<Price>, ts_book: UnixNanos) -> Self {
+        Self {
+            best_bid,
+            best_ask,
+            ts_book,
+        }
+    }
+
+    /// Creates a new [`BookContext`] instance from the top of the given `book`.
+    #[must_use]
+    pub fn from_book(book: &OrderBook) -> Self {
+        Self::new(book.best_bid_price(), book.best_ask_price(), book.ts_last)
+    }
+
+    /// Creates a new [`BookContext`] instance from the given `quote`.
+    #[must_use]
+    pub fn from_quote(quote: &QuoteTick) -> Self {
+        Self::new(Some(quote.bid_price), Some(quote.ask_price), quote.ts_event)
+    }
+
+    /// Returns the mid price, if both sides are present.
+    #[must_use]
+    pub fn mid(&self) -> Option<f64> {
+        match (self.best_bid, self.best_ask) {
+            (Some(bid), Some(ask)) => Some((bid.as_f64() + ask.as_f64()) / 2.0),
+            _ => None,
+        }
+    }
+
+    /// Returns the spread, if both sides are present.
+    #[must_use]
+    pub fn spread(&self) -> Option<f64> {
+        match (self.best_bid, self.best_ask) {
+            (Some(bid), Some(ask)) => Some(ask.as_f64() - bid.as_f64()),
+            _ => None,
+        }
+    }
+}
+
+impl Display for BookContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(best_bid={}, best_ask={}, ts_book={})",
+            stringify!(BookContext),
+            self.best_bid.map_or("None".to_string(), |p| p.to_string()),
+            self.best_ask.map_or("None".to_string(), |p| p.to_string()),
+            self.ts_book,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::data::stubs::quote_tick_ethusdt_binance;
+
+    #[rstest]
+    fn test_from_quote(quote_tick_ethusdt_binance: QuoteTick) {
+        let context = BookContext::from_quote(&quote_tick_ethusdt_binance);
+
+        assert_eq!(context.best_bid, Some(quote_tick_ethusdt_binance.bid_price));
+        assert_eq!(context.best_ask, Some(quote_tick_ethusdt_binance.ask_price));
+        assert_eq!(context.ts_book, quote_tick_ethusdt_binance.ts_event);
+    }
+
+    #[rstest]
+    fn test_mid_and_spread() {
+        let context = BookContext::new(
+            Some(Price::from("100.00")),
+            Some(Price::from("100.10")),
+            UnixNanos::default(),
+        );
+
+        assert!((context.mid().unwrap() - 100.05).abs() < 1e-9);
+        assert!((context.spread().unwrap() - 0.10).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_one_sided_book() {
+        let context = BookContext::new(Some(Price::from("100.00")), None, UnixNanos::default());
+
+        assert_eq!(context.mid(), None);
+        assert_eq!(context.spread(), None);
+    }
+}
@@ -28,7 +28,7 @@ use crate::{
         ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TrailingOffsetType,
         TriggerType,
     },
-    events::order::OrderEvent,
+    events::order::{book_context::BookContext, OrderEvent},
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
         instrument_id::InstrumentId, order_list_id::OrderListId, position_id::PositionId,
@@ -81,6 +81,9 @@ pub struct OrderInitialized {
     pub exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
     pub exec_spawn_id: Option<ClientOrderId>,
     pub tags: Option<Vec<Ustr>>,
+    /// The book context at the time the order was generated (if attached).
+    #[serde(default)]
+    pub book_context: Option<BookContext>,
 }
 
 impl Default for OrderInitialized {
@@ -116,6 +119,7 @@ impl Default for OrderInitialized {
             exec_algorithm_params: Default::default(),
             exec_spawn_id: Default::default(),
             tags: Default::default(),
+            book_context: Default::default(),
             event_id: Default::default(),
             ts_event: Default::default(),
             ts_init: Default::default(),
@@ -196,6 +200,7 @@ impl OrderInitialized {
             exec_algorithm_params,
             exec_spawn_id,
             tags,
+            book_context: None,
         })
     }
 }
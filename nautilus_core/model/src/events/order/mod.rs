@@ -14,6 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use strum::{Display, EnumIter};
 use ustr::Ustr;
 
 use crate::{
@@ -32,6 +33,7 @@ use crate::{
 
 pub mod accepted;
 pub mod any;
+pub mod book_context;
 pub mod cancel_rejected;
 pub mod canceled;
 pub mod denied;
@@ -54,7 +56,7 @@ pub mod stubs;
 pub use crate::events::order::any::OrderEventAny;
 
 /// Represents a type of [`OrderEvent`].
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Display, Hash, PartialEq, Eq, EnumIter)]
 pub enum OrderEventType {
     Initialized,
     Denied,
@@ -94,11 +94,7 @@ pub extern "C" fn bar_type_new(
 ) -> BarType {
     let aggregation_source = AggregationSource::from_repr(aggregation_source as usize)
         .expect("Error converting enum from integer");
-    BarType {
-        instrument_id,
-        spec,
-        aggregation_source,
-    }
+    BarType::new(instrument_id, spec, aggregation_source)
 }
 
 /// Returns any [`BarType`] parsing error from the provided C string pointer.
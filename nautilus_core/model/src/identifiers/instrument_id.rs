@@ -57,14 +57,17 @@ impl InstrumentId {
 impl FromStr for InstrumentId {
     type Err = anyhow::Error;
 
+    /// Parses the instrument ID, applying the identifier profile registered for the venue
+    /// (if any).
     fn from_str(s: &str) -> anyhow::Result<Self> {
         match s.rsplit_once('.') {
-            Some((symbol_part, venue_part)) => Ok(Self {
-                symbol: Symbol::new(symbol_part)
-                    .map_err(|e| anyhow::anyhow!(err_message(s, e.to_string())))?,
-                venue: Venue::new(venue_part)
-                    .map_err(|e| anyhow::anyhow!(err_message(s, e.to_string())))?,
-            }),
+            Some((symbol_part, venue_part)) => {
+                let venue = Venue::new_checked(venue_part)
+                    .map_err(|e| anyhow::anyhow!(err_message(s, e.to_string())))?;
+                let symbol = Symbol::new_checked(symbol_part, &venue)
+                    .map_err(|e| anyhow::anyhow!(err_message(s, e.to_string())))?;
+                Ok(Self { symbol, venue })
+            }
             None => {
                 anyhow::bail!(err_message(
                     s,
@@ -0,0 +1,200 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Venue-specific validation and normalization profiles for identifiers.
+//!
+//! A profile registered for a venue is applied when constructing identifiers with the checked
+//! constructors (and when parsing an [`InstrumentId`](super::instrument_id::InstrumentId)), so
+//! that malformed IDs are rejected at construction rather than at the venue.
+
+use std::{collections::HashMap, sync::RwLock};
+
+use once_cell::sync::Lazy;
+
+use super::venue::Venue;
+
+/// The registered identifier profiles per venue.
+static IDENTIFIER_PROFILES: Lazy<RwLock<HashMap<Venue, IdentifierProfile>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// The case rule applied when normalizing an identifier value.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CaseRule {
+    /// The value is left as is.
+    #[default]
+    Preserve,
+    /// The value is converted to uppercase.
+    Upper,
+    /// The value is converted to lowercase.
+    Lower,
+}
+
+/// Represents the validation and normalization rules for a venue's symbols.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IdentifierProfile {
+    /// The case rule applied before validation.
+    pub case_rule: CaseRule,
+    /// The characters permitted in addition to ASCII alphanumerics (any if `None`).
+    pub allowed_chars: Option<String>,
+    /// The maximum length of the value (after normalization).
+    pub max_len: Option<usize>,
+}
+
+impl IdentifierProfile {
+    /// Creates a new [`IdentifierProfile`] instance.
+    #[must_use]
+    pub fn new(case_rule: CaseRule, allowed_chars: Option<&str>, max_len: Option<usize>) -> Self {
+        Self {
+            case_rule,
+            allowed_chars: allowed_chars.map(str::to_string),
+            max_len,
+        }
+    }
+
+    /// Returns the given `value` normalized with the profile's case rule.
+    #[must_use]
+    pub fn normalize(&self, value: &str) -> String {
+        match self.case_rule {
+            CaseRule::Preserve => value.to_string(),
+            CaseRule::Upper => value.to_ascii_uppercase(),
+            CaseRule::Lower => value.to_ascii_lowercase(),
+        }
+    }
+
+    /// Returns the given `value` normalized and validated against the profile.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the normalized value exceeds the maximum length or
+    /// contains a character which is not allowed.
+    pub fn apply(&self, value: &str) -> anyhow::Result<String> {
+        let normalized = self.normalize(value);
+
+        if let Some(max_len) = self.max_len {
+            if normalized.len() > max_len {
+                anyhow::bail!(
+                    "Invalid value '{normalized}', length {} exceeded maximum {max_len}",
+                    normalized.len()
+                );
+            }
+        }
+
+        if let Some(allowed) = &self.allowed_chars {
+            if let Some(c) = normalized
+                .chars()
+                .find(|c| !c.is_ascii_alphanumeric() && !allowed.contains(*c))
+            {
+                anyhow::bail!("Invalid value '{normalized}', contained disallowed char '{c}'");
+            }
+        }
+
+        Ok(normalized)
+    }
+}
+
+/// Registers the given identifier `profile` for the `venue`, replacing any existing profile.
+pub fn register_identifier_profile(venue: Venue, profile: IdentifierProfile) -> anyhow::Result<()> {
+    IDENTIFIER_PROFILES
+        .write()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire lock on `IDENTIFIER_PROFILES`: {e}"))?
+        .insert(venue, profile);
+    Ok(())
+}
+
+/// Deregisters the identifier profile for the `venue`, returning the profile (if registered).
+pub fn deregister_identifier_profile(venue: &Venue) -> anyhow::Result<Option<IdentifierProfile>> {
+    Ok(IDENTIFIER_PROFILES
+        .write()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire lock on `IDENTIFIER_PROFILES`: {e}"))?
+        .remove(venue))
+}
+
+/// Returns the identifier profile registered for the `venue` (if any).
+#[must_use]
+pub fn get_identifier_profile(venue: &Venue) -> Option<IdentifierProfile> {
+    IDENTIFIER_PROFILES
+        .read()
+        .ok()
+        .and_then(|profiles| profiles.get(venue).cloned())
+}
+
+/// Returns the registered venue matching the given `value` case-insensitively (if any).
+#[must_use]
+pub fn find_profiled_venue(value: &str) -> Option<Venue> {
+    IDENTIFIER_PROFILES.read().ok().and_then(|profiles| {
+        profiles
+            .keys()
+            .find(|venue| venue.as_str().eq_ignore_ascii_case(value))
+            .copied()
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::identifiers::{instrument_id::InstrumentId, symbol::Symbol};
+
+    #[rstest]
+    #[case(CaseRule::Preserve, "EthUsdt")]
+    #[case(CaseRule::Upper, "ETHUSDT")]
+    #[case(CaseRule::Lower, "ethusdt")]
+    fn test_normalize(#[case] case_rule: CaseRule, #[case] expected: &str) {
+        let profile = IdentifierProfile::new(case_rule, None, None);
+
+        assert_eq!(profile.normalize("EthUsdt"), expected);
+    }
+
+    #[rstest]
+    #[case("ETH-USDT", true)]
+    #[case("ETH/USDT", false)]
+    #[case("ETH-USDT-PERP", false)]
+    fn test_apply(#[case] value: &str, #[case] is_ok: bool) {
+        let profile = IdentifierProfile::new(CaseRule::Upper, Some("-"), Some(8));
+
+        assert_eq!(profile.apply(value).is_ok(), is_ok);
+    }
+
+    #[rstest]
+    fn test_checked_constructors_with_registered_profile() {
+        // Use a venue unique to this test as the registry is global
+        let venue = Venue::from("XPROFILE");
+        let profile = IdentifierProfile::new(CaseRule::Upper, Some("-"), Some(12));
+        register_identifier_profile(venue, profile.clone()).unwrap();
+
+        assert_eq!(get_identifier_profile(&venue), Some(profile));
+        assert_eq!(Venue::new_checked("xprofile").unwrap(), venue);
+        assert_eq!(
+            Symbol::new_checked("eth-usdt", &venue).unwrap(),
+            Symbol::from("ETH-USDT")
+        );
+        assert_eq!(
+            InstrumentId::from_str("eth-usdt.xprofile").unwrap(),
+            InstrumentId::from("ETH-USDT.XPROFILE")
+        );
+        assert!(InstrumentId::from_str("ETH/USDT.XPROFILE").is_err());
+
+        deregister_identifier_profile(&venue).unwrap();
+
+        assert!(InstrumentId::from_str("ETH/USDT.XPROFILE").is_ok());
+        assert_eq!(Venue::new_checked("xprofile").unwrap().as_str(), "xprofile");
+    }
+}
@@ -23,6 +23,8 @@ use std::{
 use nautilus_core::correctness::check_valid_string;
 use ustr::Ustr;
 
+use super::{profile::get_identifier_profile, venue::Venue};
+
 /// Represents a valid ticker symbol ID for a tradable instrument.
 #[repr(C)]
 #[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -44,6 +46,28 @@ impl Symbol {
         Ok(Self(Ustr::from(value)))
     }
 
+    /// Creates a new [`Symbol`] instance for the given `venue`.
+    ///
+    /// The `value` is normalized and validated with the identifier profile registered for the
+    /// venue (if any).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `value` is not a valid string, or is rejected by the
+    /// venue's profile.
+    pub fn new_checked(value: &str, venue: &Venue) -> anyhow::Result<Self> {
+        match get_identifier_profile(venue) {
+            Some(profile) => {
+                check_valid_string(value, stringify!(value))?;
+                let value = profile
+                    .apply(value)
+                    .map_err(|e| anyhow::anyhow!("Invalid `Symbol` for {venue}: {e}"))?;
+                Ok(Self(Ustr::from(&value)))
+            }
+            None => Self::new(value),
+        }
+    }
+
     /// Sets the inner identifier value.
     pub(crate) fn set_inner(&mut self, value: &str) {
         self.0 = Ustr::from(value);
@@ -23,6 +23,7 @@ use std::{
 use nautilus_core::correctness::check_valid_string;
 use ustr::Ustr;
 
+use super::profile::find_profiled_venue;
 use crate::venues::VENUE_MAP;
 
 pub const SYNTHETIC_VENUE: &str = "SYNTH";
@@ -48,6 +49,18 @@ impl Venue {
         Ok(Self(Ustr::from(value)))
     }
 
+    /// Creates a new [`Venue`] instance, normalized to the venue of a registered identifier
+    /// profile which matches `value` case-insensitively (if any).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `value` is not a valid string.
+    pub fn new_checked(value: &str) -> anyhow::Result<Self> {
+        check_valid_string(value, stringify!(value))?;
+
+        Ok(find_profiled_venue(value).unwrap_or_else(|| Self(Ustr::from(value))))
+    }
+
     /// Sets the inner identifier value.
     pub(crate) fn set_inner(&mut self, value: &str) {
         self.0 = Ustr::from(value);
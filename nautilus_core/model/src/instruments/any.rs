@@ -14,19 +14,24 @@
 // -------------------------------------------------------------------------------------------------
 
 use rust_decimal::Decimal;
+use ustr::Ustr;
 
 use super::{
-    crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
-    equity::Equity, futures_contract::FuturesContract, futures_spread::FuturesSpread,
+    betting::BettingInstrument, binary_option::BinaryOption, crypto_future::CryptoFuture,
+    crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair, equity::Equity,
+    futures_contract::FuturesContract, futures_spread::FuturesSpread,
     options_contract::OptionsContract, options_spread::OptionsSpread, Instrument,
 };
 use crate::{
+    enums::AssetClass,
     identifiers::instrument_id::InstrumentId,
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
 #[derive(Clone, Debug)]
 pub enum InstrumentAny {
+    Betting(BettingInstrument),
+    BinaryOption(BinaryOption),
     CryptoFuture(CryptoFuture),
     CryptoPerpetual(CryptoPerpetual),
     CurrencyPair(CurrencyPair),
@@ -42,6 +47,8 @@ impl InstrumentAny {
     #[must_use]
     pub fn into_instrument(self) -> Box<dyn Instrument> {
         match self {
+            Self::Betting(inst) => Box::new(inst),
+            Self::BinaryOption(inst) => Box::new(inst),
             Self::CryptoFuture(inst) => Box::new(inst),
             Self::CryptoPerpetual(inst) => Box::new(inst),
             Self::CurrencyPair(inst) => Box::new(inst),
@@ -56,6 +63,8 @@ impl InstrumentAny {
     #[must_use]
     pub fn id(&self) -> InstrumentId {
         match self {
+            Self::Betting(inst) => inst.id,
+            Self::BinaryOption(inst) => inst.id,
             Self::CryptoFuture(inst) => inst.id,
             Self::CryptoPerpetual(inst) => inst.id,
             Self::CurrencyPair(inst) => inst.id,
@@ -67,9 +76,43 @@ impl InstrumentAny {
         }
     }
 
+    #[must_use]
+    pub fn asset_class(&self) -> AssetClass {
+        match self {
+            Self::Betting(inst) => inst.asset_class(),
+            Self::BinaryOption(inst) => inst.asset_class(),
+            Self::CryptoFuture(inst) => inst.asset_class(),
+            Self::CryptoPerpetual(inst) => inst.asset_class(),
+            Self::CurrencyPair(inst) => inst.asset_class(),
+            Self::Equity(inst) => inst.asset_class(),
+            Self::FuturesContract(inst) => inst.asset_class(),
+            Self::FuturesSpread(inst) => inst.asset_class(),
+            Self::OptionsContract(inst) => inst.asset_class(),
+            Self::OptionsSpread(inst) => inst.asset_class(),
+        }
+    }
+
+    #[must_use]
+    pub fn underlying(&self) -> Option<Ustr> {
+        match self {
+            Self::Betting(inst) => inst.underlying(),
+            Self::BinaryOption(inst) => inst.underlying(),
+            Self::CryptoFuture(inst) => inst.underlying(),
+            Self::CryptoPerpetual(inst) => inst.underlying(),
+            Self::CurrencyPair(inst) => inst.underlying(),
+            Self::Equity(inst) => inst.underlying(),
+            Self::FuturesContract(inst) => inst.underlying(),
+            Self::FuturesSpread(inst) => inst.underlying(),
+            Self::OptionsContract(inst) => inst.underlying(),
+            Self::OptionsSpread(inst) => inst.underlying(),
+        }
+    }
+
     #[must_use]
     pub fn base_currency(&self) -> Option<Currency> {
         match self {
+            Self::Betting(inst) => inst.base_currency(),
+            Self::BinaryOption(inst) => inst.base_currency(),
             Self::CryptoFuture(inst) => inst.base_currency(),
             Self::CryptoPerpetual(inst) => inst.base_currency(),
             Self::CurrencyPair(inst) => inst.base_currency(),
@@ -84,6 +127,8 @@ impl InstrumentAny {
     #[must_use]
     pub fn quote_currency(&self) -> Currency {
         match self {
+            Self::Betting(inst) => inst.quote_currency(),
+            Self::BinaryOption(inst) => inst.quote_currency(),
             Self::CryptoFuture(inst) => inst.quote_currency(),
             Self::CryptoPerpetual(inst) => inst.quote_currency(),
             Self::CurrencyPair(inst) => inst.quote_currency(),
@@ -98,6 +143,8 @@ impl InstrumentAny {
     #[must_use]
     pub fn settlement_currency(&self) -> Currency {
         match self {
+            Self::Betting(inst) => inst.settlement_currency(),
+            Self::BinaryOption(inst) => inst.settlement_currency(),
             Self::CryptoFuture(inst) => inst.settlement_currency(),
             Self::CryptoPerpetual(inst) => inst.settlement_currency(),
             Self::CurrencyPair(inst) => inst.settlement_currency(),
@@ -112,6 +159,8 @@ impl InstrumentAny {
     #[must_use]
     pub fn is_inverse(&self) -> bool {
         match self {
+            Self::Betting(inst) => inst.is_inverse(),
+            Self::BinaryOption(inst) => inst.is_inverse(),
             Self::CryptoFuture(inst) => inst.is_inverse(),
             Self::CryptoPerpetual(inst) => inst.is_inverse(),
             Self::CurrencyPair(inst) => inst.is_inverse(),
@@ -126,6 +175,8 @@ impl InstrumentAny {
     #[must_use]
     pub fn price_precision(&self) -> u8 {
         match self {
+            Self::Betting(inst) => inst.price_precision(),
+            Self::BinaryOption(inst) => inst.price_precision(),
             Self::CryptoFuture(inst) => inst.price_precision(),
             Self::CryptoPerpetual(inst) => inst.price_precision(),
             Self::CurrencyPair(inst) => inst.price_precision(),
@@ -140,6 +191,8 @@ impl InstrumentAny {
     #[must_use]
     pub fn size_precision(&self) -> u8 {
         match self {
+            Self::Betting(inst) => inst.size_precision(),
+            Self::BinaryOption(inst) => inst.size_precision(),
             Self::CryptoFuture(inst) => inst.size_precision(),
             Self::CryptoPerpetual(inst) => inst.size_precision(),
             Self::CurrencyPair(inst) => inst.size_precision(),
@@ -154,6 +207,8 @@ impl InstrumentAny {
     #[must_use]
     pub fn price_increment(&self) -> Price {
         match self {
+            Self::Betting(inst) => inst.price_increment(),
+            Self::BinaryOption(inst) => inst.price_increment(),
             Self::CryptoFuture(inst) => inst.price_increment(),
             Self::CryptoPerpetual(inst) => inst.price_increment(),
             Self::CurrencyPair(inst) => inst.price_increment(),
@@ -168,6 +223,8 @@ impl InstrumentAny {
     #[must_use]
     pub fn size_increment(&self) -> Quantity {
         match self {
+            Self::Betting(inst) => inst.size_increment(),
+            Self::BinaryOption(inst) => inst.size_increment(),
             Self::CryptoFuture(inst) => inst.size_increment(),
             Self::CryptoPerpetual(inst) => inst.size_increment(),
             Self::CurrencyPair(inst) => inst.size_increment(),
@@ -182,6 +239,8 @@ impl InstrumentAny {
     #[must_use]
     pub fn multiplier(&self) -> Quantity {
         match self {
+            Self::Betting(inst) => inst.multiplier(),
+            Self::BinaryOption(inst) => inst.multiplier(),
             Self::CryptoFuture(inst) => inst.multiplier(),
             Self::CryptoPerpetual(inst) => inst.multiplier(),
             Self::CurrencyPair(inst) => inst.multiplier(),
@@ -195,6 +254,8 @@ impl InstrumentAny {
 
     pub fn make_price(&self, value: f64) -> anyhow::Result<Price> {
         match self {
+            Self::Betting(inst) => inst.make_price(value),
+            Self::BinaryOption(inst) => inst.make_price(value),
             Self::CryptoFuture(inst) => inst.make_price(value),
             Self::CryptoPerpetual(inst) => inst.make_price(value),
             Self::CurrencyPair(inst) => inst.make_price(value),
@@ -208,6 +269,8 @@ impl InstrumentAny {
 
     pub fn make_qty(&self, value: f64) -> anyhow::Result<Quantity> {
         match self {
+            Self::Betting(inst) => inst.make_qty(value),
+            Self::BinaryOption(inst) => inst.make_qty(value),
             Self::CryptoFuture(inst) => inst.make_qty(value),
             Self::CryptoPerpetual(inst) => inst.make_qty(value),
             Self::CurrencyPair(inst) => inst.make_qty(value),
@@ -227,6 +290,12 @@ impl InstrumentAny {
         use_quote_for_inverse: Option<bool>,
     ) -> Money {
         match self {
+            Self::Betting(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
+            Self::BinaryOption(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
             Self::CryptoFuture(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
@@ -254,10 +323,46 @@ impl InstrumentAny {
         }
     }
 
+    // #[deprecated(since = "0.21.0", note = "Will be removed in a future version")]
+    #[must_use]
+    pub fn margin_init(&self) -> Decimal {
+        match self {
+            Self::Betting(inst) => inst.margin_init(),
+            Self::BinaryOption(inst) => inst.margin_init(),
+            Self::CryptoFuture(inst) => inst.margin_init(),
+            Self::CryptoPerpetual(inst) => inst.margin_init(),
+            Self::CurrencyPair(inst) => inst.margin_init(),
+            Self::Equity(inst) => inst.margin_init(),
+            Self::FuturesContract(inst) => inst.margin_init(),
+            Self::FuturesSpread(inst) => inst.margin_init(),
+            Self::OptionsContract(inst) => inst.margin_init(),
+            Self::OptionsSpread(inst) => inst.margin_init(),
+        }
+    }
+
+    // #[deprecated(since = "0.21.0", note = "Will be removed in a future version")]
+    #[must_use]
+    pub fn margin_maint(&self) -> Decimal {
+        match self {
+            Self::Betting(inst) => inst.margin_maint(),
+            Self::BinaryOption(inst) => inst.margin_maint(),
+            Self::CryptoFuture(inst) => inst.margin_maint(),
+            Self::CryptoPerpetual(inst) => inst.margin_maint(),
+            Self::CurrencyPair(inst) => inst.margin_maint(),
+            Self::Equity(inst) => inst.margin_maint(),
+            Self::FuturesContract(inst) => inst.margin_maint(),
+            Self::FuturesSpread(inst) => inst.margin_maint(),
+            Self::OptionsContract(inst) => inst.margin_maint(),
+            Self::OptionsSpread(inst) => inst.margin_maint(),
+        }
+    }
+
     // #[deprecated(since = "0.21.0", note = "Will be removed in a future version")]
     #[must_use]
     pub fn maker_fee(&self) -> Decimal {
         match self {
+            Self::Betting(inst) => inst.maker_fee(),
+            Self::BinaryOption(inst) => inst.maker_fee(),
             Self::CryptoFuture(inst) => inst.maker_fee(),
             Self::CryptoPerpetual(inst) => inst.maker_fee(),
             Self::CurrencyPair(inst) => inst.maker_fee(),
@@ -273,6 +378,8 @@ impl InstrumentAny {
     #[must_use]
     pub fn taker_fee(&self) -> Decimal {
         match self {
+            Self::Betting(inst) => inst.taker_fee(),
+            Self::BinaryOption(inst) => inst.taker_fee(),
             Self::CryptoFuture(inst) => inst.taker_fee(),
             Self::CryptoPerpetual(inst) => inst.taker_fee(),
             Self::CurrencyPair(inst) => inst.taker_fee(),
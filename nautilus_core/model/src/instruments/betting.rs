@@ -0,0 +1,438 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Betting instruments for Betfair-style exchanges, where prices are quoted as decimal odds.
+
+use std::hash::{Hash, Hasher};
+
+use nautilus_core::{
+    correctness::{check_equal_u8, check_in_range_inclusive_f64, check_positive_i64},
+    nanos::UnixNanos,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::{any::InstrumentAny, Instrument};
+use crate::{
+    enums::{AssetClass, InstrumentClass, OptionKind},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    tick_scheme::{tiered::BETFAIR_TICK_SCHEME, TickScheme},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+#[repr(C)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct BettingInstrument {
+    pub id: InstrumentId,
+    pub raw_symbol: Symbol,
+    pub event_type_id: u64,
+    pub event_type_name: Ustr,
+    pub competition_id: u64,
+    pub competition_name: Ustr,
+    pub event_id: u64,
+    pub event_name: Ustr,
+    pub event_country_code: Ustr,
+    pub event_open_date: UnixNanos,
+    pub betting_type: Ustr,
+    pub market_id: Ustr,
+    pub market_name: Ustr,
+    pub market_type: Ustr,
+    pub market_start_time: UnixNanos,
+    pub selection_id: u64,
+    pub selection_name: Ustr,
+    /// The handicap for the selection (if the market is a handicap market).
+    pub selection_handicap: Option<f64>,
+    pub currency: Currency,
+    pub price_precision: u8,
+    pub size_precision: u8,
+    pub price_increment: Price,
+    pub size_increment: Quantity,
+    pub maker_fee: Decimal,
+    pub taker_fee: Decimal,
+    pub margin_init: Decimal,
+    pub margin_maint: Decimal,
+    pub max_quantity: Option<Quantity>,
+    pub min_quantity: Option<Quantity>,
+    pub max_notional: Option<Money>,
+    pub min_notional: Option<Money>,
+    pub max_price: Option<Price>,
+    pub min_price: Option<Price>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl BettingInstrument {
+    /// Creates a new [`BettingInstrument`] instance.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        event_type_id: u64,
+        event_type_name: Ustr,
+        competition_id: u64,
+        competition_name: Ustr,
+        event_id: u64,
+        event_name: Ustr,
+        event_country_code: Ustr,
+        event_open_date: UnixNanos,
+        betting_type: Ustr,
+        market_id: Ustr,
+        market_name: Ustr,
+        market_type: Ustr,
+        market_start_time: UnixNanos,
+        selection_id: u64,
+        selection_name: Ustr,
+        selection_handicap: Option<f64>,
+        currency: Currency,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        maker_fee: Option<Decimal>,
+        taker_fee: Option<Decimal>,
+        margin_init: Option<Decimal>,
+        margin_maint: Option<Decimal>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_notional: Option<Money>,
+        min_notional: Option<Money>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_equal_u8(
+            price_precision,
+            price_increment.precision,
+            stringify!(price_precision),
+            stringify!(price_increment.precision),
+        )?;
+        check_equal_u8(
+            size_precision,
+            size_increment.precision,
+            stringify!(size_precision),
+            stringify!(size_increment.precision),
+        )?;
+        check_positive_i64(price_increment.raw, stringify!(price_increment.raw))?;
+
+        Ok(Self {
+            id,
+            raw_symbol,
+            event_type_id,
+            event_type_name,
+            competition_id,
+            competition_name,
+            event_id,
+            event_name,
+            event_country_code,
+            event_open_date,
+            betting_type,
+            market_id,
+            market_name,
+            market_type,
+            market_start_time,
+            selection_id,
+            selection_name,
+            selection_handicap,
+            currency,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            maker_fee: maker_fee.unwrap_or(0.into()),
+            taker_fee: taker_fee.unwrap_or(0.into()),
+            margin_init: margin_init.unwrap_or(1.into()),
+            margin_maint: margin_maint.unwrap_or(1.into()),
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Returns the decimal odds `price` for the given implied `probability`, rounded to the
+    /// price precision of the instrument.
+    pub fn probability_to_price(&self, probability: f64) -> anyhow::Result<Price> {
+        self.make_price(probability_to_odds(probability)?)
+    }
+
+    /// Returns the implied probability for the given decimal odds `price`.
+    pub fn price_to_probability(&self, price: Price) -> anyhow::Result<f64> {
+        odds_to_probability(price.as_f64())
+    }
+}
+
+/// Returns the symbol for a betting selection in the form `{market_id}-{selection_id}-{handicap}`,
+/// matching the symbology of the Betfair integration.
+pub fn make_symbol(
+    market_id: &str,
+    selection_id: u64,
+    selection_handicap: Option<f64>,
+) -> anyhow::Result<Symbol> {
+    let handicap = selection_handicap.map_or_else(|| "None".to_string(), |h| format!("{h:?}"));
+    let value: String = format!("{market_id}-{selection_id}-{handicap}")
+        .chars()
+        .filter(|c| *c != ' ' && *c != ':')
+        .collect();
+    if value.len() > 32 {
+        anyhow::bail!("Symbol too long ({}): '{value}'", value.len());
+    }
+    Symbol::new(&value)
+}
+
+/// Returns the decimal odds for the given implied `probability`.
+pub fn probability_to_odds(probability: f64) -> anyhow::Result<f64> {
+    check_in_range_inclusive_f64(probability, 0.0, 1.0, stringify!(probability))?;
+    if probability == 0.0 {
+        anyhow::bail!("Invalid `probability` zero, has no decimal odds");
+    }
+    Ok(1.0 / probability)
+}
+
+/// Returns the implied probability for the given decimal `odds`.
+pub fn odds_to_probability(odds: f64) -> anyhow::Result<f64> {
+    if !odds.is_finite() || odds < 1.0 {
+        anyhow::bail!("Invalid decimal `odds`, was {odds}");
+    }
+    Ok(1.0 / odds)
+}
+
+impl PartialEq<Self> for BettingInstrument {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for BettingInstrument {}
+
+impl Hash for BettingInstrument {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Instrument for BettingInstrument {
+    fn into_any(self) -> InstrumentAny {
+        InstrumentAny::Betting(self)
+    }
+
+    fn id(&self) -> InstrumentId {
+        self.id
+    }
+
+    fn raw_symbol(&self) -> Symbol {
+        self.raw_symbol
+    }
+
+    fn asset_class(&self) -> AssetClass {
+        AssetClass::Alternative
+    }
+
+    fn instrument_class(&self) -> InstrumentClass {
+        InstrumentClass::SportsBetting
+    }
+
+    fn underlying(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        None
+    }
+
+    fn quote_currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn settlement_currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn isin(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn option_kind(&self) -> Option<OptionKind> {
+        None
+    }
+
+    fn exchange(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn strike_price(&self) -> Option<Price> {
+        None
+    }
+
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        Some(self.event_open_date)
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        None
+    }
+
+    fn is_inverse(&self) -> bool {
+        false
+    }
+
+    fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    fn size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    fn price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    fn size_increment(&self) -> Quantity {
+        self.size_increment
+    }
+
+    fn multiplier(&self) -> Quantity {
+        Quantity::from(1)
+    }
+
+    fn lot_size(&self) -> Option<Quantity> {
+        Some(Quantity::from(1))
+    }
+
+    fn max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    fn min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    fn max_notional(&self) -> Option<Money> {
+        self.max_notional
+    }
+
+    fn min_notional(&self) -> Option<Money> {
+        self.min_notional
+    }
+
+    fn max_price(&self) -> Option<Price> {
+        self.max_price
+    }
+
+    fn min_price(&self) -> Option<Price> {
+        self.min_price
+    }
+
+    fn margin_init(&self) -> Decimal {
+        self.margin_init
+    }
+
+    fn margin_maint(&self) -> Decimal {
+        self.margin_maint
+    }
+
+    fn maker_fee(&self) -> Decimal {
+        self.maker_fee
+    }
+
+    fn taker_fee(&self) -> Decimal {
+        self.taker_fee
+    }
+
+    fn tick_scheme(&self) -> Option<&dyn TickScheme> {
+        Some(&*BETFAIR_TICK_SCHEME)
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::instruments::stubs::*;
+
+    #[rstest]
+    fn test_equality(betting: BettingInstrument) {
+        let cloned = betting;
+        assert_eq!(betting, cloned);
+    }
+
+    #[rstest]
+    fn test_instrument_class(betting: BettingInstrument) {
+        assert_eq!(betting.asset_class(), AssetClass::Alternative);
+        assert_eq!(betting.instrument_class(), InstrumentClass::SportsBetting);
+        assert_eq!(betting.id.symbol.as_str(), "1.180737193-2426838-None");
+    }
+
+    #[rstest]
+    fn test_next_prices_use_betfair_ladder(betting: BettingInstrument) {
+        assert_eq!(betting.next_bid_price(3.01, 0), Some(Price::from("3.00")));
+        assert_eq!(betting.next_ask_price(3.01, 0), Some(Price::from("3.05")));
+        assert!(betting.is_valid_price(Price::from("3.05")));
+        assert!(!betting.is_valid_price(Price::from("3.01")));
+    }
+
+    #[rstest]
+    #[case(None, "1.201070830-123456-None")]
+    #[case(Some(-1.5), "1.201070830-123456--1.5")]
+    #[case(Some(2.0), "1.201070830-123456-2.0")]
+    fn test_make_symbol(#[case] handicap: Option<f64>, #[case] expected: &str) {
+        let symbol = make_symbol("1.201070830", 123_456, handicap).unwrap();
+        assert_eq!(symbol.as_str(), expected);
+    }
+
+    #[rstest]
+    fn test_probability_price_round_trip(betting: BettingInstrument) {
+        let price = betting.probability_to_price(0.4).unwrap();
+        assert_eq!(price, Price::from("2.50"));
+        assert_eq!(betting.price_to_probability(price).unwrap(), 0.4);
+    }
+
+    #[rstest]
+    #[case(0.0)]
+    #[case(-0.1)]
+    #[case(1.1)]
+    fn test_probability_to_odds_invalid(#[case] probability: f64) {
+        assert!(probability_to_odds(probability).is_err());
+    }
+
+    #[rstest]
+    fn test_odds_to_probability_invalid() {
+        assert!(odds_to_probability(0.5).is_err());
+        assert!(odds_to_probability(f64::INFINITY).is_err());
+    }
+}
@@ -0,0 +1,341 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Binary option instruments, which pay out a fixed amount if an outcome occurs and nothing
+//! otherwise, so prices are quoted as probabilities between zero and one.
+
+use std::hash::{Hash, Hasher};
+
+use nautilus_core::{
+    correctness::{
+        check_equal_u8, check_in_range_inclusive_f64, check_positive_i64,
+        check_valid_string_optional,
+    },
+    nanos::UnixNanos,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::{any::InstrumentAny, Instrument};
+use crate::{
+    enums::{AssetClass, InstrumentClass, OptionKind},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+#[repr(C)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct BinaryOption {
+    pub id: InstrumentId,
+    pub raw_symbol: Symbol,
+    pub asset_class: AssetClass,
+    pub currency: Currency,
+    pub activation_ns: UnixNanos,
+    pub expiration_ns: UnixNanos,
+    pub price_precision: u8,
+    pub size_precision: u8,
+    pub price_increment: Price,
+    pub size_increment: Quantity,
+    /// The outcome which results in a payout (e.g. "Yes").
+    pub outcome: Option<Ustr>,
+    /// The description of the event the option settles on.
+    pub description: Option<Ustr>,
+    pub maker_fee: Decimal,
+    pub taker_fee: Decimal,
+    pub margin_init: Decimal,
+    pub margin_maint: Decimal,
+    pub max_quantity: Option<Quantity>,
+    pub min_quantity: Option<Quantity>,
+    pub max_notional: Option<Money>,
+    pub min_notional: Option<Money>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl BinaryOption {
+    /// Creates a new [`BinaryOption`] instance.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        asset_class: AssetClass,
+        currency: Currency,
+        activation_ns: UnixNanos,
+        expiration_ns: UnixNanos,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        outcome: Option<Ustr>,
+        description: Option<Ustr>,
+        maker_fee: Option<Decimal>,
+        taker_fee: Option<Decimal>,
+        margin_init: Option<Decimal>,
+        margin_maint: Option<Decimal>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_notional: Option<Money>,
+        min_notional: Option<Money>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_valid_string_optional(outcome.map(|u| u.as_str()), stringify!(outcome))?;
+        check_valid_string_optional(description.map(|u| u.as_str()), stringify!(description))?;
+        check_equal_u8(
+            price_precision,
+            price_increment.precision,
+            stringify!(price_precision),
+            stringify!(price_increment.precision),
+        )?;
+        check_equal_u8(
+            size_precision,
+            size_increment.precision,
+            stringify!(size_precision),
+            stringify!(size_increment.precision),
+        )?;
+        check_positive_i64(price_increment.raw, stringify!(price_increment.raw))?;
+
+        Ok(Self {
+            id,
+            raw_symbol,
+            asset_class,
+            currency,
+            activation_ns,
+            expiration_ns,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            outcome,
+            description,
+            maker_fee: maker_fee.unwrap_or(0.into()),
+            taker_fee: taker_fee.unwrap_or(0.into()),
+            margin_init: margin_init.unwrap_or(0.into()),
+            margin_maint: margin_maint.unwrap_or(0.into()),
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Returns the price for the given outcome `probability`, rounded to the price precision
+    /// of the instrument.
+    pub fn probability_to_price(&self, probability: f64) -> anyhow::Result<Price> {
+        check_in_range_inclusive_f64(probability, 0.0, 1.0, stringify!(probability))?;
+        self.make_price(probability)
+    }
+
+    /// Returns the implied outcome probability for the given `price`.
+    pub fn price_to_probability(&self, price: Price) -> anyhow::Result<f64> {
+        let probability = price.as_f64();
+        check_in_range_inclusive_f64(probability, 0.0, 1.0, stringify!(price))?;
+        Ok(probability)
+    }
+}
+
+impl PartialEq<Self> for BinaryOption {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for BinaryOption {}
+
+impl Hash for BinaryOption {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Instrument for BinaryOption {
+    fn into_any(self) -> InstrumentAny {
+        InstrumentAny::BinaryOption(self)
+    }
+
+    fn id(&self) -> InstrumentId {
+        self.id
+    }
+
+    fn raw_symbol(&self) -> Symbol {
+        self.raw_symbol
+    }
+
+    fn asset_class(&self) -> AssetClass {
+        self.asset_class
+    }
+
+    fn instrument_class(&self) -> InstrumentClass {
+        InstrumentClass::Option
+    }
+
+    fn underlying(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        None
+    }
+
+    fn quote_currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn settlement_currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn isin(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn option_kind(&self) -> Option<OptionKind> {
+        None
+    }
+
+    fn exchange(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn strike_price(&self) -> Option<Price> {
+        None
+    }
+
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        Some(self.activation_ns)
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        Some(self.expiration_ns)
+    }
+
+    fn is_inverse(&self) -> bool {
+        false
+    }
+
+    fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    fn size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    fn price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    fn size_increment(&self) -> Quantity {
+        self.size_increment
+    }
+
+    fn multiplier(&self) -> Quantity {
+        Quantity::from(1)
+    }
+
+    fn lot_size(&self) -> Option<Quantity> {
+        Some(Quantity::from(1))
+    }
+
+    fn max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    fn min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    fn max_notional(&self) -> Option<Money> {
+        self.max_notional
+    }
+
+    fn min_notional(&self) -> Option<Money> {
+        self.min_notional
+    }
+
+    fn max_price(&self) -> Option<Price> {
+        Some(Price::new(1.0, self.price_precision).unwrap())
+    }
+
+    fn min_price(&self) -> Option<Price> {
+        Some(Price::new(0.0, self.price_precision).unwrap())
+    }
+
+    fn margin_init(&self) -> Decimal {
+        self.margin_init
+    }
+
+    fn margin_maint(&self) -> Decimal {
+        self.margin_maint
+    }
+
+    fn maker_fee(&self) -> Decimal {
+        self.maker_fee
+    }
+
+    fn taker_fee(&self) -> Decimal {
+        self.taker_fee
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::instruments::stubs::*;
+
+    #[rstest]
+    fn test_equality(binary_option: BinaryOption) {
+        let cloned = binary_option;
+        assert_eq!(binary_option, cloned);
+    }
+
+    #[rstest]
+    fn test_price_bounds(binary_option: BinaryOption) {
+        assert_eq!(binary_option.min_price(), Some(Price::from("0.000")));
+        assert_eq!(binary_option.max_price(), Some(Price::from("1.000")));
+    }
+
+    #[rstest]
+    fn test_probability_price_round_trip(binary_option: BinaryOption) {
+        let price = binary_option.probability_to_price(0.6523).unwrap();
+        assert_eq!(price, Price::from("0.652"));
+        assert_eq!(binary_option.price_to_probability(price).unwrap(), 0.652);
+        assert!(binary_option.probability_to_price(1.5).is_err());
+        assert!(binary_option
+            .price_to_probability(Price::from("1.500"))
+            .is_err());
+    }
+}
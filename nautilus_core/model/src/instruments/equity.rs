@@ -253,11 +253,28 @@ impl Instrument for Equity {
 mod tests {
     use rstest::rstest;
 
-    use crate::instruments::{equity::Equity, stubs::*};
+    use crate::{
+        instruments::{equity::Equity, stubs::*, Instrument},
+        types::price::Price,
+    };
 
     #[rstest]
     fn test_equality(equity_aapl: Equity) {
         let cloned = equity_aapl;
         assert_eq!(equity_aapl, cloned);
     }
+
+    #[rstest]
+    fn test_next_prices_use_price_increment(equity_aapl: Equity) {
+        assert_eq!(
+            equity_aapl.next_bid_price(189.505, 0),
+            Some(Price::from("189.50"))
+        );
+        assert_eq!(
+            equity_aapl.next_ask_price(189.505, 1),
+            Some(Price::from("189.52"))
+        );
+        assert!(equity_aapl.is_valid_price(Price::from("189.50")));
+        assert!(!equity_aapl.is_valid_price(Price::from("189.505")));
+    }
 }
@@ -16,6 +16,8 @@
 //! Instrument definitions for the trading domain model.
 
 pub mod any;
+pub mod betting;
+pub mod binary_option;
 pub mod crypto_future;
 pub mod crypto_perpetual;
 pub mod currency_pair;
@@ -38,7 +40,14 @@ use self::any::InstrumentAny;
 use crate::{
     enums::{AssetClass, InstrumentClass, OptionKind},
     identifiers::{instrument_id::InstrumentId, symbol::Symbol, venue::Venue},
-    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+    tick_scheme::{
+        fixed::{next_ask_price_fixed, next_bid_price_fixed},
+        TickScheme,
+    },
+    types::{
+        currency::Currency, money::Money, price::Price, quantity::Quantity,
+        rounding::find_rounding_policy,
+    },
 };
 
 pub trait Instrument: 'static + Send {
@@ -95,13 +104,23 @@ pub trait Instrument: 'static + Send {
     fn ts_init(&self) -> UnixNanos;
 
     /// Creates a new `Price` from the given `value` with the correct price precision for the instrument.
+    ///
+    /// The value is rounded with the registered rounding policy for the instrument (if any).
     fn make_price(&self, value: f64) -> anyhow::Result<Price> {
-        Price::new(value, self.price_precision())
+        match find_rounding_policy(&self.id()) {
+            Some(policy) => policy.round_price(value, self.price_precision()),
+            None => Price::new(value, self.price_precision()),
+        }
     }
 
     /// Creates a new `Quantity` from the given `value` with the correct size precision for the instrument.
+    ///
+    /// The value is rounded with the registered rounding policy for the instrument (if any).
     fn make_qty(&self, value: f64) -> anyhow::Result<Quantity> {
-        Quantity::new(value, self.size_precision())
+        match find_rounding_policy(&self.id()) {
+            Some(policy) => policy.round_qty(value, self.size_precision()),
+            None => Quantity::new(value, self.size_precision()),
+        }
     }
 
     /// Calculates the notional value from the given parameters.
@@ -140,6 +159,46 @@ pub trait Instrument: 'static + Send {
     /// Returns the equivalent quantity of the base asset.
     fn calculate_base_quantity(&self, quantity: Quantity, last_px: Price) -> Quantity {
         let value = quantity.as_f64() * (1.0 / last_px.as_f64());
-        Quantity::new(value, self.size_precision()).unwrap() // TODO: Handle error properly
+        self.make_qty(value).unwrap() // TODO: Handle error properly
+    }
+
+    /// Returns the tick scheme for the instrument (if the price increment is not fixed).
+    fn tick_scheme(&self) -> Option<&dyn TickScheme> {
+        None
+    }
+
+    /// Returns the price `n` bid ticks below `value`, where `n` of zero returns the nearest
+    /// valid price at or below `value` (or `None` if beyond the minimum price).
+    ///
+    /// The tick scheme is used if the instrument has one, otherwise the price increment.
+    fn next_bid_price(&self, value: f64, n: usize) -> Option<Price> {
+        let price = match self.tick_scheme() {
+            Some(tick_scheme) => tick_scheme.next_bid_price(value, n)?,
+            None => next_bid_price_fixed(self.price_increment(), value, n)?,
+        };
+        match self.min_price() {
+            Some(min_price) if price < min_price => None,
+            _ => Some(price),
+        }
+    }
+
+    /// Returns the price `n` ask ticks above `value`, where `n` of zero returns the nearest
+    /// valid price at or above `value` (or `None` if beyond the maximum price).
+    ///
+    /// The tick scheme is used if the instrument has one, otherwise the price increment.
+    fn next_ask_price(&self, value: f64, n: usize) -> Option<Price> {
+        let price = match self.tick_scheme() {
+            Some(tick_scheme) => tick_scheme.next_ask_price(value, n)?,
+            None => next_ask_price_fixed(self.price_increment(), value, n)?,
+        };
+        match self.max_price() {
+            Some(max_price) if price > max_price => None,
+            _ => Some(price),
+        }
+    }
+
+    /// Returns whether the given `price` is a valid price for the instrument.
+    fn is_valid_price(&self, price: Price) -> bool {
+        self.next_bid_price(price.as_f64(), 0) == Some(price)
     }
 }
@@ -20,7 +20,11 @@ use rust_decimal_macros::dec;
 use ustr::Ustr;
 
 use super::{
-    futures_spread::FuturesSpread, options_spread::OptionsSpread, synthetic::SyntheticInstrument,
+    betting::{make_symbol, BettingInstrument},
+    binary_option::BinaryOption,
+    futures_spread::FuturesSpread,
+    options_spread::OptionsSpread,
+    synthetic::SyntheticInstrument,
 };
 use crate::{
     enums::{AssetClass, OptionKind},
@@ -51,6 +55,86 @@ impl Default for SyntheticInstrument {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// BettingInstrument
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn betting() -> BettingInstrument {
+    let symbol = make_symbol("1.180737193", 2_426_838, None).unwrap();
+    BettingInstrument::new(
+        InstrumentId::new(symbol, Venue::from("BETFAIR")),
+        symbol,
+        6423,
+        Ustr::from("American Football"),
+        12_282_733,
+        Ustr::from("NFL"),
+        29_678_534,
+        Ustr::from("NFL"),
+        Ustr::from("GB"),
+        UnixNanos::from(1_612_900_800_000_000_000),
+        Ustr::from("ODDS"),
+        Ustr::from("1.180737193"),
+        Ustr::from("AFC Conference Winner"),
+        Ustr::from("SPECIAL"),
+        UnixNanos::from(1_612_900_800_000_000_000),
+        2_426_838,
+        Ustr::from("Kansas City Chiefs"),
+        None,
+        Currency::GBP(),
+        2,
+        2,
+        Price::from("0.01"),
+        Quantity::from("0.01"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Price::from("1000.00")),
+        Some(Price::from("1.01")),
+        0.into(),
+        0.into(),
+    )
+    .unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// BinaryOption
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn binary_option() -> BinaryOption {
+    BinaryOption::new(
+        InstrumentId::from("ELECTION-2024-YES.POLYMARKET"),
+        Symbol::from("ELECTION-2024-YES"),
+        AssetClass::Alternative,
+        Currency::USDC(),
+        UnixNanos::from(1_726_200_000_000_000_000),
+        UnixNanos::from(1_730_851_200_000_000_000),
+        3,
+        2,
+        Price::from("0.001"),
+        Quantity::from("0.01"),
+        Some(Ustr::from("Yes")),
+        Some(Ustr::from("Will the outcome of this market be 'Yes'?")),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        0.into(),
+        0.into(),
+    )
+    .unwrap()
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // CryptoFuture
 ////////////////////////////////////////////////////////////////////////////////
@@ -19,7 +19,10 @@ use std::{
 };
 
 use derive_builder::Builder;
-use evalexpr::{ContextWithMutableVariables, HashMapContext, Node, Value};
+use evalexpr::{
+    ContextWithMutableFunctions, ContextWithMutableVariables, EvalexprError, EvalexprResult,
+    Function, HashMapContext, Node, Value,
+};
 use nautilus_core::nanos::UnixNanos;
 
 use crate::{
@@ -27,6 +30,19 @@ use crate::{
     types::price::Price,
 };
 
+/// The functions supported in synthetic instrument formulas.
+///
+/// - `min(a, b, ...)` and `max(a, b, ...)`: The minimum and maximum of the arguments.
+/// - `abs(x)`: The absolute value of `x`.
+/// - `floor(x)`, `ceil(x)` and `round(x)`: Rounding to an integral value.
+/// - `clamp(x, lower, upper)`: The value `x` bounded to [`lower`, `upper`].
+/// - `if(condition, a, b)`: The value `a` if the condition holds, otherwise `b`.
+/// - `wsum(x1, w1, x2, w2, ...)`: The sum of the values `x` multiplied by the weights `w`.
+/// - `wavg(x1, w1, x2, w2, ...)`: The average of the values `x` weighted by the weights `w`.
+pub const SYNTHETIC_FUNCTIONS: [&str; 10] = [
+    "min", "max", "abs", "floor", "ceil", "round", "clamp", "if", "wsum", "wavg",
+];
+
 /// Represents a synthetic instrument with prices derived from component instruments using a
 /// formula.
 #[derive(Clone, Debug, Builder)]
@@ -49,6 +65,11 @@ pub struct SyntheticInstrument {
 
 impl SyntheticInstrument {
     /// Creates a new [`SyntheticInstrument`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the `formula` cannot be parsed, references a variable
+    /// which is not one of the `components`, or calls an unsupported function.
     pub fn new(
         symbol: Symbol,
         price_precision: u8,
@@ -65,7 +86,7 @@ impl SyntheticInstrument {
             .map(std::string::ToString::to_string)
             .collect();
 
-        let operator_tree = evalexpr::build_operator_tree(&formula)?;
+        let operator_tree = build_validated_tree(&formula, &variables)?;
 
         Ok(Self {
             id: InstrumentId::new(symbol, Venue::synthetic()),
@@ -73,7 +94,7 @@ impl SyntheticInstrument {
             price_increment,
             components,
             formula,
-            context: HashMapContext::new(),
+            context: create_context()?,
             variables,
             operator_tree,
             ts_event,
@@ -81,13 +102,19 @@ impl SyntheticInstrument {
         })
     }
 
+    /// Returns whether the given `formula` is valid for the components of the instrument.
     #[must_use]
     pub fn is_valid_formula(&self, formula: &str) -> bool {
-        evalexpr::build_operator_tree(formula).is_ok()
+        build_validated_tree(formula, &self.variables).is_ok()
     }
 
+    /// Changes the formula of the synthetic instrument.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the `formula` is not valid for the components.
     pub fn change_formula(&mut self, formula: String) -> anyhow::Result<()> {
-        let operator_tree = evalexpr::build_operator_tree(&formula)?;
+        let operator_tree = build_validated_tree(&formula, &self.variables)?;
         self.formula = formula;
         self.operator_tree = operator_tree;
         Ok(())
@@ -95,20 +122,35 @@ impl SyntheticInstrument {
 
     /// Calculates the price of the synthetic instrument based on the given component input prices
     /// provided as a map.
-    #[allow(dead_code)]
+    ///
+    /// # Panics
+    ///
+    /// This function panics if a component price is missing from `inputs`, see
+    /// [`Self::try_calculate`] for the non-panicking version.
     pub fn calculate_from_map(&mut self, inputs: &HashMap<String, f64>) -> anyhow::Result<Price> {
-        let mut input_values = Vec::new();
-
-        for variable in &self.variables {
-            if let Some(&value) = inputs.get(variable) {
-                input_values.push(value);
-                self.context
-                    .set_value(variable.clone(), Value::from(value))?;
-            } else {
-                panic!("Missing price for component: {variable}");
-            }
+        if let Some(variable) = self.missing_component(inputs) {
+            panic!("Missing price for component: {variable}");
+        }
+        self.try_calculate(inputs)
+    }
+
+    /// Calculates the price of the synthetic instrument based on the given component input prices
+    /// provided as a map.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a component price is missing from `inputs`, or the
+    /// formula does not evaluate to a finite number.
+    pub fn try_calculate(&mut self, inputs: &HashMap<String, f64>) -> anyhow::Result<Price> {
+        if let Some(variable) = self.missing_component(inputs) {
+            anyhow::bail!("Missing price for component: {variable}");
         }
 
+        let input_values: Vec<f64> = self
+            .variables
+            .iter()
+            .map(|variable| inputs[variable])
+            .collect();
         self.calculate(&input_values)
     }
 
@@ -126,13 +168,105 @@ impl SyntheticInstrument {
 
         let result: Value = self.operator_tree.eval_with_context(&self.context)?;
 
-        match result {
-            Value::Float(price) => Price::new(price, self.price_precision),
-            _ => Err(anyhow::anyhow!(
-                "Failed to evaluate formula to a floating point number"
-            )),
+        let price = match result {
+            Value::Float(price) => price,
+            Value::Int(price) => price as f64,
+            _ => anyhow::bail!("Failed to evaluate formula to a floating point number"),
+        };
+        if !price.is_finite() {
+            anyhow::bail!("Formula evaluated to a non-finite number, was {price}");
+        }
+        Price::new(price, self.price_precision)
+    }
+
+    fn missing_component(&self, inputs: &HashMap<String, f64>) -> Option<&str> {
+        self.variables
+            .iter()
+            .find(|variable| !inputs.contains_key(*variable))
+            .map(String::as_str)
+    }
+}
+
+fn build_validated_tree(formula: &str, variables: &[String]) -> anyhow::Result<Node> {
+    let operator_tree = evalexpr::build_operator_tree(formula)?;
+
+    for identifier in operator_tree.iter_variable_identifiers() {
+        if !variables.iter().any(|variable| variable == identifier) {
+            anyhow::bail!("Invalid formula, '{identifier}' is not a component");
+        }
+    }
+    for identifier in operator_tree.iter_function_identifiers() {
+        if !SYNTHETIC_FUNCTIONS.contains(&identifier) {
+            anyhow::bail!("Invalid formula, function '{identifier}' is not supported");
         }
     }
+
+    Ok(operator_tree)
+}
+
+fn create_context() -> anyhow::Result<HashMapContext> {
+    let mut context = HashMapContext::new();
+
+    context.set_function(
+        "abs".to_string(),
+        Function::new(|argument| Ok(Value::Float(argument.as_number()?.abs()))),
+    )?;
+    context.set_function(
+        "clamp".to_string(),
+        Function::new(|argument| {
+            let arguments = argument.as_fixed_len_tuple(3)?;
+            let value = arguments[0].as_number()?;
+            let lower = arguments[1].as_number()?;
+            let upper = arguments[2].as_number()?;
+            if lower > upper {
+                return Err(EvalexprError::CustomMessage(format!(
+                    "Invalid `clamp` bounds, lower {lower} was greater than upper {upper}"
+                )));
+            }
+            Ok(Value::Float(value.clamp(lower, upper)))
+        }),
+    )?;
+    context.set_function(
+        "wsum".to_string(),
+        Function::new(|argument| {
+            let (sum, _) = weighted_sums(argument)?;
+            Ok(Value::Float(sum))
+        }),
+    )?;
+    context.set_function(
+        "wavg".to_string(),
+        Function::new(|argument| {
+            let (sum, total_weight) = weighted_sums(argument)?;
+            if total_weight == 0.0 {
+                return Err(EvalexprError::CustomMessage(
+                    "Invalid `wavg` weights, total weight was zero".to_string(),
+                ));
+            }
+            Ok(Value::Float(sum / total_weight))
+        }),
+    )?;
+
+    Ok(context)
+}
+
+/// Returns the weighted sum and total weight of the (value, weight) pairs in `argument`.
+fn weighted_sums(argument: &Value) -> EvalexprResult<(f64, f64)> {
+    let arguments = argument.as_tuple()?;
+    if arguments.is_empty() || arguments.len() % 2 != 0 {
+        return Err(EvalexprError::CustomMessage(format!(
+            "Expected (value, weight) pairs, was {} arguments",
+            arguments.len()
+        )));
+    }
+
+    let mut sum = 0.0;
+    let mut total_weight = 0.0;
+    for pair in arguments.chunks(2) {
+        let weight = pair[1].as_number()?;
+        sum += pair[0].as_number()? * weight;
+        total_weight += weight;
+    }
+    Ok((sum, total_weight))
 }
 
 impl PartialEq<Self> for SyntheticInstrument {
@@ -195,4 +329,66 @@ mod tests {
         assert_eq!(price.as_f64(), 75.0);
         assert_eq!(synth.formula, new_formula);
     }
+
+    #[rstest]
+    fn test_new_with_unknown_component() {
+        let result = SyntheticInstrument::new(
+            Symbol::new("BTC-LTC").unwrap(),
+            2,
+            vec![InstrumentId::from("BTC.BINANCE")],
+            "(BTC.BINANCE + LTC.BINANCE) / 2.0".to_string(),
+            0.into(),
+            0.into(),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_change_formula_with_unsupported_function() {
+        let mut synth = SyntheticInstrument::default();
+
+        assert!(!synth.is_valid_formula("math::ln(BTC.BINANCE)"));
+        assert!(synth
+            .change_formula("math::ln(BTC.BINANCE)".to_string())
+            .is_err());
+        assert_eq!(synth.formula, "(BTC.BINANCE + LTC.BINANCE) / 2.0");
+    }
+
+    #[rstest]
+    #[case("max(BTC.BINANCE, LTC.BINANCE)", 200.0)]
+    #[case("min(BTC.BINANCE, LTC.BINANCE)", 100.0)]
+    #[case("abs(BTC.BINANCE - LTC.BINANCE)", 100.0)]
+    #[case("clamp(LTC.BINANCE - BTC.BINANCE, 0.0, 50.0)", 50.0)]
+    #[case("if(BTC.BINANCE > LTC.BINANCE, BTC.BINANCE, LTC.BINANCE)", 200.0)]
+    #[case("wsum(BTC.BINANCE, 0.5, LTC.BINANCE, 2.0)", 450.0)]
+    #[case("wavg(BTC.BINANCE, 3.0, LTC.BINANCE, 1.0)", 125.0)]
+    fn test_calculate_with_functions(#[case] formula: &str, #[case] expected: f64) {
+        let mut synth = SyntheticInstrument::default();
+        synth.change_formula(formula.to_string()).unwrap();
+
+        let price = synth.calculate(&[100.0, 200.0]).unwrap();
+
+        assert_eq!(price.as_f64(), expected);
+    }
+
+    #[rstest]
+    fn test_calculate_with_invalid_weights() {
+        let mut synth = SyntheticInstrument::default();
+        synth
+            .change_formula("wavg(BTC.BINANCE, 1.0, LTC.BINANCE, -1.0)".to_string())
+            .unwrap();
+
+        assert!(synth.calculate(&[100.0, 200.0]).is_err());
+    }
+
+    #[rstest]
+    fn test_try_calculate_with_missing_component() {
+        let mut synth = SyntheticInstrument::default();
+        let inputs = HashMap::from([("BTC.BINANCE".to_string(), 100.0)]);
+
+        let result = synth.try_calculate(&inputs);
+
+        assert!(result.is_err());
+    }
 }
@@ -28,6 +28,7 @@
 //! - `python`: Enables Python bindings from `pyo3`
 //! - `stubs`: Enables type stubs for use in testing scenarios
 
+pub mod analytics;
 pub mod currencies;
 pub mod data;
 pub mod enums;
@@ -38,6 +39,8 @@ pub mod macros;
 pub mod orderbook;
 pub mod orders;
 pub mod position;
+pub mod reports;
+pub mod tick_scheme;
 pub mod types;
 pub mod venues;
 
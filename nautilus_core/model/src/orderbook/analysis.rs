@@ -78,6 +78,12 @@ pub fn get_avg_px_for_quantity(qty: Quantity, levels: &BTreeMap<BookPrice, Level
     }
 }
 
+/// Calculates the total size of the top `depth` levels from a set of order book levels.
+#[must_use]
+pub fn get_size_for_depth(depth: usize, levels: &BTreeMap<BookPrice, Level>) -> f64 {
+    levels.values().take(depth).map(Level::size).sum()
+}
+
 pub fn book_check_integrity(book: &OrderBook) -> Result<(), BookIntegrityError> {
     match book.book_type {
         BookType::L1_MBP => {
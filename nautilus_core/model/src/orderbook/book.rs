@@ -17,12 +17,20 @@
 
 use nautilus_core::nanos::UnixNanos;
 
-use super::{aggregation::pre_process_order, analysis, display::pprint_book, level::Level};
+use super::{
+    aggregation::pre_process_order,
+    analysis,
+    display::pprint_book,
+    level::{Level, QueuePosition},
+};
 use crate::{
     data::{
-        delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10, order::BookOrder,
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::{OrderBookDepth10, DEPTH10_LEN},
+        order::{BookOrder, OrderId, NULL_ORDER},
     },
-    enums::{BookAction, BookType, OrderSide, OrderSideSpecified},
+    enums::{BookAction, BookType, OrderSide, OrderSideSpecified, RecordFlag},
     identifiers::instrument_id::InstrumentId,
     orderbook::{error::BookIntegrityError, ladder::Ladder},
     types::{price::Price, quantity::Quantity},
@@ -155,6 +163,40 @@ impl OrderBook {
         }
     }
 
+    /// Returns a snapshot of the top `depth` levels per side (capped at 10).
+    ///
+    /// Each level is aggregated into a single order, and any unused levels are null orders.
+    #[must_use]
+    pub fn to_depth10(&self, depth: usize, ts_init: UnixNanos) -> OrderBookDepth10 {
+        let depth = depth.min(DEPTH10_LEN);
+        let mut bids = [NULL_ORDER; DEPTH10_LEN];
+        let mut asks = [NULL_ORDER; DEPTH10_LEN];
+        let mut bid_counts = [0; DEPTH10_LEN];
+        let mut ask_counts = [0; DEPTH10_LEN];
+
+        for (i, level) in self.bids().take(depth).enumerate() {
+            bids[i] = level_as_order(level);
+            bid_counts[i] = level.len() as u32;
+        }
+
+        for (i, level) in self.asks().take(depth).enumerate() {
+            asks[i] = level_as_order(level);
+            ask_counts[i] = level.len() as u32;
+        }
+
+        OrderBookDepth10::new(
+            self.instrument_id,
+            bids,
+            asks,
+            bid_counts,
+            ask_counts,
+            RecordFlag::F_SNAPSHOT as u8,
+            self.sequence,
+            self.ts_last,
+            ts_init,
+        )
+    }
+
     pub fn bids(&self) -> impl Iterator<Item = &Level> {
         self.bids.levels.values()
     }
@@ -197,6 +239,21 @@ impl OrderBook {
             .and_then(|top| top.first().map(|order| order.size))
     }
 
+    /// Returns the position of the order with the given `order_id` in the FIFO queue at its
+    /// price level.
+    ///
+    /// Queue positions are only available for L3 (MBO) books, as MBP books aggregate orders
+    /// per level, so `None` is returned for other book types or if the order is not found.
+    #[must_use]
+    pub fn queue_position(&self, order_id: OrderId) -> Option<QueuePosition> {
+        if self.book_type != BookType::L3_MBO {
+            return None;
+        }
+        self.bids
+            .queue_position(order_id)
+            .or_else(|| self.asks.queue_position(order_id))
+    }
+
     #[must_use]
     pub fn spread(&self) -> Option<f64> {
         match (self.best_ask_price(), self.best_bid_price()) {
@@ -224,6 +281,60 @@ impl OrderBook {
         analysis::get_avg_px_for_quantity(qty, levels)
     }
 
+    /// Returns the average of the bid and ask volume-weighted average prices to fill `qty`
+    /// on each side of the book (if both sides have liquidity).
+    #[must_use]
+    pub fn get_vwap_midpoint(&self, qty: Quantity) -> Option<f64> {
+        if !self.has_bid() || !self.has_ask() {
+            return None;
+        }
+
+        let bid_px = analysis::get_avg_px_for_quantity(qty, &self.bids.levels);
+        let ask_px = analysis::get_avg_px_for_quantity(qty, &self.asks.levels);
+        Some((bid_px + ask_px) / 2.0)
+    }
+
+    /// Returns the book imbalance ratio over the top `depth` levels of each side, in the
+    /// range [-1, 1], where a positive value indicates more size on the bid side.
+    #[must_use]
+    pub fn imbalance(&self, depth: usize) -> Option<f64> {
+        let bid_size = analysis::get_size_for_depth(depth, &self.bids.levels);
+        let ask_size = analysis::get_size_for_depth(depth, &self.asks.levels);
+        let total_size = bid_size + ask_size;
+        if total_size <= 0.0 {
+            return None;
+        }
+
+        Some((bid_size - ask_size) / total_size)
+    }
+
+    /// Returns the microprice, being the top of book prices weighted by the size on the
+    /// opposite side (if both sides have liquidity).
+    #[must_use]
+    pub fn microprice(&self) -> Option<f64> {
+        let (bid_px, ask_px, bid_size, ask_size) = match (
+            self.best_bid_price(),
+            self.best_ask_price(),
+            self.best_bid_size(),
+            self.best_ask_size(),
+        ) {
+            (Some(bid_px), Some(ask_px), Some(bid_size), Some(ask_size)) => (
+                bid_px.as_f64(),
+                ask_px.as_f64(),
+                bid_size.as_f64(),
+                ask_size.as_f64(),
+            ),
+            _ => return None,
+        };
+
+        let total_size = bid_size + ask_size;
+        if total_size <= 0.0 {
+            return None;
+        }
+
+        Some(bid_px.mul_add(ask_size, ask_px * bid_size) / total_size)
+    }
+
     #[must_use]
     pub fn get_quantity_for_price(&self, price: Price, order_side: OrderSide) -> f64 {
         let levels = match order_side {
@@ -244,6 +355,12 @@ impl OrderBook {
         }
     }
 
+    /// Returns the approximate memory (bytes) held by the order book.
+    #[must_use]
+    pub fn approx_size_bytes(&self) -> usize {
+        std::mem::size_of::<Self>() + self.bids.approx_heap_bytes() + self.asks.approx_heap_bytes()
+    }
+
     /// Return a [`String`] representation of the order book in a human-readable table format.
     #[must_use]
     pub fn pprint(&self, num_levels: usize) -> String {
@@ -257,6 +374,13 @@ impl OrderBook {
     }
 }
 
+fn level_as_order(level: &Level) -> BookOrder {
+    let size_precision = level.first().map_or(0, |order| order.size.precision);
+    let size = Quantity::from_raw(level.size_raw(), size_precision)
+        .expect("Level size should be a valid `Quantity`");
+    BookOrder::new(level.price.side, level.price.value, size, 0)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -266,7 +390,11 @@ mod tests {
 
     use crate::{
         data::{
-            depth::OrderBookDepth10, order::BookOrder, quote::QuoteTick, stubs::*, trade::TradeTick,
+            depth::OrderBookDepth10,
+            order::{BookOrder, NULL_ORDER},
+            quote::QuoteTick,
+            stubs::*,
+            trade::TradeTick,
         },
         enums::{AggressorSide, BookType, OrderSide},
         identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
@@ -308,6 +436,68 @@ mod tests {
         assert!(book.has_bid());
     }
 
+    #[rstest]
+    fn test_approx_size_bytes_grows_with_orders() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L3_MBO, instrument_id);
+        let empty_size = book.approx_size_bytes();
+        let order1 = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.000"),
+            Quantity::from("1.0"),
+            1,
+        );
+        book.add(order1, 0, 1, 100.into());
+
+        assert_eq!(empty_size, std::mem::size_of::<OrderBook>());
+        assert!(book.approx_size_bytes() > empty_size);
+    }
+
+    #[rstest]
+    fn test_to_depth10_aggregates_levels() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L3_MBO, instrument_id);
+        let order1 = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.000"),
+            Quantity::from("1.0"),
+            1,
+        );
+        let order2 = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.000"),
+            Quantity::from("2.0"),
+            2,
+        );
+        let order3 = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("0.990"),
+            Quantity::from("1.0"),
+            3,
+        );
+        let order4 = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1.010"),
+            Quantity::from("1.0"),
+            4,
+        );
+        book.add(order1, 0, 1, 100.into());
+        book.add(order2, 0, 2, 100.into());
+        book.add(order3, 0, 3, 100.into());
+        book.add(order4, 0, 4, 200.into());
+
+        let depth = book.to_depth10(1, 300.into());
+
+        assert_eq!(depth.bids[0].price, Price::from("1.000"));
+        assert_eq!(depth.bids[0].size, Quantity::from("3.0"));
+        assert_eq!(depth.bid_counts[0], 2);
+        assert_eq!(depth.bids[1], NULL_ORDER);
+        assert_eq!(depth.asks[0].price, Price::from("1.010"));
+        assert_eq!(depth.sequence, 4);
+        assert_eq!(depth.ts_event, 200);
+        assert_eq!(depth.ts_init, 300);
+    }
+
     #[rstest]
     fn test_ask_side_with_one_order() {
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
@@ -325,6 +515,53 @@ mod tests {
         assert!(book.has_ask());
     }
 
+    #[rstest]
+    fn test_queue_position_l3() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L3_MBO, instrument_id);
+        for (order_id, size) in [(1, "1.0"), (2, "2.0"), (3, "3.0")] {
+            let order = BookOrder::new(
+                OrderSide::Sell,
+                Price::from("1.001"),
+                Quantity::from(size),
+                order_id,
+            );
+            book.add(order, 0, order_id, order_id.into());
+        }
+
+        let position = book.queue_position(3).unwrap();
+        assert_eq!(position.side, OrderSide::Sell);
+        assert_eq!(position.position, 2);
+        assert_eq!(position.size_ahead, 3.0);
+
+        let order1 = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1.001"),
+            Quantity::from("1.0"),
+            1,
+        );
+        book.delete(order1, 0, 4, 4.into());
+        let position = book.queue_position(3).unwrap();
+        assert_eq!(position.position, 1);
+        assert_eq!(position.size_ahead, 2.0);
+        assert!(book.queue_position(1).is_none());
+    }
+
+    #[rstest]
+    fn test_queue_position_not_available_for_mbp() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.000"),
+            Quantity::from("1.0"),
+            1,
+        );
+        book.add(order, 0, 1, 1.into());
+
+        assert!(book.queue_position(1).is_none());
+    }
+
     #[rstest]
     fn test_spread_with_no_bids_or_asks() {
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
@@ -452,6 +689,63 @@ mod tests {
         );
     }
 
+    fn two_level_book() -> OrderBook {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+
+        let orders = [
+            (OrderSide::Sell, "2.010", "2.0"),
+            (OrderSide::Sell, "2.000", "1.0"),
+            (OrderSide::Buy, "1.000", "3.0"),
+            (OrderSide::Buy, "0.990", "2.0"),
+        ];
+        for (side, price, size) in orders {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+            book.add(order, 0, 1, 2.into());
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_analytics_no_market() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let book = OrderBook::new(BookType::L2_MBP, instrument_id);
+
+        assert_eq!(book.get_vwap_midpoint(Quantity::from("1.0")), None);
+        assert_eq!(book.imbalance(5), None);
+        assert_eq!(book.microprice(), None);
+    }
+
+    #[rstest]
+    fn test_get_vwap_midpoint() {
+        let book = two_level_book();
+
+        let vwap_mid = book.get_vwap_midpoint(Quantity::from("4.0")).unwrap();
+
+        // Bid VWAP (3 * 1.000 + 1 * 0.990) / 4, ask VWAP (1 * 2.000 + 2 * 2.010) / 3
+        let expected = (3.99 / 4.0 + 6.02 / 3.0) / 2.0;
+        assert!((vwap_mid - expected).abs() < 1e-9);
+    }
+
+    #[rstest]
+    #[case(1, 0.5)]
+    #[case(2, 0.25)]
+    #[case(10, 0.25)]
+    fn test_imbalance(#[case] depth: usize, #[case] expected: f64) {
+        let book = two_level_book();
+
+        assert!((book.imbalance(depth).unwrap() - expected).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_microprice() {
+        let book = two_level_book();
+
+        // Weighted toward the ask, as the bid has more size
+        let expected = (1.0 * 1.0 + 2.0 * 3.0) / 4.0;
+        assert!((book.microprice().unwrap() - expected).abs() < 1e-9);
+    }
+
     #[rstest]
     fn test_get_quantity_for_price() {
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
@@ -0,0 +1,281 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Buffering of order book deltas into atomic book updates.
+
+use super::{book::OrderBook, error::BookIntegrityError};
+use crate::{
+    data::{delta::OrderBookDelta, deltas::OrderBookDeltas},
+    enums::{BookAction, RecordFlag},
+    identifiers::instrument_id::InstrumentId,
+};
+
+/// Accumulates order book deltas until the `F_LAST` flag, so that each venue event is
+/// applied to a book atomically.
+///
+/// Sequence numbers are checked for gaps (a sequence of zero is treated as not applicable).
+/// Deltas belonging to the same event may share a sequence number. On a gap the pending
+/// deltas are discarded, and all further deltas are dropped until the book is resynchronized
+/// by a snapshot (starting with a `Clear` action).
+#[derive(Clone, Debug)]
+pub struct OrderBookDeltaBuffer {
+    pub instrument_id: InstrumentId,
+    buffer: Vec<OrderBookDelta>,
+    last_sequence: Option<u64>,
+    awaiting_resync: bool,
+}
+
+impl OrderBookDeltaBuffer {
+    /// Creates a new [`OrderBookDeltaBuffer`] instance.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId) -> Self {
+        Self {
+            instrument_id,
+            buffer: Vec::new(),
+            last_sequence: None,
+            awaiting_resync: false,
+        }
+    }
+
+    /// Returns the number of buffered deltas.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns whether there are no buffered deltas.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Returns whether deltas are being dropped until a snapshot resynchronizes the book.
+    #[must_use]
+    pub fn is_awaiting_resync(&self) -> bool {
+        self.awaiting_resync
+    }
+
+    /// Returns the sequence number of the last accepted delta (if any).
+    #[must_use]
+    pub fn last_sequence(&self) -> Option<u64> {
+        self.last_sequence
+    }
+
+    /// Discards all buffered deltas and sequence state.
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.last_sequence = None;
+        self.awaiting_resync = false;
+    }
+
+    /// Pushes the given `delta` into the buffer, returning the complete event when the
+    /// delta has the `F_LAST` flag.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a sequence gap is detected, in which case the
+    /// book should be resynchronized from a snapshot.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the delta is not for the buffers instrument.
+    pub fn push(
+        &mut self,
+        delta: OrderBookDelta,
+    ) -> Result<Option<OrderBookDeltas>, BookIntegrityError> {
+        assert_eq!(
+            delta.instrument_id, self.instrument_id,
+            "Delta instrument ID did not match buffer"
+        );
+
+        if delta.action == BookAction::Clear {
+            // Snapshot starts a new sequence, discarding any partial event
+            self.buffer.clear();
+            self.awaiting_resync = false;
+        } else if self.awaiting_resync {
+            return Ok(None);
+        } else if let Some(last_sequence) = self.last_sequence {
+            if delta.sequence != 0 && delta.sequence > last_sequence + 1 {
+                self.buffer.clear();
+                self.awaiting_resync = true;
+                return Err(BookIntegrityError::SequenceGap(
+                    self.instrument_id,
+                    last_sequence + 1,
+                    delta.sequence,
+                ));
+            }
+        }
+
+        if delta.sequence != 0 {
+            self.last_sequence = Some(delta.sequence);
+        }
+
+        let is_last = RecordFlag::F_LAST.matches(delta.flags);
+        self.buffer.push(delta);
+
+        if is_last {
+            let deltas = std::mem::take(&mut self.buffer);
+            Ok(Some(OrderBookDeltas::new(self.instrument_id, deltas)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Pushes the given `delta` into the buffer, applying the complete event to the `book`
+    /// when the delta has the `F_LAST` flag.
+    ///
+    /// Returns whether the book was updated.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a sequence gap is detected, in which case the
+    /// book should be resynchronized from a snapshot.
+    pub fn apply(
+        &mut self,
+        book: &mut OrderBook,
+        delta: OrderBookDelta,
+    ) -> Result<bool, BookIntegrityError> {
+        match self.push(delta)? {
+            Some(deltas) => {
+                book.apply_deltas(deltas);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::order::BookOrder,
+        enums::{BookType, OrderSide},
+        types::{price::Price, quantity::Quantity},
+    };
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("AAPL.XNAS")
+    }
+
+    fn delta(price: &str, flags: u8, sequence: u64) -> OrderBookDelta {
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from(price),
+            Quantity::from("10"),
+            sequence,
+        );
+        OrderBookDelta::new(
+            instrument_id(),
+            BookAction::Add,
+            order,
+            flags,
+            sequence,
+            1.into(),
+            2.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_push_buffers_until_last_flag() {
+        let mut buffer = OrderBookDeltaBuffer::new(instrument_id());
+
+        let first = buffer.push(delta("100.00", 0, 1)).unwrap();
+        let second = buffer
+            .push(delta("99.00", RecordFlag::F_LAST as u8, 2))
+            .unwrap();
+
+        assert!(first.is_none());
+        assert_eq!(second.unwrap().deltas.len(), 2);
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.last_sequence(), Some(2));
+    }
+
+    #[rstest]
+    fn test_push_allows_shared_sequence_within_event() {
+        let mut buffer = OrderBookDeltaBuffer::new(instrument_id());
+
+        buffer.push(delta("100.00", 0, 5)).unwrap();
+        let deltas = buffer
+            .push(delta("99.00", RecordFlag::F_LAST as u8, 5))
+            .unwrap();
+
+        assert_eq!(deltas.unwrap().sequence, 5);
+    }
+
+    #[rstest]
+    fn test_push_sequence_gap_awaits_resync() {
+        let mut buffer = OrderBookDeltaBuffer::new(instrument_id());
+        buffer.push(delta("100.00", 0, 1)).unwrap();
+
+        let result = buffer.push(delta("99.00", RecordFlag::F_LAST as u8, 3));
+        let dropped = buffer
+            .push(delta("98.00", RecordFlag::F_LAST as u8, 4))
+            .unwrap();
+
+        assert!(matches!(
+            result,
+            Err(BookIntegrityError::SequenceGap(_, 2, 3))
+        ));
+        assert!(dropped.is_none());
+        assert!(buffer.is_awaiting_resync());
+        assert!(buffer.is_empty());
+    }
+
+    #[rstest]
+    fn test_push_clear_resyncs_after_gap() {
+        let mut buffer = OrderBookDeltaBuffer::new(instrument_id());
+        buffer.push(delta("100.00", 0, 1)).unwrap();
+        let _ = buffer.push(delta("99.00", 0, 3));
+
+        buffer
+            .push(OrderBookDelta::clear(
+                instrument_id(),
+                10,
+                1.into(),
+                2.into(),
+            ))
+            .unwrap();
+        let deltas = buffer
+            .push(delta("99.00", RecordFlag::F_LAST as u8, 10))
+            .unwrap();
+
+        assert!(!buffer.is_awaiting_resync());
+        assert_eq!(deltas.unwrap().deltas.len(), 2);
+    }
+
+    #[rstest]
+    fn test_apply_updates_book_atomically() {
+        let mut buffer = OrderBookDeltaBuffer::new(instrument_id());
+        let mut book = OrderBook::new(BookType::L3_MBO, instrument_id());
+
+        let applied_first = buffer.apply(&mut book, delta("100.00", 0, 1)).unwrap();
+        let best_bid_before = book.best_bid_price();
+        let applied_last = buffer
+            .apply(&mut book, delta("101.00", RecordFlag::F_LAST as u8, 2))
+            .unwrap();
+
+        assert!(!applied_first);
+        assert_eq!(best_bid_before, None);
+        assert!(applied_last);
+        assert_eq!(book.best_bid_price(), Some(Price::from("101.00")));
+        assert_eq!(book.sequence, 2);
+    }
+}
@@ -18,7 +18,10 @@
 use nautilus_core::nanos::UnixNanos;
 
 use super::ladder::BookPrice;
-use crate::enums::{BookType, OrderSide};
+use crate::{
+    enums::{BookType, OrderSide},
+    identifiers::instrument_id::InstrumentId,
+};
 
 #[derive(thiserror::Error, Debug)]
 pub enum InvalidBookOperation {
@@ -42,4 +45,6 @@ pub enum BookIntegrityError {
     TooManyOrders(OrderSide, usize),
     #[error("Integrity error: number of {0} levels > 1 for L1_MBP book, was {1}")]
     TooManyLevels(OrderSide, usize),
+    #[error("Integrity error: sequence gap for {0}, expected {1}, was {2}")]
+    SequenceGap(InstrumentId, u64, u64),
 }
@@ -27,7 +27,7 @@ use super::error::BookIntegrityError;
 use crate::{
     data::order::{BookOrder, OrderId},
     enums::OrderSide,
-    orderbook::level::Level,
+    orderbook::level::{Level, QueuePosition},
     types::{price::Price, quantity::Quantity},
 };
 
@@ -105,6 +105,23 @@ impl Ladder {
         self.levels.is_empty()
     }
 
+    /// Returns the approximate heap memory (bytes) held by the ladder.
+    #[must_use]
+    pub fn approx_heap_bytes(&self) -> usize {
+        let levels: usize = self
+            .levels
+            .values()
+            .map(|level| {
+                std::mem::size_of::<BookPrice>()
+                    + std::mem::size_of::<Level>()
+                    + level.approx_heap_bytes()
+            })
+            .sum();
+        let cache =
+            self.cache.len() * (std::mem::size_of::<u64>() + std::mem::size_of::<BookPrice>());
+        levels + cache
+    }
+
     pub fn add_bulk(&mut self, orders: Vec<BookOrder>) {
         for order in orders {
             self.add(order);
@@ -168,6 +185,13 @@ impl Ladder {
         }
     }
 
+    /// Returns the queue position of the order with the given `order_id` (if in the ladder).
+    #[must_use]
+    pub fn queue_position(&self, order_id: OrderId) -> Option<QueuePosition> {
+        let price = self.cache.get(&order_id)?;
+        self.levels.get(price)?.queue_position(order_id)
+    }
+
     #[must_use]
     pub fn sizes(&self) -> f64 {
         self.levels.values().map(super::level::Level::size).sum()
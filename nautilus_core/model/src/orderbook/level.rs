@@ -21,10 +21,24 @@ use nautilus_core::nanos::UnixNanos;
 
 use crate::{
     data::order::{BookOrder, OrderId},
+    enums::OrderSide,
     orderbook::{error::BookIntegrityError, ladder::BookPrice},
-    types::fixed::FIXED_SCALAR,
+    types::{fixed::FIXED_SCALAR, price::Price},
 };
 
+/// Represents the position of an order in the FIFO queue at its price level.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QueuePosition {
+    pub side: OrderSide,
+    pub price: Price,
+    /// The number of orders ahead in the queue (zero is the front of the queue).
+    pub position: usize,
+    /// The total size of the orders ahead in the queue.
+    pub size_ahead: f64,
+    /// The total size of the orders at the level.
+    pub level_size: f64,
+}
+
 /// Represents a discrete price level in an order book.
 ///
 /// The level maintains a collection of orders as well as tracking insertion order
@@ -93,6 +107,13 @@ impl Level {
         self.orders.values().map(|o| o.size.as_f64()).sum()
     }
 
+    /// Returns the approximate heap memory (bytes) held by the level.
+    #[must_use]
+    pub fn approx_heap_bytes(&self) -> usize {
+        self.orders.len() * (std::mem::size_of::<OrderId>() + std::mem::size_of::<BookOrder>())
+            + self.insertion_order.capacity() * std::mem::size_of::<OrderId>()
+    }
+
     #[must_use]
     pub fn size_raw(&self) -> u64 {
         self.orders.values().map(|o| o.size.raw).sum()
@@ -131,14 +152,26 @@ impl Level {
         self.insertion_order.push(order.order_id);
     }
 
+    /// Updates the given `order` at the level, removing it if the size is zero.
+    ///
+    /// An order keeps its queue priority when its size is reduced, and loses priority
+    /// (moving to the back of the queue) when its size is increased, as at most venues.
     pub fn update(&mut self, order: BookOrder) {
         self.check_order_for_this_level(&order);
 
         if order.size.raw == 0 {
             self.orders.remove(&order.order_id);
             self.update_insertion_order();
-        } else {
-            self.orders.insert(order.order_id, order);
+            return;
+        }
+
+        match self.orders.insert(order.order_id, order) {
+            Some(existing) if order.size > existing.size => {
+                self.insertion_order.retain(|&id| id != order.order_id);
+                self.insertion_order.push(order.order_id);
+            }
+            Some(_) => {}
+            None => self.insertion_order.push(order.order_id),
         }
     }
 
@@ -156,6 +189,25 @@ impl Level {
         self.update_insertion_order();
     }
 
+    /// Returns the position of the order with the given `order_id` in the FIFO queue of the
+    /// level, where zero is the front of the queue (or `None` if not at the level).
+    #[must_use]
+    pub fn queue_position(&self, order_id: OrderId) -> Option<QueuePosition> {
+        let position = self.insertion_order.iter().position(|&id| id == order_id)?;
+        let size_ahead = self.insertion_order[..position]
+            .iter()
+            .filter_map(|id| self.orders.get(id))
+            .map(|o| o.size.as_f64())
+            .sum();
+        Some(QueuePosition {
+            side: self.price.side,
+            price: self.price.value,
+            position,
+            size_ahead,
+            level_size: self.size(),
+        })
+    }
+
     fn check_order_for_this_level(&self, order: &BookOrder) {
         assert_eq!(order.price, self.price.value);
     }
@@ -275,6 +327,64 @@ mod tests {
         assert_eq!(level.exposure(), 20.0);
     }
 
+    #[rstest]
+    fn test_queue_position() {
+        let mut level = Level::new(BookPrice::new(Price::from("1.00"), OrderSide::Buy));
+        let order1 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(10), 1);
+        let order2 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(20), 2);
+        let order3 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(30), 3);
+
+        level.add(order1);
+        level.add(order2);
+        level.add(order3);
+
+        let position = level.queue_position(3).unwrap();
+        assert_eq!(position.position, 2);
+        assert_eq!(position.size_ahead, 30.0);
+        assert_eq!(position.level_size, 60.0);
+        assert_eq!(position.price, Price::from("1.00"));
+        assert_eq!(level.queue_position(1).unwrap().position, 0);
+        assert!(level.queue_position(4).is_none());
+    }
+
+    #[rstest]
+    fn test_update_order_size_decrease_keeps_priority() {
+        let mut level = Level::new(BookPrice::new(Price::from("1.00"), OrderSide::Buy));
+        let order1 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(10), 1);
+        let order2 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(20), 2);
+
+        level.add(order1);
+        level.add(order2);
+        level.update(BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.00"),
+            Quantity::from(5),
+            1,
+        ));
+
+        assert_eq!(level.queue_position(1).unwrap().position, 0);
+        assert_eq!(level.queue_position(2).unwrap().size_ahead, 5.0);
+    }
+
+    #[rstest]
+    fn test_update_order_size_increase_loses_priority() {
+        let mut level = Level::new(BookPrice::new(Price::from("1.00"), OrderSide::Buy));
+        let order1 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(10), 1);
+        let order2 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(20), 2);
+
+        level.add(order1);
+        level.add(order2);
+        level.update(BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.00"),
+            Quantity::from(15),
+            1,
+        ));
+
+        assert_eq!(level.queue_position(1).unwrap().position, 1);
+        assert_eq!(level.first().unwrap().order_id, 2);
+    }
+
     #[rstest]
     fn test_update_order_with_zero_size() {
         let mut level = Level::new(BookPrice::new(Price::from("1.00"), OrderSide::Buy));
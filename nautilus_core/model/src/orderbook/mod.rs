@@ -18,6 +18,7 @@
 pub mod aggregation;
 pub mod analysis;
 pub mod book;
+pub mod buffer;
 pub mod display;
 pub mod error;
 pub mod ladder;
@@ -13,10 +13,11 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
 
 use nautilus_core::nanos::UnixNanos;
 use serde::{Deserialize, Serialize};
+use ustr::Ustr;
 
 use super::{
     base::{Order, OrderError},
@@ -31,12 +32,16 @@ use super::{
     trailing_stop_market::TrailingStopMarketOrder,
 };
 use crate::{
-    enums::{LiquiditySide, OrderSide, OrderSideSpecified, OrderStatus, OrderType, TriggerType},
-    events::order::OrderEventAny,
+    enums::{
+        ContingencyType, LiquiditySide, OrderSide, OrderSideSpecified, OrderStatus, OrderType,
+        TimeInForce, TriggerType,
+    },
+    events::order::{book_context::BookContext, OrderEventAny},
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
-        instrument_id::InstrumentId, position_id::PositionId, strategy_id::StrategyId,
-        trader_id::TraderId, venue_order_id::VenueOrderId,
+        instrument_id::InstrumentId, order_list_id::OrderListId, position_id::PositionId,
+        strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
+        venue_order_id::VenueOrderId,
     },
     types::{price::Price, quantity::Quantity},
 };
@@ -116,28 +121,54 @@ impl OrderAny {
         Self::StopMarket(order)
     }
 
+    /// Creates a new [`OrderAny`] by replaying the given `events` from initialization.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - `events` is empty or the first event is not `OrderInitialized`.
+    /// - Any later event is for a different client order ID or strategy ID.
+    /// - Any later event is not a valid state transition for the order.
     pub fn from_events(events: Vec<OrderEventAny>) -> anyhow::Result<Self> {
-        if events.is_empty() {
-            anyhow::bail!("No events provided");
-        }
-
-        // Pop the first event
-        let init_event = events.first().unwrap();
-        match init_event {
-            OrderEventAny::Initialized(init) => {
-                let mut order = Self::from(init.clone());
-                // Apply the rest of the events
-                for event in events.into_iter().skip(1) {
-                    // Apply event to order
-                    println!("Applying event: {:?}", event);
-                    order.apply(event).unwrap();
-                }
-                Ok(order)
+        let mut events = events.into_iter();
+        let mut order = match events.next() {
+            Some(OrderEventAny::Initialized(init)) => Self::from(init),
+            Some(event) => anyhow::bail!(
+                "First event must be `OrderInitialized`, was `{}`",
+                event.event_type()
+            ),
+            None => anyhow::bail!("No events provided"),
+        };
+
+        for (i, event) in events.enumerate() {
+            let index = i + 1;
+            let client_order_id = order.client_order_id();
+            if event.client_order_id() != client_order_id {
+                anyhow::bail!(
+                    "Event {index} `{}` was for client order ID {}, expected {client_order_id}",
+                    event.event_type(),
+                    event.client_order_id(),
+                );
             }
-            _ => {
-                anyhow::bail!("First event must be `OrderInitialized`");
+            if event.strategy_id() != order.strategy_id() {
+                anyhow::bail!(
+                    "Event {index} `{}` was for strategy ID {}, expected {}",
+                    event.event_type(),
+                    event.strategy_id(),
+                    order.strategy_id(),
+                );
             }
+
+            let event_type = event.event_type();
+            let status = order.status();
+            order.apply(event).map_err(|e| {
+                anyhow::anyhow!(
+                    "Event {index} `{event_type}` invalid for {client_order_id} in status {status}: {e}"
+                )
+            })?;
         }
+
+        Ok(order)
     }
 
     #[must_use]
@@ -185,6 +216,66 @@ impl OrderAny {
         }
     }
 
+    /// Attaches the given book `context` to the order's initialization event.
+    pub fn set_book_context(&mut self, context: BookContext) {
+        match self {
+            Self::Limit(order) => order.set_book_context(context),
+            Self::LimitIfTouched(order) => order.set_book_context(context),
+            Self::Market(order) => order.set_book_context(context),
+            Self::MarketIfTouched(order) => order.set_book_context(context),
+            Self::MarketToLimit(order) => order.set_book_context(context),
+            Self::StopLimit(order) => order.set_book_context(context),
+            Self::StopMarket(order) => order.set_book_context(context),
+            Self::TrailingStopLimit(order) => order.set_book_context(context),
+            Self::TrailingStopMarket(order) => order.set_book_context(context),
+        }
+    }
+
+    /// Sets the time in force for the order, e.g. when submitting with an emulated TIF.
+    pub fn set_time_in_force(&mut self, time_in_force: TimeInForce) {
+        match self {
+            Self::Limit(order) => order.time_in_force = time_in_force,
+            Self::LimitIfTouched(order) => order.time_in_force = time_in_force,
+            Self::Market(order) => order.time_in_force = time_in_force,
+            Self::MarketIfTouched(order) => order.time_in_force = time_in_force,
+            Self::MarketToLimit(order) => order.time_in_force = time_in_force,
+            Self::StopLimit(order) => order.time_in_force = time_in_force,
+            Self::StopMarket(order) => order.time_in_force = time_in_force,
+            Self::TrailingStopLimit(order) => order.time_in_force = time_in_force,
+            Self::TrailingStopMarket(order) => order.time_in_force = time_in_force,
+        }
+    }
+
+    #[must_use]
+    pub fn book_context(&self) -> Option<BookContext> {
+        match self {
+            Self::Limit(order) => order.book_context(),
+            Self::LimitIfTouched(order) => order.book_context(),
+            Self::Market(order) => order.book_context(),
+            Self::MarketIfTouched(order) => order.book_context(),
+            Self::MarketToLimit(order) => order.book_context(),
+            Self::StopLimit(order) => order.book_context(),
+            Self::StopMarket(order) => order.book_context(),
+            Self::TrailingStopLimit(order) => order.book_context(),
+            Self::TrailingStopMarket(order) => order.book_context(),
+        }
+    }
+
+    #[must_use]
+    pub fn events(&self) -> Vec<&OrderEventAny> {
+        match self {
+            Self::Limit(order) => order.events(),
+            Self::LimitIfTouched(order) => order.events(),
+            Self::Market(order) => order.events(),
+            Self::MarketIfTouched(order) => order.events(),
+            Self::MarketToLimit(order) => order.events(),
+            Self::StopLimit(order) => order.events(),
+            Self::StopMarket(order) => order.events(),
+            Self::TrailingStopLimit(order) => order.events(),
+            Self::TrailingStopMarket(order) => order.events(),
+        }
+    }
+
     #[must_use]
     pub fn client_order_id(&self) -> ClientOrderId {
         match self {
@@ -260,6 +351,21 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn exec_algorithm_params(&self) -> Option<&HashMap<Ustr, Ustr>> {
+        match self {
+            Self::Limit(order) => order.exec_algorithm_params.as_ref(),
+            Self::LimitIfTouched(order) => order.exec_algorithm_params.as_ref(),
+            Self::Market(order) => order.exec_algorithm_params.as_ref(),
+            Self::MarketIfTouched(order) => order.exec_algorithm_params.as_ref(),
+            Self::MarketToLimit(order) => order.exec_algorithm_params.as_ref(),
+            Self::StopLimit(order) => order.exec_algorithm_params.as_ref(),
+            Self::StopMarket(order) => order.exec_algorithm_params.as_ref(),
+            Self::TrailingStopLimit(order) => order.exec_algorithm_params.as_ref(),
+            Self::TrailingStopMarket(order) => order.exec_algorithm_params.as_ref(),
+        }
+    }
+
     #[must_use]
     pub fn exec_spawn_id(&self) -> Option<ClientOrderId> {
         match self {
@@ -275,6 +381,21 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn tags(&self) -> Option<&[Ustr]> {
+        match self {
+            Self::Limit(order) => order.tags(),
+            Self::LimitIfTouched(order) => order.tags(),
+            Self::Market(order) => order.tags(),
+            Self::MarketIfTouched(order) => order.tags(),
+            Self::MarketToLimit(order) => order.tags(),
+            Self::StopLimit(order) => order.tags(),
+            Self::StopMarket(order) => order.tags(),
+            Self::TrailingStopLimit(order) => order.tags(),
+            Self::TrailingStopMarket(order) => order.tags(),
+        }
+    }
+
     #[must_use]
     pub fn order_side(&self) -> OrderSide {
         match self {
@@ -350,6 +471,111 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn avg_px(&self) -> Option<f64> {
+        match self {
+            Self::Limit(order) => order.avg_px(),
+            Self::LimitIfTouched(order) => order.avg_px(),
+            Self::Market(order) => order.avg_px(),
+            Self::MarketIfTouched(order) => order.avg_px(),
+            Self::MarketToLimit(order) => order.avg_px(),
+            Self::StopLimit(order) => order.avg_px(),
+            Self::StopMarket(order) => order.avg_px(),
+            Self::TrailingStopLimit(order) => order.avg_px(),
+            Self::TrailingStopMarket(order) => order.avg_px(),
+        }
+    }
+
+    #[must_use]
+    pub fn trade_ids(&self) -> Vec<&TradeId> {
+        match self {
+            Self::Limit(order) => order.trade_ids(),
+            Self::LimitIfTouched(order) => order.trade_ids(),
+            Self::Market(order) => order.trade_ids(),
+            Self::MarketIfTouched(order) => order.trade_ids(),
+            Self::MarketToLimit(order) => order.trade_ids(),
+            Self::StopLimit(order) => order.trade_ids(),
+            Self::StopMarket(order) => order.trade_ids(),
+            Self::TrailingStopLimit(order) => order.trade_ids(),
+            Self::TrailingStopMarket(order) => order.trade_ids(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_reduce_only(&self) -> bool {
+        match self {
+            Self::Limit(order) => order.is_reduce_only(),
+            Self::LimitIfTouched(order) => order.is_reduce_only(),
+            Self::Market(order) => order.is_reduce_only(),
+            Self::MarketIfTouched(order) => order.is_reduce_only(),
+            Self::MarketToLimit(order) => order.is_reduce_only(),
+            Self::StopLimit(order) => order.is_reduce_only(),
+            Self::StopMarket(order) => order.is_reduce_only(),
+            Self::TrailingStopLimit(order) => order.is_reduce_only(),
+            Self::TrailingStopMarket(order) => order.is_reduce_only(),
+        }
+    }
+
+    #[must_use]
+    pub fn price(&self) -> Option<Price> {
+        match self {
+            Self::Limit(order) => order.price(),
+            Self::LimitIfTouched(order) => order.price(),
+            Self::Market(order) => order.price(),
+            Self::MarketIfTouched(order) => order.price(),
+            Self::MarketToLimit(order) => order.price(),
+            Self::StopLimit(order) => order.price(),
+            Self::StopMarket(order) => order.price(),
+            Self::TrailingStopLimit(order) => order.price(),
+            Self::TrailingStopMarket(order) => order.price(),
+        }
+    }
+
+    #[must_use]
+    pub fn trigger_price(&self) -> Option<Price> {
+        match self {
+            Self::Limit(order) => order.trigger_price(),
+            Self::LimitIfTouched(order) => order.trigger_price(),
+            Self::Market(order) => order.trigger_price(),
+            Self::MarketIfTouched(order) => order.trigger_price(),
+            Self::MarketToLimit(order) => order.trigger_price(),
+            Self::StopLimit(order) => order.trigger_price(),
+            Self::StopMarket(order) => order.trigger_price(),
+            Self::TrailingStopLimit(order) => order.trigger_price(),
+            Self::TrailingStopMarket(order) => order.trigger_price(),
+        }
+    }
+
+    #[must_use]
+    pub fn time_in_force(&self) -> TimeInForce {
+        match self {
+            Self::Limit(order) => order.time_in_force(),
+            Self::LimitIfTouched(order) => order.time_in_force(),
+            Self::Market(order) => order.time_in_force(),
+            Self::MarketIfTouched(order) => order.time_in_force(),
+            Self::MarketToLimit(order) => order.time_in_force(),
+            Self::StopLimit(order) => order.time_in_force(),
+            Self::StopMarket(order) => order.time_in_force(),
+            Self::TrailingStopLimit(order) => order.time_in_force(),
+            Self::TrailingStopMarket(order) => order.time_in_force(),
+        }
+    }
+
+    #[must_use]
+    pub fn expire_time(&self) -> Option<UnixNanos> {
+        match self {
+            Self::Limit(order) => order.expire_time(),
+            Self::LimitIfTouched(order) => order.expire_time(),
+            Self::Market(order) => order.expire_time(),
+            Self::MarketIfTouched(order) => order.expire_time(),
+            Self::MarketToLimit(order) => order.expire_time(),
+            Self::StopLimit(order) => order.expire_time(),
+            Self::StopMarket(order) => order.expire_time(),
+            Self::TrailingStopLimit(order) => order.expire_time(),
+            Self::TrailingStopMarket(order) => order.expire_time(),
+        }
+    }
+
     #[must_use]
     pub fn leaves_qty(&self) -> Quantity {
         match self {
@@ -410,6 +636,66 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn contingency_type(&self) -> Option<ContingencyType> {
+        match self {
+            Self::Limit(order) => order.contingency_type,
+            Self::LimitIfTouched(order) => order.contingency_type,
+            Self::Market(order) => order.contingency_type,
+            Self::MarketIfTouched(order) => order.contingency_type,
+            Self::MarketToLimit(order) => order.contingency_type,
+            Self::StopLimit(order) => order.contingency_type,
+            Self::StopMarket(order) => order.contingency_type,
+            Self::TrailingStopLimit(order) => order.contingency_type,
+            Self::TrailingStopMarket(order) => order.contingency_type,
+        }
+    }
+
+    #[must_use]
+    pub fn order_list_id(&self) -> Option<OrderListId> {
+        match self {
+            Self::Limit(order) => order.order_list_id,
+            Self::LimitIfTouched(order) => order.order_list_id,
+            Self::Market(order) => order.order_list_id,
+            Self::MarketIfTouched(order) => order.order_list_id,
+            Self::MarketToLimit(order) => order.order_list_id,
+            Self::StopLimit(order) => order.order_list_id,
+            Self::StopMarket(order) => order.order_list_id,
+            Self::TrailingStopLimit(order) => order.order_list_id,
+            Self::TrailingStopMarket(order) => order.order_list_id,
+        }
+    }
+
+    #[must_use]
+    pub fn linked_order_ids(&self) -> Option<&[ClientOrderId]> {
+        match self {
+            Self::Limit(order) => order.linked_order_ids.as_deref(),
+            Self::LimitIfTouched(order) => order.linked_order_ids.as_deref(),
+            Self::Market(order) => order.linked_order_ids.as_deref(),
+            Self::MarketIfTouched(order) => order.linked_order_ids.as_deref(),
+            Self::MarketToLimit(order) => order.linked_order_ids.as_deref(),
+            Self::StopLimit(order) => order.linked_order_ids.as_deref(),
+            Self::StopMarket(order) => order.linked_order_ids.as_deref(),
+            Self::TrailingStopLimit(order) => order.linked_order_ids.as_deref(),
+            Self::TrailingStopMarket(order) => order.linked_order_ids.as_deref(),
+        }
+    }
+
+    #[must_use]
+    pub fn parent_order_id(&self) -> Option<ClientOrderId> {
+        match self {
+            Self::Limit(order) => order.parent_order_id,
+            Self::LimitIfTouched(order) => order.parent_order_id,
+            Self::Market(order) => order.parent_order_id,
+            Self::MarketIfTouched(order) => order.parent_order_id,
+            Self::MarketToLimit(order) => order.parent_order_id,
+            Self::StopLimit(order) => order.parent_order_id,
+            Self::StopMarket(order) => order.parent_order_id,
+            Self::TrailingStopLimit(order) => order.parent_order_id,
+            Self::TrailingStopMarket(order) => order.parent_order_id,
+        }
+    }
+
     #[must_use]
     pub fn is_open(&self) -> bool {
         match self {
@@ -535,6 +821,39 @@ impl From<OrderAny> for StopOrderAny {
     }
 }
 
+impl From<PassiveOrderAny> for OrderAny {
+    fn from(order: PassiveOrderAny) -> OrderAny {
+        match order {
+            PassiveOrderAny::Limit(order) => order.into(),
+            PassiveOrderAny::Stop(order) => order.into(),
+        }
+    }
+}
+
+impl From<StopOrderAny> for OrderAny {
+    fn from(order: StopOrderAny) -> OrderAny {
+        match order {
+            StopOrderAny::LimitIfTouched(order) => OrderAny::LimitIfTouched(order),
+            StopOrderAny::MarketIfTouched(order) => OrderAny::MarketIfTouched(order),
+            StopOrderAny::StopLimit(order) => OrderAny::StopLimit(order),
+            StopOrderAny::StopMarket(order) => OrderAny::StopMarket(order),
+            StopOrderAny::TrailingStopLimit(order) => OrderAny::TrailingStopLimit(order),
+            StopOrderAny::TrailingStopMarket(order) => OrderAny::TrailingStopMarket(order),
+        }
+    }
+}
+
+impl From<LimitOrderAny> for OrderAny {
+    fn from(order: LimitOrderAny) -> OrderAny {
+        match order {
+            LimitOrderAny::Limit(order) => OrderAny::Limit(order),
+            LimitOrderAny::MarketToLimit(order) => OrderAny::MarketToLimit(order),
+            LimitOrderAny::StopLimit(order) => OrderAny::StopLimit(order),
+            LimitOrderAny::TrailingStopLimit(order) => OrderAny::TrailingStopLimit(order),
+        }
+    }
+}
+
 impl From<OrderAny> for LimitOrderAny {
     fn from(order: OrderAny) -> LimitOrderAny {
         match order {
@@ -725,6 +1044,42 @@ impl StopOrderAny {
         }
     }
 
+    /// Returns the limit price once triggered, or `None` for orders which execute as
+    /// market orders.
+    #[must_use]
+    pub fn limit_px(&self) -> Option<Price> {
+        match self {
+            Self::LimitIfTouched(order) => Some(order.price),
+            Self::StopLimit(order) => Some(order.price),
+            Self::TrailingStopLimit(order) => Some(order.price),
+            Self::MarketIfTouched(_) | Self::StopMarket(_) | Self::TrailingStopMarket(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub fn trigger_type(&self) -> TriggerType {
+        match self {
+            Self::LimitIfTouched(order) => order.trigger_type,
+            Self::MarketIfTouched(order) => order.trigger_type,
+            Self::StopLimit(order) => order.trigger_type,
+            Self::StopMarket(order) => order.trigger_type,
+            Self::TrailingStopLimit(order) => order.trigger_type,
+            Self::TrailingStopMarket(order) => order.trigger_type,
+        }
+    }
+
+    #[must_use]
+    pub fn is_triggered(&self) -> bool {
+        match self {
+            Self::LimitIfTouched(order) => order.is_triggered,
+            Self::MarketIfTouched(order) => order.is_triggered,
+            Self::StopLimit(order) => order.is_triggered,
+            Self::StopMarket(order) => order.is_triggered,
+            Self::TrailingStopLimit(order) => order.is_triggered,
+            Self::TrailingStopMarket(order) => order.is_triggered,
+        }
+    }
+
     #[must_use]
     pub fn is_closed(&self) -> bool {
         match self {
@@ -762,3 +1117,134 @@ impl PartialEq for StopOrderAny {
         }
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        instruments::{any::InstrumentAny, currency_pair::CurrencyPair, stubs::*},
+        orders::stubs::{TestOrderEventStubs, TestOrderStubs},
+    };
+
+    fn limit_order(audusd_sim: &CurrencyPair) -> OrderAny {
+        TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            None,
+            None,
+        )
+    }
+
+    #[rstest]
+    fn test_from_events_replays_order(audusd_sim: CurrencyPair) {
+        let mut order = limit_order(&audusd_sim);
+        let account_id = AccountId::from("SIM-001");
+        let submitted = TestOrderEventStubs::order_submitted(&order, account_id);
+        order.apply(submitted).unwrap();
+        let accepted =
+            TestOrderEventStubs::order_accepted(&order, account_id, VenueOrderId::from("V-1"));
+        order.apply(accepted).unwrap();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            &InstrumentAny::CurrencyPair(audusd_sim),
+            None,
+            None,
+            None,
+            Some(Quantity::from(40_000)),
+            None,
+            None,
+            None,
+        );
+        let OrderEventAny::Filled(fill) = filled else {
+            panic!("Expected fill event");
+        };
+        order.apply(OrderEventAny::PartiallyFilled(fill)).unwrap();
+
+        let events: Vec<OrderEventAny> = order.events().into_iter().cloned().collect();
+        let replayed = OrderAny::from_events(events).unwrap();
+
+        assert_eq!(replayed, order);
+        assert_eq!(replayed.status(), OrderStatus::PartiallyFilled);
+        assert_eq!(replayed.filled_qty(), Quantity::from(40_000));
+        assert_eq!(replayed.venue_order_id(), Some(VenueOrderId::from("V-1")));
+        assert_eq!(replayed.events().len(), 4);
+    }
+
+    #[rstest]
+    fn test_from_events_empty() {
+        assert!(OrderAny::from_events(vec![]).is_err());
+    }
+
+    #[rstest]
+    fn test_from_events_first_event_not_initialized(audusd_sim: CurrencyPair) {
+        let order = limit_order(&audusd_sim);
+        let submitted = TestOrderEventStubs::order_submitted(&order, AccountId::from("SIM-001"));
+
+        let result = OrderAny::from_events(vec![submitted]);
+
+        assert!(result.unwrap_err().to_string().contains("OrderInitialized"));
+    }
+
+    #[rstest]
+    fn test_from_events_invalid_transition(audusd_sim: CurrencyPair) {
+        let order = limit_order(&audusd_sim);
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            &InstrumentAny::CurrencyPair(audusd_sim),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let events = vec![order.events()[0].clone(), filled];
+
+        let result = OrderAny::from_events(events);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("status INITIALIZED"));
+    }
+
+    #[rstest]
+    fn test_from_events_mismatched_client_order_id(audusd_sim: CurrencyPair) {
+        let order = limit_order(&audusd_sim);
+        let other = TestOrderStubs::limit_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            Some(ClientOrderId::from("O-OTHER")),
+            None,
+        );
+        let submitted = TestOrderEventStubs::order_submitted(&other, AccountId::from("SIM-001"));
+        let events = vec![order.events()[0].clone(), submitted];
+
+        let result = OrderAny::from_events(events);
+
+        assert!(result.unwrap_err().to_string().contains("O-OTHER"));
+    }
+
+    #[rstest]
+    fn test_from_events_mismatched_strategy_id(audusd_sim: CurrencyPair) {
+        let order = limit_order(&audusd_sim);
+        let mut submitted =
+            TestOrderEventStubs::order_submitted(&order, AccountId::from("SIM-001"));
+        if let OrderEventAny::Submitted(event) = &mut submitted {
+            event.strategy_id = StrategyId::from("S-OTHER");
+        }
+        let events = vec![order.events()[0].clone(), submitted];
+
+        assert!(OrderAny::from_events(events).is_err());
+    }
+}
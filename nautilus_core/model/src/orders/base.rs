@@ -27,12 +27,13 @@ use crate::{
         TimeInForce, TrailingOffsetType, TriggerType,
     },
     events::order::{
-        accepted::OrderAccepted, cancel_rejected::OrderCancelRejected, canceled::OrderCanceled,
-        denied::OrderDenied, emulated::OrderEmulated, expired::OrderExpired, filled::OrderFilled,
-        initialized::OrderInitialized, modify_rejected::OrderModifyRejected,
-        pending_cancel::OrderPendingCancel, pending_update::OrderPendingUpdate,
-        rejected::OrderRejected, released::OrderReleased, submitted::OrderSubmitted,
-        triggered::OrderTriggered, updated::OrderUpdated, OrderEventAny,
+        accepted::OrderAccepted, book_context::BookContext, cancel_rejected::OrderCancelRejected,
+        canceled::OrderCanceled, denied::OrderDenied, emulated::OrderEmulated,
+        expired::OrderExpired, filled::OrderFilled, initialized::OrderInitialized,
+        modify_rejected::OrderModifyRejected, pending_cancel::OrderPendingCancel,
+        pending_update::OrderPendingUpdate, rejected::OrderRejected, released::OrderReleased,
+        submitted::OrderSubmitted, triggered::OrderTriggered, updated::OrderUpdated, OrderEventAny,
+        OrderEventType,
     },
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
@@ -94,73 +95,84 @@ pub fn str_hashmap_to_ustr(h: HashMap<String, String>) -> HashMap<Ustr, Ustr> {
 }
 
 impl OrderStatus {
+    /// Returns the status resulting from an event of `event_type`, or `None` if the
+    /// transition is invalid from this status.
     #[rustfmt::skip]
-    pub fn transition(&mut self, event: &OrderEventAny) -> Result<Self, OrderError> {
-        let new_state = match (self, event) {
-            (Self::Initialized, OrderEventAny::Denied(_)) => Self::Denied,
-            (Self::Initialized, OrderEventAny::Emulated(_)) => Self::Emulated,  // Emulated orders
-            (Self::Initialized, OrderEventAny::Released(_)) => Self::Released,  // Emulated orders
-            (Self::Initialized, OrderEventAny::Submitted(_)) => Self::Submitted,
-            (Self::Initialized, OrderEventAny::Rejected(_)) => Self::Rejected,  // External orders
-            (Self::Initialized, OrderEventAny::Accepted(_)) => Self::Accepted,  // External orders
-            (Self::Initialized, OrderEventAny::Canceled(_)) => Self::Canceled,  // External orders
-            (Self::Initialized, OrderEventAny::Expired(_)) => Self::Expired,  // External orders
-            (Self::Initialized, OrderEventAny::Triggered(_)) => Self::Triggered, // External orders
-            (Self::Emulated, OrderEventAny::Canceled(_)) => Self::Canceled,  // Emulated orders
-            (Self::Emulated, OrderEventAny::Expired(_)) => Self::Expired,  // Emulated orders
-            (Self::Emulated, OrderEventAny::Released(_)) => Self::Released,  // Emulated orders
-            (Self::Released, OrderEventAny::Submitted(_)) => Self::Submitted,  // Emulated orders
-            (Self::Released, OrderEventAny::Denied(_)) => Self::Denied,  // Emulated orders
-            (Self::Released, OrderEventAny::Canceled(_)) => Self::Canceled,  // Execution algo
-            (Self::Submitted, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
-            (Self::Submitted, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
-            (Self::Submitted, OrderEventAny::Rejected(_)) => Self::Rejected,
-            (Self::Submitted, OrderEventAny::Canceled(_)) => Self::Canceled,  // FOK and IOC cases
-            (Self::Submitted, OrderEventAny::Accepted(_)) => Self::Accepted,
-            (Self::Submitted, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::Submitted, OrderEventAny::Filled(_)) => Self::Filled,
-            (Self::Accepted, OrderEventAny::Rejected(_)) => Self::Rejected,  // StopLimit order
-            (Self::Accepted, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
-            (Self::Accepted, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
-            (Self::Accepted, OrderEventAny::Canceled(_)) => Self::Canceled,
-            (Self::Accepted, OrderEventAny::Triggered(_)) => Self::Triggered,
-            (Self::Accepted, OrderEventAny::Expired(_)) => Self::Expired,
-            (Self::Accepted, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::Accepted, OrderEventAny::Filled(_)) => Self::Filled,
-            (Self::Canceled, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,  // Real world possibility
-            (Self::Canceled, OrderEventAny::Filled(_)) => Self::Filled,  // Real world possibility
-            (Self::PendingUpdate, OrderEventAny::Rejected(_)) => Self::Rejected,
-            (Self::PendingUpdate, OrderEventAny::Accepted(_)) => Self::Accepted,
-            (Self::PendingUpdate, OrderEventAny::Canceled(_)) => Self::Canceled,
-            (Self::PendingUpdate, OrderEventAny::Expired(_)) => Self::Expired,
-            (Self::PendingUpdate, OrderEventAny::Triggered(_)) => Self::Triggered,
-            (Self::PendingUpdate, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,  // Allow multiple requests
-            (Self::PendingUpdate, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
-            (Self::PendingUpdate, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::PendingUpdate, OrderEventAny::Filled(_)) => Self::Filled,
-            (Self::PendingCancel, OrderEventAny::Rejected(_)) => Self::Rejected,
-            (Self::PendingCancel, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,  // Allow multiple requests
-            (Self::PendingCancel, OrderEventAny::Canceled(_)) => Self::Canceled,
-            (Self::PendingCancel, OrderEventAny::Expired(_)) => Self::Expired,
-            (Self::PendingCancel, OrderEventAny::Accepted(_)) => Self::Accepted,  // Allow failed cancel requests
-            (Self::PendingCancel, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::PendingCancel, OrderEventAny::Filled(_)) => Self::Filled,
-            (Self::Triggered, OrderEventAny::Rejected(_)) => Self::Rejected,
-            (Self::Triggered, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
-            (Self::Triggered, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
-            (Self::Triggered, OrderEventAny::Canceled(_)) => Self::Canceled,
-            (Self::Triggered, OrderEventAny::Expired(_)) => Self::Expired,
-            (Self::Triggered, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::Triggered, OrderEventAny::Filled(_)) => Self::Filled,
-            (Self::PartiallyFilled, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
-            (Self::PartiallyFilled, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
-            (Self::PartiallyFilled, OrderEventAny::Canceled(_)) => Self::Canceled,
-            (Self::PartiallyFilled, OrderEventAny::Expired(_)) => Self::Expired,
-            (Self::PartiallyFilled, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::PartiallyFilled, OrderEventAny::Filled(_)) => Self::Filled,
-            _ => return Err(OrderError::InvalidStateTransition),
+    #[must_use]
+    pub fn next_status(self, event_type: OrderEventType) -> Option<Self> {
+        let new_state = match (self, event_type) {
+            (Self::Initialized, OrderEventType::Denied) => Self::Denied,
+            (Self::Initialized, OrderEventType::Emulated) => Self::Emulated,  // Emulated orders
+            (Self::Initialized, OrderEventType::Released) => Self::Released,  // Emulated orders
+            (Self::Initialized, OrderEventType::Submitted) => Self::Submitted,
+            (Self::Initialized, OrderEventType::Rejected) => Self::Rejected,  // External orders
+            (Self::Initialized, OrderEventType::Accepted) => Self::Accepted,  // External orders
+            (Self::Initialized, OrderEventType::Canceled) => Self::Canceled,  // External orders
+            (Self::Initialized, OrderEventType::Expired) => Self::Expired,  // External orders
+            (Self::Initialized, OrderEventType::Triggered) => Self::Triggered, // External orders
+            (Self::Initialized, OrderEventType::Updated) => Self::Initialized,  // Execution algo primary orders
+            (Self::Emulated, OrderEventType::Canceled) => Self::Canceled,  // Emulated orders
+            (Self::Emulated, OrderEventType::Expired) => Self::Expired,  // Emulated orders
+            (Self::Emulated, OrderEventType::Released) => Self::Released,  // Emulated orders
+            (Self::Released, OrderEventType::Submitted) => Self::Submitted,  // Emulated orders
+            (Self::Released, OrderEventType::Denied) => Self::Denied,  // Emulated orders
+            (Self::Released, OrderEventType::Canceled) => Self::Canceled,  // Execution algo
+            (Self::Submitted, OrderEventType::PendingUpdate) => Self::PendingUpdate,
+            (Self::Submitted, OrderEventType::PendingCancel) => Self::PendingCancel,
+            (Self::Submitted, OrderEventType::Rejected) => Self::Rejected,
+            (Self::Submitted, OrderEventType::Canceled) => Self::Canceled,  // FOK and IOC cases
+            (Self::Submitted, OrderEventType::Accepted) => Self::Accepted,
+            (Self::Submitted, OrderEventType::PartiallyFilled) => Self::PartiallyFilled,
+            (Self::Submitted, OrderEventType::Filled) => Self::Filled,
+            (Self::Accepted, OrderEventType::Rejected) => Self::Rejected,  // StopLimit order
+            (Self::Accepted, OrderEventType::PendingUpdate) => Self::PendingUpdate,
+            (Self::Accepted, OrderEventType::PendingCancel) => Self::PendingCancel,
+            (Self::Accepted, OrderEventType::Canceled) => Self::Canceled,
+            (Self::Accepted, OrderEventType::Triggered) => Self::Triggered,
+            (Self::Accepted, OrderEventType::Expired) => Self::Expired,
+            (Self::Accepted, OrderEventType::PartiallyFilled) => Self::PartiallyFilled,
+            (Self::Accepted, OrderEventType::Filled) => Self::Filled,
+            (Self::Accepted, OrderEventType::Updated) => Self::Accepted,  // Trailing stop orders
+            (Self::Canceled, OrderEventType::PartiallyFilled) => Self::PartiallyFilled,  // Real world possibility
+            (Self::Canceled, OrderEventType::Filled) => Self::Filled,  // Real world possibility
+            (Self::PendingUpdate, OrderEventType::Rejected) => Self::Rejected,
+            (Self::PendingUpdate, OrderEventType::Accepted) => Self::Accepted,
+            (Self::PendingUpdate, OrderEventType::Canceled) => Self::Canceled,
+            (Self::PendingUpdate, OrderEventType::Expired) => Self::Expired,
+            (Self::PendingUpdate, OrderEventType::Triggered) => Self::Triggered,
+            (Self::PendingUpdate, OrderEventType::PendingUpdate) => Self::PendingUpdate,  // Allow multiple requests
+            (Self::PendingUpdate, OrderEventType::PendingCancel) => Self::PendingCancel,
+            (Self::PendingUpdate, OrderEventType::PartiallyFilled) => Self::PartiallyFilled,
+            (Self::PendingUpdate, OrderEventType::Filled) => Self::Filled,
+            (Self::PendingCancel, OrderEventType::Rejected) => Self::Rejected,
+            (Self::PendingCancel, OrderEventType::PendingCancel) => Self::PendingCancel,  // Allow multiple requests
+            (Self::PendingCancel, OrderEventType::Canceled) => Self::Canceled,
+            (Self::PendingCancel, OrderEventType::Expired) => Self::Expired,
+            (Self::PendingCancel, OrderEventType::Accepted) => Self::Accepted,  // Allow failed cancel requests
+            (Self::PendingCancel, OrderEventType::PartiallyFilled) => Self::PartiallyFilled,
+            (Self::PendingCancel, OrderEventType::Filled) => Self::Filled,
+            (Self::Triggered, OrderEventType::Rejected) => Self::Rejected,
+            (Self::Triggered, OrderEventType::PendingUpdate) => Self::PendingUpdate,
+            (Self::Triggered, OrderEventType::PendingCancel) => Self::PendingCancel,
+            (Self::Triggered, OrderEventType::Canceled) => Self::Canceled,
+            (Self::Triggered, OrderEventType::Expired) => Self::Expired,
+            (Self::Triggered, OrderEventType::PartiallyFilled) => Self::PartiallyFilled,
+            (Self::Triggered, OrderEventType::Filled) => Self::Filled,
+            (Self::Triggered, OrderEventType::Updated) => Self::Triggered,  // Trailing stop orders
+            (Self::PartiallyFilled, OrderEventType::PendingUpdate) => Self::PendingUpdate,
+            (Self::PartiallyFilled, OrderEventType::PendingCancel) => Self::PendingCancel,
+            (Self::PartiallyFilled, OrderEventType::Canceled) => Self::Canceled,
+            (Self::PartiallyFilled, OrderEventType::Expired) => Self::Expired,
+            (Self::PartiallyFilled, OrderEventType::PartiallyFilled) => Self::PartiallyFilled,
+            (Self::PartiallyFilled, OrderEventType::Filled) => Self::Filled,
+            _ => return None,
         };
-        Ok(new_state)
+        Some(new_state)
+    }
+
+    pub fn transition(&mut self, event: &OrderEventAny) -> Result<Self, OrderError> {
+        self.next_status(event.event_type())
+            .ok_or(OrderError::InvalidStateTransition)
     }
 }
 
@@ -268,7 +280,10 @@ pub trait Order: 'static + Send {
     }
 
     fn is_contingency(&self) -> bool {
-        self.contingency_type().is_some()
+        match self.contingency_type() {
+            Some(c) => c != ContingencyType::NoContingency,
+            None => false,
+        }
     }
 
     fn is_parent_order(&self) -> bool {
@@ -393,6 +408,10 @@ where
             exec_algorithm_params: order.exec_algorithm_params().map(|x| x.to_owned()),
             exec_spawn_id: order.exec_spawn_id(),
             tags: order.tags().map(|x| x.to_vec()),
+            book_context: order.events().first().and_then(|event| match event {
+                OrderEventAny::Initialized(init) => init.book_context,
+                _ => None,
+            }),
             event_id: order.init_id(),
             ts_event: order.ts_init(),
             ts_init: order.ts_init(),
@@ -679,6 +698,23 @@ impl OrderCore {
     pub fn init_event(&self) -> Option<OrderEventAny> {
         self.events.first().cloned()
     }
+
+    /// Returns the book context attached to the initialization event (if any).
+    #[must_use]
+    pub fn book_context(&self) -> Option<BookContext> {
+        match self.events.first() {
+            Some(OrderEventAny::Initialized(init)) => init.book_context,
+            _ => None,
+        }
+    }
+
+    /// Attaches the given book `context` to the initialization event, so that it is
+    /// persisted with the event.
+    pub fn set_book_context(&mut self, context: BookContext) {
+        if let Some(OrderEventAny::Initialized(init)) = self.events.first_mut() {
+            init.book_context = Some(context);
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -708,6 +744,27 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_set_book_context() {
+        let mut order = MarketOrder::default();
+        let context = BookContext::new(
+            Some(Price::from("100.00")),
+            Some(Price::from("100.10")),
+            UnixNanos::from(1),
+        );
+
+        order.set_book_context(context);
+
+        assert_eq!(order.book_context(), Some(context));
+        let Some(OrderEventAny::Initialized(init)) = order.init_event() else {
+            panic!("Expected `OrderInitialized`");
+        };
+        let json = serde_json::to_string(&init).unwrap();
+        let deserialized: OrderInitialized = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.book_context, Some(context));
+        assert_eq!(OrderInitialized::from(&order).book_context, Some(context));
+    }
+
     #[rstest]
     #[case(OrderSide::Buy, OrderSide::Sell)]
     #[case(OrderSide::Sell, OrderSide::Buy)]
@@ -353,10 +353,19 @@ impl Order for LimitIfTouchedOrder {
         if let OrderEventAny::Updated(ref event) = event {
             self.update(event);
         };
+        let ts_triggered = match event {
+            OrderEventAny::Triggered(ref event) => Some(event.ts_event),
+            _ => None,
+        };
         let is_order_filled = matches!(event, OrderEventAny::Filled(_));
 
         self.core.apply(event)?;
 
+        if ts_triggered.is_some() {
+            self.is_triggered = true;
+            self.ts_triggered = ts_triggered;
+        };
+
         if is_order_filled {
             self.core.set_slippage(self.price);
         };
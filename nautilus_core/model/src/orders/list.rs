@@ -19,10 +19,28 @@ use nautilus_core::{correctness::check_slice_not_empty, nanos::UnixNanos};
 use serde::{Deserialize, Serialize};
 
 use super::any::OrderAny;
-use crate::identifiers::{
-    instrument_id::InstrumentId, order_list_id::OrderListId, strategy_id::StrategyId,
+use crate::{
+    enums::{ContingencyType, OrderStatus},
+    events::order::OrderEventAny,
+    identifiers::{
+        client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
+        strategy_id::StrategyId,
+    },
+    types::quantity::Quantity,
 };
 
+/// Represents an action required on a contingent order, resulting from an event on the
+/// order it is linked to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ContingencyAction {
+    /// Submit the (child) order, as its parent has been filled.
+    Submit(ClientOrderId),
+    /// Cancel the order.
+    Cancel(ClientOrderId),
+    /// Update the quantity of the order to the given quantity.
+    UpdateQuantity(ClientOrderId, Quantity),
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(
     feature = "python",
@@ -38,6 +56,13 @@ pub struct OrderList {
 
 impl OrderList {
     /// Creates a new [`OrderList`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The `orders` is empty.
+    /// - An order has linked orders but no contingency type.
+    /// - A linked or parent order ID does not refer to another order in the list.
     pub fn new(
         order_list_id: OrderListId,
         instrument_id: InstrumentId,
@@ -50,6 +75,7 @@ impl OrderList {
             assert_eq!(instrument_id, order.instrument_id());
             assert_eq!(strategy_id, order.strategy_id());
         }
+        check_contingencies(&orders)?;
 
         Ok(Self {
             id: order_list_id,
@@ -59,6 +85,135 @@ impl OrderList {
             ts_init,
         })
     }
+
+    /// Returns the order with the given `client_order_id` (if found).
+    #[must_use]
+    pub fn get(&self, client_order_id: &ClientOrderId) -> Option<&OrderAny> {
+        self.orders
+            .iter()
+            .find(|order| order.client_order_id() == *client_order_id)
+    }
+
+    /// Applies the given `event` to its order in the list, returning the resulting actions
+    /// required on the contingent orders.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order is not in the list, or the event is invalid
+    /// for the order.
+    pub fn apply(&mut self, event: OrderEventAny) -> anyhow::Result<Vec<ContingencyAction>> {
+        let client_order_id = event.client_order_id();
+        let order = self
+            .orders
+            .iter_mut()
+            .find(|order| order.client_order_id() == client_order_id)
+            .ok_or_else(|| anyhow::anyhow!("Order {client_order_id} not in list {}", self.id))?;
+        order.apply(event)?;
+
+        Ok(self.contingency_actions(&client_order_id))
+    }
+
+    /// Returns the actions required on the orders linked to the order with the given
+    /// `client_order_id`, based on its current state.
+    ///
+    /// - `OTO`: Once the parent has fills, the children are submitted for the filled
+    ///   quantity. If the parent closes without fills, the children are canceled.
+    /// - `OCO`: Once the order has fills or closes, the linked orders are canceled.
+    /// - `OUO`: On fills the linked orders are reduced to the leaves quantity, and once the
+    ///   order closes the linked orders are canceled.
+    ///
+    /// Linked orders which are already closed require no action.
+    #[must_use]
+    pub fn contingency_actions(&self, client_order_id: &ClientOrderId) -> Vec<ContingencyAction> {
+        let Some(order) = self.get(client_order_id) else {
+            return vec![];
+        };
+        let Some(linked_order_ids) = order.linked_order_ids() else {
+            return vec![];
+        };
+
+        let has_fills = order.filled_qty().is_positive();
+        let mut actions = Vec::new();
+        for linked_order_id in linked_order_ids {
+            let Some(linked_order) = self.get(linked_order_id) else {
+                continue;
+            };
+            if linked_order.is_closed() {
+                continue;
+            }
+
+            match order.contingency_type() {
+                Some(ContingencyType::Oto) => {
+                    if has_fills {
+                        if linked_order.quantity() != order.filled_qty() {
+                            actions.push(ContingencyAction::UpdateQuantity(
+                                *linked_order_id,
+                                order.filled_qty(),
+                            ));
+                        }
+                        if linked_order.status() == OrderStatus::Initialized {
+                            actions.push(ContingencyAction::Submit(*linked_order_id));
+                        }
+                    } else if order.is_closed() {
+                        actions.push(ContingencyAction::Cancel(*linked_order_id));
+                    }
+                }
+                Some(ContingencyType::Oco) => {
+                    if has_fills || order.is_closed() {
+                        actions.push(ContingencyAction::Cancel(*linked_order_id));
+                    }
+                }
+                Some(ContingencyType::Ouo) => {
+                    if order.is_closed() {
+                        actions.push(ContingencyAction::Cancel(*linked_order_id));
+                    } else if has_fills && linked_order.leaves_qty() != order.leaves_qty() {
+                        actions.push(ContingencyAction::UpdateQuantity(
+                            *linked_order_id,
+                            order.leaves_qty(),
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+        actions
+    }
+}
+
+fn check_contingencies(orders: &[OrderAny]) -> anyhow::Result<()> {
+    let contains = |client_order_id: &ClientOrderId| {
+        orders
+            .iter()
+            .any(|order| order.client_order_id() == *client_order_id)
+    };
+
+    for order in orders {
+        let client_order_id = order.client_order_id();
+        let linked_order_ids = order.linked_order_ids().unwrap_or_default();
+        if !linked_order_ids.is_empty()
+            && matches!(
+                order.contingency_type(),
+                None | Some(ContingencyType::NoContingency)
+            )
+        {
+            anyhow::bail!("Order {client_order_id} has linked orders but no contingency type");
+        }
+        for linked_order_id in linked_order_ids {
+            if *linked_order_id == client_order_id || !contains(linked_order_id) {
+                anyhow::bail!(
+                    "Order {client_order_id} linked order {linked_order_id} not in the list"
+                );
+            }
+        }
+        if let Some(parent_order_id) = order.parent_order_id() {
+            if parent_order_id == client_order_id || !contains(&parent_order_id) {
+                anyhow::bail!(
+                    "Order {client_order_id} parent order {parent_order_id} not in the list"
+                );
+            }
+        }
+    }
+    Ok(())
 }
 
 impl PartialEq for OrderList {
@@ -93,12 +248,67 @@ mod tests {
     use super::*;
     use crate::{
         enums::OrderSide,
-        identifiers::{order_list_id::OrderListId, strategy_id::StrategyId},
-        instruments::{currency_pair::CurrencyPair, stubs::*},
-        orders::stubs::TestOrderStubs,
+        identifiers::{
+            account_id::AccountId, order_list_id::OrderListId, strategy_id::StrategyId,
+            trade_id::TradeId, venue_order_id::VenueOrderId,
+        },
+        instruments::{any::InstrumentAny, currency_pair::CurrencyPair, stubs::*},
+        orders::stubs::{TestOrderEventStubs, TestOrderStubs},
         types::{price::Price, quantity::Quantity},
     };
 
+    fn bracket(audusd_sim: &CurrencyPair, contingency_type: ContingencyType) -> OrderList {
+        TestOrderStubs::bracket_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            Price::from("1.00000"),
+            Price::from("0.99000"),
+            Price::from("1.01000"),
+            Some(contingency_type),
+        )
+    }
+
+    fn accept(order_list: &mut OrderList, client_order_id: &ClientOrderId) {
+        let account_id = AccountId::from("SIM-001");
+        let order = order_list.get(client_order_id).unwrap().clone();
+        let submitted = TestOrderEventStubs::order_submitted(&order, account_id);
+        order_list.apply(submitted).unwrap();
+        let order = order_list.get(client_order_id).unwrap().clone();
+        let venue_order_id = VenueOrderId::from(client_order_id.as_str());
+        let accepted = TestOrderEventStubs::order_accepted(&order, account_id, venue_order_id);
+        order_list.apply(accepted).unwrap();
+    }
+
+    fn fill(
+        order_list: &mut OrderList,
+        client_order_id: &ClientOrderId,
+        instrument: &InstrumentAny,
+        trade_id: &str,
+        last_qty: Quantity,
+    ) -> Vec<ContingencyAction> {
+        let order = order_list.get(client_order_id).unwrap().clone();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            Some(TradeId::from(trade_id)),
+            None,
+            None,
+            Some(last_qty),
+            None,
+            None,
+            None,
+        );
+        // The stub always emits a fill event, so partial fills are emitted explicitly
+        let filled = match filled {
+            OrderEventAny::Filled(fill) if last_qty < order.leaves_qty() => {
+                OrderEventAny::PartiallyFilled(fill)
+            }
+            event => event,
+        };
+        order_list.apply(filled).unwrap()
+    }
+
     #[rstest]
     fn test_new_and_display(audusd_sim: CurrencyPair) {
         let order1 = TestOrderStubs::limit_order(
@@ -141,4 +351,146 @@ mod tests {
             "OrderList(id=OL-001, instrument_id=AUD/USD.SIM, strategy_id=S-001, orders="
         ));
     }
+
+    #[rstest]
+    fn test_bracket_order_linkage(audusd_sim: CurrencyPair) {
+        let order_list = bracket(&audusd_sim, ContingencyType::Ouo);
+        let entry = &order_list.orders[0];
+        let stop_loss = &order_list.orders[1];
+        let take_profit = &order_list.orders[2];
+
+        assert_eq!(entry.contingency_type(), Some(ContingencyType::Oto));
+        assert_eq!(
+            entry.linked_order_ids(),
+            Some(&[stop_loss.client_order_id(), take_profit.client_order_id()][..])
+        );
+        assert_eq!(stop_loss.parent_order_id(), Some(entry.client_order_id()));
+        assert_eq!(stop_loss.order_side(), OrderSide::Sell);
+        assert_eq!(
+            take_profit.linked_order_ids(),
+            Some(&[stop_loss.client_order_id()][..])
+        );
+        assert_eq!(take_profit.order_list_id(), Some(order_list.id));
+    }
+
+    #[rstest]
+    fn test_new_with_linked_order_not_in_list(audusd_sim: CurrencyPair) {
+        let mut orders = bracket(&audusd_sim, ContingencyType::Oco).orders;
+        orders.remove(1);
+
+        let result = OrderList::new(
+            OrderListId::from("OL-001"),
+            audusd_sim.id,
+            StrategyId::default(),
+            orders,
+            UnixNanos::default(),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_entry_fill_submits_children(audusd_sim: CurrencyPair) {
+        let mut order_list = bracket(&audusd_sim, ContingencyType::Ouo);
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let entry_id = order_list.orders[0].client_order_id();
+        let sl_id = order_list.orders[1].client_order_id();
+        let tp_id = order_list.orders[2].client_order_id();
+        accept(&mut order_list, &entry_id);
+
+        let actions = fill(
+            &mut order_list,
+            &entry_id,
+            &instrument,
+            "E-1",
+            Quantity::from(100_000),
+        );
+
+        assert_eq!(
+            actions,
+            vec![
+                ContingencyAction::Submit(sl_id),
+                ContingencyAction::Submit(tp_id),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_entry_partial_fill_updates_children(audusd_sim: CurrencyPair) {
+        let mut order_list = bracket(&audusd_sim, ContingencyType::Ouo);
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let entry_id = order_list.orders[0].client_order_id();
+        let sl_id = order_list.orders[1].client_order_id();
+        accept(&mut order_list, &entry_id);
+
+        let actions = fill(
+            &mut order_list,
+            &entry_id,
+            &instrument,
+            "E-1",
+            Quantity::from(40_000),
+        );
+
+        assert_eq!(
+            actions[..2],
+            [
+                ContingencyAction::UpdateQuantity(sl_id, Quantity::from(40_000)),
+                ContingencyAction::Submit(sl_id),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_ouo_take_profit_fills_update_then_cancel_stop_loss(audusd_sim: CurrencyPair) {
+        let mut order_list = bracket(&audusd_sim, ContingencyType::Ouo);
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let sl_id = order_list.orders[1].client_order_id();
+        let tp_id = order_list.orders[2].client_order_id();
+        accept(&mut order_list, &sl_id);
+        accept(&mut order_list, &tp_id);
+
+        let partial = fill(
+            &mut order_list,
+            &tp_id,
+            &instrument,
+            "E-1",
+            Quantity::from(40_000),
+        );
+        let full = fill(
+            &mut order_list,
+            &tp_id,
+            &instrument,
+            "E-2",
+            Quantity::from(60_000),
+        );
+
+        assert_eq!(
+            partial,
+            vec![ContingencyAction::UpdateQuantity(
+                sl_id,
+                Quantity::from(60_000)
+            )]
+        );
+        assert_eq!(full, vec![ContingencyAction::Cancel(sl_id)]);
+    }
+
+    #[rstest]
+    fn test_oco_partial_fill_cancels_linked(audusd_sim: CurrencyPair) {
+        let mut order_list = bracket(&audusd_sim, ContingencyType::Oco);
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let sl_id = order_list.orders[1].client_order_id();
+        let tp_id = order_list.orders[2].client_order_id();
+        accept(&mut order_list, &sl_id);
+        accept(&mut order_list, &tp_id);
+
+        let actions = fill(
+            &mut order_list,
+            &tp_id,
+            &instrument,
+            "E-1",
+            Quantity::from(40_000),
+        );
+
+        assert_eq!(actions, vec![ContingencyAction::Cancel(sl_id)]);
+    }
 }
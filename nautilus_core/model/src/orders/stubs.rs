@@ -17,16 +17,22 @@ use std::str::FromStr;
 
 use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 
-use super::{any::OrderAny, limit::LimitOrder, stop_market::StopMarketOrder};
+use super::{
+    any::OrderAny, base::OrderCore, limit::LimitOrder, list::OrderList,
+    market_if_touched::MarketIfTouchedOrder, stop_market::StopMarketOrder,
+    trailing_stop_limit::TrailingStopLimitOrder, trailing_stop_market::TrailingStopMarketOrder,
+};
 use crate::{
-    enums::{LiquiditySide, OrderSide, TimeInForce, TriggerType},
+    enums::{
+        ContingencyType, LiquiditySide, OrderSide, TimeInForce, TrailingOffsetType, TriggerType,
+    },
     events::order::{
         accepted::OrderAccepted, filled::OrderFilled, submitted::OrderSubmitted, OrderEventAny,
     },
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
-        position_id::PositionId, strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
-        venue_order_id::VenueOrderId,
+        order_list_id::OrderListId, position_id::PositionId, strategy_id::StrategyId,
+        trade_id::TradeId, trader_id::TraderId, venue_order_id::VenueOrderId,
     },
     instruments::any::InstrumentAny,
     orders::market::MarketOrder,
@@ -243,4 +249,257 @@ impl TestOrderStubs {
         .unwrap();
         OrderAny::StopMarket(order)
     }
+
+    #[must_use]
+    pub fn market_if_touched_order(
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        trigger_price: Price,
+        quantity: Quantity,
+        trigger_type: Option<TriggerType>,
+    ) -> OrderAny {
+        let order = MarketIfTouchedOrder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            instrument_id,
+            ClientOrderId::default(),
+            order_side,
+            quantity,
+            trigger_price,
+            trigger_type.unwrap_or(TriggerType::BidAsk),
+            TimeInForce::Gtc,
+            None,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        OrderAny::MarketIfTouched(order)
+    }
+
+    #[must_use]
+    pub fn trailing_stop_market_order(
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        trigger_price: Price,
+        quantity: Quantity,
+        trigger_type: TriggerType,
+        trailing_offset: Price,
+        trailing_offset_type: TrailingOffsetType,
+    ) -> OrderAny {
+        let order = TrailingStopMarketOrder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            instrument_id,
+            ClientOrderId::default(),
+            order_side,
+            quantity,
+            trigger_price,
+            trigger_type,
+            trailing_offset,
+            trailing_offset_type,
+            TimeInForce::Gtc,
+            None,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        OrderAny::TrailingStopMarket(order)
+    }
+
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn trailing_stop_limit_order(
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        price: Price,
+        trigger_price: Price,
+        quantity: Quantity,
+        trigger_type: TriggerType,
+        limit_offset: Price,
+        trailing_offset: Price,
+        trailing_offset_type: TrailingOffsetType,
+    ) -> OrderAny {
+        let order = TrailingStopLimitOrder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            instrument_id,
+            ClientOrderId::default(),
+            order_side,
+            quantity,
+            price,
+            trigger_price,
+            trigger_type,
+            limit_offset,
+            trailing_offset,
+            trailing_offset_type,
+            TimeInForce::Gtc,
+            None,
+            false,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        OrderAny::TrailingStopLimit(order)
+    }
+
+    /// Returns a bracket order list, of a limit entry order with a stop-market stop-loss and
+    /// limit take-profit, which are released when the entry is filled.
+    ///
+    /// The stop-loss and take-profit are linked with the given `contingency_type` (default
+    /// `OUO`).
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn bracket_order(
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        entry_price: Price,
+        sl_trigger_price: Price,
+        tp_price: Price,
+        contingency_type: Option<ContingencyType>,
+    ) -> OrderList {
+        let order_list_id = OrderListId::from("OL-19700101-0000-001-001-1");
+        let entry_id = ClientOrderId::from("O-19700101-0000-001-001-1");
+        let sl_id = ClientOrderId::from("O-19700101-0000-001-001-2");
+        let tp_id = ClientOrderId::from("O-19700101-0000-001-001-3");
+        let contingency_type = contingency_type.unwrap_or(ContingencyType::Ouo);
+        let exit_side = OrderCore::opposite_side(order_side);
+
+        let entry = LimitOrder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            instrument_id,
+            entry_id,
+            order_side,
+            quantity,
+            entry_price,
+            TimeInForce::Gtc,
+            None,
+            false,
+            false,
+            false,
+            None,
+            None,
+            None,
+            Some(ContingencyType::Oto),
+            Some(order_list_id),
+            Some(vec![sl_id, tp_id]),
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        let stop_loss = StopMarketOrder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            instrument_id,
+            sl_id,
+            exit_side,
+            quantity,
+            sl_trigger_price,
+            TriggerType::Default,
+            TimeInForce::Gtc,
+            None,
+            true,
+            false,
+            None,
+            None,
+            None,
+            Some(contingency_type),
+            Some(order_list_id),
+            Some(vec![tp_id]),
+            Some(entry_id),
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        let take_profit = LimitOrder::new(
+            TraderId::default(),
+            StrategyId::default(),
+            instrument_id,
+            tp_id,
+            exit_side,
+            quantity,
+            tp_price,
+            TimeInForce::Gtc,
+            None,
+            false,
+            true,
+            false,
+            None,
+            None,
+            None,
+            Some(contingency_type),
+            Some(order_list_id),
+            Some(vec![sl_id]),
+            Some(entry_id),
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        OrderList::new(
+            order_list_id,
+            instrument_id,
+            StrategyId::default(),
+            vec![
+                OrderAny::Limit(entry),
+                OrderAny::StopMarket(stop_loss),
+                OrderAny::Limit(take_profit),
+            ],
+            UnixNanos::default(),
+        )
+        .unwrap()
+    }
 }
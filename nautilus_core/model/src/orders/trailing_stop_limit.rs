@@ -18,7 +18,7 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{correctness::check_predicate_true, nanos::UnixNanos, uuid::UUID4};
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
@@ -97,6 +97,11 @@ impl TrailingStopLimitOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
+        check_predicate_true(
+            trailing_offset_type != TrailingOffsetType::NoTrailingOffset,
+            "`trailing_offset_type` was `NoTrailingOffset`",
+        )?;
+
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -362,10 +367,19 @@ impl Order for TrailingStopLimitOrder {
         if let OrderEventAny::Updated(ref event) = event {
             self.update(event);
         };
+        let ts_triggered = match event {
+            OrderEventAny::Triggered(ref event) => Some(event.ts_event),
+            _ => None,
+        };
         let is_order_filled = matches!(event, OrderEventAny::Filled(_));
 
         self.core.apply(event)?;
 
+        if ts_triggered.is_some() {
+            self.is_triggered = true;
+            self.ts_triggered = ts_triggered;
+        };
+
         if is_order_filled {
             self.core.set_slippage(self.price);
         };
@@ -18,7 +18,7 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{correctness::check_predicate_true, nanos::UnixNanos, uuid::UUID4};
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
@@ -92,6 +92,11 @@ impl TrailingStopMarketOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
+        check_predicate_true(
+            trailing_offset_type != TrailingOffsetType::NoTrailingOffset,
+            "`trailing_offset_type` was `NoTrailingOffset`",
+        )?;
+
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -128,11 +128,6 @@ impl Position {
     }
 
     pub fn apply(&mut self, fill: &OrderFilled) {
-        assert!(
-            !self.trade_ids.contains(&fill.trade_id),
-            "`fill.trade_id` already contained in `trade_ids",
-        );
-
         if self.side == PositionSide::Flat {
             // Reset position
             self.events.clear();
@@ -153,6 +148,12 @@ impl Position {
             self.realized_pnl = None;
         }
 
+        // Checked after any reset, as a flipping fill reopens the position with the same trade
+        assert!(
+            !self.trade_ids.contains(&fill.trade_id),
+            "`fill.trade_id` already contained in `trade_ids",
+        );
+
         self.events.push(*fill);
         self.trade_ids.push(fill.trade_id);
 
@@ -204,11 +205,7 @@ impl Position {
     }
 
     pub fn handle_buy_order_fill(&mut self, fill: &OrderFilled) {
-        let mut realized_pnl = if fill.commission.unwrap().currency == self.settlement_currency {
-            -fill.commission.unwrap().as_f64()
-        } else {
-            0.0
-        };
+        let mut realized_pnl = -self.settlement_commission(fill);
         let last_px = fill.last_px.as_f64();
         let last_qty = fill.last_qty.as_f64();
         let last_qty_object = fill.last_qty;
@@ -239,11 +236,7 @@ impl Position {
     }
 
     pub fn handle_sell_order_fill(&mut self, fill: &OrderFilled) {
-        let mut realized_pnl = if fill.commission.unwrap().currency == self.settlement_currency {
-            -fill.commission.unwrap().as_f64()
-        } else {
-            0.0
-        };
+        let mut realized_pnl = -self.settlement_commission(fill);
         let last_px = fill.last_px.as_f64();
         let last_qty = fill.last_qty.as_f64();
         let last_qty_object = fill.last_qty;
@@ -273,6 +266,16 @@ impl Position {
         self.sell_qty += last_qty_object;
     }
 
+    /// Returns the commission for the given `fill` in the settlement currency.
+    ///
+    /// Commissions in any other currency are accumulated in `commissions`, but are not
+    /// deducted from the realized PnL.
+    fn settlement_commission(&self, fill: &OrderFilled) -> f64 {
+        fill.commission
+            .filter(|commission| commission.currency == self.settlement_currency)
+            .map_or(0.0, |commission| commission.as_f64())
+    }
+
     #[must_use]
     pub fn calculate_avg_px(&self, qty: f64, avg_pg: f64, last_px: f64, last_qty: f64) -> f64 {
         let start_cost = avg_pg * qty;
@@ -481,6 +484,24 @@ impl Position {
     pub fn commissions(&self) -> Vec<Money> {
         self.commissions.values().copied().collect()
     }
+
+    /// Returns the total commission paid in the given `currency` (if any).
+    #[must_use]
+    pub fn commission(&self, currency: &Currency) -> Option<Money> {
+        self.commissions.get(currency).copied()
+    }
+
+    /// Returns the return of the position as a fraction of the average open price.
+    #[must_use]
+    pub fn realized_return(&self) -> f64 {
+        self.realized_return
+    }
+
+    /// Returns the duration from the position opening to closing (zero until closed).
+    #[must_use]
+    pub fn duration_ns(&self) -> u64 {
+        self.duration_ns
+    }
 }
 
 impl PartialEq<Self> for Position {
@@ -2055,4 +2076,64 @@ mod tests {
         let position = Position::new(&audusd_sim, fill.into()).unwrap();
         assert_eq!(position.signed_qty, expected);
     }
+
+    #[rstest]
+    fn test_position_commissions_in_multiple_currencies(currency_pair_btcusdt: CurrencyPair) {
+        let btcusdt = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let order1 = TestOrderStubs::market_order(
+            currency_pair_btcusdt.id,
+            OrderSide::Buy,
+            Quantity::from(1),
+            None,
+            None,
+        );
+        let order2 = TestOrderStubs::market_order(
+            currency_pair_btcusdt.id,
+            OrderSide::Sell,
+            Quantity::from(1),
+            None,
+            None,
+        );
+        let fill1 = TestOrderEventStubs::order_filled(
+            &order1,
+            &btcusdt,
+            Some(TradeId::new("1").unwrap()),
+            Some(PositionId::from("P-1")),
+            Some(Price::from("10000.0")),
+            None,
+            Some(Money::from("0.01 BNB")),
+            Some(UnixNanos::from(1_000_000_000)),
+            None,
+        );
+        let fill2 = TestOrderEventStubs::order_filled(
+            &order2,
+            &btcusdt,
+            Some(TradeId::new("2").unwrap()),
+            Some(PositionId::from("P-1")),
+            Some(Price::from("10100.0")),
+            None,
+            Some(Money::from("10 USDT")),
+            Some(UnixNanos::from(3_000_000_000)),
+            None,
+        );
+        let mut position = Position::new(&btcusdt, fill1.into()).unwrap();
+        position.apply(&fill2.into());
+
+        let bnb = Money::from("0.01 BNB").currency;
+        assert!(position.is_closed());
+        assert_eq!(position.commissions().len(), 2);
+        assert_eq!(position.commission(&bnb), Some(Money::from("0.01 BNB")));
+        assert_eq!(
+            position.commission(&position.settlement_currency),
+            Some(Money::from("10 USDT"))
+        );
+        // Only the commission in the settlement currency is deducted
+        assert_eq!(position.realized_pnl, Some(Money::from("90 USDT")));
+        assert_eq!(
+            position.total_pnl(Price::from("10200.0")),
+            Money::from("90 USDT")
+        );
+        assert_eq!(position.realized_return(), 0.01);
+        assert_eq!(position.duration_ns(), 2_000_000_000);
+    }
 }
@@ -272,6 +272,16 @@ impl OrderInitialized {
             )?,
             None => dict.set_item("tags", py.None())?,
         }
+        match self.book_context {
+            Some(context) => {
+                let py_context = PyDict::new(py);
+                py_context.set_item("best_bid", context.best_bid.map(|p| p.to_string()))?;
+                py_context.set_item("best_ask", context.best_ask.map(|p| p.to_string()))?;
+                py_context.set_item("ts_book", context.ts_book.as_u64())?;
+                dict.set_item("book_context", py_context)?;
+            }
+            None => dict.set_item("book_context", py.None())?,
+        }
         Ok(dict.into())
     }
 }
@@ -198,6 +198,21 @@ impl OrderBook {
         self.get_avg_px_for_quantity(qty, order_side)
     }
 
+    #[pyo3(name = "get_vwap_midpoint")]
+    fn py_get_vwap_midpoint(&self, qty: Quantity) -> Option<f64> {
+        self.get_vwap_midpoint(qty)
+    }
+
+    #[pyo3(name = "imbalance")]
+    fn py_imbalance(&self, depth: usize) -> Option<f64> {
+        self.imbalance(depth)
+    }
+
+    #[pyo3(name = "microprice")]
+    fn py_microprice(&self) -> Option<f64> {
+        self.microprice()
+    }
+
     #[pyo3(name = "get_quantity_for_price")]
     fn py_get_quantity_for_price(&self, price: Price, order_side: OrderSide) -> f64 {
         self.get_quantity_for_price(price, order_side)
@@ -15,12 +15,15 @@
 
 use std::collections::HashMap;
 
-use nautilus_core::{python::to_pyruntime_err, uuid::UUID4};
+use nautilus_core::{
+    python::{to_pyruntime_err, to_pyvalue_err},
+    uuid::UUID4,
+};
 use pyo3::prelude::*;
 use ustr::Ustr;
 
 use crate::{
-    enums::{ContingencyType, OrderSide, OrderType, TimeInForce, TriggerType},
+    enums::{ContingencyType, OrderSide, OrderStatus, OrderType, TimeInForce, TriggerType},
     events::order::initialized::OrderInitialized,
     identifiers::{
         client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
@@ -69,7 +72,7 @@ impl LimitIfTouchedOrder {
         tags: Option<Vec<String>>,
     ) -> PyResult<Self> {
         let exec_algorithm_params = exec_algorithm_params.map(str_hashmap_to_ustr);
-        Ok(Self::new(
+        Self::new(
             trader_id,
             strategy_id,
             instrument_id,
@@ -98,7 +101,103 @@ impl LimitIfTouchedOrder {
             init_id,
             ts_init.into(),
         )
-        .unwrap())
+        .map_err(to_pyvalue_err)
+    }
+
+    #[getter]
+    #[pyo3(name = "trader_id")]
+    fn py_trader_id(&self) -> TraderId {
+        self.trader_id
+    }
+
+    #[getter]
+    #[pyo3(name = "strategy_id")]
+    fn py_strategy_id(&self) -> StrategyId {
+        self.strategy_id
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "client_order_id")]
+    fn py_client_order_id(&self) -> ClientOrderId {
+        self.client_order_id
+    }
+
+    #[getter]
+    #[pyo3(name = "side")]
+    fn py_order_side(&self) -> OrderSide {
+        self.side
+    }
+
+    #[getter]
+    #[pyo3(name = "quantity")]
+    fn py_quantity(&self) -> Quantity {
+        self.quantity
+    }
+
+    #[getter]
+    #[pyo3(name = "price")]
+    fn py_price(&self) -> Price {
+        self.price
+    }
+
+    #[getter]
+    #[pyo3(name = "trigger_price")]
+    fn py_trigger_price(&self) -> Price {
+        self.trigger_price
+    }
+
+    #[getter]
+    #[pyo3(name = "trigger_type")]
+    fn py_trigger_type(&self) -> TriggerType {
+        self.trigger_type
+    }
+
+    #[getter]
+    #[pyo3(name = "time_in_force")]
+    fn py_time_in_force(&self) -> TimeInForce {
+        self.time_in_force
+    }
+
+    #[getter]
+    #[pyo3(name = "expire_time")]
+    fn py_expire_time(&self) -> Option<u64> {
+        self.expire_time.map(std::convert::Into::into)
+    }
+
+    #[getter]
+    #[pyo3(name = "status")]
+    fn py_order_status(&self) -> OrderStatus {
+        self.status
+    }
+
+    #[getter]
+    #[pyo3(name = "is_triggered")]
+    fn py_is_triggered(&self) -> bool {
+        self.is_triggered
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_triggered")]
+    fn py_ts_triggered(&self) -> Option<u64> {
+        self.ts_triggered.map(std::convert::Into::into)
+    }
+
+    #[getter]
+    #[pyo3(name = "is_post_only")]
+    fn py_post_only(&self) -> bool {
+        self.is_post_only
+    }
+
+    #[getter]
+    #[pyo3(name = "is_reduce_only")]
+    fn py_reduce_only(&self) -> bool {
+        self.is_reduce_only
     }
 
     #[getter]
@@ -0,0 +1,178 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An `OrderStatusReport` of the state of an order at a venue.
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{
+    enums::{OrderSide, OrderStatus, OrderType, TimeInForce},
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        venue_order_id::VenueOrderId,
+    },
+    orders::any::OrderAny,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents the state of an order at a venue, as reported by an execution client.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OrderStatusReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    /// The client order ID, if known by the venue.
+    pub client_order_id: Option<ClientOrderId>,
+    pub venue_order_id: VenueOrderId,
+    pub order_side: OrderSide,
+    pub order_type: OrderType,
+    pub time_in_force: TimeInForce,
+    pub order_status: OrderStatus,
+    pub quantity: Quantity,
+    pub filled_qty: Quantity,
+    pub price: Option<Price>,
+    /// The average price of all fills for the order.
+    pub avg_px: Option<f64>,
+    /// The reason for a cancel or rejection, if reported by the venue.
+    pub cancel_reason: Option<Ustr>,
+    pub report_id: UUID4,
+    pub ts_accepted: UnixNanos,
+    pub ts_last: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl OrderStatusReport {
+    /// Creates a new [`OrderStatusReport`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        client_order_id: Option<ClientOrderId>,
+        venue_order_id: VenueOrderId,
+        order_side: OrderSide,
+        order_type: OrderType,
+        time_in_force: TimeInForce,
+        order_status: OrderStatus,
+        quantity: Quantity,
+        filled_qty: Quantity,
+        report_id: UUID4,
+        ts_accepted: UnixNanos,
+        ts_last: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            account_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            order_side,
+            order_type,
+            time_in_force,
+            order_status,
+            quantity,
+            filled_qty,
+            price: None,
+            avg_px: None,
+            cancel_reason: None,
+            report_id,
+            ts_accepted,
+            ts_last,
+            ts_init,
+        }
+    }
+
+    /// Sets the order price.
+    #[must_use]
+    pub fn with_price(mut self, price: Price) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    /// Sets the average fill price.
+    #[must_use]
+    pub fn with_avg_px(mut self, avg_px: f64) -> Self {
+        self.avg_px = Some(avg_px);
+        self
+    }
+
+    /// Sets the reason for a cancel or rejection.
+    #[must_use]
+    pub fn with_cancel_reason(mut self, cancel_reason: &str) -> Self {
+        self.cancel_reason = Some(Ustr::from(cancel_reason));
+        self
+    }
+
+    /// Returns whether the `order` has the same status and filled quantity as reported.
+    #[must_use]
+    pub fn is_in_sync(&self, order: &OrderAny) -> bool {
+        order.status() == self.order_status && order.filled_qty() == self.filled_qty
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::orders::stubs::TestOrderStubs;
+
+    fn report(order_status: OrderStatus, filled_qty: i64) -> OrderStatusReport {
+        OrderStatusReport::new(
+            AccountId::default(),
+            InstrumentId::from("AUD/USD.SIM"),
+            None,
+            VenueOrderId::default(),
+            OrderSide::Buy,
+            OrderType::Limit,
+            TimeInForce::Gtc,
+            order_status,
+            Quantity::from(100_000),
+            Quantity::from(filled_qty),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    #[case(OrderStatus::Initialized, 0, true)]
+    #[case(OrderStatus::Accepted, 0, false)]
+    #[case(OrderStatus::Initialized, 50_000, false)]
+    fn test_is_in_sync(
+        #[case] order_status: OrderStatus,
+        #[case] filled_qty: i64,
+        #[case] expected: bool,
+    ) {
+        let order = TestOrderStubs::limit_order(
+            InstrumentId::from("AUD/USD.SIM"),
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+
+        assert_eq!(
+            report(order_status, filled_qty).is_in_sync(&order),
+            expected
+        );
+    }
+}
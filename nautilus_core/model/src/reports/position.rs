@@ -0,0 +1,118 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `PositionStatusReport` of the state of a position at a venue.
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::PositionSide,
+    identifiers::{account_id::AccountId, instrument_id::InstrumentId, position_id::PositionId},
+    types::quantity::Quantity,
+};
+
+/// Represents the state of a position at a venue, as reported by an execution client.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PositionStatusReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    pub position_side: PositionSide,
+    pub quantity: Quantity,
+    /// The position ID assigned by the venue, if the venue assigns position IDs.
+    pub venue_position_id: Option<PositionId>,
+    pub report_id: UUID4,
+    pub ts_last: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl PositionStatusReport {
+    /// Creates a new [`PositionStatusReport`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        position_side: PositionSide,
+        quantity: Quantity,
+        venue_position_id: Option<PositionId>,
+        report_id: UUID4,
+        ts_last: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            account_id,
+            instrument_id,
+            position_side,
+            quantity,
+            venue_position_id,
+            report_id,
+            ts_last,
+            ts_init,
+        }
+    }
+
+    /// Returns the position quantity, signed negative for a short position.
+    #[must_use]
+    pub fn signed_qty(&self) -> f64 {
+        match self.position_side {
+            PositionSide::Short => -self.quantity.as_f64(),
+            _ => self.quantity.as_f64(),
+        }
+    }
+
+    /// Returns whether the `signed_qty` (e.g. of a cached position) matches the report,
+    /// within the precision of the reported quantity.
+    #[must_use]
+    pub fn is_in_sync(&self, signed_qty: f64) -> bool {
+        let tolerance = 0.5 * 10f64.powi(-i32::from(self.quantity.precision));
+        (self.signed_qty() - signed_qty).abs() < tolerance
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(PositionSide::Long, "1.50", 1.5)]
+    #[case(PositionSide::Short, "1.50", -1.5)]
+    #[case(PositionSide::Flat, "0.00", 0.0)]
+    fn test_signed_qty(
+        #[case] position_side: PositionSide,
+        #[case] quantity: &str,
+        #[case] expected: f64,
+    ) {
+        let report = PositionStatusReport::new(
+            AccountId::default(),
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            position_side,
+            Quantity::from(quantity),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+
+        assert_eq!(report.signed_qty(), expected);
+        assert!(report.is_in_sync(expected));
+        assert!(!report.is_in_sync(expected + 0.01));
+    }
+}
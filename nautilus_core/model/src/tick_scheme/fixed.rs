@@ -0,0 +1,190 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A tick scheme with a fixed price increment (e.g. for FX or crypto).
+
+use once_cell::sync::Lazy;
+
+use super::TickScheme;
+use crate::types::price::Price;
+
+/// The tolerance (in ticks) within which a value is treated as on a tick.
+const TICK_TOLERANCE: f64 = 1e-9;
+
+/// Represents a tick scheme with a fixed price increment.
+#[derive(Clone, Debug)]
+pub struct FixedTickScheme {
+    pub name: String,
+    pub increment: Price,
+    pub min_price: Price,
+    pub max_price: Price,
+}
+
+impl FixedTickScheme {
+    /// Creates a new [`FixedTickScheme`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `increment` is not positive, or the precisions of
+    /// the prices differ.
+    pub fn new(
+        name: &str,
+        increment: Price,
+        min_price: Price,
+        max_price: Price,
+    ) -> anyhow::Result<Self> {
+        if increment.raw <= 0 {
+            anyhow::bail!("Invalid `increment` for tick scheme, was {increment}");
+        }
+        if min_price.precision != increment.precision || max_price.precision != increment.precision
+        {
+            anyhow::bail!("Invalid prices for tick scheme, precisions must match the increment");
+        }
+        if min_price > max_price {
+            anyhow::bail!("Invalid `min_price` {min_price} greater than `max_price` {max_price}");
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            increment,
+            min_price,
+            max_price,
+        })
+    }
+}
+
+/// Returns the price `n` ticks of `increment` below `value`, rounding down to a tick.
+#[must_use]
+pub fn next_bid_price_fixed(increment: Price, value: f64, n: usize) -> Option<Price> {
+    let ticks = round_ticks(value / increment.as_f64(), f64::floor) - n as f64;
+    Price::new(ticks * increment.as_f64(), increment.precision).ok()
+}
+
+/// Returns the price `n` ticks of `increment` above `value`, rounding up to a tick.
+#[must_use]
+pub fn next_ask_price_fixed(increment: Price, value: f64, n: usize) -> Option<Price> {
+    let ticks = round_ticks(value / increment.as_f64(), f64::ceil) + n as f64;
+    Price::new(ticks * increment.as_f64(), increment.precision).ok()
+}
+
+fn round_ticks(ticks: f64, round: fn(f64) -> f64) -> f64 {
+    let nearest = ticks.round();
+    if (ticks - nearest).abs() < TICK_TOLERANCE {
+        nearest
+    } else {
+        round(ticks)
+    }
+}
+
+impl TickScheme for FixedTickScheme {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn min_price(&self) -> Price {
+        self.min_price
+    }
+
+    fn max_price(&self) -> Price {
+        self.max_price
+    }
+
+    fn next_bid_price(&self, value: f64, n: usize) -> Option<Price> {
+        next_bid_price_fixed(self.increment, value, n).filter(|price| *price >= self.min_price)
+    }
+
+    fn next_ask_price(&self, value: f64, n: usize) -> Option<Price> {
+        next_ask_price_fixed(self.increment, value, n).filter(|price| *price <= self.max_price)
+    }
+}
+
+/// The tick scheme for most FX pairs.
+pub static FOREX_5DECIMAL_TICK_SCHEME: Lazy<FixedTickScheme> = Lazy::new(|| {
+    FixedTickScheme::new(
+        "FOREX_5DECIMAL",
+        Price::from("0.00001"),
+        Price::from("0.00001"),
+        Price::from("9.99999"),
+    )
+    .unwrap()
+});
+
+/// The tick scheme for JPY denominated FX pairs.
+pub static FOREX_3DECIMAL_TICK_SCHEME: Lazy<FixedTickScheme> = Lazy::new(|| {
+    FixedTickScheme::new(
+        "FOREX_3DECIMAL",
+        Price::from("0.001"),
+        Price::from("0.001"),
+        Price::from("999.999"),
+    )
+    .unwrap()
+});
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(0.8, 0, "0.80000", "0.80000")]
+    #[case(0.800_005, 0, "0.80000", "0.80001")]
+    #[case(0.8, 2, "0.79998", "0.80002")]
+    #[case(1.1, 0, "1.10000", "1.10000")]
+    fn test_next_prices(
+        #[case] value: f64,
+        #[case] n: usize,
+        #[case] expected_bid: &str,
+        #[case] expected_ask: &str,
+    ) {
+        let scheme = &*FOREX_5DECIMAL_TICK_SCHEME;
+        assert_eq!(
+            scheme.next_bid_price(value, n),
+            Some(Price::from(expected_bid))
+        );
+        assert_eq!(
+            scheme.next_ask_price(value, n),
+            Some(Price::from(expected_ask))
+        );
+    }
+
+    #[rstest]
+    fn test_next_prices_beyond_bounds() {
+        let scheme = &*FOREX_3DECIMAL_TICK_SCHEME;
+        assert_eq!(scheme.next_bid_price(0.001, 1), None);
+        assert_eq!(scheme.next_ask_price(999.999, 1), None);
+    }
+
+    #[rstest]
+    fn test_is_valid_price() {
+        let scheme = &*FOREX_3DECIMAL_TICK_SCHEME;
+        assert!(scheme.is_valid_price(Price::from("110.125")));
+        assert!(!scheme.is_valid_price(Price::from("110.1255")));
+    }
+
+    #[rstest]
+    fn test_invalid_increment() {
+        let result = FixedTickScheme::new(
+            "INVALID",
+            Price::from("0.00"),
+            Price::from("0.01"),
+            Price::from("1.00"),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,43 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tick schemes mapping the valid prices of an instrument, for venues where the price
+//! increment varies by price level.
+
+pub mod fixed;
+pub mod tiered;
+
+use crate::types::price::Price;
+
+/// Maps the valid prices available for an instrument.
+pub trait TickScheme: Send + Sync {
+    /// Returns the name of the tick scheme.
+    fn name(&self) -> &str;
+    /// Returns the minimum valid price.
+    fn min_price(&self) -> Price;
+    /// Returns the maximum valid price.
+    fn max_price(&self) -> Price;
+    /// Returns the price `n` bid ticks below `value`, where `n` of zero returns the nearest
+    /// tick at or below `value` (or `None` if beyond the bounds of the scheme).
+    fn next_bid_price(&self, value: f64, n: usize) -> Option<Price>;
+    /// Returns the price `n` ask ticks above `value`, where `n` of zero returns the nearest
+    /// tick at or above `value` (or `None` if beyond the bounds of the scheme).
+    fn next_ask_price(&self, value: f64, n: usize) -> Option<Price>;
+
+    /// Returns whether the given `price` is a valid tick of the scheme.
+    fn is_valid_price(&self, price: Price) -> bool {
+        self.next_bid_price(price.as_f64(), 0) == Some(price)
+    }
+}
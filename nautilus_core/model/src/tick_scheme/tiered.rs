@@ -0,0 +1,233 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A tick scheme where the price increment changes with the price level (e.g. Betfair odds
+//! ladders or equity venues with tiered tick sizes).
+
+use once_cell::sync::Lazy;
+
+use super::TickScheme;
+use crate::types::price::Price;
+
+/// The default maximum number of ticks for a tier without an upper bound.
+pub const DEFAULT_MAX_TICKS_PER_TIER: usize = 100;
+
+/// Represents a tick scheme where the price increment changes with the price level.
+#[derive(Clone, Debug)]
+pub struct TieredTickScheme {
+    pub name: String,
+    pub price_precision: u8,
+    /// The valid prices in ascending order.
+    pub ticks: Vec<Price>,
+}
+
+impl TieredTickScheme {
+    /// Creates a new [`TieredTickScheme`] instance from the given `tiers` of
+    /// `(start, stop, increment)`, where `stop` is exclusive.
+    ///
+    /// A tier with an infinite `stop` is expanded up to `max_ticks_per_tier` ticks.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `tiers` is empty, or a tier is invalid.
+    pub fn new(
+        name: &str,
+        tiers: &[(f64, f64, f64)],
+        price_precision: u8,
+        max_ticks_per_tier: Option<usize>,
+    ) -> anyhow::Result<Self> {
+        if tiers.is_empty() {
+            anyhow::bail!("Invalid `tiers` for tick scheme {name}, was empty");
+        }
+        let max_ticks_per_tier = max_ticks_per_tier.unwrap_or(DEFAULT_MAX_TICKS_PER_TIER);
+
+        let mut ticks: Vec<Price> = Vec::new();
+        for &(start, stop, increment) in tiers {
+            if start >= stop || increment <= 0.0 || increment > start {
+                anyhow::bail!("Invalid tier ({start}, {stop}, {increment}) for tick scheme {name}");
+            }
+            let start = Price::new(start, price_precision)?;
+            let increment = Price::new(increment, price_precision)?;
+            let stop_raw = if stop.is_infinite() {
+                start.raw + (max_ticks_per_tier as i64 + 1) * increment.raw
+            } else {
+                Price::new(stop, price_precision)?.raw
+            };
+
+            if let Some(last) = ticks.last() {
+                if start <= *last {
+                    anyhow::bail!("Invalid tiers for tick scheme {name}, must be ascending");
+                }
+            }
+
+            let tier_start = ticks.len();
+            let mut raw = start.raw;
+            while raw < stop_raw && ticks.len() - tier_start <= max_ticks_per_tier {
+                ticks.push(Price::from_raw(raw, price_precision)?);
+                raw += increment.raw;
+            }
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            price_precision,
+            ticks,
+        })
+    }
+
+    /// Returns the number of ticks in the scheme.
+    #[must_use]
+    pub fn tick_count(&self) -> usize {
+        self.ticks.len()
+    }
+}
+
+impl TickScheme for TieredTickScheme {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn min_price(&self) -> Price {
+        self.ticks[0]
+    }
+
+    fn max_price(&self) -> Price {
+        self.ticks[self.ticks.len() - 1]
+    }
+
+    fn next_bid_price(&self, value: f64, n: usize) -> Option<Price> {
+        let idx = self.ticks.partition_point(|tick| tick.as_f64() <= value);
+        idx.checked_sub(1 + n).map(|idx| self.ticks[idx])
+    }
+
+    fn next_ask_price(&self, value: f64, n: usize) -> Option<Price> {
+        let idx = self.ticks.partition_point(|tick| tick.as_f64() < value);
+        self.ticks.get(idx + n).copied()
+    }
+}
+
+/// The tick scheme for the Betfair decimal odds ladder.
+pub static BETFAIR_TICK_SCHEME: Lazy<TieredTickScheme> = Lazy::new(|| {
+    TieredTickScheme::new(
+        "BETFAIR",
+        &[
+            (1.01, 2.0, 0.01),
+            (2.0, 3.0, 0.02),
+            (3.0, 4.0, 0.05),
+            (4.0, 6.0, 0.1),
+            (6.0, 10.0, 0.2),
+            (10.0, 20.0, 0.5),
+            (20.0, 30.0, 1.0),
+            (30.0, 50.0, 2.0),
+            (50.0, 100.0, 5.0),
+            (100.0, 1010.0, 10.0),
+        ],
+        2,
+        None,
+    )
+    .unwrap()
+});
+
+/// The tick scheme for TOPIX 100 constituents on the Tokyo Stock Exchange.
+pub static TOPIX100_TICK_SCHEME: Lazy<TieredTickScheme> = Lazy::new(|| {
+    TieredTickScheme::new(
+        "TOPIX100",
+        &[
+            (0.1, 1_000.0, 0.1),
+            (1_000.0, 3_000.0, 0.5),
+            (3_000.0, 10_000.0, 1.0),
+            (10_000.0, 30_000.0, 5.0),
+            (30_000.0, 100_000.0, 10.0),
+            (100_000.0, 300_000.0, 50.0),
+            (300_000.0, 1_000_000.0, 100.0),
+            (1_000_000.0, 3_000_000.0, 500.0),
+            (3_000_000.0, 10_000_000.0, 1_000.0),
+            (10_000_000.0, 30_000_000.0, 5_000.0),
+            (30_000_000.0, f64::INFINITY, 10_000.0),
+        ],
+        4,
+        Some(10_000),
+    )
+    .unwrap()
+});
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_betfair_ticks() {
+        let scheme = &*BETFAIR_TICK_SCHEME;
+        assert_eq!(scheme.min_price(), Price::from("1.01"));
+        assert_eq!(scheme.max_price(), Price::from("1000.00"));
+        assert_eq!(scheme.tick_count(), 350);
+    }
+
+    #[rstest]
+    #[case(1.01, 0, Some("1.01"), Some("1.01"))]
+    #[case(1.995, 0, Some("1.99"), Some("2.00"))]
+    #[case(2.0, 1, Some("1.99"), Some("2.02"))]
+    #[case(3.01, 2, Some("2.96"), Some("3.15"))]
+    #[case(1.0, 0, None, Some("1.01"))]
+    #[case(1000.0, 1, Some("990.00"), None)]
+    fn test_betfair_next_prices(
+        #[case] value: f64,
+        #[case] n: usize,
+        #[case] expected_bid: Option<&str>,
+        #[case] expected_ask: Option<&str>,
+    ) {
+        let scheme = &*BETFAIR_TICK_SCHEME;
+        assert_eq!(
+            scheme.next_bid_price(value, n),
+            expected_bid.map(Price::from)
+        );
+        assert_eq!(
+            scheme.next_ask_price(value, n),
+            expected_ask.map(Price::from)
+        );
+    }
+
+    #[rstest]
+    fn test_is_valid_price() {
+        let scheme = &*BETFAIR_TICK_SCHEME;
+        assert!(scheme.is_valid_price(Price::from("2.02")));
+        assert!(!scheme.is_valid_price(Price::from("2.01")));
+        assert!(!scheme.is_valid_price(Price::from("1.00")));
+    }
+
+    #[rstest]
+    fn test_topix100_unbounded_tier() {
+        let scheme = &*TOPIX100_TICK_SCHEME;
+        assert_eq!(scheme.min_price(), Price::from("0.1000"));
+        assert_eq!(
+            scheme.next_ask_price(30_000_001.0, 0),
+            Some(Price::from("30010000.0000"))
+        );
+    }
+
+    #[rstest]
+    fn test_invalid_tier() {
+        assert!(TieredTickScheme::new("INVALID", &[(2.0, 1.0, 0.1)], 2, None).is_err());
+        assert!(TieredTickScheme::new("INVALID", &[], 2, None).is_err());
+        assert!(
+            TieredTickScheme::new("INVALID", &[(2.0, 3.0, 0.1), (1.0, 2.0, 0.1)], 2, None).is_err()
+        );
+    }
+}
@@ -0,0 +1,186 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Serialization of fixed-point value types in either a human-readable or compact form.
+//!
+//! The human-readable form is the decimal string (e.g. `"1.2500"`), and the compact form is
+//! the raw fixed-point integer and precision (e.g. `[1250000000, 4]`). By default the form is
+//! selected by the serializer (see [`Serializer::is_human_readable`]), so JSON payloads remain
+//! readable while binary formats such as MessagePack use the compact form. Deserialization
+//! accepts either form.
+//!
+//! This module can also be used with `#[serde(with = "...")]` to always serialize a field in
+//! the compact form, e.g. for high-volume JSON event streams.
+
+use std::{fmt::Formatter, marker::PhantomData};
+
+use serde::{
+    de::{DeserializeOwned, SeqAccess, Visitor},
+    Deserializer, Serialize, Serializer,
+};
+
+/// Provides conversion of a fixed-point value type to and from its serialized forms.
+pub trait FixedPointSerde: Sized {
+    /// The raw fixed-point integer type.
+    type Raw: Serialize + DeserializeOwned;
+
+    /// Returns the raw fixed-point integer and precision.
+    fn to_raw_parts(&self) -> (Self::Raw, u8);
+
+    /// Returns a value from the given raw fixed-point integer and precision.
+    fn from_raw_parts(raw: Self::Raw, precision: u8) -> anyhow::Result<Self>;
+
+    /// Returns a value parsed from the given human-readable string.
+    fn from_readable(value: &str) -> anyhow::Result<Self>;
+
+    /// Returns the human-readable string for the value.
+    fn to_readable(&self) -> String;
+}
+
+/// Serializes the `value` in the compact form.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: FixedPointSerde,
+    S: Serializer,
+{
+    value.to_raw_parts().serialize(serializer)
+}
+
+/// Deserializes a value from either the compact or human-readable form.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FixedPointSerde,
+    D: Deserializer<'de>,
+{
+    let visitor = FixedPointVisitor(PhantomData);
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_tuple(2, visitor)
+    }
+}
+
+/// Serializes the `value` in the form selected by the `serializer`.
+pub(crate) fn serialize_default<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: FixedPointSerde,
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&value.to_readable())
+    } else {
+        serialize(value, serializer)
+    }
+}
+
+struct FixedPointVisitor<T>(PhantomData<T>);
+
+impl<'de, T: FixedPointSerde> Visitor<'de> for FixedPointVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "a decimal string or a [raw, precision] sequence")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        T::from_readable(v).map_err(E::custom)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let raw = seq
+            .next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+        let precision = seq
+            .next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+        T::from_raw_parts(raw, precision).map_err(serde::de::Error::custom)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde::{Deserialize, Serialize};
+
+    use crate::types::{price::Price, quantity::Quantity};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct CompactFill {
+        #[serde(with = "crate::types::compact")]
+        price: Price,
+        #[serde(with = "crate::types::compact")]
+        quantity: Quantity,
+    }
+
+    #[rstest]
+    fn test_json_uses_readable_form() {
+        let price = Price::from("1.2500");
+
+        let json = serde_json::to_string(&price).unwrap();
+
+        assert_eq!(json, "\"1.2500\"");
+        assert_eq!(serde_json::from_str::<Price>(&json).unwrap(), price);
+    }
+
+    #[rstest]
+    fn test_msgpack_uses_compact_form() {
+        let quantity = Quantity::from("100.5");
+
+        let bytes = rmp_serde::to_vec(&quantity).unwrap();
+
+        assert_eq!(
+            rmp_serde::from_slice::<(u64, u8)>(&bytes).unwrap(),
+            (quantity.raw, 1)
+        );
+        assert_eq!(rmp_serde::from_slice::<Quantity>(&bytes).unwrap(), quantity);
+    }
+
+    #[rstest]
+    fn test_compact_with_attribute() {
+        let fill = CompactFill {
+            price: Price::from("1.2500"),
+            quantity: Quantity::from(10),
+        };
+
+        let json = serde_json::to_string(&fill).unwrap();
+
+        assert_eq!(
+            json,
+            format!(
+                "{{\"price\":[{},4],\"quantity\":[{},0]}}",
+                fill.price.raw, fill.quantity.raw
+            )
+        );
+        assert_eq!(serde_json::from_str::<CompactFill>(&json).unwrap(), fill);
+    }
+
+    #[rstest]
+    fn test_deserialize_compact_into_default() {
+        let price = Price::from("-0.05");
+        let json = format!("[{}, 2]", price.raw);
+
+        assert_eq!(serde_json::from_str::<Price>(&json).unwrap(), price);
+        assert!(serde_json::from_str::<Price>("[1, 10]").is_err());
+    }
+}
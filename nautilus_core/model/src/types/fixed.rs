@@ -23,6 +23,13 @@ pub fn check_fixed_precision(precision: u8) -> anyhow::Result<()> {
     Ok(())
 }
 
+pub fn check_same_precision(lhs: u8, rhs: u8) -> anyhow::Result<()> {
+    if lhs != rhs {
+        anyhow::bail!("Condition failed: precision mismatch, was {lhs} and {rhs}")
+    }
+    Ok(())
+}
+
 #[must_use]
 pub fn f64_to_fixed_i64(value: f64, precision: u8) -> i64 {
     assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
@@ -16,10 +16,12 @@
 //! Value types for the trading domain model such as `Price`, `Quantity` and `Money`.
 
 pub mod balance;
+pub mod compact;
 pub mod currency;
 pub mod fixed;
 pub mod money;
 pub mod price;
 pub mod quantity;
+pub mod rounding;
 #[cfg(feature = "stubs")]
 pub mod stubs;
@@ -26,8 +26,14 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
-use super::fixed::{check_fixed_precision, FIXED_PRECISION, FIXED_SCALAR};
-use crate::types::fixed::{f64_to_fixed_i64, fixed_i64_to_f64};
+use super::{
+    compact::{self, FixedPointSerde},
+    fixed::{check_fixed_precision, check_same_precision, FIXED_PRECISION, FIXED_SCALAR},
+};
+use crate::types::{
+    fixed::{f64_to_fixed_i64, fixed_i64_to_f64},
+    quantity::Quantity,
+};
 
 pub const PRICE_MAX: f64 = 9_223_372_036.0;
 pub const PRICE_MIN: f64 = -9_223_372_036.0;
@@ -110,6 +116,45 @@ impl Price {
     pub fn to_formatted_string(&self) -> String {
         format!("{self}").separate_with_underscores()
     }
+
+    /// Returns the sum of this price and `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the precisions differ, or on overflow.
+    pub fn checked_add(self, rhs: Self) -> anyhow::Result<Self> {
+        check_same_precision(self.precision, rhs.precision)?;
+        let raw = self
+            .raw
+            .checked_add(rhs.raw)
+            .ok_or_else(|| anyhow::anyhow!("Overflow adding {rhs} to {self}"))?;
+        Self::from_raw(raw, self.precision)
+    }
+
+    /// Returns the difference of this price and `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the precisions differ, or on overflow.
+    pub fn checked_sub(self, rhs: Self) -> anyhow::Result<Self> {
+        check_same_precision(self.precision, rhs.precision)?;
+        let raw = self
+            .raw
+            .checked_sub(rhs.raw)
+            .ok_or_else(|| anyhow::anyhow!("Overflow subtracting {rhs} from {self}"))?;
+        Self::from_raw(raw, self.precision)
+    }
+
+    /// Returns the exact product of this price and the given `qty` (e.g. a notional value).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error on overflow.
+    pub fn mul_by_qty(self, qty: Quantity) -> anyhow::Result<Decimal> {
+        self.as_decimal()
+            .checked_mul(qty.as_decimal())
+            .ok_or_else(|| anyhow::anyhow!("Overflow multiplying {self} by {qty}"))
+    }
 }
 
 impl FromStr for Price {
@@ -202,12 +247,18 @@ impl Neg for Price {
     }
 }
 
+// The operators widen to the greater precision of the operands, and panic on overflow.
+// Use `checked_add` and `checked_sub` where these conditions must be handled.
+
 impl Add for Price {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
         Self {
-            raw: self.raw + rhs.raw,
-            precision: self.precision,
+            raw: self
+                .raw
+                .checked_add(rhs.raw)
+                .expect("Overflow adding `Price`"),
+            precision: self.precision.max(rhs.precision),
         }
     }
 }
@@ -216,21 +267,24 @@ impl Sub for Price {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
         Self {
-            raw: self.raw - rhs.raw,
-            precision: self.precision,
+            raw: self
+                .raw
+                .checked_sub(rhs.raw)
+                .expect("Overflow subtracting `Price`"),
+            precision: self.precision.max(rhs.precision),
         }
     }
 }
 
 impl AddAssign for Price {
     fn add_assign(&mut self, other: Self) {
-        self.raw += other.raw;
+        *self = *self + other;
     }
 }
 
 impl SubAssign for Price {
     fn sub_assign(&mut self, other: Self) {
-        self.raw -= other.raw;
+        *self = *self - other;
     }
 }
 
@@ -273,23 +327,41 @@ impl Display for Price {
     }
 }
 
+impl FixedPointSerde for Price {
+    type Raw = i64;
+
+    fn to_raw_parts(&self) -> (i64, u8) {
+        (self.raw, self.precision)
+    }
+
+    fn from_raw_parts(raw: i64, precision: u8) -> anyhow::Result<Self> {
+        Self::from_raw(raw, precision)
+    }
+
+    fn from_readable(value: &str) -> anyhow::Result<Self> {
+        Self::from_str(value).map_err(|e| anyhow::anyhow!(e))
+    }
+
+    fn to_readable(&self) -> String {
+        self.to_string()
+    }
+}
+
 impl Serialize for Price {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        compact::serialize_default(self, serializer)
     }
 }
 
 impl<'de> Deserialize<'de> for Price {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let price_str: &str = Deserialize::deserialize(_deserializer)?;
-        let price: Self = price_str.into();
-        Ok(price)
+        compact::deserialize(deserializer)
     }
 }
 
@@ -483,6 +555,49 @@ mod tests {
         assert_eq!(price3.raw, 11_000_000);
     }
 
+    #[rstest]
+    fn test_add_widens_precision() {
+        let price = Price::from("1.0") + Price::from("0.25");
+        assert_eq!(price, Price::from("1.25"));
+        assert_eq!(price.precision, 2);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Overflow adding `Price`")]
+    fn test_add_overflow() {
+        let _ = Price::from_raw(i64::MAX, 0).unwrap() + Price::from_raw(1, 0).unwrap();
+    }
+
+    #[rstest]
+    fn test_checked_add_and_sub() {
+        let price1 = Price::from("1.011");
+        let price2 = Price::from("1.000");
+        assert_eq!(price1.checked_add(price2).unwrap(), Price::from("2.011"));
+        assert_eq!(price1.checked_sub(price2).unwrap(), Price::from("0.011"));
+    }
+
+    #[rstest]
+    fn test_checked_add_precision_mismatch() {
+        let result = Price::from("1.0").checked_add(Price::from("1.00"));
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_checked_sub_overflow() {
+        let result = Price::from_raw(i64::MIN, 0)
+            .unwrap()
+            .checked_sub(Price::from_raw(1, 0).unwrap());
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_mul_by_qty() {
+        let notional = Price::from("1.2345")
+            .mul_by_qty(Quantity::from("100000"))
+            .unwrap();
+        assert_eq!(notional, dec!(123_450));
+    }
+
     #[rstest]
     fn test_add_assign() {
         let mut price = Price::new(1.000, 3).unwrap();
@@ -26,7 +26,10 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
-use super::fixed::{check_fixed_precision, FIXED_PRECISION, FIXED_SCALAR};
+use super::{
+    compact::{self, FixedPointSerde},
+    fixed::{check_fixed_precision, check_same_precision, FIXED_PRECISION, FIXED_SCALAR},
+};
 use crate::types::fixed::{f64_to_fixed_u64, fixed_u64_to_f64};
 
 pub const QUANTITY_MAX: f64 = 18_446_744_073.0;
@@ -92,6 +95,51 @@ impl Quantity {
     pub fn to_formatted_string(&self) -> String {
         format!("{self}").separate_with_underscores()
     }
+
+    /// Returns the sum of this quantity and `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the precisions differ, or on overflow.
+    pub fn checked_add(self, rhs: Self) -> anyhow::Result<Self> {
+        check_same_precision(self.precision, rhs.precision)?;
+        let raw = self
+            .raw
+            .checked_add(rhs.raw)
+            .ok_or_else(|| anyhow::anyhow!("Overflow adding {rhs} to {self}"))?;
+        Self::from_raw(raw, self.precision)
+    }
+
+    /// Returns the difference of this quantity and `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the precisions differ, or if the result would be negative.
+    pub fn checked_sub(self, rhs: Self) -> anyhow::Result<Self> {
+        check_same_precision(self.precision, rhs.precision)?;
+        let raw = self
+            .raw
+            .checked_sub(rhs.raw)
+            .ok_or_else(|| anyhow::anyhow!("Underflow subtracting {rhs} from {self}"))?;
+        Self::from_raw(raw, self.precision)
+    }
+
+    /// Returns the product of this quantity and `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the precisions differ, or on overflow.
+    pub fn checked_mul(self, rhs: Self) -> anyhow::Result<Self> {
+        check_same_precision(self.precision, rhs.precision)?;
+        let raw = mul_raw(self.raw, rhs.raw)
+            .ok_or_else(|| anyhow::anyhow!("Overflow multiplying {self} by {rhs}"))?;
+        Self::from_raw(raw, self.precision)
+    }
+}
+
+fn mul_raw(lhs: u64, rhs: u64) -> Option<u64> {
+    let raw = u128::from(lhs) * u128::from(rhs) / (FIXED_SCALAR as u128);
+    u64::try_from(raw).ok()
 }
 
 impl From<Quantity> for f64 {
@@ -180,12 +228,18 @@ impl Deref for Quantity {
     }
 }
 
+// The operators widen to the greater precision of the operands, and panic on overflow.
+// Use `checked_add`, `checked_sub` and `checked_mul` where these conditions must be handled.
+
 impl Add for Quantity {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
         Self {
-            raw: self.raw + rhs.raw,
-            precision: self.precision,
+            raw: self
+                .raw
+                .checked_add(rhs.raw)
+                .expect("Overflow adding `Quantity`"),
+            precision: self.precision.max(rhs.precision),
         }
     }
 }
@@ -194,8 +248,11 @@ impl Sub for Quantity {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
         Self {
-            raw: self.raw - rhs.raw,
-            precision: self.precision,
+            raw: self
+                .raw
+                .checked_sub(rhs.raw)
+                .expect("Underflow subtracting `Quantity`"),
+            precision: self.precision.max(rhs.precision),
         }
     }
 }
@@ -204,8 +261,8 @@ impl Mul for Quantity {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self::Output {
         Self {
-            raw: (self.raw * rhs.raw) / (FIXED_SCALAR as u64),
-            precision: self.precision,
+            raw: mul_raw(self.raw, rhs.raw).expect("Overflow multiplying `Quantity`"),
+            precision: self.precision.max(rhs.precision),
         }
     }
 }
@@ -265,23 +322,41 @@ impl Display for Quantity {
     }
 }
 
+impl FixedPointSerde for Quantity {
+    type Raw = u64;
+
+    fn to_raw_parts(&self) -> (u64, u8) {
+        (self.raw, self.precision)
+    }
+
+    fn from_raw_parts(raw: u64, precision: u8) -> anyhow::Result<Self> {
+        Self::from_raw(raw, precision)
+    }
+
+    fn from_readable(value: &str) -> anyhow::Result<Self> {
+        Self::from_str(value).map_err(|e| anyhow::anyhow!(e))
+    }
+
+    fn to_readable(&self) -> String {
+        self.to_string()
+    }
+}
+
 impl Serialize for Quantity {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        compact::serialize_default(self, serializer)
     }
 }
 
 impl<'de> Deserialize<'de> for Quantity {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let qty_str: &str = Deserialize::deserialize(_deserializer)?;
-        let qty: Self = qty_str.into();
-        Ok(qty)
+        compact::deserialize(deserializer)
     }
 }
 
@@ -475,6 +550,49 @@ mod tests {
         assert_eq!(quantity3.raw, 4_000_000_000);
     }
 
+    #[rstest]
+    fn test_mul_large_values() {
+        let quantity = Quantity::from("100000") * Quantity::from("100000");
+        assert_eq!(quantity, Quantity::from("10000000000"));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Underflow subtracting `Quantity`")]
+    fn test_sub_underflow() {
+        let _ = Quantity::from("1") - Quantity::from("2");
+    }
+
+    #[rstest]
+    fn test_checked_arithmetic() {
+        let quantity1 = Quantity::from("3");
+        let quantity2 = Quantity::from("2");
+        assert_eq!(
+            quantity1.checked_add(quantity2).unwrap(),
+            Quantity::from("5")
+        );
+        assert_eq!(
+            quantity1.checked_sub(quantity2).unwrap(),
+            Quantity::from("1")
+        );
+        assert_eq!(
+            quantity1.checked_mul(quantity2).unwrap(),
+            Quantity::from("6")
+        );
+        assert!(quantity2.checked_sub(quantity1).is_err());
+    }
+
+    #[rstest]
+    fn test_checked_add_precision_mismatch() {
+        let result = Quantity::from("1").checked_add(Quantity::from("1.0"));
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_checked_mul_overflow() {
+        let quantity = Quantity::from("10000000000");
+        assert!(quantity.checked_mul(quantity).is_err());
+    }
+
     #[rstest]
     fn test_equality() {
         assert_eq!(
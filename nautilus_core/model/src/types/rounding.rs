@@ -0,0 +1,256 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A central registry of rounding policies for prices, quantities and fees.
+//!
+//! A policy can be registered per venue or per instrument (an instrument policy takes precedence
+//! over its venue policy). Instruments use the resolved policy when making prices and
+//! quantities, and accounts use it when calculating commissions, so that rounding is applied
+//! consistently rather than chosen ad hoc at each call site.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        RwLock,
+    },
+};
+
+use once_cell::sync::Lazy;
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal, RoundingStrategy,
+};
+
+use super::{currency::Currency, money::Money, price::Price, quantity::Quantity};
+use crate::identifiers::{instrument_id::InstrumentId, venue::Venue};
+
+/// The registered rounding policies per scope.
+static ROUNDING_POLICIES: Lazy<RwLock<HashMap<RoundingScope, RoundingPolicy>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Whether any rounding policies are registered, checked before taking the registry lock so
+/// that resolving a policy is cheap when none are registered.
+static HAS_ROUNDING_POLICIES: AtomicBool = AtomicBool::new(false);
+
+/// The mode used when rounding a value to a precision.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds towards zero (truncates).
+    Down,
+    /// Rounds away from zero.
+    Up,
+    /// Rounds to the nearest value, with ties rounded away from zero.
+    #[default]
+    HalfUp,
+    /// Rounds to the nearest value, with ties rounded to the even neighbour (banker's rounding).
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Returns the given `value` rounded to `precision` decimal places with the mode.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `value` is not finite.
+    pub fn round(&self, value: f64, precision: u8) -> anyhow::Result<f64> {
+        let strategy = match self {
+            Self::Down => RoundingStrategy::ToZero,
+            Self::Up => RoundingStrategy::AwayFromZero,
+            Self::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            Self::HalfEven => RoundingStrategy::MidpointNearestEven,
+        };
+        Decimal::from_f64(value)
+            .and_then(|d| {
+                d.round_dp_with_strategy(u32::from(precision), strategy)
+                    .to_f64()
+            })
+            .ok_or_else(|| anyhow::anyhow!("Invalid value {value} for rounding"))
+    }
+}
+
+/// Represents the rounding modes applied to prices, quantities and fees.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RoundingPolicy {
+    /// The rounding mode for prices.
+    pub price: RoundingMode,
+    /// The rounding mode for quantities.
+    pub quantity: RoundingMode,
+    /// The rounding mode for fees (commissions).
+    pub fee: RoundingMode,
+}
+
+impl RoundingPolicy {
+    /// Creates a new [`RoundingPolicy`] instance.
+    #[must_use]
+    pub fn new(price: RoundingMode, quantity: RoundingMode, fee: RoundingMode) -> Self {
+        Self {
+            price,
+            quantity,
+            fee,
+        }
+    }
+
+    /// Returns a new [`Price`] from the given `value` rounded to `precision` with the policy.
+    pub fn round_price(&self, value: f64, precision: u8) -> anyhow::Result<Price> {
+        Price::new(self.price.round(value, precision)?, precision)
+    }
+
+    /// Returns a new [`Quantity`] from the given `value` rounded to `precision` with the policy.
+    pub fn round_qty(&self, value: f64, precision: u8) -> anyhow::Result<Quantity> {
+        Quantity::new(self.quantity.round(value, precision)?, precision)
+    }
+
+    /// Returns a new fee [`Money`] from the given `amount` rounded to the precision of the
+    /// `currency` with the policy.
+    pub fn round_fee(&self, amount: f64, currency: Currency) -> anyhow::Result<Money> {
+        Money::new(self.fee.round(amount, currency.precision)?, currency)
+    }
+}
+
+/// The scope a rounding policy is registered for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingScope {
+    Venue(Venue),
+    Instrument(InstrumentId),
+}
+
+/// Registers the given rounding `policy` for the `scope`, replacing any existing policy.
+pub fn register_rounding_policy(
+    scope: RoundingScope,
+    policy: RoundingPolicy,
+) -> anyhow::Result<()> {
+    let mut policies = ROUNDING_POLICIES
+        .write()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire lock on `ROUNDING_POLICIES`: {e}"))?;
+    policies.insert(scope, policy);
+    HAS_ROUNDING_POLICIES.store(true, Ordering::Release);
+    Ok(())
+}
+
+/// Deregisters the rounding policy for the `scope`, returning the policy (if registered).
+pub fn deregister_rounding_policy(scope: &RoundingScope) -> anyhow::Result<Option<RoundingPolicy>> {
+    let mut policies = ROUNDING_POLICIES
+        .write()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire lock on `ROUNDING_POLICIES`: {e}"))?;
+    let policy = policies.remove(scope);
+    HAS_ROUNDING_POLICIES.store(!policies.is_empty(), Ordering::Release);
+    Ok(policy)
+}
+
+/// Returns the rounding policy for the `instrument_id`.
+///
+/// The instrument policy is returned if registered, otherwise the policy for its venue, or the
+/// default policy (round half up for all values).
+#[must_use]
+pub fn get_rounding_policy(instrument_id: &InstrumentId) -> RoundingPolicy {
+    find_rounding_policy(instrument_id).unwrap_or_default()
+}
+
+/// Returns the rounding policy registered for the `instrument_id` or its venue (if any).
+///
+/// The registry lock is only taken when at least one policy is registered.
+#[must_use]
+pub fn find_rounding_policy(instrument_id: &InstrumentId) -> Option<RoundingPolicy> {
+    if !HAS_ROUNDING_POLICIES.load(Ordering::Acquire) {
+        return None;
+    }
+    ROUNDING_POLICIES.read().ok().and_then(|policies| {
+        policies
+            .get(&RoundingScope::Instrument(*instrument_id))
+            .or_else(|| policies.get(&RoundingScope::Venue(instrument_id.venue)))
+            .copied()
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(RoundingMode::Down, 1.235, 1.23)]
+    #[case(RoundingMode::Up, 1.231, 1.24)]
+    #[case(RoundingMode::HalfUp, 1.225, 1.23)]
+    #[case(RoundingMode::HalfEven, 1.225, 1.22)]
+    #[case(RoundingMode::HalfEven, 1.235, 1.24)]
+    #[case(RoundingMode::Down, -1.239, -1.23)]
+    fn test_round(#[case] mode: RoundingMode, #[case] value: f64, #[case] expected: f64) {
+        assert_eq!(mode.round(value, 2).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_round_invalid_value() {
+        assert!(RoundingMode::HalfUp.round(f64::NAN, 2).is_err());
+    }
+
+    #[rstest]
+    fn test_policy_round_values() {
+        let policy = RoundingPolicy::new(
+            RoundingMode::HalfUp,
+            RoundingMode::Down,
+            RoundingMode::HalfEven,
+        );
+
+        assert_eq!(policy.round_price(1.2345, 3).unwrap(), Price::from("1.235"));
+        assert_eq!(policy.round_qty(0.9999, 2).unwrap(), Quantity::from("0.99"));
+        assert_eq!(
+            policy.round_fee(0.125, Currency::USD()).unwrap(),
+            Money::from("0.12 USD")
+        );
+    }
+
+    #[rstest]
+    fn test_registry_resolution() {
+        // Use a venue unique to this test as the registry is global
+        let instrument_id = InstrumentId::from("ETHUSDT.XROUNDING");
+        let venue_policy = RoundingPolicy::new(
+            RoundingMode::HalfUp,
+            RoundingMode::Down,
+            RoundingMode::HalfUp,
+        );
+        let instrument_policy = RoundingPolicy::new(
+            RoundingMode::HalfEven,
+            RoundingMode::Down,
+            RoundingMode::HalfEven,
+        );
+
+        assert_eq!(
+            get_rounding_policy(&instrument_id),
+            RoundingPolicy::default()
+        );
+
+        assert!(find_rounding_policy(&instrument_id).is_none());
+
+        register_rounding_policy(RoundingScope::Venue(instrument_id.venue), venue_policy).unwrap();
+        assert_eq!(find_rounding_policy(&instrument_id), Some(venue_policy));
+        assert_eq!(get_rounding_policy(&instrument_id), venue_policy);
+
+        register_rounding_policy(RoundingScope::Instrument(instrument_id), instrument_policy)
+            .unwrap();
+        assert_eq!(get_rounding_policy(&instrument_id), instrument_policy);
+
+        deregister_rounding_policy(&RoundingScope::Instrument(instrument_id)).unwrap();
+        deregister_rounding_policy(&RoundingScope::Venue(instrument_id.venue)).unwrap();
+        assert_eq!(
+            get_rounding_policy(&instrument_id),
+            RoundingPolicy::default()
+        );
+    }
+}
@@ -32,6 +32,7 @@ async fn main() {
                 "http://127.0.0.1:3000".to_string(),
                 HashMap::new(),
                 None,
+                None,
             ));
         }
 
@@ -0,0 +1,174 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Exponential backoff with a capped retry budget, for reconnection attempts.
+
+use std::time::Duration;
+
+/// Provides delays growing exponentially from an initial delay up to a maximum delay, with
+/// an optional maximum number of attempts.
+#[derive(Clone, Debug)]
+pub struct ExponentialBackoff {
+    delay_initial: Duration,
+    delay_max: Duration,
+    factor: f64,
+    max_attempts: Option<u32>,
+    attempts: u32,
+    delay_next: Duration,
+}
+
+impl ExponentialBackoff {
+    /// Creates a new [`ExponentialBackoff`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `factor` is less than 1, or `delay_initial` is
+    /// greater than `delay_max`.
+    pub fn new(
+        delay_initial: Duration,
+        delay_max: Duration,
+        factor: f64,
+        max_attempts: Option<u32>,
+    ) -> anyhow::Result<Self> {
+        if !(factor.is_finite() && factor >= 1.0) {
+            anyhow::bail!("Invalid `factor`, was {factor}");
+        }
+        if delay_initial > delay_max {
+            anyhow::bail!(
+                "Invalid `delay_initial` {delay_initial:?} greater than `delay_max` {delay_max:?}"
+            );
+        }
+
+        Ok(Self {
+            delay_initial,
+            delay_max,
+            factor,
+            max_attempts,
+            attempts: 0,
+            delay_next: delay_initial,
+        })
+    }
+
+    /// Returns the number of attempts made since the last reset.
+    #[must_use]
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Returns whether the retry budget has been used up.
+    #[must_use]
+    pub fn is_exhausted(&self) -> bool {
+        self.max_attempts
+            .map_or(false, |max_attempts| self.attempts >= max_attempts)
+    }
+
+    /// Returns the delay before the next attempt, or `None` if the retry budget has been
+    /// used up.
+    pub fn next_delay(&mut self) -> Option<Duration> {
+        if self.is_exhausted() {
+            return None;
+        }
+
+        let delay = self.delay_next;
+        self.attempts += 1;
+        self.delay_next = self.delay_next.mul_f64(self.factor).min(self.delay_max);
+        Some(delay)
+    }
+
+    /// Resets the attempts and delay, e.g. after a successful reconnection.
+    pub fn reset(&mut self) {
+        self.attempts = 0;
+        self.delay_next = self.delay_initial;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_next_delay_grows_to_max() {
+        let mut backoff = ExponentialBackoff::new(
+            Duration::from_millis(100),
+            Duration::from_millis(500),
+            2.0,
+            None,
+        )
+        .unwrap();
+
+        let delays: Vec<Duration> = (0..5).map(|_| backoff.next_delay().unwrap()).collect();
+
+        assert_eq!(
+            delays,
+            vec![
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+                Duration::from_millis(400),
+                Duration::from_millis(500),
+                Duration::from_millis(500),
+            ]
+        );
+        assert_eq!(backoff.attempts(), 5);
+    }
+
+    #[rstest]
+    fn test_next_delay_when_budget_exhausted() {
+        let mut backoff =
+            ExponentialBackoff::new(Duration::from_secs(1), Duration::from_secs(1), 2.0, Some(2))
+                .unwrap();
+
+        assert!(backoff.next_delay().is_some());
+        assert!(backoff.next_delay().is_some());
+        assert!(backoff.next_delay().is_none());
+        assert!(backoff.is_exhausted());
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut backoff = ExponentialBackoff::new(
+            Duration::from_millis(100),
+            Duration::from_secs(1),
+            3.0,
+            Some(1),
+        )
+        .unwrap();
+        backoff.next_delay();
+
+        backoff.reset();
+
+        assert_eq!(backoff.attempts(), 0);
+        assert_eq!(backoff.next_delay(), Some(Duration::from_millis(100)));
+    }
+
+    #[rstest]
+    #[case(0.5, 100, 1_000)]
+    #[case(f64::NAN, 100, 1_000)]
+    #[case(2.0, 1_000, 100)]
+    fn test_new_invalid(#[case] factor: f64, #[case] initial_ms: u64, #[case] max_ms: u64) {
+        let result = ExponentialBackoff::new(
+            Duration::from_millis(initial_ms),
+            Duration::from_millis(max_ms),
+            factor,
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+}
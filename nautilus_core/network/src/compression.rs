@@ -0,0 +1,538 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Transparent decompression of WebSocket messages compressed with the permessage-deflate
+//! extension (RFC 7692).
+//!
+//! The stream sits beneath the WebSocket protocol layer: it watches the opening handshake
+//! response for the server accepting the extension, then rewrites each compressed message
+//! into a single uncompressed frame before it reaches the protocol layer. Outgoing messages
+//! are never compressed, which the extension permits.
+
+use std::{
+    io,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{ready, Context, Poll},
+};
+
+use flate2::{Decompress, FlushDecompress, Status};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+/// The extension offer sent with the opening handshake request.
+pub const PERMESSAGE_DEFLATE_OFFER: &str = "permessage-deflate; client_no_context_takeover";
+
+/// The maximum size of the opening handshake response header.
+const MAX_HANDSHAKE_LEN: usize = 16_384;
+/// The maximum size of a decompressed message.
+const MAX_MESSAGE_LEN: usize = 64 << 20;
+/// The size of the chunks read from the inner stream.
+const READ_CHUNK_LEN: usize = 8_192;
+/// The empty stored block removed from the end of each compressed message by the sender.
+const DEFLATE_TAIL: [u8; 4] = [0x00, 0x00, 0xFF, 0xFF];
+
+const FIN: u8 = 0x80;
+const RSV1: u8 = 0x40;
+const OPCODE_MASK: u8 = 0x0F;
+const OPCODE_CONTINUATION: u8 = 0x00;
+const CONTROL_BIT: u8 = 0x08;
+const MASK_BIT: u8 = 0x80;
+
+/// Counters for the compressed messages received on a connection.
+#[derive(Debug, Default)]
+pub struct CompressionMetrics {
+    messages: AtomicU64,
+    compressed_bytes: AtomicU64,
+    decompressed_bytes: AtomicU64,
+}
+
+impl CompressionMetrics {
+    /// Returns the count of compressed messages received.
+    #[must_use]
+    pub fn messages(&self) -> u64 {
+        self.messages.load(Ordering::Relaxed)
+    }
+
+    /// Returns the total compressed payload bytes received.
+    #[must_use]
+    pub fn compressed_bytes(&self) -> u64 {
+        self.compressed_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Returns the total payload bytes after decompression.
+    #[must_use]
+    pub fn decompressed_bytes(&self) -> u64 {
+        self.decompressed_bytes.load(Ordering::Relaxed)
+    }
+
+    fn record(&self, compressed_len: usize, decompressed_len: usize) {
+        self.messages.fetch_add(1, Ordering::Relaxed);
+        self.compressed_bytes
+            .fetch_add(compressed_len as u64, Ordering::Relaxed);
+        self.decompressed_bytes
+            .fetch_add(decompressed_len as u64, Ordering::Relaxed);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum State {
+    /// Bytes are passed through unchanged.
+    Passthrough,
+    /// Waiting for the end of the opening handshake response.
+    Handshake,
+    /// The extension was negotiated, compressed messages are decompressed.
+    Inflating,
+}
+
+#[derive(Debug)]
+struct CompressedMessage {
+    opcode: u8,
+    payload: Vec<u8>,
+}
+
+/// Wraps a client stream to decompress messages received with the permessage-deflate
+/// extension.
+///
+/// If `enabled` is false, or the server does not accept the extension, all bytes are passed
+/// through unchanged.
+pub struct PerMessageDeflateStream<S> {
+    inner: S,
+    state: State,
+    no_context_takeover: bool,
+    decompress: Decompress,
+    message: Option<CompressedMessage>,
+    read_buf: Vec<u8>,
+    ready: Vec<u8>,
+    ready_pos: usize,
+    metrics: Arc<CompressionMetrics>,
+}
+
+impl<S> PerMessageDeflateStream<S> {
+    /// Creates a new [`PerMessageDeflateStream`] instance.
+    #[must_use]
+    pub fn new(inner: S, enabled: bool, metrics: Arc<CompressionMetrics>) -> Self {
+        Self {
+            inner,
+            state: if enabled {
+                State::Handshake
+            } else {
+                State::Passthrough
+            },
+            no_context_takeover: false,
+            decompress: Decompress::new(false),
+            message: None,
+            read_buf: Vec::new(),
+            ready: Vec::new(),
+            ready_pos: 0,
+            metrics,
+        }
+    }
+
+    /// Returns whether the server accepted the extension.
+    #[must_use]
+    pub fn is_negotiated(&self) -> bool {
+        self.state == State::Inflating
+    }
+
+    fn process(&mut self) -> io::Result<()> {
+        loop {
+            match self.state {
+                State::Passthrough => {
+                    self.ready.append(&mut self.read_buf);
+                    return Ok(());
+                }
+                State::Handshake => {
+                    let Some(pos) = self
+                        .read_buf
+                        .windows(4)
+                        .position(|window| window == b"\r\n\r\n")
+                    else {
+                        if self.read_buf.len() > MAX_HANDSHAKE_LEN {
+                            return Err(invalid_data("Handshake response too large"));
+                        }
+                        return Ok(());
+                    };
+                    let header: Vec<u8> = self.read_buf.drain(..pos + 4).collect();
+                    match parse_extension_response(&header) {
+                        Some(no_context_takeover) => {
+                            self.no_context_takeover = no_context_takeover;
+                            self.state = State::Inflating;
+                        }
+                        None => self.state = State::Passthrough,
+                    }
+                    self.ready.extend_from_slice(&header);
+                }
+                State::Inflating => {
+                    while self.process_frame()? {}
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Processes the next frame in the read buffer, returning false if it is incomplete.
+    fn process_frame(&mut self) -> io::Result<bool> {
+        let buf = &self.read_buf;
+        if buf.len() < 2 {
+            return Ok(false);
+        }
+        let (b0, b1) = (buf[0], buf[1]);
+        let (payload_len, mut offset) = match b1 & 0x7F {
+            126 => {
+                if buf.len() < 4 {
+                    return Ok(false);
+                }
+                (u64::from(u16::from_be_bytes([buf[2], buf[3]])), 4)
+            }
+            127 => {
+                if buf.len() < 10 {
+                    return Ok(false);
+                }
+                let mut len = [0u8; 8];
+                len.copy_from_slice(&buf[2..10]);
+                (u64::from_be_bytes(len), 10)
+            }
+            len => (u64::from(len), 2),
+        };
+        let mask = if b1 & MASK_BIT == 0 {
+            None
+        } else {
+            if buf.len() < offset + 4 {
+                return Ok(false);
+            }
+            let mut mask = [0u8; 4];
+            mask.copy_from_slice(&buf[offset..offset + 4]);
+            offset += 4;
+            Some(mask)
+        };
+        let frame_len = usize::try_from(payload_len)
+            .ok()
+            .and_then(|len| len.checked_add(offset))
+            .ok_or_else(|| invalid_data("Frame too large"))?;
+        if buf.len() < frame_len {
+            return Ok(false);
+        }
+
+        let opcode = b0 & OPCODE_MASK;
+        let is_control = opcode & CONTROL_BIT != 0;
+        let is_compressed = b0 & RSV1 != 0;
+        let continues_message = opcode == OPCODE_CONTINUATION && self.message.is_some();
+        if is_control || !(is_compressed || continues_message) {
+            self.ready.extend(self.read_buf.drain(..frame_len));
+            return Ok(true);
+        }
+
+        let mut payload: Vec<u8> = self.read_buf.drain(..frame_len).skip(offset).collect();
+        if let Some(mask) = mask {
+            for (i, byte) in payload.iter_mut().enumerate() {
+                *byte ^= mask[i % 4];
+            }
+        }
+        let message = self.message.get_or_insert_with(|| CompressedMessage {
+            opcode,
+            payload: Vec::new(),
+        });
+        message.payload.extend_from_slice(&payload);
+        if message.payload.len() > MAX_MESSAGE_LEN {
+            return Err(invalid_data("Compressed message too large"));
+        }
+        if b0 & FIN == 0 {
+            return Ok(true); // Wait for the remaining fragments
+        }
+
+        let message = self.message.take().expect("Message should be present");
+        let data = self.inflate(message.payload)?;
+        write_frame(&mut self.ready, message.opcode, &data);
+        Ok(true)
+    }
+
+    fn inflate(&mut self, mut input: Vec<u8>) -> io::Result<Vec<u8>> {
+        let compressed_len = input.len();
+        input.extend_from_slice(&DEFLATE_TAIL);
+
+        let start_in = self.decompress.total_in();
+        let mut output = Vec::with_capacity(input.len().saturating_mul(4).max(1_024));
+        loop {
+            let total_in = self.decompress.total_in();
+            let total_out = self.decompress.total_out();
+            let consumed = (total_in - start_in) as usize;
+            let status = self
+                .decompress
+                .decompress_vec(&input[consumed..], &mut output, FlushDecompress::Sync)
+                .map_err(invalid_data)?;
+            let consumed = (self.decompress.total_in() - start_in) as usize;
+            let has_spare_capacity = output.len() < output.capacity();
+            if status == Status::StreamEnd || (consumed == input.len() && has_spare_capacity) {
+                break;
+            }
+            if output.len() > MAX_MESSAGE_LEN {
+                return Err(invalid_data("Decompressed message too large"));
+            }
+            if !has_spare_capacity {
+                output.reserve(output.capacity());
+            } else if self.decompress.total_in() == total_in
+                && self.decompress.total_out() == total_out
+            {
+                return Err(invalid_data("Invalid compressed message"));
+            }
+        }
+
+        if self.no_context_takeover {
+            self.decompress.reset(false);
+        }
+        self.metrics.record(compressed_len, output.len());
+        Ok(output)
+    }
+}
+
+impl<S: AsyncRead + Unpin> AsyncRead for PerMessageDeflateStream<S> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            if this.ready_pos < this.ready.len() {
+                let len = buf.remaining().min(this.ready.len() - this.ready_pos);
+                buf.put_slice(&this.ready[this.ready_pos..this.ready_pos + len]);
+                this.ready_pos += len;
+                if this.ready_pos == this.ready.len() {
+                    this.ready.clear();
+                    this.ready_pos = 0;
+                }
+                return Poll::Ready(Ok(()));
+            }
+
+            if this.state == State::Passthrough && this.read_buf.is_empty() {
+                return Pin::new(&mut this.inner).poll_read(cx, buf);
+            }
+
+            let mut chunk = [0u8; READ_CHUNK_LEN];
+            let mut chunk_buf = ReadBuf::new(&mut chunk);
+            ready!(Pin::new(&mut this.inner).poll_read(cx, &mut chunk_buf))?;
+            if chunk_buf.filled().is_empty() {
+                return Poll::Ready(Ok(())); // EOF
+            }
+            this.read_buf.extend_from_slice(chunk_buf.filled());
+            this.process()?;
+        }
+    }
+}
+
+impl<S: AsyncWrite + Unpin> AsyncWrite for PerMessageDeflateStream<S> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
+/// Returns whether `server_no_context_takeover` was agreed if the handshake response accepts
+/// the permessage-deflate extension, otherwise `None`.
+fn parse_extension_response(header: &[u8]) -> Option<bool> {
+    let text = String::from_utf8_lossy(header);
+    let mut lines = text.split("\r\n");
+    let status_line = lines.next()?;
+    if status_line.split_whitespace().nth(1) != Some("101") {
+        return None;
+    }
+
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        if !name.trim().eq_ignore_ascii_case("sec-websocket-extensions") {
+            continue;
+        }
+        for extension in value.split(',') {
+            let mut params = extension.split(';').map(str::trim);
+            if params.next().map_or(false, |name| {
+                name.eq_ignore_ascii_case("permessage-deflate")
+            }) {
+                return Some(
+                    params.any(|param| param.eq_ignore_ascii_case("server_no_context_takeover")),
+                );
+            }
+        }
+    }
+    None
+}
+
+fn write_frame(out: &mut Vec<u8>, opcode: u8, payload: &[u8]) {
+    out.push(FIN | opcode);
+    match payload.len() {
+        len if len < 126 => out.push(len as u8),
+        len if len <= usize::from(u16::MAX) => {
+            out.push(126);
+            out.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        len => {
+            out.push(127);
+            out.extend_from_slice(&(len as u64).to_be_bytes());
+        }
+    }
+    out.extend_from_slice(payload);
+}
+
+fn invalid_data<E>(error: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use flate2::{Compress, Compression, FlushCompress};
+    use rstest::rstest;
+    use tokio::io::AsyncReadExt;
+
+    use super::*;
+
+    const RESPONSE: &[u8] = b"HTTP/1.1 101 Switching Protocols\r\n\
+        Upgrade: websocket\r\n\
+        Sec-WebSocket-Extensions: permessage-deflate\r\n\r\n";
+    const RESPONSE_NO_EXTENSION: &[u8] =
+        b"HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\n\r\n";
+
+    /// Compresses `data` as a permessage-deflate payload, retaining the `compress` context.
+    fn deflate(compress: &mut Compress, data: &[u8]) -> Vec<u8> {
+        let mut output = Vec::with_capacity(data.len() + 64);
+        compress
+            .compress_vec(data, &mut output, FlushCompress::Sync)
+            .unwrap();
+        assert!(output.ends_with(&DEFLATE_TAIL));
+        output.truncate(output.len() - DEFLATE_TAIL.len());
+        output
+    }
+
+    fn frame(b0: u8, payload: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_frame(&mut out, 0, payload);
+        out[0] = b0;
+        out
+    }
+
+    async fn read_all(input: Vec<u8>, enabled: bool) -> (Vec<u8>, Arc<CompressionMetrics>) {
+        let metrics = Arc::new(CompressionMetrics::default());
+        let mut stream = PerMessageDeflateStream::new(input.as_slice(), enabled, metrics.clone());
+        let mut output = Vec::new();
+        stream.read_to_end(&mut output).await.unwrap();
+        (output, metrics)
+    }
+
+    #[tokio::test]
+    async fn test_decompresses_fragmented_message_with_interleaved_control_frame() {
+        let mut compress = Compress::new(Compression::default(), false);
+        let payload = deflate(&mut compress, b"hello hello hello compressed world");
+        let (first, second) = payload.split_at(payload.len() / 2);
+
+        let mut input = RESPONSE.to_vec();
+        input.extend(frame(RSV1 | 0x1, first));
+        input.extend(frame(FIN | 0x9, b"ping"));
+        input.extend(frame(FIN | OPCODE_CONTINUATION, second));
+        input.extend(frame(FIN | 0x1, b"plain"));
+
+        let (output, metrics) = read_all(input, true).await;
+
+        let mut expected = RESPONSE.to_vec();
+        expected.extend(frame(FIN | 0x9, b"ping"));
+        expected.extend(frame(FIN | 0x1, b"hello hello hello compressed world"));
+        expected.extend(frame(FIN | 0x1, b"plain"));
+        assert_eq!(output, expected);
+        assert_eq!(metrics.messages(), 1);
+        assert_eq!(metrics.compressed_bytes(), payload.len() as u64);
+        assert_eq!(metrics.decompressed_bytes(), 34);
+    }
+
+    #[tokio::test]
+    async fn test_decompresses_messages_with_context_takeover() {
+        let mut compress = Compress::new(Compression::default(), false);
+        let data = vec![b'x'; 70_000];
+        let first = deflate(&mut compress, &data);
+        let second = deflate(&mut compress, &data);
+
+        let mut input = RESPONSE.to_vec();
+        input.extend(frame(FIN | RSV1 | 0x2, &first));
+        input.extend(frame(FIN | RSV1 | 0x2, &second));
+
+        let (output, metrics) = read_all(input, true).await;
+
+        let mut expected = RESPONSE.to_vec();
+        expected.extend(frame(FIN | 0x2, &data));
+        expected.extend(frame(FIN | 0x2, &data));
+        assert_eq!(output, expected);
+        assert_eq!(metrics.messages(), 2);
+        assert_eq!(metrics.decompressed_bytes(), 140_000);
+    }
+
+    #[tokio::test]
+    async fn test_passthrough_when_server_declines_extension() {
+        let mut input = RESPONSE_NO_EXTENSION.to_vec();
+        input.extend(frame(FIN | 0x1, b"plain"));
+
+        let (output, metrics) = read_all(input.clone(), true).await;
+
+        assert_eq!(output, input);
+        assert_eq!(metrics.messages(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_passthrough_when_disabled() {
+        let mut input = RESPONSE.to_vec();
+        input.extend(frame(FIN | RSV1 | 0x1, b"not inflated"));
+
+        let (output, metrics) = read_all(input.clone(), false).await;
+
+        assert_eq!(output, input);
+        assert_eq!(metrics.messages(), 0);
+    }
+
+    #[rstest]
+    #[case(
+        "HTTP/1.1 101 Switching Protocols\r\nSec-WebSocket-Extensions: permessage-deflate\r\n\r\n",
+        Some(false)
+    )]
+    #[case("HTTP/1.1 101 Switching Protocols\r\nsec-websocket-extensions: permessage-deflate; server_no_context_takeover\r\n\r\n", Some(true))]
+    #[case("HTTP/1.1 101 Switching Protocols\r\nSec-WebSocket-Extensions: x-custom, permessage-deflate\r\n\r\n", Some(false))]
+    #[case(
+        "HTTP/1.1 101 Switching Protocols\r\nSec-WebSocket-Extensions: x-custom\r\n\r\n",
+        None
+    )]
+    #[case(
+        "HTTP/1.1 400 Bad Request\r\nSec-WebSocket-Extensions: permessage-deflate\r\n\r\n",
+        None
+    )]
+    fn test_parse_extension_response(#[case] header: &str, #[case] expected: Option<bool>) {
+        assert_eq!(parse_extension_response(header.as_bytes()), expected);
+    }
+}
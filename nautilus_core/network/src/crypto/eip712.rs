@@ -0,0 +1,271 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! EIP-712 structured data hashing.
+//!
+//! Venue order types implement [`Eip712Struct`] to describe their encoded type and fields,
+//! and the resulting signing hash is passed to a [`PayloadSigner`].
+
+use super::{keccak256, PayloadSigner};
+
+/// Represents a struct which can be hashed according to EIP-712.
+pub trait Eip712Struct {
+    /// The encoded type, e.g. `Mail(Person from,string contents)Person(string name)`, with any
+    /// referenced struct types appended in alphabetical order.
+    const ENCODED_TYPE: &'static str;
+
+    /// Returns the encoded values of the struct fields, in the order of the encoded type.
+    fn encode_data(&self) -> anyhow::Result<Vec<[u8; 32]>>;
+
+    /// Returns the hash of the encoded type.
+    #[must_use]
+    fn type_hash() -> [u8; 32] {
+        keccak256(Self::ENCODED_TYPE.as_bytes())
+    }
+
+    /// Returns the hash of the struct (`hashStruct` in the specification).
+    fn hash_struct(&self) -> anyhow::Result<[u8; 32]> {
+        let fields = self.encode_data()?;
+        let mut buf = Vec::with_capacity(32 * (fields.len() + 1));
+        buf.extend_from_slice(&Self::type_hash());
+        for field in fields {
+            buf.extend_from_slice(&field);
+        }
+        Ok(keccak256(&buf))
+    }
+}
+
+/// Represents an EIP-712 signing domain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Eip712Domain {
+    pub name: String,
+    pub version: String,
+    pub chain_id: u64,
+    pub verifying_contract: String,
+}
+
+impl Eip712Struct for Eip712Domain {
+    const ENCODED_TYPE: &'static str =
+        "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+
+    fn encode_data(&self) -> anyhow::Result<Vec<[u8; 32]>> {
+        Ok(vec![
+            encode_string(&self.name),
+            encode_string(&self.version),
+            encode_uint(u128::from(self.chain_id)),
+            encode_address(&self.verifying_contract)?,
+        ])
+    }
+}
+
+/// Returns the encoded value for a `uint` field.
+#[must_use]
+pub fn encode_uint(value: u128) -> [u8; 32] {
+    let mut buf = [0; 32];
+    buf[16..].copy_from_slice(&value.to_be_bytes());
+    buf
+}
+
+/// Returns the encoded value for an `int` field, sign extended to 256 bits.
+#[must_use]
+pub fn encode_int(value: i128) -> [u8; 32] {
+    let mut buf = if value < 0 { [0xFF; 32] } else { [0; 32] };
+    buf[16..].copy_from_slice(&value.to_be_bytes());
+    buf
+}
+
+/// Returns the encoded value for a `bool` field.
+#[must_use]
+pub fn encode_bool(value: bool) -> [u8; 32] {
+    encode_uint(u128::from(value))
+}
+
+/// Returns the encoded value for an `address` field from its hex string.
+///
+/// # Errors
+///
+/// This function returns an error if `address` is not 20 hex encoded bytes (with an optional
+/// `0x` prefix).
+pub fn encode_address(address: &str) -> anyhow::Result<[u8; 32]> {
+    let digits = address.strip_prefix("0x").unwrap_or(address);
+    let bytes =
+        hex::decode(digits).map_err(|e| anyhow::anyhow!("Invalid address {address}: {e}"))?;
+    if bytes.len() != 20 {
+        anyhow::bail!(
+            "Invalid address {address}, expected 20 bytes, was {}",
+            bytes.len()
+        );
+    }
+    let mut buf = [0; 32];
+    buf[12..].copy_from_slice(&bytes);
+    Ok(buf)
+}
+
+/// Returns the encoded value for a `string` field.
+#[must_use]
+pub fn encode_string(value: &str) -> [u8; 32] {
+    keccak256(value.as_bytes())
+}
+
+/// Returns the encoded value for a dynamic `bytes` field.
+#[must_use]
+pub fn encode_bytes(value: &[u8]) -> [u8; 32] {
+    keccak256(value)
+}
+
+/// Returns the EIP-712 signing hash for the given `message` in the given `domain`.
+pub fn signing_hash<T: Eip712Struct>(
+    domain: &Eip712Domain,
+    message: &T,
+) -> anyhow::Result<[u8; 32]> {
+    let mut buf = Vec::with_capacity(66);
+    buf.extend_from_slice(&[0x19, 0x01]);
+    buf.extend_from_slice(&domain.hash_struct()?);
+    buf.extend_from_slice(&message.hash_struct()?);
+    Ok(keccak256(&buf))
+}
+
+/// Returns the signature from the given `signer` for the given `message` in the given `domain`.
+pub fn sign_typed_data<T: Eip712Struct>(
+    signer: &impl PayloadSigner,
+    domain: &Eip712Domain,
+    message: &T,
+) -> anyhow::Result<Vec<u8>> {
+    signer.sign_hash(&signing_hash(domain, message)?)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    // The `Mail` example from the EIP-712 specification
+    struct Person {
+        name: String,
+        wallet: String,
+    }
+
+    impl Eip712Struct for Person {
+        const ENCODED_TYPE: &'static str = "Person(string name,address wallet)";
+
+        fn encode_data(&self) -> anyhow::Result<Vec<[u8; 32]>> {
+            Ok(vec![
+                encode_string(&self.name),
+                encode_address(&self.wallet)?,
+            ])
+        }
+    }
+
+    struct Mail {
+        from: Person,
+        to: Person,
+        contents: String,
+    }
+
+    impl Eip712Struct for Mail {
+        const ENCODED_TYPE: &'static str =
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)";
+
+        fn encode_data(&self) -> anyhow::Result<Vec<[u8; 32]>> {
+            Ok(vec![
+                self.from.hash_struct()?,
+                self.to.hash_struct()?,
+                encode_string(&self.contents),
+            ])
+        }
+    }
+
+    struct EchoSigner;
+
+    impl PayloadSigner for EchoSigner {
+        fn sign_hash(&self, hash: &[u8; 32]) -> anyhow::Result<Vec<u8>> {
+            Ok(hash.to_vec())
+        }
+    }
+
+    fn domain() -> Eip712Domain {
+        Eip712Domain {
+            name: "Ether Mail".to_string(),
+            version: "1".to_string(),
+            chain_id: 1,
+            verifying_contract: "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC".to_string(),
+        }
+    }
+
+    fn mail() -> Mail {
+        Mail {
+            from: Person {
+                name: "Cow".to_string(),
+                wallet: "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826".to_string(),
+            },
+            to: Person {
+                name: "Bob".to_string(),
+                wallet: "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB".to_string(),
+            },
+            contents: "Hello, Bob!".to_string(),
+        }
+    }
+
+    #[rstest]
+    fn test_type_hash() {
+        assert_eq!(
+            hex::encode(Mail::type_hash()),
+            "a0cedeb2dc280ba39b857546d74f5549c3a1d7bdc2dd96bf881f76108e23dac2"
+        );
+    }
+
+    #[rstest]
+    fn test_domain_separator() {
+        assert_eq!(
+            hex::encode(domain().hash_struct().unwrap()),
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+    }
+
+    #[rstest]
+    fn test_hash_struct() {
+        assert_eq!(
+            hex::encode(mail().hash_struct().unwrap()),
+            "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+        );
+    }
+
+    #[rstest]
+    fn test_sign_typed_data() {
+        let signature = sign_typed_data(&EchoSigner, &domain(), &mail()).unwrap();
+
+        assert_eq!(
+            hex::encode(signature),
+            "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+        );
+    }
+
+    #[rstest]
+    fn test_encode_int_negative() {
+        assert_eq!(encode_int(-1), [0xFF; 32]);
+        assert_eq!(encode_int(1), encode_uint(1));
+    }
+
+    #[rstest]
+    #[case("0x1234")]
+    #[case("0xZZ2a3d9F938E13CD947Ec05AbC7FE734Df8DD826")]
+    fn test_encode_address_invalid(#[case] address: &str) {
+        assert!(encode_address(address).is_err());
+    }
+}
@@ -0,0 +1,62 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Cryptographic primitives for signing venue order payloads.
+//!
+//! Hashing is implemented here, while the signature scheme is provided by each adapter through
+//! the [`PayloadSigner`] trait, so key management stays with the adapter.
+
+pub mod eip712;
+
+use tiny_keccak::{Hasher, Keccak};
+
+/// Returns the Keccak-256 hash of the given `data`.
+#[must_use]
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    hasher.update(data);
+    let mut output = [0; 32];
+    hasher.finalize(&mut output);
+    output
+}
+
+/// Provides signatures over 32-byte payload hashes.
+pub trait PayloadSigner {
+    /// Returns the signature for the given `hash`.
+    fn sign_hash(&self, hash: &[u8; 32]) -> anyhow::Result<Vec<u8>>;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(
+        b"",
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    )]
+    #[case(
+        b"hello",
+        "1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8"
+    )]
+    fn test_keccak256(#[case] data: &[u8], #[case] expected: &str) {
+        assert_eq!(hex::encode(keccak256(data)), expected);
+    }
+}
@@ -0,0 +1,185 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! High-precision decimal conversions for venues which use scaled integer or string amounts.
+//!
+//! On-chain venues typically represent amounts as integers scaled by a fixed count of
+//! decimals (e.g. 18 for most ERC-20 tokens), while their APIs accept canonical decimal
+//! strings. These conversions are exact and return an error rather than silently rounding.
+
+use rust_decimal::Decimal;
+
+fn split_decimal(value: Decimal, decimals: u32) -> anyhow::Result<(i128, u32)> {
+    let normalized = value.normalize();
+    let scale = normalized.scale();
+    if scale > decimals {
+        anyhow::bail!("Value {value} exceeds the maximum decimals {decimals}");
+    }
+    Ok((normalized.mantissa(), decimals - scale))
+}
+
+/// Returns the given `value` as a signed integer scaled by `10^decimals`.
+///
+/// # Errors
+///
+/// This function returns an error if `value` has more than `decimals` decimal places, or if
+/// the scaled value overflows.
+pub fn decimal_to_scaled_i128(value: Decimal, decimals: u32) -> anyhow::Result<i128> {
+    let (mantissa, exponent) = split_decimal(value, decimals)?;
+    10_i128
+        .checked_pow(exponent)
+        .and_then(|scalar| mantissa.checked_mul(scalar))
+        .ok_or_else(|| anyhow::anyhow!("Value {value} overflows with {decimals} decimals"))
+}
+
+/// Returns the given `value` as an unsigned integer scaled by `10^decimals`.
+///
+/// # Errors
+///
+/// This function returns an error if `value` is negative, has more than `decimals` decimal
+/// places, or if the scaled value overflows.
+pub fn decimal_to_scaled_u128(value: Decimal, decimals: u32) -> anyhow::Result<u128> {
+    let scaled = decimal_to_scaled_i128(value, decimals)?;
+    u128::try_from(scaled).map_err(|_| anyhow::anyhow!("Value {value} was negative"))
+}
+
+/// Returns a [`Decimal`] from the given integer `value` scaled by `10^decimals`.
+///
+/// # Errors
+///
+/// This function returns an error if the value cannot be represented as a [`Decimal`].
+pub fn scaled_to_decimal(value: i128, decimals: u32) -> anyhow::Result<Decimal> {
+    Decimal::try_from_i128_with_scale(value, decimals)
+        .map(|d| d.normalize())
+        .map_err(|e| anyhow::anyhow!("Invalid scaled value {value} with {decimals} decimals: {e}"))
+}
+
+/// Returns the given `value` rounded to the given count of significant `figures`.
+///
+/// # Errors
+///
+/// This function returns an error if `figures` is zero or the rounded value overflows.
+pub fn round_to_significant_figures(value: Decimal, figures: u32) -> anyhow::Result<Decimal> {
+    if figures == 0 {
+        anyhow::bail!("Invalid significant figures {figures}");
+    }
+    value
+        .round_sf(figures)
+        .map(|d| d.normalize())
+        .ok_or_else(|| {
+            anyhow::anyhow!("Value {value} overflows with {figures} significant figures")
+        })
+}
+
+/// Returns the canonical string for the given `value` as expected by venue APIs, with no
+/// trailing zeros and at most `max_decimals` decimal places.
+///
+/// # Errors
+///
+/// This function returns an error if `value` has more than `max_decimals` decimal places.
+pub fn format_decimal_wire(value: Decimal, max_decimals: u32) -> anyhow::Result<String> {
+    if value.round_dp(max_decimals) != value {
+        anyhow::bail!("Value {value} exceeds the maximum decimals {max_decimals}");
+    }
+    let normalized = value.normalize();
+    if normalized.is_zero() {
+        return Ok("0".to_string());
+    }
+    Ok(normalized.to_string())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("1.5", 6, 1_500_000)]
+    #[case("0.000001", 6, 1)]
+    #[case("-2.25", 2, -225)]
+    #[case("100", 0, 100)]
+    #[case("1.100", 1, 11)]
+    #[case("123456789.123456789", 18, 123_456_789_123_456_789_000_000_000)]
+    fn test_decimal_to_scaled_i128(
+        #[case] value: &str,
+        #[case] decimals: u32,
+        #[case] expected: i128,
+    ) {
+        let value = Decimal::from_str(value).unwrap();
+        assert_eq!(decimal_to_scaled_i128(value, decimals).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("0.0000001", 6)]
+    #[case("1.5", 0)]
+    fn test_decimal_to_scaled_excess_precision(#[case] value: &str, #[case] decimals: u32) {
+        let value = Decimal::from_str(value).unwrap();
+        assert!(decimal_to_scaled_i128(value, decimals).is_err());
+    }
+
+    #[rstest]
+    fn test_decimal_to_scaled_u128_negative() {
+        assert!(decimal_to_scaled_u128(Decimal::from_str("-1").unwrap(), 6).is_err());
+    }
+
+    #[rstest]
+    #[case(1_500_000, 6, "1.5")]
+    #[case(-225, 2, "-2.25")]
+    #[case(0, 18, "0")]
+    fn test_scaled_to_decimal(#[case] value: i128, #[case] decimals: u32, #[case] expected: &str) {
+        let result = scaled_to_decimal(value, decimals).unwrap();
+        assert_eq!(result, Decimal::from_str(expected).unwrap());
+    }
+
+    #[rstest]
+    #[case("12345.678", 5, "12346")]
+    #[case("0.000123456", 5, "0.00012346")]
+    #[case("1.2", 5, "1.2")]
+    fn test_round_to_significant_figures(
+        #[case] value: &str,
+        #[case] figures: u32,
+        #[case] expected: &str,
+    ) {
+        let value = Decimal::from_str(value).unwrap();
+        let result = round_to_significant_figures(value, figures).unwrap();
+        assert_eq!(result.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("1.50000000", 8, "1.5")]
+    #[case("100.0", 8, "100")]
+    #[case("0.000", 8, "0")]
+    #[case("-0.25", 2, "-0.25")]
+    fn test_format_decimal_wire(
+        #[case] value: &str,
+        #[case] max_decimals: u32,
+        #[case] expected: &str,
+    ) {
+        let value = Decimal::from_str(value).unwrap();
+        assert_eq!(format_decimal_wire(value, max_decimals).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_format_decimal_wire_excess_precision() {
+        let value = Decimal::from_str("0.123456789").unwrap();
+        assert!(format_decimal_wire(value, 8).is_err());
+    }
+}
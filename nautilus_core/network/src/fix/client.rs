@@ -0,0 +1,381 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An async FIX initiator client, running a [`FixSession`] over a [`SocketClient`].
+
+use std::{sync::Arc, time::Duration};
+
+use nautilus_core::time::get_atomic_clock_realtime;
+use tokio::{
+    sync::{mpsc, Mutex},
+    task,
+};
+use tokio_tungstenite::tungstenite::stream::Mode;
+use tracing::{debug, error};
+
+use super::{
+    message::{FixFramer, FixMessage, SOH},
+    messages::FixApplicationMessage,
+    session::{FixSession, FixSessionConfig, SessionAction, SessionStatus},
+    store::SequenceStore,
+};
+use crate::{
+    proxy::ProxyConfig,
+    socket::{MessageHandler, SocketClient, SocketConfig},
+};
+
+type SharedSession = Arc<Mutex<FixSession<Box<dyn SequenceStore>>>>;
+
+/// An event emitted by a [`FixClient`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FixEvent {
+    /// The session is logged on.
+    LoggedOn,
+    /// An application message (or session `Reject(3)`) was received.
+    Message(FixMessage),
+    /// The session is logged out.
+    LoggedOut,
+    /// The connection was dropped, for the given reason.
+    Disconnected(String),
+}
+
+/// Provides a FIX initiator client.
+///
+/// The client connects with a [`SocketClient`] using the FIX field delimiter as the message
+/// suffix, so each frame received is a single field which is reassembled into messages
+/// by a [`FixFramer`]. A session task handles the received messages and a one second timer
+/// for heartbeats, emitting [`FixEvent`]s to the receiver returned on connection.
+///
+/// The socket does not reconnect, as a new connection requires a new logon. To resume a
+/// session after a [`FixEvent::Disconnected`], connect a new client with the same store.
+pub struct FixClient {
+    session: SharedSession,
+    socket: Arc<SocketClient>,
+    session_task: task::JoinHandle<()>,
+}
+
+impl FixClient {
+    /// Connects to the counterparty at the given `url` and sends the logon.
+    ///
+    /// Returns the client and the receiver for its events, which first receives
+    /// [`FixEvent::LoggedOn`] once the counterparty accepts the logon.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the connection fails, or the logon cannot be sent.
+    pub async fn connect(
+        url: &str,
+        mode: Mode,
+        config: FixSessionConfig,
+        store: Box<dyn SequenceStore>,
+        proxy: Option<ProxyConfig>,
+    ) -> anyhow::Result<(Self, mpsc::UnboundedReceiver<FixEvent>)> {
+        let (handler, fields) = MessageHandler::channel();
+        let mut socket_config = SocketConfig::new(url, mode, vec![SOH], handler, None)
+            .with_reconnect_backoff(None, None, None, Some(0));
+        if let Some(proxy) = proxy {
+            socket_config = socket_config.with_proxy(proxy);
+        }
+        let socket = Arc::new(SocketClient::connect(socket_config, None, None, None).await?);
+
+        let mut session = FixSession::new(config, store);
+        let logon = session.logon(get_atomic_clock_realtime().get_time_ns())?;
+        send_frame(&socket, &logon).await?;
+
+        let session = Arc::new(Mutex::new(session));
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let session_task =
+            Self::spawn_session_task(session.clone(), socket.clone(), fields, event_tx);
+
+        Ok((
+            Self {
+                session,
+                socket,
+                session_task,
+            },
+            event_rx,
+        ))
+    }
+
+    /// Returns the session status.
+    pub async fn status(&self) -> SessionStatus {
+        self.session.lock().await.status()
+    }
+
+    /// Sends the given application message.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the session is not logged on, or the message
+    /// cannot be sent.
+    pub async fn send(&self, message: FixMessage) -> anyhow::Result<()> {
+        // Hold the session lock while writing, so messages are sent in sequence order
+        let mut session = self.session.lock().await;
+        let encoded = session.send_app(message, get_atomic_clock_realtime().get_time_ns())?;
+        send_frame(&self.socket, &encoded).await
+    }
+
+    /// Sends the given typed application message.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the session is not logged on, or the message
+    /// cannot be sent.
+    pub async fn send_typed<M: FixApplicationMessage>(&self, message: &M) -> anyhow::Result<()> {
+        self.send(message.to_message()).await
+    }
+
+    /// Sends a logout, the connection is dropped once the counterparty confirms.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the session is not logged on, or the logout
+    /// cannot be sent.
+    pub async fn logout(&self, text: Option<&str>) -> anyhow::Result<()> {
+        let mut session = self.session.lock().await;
+        let encoded = session.logout(text, get_atomic_clock_realtime().get_time_ns())?;
+        send_frame(&self.socket, &encoded).await
+    }
+
+    /// Drops the connection without logging out, and stops the session task.
+    pub async fn disconnect(&self) {
+        self.session_task.abort();
+        self.session.lock().await.on_disconnected();
+        self.socket.disconnect().await;
+    }
+
+    fn spawn_session_task(
+        session: SharedSession,
+        socket: Arc<SocketClient>,
+        mut fields: mpsc::UnboundedReceiver<Vec<u8>>,
+        events: mpsc::UnboundedSender<FixEvent>,
+    ) -> task::JoinHandle<()> {
+        task::spawn(async move {
+            let mut framer = FixFramer::default();
+            let mut timer = tokio::time::interval(Duration::from_secs(1));
+
+            loop {
+                let received = tokio::select! {
+                    field = fields.recv() => match field {
+                        Some(field) => match framer.push_field(&field) {
+                            Some(data) => Some(data),
+                            None => continue,
+                        },
+                        None => break,
+                    },
+                    _ = timer.tick() => None,
+                };
+
+                let mut session = session.lock().await;
+                let now = get_atomic_clock_realtime().get_time_ns();
+                let result = match received {
+                    Some(data) => session.on_message(&data, now),
+                    None if socket.is_disconnected() => {
+                        session.on_disconnected();
+                        let reason = "Connection lost".to_string();
+                        let _ = events.send(FixEvent::Disconnected(reason));
+                        break;
+                    }
+                    None => session.on_timer(now),
+                };
+                let actions = match result {
+                    Ok(actions) => actions,
+                    Err(e) => {
+                        error!("Error handling session: {e}");
+                        continue;
+                    }
+                };
+
+                for action in actions {
+                    match action {
+                        SessionAction::Send(data) => {
+                            if let Err(e) = send_frame(&socket, &data).await {
+                                error!("Failed to send message: {e}");
+                            }
+                        }
+                        SessionAction::Deliver(message) => {
+                            let _ = events.send(FixEvent::Message(message));
+                        }
+                        SessionAction::LoggedOn => {
+                            debug!("Logged on");
+                            let _ = events.send(FixEvent::LoggedOn);
+                        }
+                        SessionAction::LoggedOut => {
+                            debug!("Logged out");
+                            let _ = events.send(FixEvent::LoggedOut);
+                        }
+                        SessionAction::Disconnect(reason) => {
+                            debug!("Disconnecting: {reason}");
+                            socket.disconnect().await;
+                            let _ = events.send(FixEvent::Disconnected(reason));
+                            return;
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl Drop for FixClient {
+    fn drop(&mut self) {
+        self.session_task.abort();
+    }
+}
+
+/// Sends the encoded message, without its final delimiter as the socket appends it.
+async fn send_frame(socket: &SocketClient, data: &[u8]) -> anyhow::Result<()> {
+    let frame = data.strip_suffix(&[SOH]).unwrap_or(data);
+    socket.send_bytes(frame).await?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+        time::timeout,
+    };
+
+    use super::*;
+    use crate::fix::{
+        message::{msg_type, TAG_MSG_SEQ_NUM, TAG_SENDER_COMP_ID, TAG_TARGET_COMP_ID},
+        messages::{
+            ExecType, ExecutionReport, NewOrderSingle, OrdStatus, OrdType, Side, TAG_CL_ORD_ID,
+        },
+        session::FixVersion,
+        store::MemorySequenceStore,
+    };
+
+    /// Spawns an acceptor which confirms logon and logout, and acknowledges orders.
+    async fn spawn_acceptor() -> (u16, task::JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let handle = task::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut framer = FixFramer::default();
+            let mut seq_num = 0;
+            let mut buf = vec![0; 4096];
+            loop {
+                let n = stream.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    return;
+                }
+                for data in framer.push_bytes(&buf[..n]) {
+                    let (_, received) = FixMessage::decode(&data).unwrap();
+                    let reply = match received.msg_type() {
+                        msg_type::LOGON | msg_type::LOGOUT => FixMessage::new(received.msg_type()),
+                        msg_type::NEW_ORDER_SINGLE => {
+                            let order = NewOrderSingle::from_message(&received).unwrap();
+                            ExecutionReport {
+                                order_id: "V-1".to_string(),
+                                cl_ord_id: Some(order.cl_ord_id),
+                                orig_cl_ord_id: None,
+                                exec_id: "E-1".to_string(),
+                                exec_type: ExecType::New,
+                                ord_status: OrdStatus::New,
+                                symbol: order.symbol,
+                                side: order.side,
+                                order_qty: Some(order.order_qty),
+                                price: order.price,
+                                last_qty: None,
+                                last_px: None,
+                                leaves_qty: order.order_qty,
+                                cum_qty: dec!(0),
+                                avg_px: None,
+                                text: None,
+                                transact_time: None,
+                            }
+                            .to_message()
+                        }
+                        _ => continue,
+                    };
+                    seq_num += 1;
+                    let reply = reply
+                        .with_field(TAG_SENDER_COMP_ID, "VENUE")
+                        .with_field(TAG_TARGET_COMP_ID, "CLIENT")
+                        .with_field(TAG_MSG_SEQ_NUM, seq_num)
+                        .with_field(52, "20240101-00:00:00.000");
+                    stream.write_all(&reply.encode("FIX.4.4")).await.unwrap();
+                }
+            }
+        });
+
+        (port, handle)
+    }
+
+    async fn next_event(events: &mut mpsc::UnboundedReceiver<FixEvent>) -> FixEvent {
+        timeout(Duration::from_secs(5), events.recv())
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_client_session_round_trip() {
+        let (port, _acceptor) = spawn_acceptor().await;
+        let config = FixSessionConfig::new(FixVersion::Fix44, "CLIENT", "VENUE", 30);
+
+        let (client, mut events) = FixClient::connect(
+            &format!("127.0.0.1:{port}"),
+            Mode::Plain,
+            config,
+            Box::new(MemorySequenceStore::default()),
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(next_event(&mut events).await, FixEvent::LoggedOn);
+        assert_eq!(client.status().await, SessionStatus::Active);
+
+        let order = NewOrderSingle {
+            cl_ord_id: "O-1".to_string(),
+            symbol: "AUD/USD".to_string(),
+            side: Side::Buy,
+            order_qty: dec!(1000),
+            ord_type: OrdType::Limit,
+            price: Some(dec!(0.65)),
+            stop_px: None,
+            time_in_force: None,
+            expire_time: None,
+            account: None,
+            transact_time: get_atomic_clock_realtime().get_time_ns(),
+        };
+        client.send_typed(&order).await.unwrap();
+
+        let FixEvent::Message(message) = next_event(&mut events).await else {
+            panic!("Expected execution report");
+        };
+        let report = ExecutionReport::from_message(&message).unwrap();
+        assert_eq!(report.cl_ord_id.as_deref(), Some("O-1"));
+        assert_eq!(message.get(TAG_CL_ORD_ID), Some("O-1"));
+        assert_eq!(report.ord_status, OrdStatus::New);
+
+        client.logout(None).await.unwrap();
+        assert_eq!(next_event(&mut events).await, FixEvent::LoggedOut);
+        assert_eq!(
+            next_event(&mut events).await,
+            FixEvent::Disconnected("Logged out".to_string())
+        );
+        assert_eq!(client.status().await, SessionStatus::Disconnected);
+    }
+}
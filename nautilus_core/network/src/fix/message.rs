@@ -0,0 +1,536 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FIX tag-value message encoding, decoding and stream framing.
+
+use std::{
+    fmt::Display,
+    str::FromStr,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use nautilus_core::nanos::UnixNanos;
+
+/// The FIX field delimiter (Start of Header).
+pub const SOH: u8 = 0x01;
+
+pub const TAG_BEGIN_STRING: u32 = 8;
+pub const TAG_BODY_LENGTH: u32 = 9;
+pub const TAG_CHECKSUM: u32 = 10;
+pub const TAG_MSG_SEQ_NUM: u32 = 34;
+pub const TAG_MSG_TYPE: u32 = 35;
+pub const TAG_NEW_SEQ_NO: u32 = 36;
+pub const TAG_POSS_DUP_FLAG: u32 = 43;
+pub const TAG_REF_SEQ_NUM: u32 = 45;
+pub const TAG_SENDER_COMP_ID: u32 = 49;
+pub const TAG_SENDING_TIME: u32 = 52;
+pub const TAG_TARGET_COMP_ID: u32 = 56;
+pub const TAG_TEXT: u32 = 58;
+pub const TAG_BEGIN_SEQ_NO: u32 = 7;
+pub const TAG_END_SEQ_NO: u32 = 16;
+pub const TAG_ENCRYPT_METHOD: u32 = 98;
+pub const TAG_HEART_BT_INT: u32 = 108;
+pub const TAG_TEST_REQ_ID: u32 = 112;
+pub const TAG_ORIG_SENDING_TIME: u32 = 122;
+pub const TAG_GAP_FILL_FLAG: u32 = 123;
+pub const TAG_RESET_SEQ_NUM_FLAG: u32 = 141;
+pub const TAG_USERNAME: u32 = 553;
+pub const TAG_PASSWORD: u32 = 554;
+pub const TAG_DEFAULT_APPL_VER_ID: u32 = 1137;
+
+/// The standard header fields, in the order they are encoded after `MsgType(35)`.
+const HEADER_TAGS: [u32; 7] = [
+    TAG_SENDER_COMP_ID,
+    TAG_TARGET_COMP_ID,
+    TAG_MSG_SEQ_NUM,
+    TAG_POSS_DUP_FLAG,
+    TAG_SENDING_TIME,
+    TAG_ORIG_SENDING_TIME,
+    TAG_DEFAULT_APPL_VER_ID,
+];
+
+const SENDING_TIME_FORMAT: &str = "%Y%m%d-%H:%M:%S%.3f";
+
+/// Session level message types.
+pub mod msg_type {
+    pub const HEARTBEAT: &str = "0";
+    pub const TEST_REQUEST: &str = "1";
+    pub const RESEND_REQUEST: &str = "2";
+    pub const REJECT: &str = "3";
+    pub const SEQUENCE_RESET: &str = "4";
+    pub const LOGOUT: &str = "5";
+    pub const LOGON: &str = "A";
+    pub const EXECUTION_REPORT: &str = "8";
+    pub const ORDER_CANCEL_REJECT: &str = "9";
+    pub const NEW_ORDER_SINGLE: &str = "D";
+    pub const ORDER_CANCEL_REQUEST: &str = "F";
+    pub const ORDER_CANCEL_REPLACE_REQUEST: &str = "G";
+
+    /// Returns whether the given message type is a session level (admin) message.
+    #[must_use]
+    pub fn is_admin(msg_type: &str) -> bool {
+        matches!(msg_type, "0" | "1" | "2" | "3" | "4" | "5" | "A")
+    }
+}
+
+/// Represents a FIX message as an ordered list of tag-value fields.
+///
+/// The `BeginString(8)`, `BodyLength(9)` and `CheckSum(10)` fields are computed on
+/// encoding, so are not held in the message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixMessage {
+    msg_type: String,
+    fields: Vec<(u32, String)>,
+}
+
+impl FixMessage {
+    /// Creates a new empty [`FixMessage`] instance of the given message type.
+    #[must_use]
+    pub fn new(msg_type: &str) -> Self {
+        Self {
+            msg_type: msg_type.to_string(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Returns the `MsgType(35)` of the message.
+    #[must_use]
+    pub fn msg_type(&self) -> &str {
+        &self.msg_type
+    }
+
+    /// Returns whether the message is a session level (admin) message.
+    #[must_use]
+    pub fn is_admin(&self) -> bool {
+        msg_type::is_admin(&self.msg_type)
+    }
+
+    /// Returns the fields of the message (excluding `MsgType(35)`).
+    #[must_use]
+    pub fn fields(&self) -> &[(u32, String)] {
+        &self.fields
+    }
+
+    /// Returns the message with the given field set.
+    #[must_use]
+    pub fn with_field<T: Display>(mut self, tag: u32, value: T) -> Self {
+        self.set_field(tag, value);
+        self
+    }
+
+    /// Sets the field with the given `tag`, replacing the first existing value (if any).
+    pub fn set_field<T: Display>(&mut self, tag: u32, value: T) {
+        let value = value.to_string();
+        match self.fields.iter_mut().find(|(t, _)| *t == tag) {
+            Some(field) => field.1 = value,
+            None => self.fields.push((tag, value)),
+        }
+    }
+
+    /// Appends a field with the given `tag`, even if one already exists (e.g. in a
+    /// repeating group).
+    pub fn push_field<T: Display>(&mut self, tag: u32, value: T) {
+        self.fields.push((tag, value.to_string()));
+    }
+
+    /// Removes all fields with the given `tag`.
+    pub fn remove_field(&mut self, tag: u32) {
+        self.fields.retain(|(t, _)| *t != tag);
+    }
+
+    /// Returns the value of the first field with the given `tag`.
+    #[must_use]
+    pub fn get(&self, tag: u32) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(t, _)| *t == tag)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the value of the required field with the given `tag`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the field is missing.
+    pub fn get_required(&self, tag: u32) -> anyhow::Result<&str> {
+        self.get(tag).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Missing required tag {tag} in message type {}",
+                self.msg_type
+            )
+        })
+    }
+
+    /// Returns the parsed value of the required field with the given `tag`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the field is missing or cannot be parsed.
+    pub fn get_parsed<T>(&self, tag: u32) -> anyhow::Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = self.get_required(tag)?;
+        value
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid value '{value}' for tag {tag}: {e}"))
+    }
+
+    /// Returns the parsed value of the optional field with the given `tag`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the field is present but cannot be parsed.
+    pub fn get_parsed_opt<T>(&self, tag: u32) -> anyhow::Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.get(tag) {
+            Some(_) => self.get_parsed(tag).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns whether the boolean field with the given `tag` is set to 'Y'.
+    #[must_use]
+    pub fn get_flag(&self, tag: u32) -> bool {
+        self.get(tag) == Some("Y")
+    }
+
+    /// Returns the `MsgSeqNum(34)` of the message.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the field is missing or invalid.
+    pub fn seq_num(&self) -> anyhow::Result<u64> {
+        self.get_parsed(TAG_MSG_SEQ_NUM)
+    }
+
+    /// Encodes the message with the given `begin_string`, computing the body length
+    /// and checksum.
+    ///
+    /// The standard header fields are encoded first (after `MsgType(35)`), followed by
+    /// the remaining fields in insertion order.
+    #[must_use]
+    pub fn encode(&self, begin_string: &str) -> Vec<u8> {
+        let mut body = Vec::with_capacity(128);
+        write_field(&mut body, TAG_MSG_TYPE, &self.msg_type);
+        for tag in HEADER_TAGS {
+            if let Some(value) = self.get(tag) {
+                write_field(&mut body, tag, value);
+            }
+        }
+        for (tag, value) in &self.fields {
+            if !HEADER_TAGS.contains(tag) {
+                write_field(&mut body, *tag, value);
+            }
+        }
+
+        let mut buf = Vec::with_capacity(body.len() + 32);
+        write_field(&mut buf, TAG_BEGIN_STRING, begin_string);
+        write_field(&mut buf, TAG_BODY_LENGTH, &body.len().to_string());
+        buf.extend_from_slice(&body);
+        let checksum = checksum(&buf);
+        write_field(&mut buf, TAG_CHECKSUM, &format!("{checksum:03}"));
+        buf
+    }
+
+    /// Decodes a complete message (including the trailing delimiter), validating the
+    /// body length and checksum.
+    ///
+    /// Returns the `BeginString(8)` and the message.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the message is malformed, or the body length or
+    /// checksum does not match.
+    pub fn decode(data: &[u8]) -> anyhow::Result<(String, Self)> {
+        let text = std::str::from_utf8(data)?;
+        let mut fields = text
+            .strip_suffix(SOH as char)
+            .ok_or_else(|| anyhow::anyhow!("Message not terminated by delimiter"))?
+            .split(SOH as char)
+            .map(parse_field);
+
+        let begin_string = match fields.next() {
+            Some(Ok((TAG_BEGIN_STRING, value))) => value.to_string(),
+            _ => anyhow::bail!("Message must begin with tag {TAG_BEGIN_STRING}"),
+        };
+        let body_length: usize = match fields.next() {
+            Some(Ok((TAG_BODY_LENGTH, value))) => value.parse()?,
+            _ => anyhow::bail!("Tag {TAG_BODY_LENGTH} must be the second field"),
+        };
+
+        // The body runs from after the `BodyLength(9)` field up to the `CheckSum(10)` field
+        let body_start = text
+            .match_indices(SOH as char)
+            .nth(1)
+            .map(|(i, _)| i + 1)
+            .ok_or_else(|| anyhow::anyhow!("Message is truncated"))?;
+        let checksum_start = text
+            .rfind("\u{1}10=")
+            .map(|i| i + 1)
+            .ok_or_else(|| anyhow::anyhow!("Missing tag {TAG_CHECKSUM}"))?;
+        if checksum_start < body_start || checksum_start - body_start != body_length {
+            anyhow::bail!(
+                "Invalid body length {body_length}, was {}",
+                checksum_start.saturating_sub(body_start)
+            );
+        }
+        let expected: u8 = text[checksum_start + 3..text.len() - 1].parse()?;
+        let actual = checksum(&data[..checksum_start]);
+        if expected != actual {
+            anyhow::bail!("Invalid checksum {expected:03}, computed {actual:03}");
+        }
+
+        let mut msg_type = None;
+        let mut body = Vec::new();
+        for field in fields {
+            let (tag, value) = field?;
+            match tag {
+                TAG_MSG_TYPE if msg_type.is_none() => msg_type = Some(value.to_string()),
+                TAG_CHECKSUM => break,
+                _ => body.push((tag, value.to_string())),
+            }
+        }
+        let msg_type =
+            msg_type.ok_or_else(|| anyhow::anyhow!("Missing required tag {TAG_MSG_TYPE}"))?;
+
+        Ok((
+            begin_string,
+            Self {
+                msg_type,
+                fields: body,
+            },
+        ))
+    }
+}
+
+fn write_field(buf: &mut Vec<u8>, tag: u32, value: &str) {
+    buf.extend_from_slice(tag.to_string().as_bytes());
+    buf.push(b'=');
+    buf.extend_from_slice(value.as_bytes());
+    buf.push(SOH);
+}
+
+fn parse_field(field: &str) -> anyhow::Result<(u32, &str)> {
+    let (tag, value) = field
+        .split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("Invalid field '{field}'"))?;
+    let tag = tag
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Invalid tag in field '{field}'"))?;
+    Ok((tag, value))
+}
+
+/// Returns the FIX checksum (byte sum modulo 256) of the given `data`.
+#[must_use]
+pub fn checksum(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte))
+}
+
+/// Formats the given UNIX timestamp as a FIX UTCTimestamp with milliseconds.
+#[must_use]
+pub fn format_utc_timestamp(timestamp: UnixNanos) -> String {
+    DateTime::<Utc>::from(UNIX_EPOCH + Duration::from_nanos(timestamp.as_u64()))
+        .format(SENDING_TIME_FORMAT)
+        .to_string()
+}
+
+/// Parses the given FIX UTCTimestamp (with optional fractional seconds) as a UNIX
+/// timestamp.
+///
+/// # Errors
+///
+/// This function returns an error if the value is not a valid UTCTimestamp.
+pub fn parse_utc_timestamp(value: &str) -> anyhow::Result<UnixNanos> {
+    let dt = NaiveDateTime::parse_from_str(value, "%Y%m%d-%H:%M:%S%.f")?;
+    let nanos = dt
+        .and_utc()
+        .timestamp_nanos_opt()
+        .ok_or_else(|| anyhow::anyhow!("Timestamp out of range, was {value}"))?;
+    Ok(UnixNanos::from(u64::try_from(nanos)?))
+}
+
+/// Reassembles FIX messages from a stream of fields.
+///
+/// The [`SocketClient`](crate::socket::SocketClient) splits the byte stream on its suffix,
+/// so with a suffix of [`SOH`] each received frame is a single field. Fields are buffered
+/// until the `CheckSum(10)` field completes a message. Any partial message is discarded
+/// when a new `BeginString(8)` field is received.
+#[derive(Debug, Default)]
+pub struct FixFramer {
+    buf: Vec<u8>,
+    partial_field: Vec<u8>,
+}
+
+impl FixFramer {
+    /// Pushes the given `field` (without its delimiter), returning the complete encoded
+    /// message if the field terminates one.
+    pub fn push_field(&mut self, field: &[u8]) -> Option<Vec<u8>> {
+        if field.starts_with(b"8=") {
+            if !self.buf.is_empty() {
+                tracing::warn!("Discarding {} bytes of partial message", self.buf.len());
+            }
+            self.buf.clear();
+        } else if self.buf.is_empty() {
+            tracing::warn!("Discarding field outside of message");
+            return None;
+        }
+
+        self.buf.extend_from_slice(field);
+        self.buf.push(SOH);
+
+        if field.starts_with(b"10=") {
+            Some(std::mem::take(&mut self.buf))
+        } else {
+            None
+        }
+    }
+
+    /// Pushes the given raw `data`, returning all messages completed by it.
+    ///
+    /// Any trailing partial field is held until the next push.
+    pub fn push_bytes(&mut self, data: &[u8]) -> Vec<Vec<u8>> {
+        let mut messages = Vec::new();
+        let mut rest = data;
+        while let Some(i) = rest.iter().position(|b| *b == SOH) {
+            self.partial_field.extend_from_slice(&rest[..i]);
+            let field = std::mem::take(&mut self.partial_field);
+            messages.extend(self.push_field(&field));
+            rest = &rest[i + 1..];
+        }
+        self.partial_field.extend_from_slice(rest);
+        messages
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn to_fix(text: &str) -> Vec<u8> {
+        text.replace('|', "\u{1}").into_bytes()
+    }
+
+    #[rstest]
+    fn test_encode_heartbeat() {
+        let message = FixMessage::new(msg_type::HEARTBEAT)
+            .with_field(TAG_SENDING_TIME, "20240101-00:00:00.000")
+            .with_field(TAG_MSG_SEQ_NUM, 2)
+            .with_field(TAG_TARGET_COMP_ID, "VENUE")
+            .with_field(TAG_SENDER_COMP_ID, "CLIENT");
+
+        let encoded = message.encode("FIX.4.4");
+
+        assert_eq!(
+            encoded,
+            to_fix("8=FIX.4.4|9=54|35=0|49=CLIENT|56=VENUE|34=2|52=20240101-00:00:00.000|10=242|")
+        );
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let message = FixMessage::new(msg_type::NEW_ORDER_SINGLE)
+            .with_field(TAG_SENDER_COMP_ID, "CLIENT")
+            .with_field(TAG_TARGET_COMP_ID, "VENUE")
+            .with_field(TAG_MSG_SEQ_NUM, 7)
+            .with_field(11, "O-123")
+            .with_field(55, "AUD/USD")
+            .with_field(54, 1);
+
+        let (begin_string, decoded) = FixMessage::decode(&message.encode("FIXT.1.1")).unwrap();
+
+        assert_eq!(begin_string, "FIXT.1.1");
+        assert_eq!(decoded, message);
+        assert_eq!(decoded.seq_num().unwrap(), 7);
+        assert_eq!(decoded.get(55), Some("AUD/USD"));
+    }
+
+    #[rstest]
+    #[case("8=FIX.4.4|9=5|35=0|10=164|")] // Checksum mismatch
+    #[case("8=FIX.4.4|9=6|35=0|10=163|")] // Body length mismatch
+    #[case("9=5|8=FIX.4.4|35=0|10=163|")] // Header out of order
+    #[case("8=FIX.4.4|9=5|35=0|")] // Missing checksum
+    #[case("8=FIX.4.4|9=5|35=0|10=163")] // Not terminated
+    fn test_decode_invalid(#[case] text: &str) {
+        assert!(FixMessage::decode(&to_fix(text)).is_err());
+    }
+
+    #[rstest]
+    fn test_set_field_replaces_value() {
+        let mut message = FixMessage::new(msg_type::LOGON).with_field(TAG_HEART_BT_INT, 30);
+
+        message.set_field(TAG_HEART_BT_INT, 10);
+        message.push_field(TAG_TEXT, "a");
+        message.push_field(TAG_TEXT, "b");
+
+        assert_eq!(message.get_parsed::<u64>(TAG_HEART_BT_INT).unwrap(), 10);
+        assert_eq!(message.fields().len(), 3);
+        message.remove_field(TAG_TEXT);
+        assert_eq!(message.get(TAG_TEXT), None);
+    }
+
+    #[rstest]
+    fn test_utc_timestamp_round_trip() {
+        let timestamp = UnixNanos::from(1_704_067_200_123_000_000);
+
+        let formatted = format_utc_timestamp(timestamp);
+
+        assert_eq!(formatted, "20240101-00:00:00.123");
+        assert_eq!(parse_utc_timestamp(&formatted).unwrap(), timestamp);
+    }
+
+    #[rstest]
+    fn test_framer_push_fields() {
+        let encoded = FixMessage::new(msg_type::HEARTBEAT).encode("FIX.4.4");
+        let mut framer = FixFramer::default();
+
+        // A stray field before the message is discarded
+        assert_eq!(framer.push_field(b"58=junk"), None);
+
+        let mut completed = Vec::new();
+        for field in encoded[..encoded.len() - 1].split(|b| *b == SOH) {
+            completed.extend(framer.push_field(field));
+        }
+
+        assert_eq!(completed, vec![encoded]);
+    }
+
+    #[rstest]
+    fn test_framer_push_bytes_across_chunks() {
+        let first = FixMessage::new(msg_type::HEARTBEAT).encode("FIX.4.4");
+        let second = FixMessage::new(msg_type::TEST_REQUEST)
+            .with_field(TAG_TEST_REQ_ID, "T1")
+            .encode("FIX.4.4");
+        let mut stream = first.clone();
+        stream.extend_from_slice(&second);
+        let mut framer = FixFramer::default();
+
+        let mut completed = framer.push_bytes(&stream[..10]);
+        completed.extend(framer.push_bytes(&stream[10..first.len() + 7]));
+        completed.extend(framer.push_bytes(&stream[first.len() + 7..]));
+
+        assert_eq!(completed, vec![first, second]);
+    }
+}
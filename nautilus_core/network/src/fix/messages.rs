@@ -0,0 +1,613 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Typed encoding and decoding of common FIX execution messages.
+
+use std::{fmt::Display, str::FromStr};
+
+use nautilus_core::nanos::UnixNanos;
+use rust_decimal::Decimal;
+
+use super::message::{format_utc_timestamp, msg_type, parse_utc_timestamp, FixMessage, TAG_TEXT};
+
+pub const TAG_ACCOUNT: u32 = 1;
+pub const TAG_AVG_PX: u32 = 6;
+pub const TAG_CL_ORD_ID: u32 = 11;
+pub const TAG_CUM_QTY: u32 = 14;
+pub const TAG_EXEC_ID: u32 = 17;
+pub const TAG_LAST_PX: u32 = 31;
+pub const TAG_LAST_QTY: u32 = 32;
+pub const TAG_ORDER_ID: u32 = 37;
+pub const TAG_ORDER_QTY: u32 = 38;
+pub const TAG_ORD_STATUS: u32 = 39;
+pub const TAG_ORD_TYPE: u32 = 40;
+pub const TAG_ORIG_CL_ORD_ID: u32 = 41;
+pub const TAG_PRICE: u32 = 44;
+pub const TAG_SIDE: u32 = 54;
+pub const TAG_SYMBOL: u32 = 55;
+pub const TAG_TIME_IN_FORCE: u32 = 59;
+pub const TAG_TRANSACT_TIME: u32 = 60;
+pub const TAG_STOP_PX: u32 = 99;
+pub const TAG_CXL_REJ_REASON: u32 = 102;
+pub const TAG_EXPIRE_TIME: u32 = 126;
+pub const TAG_EXEC_TYPE: u32 = 150;
+pub const TAG_LEAVES_QTY: u32 = 151;
+pub const TAG_CXL_REJ_RESPONSE_TO: u32 = 434;
+
+/// Defines a FIX enumerated field with a single character code per variant.
+macro_rules! fix_enum {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident = $code:literal),+ $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant),+
+        }
+
+        impl $name {
+            /// Returns the FIX code for the value.
+            #[must_use]
+            pub fn code(&self) -> char {
+                match self {
+                    $(Self::$variant => $code),+
+                }
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.code())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = anyhow::Error;
+
+            fn from_str(s: &str) -> anyhow::Result<Self> {
+                match s {
+                    $(s if s.len() == 1 && s.starts_with($code) => Ok(Self::$variant),)+
+                    _ => anyhow::bail!("Invalid {} code '{s}'", stringify!($name)),
+                }
+            }
+        }
+    };
+}
+
+fix_enum!(
+    /// The FIX `Side(54)` field.
+    Side { Buy = '1', Sell = '2', SellShort = '5' }
+);
+
+fix_enum!(
+    /// The FIX `OrdType(40)` field.
+    OrdType { Market = '1', Limit = '2', Stop = '3', StopLimit = '4' }
+);
+
+fix_enum!(
+    /// The FIX `TimeInForce(59)` field.
+    TimeInForce {
+        Day = '0',
+        GoodTillCancel = '1',
+        AtTheOpening = '2',
+        ImmediateOrCancel = '3',
+        FillOrKill = '4',
+        GoodTillDate = '6',
+    }
+);
+
+fix_enum!(
+    /// The FIX `ExecType(150)` field.
+    ExecType {
+        New = '0',
+        DoneForDay = '3',
+        Canceled = '4',
+        Replaced = '5',
+        PendingCancel = '6',
+        Rejected = '8',
+        PendingNew = 'A',
+        Expired = 'C',
+        PendingReplace = 'E',
+        Trade = 'F',
+        OrderStatus = 'I',
+    }
+);
+
+fix_enum!(
+    /// The FIX `OrdStatus(39)` field.
+    OrdStatus {
+        New = '0',
+        PartiallyFilled = '1',
+        Filled = '2',
+        DoneForDay = '3',
+        Canceled = '4',
+        PendingCancel = '6',
+        Rejected = '8',
+        PendingNew = 'A',
+        Expired = 'C',
+        PendingReplace = 'E',
+    }
+);
+
+fix_enum!(
+    /// The FIX `CxlRejResponseTo(434)` field.
+    CxlRejResponseTo { OrderCancelRequest = '1', OrderCancelReplaceRequest = '2' }
+);
+
+/// Provides conversion of a typed application message to and from a [`FixMessage`].
+pub trait FixApplicationMessage: Sized {
+    /// The `MsgType(35)` of the message.
+    const MSG_TYPE: &'static str;
+
+    /// Returns the message as a [`FixMessage`] (without the session header).
+    fn to_message(&self) -> FixMessage;
+
+    /// Decodes the message from the given [`FixMessage`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the message type does not match, or a required
+    /// field is missing or invalid.
+    fn from_message(message: &FixMessage) -> anyhow::Result<Self>;
+}
+
+fn check_msg_type(message: &FixMessage, expected: &str) -> anyhow::Result<()> {
+    if message.msg_type() != expected {
+        anyhow::bail!(
+            "Invalid message type {}, expected {expected}",
+            message.msg_type()
+        );
+    }
+    Ok(())
+}
+
+fn set_opt<T: Display>(message: &mut FixMessage, tag: u32, value: Option<&T>) {
+    if let Some(value) = value {
+        message.set_field(tag, value);
+    }
+}
+
+fn get_opt_string(message: &FixMessage, tag: u32) -> Option<String> {
+    message.get(tag).map(str::to_string)
+}
+
+fn get_string(message: &FixMessage, tag: u32) -> anyhow::Result<String> {
+    message.get_required(tag).map(str::to_string)
+}
+
+fn get_timestamp(message: &FixMessage, tag: u32) -> anyhow::Result<Option<UnixNanos>> {
+    message.get(tag).map(parse_utc_timestamp).transpose()
+}
+
+/// Represents a FIX `NewOrderSingle(D)` message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NewOrderSingle {
+    pub cl_ord_id: String,
+    pub symbol: String,
+    pub side: Side,
+    pub order_qty: Decimal,
+    pub ord_type: OrdType,
+    pub price: Option<Decimal>,
+    pub stop_px: Option<Decimal>,
+    pub time_in_force: Option<TimeInForce>,
+    pub expire_time: Option<UnixNanos>,
+    pub account: Option<String>,
+    pub transact_time: UnixNanos,
+}
+
+impl FixApplicationMessage for NewOrderSingle {
+    const MSG_TYPE: &'static str = msg_type::NEW_ORDER_SINGLE;
+
+    fn to_message(&self) -> FixMessage {
+        let mut message = FixMessage::new(Self::MSG_TYPE)
+            .with_field(TAG_CL_ORD_ID, &self.cl_ord_id)
+            .with_field(TAG_SYMBOL, &self.symbol)
+            .with_field(TAG_SIDE, self.side)
+            .with_field(TAG_ORDER_QTY, self.order_qty)
+            .with_field(TAG_ORD_TYPE, self.ord_type);
+        set_opt(&mut message, TAG_PRICE, self.price.as_ref());
+        set_opt(&mut message, TAG_STOP_PX, self.stop_px.as_ref());
+        set_opt(&mut message, TAG_TIME_IN_FORCE, self.time_in_force.as_ref());
+        set_opt(
+            &mut message,
+            TAG_EXPIRE_TIME,
+            self.expire_time.map(format_utc_timestamp).as_ref(),
+        );
+        set_opt(&mut message, TAG_ACCOUNT, self.account.as_ref());
+        message.with_field(TAG_TRANSACT_TIME, format_utc_timestamp(self.transact_time))
+    }
+
+    fn from_message(message: &FixMessage) -> anyhow::Result<Self> {
+        check_msg_type(message, Self::MSG_TYPE)?;
+        Ok(Self {
+            cl_ord_id: get_string(message, TAG_CL_ORD_ID)?,
+            symbol: get_string(message, TAG_SYMBOL)?,
+            side: message.get_parsed(TAG_SIDE)?,
+            order_qty: message.get_parsed(TAG_ORDER_QTY)?,
+            ord_type: message.get_parsed(TAG_ORD_TYPE)?,
+            price: message.get_parsed_opt(TAG_PRICE)?,
+            stop_px: message.get_parsed_opt(TAG_STOP_PX)?,
+            time_in_force: message.get_parsed_opt(TAG_TIME_IN_FORCE)?,
+            expire_time: get_timestamp(message, TAG_EXPIRE_TIME)?,
+            account: get_opt_string(message, TAG_ACCOUNT),
+            transact_time: parse_utc_timestamp(message.get_required(TAG_TRANSACT_TIME)?)?,
+        })
+    }
+}
+
+/// Represents a FIX `OrderCancelRequest(F)` message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderCancelRequest {
+    pub cl_ord_id: String,
+    pub orig_cl_ord_id: String,
+    pub order_id: Option<String>,
+    pub symbol: String,
+    pub side: Side,
+    pub order_qty: Option<Decimal>,
+    pub transact_time: UnixNanos,
+}
+
+impl FixApplicationMessage for OrderCancelRequest {
+    const MSG_TYPE: &'static str = msg_type::ORDER_CANCEL_REQUEST;
+
+    fn to_message(&self) -> FixMessage {
+        let mut message = FixMessage::new(Self::MSG_TYPE)
+            .with_field(TAG_CL_ORD_ID, &self.cl_ord_id)
+            .with_field(TAG_ORIG_CL_ORD_ID, &self.orig_cl_ord_id);
+        set_opt(&mut message, TAG_ORDER_ID, self.order_id.as_ref());
+        let mut message = message
+            .with_field(TAG_SYMBOL, &self.symbol)
+            .with_field(TAG_SIDE, self.side);
+        set_opt(&mut message, TAG_ORDER_QTY, self.order_qty.as_ref());
+        message.with_field(TAG_TRANSACT_TIME, format_utc_timestamp(self.transact_time))
+    }
+
+    fn from_message(message: &FixMessage) -> anyhow::Result<Self> {
+        check_msg_type(message, Self::MSG_TYPE)?;
+        Ok(Self {
+            cl_ord_id: get_string(message, TAG_CL_ORD_ID)?,
+            orig_cl_ord_id: get_string(message, TAG_ORIG_CL_ORD_ID)?,
+            order_id: get_opt_string(message, TAG_ORDER_ID),
+            symbol: get_string(message, TAG_SYMBOL)?,
+            side: message.get_parsed(TAG_SIDE)?,
+            order_qty: message.get_parsed_opt(TAG_ORDER_QTY)?,
+            transact_time: parse_utc_timestamp(message.get_required(TAG_TRANSACT_TIME)?)?,
+        })
+    }
+}
+
+/// Represents a FIX `OrderCancelReplaceRequest(G)` message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderCancelReplaceRequest {
+    pub cl_ord_id: String,
+    pub orig_cl_ord_id: String,
+    pub order_id: Option<String>,
+    pub symbol: String,
+    pub side: Side,
+    pub order_qty: Decimal,
+    pub ord_type: OrdType,
+    pub price: Option<Decimal>,
+    pub stop_px: Option<Decimal>,
+    pub time_in_force: Option<TimeInForce>,
+    pub transact_time: UnixNanos,
+}
+
+impl FixApplicationMessage for OrderCancelReplaceRequest {
+    const MSG_TYPE: &'static str = msg_type::ORDER_CANCEL_REPLACE_REQUEST;
+
+    fn to_message(&self) -> FixMessage {
+        let mut message = FixMessage::new(Self::MSG_TYPE)
+            .with_field(TAG_CL_ORD_ID, &self.cl_ord_id)
+            .with_field(TAG_ORIG_CL_ORD_ID, &self.orig_cl_ord_id);
+        set_opt(&mut message, TAG_ORDER_ID, self.order_id.as_ref());
+        let mut message = message
+            .with_field(TAG_SYMBOL, &self.symbol)
+            .with_field(TAG_SIDE, self.side)
+            .with_field(TAG_ORDER_QTY, self.order_qty)
+            .with_field(TAG_ORD_TYPE, self.ord_type);
+        set_opt(&mut message, TAG_PRICE, self.price.as_ref());
+        set_opt(&mut message, TAG_STOP_PX, self.stop_px.as_ref());
+        set_opt(&mut message, TAG_TIME_IN_FORCE, self.time_in_force.as_ref());
+        message.with_field(TAG_TRANSACT_TIME, format_utc_timestamp(self.transact_time))
+    }
+
+    fn from_message(message: &FixMessage) -> anyhow::Result<Self> {
+        check_msg_type(message, Self::MSG_TYPE)?;
+        Ok(Self {
+            cl_ord_id: get_string(message, TAG_CL_ORD_ID)?,
+            orig_cl_ord_id: get_string(message, TAG_ORIG_CL_ORD_ID)?,
+            order_id: get_opt_string(message, TAG_ORDER_ID),
+            symbol: get_string(message, TAG_SYMBOL)?,
+            side: message.get_parsed(TAG_SIDE)?,
+            order_qty: message.get_parsed(TAG_ORDER_QTY)?,
+            ord_type: message.get_parsed(TAG_ORD_TYPE)?,
+            price: message.get_parsed_opt(TAG_PRICE)?,
+            stop_px: message.get_parsed_opt(TAG_STOP_PX)?,
+            time_in_force: message.get_parsed_opt(TAG_TIME_IN_FORCE)?,
+            transact_time: parse_utc_timestamp(message.get_required(TAG_TRANSACT_TIME)?)?,
+        })
+    }
+}
+
+/// Represents a FIX `ExecutionReport(8)` message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionReport {
+    pub order_id: String,
+    pub cl_ord_id: Option<String>,
+    pub orig_cl_ord_id: Option<String>,
+    pub exec_id: String,
+    pub exec_type: ExecType,
+    pub ord_status: OrdStatus,
+    pub symbol: String,
+    pub side: Side,
+    pub order_qty: Option<Decimal>,
+    pub price: Option<Decimal>,
+    pub last_qty: Option<Decimal>,
+    pub last_px: Option<Decimal>,
+    pub leaves_qty: Decimal,
+    pub cum_qty: Decimal,
+    pub avg_px: Option<Decimal>,
+    pub text: Option<String>,
+    pub transact_time: Option<UnixNanos>,
+}
+
+impl ExecutionReport {
+    /// Returns whether the report is for a fill (`ExecType` of `Trade`).
+    #[must_use]
+    pub fn is_fill(&self) -> bool {
+        self.exec_type == ExecType::Trade
+    }
+}
+
+impl FixApplicationMessage for ExecutionReport {
+    const MSG_TYPE: &'static str = msg_type::EXECUTION_REPORT;
+
+    fn to_message(&self) -> FixMessage {
+        let mut message = FixMessage::new(Self::MSG_TYPE).with_field(TAG_ORDER_ID, &self.order_id);
+        set_opt(&mut message, TAG_CL_ORD_ID, self.cl_ord_id.as_ref());
+        set_opt(
+            &mut message,
+            TAG_ORIG_CL_ORD_ID,
+            self.orig_cl_ord_id.as_ref(),
+        );
+        let mut message = message
+            .with_field(TAG_EXEC_ID, &self.exec_id)
+            .with_field(TAG_EXEC_TYPE, self.exec_type)
+            .with_field(TAG_ORD_STATUS, self.ord_status)
+            .with_field(TAG_SYMBOL, &self.symbol)
+            .with_field(TAG_SIDE, self.side);
+        set_opt(&mut message, TAG_ORDER_QTY, self.order_qty.as_ref());
+        set_opt(&mut message, TAG_PRICE, self.price.as_ref());
+        set_opt(&mut message, TAG_LAST_QTY, self.last_qty.as_ref());
+        set_opt(&mut message, TAG_LAST_PX, self.last_px.as_ref());
+        let mut message = message
+            .with_field(TAG_LEAVES_QTY, self.leaves_qty)
+            .with_field(TAG_CUM_QTY, self.cum_qty);
+        set_opt(&mut message, TAG_AVG_PX, self.avg_px.as_ref());
+        set_opt(&mut message, TAG_TEXT, self.text.as_ref());
+        set_opt(
+            &mut message,
+            TAG_TRANSACT_TIME,
+            self.transact_time.map(format_utc_timestamp).as_ref(),
+        );
+        message
+    }
+
+    fn from_message(message: &FixMessage) -> anyhow::Result<Self> {
+        check_msg_type(message, Self::MSG_TYPE)?;
+        Ok(Self {
+            order_id: get_string(message, TAG_ORDER_ID)?,
+            cl_ord_id: get_opt_string(message, TAG_CL_ORD_ID),
+            orig_cl_ord_id: get_opt_string(message, TAG_ORIG_CL_ORD_ID),
+            exec_id: get_string(message, TAG_EXEC_ID)?,
+            exec_type: message.get_parsed(TAG_EXEC_TYPE)?,
+            ord_status: message.get_parsed(TAG_ORD_STATUS)?,
+            symbol: get_string(message, TAG_SYMBOL)?,
+            side: message.get_parsed(TAG_SIDE)?,
+            order_qty: message.get_parsed_opt(TAG_ORDER_QTY)?,
+            price: message.get_parsed_opt(TAG_PRICE)?,
+            last_qty: message.get_parsed_opt(TAG_LAST_QTY)?,
+            last_px: message.get_parsed_opt(TAG_LAST_PX)?,
+            leaves_qty: message.get_parsed(TAG_LEAVES_QTY)?,
+            cum_qty: message.get_parsed(TAG_CUM_QTY)?,
+            avg_px: message.get_parsed_opt(TAG_AVG_PX)?,
+            text: get_opt_string(message, TAG_TEXT),
+            transact_time: get_timestamp(message, TAG_TRANSACT_TIME)?,
+        })
+    }
+}
+
+/// Represents a FIX `OrderCancelReject(9)` message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderCancelReject {
+    pub order_id: String,
+    pub cl_ord_id: String,
+    pub orig_cl_ord_id: Option<String>,
+    pub ord_status: OrdStatus,
+    pub cxl_rej_response_to: CxlRejResponseTo,
+    pub cxl_rej_reason: Option<u32>,
+    pub text: Option<String>,
+}
+
+impl FixApplicationMessage for OrderCancelReject {
+    const MSG_TYPE: &'static str = msg_type::ORDER_CANCEL_REJECT;
+
+    fn to_message(&self) -> FixMessage {
+        let mut message = FixMessage::new(Self::MSG_TYPE)
+            .with_field(TAG_ORDER_ID, &self.order_id)
+            .with_field(TAG_CL_ORD_ID, &self.cl_ord_id);
+        set_opt(
+            &mut message,
+            TAG_ORIG_CL_ORD_ID,
+            self.orig_cl_ord_id.as_ref(),
+        );
+        let mut message = message
+            .with_field(TAG_ORD_STATUS, self.ord_status)
+            .with_field(TAG_CXL_REJ_RESPONSE_TO, self.cxl_rej_response_to);
+        set_opt(
+            &mut message,
+            TAG_CXL_REJ_REASON,
+            self.cxl_rej_reason.as_ref(),
+        );
+        set_opt(&mut message, TAG_TEXT, self.text.as_ref());
+        message
+    }
+
+    fn from_message(message: &FixMessage) -> anyhow::Result<Self> {
+        check_msg_type(message, Self::MSG_TYPE)?;
+        Ok(Self {
+            order_id: get_string(message, TAG_ORDER_ID)?,
+            cl_ord_id: get_string(message, TAG_CL_ORD_ID)?,
+            orig_cl_ord_id: get_opt_string(message, TAG_ORIG_CL_ORD_ID),
+            ord_status: message.get_parsed(TAG_ORD_STATUS)?,
+            cxl_rej_response_to: message.get_parsed(TAG_CXL_REJ_RESPONSE_TO)?,
+            cxl_rej_reason: message.get_parsed_opt(TAG_CXL_REJ_REASON)?,
+            text: get_opt_string(message, TAG_TEXT),
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[rstest]
+    fn test_new_order_single_round_trip() {
+        let order = NewOrderSingle {
+            cl_ord_id: "O-20240101-001".to_string(),
+            symbol: "AUD/USD".to_string(),
+            side: Side::Buy,
+            order_qty: dec!(100000),
+            ord_type: OrdType::Limit,
+            price: Some(dec!(0.68125)),
+            stop_px: None,
+            time_in_force: Some(TimeInForce::GoodTillDate),
+            expire_time: Some(UnixNanos::from(1_704_153_600_000_000_000)),
+            account: Some("ACC-1".to_string()),
+            transact_time: UnixNanos::from(1_704_067_200_000_000_000),
+        };
+
+        let message = order.to_message();
+
+        assert_eq!(message.get(TAG_SIDE), Some("1"));
+        assert_eq!(message.get(TAG_ORD_TYPE), Some("2"));
+        assert_eq!(message.get(TAG_PRICE), Some("0.68125"));
+        assert_eq!(message.get(TAG_TIME_IN_FORCE), Some("6"));
+        assert_eq!(message.get(TAG_EXPIRE_TIME), Some("20240102-00:00:00.000"));
+        assert_eq!(message.get(TAG_STOP_PX), None);
+        assert_eq!(NewOrderSingle::from_message(&message).unwrap(), order);
+    }
+
+    #[rstest]
+    fn test_cancel_and_replace_round_trip() {
+        let cancel = OrderCancelRequest {
+            cl_ord_id: "O-2".to_string(),
+            orig_cl_ord_id: "O-1".to_string(),
+            order_id: Some("V-1".to_string()),
+            symbol: "ESZ4".to_string(),
+            side: Side::Sell,
+            order_qty: None,
+            transact_time: UnixNanos::from(1_704_067_200_000_000_000),
+        };
+        let replace = OrderCancelReplaceRequest {
+            cl_ord_id: "O-3".to_string(),
+            orig_cl_ord_id: "O-2".to_string(),
+            order_id: None,
+            symbol: "ESZ4".to_string(),
+            side: Side::Sell,
+            order_qty: dec!(5),
+            ord_type: OrdType::StopLimit,
+            price: Some(dec!(5000.25)),
+            stop_px: Some(dec!(5001)),
+            time_in_force: None,
+            transact_time: UnixNanos::from(1_704_067_200_000_000_000),
+        };
+
+        assert_eq!(
+            OrderCancelRequest::from_message(&cancel.to_message()).unwrap(),
+            cancel
+        );
+        assert_eq!(
+            OrderCancelReplaceRequest::from_message(&replace.to_message()).unwrap(),
+            replace
+        );
+    }
+
+    #[rstest]
+    fn test_execution_report_from_encoded_fill() {
+        let encoded = "8=FIX.4.4|9=0|35=8|37=V-1|11=O-1|17=E-1|150=F|39=1|55=AUD/USD|54=2|\
+                       38=100000|32=40000|31=0.68120|151=60000|14=40000|6=0.68120|\
+                       60=20240101-00:00:01.250|";
+        let mut message = FixMessage::new(msg_type::EXECUTION_REPORT);
+        for field in encoded.split('|').skip(3).filter(|f| !f.is_empty()) {
+            let (tag, value) = field.split_once('=').unwrap();
+            message.push_field(tag.parse().unwrap(), value);
+        }
+
+        let report = ExecutionReport::from_message(&message).unwrap();
+
+        assert!(report.is_fill());
+        assert_eq!(report.ord_status, OrdStatus::PartiallyFilled);
+        assert_eq!(report.side, Side::Sell);
+        assert_eq!(report.last_qty, Some(dec!(40000)));
+        assert_eq!(report.leaves_qty, dec!(60000));
+        assert_eq!(
+            report.transact_time,
+            Some(UnixNanos::from(1_704_067_201_250_000_000))
+        );
+        assert_eq!(
+            ExecutionReport::from_message(&report.to_message()).unwrap(),
+            report
+        );
+    }
+
+    #[rstest]
+    fn test_order_cancel_reject_round_trip() {
+        let reject = OrderCancelReject {
+            order_id: "NONE".to_string(),
+            cl_ord_id: "O-2".to_string(),
+            orig_cl_ord_id: Some("O-1".to_string()),
+            ord_status: OrdStatus::Filled,
+            cxl_rej_response_to: CxlRejResponseTo::OrderCancelRequest,
+            cxl_rej_reason: Some(0),
+            text: Some("Too late to cancel".to_string()),
+        };
+
+        assert_eq!(
+            OrderCancelReject::from_message(&reject.to_message()).unwrap(),
+            reject
+        );
+    }
+
+    #[rstest]
+    #[case("X")]
+    #[case("12")]
+    #[case("")]
+    fn test_invalid_enum_code(#[case] code: &str) {
+        assert!(code.parse::<Side>().is_err());
+    }
+
+    #[rstest]
+    fn test_from_message_wrong_type_or_missing_field() {
+        let message = FixMessage::new(msg_type::NEW_ORDER_SINGLE).with_field(TAG_CL_ORD_ID, "O-1");
+
+        assert!(ExecutionReport::from_message(&message).is_err());
+        assert!(NewOrderSingle::from_message(&message).is_err());
+    }
+}
@@ -0,0 +1,28 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A FIX 4.4 and 5.0 (FIXT 1.1) session layer for integrating venues over FIX.
+//!
+//! - [`message`]: tag-value message encoding, decoding and stream framing.
+//! - [`store`]: sequence number and sent message stores.
+//! - [`session`]: the session state machine (logon, heartbeats, resends, logout).
+//! - [`messages`]: typed execution messages (orders, cancels, execution reports).
+//! - [`client`]: an async initiator client running a session over a socket.
+
+pub mod client;
+pub mod message;
+pub mod messages;
+pub mod session;
+pub mod store;
@@ -0,0 +1,936 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The FIX session layer state machine.
+//!
+//! The [`FixSession`] implements logon, heartbeats, test requests, sequence number
+//! validation, resend requests (with gap fills for session messages) and logout. It
+//! performs no I/O: received messages and timer ticks are passed in, and the resulting
+//! [`SessionAction`]s are returned for the caller to carry out.
+
+use std::collections::BTreeMap;
+
+use nautilus_core::nanos::UnixNanos;
+use tracing::{debug, warn};
+
+use super::{
+    message::{
+        format_utc_timestamp, msg_type, FixMessage, TAG_BEGIN_SEQ_NO, TAG_DEFAULT_APPL_VER_ID,
+        TAG_ENCRYPT_METHOD, TAG_END_SEQ_NO, TAG_GAP_FILL_FLAG, TAG_HEART_BT_INT, TAG_MSG_SEQ_NUM,
+        TAG_NEW_SEQ_NO, TAG_ORIG_SENDING_TIME, TAG_PASSWORD, TAG_POSS_DUP_FLAG,
+        TAG_RESET_SEQ_NUM_FLAG, TAG_SENDER_COMP_ID, TAG_SENDING_TIME, TAG_TARGET_COMP_ID,
+        TAG_TEST_REQ_ID, TAG_TEXT, TAG_USERNAME,
+    },
+    store::SequenceStore,
+};
+
+const NANOSECONDS_IN_SECOND: u64 = 1_000_000_000;
+
+/// The FIX protocol version of a session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FixVersion {
+    /// FIX 4.4.
+    Fix44,
+    /// FIX 5.0 over the FIXT 1.1 session layer.
+    Fix50,
+}
+
+impl FixVersion {
+    /// Returns the `BeginString(8)` for the version.
+    #[must_use]
+    pub fn begin_string(&self) -> &'static str {
+        match self {
+            Self::Fix44 => "FIX.4.4",
+            Self::Fix50 => "FIXT.1.1",
+        }
+    }
+
+    /// Returns the `DefaultApplVerID(1137)` sent on logon (FIXT sessions only).
+    #[must_use]
+    pub fn default_appl_ver_id(&self) -> Option<&'static str> {
+        match self {
+            Self::Fix44 => None,
+            Self::Fix50 => Some("7"),
+        }
+    }
+}
+
+/// Configuration for a FIX session.
+#[derive(Clone, Debug)]
+pub struct FixSessionConfig {
+    /// The FIX protocol version.
+    pub version: FixVersion,
+    /// The `SenderCompID(49)` of this side of the session.
+    pub sender_comp_id: String,
+    /// The `TargetCompID(56)` of the counterparty.
+    pub target_comp_id: String,
+    /// The heartbeat interval (seconds) requested on logon.
+    pub heartbeat_interval_secs: u64,
+    /// If sequence numbers should be reset to 1 on logon.
+    pub reset_on_logon: bool,
+    /// The optional `Username(553)` sent on logon.
+    pub username: Option<String>,
+    /// The optional `Password(554)` sent on logon.
+    pub password: Option<String>,
+}
+
+impl FixSessionConfig {
+    /// Creates a new [`FixSessionConfig`] instance.
+    #[must_use]
+    pub fn new(
+        version: FixVersion,
+        sender_comp_id: &str,
+        target_comp_id: &str,
+        heartbeat_interval_secs: u64,
+    ) -> Self {
+        Self {
+            version,
+            sender_comp_id: sender_comp_id.to_string(),
+            target_comp_id: target_comp_id.to_string(),
+            heartbeat_interval_secs,
+            reset_on_logon: false,
+            username: None,
+            password: None,
+        }
+    }
+
+    /// Returns the config with the given logon credentials.
+    #[must_use]
+    pub fn with_credentials(mut self, username: &str, password: &str) -> Self {
+        self.username = Some(username.to_string());
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Returns the config with the given reset on logon setting.
+    #[must_use]
+    pub fn with_reset_on_logon(mut self, reset_on_logon: bool) -> Self {
+        self.reset_on_logon = reset_on_logon;
+        self
+    }
+
+    /// Returns the session ID, unique per version and counterparty pair (e.g. for naming
+    /// the files of a [`FileSequenceStore`](super::store::FileSequenceStore)).
+    #[must_use]
+    pub fn session_id(&self) -> String {
+        format!(
+            "{}-{}-{}",
+            self.version.begin_string(),
+            self.sender_comp_id,
+            self.target_comp_id
+        )
+    }
+}
+
+/// The status of a FIX session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SessionStatus {
+    /// Not connected, or connected but logon not yet sent.
+    Disconnected,
+    /// Logon sent, awaiting the counterparty logon.
+    LogonSent,
+    /// Logged on, application messages can be exchanged.
+    Active,
+    /// Logout sent, awaiting the counterparty logout.
+    LogoutSent,
+}
+
+/// An action to be carried out by the owner of a [`FixSession`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SessionAction {
+    /// Send the encoded message to the counterparty.
+    Send(Vec<u8>),
+    /// Deliver the application message (or session `Reject(3)`) to the application.
+    Deliver(FixMessage),
+    /// The session is now logged on.
+    LoggedOn,
+    /// The session is now logged out.
+    LoggedOut,
+    /// Drop the connection, for the given reason.
+    Disconnect(String),
+}
+
+/// Provides the FIX session layer for one counterparty.
+#[derive(Debug)]
+pub struct FixSession<S: SequenceStore> {
+    config: FixSessionConfig,
+    store: S,
+    status: SessionStatus,
+    last_sent: UnixNanos,
+    last_received: UnixNanos,
+    test_req_id: Option<String>,
+    test_req_count: u64,
+    resend_end: Option<u64>,
+    queued: BTreeMap<u64, FixMessage>,
+}
+
+impl<S: SequenceStore> FixSession<S> {
+    /// Creates a new [`FixSession`] instance.
+    #[must_use]
+    pub fn new(config: FixSessionConfig, store: S) -> Self {
+        Self {
+            config,
+            store,
+            status: SessionStatus::Disconnected,
+            last_sent: UnixNanos::default(),
+            last_received: UnixNanos::default(),
+            test_req_id: None,
+            test_req_count: 0,
+            resend_end: None,
+            queued: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the session config.
+    #[must_use]
+    pub fn config(&self) -> &FixSessionConfig {
+        &self.config
+    }
+
+    /// Returns the session status.
+    #[must_use]
+    pub fn status(&self) -> SessionStatus {
+        self.status
+    }
+
+    /// Returns the sequence store of the session.
+    #[must_use]
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Starts the session on a new connection, returning the `Logon(A)` to send.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the session is already started, or the store
+    /// fails.
+    pub fn logon(&mut self, now: UnixNanos) -> anyhow::Result<Vec<u8>> {
+        if self.status != SessionStatus::Disconnected {
+            anyhow::bail!("Cannot logon with session status {:?}", self.status);
+        }
+        if self.config.reset_on_logon {
+            self.store.reset()?;
+        }
+
+        let mut logon = FixMessage::new(msg_type::LOGON)
+            .with_field(TAG_ENCRYPT_METHOD, 0)
+            .with_field(TAG_HEART_BT_INT, self.config.heartbeat_interval_secs);
+        if self.config.reset_on_logon {
+            logon.set_field(TAG_RESET_SEQ_NUM_FLAG, "Y");
+        }
+        if let Some(username) = &self.config.username {
+            logon.set_field(TAG_USERNAME, username);
+        }
+        if let Some(password) = &self.config.password {
+            logon.set_field(TAG_PASSWORD, password);
+        }
+        if let Some(appl_ver_id) = self.config.version.default_appl_ver_id() {
+            logon.set_field(TAG_DEFAULT_APPL_VER_ID, appl_ver_id);
+        }
+
+        let encoded = self.send(logon, now)?;
+        self.status = SessionStatus::LogonSent;
+        self.last_received = now;
+        Ok(encoded)
+    }
+
+    /// Starts logging out of the session, returning the `Logout(5)` to send.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the session is not active, or the store fails.
+    pub fn logout(&mut self, text: Option<&str>, now: UnixNanos) -> anyhow::Result<Vec<u8>> {
+        if self.status != SessionStatus::Active {
+            anyhow::bail!("Cannot logout with session status {:?}", self.status);
+        }
+        let encoded = self.send(logout_message(text), now)?;
+        self.status = SessionStatus::LogoutSent;
+        Ok(encoded)
+    }
+
+    /// Returns the given application `message` stamped with the session header and
+    /// encoded to send, storing it for any resend request.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the session is not active, the message is a
+    /// session level message, or the store fails.
+    pub fn send_app(&mut self, message: FixMessage, now: UnixNanos) -> anyhow::Result<Vec<u8>> {
+        if self.status != SessionStatus::Active {
+            anyhow::bail!("Cannot send with session status {:?}", self.status);
+        }
+        if message.is_admin() {
+            anyhow::bail!(
+                "Cannot send session message type {} as application message",
+                message.msg_type()
+            );
+        }
+        self.send(message, now)
+    }
+
+    /// Resets the session state on the connection being lost.
+    ///
+    /// The sequence numbers are retained in the store, so the session resumes on the
+    /// next logon.
+    pub fn on_disconnected(&mut self) {
+        self.status = SessionStatus::Disconnected;
+        self.test_req_id = None;
+        self.resend_end = None;
+        self.queued.clear();
+    }
+
+    /// Handles the given encoded received message.
+    ///
+    /// Garbled messages (e.g. with an invalid checksum) are ignored, as the gap will be
+    /// detected and recovered from the next message received.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the store fails.
+    pub fn on_message(
+        &mut self,
+        data: &[u8],
+        now: UnixNanos,
+    ) -> anyhow::Result<Vec<SessionAction>> {
+        self.last_received = now;
+
+        let (begin_string, message) = match FixMessage::decode(data) {
+            Ok(decoded) => decoded,
+            Err(e) => {
+                warn!("Ignoring garbled message: {e}");
+                return Ok(Vec::new());
+            }
+        };
+
+        if let Err(e) = self.validate_header(&begin_string, &message) {
+            return self.terminate(&e.to_string(), now);
+        }
+        let seq_num = match message.seq_num() {
+            Ok(seq_num) => seq_num,
+            Err(e) => return self.terminate(&e.to_string(), now),
+        };
+
+        // Any message received confirms the connection is alive
+        self.test_req_id = None;
+
+        // A logon requesting a reset restarts the counterparty sequence numbers
+        if message.msg_type() == msg_type::LOGON && message.get_flag(TAG_RESET_SEQ_NUM_FLAG) {
+            self.store.set_next_target_seq_num(1)?;
+        }
+
+        // A sequence reset in reset mode is processed regardless of its sequence number
+        if message.msg_type() == msg_type::SEQUENCE_RESET && !message.get_flag(TAG_GAP_FILL_FLAG) {
+            return self.handle_sequence_reset(&message).map(|()| Vec::new());
+        }
+
+        let expected = self.store.next_target_seq_num();
+        if seq_num < expected {
+            if message.get_flag(TAG_POSS_DUP_FLAG) {
+                debug!("Ignoring possible duplicate message {seq_num}");
+                return Ok(Vec::new());
+            }
+            return self.terminate(
+                &format!("MsgSeqNum too low, expecting {expected} but received {seq_num}"),
+                now,
+            );
+        }
+
+        let mut actions = Vec::new();
+        if seq_num > expected {
+            match message.msg_type() {
+                // Process logon, logout and resend requests immediately, then recover the
+                // gap (if still connected), so both sides can recover their gaps concurrently
+                msg_type::LOGON | msg_type::LOGOUT | msg_type::RESEND_REQUEST => {
+                    actions.extend(self.process(message, now)?);
+                    self.store.set_next_target_seq_num(expected)?;
+                }
+                _ => {
+                    self.queued.insert(seq_num, message);
+                }
+            }
+            if matches!(
+                self.status,
+                SessionStatus::Active | SessionStatus::LogonSent
+            ) && self.resend_end.map_or(true, |end| end < expected)
+            {
+                actions.push(self.send_resend_request(expected, seq_num, now)?);
+            }
+            return Ok(actions);
+        }
+
+        actions.extend(self.process(message, now)?);
+
+        // Process any queued messages which are now in sequence
+        loop {
+            let expected = self.store.next_target_seq_num();
+            self.queued.retain(|seq_num, _| *seq_num >= expected);
+            match self.queued.remove(&expected) {
+                Some(message) => actions.extend(self.process(message, now)?),
+                None => break,
+            }
+        }
+        if self
+            .resend_end
+            .map_or(false, |end| end < self.store.next_target_seq_num())
+        {
+            debug!("Resend complete");
+            self.resend_end = None;
+        }
+
+        Ok(actions)
+    }
+
+    /// Handles a timer tick, sending heartbeats and test requests as required.
+    ///
+    /// Should be called at least once per second.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the store fails.
+    pub fn on_timer(&mut self, now: UnixNanos) -> anyhow::Result<Vec<SessionAction>> {
+        let interval = self.config.heartbeat_interval_secs * NANOSECONDS_IN_SECOND;
+        let since_received = now.as_u64().saturating_sub(self.last_received.as_u64());
+        let since_sent = now.as_u64().saturating_sub(self.last_sent.as_u64());
+
+        let mut actions = Vec::new();
+        match self.status {
+            SessionStatus::Disconnected => {}
+            SessionStatus::LogonSent | SessionStatus::LogoutSent => {
+                if since_sent >= interval {
+                    let reason = format!("Timed out in status {:?}", self.status);
+                    self.on_disconnected();
+                    actions.push(SessionAction::Disconnect(reason));
+                }
+            }
+            SessionStatus::Active => {
+                // Allow 20% grace for transmission delay before testing the connection
+                let grace = interval + interval / 5;
+                if since_received >= 2 * grace {
+                    self.on_disconnected();
+                    actions.push(SessionAction::Disconnect("Heartbeat timeout".to_string()));
+                    return Ok(actions);
+                }
+                if since_received >= grace && self.test_req_id.is_none() {
+                    self.test_req_count += 1;
+                    let test_req_id = format!("TEST-{}", self.test_req_count);
+                    let test_request = FixMessage::new(msg_type::TEST_REQUEST)
+                        .with_field(TAG_TEST_REQ_ID, &test_req_id);
+                    self.test_req_id = Some(test_req_id);
+                    actions.push(SessionAction::Send(self.send(test_request, now)?));
+                } else if since_sent >= interval {
+                    let heartbeat = FixMessage::new(msg_type::HEARTBEAT);
+                    actions.push(SessionAction::Send(self.send(heartbeat, now)?));
+                }
+            }
+        }
+        Ok(actions)
+    }
+
+    fn validate_header(&self, begin_string: &str, message: &FixMessage) -> anyhow::Result<()> {
+        let expected = self.config.version.begin_string();
+        if begin_string != expected {
+            anyhow::bail!("Invalid BeginString {begin_string}, expected {expected}");
+        }
+        let sender = message.get_required(TAG_SENDER_COMP_ID)?;
+        let target = message.get_required(TAG_TARGET_COMP_ID)?;
+        if sender != self.config.target_comp_id || target != self.config.sender_comp_id {
+            anyhow::bail!("Invalid CompIDs, was {sender}->{target}");
+        }
+        Ok(())
+    }
+
+    fn process(
+        &mut self,
+        message: FixMessage,
+        now: UnixNanos,
+    ) -> anyhow::Result<Vec<SessionAction>> {
+        let seq_num = message.seq_num()?;
+        let mut actions = Vec::new();
+        match message.msg_type() {
+            msg_type::LOGON => {
+                if self.status == SessionStatus::LogonSent {
+                    self.status = SessionStatus::Active;
+                    actions.push(SessionAction::LoggedOn);
+                } else {
+                    warn!("Ignoring logon with session status {:?}", self.status);
+                }
+            }
+            msg_type::HEARTBEAT => {}
+            msg_type::TEST_REQUEST => {
+                let mut heartbeat = FixMessage::new(msg_type::HEARTBEAT);
+                if let Some(test_req_id) = message.get(TAG_TEST_REQ_ID) {
+                    heartbeat.set_field(TAG_TEST_REQ_ID, test_req_id);
+                }
+                actions.push(SessionAction::Send(self.send(heartbeat, now)?));
+            }
+            msg_type::RESEND_REQUEST => {
+                let begin: u64 = message.get_parsed(TAG_BEGIN_SEQ_NO)?;
+                let end: u64 = message.get_parsed(TAG_END_SEQ_NO)?;
+                actions.extend(self.resend(begin, end, now)?);
+            }
+            msg_type::SEQUENCE_RESET => {
+                // Gap fill mode (reset mode is handled before sequence validation)
+                return self.handle_sequence_reset(&message).map(|()| actions);
+            }
+            msg_type::LOGOUT => {
+                if self.status != SessionStatus::LogoutSent {
+                    let logout = logout_message(None);
+                    actions.push(SessionAction::Send(self.send(logout, now)?));
+                }
+                if let Some(text) = message.get(TAG_TEXT) {
+                    debug!("Logout: {text}");
+                }
+                self.store.set_next_target_seq_num(seq_num + 1)?;
+                self.on_disconnected();
+                actions.push(SessionAction::LoggedOut);
+                actions.push(SessionAction::Disconnect("Logged out".to_string()));
+                return Ok(actions);
+            }
+            _ => actions.push(SessionAction::Deliver(message)),
+        }
+
+        self.store.set_next_target_seq_num(seq_num + 1)?;
+        Ok(actions)
+    }
+
+    fn handle_sequence_reset(&mut self, message: &FixMessage) -> anyhow::Result<()> {
+        let new_seq_num: u64 = message.get_parsed(TAG_NEW_SEQ_NO)?;
+        let expected = self.store.next_target_seq_num();
+        if new_seq_num < expected {
+            // Cannot reset to a lower sequence number, so ignore
+            warn!("Ignoring SequenceReset to {new_seq_num}, expecting {expected}");
+            return Ok(());
+        }
+        debug!("Sequence reset to {new_seq_num}");
+        self.store.set_next_target_seq_num(new_seq_num)
+    }
+
+    fn resend(
+        &mut self,
+        begin: u64,
+        end: u64,
+        now: UnixNanos,
+    ) -> anyhow::Result<Vec<SessionAction>> {
+        let last_sent = self.store.next_sender_seq_num() - 1;
+        let end = if end == 0 || end > last_sent {
+            last_sent
+        } else {
+            end
+        };
+        debug!("Resending messages {begin} to {end}");
+
+        let mut actions = Vec::new();
+        let mut gap_start = None;
+        for seq_num in begin..=end {
+            let stored = self
+                .store
+                .get_messages(seq_num, seq_num)
+                .pop()
+                .and_then(|(_, data)| FixMessage::decode(&data).ok())
+                .filter(|(_, message)| !message.is_admin());
+            match stored {
+                Some((_, mut message)) => {
+                    if let Some(start) = gap_start.take() {
+                        actions.push(self.gap_fill(start, seq_num, now));
+                    }
+                    let orig_sending_time = message.get(TAG_SENDING_TIME).map(str::to_string);
+                    message.set_field(TAG_POSS_DUP_FLAG, "Y");
+                    message.set_field(TAG_SENDING_TIME, format_utc_timestamp(now));
+                    if let Some(orig_sending_time) = orig_sending_time {
+                        message.set_field(TAG_ORIG_SENDING_TIME, orig_sending_time);
+                    }
+                    actions.push(SessionAction::Send(
+                        message.encode(self.config.version.begin_string()),
+                    ));
+                }
+                // Session messages (and any messages missing from the store) are not resent
+                None => {
+                    gap_start.get_or_insert(seq_num);
+                }
+            }
+        }
+        if let Some(start) = gap_start {
+            actions.push(self.gap_fill(start, end + 1, now));
+        }
+        self.last_sent = now;
+        Ok(actions)
+    }
+
+    fn gap_fill(&self, seq_num: u64, new_seq_num: u64, now: UnixNanos) -> SessionAction {
+        let gap_fill = self
+            .stamp(FixMessage::new(msg_type::SEQUENCE_RESET), seq_num, now)
+            .with_field(TAG_POSS_DUP_FLAG, "Y")
+            .with_field(TAG_GAP_FILL_FLAG, "Y")
+            .with_field(TAG_NEW_SEQ_NO, new_seq_num);
+        SessionAction::Send(gap_fill.encode(self.config.version.begin_string()))
+    }
+
+    fn send_resend_request(
+        &mut self,
+        begin: u64,
+        received: u64,
+        now: UnixNanos,
+    ) -> anyhow::Result<SessionAction> {
+        debug!("Gap detected, requesting resend from {begin}");
+        self.resend_end = Some(received - 1);
+        let resend_request = FixMessage::new(msg_type::RESEND_REQUEST)
+            .with_field(TAG_BEGIN_SEQ_NO, begin)
+            .with_field(TAG_END_SEQ_NO, 0);
+        Ok(SessionAction::Send(self.send(resend_request, now)?))
+    }
+
+    fn terminate(&mut self, reason: &str, now: UnixNanos) -> anyhow::Result<Vec<SessionAction>> {
+        warn!("Terminating session: {reason}");
+        let logout = self.send(logout_message(Some(reason)), now)?;
+        self.on_disconnected();
+        Ok(vec![
+            SessionAction::Send(logout),
+            SessionAction::Disconnect(reason.to_string()),
+        ])
+    }
+
+    fn stamp(&self, message: FixMessage, seq_num: u64, now: UnixNanos) -> FixMessage {
+        message
+            .with_field(TAG_SENDER_COMP_ID, &self.config.sender_comp_id)
+            .with_field(TAG_TARGET_COMP_ID, &self.config.target_comp_id)
+            .with_field(TAG_MSG_SEQ_NUM, seq_num)
+            .with_field(TAG_SENDING_TIME, format_utc_timestamp(now))
+    }
+
+    fn send(&mut self, message: FixMessage, now: UnixNanos) -> anyhow::Result<Vec<u8>> {
+        let seq_num = self.store.next_sender_seq_num();
+        let encoded = self
+            .stamp(message, seq_num, now)
+            .encode(self.config.version.begin_string());
+        self.store.store_message(seq_num, &encoded)?;
+        self.store.set_next_sender_seq_num(seq_num + 1)?;
+        self.last_sent = now;
+        Ok(encoded)
+    }
+}
+
+fn logout_message(text: Option<&str>) -> FixMessage {
+    let mut logout = FixMessage::new(msg_type::LOGOUT);
+    if let Some(text) = text {
+        logout.set_field(TAG_TEXT, text);
+    }
+    logout
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::fix::store::MemorySequenceStore;
+
+    const SECOND: u64 = NANOSECONDS_IN_SECOND;
+
+    fn venue_message(message: FixMessage, seq_num: u64) -> Vec<u8> {
+        message
+            .with_field(TAG_SENDER_COMP_ID, "VENUE")
+            .with_field(TAG_TARGET_COMP_ID, "CLIENT")
+            .with_field(TAG_MSG_SEQ_NUM, seq_num)
+            .with_field(TAG_SENDING_TIME, "20240101-00:00:00.000")
+            .encode("FIX.4.4")
+    }
+
+    fn app_message(seq_num: u64) -> Vec<u8> {
+        venue_message(FixMessage::new(msg_type::EXECUTION_REPORT), seq_num)
+    }
+
+    fn sent_messages(actions: &[SessionAction]) -> Vec<FixMessage> {
+        actions
+            .iter()
+            .filter_map(|action| match action {
+                SessionAction::Send(data) => Some(FixMessage::decode(data).unwrap().1),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn delivered_seq_nums(actions: &[SessionAction]) -> Vec<u64> {
+        actions
+            .iter()
+            .filter_map(|action| match action {
+                SessionAction::Deliver(message) => Some(message.seq_num().unwrap()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[fixture]
+    fn session() -> FixSession<MemorySequenceStore> {
+        let config = FixSessionConfig::new(FixVersion::Fix44, "CLIENT", "VENUE", 30);
+        FixSession::new(config, MemorySequenceStore::default())
+    }
+
+    #[fixture]
+    fn active_session(
+        mut session: FixSession<MemorySequenceStore>,
+    ) -> FixSession<MemorySequenceStore> {
+        session.logon(UnixNanos::from(0)).unwrap();
+        let logon = venue_message(
+            FixMessage::new(msg_type::LOGON).with_field(TAG_HEART_BT_INT, 30),
+            1,
+        );
+        session.on_message(&logon, UnixNanos::from(0)).unwrap();
+        session
+    }
+
+    #[rstest]
+    fn test_logon_handshake(mut session: FixSession<MemorySequenceStore>) {
+        let session_config = session
+            .config()
+            .clone()
+            .with_credentials("user", "pass")
+            .with_reset_on_logon(true);
+        session = FixSession::new(session_config, MemorySequenceStore::default());
+
+        let (begin_string, logon) = FixMessage::decode(&session.logon(0.into()).unwrap()).unwrap();
+        let actions = session
+            .on_message(
+                &venue_message(FixMessage::new(msg_type::LOGON), 1),
+                0.into(),
+            )
+            .unwrap();
+
+        assert_eq!(begin_string, "FIX.4.4");
+        assert_eq!(logon.msg_type(), msg_type::LOGON);
+        assert_eq!(logon.seq_num().unwrap(), 1);
+        assert_eq!(logon.get(TAG_HEART_BT_INT), Some("30"));
+        assert_eq!(logon.get(TAG_RESET_SEQ_NUM_FLAG), Some("Y"));
+        assert_eq!(logon.get(TAG_USERNAME), Some("user"));
+        assert_eq!(actions, vec![SessionAction::LoggedOn]);
+        assert_eq!(session.status(), SessionStatus::Active);
+        assert_eq!(session.store().next_target_seq_num(), 2);
+    }
+
+    #[rstest]
+    fn test_send_app_when_not_active(mut session: FixSession<MemorySequenceStore>) {
+        let result = session.send_app(FixMessage::new(msg_type::NEW_ORDER_SINGLE), 0.into());
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_test_request_replied_with_heartbeat(
+        mut active_session: FixSession<MemorySequenceStore>,
+    ) {
+        let test_request =
+            FixMessage::new(msg_type::TEST_REQUEST).with_field(TAG_TEST_REQ_ID, "T1");
+
+        let actions = active_session
+            .on_message(&venue_message(test_request, 2), 0.into())
+            .unwrap();
+
+        let sent = sent_messages(&actions);
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].msg_type(), msg_type::HEARTBEAT);
+        assert_eq!(sent[0].get(TAG_TEST_REQ_ID), Some("T1"));
+        assert_eq!(sent[0].seq_num().unwrap(), 2);
+    }
+
+    #[rstest]
+    fn test_gap_recovered_by_resend(mut active_session: FixSession<MemorySequenceStore>) {
+        // Receive 4 while expecting 2
+        let actions = active_session
+            .on_message(&app_message(4), 0.into())
+            .unwrap();
+        let sent = sent_messages(&actions);
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].msg_type(), msg_type::RESEND_REQUEST);
+        assert_eq!(sent[0].get(TAG_BEGIN_SEQ_NO), Some("2"));
+        assert_eq!(sent[0].get(TAG_END_SEQ_NO), Some("0"));
+        assert!(delivered_seq_nums(&actions).is_empty());
+
+        // A further message in the gap does not request another resend
+        let actions = active_session
+            .on_message(&app_message(5), 0.into())
+            .unwrap();
+        assert!(actions.is_empty());
+
+        // The counterparty resends 2 and gap fills 3
+        let resent = FixMessage::new(msg_type::EXECUTION_REPORT).with_field(TAG_POSS_DUP_FLAG, "Y");
+        let mut actions = active_session
+            .on_message(&venue_message(resent, 2), 0.into())
+            .unwrap();
+        let gap_fill = FixMessage::new(msg_type::SEQUENCE_RESET)
+            .with_field(TAG_GAP_FILL_FLAG, "Y")
+            .with_field(TAG_NEW_SEQ_NO, 4);
+        actions.extend(
+            active_session
+                .on_message(&venue_message(gap_fill, 3), 0.into())
+                .unwrap(),
+        );
+
+        assert_eq!(delivered_seq_nums(&actions), vec![2, 4, 5]);
+        assert_eq!(active_session.store().next_target_seq_num(), 6);
+    }
+
+    #[rstest]
+    fn test_duplicate_ignored_and_low_seq_num_terminates(
+        mut active_session: FixSession<MemorySequenceStore>,
+    ) {
+        let duplicate =
+            FixMessage::new(msg_type::EXECUTION_REPORT).with_field(TAG_POSS_DUP_FLAG, "Y");
+
+        let duplicate_actions = active_session
+            .on_message(&venue_message(duplicate, 1), 0.into())
+            .unwrap();
+        let actions = active_session
+            .on_message(&app_message(1), 0.into())
+            .unwrap();
+
+        assert!(duplicate_actions.is_empty());
+        let sent = sent_messages(&actions);
+        assert_eq!(sent[0].msg_type(), msg_type::LOGOUT);
+        assert!(matches!(actions.last(), Some(SessionAction::Disconnect(_))));
+        assert_eq!(active_session.status(), SessionStatus::Disconnected);
+    }
+
+    #[rstest]
+    fn test_invalid_comp_ids_terminates(mut active_session: FixSession<MemorySequenceStore>) {
+        let message = FixMessage::new(msg_type::HEARTBEAT)
+            .with_field(TAG_SENDER_COMP_ID, "OTHER")
+            .with_field(TAG_TARGET_COMP_ID, "CLIENT")
+            .with_field(TAG_MSG_SEQ_NUM, 2)
+            .encode("FIX.4.4");
+
+        let actions = active_session.on_message(&message, 0.into()).unwrap();
+
+        assert!(matches!(actions.last(), Some(SessionAction::Disconnect(_))));
+    }
+
+    #[rstest]
+    fn test_resend_request_gap_fills_session_messages(
+        mut active_session: FixSession<MemorySequenceStore>,
+    ) {
+        // Sent: 1 logon, 2 order, 3 heartbeat, 4 order
+        let order = FixMessage::new(msg_type::NEW_ORDER_SINGLE).with_field(11, "O-1");
+        active_session.send_app(order.clone(), 0.into()).unwrap();
+        active_session.on_timer((30 * SECOND).into()).unwrap();
+        active_session.send_app(order, 0.into()).unwrap();
+        let resend_request = FixMessage::new(msg_type::RESEND_REQUEST)
+            .with_field(TAG_BEGIN_SEQ_NO, 1)
+            .with_field(TAG_END_SEQ_NO, 0);
+
+        let actions = active_session
+            .on_message(&venue_message(resend_request, 2), (31 * SECOND).into())
+            .unwrap();
+
+        let sent = sent_messages(&actions);
+        let summary: Vec<(&str, u64, Option<&str>)> = sent
+            .iter()
+            .map(|m| (m.msg_type(), m.seq_num().unwrap(), m.get(TAG_NEW_SEQ_NO)))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (msg_type::SEQUENCE_RESET, 1, Some("2")),
+                (msg_type::NEW_ORDER_SINGLE, 2, None),
+                (msg_type::SEQUENCE_RESET, 3, Some("4")),
+                (msg_type::NEW_ORDER_SINGLE, 4, None),
+            ]
+        );
+        assert!(sent.iter().all(|m| m.get_flag(TAG_POSS_DUP_FLAG)));
+        assert_eq!(
+            sent[1].get(TAG_ORIG_SENDING_TIME),
+            Some("19700101-00:00:00.000")
+        );
+        assert_eq!(active_session.store().next_sender_seq_num(), 5);
+    }
+
+    #[rstest]
+    fn test_resend_request_above_expected_processed_immediately(
+        mut active_session: FixSession<MemorySequenceStore>,
+    ) {
+        // Sent: 1 logon, 2 order
+        let order = FixMessage::new(msg_type::NEW_ORDER_SINGLE).with_field(11, "O-1");
+        active_session.send_app(order, 0.into()).unwrap();
+        let resend_request = FixMessage::new(msg_type::RESEND_REQUEST)
+            .with_field(TAG_BEGIN_SEQ_NO, 2)
+            .with_field(TAG_END_SEQ_NO, 0);
+
+        // Receive 4 while expecting 2
+        let actions = active_session
+            .on_message(&venue_message(resend_request, 4), 0.into())
+            .unwrap();
+
+        let sent = sent_messages(&actions);
+        let summary: Vec<(&str, u64)> = sent
+            .iter()
+            .map(|m| (m.msg_type(), m.seq_num().unwrap()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (msg_type::NEW_ORDER_SINGLE, 2),
+                (msg_type::RESEND_REQUEST, 3),
+            ]
+        );
+        assert_eq!(sent[1].get(TAG_BEGIN_SEQ_NO), Some("2"));
+        assert_eq!(active_session.store().next_target_seq_num(), 2);
+    }
+
+    #[rstest]
+    fn test_timer_heartbeat_test_request_and_timeout(
+        mut active_session: FixSession<MemorySequenceStore>,
+    ) {
+        let heartbeat = active_session.on_timer((30 * SECOND).into()).unwrap();
+        let test_request = active_session.on_timer((36 * SECOND).into()).unwrap();
+        let pending = active_session.on_timer((40 * SECOND).into()).unwrap();
+        let timeout = active_session.on_timer((72 * SECOND).into()).unwrap();
+
+        assert_eq!(sent_messages(&heartbeat)[0].msg_type(), msg_type::HEARTBEAT);
+        assert_eq!(
+            sent_messages(&test_request)[0].get(TAG_TEST_REQ_ID),
+            Some("TEST-1")
+        );
+        assert!(pending.is_empty());
+        assert_eq!(
+            timeout,
+            vec![SessionAction::Disconnect("Heartbeat timeout".to_string())]
+        );
+    }
+
+    #[rstest]
+    fn test_logout_handshake(mut active_session: FixSession<MemorySequenceStore>) {
+        let logout = active_session.logout(Some("Done"), 0.into()).unwrap();
+        let actions = active_session
+            .on_message(
+                &venue_message(FixMessage::new(msg_type::LOGOUT), 2),
+                0.into(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            FixMessage::decode(&logout).unwrap().1.get(TAG_TEXT),
+            Some("Done")
+        );
+        assert_eq!(
+            actions,
+            vec![
+                SessionAction::LoggedOut,
+                SessionAction::Disconnect("Logged out".to_string())
+            ]
+        );
+        assert_eq!(active_session.status(), SessionStatus::Disconnected);
+    }
+}
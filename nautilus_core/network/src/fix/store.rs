@@ -0,0 +1,323 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Sequence number and sent message stores for FIX sessions.
+
+use std::{
+    collections::BTreeMap,
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, Read, Write},
+    path::{Path, PathBuf},
+};
+
+/// Provides storage of the sequence numbers and sent messages of a FIX session, so that
+/// the session can be resumed after a reconnection and resend requests can be serviced.
+pub trait SequenceStore: Send {
+    /// Returns the sequence number of the next message to be sent.
+    fn next_sender_seq_num(&self) -> u64;
+
+    /// Returns the sequence number expected for the next message received.
+    fn next_target_seq_num(&self) -> u64;
+
+    /// Sets the sequence number of the next message to be sent.
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()>;
+
+    /// Sets the sequence number expected for the next message received.
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()>;
+
+    /// Stores the given encoded sent `message`.
+    fn store_message(&mut self, seq_num: u64, message: &[u8]) -> anyhow::Result<()>;
+
+    /// Returns the stored sent messages in the inclusive range `begin..=end`, in sequence
+    /// order.
+    fn get_messages(&self, begin: u64, end: u64) -> Vec<(u64, Vec<u8>)>;
+
+    /// Resets both sequence numbers to 1 and discards all stored messages.
+    fn reset(&mut self) -> anyhow::Result<()>;
+}
+
+impl<S: SequenceStore + ?Sized> SequenceStore for Box<S> {
+    fn next_sender_seq_num(&self) -> u64 {
+        (**self).next_sender_seq_num()
+    }
+
+    fn next_target_seq_num(&self) -> u64 {
+        (**self).next_target_seq_num()
+    }
+
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        (**self).set_next_sender_seq_num(seq_num)
+    }
+
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        (**self).set_next_target_seq_num(seq_num)
+    }
+
+    fn store_message(&mut self, seq_num: u64, message: &[u8]) -> anyhow::Result<()> {
+        (**self).store_message(seq_num, message)
+    }
+
+    fn get_messages(&self, begin: u64, end: u64) -> Vec<(u64, Vec<u8>)> {
+        (**self).get_messages(begin, end)
+    }
+
+    fn reset(&mut self) -> anyhow::Result<()> {
+        (**self).reset()
+    }
+}
+
+/// Provides an in-memory [`SequenceStore`], which does not survive a restart.
+#[derive(Debug)]
+pub struct MemorySequenceStore {
+    next_sender_seq_num: u64,
+    next_target_seq_num: u64,
+    messages: BTreeMap<u64, Vec<u8>>,
+}
+
+impl Default for MemorySequenceStore {
+    fn default() -> Self {
+        Self {
+            next_sender_seq_num: 1,
+            next_target_seq_num: 1,
+            messages: BTreeMap::new(),
+        }
+    }
+}
+
+impl SequenceStore for MemorySequenceStore {
+    fn next_sender_seq_num(&self) -> u64 {
+        self.next_sender_seq_num
+    }
+
+    fn next_target_seq_num(&self) -> u64 {
+        self.next_target_seq_num
+    }
+
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.next_sender_seq_num = seq_num;
+        Ok(())
+    }
+
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.next_target_seq_num = seq_num;
+        Ok(())
+    }
+
+    fn store_message(&mut self, seq_num: u64, message: &[u8]) -> anyhow::Result<()> {
+        self.messages.insert(seq_num, message.to_vec());
+        Ok(())
+    }
+
+    fn get_messages(&self, begin: u64, end: u64) -> Vec<(u64, Vec<u8>)> {
+        if begin > end {
+            return Vec::new();
+        }
+        self.messages
+            .range(begin..=end)
+            .map(|(seq_num, message)| (*seq_num, message.clone()))
+            .collect()
+    }
+
+    fn reset(&mut self) -> anyhow::Result<()> {
+        *self = Self::default();
+        Ok(())
+    }
+}
+
+/// Provides a file backed [`SequenceStore`], so that a session can be resumed after a
+/// restart without resetting sequence numbers.
+///
+/// The sequence numbers are persisted to `<session_id>.seqnums`, and sent messages are
+/// appended to `<session_id>.body` (and also held in memory to service resend requests).
+#[derive(Debug)]
+pub struct FileSequenceStore {
+    seqnums_path: PathBuf,
+    body_path: PathBuf,
+    body_file: File,
+    cache: MemorySequenceStore,
+}
+
+impl FileSequenceStore {
+    /// Opens (or creates) the store for the given `session_id` in the directory `dir`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the files cannot be created, or existing files
+    /// are corrupt.
+    pub fn open<P: AsRef<Path>>(dir: P, session_id: &str) -> anyhow::Result<Self> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        let seqnums_path = dir.join(format!("{session_id}.seqnums"));
+        let body_path = dir.join(format!("{session_id}.body"));
+
+        let mut cache = MemorySequenceStore::default();
+        if seqnums_path.exists() {
+            let contents = fs::read_to_string(&seqnums_path)?;
+            let (sender, target) = contents
+                .trim()
+                .split_once(':')
+                .ok_or_else(|| anyhow::anyhow!("Invalid sequence numbers '{contents}'"))?;
+            cache.next_sender_seq_num = sender.parse()?;
+            cache.next_target_seq_num = target.parse()?;
+        }
+        if body_path.exists() {
+            let mut reader = BufReader::new(File::open(&body_path)?);
+            let mut header = String::new();
+            while reader.read_line(&mut header)? > 0 {
+                let (seq_num, len) = header
+                    .trim_end()
+                    .split_once(',')
+                    .ok_or_else(|| anyhow::anyhow!("Invalid message header '{header}'"))?;
+                let mut message = vec![0; len.parse()?];
+                reader.read_exact(&mut message)?;
+                cache.messages.insert(seq_num.parse()?, message);
+                header.clear();
+            }
+        }
+
+        let body_file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&body_path)?;
+
+        Ok(Self {
+            seqnums_path,
+            body_path,
+            body_file,
+            cache,
+        })
+    }
+
+    fn persist_seqnums(&self) -> anyhow::Result<()> {
+        fs::write(
+            &self.seqnums_path,
+            format!(
+                "{}:{}",
+                self.cache.next_sender_seq_num, self.cache.next_target_seq_num
+            ),
+        )?;
+        Ok(())
+    }
+}
+
+impl SequenceStore for FileSequenceStore {
+    fn next_sender_seq_num(&self) -> u64 {
+        self.cache.next_sender_seq_num()
+    }
+
+    fn next_target_seq_num(&self) -> u64 {
+        self.cache.next_target_seq_num()
+    }
+
+    fn set_next_sender_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.cache.set_next_sender_seq_num(seq_num)?;
+        self.persist_seqnums()
+    }
+
+    fn set_next_target_seq_num(&mut self, seq_num: u64) -> anyhow::Result<()> {
+        self.cache.set_next_target_seq_num(seq_num)?;
+        self.persist_seqnums()
+    }
+
+    fn store_message(&mut self, seq_num: u64, message: &[u8]) -> anyhow::Result<()> {
+        self.body_file
+            .write_all(format!("{seq_num},{}\n", message.len()).as_bytes())?;
+        self.body_file.write_all(message)?;
+        self.body_file.flush()?;
+        self.cache.store_message(seq_num, message)
+    }
+
+    fn get_messages(&self, begin: u64, end: u64) -> Vec<(u64, Vec<u8>)> {
+        self.cache.get_messages(begin, end)
+    }
+
+    fn reset(&mut self) -> anyhow::Result<()> {
+        self.cache.reset()?;
+        self.body_file = File::create(&self.body_path)?;
+        self.persist_seqnums()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[rstest]
+    fn test_memory_store_get_messages_range() {
+        let mut store = MemorySequenceStore::default();
+        for seq_num in 1..=5 {
+            store
+                .store_message(seq_num, format!("msg{seq_num}").as_bytes())
+                .unwrap();
+        }
+
+        let messages = store.get_messages(2, 4);
+
+        assert_eq!(
+            messages,
+            vec![
+                (2, b"msg2".to_vec()),
+                (3, b"msg3".to_vec()),
+                (4, b"msg4".to_vec())
+            ]
+        );
+        assert!(store.get_messages(4, 2).is_empty());
+    }
+
+    #[rstest]
+    fn test_file_store_reopen_restores_state() {
+        let dir = tempdir().unwrap();
+        {
+            let mut store = FileSequenceStore::open(dir.path(), "FIX.4.4-A-B").unwrap();
+            store.store_message(1, b"8=FIX.4.4\x019=5\x01").unwrap();
+            store.store_message(2, b"second\nline").unwrap();
+            store.set_next_sender_seq_num(3).unwrap();
+            store.set_next_target_seq_num(7).unwrap();
+        }
+
+        let store = FileSequenceStore::open(dir.path(), "FIX.4.4-A-B").unwrap();
+
+        assert_eq!(store.next_sender_seq_num(), 3);
+        assert_eq!(store.next_target_seq_num(), 7);
+        assert_eq!(
+            store.get_messages(1, 2),
+            vec![
+                (1, b"8=FIX.4.4\x019=5\x01".to_vec()),
+                (2, b"second\nline".to_vec())
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_file_store_reset() {
+        let dir = tempdir().unwrap();
+        let mut store = FileSequenceStore::open(dir.path(), "session").unwrap();
+        store.store_message(1, b"message").unwrap();
+        store.set_next_sender_seq_num(2).unwrap();
+
+        store.reset().unwrap();
+        let reopened = FileSequenceStore::open(dir.path(), "session").unwrap();
+
+        assert_eq!(reopened.next_sender_seq_num(), 1);
+        assert_eq!(reopened.next_target_seq_num(), 1);
+        assert!(reopened.get_messages(1, 10).is_empty());
+    }
+}
@@ -19,17 +19,31 @@ use std::{
     collections::{hash_map::DefaultHasher, HashMap},
     hash::{Hash, Hasher},
     sync::Arc,
+    time::Duration,
 };
 
-use futures_util::{stream, StreamExt};
-use pyo3::{exceptions::PyException, prelude::*, types::PyBytes};
+use futures_util::{stream::BoxStream, StreamExt, TryStreamExt};
+use nautilus_core::{
+    python::{to_pyruntime_err, to_pyvalue_err},
+    uuid::UUID4,
+};
+use pyo3::{
+    exceptions::{PyException, PyStopAsyncIteration},
+    prelude::*,
+    types::PyBytes,
+};
 use reqwest::{
     header::{HeaderMap, HeaderName},
     Method, Response, Url,
 };
-use tracing::trace;
+use tokio::{sync::Mutex, time::sleep};
+use tracing::{trace, warn};
 
-use crate::ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter};
+use crate::{
+    backoff::ExponentialBackoff,
+    proxy::{resolve_proxy, ProxyConfig},
+    ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter},
+};
 
 /// A high-performance `HttpClient` for HTTP requests.
 ///
@@ -43,16 +57,61 @@ use crate::ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter};
 pub struct InnerHttpClient {
     client: reqwest::Client,
     header_keys: Vec<String>,
+    retry_policy: Option<HttpRetryPolicy>,
 }
 
 impl InnerHttpClient {
+    /// Sends an HTTP request and buffers the full response body.
+    ///
+    /// The request is retried according to the client's retry policy (if any), and fails
+    /// if not completed within the optional `timeout`.
     pub async fn send_request(
         &self,
         method: Method,
         url: String,
         headers: HashMap<String, String>,
         body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
     ) -> Result<HttpResponse, Box<dyn std::error::Error + Send + Sync>> {
+        let response = self
+            .execute_with_retry(method, url, headers, body, timeout)
+            .await?;
+        self.to_response(response).await
+    }
+
+    /// Sends an HTTP request and returns the response body as a stream of chunks, rather
+    /// than buffering it (e.g. for large downloads).
+    ///
+    /// Retries only apply until the response headers are received. Note the optional
+    /// `timeout` applies to the full request, including reading the body.
+    pub async fn send_request_stream(
+        &self,
+        method: Method,
+        url: String,
+        headers: HashMap<String, String>,
+        body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
+    ) -> Result<HttpStreamResponse, Box<dyn std::error::Error + Send + Sync>> {
+        let response = self
+            .execute_with_retry(method, url, headers, body, timeout)
+            .await?;
+        trace!("{response:?}");
+
+        Ok(HttpStreamResponse {
+            status: response.status().as_u16(),
+            headers: self.extract_headers(&response),
+            stream: response.bytes_stream().map_ok(|b| b.to_vec()).boxed(),
+        })
+    }
+
+    async fn execute_with_retry(
+        &self,
+        method: Method,
+        url: String,
+        headers: HashMap<String, String>,
+        body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
+    ) -> Result<Response, Box<dyn std::error::Error + Send + Sync>> {
         let reqwest_url = Url::parse(url.as_str())?;
 
         let mut header_map = HeaderMap::new();
@@ -61,17 +120,71 @@ impl InnerHttpClient {
             let _ = header_map.insert(key, header_value.parse().unwrap());
         }
 
-        let request_builder = self.client.request(method, reqwest_url).headers(header_map);
-
-        let request = match body {
-            Some(b) => request_builder.body(b).build()?,
-            None => request_builder.build()?,
+        let mut backoff = match &self.retry_policy {
+            Some(policy) if policy.is_retryable(&method) => {
+                if let Some(header) = &policy.idempotency_header {
+                    // The same key is sent with every attempt so the server can deduplicate
+                    let key = HeaderName::from_bytes(header.as_bytes())?;
+                    if !header_map.contains_key(&key) {
+                        header_map.insert(key, UUID4::new().to_string().parse()?);
+                    }
+                }
+                Some(policy.backoff()?)
+            }
+            _ => None,
         };
 
-        trace!("{request:?}");
+        loop {
+            let mut request_builder = self
+                .client
+                .request(method.clone(), reqwest_url.clone())
+                .headers(header_map.clone());
+            if let Some(timeout) = timeout {
+                request_builder = request_builder.timeout(timeout);
+            }
+            if let Some(b) = &body {
+                request_builder = request_builder.body(b.clone());
+            }
+            let request = request_builder.build()?;
+
+            trace!("{request:?}");
+
+            let result = self.client.execute(request).await;
+            let should_retry = self
+                .retry_policy
+                .as_ref()
+                .map_or(false, |policy| match &result {
+                    Ok(response) => policy
+                        .retry_status_codes
+                        .contains(&response.status().as_u16()),
+                    Err(e) => policy.retry_on_connect_error && (e.is_connect() || e.is_timeout()),
+                });
+
+            if should_retry {
+                if let Some(delay) = backoff.as_mut().and_then(ExponentialBackoff::next_delay) {
+                    match &result {
+                        Ok(response) => warn!(
+                            "Retrying {method} {url} in {delay:?}, status {}",
+                            response.status()
+                        ),
+                        Err(e) => warn!("Retrying {method} {url} in {delay:?}: {e}"),
+                    }
+                    sleep(delay).await;
+                    continue;
+                }
+            }
 
-        let response = self.client.execute(request).await?;
-        self.to_response(response).await
+            return Ok(result?);
+        }
+    }
+
+    fn extract_headers(&self, response: &Response) -> HashMap<String, String> {
+        self.header_keys
+            .iter()
+            .filter_map(|key| response.headers().get(key).map(|val| (key, val)))
+            .filter_map(|(key, val)| val.to_str().map(|v| (key, v)).ok())
+            .map(|(k, v)| (k.clone(), v.to_owned()))
+            .collect()
     }
 
     pub async fn to_response(
@@ -80,13 +193,7 @@ impl InnerHttpClient {
     ) -> Result<HttpResponse, Box<dyn std::error::Error + Send + Sync>> {
         trace!("{response:?}");
 
-        let headers: HashMap<String, String> = self
-            .header_keys
-            .iter()
-            .filter_map(|key| response.headers().get(key).map(|val| (key, val)))
-            .filter_map(|(key, val)| val.to_str().map(|v| (key, v)).ok())
-            .map(|(k, v)| (k.clone(), v.to_owned()))
-            .collect();
+        let headers = self.extract_headers(&response);
         let status = response.status().as_u16();
         let bytes = response.bytes().await?;
 
@@ -98,6 +205,154 @@ impl InnerHttpClient {
     }
 }
 
+/// The retry policy for HTTP requests.
+///
+/// Requests with idempotent methods are retried when failing to connect (or timing out), or
+/// on a retryable response status, with exponential backoff between attempts. Requests with
+/// non-idempotent methods (e.g. POST) are only retried if an idempotency key header is set.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct HttpRetryPolicy {
+    /// The maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+    /// The response status codes which are retried.
+    pub retry_status_codes: Vec<u16>,
+    /// If requests which fail to connect or time out are retried.
+    pub retry_on_connect_error: bool,
+    /// The delay before the first retry.
+    pub delay_initial: Duration,
+    /// The maximum delay between retries.
+    pub delay_max: Duration,
+    /// The factor the delay grows by after each retry.
+    pub backoff_factor: f64,
+    /// The header for a key generated once per request and sent with every attempt.
+    pub idempotency_header: Option<String>,
+}
+
+impl Default for HttpRetryPolicy {
+    /// Creates a new default [`HttpRetryPolicy`] instance.
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            retry_status_codes: vec![429, 500, 502, 503, 504],
+            retry_on_connect_error: true,
+            delay_initial: Duration::from_millis(100),
+            delay_max: Duration::from_secs(5),
+            backoff_factor: 2.0,
+            idempotency_header: None,
+        }
+    }
+}
+
+impl HttpRetryPolicy {
+    /// Returns whether requests with the given `method` can be retried under the policy.
+    #[must_use]
+    pub fn is_retryable(&self, method: &Method) -> bool {
+        method.is_idempotent() || self.idempotency_header.is_some()
+    }
+
+    /// Returns a new exponential backoff for the retries of a single request.
+    pub fn backoff(&self) -> anyhow::Result<ExponentialBackoff> {
+        ExponentialBackoff::new(
+            self.delay_initial,
+            self.delay_max,
+            self.backoff_factor,
+            Some(self.max_retries),
+        )
+    }
+}
+
+#[pymethods]
+impl HttpRetryPolicy {
+    #[new]
+    #[pyo3(signature = (
+        max_retries = 3,
+        retry_status_codes = None,
+        retry_on_connect_error = true,
+        delay_initial_ms = 100,
+        delay_max_ms = 5_000,
+        backoff_factor = 2.0,
+        idempotency_header = None,
+    ))]
+    fn py_new(
+        max_retries: u32,
+        retry_status_codes: Option<Vec<u16>>,
+        retry_on_connect_error: bool,
+        delay_initial_ms: u64,
+        delay_max_ms: u64,
+        backoff_factor: f64,
+        idempotency_header: Option<String>,
+    ) -> PyResult<Self> {
+        let policy = Self {
+            max_retries,
+            retry_status_codes: retry_status_codes
+                .unwrap_or_else(|| Self::default().retry_status_codes),
+            retry_on_connect_error,
+            delay_initial: Duration::from_millis(delay_initial_ms),
+            delay_max: Duration::from_millis(delay_max_ms),
+            backoff_factor,
+            idempotency_header,
+        };
+        policy.backoff().map_err(to_pyvalue_err)?;
+        Ok(policy)
+    }
+}
+
+/// Represents an HTTP response with a streamed body.
+pub struct HttpStreamResponse {
+    pub status: u16,
+    pub headers: HashMap<String, String>,
+    pub stream: BoxStream<'static, Result<Vec<u8>, reqwest::Error>>,
+}
+
+/// Provides an async iterator over the body chunks of a streamed HTTP response.
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct HttpResponseStream {
+    #[pyo3(get)]
+    pub status: u16,
+    #[pyo3(get)]
+    headers: HashMap<String, String>,
+    stream: Arc<Mutex<BoxStream<'static, Result<Vec<u8>, reqwest::Error>>>>,
+}
+
+impl From<HttpStreamResponse> for HttpResponseStream {
+    fn from(value: HttpStreamResponse) -> Self {
+        Self {
+            status: value.status,
+            headers: value.headers,
+            stream: Arc::new(Mutex::new(value.stream)),
+        }
+    }
+}
+
+#[pymethods]
+impl HttpResponseStream {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        let stream = self.stream.clone();
+        let future = pyo3_asyncio::tokio::future_into_py(py, async move {
+            match stream.lock().await.next().await {
+                Some(Ok(chunk)) => Ok(Python::with_gil(|py| {
+                    let chunk: PyObject = PyBytes::new(py, &chunk).into_py(py);
+                    chunk
+                })),
+                Some(Err(e)) => Err(to_pyruntime_err(e)),
+                None => Err(PyStopAsyncIteration::new_err("Stream exhausted")),
+            }
+        })?;
+        Ok(Some(future.into()))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(
     feature = "python",
@@ -154,6 +409,7 @@ impl Default for InnerHttpClient {
         Self {
             client,
             header_keys: Default::default(),
+            retry_policy: None,
         }
     }
 }
@@ -184,34 +440,123 @@ pub struct HttpClient {
     client: InnerHttpClient,
 }
 
-#[pymethods]
 impl HttpClient {
-    /// Create a new HttpClient.
+    /// Creates a new [`HttpClient`] instance.
     ///
-    /// * `header_keys` - The key value pairs for the given `header_keys` are retained from the responses.
-    /// * `keyed_quota` - A list of string quota pairs that gives quota for specific key values.
-    /// * `default_quota` - The default rate limiting quota for any request.
-    /// Default quota is optional and no quota is passthrough.
-    #[new]
-    #[pyo3(signature = (header_keys = Vec::new(), keyed_quotas = Vec::new(), default_quota = None))]
-    #[must_use]
-    pub fn py_new(
+    /// Requests are rate limited per key (e.g. per route or endpoint) using the `keyed_quotas`,
+    /// falling back to the `default_quota` for keys without a specific quota. Failed requests
+    /// are retried according to the optional `retry_policy`.
+    ///
+    /// Requests are sent through the `proxy` if given, otherwise through the default proxy
+    /// (if set).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the proxy is rejected or the client fails to build.
+    pub fn new(
         header_keys: Vec<String>,
         keyed_quotas: Vec<(String, Quota)>,
         default_quota: Option<Quota>,
-    ) -> Self {
-        let client = reqwest::Client::new();
+        retry_policy: Option<HttpRetryPolicy>,
+        proxy: Option<ProxyConfig>,
+    ) -> anyhow::Result<Self> {
+        let mut builder = reqwest::Client::builder();
+        if let Some(proxy) = resolve_proxy(proxy.as_ref()) {
+            builder = builder.proxy(proxy.to_reqwest()?);
+        }
+        let client = builder.build()?;
         let rate_limiter = Arc::new(RateLimiter::new_with_quota(default_quota, keyed_quotas));
 
         let client = InnerHttpClient {
             client,
             header_keys,
+            retry_policy,
         };
 
-        Self {
+        Ok(Self {
             rate_limiter,
             client,
+        })
+    }
+
+    /// Sends an HTTP request once the quota for each of the rate limiting `keys` allows it.
+    ///
+    /// Each key is charged the `weight` of the request, and the request is queued (not rejected)
+    /// until all of the keys have capacity.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn request(
+        &self,
+        method: Method,
+        url: String,
+        headers: HashMap<String, String>,
+        body: Option<Vec<u8>>,
+        keys: &[String],
+        weight: u32,
+        timeout: Option<Duration>,
+    ) -> Result<HttpResponse, Box<dyn std::error::Error + Send + Sync>> {
+        for key in keys {
+            self.rate_limiter.until_key_ready_n(key, weight).await;
         }
+        self.client
+            .send_request(method, url, headers, body, timeout)
+            .await
+    }
+
+    /// Sends an HTTP request once the quota for each of the rate limiting `keys` allows it,
+    /// returning the response body as a stream of chunks.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn stream(
+        &self,
+        method: Method,
+        url: String,
+        headers: HashMap<String, String>,
+        body: Option<Vec<u8>>,
+        keys: &[String],
+        weight: u32,
+        timeout: Option<Duration>,
+    ) -> Result<HttpStreamResponse, Box<dyn std::error::Error + Send + Sync>> {
+        for key in keys {
+            self.rate_limiter.until_key_ready_n(key, weight).await;
+        }
+        self.client
+            .send_request_stream(method, url, headers, body, timeout)
+            .await
+    }
+}
+
+#[pymethods]
+impl HttpClient {
+    /// Create a new HttpClient.
+    ///
+    /// * `header_keys` - The key value pairs for the given `header_keys` are retained from the responses.
+    /// * `keyed_quota` - A list of string quota pairs that gives quota for specific key values.
+    /// * `default_quota` - The default rate limiting quota for any request.
+    /// Default quota is optional and no quota is passthrough.
+    /// * `retry_policy` - The retry policy for failed requests (no retries if `None`).
+    /// * `proxy` - The proxy for requests (the default proxy if `None`).
+    #[new]
+    #[pyo3(signature = (
+        header_keys = Vec::new(),
+        keyed_quotas = Vec::new(),
+        default_quota = None,
+        retry_policy = None,
+        proxy = None,
+    ))]
+    pub fn py_new(
+        header_keys: Vec<String>,
+        keyed_quotas: Vec<(String, Quota)>,
+        default_quota: Option<Quota>,
+        retry_policy: Option<HttpRetryPolicy>,
+        proxy: Option<ProxyConfig>,
+    ) -> PyResult<Self> {
+        Self::new(
+            header_keys,
+            keyed_quotas,
+            default_quota,
+            retry_policy,
+            proxy,
+        )
+        .map_err(to_pyvalue_err)
     }
 
     /// Send an HTTP request.
@@ -221,7 +566,10 @@ impl HttpClient {
     /// * `headers` - The header key value pairs in the request.
     /// * `body` - The bytes sent in the body of request.
     /// * `keys` - The keys used for rate limiting the request.
+    /// * `weight` - The number of quota cells the request consumes for each key (default 1).
+    /// * `timeout_secs` - The timeout for the request (no timeout if `None`).
     #[pyo3(name = "request")]
+    #[allow(clippy::too_many_arguments)]
     fn py_request<'py>(
         &self,
         method: HttpMethod,
@@ -229,23 +577,27 @@ impl HttpClient {
         headers: Option<HashMap<String, String>>,
         body: Option<&'py PyBytes>,
         keys: Option<Vec<String>>,
+        weight: Option<u32>,
+        timeout_secs: Option<u64>,
         py: Python<'py>,
     ) -> PyResult<&'py PyAny> {
         let headers = headers.unwrap_or_default();
         let body_vec = body.map(|py_bytes| py_bytes.as_bytes().to_vec());
         let keys = keys.unwrap_or_default();
+        let weight = weight.unwrap_or(1);
+        let timeout = timeout_secs.map(Duration::from_secs);
         let client = self.client.clone();
         let rate_limiter = self.rate_limiter.clone();
         let method = method.into();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            // Check keys for rate limiting quota
-            let tasks = keys.iter().map(|key| rate_limiter.until_key_ready(key));
-            stream::iter(tasks)
-                .for_each(|key| async move {
-                    key.await;
-                })
-                .await;
-            match client.send_request(method, url, headers, body_vec).await {
+            // Queue the request until each key has quota for its weight
+            for key in &keys {
+                rate_limiter.until_key_ready_n(key, weight).await;
+            }
+            match client
+                .send_request(method, url, headers, body_vec, timeout)
+                .await
+            {
                 Ok(res) => Ok(res),
                 Err(e) => Err(PyErr::new::<PyException, _>(format!(
                     "Error handling response: {e}"
@@ -253,6 +605,47 @@ impl HttpClient {
             }
         })
     }
+
+    /// Send an HTTP request, streaming the response body.
+    ///
+    /// The parameters are as for `request`, with the returned response providing an async
+    /// iterator over the body chunks (rather than buffering the full body).
+    #[pyo3(name = "stream")]
+    #[allow(clippy::too_many_arguments)]
+    fn py_stream<'py>(
+        &self,
+        method: HttpMethod,
+        url: String,
+        headers: Option<HashMap<String, String>>,
+        body: Option<&'py PyBytes>,
+        keys: Option<Vec<String>>,
+        weight: Option<u32>,
+        timeout_secs: Option<u64>,
+        py: Python<'py>,
+    ) -> PyResult<&'py PyAny> {
+        let headers = headers.unwrap_or_default();
+        let body_vec = body.map(|py_bytes| py_bytes.as_bytes().to_vec());
+        let keys = keys.unwrap_or_default();
+        let weight = weight.unwrap_or(1);
+        let timeout = timeout_secs.map(Duration::from_secs);
+        let client = self.client.clone();
+        let rate_limiter = self.rate_limiter.clone();
+        let method = method.into();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            for key in &keys {
+                rate_limiter.until_key_ready_n(key, weight).await;
+            }
+            match client
+                .send_request_stream(method, url, headers, body_vec, timeout)
+                .await
+            {
+                Ok(res) => Ok(HttpResponseStream::from(res)),
+                Err(e) => Err(PyErr::new::<PyException, _>(format!(
+                    "Error handling response: {e}"
+                ))),
+            }
+        })
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -260,7 +653,10 @@ impl HttpClient {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::net::{SocketAddr, TcpListener};
+    use std::{
+        net::{SocketAddr, TcpListener},
+        sync::atomic::{AtomicUsize, Ordering},
+    };
 
     use axum::{
         routing::{delete, get, patch, post},
@@ -291,6 +687,12 @@ mod tests {
     }
 
     async fn start_test_server() -> Result<SocketAddr, Box<dyn std::error::Error + Send + Sync>> {
+        start_test_server_with_router(create_router()).await
+    }
+
+    async fn start_test_server_with_router(
+        router: Router,
+    ) -> Result<SocketAddr, Box<dyn std::error::Error + Send + Sync>> {
         let port = get_unique_port();
         let listener = tokio::net::TcpListener::bind(format!("127.0.0.1:{port}"))
             .await
@@ -298,7 +700,7 @@ mod tests {
         let addr = listener.local_addr().unwrap();
 
         tokio::spawn(async move {
-            serve(listener, create_router()).await.unwrap();
+            serve(listener, router).await.unwrap();
         });
 
         Ok(addr)
@@ -316,6 +718,7 @@ mod tests {
                 format!("{url}/get"),
                 HashMap::new(),
                 None,
+                None,
             )
             .await
             .unwrap();
@@ -336,6 +739,7 @@ mod tests {
                 format!("{url}/post"),
                 HashMap::new(),
                 None,
+                None,
             )
             .await
             .unwrap();
@@ -369,6 +773,7 @@ mod tests {
                 format!("{url}/post"),
                 HashMap::new(),
                 Some(body_bytes),
+                None,
             )
             .await
             .unwrap();
@@ -388,6 +793,7 @@ mod tests {
                 format!("{url}/patch"),
                 HashMap::new(),
                 None,
+                None,
             )
             .await
             .unwrap();
@@ -407,10 +813,113 @@ mod tests {
                 format!("{url}/delete"),
                 HashMap::new(),
                 None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_status() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let counter = attempts.clone();
+        let router = Router::new().route(
+            "/flaky",
+            get(move || {
+                let counter = counter.clone();
+                async move {
+                    if counter.fetch_add(1, Ordering::SeqCst) < 2 {
+                        StatusCode::SERVICE_UNAVAILABLE
+                    } else {
+                        StatusCode::OK
+                    }
+                }
+            }),
+        );
+        let addr = start_test_server_with_router(router).await.unwrap();
+
+        let client = InnerHttpClient {
+            client: reqwest::Client::new(),
+            header_keys: Vec::new(),
+            retry_policy: Some(HttpRetryPolicy {
+                delay_initial: Duration::from_millis(1),
+                ..Default::default()
+            }),
+        };
+        let response = client
+            .send_request(
+                reqwest::Method::GET,
+                format!("http://{addr}/flaky"),
+                HashMap::new(),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_for_post_without_idempotency_header() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let counter = attempts.clone();
+        let router = Router::new().route(
+            "/flaky",
+            post(move || {
+                let counter = counter.clone();
+                async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    StatusCode::SERVICE_UNAVAILABLE
+                }
+            }),
+        );
+        let addr = start_test_server_with_router(router).await.unwrap();
+
+        let client = InnerHttpClient {
+            client: reqwest::Client::new(),
+            header_keys: Vec::new(),
+            retry_policy: Some(HttpRetryPolicy {
+                delay_initial: Duration::from_millis(1),
+                ..Default::default()
+            }),
+        };
+        let response = client
+            .send_request(
+                reqwest::Method::POST,
+                format!("http://{addr}/flaky"),
+                HashMap::new(),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_stream() {
+        let addr = start_test_server().await.unwrap();
+
+        let client = InnerHttpClient::default();
+        let response = client
+            .send_request_stream(
+                reqwest::Method::GET,
+                format!("http://{addr}/get"),
+                HashMap::new(),
+                None,
+                None,
             )
             .await
             .unwrap();
 
         assert_eq!(response.status, StatusCode::OK);
+        let body: Vec<u8> = response.stream.try_concat().await.unwrap();
+        assert_eq!(String::from_utf8_lossy(&body), "hello-world!");
     }
 }
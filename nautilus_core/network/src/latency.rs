@@ -0,0 +1,424 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Latency injection for network clients, so integration tests can reproduce venue latency
+//! conditions without an external network shaper.
+//!
+//! Delays are sampled with nanosecond resolution from a configured distribution using a
+//! seeded generator, so a test run is deterministic for a given seed. Each frame is delivered
+//! no earlier than the frame before it, preserving the ordering of the underlying stream.
+
+use std::{
+    fmt::Display,
+    future::Future,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use nautilus_core::python::to_pyvalue_err;
+use pyo3::prelude::*;
+use tokio::{
+    sync::mpsc::{unbounded_channel, UnboundedSender},
+    task,
+    time::{sleep_until, Instant},
+};
+
+/// Represents a distribution of latencies (nanoseconds).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LatencyDistribution {
+    /// A constant delay.
+    Fixed { delay_ns: u64 },
+    /// A delay uniformly distributed in the range [`min_ns`, `max_ns`].
+    Uniform { min_ns: u64, max_ns: u64 },
+    /// A normally distributed delay, truncated at zero.
+    Normal { mean_ns: u64, std_dev_ns: u64 },
+    /// An exponentially distributed delay, modelling occasional long tail spikes.
+    Exponential { mean_ns: u64 },
+}
+
+impl LatencyDistribution {
+    /// Returns a delay sampled from the distribution, using the `rng` state.
+    fn sample(&self, rng: &mut u64) -> Duration {
+        let nanos = match *self {
+            Self::Fixed { delay_ns } => delay_ns as f64,
+            Self::Uniform { min_ns, max_ns } => {
+                ((max_ns - min_ns) as f64).mul_add(next_f64(rng), min_ns as f64)
+            }
+            Self::Normal {
+                mean_ns,
+                std_dev_ns,
+            } => {
+                // Box-Muller transform, using (0, 1] to avoid the logarithm of zero
+                let u1 = 1.0 - next_f64(rng);
+                let u2 = next_f64(rng);
+                let z = (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos();
+                (std_dev_ns as f64).mul_add(z, mean_ns as f64).max(0.0)
+            }
+            Self::Exponential { mean_ns } => -(mean_ns as f64) * (1.0 - next_f64(rng)).ln(),
+        };
+        Duration::from_nanos(nanos.round() as u64)
+    }
+}
+
+impl FromStr for LatencyDistribution {
+    type Err = anyhow::Error;
+
+    /// Parses a distribution from a string, such as `fixed:500000`, `uniform:100000,900000`,
+    /// `normal:500000,50000` or `exponential:500000` (all values in nanoseconds).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, params) = s
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("Invalid latency distribution, was '{s}'"))?;
+        let params: Vec<u64> = params
+            .split(',')
+            .map(|p| p.trim().parse::<u64>())
+            .collect::<Result<_, _>>()
+            .map_err(|e| anyhow::anyhow!("Invalid latency distribution '{s}': {e}"))?;
+
+        match (kind.trim(), params.as_slice()) {
+            ("fixed", [delay_ns]) => Ok(Self::Fixed {
+                delay_ns: *delay_ns,
+            }),
+            ("uniform", [min_ns, max_ns]) if min_ns <= max_ns => Ok(Self::Uniform {
+                min_ns: *min_ns,
+                max_ns: *max_ns,
+            }),
+            ("normal", [mean_ns, std_dev_ns]) => Ok(Self::Normal {
+                mean_ns: *mean_ns,
+                std_dev_ns: *std_dev_ns,
+            }),
+            ("exponential", [mean_ns]) => Ok(Self::Exponential { mean_ns: *mean_ns }),
+            _ => anyhow::bail!("Invalid latency distribution, was '{s}'"),
+        }
+    }
+}
+
+impl Display for LatencyDistribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fixed { delay_ns } => write!(f, "fixed:{delay_ns}"),
+            Self::Uniform { min_ns, max_ns } => write!(f, "uniform:{min_ns},{max_ns}"),
+            Self::Normal {
+                mean_ns,
+                std_dev_ns,
+            } => write!(f, "normal:{mean_ns},{std_dev_ns}"),
+            Self::Exponential { mean_ns } => write!(f, "exponential:{mean_ns}"),
+        }
+    }
+}
+
+/// Configuration for the latency injected on the inbound and outbound frames of a client.
+///
+/// This is intended for testing only, frames being held in memory for the sampled delay.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct LatencyConfig {
+    /// The distribution of delays for received frames (no delay if `None`).
+    pub inbound: Option<LatencyDistribution>,
+    /// The distribution of delays for sent frames (no delay if `None`).
+    pub outbound: Option<LatencyDistribution>,
+    /// The seed for the random delay generator.
+    pub seed: u64,
+}
+
+impl Default for LatencyConfig {
+    /// Creates a new default [`LatencyConfig`] instance which injects no latency.
+    fn default() -> Self {
+        Self {
+            inbound: None,
+            outbound: None,
+            seed: 42,
+        }
+    }
+}
+
+impl LatencyConfig {
+    /// Returns a new injector for the inbound frames (if configured).
+    #[must_use]
+    pub fn inbound_injector(&self) -> Option<LatencyInjector> {
+        self.inbound
+            .map(|distribution| LatencyInjector::new(distribution, self.seed))
+    }
+
+    /// Returns a new injector for the outbound frames (if configured).
+    ///
+    /// The seed is offset from the inbound seed so the two directions are independent.
+    #[must_use]
+    pub fn outbound_injector(&self) -> Option<LatencyInjector> {
+        self.outbound
+            .map(|distribution| LatencyInjector::new(distribution, !self.seed))
+    }
+}
+
+#[pymethods]
+impl LatencyConfig {
+    #[new]
+    #[pyo3(signature = (inbound=None, outbound=None, seed=42))]
+    fn py_new(inbound: Option<&str>, outbound: Option<&str>, seed: u64) -> PyResult<Self> {
+        Ok(Self {
+            inbound: inbound
+                .map(LatencyDistribution::from_str)
+                .transpose()
+                .map_err(to_pyvalue_err)?,
+            outbound: outbound
+                .map(LatencyDistribution::from_str)
+                .transpose()
+                .map_err(to_pyvalue_err)?,
+            seed,
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        let fmt = |d: Option<LatencyDistribution>| d.map_or("None".to_string(), |d| d.to_string());
+        format!(
+            "{}(inbound={}, outbound={}, seed={})",
+            stringify!(LatencyConfig),
+            fmt(self.inbound),
+            fmt(self.outbound),
+            self.seed,
+        )
+    }
+}
+
+#[derive(Debug)]
+struct InjectorState {
+    rng: u64,
+    last_due: Option<Instant>,
+}
+
+/// Samples delays for a stream of frames, with due times which never precede the due time
+/// of the previous frame.
+#[derive(Clone, Debug)]
+pub struct LatencyInjector {
+    distribution: LatencyDistribution,
+    state: Arc<Mutex<InjectorState>>,
+}
+
+impl LatencyInjector {
+    /// Creates a new [`LatencyInjector`] instance.
+    #[must_use]
+    pub fn new(distribution: LatencyDistribution, seed: u64) -> Self {
+        Self {
+            distribution,
+            state: Arc::new(Mutex::new(InjectorState {
+                rng: seed,
+                last_due: None,
+            })),
+        }
+    }
+
+    /// Returns the time at which a frame received or sent at `now` is due.
+    pub fn next_due(&self, now: Instant) -> Instant {
+        let mut state = self.state.lock().expect("Failed to lock latency state");
+        let delay = self.distribution.sample(&mut state.rng);
+        let due = state
+            .last_due
+            .map_or(now + delay, |last| (now + delay).max(last));
+        state.last_due = Some(due);
+        due
+    }
+}
+
+/// Delays items by the latency sampled for each, delivering them in order from a
+/// background task.
+///
+/// The task ends when all senders are dropped (after delivering the items in flight),
+/// or when the `deliver` function returns `false`.
+pub struct DelayLine<T> {
+    tx: UnboundedSender<(Instant, T)>,
+    injector: LatencyInjector,
+}
+
+impl<T> Clone for DelayLine<T> {
+    fn clone(&self) -> Self {
+        Self {
+            tx: self.tx.clone(),
+            injector: self.injector.clone(),
+        }
+    }
+}
+
+impl<T: Send + 'static> DelayLine<T> {
+    /// Creates a new [`DelayLine`] instance, spawning the delivery task.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn spawn<F, Fut>(injector: LatencyInjector, mut deliver: F) -> Self
+    where
+        F: FnMut(T) -> Fut + Send + 'static,
+        Fut: Future<Output = bool> + Send,
+    {
+        let (tx, mut rx) = unbounded_channel::<(Instant, T)>();
+        task::spawn(async move {
+            while let Some((due, item)) = rx.recv().await {
+                sleep_until(due).await;
+                if !deliver(item).await {
+                    break;
+                }
+            }
+        });
+        Self { tx, injector }
+    }
+
+    /// Queues the `item` for delivery after its sampled delay.
+    ///
+    /// Returns `false` if the delivery task has ended, in which case the item is dropped.
+    pub fn push(&self, item: T) -> bool {
+        let due = self.injector.next_due(Instant::now());
+        self.tx.send((due, item)).is_ok()
+    }
+}
+
+// SplitMix64, which is sufficient for sampling delays without an extra dependency
+fn next_f64(state: &mut u64) -> f64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1_u64 << 53) as f64
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn sample_nanos(distribution: LatencyDistribution, count: usize) -> Vec<u64> {
+        let mut rng = 42;
+        (0..count)
+            .map(|_| distribution.sample(&mut rng).as_nanos() as u64)
+            .collect()
+    }
+
+    #[rstest]
+    #[case("fixed:500", LatencyDistribution::Fixed { delay_ns: 500 })]
+    #[case("uniform:100, 900", LatencyDistribution::Uniform { min_ns: 100, max_ns: 900 })]
+    #[case("normal:500,50", LatencyDistribution::Normal { mean_ns: 500, std_dev_ns: 50 })]
+    #[case("exponential:500", LatencyDistribution::Exponential { mean_ns: 500 })]
+    fn test_from_str(#[case] input: &str, #[case] expected: LatencyDistribution) {
+        let distribution = LatencyDistribution::from_str(input).unwrap();
+
+        assert_eq!(distribution, expected);
+        assert_eq!(
+            LatencyDistribution::from_str(&distribution.to_string()).unwrap(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("fixed")]
+    #[case("fixed:-1")]
+    #[case("uniform:900,100")]
+    #[case("normal:500")]
+    #[case("gamma:1,2")]
+    fn test_from_str_invalid(#[case] input: &str) {
+        assert!(LatencyDistribution::from_str(input).is_err());
+    }
+
+    #[rstest]
+    fn test_sample_fixed_has_nanosecond_resolution() {
+        let samples = sample_nanos(
+            LatencyDistribution::Fixed {
+                delay_ns: 1_234_567,
+            },
+            10,
+        );
+
+        assert!(samples.iter().all(|ns| *ns == 1_234_567));
+    }
+
+    #[rstest]
+    fn test_sample_uniform_within_range() {
+        let distribution = LatencyDistribution::Uniform {
+            min_ns: 100,
+            max_ns: 900,
+        };
+
+        let samples = sample_nanos(distribution, 10_000);
+
+        assert!(samples.iter().all(|ns| (100..=900).contains(ns)));
+    }
+
+    #[rstest]
+    #[case(LatencyDistribution::Normal { mean_ns: 1_000_000, std_dev_ns: 100_000 })]
+    #[case(LatencyDistribution::Exponential { mean_ns: 1_000_000 })]
+    fn test_sample_mean(#[case] distribution: LatencyDistribution) {
+        let samples = sample_nanos(distribution, 10_000);
+
+        let mean = samples.iter().sum::<u64>() as f64 / samples.len() as f64;
+        assert!((950_000.0..1_050_000.0).contains(&mean), "mean was {mean}");
+    }
+
+    #[rstest]
+    fn test_sample_is_deterministic_for_seed() {
+        let distribution = LatencyDistribution::Exponential { mean_ns: 1_000 };
+
+        assert_eq!(
+            sample_nanos(distribution, 100),
+            sample_nanos(distribution, 100)
+        );
+    }
+
+    #[rstest]
+    fn test_next_due_preserves_order() {
+        let injector = LatencyInjector::new(
+            LatencyDistribution::Uniform {
+                min_ns: 0,
+                max_ns: 10_000_000,
+            },
+            1,
+        );
+        let start = Instant::now();
+
+        let dues: Vec<Instant> = (0..1_000)
+            .map(|i| injector.next_due(start + Duration::from_nanos(i)))
+            .collect();
+
+        assert!(dues.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[tokio::test]
+    async fn test_delay_line_delivers_in_order_after_delay() {
+        let injector = LatencyInjector::new(
+            LatencyDistribution::Fixed {
+                delay_ns: 5_000_000,
+            },
+            1,
+        );
+        let (tx, mut rx) = unbounded_channel();
+        let line = DelayLine::spawn(injector, move |item: u32| {
+            let sent = tx.send((item, Instant::now())).is_ok();
+            async move { sent }
+        });
+        let start = Instant::now();
+
+        assert!(line.push(1));
+        assert!(line.push(2));
+
+        let (first, first_at) = rx.recv().await.unwrap();
+        let (second, second_at) = rx.recv().await.unwrap();
+        assert_eq!((first, second), (1, 2));
+        assert!(first_at - start >= Duration::from_millis(5));
+        assert!(second_at >= first_at);
+    }
+}
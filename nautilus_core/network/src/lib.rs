@@ -28,10 +28,18 @@
 
 #![allow(warnings)] // non-local `impl` definition, temporary allow until pyo3 upgrade
 
+pub mod backoff;
+pub mod compression;
+pub mod crypto;
+pub mod decimal;
+pub mod fix;
 pub mod http;
-#[allow(dead_code)]
-mod ratelimiter;
+pub mod latency;
+pub mod multicast;
+pub mod proxy;
+pub mod ratelimiter;
 pub mod socket;
+pub mod tls;
 pub mod websocket;
 
 #[cfg(feature = "python")]
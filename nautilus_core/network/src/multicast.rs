@@ -0,0 +1,743 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A UDP multicast client for direct exchange market data feeds (e.g. CME MDP3, OPRA).
+//!
+//! Exchanges typically publish identical A and B feeds on separate multicast groups. The
+//! client receives both, and arbitrates between them by packet sequence number: the first
+//! copy of each packet is delivered in sequence order and later copies are dropped. A packet
+//! missing from one feed is recovered from the other if it arrives within the reorder window,
+//! otherwise the missing range is reported as a gap.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Formatter},
+    net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::{
+    net::UdpSocket,
+    sync::mpsc,
+    task,
+    time::{interval, Instant, MissedTickBehavior},
+};
+use tracing::{debug, error, warn};
+
+use crate::socket::MessageHandler;
+
+/// The maximum payload size of a UDP datagram (bytes).
+const MAX_DATAGRAM_LEN: usize = 65_507;
+
+/// Represents a handler for sequence gaps detected by a [`MulticastClient`].
+pub type GapHandler = Arc<dyn Fn(SequenceGap) + Send + Sync>;
+
+/// Represents a multicast group to receive a feed from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MulticastFeed {
+    /// The multicast group address.
+    pub group: Ipv4Addr,
+    /// The UDP port.
+    pub port: u16,
+    /// The address of the local interface to join the group on (any interface if unspecified).
+    pub interface: Ipv4Addr,
+}
+
+impl MulticastFeed {
+    /// Creates a new [`MulticastFeed`] instance, joined on any interface.
+    #[must_use]
+    pub fn new(group: Ipv4Addr, port: u16) -> Self {
+        Self {
+            group,
+            port,
+            interface: Ipv4Addr::UNSPECIFIED,
+        }
+    }
+
+    /// Returns the feed joined on the local interface with the given address.
+    #[must_use]
+    pub fn with_interface(mut self, interface: Ipv4Addr) -> Self {
+        self.interface = interface;
+        self
+    }
+}
+
+/// The location and encoding of the sequence number in each packet.
+#[derive(Clone)]
+pub enum SequenceFormat {
+    /// An unsigned 32-bit little-endian integer at the given offset
+    /// (e.g. the CME MDP3 packet header `MsgSeqNum` at offset 0).
+    U32Le(usize),
+    /// An unsigned 32-bit big-endian integer at the given offset.
+    U32Be(usize),
+    /// An unsigned 64-bit little-endian integer at the given offset.
+    U64Le(usize),
+    /// An unsigned 64-bit big-endian integer at the given offset.
+    U64Be(usize),
+    /// A custom function returning the sequence number of a packet (if valid).
+    Custom(Arc<dyn Fn(&[u8]) -> Option<u64> + Send + Sync>),
+}
+
+impl Debug for SequenceFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::U32Le(offset) => write!(f, "U32Le({offset})"),
+            Self::U32Be(offset) => write!(f, "U32Be({offset})"),
+            Self::U64Le(offset) => write!(f, "U64Le({offset})"),
+            Self::U64Be(offset) => write!(f, "U64Be({offset})"),
+            Self::Custom(_) => write!(f, "Custom"),
+        }
+    }
+}
+
+impl SequenceFormat {
+    /// Returns the sequence number of the given packet, or `None` if the packet is too short.
+    #[must_use]
+    pub fn extract(&self, data: &[u8]) -> Option<u64> {
+        match self {
+            Self::U32Le(offset) => read_bytes(data, *offset)
+                .map(u32::from_le_bytes)
+                .map(u64::from),
+            Self::U32Be(offset) => read_bytes(data, *offset)
+                .map(u32::from_be_bytes)
+                .map(u64::from),
+            Self::U64Le(offset) => read_bytes(data, *offset).map(u64::from_le_bytes),
+            Self::U64Be(offset) => read_bytes(data, *offset).map(u64::from_be_bytes),
+            Self::Custom(extract) => extract(data),
+        }
+    }
+}
+
+fn read_bytes<const N: usize>(data: &[u8], offset: usize) -> Option<[u8; N]> {
+    data.get(offset..offset.checked_add(N)?)?.try_into().ok()
+}
+
+/// Represents a range of sequence numbers (inclusive) missing from all feeds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SequenceGap {
+    /// The first missing sequence number.
+    pub start: u64,
+    /// The last missing sequence number.
+    pub end: u64,
+}
+
+impl SequenceGap {
+    /// Returns the count of missing packets.
+    #[must_use]
+    pub fn len(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    /// Returns whether the gap is empty (never the case for detected gaps).
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.end < self.start
+    }
+}
+
+/// Represents an output of the [`Arbitrator`], in sequence order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArbitrationEvent {
+    /// A packet to deliver.
+    Packet { seq: u64, data: Vec<u8> },
+    /// A range of packets which will not be delivered.
+    Gap(SequenceGap),
+}
+
+/// Counters for the packets processed by a [`MulticastClient`].
+#[derive(Debug, Default)]
+pub struct MulticastMetrics {
+    received: AtomicU64,
+    delivered: AtomicU64,
+    duplicates: AtomicU64,
+    invalid: AtomicU64,
+    gaps: AtomicU64,
+    missed: AtomicU64,
+}
+
+impl MulticastMetrics {
+    /// Returns the count of packets received (across all feeds).
+    #[must_use]
+    pub fn received(&self) -> u64 {
+        self.received.load(Ordering::Relaxed)
+    }
+
+    /// Returns the count of packets delivered.
+    #[must_use]
+    pub fn delivered(&self) -> u64 {
+        self.delivered.load(Ordering::Relaxed)
+    }
+
+    /// Returns the count of packets dropped as already received (e.g. from the other feed).
+    #[must_use]
+    pub fn duplicates(&self) -> u64 {
+        self.duplicates.load(Ordering::Relaxed)
+    }
+
+    /// Returns the count of packets dropped as having no valid sequence number.
+    #[must_use]
+    pub fn invalid(&self) -> u64 {
+        self.invalid.load(Ordering::Relaxed)
+    }
+
+    /// Returns the count of sequence gaps detected.
+    #[must_use]
+    pub fn gaps(&self) -> u64 {
+        self.gaps.load(Ordering::Relaxed)
+    }
+
+    /// Returns the total count of packets missed in sequence gaps.
+    #[must_use]
+    pub fn missed(&self) -> u64 {
+        self.missed.load(Ordering::Relaxed)
+    }
+}
+
+/// Arbitrates packets from one or more feeds by sequence number.
+///
+/// Packets ahead of the next expected sequence number are held pending until the missing
+/// packets arrive (from any feed). If more than `max_pending` packets are held, or the lowest
+/// pending packet has been held for `gap_timeout`, the missing packets are declared a gap and
+/// delivery continues from the pending packets.
+///
+/// The arbitrator does no I/O, so it can be driven with recorded packets.
+#[derive(Debug)]
+pub struct Arbitrator {
+    next_seq: Option<u64>,
+    pending: BTreeMap<u64, (Instant, Vec<u8>)>,
+    max_pending: usize,
+    gap_timeout: Duration,
+    metrics: Arc<MulticastMetrics>,
+}
+
+impl Arbitrator {
+    /// Creates a new [`Arbitrator`] instance.
+    #[must_use]
+    pub fn new(max_pending: usize, gap_timeout: Duration) -> Self {
+        Self {
+            next_seq: None,
+            pending: BTreeMap::new(),
+            max_pending,
+            gap_timeout,
+            metrics: Arc::new(MulticastMetrics::default()),
+        }
+    }
+
+    /// Returns the next expected sequence number (`None` until the first packet).
+    #[must_use]
+    pub fn next_seq(&self) -> Option<u64> {
+        self.next_seq
+    }
+
+    /// Returns the count of packets held pending.
+    #[must_use]
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns the metrics for the arbitrator.
+    #[must_use]
+    pub fn metrics(&self) -> Arc<MulticastMetrics> {
+        self.metrics.clone()
+    }
+
+    /// Resets the expected sequence number (e.g. after a scheduled sequence reset by the
+    /// exchange), discarding any pending packets.
+    pub fn reset(&mut self) {
+        self.next_seq = None;
+        self.pending.clear();
+    }
+
+    /// Processes a packet with the given sequence number received at `now`.
+    pub fn on_packet(&mut self, seq: u64, data: Vec<u8>, now: Instant) -> Vec<ArbitrationEvent> {
+        self.metrics.received.fetch_add(1, Ordering::Relaxed);
+        let mut events = Vec::new();
+
+        let next_seq = *self.next_seq.get_or_insert(seq);
+        if seq < next_seq || self.pending.contains_key(&seq) {
+            self.metrics.duplicates.fetch_add(1, Ordering::Relaxed);
+            return events;
+        }
+
+        if seq == next_seq {
+            self.deliver(seq, data, &mut events);
+            self.drain(&mut events);
+        } else {
+            self.pending.insert(seq, (now, data));
+            if self.pending.len() > self.max_pending {
+                self.skip_to_pending(&mut events);
+            }
+        }
+        events
+    }
+
+    /// Processes a packet without a valid sequence number.
+    pub fn on_invalid(&mut self) {
+        self.metrics.received.fetch_add(1, Ordering::Relaxed);
+        self.metrics.invalid.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Declares gaps for any pending packets held for longer than the gap timeout.
+    pub fn on_timer(&mut self, now: Instant) -> Vec<ArbitrationEvent> {
+        let mut events = Vec::new();
+        while let Some((_, (received, _))) = self.pending.first_key_value() {
+            if now.duration_since(*received) < self.gap_timeout {
+                break;
+            }
+            self.skip_to_pending(&mut events);
+        }
+        events
+    }
+
+    fn deliver(&mut self, seq: u64, data: Vec<u8>, events: &mut Vec<ArbitrationEvent>) {
+        self.metrics.delivered.fetch_add(1, Ordering::Relaxed);
+        self.next_seq = Some(seq + 1);
+        events.push(ArbitrationEvent::Packet { seq, data });
+    }
+
+    fn drain(&mut self, events: &mut Vec<ArbitrationEvent>) {
+        while let Some(entry) = self.pending.first_entry() {
+            if Some(*entry.key()) != self.next_seq {
+                break;
+            }
+            let (seq, (_, data)) = entry.remove_entry();
+            self.deliver(seq, data, events);
+        }
+    }
+
+    fn skip_to_pending(&mut self, events: &mut Vec<ArbitrationEvent>) {
+        let (Some(next_seq), Some(first_pending)) =
+            (self.next_seq, self.pending.keys().next().copied())
+        else {
+            return;
+        };
+
+        let gap = SequenceGap {
+            start: next_seq,
+            end: first_pending - 1,
+        };
+        warn!(
+            "Sequence gap detected {}..={} ({} missed)",
+            gap.start,
+            gap.end,
+            gap.len()
+        );
+        self.metrics.gaps.fetch_add(1, Ordering::Relaxed);
+        self.metrics.missed.fetch_add(gap.len(), Ordering::Relaxed);
+        events.push(ArbitrationEvent::Gap(gap));
+
+        self.next_seq = Some(first_pending);
+        self.drain(events);
+    }
+}
+
+/// Configuration for a [`MulticastClient`].
+#[derive(Clone)]
+pub struct MulticastConfig {
+    /// The feeds to receive and arbitrate between (e.g. the A and B feeds).
+    pub feeds: Vec<MulticastFeed>,
+    /// The location of the sequence number in each packet.
+    pub sequence: SequenceFormat,
+    /// The handler for delivered packets.
+    pub handler: MessageHandler,
+    /// The optional handler for detected sequence gaps.
+    pub gap_handler: Option<GapHandler>,
+    /// The maximum number of packets held pending while waiting for missing packets.
+    pub max_pending: usize,
+    /// The maximum time to wait for missing packets before declaring a gap.
+    pub gap_timeout: Duration,
+    /// The optional size (bytes) of the socket receive buffer (the OS default if `None`).
+    pub recv_buffer_size: Option<usize>,
+}
+
+impl MulticastConfig {
+    /// Creates a new [`MulticastConfig`] instance, with a reorder window of 1,000 packets
+    /// and a gap timeout of 50 milliseconds.
+    #[must_use]
+    pub fn new(
+        feeds: Vec<MulticastFeed>,
+        sequence: SequenceFormat,
+        handler: MessageHandler,
+    ) -> Self {
+        Self {
+            feeds,
+            sequence,
+            handler,
+            gap_handler: None,
+            max_pending: 1_000,
+            gap_timeout: Duration::from_millis(50),
+            recv_buffer_size: None,
+        }
+    }
+
+    /// Returns the config with the given gap handler.
+    #[must_use]
+    pub fn with_gap_handler<H>(mut self, handler: H) -> Self
+    where
+        H: Fn(SequenceGap) + Send + Sync + 'static,
+    {
+        self.gap_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Returns the config with the given reorder window.
+    #[must_use]
+    pub fn with_reorder_window(mut self, max_pending: usize, gap_timeout: Duration) -> Self {
+        self.max_pending = max_pending;
+        self.gap_timeout = gap_timeout;
+        self
+    }
+
+    /// Returns the config with the given socket receive buffer size.
+    #[must_use]
+    pub fn with_recv_buffer_size(mut self, size: usize) -> Self {
+        self.recv_buffer_size = Some(size);
+        self
+    }
+}
+
+/// A client which receives packets from one or more multicast feeds, and delivers them to a
+/// handler in sequence order after arbitration.
+///
+/// Each feed is received on its own task, with the packets arbitrated on a single task which
+/// calls the handlers.
+pub struct MulticastClient {
+    feed_tasks: Vec<task::JoinHandle<()>>,
+    arbitration_task: task::JoinHandle<()>,
+    metrics: Arc<MulticastMetrics>,
+}
+
+impl MulticastClient {
+    /// Joins the multicast groups of the configured feeds and starts receiving.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no feeds are configured, or a group cannot be joined.
+    pub async fn connect(config: MulticastConfig) -> anyhow::Result<Self> {
+        if config.feeds.is_empty() {
+            anyhow::bail!("No multicast feeds configured");
+        }
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut feed_tasks = Vec::with_capacity(config.feeds.len());
+        for feed in &config.feeds {
+            let socket = join_multicast(feed, config.recv_buffer_size)?;
+            debug!("Joined multicast group {}:{}", feed.group, feed.port);
+            feed_tasks.push(Self::spawn_feed_task(*feed, socket, tx.clone()));
+        }
+
+        let arbitrator = Arbitrator::new(config.max_pending, config.gap_timeout);
+        let metrics = arbitrator.metrics();
+        let arbitration_task = Self::spawn_arbitration_task(config, arbitrator, rx);
+
+        Ok(Self {
+            feed_tasks,
+            arbitration_task,
+            metrics,
+        })
+    }
+
+    /// Returns the metrics for the client.
+    #[must_use]
+    pub fn metrics(&self) -> &MulticastMetrics {
+        &self.metrics
+    }
+
+    /// Returns whether the client is receiving from at least one feed.
+    #[must_use]
+    pub fn is_alive(&self) -> bool {
+        !self.arbitration_task.is_finished()
+            && self.feed_tasks.iter().any(|task| !task.is_finished())
+    }
+
+    /// Stops receiving from all feeds.
+    pub fn disconnect(&self) {
+        for task in &self.feed_tasks {
+            task.abort();
+        }
+        self.arbitration_task.abort();
+        debug!("Disconnected multicast client");
+    }
+
+    fn spawn_feed_task(
+        feed: MulticastFeed,
+        socket: UdpSocket,
+        tx: mpsc::UnboundedSender<(Instant, Vec<u8>)>,
+    ) -> task::JoinHandle<()> {
+        task::spawn(async move {
+            let mut buf = vec![0u8; MAX_DATAGRAM_LEN];
+            loop {
+                match socket.recv(&mut buf).await {
+                    Ok(len) => {
+                        if tx.send((Instant::now(), buf[..len].to_vec())).is_err() {
+                            break; // Arbitration task ended
+                        }
+                    }
+                    Err(e) => {
+                        error!("Error receiving from {}:{}: {e}", feed.group, feed.port);
+                        break;
+                    }
+                }
+            }
+        })
+    }
+
+    fn spawn_arbitration_task(
+        config: MulticastConfig,
+        mut arbitrator: Arbitrator,
+        mut rx: mpsc::UnboundedReceiver<(Instant, Vec<u8>)>,
+    ) -> task::JoinHandle<()> {
+        task::spawn(async move {
+            let MulticastConfig {
+                sequence,
+                handler,
+                gap_handler,
+                gap_timeout,
+                ..
+            } = config;
+            let mut timer = interval(gap_timeout.max(Duration::from_millis(1)));
+            timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                let events = tokio::select! {
+                    packet = rx.recv() => {
+                        let Some((received, data)) = packet else {
+                            break; // All feed tasks ended
+                        };
+                        match sequence.extract(&data) {
+                            Some(seq) => arbitrator.on_packet(seq, data, received),
+                            None => {
+                                arbitrator.on_invalid();
+                                continue;
+                            }
+                        }
+                    }
+                    _ = timer.tick() => arbitrator.on_timer(Instant::now()),
+                };
+
+                for event in events {
+                    match event {
+                        ArbitrationEvent::Packet { data, .. } => {
+                            if let Err(e) = handler.handle(&data) {
+                                error!("Error calling handler: {e}");
+                            }
+                        }
+                        ArbitrationEvent::Gap(gap) => {
+                            if let Some(gap_handler) = &gap_handler {
+                                gap_handler(gap);
+                            }
+                        }
+                    }
+                }
+            }
+            debug!("Completed task `arbitration`");
+        })
+    }
+}
+
+impl Drop for MulticastClient {
+    fn drop(&mut self) {
+        self.disconnect();
+    }
+}
+
+/// Creates a UDP socket joined to the multicast group of the given feed.
+///
+/// The address is reused so that several processes (or both feeds of a channel sharing a
+/// port) can receive on the same port.
+fn join_multicast(
+    feed: &MulticastFeed,
+    recv_buffer_size: Option<usize>,
+) -> anyhow::Result<UdpSocket> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_reuse_address(true)?;
+    if let Some(size) = recv_buffer_size {
+        socket.set_recv_buffer_size(size)?;
+    }
+
+    // Binding to the group address filters out other groups on the same port (not
+    // supported on Windows, which must bind to the unspecified address)
+    let bind_addr = if cfg!(windows) {
+        Ipv4Addr::UNSPECIFIED
+    } else {
+        feed.group
+    };
+    socket.bind(&SocketAddr::V4(SocketAddrV4::new(bind_addr, feed.port)).into())?;
+    socket.join_multicast_v4(&feed.group, &feed.interface)?;
+    socket.set_nonblocking(true)?;
+
+    Ok(UdpSocket::from_std(socket.into())?)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn packets(events: &[ArbitrationEvent]) -> Vec<u64> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                ArbitrationEvent::Packet { seq, .. } => Some(*seq),
+                ArbitrationEvent::Gap(_) => None,
+            })
+            .collect()
+    }
+
+    fn gaps(events: &[ArbitrationEvent]) -> Vec<SequenceGap> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                ArbitrationEvent::Gap(gap) => Some(*gap),
+                ArbitrationEvent::Packet { .. } => None,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(SequenceFormat::U32Le(0), Some(0x0403_0201))]
+    #[case(SequenceFormat::U32Be(0), Some(0x0102_0304))]
+    #[case(SequenceFormat::U32Le(2), Some(0x0605_0403))]
+    #[case(SequenceFormat::U64Le(0), Some(0x0807_0605_0403_0201))]
+    #[case(SequenceFormat::U64Be(0), Some(0x0102_0304_0506_0708))]
+    #[case(SequenceFormat::U64Le(1), None)]
+    #[case(SequenceFormat::U32Le(usize::MAX), None)]
+    #[case(SequenceFormat::Custom(Arc::new(|data: &[u8]| data.last().map(|b| u64::from(*b)))), Some(8))]
+    fn test_sequence_format_extract(#[case] format: SequenceFormat, #[case] expected: Option<u64>) {
+        let data = [1, 2, 3, 4, 5, 6, 7, 8];
+
+        assert_eq!(format.extract(&data), expected);
+    }
+
+    #[rstest]
+    fn test_arbitrator_drops_duplicates_from_other_feed() {
+        let mut arbitrator = Arbitrator::new(10, Duration::from_millis(50));
+        let now = Instant::now();
+
+        let mut events = Vec::new();
+        for seq in [1, 1, 2, 2, 3, 3] {
+            events.extend(arbitrator.on_packet(seq, vec![], now));
+        }
+
+        assert_eq!(packets(&events), vec![1, 2, 3]);
+        assert_eq!(arbitrator.next_seq(), Some(4));
+        assert_eq!(arbitrator.metrics().received(), 6);
+        assert_eq!(arbitrator.metrics().duplicates(), 3);
+        assert_eq!(arbitrator.metrics().gaps(), 0);
+    }
+
+    #[rstest]
+    fn test_arbitrator_recovers_missing_packet_from_other_feed() {
+        let mut arbitrator = Arbitrator::new(10, Duration::from_millis(50));
+        let now = Instant::now();
+
+        // Feed A drops packet 2, feed B delivers it late
+        let mut events = arbitrator.on_packet(1, vec![], now);
+        events.extend(arbitrator.on_packet(3, vec![], now));
+        events.extend(arbitrator.on_packet(4, vec![], now));
+        assert_eq!(packets(&events), vec![1]);
+        assert_eq!(arbitrator.pending_len(), 2);
+
+        events.extend(arbitrator.on_packet(2, vec![], now));
+        events.extend(arbitrator.on_packet(3, vec![], now));
+
+        assert_eq!(packets(&events), vec![1, 2, 3, 4]);
+        assert!(gaps(&events).is_empty());
+        assert_eq!(arbitrator.pending_len(), 0);
+        assert_eq!(arbitrator.metrics().duplicates(), 1);
+    }
+
+    #[rstest]
+    fn test_arbitrator_declares_gap_when_reorder_window_full() {
+        let mut arbitrator = Arbitrator::new(2, Duration::from_secs(60));
+        let now = Instant::now();
+
+        let mut events = arbitrator.on_packet(10, vec![], now);
+        for seq in [13, 14, 15] {
+            events.extend(arbitrator.on_packet(seq, vec![], now));
+        }
+
+        assert_eq!(packets(&events), vec![10, 13, 14, 15]);
+        assert_eq!(gaps(&events), vec![SequenceGap { start: 11, end: 12 }]);
+        assert_eq!(arbitrator.metrics().missed(), 2);
+
+        // A late copy of a missed packet is not delivered
+        assert!(arbitrator.on_packet(11, vec![], now).is_empty());
+    }
+
+    #[rstest]
+    fn test_arbitrator_declares_gaps_on_timeout() {
+        let mut arbitrator = Arbitrator::new(100, Duration::from_millis(50));
+        let now = Instant::now();
+
+        let mut events = arbitrator.on_packet(1, vec![], now);
+        events.extend(arbitrator.on_packet(3, vec![], now));
+        events.extend(arbitrator.on_packet(6, vec![], now));
+        events.extend(arbitrator.on_timer(now + Duration::from_millis(10)));
+        assert_eq!(packets(&events), vec![1]);
+
+        events.extend(arbitrator.on_timer(now + Duration::from_millis(50)));
+
+        assert_eq!(packets(&events), vec![1, 3, 6]);
+        assert_eq!(
+            gaps(&events),
+            vec![
+                SequenceGap { start: 2, end: 2 },
+                SequenceGap { start: 4, end: 5 },
+            ]
+        );
+        assert_eq!(arbitrator.next_seq(), Some(7));
+    }
+
+    #[rstest]
+    fn test_arbitrator_reset() {
+        let mut arbitrator = Arbitrator::new(10, Duration::from_millis(50));
+        let now = Instant::now();
+        arbitrator.on_packet(1_000, vec![], now);
+        arbitrator.on_packet(1_002, vec![], now);
+
+        arbitrator.reset();
+        let events = arbitrator.on_packet(1, vec![1], now);
+
+        assert_eq!(
+            events,
+            vec![ArbitrationEvent::Packet {
+                seq: 1,
+                data: vec![1]
+            }]
+        );
+        assert_eq!(arbitrator.pending_len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_connect_when_no_feeds() {
+        let config = MulticastConfig::new(
+            vec![],
+            SequenceFormat::U32Le(0),
+            MessageHandler::from_fn(|_| {}),
+        );
+
+        assert!(MulticastClient::connect(config).await.is_err());
+    }
+}
@@ -17,7 +17,7 @@
 
 use pyo3::prelude::*;
 
-use crate::{http, ratelimiter, socket, websocket};
+use crate::{http, latency, proxy, ratelimiter, socket, tls, websocket};
 
 /// Loaded as nautilus_pyo3.network
 #[pymodule]
@@ -25,10 +25,16 @@ pub fn network(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<http::HttpClient>()?;
     m.add_class::<http::HttpMethod>()?;
     m.add_class::<http::HttpResponse>()?;
+    m.add_class::<http::HttpResponseStream>()?;
+    m.add_class::<http::HttpRetryPolicy>()?;
+    m.add_class::<latency::LatencyConfig>()?;
+    m.add_class::<proxy::ProxyConfig>()?;
+    m.add_function(wrap_pyfunction!(proxy::py_set_default_proxy, m)?)?;
     m.add_class::<ratelimiter::quota::Quota>()?;
     m.add_class::<websocket::WebSocketClient>()?;
     m.add_class::<websocket::WebSocketConfig>()?;
     m.add_class::<socket::SocketClient>()?;
     m.add_class::<socket::SocketConfig>()?;
+    m.add_class::<tls::TlsConfig>()?;
     Ok(())
 }
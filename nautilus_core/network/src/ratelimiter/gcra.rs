@@ -143,20 +143,38 @@ impl Gcra {
         key: &K,
         state: &S,
         t0: P,
+    ) -> Result<(), NotUntil<P>> {
+        self.test_n_and_update(start, key, state, t0, 1)
+    }
+
+    /// Tests `n` cells (the weight of a request) against the rate limiter state and updates
+    /// it at the given key.
+    ///
+    /// A weight greater than the burst capacity is capped at the burst capacity, so that the
+    /// cells can eventually conform instead of being rejected forever.
+    pub(crate) fn test_n_and_update<K, S: StateStore<Key = K>, P: clock::Reference>(
+        &self,
+        start: P,
+        key: &K,
+        state: &S,
+        t0: P,
+        n: u32,
     ) -> Result<(), NotUntil<P>> {
         let t0 = t0.duration_since(start);
         let tau = self.tau;
         let t = self.t;
+        let n = u64::from(n).clamp(1, cmp::max(tau / t, 1));
+        let additional_weight = t * (n - 1);
         state.measure_and_replace(key, |tat| {
             let tat = tat.unwrap_or_else(|| self.starting_state(t0));
-            let earliest_time = tat.saturating_sub(tau);
+            let earliest_time = (tat + additional_weight).saturating_sub(tau);
             if t0 < earliest_time {
                 Err(NotUntil::new(
                     StateSnapshot::new(self.t, self.tau, earliest_time, earliest_time),
                     start,
                 ))
             } else {
-                let next = cmp::max(tat, t0) + t;
+                let next = cmp::max(tat, t0) + t + additional_weight;
                 Ok(((), next))
             }
         })
@@ -173,18 +173,35 @@ where
     }
 
     pub fn check_key(&self, key: &K) -> Result<(), NotUntil<C::Instant>> {
+        self.check_key_n(key, 1)
+    }
+
+    /// Checks whether a request with the given `weight` conforms to the quota for `key`, and
+    /// consumes `weight` cells of the quota if so.
+    ///
+    /// Keys without a quota (and no default quota) always conform.
+    pub fn check_key_n(&self, key: &K, weight: u32) -> Result<(), NotUntil<C::Instant>> {
         match self.gcra.get(key) {
-            Some(quota) => quota.test_and_update(self.start, key, &self.state, self.clock.now()),
+            Some(quota) => {
+                quota.test_n_and_update(self.start, key, &self.state, self.clock.now(), weight)
+            }
             None => self.default_gcra.as_ref().map_or(Ok(()), |gcra| {
-                gcra.test_and_update(self.start, key, &self.state, self.clock.now())
+                gcra.test_n_and_update(self.start, key, &self.state, self.clock.now(), weight)
             }),
         }
     }
 
     pub async fn until_key_ready(&self, key: &K) {
+        self.until_key_ready_n(key, 1).await;
+    }
+
+    /// Waits until a request with the given `weight` conforms to the quota for `key`.
+    ///
+    /// Requests are queued (by sleeping until the earliest conforming time) rather than rejected.
+    pub async fn until_key_ready_n(&self, key: &K, weight: u32) {
         loop {
-            match self.check_key(key) {
-                Ok(x) => x,
+            match self.check_key_n(key, weight) {
+                Ok(()) => break,
                 Err(neg) => {
                     sleep(neg.wait_time_from(self.clock.now())).await;
                 }
@@ -247,4 +264,28 @@ mod tests {
         assert!(mock_limiter.check_key(&"yeet".to_string()).is_ok());
         assert!(mock_limiter.check_key(&"yeet".to_string()).is_err());
     }
+
+    #[test]
+    fn test_weighted_quota() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        mock_limiter.add_quota_for_key(
+            "orders".to_string(),
+            Quota::per_second(NonZeroU32::new(10).unwrap()),
+        );
+
+        // a weight of 6 leaves capacity for 4 more cells
+        assert!(mock_limiter.check_key_n(&"orders".to_string(), 6).is_ok());
+        assert!(mock_limiter.check_key_n(&"orders".to_string(), 5).is_err());
+        assert!(mock_limiter.check_key_n(&"orders".to_string(), 4).is_ok());
+        assert!(mock_limiter.check_key(&"orders".to_string()).is_err());
+
+        // replenish half the quota
+        mock_limiter.advance_clock(Duration::from_millis(500));
+        assert!(mock_limiter.check_key_n(&"orders".to_string(), 5).is_ok());
+        assert!(mock_limiter.check_key(&"orders".to_string()).is_err());
+
+        // a weight above the burst capacity is capped rather than rejected forever
+        mock_limiter.advance_clock(Duration::from_secs(1));
+        assert!(mock_limiter.check_key_n(&"orders".to_string(), 20).is_ok());
+    }
 }
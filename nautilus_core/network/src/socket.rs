@@ -15,14 +15,21 @@
 
 //! A high-performance raw TCP client implementation with TLS capability.
 
-use std::{sync::Arc, time::Duration};
+use std::{
+    fmt::{Debug, Formatter},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
-use nautilus_core::python::to_pyruntime_err;
+use nautilus_core::python::{to_pyruntime_err, to_pyvalue_err};
 use pyo3::prelude::*;
 use tokio::{
     io::{split, AsyncReadExt, AsyncWriteExt, ReadHalf, WriteHalf},
     net::TcpStream,
-    sync::Mutex,
+    sync::{mpsc, Mutex},
     task,
     time::sleep,
 };
@@ -31,11 +38,198 @@ use tokio_tungstenite::{
     tungstenite::{client::IntoClientRequest, stream::Mode, Error},
     MaybeTlsStream,
 };
-use tracing::{debug, error};
+use tracing::{debug, error, warn};
+
+use crate::{
+    backoff::ExponentialBackoff,
+    latency::{DelayLine, LatencyConfig, LatencyInjector},
+    proxy::{resolve_proxy, ProxyConfig},
+    tls::TlsConfig,
+    websocket::call_handler,
+};
 
 type TcpWriter = WriteHalf<MaybeTlsStream<TcpStream>>;
 type SharedTcpWriter = Arc<Mutex<WriteHalf<MaybeTlsStream<TcpStream>>>>;
 type TcpReader = ReadHalf<MaybeTlsStream<TcpStream>>;
+type RustMessageHandler = Arc<dyn Fn(&[u8]) + Send + Sync>;
+type RustConnectionHandler = Arc<dyn Fn() + Send + Sync>;
+
+/// Represents a handler for messages received by a [`SocketClient`].
+///
+/// A Rust handler is called directly from the read task without acquiring the GIL,
+/// so the client can be used from pure-Rust adapters.
+#[derive(Clone)]
+pub enum MessageHandler {
+    /// A Python callable, called with each message as `bytes`.
+    Python(PyObject),
+    /// A Rust function, called with each message.
+    Rust(RustMessageHandler),
+}
+
+impl MessageHandler {
+    /// Creates a new [`MessageHandler`] from the given Rust function.
+    pub fn from_fn<H>(handler: H) -> Self
+    where
+        H: Fn(&[u8]) + Send + Sync + 'static,
+    {
+        Self::Rust(Arc::new(handler))
+    }
+
+    /// Creates a new [`MessageHandler`] which forwards each message to the returned
+    /// channel receiver.
+    #[must_use]
+    pub fn channel() -> (Self, mpsc::UnboundedReceiver<Vec<u8>>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let handler = Self::from_fn(move |data| {
+            if let Err(e) = tx.send(data.to_vec()) {
+                error!("Failed to forward message: {e}");
+            }
+        });
+        (handler, rx)
+    }
+
+    /// Returns a handler which passes each message to this handler after the delay sampled
+    /// by the `injector`, preserving the message order.
+    ///
+    /// Must be called from within a tokio runtime.
+    #[must_use]
+    pub fn with_latency(self, injector: LatencyInjector) -> Self {
+        let line = DelayLine::spawn(injector, move |data: Vec<u8>| {
+            if let Err(e) = self.handle(&data) {
+                error!("Call to handler failed: {e}");
+            }
+            async { true }
+        });
+        Self::from_fn(move |data| {
+            if !line.push(data.to_vec()) {
+                error!("Failed to delay message: delivery task ended");
+            }
+        })
+    }
+
+    pub(crate) fn handle(&self, data: &[u8]) -> anyhow::Result<()> {
+        match self {
+            Self::Python(handler) => {
+                Python::with_gil(|py| handler.call1(py, (data,)))?;
+            }
+            Self::Rust(handler) => handler(data),
+        }
+        Ok(())
+    }
+}
+
+/// Represents a handler called when the connection state of a [`SocketClient`] changes.
+///
+/// A Rust handler lets a pure-Rust consumer observe a reconnection, for example to
+/// resubscribe or resend its logon.
+#[derive(Clone)]
+pub enum ConnectionHandler {
+    /// A Python callable, called with no arguments.
+    Python(PyObject),
+    /// A Rust function.
+    Rust(RustConnectionHandler),
+}
+
+impl ConnectionHandler {
+    /// Creates a new [`ConnectionHandler`] from the given Rust function.
+    pub fn from_fn<H>(handler: H) -> Self
+    where
+        H: Fn() + Send + Sync + 'static,
+    {
+        Self::Rust(Arc::new(handler))
+    }
+
+    fn handle(&self, name: &str) {
+        match self {
+            Self::Python(handler) => call_handler(Some(handler), name),
+            Self::Rust(handler) => {
+                handler();
+                debug!("Called `{name}` handler");
+            }
+        }
+    }
+}
+
+/// Calls the given connection `handler` (if any).
+fn call_connection_handler(handler: Option<&ConnectionHandler>, name: &str) {
+    if let Some(handler) = handler {
+        handler.handle(name);
+    }
+}
+
+/// The policy applied when the read buffer of a [`SocketClient`] exceeds its limit.
+#[derive(Clone, Debug)]
+pub enum BufferOverflowPolicy {
+    /// Drop the connection (the client will then reconnect).
+    Disconnect,
+    /// Drop the oldest buffered bytes to bring the buffer back within its limit.
+    DropOldest,
+    /// Pass the buffered bytes to the handler, then discard them.
+    Callback(MessageHandler),
+}
+
+/// Represents the limit on the read buffer of a [`SocketClient`], which otherwise grows
+/// unbounded while a message suffix has not been received.
+#[derive(Clone, Debug)]
+pub struct ReadBufferLimit {
+    /// The maximum size (bytes) of the buffer.
+    pub max_size: usize,
+    /// The policy applied when the buffer exceeds the maximum size.
+    pub policy: BufferOverflowPolicy,
+}
+
+impl ReadBufferLimit {
+    /// Applies the overflow policy to the given `buf` (if it exceeds the maximum size).
+    ///
+    /// Returns `false` if the connection should be dropped.
+    fn apply(&self, buf: &mut Vec<u8>) -> bool {
+        if buf.len() <= self.max_size {
+            return true;
+        }
+
+        match &self.policy {
+            BufferOverflowPolicy::Disconnect => {
+                error!(
+                    "Read buffer of {} bytes exceeded limit of {} bytes, disconnecting",
+                    buf.len(),
+                    self.max_size
+                );
+                return false;
+            }
+            BufferOverflowPolicy::DropOldest => {
+                let excess = buf.len() - self.max_size;
+                warn!(
+                    "Read buffer exceeded limit of {} bytes, dropping {excess} oldest bytes",
+                    self.max_size
+                );
+                buf.drain(..excess);
+            }
+            BufferOverflowPolicy::Callback(handler) => {
+                warn!(
+                    "Read buffer of {} bytes exceeded limit of {} bytes, discarding",
+                    buf.len(),
+                    self.max_size
+                );
+                if let Err(e) = handler.handle(buf) {
+                    error!("Call to overflow handler failed: {e}");
+                }
+                buf.clear();
+            }
+        }
+        true
+    }
+}
+
+impl Debug for MessageHandler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Python(handler) => {
+                write!(f, "{}::Python({handler:?})", stringify!(MessageHandler))
+            }
+            Self::Rust(_) => write!(f, "{}::Rust", stringify!(MessageHandler)),
+        }
+    }
+}
 
 /// Configuration for TCP socket connection.
 #[derive(Debug, Clone)]
@@ -50,30 +244,191 @@ pub struct SocketConfig {
     mode: Mode,
     /// The sequence of bytes which separates lines.
     suffix: Vec<u8>,
-    /// The Python function to handle incoming messages.
-    handler: PyObject,
-    /// The optional heartbeat with period and beat message.
+    /// The handler for incoming messages.
+    handler: MessageHandler,
+    /// The optional heartbeat with period (seconds) and beat message.
     heartbeat: Option<(u64, Vec<u8>)>,
+    /// The initial delay (milliseconds) before reconnecting.
+    reconnect_delay_initial_ms: Option<u64>,
+    /// The maximum delay (milliseconds) between reconnection attempts.
+    reconnect_delay_max_ms: Option<u64>,
+    /// The factor applied to the delay after each failed reconnection attempt.
+    reconnect_backoff_factor: Option<f64>,
+    /// The maximum number of consecutive reconnection attempts (unlimited if `None`).
+    reconnect_max_attempts: Option<u32>,
+    /// The optional limit on the read buffer (unbounded if `None`).
+    read_buffer_limit: Option<ReadBufferLimit>,
+    /// The optional proxy to connect through (the default proxy if `None`).
+    proxy: Option<ProxyConfig>,
+    /// The optional TLS configuration (the native root CAs if `None`).
+    tls: Option<TlsConfig>,
+    /// The optional latency to inject on frames, for testing only.
+    latency: Option<LatencyConfig>,
+}
+
+impl SocketConfig {
+    /// Creates a new [`SocketConfig`] instance, using the default reconnection backoff.
+    #[must_use]
+    pub fn new(
+        url: &str,
+        mode: Mode,
+        suffix: Vec<u8>,
+        handler: MessageHandler,
+        heartbeat: Option<(u64, Vec<u8>)>,
+    ) -> Self {
+        Self {
+            url: url.to_string(),
+            mode,
+            suffix,
+            handler,
+            heartbeat,
+            reconnect_delay_initial_ms: None,
+            reconnect_delay_max_ms: None,
+            reconnect_backoff_factor: None,
+            reconnect_max_attempts: None,
+            read_buffer_limit: None,
+            proxy: None,
+            tls: None,
+            latency: None,
+        }
+    }
+
+    /// Returns the config with the given latency injected on inbound and outbound frames.
+    ///
+    /// This is intended for testing against venue latency conditions, and should not be
+    /// used for live trading.
+    #[must_use]
+    pub fn with_latency(mut self, latency: LatencyConfig) -> Self {
+        self.latency = Some(latency);
+        self
+    }
+
+    /// Returns the config with the given TLS configuration, e.g. for custom root CAs or
+    /// pinned certificates.
+    #[must_use]
+    pub fn with_tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Returns the config with the given proxy, overriding the default proxy.
+    #[must_use]
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Returns the config with the given read buffer limit.
+    #[must_use]
+    pub fn with_read_buffer_limit(mut self, limit: ReadBufferLimit) -> Self {
+        self.read_buffer_limit = Some(limit);
+        self
+    }
+
+    /// Returns the config with the given reconnection backoff settings.
+    #[must_use]
+    pub fn with_reconnect_backoff(
+        mut self,
+        delay_initial_ms: Option<u64>,
+        delay_max_ms: Option<u64>,
+        backoff_factor: Option<f64>,
+        max_attempts: Option<u32>,
+    ) -> Self {
+        self.reconnect_delay_initial_ms = delay_initial_ms;
+        self.reconnect_delay_max_ms = delay_max_ms;
+        self.reconnect_backoff_factor = backoff_factor;
+        self.reconnect_max_attempts = max_attempts;
+        self
+    }
+
+    /// Returns the reconnection backoff for the config.
+    ///
+    /// Defaults to an initial delay of 1 second, doubling up to 30 seconds, with no limit on
+    /// the number of attempts.
+    pub fn reconnect_backoff(&self) -> anyhow::Result<ExponentialBackoff> {
+        let delay_initial = self.reconnect_delay_initial_ms.unwrap_or(1_000);
+        let delay_max = self
+            .reconnect_delay_max_ms
+            .unwrap_or(30_000)
+            .max(delay_initial);
+        ExponentialBackoff::new(
+            Duration::from_millis(delay_initial),
+            Duration::from_millis(delay_max),
+            self.reconnect_backoff_factor.unwrap_or(2.0),
+            self.reconnect_max_attempts,
+        )
+    }
 }
 
 #[pymethods]
 impl SocketConfig {
     #[new]
+    #[allow(clippy::too_many_arguments)]
     fn py_new(
         url: String,
         ssl: bool,
         suffix: Vec<u8>,
         handler: PyObject,
         heartbeat: Option<(u64, Vec<u8>)>,
-    ) -> Self {
+        reconnect_delay_initial_ms: Option<u64>,
+        reconnect_delay_max_ms: Option<u64>,
+        reconnect_backoff_factor: Option<f64>,
+        reconnect_max_attempts: Option<u32>,
+        max_buffer_size: Option<usize>,
+        overflow_policy: Option<&str>,
+        overflow_handler: Option<PyObject>,
+        proxy: Option<ProxyConfig>,
+        tls: Option<TlsConfig>,
+        latency: Option<LatencyConfig>,
+    ) -> PyResult<Self> {
         let mode = if ssl { Mode::Tls } else { Mode::Plain };
-        Self {
-            url,
+        if matches!(&heartbeat, Some((0, _))) {
+            return Err(to_pyvalue_err("Invalid `heartbeat` interval, was 0"));
+        }
+        let policy = match (overflow_policy.unwrap_or("disconnect"), overflow_handler) {
+            ("disconnect", _) => BufferOverflowPolicy::Disconnect,
+            ("drop_oldest", _) => BufferOverflowPolicy::DropOldest,
+            ("callback", Some(handler)) => {
+                BufferOverflowPolicy::Callback(MessageHandler::Python(handler))
+            }
+            ("callback", None) => {
+                return Err(to_pyvalue_err(
+                    "`overflow_handler` required for 'callback' overflow policy",
+                ))
+            }
+            (policy, _) => {
+                return Err(to_pyvalue_err(format!(
+                    "Invalid `overflow_policy`, was '{policy}'"
+                )))
+            }
+        };
+        let mut config = Self::new(
+            &url,
             mode,
             suffix,
-            handler,
+            MessageHandler::Python(handler),
             heartbeat,
+        )
+        .with_reconnect_backoff(
+            reconnect_delay_initial_ms,
+            reconnect_delay_max_ms,
+            reconnect_backoff_factor,
+            reconnect_max_attempts,
+        );
+        if let Some(max_size) = max_buffer_size {
+            config = config.with_read_buffer_limit(ReadBufferLimit { max_size, policy });
+        }
+        if let Some(proxy) = proxy {
+            config = config.with_proxy(proxy);
+        }
+        if let Some(tls) = tls {
+            config = config.with_tls(tls);
+        }
+        if let Some(latency) = latency {
+            config = config.with_latency(latency);
         }
+        config.reconnect_backoff().map_err(to_pyvalue_err)?;
+        Ok(config)
     }
 }
 
@@ -82,12 +437,15 @@ impl SocketConfig {
 /// The stream can be encrypted with TLS or Plain. The stream is split into
 /// read and write ends.
 /// * The read end is passed to task that keeps receiving
-///   messages from the server and passing them to a handler.
+///   messages from the server and passing them to a handler. It is owned
+///   by the read task, so a pending read never holds the writer lock.
 /// * The write end is wrapped in an Arc Mutex and used to send messages
-///   or heart beats
+///   or heart beats. The lock is only held for the duration of a single
+///   frame write.
 ///
 /// The heartbeat is optional and can be configured with an interval and data to
-/// send.
+/// send. A heartbeat which fails to send ends the heartbeat task, which marks the
+/// connection as dead so that the controller reconnects.
 ///
 /// The client uses a suffix to separate messages on the byte stream. It is
 /// appended to all sent messages and heartbeats. It is also used the split
@@ -98,6 +456,7 @@ impl SocketConfig {
 )]
 struct SocketClientInner {
     config: SocketConfig,
+    inbound_latency: Option<LatencyInjector>,
     read_task: task::JoinHandle<()>,
     heartbeat_task: Option<task::JoinHandle<()>>,
     writer: SharedTcpWriter,
@@ -111,12 +470,26 @@ impl SocketClientInner {
             heartbeat,
             suffix,
             handler,
+            read_buffer_limit,
+            proxy,
+            tls,
+            latency,
+            ..
         } = &config;
-        let (reader, writer) = Self::tls_connect_with_server(url, *mode).await?;
+        let (reader, writer) =
+            Self::tls_connect_with_server(url, *mode, proxy.as_ref(), tls.as_ref()).await?;
         let shared_writer = Arc::new(Mutex::new(writer));
 
+        // The injector is shared across reconnections so the sampled delays continue
+        let inbound_latency = latency.as_ref().and_then(LatencyConfig::inbound_injector);
+
         // Keep receiving messages from socket pass them as arguments to handler
-        let read_task = Self::spawn_read_task(reader, handler.clone(), suffix.clone());
+        let read_task = Self::spawn_read_task(
+            reader,
+            Self::delayed_handler(handler, inbound_latency.as_ref()),
+            suffix.clone(),
+            read_buffer_limit.clone(),
+        );
 
         // Optionally create heartbeat task
         let heartbeat_task =
@@ -124,34 +497,75 @@ impl SocketClientInner {
 
         Ok(Self {
             config,
+            inbound_latency,
             read_task,
             heartbeat_task,
             writer: shared_writer,
         })
     }
 
+    /// Returns the `handler`, delaying messages when inbound latency is configured.
+    fn delayed_handler(
+        handler: &MessageHandler,
+        latency: Option<&LatencyInjector>,
+    ) -> MessageHandler {
+        match latency {
+            Some(injector) => handler.clone().with_latency(injector.clone()),
+            None => handler.clone(),
+        }
+    }
+
     pub async fn tls_connect_with_server(
         url: &str,
         mode: Mode,
+        proxy: Option<&ProxyConfig>,
+        tls: Option<&TlsConfig>,
     ) -> Result<(TcpReader, TcpWriter), Error> {
         debug!("Connecting to server");
-        let stream = TcpStream::connect(url).await?;
+        let stream = match resolve_proxy(proxy) {
+            Some(proxy) => {
+                let (host, port) = url
+                    .rsplit_once(':')
+                    .and_then(|(host, port)| Some((host, port.parse::<u16>().ok()?)))
+                    .ok_or_else(|| {
+                        std::io::Error::new(
+                            std::io::ErrorKind::InvalidInput,
+                            format!("Invalid `url` for proxy connection, was '{url}'"),
+                        )
+                    })?;
+                let host = host.trim_start_matches('[').trim_end_matches(']');
+                proxy.connect(host, port).await?
+            }
+            None => TcpStream::connect(url).await?,
+        };
+        // Send small frames immediately rather than coalescing them (Nagle)
+        stream.set_nodelay(true)?;
         debug!("Making TLS connection");
         let request = url.into_client_request()?;
-        tcp_tls(&request, mode, stream, None).await.map(split)
+        let connector = tls
+            .map(TlsConfig::connector)
+            .transpose()
+            .map_err(|e| Error::Io(std::io::Error::new(std::io::ErrorKind::InvalidInput, e)))?;
+        tcp_tls(&request, mode, stream, connector).await.map(split)
     }
 
     #[must_use]
     pub fn spawn_read_task(
         mut reader: TcpReader,
-        handler: PyObject,
+        handler: MessageHandler,
         suffix: Vec<u8>,
+        read_buffer_limit: Option<ReadBufferLimit>,
     ) -> task::JoinHandle<()> {
         // Keep receiving messages from socket pass them as arguments to handler
         task::spawn(async move {
             let mut buf = Vec::new();
 
             loop {
+                #[cfg(feature = "chaos")]
+                if let Some(delay) = nautilus_core::chaos::socket_read_delay() {
+                    tokio::time::sleep(delay).await;
+                }
+
                 match reader.read_buf(&mut buf).await {
                     // Connection has been terminated or vector buffer is completely
                     Ok(0) => {
@@ -176,13 +590,18 @@ impl SocketClientInner {
                             let mut data: Vec<u8> = buf.drain(0..i + suffix.len()).collect();
                             data.truncate(data.len() - suffix.len());
 
-                            if let Err(e) =
-                                Python::with_gil(|py| handler.call1(py, (data.as_slice(),)))
-                            {
+                            if let Err(e) = handler.handle(&data) {
                                 error!("Call to handler failed: {e}");
                                 break;
                             }
                         }
+
+                        // Bound the remaining partial message
+                        if let Some(ref limit) = read_buffer_limit {
+                            if !limit.apply(&mut buf) {
+                                break;
+                            }
+                        }
                     }
                 };
             }
@@ -205,7 +624,10 @@ impl SocketClientInner {
                     let mut guard = writer.lock().await;
                     match guard.write_all(&message).await {
                         Ok(()) => debug!("Sent heartbeat"),
-                        Err(e) => error!("Failed to send heartbeat: {e}"),
+                        Err(e) => {
+                            error!("Failed to send heartbeat: {e}");
+                            break;
+                        }
                     }
                 }
             })
@@ -239,8 +661,9 @@ impl SocketClientInner {
 
     /// Reconnect with server.
     ///
-    /// Make a new connection with server. Use the new read and write halves
-    /// to update the shared writer and the read and heartbeat tasks.
+    /// Make a new connection with server, re-establishing TLS if configured. Use
+    /// the new read and write halves to update the shared writer and the read and
+    /// heartbeat tasks.
     ///
     /// TODO: fix error type
     pub async fn reconnect(&mut self) -> Result<(), Error> {
@@ -250,9 +673,24 @@ impl SocketClientInner {
             heartbeat,
             suffix,
             handler,
+            read_buffer_limit,
+            proxy,
+            tls,
+            ..
         } = &self.config;
         debug!("Reconnecting client");
-        let (reader, new_writer) = Self::tls_connect_with_server(url, *mode).await?;
+        let (reader, new_writer) =
+            Self::tls_connect_with_server(url, *mode, proxy.as_ref(), tls.as_ref()).await?;
+
+        // Abort any remaining tasks from the previous connection
+        if !self.read_task.is_finished() {
+            self.read_task.abort();
+        }
+        if let Some(ref handle) = self.heartbeat_task.take() {
+            if !handle.is_finished() {
+                handle.abort();
+            }
+        }
 
         debug!("Use new writer end");
         let mut guard = self.writer.lock().await;
@@ -260,7 +698,12 @@ impl SocketClientInner {
         drop(guard);
 
         debug!("Recreate reader and heartbeat task");
-        self.read_task = Self::spawn_read_task(reader, handler.clone(), suffix.clone());
+        self.read_task = Self::spawn_read_task(
+            reader,
+            Self::delayed_handler(handler, self.inbound_latency.as_ref()),
+            suffix.clone(),
+            read_buffer_limit.clone(),
+        );
         self.heartbeat_task =
             Self::spawn_heartbeat_task(heartbeat.clone(), self.writer.clone(), suffix.clone());
         Ok(())
@@ -268,14 +711,18 @@ impl SocketClientInner {
 
     /// Check if the client is still connected.
     ///
-    /// The client is connected if the read task has not finished. It is expected
-    /// that in case of any failure client or server side. The read task will be
-    /// shutdown. There might be some delay between the connection being closed
-    /// and the client detecting it.
+    /// The client is connected if the read task, and the heartbeat task (if any),
+    /// have not finished. It is expected that in case of any failure client or
+    /// server side. The read task will be shutdown. There might be some delay
+    /// between the connection being closed and the client detecting it.
     #[inline]
     #[must_use]
     pub fn is_alive(&self) -> bool {
         !self.read_task.is_finished()
+            && self
+                .heartbeat_task
+                .as_ref()
+                .map_or(true, |handle| !handle.is_finished())
     }
 }
 
@@ -302,42 +749,65 @@ pub struct SocketClient {
     writer: SharedTcpWriter,
     controller_task: task::JoinHandle<()>,
     disconnect_mode: Arc<Mutex<bool>>,
+    reconnect_attempts: Arc<AtomicU32>,
     suffix: Vec<u8>,
+    outbound: Option<DelayLine<Vec<u8>>>,
 }
 
 impl SocketClient {
+    /// Connect to the server.
+    ///
+    /// When the connection drops (including a failed heartbeat) the client
+    /// reconnects with exponential backoff, calling `post_reconnection` after each
+    /// successful reconnection. If the retry budget is used up then
+    /// `post_disconnection` is called and the client terminates.
     pub async fn connect(
         config: SocketConfig,
-        post_connection: Option<PyObject>,
-        post_reconnection: Option<PyObject>,
-        post_disconnection: Option<PyObject>,
+        post_connection: Option<ConnectionHandler>,
+        post_reconnection: Option<ConnectionHandler>,
+        post_disconnection: Option<ConnectionHandler>,
     ) -> Result<Self, Error> {
         let suffix = config.suffix.clone();
+        let outbound_latency = config
+            .latency
+            .as_ref()
+            .and_then(LatencyConfig::outbound_injector);
+        let backoff = config
+            .reconnect_backoff()
+            .map_err(|e| Error::Io(std::io::Error::new(std::io::ErrorKind::InvalidInput, e)))?;
         let inner = SocketClientInner::connect_url(config).await?;
         let writer = inner.writer.clone();
+        let outbound =
+            outbound_latency.map(|injector| spawn_outbound_line(injector, writer.clone()));
         let disconnect_mode = Arc::new(Mutex::new(false));
+        let reconnect_attempts = Arc::new(AtomicU32::new(0));
         let controller_task = Self::spawn_controller_task(
             inner,
             disconnect_mode.clone(),
+            backoff,
+            reconnect_attempts.clone(),
             post_reconnection,
             post_disconnection,
         );
 
-        if let Some(handler) = post_connection {
-            Python::with_gil(|py| match handler.call0(py) {
-                Ok(_) => debug!("Called `post_connection` handler"),
-                Err(e) => error!("Error calling `post_connection` handler: {e}"),
-            });
-        }
+        call_connection_handler(post_connection.as_ref(), "post_connection");
 
         Ok(Self {
             writer,
             controller_task,
             disconnect_mode,
+            reconnect_attempts,
             suffix,
+            outbound,
         })
     }
 
+    /// Returns the number of failed reconnection attempts since the last successful connection.
+    #[must_use]
+    pub fn reconnect_attempts(&self) -> u32 {
+        self.reconnect_attempts.load(Ordering::SeqCst)
+    }
+
     /// Set disconnect mode to true.
     ///
     /// Controller task will periodically check the disconnect mode
@@ -346,10 +816,16 @@ impl SocketClient {
         *self.disconnect_mode.lock().await = true;
     }
 
+    /// Send the `data` followed by the suffix as a single frame.
+    ///
+    /// The frame is assembled before acquiring the writer lock, so the lock is
+    /// only held for one write.
     pub async fn send_bytes(&self, data: &[u8]) -> Result<(), std::io::Error> {
-        let mut writer = self.writer.lock().await;
-        writer.write_all(data).await?;
-        writer.write_all(&self.suffix).await
+        let mut frame = Vec::with_capacity(data.len() + self.suffix.len());
+        frame.extend_from_slice(data);
+        frame.extend_from_slice(&self.suffix);
+
+        send_frame(&self.writer, self.outbound.as_ref(), frame).await
     }
 
     #[must_use]
@@ -360,8 +836,10 @@ impl SocketClient {
     fn spawn_controller_task(
         mut inner: SocketClientInner,
         disconnect_mode: Arc<Mutex<bool>>,
-        post_reconnection: Option<PyObject>,
-        post_disconnection: Option<PyObject>,
+        mut backoff: ExponentialBackoff,
+        reconnect_attempts: Arc<AtomicU32>,
+        post_reconnection: Option<ConnectionHandler>,
+        post_disconnection: Option<ConnectionHandler>,
     ) -> task::JoinHandle<()> {
         task::spawn(async move {
             let mut disconnect_flag;
@@ -374,23 +852,36 @@ impl SocketClient {
                 drop(guard);
 
                 match (disconnect_flag, inner.is_alive()) {
-                    (false, false) => match inner.reconnect().await {
-                        Ok(()) => {
-                            debug!("Reconnected successfully");
-                            if let Some(ref handler) = post_reconnection {
-                                Python::with_gil(|py| match handler.call0(py) {
-                                    Ok(_) => debug!("Called `post_reconnection` handler"),
-                                    Err(e) => {
-                                        error!("Error calling `post_reconnection` handler: {e}");
-                                    }
-                                });
-                            }
-                        }
-                        Err(e) => {
-                            error!("Reconnect failed {e}");
+                    (false, false) => {
+                        let Some(delay) = backoff.next_delay() else {
+                            error!(
+                                "Reconnect failed after {} attempts - terminating",
+                                backoff.attempts()
+                            );
+                            call_connection_handler(
+                                post_disconnection.as_ref(),
+                                "post_disconnection",
+                            );
                             break;
+                        };
+                        sleep(delay).await;
+
+                        match inner.reconnect().await {
+                            Ok(()) => {
+                                debug!("Reconnected successfully");
+                                backoff.reset();
+                                reconnect_attempts.store(0, Ordering::SeqCst);
+                                call_connection_handler(
+                                    post_reconnection.as_ref(),
+                                    "post_reconnection",
+                                );
+                            }
+                            Err(e) => {
+                                reconnect_attempts.store(backoff.attempts(), Ordering::SeqCst);
+                                warn!("Reconnect attempt {} failed: {e}", backoff.attempts());
+                            }
                         }
-                    },
+                    }
                     (true, true) => {
                         debug!("Shutting down inner client");
                         match inner.shutdown().await {
@@ -398,14 +889,7 @@ impl SocketClient {
                             Err(e) => error!("Error on `shutdown`: {e}"),
                         }
 
-                        if let Some(ref handler) = post_disconnection {
-                            Python::with_gil(|py| match handler.call0(py) {
-                                Ok(_) => debug!("Called `post_disconnection` handler"),
-                                Err(e) => {
-                                    error!("Error calling `post_disconnection` handler: {e}");
-                                }
-                            });
-                        }
+                        call_connection_handler(post_disconnection.as_ref(), "post_disconnection");
                         break;
                     }
                     (true, false) => break,
@@ -416,6 +900,41 @@ impl SocketClient {
     }
 }
 
+/// Spawns the delay line for outbound frames, which writes each frame once it is due.
+fn spawn_outbound_line(injector: LatencyInjector, writer: SharedTcpWriter) -> DelayLine<Vec<u8>> {
+    DelayLine::spawn(injector, move |frame: Vec<u8>| {
+        let writer = writer.clone();
+        async move {
+            if let Err(e) = writer.lock().await.write_all(&frame).await {
+                error!("Failed to send delayed frame: {e}");
+            }
+            true
+        }
+    })
+}
+
+/// Writes the `frame`, or queues it on the `outbound` delay line (if any).
+///
+/// A delayed frame is sent after this returns, so any write error is only logged.
+async fn send_frame(
+    writer: &SharedTcpWriter,
+    outbound: Option<&DelayLine<Vec<u8>>>,
+    frame: Vec<u8>,
+) -> Result<(), std::io::Error> {
+    if let Some(line) = outbound {
+        if !line.push(frame) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::BrokenPipe,
+                "Outbound delay line ended",
+            ));
+        }
+        return Ok(());
+    }
+
+    let mut writer = writer.lock().await;
+    writer.write_all(&frame).await
+}
+
 #[pymethods]
 impl SocketClient {
     /// Create a socket client.
@@ -435,9 +954,9 @@ impl SocketClient {
         pyo3_asyncio::tokio::future_into_py(py, async move {
             Self::connect(
                 config,
-                post_connection,
-                post_reconnection,
-                post_disconnection,
+                post_connection.map(ConnectionHandler::Python),
+                post_reconnection.map(ConnectionHandler::Python),
+                post_disconnection.map(ConnectionHandler::Python),
             )
             .await
             .map_err(to_pyruntime_err)
@@ -467,8 +986,8 @@ impl SocketClient {
     /// Check if the client is still alive.
     ///
     /// Even if the connection is disconnected the client will still be alive
-    /// and try to reconnect. Only when reconnect fails the client will
-    /// terminate.
+    /// and try to reconnect. Only when the reconnect retry budget is used up
+    /// will the client terminate.
     ///
     /// This is particularly useful for check why a `send` failed. It could
     /// because the connection disconnected and the client is still alive
@@ -479,6 +998,14 @@ impl SocketClient {
         !slf.controller_task.is_finished()
     }
 
+    /// Returns the number of failed reconnection attempts since the last
+    /// successful connection.
+    #[getter]
+    #[pyo3(name = "reconnect_attempts")]
+    fn py_reconnect_attempts(slf: PyRef<'_, Self>) -> u32 {
+        slf.reconnect_attempts()
+    }
+
     /// Send bytes data to the connection.
     ///
     /// # Safety
@@ -491,11 +1018,11 @@ impl SocketClient {
         py: Python<'py>,
     ) -> PyResult<&'py PyAny> {
         let writer = slf.writer.clone();
+        let outbound = slf.outbound.clone();
         data.extend(&slf.suffix);
 
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let mut writer = writer.lock().await;
-            writer.write_all(&data).await?;
+            send_frame(&writer, outbound.as_ref(), data).await?;
             Ok(())
         })
     }
@@ -506,7 +1033,13 @@ impl SocketClient {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
     use pyo3::{prelude::*, prepare_freethreaded_python};
+    use rstest::rstest;
     use tokio::{
         io::{AsyncReadExt, AsyncWriteExt},
         net::TcpListener,
@@ -517,7 +1050,13 @@ mod tests {
     use tracing::debug;
     use tracing_test::traced_test;
 
-    use crate::socket::{SocketClient, SocketConfig};
+    use crate::{
+        latency::{LatencyConfig, LatencyDistribution},
+        socket::{
+            BufferOverflowPolicy, ConnectionHandler, MessageHandler, ReadBufferLimit, SocketClient,
+            SocketConfig,
+        },
+    };
 
     struct TestServer {
         task: JoinHandle<()>,
@@ -622,13 +1161,14 @@ counter = Counter()",
             (counter, handler)
         });
 
-        let config = SocketConfig {
-            url: format!("127.0.0.1:{}", server.port),
-            handler: handler.clone(),
-            mode: Mode::Plain,
-            suffix: b"\r\n".to_vec(),
-            heartbeat: None,
-        };
+        let config = SocketConfig::new(
+            &format!("127.0.0.1:{}", server.port),
+            Mode::Plain,
+            b"\r\n".to_vec(),
+            MessageHandler::Python(handler.clone()),
+            None,
+        )
+        .with_reconnect_backoff(Some(100), None, None, None);
         let client: SocketClient = SocketClient::connect(config, None, None, None)
             .await
             .unwrap();
@@ -685,4 +1225,196 @@ counter = Counter()",
         sleep(Duration::from_secs(1)).await;
         assert!(client.is_disconnected());
     }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn heartbeat_test() {
+        prepare_freethreaded_python();
+
+        // Initialize test server which echoes the heartbeats back
+        let server = TestServer::basic_client_test().await;
+
+        let (store, handler) = Python::with_gil(|py| {
+            let store: PyObject = pyo3::types::PyList::empty(py).into_py(py);
+            let handler = store.getattr(py, "append").unwrap().into_py(py);
+            (store, handler)
+        });
+
+        let config = SocketConfig::new(
+            &format!("127.0.0.1:{}", server.port),
+            Mode::Plain,
+            b"\r\n".to_vec(),
+            MessageHandler::Python(handler),
+            Some((1, b"heartbeat".to_vec())),
+        );
+        let client = SocketClient::connect(config, None, None, None)
+            .await
+            .unwrap();
+
+        sleep(Duration::from_millis(2_500)).await;
+        let received: Vec<Vec<u8>> = Python::with_gil(|py| store.extract(py).unwrap());
+
+        assert_eq!(received, vec![b"heartbeat".to_vec(); 2]);
+        assert_eq!(client.reconnect_attempts(), 0);
+
+        client.disconnect().await;
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn rust_handler_test() {
+        // Initialize test server
+        let server = TestServer::basic_client_test().await;
+
+        let (handler, mut rx) = MessageHandler::channel();
+        let config = SocketConfig::new(
+            &format!("127.0.0.1:{}", server.port),
+            Mode::Plain,
+            b"\r\n".to_vec(),
+            handler,
+            None,
+        );
+        let client = SocketClient::connect(config, None, None, None)
+            .await
+            .unwrap();
+
+        client.send_bytes(b"ping".as_slice()).await.unwrap();
+        let received = tokio::time::timeout(Duration::from_secs(1), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(received, b"ping".to_vec());
+
+        client.disconnect().await;
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn rust_connection_handlers_test() {
+        // Initialize test server
+        let server = TestServer::basic_client_test().await;
+
+        let (handler, mut rx) = MessageHandler::channel();
+        let config = SocketConfig::new(
+            &format!("127.0.0.1:{}", server.port),
+            Mode::Plain,
+            b"\r\n".to_vec(),
+            handler,
+            None,
+        )
+        .with_reconnect_backoff(Some(100), None, None, None);
+        let connections = Arc::new(AtomicUsize::new(0));
+        let reconnections = Arc::new(AtomicUsize::new(0));
+        let post_connection = {
+            let connections = connections.clone();
+            ConnectionHandler::from_fn(move || {
+                connections.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+        let post_reconnection = {
+            let reconnections = reconnections.clone();
+            ConnectionHandler::from_fn(move || {
+                reconnections.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+        let client =
+            SocketClient::connect(config, Some(post_connection), Some(post_reconnection), None)
+                .await
+                .unwrap();
+
+        // The server drops the connection on a close message, and the client reconnects
+        client.send_bytes(b"close".as_slice()).await.unwrap();
+        sleep(Duration::from_secs(2)).await;
+        client.send_bytes(b"ping".as_slice()).await.unwrap();
+        let received = tokio::time::timeout(Duration::from_secs(1), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(received, b"ping".to_vec());
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+        assert_eq!(reconnections.load(Ordering::SeqCst), 1);
+
+        client.disconnect().await;
+    }
+
+    #[tokio::test]
+    async fn latency_injection_test() {
+        // Initialize test server
+        let server = TestServer::basic_client_test().await;
+
+        let (handler, mut rx) = MessageHandler::channel();
+        let latency = LatencyConfig {
+            inbound: Some(LatencyDistribution::Fixed {
+                delay_ns: 50_000_000,
+            }),
+            outbound: Some(LatencyDistribution::Fixed {
+                delay_ns: 50_000_000,
+            }),
+            ..Default::default()
+        };
+        let config = SocketConfig::new(
+            &format!("127.0.0.1:{}", server.port),
+            Mode::Plain,
+            b"\r\n".to_vec(),
+            handler,
+            None,
+        )
+        .with_latency(latency);
+        let client = SocketClient::connect(config, None, None, None)
+            .await
+            .unwrap();
+
+        let start = std::time::Instant::now();
+        client.send_bytes(b"first".as_slice()).await.unwrap();
+        client.send_bytes(b"second".as_slice()).await.unwrap();
+
+        let mut received = Vec::new();
+        for _ in 0..2 {
+            let data = tokio::time::timeout(Duration::from_secs(1), rx.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            received.push(data);
+        }
+
+        // The round trip is delayed both outbound and inbound, in order
+        assert!(start.elapsed() >= Duration::from_millis(100));
+        assert_eq!(received, vec![b"first".to_vec(), b"second".to_vec()]);
+
+        client.disconnect().await;
+    }
+
+    #[rstest]
+    #[case(BufferOverflowPolicy::Disconnect, false, 8)]
+    #[case(BufferOverflowPolicy::DropOldest, true, 4)]
+    fn test_read_buffer_limit(
+        #[case] policy: BufferOverflowPolicy,
+        #[case] expected_continue: bool,
+        #[case] expected_len: usize,
+    ) {
+        let limit = ReadBufferLimit {
+            max_size: 4,
+            policy,
+        };
+        let mut buf = b"abcdefgh".to_vec();
+
+        assert_eq!(limit.apply(&mut buf), expected_continue);
+        assert_eq!(buf.len(), expected_len);
+    }
+
+    #[rstest]
+    fn test_read_buffer_limit_callback() {
+        let (handler, mut rx) = MessageHandler::channel();
+        let limit = ReadBufferLimit {
+            max_size: 4,
+            policy: BufferOverflowPolicy::Callback(handler),
+        };
+        let mut buf = b"abcdefgh".to_vec();
+
+        assert!(limit.apply(&mut buf));
+        assert!(buf.is_empty());
+        assert_eq!(rx.try_recv().unwrap(), b"abcdefgh".to_vec());
+    }
 }
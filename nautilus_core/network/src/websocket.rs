@@ -15,7 +15,14 @@
 
 //! A high-performance WebSocket client implementation.
 
-use std::{str::FromStr, sync::Arc, time::Duration};
+use std::{
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use futures_util::{
     stream::{SplitSink, SplitStream},
@@ -26,16 +33,31 @@ use nautilus_core::python::{to_pyruntime_err, to_pyvalue_err};
 use pyo3::{prelude::*, types::PyBytes};
 use tokio::{net::TcpStream, sync::Mutex, task, time::sleep};
 use tokio_tungstenite::{
-    connect_async,
-    tungstenite::{client::IntoClientRequest, http::HeaderValue, Error, Message},
+    client_async,
+    tls::tcp_tls,
+    tungstenite::{
+        client::{uri_mode, IntoClientRequest},
+        error::UrlError,
+        http::{header::SEC_WEBSOCKET_EXTENSIONS, HeaderValue},
+        Error, Message,
+    },
     MaybeTlsStream, WebSocketStream,
 };
-use tracing::{debug, error};
+use tracing::{debug, error, warn};
+
+use crate::{
+    backoff::ExponentialBackoff,
+    compression::{CompressionMetrics, PerMessageDeflateStream, PERMESSAGE_DEFLATE_OFFER},
+    latency::{DelayLine, LatencyConfig, LatencyInjector},
+    proxy::{resolve_proxy, ProxyConfig},
+    ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter},
+    tls::TlsConfig,
+};
 
-type MessageWriter = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>;
-type SharedMessageWriter =
-    Arc<Mutex<SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>>>;
-type MessageReader = SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>;
+type WsStream = WebSocketStream<PerMessageDeflateStream<MaybeTlsStream<TcpStream>>>;
+type MessageWriter = SplitSink<WsStream, Message>;
+type SharedMessageWriter = Arc<Mutex<SplitSink<WsStream, Message>>>;
+type MessageReader = SplitStream<WsStream>;
 
 #[derive(Debug, Clone)]
 #[cfg_attr(
@@ -49,11 +71,40 @@ pub struct WebSocketConfig {
     heartbeat: Option<u64>,
     heartbeat_msg: Option<String>,
     ping_handler: Option<PyObject>,
+    reconnect_delay_initial_ms: Option<u64>,
+    reconnect_delay_max_ms: Option<u64>,
+    reconnect_backoff_factor: Option<f64>,
+    reconnect_max_attempts: Option<u32>,
+    proxy: Option<ProxyConfig>,
+    compression: bool,
+    tls: Option<TlsConfig>,
+    latency: Option<LatencyConfig>,
+}
+
+impl WebSocketConfig {
+    /// Returns the reconnection backoff for the config.
+    ///
+    /// Defaults to an initial delay of 1 second, doubling up to 30 seconds, with no limit on
+    /// the number of attempts.
+    pub fn reconnect_backoff(&self) -> anyhow::Result<ExponentialBackoff> {
+        let delay_initial = self.reconnect_delay_initial_ms.unwrap_or(1_000);
+        let delay_max = self
+            .reconnect_delay_max_ms
+            .unwrap_or(30_000)
+            .max(delay_initial);
+        ExponentialBackoff::new(
+            Duration::from_millis(delay_initial),
+            Duration::from_millis(delay_max),
+            self.reconnect_backoff_factor.unwrap_or(2.0),
+            self.reconnect_max_attempts,
+        )
+    }
 }
 
 #[pymethods]
 impl WebSocketConfig {
     #[new]
+    #[allow(clippy::too_many_arguments)]
     fn py_new(
         url: String,
         handler: PyObject,
@@ -61,15 +112,33 @@ impl WebSocketConfig {
         heartbeat: Option<u64>,
         heartbeat_msg: Option<String>,
         ping_handler: Option<PyObject>,
-    ) -> Self {
-        Self {
+        reconnect_delay_initial_ms: Option<u64>,
+        reconnect_delay_max_ms: Option<u64>,
+        reconnect_backoff_factor: Option<f64>,
+        reconnect_max_attempts: Option<u32>,
+        proxy: Option<ProxyConfig>,
+        compression: Option<bool>,
+        tls: Option<TlsConfig>,
+        latency: Option<LatencyConfig>,
+    ) -> PyResult<Self> {
+        let config = Self {
             url,
             handler,
             headers,
             heartbeat,
             heartbeat_msg,
             ping_handler,
-        }
+            reconnect_delay_initial_ms,
+            reconnect_delay_max_ms,
+            reconnect_backoff_factor,
+            reconnect_max_attempts,
+            proxy,
+            compression: compression.unwrap_or(false),
+            tls,
+            latency,
+        };
+        config.reconnect_backoff().map_err(to_pyvalue_err)?;
+        Ok(config)
     }
 }
 
@@ -90,9 +159,11 @@ impl WebSocketConfig {
 /// frequently - than the required amount.
 struct WebSocketClientInner {
     config: WebSocketConfig,
+    inbound_latency: Option<LatencyInjector>,
     read_task: task::JoinHandle<()>,
     heartbeat_task: Option<task::JoinHandle<()>>,
     writer: SharedMessageWriter,
+    compression_metrics: Arc<CompressionMetrics>,
 }
 
 impl WebSocketClientInner {
@@ -105,28 +176,62 @@ impl WebSocketClientInner {
             headers,
             heartbeat_msg,
             ping_handler,
+            proxy,
+            compression,
+            tls,
+            latency,
+            ..
         } = &config;
-        let (writer, reader) = Self::connect_with_server(url, headers.clone()).await?;
+        let compression_metrics = Arc::new(CompressionMetrics::default());
+        let (writer, reader) = Self::connect_with_server(
+            url,
+            headers.clone(),
+            proxy.as_ref(),
+            tls.as_ref(),
+            *compression,
+            compression_metrics.clone(),
+        )
+        .await?;
         let writer = Arc::new(Mutex::new(writer));
 
+        // The injector is shared across reconnections so the sampled delays continue
+        let inbound_latency = latency.as_ref().and_then(LatencyConfig::inbound_injector);
+
         // Keep receiving messages from socket and pass them as arguments to handler
-        let read_task = Self::spawn_read_task(reader, handler.clone(), ping_handler.clone());
+        let read_task = Self::spawn_read_task(
+            reader,
+            handler.clone(),
+            ping_handler.clone(),
+            inbound_latency.clone(),
+        );
         let heartbeat_task =
             Self::spawn_heartbeat_task(*heartbeat, heartbeat_msg.clone(), writer.clone());
 
         Ok(Self {
             config,
+            inbound_latency,
             read_task,
             heartbeat_task,
             writer,
+            compression_metrics,
         })
     }
 
     /// Connects with the server creating a tokio-tungstenite websocket stream.
+    ///
+    /// The connection is tunnelled through the `proxy` if given, otherwise through the
+    /// default proxy (if set). The TLS connection uses the `tls` config if given (e.g. for
+    /// custom root CAs or pinned certificates). If `compression` is enabled then the permessage-deflate
+    /// extension is offered, and messages compressed by the server are decompressed
+    /// transparently (recorded in the `compression_metrics`).
     #[inline]
     pub async fn connect_with_server(
         url: &str,
         headers: Vec<(String, String)>,
+        proxy: Option<&ProxyConfig>,
+        tls: Option<&TlsConfig>,
+        compression: bool,
+        compression_metrics: Arc<CompressionMetrics>,
     ) -> Result<(MessageWriter, MessageReader), Error> {
         let mut request = url.into_client_request()?;
         let req_headers = request.headers_mut();
@@ -140,7 +245,43 @@ impl WebSocketClientInner {
             req_headers.insert(header_name_str, header_value);
         }
 
-        connect_async(request).await.map(|resp| resp.0.split())
+        if compression {
+            req_headers.insert(
+                SEC_WEBSOCKET_EXTENSIONS,
+                HeaderValue::from_static(PERMESSAGE_DEFLATE_OFFER),
+            );
+        }
+
+        let uri = request.uri();
+        let mode = uri_mode(uri)?;
+        let host = uri
+            .host()
+            .ok_or(Error::Url(UrlError::NoHostName))?
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_string();
+        let port = uri
+            .port_u16()
+            .or_else(|| match uri.scheme_str() {
+                Some("wss") => Some(443),
+                Some("ws") => Some(80),
+                _ => None,
+            })
+            .ok_or(Error::Url(UrlError::UnsupportedUrlScheme))?;
+
+        let stream = match resolve_proxy(proxy) {
+            Some(proxy) => proxy.connect(&host, port).await?,
+            None => TcpStream::connect((host.as_str(), port)).await?,
+        };
+        let connector = tls
+            .map(TlsConfig::connector)
+            .transpose()
+            .map_err(|e| Error::Io(std::io::Error::new(std::io::ErrorKind::InvalidInput, e)))?;
+        let stream = tcp_tls(&request, mode, stream, connector).await?;
+        let stream = PerMessageDeflateStream::new(stream, compression, compression_metrics);
+        client_async(request, stream)
+            .await
+            .map(|resp| resp.0.split())
     }
 
     /// Optionally spawn a hearbeat task to periodically ping the server.
@@ -170,17 +311,40 @@ impl WebSocketClientInner {
     }
 
     /// Keep receiving messages from socket and pass them as arguments to handler.
+    ///
+    /// If an inbound `latency` injector is given then data messages are passed to the
+    /// handler after the sampled delay, in order.
     pub fn spawn_read_task(
         mut reader: MessageReader,
         handler: PyObject,
         ping_handler: Option<PyObject>,
+        latency: Option<LatencyInjector>,
     ) -> task::JoinHandle<()> {
         debug!("Started task `read`");
+        let delay_line = latency.map(|injector| {
+            let handler = handler.clone();
+            DelayLine::spawn(injector, move |data: Vec<u8>| {
+                if let Err(e) = Python::with_gil(|py| handler.call1(py, (PyBytes::new(py, &data),)))
+                {
+                    error!("Error calling handler: {e}");
+                }
+                async { true }
+            })
+        });
         task::spawn(async move {
             loop {
+                #[cfg(feature = "chaos")]
+                if let Some(delay) = nautilus_core::chaos::socket_read_delay() {
+                    tokio::time::sleep(delay).await;
+                }
+
                 match reader.next().await {
                     Some(Ok(Message::Binary(data))) => {
                         debug!("Received message <binary>");
+                        if let Some(ref line) = delay_line {
+                            line.push(data);
+                            continue;
+                        }
                         if let Err(e) =
                             Python::with_gil(|py| handler.call1(py, (PyBytes::new(py, &data),)))
                         {
@@ -191,6 +355,10 @@ impl WebSocketClientInner {
                     }
                     Some(Ok(Message::Text(data))) => {
                         debug!("Received message: {data}");
+                        if let Some(ref line) = delay_line {
+                            line.push(data.into_bytes());
+                            continue;
+                        }
                         if let Err(e) = Python::with_gil(|py| {
                             handler.call1(py, (PyBytes::new(py, data.as_bytes()),))
                         }) {
@@ -268,8 +436,15 @@ impl WebSocketClientInner {
     /// Make a new connection with server. Use the new read and write halves
     /// to update self writer and read and heartbeat tasks.
     pub async fn reconnect(&mut self) -> Result<(), Error> {
-        let (new_writer, reader) =
-            Self::connect_with_server(&self.config.url, self.config.headers.clone()).await?;
+        let (new_writer, reader) = Self::connect_with_server(
+            &self.config.url,
+            self.config.headers.clone(),
+            self.config.proxy.as_ref(),
+            self.config.tls.as_ref(),
+            self.config.compression,
+            self.compression_metrics.clone(),
+        )
+        .await?;
         let mut guard = self.writer.lock().await;
         *guard = new_writer;
         drop(guard);
@@ -278,6 +453,7 @@ impl WebSocketClientInner {
             reader,
             self.config.handler.clone(),
             self.config.ping_handler.clone(),
+            self.inbound_latency.clone(),
         );
         self.heartbeat_task = Self::spawn_heartbeat_task(
             self.config.heartbeat,
@@ -323,6 +499,10 @@ pub struct WebSocketClient {
     writer: SharedMessageWriter,
     controller_task: task::JoinHandle<()>,
     disconnect_mode: Arc<Mutex<bool>>,
+    reconnect_attempts: Arc<AtomicU32>,
+    rate_limiter: Arc<RateLimiter<String, MonotonicClock>>,
+    compression_metrics: Arc<CompressionMetrics>,
+    outbound: Option<DelayLine<Message>>,
 }
 
 impl WebSocketClient {
@@ -330,19 +510,42 @@ impl WebSocketClient {
     ///
     /// Creates an inner client and controller task to reconnect or disconnect
     /// the client. Also assumes ownership of writer from inner client.
+    ///
+    /// When the connection drops the client reconnects with exponential backoff, calling
+    /// `post_reconnection` after each successful reconnection (e.g. to resubscribe). If the
+    /// retry budget is used up then `post_disconnection` is called and the client terminates.
+    ///
+    /// Outbound messages are rate limited per key using the `keyed_quotas`, falling back to the
+    /// `default_quota` for keys without a specific quota.
     pub async fn connect(
         config: WebSocketConfig,
         post_connection: Option<PyObject>,
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
+        keyed_quotas: Vec<(String, Quota)>,
+        default_quota: Option<Quota>,
     ) -> Result<Self, Error> {
         debug!("Connecting");
+        let outbound_latency = config
+            .latency
+            .as_ref()
+            .and_then(LatencyConfig::outbound_injector);
+        let backoff = config
+            .reconnect_backoff()
+            .map_err(|e| Error::Io(std::io::Error::new(std::io::ErrorKind::InvalidInput, e)))?;
         let inner = WebSocketClientInner::connect_url(config).await?;
         let writer = inner.writer.clone();
+        let outbound =
+            outbound_latency.map(|injector| spawn_outbound_line(injector, writer.clone()));
+        let compression_metrics = inner.compression_metrics.clone();
         let disconnect_mode = Arc::new(Mutex::new(false));
+        let reconnect_attempts = Arc::new(AtomicU32::new(0));
+        let rate_limiter = Arc::new(RateLimiter::new_with_quota(default_quota, keyed_quotas));
         let controller_task = Self::spawn_controller_task(
             inner,
             disconnect_mode.clone(),
+            backoff,
+            reconnect_attempts.clone(),
             post_reconnection,
             post_disconnection,
         );
@@ -358,9 +561,25 @@ impl WebSocketClient {
             writer,
             controller_task,
             disconnect_mode,
+            reconnect_attempts,
+            rate_limiter,
+            compression_metrics,
+            outbound,
         })
     }
 
+    /// Returns the metrics for messages received compressed with permessage-deflate.
+    #[must_use]
+    pub fn compression_metrics(&self) -> &CompressionMetrics {
+        &self.compression_metrics
+    }
+
+    /// Returns the number of failed reconnection attempts since the last successful connection.
+    #[must_use]
+    pub fn reconnect_attempts(&self) -> u32 {
+        self.reconnect_attempts.load(Ordering::SeqCst)
+    }
+
     #[must_use]
     pub fn is_disconnected(&self) -> bool {
         self.controller_task.is_finished()
@@ -377,13 +596,21 @@ impl WebSocketClient {
 
     pub async fn send_bytes(&self, data: Vec<u8>) -> Result<(), Error> {
         debug!("Sending bytes: {:?}", data);
-        let mut guard = self.writer.lock().await;
-        guard.send(Message::Binary(data)).await
+        send_message(&self.writer, self.outbound.as_ref(), Message::Binary(data)).await
+    }
+
+    /// Sends a text message once the quota for each of the rate limiting `keys` allows it.
+    ///
+    /// Each key is charged the `weight` of the message, and the message is queued (not
+    /// rejected) until all of the keys have capacity.
+    pub async fn send_text(&self, data: String, keys: &[String], weight: u32) -> Result<(), Error> {
+        wait_for_quota(&self.rate_limiter, keys, weight).await;
+        debug!("Sending text: {}", data);
+        send_message(&self.writer, self.outbound.as_ref(), Message::Text(data)).await
     }
 
     pub async fn send_close_message(&self) {
-        let mut guard = self.writer.lock().await;
-        match guard.send(Message::Close(None)).await {
+        match send_message(&self.writer, self.outbound.as_ref(), Message::Close(None)).await {
             Ok(()) => debug!("Sent close message"),
             Err(e) => error!("Error sending close message: {e}"),
         }
@@ -392,6 +619,8 @@ impl WebSocketClient {
     fn spawn_controller_task(
         mut inner: WebSocketClientInner,
         disconnect_mode: Arc<Mutex<bool>>,
+        mut backoff: ExponentialBackoff,
+        reconnect_attempts: Arc<AtomicU32>,
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
     ) -> task::JoinHandle<()> {
@@ -406,34 +635,34 @@ impl WebSocketClient {
                 drop(guard);
 
                 match (disconnect_flag, inner.is_alive()) {
-                    (false, false) => match inner.reconnect().await {
-                        Ok(()) => {
-                            debug!("Reconnected successfully");
-                            if let Some(ref handler) = post_reconnection {
-                                Python::with_gil(|py| match handler.call0(py) {
-                                    Ok(_) => debug!("Called `post_reconnection` handler"),
-                                    Err(e) => {
-                                        error!("Error calling `post_reconnection` handler: {e}");
-                                    }
-                                });
-                            }
-                        }
-                        Err(e) => {
-                            error!("Reconnect failed {e}");
+                    (false, false) => {
+                        let Some(delay) = backoff.next_delay() else {
+                            error!(
+                                "Reconnect failed after {} attempts - terminating",
+                                backoff.attempts()
+                            );
+                            call_handler(post_disconnection.as_ref(), "post_disconnection");
                             break;
+                        };
+                        sleep(delay).await;
+
+                        match inner.reconnect().await {
+                            Ok(()) => {
+                                debug!("Reconnected successfully");
+                                backoff.reset();
+                                reconnect_attempts.store(0, Ordering::SeqCst);
+                                call_handler(post_reconnection.as_ref(), "post_reconnection");
+                            }
+                            Err(e) => {
+                                reconnect_attempts.store(backoff.attempts(), Ordering::SeqCst);
+                                warn!("Reconnect attempt {} failed: {e}", backoff.attempts());
+                            }
                         }
-                    },
+                    }
                     (true, true) => {
                         debug!("Shutting down inner client");
                         inner.shutdown().await;
-                        if let Some(ref handler) = post_disconnection {
-                            Python::with_gil(|py| match handler.call0(py) {
-                                Ok(_) => debug!("Called `post_reconnection` handler"),
-                                Err(e) => {
-                                    error!("Error calling `post_reconnection` handler: {e}");
-                                }
-                            });
-                        }
+                        call_handler(post_disconnection.as_ref(), "post_disconnection");
                         break;
                     }
                     (true, false) => break,
@@ -444,13 +673,69 @@ impl WebSocketClient {
     }
 }
 
+/// Spawns the delay line for outbound messages, which sends each message once it is due.
+fn spawn_outbound_line(
+    injector: LatencyInjector,
+    writer: SharedMessageWriter,
+) -> DelayLine<Message> {
+    DelayLine::spawn(injector, move |msg: Message| {
+        let writer = writer.clone();
+        async move {
+            if let Err(e) = writer.lock().await.send(msg).await {
+                error!("Error sending delayed message: {e}");
+            }
+            true
+        }
+    })
+}
+
+/// Sends the `msg`, or queues it on the `outbound` delay line (if any).
+///
+/// A delayed message is sent after this returns, so any send error is only logged.
+async fn send_message(
+    writer: &SharedMessageWriter,
+    outbound: Option<&DelayLine<Message>>,
+    msg: Message,
+) -> Result<(), Error> {
+    if let Some(line) = outbound {
+        if !line.push(msg) {
+            return Err(Error::AlreadyClosed);
+        }
+        return Ok(());
+    }
+
+    let mut guard = writer.lock().await;
+    guard.send(msg).await
+}
+
+/// Waits until each of the rate limiting `keys` has quota for a message of the given `weight`.
+async fn wait_for_quota(
+    rate_limiter: &RateLimiter<String, MonotonicClock>,
+    keys: &[String],
+    weight: u32,
+) {
+    for key in keys {
+        rate_limiter.until_key_ready_n(key, weight).await;
+    }
+}
+
+/// Calls the optional `handler` (with no arguments), logging any error.
+pub(crate) fn call_handler(handler: Option<&PyObject>, name: &str) {
+    if let Some(handler) = handler {
+        Python::with_gil(|py| match handler.call0(py) {
+            Ok(_) => debug!("Called `{name}` handler"),
+            Err(e) => error!("Error calling `{name}` handler: {e}"),
+        });
+    }
+}
+
 #[pymethods]
 impl WebSocketClient {
     /// Check if the client is still alive.
     ///
     /// Even if the connection is disconnected the client will still be alive
-    /// and trying to reconnect. Only when reconnect fails the client will
-    /// terminate.
+    /// and trying to reconnect. Only when the reconnect retry budget is used
+    /// up will the client terminate.
     ///
     /// This is particularly useful for checking why a `send` failed. It could
     /// because the connection disconnected and the client is still alive
@@ -461,6 +746,35 @@ impl WebSocketClient {
         !slf.controller_task.is_finished()
     }
 
+    /// Returns the number of failed reconnection attempts since the last
+    /// successful connection.
+    #[getter]
+    #[pyo3(name = "reconnect_attempts")]
+    fn py_reconnect_attempts(slf: PyRef<'_, Self>) -> u32 {
+        slf.reconnect_attempts()
+    }
+
+    /// Returns the count of messages received compressed.
+    #[getter]
+    #[pyo3(name = "compressed_messages")]
+    fn py_compressed_messages(slf: PyRef<'_, Self>) -> u64 {
+        slf.compression_metrics.messages()
+    }
+
+    /// Returns the total compressed payload bytes received.
+    #[getter]
+    #[pyo3(name = "compressed_bytes")]
+    fn py_compressed_bytes(slf: PyRef<'_, Self>) -> u64 {
+        slf.compression_metrics.compressed_bytes()
+    }
+
+    /// Returns the total payload bytes received after decompression.
+    #[getter]
+    #[pyo3(name = "decompressed_bytes")]
+    fn py_decompressed_bytes(slf: PyRef<'_, Self>) -> u64 {
+        slf.compression_metrics.decompressed_bytes()
+    }
+
     /// Create a websocket client.
     ///
     /// # Safety
@@ -468,11 +782,21 @@ impl WebSocketClient {
     /// - Throws an Exception if it is unable to make websocket connection
     #[staticmethod]
     #[pyo3(name = "connect")]
+    #[pyo3(signature = (
+        config,
+        post_connection = None,
+        post_reconnection = None,
+        post_disconnection = None,
+        keyed_quotas = Vec::new(),
+        default_quota = None,
+    ))]
     fn py_connect(
         config: WebSocketConfig,
         post_connection: Option<PyObject>,
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
+        keyed_quotas: Vec<(String, Quota)>,
+        default_quota: Option<Quota>,
         py: Python<'_>,
     ) -> PyResult<&PyAny> {
         pyo3_asyncio::tokio::future_into_py(py, async move {
@@ -481,6 +805,8 @@ impl WebSocketClient {
                 post_connection,
                 post_reconnection,
                 post_disconnection,
+                keyed_quotas,
+                default_quota,
             )
             .await
             .map_err(to_pyruntime_err)
@@ -507,17 +833,28 @@ impl WebSocketClient {
 
     /// Send bytes data to the server.
     ///
+    /// The message is queued until each of the rate limiting `keys` has quota for its `weight`.
+    ///
     /// # Safety
     ///
     /// - Raises PyRuntimeError if not able to send data.
     #[pyo3(name = "send")]
-    fn py_send<'py>(slf: PyRef<'_, Self>, data: Vec<u8>, py: Python<'py>) -> PyResult<&'py PyAny> {
-        debug!("Sending bytes {:?}", data);
+    fn py_send<'py>(
+        slf: PyRef<'_, Self>,
+        data: Vec<u8>,
+        keys: Option<Vec<String>>,
+        weight: Option<u32>,
+        py: Python<'py>,
+    ) -> PyResult<&'py PyAny> {
+        let keys = keys.unwrap_or_default();
+        let weight = weight.unwrap_or(1);
         let writer = slf.writer.clone();
+        let outbound = slf.outbound.clone();
+        let rate_limiter = slf.rate_limiter.clone();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let mut guard = writer.lock().await;
-            guard
-                .send(Message::Binary(data))
+            wait_for_quota(&rate_limiter, &keys, weight).await;
+            debug!("Sending bytes {:?}", data);
+            send_message(&writer, outbound.as_ref(), Message::Binary(data))
                 .await
                 .map_err(to_pyruntime_err)
         })
@@ -525,6 +862,8 @@ impl WebSocketClient {
 
     /// Send text data to the server.
     ///
+    /// The message is queued until each of the rate limiting `keys` has quota for its `weight`.
+    ///
     /// # Safety
     ///
     /// - Raises PyRuntimeError if not able to send data.
@@ -532,14 +871,19 @@ impl WebSocketClient {
     fn py_send_text<'py>(
         slf: PyRef<'_, Self>,
         data: String,
+        keys: Option<Vec<String>>,
+        weight: Option<u32>,
         py: Python<'py>,
     ) -> PyResult<&'py PyAny> {
-        debug!("Sending text: {}", data);
+        let keys = keys.unwrap_or_default();
+        let weight = weight.unwrap_or(1);
         let writer = slf.writer.clone();
+        let outbound = slf.outbound.clone();
+        let rate_limiter = slf.rate_limiter.clone();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let mut guard = writer.lock().await;
-            guard
-                .send(Message::Text(data))
+            wait_for_quota(&rate_limiter, &keys, weight).await;
+            debug!("Sending text: {}", data);
+            send_message(&writer, outbound.as_ref(), Message::Text(data))
                 .await
                 .map_err(to_pyruntime_err)
         })
@@ -559,10 +903,9 @@ impl WebSocketClient {
         let data_str = String::from_utf8(data.clone()).map_err(to_pyvalue_err)?;
         debug!("Sending pong: {}", data_str);
         let writer = slf.writer.clone();
+        let outbound = slf.outbound.clone();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let mut guard = writer.lock().await;
-            guard
-                .send(Message::Pong(data))
+            send_message(&writer, outbound.as_ref(), Message::Pong(data))
                 .await
                 .map_err(to_pyruntime_err)
         })
@@ -713,8 +1056,17 @@ counter = Counter()",
             None,
             None,
             None,
-        );
-        let client = WebSocketClient::connect(config, None, None, None)
+            Some(100),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let client = WebSocketClient::connect(config, None, None, None, vec![], None)
             .await
             .unwrap();
 
@@ -818,8 +1170,17 @@ checker = Checker()",
             Some(1),
             Some("heartbeat message".to_string()),
             None,
-        );
-        let client = WebSocketClient::connect(config, None, None, None)
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let client = WebSocketClient::connect(config, None, None, None, vec![], None)
             .await
             .unwrap();
 
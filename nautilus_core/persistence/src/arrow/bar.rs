@@ -145,16 +145,8 @@ impl DecodeFromRecordBatch for Bar {
                 let ts_event = ts_event_values.value(i).into();
                 let ts_init = ts_init_values.value(i).into();
 
-                Ok(Self {
-                    bar_type,
-                    open,
-                    high,
-                    low,
-                    close,
-                    volume,
-                    ts_event,
-                    ts_init,
-                })
+                Self::new_checked(bar_type, open, high, low, close, volume, ts_event, ts_init)
+                    .map_err(|e| EncodingError::ParseError("bar", e.to_string()))
             })
             .collect();
 
@@ -284,7 +276,7 @@ mod tests {
         let metadata = Bar::get_metadata(&bar_type, 2, 0);
 
         let open = Int64Array::from(vec![100_100_000_000, 10_000_000_000]);
-        let high = Int64Array::from(vec![102_000_000_000, 10_000_000_000]);
+        let high = Int64Array::from(vec![102_000_000_000, 10_010_000_000]);
         let low = Int64Array::from(vec![100_000_000_000, 10_000_000_000]);
         let close = Int64Array::from(vec![101_000_000_000, 10_010_000_000]);
         let volume = UInt64Array::from(vec![11_000_000_000, 10_000_000_000]);
@@ -308,4 +300,28 @@ mod tests {
         let decoded_data = Bar::decode_batch(&metadata, record_batch).unwrap();
         assert_eq!(decoded_data.len(), 2);
     }
+
+    #[rstest]
+    fn test_decode_batch_with_invalid_bar() {
+        let bar_type = BarType::from_str("AAPL.XNAS-1-MINUTE-LAST-INTERNAL").unwrap();
+        let metadata = Bar::get_metadata(&bar_type, 2, 0);
+
+        let record_batch = RecordBatch::try_new(
+            Bar::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(Int64Array::from(vec![100_100_000_000])),
+                Arc::new(Int64Array::from(vec![100_000_000_000])), // High below open
+                Arc::new(Int64Array::from(vec![99_000_000_000])),
+                Arc::new(Int64Array::from(vec![100_000_000_000])),
+                Arc::new(UInt64Array::from(vec![11_000_000_000])),
+                Arc::new(UInt64Array::from(vec![1])),
+                Arc::new(UInt64Array::from(vec![3])),
+            ],
+        )
+        .unwrap();
+
+        let result = Bar::decode_batch(&metadata, record_batch);
+
+        assert!(matches!(result, Err(EncodingError::ParseError("bar", _))));
+    }
 }
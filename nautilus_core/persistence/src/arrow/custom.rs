@@ -0,0 +1,159 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use datafusion::arrow::{
+    array::{BinaryArray, BinaryBuilder, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::data::custom::{self, CustomData};
+
+use super::{
+    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_METADATA, KEY_TYPE_NAME,
+};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for CustomData {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("value", DataType::Binary, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(metadata: &HashMap<String, String>) -> Result<custom::DataType, EncodingError> {
+    let type_name = metadata
+        .get(KEY_TYPE_NAME)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_TYPE_NAME))?;
+
+    let type_metadata = match metadata.get(KEY_METADATA) {
+        Some(json) => serde_json::from_str::<BTreeMap<String, String>>(json)
+            .map_err(|e| EncodingError::ParseError(KEY_METADATA, e.to_string()))?,
+        None => BTreeMap::new(),
+    };
+
+    Ok(custom::DataType::new(type_name, type_metadata))
+}
+
+impl EncodeToRecordBatch for CustomData {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut value_builder = BinaryBuilder::new();
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for custom in data {
+            value_builder.append_value(&custom.value);
+            ts_event_builder.append_value(custom.ts_event.as_u64());
+            ts_init_builder.append_value(custom.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(value_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for CustomData {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let data_type = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let value_values = extract_column::<BinaryArray>(cols, "value", 0, DataType::Binary)?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 1, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 2, DataType::UInt64)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                Self::new(
+                    data_type.clone(),
+                    value_values.value(i).to_vec(),
+                    ts_event_values.value(i).into(),
+                    ts_init_values.value(i).into(),
+                )
+                .map_err(|e| EncodingError::ParseError(KEY_TYPE_NAME, e.to_string()))
+            })
+            .collect()
+    }
+}
+
+impl DecodeDataFromRecordBatch for CustomData {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let custom: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(custom.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::stubs::stub_custom_data;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_encode_decode_round_trip(stub_custom_data: CustomData) {
+        let metadata = CustomData::get_metadata(&stub_custom_data.data_type);
+        let data = vec![stub_custom_data];
+
+        let record_batch = CustomData::encode_batch(&metadata, &data).unwrap();
+        let decoded = CustomData::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, data);
+    }
+
+    #[rstest]
+    fn test_decode_unregistered_type(stub_custom_data: CustomData) {
+        let mut metadata = CustomData::get_metadata(&stub_custom_data.data_type);
+        let record_batch = CustomData::encode_batch(&metadata, &[stub_custom_data]).unwrap();
+        metadata.insert(KEY_TYPE_NAME.to_string(), "UnregisteredType".to_string());
+
+        let result = CustomData::decode_batch(&metadata, record_batch);
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::ParseError(KEY_TYPE_NAME, _))
+        ));
+    }
+}
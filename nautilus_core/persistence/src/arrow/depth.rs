@@ -13,7 +13,11 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, str::FromStr, sync::Arc};
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{Arc, OnceLock},
+};
 
 use datafusion::arrow::{
     array::{Array, Int64Array, UInt32Array, UInt64Array, UInt8Array},
@@ -23,7 +27,7 @@ use datafusion::arrow::{
 };
 use nautilus_model::{
     data::{
-        depth::{OrderBookDepth10, DEPTH10_LEN},
+        depth::{OrderBookDepth10, OrderBookDepth50, DEPTH10_LEN, DEPTH50_LEN},
         order::BookOrder,
     },
     enums::OrderSide,
@@ -419,6 +423,244 @@ impl DecodeDataFromRecordBatch for OrderBookDepth10 {
     }
 }
 
+/// The per-level column groups for `OrderBookDepth50`, in schema order.
+const DEPTH50_LEVEL_COLUMNS: [(&str, DataType); 6] = [
+    ("bid_price", DataType::Int64),
+    ("ask_price", DataType::Int64),
+    ("bid_size", DataType::UInt64),
+    ("ask_size", DataType::UInt64),
+    ("bid_count", DataType::UInt32),
+    ("ask_count", DataType::UInt32),
+];
+
+/// Returns the per-level column names for `OrderBookDepth50`, where the name for level `i`
+/// of group `g` is at index `g * DEPTH50_LEN + i`.
+fn depth50_column_names() -> &'static [String] {
+    static COLUMN_NAMES: OnceLock<Vec<String>> = OnceLock::new();
+    COLUMN_NAMES.get_or_init(|| {
+        DEPTH50_LEVEL_COLUMNS
+            .iter()
+            .flat_map(|(prefix, _)| (0..DEPTH50_LEN).map(move |i| format!("{prefix}_{i}")))
+            .collect()
+    })
+}
+
+impl ArrowSchemaProvider for OrderBookDepth50 {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let names = depth50_column_names();
+        let mut fields = Vec::with_capacity(names.len() + 4);
+        for (g, (_, data_type)) in DEPTH50_LEVEL_COLUMNS.iter().enumerate() {
+            for i in 0..DEPTH50_LEN {
+                fields.push(Field::new(
+                    names[g * DEPTH50_LEN + i].as_str(),
+                    data_type.clone(),
+                    false,
+                ));
+            }
+        }
+        fields.push(Field::new("flags", DataType::UInt8, false));
+        fields.push(Field::new("sequence", DataType::UInt64, false));
+        fields.push(Field::new("ts_event", DataType::UInt64, false));
+        fields.push(Field::new("ts_init", DataType::UInt64, false));
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for OrderBookDepth50 {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut bid_price_builders = Vec::with_capacity(DEPTH50_LEN);
+        let mut ask_price_builders = Vec::with_capacity(DEPTH50_LEN);
+        let mut bid_size_builders = Vec::with_capacity(DEPTH50_LEN);
+        let mut ask_size_builders = Vec::with_capacity(DEPTH50_LEN);
+        let mut bid_count_builders = Vec::with_capacity(DEPTH50_LEN);
+        let mut ask_count_builders = Vec::with_capacity(DEPTH50_LEN);
+
+        for _ in 0..DEPTH50_LEN {
+            bid_price_builders.push(Int64Array::builder(data.len()));
+            ask_price_builders.push(Int64Array::builder(data.len()));
+            bid_size_builders.push(UInt64Array::builder(data.len()));
+            ask_size_builders.push(UInt64Array::builder(data.len()));
+            bid_count_builders.push(UInt32Array::builder(data.len()));
+            ask_count_builders.push(UInt32Array::builder(data.len()));
+        }
+
+        let mut flags_builder = UInt8Array::builder(data.len());
+        let mut sequence_builder = UInt64Array::builder(data.len());
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for depth in data {
+            for i in 0..DEPTH50_LEN {
+                bid_price_builders[i].append_value(depth.bids[i].price.raw);
+                ask_price_builders[i].append_value(depth.asks[i].price.raw);
+                bid_size_builders[i].append_value(depth.bids[i].size.raw);
+                ask_size_builders[i].append_value(depth.asks[i].size.raw);
+                bid_count_builders[i].append_value(depth.bid_counts[i]);
+                ask_count_builders[i].append_value(depth.ask_counts[i]);
+            }
+
+            flags_builder.append_value(depth.flags);
+            sequence_builder.append_value(depth.sequence);
+            ts_event_builder.append_value(depth.ts_event.as_u64());
+            ts_init_builder.append_value(depth.ts_init.as_u64());
+        }
+
+        let mut columns: Vec<Arc<dyn Array>> = Vec::with_capacity(6 * DEPTH50_LEN + 4);
+        columns.extend(
+            bid_price_builders
+                .iter_mut()
+                .chain(ask_price_builders.iter_mut())
+                .map(|b| Arc::new(b.finish()) as Arc<dyn Array>),
+        );
+        columns.extend(
+            bid_size_builders
+                .iter_mut()
+                .chain(ask_size_builders.iter_mut())
+                .map(|b| Arc::new(b.finish()) as Arc<dyn Array>),
+        );
+        columns.extend(
+            bid_count_builders
+                .iter_mut()
+                .chain(ask_count_builders.iter_mut())
+                .map(|b| Arc::new(b.finish()) as Arc<dyn Array>),
+        );
+        columns.push(Arc::new(flags_builder.finish()));
+        columns.push(Arc::new(sequence_builder.finish()));
+        columns.push(Arc::new(ts_event_builder.finish()));
+        columns.push(Arc::new(ts_init_builder.finish()));
+
+        RecordBatch::try_new(Self::get_schema(Some(metadata.clone())).into(), columns)
+    }
+}
+
+impl DecodeFromRecordBatch for OrderBookDepth50 {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+        let names = depth50_column_names();
+
+        let mut bid_prices = Vec::with_capacity(DEPTH50_LEN);
+        let mut ask_prices = Vec::with_capacity(DEPTH50_LEN);
+        let mut bid_sizes = Vec::with_capacity(DEPTH50_LEN);
+        let mut ask_sizes = Vec::with_capacity(DEPTH50_LEN);
+        let mut bid_counts = Vec::with_capacity(DEPTH50_LEN);
+        let mut ask_counts = Vec::with_capacity(DEPTH50_LEN);
+
+        for i in 0..DEPTH50_LEN {
+            let idx = |g: usize| g * DEPTH50_LEN + i;
+            bid_prices.push(extract_column::<Int64Array>(
+                cols,
+                names[idx(0)].as_str(),
+                idx(0),
+                DataType::Int64,
+            )?);
+            ask_prices.push(extract_column::<Int64Array>(
+                cols,
+                names[idx(1)].as_str(),
+                idx(1),
+                DataType::Int64,
+            )?);
+            bid_sizes.push(extract_column::<UInt64Array>(
+                cols,
+                names[idx(2)].as_str(),
+                idx(2),
+                DataType::UInt64,
+            )?);
+            ask_sizes.push(extract_column::<UInt64Array>(
+                cols,
+                names[idx(3)].as_str(),
+                idx(3),
+                DataType::UInt64,
+            )?);
+            bid_counts.push(extract_column::<UInt32Array>(
+                cols,
+                names[idx(4)].as_str(),
+                idx(4),
+                DataType::UInt32,
+            )?);
+            ask_counts.push(extract_column::<UInt32Array>(
+                cols,
+                names[idx(5)].as_str(),
+                idx(5),
+                DataType::UInt32,
+            )?);
+        }
+
+        let flags = extract_column::<UInt8Array>(cols, "flags", 6 * DEPTH50_LEN, DataType::UInt8)?;
+        let sequence =
+            extract_column::<UInt64Array>(cols, "sequence", 6 * DEPTH50_LEN + 1, DataType::UInt64)?;
+        let ts_event =
+            extract_column::<UInt64Array>(cols, "ts_event", 6 * DEPTH50_LEN + 2, DataType::UInt64)?;
+        let ts_init =
+            extract_column::<UInt64Array>(cols, "ts_init", 6 * DEPTH50_LEN + 3, DataType::UInt64)?;
+
+        let parse_order =
+            |side: OrderSide, price_raw: i64, size_raw: u64| -> Result<BookOrder, EncodingError> {
+                let price = Price::from_raw(price_raw, price_precision)
+                    .map_err(|e| EncodingError::ParseError("price", e.to_string()))?;
+                let size = Quantity::from_raw(size_raw, size_precision)
+                    .map_err(|e| EncodingError::ParseError("size", e.to_string()))?;
+                Ok(BookOrder::new(side, price, size, 0)) // Order ID always zero
+            };
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let mut bids = [BookOrder::default(); DEPTH50_LEN];
+                let mut asks = [BookOrder::default(); DEPTH50_LEN];
+                let mut bid_count_arr = [0u32; DEPTH50_LEN];
+                let mut ask_count_arr = [0u32; DEPTH50_LEN];
+
+                for j in 0..DEPTH50_LEN {
+                    bids[j] = parse_order(
+                        OrderSide::Buy,
+                        bid_prices[j].value(i),
+                        bid_sizes[j].value(i),
+                    )?;
+                    asks[j] = parse_order(
+                        OrderSide::Sell,
+                        ask_prices[j].value(i),
+                        ask_sizes[j].value(i),
+                    )?;
+                    bid_count_arr[j] = bid_counts[j].value(i);
+                    ask_count_arr[j] = ask_counts[j].value(i);
+                }
+
+                Ok(Self {
+                    instrument_id,
+                    bids,
+                    asks,
+                    bid_counts: bid_count_arr,
+                    ask_counts: ask_count_arr,
+                    flags: flags.value(i),
+                    sequence: sequence.value(i),
+                    ts_event: ts_event.value(i).into(),
+                    ts_init: ts_init.value(i).into(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl DecodeDataFromRecordBatch for OrderBookDepth50 {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let depths: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(depths.into_iter().map(Data::from).collect())
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -426,7 +668,7 @@ impl DecodeDataFromRecordBatch for OrderBookDepth10 {
 mod tests {
 
     use datafusion::arrow::datatypes::{DataType, Field, Schema};
-    use nautilus_model::data::stubs::stub_depth10;
+    use nautilus_model::data::stubs::{stub_depth10, stub_depth50};
     use rstest::rstest;
 
     use super::*;
@@ -813,4 +1055,52 @@ mod tests {
 
         assert_eq!(decoded_data.len(), 1);
     }
+
+    #[rstest]
+    fn test_depth50_get_schema() {
+        let schema = OrderBookDepth50::get_schema(None);
+
+        assert_eq!(schema.fields().len(), 6 * DEPTH50_LEN + 4);
+        assert_eq!(schema.field(0).name(), "bid_price_0");
+        assert_eq!(schema.field(DEPTH50_LEN - 1).name(), "bid_price_49");
+        assert_eq!(schema.field(5 * DEPTH50_LEN).name(), "ask_count_0");
+        assert_eq!(schema.field(6 * DEPTH50_LEN).name(), "flags");
+        assert_eq!(schema.field(2 * DEPTH50_LEN).data_type(), &DataType::UInt64);
+    }
+
+    #[rstest]
+    fn test_depth50_encode_decode_round_trip(stub_depth50: OrderBookDepth50) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let metadata = OrderBookDepth50::get_metadata(&instrument_id, 2, 0);
+
+        let data = vec![stub_depth50.clone()];
+        let record_batch = OrderBookDepth50::encode_batch(&metadata, &data).unwrap();
+        let decoded_data = OrderBookDepth50::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded_data.len(), 1);
+        let decoded = &decoded_data[0];
+        assert_eq!(decoded.instrument_id, instrument_id);
+        for i in 0..DEPTH50_LEN {
+            assert_eq!(decoded.bids[i].price, stub_depth50.bids[i].price);
+            assert_eq!(decoded.bids[i].size, stub_depth50.bids[i].size);
+            assert_eq!(decoded.asks[i].price, stub_depth50.asks[i].price);
+            assert_eq!(decoded.asks[i].size, stub_depth50.asks[i].size);
+        }
+        assert_eq!(decoded.bid_counts, stub_depth50.bid_counts);
+        assert_eq!(decoded.ask_counts, stub_depth50.ask_counts);
+        assert_eq!(decoded.ts_event, stub_depth50.ts_event);
+        assert_eq!(decoded.ts_init, stub_depth50.ts_init);
+    }
+
+    #[rstest]
+    fn test_depth50_decode_data_batch(stub_depth50: OrderBookDepth50) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let metadata = OrderBookDepth50::get_metadata(&instrument_id, 2, 0);
+
+        let record_batch = OrderBookDepth50::encode_batch(&metadata, &[stub_depth50]).unwrap();
+        let data = OrderBookDepth50::decode_data_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(data.len(), 1);
+        assert!(matches!(data[0], Data::Deltas(_)));
+    }
 }
@@ -0,0 +1,161 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use datafusion::arrow::{
+    array::{Float64Array, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{data::funding::FundingRateUpdate, identifiers::instrument_id::InstrumentId};
+
+use super::{extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for FundingRateUpdate {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("rate", DataType::Float64, false),
+            Field::new("next_funding_ns", DataType::UInt64, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(metadata: &HashMap<String, String>) -> Result<InstrumentId, EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))
+}
+
+impl EncodeToRecordBatch for FundingRateUpdate {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut rate_builder = Float64Array::builder(data.len());
+        let mut next_funding_ns_builder = UInt64Array::builder(data.len());
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for funding in data {
+            rate_builder.append_value(funding.rate);
+            next_funding_ns_builder.append_value(funding.next_funding_ns.as_u64());
+            ts_event_builder.append_value(funding.ts_event.as_u64());
+            ts_init_builder.append_value(funding.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(rate_builder.finish()),
+                Arc::new(next_funding_ns_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for FundingRateUpdate {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let instrument_id = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let rate_values = extract_column::<Float64Array>(cols, "rate", 0, DataType::Float64)?;
+        let next_funding_ns_values =
+            extract_column::<UInt64Array>(cols, "next_funding_ns", 1, DataType::UInt64)?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 2, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 3, DataType::UInt64)?;
+
+        Ok((0..record_batch.num_rows())
+            .map(|i| Self {
+                instrument_id,
+                rate: rate_values.value(i),
+                next_funding_ns: next_funding_ns_values.value(i).into(),
+                ts_event: ts_event_values.value(i).into(),
+                ts_init: ts_init_values.value(i).into(),
+            })
+            .collect())
+    }
+}
+
+impl DecodeDataFromRecordBatch for FundingRateUpdate {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let updates: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(updates.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::stubs::stub_funding_rate;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = FundingRateUpdate::get_schema_map();
+
+        assert_eq!(schema_map.len(), 4);
+        assert_eq!(schema_map["rate"], "Float64");
+        assert_eq!(schema_map["next_funding_ns"], "UInt64");
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip(stub_funding_rate: FundingRateUpdate) {
+        let metadata = FundingRateUpdate::get_metadata(&stub_funding_rate.instrument_id);
+        let data = vec![stub_funding_rate];
+
+        let record_batch = FundingRateUpdate::encode_batch(&metadata, &data).unwrap();
+        let decoded = FundingRateUpdate::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, data);
+    }
+
+    #[rstest]
+    fn test_decode_missing_metadata(stub_funding_rate: FundingRateUpdate) {
+        let metadata = FundingRateUpdate::get_metadata(&stub_funding_rate.instrument_id);
+        let record_batch =
+            FundingRateUpdate::encode_batch(&metadata, &[stub_funding_rate]).unwrap();
+
+        let result = FundingRateUpdate::decode_batch(&HashMap::new(), record_batch);
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))
+        ));
+    }
+}
@@ -0,0 +1,251 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use datafusion::arrow::{
+    array::{Int64Array, UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    data::imbalance::AuctionImbalance,
+    enums::OrderSide,
+    identifiers::instrument_id::InstrumentId,
+    types::{price::Price, quantity::Quantity},
+};
+
+use super::{
+    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
+    KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
+};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for AuctionImbalance {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("indicative_price", DataType::Int64, false),
+            Field::new("paired_qty", DataType::UInt64, false),
+            Field::new("imbalance_qty", DataType::UInt64, false),
+            Field::new("imbalance_side", DataType::UInt8, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(
+    metadata: &HashMap<String, String>,
+) -> Result<(InstrumentId, u8, u8), EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    let instrument_id = InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+
+    let price_precision = metadata
+        .get(KEY_PRICE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_PRICE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_PRICE_PRECISION, e.to_string()))?;
+
+    let size_precision = metadata
+        .get(KEY_SIZE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_SIZE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_SIZE_PRECISION, e.to_string()))?;
+
+    Ok((instrument_id, price_precision, size_precision))
+}
+
+impl EncodeToRecordBatch for AuctionImbalance {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut indicative_price_builder = Int64Array::builder(data.len());
+        let mut paired_qty_builder = UInt64Array::builder(data.len());
+        let mut imbalance_qty_builder = UInt64Array::builder(data.len());
+        let mut imbalance_side_builder = UInt8Array::builder(data.len());
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for imbalance in data {
+            indicative_price_builder.append_value(imbalance.indicative_price.raw);
+            paired_qty_builder.append_value(imbalance.paired_qty.raw);
+            imbalance_qty_builder.append_value(imbalance.imbalance_qty.raw);
+            imbalance_side_builder.append_value(imbalance.imbalance_side as u8);
+            ts_event_builder.append_value(imbalance.ts_event.as_u64());
+            ts_init_builder.append_value(imbalance.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(indicative_price_builder.finish()),
+                Arc::new(paired_qty_builder.finish()),
+                Arc::new(imbalance_qty_builder.finish()),
+                Arc::new(imbalance_side_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for AuctionImbalance {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let indicative_price_values =
+            extract_column::<Int64Array>(cols, "indicative_price", 0, DataType::Int64)?;
+        let paired_qty_values =
+            extract_column::<UInt64Array>(cols, "paired_qty", 1, DataType::UInt64)?;
+        let imbalance_qty_values =
+            extract_column::<UInt64Array>(cols, "imbalance_qty", 2, DataType::UInt64)?;
+        let imbalance_side_values =
+            extract_column::<UInt8Array>(cols, "imbalance_side", 3, DataType::UInt8)?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 4, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 5, DataType::UInt64)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let indicative_price =
+                    Price::from_raw(indicative_price_values.value(i), price_precision).map_err(
+                        |e| EncodingError::ParseError("indicative_price", e.to_string()),
+                    )?;
+                let paired_qty = Quantity::from_raw(paired_qty_values.value(i), size_precision)
+                    .map_err(|e| EncodingError::ParseError("paired_qty", e.to_string()))?;
+                let imbalance_qty =
+                    Quantity::from_raw(imbalance_qty_values.value(i), size_precision)
+                        .map_err(|e| EncodingError::ParseError("imbalance_qty", e.to_string()))?;
+                let imbalance_side_value = imbalance_side_values.value(i);
+                let imbalance_side = OrderSide::from_repr(imbalance_side_value as usize)
+                    .ok_or_else(|| {
+                        EncodingError::ParseError(
+                            stringify!(OrderSide),
+                            format!("Invalid enum value, was {imbalance_side_value}"),
+                        )
+                    })?;
+
+                Ok(Self {
+                    instrument_id,
+                    indicative_price,
+                    paired_qty,
+                    imbalance_qty,
+                    imbalance_side,
+                    ts_event: ts_event_values.value(i).into(),
+                    ts_init: ts_init_values.value(i).into(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl DecodeDataFromRecordBatch for AuctionImbalance {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let imbalances: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(imbalances.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::stubs::stub_auction_imbalance;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_encode_decode_round_trip(stub_auction_imbalance: AuctionImbalance) {
+        let metadata = AuctionImbalance::get_metadata(&stub_auction_imbalance.instrument_id, 2, 0);
+        let data = vec![stub_auction_imbalance];
+
+        let record_batch = AuctionImbalance::encode_batch(&metadata, &data).unwrap();
+        let decoded = AuctionImbalance::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, data);
+    }
+
+    #[rstest]
+    fn test_decode_missing_precision(stub_auction_imbalance: AuctionImbalance) {
+        let mut metadata =
+            AuctionImbalance::get_metadata(&stub_auction_imbalance.instrument_id, 2, 0);
+        let record_batch =
+            AuctionImbalance::encode_batch(&metadata, &[stub_auction_imbalance]).unwrap();
+        metadata.remove(KEY_PRICE_PRECISION);
+
+        let result = AuctionImbalance::decode_batch(&metadata, record_batch);
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::MissingMetadata(KEY_PRICE_PRECISION))
+        ));
+    }
+
+    #[rstest]
+    fn test_decode_invalid_price_precision(stub_auction_imbalance: AuctionImbalance) {
+        let metadata = AuctionImbalance::get_metadata(&stub_auction_imbalance.instrument_id, 10, 0);
+        let record_batch =
+            AuctionImbalance::encode_batch(&metadata, &[stub_auction_imbalance]).unwrap();
+
+        let result = AuctionImbalance::decode_batch(&metadata, record_batch);
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::ParseError("indicative_price", _))
+        ));
+    }
+
+    #[rstest]
+    fn test_decode_invalid_side(stub_auction_imbalance: AuctionImbalance) {
+        let metadata = AuctionImbalance::get_metadata(&stub_auction_imbalance.instrument_id, 2, 0);
+        let record_batch = RecordBatch::try_new(
+            AuctionImbalance::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(Int64Array::from(vec![420_500_000_000])),
+                Arc::new(UInt64Array::from(vec![1])),
+                Arc::new(UInt64Array::from(vec![1])),
+                Arc::new(UInt8Array::from(vec![9])),
+                Arc::new(UInt64Array::from(vec![1])),
+                Arc::new(UInt64Array::from(vec![1])),
+            ],
+        )
+        .unwrap();
+
+        let result = AuctionImbalance::decode_data_batch(&metadata, record_batch);
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::ParseError("OrderSide", _))
+        ));
+    }
+}
@@ -16,9 +16,14 @@
 //! Defines the Apache Arrow schema for Nautilus types.
 
 pub mod bar;
+pub mod custom;
 pub mod delta;
 pub mod depth;
+pub mod funding;
+pub mod imbalance;
 pub mod quote;
+pub mod snapshot;
+pub mod status;
 pub mod trade;
 
 use std::{
@@ -37,10 +42,14 @@ use nautilus_model::data::Data;
 use pyo3::prelude::*;
 
 // Define metadata key constants constants
+const KEY_ACCOUNT_ID: &str = "account_id";
 const KEY_BAR_TYPE: &str = "bar_type";
 const KEY_INSTRUMENT_ID: &str = "instrument_id";
+const KEY_METADATA: &str = "metadata";
 const KEY_PRICE_PRECISION: &str = "price_precision";
 const KEY_SIZE_PRECISION: &str = "size_precision";
+const KEY_TYPE_NAME: &str = "type_name";
+const KEY_VENUE: &str = "venue";
 
 #[derive(thiserror::Error, Debug)]
 pub enum DataStreamingError {
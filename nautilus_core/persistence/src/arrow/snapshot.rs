@@ -0,0 +1,271 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use datafusion::arrow::{
+    array::{Float64Array, Int64Array, StringArray, StringBuilder, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    identifiers::account_id::AccountId,
+    types::{balance::AccountBalance, currency::Currency, money::Money},
+};
+
+use super::{extract_column, EncodingError, KEY_ACCOUNT_ID};
+use crate::{
+    arrow::{ArrowSchemaProvider, EncodeToRecordBatch},
+    snapshots::{AccountBalanceSnapshot, ExchangeRateSnapshot},
+};
+
+fn parse_currency(code: &str) -> Result<Currency, EncodingError> {
+    Currency::from_str(code).map_err(|e| EncodingError::ParseError("currency", e.to_string()))
+}
+
+impl ArrowSchemaProvider for ExchangeRateSnapshot {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("from_currency", DataType::Utf8, false),
+            Field::new("to_currency", DataType::Utf8, false),
+            Field::new("rate", DataType::Float64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for ExchangeRateSnapshot {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut from_currency_builder = StringBuilder::new();
+        let mut to_currency_builder = StringBuilder::new();
+        let mut rate_builder = Float64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for snapshot in data {
+            from_currency_builder.append_value(snapshot.from_currency.code);
+            to_currency_builder.append_value(snapshot.to_currency.code);
+            rate_builder.append_value(snapshot.rate);
+            ts_init_builder.append_value(snapshot.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(from_currency_builder.finish()),
+                Arc::new(to_currency_builder.finish()),
+                Arc::new(rate_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+}
+
+impl ExchangeRateSnapshot {
+    /// Decodes the snapshots from the given `record_batch`.
+    pub fn decode_batch(record_batch: &RecordBatch) -> Result<Vec<Self>, EncodingError> {
+        let cols = record_batch.columns();
+
+        let from_currency_values =
+            extract_column::<StringArray>(cols, "from_currency", 0, DataType::Utf8)?;
+        let to_currency_values =
+            extract_column::<StringArray>(cols, "to_currency", 1, DataType::Utf8)?;
+        let rate_values = extract_column::<Float64Array>(cols, "rate", 2, DataType::Float64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 3, DataType::UInt64)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                Ok(Self {
+                    from_currency: parse_currency(from_currency_values.value(i))?,
+                    to_currency: parse_currency(to_currency_values.value(i))?,
+                    rate: rate_values.value(i),
+                    ts_init: ts_init_values.value(i).into(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl ArrowSchemaProvider for AccountBalanceSnapshot {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("currency", DataType::Utf8, false),
+            Field::new("total", DataType::Int64, false),
+            Field::new("locked", DataType::Int64, false),
+            Field::new("free", DataType::Int64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for AccountBalanceSnapshot {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut currency_builder = StringBuilder::new();
+        let mut total_builder = Int64Array::builder(data.len());
+        let mut locked_builder = Int64Array::builder(data.len());
+        let mut free_builder = Int64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for snapshot in data {
+            currency_builder.append_value(snapshot.balance.currency.code);
+            total_builder.append_value(snapshot.balance.total.raw);
+            locked_builder.append_value(snapshot.balance.locked.raw);
+            free_builder.append_value(snapshot.balance.free.raw);
+            ts_init_builder.append_value(snapshot.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(currency_builder.finish()),
+                Arc::new(total_builder.finish()),
+                Arc::new(locked_builder.finish()),
+                Arc::new(free_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+}
+
+impl AccountBalanceSnapshot {
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(account_id: &AccountId) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert(KEY_ACCOUNT_ID.to_string(), account_id.to_string());
+        metadata
+    }
+
+    /// Decodes the snapshots from the given `record_batch`.
+    pub fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: &RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let account_id_str = metadata
+            .get(KEY_ACCOUNT_ID)
+            .ok_or_else(|| EncodingError::MissingMetadata(KEY_ACCOUNT_ID))?;
+        let account_id = AccountId::new(account_id_str)
+            .map_err(|e| EncodingError::ParseError(KEY_ACCOUNT_ID, e.to_string()))?;
+        let cols = record_batch.columns();
+
+        let currency_values = extract_column::<StringArray>(cols, "currency", 0, DataType::Utf8)?;
+        let total_values = extract_column::<Int64Array>(cols, "total", 1, DataType::Int64)?;
+        let locked_values = extract_column::<Int64Array>(cols, "locked", 2, DataType::Int64)?;
+        let free_values = extract_column::<Int64Array>(cols, "free", 3, DataType::Int64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 4, DataType::UInt64)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                let currency = parse_currency(currency_values.value(i))?;
+                let balance = AccountBalance {
+                    currency,
+                    total: Money::from_raw(total_values.value(i), currency),
+                    locked: Money::from_raw(locked_values.value(i), currency),
+                    free: Money::from_raw(free_values.value(i), currency),
+                };
+
+                Ok(Self {
+                    account_id,
+                    balance,
+                    ts_init: ts_init_values.value(i).into(),
+                })
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_exchange_rate_round_trip() {
+        let snapshots = vec![
+            ExchangeRateSnapshot {
+                from_currency: Currency::AUD(),
+                to_currency: Currency::USD(),
+                rate: 0.66512,
+                ts_init: 1.into(),
+            },
+            ExchangeRateSnapshot {
+                from_currency: Currency::EUR(),
+                to_currency: Currency::USD(),
+                rate: 1.0821,
+                ts_init: 1.into(),
+            },
+        ];
+
+        let record_batch = ExchangeRateSnapshot::encode_batch(&HashMap::new(), &snapshots).unwrap();
+        let decoded = ExchangeRateSnapshot::decode_batch(&record_batch).unwrap();
+
+        assert_eq!(record_batch.num_columns(), 4);
+        assert_eq!(decoded, snapshots);
+    }
+
+    #[rstest]
+    fn test_account_balance_round_trip() {
+        let account_id = AccountId::from("SIM-001");
+        let balance = AccountBalance::new(
+            Money::from("1000000 USD"),
+            Money::from("25000 USD"),
+            Money::from("975000 USD"),
+        )
+        .unwrap();
+        let snapshots = vec![AccountBalanceSnapshot {
+            account_id,
+            balance,
+            ts_init: 2.into(),
+        }];
+        let metadata = AccountBalanceSnapshot::get_metadata(&account_id);
+
+        let record_batch = AccountBalanceSnapshot::encode_batch(&metadata, &snapshots).unwrap();
+        let decoded = AccountBalanceSnapshot::decode_batch(&metadata, &record_batch).unwrap();
+
+        assert_eq!(decoded, snapshots);
+    }
+
+    #[rstest]
+    fn test_account_balance_decode_missing_metadata() {
+        let record_batch = AccountBalanceSnapshot::encode_batch(&HashMap::new(), &[]).unwrap();
+
+        let result = AccountBalanceSnapshot::decode_batch(&HashMap::new(), &record_batch);
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::MissingMetadata(KEY_ACCOUNT_ID))
+        ));
+    }
+}
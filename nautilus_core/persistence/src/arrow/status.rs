@@ -0,0 +1,292 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use datafusion::arrow::{
+    array::{UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    data::status::{InstrumentStatus, VenueStatus},
+    enums::{HaltReason, MarketStatus},
+    identifiers::{instrument_id::InstrumentId, venue::Venue},
+};
+
+use super::{
+    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID, KEY_VENUE,
+};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+/// The decoded common columns of a status record batch.
+struct StatusColumns<'a> {
+    status: &'a UInt8Array,
+    halt_reason: &'a UInt8Array,
+    ts_event: &'a UInt64Array,
+    ts_init: &'a UInt64Array,
+}
+
+impl<'a> StatusColumns<'a> {
+    fn extract(record_batch: &'a RecordBatch) -> Result<Self, EncodingError> {
+        let cols = record_batch.columns();
+        Ok(Self {
+            status: extract_column::<UInt8Array>(cols, "status", 0, DataType::UInt8)?,
+            halt_reason: extract_column::<UInt8Array>(cols, "halt_reason", 1, DataType::UInt8)?,
+            ts_event: extract_column::<UInt64Array>(cols, "ts_event", 2, DataType::UInt64)?,
+            ts_init: extract_column::<UInt64Array>(cols, "ts_init", 3, DataType::UInt64)?,
+        })
+    }
+
+    fn status(&self, i: usize) -> Result<MarketStatus, EncodingError> {
+        let value = self.status.value(i);
+        MarketStatus::from_repr(value as usize).ok_or_else(|| {
+            EncodingError::ParseError(
+                stringify!(MarketStatus),
+                format!("Invalid enum value, was {value}"),
+            )
+        })
+    }
+
+    fn halt_reason(&self, i: usize) -> Result<HaltReason, EncodingError> {
+        let value = self.halt_reason.value(i);
+        HaltReason::from_repr(value as usize).ok_or_else(|| {
+            EncodingError::ParseError(
+                stringify!(HaltReason),
+                format!("Invalid enum value, was {value}"),
+            )
+        })
+    }
+}
+
+fn status_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+    let fields = vec![
+        Field::new("status", DataType::UInt8, false),
+        Field::new("halt_reason", DataType::UInt8, false),
+        Field::new("ts_event", DataType::UInt64, false),
+        Field::new("ts_init", DataType::UInt64, false),
+    ];
+
+    match metadata {
+        Some(metadata) => Schema::new_with_metadata(fields, metadata),
+        None => Schema::new(fields),
+    }
+}
+
+fn encode_status_batch(
+    schema: Schema,
+    rows: impl ExactSizeIterator<Item = (MarketStatus, HaltReason, u64, u64)>,
+) -> Result<RecordBatch, ArrowError> {
+    let mut status_builder = UInt8Array::builder(rows.len());
+    let mut halt_reason_builder = UInt8Array::builder(rows.len());
+    let mut ts_event_builder = UInt64Array::builder(rows.len());
+    let mut ts_init_builder = UInt64Array::builder(rows.len());
+
+    for (status, halt_reason, ts_event, ts_init) in rows {
+        status_builder.append_value(status as u8);
+        halt_reason_builder.append_value(halt_reason as u8);
+        ts_event_builder.append_value(ts_event);
+        ts_init_builder.append_value(ts_init);
+    }
+
+    RecordBatch::try_new(
+        schema.into(),
+        vec![
+            Arc::new(status_builder.finish()),
+            Arc::new(halt_reason_builder.finish()),
+            Arc::new(ts_event_builder.finish()),
+            Arc::new(ts_init_builder.finish()),
+        ],
+    )
+}
+
+impl ArrowSchemaProvider for InstrumentStatus {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        status_schema(metadata)
+    }
+}
+
+impl EncodeToRecordBatch for InstrumentStatus {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        encode_status_batch(
+            Self::get_schema(Some(metadata.clone())),
+            data.iter().map(|s| {
+                (
+                    s.status,
+                    s.halt_reason,
+                    s.ts_event.as_u64(),
+                    s.ts_init.as_u64(),
+                )
+            }),
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for InstrumentStatus {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let instrument_id_str = metadata
+            .get(KEY_INSTRUMENT_ID)
+            .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+        let instrument_id = InstrumentId::from_str(instrument_id_str)
+            .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+        let cols = StatusColumns::extract(&record_batch)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                Ok(Self {
+                    instrument_id,
+                    status: cols.status(i)?,
+                    halt_reason: cols.halt_reason(i)?,
+                    ts_event: cols.ts_event.value(i).into(),
+                    ts_init: cols.ts_init.value(i).into(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl DecodeDataFromRecordBatch for InstrumentStatus {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let statuses: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(statuses.into_iter().map(Data::from).collect())
+    }
+}
+
+impl ArrowSchemaProvider for VenueStatus {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        status_schema(metadata)
+    }
+}
+
+impl EncodeToRecordBatch for VenueStatus {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        encode_status_batch(
+            Self::get_schema(Some(metadata.clone())),
+            data.iter().map(|s| {
+                (
+                    s.status,
+                    s.halt_reason,
+                    s.ts_event.as_u64(),
+                    s.ts_init.as_u64(),
+                )
+            }),
+        )
+    }
+}
+
+impl DecodeFromRecordBatch for VenueStatus {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let venue_str = metadata
+            .get(KEY_VENUE)
+            .ok_or_else(|| EncodingError::MissingMetadata(KEY_VENUE))?;
+        let venue = Venue::new(venue_str)
+            .map_err(|e| EncodingError::ParseError(KEY_VENUE, e.to_string()))?;
+        let cols = StatusColumns::extract(&record_batch)?;
+
+        (0..record_batch.num_rows())
+            .map(|i| {
+                Ok(Self {
+                    venue,
+                    status: cols.status(i)?,
+                    halt_reason: cols.halt_reason(i)?,
+                    ts_event: cols.ts_event.value(i).into(),
+                    ts_init: cols.ts_init.value(i).into(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl DecodeDataFromRecordBatch for VenueStatus {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let statuses: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(statuses.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::stubs::{stub_instrument_status, stub_venue_status};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_instrument_status_round_trip(stub_instrument_status: InstrumentStatus) {
+        let metadata = InstrumentStatus::get_metadata(&stub_instrument_status.instrument_id);
+        let data = vec![stub_instrument_status];
+
+        let record_batch = InstrumentStatus::encode_batch(&metadata, &data).unwrap();
+        let decoded = InstrumentStatus::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, data);
+    }
+
+    #[rstest]
+    fn test_venue_status_round_trip(stub_venue_status: VenueStatus) {
+        let metadata = VenueStatus::get_metadata(&stub_venue_status.venue);
+        let data = vec![stub_venue_status];
+
+        let record_batch = VenueStatus::encode_batch(&metadata, &data).unwrap();
+        let decoded = VenueStatus::decode_data_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded.len(), 1);
+        assert!(matches!(&decoded[0], Data::VenueStatus(status) if *status == data[0]));
+    }
+
+    #[rstest]
+    fn test_decode_invalid_status(stub_venue_status: VenueStatus) {
+        let metadata = VenueStatus::get_metadata(&stub_venue_status.venue);
+        let record_batch = RecordBatch::try_new(
+            VenueStatus::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(UInt8Array::from(vec![99])),
+                Arc::new(UInt8Array::from(vec![1])),
+                Arc::new(UInt64Array::from(vec![1])),
+                Arc::new(UInt64Array::from(vec![1])),
+            ],
+        )
+        .unwrap();
+
+        let result = VenueStatus::decode_batch(&metadata, record_batch);
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::ParseError("MarketStatus", _))
+        ));
+    }
+}
@@ -13,19 +13,20 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, sync::Arc, vec::IntoIter};
+use std::{collections::HashMap, path::Path, sync::Arc, vec::IntoIter};
 
 use compare::Compare;
 use datafusion::{
     error::Result, logical_expr::expr::Sort, physical_plan::SendableRecordBatchStream, prelude::*,
 };
 use futures::StreamExt;
-use nautilus_core::ffi::cvec::CVec;
+use nautilus_core::{ffi::cvec::CVec, nanos::UnixNanos};
 use nautilus_model::data::{Data, GetTsInit};
 
 use super::kmerge_batch::{EagerStream, ElementBatchIter, KMerge};
-use crate::arrow::{
-    DataStreamingError, DecodeDataFromRecordBatch, EncodeToRecordBatch, WriteStream,
+use crate::{
+    arrow::{DataStreamingError, DecodeDataFromRecordBatch, EncodeToRecordBatch, WriteStream},
+    codec::CodecRegistry,
 };
 
 #[derive(Debug, Default)]
@@ -61,6 +62,7 @@ pub struct DataBackendSession {
     pub runtime: Arc<tokio::runtime::Runtime>,
     session_ctx: SessionContext,
     batch_streams: Vec<EagerStream<IntoIter<Data>>>,
+    codecs: CodecRegistry,
 }
 
 impl DataBackendSession {
@@ -77,6 +79,7 @@ impl DataBackendSession {
         Self {
             session_ctx,
             batch_streams: Vec::default(),
+            codecs: CodecRegistry::default(),
             chunk_size,
             runtime: Arc::new(runtime),
         }
@@ -139,6 +142,51 @@ impl DataBackendSession {
         Ok(())
     }
 
+    /// Returns a mutable reference to the registry of encoders and decoders for custom
+    /// on-disk formats.
+    pub fn codecs_mut(&mut self) -> &mut CodecRegistry {
+        &mut self.codecs
+    }
+
+    /// Query a file in a custom format for its records, decoded with the decoder registered
+    /// for `type_name`. Records with a `ts_init` outside of `start` to `end` (inclusive) are
+    /// excluded.
+    ///
+    /// The decoded records are merged with those of all other queries.
+    ///
+    /// # Safety
+    ///
+    /// The file data must be ordered by the `ts_init` in ascending order for this
+    /// to work correctly.
+    pub fn add_encoded_file(
+        &mut self,
+        type_name: &str,
+        file_path: &str,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<()> {
+        let batches = self.codecs.read_file(type_name, Path::new(file_path))?;
+        let file_path = file_path.to_string();
+        let transform = futures::stream::iter(batches).map(move |result| match result {
+            Ok(mut batch) => {
+                batch.retain(|data| {
+                    let ts_init = data.ts_init();
+                    start.map_or(true, |start| ts_init >= start)
+                        && end.map_or(true, |end| ts_init <= end)
+                });
+                batch.into_iter()
+            }
+            Err(err) => panic!("Error decoding next batch from '{file_path}': {err}"),
+        });
+
+        self.batch_streams
+            .push(EagerStream::from_stream_with_runtime(
+                transform,
+                self.runtime.clone(),
+            ));
+        Ok(())
+    }
+
     fn add_batch_stream<T>(&mut self, stream: SendableRecordBatchStream)
     where
         T: DecodeDataFromRecordBatch + Into<Data>,
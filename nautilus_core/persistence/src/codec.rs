@@ -0,0 +1,268 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Pluggable on-disk formats for data.
+//!
+//! An [`Encoder`] and [`Decoder`] can be registered for a data type name, so that data can be
+//! stored in a custom format (e.g. an in-house binary format) rather than Parquet, while still
+//! being queried and merged with other data by the [`DataBackendSession`].
+//!
+//! [`DataBackendSession`]: crate::backend::session::DataBackendSession
+
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+    sync::Arc,
+};
+
+use nautilus_model::data::Data;
+
+/// Represents the decoded batches of data from a source, ordered by `ts_init`.
+pub type DataBatches = Box<dyn Iterator<Item = anyhow::Result<Vec<Data>>> + Send>;
+
+/// Provides encoding of data to a custom on-disk format.
+pub trait Encoder: Debug + Send + Sync {
+    /// Returns the name of the format (used as the file extension).
+    fn format(&self) -> &str;
+
+    /// Encodes the given data, ordered by `ts_init`, to the writer.
+    fn encode(&self, data: &[Data], writer: &mut dyn Write) -> anyhow::Result<()>;
+}
+
+/// Provides decoding of data from a custom on-disk format.
+pub trait Decoder: Debug + Send + Sync {
+    /// Returns the name of the format (used as the file extension).
+    fn format(&self) -> &str;
+
+    /// Decodes data from the reader as batches ordered by `ts_init`.
+    ///
+    /// The batches should be read lazily, so that sources larger than memory can be queried.
+    fn decode(&self, reader: Box<dyn Read + Send>) -> anyhow::Result<DataBatches>;
+}
+
+/// Provides a registry of encoders and decoders by data type name.
+#[derive(Clone, Debug, Default)]
+pub struct CodecRegistry {
+    encoders: HashMap<String, Arc<dyn Encoder>>,
+    decoders: HashMap<String, Arc<dyn Decoder>>,
+}
+
+impl CodecRegistry {
+    /// Creates a new empty [`CodecRegistry`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the encoder for the given data type name, replacing any existing encoder.
+    pub fn register_encoder(&mut self, type_name: &str, encoder: Arc<dyn Encoder>) {
+        self.encoders.insert(type_name.to_string(), encoder);
+    }
+
+    /// Registers the decoder for the given data type name, replacing any existing decoder.
+    pub fn register_decoder(&mut self, type_name: &str, decoder: Arc<dyn Decoder>) {
+        self.decoders.insert(type_name.to_string(), decoder);
+    }
+
+    /// Returns the encoder for the given data type name (if registered).
+    #[must_use]
+    pub fn encoder(&self, type_name: &str) -> Option<Arc<dyn Encoder>> {
+        self.encoders.get(type_name).cloned()
+    }
+
+    /// Returns the decoder for the given data type name (if registered).
+    #[must_use]
+    pub fn decoder(&self, type_name: &str) -> Option<Arc<dyn Decoder>> {
+        self.decoders.get(type_name).cloned()
+    }
+
+    /// Writes the given data to a file at `path`, with the encoder for the data type name.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no encoder is registered for the data type name,
+    /// or if writing fails.
+    pub fn write_file(&self, type_name: &str, data: &[Data], path: &Path) -> anyhow::Result<()> {
+        let encoder = self
+            .encoder(type_name)
+            .ok_or_else(|| anyhow::anyhow!("No encoder registered for '{type_name}'"))?;
+        let mut writer = BufWriter::new(File::create(path)?);
+        encoder.encode(data, &mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Reads the data from the file at `path`, with the decoder for the data type name.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no decoder is registered for the data type name,
+    /// or if the file cannot be opened.
+    pub fn read_file(&self, type_name: &str, path: &Path) -> anyhow::Result<DataBatches> {
+        let decoder = self
+            .decoder(type_name)
+            .ok_or_else(|| anyhow::anyhow!("No decoder registered for '{type_name}'"))?;
+        let reader = BufReader::new(File::open(path)?);
+        decoder.decode(Box::new(reader))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::io::BufRead;
+
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::data::{quote::QuoteTick, stubs::quote_tick_ethusdt_binance, GetTsInit};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::backend::session::DataBackendSession;
+
+    /// Encodes quotes as JSON lines, decoded in batches of two.
+    #[derive(Debug)]
+    struct JsonLinesCodec;
+
+    impl Encoder for JsonLinesCodec {
+        fn format(&self) -> &str {
+            "jsonl"
+        }
+
+        fn encode(&self, data: &[Data], writer: &mut dyn Write) -> anyhow::Result<()> {
+            for item in data {
+                let Data::Quote(quote) = item else {
+                    anyhow::bail!("Unsupported data {item:?}");
+                };
+                serde_json::to_writer(&mut *writer, quote)?;
+                writer.write_all(b"\n")?;
+            }
+            Ok(())
+        }
+    }
+
+    impl Decoder for JsonLinesCodec {
+        fn format(&self) -> &str {
+            "jsonl"
+        }
+
+        fn decode(&self, reader: Box<dyn Read + Send>) -> anyhow::Result<DataBatches> {
+            let mut lines = std::io::BufReader::new(reader).lines();
+            Ok(Box::new(std::iter::from_fn(move || {
+                let mut batch = Vec::new();
+                for line in lines.by_ref().take(2) {
+                    let result = line
+                        .map_err(anyhow::Error::from)
+                        .and_then(|line| Ok(serde_json::from_str::<QuoteTick>(&line)?));
+                    match result {
+                        Ok(quote) => batch.push(Data::Quote(quote)),
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+                (!batch.is_empty()).then_some(Ok(batch))
+            })))
+        }
+    }
+
+    fn quotes(ts_inits: &[u64]) -> Vec<Data> {
+        ts_inits
+            .iter()
+            .map(|ts| {
+                let mut quote = quote_tick_ethusdt_binance();
+                quote.ts_event = UnixNanos::from(*ts);
+                quote.ts_init = UnixNanos::from(*ts);
+                Data::Quote(quote)
+            })
+            .collect()
+    }
+
+    fn registry() -> CodecRegistry {
+        let mut registry = CodecRegistry::new();
+        registry.register_encoder("QuoteTick", Arc::new(JsonLinesCodec));
+        registry.register_decoder("QuoteTick", Arc::new(JsonLinesCodec));
+        registry
+    }
+
+    #[rstest]
+    fn test_write_and_read_file_round_trip() {
+        let registry = registry();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("quotes.jsonl");
+        let data = quotes(&[1, 2, 3, 4, 5]);
+
+        registry.write_file("QuoteTick", &data, &path).unwrap();
+        let batches: Vec<Vec<Data>> = registry
+            .read_file("QuoteTick", &path)
+            .unwrap()
+            .collect::<anyhow::Result<_>>()
+            .unwrap();
+
+        assert_eq!(batches.len(), 3);
+        let ts_inits: Vec<u64> = batches
+            .iter()
+            .flatten()
+            .map(|d| d.ts_init().as_u64())
+            .collect();
+        assert_eq!(ts_inits, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[rstest]
+    fn test_write_and_read_file_when_not_registered() {
+        let registry = CodecRegistry::new();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("quotes.jsonl");
+
+        assert!(registry.write_file("QuoteTick", &[], &path).is_err());
+        assert!(registry.read_file("QuoteTick", &path).is_err());
+    }
+
+    #[rstest]
+    fn test_session_merges_encoded_files() {
+        let registry = registry();
+        let dir = tempfile::tempdir().unwrap();
+        let path1 = dir.path().join("quotes-1.jsonl");
+        let path2 = dir.path().join("quotes-2.jsonl");
+        registry
+            .write_file("QuoteTick", &quotes(&[1, 4, 5, 8]), &path1)
+            .unwrap();
+        registry
+            .write_file("QuoteTick", &quotes(&[2, 3, 6, 7]), &path2)
+            .unwrap();
+
+        let mut session = DataBackendSession::new(1_000);
+        *session.codecs_mut() = registry;
+        session
+            .add_encoded_file("QuoteTick", path1.to_str().unwrap(), None, None)
+            .unwrap();
+        session
+            .add_encoded_file(
+                "QuoteTick",
+                path2.to_str().unwrap(),
+                Some(UnixNanos::from(3)),
+                Some(UnixNanos::from(6)),
+            )
+            .unwrap();
+        let ts_inits: Vec<u64> = session
+            .get_query_result()
+            .map(|d| d.ts_init().as_u64())
+            .collect();
+
+        assert_eq!(ts_inits, vec![1, 3, 4, 5, 6, 8]);
+    }
+}
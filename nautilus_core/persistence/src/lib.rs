@@ -29,6 +29,9 @@
 
 pub mod arrow;
 pub mod backend;
+pub mod codec;
+pub mod reconstruction;
+pub mod snapshots;
 
 #[cfg(feature = "python")]
 pub mod python;
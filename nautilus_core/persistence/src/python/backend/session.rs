@@ -15,7 +15,15 @@
 
 use nautilus_core::{ffi::cvec::CVec, python::to_pyruntime_err};
 use nautilus_model::data::{
-    bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick, trade::TradeTick,
+    bar::Bar,
+    custom::CustomData,
+    delta::OrderBookDelta,
+    depth::OrderBookDepth10,
+    funding::FundingRateUpdate,
+    imbalance::AuctionImbalance,
+    quote::QuoteTick,
+    status::{InstrumentStatus, VenueStatus},
+    trade::TradeTick,
 };
 use pyo3::{prelude::*, types::PyCapsule};
 
@@ -25,12 +33,16 @@ use crate::backend::session::{DataBackendSession, DataQueryResult};
 #[pyclass]
 #[derive(Clone, Copy, Debug)]
 pub enum NautilusDataType {
-    // Custom = 0,  # First slot reserved for custom data
+    CustomData = 0,
     OrderBookDelta = 1,
     OrderBookDepth10 = 2,
     QuoteTick = 3,
     TradeTick = 4,
     Bar = 5,
+    FundingRateUpdate = 6,
+    InstrumentStatus = 7,
+    VenueStatus = 8,
+    AuctionImbalance = 9,
 }
 
 #[pymethods]
@@ -80,6 +92,21 @@ impl DataBackendSession {
             NautilusDataType::Bar => slf
                 .add_file::<Bar>(table_name, file_path, sql_query)
                 .map_err(to_pyruntime_err),
+            NautilusDataType::FundingRateUpdate => slf
+                .add_file::<FundingRateUpdate>(table_name, file_path, sql_query)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::InstrumentStatus => slf
+                .add_file::<InstrumentStatus>(table_name, file_path, sql_query)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::VenueStatus => slf
+                .add_file::<VenueStatus>(table_name, file_path, sql_query)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::AuctionImbalance => slf
+                .add_file::<AuctionImbalance>(table_name, file_path, sql_query)
+                .map_err(to_pyruntime_err),
+            NautilusDataType::CustomData => slf
+                .add_file::<CustomData>(table_name, file_path, sql_query)
+                .map_err(to_pyruntime_err),
         }
     }
 
@@ -20,8 +20,16 @@ use datafusion::arrow::{
 };
 use nautilus_core::python::to_pyvalue_err;
 use nautilus_model::data::{
-    bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, is_monotonically_increasing_by_init,
-    quote::QuoteTick, trade::TradeTick,
+    bar::Bar,
+    custom::CustomData,
+    delta::OrderBookDelta,
+    depth::OrderBookDepth10,
+    funding::FundingRateUpdate,
+    imbalance::AuctionImbalance,
+    is_monotonically_increasing_by_init,
+    quote::QuoteTick,
+    status::{InstrumentStatus, VenueStatus},
+    trade::TradeTick,
 };
 use pyo3::{
     exceptions::{PyRuntimeError, PyTypeError, PyValueError},
@@ -140,6 +148,11 @@ impl DataTransformer {
             stringify!(QuoteTick) => QuoteTick::get_schema_map(),
             stringify!(TradeTick) => TradeTick::get_schema_map(),
             stringify!(Bar) => Bar::get_schema_map(),
+            stringify!(FundingRateUpdate) => FundingRateUpdate::get_schema_map(),
+            stringify!(InstrumentStatus) => InstrumentStatus::get_schema_map(),
+            stringify!(VenueStatus) => VenueStatus::get_schema_map(),
+            stringify!(AuctionImbalance) => AuctionImbalance::get_schema_map(),
+            stringify!(CustomData) => CustomData::get_schema_map(),
             _ => {
                 return Err(PyTypeError::new_err(format!(
                     "Arrow schema for `{cls_str}` is not currently implemented in Rust."
@@ -0,0 +1,293 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Reconstruction of historical order book states from stored delta streams.
+//!
+//! Book states are checkpointed at a fixed count of deltas, so that reconstructing the book
+//! as at any timestamp replays at most one checkpoint interval of deltas.
+
+use std::collections::HashMap;
+
+use nautilus_core::{correctness::check_positive_u64, nanos::UnixNanos};
+use nautilus_model::{
+    data::{delta::OrderBookDelta, Data},
+    enums::BookType,
+    identifiers::instrument_id::InstrumentId,
+    orderbook::book::OrderBook,
+};
+
+/// Represents the stored deltas and book checkpoints for a single instrument.
+#[derive(Debug)]
+struct DeltaHistory {
+    deltas: Vec<OrderBookDelta>,
+    /// The book state after each multiple of the checkpoint interval of deltas.
+    checkpoints: Vec<OrderBook>,
+    head: OrderBook,
+}
+
+/// Provides reconstruction of order book states at arbitrary historical timestamps from
+/// stored delta streams, e.g. the `OrderBookDelta` data of the catalog.
+///
+/// Deltas must be added in ascending order of `ts_init` per instrument (the catalog order),
+/// and the book is reconstructed as at `ts_init`, i.e. as the data was received.
+#[derive(Debug)]
+pub struct BookReconstructor {
+    book_type: BookType,
+    checkpoint_interval: usize,
+    histories: HashMap<InstrumentId, DeltaHistory>,
+}
+
+impl BookReconstructor {
+    /// Creates a new [`BookReconstructor`] instance.
+    ///
+    /// The `checkpoint_interval` is the count of deltas between book checkpoints, which
+    /// bounds the count of deltas replayed for each reconstruction.
+    pub fn new(book_type: BookType, checkpoint_interval: usize) -> anyhow::Result<Self> {
+        check_positive_u64(checkpoint_interval as u64, stringify!(checkpoint_interval))?;
+
+        Ok(Self {
+            book_type,
+            checkpoint_interval,
+            histories: HashMap::new(),
+        })
+    }
+
+    /// Returns the instrument IDs with stored deltas.
+    #[must_use]
+    pub fn instrument_ids(&self) -> Vec<InstrumentId> {
+        let mut instrument_ids: Vec<InstrumentId> = self.histories.keys().copied().collect();
+        instrument_ids.sort();
+        instrument_ids
+    }
+
+    /// Returns the count of stored deltas for the given `instrument_id`.
+    #[must_use]
+    pub fn delta_count(&self, instrument_id: &InstrumentId) -> usize {
+        self.histories
+            .get(instrument_id)
+            .map_or(0, |history| history.deltas.len())
+    }
+
+    /// Returns the count of book checkpoints for the given `instrument_id`.
+    #[must_use]
+    pub fn checkpoint_count(&self, instrument_id: &InstrumentId) -> usize {
+        self.histories
+            .get(instrument_id)
+            .map_or(0, |history| history.checkpoints.len())
+    }
+
+    /// Adds the given `delta` to the stored stream for its instrument.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `delta.ts_init` is earlier than the last stored
+    /// delta for the instrument.
+    pub fn add_delta(&mut self, delta: OrderBookDelta) -> anyhow::Result<()> {
+        let book_type = self.book_type;
+        let history = self
+            .histories
+            .entry(delta.instrument_id)
+            .or_insert_with(|| DeltaHistory {
+                deltas: Vec::new(),
+                checkpoints: vec![OrderBook::new(book_type, delta.instrument_id)],
+                head: OrderBook::new(book_type, delta.instrument_id),
+            });
+
+        if let Some(last) = history.deltas.last() {
+            if delta.ts_init < last.ts_init {
+                anyhow::bail!(
+                    "Delta for {} out of order, `ts_init` {} was before last {}",
+                    delta.instrument_id,
+                    delta.ts_init,
+                    last.ts_init,
+                );
+            }
+        }
+
+        history.head.apply_delta(delta);
+        history.deltas.push(delta);
+        if history.deltas.len() % self.checkpoint_interval == 0 {
+            history.checkpoints.push(history.head.clone());
+        }
+        Ok(())
+    }
+
+    /// Adds the given `deltas` to the stored streams for their instruments.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any delta is out of order for its instrument.
+    pub fn add_deltas(
+        &mut self,
+        deltas: impl IntoIterator<Item = OrderBookDelta>,
+    ) -> anyhow::Result<()> {
+        for delta in deltas {
+            self.add_delta(delta)?;
+        }
+        Ok(())
+    }
+
+    /// Adds the order book deltas from the given `data` (e.g. a catalog query result),
+    /// ignoring all other data types.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if any delta is out of order for its instrument.
+    pub fn add_data(&mut self, data: impl IntoIterator<Item = Data>) -> anyhow::Result<()> {
+        for item in data {
+            match item {
+                Data::Delta(delta) => self.add_delta(delta)?,
+                Data::Deltas(deltas) => self.add_deltas(deltas.deltas.iter().copied())?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the order book for the given `instrument_id` as at `ts`, with all deltas
+    /// up to and including `ts` applied.
+    ///
+    /// Returns `None` if there are no deltas for the instrument at or before `ts`.
+    #[must_use]
+    pub fn book_at(&self, instrument_id: &InstrumentId, ts: UnixNanos) -> Option<OrderBook> {
+        let history = self.histories.get(instrument_id)?;
+        let count = history.deltas.partition_point(|delta| delta.ts_init <= ts);
+        if count == 0 {
+            return None;
+        }
+
+        let checkpoint = count / self.checkpoint_interval;
+        let mut book = history.checkpoints[checkpoint].clone();
+        for delta in &history.deltas[checkpoint * self.checkpoint_interval..count] {
+            book.apply_delta(*delta);
+        }
+        Some(book)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::{
+            deltas::{OrderBookDeltas, OrderBookDeltas_API},
+            order::BookOrder,
+        },
+        enums::{BookAction, OrderSide},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::*;
+
+    use super::*;
+
+    fn delta(
+        action: BookAction,
+        side: OrderSide,
+        price: &str,
+        size: &str,
+        ts: u64,
+    ) -> OrderBookDelta {
+        let order_id = if side == OrderSide::Buy { 1 } else { 2 };
+        OrderBookDelta::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            action,
+            BookOrder::new(side, Price::from(price), Quantity::from(size), order_id),
+            0,
+            ts,
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    #[fixture]
+    fn deltas() -> Vec<OrderBookDelta> {
+        vec![
+            delta(BookAction::Add, OrderSide::Buy, "100.00", "1.0", 1),
+            delta(BookAction::Add, OrderSide::Sell, "100.10", "2.0", 2),
+            delta(BookAction::Update, OrderSide::Buy, "100.00", "3.0", 3),
+            delta(BookAction::Delete, OrderSide::Sell, "100.10", "0.0", 4),
+            delta(BookAction::Add, OrderSide::Sell, "100.20", "4.0", 5),
+        ]
+    }
+
+    #[rstest]
+    fn test_new_with_zero_checkpoint_interval() {
+        assert!(BookReconstructor::new(BookType::L2_MBP, 0).is_err());
+    }
+
+    #[rstest]
+    fn test_book_at(deltas: Vec<OrderBookDelta>) {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut reconstructor = BookReconstructor::new(BookType::L2_MBP, 2).unwrap();
+        reconstructor.add_deltas(deltas).unwrap();
+
+        let book_3 = reconstructor.book_at(&instrument_id, 3.into()).unwrap();
+        let book_4 = reconstructor.book_at(&instrument_id, 4.into()).unwrap();
+        let book_last = reconstructor.book_at(&instrument_id, 100.into()).unwrap();
+
+        assert!(reconstructor.book_at(&instrument_id, 0.into()).is_none());
+        assert_eq!(reconstructor.delta_count(&instrument_id), 5);
+        assert_eq!(reconstructor.checkpoint_count(&instrument_id), 3);
+        assert_eq!(book_3.best_bid_size(), Some(Quantity::from("3.0")));
+        assert_eq!(book_3.best_ask_price(), Some(Price::from("100.10")));
+        assert_eq!(book_3.ts_last, UnixNanos::from(3));
+        assert!(!book_4.has_ask());
+        assert_eq!(book_last.best_ask_price(), Some(Price::from("100.20")));
+        assert_eq!(book_last.sequence, 5);
+    }
+
+    #[rstest]
+    fn test_book_at_matches_full_replay(deltas: Vec<OrderBookDelta>) {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut checkpointed = BookReconstructor::new(BookType::L2_MBP, 1).unwrap();
+        let mut replayed = BookReconstructor::new(BookType::L2_MBP, usize::MAX).unwrap();
+        checkpointed.add_deltas(deltas.clone()).unwrap();
+        replayed.add_deltas(deltas).unwrap();
+
+        for ts in 1..=5 {
+            let lhs = checkpointed.book_at(&instrument_id, ts.into()).unwrap();
+            let rhs = replayed.book_at(&instrument_id, ts.into()).unwrap();
+            assert_eq!(lhs.pprint(5), rhs.pprint(5));
+        }
+    }
+
+    #[rstest]
+    fn test_add_delta_out_of_order(deltas: Vec<OrderBookDelta>) {
+        let mut reconstructor = BookReconstructor::new(BookType::L2_MBP, 2).unwrap();
+        reconstructor.add_delta(deltas[1]).unwrap();
+
+        assert!(reconstructor.add_delta(deltas[0]).is_err());
+    }
+
+    #[rstest]
+    fn test_add_data(deltas: Vec<OrderBookDelta>) {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut reconstructor = BookReconstructor::new(BookType::L2_MBP, 2).unwrap();
+        let data = vec![
+            Data::Delta(deltas[0]),
+            Data::Deltas(OrderBookDeltas_API::new(OrderBookDeltas::new(
+                instrument_id,
+                deltas[1..].to_vec(),
+            ))),
+        ];
+
+        reconstructor.add_data(data).unwrap();
+
+        assert_eq!(reconstructor.instrument_ids(), vec![instrument_id]);
+        assert_eq!(reconstructor.delta_count(&instrument_id), 5);
+    }
+}
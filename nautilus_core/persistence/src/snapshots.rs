@@ -0,0 +1,514 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Periodic snapshots for writing to the data catalog.
+//!
+//! Includes order book snapshots for replay, and exchange rate and account balance snapshots
+//! so that historical portfolio valuation can be reconstructed after the fact.
+
+use std::collections::HashMap;
+
+use nautilus_core::{
+    correctness::{check_in_range_inclusive_usize, check_positive_u64},
+    nanos::UnixNanos,
+};
+use nautilus_model::{
+    data::depth::{OrderBookDepth10, DEPTH10_LEN},
+    identifiers::{account_id::AccountId, instrument_id::InstrumentId},
+    orderbook::book::OrderBook,
+    types::{balance::AccountBalance, currency::Currency},
+};
+
+use crate::{
+    arrow::{DataStreamingError, WriteStream},
+    backend::session::DataBackendSession,
+};
+
+/// Provides interval aligned snapshot timing.
+///
+/// Snapshot times are aligned to multiples of the interval, so that snapshots across
+/// instruments (and runs) share the same timestamps.
+#[derive(Debug)]
+struct SnapshotTimer {
+    interval_ns: u64,
+    next_snapshot_ns: Option<UnixNanos>,
+}
+
+impl SnapshotTimer {
+    fn new(interval_ns: u64) -> anyhow::Result<Self> {
+        check_positive_u64(interval_ns, stringify!(interval_ns))?;
+
+        Ok(Self {
+            interval_ns,
+            next_snapshot_ns: None,
+        })
+    }
+
+    /// Returns the aligned snapshot time if a snapshot is due at `now`, scheduling the next
+    /// snapshot if so.
+    ///
+    /// The snapshot time is the latest interval boundary at or before `now`, which is the
+    /// scheduled time unless whole intervals have passed without a call.
+    /// The first call only schedules the next snapshot at the following interval boundary.
+    fn on_time(&mut self, now: UnixNanos) -> Option<UnixNanos> {
+        let is_due = self
+            .next_snapshot_ns
+            .map_or(false, |next_snapshot_ns| now >= next_snapshot_ns);
+
+        let now_ns = now.as_u64();
+        let boundary_ns = now_ns - now_ns % self.interval_ns;
+        if is_due || self.next_snapshot_ns.is_none() {
+            self.next_snapshot_ns = Some((boundary_ns + self.interval_ns).into());
+        }
+
+        is_due.then_some(boundary_ns.into())
+    }
+}
+
+/// Provides a scheduler which snapshots the top levels of maintained order books at a
+/// fixed cadence, buffering the snapshots as `OrderBookDepth10` records for the catalog.
+#[derive(Debug)]
+pub struct BookSnapshotScheduler {
+    timer: SnapshotTimer,
+    depth: usize,
+    buffers: HashMap<InstrumentId, Vec<OrderBookDepth10>>,
+}
+
+impl BookSnapshotScheduler {
+    /// Creates a new [`BookSnapshotScheduler`] instance.
+    ///
+    /// The `depth` is the number of levels per side to snapshot, in the range [1, 10].
+    pub fn new(interval_ns: u64, depth: usize) -> anyhow::Result<Self> {
+        check_in_range_inclusive_usize(depth, 1, DEPTH10_LEN, stringify!(depth))?;
+
+        Ok(Self {
+            timer: SnapshotTimer::new(interval_ns)?,
+            depth,
+            buffers: HashMap::new(),
+        })
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) of the next scheduled snapshot (if started).
+    #[must_use]
+    pub fn next_snapshot_ns(&self) -> Option<UnixNanos> {
+        self.timer.next_snapshot_ns
+    }
+
+    /// Returns the count of buffered snapshots awaiting writing.
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.buffers.values().map(Vec::len).sum()
+    }
+
+    /// Handles the current time `now`, snapshotting the given `books` if a snapshot is due.
+    ///
+    /// Snapshots are stamped with the aligned snapshot time as `ts_event`, and `now` as
+    /// `ts_init`. The first call only schedules the next snapshot at the following interval
+    /// boundary. Returns the count of snapshots taken.
+    pub fn on_time<'a>(
+        &mut self,
+        now: UnixNanos,
+        books: impl IntoIterator<Item = &'a OrderBook>,
+    ) -> usize {
+        match self.timer.on_time(now) {
+            Some(snapshot_ns) => self.buffer(books, Some(snapshot_ns), now),
+            None => 0,
+        }
+    }
+
+    /// Snapshots the given `books` immediately, returning the count of snapshots taken.
+    ///
+    /// Snapshots are stamped with the last book update as `ts_event`.
+    pub fn snapshot<'a>(
+        &mut self,
+        ts_init: UnixNanos,
+        books: impl IntoIterator<Item = &'a OrderBook>,
+    ) -> usize {
+        self.buffer(books, None, ts_init)
+    }
+
+    fn buffer<'a>(
+        &mut self,
+        books: impl IntoIterator<Item = &'a OrderBook>,
+        ts_event: Option<UnixNanos>,
+        ts_init: UnixNanos,
+    ) -> usize {
+        let mut count = 0;
+        for book in books {
+            let mut depth = book.to_depth10(self.depth, ts_init);
+            if let Some(ts_event) = ts_event {
+                depth.ts_event = ts_event;
+            }
+            self.buffers
+                .entry(book.instrument_id)
+                .or_default()
+                .push(depth);
+            count += 1;
+        }
+        count
+    }
+
+    /// Drains the buffered snapshots, grouped by instrument ID.
+    pub fn drain(&mut self) -> Vec<(InstrumentId, Vec<OrderBookDepth10>)> {
+        let mut snapshots: Vec<(InstrumentId, Vec<OrderBookDepth10>)> =
+            self.buffers.drain().collect();
+        snapshots.sort_by_key(|(instrument_id, _)| *instrument_id);
+        snapshots
+    }
+}
+
+/// Represents the exchange rate between two currencies at a point in time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExchangeRateSnapshot {
+    /// The currency converted from.
+    pub from_currency: Currency,
+    /// The currency converted to.
+    pub to_currency: Currency,
+    /// The exchange rate, i.e. the units of `to_currency` per unit of `from_currency`.
+    pub rate: f64,
+    /// The UNIX timestamp (nanoseconds) when the snapshot was taken.
+    pub ts_init: UnixNanos,
+}
+
+/// Represents the balance of an account in a single currency at a point in time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AccountBalanceSnapshot {
+    /// The account ID for the balance.
+    pub account_id: AccountId,
+    /// The account balance.
+    pub balance: AccountBalance,
+    /// The UNIX timestamp (nanoseconds) when the snapshot was taken.
+    pub ts_init: UnixNanos,
+}
+
+/// Provides a scheduler which snapshots exchange rates and account balances at a fixed
+/// cadence, buffering the snapshots for the catalog.
+///
+/// Together these allow the portfolio to be valued historically in any settlement currency
+/// (e.g. for an equity curve), using the rates which were in effect at the time.
+#[derive(Debug)]
+pub struct ValuationSnapshotScheduler {
+    timer: SnapshotTimer,
+    rates: Vec<ExchangeRateSnapshot>,
+    balances: HashMap<AccountId, Vec<AccountBalanceSnapshot>>,
+}
+
+impl ValuationSnapshotScheduler {
+    /// Creates a new [`ValuationSnapshotScheduler`] instance.
+    pub fn new(interval_ns: u64) -> anyhow::Result<Self> {
+        Ok(Self {
+            timer: SnapshotTimer::new(interval_ns)?,
+            rates: Vec::new(),
+            balances: HashMap::new(),
+        })
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) of the next scheduled snapshot (if started).
+    #[must_use]
+    pub fn next_snapshot_ns(&self) -> Option<UnixNanos> {
+        self.timer.next_snapshot_ns
+    }
+
+    /// Returns the count of buffered snapshots awaiting writing.
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.rates.len() + self.balances.values().map(Vec::len).sum::<usize>()
+    }
+
+    /// Returns the aligned snapshot time if a snapshot is due at `now`, in which case the
+    /// caller should snapshot the current rates and balances with it as `ts_init`.
+    ///
+    /// The first call only schedules the next snapshot at the following interval boundary.
+    pub fn on_time(&mut self, now: UnixNanos) -> Option<UnixNanos> {
+        self.timer.on_time(now)
+    }
+
+    /// Snapshots the given exchange `rates` as (from currency, to currency, rate).
+    pub fn snapshot_rates(
+        &mut self,
+        ts_init: UnixNanos,
+        rates: impl IntoIterator<Item = (Currency, Currency, f64)>,
+    ) {
+        self.rates
+            .extend(rates.into_iter().map(|(from_currency, to_currency, rate)| {
+                ExchangeRateSnapshot {
+                    from_currency,
+                    to_currency,
+                    rate,
+                    ts_init,
+                }
+            }));
+    }
+
+    /// Snapshots the given `balances` for the account.
+    pub fn snapshot_balances(
+        &mut self,
+        ts_init: UnixNanos,
+        account_id: AccountId,
+        balances: impl IntoIterator<Item = AccountBalance>,
+    ) {
+        let buffer = self.balances.entry(account_id).or_default();
+        let mut balances: Vec<AccountBalance> = balances.into_iter().collect();
+        balances.sort_by_key(|balance| balance.currency.code);
+        buffer.extend(balances.into_iter().map(|balance| AccountBalanceSnapshot {
+            account_id,
+            balance,
+            ts_init,
+        }));
+    }
+
+    /// Drains the buffered exchange rate snapshots.
+    pub fn drain_rates(&mut self) -> Vec<ExchangeRateSnapshot> {
+        std::mem::take(&mut self.rates)
+    }
+
+    /// Drains the buffered account balance snapshots, grouped by account ID.
+    pub fn drain_balances(&mut self) -> Vec<(AccountId, Vec<AccountBalanceSnapshot>)> {
+        let mut snapshots: Vec<(AccountId, Vec<AccountBalanceSnapshot>)> =
+            self.balances.drain().collect();
+        snapshots.sort_by_key(|(account_id, _)| *account_id);
+        snapshots
+    }
+}
+
+/// Writes the `snapshots` for a single instrument to the given catalog `stream`.
+///
+/// The price and size precisions for the metadata are taken from the best bid (or ask)
+/// of the first snapshot.
+pub fn write_book_snapshots(
+    snapshots: &[OrderBookDepth10],
+    stream: &mut dyn WriteStream,
+) -> Result<(), DataStreamingError> {
+    let Some(first) = snapshots.first() else {
+        return Ok(()); // Nothing to write
+    };
+
+    let top = if first.bid_counts[0] > 0 {
+        first.bids[0]
+    } else {
+        first.asks[0]
+    };
+
+    let metadata = OrderBookDepth10::get_metadata(
+        &first.instrument_id,
+        top.price.precision,
+        top.size.precision,
+    );
+
+    DataBackendSession::write_data(snapshots, &metadata, stream)
+}
+
+/// Writes the exchange rate `snapshots` to the given catalog `stream`.
+pub fn write_exchange_rate_snapshots(
+    snapshots: &[ExchangeRateSnapshot],
+    stream: &mut dyn WriteStream,
+) -> Result<(), DataStreamingError> {
+    if snapshots.is_empty() {
+        return Ok(()); // Nothing to write
+    }
+
+    DataBackendSession::write_data(snapshots, &HashMap::new(), stream)
+}
+
+/// Writes the balance `snapshots` for a single account to the given catalog `stream`.
+pub fn write_account_balance_snapshots(
+    snapshots: &[AccountBalanceSnapshot],
+    stream: &mut dyn WriteStream,
+) -> Result<(), DataStreamingError> {
+    let Some(first) = snapshots.first() else {
+        return Ok(()); // Nothing to write
+    };
+
+    let metadata = AccountBalanceSnapshot::get_metadata(&first.account_id);
+    DataBackendSession::write_data(snapshots, &metadata, stream)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use datafusion::arrow::record_batch::RecordBatch;
+    use nautilus_model::{
+        data::order::BookOrder,
+        enums::{BookType, OrderSide},
+        types::{money::Money, price::Price, quantity::Quantity},
+    };
+    use rstest::*;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct RecordingStream {
+        rows: usize,
+    }
+
+    impl WriteStream for RecordingStream {
+        fn write(&mut self, record_batch: &RecordBatch) -> Result<(), DataStreamingError> {
+            self.rows += record_batch.num_rows();
+            Ok(())
+        }
+    }
+
+    #[fixture]
+    fn book() -> OrderBook {
+        let mut book = OrderBook::new(BookType::L2_MBP, InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+        let bid = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from("1.0"),
+            1,
+        );
+        let ask = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("100.10"),
+            Quantity::from("2.0"),
+            2,
+        );
+        book.add(bid, 0, 1, 1.into());
+        book.add(ask, 0, 2, 1.into());
+        book
+    }
+
+    #[rstest]
+    fn test_new_with_invalid_depth() {
+        assert!(BookSnapshotScheduler::new(1_000, 0).is_err());
+        assert!(BookSnapshotScheduler::new(1_000, 11).is_err());
+    }
+
+    #[rstest]
+    fn test_first_time_schedules_next_interval(book: OrderBook) {
+        let mut scheduler = BookSnapshotScheduler::new(1_000, 5).unwrap();
+
+        let count = scheduler.on_time(1_500.into(), [&book]);
+
+        assert_eq!(count, 0);
+        assert_eq!(scheduler.next_snapshot_ns(), Some(2_000.into()));
+    }
+
+    #[rstest]
+    fn test_snapshots_when_due(book: OrderBook) {
+        let mut scheduler = BookSnapshotScheduler::new(1_000, 5).unwrap();
+        scheduler.on_time(1_500.into(), [&book]);
+
+        assert_eq!(scheduler.on_time(1_999.into(), [&book]), 0);
+        assert_eq!(scheduler.on_time(2_100.into(), [&book]), 1);
+        assert_eq!(scheduler.next_snapshot_ns(), Some(3_000.into()));
+        assert_eq!(scheduler.pending_count(), 1);
+
+        let snapshots = scheduler.drain();
+
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[0].0, book.instrument_id);
+        assert_eq!(snapshots[0].1[0].bids[0].price, Price::from("100.00"));
+        assert_eq!(snapshots[0].1[0].ts_event, 2_000);
+        assert_eq!(snapshots[0].1[0].ts_init, 2_100);
+        assert_eq!(scheduler.pending_count(), 0);
+    }
+
+    #[rstest]
+    fn test_snapshot_time_aligned_after_missed_intervals(book: OrderBook) {
+        let mut scheduler = BookSnapshotScheduler::new(1_000, 5).unwrap();
+        scheduler.on_time(1_500.into(), [&book]);
+
+        assert_eq!(scheduler.on_time(4_250.into(), [&book]), 1);
+        assert_eq!(scheduler.next_snapshot_ns(), Some(5_000.into()));
+
+        let snapshots = scheduler.drain();
+
+        assert_eq!(snapshots[0].1[0].ts_event, 4_000);
+    }
+
+    #[rstest]
+    fn test_write_book_snapshots(book: OrderBook) {
+        let mut scheduler = BookSnapshotScheduler::new(1_000, 5).unwrap();
+        scheduler.snapshot(1_000.into(), [&book]);
+        scheduler.snapshot(2_000.into(), [&book]);
+        let mut stream = RecordingStream::default();
+
+        for (_, snapshots) in scheduler.drain() {
+            write_book_snapshots(&snapshots, &mut stream).unwrap();
+        }
+
+        assert_eq!(stream.rows, 2);
+    }
+
+    #[rstest]
+    fn test_valuation_snapshots_when_due() {
+        let mut scheduler = ValuationSnapshotScheduler::new(1_000).unwrap();
+        let account_id = AccountId::from("SIM-001");
+        let usd = AccountBalance::new(
+            Money::from("1000 USD"),
+            Money::from("0 USD"),
+            Money::from("1000 USD"),
+        )
+        .unwrap();
+        let aud = AccountBalance::new(
+            Money::from("500 AUD"),
+            Money::from("0 AUD"),
+            Money::from("500 AUD"),
+        )
+        .unwrap();
+
+        assert_eq!(scheduler.on_time(1_500.into()), None);
+        assert_eq!(scheduler.on_time(2_000.into()), Some(2_000.into()));
+        scheduler.snapshot_rates(2_000.into(), [(Currency::AUD(), Currency::USD(), 0.665)]);
+        scheduler.snapshot_balances(2_000.into(), account_id, [usd, aud]);
+
+        assert_eq!(scheduler.pending_count(), 3);
+        assert_eq!(scheduler.next_snapshot_ns(), Some(3_000.into()));
+
+        let rates = scheduler.drain_rates();
+        let balances = scheduler.drain_balances();
+
+        assert_eq!(rates.len(), 1);
+        assert_eq!(rates[0].rate, 0.665);
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].0, account_id);
+        assert_eq!(balances[0].1[0].balance.currency, Currency::AUD());
+        assert_eq!(scheduler.pending_count(), 0);
+    }
+
+    #[rstest]
+    fn test_write_valuation_snapshots() {
+        let mut scheduler = ValuationSnapshotScheduler::new(1_000).unwrap();
+        let account_id = AccountId::from("SIM-001");
+        let balance = AccountBalance::new(
+            Money::from("1000 USD"),
+            Money::from("100 USD"),
+            Money::from("900 USD"),
+        )
+        .unwrap();
+        scheduler.snapshot_rates(1_000.into(), [(Currency::EUR(), Currency::USD(), 1.08)]);
+        scheduler.snapshot_balances(1_000.into(), account_id, [balance]);
+        let mut stream = RecordingStream::default();
+
+        write_exchange_rate_snapshots(&scheduler.drain_rates(), &mut stream).unwrap();
+        for (_, snapshots) in scheduler.drain_balances() {
+            write_account_balance_snapshots(&snapshots, &mut stream).unwrap();
+        }
+
+        assert_eq!(stream.rows, 2);
+    }
+
+    #[rstest]
+    fn test_write_book_snapshots_when_empty() {
+        let mut stream = RecordingStream::default();
+
+        write_book_snapshots(&[], &mut stream).unwrap();
+
+        assert_eq!(stream.rows, 0);
+    }
+}
@@ -0,0 +1,768 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a `NodeBuilder` which assembles a runnable `LiveNode` or `BacktestNode` from a
+//! single [`NodeConfig`].
+
+use std::{future::Future, time::Duration};
+
+use log::info;
+use nautilus_common::{
+    cache::{database::CacheDatabaseAdapter, Cache},
+    clock::{LiveClock, TestClock},
+    live::data_engine::{DataClient, DataEngineMessage, LiveDataEngine},
+    msgbus::MessageBus,
+};
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_execution::{
+    algorithm::ExecAlgorithm,
+    client::ExecutionClient,
+    live::execution_engine::{ExecutionEngineMessage, LiveExecutionEngine},
+};
+use nautilus_model::data::{Data, GetTsInit};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use crate::{
+    config::{Environment, NodeConfig},
+    kernel::{NautilusKernel, NodeClock},
+    risk::RiskEngine,
+    strategy::Strategy,
+};
+
+/// Provides a builder which wires the clock, cache, message bus, engines, clients,
+/// strategies and execution algorithms of a trading node from a [`NodeConfig`].
+///
+/// Clients pass received data and order events back to the node through the channels from
+/// [`NodeBuilder::data_sender`] and [`NodeBuilder::execution_sender`].
+pub struct NodeBuilder {
+    config: NodeConfig,
+    cache_database: Option<Box<dyn CacheDatabaseAdapter>>,
+    data_clients: Vec<(Box<dyn DataClient>, bool)>,
+    exec_clients: Vec<(Box<dyn ExecutionClient>, bool)>,
+    strategies: Vec<Box<dyn Strategy>>,
+    exec_algorithms: Vec<Box<dyn ExecAlgorithm>>,
+    data_tx: UnboundedSender<DataEngineMessage>,
+    data_rx: UnboundedReceiver<DataEngineMessage>,
+    exec_tx: UnboundedSender<ExecutionEngineMessage>,
+    exec_rx: UnboundedReceiver<ExecutionEngineMessage>,
+}
+
+impl NodeBuilder {
+    /// Creates a new [`NodeBuilder`] instance.
+    #[must_use]
+    pub fn new(config: NodeConfig) -> Self {
+        let (data_tx, data_rx) = unbounded_channel();
+        let (exec_tx, exec_rx) = unbounded_channel();
+        Self {
+            config,
+            cache_database: None,
+            data_clients: Vec::new(),
+            exec_clients: Vec::new(),
+            strategies: Vec::new(),
+            exec_algorithms: Vec::new(),
+            data_tx,
+            data_rx,
+            exec_tx,
+            exec_rx,
+        }
+    }
+
+    /// Returns a sender for data clients to pass data and responses to the node.
+    #[must_use]
+    pub fn data_sender(&self) -> UnboundedSender<DataEngineMessage> {
+        self.data_tx.clone()
+    }
+
+    /// Returns a sender for execution clients to pass order events to the node.
+    #[must_use]
+    pub fn execution_sender(&self) -> UnboundedSender<ExecutionEngineMessage> {
+        self.exec_tx.clone()
+    }
+
+    /// Sets the database for the cache, overriding any `cache_database` config.
+    #[must_use]
+    pub fn with_cache_database(mut self, database: Box<dyn CacheDatabaseAdapter>) -> Self {
+        self.cache_database = Some(database);
+        self
+    }
+
+    /// Adds the data `client`, routed to for its venue.
+    #[must_use]
+    pub fn with_data_client(mut self, client: Box<dyn DataClient>) -> Self {
+        self.data_clients.push((client, false));
+        self
+    }
+
+    /// Adds the data `client` as the default, for subscriptions with no other route.
+    #[must_use]
+    pub fn with_default_data_client(mut self, client: Box<dyn DataClient>) -> Self {
+        self.data_clients.push((client, true));
+        self
+    }
+
+    /// Adds the execution `client`, routed to for its venue.
+    #[must_use]
+    pub fn with_execution_client(mut self, client: Box<dyn ExecutionClient>) -> Self {
+        self.exec_clients.push((client, false));
+        self
+    }
+
+    /// Adds the execution `client` as the default, for commands with no other route.
+    #[must_use]
+    pub fn with_default_execution_client(mut self, client: Box<dyn ExecutionClient>) -> Self {
+        self.exec_clients.push((client, true));
+        self
+    }
+
+    /// Adds the `strategy` to be run by the node.
+    #[must_use]
+    pub fn with_strategy(mut self, strategy: Box<dyn Strategy>) -> Self {
+        self.strategies.push(strategy);
+        self
+    }
+
+    /// Adds the execution `algorithm`, for orders with its `exec_algorithm_id`.
+    #[must_use]
+    pub fn with_exec_algorithm(mut self, algorithm: Box<dyn ExecAlgorithm>) -> Self {
+        self.exec_algorithms.push(algorithm);
+        self
+    }
+
+    /// Builds a [`LiveNode`] for the live or sandbox environments.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the environment is backtest, or any component
+    /// fails to build.
+    pub fn build_live(self) -> anyhow::Result<LiveNode> {
+        if self.config.environment == Environment::Backtest {
+            anyhow::bail!("Cannot build a live node for the backtest environment");
+        }
+
+        let inflight_check_interval =
+            Duration::from_millis(self.config.exec_engine.inflight_check_interval_ms);
+        let (kernel, data_rx, exec_rx) = self.build_kernel()?;
+        Ok(LiveNode {
+            kernel,
+            data_rx,
+            exec_rx,
+            inflight_check_interval,
+        })
+    }
+
+    /// Builds a [`BacktestNode`] for the backtest environment.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the environment is not backtest, or any component
+    /// fails to build.
+    pub fn build_backtest(self) -> anyhow::Result<BacktestNode> {
+        if self.config.environment != Environment::Backtest {
+            anyhow::bail!(
+                "Cannot build a backtest node for the {} environment",
+                self.config.environment
+            );
+        }
+
+        let (kernel, data_rx, exec_rx) = self.build_kernel()?;
+        Ok(BacktestNode {
+            kernel,
+            data_rx,
+            exec_rx,
+        })
+    }
+
+    fn build_kernel(
+        self,
+    ) -> anyhow::Result<(
+        NautilusKernel,
+        UnboundedReceiver<DataEngineMessage>,
+        UnboundedReceiver<ExecutionEngineMessage>,
+    )> {
+        let config = self.config;
+        let trader_id = config.trader_id;
+        let instance_id = config.instance_id.unwrap_or_default();
+
+        let clock = match config.environment {
+            Environment::Backtest => NodeClock::Test(TestClock::new()),
+            Environment::Sandbox | Environment::Live => NodeClock::Live(LiveClock::new()),
+        };
+
+        let cache_database = match self.cache_database {
+            Some(database) => Some(database),
+            None => match config.cache_database {
+                Some(database_config) => Some(build_cache_database(
+                    trader_id,
+                    instance_id,
+                    database_config,
+                    config.cache.encoding,
+                )?),
+                None => None,
+            },
+        };
+        let cache = Cache::new(config.cache, cache_database);
+        let msgbus = MessageBus::new(trader_id, instance_id, None, config.msgbus)?;
+
+        let (data_out_tx, data_out_rx) = unbounded_channel();
+        let mut data_engine = LiveDataEngine::new(data_out_tx);
+        for (client, is_default) in self.data_clients {
+            if is_default {
+                data_engine.register_default_client(client)?;
+            } else {
+                data_engine.register_client(client)?;
+            }
+        }
+
+        let (event_tx, event_rx) = unbounded_channel();
+        let mut exec_engine = LiveExecutionEngine::new(cache, event_tx, config.exec_engine)?;
+        for (client, is_default) in self.exec_clients {
+            if is_default {
+                exec_engine.register_default_client(client)?;
+            } else {
+                exec_engine.register_client(client)?;
+            }
+        }
+
+        let mut kernel = NautilusKernel::new(
+            config.environment,
+            clock,
+            msgbus,
+            data_engine,
+            exec_engine,
+            RiskEngine::new(config.risk_engine)?,
+            config.reconciliation,
+            data_out_rx,
+            event_rx,
+        );
+        for strategy in self.strategies {
+            kernel.add_strategy(strategy)?;
+        }
+        for algorithm in self.exec_algorithms {
+            kernel.add_exec_algorithm(algorithm)?;
+        }
+
+        info!("Built {} node for {trader_id}", config.environment);
+        Ok((kernel, self.data_rx, self.exec_rx))
+    }
+}
+
+#[cfg(feature = "redis")]
+fn build_cache_database(
+    trader_id: nautilus_model::identifiers::trader_id::TraderId,
+    instance_id: UUID4,
+    config: std::collections::HashMap<String, serde_json::Value>,
+    encoding: nautilus_common::enums::SerializationEncoding,
+) -> anyhow::Result<Box<dyn CacheDatabaseAdapter>> {
+    use nautilus_infrastructure::redis::cache::{RedisCacheDatabase, RedisCacheDatabaseAdapter};
+
+    let database = RedisCacheDatabase::new(trader_id, instance_id, config)?;
+    Ok(Box::new(RedisCacheDatabaseAdapter::new(database, encoding)))
+}
+
+#[cfg(not(feature = "redis"))]
+fn build_cache_database(
+    _trader_id: nautilus_model::identifiers::trader_id::TraderId,
+    _instance_id: UUID4,
+    _config: std::collections::HashMap<String, serde_json::Value>,
+    _encoding: nautilus_common::enums::SerializationEncoding,
+) -> anyhow::Result<Box<dyn CacheDatabaseAdapter>> {
+    anyhow::bail!("The `cache_database` config requires the `redis` feature")
+}
+
+/// The resolution of the execution algorithm timers for a live node.
+const TIMER_RESOLUTION: Duration = Duration::from_millis(100);
+
+/// Provides a live trading node, running the kernel with the messages from its clients.
+pub struct LiveNode {
+    kernel: NautilusKernel,
+    data_rx: UnboundedReceiver<DataEngineMessage>,
+    exec_rx: UnboundedReceiver<ExecutionEngineMessage>,
+    inflight_check_interval: Duration,
+}
+
+impl LiveNode {
+    /// Returns a reference to the kernel.
+    #[must_use]
+    pub fn kernel(&self) -> &NautilusKernel {
+        &self.kernel
+    }
+
+    /// Returns a mutable reference to the kernel.
+    pub fn kernel_mut(&mut self) -> &mut NautilusKernel {
+        &mut self.kernel
+    }
+
+    /// Starts the node and handles client messages until `shutdown` completes, then stops
+    /// the node.
+    pub async fn run(&mut self, shutdown: impl Future<Output = ()>) -> anyhow::Result<()> {
+        self.kernel.start()?;
+
+        let mut interval = tokio::time::interval(self.inflight_check_interval);
+        let mut timer_interval = tokio::time::interval(TIMER_RESOLUTION);
+        tokio::pin!(shutdown);
+
+        loop {
+            tokio::select! {
+                () = &mut shutdown => break,
+                Some(msg) = self.data_rx.recv() => self.kernel.handle_data_message(msg),
+                Some(msg) = self.exec_rx.recv() => self.kernel.handle_execution_message(msg),
+                _ = interval.tick() => {
+                    self.kernel.check_in_flight();
+                    self.kernel.accrue_interest();
+                    self.kernel.cache().record_memory_usage();
+                }
+                _ = timer_interval.tick() => self.kernel.process_timers(),
+            }
+        }
+
+        self.kernel.stop()
+    }
+}
+
+/// Provides a backtest node, running the kernel over historical data in timestamp order.
+///
+/// Simulated execution clients pass their order events through the execution sender, and
+/// are processed after each data point.
+pub struct BacktestNode {
+    kernel: NautilusKernel,
+    data_rx: UnboundedReceiver<DataEngineMessage>,
+    exec_rx: UnboundedReceiver<ExecutionEngineMessage>,
+}
+
+impl BacktestNode {
+    /// Returns a reference to the kernel.
+    #[must_use]
+    pub fn kernel(&self) -> &NautilusKernel {
+        &self.kernel
+    }
+
+    /// Returns a mutable reference to the kernel.
+    pub fn kernel_mut(&mut self) -> &mut NautilusKernel {
+        &mut self.kernel
+    }
+
+    /// Runs the backtest over the `data`, advancing the clock to each data point and to each
+    /// execution algorithm timer in between.
+    pub fn run(&mut self, mut data: Vec<Data>) -> anyhow::Result<()> {
+        data.sort_by_key(GetTsInit::ts_init);
+        if let Some(first) = data.first() {
+            self.kernel.clock.set_time(first.ts_init());
+        }
+
+        self.kernel.start()?;
+        self.process_messages();
+
+        for item in data {
+            self.advance_timers(item.ts_init());
+            self.kernel.clock.set_time(item.ts_init());
+            self.kernel.process_data(item);
+            self.process_messages();
+        }
+
+        self.kernel.stop()?;
+        self.process_messages();
+        Ok(())
+    }
+
+    fn advance_timers(&mut self, to_time: UnixNanos) {
+        while let Some(time) = self.kernel.exec_algorithms.next_timer_ns() {
+            if time > to_time {
+                break;
+            }
+            self.kernel.clock.set_time(time);
+            self.kernel.process_timers();
+            self.process_messages();
+        }
+    }
+
+    fn process_messages(&mut self) {
+        loop {
+            if let Ok(msg) = self.exec_rx.try_recv() {
+                self.kernel.handle_execution_message(msg);
+            } else if let Ok(msg) = self.data_rx.try_recv() {
+                self.kernel.handle_data_message(msg);
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_accounting::account::margin::MarginAccount;
+    use nautilus_common::live::data_engine::{
+        DataEngineMessage, DataRequest, DataResponse, DataSubscription,
+    };
+    use nautilus_execution::messages::{
+        cancel::CancelOrder, cancel_all::CancelAllOrders, modify::ModifyOrder, query::QueryOrder,
+        submit::SubmitOrder, submit_list::SubmitOrderList,
+    };
+    use nautilus_indicators::warmup::WarmupRequirement;
+    use nautilus_model::{
+        data::{
+            bar::BarType,
+            borrow::{BorrowRateUpdate, NANOSECONDS_IN_YEAR},
+            quote::QuoteTick,
+            stubs::quote_tick_ethusdt_binance,
+        },
+        enums::{OmsType, OrderSide, OrderStatus, TradingState},
+        events::{account::stubs::margin_account_state, order::OrderEventAny},
+        identifiers::{
+            account_id::AccountId, client_id::ClientId, instrument_id::InstrumentId,
+            strategy_id::StrategyId, stubs::account_id, trader_id::TraderId, venue::Venue,
+            venue_order_id::VenueOrderId,
+        },
+        orders::stubs::{TestOrderEventStubs, TestOrderStubs},
+        reports::{fill::FillReport, order::OrderStatusReport, position::PositionStatusReport},
+        types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::strategy::StrategyContext;
+
+    type EventLog = Rc<RefCell<Vec<String>>>;
+
+    struct StubDataClient;
+
+    impl DataClient for StubDataClient {
+        fn client_id(&self) -> ClientId {
+            ClientId::from("BINANCE")
+        }
+
+        fn venue(&self) -> Option<Venue> {
+            Some(Venue::from("BINANCE"))
+        }
+
+        fn subscribe(&mut self, _subscription: &DataSubscription) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn unsubscribe(&mut self, _subscription: &DataSubscription) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn request(&mut self, _request: &DataRequest) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Accepts every submitted order immediately.
+    struct StubExecutionClient {
+        tx: UnboundedSender<ExecutionEngineMessage>,
+    }
+
+    impl ExecutionClient for StubExecutionClient {
+        fn client_id(&self) -> ClientId {
+            ClientId::from("BINANCE")
+        }
+
+        fn venue(&self) -> Venue {
+            Venue::from("BINANCE")
+        }
+
+        fn oms_type(&self) -> OmsType {
+            OmsType::Netting
+        }
+
+        fn account_id(&self) -> AccountId {
+            AccountId::from("BINANCE-001")
+        }
+
+        fn is_connected(&self) -> bool {
+            true
+        }
+
+        fn submit_order(&mut self, command: &SubmitOrder) -> anyhow::Result<()> {
+            let account_id = self.account_id();
+            for event in [
+                TestOrderEventStubs::order_submitted(&command.order, account_id),
+                TestOrderEventStubs::order_accepted(
+                    &command.order,
+                    account_id,
+                    VenueOrderId::from("V-001"),
+                ),
+            ] {
+                self.tx.send(ExecutionEngineMessage::Event(event))?;
+            }
+            Ok(())
+        }
+
+        fn submit_order_list(&mut self, _command: &SubmitOrderList) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn modify_order(&mut self, _command: &ModifyOrder) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn cancel_order(&mut self, _command: &CancelOrder) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn cancel_all_orders(&mut self, _command: &CancelAllOrders) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn query_order(&mut self, _command: &QueryOrder) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn generate_order_status_reports(
+            &self,
+            _instrument_id: Option<InstrumentId>,
+            _start: Option<UnixNanos>,
+            _end: Option<UnixNanos>,
+            _open_only: bool,
+        ) -> anyhow::Result<Vec<OrderStatusReport>> {
+            Ok(Vec::new())
+        }
+
+        fn generate_fill_reports(
+            &self,
+            _instrument_id: Option<InstrumentId>,
+            _venue_order_id: Option<VenueOrderId>,
+            _start: Option<UnixNanos>,
+            _end: Option<UnixNanos>,
+        ) -> anyhow::Result<Vec<FillReport>> {
+            Ok(Vec::new())
+        }
+
+        fn generate_position_status_reports(
+            &self,
+            _instrument_id: Option<InstrumentId>,
+            _start: Option<UnixNanos>,
+            _end: Option<UnixNanos>,
+        ) -> anyhow::Result<Vec<PositionStatusReport>> {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Submits a limit order on the first quote received.
+    struct StubStrategy {
+        log: EventLog,
+    }
+
+    impl Strategy for StubStrategy {
+        fn strategy_id(&self) -> StrategyId {
+            StrategyId::default()
+        }
+
+        fn on_start(&mut self, ctx: &mut StrategyContext) -> anyhow::Result<()> {
+            ctx.subscribe(DataSubscription::Quotes(InstrumentId::from(
+                "ETHUSDT-PERP.BINANCE",
+            )));
+            Ok(())
+        }
+
+        fn on_data(&mut self, ctx: &mut StrategyContext, _data: &Data) -> anyhow::Result<()> {
+            self.log.borrow_mut().push("Data".to_string());
+            if self.log.borrow().len() == 1 {
+                let order = TestOrderStubs::limit_order(
+                    InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+                    OrderSide::Buy,
+                    Price::from("10000.0000"),
+                    Quantity::from(1),
+                    None,
+                    None,
+                );
+                ctx.submit_order(order, None)?;
+            }
+            Ok(())
+        }
+
+        fn on_order_event(
+            &mut self,
+            _ctx: &mut StrategyContext,
+            event: &OrderEventAny,
+        ) -> anyhow::Result<()> {
+            self.log.borrow_mut().push(event.event_type().to_string());
+            Ok(())
+        }
+    }
+
+    /// Requests warm-up history on start.
+    struct WarmupStrategy {
+        log: EventLog,
+        request_id: Rc<RefCell<Option<UUID4>>>,
+    }
+
+    impl Strategy for WarmupStrategy {
+        fn strategy_id(&self) -> StrategyId {
+            StrategyId::default()
+        }
+
+        fn on_start(&mut self, ctx: &mut StrategyContext) -> anyhow::Result<()> {
+            let bar_type = BarType::from("ETHUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL");
+            let requirement = WarmupRequirement::new(bar_type, 10)?;
+            *self.request_id.borrow_mut() = Some(ctx.request_warmup(&requirement));
+            Ok(())
+        }
+
+        fn on_response(
+            &mut self,
+            _ctx: &mut StrategyContext,
+            response: &DataResponse,
+        ) -> anyhow::Result<()> {
+            self.log
+                .borrow_mut()
+                .push(format!("Response({})", response.correlation_id));
+            Ok(())
+        }
+    }
+
+    fn quote(ts: u64) -> Data {
+        Data::Quote(QuoteTick {
+            ts_event: ts.into(),
+            ts_init: ts.into(),
+            ..quote_tick_ethusdt_binance()
+        })
+    }
+
+    fn backtest_node(config: NodeConfig, log: &EventLog) -> BacktestNode {
+        let builder = NodeBuilder::new(config);
+        let exec_tx = builder.execution_sender();
+        builder
+            .with_data_client(Box::new(StubDataClient))
+            .with_execution_client(Box::new(StubExecutionClient { tx: exec_tx }))
+            .with_strategy(Box::new(StubStrategy { log: log.clone() }))
+            .build_backtest()
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_backtest_node_runs_strategy() {
+        let log = EventLog::default();
+        let mut node = backtest_node(
+            NodeConfig::new(Environment::Backtest, TraderId::default()),
+            &log,
+        );
+
+        node.run(vec![quote(2), quote(1)]).unwrap();
+
+        let order = node
+            .kernel()
+            .cache()
+            .orders_open(None, None, None, None)
+            .into_iter()
+            .next()
+            .cloned()
+            .unwrap();
+        assert_eq!(*log.borrow(), vec!["Data", "Submitted", "Accepted", "Data"]);
+        assert_eq!(node.kernel().msgbus.pub_count, 4);
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(node.kernel().clock.timestamp_ns(), 2);
+        assert!(!node.kernel().is_running());
+    }
+
+    #[rstest]
+    fn test_backtest_node_denies_orders_when_halted() {
+        let log = EventLog::default();
+        let mut node = backtest_node(
+            NodeConfig::new(Environment::Backtest, TraderId::default()),
+            &log,
+        );
+        node.kernel_mut()
+            .risk_engine
+            .set_trading_state(TradingState::Halted);
+
+        node.run(vec![quote(1)]).unwrap();
+
+        assert_eq!(*log.borrow(), vec!["Data", "Denied"]);
+    }
+
+    #[rstest]
+    fn test_node_routes_warmup_response_to_strategy() {
+        let log = EventLog::default();
+        let request_id = Rc::new(RefCell::new(None));
+        let mut node =
+            NodeBuilder::new(NodeConfig::new(Environment::Backtest, TraderId::default()))
+                .with_data_client(Box::new(StubDataClient))
+                .with_strategy(Box::new(WarmupStrategy {
+                    log: log.clone(),
+                    request_id: request_id.clone(),
+                }))
+                .build_backtest()
+                .unwrap();
+        node.kernel_mut().start().unwrap();
+
+        let correlation_id = request_id.borrow().unwrap();
+        node.kernel_mut()
+            .handle_data_message(DataEngineMessage::Response(DataResponse {
+                correlation_id,
+                client_id: ClientId::from("BINANCE"),
+                data: Vec::new(),
+                ts_init: UnixNanos::default(),
+            }));
+
+        assert!(!node
+            .kernel()
+            .data_engine
+            .is_pending_request(&correlation_id));
+        assert_eq!(*log.borrow(), vec![format!("Response({correlation_id})")]);
+    }
+
+    #[rstest]
+    fn test_backtest_node_accrues_margin_interest() {
+        let one_day_ns = NANOSECONDS_IN_YEAR / 365;
+        let mut account = MarginAccount::new(margin_account_state(), true).unwrap();
+        account
+            .update_borrowed(Money::from("100000 USD"), UnixNanos::default())
+            .unwrap();
+        let mut node =
+            NodeBuilder::new(NodeConfig::new(Environment::Backtest, TraderId::default()))
+                .build_backtest()
+                .unwrap();
+        node.kernel_mut()
+            .exec_engine
+            .cache_mut()
+            .add_account(Box::new(account))
+            .unwrap();
+        let borrow_rate = BorrowRateUpdate::new(
+            Venue::from("SIM"),
+            Currency::USD(),
+            0.0365,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+
+        node.run(vec![Data::BorrowRate(borrow_rate), quote(one_day_ns)])
+            .unwrap();
+
+        let account = node.kernel().cache().account(&account_id()).unwrap();
+        assert_eq!(account.event_count(), 2);
+        assert_eq!(
+            account.balance_total(None),
+            Some(Money::from("1524990 USD"))
+        );
+    }
+
+    #[rstest]
+    #[case(Environment::Backtest, true)]
+    #[case(Environment::Live, false)]
+    fn test_build_live_for_environment(#[case] environment: Environment, #[case] is_err: bool) {
+        let config = NodeConfig::new(environment, TraderId::default());
+
+        let result = NodeBuilder::new(config).build_live();
+
+        assert_eq!(result.is_err(), is_err);
+    }
+}
@@ -0,0 +1,83 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Configuration for assembling a trading node.
+
+use std::collections::HashMap;
+
+use nautilus_common::cache::core::CacheConfig;
+use nautilus_core::uuid::UUID4;
+use nautilus_execution::live::execution_engine::LiveExecutionEngineConfig;
+use nautilus_model::identifiers::trader_id::TraderId;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+use crate::risk::RiskEngineConfig;
+
+/// The environment context for a trading node.
+#[derive(Copy, Clone, Debug, Display, Hash, PartialEq, Eq, EnumString, Serialize, Deserialize)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum Environment {
+    /// Historical data replayed against simulated execution.
+    Backtest,
+    /// Live data with simulated execution.
+    Sandbox,
+    /// Live data with live execution.
+    Live,
+}
+
+/// Configuration for a trading node, as assembled by a `NodeBuilder`.
+pub struct NodeConfig {
+    /// The environment context for the node.
+    pub environment: Environment,
+    /// The trader ID for the node.
+    pub trader_id: TraderId,
+    /// The instance ID for the node, otherwise generated on build.
+    pub instance_id: Option<UUID4>,
+    /// The configuration for the cache.
+    pub cache: CacheConfig,
+    /// The config for the Redis cache database, as passed to `RedisCacheDatabase` (requires
+    /// the `redis` feature). If `None` then the cache is in-memory only.
+    pub cache_database: Option<HashMap<String, serde_json::Value>>,
+    /// The configuration for the message bus.
+    pub msgbus: Option<HashMap<String, serde_json::Value>>,
+    /// The configuration for the risk engine.
+    pub risk_engine: RiskEngineConfig,
+    /// The configuration for the execution engine.
+    pub exec_engine: LiveExecutionEngineConfig,
+    /// If the cached orders are reconciled with the execution clients on start (live only).
+    pub reconciliation: bool,
+}
+
+impl NodeConfig {
+    /// Creates a new [`NodeConfig`] instance for the given `environment`, with defaults for
+    /// all components.
+    #[must_use]
+    pub fn new(environment: Environment, trader_id: TraderId) -> Self {
+        Self {
+            environment,
+            trader_id,
+            instance_id: None,
+            cache: CacheConfig::default(),
+            cache_database: None,
+            msgbus: None,
+            risk_engine: RiskEngineConfig::default(),
+            exec_engine: LiveExecutionEngineConfig::default(),
+            reconciliation: true,
+        }
+    }
+}
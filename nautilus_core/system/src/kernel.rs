@@ -0,0 +1,533 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The `NautilusKernel` which holds the core components of a trading node, and dispatches
+//! data and order events to the strategies and execution algorithms.
+
+use indexmap::{IndexMap, IndexSet};
+use log::{debug, error, info, warn};
+use nautilus_common::{
+    cache::Cache,
+    clock::{LiveClock, TestClock},
+    handlers::MessageHandler,
+    interface::account::Account,
+    live::data_engine::{DataCommand, DataEngineMessage, DataEngineOutput, LiveDataEngine},
+    msgbus::{core::is_matching, switchboard::get_order_events_topic, MessageBus},
+};
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_execution::{
+    algorithm::{ExecAlgorithm, ExecAlgorithmEngine},
+    live::execution_engine::{ExecutionEngineMessage, LiveExecutionEngine},
+    messages::TradingCommand,
+};
+use nautilus_model::{
+    data::{borrow::BorrowRateUpdate, Data},
+    events::{
+        account::state::AccountState,
+        order::{denied::OrderDenied, OrderEventAny},
+    },
+    identifiers::{account_id::AccountId, strategy_id::StrategyId, trader_id::TraderId},
+    orders::any::OrderAny,
+};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use ustr::Ustr;
+
+use crate::{
+    config::Environment,
+    risk::RiskEngine,
+    strategy::{Strategy, StrategyCommand, StrategyContext},
+};
+
+/// The clock for a node, which is a live clock unless backtesting.
+pub enum NodeClock {
+    Live(LiveClock),
+    Test(TestClock),
+}
+
+impl NodeClock {
+    /// Returns the current UNIX timestamp (nanoseconds).
+    #[must_use]
+    pub fn timestamp_ns(&self) -> UnixNanos {
+        match self {
+            Self::Live(clock) => clock.get_time_ns(),
+            Self::Test(clock) => clock.get_time_ns(),
+        }
+    }
+
+    /// Sets the time of a test clock, which is ignored for a live clock.
+    pub fn set_time(&mut self, time: UnixNanos) {
+        if let Self::Test(clock) = self {
+            clock.set_time(time);
+        }
+    }
+}
+
+/// Provides the core components of a trading node, dispatching data and order events
+/// to the strategies and executing the commands they issue.
+///
+/// Strategies subscribe to data and order event topics on the message bus, through which
+/// the data and events published by the engines are dispatched to them.
+///
+/// Orders for an execution algorithm are passed to the algorithm, and trading commands pass
+/// the pre-trade checks of the risk engine before execution, with denied orders applied an
+/// `OrderDenied` event.
+pub struct NautilusKernel {
+    pub environment: Environment,
+    pub trader_id: TraderId,
+    pub instance_id: UUID4,
+    pub clock: NodeClock,
+    pub msgbus: MessageBus,
+    pub data_engine: LiveDataEngine<UnboundedSender<DataEngineOutput>>,
+    pub exec_engine: LiveExecutionEngine<UnboundedSender<(Ustr, OrderEventAny)>>,
+    pub risk_engine: RiskEngine,
+    pub exec_algorithms: ExecAlgorithmEngine,
+    reconciliation: bool,
+    is_running: bool,
+    strategies: IndexMap<StrategyId, Box<dyn Strategy>>,
+    data_rx: UnboundedReceiver<DataEngineOutput>,
+    event_rx: UnboundedReceiver<(Ustr, OrderEventAny)>,
+}
+
+impl NautilusKernel {
+    /// Creates a new [`NautilusKernel`] instance, with the engines publishing to the given
+    /// channels for dispatch.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        environment: Environment,
+        clock: NodeClock,
+        msgbus: MessageBus,
+        data_engine: LiveDataEngine<UnboundedSender<DataEngineOutput>>,
+        exec_engine: LiveExecutionEngine<UnboundedSender<(Ustr, OrderEventAny)>>,
+        risk_engine: RiskEngine,
+        reconciliation: bool,
+        data_rx: UnboundedReceiver<DataEngineOutput>,
+        event_rx: UnboundedReceiver<(Ustr, OrderEventAny)>,
+    ) -> Self {
+        let trader_id = msgbus.trader_id;
+        Self {
+            environment,
+            trader_id,
+            instance_id: msgbus.instance_id,
+            clock,
+            msgbus,
+            data_engine,
+            exec_engine,
+            risk_engine,
+            exec_algorithms: ExecAlgorithmEngine::new(trader_id),
+            reconciliation,
+            is_running: false,
+            strategies: IndexMap::new(),
+            data_rx,
+            event_rx,
+        }
+    }
+
+    /// Returns whether the kernel has been started (and not stopped).
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.is_running
+    }
+
+    /// Returns a reference to the cache.
+    #[must_use]
+    pub fn cache(&self) -> &Cache {
+        self.exec_engine.cache()
+    }
+
+    /// Returns the IDs of the added strategies.
+    #[must_use]
+    pub fn strategy_ids(&self) -> Vec<StrategyId> {
+        self.strategies.keys().copied().collect()
+    }
+
+    /// Adds the given `strategy`, which is started with the kernel.
+    pub fn add_strategy(&mut self, strategy: Box<dyn Strategy>) -> anyhow::Result<()> {
+        let strategy_id = strategy.strategy_id();
+        if self.strategies.contains_key(&strategy_id) {
+            anyhow::bail!("Strategy {strategy_id} already added");
+        }
+        self.strategies.insert(strategy_id, strategy);
+        self.msgbus.subscribe(
+            &get_order_events_topic(&strategy_id),
+            strategy_handler(strategy_id),
+            None,
+        );
+        debug!("Added strategy {strategy_id}");
+        Ok(())
+    }
+
+    /// Adds the given execution `algorithm`, for orders with its `exec_algorithm_id`.
+    pub fn add_exec_algorithm(&mut self, algorithm: Box<dyn ExecAlgorithm>) -> anyhow::Result<()> {
+        self.exec_algorithms.register(algorithm)
+    }
+
+    /// Starts the kernel, restoring the recent command window and reconciling execution
+    /// state (if live) before starting the strategies.
+    pub fn start(&mut self) -> anyhow::Result<()> {
+        if self.is_running {
+            anyhow::bail!("Kernel already running");
+        }
+
+        self.exec_engine.load_recent_commands()?;
+
+        if self.reconciliation && self.environment != Environment::Backtest {
+            self.exec_engine.reconcile(self.clock.timestamp_ns())?;
+            self.drain();
+        }
+
+        for strategy_id in self.strategy_ids() {
+            let commands = self.call_strategy(strategy_id, |strategy, ctx| strategy.on_start(ctx));
+            self.execute_strategy_commands(strategy_id, commands);
+            self.drain();
+        }
+
+        self.is_running = true;
+        info!("Started {} kernel for {}", self.environment, self.trader_id);
+        Ok(())
+    }
+
+    /// Stops the strategies, then accrues interest on borrowed balances and persists the
+    /// recent command window.
+    pub fn stop(&mut self) -> anyhow::Result<()> {
+        if !self.is_running {
+            anyhow::bail!("Kernel not running");
+        }
+
+        for strategy_id in self.strategy_ids() {
+            let commands = self.call_strategy(strategy_id, |strategy, ctx| strategy.on_stop(ctx));
+            self.execute_strategy_commands(strategy_id, commands);
+            self.drain();
+        }
+        self.accrue_interest();
+        self.exec_engine.save_recent_commands()?;
+
+        self.is_running = false;
+        info!("Stopped {} kernel for {}", self.environment, self.trader_id);
+        Ok(())
+    }
+
+    /// Processes the `data` through the data engine, dispatching it to subscribed strategies.
+    pub fn process_data(&mut self, data: Data) {
+        self.data_engine.process(data);
+        self.drain();
+    }
+
+    /// Handles the message for the data engine, e.g. from a data client.
+    pub fn handle_data_message(&mut self, msg: DataEngineMessage) {
+        self.data_engine.handle(msg);
+        self.drain();
+    }
+
+    /// Handles the message for the execution engine, e.g. from an execution client.
+    ///
+    /// Trading commands are checked by the risk engine before execution.
+    pub fn handle_execution_message(&mut self, msg: ExecutionEngineMessage) {
+        let result = match msg {
+            ExecutionEngineMessage::Command(command) => self.execute(command),
+            ExecutionEngineMessage::Event(event) => self.exec_engine.process(event),
+        };
+        if let Err(e) = result {
+            error!("Error handling execution message: {e}");
+        }
+        self.drain();
+    }
+
+    /// Accrues interest on the borrowed balances of the cached accounts up to the current time,
+    /// applying the resulting account states.
+    pub fn accrue_interest(&mut self) {
+        let ts_now = self.clock.timestamp_ns();
+        for account_id in self.exec_engine.cache().account_ids() {
+            let result =
+                self.update_account(account_id, |account| account.accrue_borrow_interest(ts_now));
+            if let Err(e) = result {
+                error!("Error accruing interest for {account_id}: {e}");
+            }
+        }
+    }
+
+    /// Checks for timed out in-flight commands, and expired orders with an emulated TIF,
+    /// with the execution engine.
+    pub fn check_in_flight(&mut self) {
+        let ts_now = self.clock.timestamp_ns();
+        self.exec_engine.check_in_flight(ts_now);
+        self.exec_engine.check_expired_tifs(ts_now);
+    }
+
+    /// Fires the execution algorithm timers due at the current time.
+    pub fn process_timers(&mut self) {
+        let ts_now = self.clock.timestamp_ns();
+        let commands = self
+            .exec_algorithms
+            .advance_time(self.exec_engine.cache_mut(), ts_now);
+        self.execute_algorithm_commands(commands);
+        self.drain();
+    }
+
+    /// Executes the trading `command`, passing orders for an execution algorithm to the
+    /// algorithm.
+    pub fn execute(&mut self, command: TradingCommand) -> anyhow::Result<()> {
+        match command {
+            TradingCommand::SubmitOrder(submit) if self.exec_algorithms.handles(&submit.order) => {
+                let ts_now = self.clock.timestamp_ns();
+                let commands =
+                    self.exec_algorithms
+                        .execute(self.exec_engine.cache_mut(), submit, ts_now)?;
+                self.execute_algorithm_commands(commands);
+                Ok(())
+            }
+            command => self.execute_checked(command),
+        }
+    }
+
+    /// Executes the trading `command` if it passes the pre-trade checks, otherwise denies it.
+    fn execute_checked(&mut self, command: TradingCommand) -> anyhow::Result<()> {
+        let ts_now = self.clock.timestamp_ns();
+        match self
+            .risk_engine
+            .check(self.exec_engine.cache(), &command, ts_now)
+        {
+            None => self.exec_engine.execute(command),
+            Some(reason) => self.deny(command, &reason),
+        }
+    }
+
+    fn execute_algorithm_commands(&mut self, commands: Vec<TradingCommand>) {
+        for command in commands {
+            if let Err(e) = self.execute_checked(command) {
+                error!("Error executing command from execution algorithm: {e}");
+            }
+        }
+    }
+
+    fn deny(&mut self, command: TradingCommand, reason: &str) -> anyhow::Result<()> {
+        let orders = match command {
+            TradingCommand::SubmitOrder(submit) => vec![submit.order],
+            TradingCommand::SubmitOrderList(submit) => submit.order_list.orders,
+            command => {
+                warn!("Denied {command}: {reason}");
+                return Ok(());
+            }
+        };
+
+        for order in orders {
+            self.deny_order(order, reason)?;
+        }
+        Ok(())
+    }
+
+    fn deny_order(&mut self, order: OrderAny, reason: &str) -> anyhow::Result<()> {
+        let client_order_id = order.client_order_id();
+        warn!("Denied order {client_order_id}: {reason}");
+
+        let ts_now = self.clock.timestamp_ns();
+        let event = OrderDenied::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        )?;
+
+        let cache = self.exec_engine.cache_mut();
+        if cache.order(&client_order_id).is_none() {
+            cache.add_order(order, None, None, false)?;
+        }
+        self.exec_engine.process(OrderEventAny::Denied(event))
+    }
+
+    fn call_strategy(
+        &mut self,
+        strategy_id: StrategyId,
+        handler: impl FnOnce(&mut dyn Strategy, &mut StrategyContext) -> anyhow::Result<()>,
+    ) -> Vec<StrategyCommand> {
+        let ts_now = self.clock.timestamp_ns();
+        let Some(strategy) = self.strategies.get_mut(&strategy_id) else {
+            warn!("No strategy {strategy_id} to dispatch to");
+            return Vec::new();
+        };
+
+        let mut ctx = StrategyContext::new(
+            self.trader_id,
+            strategy_id,
+            ts_now,
+            self.exec_engine.cache(),
+        );
+        if let Err(e) = handler(strategy.as_mut(), &mut ctx) {
+            error!("Error in strategy {strategy_id}: {e}");
+        }
+        ctx.into_commands()
+    }
+
+    fn execute_strategy_commands(
+        &mut self,
+        strategy_id: StrategyId,
+        commands: Vec<StrategyCommand>,
+    ) {
+        for command in commands {
+            let result = match command {
+                StrategyCommand::Data(command) => {
+                    let handler = strategy_handler(strategy_id);
+                    match &command {
+                        DataCommand::Subscribe { subscription, .. } => {
+                            self.msgbus.subscribe(&subscription.topic(), handler, None);
+                        }
+                        DataCommand::Unsubscribe { subscription, .. } => {
+                            self.msgbus.unsubscribe(&subscription.topic(), handler);
+                        }
+                    }
+                    self.data_engine.execute(command)
+                }
+                StrategyCommand::Request(request) => self.data_engine.request(request),
+                StrategyCommand::Trading(command) => self.execute(command),
+            };
+
+            if let Err(e) = result {
+                error!("Error executing command from {strategy_id}: {e}");
+            }
+        }
+    }
+
+    /// Dispatches the data and order events published by the engines, until none remain.
+    fn drain(&mut self) {
+        loop {
+            if let Ok(output) = self.data_rx.try_recv() {
+                self.dispatch_data(output);
+            } else if let Ok((topic, event)) = self.event_rx.try_recv() {
+                self.dispatch_event(topic, &event);
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn dispatch_data(&mut self, output: DataEngineOutput) {
+        match output {
+            DataEngineOutput::Data { topic, data } => {
+                if let Data::BorrowRate(borrow_rate) = &data {
+                    self.update_borrow_rate(*borrow_rate);
+                }
+                for strategy_id in self.publish(topic) {
+                    let commands = self
+                        .call_strategy(strategy_id, |strategy, ctx| strategy.on_data(ctx, &data));
+                    self.execute_strategy_commands(strategy_id, commands);
+                }
+            }
+            DataEngineOutput::Response {
+                requester,
+                response,
+            } => {
+                let Some(strategy_id) = self
+                    .strategies
+                    .keys()
+                    .find(|strategy_id| strategy_id.inner() == requester)
+                    .copied()
+                else {
+                    debug!("Dropping data response for {requester}");
+                    return;
+                };
+                let commands = self.call_strategy(strategy_id, |strategy, ctx| {
+                    strategy.on_response(ctx, &response)
+                });
+                self.execute_strategy_commands(strategy_id, commands);
+            }
+        }
+    }
+
+    /// Updates the borrow rate for the accounts at its venue, accruing interest at the
+    /// previous rate.
+    fn update_borrow_rate(&mut self, borrow_rate: BorrowRateUpdate) {
+        let account_ids: Vec<AccountId> = self
+            .exec_engine
+            .cache()
+            .account_ids()
+            .into_iter()
+            .filter(|account_id| account_id.get_issuer() == borrow_rate.venue)
+            .collect();
+
+        for account_id in account_ids {
+            let result =
+                self.update_account(account_id, |account| account.apply_borrow_rate(borrow_rate));
+            if let Err(e) = result {
+                error!("Error updating borrow rate for {account_id}: {e}");
+            }
+        }
+    }
+
+    /// Updates the cached account with the given function, applying any resulting account
+    /// state to the account.
+    fn update_account(
+        &mut self,
+        account_id: AccountId,
+        update: impl FnOnce(&mut dyn Account) -> anyhow::Result<Option<AccountState>>,
+    ) -> anyhow::Result<()> {
+        let cache = self.exec_engine.cache_mut();
+        let Some(account) = cache.account_mut(&account_id) else {
+            return Ok(());
+        };
+
+        if let Some(state) = update(account)? {
+            debug!("Updated account state {state}");
+            cache.apply_account_state(state)?;
+        }
+        Ok(())
+    }
+
+    fn dispatch_event(&mut self, topic: Ustr, event: &OrderEventAny) {
+        self.risk_engine.on_order_event(event);
+
+        for strategy_id in self.publish(topic) {
+            let commands = self.call_strategy(strategy_id, |strategy, ctx| {
+                strategy.on_order_event(ctx, event)
+            });
+            self.execute_strategy_commands(strategy_id, commands);
+        }
+
+        let ts_now = self.clock.timestamp_ns();
+        let commands = self
+            .exec_algorithms
+            .process(self.exec_engine.cache_mut(), event, ts_now);
+        self.execute_algorithm_commands(commands);
+    }
+
+    /// Publishes on the `topic` through the message bus, returning the strategies subscribed
+    /// to it in subscription priority order.
+    fn publish(&mut self, topic: Ustr) -> Vec<StrategyId> {
+        self.msgbus.pub_count += 1;
+
+        let mut subscriptions = self.msgbus.subscriptions();
+        subscriptions.retain(|sub| is_matching(&topic, &sub.topic));
+        subscriptions.sort();
+
+        let mut subscribers = IndexSet::new();
+        for sub in subscriptions {
+            if let Some(strategy_id) = self
+                .strategies
+                .keys()
+                .find(|strategy_id| strategy_id.inner() == sub.handler.handler_id)
+            {
+                subscribers.insert(*strategy_id);
+            }
+        }
+        subscribers.into_iter().collect()
+    }
+}
+
+/// Returns the message bus handler for the given strategy, identified by its strategy ID.
+fn strategy_handler(strategy_id: StrategyId) -> MessageHandler {
+    MessageHandler::new(strategy_id.inner(), None)
+}
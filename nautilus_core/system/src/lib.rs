@@ -0,0 +1,35 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! [NautilusTrader](http://nautilustrader.io) is an open-source, high-performance, production-grade
+//! algorithmic trading platform, providing quantitative traders with the ability to backtest
+//! portfolios of automated trading strategies on historical data with an event-driven engine,
+//! and also deploy those same strategies live, with no code changes.
+//!
+//! The `system` crate assembles the Rust components into a runnable trading node, with a
+//! `NodeBuilder` wiring the clock, cache, message bus, engines, clients and strategies from
+//! a single `NodeConfig`.
+//!
+//! # Feature flags
+//!
+//! This crate provides feature flags to control source code inclusion during compilation:
+//!
+//! - `redis`: Enables the Redis cache database for the node
+
+pub mod builder;
+pub mod config;
+pub mod kernel;
+pub mod risk;
+pub mod strategy;
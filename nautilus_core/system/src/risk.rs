@@ -0,0 +1,465 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a `RiskEngine` performing pre-trade checks on trading commands before they are
+//! routed to the execution engine.
+
+use std::collections::HashMap;
+
+use nautilus_common::cache::Cache;
+use nautilus_core::nanos::UnixNanos;
+use nautilus_execution::{
+    compliance::{ComplianceAction, ComplianceConfig, VenueComplianceTracker},
+    messages::TradingCommand,
+};
+use nautilus_model::{
+    enums::{OrderSide, PriceType, TradingState},
+    events::order::OrderEventAny,
+    identifiers::instrument_id::InstrumentId,
+    orders::any::OrderAny,
+    types::price::Price,
+};
+
+/// Configuration for [`RiskEngine`] instances.
+#[derive(Clone, Debug, Default)]
+pub struct RiskEngineConfig {
+    /// If all pre-trade checks are bypassed.
+    pub bypass: bool,
+    /// The maximum notional value (in quote currency) per order, by instrument.
+    pub max_notional_per_order: HashMap<InstrumentId, f64>,
+    /// The optional order-to-trade ratio compliance thresholds, applied to all venues.
+    pub compliance: Option<ComplianceConfig>,
+}
+
+/// Provides pre-trade checks on trading commands, according to the trading state and
+/// configured limits.
+///
+/// Commands which fail a check are denied, and should not be routed for execution.
+#[derive(Debug)]
+pub struct RiskEngine {
+    config: RiskEngineConfig,
+    trading_state: TradingState,
+    compliance: Option<VenueComplianceTracker>,
+}
+
+impl RiskEngine {
+    /// Creates a new [`RiskEngine`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the compliance config is invalid.
+    pub fn new(config: RiskEngineConfig) -> anyhow::Result<Self> {
+        let compliance = config
+            .compliance
+            .clone()
+            .map(VenueComplianceTracker::new)
+            .transpose()?;
+        Ok(Self {
+            config,
+            trading_state: TradingState::Active,
+            compliance,
+        })
+    }
+
+    /// Returns the current trading state.
+    #[must_use]
+    pub fn trading_state(&self) -> TradingState {
+        self.trading_state
+    }
+
+    /// Sets the trading state, which applies to all subsequent commands.
+    pub fn set_trading_state(&mut self, trading_state: TradingState) {
+        self.trading_state = trading_state;
+    }
+
+    /// Records the fills of the given order `event` for venue compliance.
+    pub fn on_order_event(&mut self, event: &OrderEventAny) {
+        if let (Some(tracker), OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill)) =
+            (self.compliance.as_mut(), event)
+        {
+            tracker.record(
+                fill.instrument_id.venue,
+                ComplianceAction::Fill,
+                fill.ts_event,
+            );
+        }
+    }
+
+    /// Checks the `command` at `ts_now`, returning the reason for denial if it fails a check.
+    ///
+    /// Permitted commands are recorded for venue compliance, with submits and modifies denied
+    /// once the venues order-to-trade ratio is blocked. Cancel and query commands are always
+    /// permitted.
+    #[must_use]
+    pub fn check(
+        &mut self,
+        cache: &Cache,
+        command: &TradingCommand,
+        ts_now: UnixNanos,
+    ) -> Option<String> {
+        if self.config.bypass {
+            return None;
+        }
+
+        let reason = self.check_command(cache, command);
+        if reason.is_some() {
+            return reason;
+        }
+        self.check_compliance(command, ts_now)
+    }
+
+    fn check_command(&self, cache: &Cache, command: &TradingCommand) -> Option<String> {
+        match command {
+            TradingCommand::SubmitOrder(submit) => self.check_order(cache, &submit.order),
+            TradingCommand::SubmitOrderList(submit) => submit
+                .order_list
+                .orders
+                .iter()
+                .find_map(|order| self.check_order(cache, order)),
+            TradingCommand::ModifyOrder(modify) => {
+                if self.trading_state == TradingState::Halted {
+                    return Some("TradingState::HALTED".to_string());
+                }
+                let Some(order) = cache.order(&modify.client_order_id) else {
+                    return Some(format!("ORDER_NOT_FOUND: {}", modify.client_order_id));
+                };
+                let quantity = modify.quantity.unwrap_or_else(|| order.quantity());
+                let price = modify
+                    .price
+                    .or(modify.trigger_price)
+                    .or_else(|| order_price(cache, order));
+                self.check_order_notional(&modify.instrument_id, quantity.as_f64(), price)
+            }
+            TradingCommand::CancelOrder(_)
+            | TradingCommand::CancelAllOrders(_)
+            | TradingCommand::BatchCancelOrders(_)
+            | TradingCommand::QueryOrder(_) => None,
+        }
+    }
+
+    fn check_compliance(&mut self, command: &TradingCommand, ts_now: UnixNanos) -> Option<String> {
+        let tracker = self.compliance.as_mut()?;
+        let (action, count) = match command {
+            TradingCommand::SubmitOrder(_) => (ComplianceAction::Submit, 1),
+            TradingCommand::SubmitOrderList(submit) => {
+                (ComplianceAction::Submit, submit.order_list.orders.len())
+            }
+            TradingCommand::ModifyOrder(_) => (ComplianceAction::Modify, 1),
+            TradingCommand::CancelOrder(_) | TradingCommand::CancelAllOrders(_) => {
+                (ComplianceAction::Cancel, 1)
+            }
+            TradingCommand::BatchCancelOrders(cancel) => {
+                (ComplianceAction::Cancel, cancel.cancels.len())
+            }
+            TradingCommand::QueryOrder(_) => return None,
+        };
+
+        let venue = command.instrument_id().venue;
+        if action != ComplianceAction::Cancel && tracker.would_block(venue, action, ts_now) {
+            return Some(format!("ORDER_TO_TRADE_RATIO_EXCEEDED: venue={venue}"));
+        }
+
+        for _ in 0..count {
+            tracker.record(venue, action, ts_now);
+        }
+        None
+    }
+
+    fn check_order(&self, cache: &Cache, order: &OrderAny) -> Option<String> {
+        match self.trading_state {
+            TradingState::Halted => return Some("TradingState::HALTED".to_string()),
+            TradingState::Reducing if !order.is_reduce_only() => {
+                return Some("TradingState::REDUCING and order not reduce-only".to_string())
+            }
+            _ => {}
+        }
+
+        self.check_order_notional(
+            &order.instrument_id(),
+            order.quantity().as_f64(),
+            order_price(cache, order),
+        )
+    }
+
+    fn check_order_notional(
+        &self,
+        instrument_id: &InstrumentId,
+        quantity: f64,
+        price: Option<Price>,
+    ) -> Option<String> {
+        if !self
+            .config
+            .max_notional_per_order
+            .contains_key(instrument_id)
+        {
+            return None;
+        }
+        match price {
+            Some(price) => self.check_notional(instrument_id, quantity * price.as_f64()),
+            None => Some(format!("NO_PRICE_FOR_NOTIONAL_CHECK: {instrument_id}")),
+        }
+    }
+
+    fn check_notional(&self, instrument_id: &InstrumentId, notional: f64) -> Option<String> {
+        let max_notional = self.config.max_notional_per_order.get(instrument_id)?;
+        if notional > *max_notional {
+            return Some(format!(
+                "NOTIONAL_EXCEEDS_MAX_PER_ORDER: max_notional={max_notional}, notional={notional}"
+            ));
+        }
+        None
+    }
+}
+
+/// Returns the price to value the given `order` at, which for orders without a price is the
+/// latest quote on the side the order would fill against (or the latest trade).
+fn order_price(cache: &Cache, order: &OrderAny) -> Option<Price> {
+    let instrument_id = order.instrument_id();
+    let price_type = match order.order_side() {
+        OrderSide::Buy => PriceType::Ask,
+        _ => PriceType::Bid,
+    };
+    order
+        .price()
+        .or_else(|| order.trigger_price())
+        .or_else(|| cache.price(&instrument_id, price_type))
+        .or_else(|| cache.price(&instrument_id, PriceType::Last))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+    use nautilus_execution::messages::{modify::ModifyOrder, submit::SubmitOrder};
+    use nautilus_model::{
+        data::quote::QuoteTick,
+        identifiers::{
+            client_id::ClientId, client_order_id::ClientOrderId, strategy_id::StrategyId,
+            trader_id::TraderId, venue_order_id::VenueOrderId,
+        },
+        orders::stubs::TestOrderStubs,
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn submit(order: OrderAny) -> TradingCommand {
+        TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                order.trader_id(),
+                ClientId::from("SIM"),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                VenueOrderId::default(),
+                order,
+                None,
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn limit_order(quantity: i64) -> OrderAny {
+        TestOrderStubs::limit_order(
+            InstrumentId::from("AUD/USD.SIM"),
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(quantity),
+            None,
+            None,
+        )
+    }
+
+    #[rstest]
+    #[case(TradingState::Active, None)]
+    #[case(TradingState::Halted, Some("TradingState::HALTED"))]
+    #[case(
+        TradingState::Reducing,
+        Some("TradingState::REDUCING and order not reduce-only")
+    )]
+    fn test_check_submit_for_trading_state(
+        #[case] trading_state: TradingState,
+        #[case] expected: Option<&str>,
+    ) {
+        let mut risk_engine = RiskEngine::new(RiskEngineConfig::default()).unwrap();
+        risk_engine.set_trading_state(trading_state);
+
+        let result = risk_engine.check(
+            &Cache::default(),
+            &submit(limit_order(100_000)),
+            UnixNanos::default(),
+        );
+
+        assert_eq!(result.as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case(100_000, false)]
+    #[case(200_000, true)]
+    fn test_check_submit_max_notional(#[case] quantity: i64, #[case] expected_denied: bool) {
+        let config = RiskEngineConfig {
+            bypass: false,
+            max_notional_per_order: HashMap::from([(InstrumentId::from("AUD/USD.SIM"), 150_000.0)]),
+            ..Default::default()
+        };
+        let mut risk_engine = RiskEngine::new(config).unwrap();
+
+        let result = risk_engine.check(
+            &Cache::default(),
+            &submit(limit_order(quantity)),
+            UnixNanos::default(),
+        );
+
+        assert_eq!(result.is_some(), expected_denied);
+    }
+
+    #[rstest]
+    fn test_check_bypassed() {
+        let mut risk_engine = RiskEngine::new(RiskEngineConfig {
+            bypass: true,
+            ..Default::default()
+        })
+        .unwrap();
+        risk_engine.set_trading_state(TradingState::Halted);
+
+        let result = risk_engine.check(
+            &Cache::default(),
+            &submit(limit_order(100_000)),
+            UnixNanos::default(),
+        );
+
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    fn test_check_submit_denied_when_order_to_trade_ratio_blocked() {
+        let config = RiskEngineConfig {
+            compliance: Some(ComplianceConfig {
+                window_ns: 1_000,
+                warn_ratio: 1.0,
+                block_ratio: 2.0,
+                min_order_messages: 1,
+                max_order_messages: None,
+            }),
+            ..Default::default()
+        };
+        let mut risk_engine = RiskEngine::new(config).unwrap();
+        let cache = Cache::default();
+
+        let result1 = risk_engine.check(&cache, &submit(limit_order(100)), 1.into());
+        let result2 = risk_engine.check(&cache, &submit(limit_order(100)), 2.into());
+
+        assert!(result1.is_none());
+        assert_eq!(
+            result2.as_deref(),
+            Some("ORDER_TO_TRADE_RATIO_EXCEEDED: venue=SIM")
+        );
+    }
+
+    #[rstest]
+    #[case(100_000, false)]
+    #[case(200_000, true)]
+    fn test_check_submit_market_order_max_notional_from_quote(
+        #[case] quantity: i64,
+        #[case] expected_denied: bool,
+    ) {
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        let config = RiskEngineConfig {
+            max_notional_per_order: HashMap::from([(instrument_id, 150_000.0)]),
+            ..Default::default()
+        };
+        let mut risk_engine = RiskEngine::new(config).unwrap();
+        let mut cache = Cache::default();
+        let quote = QuoteTick::new(
+            instrument_id,
+            Price::from("0.99990"),
+            Price::from("1.00000"),
+            Quantity::from(1_000_000),
+            Quantity::from(1_000_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        cache.add_quote(quote).unwrap();
+        let order = TestOrderStubs::market_order(
+            instrument_id,
+            OrderSide::Buy,
+            Quantity::from(quantity),
+            None,
+            None,
+        );
+
+        let result = risk_engine.check(&cache, &submit(order), UnixNanos::default());
+
+        assert_eq!(result.is_some(), expected_denied);
+    }
+
+    #[rstest]
+    fn test_check_market_order_denied_without_price_when_max_notional() {
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        let config = RiskEngineConfig {
+            max_notional_per_order: HashMap::from([(instrument_id, 150_000.0)]),
+            ..Default::default()
+        };
+        let mut risk_engine = RiskEngine::new(config).unwrap();
+        let order = TestOrderStubs::market_order(
+            instrument_id,
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            None,
+            None,
+        );
+
+        let result = risk_engine.check(&Cache::default(), &submit(order), UnixNanos::default());
+
+        assert_eq!(
+            result.as_deref(),
+            Some("NO_PRICE_FOR_NOTIONAL_CHECK: AUD/USD.SIM")
+        );
+    }
+
+    #[rstest]
+    fn test_check_modify_denied_when_order_not_found() {
+        let mut risk_engine = RiskEngine::new(RiskEngineConfig::default()).unwrap();
+        let command = ModifyOrder::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            StrategyId::default(),
+            InstrumentId::from("AUD/USD.SIM"),
+            ClientOrderId::from("O-123456789"),
+            VenueOrderId::default(),
+            Some(Quantity::from(100)),
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        let result = risk_engine.check(
+            &Cache::default(),
+            &TradingCommand::ModifyOrder(command),
+            UnixNanos::default(),
+        );
+
+        assert_eq!(result.as_deref(), Some("ORDER_NOT_FOUND: O-123456789"));
+    }
+}
@@ -0,0 +1,382 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The `Strategy` trait for trading strategies run by a node, and the `StrategyContext`
+//! through which they issue commands.
+
+use nautilus_common::{
+    cache::Cache,
+    live::data_engine::{DataCommand, DataRequest, DataResponse, DataSubscription},
+};
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_execution::messages::{cancel::CancelOrder, submit::SubmitOrder, TradingCommand};
+use nautilus_indicators::warmup::WarmupRequirement;
+use nautilus_model::{
+    data::Data,
+    events::order::OrderEventAny,
+    identifiers::{
+        client_id::ClientId, client_order_id::ClientOrderId, strategy_id::StrategyId,
+        trader_id::TraderId,
+    },
+    orders::any::OrderAny,
+};
+use ustr::Ustr;
+
+/// Represents a command issued by a strategy, to be executed by the node.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum StrategyCommand {
+    Data(DataCommand),
+    Request(DataRequest),
+    Trading(TradingCommand),
+}
+
+/// Provides a strategy with access to the node state, and collects the commands it issues
+/// while handling a callback.
+pub struct StrategyContext<'a> {
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    ts_now: UnixNanos,
+    cache: &'a Cache,
+    commands: Vec<StrategyCommand>,
+}
+
+impl<'a> StrategyContext<'a> {
+    /// Creates a new [`StrategyContext`] instance.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        ts_now: UnixNanos,
+        cache: &'a Cache,
+    ) -> Self {
+        Self {
+            trader_id,
+            strategy_id,
+            ts_now,
+            cache,
+            commands: Vec::new(),
+        }
+    }
+
+    /// Returns the trader ID for the node.
+    #[must_use]
+    pub fn trader_id(&self) -> TraderId {
+        self.trader_id
+    }
+
+    /// Returns the current UNIX timestamp (nanoseconds) of the node clock.
+    #[must_use]
+    pub fn ts_now(&self) -> UnixNanos {
+        self.ts_now
+    }
+
+    /// Returns a reference to the node cache.
+    #[must_use]
+    pub fn cache(&self) -> &Cache {
+        self.cache
+    }
+
+    /// Returns the commands issued through the context.
+    #[must_use]
+    pub fn into_commands(self) -> Vec<StrategyCommand> {
+        self.commands
+    }
+
+    /// Subscribes the strategy to the given stream of data.
+    pub fn subscribe(&mut self, subscription: DataSubscription) {
+        self.commands
+            .push(StrategyCommand::Data(DataCommand::Subscribe {
+                subscriber: Ustr::from(self.strategy_id.as_str()),
+                subscription,
+                client_id: None,
+            }));
+    }
+
+    /// Unsubscribes the strategy from the given stream of data.
+    pub fn unsubscribe(&mut self, subscription: DataSubscription) {
+        self.commands
+            .push(StrategyCommand::Data(DataCommand::Unsubscribe {
+                subscriber: Ustr::from(self.strategy_id.as_str()),
+                subscription,
+                client_id: None,
+            }));
+    }
+
+    /// Requests historical data for the given `subscription`, returning the request ID.
+    ///
+    /// The response is passed to [`Strategy::on_response`].
+    pub fn request(
+        &mut self,
+        subscription: DataSubscription,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        limit: Option<usize>,
+    ) -> UUID4 {
+        let request_id = UUID4::new();
+        self.commands.push(StrategyCommand::Request(DataRequest {
+            request_id,
+            requester: Ustr::from(self.strategy_id.as_str()),
+            subscription,
+            client_id: None,
+            start,
+            end,
+            limit,
+            ts_init: self.ts_now,
+        }));
+        request_id
+    }
+
+    /// Requests the history window for the given warm-up `requirement`, returning the
+    /// request ID.
+    ///
+    /// Time aggregated bars are requested from the start of the window, otherwise by count.
+    pub fn request_warmup(&mut self, requirement: &WarmupRequirement) -> UUID4 {
+        self.request(
+            DataSubscription::Bars(requirement.bar_type),
+            requirement.start(self.ts_now),
+            Some(self.ts_now),
+            Some(requirement.count),
+        )
+    }
+
+    /// Submits the `order` for execution, routed to the given client or otherwise by venue.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order belongs to a different strategy.
+    pub fn submit_order(
+        &mut self,
+        order: OrderAny,
+        client_id: Option<ClientId>,
+    ) -> anyhow::Result<()> {
+        if order.strategy_id() != self.strategy_id {
+            anyhow::bail!(
+                "Order {} belongs to strategy {}, not {}",
+                order.client_order_id(),
+                order.strategy_id(),
+                self.strategy_id
+            );
+        }
+
+        let instrument_id = order.instrument_id();
+        let command = SubmitOrder::new(
+            self.trader_id,
+            client_id.unwrap_or_else(|| ClientId::from(instrument_id.venue.as_str())),
+            self.strategy_id,
+            instrument_id,
+            order.client_order_id(),
+            order.venue_order_id().unwrap_or_default(),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            self.ts_now,
+        )?;
+        self.commands
+            .push(StrategyCommand::Trading(TradingCommand::SubmitOrder(
+                command,
+            )));
+        Ok(())
+    }
+
+    /// Cancels the cached order with the given `client_order_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the order is not found in the cache, or has not
+    /// been accepted by the venue.
+    pub fn cancel_order(
+        &mut self,
+        client_order_id: &ClientOrderId,
+        client_id: Option<ClientId>,
+    ) -> anyhow::Result<()> {
+        let order = self
+            .cache
+            .order(client_order_id)
+            .ok_or_else(|| anyhow::anyhow!("Order {client_order_id} not found in cache"))?;
+        let venue_order_id = order
+            .venue_order_id()
+            .ok_or_else(|| anyhow::anyhow!("Order {client_order_id} has no venue order ID"))?;
+
+        let instrument_id = order.instrument_id();
+        let command = CancelOrder::new(
+            self.trader_id,
+            client_id.unwrap_or_else(|| ClientId::from(instrument_id.venue.as_str())),
+            self.strategy_id,
+            instrument_id,
+            *client_order_id,
+            venue_order_id,
+            UUID4::new(),
+            self.ts_now,
+        )?;
+        self.commands
+            .push(StrategyCommand::Trading(TradingCommand::CancelOrder(
+                command,
+            )));
+        Ok(())
+    }
+}
+
+/// A trading strategy run by a node.
+///
+/// Each handler receives a [`StrategyContext`] through which it can issue commands, which
+/// are executed by the node after the handler returns.
+pub trait Strategy {
+    /// Returns the identifier for the strategy.
+    fn strategy_id(&self) -> StrategyId;
+
+    /// Called when the node starts.
+    fn on_start(&mut self, _ctx: &mut StrategyContext) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Called when the node stops.
+    fn on_stop(&mut self, _ctx: &mut StrategyContext) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Called with data for the strategy's subscriptions.
+    fn on_data(&mut self, _ctx: &mut StrategyContext, _data: &Data) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Called with the response to a data request made by the strategy.
+    fn on_response(
+        &mut self,
+        _ctx: &mut StrategyContext,
+        _response: &DataResponse,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Called with events for the strategy's orders.
+    fn on_order_event(
+        &mut self,
+        _ctx: &mut StrategyContext,
+        _event: &OrderEventAny,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::datetime::NANOSECONDS_IN_SECOND;
+    use nautilus_model::{
+        data::bar::BarType,
+        enums::OrderSide,
+        identifiers::instrument_id::InstrumentId,
+        orders::stubs::TestOrderStubs,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn limit_order() -> OrderAny {
+        TestOrderStubs::limit_order(
+            InstrumentId::from("AUD/USD.SIM"),
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            None,
+            None,
+        )
+    }
+
+    #[rstest]
+    fn test_submit_order_routes_by_venue() {
+        let cache = Cache::default();
+        let mut ctx = StrategyContext::new(
+            TraderId::default(),
+            StrategyId::default(),
+            UnixNanos::from(1),
+            &cache,
+        );
+
+        ctx.submit_order(limit_order(), None).unwrap();
+
+        let commands = ctx.into_commands();
+        let StrategyCommand::Trading(TradingCommand::SubmitOrder(submit)) = &commands[0] else {
+            panic!("Expected `SubmitOrder`, was {commands:?}");
+        };
+        assert_eq!(submit.client_id, ClientId::from("SIM"));
+        assert_eq!(submit.ts_init, UnixNanos::from(1));
+    }
+
+    #[rstest]
+    fn test_request_warmup() {
+        let cache = Cache::default();
+        let mut ctx = StrategyContext::new(
+            TraderId::default(),
+            StrategyId::default(),
+            UnixNanos::from(300 * 60 * NANOSECONDS_IN_SECOND),
+            &cache,
+        );
+        let bar_type = BarType::from("AUD/USD.SIM-1-MINUTE-BID-EXTERNAL");
+        let requirement = WarmupRequirement::new(bar_type, 200).unwrap();
+
+        let request_id = ctx.request_warmup(&requirement);
+
+        let commands = ctx.into_commands();
+        let StrategyCommand::Request(request) = &commands[0] else {
+            panic!("Expected `Request`, was {commands:?}");
+        };
+        assert_eq!(request.request_id, request_id);
+        assert_eq!(request.subscription, DataSubscription::Bars(bar_type));
+        assert_eq!(
+            request.start,
+            Some(UnixNanos::from(100 * 60 * NANOSECONDS_IN_SECOND))
+        );
+        assert_eq!(request.limit, Some(200));
+    }
+
+    #[rstest]
+    fn test_submit_order_for_other_strategy() {
+        let cache = Cache::default();
+        let mut ctx = StrategyContext::new(
+            TraderId::default(),
+            StrategyId::from("OTHER-001"),
+            UnixNanos::default(),
+            &cache,
+        );
+
+        let result = ctx.submit_order(limit_order(), None);
+
+        assert!(result.is_err());
+        assert!(ctx.into_commands().is_empty());
+    }
+
+    #[rstest]
+    fn test_cancel_order_not_accepted() {
+        let mut cache = Cache::default();
+        let order = limit_order();
+        cache.add_order(order.clone(), None, None, false).unwrap();
+        let mut ctx = StrategyContext::new(
+            TraderId::default(),
+            StrategyId::default(),
+            UnixNanos::default(),
+            &cache,
+        );
+
+        let result = ctx.cancel_order(&order.client_order_id(), None);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,263 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+"""
+Calibration of simple execution cost models from recorded market data.
+"""
+
+from __future__ import annotations
+
+import json
+from dataclasses import asdict
+from dataclasses import dataclass
+from pathlib import Path
+
+from nautilus_trader.backtest.models import FillModel
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.persistence.catalog.base import BaseDataCatalog
+
+
+@dataclass(frozen=True)
+class CostModelParameters:
+    """
+    Represents execution cost model parameters calibrated for a single instrument.
+
+    Parameters
+    ----------
+    instrument_id : str
+        The instrument ID the parameters were calibrated for.
+    quote_count : int
+        The number of quotes used for calibration.
+    trade_count : int
+        The number of trades used for calibration.
+    avg_spread : float
+        The average quoted spread.
+    avg_spread_ticks : float, optional
+        The average quoted spread in ticks (if the price increment was known).
+    prob_fill_on_limit : float
+        The probability of a resting order at the touch filling before the touch moves.
+    prob_slippage : float
+        The probability of a trade printing beyond the prevailing touch.
+
+    """
+
+    instrument_id: str
+    quote_count: int
+    trade_count: int
+    avg_spread: float
+    avg_spread_ticks: float | None
+    prob_fill_on_limit: float
+    prob_slippage: float
+
+    def to_fill_model(
+        self,
+        prob_fill_on_stop: float = 1.0,
+        random_seed: int | None = None,
+    ) -> FillModel:
+        """
+        Return a fill model using the calibrated probabilities.
+
+        Parameters
+        ----------
+        prob_fill_on_stop : float, default 1.0
+            The probability of stop orders filling if the market rests on its price.
+        random_seed : int, optional
+            The random seed for the fill model.
+
+        Returns
+        -------
+        FillModel
+
+        """
+        return FillModel(
+            prob_fill_on_limit=self.prob_fill_on_limit,
+            prob_fill_on_stop=prob_fill_on_stop,
+            prob_slippage=self.prob_slippage,
+            random_seed=random_seed,
+        )
+
+    def to_dict(self) -> dict:
+        return asdict(self)
+
+    @staticmethod
+    def from_dict(values: dict) -> CostModelParameters:
+        return CostModelParameters(**values)
+
+
+def calibrate_cost_model(
+    instrument_id: str,
+    quotes: list[QuoteTick],
+    trades: list[TradeTick],
+    price_increment: float | None = None,
+) -> CostModelParameters:
+    """
+    Calibrate cost model parameters from the given quotes and trades.
+
+    Each quote starts a touch period lasting until the next quote. The fill probability is
+    the proportion of touch sides (bid and ask) where a trade printed at or through the
+    touch during the period. The slippage probability is the proportion of trades printing
+    beyond the prevailing touch on the aggressor side.
+
+    Parameters
+    ----------
+    instrument_id : str
+        The instrument ID for the data.
+    quotes : list[QuoteTick]
+        The quotes for calibration, sorted by `ts_init`.
+    trades : list[TradeTick]
+        The trades for calibration, sorted by `ts_init`.
+    price_increment : float, optional
+        The price increment for the instrument, to express the spread in ticks.
+
+    Returns
+    -------
+    CostModelParameters
+
+    Raises
+    ------
+    ValueError
+        If `quotes` is empty.
+
+    """
+    if not quotes:
+        raise ValueError(f"No quotes to calibrate cost model for {instrument_id}")
+
+    total_spread = 0.0
+    for quote in quotes:
+        total_spread += quote.ask_price.as_double() - quote.bid_price.as_double()
+    avg_spread = total_spread / len(quotes)
+
+    touch_sides = 0
+    touch_sides_filled = 0
+    trades_slipped = 0
+    trades_matched = 0
+
+    trade_idx = 0
+    for i, quote in enumerate(quotes):
+        period_end = quotes[i + 1].ts_init if i + 1 < len(quotes) else None
+        bid = quote.bid_price.as_double()
+        ask = quote.ask_price.as_double()
+        bid_filled = False
+        ask_filled = False
+
+        # Skip trades before the first quote (no prevailing touch)
+        while trade_idx < len(trades) and trades[trade_idx].ts_init < quote.ts_init:
+            trade_idx += 1
+
+        while trade_idx < len(trades) and (
+            period_end is None or trades[trade_idx].ts_init < period_end
+        ):
+            trade = trades[trade_idx]
+            trade_idx += 1
+            trades_matched += 1
+            price = trade.price.as_double()
+            if trade.aggressor_side == AggressorSide.BUYER:
+                ask_filled = ask_filled or price >= ask
+                trades_slipped += price > ask
+            elif trade.aggressor_side == AggressorSide.SELLER:
+                bid_filled = bid_filled or price <= bid
+                trades_slipped += price < bid
+            else:
+                ask_filled = ask_filled or price >= ask
+                bid_filled = bid_filled or price <= bid
+
+        touch_sides += 2
+        touch_sides_filled += bid_filled + ask_filled
+
+    return CostModelParameters(
+        instrument_id=instrument_id,
+        quote_count=len(quotes),
+        trade_count=trades_matched,
+        avg_spread=avg_spread,
+        avg_spread_ticks=avg_spread / price_increment if price_increment else None,
+        prob_fill_on_limit=touch_sides_filled / touch_sides,
+        prob_slippage=trades_slipped / trades_matched if trades_matched else 0.0,
+    )
+
+
+def calibrate_cost_model_from_catalog(
+    catalog: BaseDataCatalog,
+    instrument_id: str,
+    start: int | str | None = None,
+    end: int | str | None = None,
+) -> CostModelParameters:
+    """
+    Calibrate cost model parameters from the quotes and trades recorded in the catalog.
+
+    Parameters
+    ----------
+    catalog : BaseDataCatalog
+        The data catalog to query.
+    instrument_id : str
+        The instrument ID to calibrate for.
+    start : int or str, optional
+        The start of the data to query (UNIX nanoseconds or ISO 8601 string).
+    end : int or str, optional
+        The end of the data to query (UNIX nanoseconds or ISO 8601 string).
+
+    Returns
+    -------
+    CostModelParameters
+
+    """
+    instruments = catalog.instruments(instrument_ids=[instrument_id])
+    price_increment = instruments[0].price_increment.as_double() if instruments else None
+    quotes = catalog.quote_ticks(instrument_ids=[instrument_id], start=start, end=end)
+    trades = catalog.trade_ticks(instrument_ids=[instrument_id], start=start, end=end)
+    return calibrate_cost_model(
+        instrument_id=instrument_id,
+        quotes=sorted(quotes, key=lambda x: x.ts_init),
+        trades=sorted(trades, key=lambda x: x.ts_init),
+        price_increment=price_increment,
+    )
+
+
+def save_cost_models(params: list[CostModelParameters], path: str | Path) -> None:
+    """
+    Save the given cost model parameters to a JSON file, keyed by instrument ID.
+
+    Parameters
+    ----------
+    params : list[CostModelParameters]
+        The parameters to save.
+    path : str or Path
+        The path of the file to write.
+
+    """
+    data = {p.instrument_id: p.to_dict() for p in params}
+    Path(path).write_text(json.dumps(data, indent=2))
+
+
+def load_cost_models(path: str | Path) -> dict[str, CostModelParameters]:
+    """
+    Load cost model parameters from a JSON file written by `save_cost_models`.
+
+    Parameters
+    ----------
+    path : str or Path
+        The path of the file to read.
+
+    Returns
+    -------
+    dict[str, CostModelParameters]
+
+    """
+    data = json.loads(Path(path).read_text())
+    return {
+        instrument_id: CostModelParameters.from_dict(values)
+        for instrument_id, values in data.items()
+    }
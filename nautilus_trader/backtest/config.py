@@ -24,6 +24,7 @@ from nautilus_trader.common import Environment
 from nautilus_trader.common.config import ActorConfig
 from nautilus_trader.common.config import ImportableActorConfig
 from nautilus_trader.common.config import NautilusConfig
+from nautilus_trader.common.config import PositiveInt
 from nautilus_trader.common.config import resolve_path
 from nautilus_trader.core.datetime import dt_to_unix_nanos
 from nautilus_trader.data.config import DataEngineConfig
@@ -221,6 +222,8 @@ class BacktestEngineConfig(NautilusKernelConfig, frozen=True):
         If logging should be bypassed.
     run_analysis : bool, default True
         If post backtest performance analysis should be run.
+    progress_interval : PositiveInt, default 10_000
+        The count of data events processed between reports to progress callbacks.
 
     """
 
@@ -230,6 +233,7 @@ class BacktestEngineConfig(NautilusKernelConfig, frozen=True):
     risk_engine: RiskEngineConfig = RiskEngineConfig()
     exec_engine: ExecEngineConfig = ExecEngineConfig()
     run_analysis: bool = True
+    progress_interval: PositiveInt = 10_000
 
 
 class BacktestRunConfig(NautilusConfig, frozen=True):
@@ -278,3 +282,21 @@ class FXRolloverInterestConfig(SimulationModuleConfig, frozen=True):
     """
 
     rate_data: pd.DataFrame  # TODO: This could probably just become JSON data
+
+
+class MaintenanceWindowConfig(SimulationModuleConfig, frozen=True):
+    """
+    Configuration for ``MaintenanceWindowModule`` instances.
+
+    Parameters
+    ----------
+    windows : list[tuple[str, str]], optional
+        The one-off maintenance windows as (start, end) UTC timestamps (ISO 8601 strings).
+    weekly_windows : list[tuple[int, str, int]], optional
+        The recurring weekly maintenance windows as (ISO weekday, start time 'HH:MM' UTC,
+        duration in minutes), e.g. `(3, "06:00", 30)` for Wednesdays 06:00-06:30 UTC.
+
+    """
+
+    windows: list[tuple[str, str]] | None = None
+    weekly_windows: list[tuple[int, str, int]] | None = None
@@ -20,6 +20,7 @@ from nautilus_trader.backtest.exchange cimport SimulatedExchange
 from nautilus_trader.common.component cimport Clock
 from nautilus_trader.common.component cimport Logger
 from nautilus_trader.core.data cimport Data
+from nautilus_trader.core.rust.backtest cimport ProgressReporterAPI
 from nautilus_trader.core.rust.backtest cimport TimeEventAccumulatorAPI
 from nautilus_trader.core.rust.core cimport CVec
 from nautilus_trader.core.uuid cimport UUID4
@@ -47,6 +48,8 @@ cdef class BacktestEngine:
     cdef uint64_t _data_len
     cdef uint64_t _index
     cdef uint64_t _iteration
+    cdef bint _cancelled
+    cdef list _progress_callbacks
 
     cdef Data _next(self)
     cdef CVec _advance_time(self, uint64_t ts_now)
@@ -56,3 +59,9 @@ cdef class BacktestEngine:
         uint64_t ts_now,
         bint only_now,
     )
+    cdef void _report_progress(
+        self,
+        ProgressReporterAPI *progress,
+        uint64_t total,
+        uint64_t ts_sim,
+    ) except *
@@ -15,10 +15,12 @@
 
 import pickle
 from decimal import Decimal
+from typing import Callable
 
 import pandas as pd
 
 from nautilus_trader.accounting.error import AccountError
+from nautilus_trader.backtest.results import BacktestProgress
 from nautilus_trader.backtest.results import BacktestResult
 from nautilus_trader.common import Environment
 from nautilus_trader.common.component import is_logging_pyo3
@@ -35,6 +37,7 @@ from nautilus_trader.trading.trader import Trader
 
 from cpython.datetime cimport datetime
 from cpython.object cimport PyObject
+from libc.math cimport isnan
 from libc.stdint cimport uint64_t
 
 from nautilus_trader.backtest.data_client cimport BacktestDataClient
@@ -63,7 +66,15 @@ from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
 from nautilus_trader.core.datetime cimport maybe_dt_to_unix_nanos
 from nautilus_trader.core.datetime cimport unix_nanos_to_dt
+from nautilus_trader.core.rust.backtest cimport ProgressReporterAPI
 from nautilus_trader.core.rust.backtest cimport TimeEventAccumulatorAPI
+from nautilus_trader.core.rust.backtest cimport progress_reporter_drop
+from nautilus_trader.core.rust.backtest cimport progress_reporter_eta_secs
+from nautilus_trader.core.rust.backtest cimport progress_reporter_events_per_sec
+from nautilus_trader.core.rust.backtest cimport progress_reporter_new
+from nautilus_trader.core.rust.backtest cimport progress_reporter_on_event
+from nautilus_trader.core.rust.backtest cimport progress_reporter_percent
+from nautilus_trader.core.rust.backtest cimport progress_reporter_processed
 from nautilus_trader.core.rust.backtest cimport time_event_accumulator_advance_clock
 from nautilus_trader.core.rust.backtest cimport time_event_accumulator_drain
 from nautilus_trader.core.rust.backtest cimport time_event_accumulator_drop
@@ -134,6 +145,8 @@ cdef class BacktestEngine:
         self._data_len: uint64_t = 0
         self._index: uint64_t = 0
         self._iteration: uint64_t = 0
+        self._cancelled = False
+        self._progress_callbacks: list = []
 
         # Timing
         self._run_started: datetime | None = None
@@ -250,6 +263,18 @@ cdef class BacktestEngine:
         """
         return self._iteration
 
+    @property
+    def is_cancelled(self) -> bool:
+        """
+        Return whether cancellation of the backtest run has been requested.
+
+        Returns
+        -------
+        bool
+
+        """
+        return self._cancelled
+
     @property
     def run_started(self) -> datetime | None:
         """
@@ -730,6 +755,23 @@ cdef class BacktestEngine:
         # Checked inside trader
         self.kernel.trader.add_actors(actors)
 
+    def register_progress_callback(self, callback: Callable[[BacktestProgress], None]) -> None:
+        """
+        Register the given callback to receive progress reports during a run.
+
+        Reports are delivered every `progress_interval` processed data events (from the
+        engine config) and on the final event. A callback may call `cancel()` to stop the run.
+
+        Parameters
+        ----------
+        callback : Callable[[BacktestProgress], None]
+            The callback to register.
+
+        """
+        Condition.callable(callback, "callback")
+
+        self._progress_callbacks.append(callback)
+
     def add_strategy(self, strategy: Strategy) -> None:
         """
         Add the given strategy to the backtest engine.
@@ -830,6 +872,7 @@ cdef class BacktestEngine:
         # Reset timing
         self._iteration = 0
         self._index = 0
+        self._cancelled = False
         self._run_started = None
         self._run_finished = None
         self._backtest_start = None
@@ -902,6 +945,9 @@ cdef class BacktestEngine:
          - Add next batch of data stream.
          - Call either `run(streaming=False)` or `end()`. When there is no more data to run on.
 
+        A long running backtest may be stopped early by calling `cancel()`, in which
+        case the run ends at the next event boundary with partial results.
+
         Parameters
         ----------
         start : datetime or str or int, optional
@@ -928,6 +974,21 @@ cdef class BacktestEngine:
         if not streaming:
             self.end()
 
+    def cancel(self) -> None:
+        """
+        Request cancellation of the current backtest run.
+
+        The run stops cleanly at the next event boundary, once all data and time
+        events for the current timestamp have been processed. The backtest is then
+        ended as normal, so partial results remain available from `get_result`.
+
+        May be called from another thread, from a progress callback, or from within an
+        actor or strategy handler.
+        The request remains in effect until the engine is reset.
+
+        """
+        self._cancelled = True
+
     def end(self):
         """
         Manually end the backtest.
@@ -989,6 +1050,7 @@ cdef class BacktestEngine:
             backtest_end=maybe_dt_to_unix_nanos(self._backtest_end),
             elapsed_time=(self._backtest_end - self._backtest_start).total_seconds(),
             iterations=self._index,
+            cancelled=self._cancelled,
             total_events=self._kernel.exec_engine.event_count,
             total_orders=self._kernel.cache.orders_total_count(),
             total_positions=self._kernel.cache.positions_total_count(),
@@ -1072,6 +1134,13 @@ cdef class BacktestEngine:
                 self._index = i
                 break
 
+        # Report progress over the remaining data
+        cdef ProgressReporterAPI progress
+        cdef uint64_t progress_total = self._data_len - self._index
+        cdef bint report_progress = len(self._progress_callbacks) > 0
+        if report_progress:
+            progress = progress_reporter_new(progress_total, self._config.progress_interval)
+
         # -- MAIN BACKTEST LOOP -----------------------------------------------#
         cdef bint force_stop = False
         cdef uint64_t last_ns = 0
@@ -1084,12 +1153,18 @@ cdef class BacktestEngine:
                 if data.ts_init > end_ns:
                     # End of backtest
                     break
+                if self._cancelled and data.ts_init > last_ns:
+                    # Cancelled at event boundary
+                    self._log.warning(f"Backtest cancelled after {self._iteration:,} iterations")
+                    self._index -= 1  # Data not processed
+                    break
                 if data.ts_init > last_ns:
                     # Advance clocks to the next data time
                     raw_handlers = self._advance_time(data.ts_init)
                     raw_handlers_count = raw_handlers.len
 
                 # Process data through venue
+                venue = None
                 if isinstance(data, OrderBookDelta):
                     venue = self._venues[data.instrument_id.venue]
                     venue.process_order_book_delta(data)
@@ -1112,7 +1187,9 @@ cdef class BacktestEngine:
                     venue = self._venues[data.instrument_id.venue]
                     venue.process_instrument_status(data)
 
-                self._data_engine.process(data)
+                if venue is None or not venue.is_halted:
+                    # Venue data is halted during maintenance
+                    self._data_engine.process(data)
 
                 # Process all exchange messages
                 for exchange in self._venues.values():
@@ -1133,9 +1210,14 @@ cdef class BacktestEngine:
                     raw_handlers_count = 0
 
                 self._iteration += 1
+                if report_progress and progress_reporter_on_event(&progress, last_ns):
+                    self._report_progress(&progress, progress_total, last_ns)
         except AccountError as e:
             force_stop = True
             self._log.error(f"Stopping backtest from {e}")
+        finally:
+            if report_progress:
+                progress_reporter_drop(progress)
         # ---------------------------------------------------------------------#
 
         if force_stop:
@@ -1230,6 +1312,24 @@ cdef class BacktestEngine:
                 for exchange in self._venues.values():
                     exchange.process(ts_event_init)
 
+    cdef void _report_progress(
+        self,
+        ProgressReporterAPI *progress,
+        uint64_t total,
+        uint64_t ts_sim,
+    ) except *:
+        cdef double eta_secs = progress_reporter_eta_secs(progress)
+        report = BacktestProgress(
+            processed=progress_reporter_processed(progress),
+            total=total,
+            percent=progress_reporter_percent(progress),
+            ts_sim=ts_sim,
+            events_per_sec=progress_reporter_events_per_sec(progress),
+            eta_secs=None if isnan(eta_secs) else eta_secs,
+        )
+        for callback in self._progress_callbacks:
+            callback(report)
+
     def _get_log_color_code(self):
         return "\033[36m" if logging_is_colored() else ""
 
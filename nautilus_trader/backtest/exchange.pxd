@@ -101,8 +101,11 @@ cdef class SimulatedExchange:
     """The simulation modules registered with the exchange.\n\n:returns: `list[SimulationModule]`"""
     cdef readonly dict instruments
     """The exchange instruments.\n\n:returns: `dict[InstrumentId, Instrument]`"""
+    cdef readonly bint is_halted
+    """If the exchange is halted for maintenance (trading commands rejected and market data dropped).\n\n:returns: `bool`"""
 
     cdef dict _matching_engines
+    cdef set _awaiting_snapshot
     cdef object _message_queue
     cdef list _inflight_queue
     cdef dict _inflight_counter
@@ -131,6 +134,8 @@ cdef class SimulatedExchange:
 # -- COMMANDS -------------------------------------------------------------------------------------
 
     cpdef void adjust_account(self, Money adjustment)
+    cpdef void halt(self)
+    cpdef void resume(self)
     cdef tuple generate_inflight_command(self, TradingCommand command)
     cpdef void send(self, TradingCommand command)
     cpdef void process_order_book_delta(self, OrderBookDelta delta)
@@ -144,6 +149,7 @@ cdef class SimulatedExchange:
     cpdef void reset(self)
 
     cdef void _process_trading_command(self, TradingCommand command)
+    cdef void _reject_trading_command(self, TradingCommand command, str reason)
 
 # -- EVENT GENERATORS -----------------------------------------------------------------------------
 
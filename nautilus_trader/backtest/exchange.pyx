@@ -36,6 +36,7 @@ from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.rust.model cimport AccountType
 from nautilus_trader.core.rust.model cimport BookType
 from nautilus_trader.core.rust.model cimport OmsType
+from nautilus_trader.core.rust.model cimport RecordFlag
 from nautilus_trader.execution.messages cimport BatchCancelOrders
 from nautilus_trader.execution.messages cimport CancelAllOrders
 from nautilus_trader.execution.messages cimport CancelOrder
@@ -227,6 +228,10 @@ cdef class SimulatedExchange:
         self.instruments: dict[InstrumentId, Instrument] = {}
         self._matching_engines: dict[InstrumentId, OrderMatchingEngine] = {}
 
+        # Maintenance
+        self.is_halted = False
+        self._awaiting_snapshot: set[InstrumentId] = set()
+
         self._message_queue = deque()
         self._inflight_queue: list[tuple[(uint64_t, uint64_t), TradingCommand]] = []
         self._inflight_counter: dict[uint64_t, uint64_t] = {}
@@ -612,6 +617,40 @@ cdef class SimulatedExchange:
             ts_event=self._clock.timestamp_ns(),
         )
 
+    cpdef void halt(self):
+        """
+        Halt the exchange for maintenance.
+
+        While halted all trading commands are rejected and market data is dropped.
+
+        """
+        self.is_halted = True
+
+        self._log.warning("Halted for maintenance")
+
+    cpdef void resume(self):
+        """
+        Resume the exchange after maintenance.
+
+        As market data was dropped while halted, the order books are cleared and then rebuilt
+        from the next snapshot (order book deltas are ignored until then). L1 books are rebuilt
+        from the next quote, trade or bar.
+
+        """
+        cdef uint64_t ts_now = self._clock.timestamp_ns()
+
+        cdef:
+            InstrumentId instrument_id
+            OrderMatchingEngine matching_engine
+        for instrument_id, matching_engine in self._matching_engines.items():
+            matching_engine.get_book().clear(ts_now)
+            if matching_engine.book_type != BookType.L1_MBP:
+                self._awaiting_snapshot.add(instrument_id)
+
+        self.is_halted = False
+
+        self._log.info("Resumed after maintenance")
+
     cpdef void send(self, TradingCommand command):
         """
         Send the given trading command into the exchange.
@@ -634,11 +673,11 @@ cdef class SimulatedExchange:
     cdef tuple generate_inflight_command(self, TradingCommand command):
         cdef uint64_t ts
         if isinstance(command, (SubmitOrder, SubmitOrderList)):
-            ts = command.ts_init + self.latency_model.insert_latency_nanos
+            ts = command.ts_init + self.latency_model.get_insert_latency()
         elif isinstance(command, ModifyOrder):
-            ts = command.ts_init + self.latency_model.update_latency_nanos
+            ts = command.ts_init + self.latency_model.get_update_latency()
         elif isinstance(command, (CancelOrder, CancelAllOrders, BatchCancelOrders)):
-            ts = command.ts_init + self.latency_model.cancel_latency_nanos
+            ts = command.ts_init + self.latency_model.get_cancel_latency()
         else:
             raise ValueError(f"invalid `TradingCommand`, was {command}")  # pragma: no cover (design-time error)
         if ts not in self._inflight_counter:
@@ -663,6 +702,14 @@ cdef class SimulatedExchange:
         for module in self.modules:
             module.pre_process(delta)
 
+        if self.is_halted:
+            return  # Market data dropped during maintenance
+
+        if delta.instrument_id in self._awaiting_snapshot:
+            if not delta.flags & RecordFlag.F_SNAPSHOT:
+                return  # Book is rebuilt from the next snapshot
+            self._awaiting_snapshot.discard(delta.instrument_id)
+
         cdef OrderMatchingEngine matching_engine = self._matching_engines.get(delta.instrument_id)
         if matching_engine is None:
             instrument = self.cache.instrument(delta.instrument_id)
@@ -689,6 +736,14 @@ cdef class SimulatedExchange:
         for module in self.modules:
             module.pre_process(deltas)
 
+        if self.is_halted:
+            return  # Market data dropped during maintenance
+
+        if deltas.instrument_id in self._awaiting_snapshot:
+            if not deltas.is_snapshot:
+                return  # Book is rebuilt from the next snapshot
+            self._awaiting_snapshot.discard(deltas.instrument_id)
+
         cdef OrderMatchingEngine matching_engine = self._matching_engines.get(deltas.instrument_id)
         if matching_engine is None:
             instrument = self.cache.instrument(deltas.instrument_id)
@@ -717,6 +772,9 @@ cdef class SimulatedExchange:
         for module in self.modules:
             module.pre_process(tick)
 
+        if self.is_halted:
+            return  # Market data dropped during maintenance
+
         cdef OrderMatchingEngine matching_engine = self._matching_engines.get(tick.instrument_id)
         if matching_engine is None:
             instrument = self.cache.instrument(tick.instrument_id)
@@ -745,6 +803,9 @@ cdef class SimulatedExchange:
         for module in self.modules:
             module.pre_process(tick)
 
+        if self.is_halted:
+            return  # Market data dropped during maintenance
+
         cdef OrderMatchingEngine matching_engine = self._matching_engines.get(tick.instrument_id)
         if matching_engine is None:
             instrument = self.cache.instrument(tick.instrument_id)
@@ -773,6 +834,9 @@ cdef class SimulatedExchange:
         for module in self.modules:
             module.pre_process(bar)
 
+        if self.is_halted:
+            return  # Market data dropped during maintenance
+
         cdef OrderMatchingEngine matching_engine = self._matching_engines.get(bar.bar_type.instrument_id)
         if matching_engine is None:
             instrument = self.cache.instrument(bar.bar_type.instrument_id)
@@ -799,6 +863,9 @@ cdef class SimulatedExchange:
         for module in self.modules:
             module.pre_process(data)
 
+        if self.is_halted:
+            return  # Market data dropped during maintenance
+
         cdef OrderMatchingEngine matching_engine
         for matching_engine in self._matching_engines.values():
             matching_engine.process_status(data.status)
@@ -819,6 +886,9 @@ cdef class SimulatedExchange:
         for module in self.modules:
             module.pre_process(data)
 
+        if self.is_halted:
+            return  # Market data dropped during maintenance
+
         cdef OrderMatchingEngine matching_engine = self._matching_engines.get(data.instrument_id)
         if matching_engine is None:
             instrument = self.cache.instrument(data.instrument_id)
@@ -885,6 +955,9 @@ cdef class SimulatedExchange:
         self._inflight_queue.clear()
         self._inflight_counter.clear()
 
+        self.is_halted = False
+        self._awaiting_snapshot.clear()
+
         self._log.info("Reset")
 
     cdef void _process_trading_command(self, TradingCommand command):
@@ -892,6 +965,10 @@ cdef class SimulatedExchange:
         if matching_engine is None:
             raise RuntimeError(f"Cannot process command: no matching engine for {command.instrument_id}")
 
+        if self.is_halted:
+            self._reject_trading_command(command, f"{self.id} halted for maintenance")
+            return
+
         cdef:
             Order order
             list[Order] orders
@@ -909,6 +986,72 @@ cdef class SimulatedExchange:
         elif isinstance(command, BatchCancelOrders):
             matching_engine.process_batch_cancel(command, self.exec_client.account_id)
 
+    cdef void _reject_trading_command(self, TradingCommand command, str reason):
+        cdef uint64_t ts_now = self._clock.timestamp_ns()
+
+        cdef:
+            Order order
+            CancelOrder cancel
+        if isinstance(command, SubmitOrder):
+            order = command.order
+            self.exec_client.generate_order_rejected(
+                order.strategy_id,
+                order.instrument_id,
+                order.client_order_id,
+                reason,
+                ts_now,
+            )
+        elif isinstance(command, SubmitOrderList):
+            for order in command.order_list.orders:
+                self.exec_client.generate_order_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    reason,
+                    ts_now,
+                )
+        elif isinstance(command, ModifyOrder):
+            self.exec_client.generate_order_modify_rejected(
+                command.strategy_id,
+                command.instrument_id,
+                command.client_order_id,
+                command.venue_order_id,
+                reason,
+                ts_now,
+            )
+        elif isinstance(command, CancelOrder):
+            self.exec_client.generate_order_cancel_rejected(
+                command.strategy_id,
+                command.instrument_id,
+                command.client_order_id,
+                command.venue_order_id,
+                reason,
+                ts_now,
+            )
+        elif isinstance(command, CancelAllOrders):
+            for order in self.cache.orders_open(
+                instrument_id=command.instrument_id,
+                strategy_id=command.strategy_id,
+            ):
+                self.exec_client.generate_order_cancel_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    order.venue_order_id,
+                    reason,
+                    ts_now,
+                )
+        elif isinstance(command, BatchCancelOrders):
+            for cancel in command.cancels:
+                self.exec_client.generate_order_cancel_rejected(
+                    cancel.strategy_id,
+                    cancel.instrument_id,
+                    cancel.client_order_id,
+                    cancel.venue_order_id,
+                    reason,
+                    ts_now,
+                )
+
 # -- EVENT GENERATORS -----------------------------------------------------------------------------
 
     cdef void _generate_fresh_account_state(self):
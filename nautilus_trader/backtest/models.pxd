@@ -47,6 +47,31 @@ cdef class LatencyModel:
     cdef readonly uint64_t cancel_latency_nanos
     """The latency (nanoseconds) for order cancel messages to reach the exchange.\n\n:returns: `int`"""
 
+    cpdef uint64_t get_insert_latency(self)
+    cpdef uint64_t get_update_latency(self)
+    cpdef uint64_t get_cancel_latency(self)
+
+
+cdef class LatencyHistogram:
+    cdef list _bounds_nanos
+    cdef list _cumulative_counts
+
+    cdef readonly uint64_t total_count
+    """The total count of measured latencies in the histogram.\n\n:returns: `int`"""
+
+    cpdef uint64_t sample(self, rng)
+
+
+cdef class EmpiricalLatencyModel(LatencyModel):
+    cdef object _rng
+
+    cdef readonly LatencyHistogram insert_histogram
+    """The latency histogram for order insert messages (if any).\n\n:returns: `LatencyHistogram` or ``None``"""
+    cdef readonly LatencyHistogram update_histogram
+    """The latency histogram for order update messages (if any).\n\n:returns: `LatencyHistogram` or ``None``"""
+    cdef readonly LatencyHistogram cancel_histogram
+    """The latency histogram for order cancel messages (if any).\n\n:returns: `LatencyHistogram` or ``None``"""
+
 
 cdef class FeeModel:
     cpdef Money get_commission(self, Order order, Quantity fill_qty, Price fill_px, Instrument instrument)
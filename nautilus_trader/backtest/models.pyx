@@ -14,6 +14,7 @@
 # -------------------------------------------------------------------------------------------------
 
 import random
+from bisect import bisect_right
 
 from libc.stdint cimport uint64_t
 
@@ -162,6 +163,195 @@ cdef class LatencyModel:
         self.update_latency_nanos = base_latency_nanos + update_latency_nanos
         self.cancel_latency_nanos = base_latency_nanos + cancel_latency_nanos
 
+    cpdef uint64_t get_insert_latency(self):
+        """
+        Return the latency (nanoseconds) for the next order insert message.
+
+        Returns
+        -------
+        uint64_t
+
+        """
+        return self.insert_latency_nanos
+
+    cpdef uint64_t get_update_latency(self):
+        """
+        Return the latency (nanoseconds) for the next order update message.
+
+        Returns
+        -------
+        uint64_t
+
+        """
+        return self.update_latency_nanos
+
+    cpdef uint64_t get_cancel_latency(self):
+        """
+        Return the latency (nanoseconds) for the next order cancel message.
+
+        Returns
+        -------
+        uint64_t
+
+        """
+        return self.cancel_latency_nanos
+
+
+cdef class LatencyHistogram:
+    """
+    Provides an empirical latency distribution from a measured latency histogram.
+
+    Latencies are sampled by selecting a bucket in proportion to its count, and then
+    uniformly within the bounds of the bucket.
+
+    Parameters
+    ----------
+    bounds_nanos : list[int]
+        The bucket bounds (nanoseconds) in ascending order, where bucket `i` is the
+        interval [`bounds_nanos[i]`, `bounds_nanos[i + 1]`).
+    counts : list[int]
+        The count of measured latencies for each bucket.
+
+    Raises
+    ------
+    ValueError
+        If `bounds_nanos` is not one longer than `counts`.
+    ValueError
+        If `bounds_nanos` is not strictly ascending, or any bound is negative (< 0).
+    ValueError
+        If any count is negative (< 0), or the total count is zero.
+    """
+
+    def __init__(
+        self,
+        list bounds_nanos not None,
+        list counts not None,
+    ):
+        Condition.equal(len(bounds_nanos), len(counts) + 1, "len(bounds_nanos)", "len(counts) + 1")
+        Condition.not_negative_int(bounds_nanos[0], "bounds_nanos[0]")
+        for i in range(len(counts)):
+            Condition.true(
+                bounds_nanos[i + 1] > bounds_nanos[i],
+                f"`bounds_nanos` not strictly ascending at index {i + 1}",
+            )
+            Condition.not_negative_int(counts[i], "count")
+
+        cdef list cumulative_counts = []
+        cdef uint64_t total = 0
+        for count in counts:
+            total += count
+            cumulative_counts.append(total)
+        Condition.positive_int(total, "total_count")
+
+        self._bounds_nanos = list(bounds_nanos)
+        self._cumulative_counts = cumulative_counts
+        self.total_count = total
+
+    @staticmethod
+    def from_dict(dict values) -> LatencyHistogram:
+        """
+        Return a latency histogram parsed from the given values.
+
+        Parameters
+        ----------
+        values : dict[str, list[int]]
+            The values with 'bounds_nanos' and 'counts' keys, e.g. as loaded from JSON.
+
+        Returns
+        -------
+        LatencyHistogram
+
+        """
+        Condition.not_none(values, "values")
+
+        return LatencyHistogram(
+            bounds_nanos=values["bounds_nanos"],
+            counts=values["counts"],
+        )
+
+    cpdef uint64_t sample(self, rng):
+        """
+        Return a latency (nanoseconds) sampled from the histogram.
+
+        Parameters
+        ----------
+        rng : random.Random
+            The random number generator for sampling.
+
+        Returns
+        -------
+        uint64_t
+
+        """
+        cdef int bucket = bisect_right(self._cumulative_counts, rng.random() * self.total_count)
+        bucket = min(bucket, len(self._cumulative_counts) - 1)
+        cdef uint64_t lower = self._bounds_nanos[bucket]
+        cdef uint64_t upper = self._bounds_nanos[bucket + 1]
+        return lower + <uint64_t>(rng.random() * (upper - lower))
+
+
+cdef class EmpiricalLatencyModel(LatencyModel):
+    """
+    Provides a latency model for simulated exchange message I/O, with latencies sampled
+    per command type from measured latency histograms.
+
+    This allows simulated round trips to statistically match those measured for a venue,
+    rather than fixed constants. Command types without a histogram use the base latency.
+
+    Parameters
+    ----------
+    insert_histogram : LatencyHistogram, optional
+        The latency histogram for order insert messages.
+    update_histogram : LatencyHistogram, optional
+        The latency histogram for order update messages.
+    cancel_histogram : LatencyHistogram, optional
+        The latency histogram for order cancel messages.
+    base_latency_nanos : int, default 1_000_000
+        The base latency (nanoseconds) for command types without a histogram.
+    random_seed : int, optional
+        The random seed for sampling (if None then no random seed).
+
+    Raises
+    ------
+    ValueError
+        If `base_latency_nanos` is negative (< 0).
+    TypeError
+        If `random_seed` is not None and not of type `int`.
+    """
+
+    def __init__(
+        self,
+        LatencyHistogram insert_histogram = None,
+        LatencyHistogram update_histogram = None,
+        LatencyHistogram cancel_histogram = None,
+        uint64_t base_latency_nanos = NANOSECONDS_IN_MILLISECOND,
+        random_seed: int | None = None,
+    ):
+        if random_seed is not None:
+            Condition.type(random_seed, int, "random_seed")
+
+        super().__init__(base_latency_nanos=base_latency_nanos)
+
+        self._rng = random.Random(random_seed)
+        self.insert_histogram = insert_histogram
+        self.update_histogram = update_histogram
+        self.cancel_histogram = cancel_histogram
+
+    cpdef uint64_t get_insert_latency(self):
+        if self.insert_histogram is None:
+            return self.insert_latency_nanos
+        return self.insert_histogram.sample(self._rng)
+
+    cpdef uint64_t get_update_latency(self):
+        if self.update_histogram is None:
+            return self.update_latency_nanos
+        return self.update_histogram.sample(self._rng)
+
+    cpdef uint64_t get_cancel_latency(self):
+        if self.cancel_histogram is None:
+            return self.cancel_latency_nanos
+        return self.cancel_histogram.sample(self._rng)
+
 
 cdef class FeeModel:
     """
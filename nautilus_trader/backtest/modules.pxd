@@ -21,6 +21,7 @@ from nautilus_trader.backtest.exchange cimport SimulatedExchange
 from nautilus_trader.common.actor cimport Actor
 from nautilus_trader.common.component cimport Logger
 from nautilus_trader.core.data cimport Data
+from nautilus_trader.core.rust.model cimport MarketStatus
 
 
 cdef class SimulationModule(Actor):
@@ -42,3 +43,13 @@ cdef class FXRolloverInterestModule(SimulationModule):
     cdef int _day_number
 
     cdef void _apply_rollover_interest(self, datetime timestamp, int iso_week_day)
+
+
+cdef class MaintenanceWindowModule(SimulationModule):
+    cdef list _windows
+    cdef list _weekly_windows
+    cdef int _halt_count
+
+    cdef void _update(self, uint64_t ts_now)
+    cdef bint _is_in_window(self, uint64_t ts_now)
+    cdef void _publish_status(self, MarketStatus status, uint64_t ts_now)
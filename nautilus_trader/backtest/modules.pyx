@@ -18,6 +18,7 @@ import pandas as pd
 import pytz
 
 from nautilus_trader.backtest.config import FXRolloverInterestConfig
+from nautilus_trader.backtest.config import MaintenanceWindowConfig
 from nautilus_trader.backtest.config import SimulationModuleConfig
 from nautilus_trader.common.config import ActorConfig
 
@@ -28,9 +29,12 @@ from nautilus_trader.accounting.calculators cimport RolloverInterestCalculator
 from nautilus_trader.backtest.exchange cimport SimulatedExchange
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
+from nautilus_trader.core.datetime cimport dt_to_unix_nanos
 from nautilus_trader.core.rust.model cimport AssetClass
+from nautilus_trader.core.rust.model cimport MarketStatus
 from nautilus_trader.core.rust.model cimport PriceType
 from nautilus_trader.model.book cimport OrderBook
+from nautilus_trader.model.data cimport VenueStatus
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.objects cimport Currency
@@ -219,3 +223,126 @@ cdef class FXRolloverInterestModule(SimulationModule):
         self._rollover_applied = False
         self._rollover_totals = {}
         self._day_number = 0
+
+
+cdef class MaintenanceWindowModule(SimulationModule):
+    """
+    Provides a simulation module for scheduled exchange maintenance windows.
+
+    While a window is active the exchange is halted: trading commands are rejected and market
+    data for the venue is dropped. When the window ends the exchange resumes, with its order
+    books rebuilt from the next snapshot. A `VenueStatus` of ``HALT`` is published at the start
+    of each window, and ``REOPEN`` at the end.
+
+    Parameters
+    ----------
+    config  : MaintenanceWindowConfig
+
+    Raises
+    ------
+    ValueError
+        If any window does not end after it starts.
+
+    """
+
+    def __init__(self, config: MaintenanceWindowConfig):
+        super().__init__(config)
+
+        self._windows = []
+        for start, end in config.windows or []:
+            start_ns = dt_to_unix_nanos(pd.Timestamp(start, tz="UTC"))
+            end_ns = dt_to_unix_nanos(pd.Timestamp(end, tz="UTC"))
+            Condition.true(start_ns < end_ns, f"window start {start} was not before end {end}")
+            self._windows.append((start_ns, end_ns))
+
+        self._weekly_windows = []
+        for iso_weekday, start_time, duration_mins in config.weekly_windows or []:
+            Condition.in_range_int(iso_weekday, 1, 7, "iso_weekday")
+            Condition.positive_int(duration_mins, "duration_mins")
+            self._weekly_windows.append(
+                (
+                    pd.Timedelta(days=iso_weekday - 1) + pd.Timedelta(f"{start_time}:00"),
+                    pd.Timedelta(minutes=duration_mins),
+                ),
+            )
+
+        self._halt_count = 0
+
+    cpdef void pre_process(self, Data data):
+        """
+        Halt or resume the exchange ahead of processing the given data.
+
+        Parameters
+        ----------
+        data : Data
+            The data about to be processed by the exchange.
+
+        """
+        self._update(data.ts_init)
+
+    cpdef void process(self, uint64_t ts_now):
+        """
+        Process the module to the given time, halting or resuming the exchange.
+
+        Parameters
+        ----------
+        ts_now : uint64_t
+            The current UNIX time (nanoseconds) in the simulated exchange.
+
+        """
+        self._update(ts_now)
+
+    cdef void _update(self, uint64_t ts_now):
+        cdef bint in_window = self._is_in_window(ts_now)
+        if in_window and not self.exchange.is_halted:
+            self.exchange.halt()
+            self._halt_count += 1
+            self._publish_status(MarketStatus.HALT, ts_now)
+        elif not in_window and self.exchange.is_halted:
+            self.exchange.resume()
+            self._publish_status(MarketStatus.REOPEN, ts_now)
+
+    cdef bint _is_in_window(self, uint64_t ts_now):
+        cdef uint64_t start_ns
+        cdef uint64_t end_ns
+        for start_ns, end_ns in self._windows:
+            if start_ns <= ts_now < end_ns:
+                return True
+
+        if not self._weekly_windows:
+            return False
+
+        now = pd.Timestamp(ts_now, tz="UTC")
+        week_start = now.normalize() - pd.Timedelta(days=now.dayofweek)
+        for offset, duration in self._weekly_windows:
+            start = week_start + offset
+            # Check the previous week also, for a window spanning the week boundary
+            for window_start in (start, start - pd.Timedelta(days=7)):
+                if window_start <= now < window_start + duration:
+                    return True
+
+        return False
+
+    cdef void _publish_status(self, MarketStatus status, uint64_t ts_now):
+        cdef VenueStatus data = VenueStatus(
+            venue=self.exchange.id,
+            status=status,
+            ts_event=ts_now,
+            ts_init=ts_now,
+        )
+        self._msgbus.publish_c(topic=f"data.status.{self.exchange.id}", msg=data)
+
+    cpdef void log_diagnostics(self, Logger logger):
+        """
+        Log diagnostics out to the `BacktestEngine` logger.
+
+        Parameters
+        ----------
+        logger : Logger
+            The logger to log to.
+
+        """
+        logger.info(f"Maintenance windows (halts): {self._halt_count}")
+
+    cpdef void reset(self):
+        self._halt_count = 0
@@ -16,6 +16,20 @@
 from dataclasses import dataclass
 
 
+@dataclass
+class BacktestProgress:
+    """
+    Represents a snapshot of progress for a running backtest.
+    """
+
+    processed: int
+    total: int
+    percent: float
+    ts_sim: int
+    events_per_sec: float
+    eta_secs: float | None
+
+
 @dataclass
 class BacktestResult:
     """
@@ -38,6 +52,7 @@ class BacktestResult:
     total_positions: int
     stats_pnls: dict[str, dict[str, float]]
     stats_returns: dict[str, float]
+    cancelled: bool = False
 
     # account_balances: pd.DataFrame
     # fills_report: pd.DataFrame
@@ -43,6 +43,9 @@ class CacheConfig(NautilusConfig, frozen=True):
         If the traders instance ID is used for keys.
     flush_on_start : bool, default False
         If database should be flushed on start.
+    read_only : bool, default False
+        If the cache database should be attached read-only (no writes will be issued).
+        Intended for secondary processes attaching to a live trader's cache (e.g. analytics).
     drop_instruments_on_reset : bool, default True
         If instruments data should be dropped from the caches memory on reset.
     tick_capacity : PositiveInt, default 10_000
@@ -59,6 +62,7 @@ class CacheConfig(NautilusConfig, frozen=True):
     use_trader_prefix: bool = True
     use_instance_id: bool = False
     flush_on_start: bool = False
+    read_only: bool = False
     drop_instruments_on_reset: bool = True
     tick_capacity: PositiveInt = 10_000
     bar_capacity: PositiveInt = 10_000
@@ -1967,7 +1967,7 @@ cdef class MessageBus:
         The custom name for the message bus.
     serializer : Serializer, optional
         The serializer for database operations.
-    database : nautilus_pyo3.RedisMessageBusDatabase, optional
+    database : nautilus_pyo3.RedisMessageBusDatabase or nautilus_pyo3.NatsMessageBusDatabase or nautilus_pyo3.ZmqMessageBusDatabase, optional
         The backing database for the message bus.
     snapshot_orders : bool, default False
         If order state snapshots should be published externally.
@@ -1994,7 +1994,7 @@ cdef class MessageBus:
         UUID4 instance_id = None,
         str name = None,
         Serializer serializer = None,
-        database: nautilus_pyo3.RedisMessageBusDatabase | None = None,
+        database: nautilus_pyo3.RedisMessageBusDatabase | nautilus_pyo3.NatsMessageBusDatabase | nautilus_pyo3.ZmqMessageBusDatabase | None = None,
         bint snapshot_orders: bool = False,
         bint snapshot_positions: bool = False,
         config: Any | None = None,
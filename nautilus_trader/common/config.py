@@ -237,8 +237,8 @@ class DatabaseConfig(NautilusConfig, frozen=True):
 
     Parameters
     ----------
-    type : str, {'redis'}, default 'redis'
-        The database type.
+    type : str, {'redis', 'nats', 'zmq'}, default 'redis'
+        The database type ('nats' and 'zmq' are only supported as a message bus backing).
     host : str, optional
         The database host address. If `None` then should use the typical default.
     port : int, optional
@@ -257,6 +257,10 @@ class DatabaseConfig(NautilusConfig, frozen=True):
     -----
     If `type` is 'redis' then requires Redis version 6.2.0 and above for correct operation.
 
+    If `type` is 'zmq' then the message bus publishes on a ZeroMQ PUB socket bound to
+    the `host` and `port` (default 127.0.0.1:5555), each message having the topic frame
+    followed by the payload frame, so external subscribers can filter by topic prefix.
+
     """
 
     type: str = "redis"
@@ -321,6 +325,9 @@ class MessageBusConfig(NautilusConfig, frozen=True):
         many traders to be configured to write to the same streams.
     types_filter : list[type], optional
         A list of serializable types **not** to publish externally.
+    use_jetstream : bool, default False
+        If messages should be persisted with a JetStream stream (NATS database only).
+        The `autotrim_mins` then applies as the maximum age of the stream messages.
 
     """
 
@@ -334,6 +341,7 @@ class MessageBusConfig(NautilusConfig, frozen=True):
     use_instance_id: bool = False
     streams_prefix: str = "streams"
     types_filter: list[type] | None = None
+    use_jetstream: bool = False
 
 
 class InstrumentProviderConfig(NautilusConfig, frozen=True):
@@ -24,6 +24,7 @@ from nautilus_trader.backtest.config import BacktestEngineConfig
 from nautilus_trader.backtest.config import BacktestRunConfig
 from nautilus_trader.backtest.config import BacktestVenueConfig
 from nautilus_trader.backtest.config import FXRolloverInterestConfig
+from nautilus_trader.backtest.config import MaintenanceWindowConfig
 from nautilus_trader.backtest.config import SimulationModuleConfig
 from nautilus_trader.cache.config import CacheConfig
 from nautilus_trader.common.config import ActorConfig
@@ -56,6 +57,7 @@ from nautilus_trader.execution.config import ImportableExecAlgorithmConfig
 from nautilus_trader.live.config import ControllerConfig
 from nautilus_trader.live.config import ControllerFactory
 from nautilus_trader.live.config import ImportableControllerConfig
+from nautilus_trader.live.config import LeaderElectionConfig
 from nautilus_trader.live.config import LiveDataClientConfig
 from nautilus_trader.live.config import LiveDataEngineConfig
 from nautilus_trader.live.config import LiveExecClientConfig
@@ -96,6 +98,7 @@ __all__ = [
     "InstrumentProviderConfig",
     "InvalidConfiguration",
     "LoggingConfig",
+    "MaintenanceWindowConfig",
     "MessageBusConfig",
     "NautilusConfig",
     "NautilusKernelConfig",
@@ -110,6 +113,7 @@ __all__ = [
     "StreamingConfig",
     "SimulationModuleConfig",
     "ImportableConfig",
+    "LeaderElectionConfig",
     "LiveDataClientConfig",
     "LiveDataEngineConfig",
     "LiveExecClientConfig",
@@ -5,6 +5,11 @@
 #include <stdint.h>
 #include <Python.h>
 
+/**
+ * Provides progress reports for a backtest run at a fixed interval of processed events.
+ */
+typedef struct ProgressReporter ProgressReporter;
+
 /**
  * Provides a means of accumulating and draining time event handlers.
  */
@@ -14,6 +19,10 @@ typedef struct TimeEventAccumulatorAPI {
     struct TimeEventAccumulator *_0;
 } TimeEventAccumulatorAPI;
 
+typedef struct ProgressReporterAPI {
+    struct ProgressReporter *_0;
+} ProgressReporterAPI;
+
 struct TimeEventAccumulatorAPI time_event_accumulator_new(void);
 
 void time_event_accumulator_drop(struct TimeEventAccumulatorAPI accumulator);
@@ -24,3 +33,23 @@ void time_event_accumulator_advance_clock(struct TimeEventAccumulatorAPI *accumu
                                           uint8_t set_time);
 
 CVec time_event_accumulator_drain(struct TimeEventAccumulatorAPI *accumulator);
+
+/**
+ * A zero `report_interval` is treated as a report on every event.
+ */
+struct ProgressReporterAPI progress_reporter_new(uint64_t total, uint64_t report_interval);
+
+void progress_reporter_drop(struct ProgressReporterAPI reporter);
+
+uint8_t progress_reporter_on_event(struct ProgressReporterAPI *reporter, uint64_t ts_sim);
+
+uint64_t progress_reporter_processed(const struct ProgressReporterAPI *reporter);
+
+double progress_reporter_percent(const struct ProgressReporterAPI *reporter);
+
+double progress_reporter_events_per_sec(const struct ProgressReporterAPI *reporter);
+
+/**
+ * Returns the estimated seconds remaining, or NaN if no rate has been established.
+ */
+double progress_reporter_eta_secs(const struct ProgressReporterAPI *reporter);
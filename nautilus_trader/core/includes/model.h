@@ -696,6 +696,11 @@ typedef enum TriggerType {
     INDEX_PRICE = 9,
 } TriggerType;
 
+/**
+ * Represents a user-defined data payload of a registered [`DataType`].
+ */
+typedef struct CustomData CustomData;
+
 /**
  * Represents a discrete price level in an order book.
  *
@@ -837,6 +842,16 @@ typedef struct OrderBookDeltas_API {
     struct OrderBookDeltas_t *_0;
 } OrderBookDeltas_API;
 
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`CustomData`].
+ *
+ * This struct wraps `CustomData` in a way that makes it compatible with C function
+ * calls, enabling interaction with `CustomData` in a C environment.
+ */
+typedef struct CustomData_API {
+    struct CustomData *_0;
+} CustomData_API;
+
 /**
  * Represents a aggregated order book update with a fixed depth of 10 levels per side.
  *
@@ -1003,6 +1018,18 @@ typedef struct BarType_t {
      * The bar types aggregation source.
      */
     enum AggregationSource aggregation_source;
+    /**
+     * The step of the source bars for a composite bar type (zero for a standard bar type).
+     */
+    uintptr_t composite_step;
+    /**
+     * The aggregation of the source bars for a composite bar type.
+     */
+    enum BarAggregation composite_aggregation;
+    /**
+     * The aggregation source of the source bars for a composite bar type.
+     */
+    enum AggregationSource composite_aggregation_source;
 } BarType_t;
 
 /**
@@ -1043,6 +1070,152 @@ typedef struct Bar_t {
     uint64_t ts_init;
 } Bar_t;
 
+/**
+ * Represents a funding rate update for a perpetual contract instrument.
+ */
+typedef struct FundingRateUpdate_t {
+    /**
+     * The instrument ID for the funding rate.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The funding rate for the interval (e.g. 0.0001 for 0.01%).
+     */
+    double rate;
+    /**
+     * The UNIX timestamp (nanoseconds) of the next funding, zero if unknown.
+     */
+    uint64_t next_funding_ns;
+    /**
+     * The UNIX timestamp (nanoseconds) when the funding rate event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * The UNIX timestamp (nanoseconds) when the struct was initialized.
+     */
+    uint64_t ts_init;
+} FundingRateUpdate_t;
+
+typedef struct Currency_t {
+    char* code;
+    uint8_t precision;
+    uint16_t iso4217;
+    char* name;
+    enum CurrencyType currency_type;
+} Currency_t;
+
+/**
+ * Represents a borrow (margin lending) rate update for a currency at a venue.
+ */
+typedef struct BorrowRateUpdate_t {
+    /**
+     * The venue for the borrow rate.
+     */
+    struct Venue_t venue;
+    /**
+     * The borrowed currency.
+     */
+    struct Currency_t currency;
+    /**
+     * The annualized borrow rate (e.g. 0.05 for 5% per year).
+     */
+    double rate;
+    /**
+     * The UNIX timestamp (nanoseconds) when the borrow rate event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * The UNIX timestamp (nanoseconds) when the struct was initialized.
+     */
+    uint64_t ts_init;
+} BorrowRateUpdate_t;
+
+/**
+ * Represents a market status change for an instrument.
+ */
+typedef struct InstrumentStatus_t {
+    /**
+     * The instrument ID for the status change.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The market status for the instrument.
+     */
+    enum MarketStatus status;
+    /**
+     * The reason for a halt, or `NotHalted`.
+     */
+    enum HaltReason halt_reason;
+    /**
+     * The UNIX timestamp (nanoseconds) when the status event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * The UNIX timestamp (nanoseconds) when the struct was initialized.
+     */
+    uint64_t ts_init;
+} InstrumentStatus_t;
+
+/**
+ * Represents a market status change for an entire venue.
+ */
+typedef struct VenueStatus_t {
+    /**
+     * The venue for the status change.
+     */
+    struct Venue_t venue;
+    /**
+     * The market status for the venue.
+     */
+    enum MarketStatus status;
+    /**
+     * The reason for a halt, or `NotHalted`.
+     */
+    enum HaltReason halt_reason;
+    /**
+     * The UNIX timestamp (nanoseconds) when the status event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * The UNIX timestamp (nanoseconds) when the struct was initialized.
+     */
+    uint64_t ts_init;
+} VenueStatus_t;
+
+/**
+ * Represents an auction imbalance for an instrument.
+ */
+typedef struct AuctionImbalance_t {
+    /**
+     * The instrument ID for the auction.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The indicative price at which the auction would currently match.
+     */
+    struct Price_t indicative_price;
+    /**
+     * The quantity matched at the indicative price.
+     */
+    struct Quantity_t paired_qty;
+    /**
+     * The unmatched quantity at the indicative price.
+     */
+    struct Quantity_t imbalance_qty;
+    /**
+     * The side of the unmatched quantity, or `NoOrderSide` if balanced.
+     */
+    enum OrderSide imbalance_side;
+    /**
+     * The UNIX timestamp (nanoseconds) when the imbalance event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * The UNIX timestamp (nanoseconds) when the struct was initialized.
+     */
+    uint64_t ts_init;
+} AuctionImbalance_t;
+
 /**
  * A built-in Nautilus data type.
  *
@@ -1056,6 +1229,12 @@ typedef enum Data_t_Tag {
     QUOTE,
     TRADE,
     BAR,
+    FUNDING_RATE,
+    BORROW_RATE,
+    INSTRUMENT_STATUS,
+    VENUE_STATUS,
+    AUCTION_IMBALANCE,
+    CUSTOM,
 } Data_t_Tag;
 
 typedef struct Data_t {
@@ -1079,6 +1258,24 @@ typedef struct Data_t {
         struct {
             struct Bar_t bar;
         };
+        struct {
+            struct FundingRateUpdate_t funding_rate;
+        };
+        struct {
+            struct BorrowRateUpdate_t borrow_rate;
+        };
+        struct {
+            struct InstrumentStatus_t instrument_status;
+        };
+        struct {
+            struct VenueStatus_t venue_status;
+        };
+        struct {
+            struct AuctionImbalance_t auction_imbalance;
+        };
+        struct {
+            struct CustomData_API custom;
+        };
     };
 } Data_t;
 
@@ -1264,14 +1461,6 @@ typedef struct Level_API {
     struct Level *_0;
 } Level_API;
 
-typedef struct Currency_t {
-    char* code;
-    uint8_t precision;
-    uint16_t iso4217;
-    char* name;
-    enum CurrencyType currency_type;
-} Currency_t;
-
 typedef struct Money_t {
     int64_t raw;
     struct Currency_t currency;
@@ -7,7 +7,7 @@ from collections.abc import Callable
 from decimal import Decimal
 from enum import Enum
 from os import PathLike
-from typing import Any, TypeAlias, Union
+from typing import Any, Literal, TypeAlias, Union
 
 from nautilus_trader.core.data import Data
 
@@ -191,6 +191,51 @@ def convert_to_snake_case(s: str) -> str:
 
     """
 
+
+def interned_string_stats() -> dict[str, int]:
+    """
+    Return the interned string cache metrics.
+
+    Returns
+    -------
+    dict[str, int]
+        The `num_entries`, `total_allocated` and `total_capacity` (bytes) of the cache.
+
+    """
+
+
+def largest_interned_strings(n: int) -> list[str]:
+    """
+    Return the `n` largest interned strings, in descending order of length.
+
+    Parameters
+    ----------
+    n : int
+        The maximum number of strings to return.
+
+    Returns
+    -------
+    list[str]
+
+    """
+
+
+def pre_intern(values: list[str]) -> int:
+    """
+    Intern the given values (e.g. a known symbol universe at startup).
+
+    Parameters
+    ----------
+    values : list[str]
+        The values to intern.
+
+    Returns
+    -------
+    int
+        The number of newly interned strings.
+
+    """
+
 ###################################################################################################
 # Common
 ###################################################################################################
@@ -231,6 +276,8 @@ def log_header(
 
 def log_sysinfo(component: str) -> None: ...
 
+def set_log_levels(spec: str) -> None: ...
+
 ###################################################################################################
 # Model
 ###################################################################################################
@@ -451,6 +498,9 @@ class BarType:
     def aggregation_source(self) -> AggregationSource: ...
     @classmethod
     def from_str(cls, value: str) -> BarType: ...
+    def is_composite(self) -> bool: ...
+    def standard(self) -> BarType: ...
+    def composite_source(self) -> BarType | None: ...
 
 class Bar:
     def __init__(
@@ -819,6 +869,11 @@ class LogColor(Enum):
     YELLOW = "YELLOW"
     RED = "RED"
 
+class ErrorSeverity(Enum):
+    WARNING = "WARNING"
+    ERROR = "ERROR"
+    CRITICAL = "CRITICAL"
+
 ### Identifiers
 
 class AccountId:
@@ -2250,6 +2305,9 @@ class OrderBook:
     def spread(self) -> float | None: ...
     def midpoint(self) -> float | None: ...
     def get_avg_px_for_quantity(self, qty: Quantity, order_side: OrderSide) -> float: ...
+    def get_vwap_midpoint(self, qty: Quantity) -> float | None: ...
+    def imbalance(self, depth: int) -> float | None: ...
+    def microprice(self) -> float | None: ...
     def get_quantity_for_price(self, price: Price, order_side: OrderSide) -> float: ...
     def simulate_fills(self, order: BookOrder) -> list[tuple[Price, Quantity]]: ...
     def pprint(self, num_levels: int) -> str: ...
@@ -2271,6 +2329,43 @@ class RedisMessageBusDatabase:
     def publish(self, topic: str, payload: bytes) -> None: ...
     def close(self) -> None: ...
 
+class RedisLeaderLease:
+    def __init__(
+        self,
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config_json: bytes,
+    ) -> None: ...
+    @property
+    def is_leader(self) -> bool: ...
+    @property
+    def term(self) -> int: ...
+    def holder(self) -> str | None: ...
+    def poll(self) -> int | None: ...
+    def release(self) -> None: ...
+
+class NatsMessageBusDatabase:
+    def __init__(
+        self,
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config_json: bytes,
+    ) -> None: ...
+    def publish(self, topic: str, payload: bytes) -> None: ...
+    def close(self) -> None: ...
+
+class ZmqMessageBusDatabase:
+    def __init__(
+        self,
+        trader_id: TraderId,
+        instance_id: UUID4,
+        config_json: bytes,
+    ) -> None: ...
+    @property
+    def endpoint(self) -> str: ...
+    def publish(self, topic: str, payload: bytes) -> None: ...
+    def close(self) -> None: ...
+
 class RedisCacheDatabase:
     def __init__(
         self,
@@ -2313,6 +2408,8 @@ class HttpClient:
         header_keys: list[str] = [],
         keyed_quotas: list[tuple[str, Quota]] = [],
         default_quota: Quota | None = None,
+        retry_policy: HttpRetryPolicy | None = None,
+        proxy: ProxyConfig | None = None,
     ) -> None: ...
     async def request(
         self,
@@ -2321,7 +2418,31 @@ class HttpClient:
         headers: dict[str, str] | None = None,
         body: bytes | None = None,
         keys: list[str] | None = None,
+        weight: int | None = None,
+        timeout_secs: int | None = None,
     ) -> HttpResponse: ...
+    async def stream(
+        self,
+        method: HttpMethod,
+        url: str,
+        headers: dict[str, str] | None = None,
+        body: bytes | None = None,
+        keys: list[str] | None = None,
+        weight: int | None = None,
+        timeout_secs: int | None = None,
+    ) -> HttpResponseStream: ...
+
+class HttpRetryPolicy:
+    def __init__(
+        self,
+        max_retries: int = 3,
+        retry_status_codes: list[int] | None = None,
+        retry_on_connect_error: bool = True,
+        delay_initial_ms: int = 100,
+        delay_max_ms: int = 5_000,
+        backoff_factor: float = 2.0,
+        idempotency_header: str | None = None,
+    ) -> None: ...
 
 class HttpMethod(Enum):
     GET = "GET"
@@ -2338,6 +2459,26 @@ class HttpResponse:
     @property
     def headers(self) -> dict[str, str]: ...
 
+class HttpResponseStream:
+    @property
+    def status(self) -> int: ...
+    @property
+    def headers(self) -> dict[str, str]: ...
+    def __aiter__(self) -> HttpResponseStream: ...
+    async def __anext__(self) -> bytes: ...
+
+class ProxyConfig:
+    def __init__(
+        self,
+        url: str,
+        username: str | None = None,
+        password: str | None = None,
+    ) -> None: ...
+    @property
+    def url(self) -> str: ...
+
+def set_default_proxy(proxy: ProxyConfig | None) -> None: ...
+
 class Quota:
     @classmethod
     def rate_per_second(cls, max_burst: int) -> Quota: ...
@@ -2355,6 +2496,14 @@ class WebSocketConfig:
         heartbeat: int | None = None,
         heartbeat_msg: str | None = None,
         ping_handler: Callable[..., Any] | None = None,
+        reconnect_delay_initial_ms: int | None = None,
+        reconnect_delay_max_ms: int | None = None,
+        reconnect_backoff_factor: float | None = None,
+        reconnect_max_attempts: int | None = None,
+        proxy: ProxyConfig | None = None,
+        compression: bool | None = None,
+        tls: TlsConfig | None = None,
+        latency: LatencyConfig | None = None,
     ) -> None: ...
 
 class WebSocketClient:
@@ -2365,12 +2514,32 @@ class WebSocketClient:
         post_connection: Callable[..., None] | None = None,
         post_reconnection: Callable[..., None] | None = None,
         post_disconnection: Callable[..., None] | None = None,
+        keyed_quotas: list[tuple[str, Quota]] = [],
+        default_quota: Quota | None = None,
     ) -> Awaitable[WebSocketClient]: ...
     def disconnect(self) -> Any: ...
     @property
     def is_alive(self) -> bool: ...
-    def send(self, data: bytes) -> Awaitable[None]: ...
-    def send_text(self, data: str) -> Awaitable[None]: ...
+    @property
+    def reconnect_attempts(self) -> int: ...
+    @property
+    def compressed_messages(self) -> int: ...
+    @property
+    def compressed_bytes(self) -> int: ...
+    @property
+    def decompressed_bytes(self) -> int: ...
+    def send(
+        self,
+        data: bytes,
+        keys: list[str] | None = None,
+        weight: int | None = None,
+    ) -> Awaitable[None]: ...
+    def send_text(
+        self,
+        data: str,
+        keys: list[str] | None = None,
+        weight: int | None = None,
+    ) -> Awaitable[None]: ...
     def send_pong(self, data: bytes) -> Awaitable[None]: ...
 
 class SocketClient:
@@ -2385,6 +2554,8 @@ class SocketClient:
     def disconnect(self) -> None: ...
     @property
     def is_alive(self) -> bool: ...
+    @property
+    def reconnect_attempts(self) -> int: ...
     def send(self, data: bytes) -> Awaitable[None]: ...
 
 class SocketConfig:
@@ -2395,6 +2566,32 @@ class SocketConfig:
         suffix: bytes,
         handler: Callable[..., Any],
         heartbeat: tuple[int, list[int]] | None = None,
+        reconnect_delay_initial_ms: int | None = None,
+        reconnect_delay_max_ms: int | None = None,
+        reconnect_backoff_factor: float | None = None,
+        reconnect_max_attempts: int | None = None,
+        max_buffer_size: int | None = None,
+        overflow_policy: Literal["disconnect", "drop_oldest", "callback"] | None = None,
+        overflow_handler: Callable[[bytes], Any] | None = None,
+        proxy: ProxyConfig | None = None,
+        tls: TlsConfig | None = None,
+        latency: LatencyConfig | None = None,
+    ) -> None: ...
+
+class TlsConfig:
+    def __init__(
+        self,
+        ca_certs: list[str] | None = None,
+        pinned_fingerprints: list[str] | None = None,
+        native_roots: bool = True,
+    ) -> None: ...
+
+class LatencyConfig:
+    def __init__(
+        self,
+        inbound: str | None = None,
+        outbound: str | None = None,
+        seed: int = 42,
     ) -> None: ...
 
 ###################################################################################################
@@ -2402,11 +2599,16 @@ class SocketConfig:
 ###################################################################################################
 
 class NautilusDataType(Enum):
+    CustomData = 0
     OrderBookDelta = 1
     OrderBookDepth10 = 2
     QuoteTick = 3
     TradeTick = 4
     Bar = 5
+    FundingRateUpdate = 6
+    InstrumentStatus = 7
+    VenueStatus = 8
+    AuctionImbalance = 9
 
 class DataBackendSession:
     def __init__(self, chunk_size: int = 5000) -> None: ...
@@ -7,6 +7,10 @@ from nautilus_trader.core.rust.core cimport CVec, UUID4_t
 
 cdef extern from "../includes/backtest.h":
 
+    # Provides progress reports for a backtest run at a fixed interval of processed events.
+    cdef struct ProgressReporter:
+        pass
+
     # Provides a means of accumulating and draining time event handlers.
     cdef struct TimeEventAccumulator:
         pass
@@ -14,6 +18,9 @@ cdef extern from "../includes/backtest.h":
     cdef struct TimeEventAccumulatorAPI:
         TimeEventAccumulator *_0;
 
+    cdef struct ProgressReporterAPI:
+        ProgressReporter *_0;
+
     TimeEventAccumulatorAPI time_event_accumulator_new();
 
     void time_event_accumulator_drop(TimeEventAccumulatorAPI accumulator);
@@ -24,3 +31,19 @@ cdef extern from "../includes/backtest.h":
                                               uint8_t set_time);
 
     CVec time_event_accumulator_drain(TimeEventAccumulatorAPI *accumulator);
+
+    # A zero `report_interval` is treated as a report on every event.
+    ProgressReporterAPI progress_reporter_new(uint64_t total, uint64_t report_interval);
+
+    void progress_reporter_drop(ProgressReporterAPI reporter);
+
+    uint8_t progress_reporter_on_event(ProgressReporterAPI *reporter, uint64_t ts_sim);
+
+    uint64_t progress_reporter_processed(const ProgressReporterAPI *reporter);
+
+    double progress_reporter_percent(const ProgressReporterAPI *reporter);
+
+    double progress_reporter_events_per_sec(const ProgressReporterAPI *reporter);
+
+    # Returns the estimated seconds remaining, or NaN if no rate has been established.
+    double progress_reporter_eta_secs(const ProgressReporterAPI *reporter);
@@ -371,6 +371,10 @@ cdef extern from "../includes/model.h":
         # Based on the index price for the instrument.
         INDEX_PRICE # = 9,
 
+    # Represents a user-defined data payload of a registered [`DataType`].
+    cdef struct CustomData:
+        pass
+
     # Represents a discrete price level in an order book.
     #
     # The level maintains a collection of orders as well as tracking insertion order
@@ -462,6 +466,13 @@ cdef extern from "../includes/model.h":
     cdef struct OrderBookDeltas_API:
         OrderBookDeltas_t *_0;
 
+    # C compatible Foreign Function Interface (FFI) for an underlying [`CustomData`].
+    #
+    # This struct wraps `CustomData` in a way that makes it compatible with C function
+    # calls, enabling interaction with `CustomData` in a C environment.
+    cdef struct CustomData_API:
+        CustomData *_0;
+
     # Represents a aggregated order book update with a fixed depth of 10 levels per side.
     #
     # This structure is specifically designed for scenarios where a snapshot of the top 10 bid and
@@ -551,6 +562,12 @@ cdef extern from "../includes/model.h":
         BarSpecification_t spec;
         # The bar types aggregation source.
         AggregationSource aggregation_source;
+        # The step of the source bars for a composite bar type (zero for a standard bar type).
+        uintptr_t composite_step;
+        # The aggregation of the source bars for a composite bar type.
+        BarAggregation composite_aggregation;
+        # The aggregation source of the source bars for a composite bar type.
+        AggregationSource composite_aggregation_source;
 
     # Represents an aggregated bar.
     cdef struct Bar_t:
@@ -571,6 +588,82 @@ cdef extern from "../includes/model.h":
         # The UNIX timestamp (nanoseconds) when the struct was initialized.
         uint64_t ts_init;
 
+    # Represents a funding rate update for a perpetual contract instrument.
+    cdef struct FundingRateUpdate_t:
+        # The instrument ID for the funding rate.
+        InstrumentId_t instrument_id;
+        # The funding rate for the interval (e.g. 0.0001 for 0.01%).
+        double rate;
+        # The UNIX timestamp (nanoseconds) of the next funding, zero if unknown.
+        uint64_t next_funding_ns;
+        # The UNIX timestamp (nanoseconds) when the funding rate event occurred.
+        uint64_t ts_event;
+        # The UNIX timestamp (nanoseconds) when the struct was initialized.
+        uint64_t ts_init;
+
+    cdef struct Currency_t:
+        char* code;
+        uint8_t precision;
+        uint16_t iso4217;
+        char* name;
+        CurrencyType currency_type;
+
+    # Represents a borrow (margin lending) rate update for a currency at a venue.
+    cdef struct BorrowRateUpdate_t:
+        # The venue for the borrow rate.
+        Venue_t venue;
+        # The borrowed currency.
+        Currency_t currency;
+        # The annualized borrow rate (e.g. 0.05 for 5% per year).
+        double rate;
+        # The UNIX timestamp (nanoseconds) when the borrow rate event occurred.
+        uint64_t ts_event;
+        # The UNIX timestamp (nanoseconds) when the struct was initialized.
+        uint64_t ts_init;
+
+    # Represents a market status change for an instrument.
+    cdef struct InstrumentStatus_t:
+        # The instrument ID for the status change.
+        InstrumentId_t instrument_id;
+        # The market status for the instrument.
+        MarketStatus status;
+        # The reason for a halt, or `NotHalted`.
+        HaltReason halt_reason;
+        # The UNIX timestamp (nanoseconds) when the status event occurred.
+        uint64_t ts_event;
+        # The UNIX timestamp (nanoseconds) when the struct was initialized.
+        uint64_t ts_init;
+
+    # Represents a market status change for an entire venue.
+    cdef struct VenueStatus_t:
+        # The venue for the status change.
+        Venue_t venue;
+        # The market status for the venue.
+        MarketStatus status;
+        # The reason for a halt, or `NotHalted`.
+        HaltReason halt_reason;
+        # The UNIX timestamp (nanoseconds) when the status event occurred.
+        uint64_t ts_event;
+        # The UNIX timestamp (nanoseconds) when the struct was initialized.
+        uint64_t ts_init;
+
+    # Represents an auction imbalance for an instrument.
+    cdef struct AuctionImbalance_t:
+        # The instrument ID for the auction.
+        InstrumentId_t instrument_id;
+        # The indicative price at which the auction would currently match.
+        Price_t indicative_price;
+        # The quantity matched at the indicative price.
+        Quantity_t paired_qty;
+        # The unmatched quantity at the indicative price.
+        Quantity_t imbalance_qty;
+        # The side of the unmatched quantity, or `NoOrderSide` if balanced.
+        OrderSide imbalance_side;
+        # The UNIX timestamp (nanoseconds) when the imbalance event occurred.
+        uint64_t ts_event;
+        # The UNIX timestamp (nanoseconds) when the struct was initialized.
+        uint64_t ts_init;
+
     # A built-in Nautilus data type.
     #
     # Not recommended for storing large amounts of data, as the largest variant is significantly
@@ -582,6 +675,12 @@ cdef extern from "../includes/model.h":
         QUOTE,
         TRADE,
         BAR,
+        FUNDING_RATE,
+        BORROW_RATE,
+        INSTRUMENT_STATUS,
+        VENUE_STATUS,
+        AUCTION_IMBALANCE,
+        CUSTOM,
 
     cdef struct Data_t:
         Data_t_Tag tag;
@@ -591,6 +690,12 @@ cdef extern from "../includes/model.h":
         QuoteTick_t quote;
         TradeTick_t trade;
         Bar_t bar;
+        FundingRateUpdate_t funding_rate;
+        BorrowRateUpdate_t borrow_rate;
+        InstrumentStatus_t instrument_status;
+        VenueStatus_t venue_status;
+        AuctionImbalance_t auction_imbalance;
+        CustomData_API custom;
 
     # Represents a valid trader ID.
     cdef struct TraderId_t:
@@ -729,13 +834,6 @@ cdef extern from "../includes/model.h":
     cdef struct Level_API:
         Level *_0;
 
-    cdef struct Currency_t:
-        char* code;
-        uint8_t precision;
-        uint16_t iso4217;
-        char* name;
-        CurrencyType currency_type;
-
     cdef struct Money_t:
         int64_t raw;
         Currency_t currency;
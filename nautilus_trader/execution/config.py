@@ -20,6 +20,7 @@ from typing import Any
 import msgspec
 
 from nautilus_trader.common.config import NautilusConfig
+from nautilus_trader.common.config import PositiveInt
 from nautilus_trader.common.config import msgspec_encoding_hook
 from nautilus_trader.common.config import resolve_config_path
 from nautilus_trader.common.config import resolve_path
@@ -35,12 +36,20 @@ class ExecEngineConfig(NautilusConfig, frozen=True):
     ----------
     load_cache : bool, default True
         If the cache should be loaded on initialization.
+    coalesce_modifies : bool, default False
+        If modify commands for an order with a modify already in flight are coalesced,
+        so that only the latest pending modify is sent once the venue responds.
+    modify_timeout_ms : PositiveInt, default 10_000
+        The timeout (milliseconds) to await a response to an in-flight modify, after which
+        any held modify is sent regardless.
     debug : bool, default False
         If debug mode is active (will provide extra debug logging).
 
     """
 
     load_cache: bool = True
+    coalesce_modifies: bool = False
+    modify_timeout_ms: PositiveInt = 10_000
     debug: bool = False
 
 
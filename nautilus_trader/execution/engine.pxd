@@ -13,6 +13,8 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from libc.stdint cimport uint64_t
+
 from nautilus_trader.cache.cache cimport Cache
 from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.generators cimport PositionIdGenerator
@@ -30,6 +32,7 @@ from nautilus_trader.execution.messages cimport SubmitOrderList
 from nautilus_trader.execution.messages cimport TradingCommand
 from nautilus_trader.model.events.order cimport OrderEvent
 from nautilus_trader.model.events.order cimport OrderFilled
+from nautilus_trader.model.identifiers cimport ClientOrderId
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport PositionId
 from nautilus_trader.model.identifiers cimport StrategyId
@@ -50,9 +53,16 @@ cdef class ExecutionEngine(Component):
     cdef readonly dict[Venue, ExecutionClient] _routing_map
     cdef readonly dict[StrategyId, OmsType] _oms_overrides
     cdef readonly dict[InstrumentId, StrategyId] _external_order_claims
+    cdef readonly dict _modifies_in_flight
+    cdef readonly dict _pending_modifies
 
     cdef readonly bint debug
     """If debug mode is active (will provide extra debug logging).\n\n:returns: `bool`"""
+    cdef readonly bint coalesce_modifies
+    """If modify commands are coalesced while a modify is in flight for the order.\n\n:returns: `bool`"""
+    cdef uint64_t _modify_timeout_ns
+    cdef readonly int coalesced_modify_count
+    """The total count of modify commands superseded by a later modify.\n\n:returns: `int`"""
     cdef readonly int command_count
     """The total count of commands received by the engine.\n\n:returns: `int`"""
     cdef readonly int event_count
@@ -106,6 +116,10 @@ cdef class ExecutionEngine(Component):
     cpdef void _handle_cancel_all_orders(self, ExecutionClient client, CancelAllOrders command)
     cpdef void _handle_batch_cancel_orders(self, ExecutionClient client, BatchCancelOrders command)
     cpdef void _handle_query_order(self, ExecutionClient client, QueryOrder command)
+    cpdef ModifyOrder _coalesce_modify(self, ModifyOrder superseded, ModifyOrder command)
+    cpdef ModifyOrder _refresh_modify(self, ModifyOrder command, Order order)
+    cpdef void _release_modify(self, ClientOrderId client_order_id)
+    cpdef void _check_modifies_in_flight(self)
 
 # -- EVENT HANDLERS -------------------------------------------------------------------------------
 
@@ -115,6 +129,7 @@ cdef class ExecutionEngine(Component):
     cpdef PositionId _determine_hedging_position_id(self, OrderFilled fill)
     cpdef PositionId _determine_netting_position_id(self, OrderFilled fill)
     cpdef void _apply_event_to_order(self, Order order, OrderEvent event)
+    cpdef void _handle_modify_response(self, Order order, OrderEvent event)
     cpdef void _handle_order_fill(self, Order order, OrderFilled fill, OmsType oms_type)
     cpdef Position _open_position(self, Instrument instrument, Position position, OrderFilled fill, OmsType oms_type)
     cpdef void _update_position(self, Instrument instrument, Position position, OrderFilled fill, OmsType oms_type)
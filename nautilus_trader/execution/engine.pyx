@@ -52,6 +52,7 @@ from nautilus_trader.common.component cimport MessageBus
 from nautilus_trader.common.generators cimport PositionIdGenerator
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.fsm cimport InvalidStateTrigger
+from nautilus_trader.core.rust.core cimport millis_to_nanos
 from nautilus_trader.core.rust.model cimport ContingencyType
 from nautilus_trader.core.rust.model cimport OmsType
 from nautilus_trader.core.rust.model cimport PositionSide
@@ -70,6 +71,8 @@ from nautilus_trader.model.data cimport TradeTick
 from nautilus_trader.model.events.order cimport OrderDenied
 from nautilus_trader.model.events.order cimport OrderEvent
 from nautilus_trader.model.events.order cimport OrderFilled
+from nautilus_trader.model.events.order cimport OrderModifyRejected
+from nautilus_trader.model.events.order cimport OrderUpdated
 from nautilus_trader.model.events.position cimport PositionChanged
 from nautilus_trader.model.events.position cimport PositionClosed
 from nautilus_trader.model.events.position cimport PositionEvent
@@ -137,6 +140,8 @@ cdef class ExecutionEngine(Component):
         self._default_client: ExecutionClient | None = None
         self._oms_overrides: dict[StrategyId, OmsType] = {}
         self._external_order_claims: dict[InstrumentId, StrategyId] = {}
+        self._modifies_in_flight: dict[ClientOrderId, uint64_t] = {}
+        self._pending_modifies: dict[ClientOrderId, tuple[ExecutionClient, ModifyOrder]] = {}
 
         self._pos_id_generator: PositionIdGenerator = PositionIdGenerator(
             trader_id=msgbus.trader_id,
@@ -145,11 +150,14 @@ cdef class ExecutionEngine(Component):
 
         # Settings
         self.debug: bool = config.debug
+        self.coalesce_modifies: bool = config.coalesce_modifies
+        self._modify_timeout_ns: uint64_t = millis_to_nanos(config.modify_timeout_ms)
 
         # Counters
         self.command_count: int = 0
         self.event_count: int = 0
         self.report_count: int = 0
+        self.coalesced_modify_count: int = 0
 
         # Register endpoints
         self._msgbus.register(endpoint="ExecEngine.execute", handler=self.execute)
@@ -551,10 +559,13 @@ cdef class ExecutionEngine(Component):
 
         self._cache.reset()
         self._pos_id_generator.reset()
+        self._modifies_in_flight.clear()
+        self._pending_modifies.clear()
 
         self.command_count = 0
         self.event_count = 0
         self.report_count = 0
+        self.coalesced_modify_count = 0
 
     cpdef void _dispose(self):
         for client in self._clients.values():
@@ -823,6 +834,28 @@ cdef class ExecutionEngine(Component):
         client.submit_order_list(command)
 
     cpdef void _handle_modify_order(self, ExecutionClient client, ModifyOrder command):
+        if not self.coalesce_modifies:
+            client.modify_order(command)
+            return
+
+        cdef ClientOrderId client_order_id = command.client_order_id
+        cdef uint64_t ts_now = self._clock.timestamp_ns()
+        cdef tuple pending = self._pending_modifies.get(client_order_id)
+        if pending is not None:
+            command = self._coalesce_modify(pending[1], command)
+            self.coalesced_modify_count += 1
+
+        if client_order_id in self._modifies_in_flight:
+            if ts_now < self._modifies_in_flight[client_order_id] + self._modify_timeout_ns:
+                # Hold the command until the venue responds to the in-flight modify
+                self._pending_modifies[client_order_id] = (client, command)
+                if self.debug:
+                    self._log.debug(f"Pending {command} (modify in flight)", LogColor.MAGENTA)
+                return
+            self._log.warning(f"Timed out awaiting modify response for {client_order_id!r}")
+
+        self._pending_modifies.pop(client_order_id, None)
+        self._modifies_in_flight[client_order_id] = ts_now
         client.modify_order(command)
 
     cpdef void _handle_cancel_order(self, ExecutionClient client, CancelOrder command):
@@ -837,6 +870,63 @@ cdef class ExecutionEngine(Component):
     cpdef void _handle_query_order(self, ExecutionClient client, QueryOrder command):
         client.query_order(command)
 
+    cpdef ModifyOrder _coalesce_modify(self, ModifyOrder superseded, ModifyOrder command):
+        # The latest command wins, retaining any amendments only made by the superseded command
+        return ModifyOrder(
+            trader_id=command.trader_id,
+            strategy_id=command.strategy_id,
+            instrument_id=command.instrument_id,
+            client_order_id=command.client_order_id,
+            venue_order_id=command.venue_order_id or superseded.venue_order_id,
+            quantity=command.quantity or superseded.quantity,
+            price=command.price or superseded.price,
+            trigger_price=command.trigger_price or superseded.trigger_price,
+            command_id=command.id,
+            ts_init=command.ts_init,
+            client_id=command.client_id,
+        )
+
+    cpdef ModifyOrder _refresh_modify(self, ModifyOrder command, Order order):
+        # Cancel-replace venues assign a new venue order ID on each modify
+        if order.venue_order_id is None or order.venue_order_id == command.venue_order_id:
+            return command
+        return ModifyOrder(
+            trader_id=command.trader_id,
+            strategy_id=command.strategy_id,
+            instrument_id=command.instrument_id,
+            client_order_id=command.client_order_id,
+            venue_order_id=order.venue_order_id,
+            quantity=command.quantity,
+            price=command.price,
+            trigger_price=command.trigger_price,
+            command_id=command.id,
+            ts_init=command.ts_init,
+            client_id=command.client_id,
+        )
+
+    cpdef void _release_modify(self, ClientOrderId client_order_id):
+        # Sends any held modify against the latest cached order state
+        self._modifies_in_flight.pop(client_order_id, None)
+        cdef tuple pending = self._pending_modifies.pop(client_order_id, None)
+        if pending is None:
+            return
+
+        cdef Order order = self._cache.order(client_order_id)
+        if order is None or order.is_closed_c():
+            self._log.debug(f"Dropped {pending[1]} (order closed)")
+            return
+
+        self._handle_modify_order(pending[0], self._refresh_modify(pending[1], order))
+
+    cpdef void _check_modifies_in_flight(self):
+        cdef uint64_t ts_now = self._clock.timestamp_ns()
+        cdef ClientOrderId client_order_id
+        cdef uint64_t ts_sent
+        for client_order_id, ts_sent in list(self._modifies_in_flight.items()):
+            if ts_now >= ts_sent + self._modify_timeout_ns:
+                self._log.warning(f"Timed out awaiting modify response for {client_order_id!r}")
+                self._release_modify(client_order_id)
+
 # -- EVENT HANDLERS -------------------------------------------------------------------------------
 
     cpdef void _handle_event(self, OrderEvent event):
@@ -897,6 +987,9 @@ cdef class ExecutionEngine(Component):
         else:
             self._apply_event_to_order(order, event)
 
+        if client_order_id in self._modifies_in_flight:
+            self._handle_modify_response(order, event)
+
     cpdef OmsType _determine_oms_type(self, OrderFilled fill):
         cdef ExecutionClient client
         # Check for strategy OMS override
@@ -1022,6 +1115,12 @@ cdef class ExecutionEngine(Component):
         if self._msgbus.has_backing and self._msgbus.snapshot_orders:
             self._publish_order_snapshot(order)
 
+    cpdef void _handle_modify_response(self, Order order, OrderEvent event):
+        if not order.is_closed_c() and not isinstance(event, (OrderUpdated, OrderModifyRejected)):
+            return  # Modify still in flight
+
+        self._release_modify(order.client_order_id)
+
     cpdef void _handle_order_fill(self, Order order, OrderFilled fill, OmsType oms_type):
         cdef Instrument instrument = self._cache.load_instrument(fill.instrument_id)
         if instrument is None:
@@ -19,6 +19,7 @@ import msgspec
 
 from nautilus_trader.common import Environment
 from nautilus_trader.common.config import ActorConfig
+from nautilus_trader.common.config import DatabaseConfig
 from nautilus_trader.common.config import InstrumentProviderConfig
 from nautilus_trader.common.config import NautilusConfig
 from nautilus_trader.common.config import NonNegativeInt
@@ -43,10 +44,15 @@ class LiveDataEngineConfig(DataEngineConfig, frozen=True):
     ----------
     qsize : PositiveInt, default 100_000
         The queue size for the engines internal queue buffers.
+    prioritize_commands : bool, default True
+        If pending commands and order events on the priority lanes (the data command queue,
+        and the live risk and execution engine queues) are processed ahead of any data
+        backlog, bounding command latency during data bursts.
 
     """
 
     qsize: PositiveInt = 100_000
+    prioritize_commands: bool = True
 
 
 class LiveRiskEngineConfig(RiskEngineConfig, frozen=True):
@@ -187,6 +193,37 @@ class ControllerFactory:
         return controller_cls(config=config, trader=trader)
 
 
+class LeaderElectionConfig(NautilusConfig, frozen=True):
+    """
+    Configuration for high-availability leader election between ``TradingNode`` instances.
+
+    Nodes with the same trader ID compete for a Redis based lease. The leader node trades,
+    while a standby node keeps its cache warm from the shared cache database and takes over
+    once the leader stops renewing the lease.
+
+    Parameters
+    ----------
+    database : DatabaseConfig
+        The configuration for the Redis database holding the lease.
+    lease_ttl_ms : PositiveInt, default 5000
+        The lease time-to-live (milliseconds), after which a standby node may take over.
+    poll_interval_ms : PositiveInt, default 1000
+        The interval (milliseconds) between lease renewals or acquisition attempts.
+        Must be less than `lease_ttl_ms`.
+    cache_refresh_interval_ms : PositiveInt, default 30000
+        The interval (milliseconds) between cache reloads from the database while standby.
+    use_trader_prefix : bool, default True
+        If a 'trader-' prefix is used for the lease keys.
+
+    """
+
+    database: DatabaseConfig
+    lease_ttl_ms: PositiveInt = 5_000
+    poll_interval_ms: PositiveInt = 1_000
+    cache_refresh_interval_ms: PositiveInt = 30_000
+    use_trader_prefix: bool = True
+
+
 class TradingNodeConfig(NautilusKernelConfig, frozen=True):
     """
     Configuration for ``TradingNode`` instances.
@@ -209,6 +246,8 @@ class TradingNodeConfig(NautilusKernelConfig, frozen=True):
         The execution client configurations.
     heartbeat_interval : PositiveFloat, optional
         The heartbeat interval (seconds) to use for trading node health.
+    leader_election : LeaderElectionConfig, optional
+        The leader election configuration, if the node should run in high-availability mode.
 
     """
 
@@ -220,3 +259,4 @@ class TradingNodeConfig(NautilusKernelConfig, frozen=True):
     data_clients: dict[str, LiveDataClientConfig] = {}
     exec_clients: dict[str, LiveExecClientConfig] = {}
     heartbeat_interval: PositiveFloat | None = None
+    leader_election: LeaderElectionConfig | None = None
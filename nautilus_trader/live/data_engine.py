@@ -15,6 +15,7 @@
 
 import asyncio
 from asyncio import Queue
+from collections.abc import Callable
 from typing import Final
 
 from nautilus_trader.cache.cache import Cache
@@ -79,6 +80,13 @@ class LiveDataEngine(DataEngine):
         self._res_queue: asyncio.Queue = Queue(maxsize=config.qsize)
         self._data_queue: asyncio.Queue = Queue(maxsize=config.qsize)
 
+        # Priority lanes (queue sizes) processed ahead of the data queue
+        self._prioritize_commands: bool = config.prioritize_commands
+        self._priority_lanes: list[Callable[[], int]] = [self.cmd_qsize]
+
+        # Counters
+        self.priority_yield_count: int = 0
+
         # Async tasks
         self._cmd_queue_task: asyncio.Task | None = None
         self._req_queue_task: asyncio.Task | None = None
@@ -156,6 +164,24 @@ class LiveDataEngine(DataEngine):
         """
         return self._data_queue_task
 
+    def register_priority_lane(self, qsize: Callable[[], int]) -> None:
+        """
+        Register the given queue as a priority lane, to be processed ahead of any data
+        backlog.
+
+        While any priority lane has pending messages, the data queue yields to the
+        event loop before processing each data item.
+
+        Parameters
+        ----------
+        qsize : Callable[[], int]
+            The callable returning the current size of the queue.
+
+        """
+        PyCondition.callable(qsize, "qsize")
+
+        self._priority_lanes.append(qsize)
+
     def cmd_qsize(self) -> int:
         """
         Return the number of `DataCommand` objects buffered on the internal queue.
@@ -350,6 +376,9 @@ class LiveDataEngine(DataEngine):
 
     # -- INTERNAL -------------------------------------------------------------------------------------
 
+    def _is_priority_pending(self) -> bool:
+        return any(qsize() > 0 for qsize in self._priority_lanes)
+
     def _enqueue_sentinels(self) -> None:
         self._loop.call_soon_threadsafe(self._cmd_queue.put_nowait, self._sentinel)
         self._loop.call_soon_threadsafe(self._req_queue.put_nowait, self._sentinel)
@@ -445,6 +474,10 @@ class LiveDataEngine(DataEngine):
         self._log.debug(f"Data queue processing starting (qsize={self.data_qsize()})")
         try:
             while True:
+                if self._prioritize_commands and self._is_priority_pending():
+                    # Yield so the priority lanes are processed ahead of the data backlog
+                    self.priority_yield_count += 1
+                    await asyncio.sleep(0)
                 data: Data | None = await self._data_queue.get()
                 if data is self._sentinel:
                     break
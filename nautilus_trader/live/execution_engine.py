@@ -409,6 +409,8 @@ class LiveExecutionEngine(ExecutionEngine):
     async def _check_inflight_orders(self) -> None:
         self._log.debug("Checking in-flight orders status...")
 
+        self._check_modifies_in_flight()
+
         inflight_orders: list[Order] = self._cache.orders_inflight()
         inflight_len = len(inflight_orders)
         self._log.debug(f"Found {inflight_len} order{'' if inflight_len == 1 else 's'} in-flight")
@@ -498,6 +500,7 @@ class LiveExecutionEngine(ExecutionEngine):
 
         if isinstance(report, OrderStatusReport):
             result = self._reconcile_order_report(report, [])  # No trades to reconcile
+            self._release_modify(report.client_order_id)
         elif isinstance(report, FillReport):
             result = self._reconcile_fill_report_single(report)
         elif isinstance(report, PositionStatusReport):
@@ -572,6 +575,7 @@ class LiveExecutionEngine(ExecutionEngine):
             except InvalidStateTrigger as e:
                 self._log.error(str(e))
                 result = False
+            self._release_modify(order_report.client_order_id)
             results.append(result)
             reconciled_orders.add(order_report.client_order_id)
 
@@ -18,10 +18,13 @@ import signal
 import time
 from datetime import timedelta
 
+import msgspec
+
 from nautilus_trader.cache.base import CacheFacade
 from nautilus_trader.common.component import Logger
 from nautilus_trader.common.enums import LogColor
 from nautilus_trader.config import TradingNodeConfig
+from nautilus_trader.core import nautilus_pyo3
 from nautilus_trader.core.correctness import PyCondition
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.live.factories import LiveDataClientFactory
@@ -88,9 +91,32 @@ class TradingNode:
         self.kernel.logger.info(f"{self._has_cache_backing=}", LogColor.BLUE)
         self.kernel.logger.info(f"{self._has_msgbus_backing=}", LogColor.BLUE)
 
+        # High-availability leader election
+        self._lease: nautilus_pyo3.RedisLeaderLease | None = None
+        if config.leader_election:
+            election = config.leader_election
+            PyCondition.true(
+                self._has_cache_backing,
+                "a cache database is required for leader election",
+            )
+            PyCondition.true(
+                config.exec_engine.reconciliation,
+                "execution reconciliation is required for leader election",
+            )
+            PyCondition.true(
+                election.poll_interval_ms < election.lease_ttl_ms,
+                "`poll_interval_ms` was not less than `lease_ttl_ms`",
+            )
+            self._lease = nautilus_pyo3.RedisLeaderLease(
+                trader_id=nautilus_pyo3.TraderId(self.trader_id.value),
+                instance_id=nautilus_pyo3.UUID4(self.instance_id.value),
+                config_json=msgspec.json.encode(election),
+            )
+
         # Async tasks
         self._task_heartbeats: asyncio.Task | None = None
         self._task_position_snapshots: asyncio.Task | None = None
+        self._task_leader_lease: asyncio.Task | None = None
 
     @property
     def trader_id(self) -> TraderId:
@@ -287,6 +313,10 @@ class TradingNode:
                 )
 
             self._is_running = True
+
+            if self._lease is not None:
+                await self.await_leadership()
+
             await self.kernel.start_async()
 
             if self.kernel.loop.is_running():
@@ -315,10 +345,85 @@ class TradingNode:
                     self.snapshot_open_positions(self._config.snapshot_positions_interval),
                 )
 
+            if self._lease is not None:
+                self._task_leader_lease = asyncio.create_task(
+                    self.maintain_leader_lease(self._config.leader_election.poll_interval_ms),
+                )
+
             await asyncio.gather(*tasks)
         except asyncio.CancelledError as e:
             self.kernel.logger.error(str(e))
 
+    async def await_leadership(self) -> None:
+        """
+        Wait in standby until the leader lease is acquired.
+
+        While standby the cache is periodically reloaded from the database, and is reloaded
+        once more on promotion so the node takes over with the final state of the previous
+        leader. Any in-flight orders inherited are then resolved by execution reconciliation
+        on start, before strategies are able to submit orders.
+
+        """
+        assert self._lease is not None  # Type checking
+        election = self._config.leader_election
+        self.kernel.logger.info("Standby: awaiting leader lease", LogColor.BLUE)
+
+        ts_last_refresh = time.monotonic()
+        while self._lease.poll() is None:
+            await asyncio.sleep(election.poll_interval_ms / 1000)
+            if (time.monotonic() - ts_last_refresh) * 1000 >= election.cache_refresh_interval_ms:
+                self.kernel.exec_engine.load_cache()
+                ts_last_refresh = time.monotonic()
+
+        self.kernel.logger.warning(f"Promoted to leader (term {self._lease.term})")
+        self.kernel.exec_engine.load_cache()
+
+        inflight_orders = self.kernel.cache.orders_inflight()
+        if inflight_orders:
+            self.kernel.logger.warning(
+                f"Inherited {len(inflight_orders)} in-flight order(s) to reconcile: "
+                f"{[o.client_order_id.value for o in inflight_orders]}",
+            )
+
+    async def maintain_leader_lease(self, interval_ms: int) -> None:
+        """
+        Renew the leader lease at the given `interval_ms` while the node is running.
+
+        If the lease is lost then the trader is stopped immediately, so no further orders
+        are submitted, and the node is stopped.
+
+        Parameters
+        ----------
+        interval_ms : int
+            The interval (milliseconds) between lease renewals.
+
+        """
+        assert self._lease is not None  # Type checking
+        self.kernel.logger.info(
+            f"Starting task: leader lease renewals at {interval_ms}ms intervals",
+            LogColor.BLUE,
+        )
+        try:
+            while True:
+                await asyncio.sleep(interval_ms / 1000)
+                try:
+                    self._lease.poll()
+                except RuntimeError as e:
+                    self.kernel.logger.error(f"Error renewing leader lease: {e}")
+                    continue
+                if not self._lease.is_leader:
+                    self.kernel.logger.error("Leader lease lost, stopping trader")
+                    if self.kernel.trader.is_running:
+                        self.kernel.trader.stop()
+                    self._task_leader_lease = None
+                    await self.stop_async()
+                    return
+        except asyncio.CancelledError:
+            pass
+        except Exception as e:
+            # Catch-all exceptions for development purposes (unexpected errors)
+            self.kernel.logger.error(str(e))
+
     async def maintain_heartbeat(self, interval: float) -> None:
         """
         Maintain heartbeats at the given `interval` while the node is running.
@@ -423,8 +528,16 @@ class TradingNode:
             self._task_position_snapshots.cancel()
             self._task_position_snapshots = None
 
+        if self._task_leader_lease:
+            self.kernel.logger.info("Cancelling `task_leader_lease` task")
+            self._task_leader_lease.cancel()
+            self._task_leader_lease = None
+
         await self.kernel.stop_async()
 
+        if self._lease is not None:
+            self._lease.release()
+
         self._is_running = False
 
     def dispose(self) -> None:
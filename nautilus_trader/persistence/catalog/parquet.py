@@ -490,6 +490,57 @@ class ParquetDataCatalog(BaseDataCatalog):
 
         return data
 
+    def query_iter(
+        self,
+        data_cls: type,
+        instrument_ids: list[str] | None = None,
+        bar_types: list[str] | None = None,
+        start: TimestampLike | None = None,
+        end: TimestampLike | None = None,
+        where: str | None = None,
+        batch_size_bytes: int | None = None,
+        **kwargs: Any,
+    ) -> Generator[Data, None, None]:
+        """
+        Return a generator of the data for the given query, ordered by `ts_init`.
+
+        Data types supported by the Rust backend are streamed in batches of approximately
+        `batch_size_bytes`, so the full result never needs to be held in memory. Other data
+        types fall back to a single in-memory `query`.
+
+        """
+        if self.fs_protocol == "file" and data_cls in (
+            OrderBookDelta,
+            OrderBookDepth10,
+            QuoteTick,
+            TradeTick,
+            Bar,
+        ):
+            session = self.backend_session(
+                data_cls=data_cls,
+                instrument_ids=instrument_ids,
+                bar_types=bar_types,
+                start=start,
+                end=end,
+                where=where,
+                session=(
+                    DataBackendSession(chunk_size=batch_size_bytes) if batch_size_bytes else None
+                ),
+                **kwargs,
+            )
+            for chunk in session.to_query_result():
+                yield from capsule_to_list(chunk)
+        else:
+            yield from self.query(
+                data_cls=data_cls,
+                instrument_ids=instrument_ids,
+                bar_types=bar_types,
+                start=start,
+                end=end,
+                where=where,
+                **kwargs,
+            )
+
     def query_pyarrow(
         self,
         data_cls: type,
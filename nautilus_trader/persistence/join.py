@@ -0,0 +1,228 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+"""
+Provides as-of joins of time-ordered data streams for research.
+"""
+
+from __future__ import annotations
+
+from collections import deque
+from collections.abc import Callable
+from collections.abc import Hashable
+from collections.abc import Iterable
+from collections.abc import Iterator
+from typing import Any, Literal, NamedTuple
+
+from nautilus_trader.core.correctness import PyCondition
+
+
+AsOfDirection = Literal["backward", "forward", "nearest"]
+
+_EXHAUSTED = object()
+
+
+class AsOfRecord(NamedTuple):
+    """
+    Represents a record from the left stream joined with its as-of match from the right stream.
+    """
+
+    left: Any
+    right: Any | None
+    delta_ns: int | None  # `right` timestamp minus `left` timestamp (if matched)
+
+
+class _TimeOrderChecker:
+    def __init__(self, name: str, ts_attr: str) -> None:
+        self._name = name
+        self._ts_attr = ts_attr
+        self._last_ts = -1
+
+    def ts(self, item: Any) -> int:
+        ts = getattr(item, self._ts_attr)
+        if ts < self._last_ts:
+            raise ValueError(
+                f"`{self._name}` stream not ordered by `{self._ts_attr}`, "
+                f"{ts} was less than previous {self._last_ts}",
+            )
+        self._last_ts = ts
+        return ts
+
+
+def asof_join(
+    left: Iterable[Any],
+    right: Iterable[Any],
+    tolerance_ns: int | None = None,
+    direction: AsOfDirection = "backward",
+    by: Callable[[Any], Hashable] | None = None,
+    ts_attr: str = "ts_init",
+) -> Iterator[AsOfRecord]:
+    """
+    Join each item of the `left` stream to the `right` item nearest in time.
+
+    Both streams must be ordered by `ts_attr`, and are consumed lazily, so the join can be
+    run over catalog query generators for datasets larger than memory
+    (see `ParquetDataCatalog.query_iter`). Every `left` item produces exactly one record,
+    where `right` is ``None`` if there is no match within the tolerance.
+
+    With a `forward` or `nearest` direction, `right` items are buffered ahead of the current
+    `left` timestamp only as far as needed to find a match (bounded by `tolerance_ns`).
+
+    Parameters
+    ----------
+    left : Iterable[Any]
+        The stream to join onto (e.g. fills or signals).
+    right : Iterable[Any]
+        The stream to match from (e.g. quotes or bars).
+    tolerance_ns : int, optional
+        The maximum time difference (nanoseconds) for a match (unlimited if ``None``).
+    direction : {'backward', 'forward', 'nearest'}, default 'backward'
+        The direction to search for a match, 'backward' matches the last `right` item at or
+        before the `left` item (the as-of value), 'forward' the first at or after, and
+        'nearest' the closest of both (preferring 'backward' when equal).
+    by : Callable[[Any], Hashable], optional
+        The function returning the key which items must share to match (e.g. the
+        instrument ID), applied to items of both streams.
+    ts_attr : str, default 'ts_init'
+        The timestamp attribute of items to join on.
+
+    Yields
+    ------
+    AsOfRecord
+
+    Raises
+    ------
+    ValueError
+        If `tolerance_ns` is negative.
+    ValueError
+        If `direction` is not a valid direction.
+    ValueError
+        If either stream is not ordered by `ts_attr` (raised when consumed).
+
+    Examples
+    --------
+    >>> fills = catalog.query_iter(OrderFilled)  # doctest: +SKIP
+    >>> quotes = catalog.query_iter(QuoteTick, instrument_ids=["EUR/USD.SIM"])  # doctest: +SKIP
+    >>> for record in asof_join(fills, quotes, tolerance_ns=1_000_000_000):  # doctest: +SKIP
+    ...     print(record.left.last_px, record.right)
+
+    """
+    if tolerance_ns is not None:
+        PyCondition.not_negative_int(tolerance_ns, "tolerance_ns")
+    PyCondition.is_in(direction, ("backward", "forward", "nearest"), "direction", "directions")
+
+    return _asof_join(
+        left=left,
+        right=right,
+        tolerance_ns=tolerance_ns,
+        direction=direction,
+        by=by or (lambda _: None),
+        ts_attr=ts_attr,
+    )
+
+
+def _asof_join(
+    left: Iterable[Any],
+    right: Iterable[Any],
+    tolerance_ns: int | None,
+    direction: AsOfDirection,
+    by: Callable[[Any], Hashable],
+    ts_attr: str,
+) -> Iterator[AsOfRecord]:
+    left_order = _TimeOrderChecker("left", ts_attr)
+    right_order = _TimeOrderChecker("right", ts_attr)
+    right_iter = iter(right)
+
+    # The last `right` item at or before the current `left` timestamp, per key
+    last: dict[Hashable, tuple[int, Any]] = {}
+    # The `right` items read ahead of the current `left` timestamp, in time order
+    ahead: deque[tuple[int, Any]] = deque()
+    exhausted = False
+
+    def read_next() -> bool:
+        nonlocal exhausted
+        if exhausted:
+            return False
+        item = next(right_iter, _EXHAUSTED)
+        if item is _EXHAUSTED:
+            exhausted = True
+            return False
+        ahead.append((right_order.ts(item), item))
+        return True
+
+    for item in left:
+        ts = left_order.ts(item)
+        key = by(item)
+
+        # Advance `right` up to the current timestamp
+        while ahead or read_next():
+            if ahead[0][0] > ts:
+                break
+            right_ts, right_item = ahead.popleft()
+            last[by(right_item)] = (right_ts, right_item)
+
+        backward = last.get(key) if direction != "forward" else None
+
+        forward = None
+        if direction != "backward":
+            candidate = last.get(key)
+            if candidate is not None and candidate[0] == ts:
+                forward = candidate
+            else:
+                forward = _find_ahead(ahead, key, by, ts, tolerance_ns, read_next)
+
+        match = _select(ts, backward, forward, tolerance_ns)
+        if match is None:
+            yield AsOfRecord(item, None, None)
+        else:
+            yield AsOfRecord(item, match[1], match[0] - ts)
+
+
+def _find_ahead(
+    ahead: deque[tuple[int, Any]],
+    key: Hashable,
+    by: Callable[[Any], Hashable],
+    ts: int,
+    tolerance_ns: int | None,
+    read_next: Callable[[], bool],
+) -> tuple[int, Any] | None:
+    index = 0
+    while index < len(ahead) or read_next():
+        right_ts, right_item = ahead[index]
+        if tolerance_ns is not None and right_ts - ts > tolerance_ns:
+            return None
+        if by(right_item) == key:
+            return right_ts, right_item
+        index += 1
+    return None
+
+
+def _select(
+    ts: int,
+    backward: tuple[int, Any] | None,
+    forward: tuple[int, Any] | None,
+    tolerance_ns: int | None,
+) -> tuple[int, Any] | None:
+    if tolerance_ns is not None:
+        if backward is not None and ts - backward[0] > tolerance_ns:
+            backward = None
+        if forward is not None and forward[0] - ts > tolerance_ns:
+            forward = None
+
+    if backward is None:
+        return forward
+    if forward is None:
+        return backward
+    return backward if ts - backward[0] <= forward[0] - ts else forward
@@ -0,0 +1,330 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+"""
+Provides a read-only view of a live trader's cache and portfolio for analytics.
+"""
+
+from __future__ import annotations
+
+from decimal import Decimal
+
+import msgspec
+
+from nautilus_trader.cache.cache import Cache
+from nautilus_trader.cache.config import CacheConfig
+from nautilus_trader.cache.database import CacheDatabaseAdapter
+from nautilus_trader.cache.facade import CacheDatabaseFacade
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import Logger
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import StrategyId
+from nautilus_trader.model.identifiers import TraderId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.orders import Order
+from nautilus_trader.model.position import Position
+from nautilus_trader.portfolio.portfolio import Portfolio
+from nautilus_trader.serialization.serializer import MsgSpecSerializer
+
+
+class PortfolioView:
+    """
+    Provides a read-only view of a trader's cache and portfolio.
+
+    The view hydrates its own `Cache` and `Portfolio` from the given database, and
+    serves analytics queries (open orders, positions, exposures) without issuing any
+    writes. This allows a secondary process, such as an ops dashboard, to attach to a
+    live trader's cache alongside the running node.
+
+    Parameters
+    ----------
+    trader_id : TraderId
+        The trader ID for the view.
+    database : CacheDatabaseFacade
+        The cache database to hydrate the view from.
+    clock : LiveClock, optional
+        The clock for the view.
+
+    Warnings
+    --------
+    The view is a snapshot as of the last call to `refresh`, market prices are not
+    persisted to the cache database so exposures require prices to be provided with
+    `update_quote_tick` or `update_trade_tick`.
+
+    """
+
+    def __init__(
+        self,
+        trader_id: TraderId,
+        database: CacheDatabaseFacade,
+        clock: LiveClock | None = None,
+    ) -> None:
+        PyCondition.type(trader_id, TraderId, "trader_id")
+        PyCondition.type(database, CacheDatabaseFacade, "database")
+
+        self._log = Logger(name=type(self).__name__)
+        self._clock = clock or LiveClock()
+        self._database = database
+        self._msgbus = MessageBus(trader_id=trader_id, clock=self._clock)
+        self._cache = Cache(database=database)
+        self._portfolio = Portfolio(
+            msgbus=self._msgbus,
+            cache=self._cache,
+            clock=self._clock,
+        )
+
+        self.trader_id = trader_id
+
+    @classmethod
+    def attach(
+        cls,
+        trader_id: TraderId,
+        config: CacheConfig,
+        instance_id: UUID4 | None = None,
+    ) -> PortfolioView:
+        """
+        Attach a view to the Redis cache database of a live trader.
+
+        The database adapter is always created read-only and is never flushed,
+        regardless of the given `config`.
+
+        Parameters
+        ----------
+        trader_id : TraderId
+            The trader ID of the live trader.
+        config : CacheConfig
+            The cache configuration of the live trader.
+        instance_id : UUID4, optional
+            The instance ID of the live trader (required if `config.use_instance_id`).
+
+        Returns
+        -------
+        PortfolioView
+
+        Raises
+        ------
+        ValueError
+            If `config.database` is ``None``.
+        ValueError
+            If `config.use_instance_id` and `instance_id` is ``None``.
+
+        """
+        PyCondition.type(config, CacheConfig, "config")
+        PyCondition.not_none(config.database, "config.database")
+        if config.use_instance_id:
+            PyCondition.not_none(instance_id, "instance_id")
+
+        config = msgspec.structs.replace(config, read_only=True, flush_on_start=False)
+        database = CacheDatabaseAdapter(
+            trader_id=trader_id,
+            instance_id=instance_id or UUID4(),
+            serializer=MsgSpecSerializer(
+                encoding=msgspec.msgpack if config.encoding == "msgpack" else msgspec.json,
+                timestamps_as_str=True,  # Hardcoded for now
+                timestamps_as_iso8601=config.timestamps_as_iso8601,
+            ),
+            config=config,
+        )
+
+        view = cls(trader_id=trader_id, database=database)
+        view.refresh()
+        return view
+
+    @property
+    def cache(self) -> Cache:
+        """
+        Return the cache for the view.
+
+        Returns
+        -------
+        Cache
+
+        """
+        return self._cache
+
+    @property
+    def portfolio(self) -> Portfolio:
+        """
+        Return the portfolio for the view.
+
+        Returns
+        -------
+        Portfolio
+
+        """
+        return self._portfolio
+
+    def refresh(self) -> None:
+        """
+        Reload the view from the cache database.
+
+        Any market prices previously provided are retained.
+        """
+        self._cache.cache_general()
+        self._cache.cache_currencies()
+        self._cache.cache_instruments()
+        self._cache.cache_accounts()
+        self._cache.cache_orders()
+        self._cache.cache_order_lists()
+        self._cache.cache_positions()
+        self._cache.build_index()
+
+        self._portfolio.initialize_orders()
+        self._portfolio.initialize_positions()
+
+        self._log.info(
+            f"Refreshed view with {self._cache.orders_open_count()} open orders, "
+            f"{self._cache.positions_open_count()} open positions",
+        )
+
+    def close(self) -> None:
+        """
+        Close the underlying cache database.
+        """
+        self._database.close()
+
+    def update_quote_tick(self, tick: QuoteTick) -> None:
+        """
+        Update the view with the given quote tick (used for marking exposures).
+
+        Parameters
+        ----------
+        tick : QuoteTick
+            The tick for the update.
+
+        """
+        self._cache.add_quote_tick(tick)
+        self._portfolio.update_quote_tick(tick)
+
+    def update_trade_tick(self, tick: TradeTick) -> None:
+        """
+        Update the view with the given trade tick (used for marking exposures).
+
+        Parameters
+        ----------
+        tick : TradeTick
+            The tick for the update.
+
+        """
+        self._cache.add_trade_tick(tick)
+
+    def open_orders(
+        self,
+        venue: Venue | None = None,
+        instrument_id: InstrumentId | None = None,
+        strategy_id: StrategyId | None = None,
+        side: OrderSide = OrderSide.NO_ORDER_SIDE,
+    ) -> list[Order]:
+        """
+        Return all open orders with the given query filters.
+
+        Parameters
+        ----------
+        venue : Venue, optional
+            The venue ID query filter.
+        instrument_id : InstrumentId, optional
+            The instrument ID query filter.
+        strategy_id : StrategyId, optional
+            The strategy ID query filter.
+        side : OrderSide, default ``NO_ORDER_SIDE`` (no filter)
+            The order side query filter.
+
+        Returns
+        -------
+        list[Order]
+
+        """
+        return self._cache.orders_open(venue, instrument_id, strategy_id, side)
+
+    def open_positions(
+        self,
+        venue: Venue | None = None,
+        instrument_id: InstrumentId | None = None,
+        strategy_id: StrategyId | None = None,
+    ) -> list[Position]:
+        """
+        Return all open positions with the given query filters.
+
+        Parameters
+        ----------
+        venue : Venue, optional
+            The venue ID query filter.
+        instrument_id : InstrumentId, optional
+            The instrument ID query filter.
+        strategy_id : StrategyId, optional
+            The strategy ID query filter.
+
+        Returns
+        -------
+        list[Position]
+
+        """
+        return self._cache.positions_open(venue, instrument_id, strategy_id)
+
+    def net_position(self, instrument_id: InstrumentId) -> Decimal:
+        """
+        Return the total net position for the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument for the query.
+
+        Returns
+        -------
+        Decimal
+
+        """
+        return self._portfolio.net_position(instrument_id)
+
+    def net_exposure(self, instrument_id: InstrumentId) -> Money | None:
+        """
+        Return the net exposure for the given instrument (if calculable).
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument for the calculation.
+
+        Returns
+        -------
+        Money or ``None``
+
+        """
+        return self._portfolio.net_exposure(instrument_id)
+
+    def net_exposures(self, venue: Venue) -> dict[Currency, Money] | None:
+        """
+        Return the net exposures for the given venue (if calculable).
+
+        Parameters
+        ----------
+        venue : Venue
+            The venue for the calculation.
+
+        Returns
+        -------
+        dict[Currency, Money] or ``None``
+
+        """
+        return self._portfolio.net_exposures(venue)
@@ -33,6 +33,20 @@ class RiskEngineConfig(NautilusConfig, frozen=True):
     max_notional_per_order : dict[str, int], default empty dict
         The maximum notional value of an order per instrument ID.
         The value should be a valid decimal format.
+    news_blackout_instruments : list[str], default empty list
+        The instrument IDs for which order flow is restricted during windows around
+        scheduled economic news events affecting the instruments currencies.
+    news_blackout_impacts : list[str], default ['HIGH']
+        The news impact levels which trigger a blackout ('LOW', 'MEDIUM', 'HIGH').
+    news_blackout_before_secs : int, default 300
+        The duration (seconds) of the blackout window before a news event.
+    news_blackout_after_secs : int, default 300
+        The duration (seconds) of the blackout window after a news event.
+    news_blackout_mode : str, default 'BLOCK'
+        The blackout mode, 'BLOCK' denies all new orders, 'REDUCE' only allows new
+        orders which reduce an open position.
+    news_blackout_exempt_strategies : list[str], default empty list
+        The strategy IDs which are exempt from news blackouts.
     debug : bool, default False
         If debug mode is active (will provide extra debug logging).
 
@@ -42,4 +56,10 @@ class RiskEngineConfig(NautilusConfig, frozen=True):
     max_order_submit_rate: str = "100/00:00:01"
     max_order_modify_rate: str = "100/00:00:01"
     max_notional_per_order: dict[str, int] = {}
+    news_blackout_instruments: list[str] = []
+    news_blackout_impacts: list[str] = ["HIGH"]
+    news_blackout_before_secs: int = 300
+    news_blackout_after_secs: int = 300
+    news_blackout_mode: str = "BLOCK"
+    news_blackout_exempt_strategies: list[str] = []
     debug: bool = False
@@ -15,6 +15,8 @@
 
 from decimal import Decimal
 
+from libc.stdint cimport uint64_t
+
 from nautilus_trader.cache.cache cimport Cache
 from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.component cimport Throttler
@@ -28,6 +30,7 @@ from nautilus_trader.execution.messages cimport SubmitOrder
 from nautilus_trader.execution.messages cimport SubmitOrderList
 from nautilus_trader.execution.messages cimport TradingCommand
 from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
@@ -42,6 +45,13 @@ cdef class RiskEngine(Component):
     cdef readonly dict _max_notional_per_order
     cdef readonly Throttler _order_submit_throttler
     cdef readonly Throttler _order_modify_throttler
+    cdef readonly set _news_blackout_instruments
+    cdef readonly set _news_blackout_impacts
+    cdef readonly set _news_blackout_exempt_strategies
+    cdef readonly list _news_events
+    cdef readonly uint64_t _news_blackout_before_ns
+    cdef readonly uint64_t _news_blackout_after_ns
+    cdef readonly bint _news_blackout_reduce_only
 
     cdef readonly TradingState trading_state
     """The current trading state for the engine.\n\n:returns: `TradingState`"""
@@ -60,6 +70,8 @@ cdef class RiskEngine(Component):
     cpdef void process(self, Event event)
     cpdef void set_trading_state(self, TradingState state)
     cpdef void set_max_notional_per_order(self, InstrumentId instrument_id, new_value: Decimal)
+    cpdef void set_news_blackout_exempt(self, StrategyId strategy_id, bint exempt)
+    cpdef void add_news_event(self, event)
     cpdef void _log_state(self)
 
 # -- RISK SETTINGS --------------------------------------------------------------------------------
@@ -68,6 +80,7 @@ cdef class RiskEngine(Component):
     cpdef tuple max_order_modify_rate(self)
     cpdef dict max_notionals_per_order(self)
     cpdef object max_notional_per_order(self, InstrumentId instrument_id)
+    cpdef object news_blackout_event(self, Instrument instrument, uint64_t ts)
 
 # -- ABSTRACT METHODS -----------------------------------------------------------------------------
 
@@ -87,6 +100,7 @@ cdef class RiskEngine(Component):
     cpdef bint _check_order_price(self, Instrument instrument, Order order)
     cpdef bint _check_order_quantity(self, Instrument instrument, Order order)
     cpdef bint _check_orders_risk(self, Instrument instrument, list orders)
+    cpdef bint _check_news_blackout(self, Instrument instrument, TradingCommand command)
     cpdef str _check_price(self, Instrument instrument, Price price)
     cpdef str _check_quantity(self, Instrument instrument, Quantity quantity)
 
@@ -17,7 +17,9 @@ from decimal import Decimal
 
 import pandas as pd
 
+from nautilus_trader.core.datetime import secs_to_nanos
 from nautilus_trader.risk.config import RiskEngineConfig
+from nautilus_trader.trading.filters import NewsImpact
 
 from libc.stdint cimport uint64_t
 
@@ -58,6 +60,7 @@ from nautilus_trader.model.functions cimport order_type_to_str
 from nautilus_trader.model.functions cimport trading_state_to_str
 from nautilus_trader.model.identifiers cimport ComponentId
 from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.instruments.currency_pair cimport CurrencyPair
 from nautilus_trader.model.objects cimport Currency
@@ -83,6 +86,10 @@ cdef class RiskEngine(Component):
      - ``REDUCING`` (only new orders or updates which reduce an open position are allowed).
      - ``HALTED`` (all trading commands except cancels are denied).
 
+    New orders for the configured news blackout instruments are also restricted
+    during windows around scheduled economic news events (``NewsEvent`` data)
+    for the instruments base or quote currency, unless the strategy is exempt.
+
     Parameters
     ----------
     portfolio : PortfolioFacade
@@ -172,6 +179,13 @@ cdef class RiskEngine(Component):
 
         # Risk settings
         self._max_notional_per_order: dict[InstrumentId, Decimal] = {}
+        self._news_blackout_instruments: set[InstrumentId] = set()
+        self._news_blackout_impacts: set[NewsImpact] = set()
+        self._news_blackout_exempt_strategies: set[StrategyId] = set()
+        self._news_events: list = []  # Scheduled news events in timestamp order
+        self._news_blackout_before_ns = 0
+        self._news_blackout_after_ns = 0
+        self._news_blackout_reduce_only = False
 
         # Configure
         self._initialize_risk_checks(config)
@@ -184,11 +198,36 @@ cdef class RiskEngine(Component):
         self._msgbus.subscribe(topic="events.order.*", handler=self._handle_event, priority=10)
         self._msgbus.subscribe(topic="events.position.*", handler=self._handle_event, priority=10)
 
+        if self._news_blackout_instruments:
+            self._msgbus.subscribe(topic="data.NewsEvent*", handler=self.add_news_event)
+
     def _initialize_risk_checks(self, config: RiskEngineConfig):
         cdef dict max_notional_config = config.max_notional_per_order
         for instrument_id, value in max_notional_config.items():
             self.set_max_notional_per_order(InstrumentId.from_str_c(instrument_id), Decimal(value))
 
+        Condition.is_in(config.news_blackout_mode, ("BLOCK", "REDUCE"), "news_blackout_mode", "modes")
+        Condition.not_negative_int(config.news_blackout_before_secs, "news_blackout_before_secs")
+        Condition.not_negative_int(config.news_blackout_after_secs, "news_blackout_after_secs")
+        self._news_blackout_instruments = {
+            InstrumentId.from_str_c(instrument_id)
+            for instrument_id in config.news_blackout_instruments
+        }
+        self._news_blackout_impacts = {NewsImpact[impact] for impact in config.news_blackout_impacts}
+        self._news_blackout_before_ns = secs_to_nanos(config.news_blackout_before_secs)
+        self._news_blackout_after_ns = secs_to_nanos(config.news_blackout_after_secs)
+        self._news_blackout_reduce_only = config.news_blackout_mode == "REDUCE"
+        for strategy_id in config.news_blackout_exempt_strategies:
+            self.set_news_blackout_exempt(StrategyId(strategy_id), True)
+
+        if self._news_blackout_instruments:
+            self._log.info(
+                f"Set NEWS_BLACKOUT {config.news_blackout_mode}: "
+                f"{sorted(str(i) for i in self._news_blackout_instruments)} "
+                f"-{config.news_blackout_before_secs}s/+{config.news_blackout_after_secs}s",
+                color=LogColor.BLUE,
+            )
+
 # -- COMMANDS -------------------------------------------------------------------------------------
 
     cpdef void execute(self, Command command):
@@ -304,6 +343,56 @@ cdef class RiskEngine(Component):
             color=LogColor.BLUE,
         )
 
+    cpdef void set_news_blackout_exempt(self, StrategyId strategy_id, bint exempt):
+        """
+        Set whether the given strategy is exempt from news blackouts.
+
+        Parameters
+        ----------
+        strategy_id : StrategyId
+            The strategy ID for the override.
+        exempt : bool
+            If the strategy is exempt from news blackouts.
+
+        """
+        Condition.not_none(strategy_id, "strategy_id")
+
+        if exempt:
+            self._news_blackout_exempt_strategies.add(strategy_id)
+        else:
+            self._news_blackout_exempt_strategies.discard(strategy_id)
+
+        self._log.info(
+            f"Set NEWS_BLACKOUT_EXEMPT: {strategy_id} {exempt}",
+            color=LogColor.BLUE,
+        )
+
+    cpdef void add_news_event(self, event):
+        """
+        Add the given scheduled economic news event.
+
+        Events with an impact level which does not trigger a blackout are ignored.
+
+        Parameters
+        ----------
+        event : NewsEvent
+            The scheduled news event to add.
+
+        """
+        Condition.not_none(event, "event")
+
+        if event.impact not in self._news_blackout_impacts:
+            return
+
+        # Discard events whose blackout window has already closed
+        cdef uint64_t ts_now = self._clock.timestamp_ns()
+        self._news_events = [
+            e for e in self._news_events
+            if e.ts_event + self._news_blackout_after_ns >= ts_now
+        ]
+        self._news_events.append(event)
+        self._news_events.sort(key=lambda e: e.ts_event)
+
 # -- RISK SETTINGS --------------------------------------------------------------------------------
 
     cpdef tuple max_order_submit_rate(self):
@@ -358,6 +447,39 @@ cdef class RiskEngine(Component):
         """
         return self._max_notional_per_order.get(instrument_id)
 
+    cpdef object news_blackout_event(self, Instrument instrument, uint64_t ts):
+        """
+        Return the news event whose blackout window for the given instrument
+        contains the given timestamp (if found).
+
+        Parameters
+        ----------
+        instrument : Instrument
+            The instrument for the blackout.
+        ts : uint64_t
+            The UNIX timestamp (nanoseconds) to check.
+
+        Returns
+        -------
+        NewsEvent or ``None``
+
+        """
+        Condition.not_none(instrument, "instrument")
+
+        if instrument.id not in self._news_blackout_instruments:
+            return None
+
+        cdef tuple currencies = (instrument.get_base_currency(), instrument.quote_currency)
+        for event in self._news_events:
+            if ts + self._news_blackout_before_ns < event.ts_event:
+                break  # Events are in timestamp order
+            if ts > event.ts_event + self._news_blackout_after_ns:
+                continue
+            if event.currency in currencies:
+                return event
+
+        return None
+
 # -- ABSTRACT METHODS -----------------------------------------------------------------------------
 
     cpdef void _on_start(self):
@@ -381,6 +503,7 @@ cdef class RiskEngine(Component):
         self.event_count = 0
         self._order_submit_throttler.reset()
         self._order_modify_throttler.reset()
+        self._news_events.clear()
 
     cpdef void _dispose(self):
         pass
@@ -769,6 +892,37 @@ cdef class RiskEngine(Component):
         # Finally
         return True  # Passed
 
+    cpdef bint _check_news_blackout(self, Instrument instrument, TradingCommand command):
+        if command.strategy_id in self._news_blackout_exempt_strategies:
+            return True  # Passed
+
+        event = self.news_blackout_event(instrument, self._clock.timestamp_ns())
+        if event is None:
+            return True  # Passed
+
+        cdef list orders
+        if isinstance(command, SubmitOrder):
+            orders = [command.order]
+        elif isinstance(command, SubmitOrderList):
+            orders = command.order_list.orders
+        else:  # pragma: no cover (design-time error)
+            raise RuntimeError(f"Cannot check news blackout for command {command}")  # pragma: no cover (design-time error)
+
+        cdef Order order
+        for order in orders:
+            if self._news_blackout_reduce_only:
+                if order.is_buy_c() and self._portfolio.is_net_short(instrument.id):
+                    continue  # Reduces position
+                if order.is_sell_c() and self._portfolio.is_net_long(instrument.id):
+                    continue  # Reduces position
+            self._deny_command(
+                command=command,
+                reason=f"NEWS_BLACKOUT: {event.impact.name} {event.name} ({event.currency})",
+            )
+            return False  # Denied
+
+        return True  # Passed
+
     cpdef str _check_price(self, Instrument instrument, Price price):
         if price is None:
             # Nothing to check
@@ -901,6 +1055,10 @@ cdef class RiskEngine(Component):
                         )
                         return  # Denied
 
+        # Check news blackout
+        if not self._check_news_blackout(instrument, command):
+            return  # Denied
+
         # All checks passed: send to ORDER_RATE throttler
         self._order_submit_throttler.send(command)
 
@@ -247,10 +247,22 @@ class NautilusKernel:
                 instance_id=nautilus_pyo3.UUID4(self._instance_id.value),
                 config_json=msgspec.json.encode(config.message_bus),
             )
+        elif config.message_bus.database.type == "nats":
+            msgbus_db = nautilus_pyo3.NatsMessageBusDatabase(
+                trader_id=nautilus_pyo3.TraderId(self._trader_id.value),
+                instance_id=nautilus_pyo3.UUID4(self._instance_id.value),
+                config_json=msgspec.json.encode(config.message_bus),
+            )
+        elif config.message_bus.database.type == "zmq":
+            msgbus_db = nautilus_pyo3.ZmqMessageBusDatabase(
+                trader_id=nautilus_pyo3.TraderId(self._trader_id.value),
+                instance_id=nautilus_pyo3.UUID4(self._instance_id.value),
+                config_json=msgspec.json.encode(config.message_bus),
+            )
         else:
             raise ValueError(
                 f"Unrecognized `config.message_bus.database.type`, was '{config.message_bus.database.type}'. "
-                "The only database type currently supported is 'redis', if you don't want a message bus database backing "
+                "The database types currently supported are 'redis', 'nats' and 'zmq', if you don't want a message bus database backing "
                 "then you can pass `None` for the `message_bus.database` ('in-memory' is no longer valid)",
             )
 
@@ -405,6 +417,13 @@ class NautilusKernel:
         if config.exec_engine and config.exec_engine.load_cache:
             self.exec_engine.load_cache()
 
+        if isinstance(self._data_engine, LiveDataEngine):
+            # Process commands and order events ahead of any data backlog
+            for engine in (self._risk_engine, self._exec_engine):
+                if isinstance(engine, LiveRiskEngine | LiveExecutionEngine):
+                    self._data_engine.register_priority_lane(engine.cmd_qsize)
+                    self._data_engine.register_priority_lane(engine.evt_qsize)
+
         self._emulator = OrderEmulator(
             portfolio=self._portfolio,
             msgbus=self._msgbus,
@@ -478,6 +497,10 @@ class NautilusKernel:
             exec_algorithm: ExecAlgorithm = ExecAlgorithmFactory.create(exec_algorithm_config)
             self._trader.add_exec_algorithm(exec_algorithm)
 
+        # Restore actors and strategies from a previous run
+        if self._load_state:
+            self._trader.restore_registrations()
+
         build_time_ms = nanos_to_millis(time.time_ns() - self.ts_created)
         self._log.info(f"Initialized in {build_time_ms}ms")
 
@@ -822,6 +845,10 @@ class NautilusKernel:
 
         self._start_engines()
         self._connect_clients()
+
+        if self._load_state:
+            self._trader.restore_subscriptions()
+
         self._emulator.start()
         self._initialize_portfolio()
         self._trader.start()
@@ -851,6 +878,9 @@ class NautilusKernel:
         if not await self._await_engines_connected():
             return
 
+        if self._load_state:
+            self._trader.restore_subscriptions()
+
         if not await self._await_execution_reconciliation():
             return
 
@@ -874,6 +904,9 @@ class NautilusKernel:
         if self._controller:
             self._controller.stop()
 
+        if self.save_state:
+            self._trader.save_subscriptions()
+
         if self._trader.is_running:
             self._trader.stop()
 
@@ -907,6 +940,9 @@ class NautilusKernel:
 
         self._log.info("STOPPING")
 
+        if self.save_state:
+            self._trader.save_subscriptions()
+
         if self._trader.is_running:
             self._trader.stop()
             await self._await_trader_residuals()
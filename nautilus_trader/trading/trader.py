@@ -25,6 +25,7 @@ import asyncio
 from collections.abc import Callable
 from typing import Any
 
+import msgspec
 import pandas as pd
 
 from nautilus_trader.analysis.reporter import ReportProvider
@@ -36,19 +37,35 @@ from nautilus_trader.common.component import MessageBus
 from nautilus_trader.common.component import deregister_component_clock
 from nautilus_trader.common.component import register_component_clock
 from nautilus_trader.common.component import remove_instance_component_clocks
+from nautilus_trader.common.config import ActorFactory
+from nautilus_trader.common.config import ImportableActorConfig
 from nautilus_trader.core.correctness import PyCondition
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.data.engine import DataEngine
+from nautilus_trader.data.messages import Subscribe
+from nautilus_trader.model.data import Bar
+from nautilus_trader.model.data import BarType
+from nautilus_trader.model.data import DataType
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
 from nautilus_trader.model.identifiers import ComponentId
 from nautilus_trader.model.identifiers import ExecAlgorithmId
+from nautilus_trader.model.identifiers import InstrumentId
 from nautilus_trader.model.identifiers import StrategyId
 from nautilus_trader.model.identifiers import TraderId
 from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.instruments import Instrument
 from nautilus_trader.portfolio.portfolio import Portfolio
 from nautilus_trader.risk.engine import RiskEngine
+from nautilus_trader.trading.config import ImportableStrategyConfig
+from nautilus_trader.trading.config import StrategyFactory
 from nautilus_trader.trading.strategy import Strategy
 
 
+REGISTRATIONS_KEY = "trader-registrations"
+SUBSCRIPTIONS_KEY = "data-subscriptions"
+
+
 class Trader(Component):
     """
     Provides a trader for managing a fleet of actors, execution algorithms and trading
@@ -769,6 +786,9 @@ class Trader(Component):
     def save(self) -> None:
         """
         Save all actor and strategy states to the cache.
+
+        The actor and strategy registrations are also saved, so they can be restored on a
+        warm restart.
         """
         for actor in self._actors.values():
             self._cache.update_actor(actor)
@@ -776,6 +796,8 @@ class Trader(Component):
         for strategy in self._strategies.values():
             self._cache.update_strategy(strategy)
 
+        self.save_registrations()
+
     def load(self) -> None:
         """
         Load all actor and strategy states from the cache.
@@ -786,6 +808,116 @@ class Trader(Component):
         for strategy in self._strategies.values():
             self._cache.load_strategy(strategy)
 
+    def save_registrations(self) -> None:
+        """
+        Save the importable configurations of all registered actors and strategies to the
+        cache.
+        """
+        registrations = {
+            "actors": [a.to_importable_config().json_primitives() for a in self._actors.values()],
+            "strategies": [
+                s.to_importable_config().json_primitives() for s in self._strategies.values()
+            ],
+        }
+        self._cache.add(REGISTRATIONS_KEY, msgspec.json.encode(registrations))
+
+    def restore_registrations(self) -> None:
+        """
+        Restore the actors and strategies saved in the cache which do not match the
+        configuration of a component already registered with the trader.
+
+        Restored components have their state loaded from the cache.
+        """
+        raw: bytes | None = self._cache.get(REGISTRATIONS_KEY)
+        if raw is None:
+            self._log.info("No registrations to restore")
+            return
+
+        registrations = msgspec.json.decode(raw)
+        registered = [
+            c.to_importable_config().json_primitives()
+            for c in [*self._actors.values(), *self._strategies.values()]
+        ]
+
+        for config in registrations.get("actors", []):
+            if config in registered:
+                continue
+            actor: Actor = ActorFactory.create(
+                ImportableActorConfig.parse(msgspec.json.encode(config)),
+            )
+            self.add_actor(actor)
+            self._cache.load_actor(actor)
+
+        for config in registrations.get("strategies", []):
+            if config in registered:
+                continue
+            strategy: Strategy = StrategyFactory.create(
+                ImportableStrategyConfig.parse(msgspec.json.encode(config)),
+            )
+            self.add_strategy(strategy)
+            self._cache.load_strategy(strategy)
+
+    def save_subscriptions(self) -> None:
+        """
+        Save the active instrument, quote tick, trade tick and bar subscriptions of the
+        data engine to the cache.
+
+        Order book subscriptions are not saved, as their book parameters are not tracked by
+        the data engine. This method should be called prior to stopping the trader, as
+        components may unsubscribe from data when stopped.
+        """
+        subscriptions = {
+            "instruments": [str(i) for i in self._data_engine.subscribed_instruments()],
+            "quote_ticks": [str(i) for i in self._data_engine.subscribed_quote_ticks()],
+            "trade_ticks": [str(i) for i in self._data_engine.subscribed_trade_ticks()],
+            "bars": [str(b) for b in self._data_engine.subscribed_bars()],
+        }
+        self._cache.add(SUBSCRIPTIONS_KEY, msgspec.json.encode(subscriptions))
+
+    def restore_subscriptions(self) -> None:
+        """
+        Re-establish the data subscriptions saved in the cache with the data engine.
+
+        The data clients should be connected prior to calling this method.
+        """
+        raw: bytes | None = self._cache.get(SUBSCRIPTIONS_KEY)
+        if raw is None:
+            self._log.info("No subscriptions to restore")
+            return
+
+        subscriptions = msgspec.json.decode(raw)
+
+        data_types: list[DataType] = []
+        for value in subscriptions.get("instruments", []):
+            instrument_id = InstrumentId.from_str(value)
+            data_types.append(DataType(Instrument, metadata={"instrument_id": instrument_id}))
+        for value in subscriptions.get("quote_ticks", []):
+            instrument_id = InstrumentId.from_str(value)
+            data_types.append(DataType(QuoteTick, metadata={"instrument_id": instrument_id}))
+        for value in subscriptions.get("trade_ticks", []):
+            instrument_id = InstrumentId.from_str(value)
+            data_types.append(DataType(TradeTick, metadata={"instrument_id": instrument_id}))
+        for value in subscriptions.get("bars", []):
+            bar_type = BarType.from_str(value)
+            data_types.append(
+                DataType(Bar, metadata={"bar_type": bar_type, "await_partial": False}),
+            )
+
+        for data_type in data_types:
+            instrument_id = data_type.metadata.get("instrument_id")
+            if instrument_id is None:
+                instrument_id = data_type.metadata["bar_type"].instrument_id
+            command = Subscribe(
+                client_id=None,
+                venue=instrument_id.venue,
+                data_type=data_type,
+                command_id=UUID4(),
+                ts_init=self._clock.timestamp_ns(),
+            )
+            self._data_engine.execute(command)
+
+        self._log.info(f"Restored {len(data_types)} data subscription(s)")
+
     def check_residuals(self) -> None:
         """
         Check for residual open state such as open orders or open positions.
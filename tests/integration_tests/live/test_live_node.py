@@ -23,7 +23,9 @@ from nautilus_trader.adapters.binance.config import BinanceDataClientConfig
 from nautilus_trader.adapters.binance.config import BinanceExecClientConfig
 from nautilus_trader.adapters.binance.factories import BinanceLiveDataClientFactory
 from nautilus_trader.adapters.binance.factories import BinanceLiveExecClientFactory
+from nautilus_trader.config import DatabaseConfig
 from nautilus_trader.config import InstrumentProviderConfig
+from nautilus_trader.config import LeaderElectionConfig
 from nautilus_trader.config import LoggingConfig
 from nautilus_trader.config import TradingNodeConfig
 from nautilus_trader.live.node import TradingNode
@@ -205,6 +207,21 @@ class TestTradingNodeConfiguration:
         assert len(node.kernel.instance_id.value) == 36
 
 
+    def test_leader_election_without_cache_database_raises_value_error(self):
+        # Arrange
+        loop = asyncio.new_event_loop()
+        asyncio.set_event_loop(loop)
+
+        config = TradingNodeConfig(
+            logging=LoggingConfig(bypass_logging=True),
+            leader_election=LeaderElectionConfig(database=DatabaseConfig()),
+        )
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            TradingNode(config=config, loop=loop)
+
+
 class TestTradingNodeOperation:
     def teardown(self):
         ensure_all_tasks_completed()
@@ -0,0 +1,147 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.backtest.calibration import CostModelParameters
+from nautilus_trader.backtest.calibration import calibrate_cost_model
+from nautilus_trader.backtest.calibration import load_cost_models
+from nautilus_trader.backtest.calibration import save_cost_models
+from nautilus_trader.backtest.models import FillModel
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
+
+
+AUDUSD_ID = TestIdStubs.audusd_id()
+
+
+def _quote(bid: str, ask: str, ts: int) -> QuoteTick:
+    return QuoteTick(
+        instrument_id=AUDUSD_ID,
+        bid_price=Price.from_str(bid),
+        ask_price=Price.from_str(ask),
+        bid_size=Quantity.from_int(1_000_000),
+        ask_size=Quantity.from_int(1_000_000),
+        ts_event=ts,
+        ts_init=ts,
+    )
+
+
+def _trade(price: str, side: AggressorSide, ts: int) -> TradeTick:
+    return TradeTick(
+        instrument_id=AUDUSD_ID,
+        price=Price.from_str(price),
+        size=Quantity.from_int(100_000),
+        aggressor_side=side,
+        trade_id=TradeId(str(ts)),
+        ts_event=ts,
+        ts_init=ts,
+    )
+
+
+class TestCostModelCalibration:
+    def test_calibrate_with_no_quotes_raises(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            calibrate_cost_model(AUDUSD_ID.value, quotes=[], trades=[])
+
+    def test_calibrate_cost_model(self):
+        # Arrange
+        quotes = [
+            _quote("0.80000", "0.80002", 0),
+            _quote("0.80001", "0.80005", 10),
+            _quote("0.80002", "0.80004", 20),
+            _quote("0.80002", "0.80004", 30),
+        ]
+        trades = [
+            _trade("0.79999", AggressorSide.SELLER, 1),  # Through the bid (slipped)
+            _trade("0.80005", AggressorSide.BUYER, 15),  # At the ask
+            _trade("0.80003", AggressorSide.BUYER, 25),  # Inside the spread
+        ]
+
+        # Act
+        params = calibrate_cost_model(
+            AUDUSD_ID.value,
+            quotes=quotes,
+            trades=trades,
+            price_increment=0.00001,
+        )
+
+        # Assert
+        assert params.quote_count == 4
+        assert params.trade_count == 3
+        assert params.avg_spread == pytest.approx(0.000025)
+        assert params.avg_spread_ticks == pytest.approx(2.5)
+        assert params.prob_fill_on_limit == pytest.approx(2 / 8)
+        assert params.prob_slippage == pytest.approx(1 / 3)
+
+    def test_trades_before_first_quote_are_ignored(self):
+        # Arrange
+        quotes = [_quote("0.80000", "0.80002", 10)]
+        trades = [_trade("0.80002", AggressorSide.BUYER, 5)]
+
+        # Act
+        params = calibrate_cost_model(AUDUSD_ID.value, quotes=quotes, trades=trades)
+
+        # Assert
+        assert params.trade_count == 0
+        assert params.prob_fill_on_limit == 0.0
+        assert params.prob_slippage == 0.0
+        assert params.avg_spread_ticks is None
+
+    def test_to_fill_model(self):
+        # Arrange
+        params = CostModelParameters(
+            instrument_id=AUDUSD_ID.value,
+            quote_count=100,
+            trade_count=10,
+            avg_spread=0.00002,
+            avg_spread_ticks=2.0,
+            prob_fill_on_limit=0.4,
+            prob_slippage=0.1,
+        )
+
+        # Act
+        fill_model = params.to_fill_model(random_seed=42)
+
+        # Assert
+        assert isinstance(fill_model, FillModel)
+        assert fill_model.prob_fill_on_limit == 0.4
+        assert fill_model.prob_slippage == 0.1
+
+    def test_save_and_load_cost_models(self, tmp_path):
+        # Arrange
+        params = CostModelParameters(
+            instrument_id=AUDUSD_ID.value,
+            quote_count=100,
+            trade_count=10,
+            avg_spread=0.00002,
+            avg_spread_ticks=None,
+            prob_fill_on_limit=0.4,
+            prob_slippage=0.1,
+        )
+        path = tmp_path / "cost_models.json"
+
+        # Act
+        save_cost_models([params], path)
+        loaded = load_cost_models(path)
+
+        # Assert
+        assert loaded == {AUDUSD_ID.value: params}
@@ -23,6 +23,7 @@ import pytest
 from nautilus_trader.backtest.engine import BacktestEngine
 from nautilus_trader.backtest.engine import BacktestEngineConfig
 from nautilus_trader.backtest.models import FillModel
+from nautilus_trader.backtest.results import BacktestProgress
 from nautilus_trader.common.actor import Actor
 from nautilus_trader.config import ImportableControllerConfig
 from nautilus_trader.config import InvalidConfiguration
@@ -44,6 +45,7 @@ from nautilus_trader.model.data import DataType
 from nautilus_trader.model.data import InstrumentStatus
 from nautilus_trader.model.data import OrderBookDelta
 from nautilus_trader.model.data import OrderBookDeltas
+from nautilus_trader.model.data import QuoteTick
 from nautilus_trader.model.enums import AccountType
 from nautilus_trader.model.enums import AggregationSource
 from nautilus_trader.model.enums import BarAggregation
@@ -195,6 +197,89 @@ class TestBacktestEngine:
         # Assert
         assert len(self.engine.trader.strategy_states()) == 1
 
+    def test_run_when_cancelled_before_run(self):
+        # Arrange
+        self.engine.cancel()
+
+        # Act
+        self.engine.run()
+
+        # Assert
+        assert self.engine.is_cancelled
+        assert self.engine.iteration == 0
+        assert self.engine.get_result().cancelled
+
+    def test_run_cancelled_during_run_returns_partial_results(self):
+        # Arrange
+        def handler(quote: QuoteTick) -> None:
+            if self.engine.iteration >= 100:
+                self.engine.cancel()
+
+        self.engine.kernel.msgbus.subscribe(topic="data.quotes.*", handler=handler)
+
+        # Act
+        self.engine.run()
+
+        # Assert
+        result = self.engine.get_result()
+        assert result.cancelled
+        assert 100 <= self.engine.iteration < 8000
+        assert result.iterations == self.engine.iteration
+        assert not self.engine.kernel.trader.is_running
+
+    def test_reset_clears_cancellation(self):
+        # Arrange
+        self.engine.cancel()
+
+        # Act
+        self.engine.reset()
+
+        # Assert
+        assert not self.engine.is_cancelled
+
+    def test_run_reports_progress_to_callbacks(self):
+        # Arrange
+        engine = self.create_engine(
+            BacktestEngineConfig(
+                logging=LoggingConfig(bypass_logging=True),
+                progress_interval=1_000,
+            ),
+        )
+        reports: list[BacktestProgress] = []
+        engine.register_progress_callback(reports.append)
+
+        # Act
+        engine.run()
+
+        # Assert
+        assert [p.processed for p in reports] == list(range(1_000, 9_000, 1_000))
+        assert reports[0].percent == 12.5
+        assert reports[-1].percent == 100.0
+        assert reports[-1].total == 8_000
+        engine.dispose()
+
+    def test_progress_callback_cancels_run(self):
+        # Arrange
+        engine = self.create_engine(
+            BacktestEngineConfig(
+                logging=LoggingConfig(bypass_logging=True),
+                progress_interval=1_000,
+            ),
+        )
+
+        def callback(progress: BacktestProgress) -> None:
+            engine.cancel()
+
+        engine.register_progress_callback(callback)
+
+        # Act
+        engine.run()
+
+        # Assert
+        assert engine.get_result().cancelled
+        assert 1_000 <= engine.iteration < 8_000
+        engine.dispose()
+
     def test_change_fill_model(self):
         # Arrange, Act
         self.engine.change_fill_model(Venue("SIM"), FillModel())
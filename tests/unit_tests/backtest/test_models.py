@@ -13,7 +13,11 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+import pytest
+
+from nautilus_trader.backtest.models import EmpiricalLatencyModel
 from nautilus_trader.backtest.models import FillModel
+from nautilus_trader.backtest.models import LatencyHistogram
 from nautilus_trader.backtest.models import LatencyModel
 
 
@@ -76,3 +80,59 @@ class TestExchangeLatency:
         assert latency.insert_latency_nanos == self.NANOSECONDS_IN_MILLISECOND
         assert latency.update_latency_nanos == self.NANOSECONDS_IN_MILLISECOND
         assert latency.cancel_latency_nanos == self.NANOSECONDS_IN_MILLISECOND
+        assert latency.get_insert_latency() == self.NANOSECONDS_IN_MILLISECOND
+
+
+class TestLatencyHistogram:
+    @pytest.mark.parametrize(
+        ("bounds_nanos", "counts"),
+        [
+            [[0, 100], [1, 1]],
+            [[100, 100], [1]],
+            [[0, 100], [0]],
+            [[0, 100], [-1]],
+        ],
+    )
+    def test_instantiate_with_invalid_values_raises(self, bounds_nanos, counts):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            LatencyHistogram(bounds_nanos=bounds_nanos, counts=counts)
+
+    def test_sample_within_populated_buckets(self):
+        # Arrange
+        histogram = LatencyHistogram.from_dict(
+            {"bounds_nanos": [0, 100, 200, 300], "counts": [0, 10, 0]},
+        )
+        model = EmpiricalLatencyModel(insert_histogram=histogram, random_seed=42)
+
+        # Act
+        latencies = [model.get_insert_latency() for _ in range(100)]
+
+        # Assert
+        assert histogram.total_count == 10
+        assert all(100 <= latency < 200 for latency in latencies)
+
+
+class TestEmpiricalLatencyModel:
+    def test_command_types_without_histogram_use_base_latency(self):
+        # Arrange
+        histogram = LatencyHistogram(bounds_nanos=[1_000, 2_000], counts=[1])
+        model = EmpiricalLatencyModel(cancel_histogram=histogram, base_latency_nanos=500)
+
+        # Act, Assert
+        assert model.get_insert_latency() == 500
+        assert model.get_update_latency() == 500
+        assert 1_000 <= model.get_cancel_latency() < 2_000
+
+    def test_samples_are_reproducible_with_random_seed(self):
+        # Arrange
+        histogram = LatencyHistogram(bounds_nanos=[0, 1_000, 10_000], counts=[9, 1])
+        model1 = EmpiricalLatencyModel(insert_histogram=histogram, random_seed=1)
+        model2 = EmpiricalLatencyModel(insert_histogram=histogram, random_seed=1)
+
+        # Act
+        latencies1 = [model1.get_insert_latency() for _ in range(10)]
+        latencies2 = [model2.get_insert_latency() for _ in range(10)]
+
+        # Assert
+        assert latencies1 == latencies2
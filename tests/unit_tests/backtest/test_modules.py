@@ -18,6 +18,8 @@ import pandas as pd
 from nautilus_trader.backtest.engine import BacktestEngine
 from nautilus_trader.backtest.modules import FXRolloverInterestConfig
 from nautilus_trader.backtest.modules import FXRolloverInterestModule
+from nautilus_trader.backtest.modules import MaintenanceWindowConfig
+from nautilus_trader.backtest.modules import MaintenanceWindowModule
 from nautilus_trader.backtest.modules import SimulationModule
 from nautilus_trader.common.component import Logger
 from nautilus_trader.config import BacktestEngineConfig
@@ -103,3 +105,34 @@ class TestSimulationModules:
 
         # Act
         engine.run()
+
+    def test_maintenance_window_module_drops_data_during_window(self):
+        # Arrange
+        config = MaintenanceWindowConfig(
+            windows=[("2013-02-01 00:03:00", "2013-02-01 00:06:00")],
+        )
+        module = MaintenanceWindowModule(config)
+        engine = self.create_engine(modules=[module])
+        baseline = self.create_engine(modules=[])
+
+        # Act
+        engine.run()
+        baseline.run()
+
+        # Assert
+        assert not module.exchange.is_halted
+        assert engine.kernel.data_engine.data_count < baseline.kernel.data_engine.data_count
+
+    def test_maintenance_window_module_weekly_window(self):
+        # Arrange (2013-02-01 is a Friday)
+        config = MaintenanceWindowConfig(weekly_windows=[(5, "00:03", 3)])
+        module = MaintenanceWindowModule(config)
+        engine = self.create_engine(modules=[module])
+        baseline = self.create_engine(modules=[])
+
+        # Act
+        engine.run()
+        baseline.run()
+
+        # Assert
+        assert engine.kernel.data_engine.data_count < baseline.kernel.data_engine.data_count
@@ -41,6 +41,7 @@ from nautilus_trader.model.enums import PositionSide
 from nautilus_trader.model.enums import TriggerType
 from nautilus_trader.model.events import OrderCanceled
 from nautilus_trader.model.events import OrderDenied
+from nautilus_trader.model.events import OrderModifyRejected
 from nautilus_trader.model.events import OrderUpdated
 from nautilus_trader.model.identifiers import ClientId
 from nautilus_trader.model.identifiers import ClientOrderId
@@ -905,6 +906,197 @@ class TestExecutionEngine:
         assert order.status == OrderStatus.FILLED
         assert order.quantity == Quantity.from_int(100_000)
 
+    def _setup_coalescing_exec_engine(self) -> Strategy:
+        self.msgbus.deregister("ExecEngine.execute", self.exec_engine.execute)
+        self.msgbus.deregister("ExecEngine.process", self.exec_engine.process)
+
+        self.exec_engine = ExecutionEngine(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=ExecEngineConfig(coalesce_modifies=True),
+        )
+        self.exec_engine.register_client(self.exec_client)
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        return strategy
+
+    def _modify(self, order, price: str) -> ModifyOrder:
+        return ModifyOrder(
+            self.trader_id,
+            order.strategy_id,
+            order.instrument_id,
+            order.client_order_id,
+            order.venue_order_id,
+            None,
+            Price.from_str(price),
+            None,
+            UUID4(),
+            self.clock.timestamp_ns(),
+        )
+
+    def test_modify_orders_when_modify_in_flight_then_coalesces_to_latest(self) -> None:
+        # Arrange
+        strategy = self._setup_coalescing_exec_engine()
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("0.80000"),
+        )
+        strategy.submit_order(order)
+        self.exec_engine.process(TestEventStubs.order_submitted(order))
+        self.exec_engine.process(TestEventStubs.order_accepted(order))
+
+        # Act
+        self.exec_engine.execute(self._modify(order, "0.80001"))
+        self.exec_engine.execute(self._modify(order, "0.80002"))
+        self.exec_engine.execute(self._modify(order, "0.80003"))
+
+        # Assert
+        assert self.exec_client.calls == ["_start", "submit_order", "modify_order"]
+        assert self.exec_engine.coalesced_modify_count == 1
+
+        self.exec_engine.process(
+            TestEventStubs.order_updated(order, price=Price.from_str("0.80001")),
+        )
+
+        assert self.exec_client.calls == ["_start", "submit_order", "modify_order", "modify_order"]
+        assert self.exec_client.commands[-1].price == Price.from_str("0.80003")
+        assert order.price == Price.from_str("0.80001")
+
+    def test_modify_order_when_in_flight_modify_rejected_then_sends_pending(self) -> None:
+        # Arrange
+        strategy = self._setup_coalescing_exec_engine()
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("0.80000"),
+        )
+        strategy.submit_order(order)
+        self.exec_engine.process(TestEventStubs.order_submitted(order))
+        self.exec_engine.process(TestEventStubs.order_accepted(order))
+        self.exec_engine.execute(self._modify(order, "0.80001"))
+        self.exec_engine.execute(self._modify(order, "0.80002"))
+
+        # Act
+        self.exec_engine.process(
+            OrderModifyRejected(
+                trader_id=order.trader_id,
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                venue_order_id=order.venue_order_id,
+                account_id=order.account_id,
+                reason="TOO_FAST",
+                event_id=UUID4(),
+                ts_event=0,
+                ts_init=0,
+            ),
+        )
+
+        # Assert
+        assert self.exec_client.calls == ["_start", "submit_order", "modify_order", "modify_order"]
+        assert self.exec_client.commands[-1].price == Price.from_str("0.80002")
+        assert self.exec_engine.coalesced_modify_count == 0
+
+    def test_modify_order_when_order_closed_with_modify_in_flight_then_drops_pending(self) -> None:
+        # Arrange
+        strategy = self._setup_coalescing_exec_engine()
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("0.80000"),
+        )
+        strategy.submit_order(order)
+        self.exec_engine.process(TestEventStubs.order_submitted(order))
+        self.exec_engine.process(TestEventStubs.order_accepted(order))
+        self.exec_engine.execute(self._modify(order, "0.80001"))
+        self.exec_engine.execute(self._modify(order, "0.80002"))
+
+        # Act
+        self.exec_engine.process(TestEventStubs.order_canceled(order))
+
+        # Assert
+        assert self.exec_client.calls == ["_start", "submit_order", "modify_order"]
+        assert self.exec_engine._pending_modifies == {}
+        assert self.exec_engine._modifies_in_flight == {}
+
+    def test_modify_order_when_venue_order_id_replaced_then_sends_pending_with_new_id(self) -> None:
+        # Arrange
+        strategy = self._setup_coalescing_exec_engine()
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("0.80000"),
+        )
+        strategy.submit_order(order)
+        self.exec_engine.process(TestEventStubs.order_submitted(order))
+        self.exec_engine.process(TestEventStubs.order_accepted(order))
+        self.exec_engine.execute(self._modify(order, "0.80001"))
+        self.exec_engine.execute(self._modify(order, "0.80002"))
+
+        # Act
+        self.exec_engine.process(
+            OrderUpdated(
+                trader_id=order.trader_id,
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                venue_order_id=VenueOrderId("2"),
+                account_id=order.account_id,
+                quantity=order.quantity,
+                price=Price.from_str("0.80001"),
+                trigger_price=None,
+                event_id=UUID4(),
+                ts_event=0,
+                ts_init=0,
+            ),
+        )
+
+        # Assert
+        assert self.exec_client.calls == ["_start", "submit_order", "modify_order", "modify_order"]
+        assert self.exec_client.commands[-1].price == Price.from_str("0.80002")
+        assert self.exec_client.commands[-1].venue_order_id == VenueOrderId("2")
+
+    def test_check_modifies_in_flight_when_timed_out_then_sends_pending(self) -> None:
+        # Arrange
+        strategy = self._setup_coalescing_exec_engine()
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("0.80000"),
+        )
+        strategy.submit_order(order)
+        self.exec_engine.process(TestEventStubs.order_submitted(order))
+        self.exec_engine.process(TestEventStubs.order_accepted(order))
+        self.exec_engine.execute(self._modify(order, "0.80001"))
+        self.exec_engine.execute(self._modify(order, "0.80002"))
+        self.exec_engine._check_modifies_in_flight()
+        assert self.exec_client.calls == ["_start", "submit_order", "modify_order"]
+
+        # Act
+        self.clock.advance_time(self.clock.timestamp_ns() + 10_000_000_000)
+        self.exec_engine._check_modifies_in_flight()
+
+        # Assert
+        assert self.exec_client.calls == ["_start", "submit_order", "modify_order", "modify_order"]
+        assert self.exec_client.commands[-1].price == Price.from_str("0.80002")
+        assert order.client_order_id in self.exec_engine._modifies_in_flight
+        assert self.exec_engine._pending_modifies == {}
+
     def test_handle_order_event_with_random_client_order_id_and_order_id_cached(self) -> None:
         # Arrange
         self.exec_engine.start()
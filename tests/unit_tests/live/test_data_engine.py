@@ -34,6 +34,7 @@ from nautilus_trader.model.identifiers import Symbol
 from nautilus_trader.model.identifiers import Venue
 from nautilus_trader.portfolio.portfolio import Portfolio
 from nautilus_trader.test_kit.functions import ensure_all_tasks_completed
+from nautilus_trader.test_kit.functions import eventually
 from nautilus_trader.test_kit.providers import TestInstrumentProvider
 from nautilus_trader.test_kit.stubs.component import TestComponentStubs
 from nautilus_trader.test_kit.stubs.data import TestDataStubs
@@ -361,3 +362,63 @@ class TestLiveDataEngine:
 
         # Tear Down
         self.engine.stop()
+
+    @pytest.mark.asyncio
+    @pytest.mark.parametrize(
+        ("prioritize_commands", "expected_index", "expected_yields"),
+        [
+            [True, 100, 1],  # Processed immediately after the current data item
+            [False, 1_000, 0],  # Processed after the entire data backlog
+        ],
+    )
+    async def test_priority_lane_processed_ahead_of_data_backlog(
+        self,
+        prioritize_commands: bool,
+        expected_index: int,
+        expected_yields: int,
+    ):
+        # Arrange
+        self.msgbus.deregister(endpoint="DataEngine.execute", handler=self.engine.execute)
+        self.msgbus.deregister(endpoint="DataEngine.process", handler=self.engine.process)
+        self.msgbus.deregister(endpoint="DataEngine.request", handler=self.engine.request)
+        self.msgbus.deregister(endpoint="DataEngine.response", handler=self.engine.response)
+
+        self.engine = LiveDataEngine(
+            loop=self.loop,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=LiveDataEngineConfig(prioritize_commands=prioritize_commands),
+        )
+
+        processed: list[str] = []
+        commands: asyncio.Queue = asyncio.Queue()
+
+        async def run_commands() -> None:
+            while True:
+                processed.append(await commands.get())
+
+        def handle_data(data: Data) -> None:
+            processed.append("data")
+            if len(processed) == 100:
+                # Command arrives part way through the data burst
+                commands.put_nowait("command")
+
+        command_task = self.loop.create_task(run_commands())
+        self.engine.register_priority_lane(commands.qsize)
+        self.engine._handle_data = handle_data
+        self.engine.start()
+
+        # Act
+        tick = TestDataStubs.trade_tick()
+        for _ in range(1_000):
+            self.engine.process(tick)
+        await eventually(lambda: len(processed) == 1_001)
+
+        # Assert
+        assert processed.index("command") == expected_index
+        assert self.engine.priority_yield_count == expected_yields
+
+        # Tear Down
+        self.engine.stop()
+        command_task.cancel()
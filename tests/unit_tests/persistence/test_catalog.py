@@ -314,6 +314,26 @@ def test_catalog_write_pyo3_quote_ticks(catalog: ParquetDataCatalog) -> None:
     assert len(all_quotes) == 100_000
 
 
+def test_catalog_query_iter_matches_query(catalog: ParquetDataCatalog) -> None:
+    # Arrange
+    path = TEST_DATA_DIR / "truefx" / "audusd-ticks.csv"
+    df = pd.read_csv(path)
+    instrument = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+    wrangler = QuoteTickDataWranglerV2.from_instrument(instrument)
+    catalog.write_data(sorted(wrangler.from_pandas(df), key=lambda x: x.ts_init))
+
+    # Act
+    quotes = catalog.query_iter(
+        QuoteTick,
+        instrument_ids=[instrument.id],
+        batch_size_bytes=100_000,
+    )
+
+    # Assert
+    assert not isinstance(quotes, list)
+    assert list(quotes) == catalog.quote_ticks(instrument_ids=[instrument.id])
+
+
 def test_catalog_write_pyo3_trade_ticks(catalog: ParquetDataCatalog) -> None:
     # Arrange
     path = TEST_DATA_DIR / "binance" / "ethusdt-trades.csv"
@@ -0,0 +1,150 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+
+from typing import Any, NamedTuple
+
+import pytest
+
+from nautilus_trader.persistence.join import asof_join
+
+
+class Item(NamedTuple):
+    ts_init: int
+    key: str
+    name: str
+
+
+LEFT = [
+    Item(5, "a", "l1"),
+    Item(10, "a", "l2"),
+    Item(20, "b", "l3"),
+    Item(30, "a", "l4"),
+]
+
+RIGHT = [
+    Item(3, "a", "r1"),
+    Item(10, "a", "r2"),
+    Item(12, "b", "r3"),
+    Item(25, "a", "r4"),
+]
+
+
+def _summarize(records: Any) -> list[tuple[str, str | None, int | None]]:
+    return [(r.left.name, r.right.name if r.right else None, r.delta_ns) for r in records]
+
+
+@pytest.mark.parametrize(
+    ("direction", "expected"),
+    [
+        (
+            "backward",
+            [("l1", "r1", -2), ("l2", "r2", 0), ("l3", "r3", -8), ("l4", "r4", -5)],
+        ),
+        (
+            "forward",
+            [("l1", "r2", 5), ("l2", "r2", 0), ("l3", "r4", 5), ("l4", None, None)],
+        ),
+        (
+            "nearest",
+            [("l1", "r1", -2), ("l2", "r2", 0), ("l3", "r4", 5), ("l4", "r4", -5)],
+        ),
+    ],
+)
+def test_asof_join_directions(direction: str, expected: list) -> None:
+    # Arrange, Act
+    records = asof_join(LEFT, RIGHT, direction=direction)
+
+    # Assert
+    assert _summarize(records) == expected
+
+
+@pytest.mark.parametrize(
+    ("direction", "expected"),
+    [
+        (
+            "backward",
+            [("l1", "r1", -2), ("l2", "r2", 0), ("l3", None, None), ("l4", None, None)],
+        ),
+        (
+            "forward",
+            [("l1", None, None), ("l2", "r2", 0), ("l3", None, None), ("l4", None, None)],
+        ),
+        (
+            "nearest",
+            [("l1", "r1", -2), ("l2", "r2", 0), ("l3", None, None), ("l4", None, None)],
+        ),
+    ],
+)
+def test_asof_join_with_tolerance(direction: str, expected: list) -> None:
+    # Arrange, Act
+    records = asof_join(LEFT, RIGHT, tolerance_ns=3, direction=direction)
+
+    # Assert
+    assert _summarize(records) == expected
+
+
+def test_asof_join_by_key() -> None:
+    # Arrange, Act
+    records = asof_join(LEFT, RIGHT, direction="forward", by=lambda x: x.key)
+
+    # Assert
+    assert _summarize(records) == [
+        ("l1", "r2", 5),
+        ("l2", "r2", 0),
+        ("l3", None, None),
+        ("l4", None, None),
+    ]
+
+
+def test_asof_join_consumes_streams_lazily() -> None:
+    # Arrange
+    consumed: list[int] = []
+
+    def right_stream():
+        for ts in range(0, 1_000_000, 10):
+            consumed.append(ts)
+            yield Item(ts, "a", f"r{ts}")
+
+    left = (Item(ts, "a", f"l{ts}") for ts in (15, 25))
+
+    # Act
+    records = list(asof_join(left, right_stream()))
+
+    # Assert
+    assert [r.right.ts_init for r in records] == [10, 20]
+    assert len(consumed) == 4  # Read one item ahead of the last left timestamp
+
+
+def test_asof_join_when_stream_not_ordered_raises_value_error() -> None:
+    # Arrange
+    right = [Item(10, "a", "r1"), Item(5, "a", "r2")]
+
+    # Act, Assert
+    with pytest.raises(ValueError):
+        list(asof_join(LEFT, right))
+
+
+@pytest.mark.parametrize(
+    ("kwargs"),
+    [
+        {"tolerance_ns": -1},
+        {"direction": "sideways"},
+    ],
+)
+def test_asof_join_with_invalid_args_raises_value_error(kwargs: dict) -> None:
+    # Arrange, Act, Assert
+    with pytest.raises(ValueError):
+        asof_join(LEFT, RIGHT, **kwargs)
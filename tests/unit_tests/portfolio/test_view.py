@@ -0,0 +1,217 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+
+from decimal import Decimal
+
+import pytest
+
+from nautilus_trader.accounting.factory import AccountFactory
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.common.factories import OrderFactory
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.model.currencies import BTC
+from nautilus_trader.model.currencies import USDT
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.events import AccountState
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import PositionId
+from nautilus_trader.model.identifiers import StrategyId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.objects import AccountBalance
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.model.position import Position
+from nautilus_trader.portfolio.view import PortfolioView
+from nautilus_trader.test_kit.mocks.cache_database import MockCacheDatabase
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.events import TestEventStubs
+from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
+
+
+BINANCE = Venue("BINANCE")
+BTCUSDT_BINANCE = TestInstrumentProvider.btcusdt_binance()
+
+
+class ReadOnlyMockCacheDatabase(MockCacheDatabase):
+    """
+    Provides a mock cache database which fails on any write.
+    """
+
+    def _fail(self, *args, **kwargs) -> None:
+        raise RuntimeError("Cache database is read-only")
+
+    flush = _fail
+    add_currency = _fail
+    add_instrument = _fail
+    add_synthetic = _fail
+    add_account = _fail
+    add_order = _fail
+    add_position = _fail
+    index_order_position = _fail
+    update_account = _fail
+    update_order = _fail
+    update_position = _fail
+    update_strategy = _fail
+    delete_strategy = _fail
+
+
+class TestPortfolioView:
+    def setup(self):
+        # Fixture Setup
+        AccountFactory.register_calculated_account("BINANCE")
+
+        self.trader_id = TestIdStubs.trader_id()
+        self.account_id = AccountId("BINANCE-01234")
+        self.order_factory = OrderFactory(
+            trader_id=self.trader_id,
+            strategy_id=StrategyId("S-001"),
+            clock=TestClock(),
+        )
+
+        # Populate the database as a live trader would have
+        self.database = ReadOnlyMockCacheDatabase()
+        self.database.instruments[BTCUSDT_BINANCE.id] = BTCUSDT_BINANCE
+        account = AccountFactory.create(
+            AccountState(
+                account_id=self.account_id,
+                account_type=AccountType.MARGIN,
+                base_currency=None,  # Multi-currency account
+                reported=True,
+                balances=[
+                    AccountBalance(
+                        Money(10.00000000, BTC),
+                        Money(0.00000000, BTC),
+                        Money(10.00000000, BTC),
+                    ),
+                    AccountBalance(
+                        Money(100000.00000000, USDT),
+                        Money(0.00000000, USDT),
+                        Money(100000.00000000, USDT),
+                    ),
+                ],
+                margins=[],
+                info={},
+                event_id=UUID4(),
+                ts_event=0,
+                ts_init=0,
+            ),
+        )
+        self.database.accounts[account.id] = account
+
+        self.view = PortfolioView(trader_id=self.trader_id, database=self.database)
+
+    def _add_open_order(self):
+        order = self.order_factory.limit(
+            BTCUSDT_BINANCE.id,
+            OrderSide.BUY,
+            Quantity.from_str("1.000000"),
+            Price.from_str("10000.00"),
+        )
+        order.apply(TestEventStubs.order_submitted(order, account_id=self.account_id))
+        order.apply(TestEventStubs.order_accepted(order, account_id=self.account_id))
+        self.database.orders[order.client_order_id] = order
+        return order
+
+    def _add_open_position(self):
+        order = self.order_factory.market(
+            BTCUSDT_BINANCE.id,
+            OrderSide.BUY,
+            Quantity.from_str("10.000000"),
+        )
+        fill = TestEventStubs.order_filled(
+            order=order,
+            instrument=BTCUSDT_BINANCE,
+            strategy_id=StrategyId("S-001"),
+            account_id=self.account_id,
+            position_id=PositionId("P-123456"),
+            last_px=Price.from_str("10500.00"),
+        )
+        order.apply(fill)
+        position = Position(instrument=BTCUSDT_BINANCE, fill=fill)
+        self.database.orders[order.client_order_id] = order
+        self.database.positions[position.id] = position
+        return position
+
+    def test_view_when_database_empty_returns_no_orders_or_positions(self):
+        # Arrange
+        view = PortfolioView(trader_id=self.trader_id, database=ReadOnlyMockCacheDatabase())
+
+        # Act
+        view.refresh()
+
+        # Assert
+        assert view.open_orders() == []
+        assert view.open_positions() == []
+        assert view.net_position(BTCUSDT_BINANCE.id) == Decimal(0)
+
+    def test_refresh_hydrates_open_orders_and_positions(self):
+        # Arrange
+        order = self._add_open_order()
+        position = self._add_open_position()
+
+        # Act
+        self.view.refresh()
+
+        # Assert
+        assert self.view.open_orders() == [order]
+        assert self.view.open_orders(side=OrderSide.SELL) == []
+        assert self.view.open_positions(venue=BINANCE) == [position]
+        assert self.view.net_position(BTCUSDT_BINANCE.id) == Decimal("10.00000000")
+        assert self.view.portfolio.account(BINANCE).id == self.account_id
+
+    def test_refresh_picks_up_changes_made_by_live_trader(self):
+        # Arrange
+        self.view.refresh()
+        assert self.view.open_orders() == []
+
+        # Act
+        order = self._add_open_order()
+        self.view.refresh()
+
+        # Assert
+        assert self.view.open_orders() == [order]
+
+    @pytest.mark.parametrize(
+        ("bid", "ask", "expected"),
+        [
+            ("10510.00", "10511.00", Money(105100.00000000, USDT)),
+            ("10490.00", "10491.00", Money(104900.00000000, USDT)),
+        ],
+    )
+    def test_net_exposures_marked_with_provided_prices(self, bid, ask, expected):
+        # Arrange
+        self._add_open_position()
+        self.view.refresh()
+
+        quote = QuoteTick(
+            instrument_id=BTCUSDT_BINANCE.id,
+            bid_price=Price.from_str(bid),
+            ask_price=Price.from_str(ask),
+            bid_size=Quantity.from_str("1.000000"),
+            ask_size=Quantity.from_str("1.000000"),
+            ts_event=0,
+            ts_init=0,
+        )
+
+        # Act
+        self.view.update_quote_tick(quote)
+
+        # Assert
+        assert self.view.net_exposures(BINANCE) == {USDT: expected}
+        assert self.view.net_exposure(BTCUSDT_BINANCE.id) == expected
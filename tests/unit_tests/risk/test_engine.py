@@ -23,6 +23,7 @@ from nautilus_trader.common.component import TestClock
 from nautilus_trader.common.messages import TradingStateChanged
 from nautilus_trader.config import ExecEngineConfig
 from nautilus_trader.config import RiskEngineConfig
+from nautilus_trader.core.datetime import secs_to_nanos
 from nautilus_trader.core.message import Event
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.execution.emulator import OrderEmulator
@@ -32,6 +33,7 @@ from nautilus_trader.execution.messages import SubmitOrder
 from nautilus_trader.execution.messages import SubmitOrderList
 from nautilus_trader.execution.messages import TradingCommand
 from nautilus_trader.model.currencies import ADA
+from nautilus_trader.model.currencies import AUD
 from nautilus_trader.model.currencies import ETH
 from nautilus_trader.model.currencies import GBP
 from nautilus_trader.model.currencies import USD
@@ -66,6 +68,8 @@ from nautilus_trader.test_kit.stubs.component import TestComponentStubs
 from nautilus_trader.test_kit.stubs.data import TestDataStubs
 from nautilus_trader.test_kit.stubs.events import TestEventStubs
 from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
+from nautilus_trader.trading.filters import NewsEvent
+from nautilus_trader.trading.filters import NewsImpact
 from nautilus_trader.trading.strategy import Strategy
 
 
@@ -1957,6 +1961,222 @@ class TestRiskEngineWithCashAccount:
         assert order.trigger_price == new_trigger_price
 
 
+    def _setup_news_blackout(self, **kwargs) -> Strategy:
+        self.msgbus.deregister("RiskEngine.execute", self.risk_engine.execute)
+        self.msgbus.deregister("RiskEngine.process", self.risk_engine.process)
+
+        self.risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=RiskEngineConfig(
+                news_blackout_instruments=[str(_AUDUSD_SIM.id)],
+                news_blackout_before_secs=300,
+                news_blackout_after_secs=300,
+                **kwargs,
+            ),
+        )
+        self.risk_engine.add_news_event(
+            NewsEvent(
+                impact=NewsImpact.HIGH,
+                name="RBA Interest Rate Decision",
+                currency=AUD,
+                ts_event=secs_to_nanos(600),
+                ts_init=0,
+            ),
+        )
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        return strategy
+
+    @pytest.mark.parametrize(
+        ("ts_secs", "expected_event"),
+        [
+            [0, False],
+            [300, True],
+            [600, True],
+            [900, True],
+            [901, False],
+        ],
+    )
+    def test_news_blackout_event_window(self, ts_secs: int, expected_event: bool):
+        # Arrange
+        self._setup_news_blackout()
+
+        # Act
+        event = self.risk_engine.news_blackout_event(_AUDUSD_SIM, secs_to_nanos(ts_secs))
+
+        # Assert
+        assert (event is not None) == expected_event
+        assert self.risk_engine.news_blackout_event(_GBPUSD_SIM, secs_to_nanos(ts_secs)) is None
+
+    def test_news_blackout_ignores_lower_impact_events(self):
+        # Arrange
+        self._setup_news_blackout()
+        self.risk_engine.add_news_event(
+            NewsEvent(
+                impact=NewsImpact.LOW,
+                name="Building Approvals",
+                currency=AUD,
+                ts_event=secs_to_nanos(2_000),
+                ts_init=0,
+            ),
+        )
+
+        # Act
+        event = self.risk_engine.news_blackout_event(_AUDUSD_SIM, secs_to_nanos(2_000))
+
+        # Assert
+        assert event is None
+
+    def test_submit_order_during_news_blackout_then_denies(self):
+        # Arrange
+        strategy = self._setup_news_blackout()
+        self.clock.set_time(secs_to_nanos(500))
+
+        order = strategy.order_factory.market(
+            _AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert order.status == OrderStatus.DENIED
+        assert self.exec_engine.command_count == 0
+
+    def test_submit_order_after_news_blackout_then_sends_to_client(self):
+        # Arrange
+        strategy = self._setup_news_blackout()
+        self.clock.set_time(secs_to_nanos(1_000))
+
+        order = strategy.order_factory.market(
+            _AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert order.status == OrderStatus.INITIALIZED
+        assert self.exec_client.calls == ["_start", "submit_order"]
+
+    def test_submit_order_during_news_blackout_when_strategy_exempt_then_sends_to_client(self):
+        # Arrange
+        strategy = self._setup_news_blackout(
+            news_blackout_exempt_strategies=["Strategy-None"],
+        )
+        self.clock.set_time(secs_to_nanos(500))
+
+        order = strategy.order_factory.market(
+            _AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert order.status == OrderStatus.INITIALIZED
+        assert self.exec_client.calls == ["_start", "submit_order"]
+
+    def test_set_news_blackout_exempt_then_removing_override_denies(self):
+        # Arrange
+        strategy = self._setup_news_blackout(
+            news_blackout_exempt_strategies=["Strategy-None"],
+        )
+        self.risk_engine.set_news_blackout_exempt(strategy.id, False)
+        self.clock.set_time(secs_to_nanos(500))
+
+        order = strategy.order_factory.market(
+            _AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert order.status == OrderStatus.DENIED
+
+    def test_submit_order_during_news_blackout_in_reduce_mode_when_flat_then_denies(self):
+        # Arrange
+        strategy = self._setup_news_blackout(news_blackout_mode="REDUCE")
+        self.clock.set_time(secs_to_nanos(700))
+
+        order = strategy.order_factory.market(
+            _AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100_000),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert order.status == OrderStatus.DENIED
+        assert self.exec_engine.command_count == 0
+
 class TestRiskEngineWithBettingAccount:
     def setup(self):
         # Fixture Setup
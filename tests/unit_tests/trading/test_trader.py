@@ -536,3 +536,55 @@ class TestTrader:
 
         # Assert
         assert len(self.msgbus.subscriptions("events*")) == 5
+
+    def test_restore_registrations_when_nothing_saved_does_nothing(self) -> None:
+        # Arrange, Act
+        self.trader.restore_registrations()
+
+        # Assert
+        assert self.trader.actors() == []
+        assert self.trader.strategies() == []
+
+    def test_save_and_restore_registrations(self) -> None:
+        # Arrange
+        self.trader.add_actor(Actor(config=ActorConfig(component_id=ComponentId("MyActor-001"))))
+        self.trader.add_strategy(
+            MyStrategy(config=MyStrategyConfig(instrument_id=USDJPY_SIM.id, order_id_tag="001")),
+        )
+        self.trader.save()
+        self.trader.clear_actors()
+        self.trader.clear_strategies()
+
+        # Act
+        self.trader.restore_registrations()
+
+        # Assert
+        assert self.trader.actor_ids() == [ComponentId("MyActor-001")]
+        assert self.trader.strategy_ids() == [StrategyId("MyStrategy-001")]
+        assert self.trader.strategies()[0].config.instrument_id == USDJPY_SIM.id
+
+    def test_restore_registrations_skips_already_registered_components(self) -> None:
+        # Arrange
+        config = MyStrategyConfig(instrument_id=USDJPY_SIM.id, order_id_tag="001")
+        self.trader.add_strategy(MyStrategy(config=config))
+        self.trader.save()
+
+        # Act
+        self.trader.restore_registrations()
+
+        # Assert
+        assert self.trader.strategy_ids() == [StrategyId("MyStrategy-001")]
+
+    def test_save_and_restore_subscriptions(self) -> None:
+        # Arrange
+        self.data_client.start()
+        self.data_client.subscribe_quote_ticks(USDJPY_SIM.id)
+        self.trader.save_subscriptions()
+        self.data_client.unsubscribe_quote_ticks(USDJPY_SIM.id)
+
+        # Act
+        self.trader.restore_subscriptions()
+
+        # Assert
+        assert self.data_engine.subscribed_quote_ticks() == [USDJPY_SIM.id]
+        assert self.data_engine.subscribed_trade_ticks() == []